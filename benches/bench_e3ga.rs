@@ -1,4 +1,8 @@
-use cggeomalg::e3ga::EuclideanMultivector3;
+use cggeomalg::e3ga::{
+    Bivector3,
+    EuclideanMultivector3,
+    Vector3,
+};
 use core::ops::{
     Add,
     BitOr,
@@ -42,6 +46,26 @@ where
     )
 }
 
+fn gen_vector3<S>() -> Vector3<S>
+where
+    StandardUniform: Distribution<S>,
+{
+    use rand::SeedableRng;
+    let mut rng = IsaacRng::seed_from_u64(0);
+
+    Vector3::new(rng.random(), rng.random(), rng.random())
+}
+
+fn gen_bivector3<S>() -> Bivector3<S>
+where
+    StandardUniform: Distribution<S>,
+{
+    use rand::SeedableRng;
+    let mut rng = IsaacRng::seed_from_u64(0);
+
+    Bivector3::new(rng.random(), rng.random(), rng.random())
+}
+
 macro_rules! bench_binop(
     ($name: ident, $scalar_type:ty, $type1:ty, $type2:ty, $generator_t1:ident, $generator_t2:ident, $binop:ident) => {
         fn $name(bh: &mut criterion::Criterion) {
@@ -144,6 +168,43 @@ bench_binop!(
     shr
 );
 
+bench_binop!(
+    vector3_mul_vector3_f32,
+    f32,
+    Vector3<f32>,
+    Vector3<f32>,
+    gen_vector3,
+    gen_vector3,
+    mul
+);
+bench_binop!(
+    vector3_outer_product_vector3_f32,
+    f32,
+    Vector3<f32>,
+    Vector3<f32>,
+    gen_vector3,
+    gen_vector3,
+    bitxor
+);
+bench_binop!(
+    vector3_mul_bivector3_f32,
+    f32,
+    Vector3<f32>,
+    Bivector3<f32>,
+    gen_vector3,
+    gen_bivector3,
+    mul
+);
+bench_binop!(
+    bivector3_mul_bivector3_f32,
+    f32,
+    Bivector3<f32>,
+    Bivector3<f32>,
+    gen_bivector3,
+    gen_bivector3,
+    mul
+);
+
 bench_binop_ref!(
     multivector3_commutator_multivector3_f32,
     f32,
@@ -198,6 +259,10 @@ criterion_group!(
     multivector3_scalar_product_multivector3_f32,
     multivector3_left_contract_multivector3_f32,
     multivector3_right_contract_multivector3_f32,
+    vector3_mul_vector3_f32,
+    vector3_outer_product_vector3_f32,
+    vector3_mul_bivector3_f32,
+    bivector3_mul_bivector3_f32,
     multivector3_commutator_multivector3_f32,
     multivector3_anticommutator_multivector3_f32,
     multivector3_magnitude_f32,
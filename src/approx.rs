@@ -0,0 +1,246 @@
+/*
+ * Generate the `approx_cmp` comparison trait implementations for a data type
+ * whose components are stored in an array field named `data`.
+ *
+ * Each comparison is delegated component-wise to the underlying array, so
+ * that the tolerance types of a data type `T<S>` are `T<S::Tolerance>` for
+ * the component-wise comparisons and `S::AllTolerance` for the uniform
+ * comparisons. For example, invoking the macro on a rotor type
+ * ```text
+ * impl_approx_cmp_ops!(Rotor3);
+ * ```
+ * generates `AbsDiffEq`, `RelativeEq`, `UlpsEq`, their `All` variants, and
+ * all of their assertion counterparts for `Rotor3<S>` with `S: ScalarFloat`.
+ * Data types with const generic parameters after the scalar parameter list
+ * them explicitly, as in
+ * ```text
+ * impl_approx_cmp_ops!(Multivector<S, const P: usize, const Q: usize, const R: usize, const N: usize>);
+ * ```
+ */
+#[macro_export]
+macro_rules! impl_approx_cmp_ops {
+    ($T:ident) => {
+        $crate::impl_approx_cmp_ops!($T<S>);
+    };
+    ($T:ident<S $(, const $param:ident: $ty:ty)*>) => {
+        impl<S $(, const $param: $ty)*> ::approx_cmp::AbsDiffEq for $T<S $(, $param)*>
+        where
+            S: $crate::scalar::ScalarFloat,
+        {
+            type Tolerance = $T<<S as ::approx_cmp::AbsDiffEq>::Tolerance $(, $param)*>;
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> bool {
+                ::approx_cmp::AbsDiffEq::abs_diff_eq(&self.data, &other.data, &max_abs_diff.data)
+            }
+        }
+
+        impl<S $(, const $param: $ty)*> ::approx_cmp::AbsDiffAllEq for $T<S $(, $param)*>
+        where
+            S: $crate::scalar::ScalarFloat,
+        {
+            type AllTolerance = <S as ::approx_cmp::AbsDiffAllEq>::AllTolerance;
+
+            #[inline]
+            fn abs_diff_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> bool {
+                ::approx_cmp::AbsDiffAllEq::abs_diff_all_eq(&self.data, &other.data, max_abs_diff)
+            }
+        }
+
+        impl<S $(, const $param: $ty)*> ::approx_cmp::AssertAbsDiffEq for $T<S $(, $param)*>
+        where
+            S: $crate::scalar::ScalarFloat,
+        {
+            type DebugAbsDiff = $T<<S as ::approx_cmp::AssertAbsDiffEq>::DebugAbsDiff $(, $param)*>;
+            type DebugTolerance = $T<<S as ::approx_cmp::AssertAbsDiffEq>::DebugTolerance $(, $param)*>;
+
+            #[inline]
+            fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+                let data = ::approx_cmp::AssertAbsDiffEq::debug_abs_diff(&self.data, &other.data);
+
+                $T { data }
+            }
+
+            #[inline]
+            fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+                let data = ::approx_cmp::AssertAbsDiffEq::debug_abs_diff_tolerance(&self.data, &other.data, &max_abs_diff.data);
+
+                $T { data }
+            }
+        }
+
+        impl<S $(, const $param: $ty)*> ::approx_cmp::AssertAbsDiffAllEq for $T<S $(, $param)*>
+        where
+            S: $crate::scalar::ScalarFloat,
+        {
+            type AllDebugTolerance = $T<<S as ::approx_cmp::AssertAbsDiffAllEq>::AllDebugTolerance $(, $param)*>;
+
+            #[inline]
+            fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+                let data = ::approx_cmp::AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(&self.data, &other.data, max_abs_diff);
+
+                $T { data }
+            }
+        }
+
+        impl<S $(, const $param: $ty)*> ::approx_cmp::RelativeEq for $T<S $(, $param)*>
+        where
+            S: $crate::scalar::ScalarFloat,
+        {
+            type Tolerance = $T<<S as ::approx_cmp::RelativeEq>::Tolerance $(, $param)*>;
+
+            #[inline]
+            fn relative_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+                ::approx_cmp::RelativeEq::relative_eq(&self.data, &other.data, &max_abs_diff.data, &max_relative.data)
+            }
+        }
+
+        impl<S $(, const $param: $ty)*> ::approx_cmp::RelativeAllEq for $T<S $(, $param)*>
+        where
+            S: $crate::scalar::ScalarFloat,
+        {
+            type AllTolerance = <S as ::approx_cmp::RelativeAllEq>::AllTolerance;
+
+            #[inline]
+            fn relative_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance, max_relative: &Self::AllTolerance) -> bool {
+                ::approx_cmp::RelativeAllEq::relative_all_eq(&self.data, &other.data, max_abs_diff, max_relative)
+            }
+        }
+
+        impl<S $(, const $param: $ty)*> ::approx_cmp::AssertRelativeEq for $T<S $(, $param)*>
+        where
+            S: $crate::scalar::ScalarFloat,
+        {
+            type DebugAbsDiff = $T<<S as ::approx_cmp::AssertRelativeEq>::DebugAbsDiff $(, $param)*>;
+            type DebugTolerance = $T<<S as ::approx_cmp::AssertRelativeEq>::DebugTolerance $(, $param)*>;
+
+            #[inline]
+            fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+                let data = ::approx_cmp::AssertRelativeEq::debug_abs_diff(&self.data, &other.data);
+
+                $T { data }
+            }
+
+            #[inline]
+            fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+                let data = ::approx_cmp::AssertRelativeEq::debug_abs_diff_tolerance(&self.data, &other.data, &max_abs_diff.data);
+
+                $T { data }
+            }
+
+            #[inline]
+            fn debug_relative_tolerance(&self, other: &Self, max_relative: &Self::Tolerance) -> Self::DebugTolerance {
+                let data = ::approx_cmp::AssertRelativeEq::debug_relative_tolerance(&self.data, &other.data, &max_relative.data);
+
+                $T { data }
+            }
+        }
+
+        impl<S $(, const $param: $ty)*> ::approx_cmp::AssertRelativeAllEq for $T<S $(, $param)*>
+        where
+            S: $crate::scalar::ScalarFloat,
+        {
+            type AllDebugTolerance = $T<<S as ::approx_cmp::AssertRelativeAllEq>::AllDebugTolerance $(, $param)*>;
+
+            #[inline]
+            fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+                let data = ::approx_cmp::AssertRelativeAllEq::debug_abs_diff_all_tolerance(&self.data, &other.data, max_abs_diff);
+
+                $T { data }
+            }
+
+            #[inline]
+            fn debug_relative_all_tolerance(&self, other: &Self, max_relative: &Self::AllTolerance) -> Self::AllDebugTolerance {
+                let data = ::approx_cmp::AssertRelativeAllEq::debug_relative_all_tolerance(&self.data, &other.data, max_relative);
+
+                $T { data }
+            }
+        }
+
+        impl<S $(, const $param: $ty)*> ::approx_cmp::UlpsEq for $T<S $(, $param)*>
+        where
+            S: $crate::scalar::ScalarFloat,
+        {
+            type Tolerance = $T<<S as ::approx_cmp::UlpsEq>::Tolerance $(, $param)*>;
+            type UlpsTolerance = $T<<S as ::approx_cmp::UlpsEq>::UlpsTolerance $(, $param)*>;
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+                ::approx_cmp::UlpsEq::ulps_eq(&self.data, &other.data, &max_abs_diff.data, &max_ulps.data)
+            }
+        }
+
+        impl<S $(, const $param: $ty)*> ::approx_cmp::UlpsAllEq for $T<S $(, $param)*>
+        where
+            S: $crate::scalar::ScalarFloat,
+        {
+            type AllTolerance = <S as ::approx_cmp::UlpsAllEq>::AllTolerance;
+            type AllUlpsTolerance = <S as ::approx_cmp::UlpsAllEq>::AllUlpsTolerance;
+
+            #[inline]
+            fn ulps_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance, max_ulps: &Self::AllUlpsTolerance) -> bool {
+                ::approx_cmp::UlpsAllEq::ulps_all_eq(&self.data, &other.data, max_abs_diff, max_ulps)
+            }
+        }
+
+        impl<S $(, const $param: $ty)*> ::approx_cmp::AssertUlpsEq for $T<S $(, $param)*>
+        where
+            S: $crate::scalar::ScalarFloat,
+        {
+            type DebugAbsDiff = $T<<S as ::approx_cmp::AssertUlpsEq>::DebugAbsDiff $(, $param)*>;
+            type DebugUlpsDiff = $T<<S as ::approx_cmp::AssertUlpsEq>::DebugUlpsDiff $(, $param)*>;
+            type DebugTolerance = $T<<S as ::approx_cmp::AssertUlpsEq>::DebugTolerance $(, $param)*>;
+            type DebugUlpsTolerance = $T<<S as ::approx_cmp::AssertUlpsEq>::DebugUlpsTolerance $(, $param)*>;
+
+            #[inline]
+            fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+                let data = ::approx_cmp::AssertUlpsEq::debug_abs_diff(&self.data, &other.data);
+
+                $T { data }
+            }
+
+            #[inline]
+            fn debug_ulps_diff(&self, other: &Self) -> Self::DebugUlpsDiff {
+                let data = ::approx_cmp::AssertUlpsEq::debug_ulps_diff(&self.data, &other.data);
+
+                $T { data }
+            }
+
+            #[inline]
+            fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+                let data = ::approx_cmp::AssertUlpsEq::debug_abs_diff_tolerance(&self.data, &other.data, &max_abs_diff.data);
+
+                $T { data }
+            }
+
+            #[inline]
+            fn debug_ulps_tolerance(&self, other: &Self, max_ulps: &Self::UlpsTolerance) -> Self::DebugUlpsTolerance {
+                let data = ::approx_cmp::AssertUlpsEq::debug_ulps_tolerance(&self.data, &other.data, &max_ulps.data);
+
+                $T { data }
+            }
+        }
+
+        impl<S $(, const $param: $ty)*> ::approx_cmp::AssertUlpsAllEq for $T<S $(, $param)*>
+        where
+            S: $crate::scalar::ScalarFloat,
+        {
+            type AllDebugTolerance = $T<<S as ::approx_cmp::AssertUlpsAllEq>::AllDebugTolerance $(, $param)*>;
+            type AllDebugUlpsTolerance = $T<<S as ::approx_cmp::AssertUlpsAllEq>::AllDebugUlpsTolerance $(, $param)*>;
+
+            #[inline]
+            fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+                let data = ::approx_cmp::AssertUlpsAllEq::debug_abs_diff_all_tolerance(&self.data, &other.data, max_abs_diff);
+
+                $T { data }
+            }
+
+            #[inline]
+            fn debug_ulps_all_tolerance(&self, other: &Self, max_ulps: &Self::AllUlpsTolerance) -> Self::AllDebugUlpsTolerance {
+                let data = ::approx_cmp::AssertUlpsAllEq::debug_ulps_all_tolerance(&self.data, &other.data, max_ulps);
+
+                $T { data }
+            }
+        }
+    };
+}
@@ -0,0 +1,4283 @@
+use crate::e2ga::EuclideanMultivector2;
+use crate::scalar::{
+    Scalar,
+    ScalarFloat,
+    ScalarSigned,
+};
+use crate::{
+    impl_approx_cmp_ops,
+    impl_coords,
+    impl_coords_deref,
+};
+use approx_cmp::ulps_ne;
+use core::fmt;
+use core::ops;
+
+
+/// A stack-allocated, two-dimensional conformal multivector in the basis
+/// `{1, e0, e1, e2, einf, e01, e02, e12, e1inf, e2inf, e0inf, e012, e01inf, e02inf, e12inf, e012inf}`.
+///
+/// This is the geometric algebra `Cl(3, 1)`, written in a null basis. The basis
+/// vectors `e1` and `e2` are orthonormal, and they are orthogonal to the null basis
+/// vectors `e0` and `einf`, which represent the origin and the point at infinity,
+/// and satisfy
+/// ```text
+/// e0 * e0 == einf * einf == 0
+/// e0 | einf == -1
+/// ```
+/// The basis blades are the outer products of the basis vectors. Geometric objects
+/// are represented by blades spanned by the points they contain: circles and lines
+/// are three-blades, and point pairs and flat points are two-blades. The outer
+/// product `^` computes the join of geometric objects, and the regressive product
+/// `&` computes their meet (intersection).
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ConformalMultivector2<S> {
+    data: [S; 16],
+}
+
+impl<S> ConformalMultivector2<S> {
+    /// Construct a new general multivector.
+    #[inline]
+    pub const fn new(
+        scalar: S,
+        e0: S,
+        e1: S,
+        e2: S,
+        einf: S,
+        e01: S,
+        e02: S,
+        e12: S,
+        e1inf: S,
+        e2inf: S,
+        e0inf: S,
+        e012: S,
+        e01inf: S,
+        e02inf: S,
+        e12inf: S,
+        e012inf: S,
+    ) -> Self {
+        Self {
+            data: [scalar, e0, e1, e2, einf, e01, e02, e12, e1inf, e2inf, e0inf, e012, e01inf, e02inf, e12inf, e012inf],
+        }
+    }
+
+    /// Returns the number of components in a multivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// #
+    /// let mv = ConformalMultivector2::new(1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32);
+    ///
+    /// assert_eq!(mv.len(), 16);
+    /// ```
+    #[inline]
+    pub const fn len(&self) -> usize {
+        16
+    }
+
+    /// Get a pointer to the underlying component array.
+    #[inline]
+    pub const fn as_ptr(&self) -> *const S {
+        &self.data[0]
+    }
+
+    /// Get a mutable pointer to the underlying component array.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut S {
+        &mut self.data[0]
+    }
+
+    /// Get a slice of the underlying elements of the data type.
+    #[inline]
+    pub fn as_slice(&self) -> &[S] {
+        <Self as AsRef<[S; 16]>>::as_ref(self)
+    }
+}
+
+impl<S> ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    /// Construct the additive unit (zero) multivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// #
+    /// let mv = ConformalMultivector2::new(
+    ///     1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64,
+    ///     9_f64, 10_f64, 11_f64, 12_f64, 13_f64, 14_f64, 15_f64, 16_f64
+    /// );
+    /// let zero: ConformalMultivector2<f64> = ConformalMultivector2::zero();
+    ///
+    /// assert_eq!(mv + zero, mv);
+    /// assert_eq!(zero + mv, mv);
+    /// ```
+    #[inline]
+    pub fn zero() -> Self {
+        Self { data: [S::zero(); 16] }
+    }
+
+    /// Determine whether a multivector is the zero mutlivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// #
+    /// let zero: ConformalMultivector2<f64> = ConformalMultivector2::zero();
+    ///
+    /// assert!(zero.is_zero());
+    ///
+    /// let mv = ConformalMultivector2::new(
+    ///     1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64,
+    ///     9_f64, 10_f64, 11_f64, 12_f64, 13_f64, 14_f64, 15_f64, 16_f64
+    /// );
+    ///
+    /// assert!(!mv.is_zero());
+    /// ```
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.data.iter().all(|component| component.is_zero())
+    }
+
+    /// Construct a new multivector from the scalar part only.
+    ///
+    /// A scalar is a multivector whose vector, bivector, etc. components are
+    /// all zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// #
+    /// let scalar_part = 2;
+    /// let scalar = ConformalMultivector2::from_scalar(scalar_part);
+    ///
+    /// assert_eq!(scalar.scalar, scalar_part);
+    /// assert_eq!(scalar.e0, 0);
+    /// assert_eq!(scalar.e1, 0);
+    /// assert_eq!(scalar.e2, 0);
+    /// assert_eq!(scalar.einf, 0);
+    /// assert_eq!(scalar.e01, 0);
+    /// assert_eq!(scalar.e02, 0);
+    /// assert_eq!(scalar.e12, 0);
+    /// assert_eq!(scalar.e1inf, 0);
+    /// assert_eq!(scalar.e2inf, 0);
+    /// assert_eq!(scalar.e0inf, 0);
+    /// assert_eq!(scalar.e012, 0);
+    /// assert_eq!(scalar.e01inf, 0);
+    /// assert_eq!(scalar.e02inf, 0);
+    /// assert_eq!(scalar.e12inf, 0);
+    /// assert_eq!(scalar.e012inf, 0);
+    /// ```
+    #[inline]
+    pub fn from_scalar(scalar: S) -> Self {
+        let mut result = Self::zero();
+        result.data[0] = scalar;
+
+        result
+    }
+
+    /// Returns the unit scalar.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// #
+    /// let unit_scalar: ConformalMultivector2<isize> = ConformalMultivector2::unit_scalar();
+    ///
+    /// assert_eq!(unit_scalar.scalar, 1);
+    /// assert_eq!(unit_scalar.e0, 0);
+    /// assert_eq!(unit_scalar.e1, 0);
+    /// assert_eq!(unit_scalar.e2, 0);
+    /// assert_eq!(unit_scalar.einf, 0);
+    /// assert_eq!(unit_scalar.e01, 0);
+    /// assert_eq!(unit_scalar.e02, 0);
+    /// assert_eq!(unit_scalar.e12, 0);
+    /// assert_eq!(unit_scalar.e1inf, 0);
+    /// assert_eq!(unit_scalar.e2inf, 0);
+    /// assert_eq!(unit_scalar.e0inf, 0);
+    /// assert_eq!(unit_scalar.e012, 0);
+    /// assert_eq!(unit_scalar.e01inf, 0);
+    /// assert_eq!(unit_scalar.e02inf, 0);
+    /// assert_eq!(unit_scalar.e12inf, 0);
+    /// assert_eq!(unit_scalar.e012inf, 0);
+    /// ```
+    #[inline]
+    pub fn unit_scalar() -> Self {
+        let mut result = Self::zero();
+        result.data[0] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e0` vector, which represents the origin.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// #
+    /// let unit_e0: ConformalMultivector2<isize> = ConformalMultivector2::unit_e0();
+    ///
+    /// assert_eq!(unit_e0.scalar, 0);
+    /// assert_eq!(unit_e0.e0, 1);
+    /// assert_eq!(unit_e0.e1, 0);
+    /// assert_eq!(unit_e0.e2, 0);
+    /// assert_eq!(unit_e0.einf, 0);
+    /// assert_eq!(unit_e0.e01, 0);
+    /// assert_eq!(unit_e0.e02, 0);
+    /// assert_eq!(unit_e0.e12, 0);
+    /// assert_eq!(unit_e0.e1inf, 0);
+    /// assert_eq!(unit_e0.e2inf, 0);
+    /// assert_eq!(unit_e0.e0inf, 0);
+    /// assert_eq!(unit_e0.e012, 0);
+    /// assert_eq!(unit_e0.e01inf, 0);
+    /// assert_eq!(unit_e0.e02inf, 0);
+    /// assert_eq!(unit_e0.e12inf, 0);
+    /// assert_eq!(unit_e0.e012inf, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e0() -> Self {
+        let mut result = Self::zero();
+        result.data[1] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `x`-axis vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// #
+    /// let unit_e1: ConformalMultivector2<isize> = ConformalMultivector2::unit_e1();
+    ///
+    /// assert_eq!(unit_e1.scalar, 0);
+    /// assert_eq!(unit_e1.e0, 0);
+    /// assert_eq!(unit_e1.e1, 1);
+    /// assert_eq!(unit_e1.e2, 0);
+    /// assert_eq!(unit_e1.einf, 0);
+    /// assert_eq!(unit_e1.e01, 0);
+    /// assert_eq!(unit_e1.e02, 0);
+    /// assert_eq!(unit_e1.e12, 0);
+    /// assert_eq!(unit_e1.e1inf, 0);
+    /// assert_eq!(unit_e1.e2inf, 0);
+    /// assert_eq!(unit_e1.e0inf, 0);
+    /// assert_eq!(unit_e1.e012, 0);
+    /// assert_eq!(unit_e1.e01inf, 0);
+    /// assert_eq!(unit_e1.e02inf, 0);
+    /// assert_eq!(unit_e1.e12inf, 0);
+    /// assert_eq!(unit_e1.e012inf, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e1() -> Self {
+        let mut result = Self::zero();
+        result.data[2] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `y`-axis vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// #
+    /// let unit_e2: ConformalMultivector2<isize> = ConformalMultivector2::unit_e2();
+    ///
+    /// assert_eq!(unit_e2.scalar, 0);
+    /// assert_eq!(unit_e2.e0, 0);
+    /// assert_eq!(unit_e2.e1, 0);
+    /// assert_eq!(unit_e2.e2, 1);
+    /// assert_eq!(unit_e2.einf, 0);
+    /// assert_eq!(unit_e2.e01, 0);
+    /// assert_eq!(unit_e2.e02, 0);
+    /// assert_eq!(unit_e2.e12, 0);
+    /// assert_eq!(unit_e2.e1inf, 0);
+    /// assert_eq!(unit_e2.e2inf, 0);
+    /// assert_eq!(unit_e2.e0inf, 0);
+    /// assert_eq!(unit_e2.e012, 0);
+    /// assert_eq!(unit_e2.e01inf, 0);
+    /// assert_eq!(unit_e2.e02inf, 0);
+    /// assert_eq!(unit_e2.e12inf, 0);
+    /// assert_eq!(unit_e2.e012inf, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e2() -> Self {
+        let mut result = Self::zero();
+        result.data[3] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `einf` vector, which represents the point at infinity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// #
+    /// let unit_einf: ConformalMultivector2<isize> = ConformalMultivector2::unit_einf();
+    ///
+    /// assert_eq!(unit_einf.scalar, 0);
+    /// assert_eq!(unit_einf.e0, 0);
+    /// assert_eq!(unit_einf.e1, 0);
+    /// assert_eq!(unit_einf.e2, 0);
+    /// assert_eq!(unit_einf.einf, 1);
+    /// assert_eq!(unit_einf.e01, 0);
+    /// assert_eq!(unit_einf.e02, 0);
+    /// assert_eq!(unit_einf.e12, 0);
+    /// assert_eq!(unit_einf.e1inf, 0);
+    /// assert_eq!(unit_einf.e2inf, 0);
+    /// assert_eq!(unit_einf.e0inf, 0);
+    /// assert_eq!(unit_einf.e012, 0);
+    /// assert_eq!(unit_einf.e01inf, 0);
+    /// assert_eq!(unit_einf.e02inf, 0);
+    /// assert_eq!(unit_einf.e12inf, 0);
+    /// assert_eq!(unit_einf.e012inf, 0);
+    /// ```
+    #[inline]
+    pub fn unit_einf() -> Self {
+        let mut result = Self::zero();
+        result.data[4] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e01` bivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// #
+    /// let unit_e01: ConformalMultivector2<isize> = ConformalMultivector2::unit_e01();
+    ///
+    /// assert_eq!(unit_e01.scalar, 0);
+    /// assert_eq!(unit_e01.e0, 0);
+    /// assert_eq!(unit_e01.e1, 0);
+    /// assert_eq!(unit_e01.e2, 0);
+    /// assert_eq!(unit_e01.einf, 0);
+    /// assert_eq!(unit_e01.e01, 1);
+    /// assert_eq!(unit_e01.e02, 0);
+    /// assert_eq!(unit_e01.e12, 0);
+    /// assert_eq!(unit_e01.e1inf, 0);
+    /// assert_eq!(unit_e01.e2inf, 0);
+    /// assert_eq!(unit_e01.e0inf, 0);
+    /// assert_eq!(unit_e01.e012, 0);
+    /// assert_eq!(unit_e01.e01inf, 0);
+    /// assert_eq!(unit_e01.e02inf, 0);
+    /// assert_eq!(unit_e01.e12inf, 0);
+    /// assert_eq!(unit_e01.e012inf, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e01() -> Self {
+        let mut result = Self::zero();
+        result.data[5] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e02` bivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// #
+    /// let unit_e02: ConformalMultivector2<isize> = ConformalMultivector2::unit_e02();
+    ///
+    /// assert_eq!(unit_e02.scalar, 0);
+    /// assert_eq!(unit_e02.e0, 0);
+    /// assert_eq!(unit_e02.e1, 0);
+    /// assert_eq!(unit_e02.e2, 0);
+    /// assert_eq!(unit_e02.einf, 0);
+    /// assert_eq!(unit_e02.e01, 0);
+    /// assert_eq!(unit_e02.e02, 1);
+    /// assert_eq!(unit_e02.e12, 0);
+    /// assert_eq!(unit_e02.e1inf, 0);
+    /// assert_eq!(unit_e02.e2inf, 0);
+    /// assert_eq!(unit_e02.e0inf, 0);
+    /// assert_eq!(unit_e02.e012, 0);
+    /// assert_eq!(unit_e02.e01inf, 0);
+    /// assert_eq!(unit_e02.e02inf, 0);
+    /// assert_eq!(unit_e02.e12inf, 0);
+    /// assert_eq!(unit_e02.e012inf, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e02() -> Self {
+        let mut result = Self::zero();
+        result.data[6] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e12` bivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// #
+    /// let unit_e12: ConformalMultivector2<isize> = ConformalMultivector2::unit_e12();
+    ///
+    /// assert_eq!(unit_e12.scalar, 0);
+    /// assert_eq!(unit_e12.e0, 0);
+    /// assert_eq!(unit_e12.e1, 0);
+    /// assert_eq!(unit_e12.e2, 0);
+    /// assert_eq!(unit_e12.einf, 0);
+    /// assert_eq!(unit_e12.e01, 0);
+    /// assert_eq!(unit_e12.e02, 0);
+    /// assert_eq!(unit_e12.e12, 1);
+    /// assert_eq!(unit_e12.e1inf, 0);
+    /// assert_eq!(unit_e12.e2inf, 0);
+    /// assert_eq!(unit_e12.e0inf, 0);
+    /// assert_eq!(unit_e12.e012, 0);
+    /// assert_eq!(unit_e12.e01inf, 0);
+    /// assert_eq!(unit_e12.e02inf, 0);
+    /// assert_eq!(unit_e12.e12inf, 0);
+    /// assert_eq!(unit_e12.e012inf, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e12() -> Self {
+        let mut result = Self::zero();
+        result.data[7] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e1inf` bivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// #
+    /// let unit_e1inf: ConformalMultivector2<isize> = ConformalMultivector2::unit_e1inf();
+    ///
+    /// assert_eq!(unit_e1inf.scalar, 0);
+    /// assert_eq!(unit_e1inf.e0, 0);
+    /// assert_eq!(unit_e1inf.e1, 0);
+    /// assert_eq!(unit_e1inf.e2, 0);
+    /// assert_eq!(unit_e1inf.einf, 0);
+    /// assert_eq!(unit_e1inf.e01, 0);
+    /// assert_eq!(unit_e1inf.e02, 0);
+    /// assert_eq!(unit_e1inf.e12, 0);
+    /// assert_eq!(unit_e1inf.e1inf, 1);
+    /// assert_eq!(unit_e1inf.e2inf, 0);
+    /// assert_eq!(unit_e1inf.e0inf, 0);
+    /// assert_eq!(unit_e1inf.e012, 0);
+    /// assert_eq!(unit_e1inf.e01inf, 0);
+    /// assert_eq!(unit_e1inf.e02inf, 0);
+    /// assert_eq!(unit_e1inf.e12inf, 0);
+    /// assert_eq!(unit_e1inf.e012inf, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e1inf() -> Self {
+        let mut result = Self::zero();
+        result.data[8] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e2inf` bivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// #
+    /// let unit_e2inf: ConformalMultivector2<isize> = ConformalMultivector2::unit_e2inf();
+    ///
+    /// assert_eq!(unit_e2inf.scalar, 0);
+    /// assert_eq!(unit_e2inf.e0, 0);
+    /// assert_eq!(unit_e2inf.e1, 0);
+    /// assert_eq!(unit_e2inf.e2, 0);
+    /// assert_eq!(unit_e2inf.einf, 0);
+    /// assert_eq!(unit_e2inf.e01, 0);
+    /// assert_eq!(unit_e2inf.e02, 0);
+    /// assert_eq!(unit_e2inf.e12, 0);
+    /// assert_eq!(unit_e2inf.e1inf, 0);
+    /// assert_eq!(unit_e2inf.e2inf, 1);
+    /// assert_eq!(unit_e2inf.e0inf, 0);
+    /// assert_eq!(unit_e2inf.e012, 0);
+    /// assert_eq!(unit_e2inf.e01inf, 0);
+    /// assert_eq!(unit_e2inf.e02inf, 0);
+    /// assert_eq!(unit_e2inf.e12inf, 0);
+    /// assert_eq!(unit_e2inf.e012inf, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e2inf() -> Self {
+        let mut result = Self::zero();
+        result.data[9] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e0inf` bivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// #
+    /// let unit_e0inf: ConformalMultivector2<isize> = ConformalMultivector2::unit_e0inf();
+    ///
+    /// assert_eq!(unit_e0inf.scalar, 0);
+    /// assert_eq!(unit_e0inf.e0, 0);
+    /// assert_eq!(unit_e0inf.e1, 0);
+    /// assert_eq!(unit_e0inf.e2, 0);
+    /// assert_eq!(unit_e0inf.einf, 0);
+    /// assert_eq!(unit_e0inf.e01, 0);
+    /// assert_eq!(unit_e0inf.e02, 0);
+    /// assert_eq!(unit_e0inf.e12, 0);
+    /// assert_eq!(unit_e0inf.e1inf, 0);
+    /// assert_eq!(unit_e0inf.e2inf, 0);
+    /// assert_eq!(unit_e0inf.e0inf, 1);
+    /// assert_eq!(unit_e0inf.e012, 0);
+    /// assert_eq!(unit_e0inf.e01inf, 0);
+    /// assert_eq!(unit_e0inf.e02inf, 0);
+    /// assert_eq!(unit_e0inf.e12inf, 0);
+    /// assert_eq!(unit_e0inf.e012inf, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e0inf() -> Self {
+        let mut result = Self::zero();
+        result.data[10] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e012` trivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// #
+    /// let unit_e012: ConformalMultivector2<isize> = ConformalMultivector2::unit_e012();
+    ///
+    /// assert_eq!(unit_e012.scalar, 0);
+    /// assert_eq!(unit_e012.e0, 0);
+    /// assert_eq!(unit_e012.e1, 0);
+    /// assert_eq!(unit_e012.e2, 0);
+    /// assert_eq!(unit_e012.einf, 0);
+    /// assert_eq!(unit_e012.e01, 0);
+    /// assert_eq!(unit_e012.e02, 0);
+    /// assert_eq!(unit_e012.e12, 0);
+    /// assert_eq!(unit_e012.e1inf, 0);
+    /// assert_eq!(unit_e012.e2inf, 0);
+    /// assert_eq!(unit_e012.e0inf, 0);
+    /// assert_eq!(unit_e012.e012, 1);
+    /// assert_eq!(unit_e012.e01inf, 0);
+    /// assert_eq!(unit_e012.e02inf, 0);
+    /// assert_eq!(unit_e012.e12inf, 0);
+    /// assert_eq!(unit_e012.e012inf, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e012() -> Self {
+        let mut result = Self::zero();
+        result.data[11] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e01inf` trivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// #
+    /// let unit_e01inf: ConformalMultivector2<isize> = ConformalMultivector2::unit_e01inf();
+    ///
+    /// assert_eq!(unit_e01inf.scalar, 0);
+    /// assert_eq!(unit_e01inf.e0, 0);
+    /// assert_eq!(unit_e01inf.e1, 0);
+    /// assert_eq!(unit_e01inf.e2, 0);
+    /// assert_eq!(unit_e01inf.einf, 0);
+    /// assert_eq!(unit_e01inf.e01, 0);
+    /// assert_eq!(unit_e01inf.e02, 0);
+    /// assert_eq!(unit_e01inf.e12, 0);
+    /// assert_eq!(unit_e01inf.e1inf, 0);
+    /// assert_eq!(unit_e01inf.e2inf, 0);
+    /// assert_eq!(unit_e01inf.e0inf, 0);
+    /// assert_eq!(unit_e01inf.e012, 0);
+    /// assert_eq!(unit_e01inf.e01inf, 1);
+    /// assert_eq!(unit_e01inf.e02inf, 0);
+    /// assert_eq!(unit_e01inf.e12inf, 0);
+    /// assert_eq!(unit_e01inf.e012inf, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e01inf() -> Self {
+        let mut result = Self::zero();
+        result.data[12] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e02inf` trivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// #
+    /// let unit_e02inf: ConformalMultivector2<isize> = ConformalMultivector2::unit_e02inf();
+    ///
+    /// assert_eq!(unit_e02inf.scalar, 0);
+    /// assert_eq!(unit_e02inf.e0, 0);
+    /// assert_eq!(unit_e02inf.e1, 0);
+    /// assert_eq!(unit_e02inf.e2, 0);
+    /// assert_eq!(unit_e02inf.einf, 0);
+    /// assert_eq!(unit_e02inf.e01, 0);
+    /// assert_eq!(unit_e02inf.e02, 0);
+    /// assert_eq!(unit_e02inf.e12, 0);
+    /// assert_eq!(unit_e02inf.e1inf, 0);
+    /// assert_eq!(unit_e02inf.e2inf, 0);
+    /// assert_eq!(unit_e02inf.e0inf, 0);
+    /// assert_eq!(unit_e02inf.e012, 0);
+    /// assert_eq!(unit_e02inf.e01inf, 0);
+    /// assert_eq!(unit_e02inf.e02inf, 1);
+    /// assert_eq!(unit_e02inf.e12inf, 0);
+    /// assert_eq!(unit_e02inf.e012inf, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e02inf() -> Self {
+        let mut result = Self::zero();
+        result.data[13] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e12inf` trivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// #
+    /// let unit_e12inf: ConformalMultivector2<isize> = ConformalMultivector2::unit_e12inf();
+    ///
+    /// assert_eq!(unit_e12inf.scalar, 0);
+    /// assert_eq!(unit_e12inf.e0, 0);
+    /// assert_eq!(unit_e12inf.e1, 0);
+    /// assert_eq!(unit_e12inf.e2, 0);
+    /// assert_eq!(unit_e12inf.einf, 0);
+    /// assert_eq!(unit_e12inf.e01, 0);
+    /// assert_eq!(unit_e12inf.e02, 0);
+    /// assert_eq!(unit_e12inf.e12, 0);
+    /// assert_eq!(unit_e12inf.e1inf, 0);
+    /// assert_eq!(unit_e12inf.e2inf, 0);
+    /// assert_eq!(unit_e12inf.e0inf, 0);
+    /// assert_eq!(unit_e12inf.e012, 0);
+    /// assert_eq!(unit_e12inf.e01inf, 0);
+    /// assert_eq!(unit_e12inf.e02inf, 0);
+    /// assert_eq!(unit_e12inf.e12inf, 1);
+    /// assert_eq!(unit_e12inf.e012inf, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e12inf() -> Self {
+        let mut result = Self::zero();
+        result.data[14] = S::one();
+
+        result
+    }
+
+    /// Returns the unit volume element `e012inf`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// #
+    /// let unit_e012inf: ConformalMultivector2<isize> = ConformalMultivector2::unit_e012inf();
+    ///
+    /// assert_eq!(unit_e012inf.scalar, 0);
+    /// assert_eq!(unit_e012inf.e0, 0);
+    /// assert_eq!(unit_e012inf.e1, 0);
+    /// assert_eq!(unit_e012inf.e2, 0);
+    /// assert_eq!(unit_e012inf.einf, 0);
+    /// assert_eq!(unit_e012inf.e01, 0);
+    /// assert_eq!(unit_e012inf.e02, 0);
+    /// assert_eq!(unit_e012inf.e12, 0);
+    /// assert_eq!(unit_e012inf.e1inf, 0);
+    /// assert_eq!(unit_e012inf.e2inf, 0);
+    /// assert_eq!(unit_e012inf.e0inf, 0);
+    /// assert_eq!(unit_e012inf.e012, 0);
+    /// assert_eq!(unit_e012inf.e01inf, 0);
+    /// assert_eq!(unit_e012inf.e02inf, 0);
+    /// assert_eq!(unit_e012inf.e12inf, 0);
+    /// assert_eq!(unit_e012inf.e012inf, 1);
+    /// ```
+    #[inline]
+    pub fn unit_e012inf() -> Self {
+        let mut result = Self::zero();
+        result.data[15] = S::one();
+
+        result
+    }
+
+    /// Returns the unit volume element for two-dimensional conformal geometric algebra.
+    ///
+    /// This is a synonym for `unit_e012inf`.
+    #[inline(always)]
+    pub fn pseudoscalar() -> Self {
+        Self::unit_e012inf()
+    }
+
+    /// Project the multivector onto the grade `grade`.
+    ///
+    /// Return a multivector where the components of each grade other than
+    /// input grade are zero. For each grade larger than the dimension of the
+    /// underlying vector space, the grade projection is always zero. In this
+    /// case, any grade projection onto a grade larger than 4 will be zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// #
+    /// let mv: ConformalMultivector2<isize> = ConformalMultivector2::new(1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize);
+    /// let expected_0 = ConformalMultivector2::new(1_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize);
+    /// let mv_0 = mv.grade(0);
+    /// let expected_1 = ConformalMultivector2::new(0_isize, 1_isize, 1_isize, 1_isize, 1_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize);
+    /// let mv_1 = mv.grade(1);
+    /// let expected_2 = ConformalMultivector2::new(0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize);
+    /// let mv_2 = mv.grade(2);
+    /// let expected_3 = ConformalMultivector2::new(0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 1_isize, 1_isize, 1_isize, 1_isize, 0_isize);
+    /// let mv_3 = mv.grade(3);
+    /// let expected_4 = ConformalMultivector2::new(0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 1_isize);
+    /// let mv_4 = mv.grade(4);
+    ///
+    /// assert_eq!(mv_0, expected_0);
+    /// assert_eq!(mv_1, expected_1);
+    /// assert_eq!(mv_2, expected_2);
+    /// assert_eq!(mv_3, expected_3);
+    /// assert_eq!(mv_4, expected_4);
+    ///
+    /// // Any grade larger than 4 should be zero.
+    /// let zero: ConformalMultivector2<isize> = ConformalMultivector2::zero();
+    /// assert_eq!(mv.grade(5), zero);
+    /// assert_eq!(mv.grade(usize::MAX), zero);
+    /// ```
+    #[inline]
+    pub fn grade(&self, grade: usize) -> Self {
+        let mut result = Self::zero();
+        match grade {
+            0 => {
+                result.data[0] = self.data[0];
+            }
+            1 => {
+                result.data[1] = self.data[1];
+                result.data[2] = self.data[2];
+                result.data[3] = self.data[3];
+                result.data[4] = self.data[4];
+            }
+            2 => {
+                result.data[5] = self.data[5];
+                result.data[6] = self.data[6];
+                result.data[7] = self.data[7];
+                result.data[8] = self.data[8];
+                result.data[9] = self.data[9];
+                result.data[10] = self.data[10];
+            }
+            3 => {
+                result.data[11] = self.data[11];
+                result.data[12] = self.data[12];
+                result.data[13] = self.data[13];
+                result.data[14] = self.data[14];
+            }
+            4 => {
+                result.data[15] = self.data[15];
+            }
+            _ => {}
+        }
+
+        result
+    }
+
+    /// Compute the left contraction of `self` with `other`.
+    ///
+    /// This is a synonym for the `<<` operator.
+    #[inline]
+    pub fn left_contract(&self, other: &Self) -> Self {
+        self << other
+    }
+
+    /// Compute the right contraction of `self` with `other`.
+    ///
+    /// This is a synonym for the `>>` operator.
+    #[inline]
+    pub fn right_contract(&self, other: &Self) -> Self {
+        self >> other
+    }
+
+    /// Compute the scalar product of `self` and `other`.
+    ///
+    /// This is a synonym for the `|` operator.
+    #[inline]
+    pub fn scalar_product(&self, other: &Self) -> Self {
+        self | other
+    }
+
+    /// Compute the outer product of `self` and `other`.
+    ///
+    /// This is a synonym for the `^` operator.
+    #[inline]
+    pub fn outer_product(&self, other: &Self) -> Self {
+        self ^ other
+    }
+
+    /// Compute the regressive product of `self` and `other`.
+    ///
+    /// The regressive product is the outer product of the duals, mapped back
+    /// by the inverse of the duality map
+    /// ```text
+    /// mv1 & mv2 := undual(dual(mv1) ^ dual(mv2))
+    /// ```
+    /// In conformal geometric algebra, the regressive product computes the
+    /// meet of geometric objects. For instance, the meet of two circles is
+    /// their point pair of intersection.
+    ///
+    /// This is a synonym for the `&` operator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// #
+    /// let e012: ConformalMultivector2<i32> = ConformalMultivector2::unit_e012();
+    /// let e12inf: ConformalMultivector2<i32> = ConformalMultivector2::unit_e12inf();
+    /// let result = e012.regressive_product(&e12inf);
+    ///
+    /// assert_eq!(result, (e012.dual() ^ e12inf.dual()).undual());
+    /// assert_eq!(result, -ConformalMultivector2::unit_e12());
+    /// ```
+    #[inline]
+    pub fn regressive_product(&self, other: &Self) -> Self {
+        self & other
+    }
+}
+
+impl<S> ops::Index<usize> for ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = S;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.data[index]
+    }
+}
+
+impl<S> ops::IndexMut<usize> for ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.data[index]
+    }
+}
+
+impl<S> AsRef<[S; 16]> for ConformalMultivector2<S> {
+    #[inline]
+    fn as_ref(&self) -> &[S; 16] {
+        unsafe { &*(self as *const ConformalMultivector2<S> as *const [S; 16]) }
+    }
+}
+
+impl<S> AsMut<[S; 16]> for ConformalMultivector2<S> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [S; 16] {
+        unsafe { &mut *(self as *mut ConformalMultivector2<S> as *mut [S; 16]) }
+    }
+}
+
+impl<S> fmt::Display for ConformalMultivector2<S>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "{} + {}^e0 + {}^e1 + {}^e2 + {}^einf + {}^e01 + {}^e02 + {}^e12 + {}^e1inf + {}^e2inf + {}^e0inf + {}^e012 + {}^e01inf + {}^e02inf + {}^e12inf + {}^e012inf",
+            self.data[0],
+            self.data[1],
+            self.data[2],
+            self.data[3],
+            self.data[4],
+            self.data[5],
+            self.data[6],
+            self.data[7],
+            self.data[8],
+            self.data[9],
+            self.data[10],
+            self.data[11],
+            self.data[12],
+            self.data[13],
+            self.data[14],
+            self.data[15],
+        )
+    }
+}
+
+
+impl<S> ConformalMultivector2<S>
+where
+    S: ScalarSigned,
+{
+    /// Compute the reverse of a multivector.
+    ///
+    /// The reverse of a multivector reverses the order of the vectors in each
+    /// basis blade. For each grade of multivector it is given by
+    /// ```text
+    /// When mv has grade 0, rev(mv) := mv
+    /// When mv has grade 1, rev(mv) := mv
+    /// When mv has grade 2, rev(mv) := -mv
+    /// When mv has grade 3, rev(mv) := -mv
+    /// When mv has grade 4, rev(mv) := mv
+    /// ```
+    /// The reverse of each basis blade is
+    /// ```text
+    /// rev(1)       = 1
+    /// rev(e0)      = e0
+    /// rev(e1)      = e1
+    /// rev(e2)      = e2
+    /// rev(einf)    = einf
+    /// rev(e01)     = -e01
+    /// rev(e02)     = -e02
+    /// rev(e12)     = -e12
+    /// rev(e1inf)   = -e1inf
+    /// rev(e2inf)   = -e2inf
+    /// rev(e0inf)   = -e0inf
+    /// rev(e012)    = -e012
+    /// rev(e01inf)  = -e01inf
+    /// rev(e02inf)  = -e02inf
+    /// rev(e12inf)  = -e12inf
+    /// rev(e012inf) = e012inf
+    /// ```
+    /// and the reverse of a general multivector follows by linearity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// #
+    /// let mv = ConformalMultivector2::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = ConformalMultivector2::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, -6_i32, -7_i32, -8_i32,
+    ///     -9_i32, -10_i32, -11_i32, -12_i32, -13_i32, -14_i32, -15_i32, 16_i32
+    /// );
+    /// let result = mv.reverse();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn reverse(&self) -> Self {
+        let mut result = *self;
+        result.reverse_mut();
+
+        result
+    }
+
+    /// Compute the reverse of a multivector mutably in place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// #
+    /// let mut result = ConformalMultivector2::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = ConformalMultivector2::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, -6_i32, -7_i32, -8_i32,
+    ///     -9_i32, -10_i32, -11_i32, -12_i32, -13_i32, -14_i32, -15_i32, 16_i32
+    /// );
+    /// result.reverse_mut();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn reverse_mut(&mut self) {
+        self.data[5] = -self.data[5];
+        self.data[6] = -self.data[6];
+        self.data[7] = -self.data[7];
+        self.data[8] = -self.data[8];
+        self.data[9] = -self.data[9];
+        self.data[10] = -self.data[10];
+        self.data[11] = -self.data[11];
+        self.data[12] = -self.data[12];
+        self.data[13] = -self.data[13];
+        self.data[14] = -self.data[14];
+    }
+
+    /// Compute the conjugate of a multivector.
+    ///
+    /// The conjugate of a multivector is the composition of the reverse and the
+    /// grade involution. For each grade of multivector it is given by
+    /// ```text
+    /// When mv has grade 0, conj(mv) := mv
+    /// When mv has grade 1, conj(mv) := -mv
+    /// When mv has grade 2, conj(mv) := -mv
+    /// When mv has grade 3, conj(mv) := mv
+    /// When mv has grade 4, conj(mv) := mv
+    /// ```
+    /// The conjugate of each basis blade is
+    /// ```text
+    /// conj(1)       = 1
+    /// conj(e0)      = -e0
+    /// conj(e1)      = -e1
+    /// conj(e2)      = -e2
+    /// conj(einf)    = -einf
+    /// conj(e01)     = -e01
+    /// conj(e02)     = -e02
+    /// conj(e12)     = -e12
+    /// conj(e1inf)   = -e1inf
+    /// conj(e2inf)   = -e2inf
+    /// conj(e0inf)   = -e0inf
+    /// conj(e012)    = e012
+    /// conj(e01inf)  = e01inf
+    /// conj(e02inf)  = e02inf
+    /// conj(e12inf)  = e12inf
+    /// conj(e012inf) = e012inf
+    /// ```
+    /// and the conjugate of a general multivector follows by linearity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// #
+    /// let mv = ConformalMultivector2::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = ConformalMultivector2::new(
+    ///     1_i32, -2_i32, -3_i32, -4_i32, -5_i32, -6_i32, -7_i32, -8_i32,
+    ///     -9_i32, -10_i32, -11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let result = mv.conjugate();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn conjugate(&self) -> Self {
+        let mut result = *self;
+        result.conjugate_mut();
+
+        result
+    }
+
+    /// Compute the conjugate of a multivector mutably in place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// #
+    /// let mut result = ConformalMultivector2::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = ConformalMultivector2::new(
+    ///     1_i32, -2_i32, -3_i32, -4_i32, -5_i32, -6_i32, -7_i32, -8_i32,
+    ///     -9_i32, -10_i32, -11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// result.conjugate_mut();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn conjugate_mut(&mut self) {
+        self.data[1] = -self.data[1];
+        self.data[2] = -self.data[2];
+        self.data[3] = -self.data[3];
+        self.data[4] = -self.data[4];
+        self.data[5] = -self.data[5];
+        self.data[6] = -self.data[6];
+        self.data[7] = -self.data[7];
+        self.data[8] = -self.data[8];
+        self.data[9] = -self.data[9];
+        self.data[10] = -self.data[10];
+    }
+
+    /// Compute the grade involution of a multivector.
+    ///
+    /// The grade involution of a multivector negates the components of odd grade.
+    /// For each grade of multivector it is given by
+    /// ```text
+    /// When mv has grade 0, invol(mv) := mv
+    /// When mv has grade 1, invol(mv) := -mv
+    /// When mv has grade 2, invol(mv) := mv
+    /// When mv has grade 3, invol(mv) := -mv
+    /// When mv has grade 4, invol(mv) := mv
+    /// ```
+    /// The grade involution of each basis blade is
+    /// ```text
+    /// invol(1)       = 1
+    /// invol(e0)      = -e0
+    /// invol(e1)      = -e1
+    /// invol(e2)      = -e2
+    /// invol(einf)    = -einf
+    /// invol(e01)     = e01
+    /// invol(e02)     = e02
+    /// invol(e12)     = e12
+    /// invol(e1inf)   = e1inf
+    /// invol(e2inf)   = e2inf
+    /// invol(e0inf)   = e0inf
+    /// invol(e012)    = -e012
+    /// invol(e01inf)  = -e01inf
+    /// invol(e02inf)  = -e02inf
+    /// invol(e12inf)  = -e12inf
+    /// invol(e012inf) = e012inf
+    /// ```
+    /// and the grade involution of a general multivector follows by linearity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// #
+    /// let mv = ConformalMultivector2::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = ConformalMultivector2::new(
+    ///     1_i32, -2_i32, -3_i32, -4_i32, -5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, -12_i32, -13_i32, -14_i32, -15_i32, 16_i32
+    /// );
+    /// let result = mv.involute();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn involute(&self) -> Self {
+        let mut result = *self;
+        result.involute_mut();
+
+        result
+    }
+
+    /// Compute the grade involution of a multivector mutably in place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// #
+    /// let mut result = ConformalMultivector2::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = ConformalMultivector2::new(
+    ///     1_i32, -2_i32, -3_i32, -4_i32, -5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, -12_i32, -13_i32, -14_i32, -15_i32, 16_i32
+    /// );
+    /// result.involute_mut();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn involute_mut(&mut self) {
+        self.data[1] = -self.data[1];
+        self.data[2] = -self.data[2];
+        self.data[3] = -self.data[3];
+        self.data[4] = -self.data[4];
+        self.data[11] = -self.data[11];
+        self.data[12] = -self.data[12];
+        self.data[13] = -self.data[13];
+        self.data[14] = -self.data[14];
+    }
+
+    /// Compute the dual of a multivector.
+    ///
+    /// The dual of a multivector is also known as the orthogonal complement.
+    /// The dual of a multivector `mv` is defined by
+    /// ```text
+    /// dual(mv) := mv * inv(e012inf)
+    /// ```
+    /// where `inv(e012inf) == -e012inf`. The dual maps a blade representing a
+    /// geometric object by the points it contains, such as a circle through three
+    /// points, to the blade representing the object by the points orthogonal to it,
+    /// such as the dual circle `c - (1 / 2) * r^2 * einf`.
+    ///
+    /// The dual of each basis blade is
+    /// ```text
+    /// dual(1)       = -e012inf
+    /// dual(e0)      = -e012
+    /// dual(e1)      = e02inf
+    /// dual(e2)      = -e01inf
+    /// dual(einf)    = e12inf
+    /// dual(e01)     = -e02
+    /// dual(e02)     = e01
+    /// dual(e12)     = e0inf
+    /// dual(e1inf)   = e2inf
+    /// dual(e2inf)   = -e1inf
+    /// dual(e0inf)   = -e12
+    /// dual(e012)    = e0
+    /// dual(e01inf)  = e2
+    /// dual(e02inf)  = -e1
+    /// dual(e12inf)  = -einf
+    /// dual(e012inf) = 1
+    /// ```
+    /// and the dual of a general multivector follows by linearity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// #
+    /// let mv = ConformalMultivector2::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = ConformalMultivector2::new(
+    ///     16_i32, 12_i32, -14_i32, 13_i32, -15_i32, 7_i32, -6_i32, -11_i32,
+    ///     -10_i32, 9_i32, 8_i32, -2_i32, -4_i32, 3_i32, 5_i32, -1_i32
+    /// );
+    /// let result = mv.dual();
+    ///
+    /// assert_eq!(result, expected);
+    /// assert_eq!(result.undual(), mv);
+    /// ```
+    #[rustfmt::skip]
+    pub fn dual(&self) -> Self {
+        let mut result = Self::zero();
+        result.data[0]  =  self.data[15];
+        result.data[1]  =  self.data[11];
+        result.data[2]  = -self.data[13];
+        result.data[3]  =  self.data[12];
+        result.data[4]  = -self.data[14];
+        result.data[5]  =  self.data[6];
+        result.data[6]  = -self.data[5];
+        result.data[7]  = -self.data[10];
+        result.data[8]  = -self.data[9];
+        result.data[9]  =  self.data[8];
+        result.data[10] =  self.data[7];
+        result.data[11] = -self.data[1];
+        result.data[12] = -self.data[3];
+        result.data[13] =  self.data[2];
+        result.data[14] =  self.data[4];
+        result.data[15] = -self.data[0];
+
+        result
+    }
+
+    /// Compute the dual of a multivector mutably in place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// #
+    /// let mut result = ConformalMultivector2::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = ConformalMultivector2::new(
+    ///     16_i32, 12_i32, -14_i32, 13_i32, -15_i32, 7_i32, -6_i32, -11_i32,
+    ///     -10_i32, 9_i32, 8_i32, -2_i32, -4_i32, 3_i32, 5_i32, -1_i32
+    /// );
+    /// result.dual_mut();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn dual_mut(&mut self) {
+        *self = self.dual();
+    }
+
+    /// Compute the undual of a multivector.
+    ///
+    /// The undual is the inverse of the dual, i.e. `undual(dual(mv)) == mv`
+    /// and `dual(undual(mv)) == mv` for every multivector `mv`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// #
+    /// let mv = ConformalMultivector2::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    ///
+    /// assert_eq!(mv.dual().undual(), mv);
+    /// assert_eq!(mv.undual().dual(), mv);
+    /// ```
+    #[rustfmt::skip]
+    pub fn undual(&self) -> Self {
+        let mut result = Self::zero();
+        result.data[0]  = -self.data[15];
+        result.data[1]  = -self.data[11];
+        result.data[2]  =  self.data[13];
+        result.data[3]  = -self.data[12];
+        result.data[4]  =  self.data[14];
+        result.data[5]  = -self.data[6];
+        result.data[6]  =  self.data[5];
+        result.data[7]  =  self.data[10];
+        result.data[8]  =  self.data[9];
+        result.data[9]  = -self.data[8];
+        result.data[10] = -self.data[7];
+        result.data[11] =  self.data[1];
+        result.data[12] =  self.data[3];
+        result.data[13] = -self.data[2];
+        result.data[14] = -self.data[4];
+        result.data[15] =  self.data[0];
+
+        result
+    }
+
+    /// Construct the inverse pseudoscalar of the geometric algebra.
+    ///
+    /// In the case of the two-dimensional conformal geometric algebra, the
+    /// inverse of the pseudoscalar is the four-blade `inv(e012inf) = -e012inf`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// #
+    /// let ps: ConformalMultivector2<f64> = ConformalMultivector2::pseudoscalar();
+    /// let ps_inv: ConformalMultivector2<f64> = ConformalMultivector2::inv_pseudoscalar();
+    /// let one: ConformalMultivector2<f64> = ConformalMultivector2::unit_scalar();
+    ///
+    /// assert_eq!(ps * ps_inv, one);
+    /// assert_eq!(ps_inv * ps, one);
+    /// ```
+    #[inline]
+    pub fn inv_pseudoscalar() -> Self {
+        -Self::unit_e012inf()
+    }
+}
+
+impl<S> ops::Not for ConformalMultivector2<S>
+where
+    S: ScalarSigned,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        self.dual()
+    }
+}
+
+impl<S> ops::Not for &ConformalMultivector2<S>
+where
+    S: ScalarSigned,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        self.dual()
+    }
+}
+
+impl<S> ops::Neg for ConformalMultivector2<S>
+where
+    S: ScalarSigned,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn neg(self) -> Self::Output {
+        let result_1       = -self.data[0];
+        let result_e0      = -self.data[1];
+        let result_e1      = -self.data[2];
+        let result_e2      = -self.data[3];
+        let result_einf    = -self.data[4];
+        let result_e01     = -self.data[5];
+        let result_e02     = -self.data[6];
+        let result_e12     = -self.data[7];
+        let result_e1inf   = -self.data[8];
+        let result_e2inf   = -self.data[9];
+        let result_e0inf   = -self.data[10];
+        let result_e012    = -self.data[11];
+        let result_e01inf  = -self.data[12];
+        let result_e02inf  = -self.data[13];
+        let result_e12inf  = -self.data[14];
+        let result_e012inf = -self.data[15];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::Neg for &ConformalMultivector2<S>
+where
+    S: ScalarSigned,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn neg(self) -> Self::Output {
+        let result_1       = -self.data[0];
+        let result_e0      = -self.data[1];
+        let result_e1      = -self.data[2];
+        let result_e2      = -self.data[3];
+        let result_einf    = -self.data[4];
+        let result_e01     = -self.data[5];
+        let result_e02     = -self.data[6];
+        let result_e12     = -self.data[7];
+        let result_e1inf   = -self.data[8];
+        let result_e2inf   = -self.data[9];
+        let result_e0inf   = -self.data[10];
+        let result_e012    = -self.data[11];
+        let result_e01inf  = -self.data[12];
+        let result_e02inf  = -self.data[13];
+        let result_e12inf  = -self.data[14];
+        let result_e012inf = -self.data[15];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::Mul<ConformalMultivector2<S>> for ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: ConformalMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1       = a[0] * b[0] - a[1] * b[4] + a[2] * b[2] + a[3] * b[3] - a[4] * b[1] - a[5] * b[8] - a[6] * b[9] - a[7] * b[7] - a[8] * b[5] - a[9] * b[6] + a[10] * b[10] + a[11] * b[14] + a[12] * b[12] + a[13] * b[13] + a[14] * b[11] - a[15] * b[15];
+        let result_e0      = a[0] * b[1] + a[1] * b[0] + a[1] * b[10] - a[2] * b[5] - a[3] * b[6] + a[5] * b[2] - a[5] * b[12] + a[6] * b[3] - a[6] * b[13] - a[7] * b[11] - a[10] * b[1] - a[11] * b[7] - a[11] * b[15] - a[12] * b[5] - a[13] * b[6] + a[15] * b[11];
+        let result_e1      = a[0] * b[2] + a[1] * b[8] + a[2] * b[0] - a[3] * b[7] - a[4] * b[5] + a[5] * b[4] - a[6] * b[14] + a[7] * b[3] - a[8] * b[1] + a[9] * b[11] - a[10] * b[12] + a[11] * b[9] - a[12] * b[10] + a[13] * b[15] - a[14] * b[6] - a[15] * b[13];
+        let result_e2      = a[0] * b[3] + a[1] * b[9] + a[2] * b[7] + a[3] * b[0] - a[4] * b[6] + a[5] * b[14] + a[6] * b[4] - a[7] * b[2] - a[8] * b[11] - a[9] * b[1] - a[10] * b[13] - a[11] * b[8] - a[12] * b[15] - a[13] * b[10] + a[14] * b[5] + a[15] * b[12];
+        let result_einf    = a[0] * b[4] + a[2] * b[8] + a[3] * b[9] + a[4] * b[0] - a[4] * b[10] - a[7] * b[14] - a[8] * b[2] - a[8] * b[12] - a[9] * b[3] - a[9] * b[13] + a[10] * b[4] - a[12] * b[8] - a[13] * b[9] - a[14] * b[7] + a[14] * b[15] - a[15] * b[14];
+        let result_e01     = a[0] * b[5] + a[1] * b[2] - a[1] * b[12] - a[2] * b[1] + a[3] * b[11] + a[5] * b[0] + a[5] * b[10] - a[6] * b[7] - a[6] * b[15] + a[7] * b[6] - a[10] * b[5] + a[11] * b[3] - a[11] * b[13] - a[12] * b[1] + a[13] * b[11] - a[15] * b[6];
+        let result_e02     = a[0] * b[6] + a[1] * b[3] - a[1] * b[13] - a[2] * b[11] - a[3] * b[1] + a[5] * b[7] + a[5] * b[15] + a[6] * b[0] + a[6] * b[10] - a[7] * b[5] - a[10] * b[6] - a[11] * b[2] + a[11] * b[12] - a[12] * b[11] - a[13] * b[1] + a[15] * b[5];
+        let result_e12     = a[0] * b[7] - a[1] * b[14] + a[2] * b[3] - a[3] * b[2] - a[4] * b[11] - a[5] * b[9] + a[6] * b[8] + a[7] * b[0] - a[8] * b[6] + a[9] * b[5] + a[10] * b[15] - a[11] * b[4] + a[12] * b[13] - a[13] * b[12] - a[14] * b[1] + a[15] * b[10];
+        let result_e1inf   = a[0] * b[8] + a[2] * b[4] - a[3] * b[14] - a[4] * b[2] - a[4] * b[12] + a[7] * b[9] + a[8] * b[0] - a[8] * b[10] - a[9] * b[7] + a[9] * b[15] + a[10] * b[8] - a[12] * b[4] + a[13] * b[14] - a[14] * b[3] - a[14] * b[13] + a[15] * b[9];
+        let result_e2inf   = a[0] * b[9] + a[2] * b[14] + a[3] * b[4] - a[4] * b[3] - a[4] * b[13] - a[7] * b[8] + a[8] * b[7] - a[8] * b[15] + a[9] * b[0] - a[9] * b[10] + a[10] * b[9] - a[12] * b[14] - a[13] * b[4] + a[14] * b[2] + a[14] * b[12] - a[15] * b[8];
+        let result_e0inf   = a[0] * b[10] + a[1] * b[4] - a[2] * b[12] - a[3] * b[13] - a[4] * b[1] + a[5] * b[8] + a[6] * b[9] - a[7] * b[15] - a[8] * b[5] - a[9] * b[6] + a[10] * b[0] - a[11] * b[14] - a[12] * b[2] - a[13] * b[3] + a[14] * b[11] - a[15] * b[7];
+        let result_e012    = a[0] * b[11] + a[1] * b[7] + a[1] * b[15] - a[2] * b[6] + a[3] * b[5] + a[5] * b[3] - a[5] * b[13] - a[6] * b[2] + a[6] * b[12] + a[7] * b[1] - a[10] * b[11] + a[11] * b[0] + a[11] * b[10] - a[12] * b[6] + a[13] * b[5] - a[15] * b[1];
+        let result_e01inf  = a[0] * b[12] + a[1] * b[8] - a[2] * b[10] + a[3] * b[15] + a[4] * b[5] + a[5] * b[4] - a[6] * b[14] + a[7] * b[13] + a[8] * b[1] - a[9] * b[11] - a[10] * b[2] + a[11] * b[9] + a[12] * b[0] - a[13] * b[7] + a[14] * b[6] - a[15] * b[3];
+        let result_e02inf  = a[0] * b[13] + a[1] * b[9] - a[2] * b[15] - a[3] * b[10] + a[4] * b[6] + a[5] * b[14] + a[6] * b[4] - a[7] * b[12] + a[8] * b[11] + a[9] * b[1] - a[10] * b[3] - a[11] * b[8] + a[12] * b[7] + a[13] * b[0] - a[14] * b[5] + a[15] * b[2];
+        let result_e12inf  = a[0] * b[14] + a[2] * b[9] - a[3] * b[8] + a[4] * b[7] - a[4] * b[15] + a[7] * b[4] - a[8] * b[3] - a[8] * b[13] + a[9] * b[2] + a[9] * b[12] + a[10] * b[14] - a[12] * b[9] + a[13] * b[8] + a[14] * b[0] - a[14] * b[10] + a[15] * b[4];
+        let result_e012inf = a[0] * b[15] + a[1] * b[14] - a[2] * b[13] + a[3] * b[12] - a[4] * b[11] + a[5] * b[9] - a[6] * b[8] + a[7] * b[10] - a[8] * b[6] + a[9] * b[5] + a[10] * b[7] + a[11] * b[4] - a[12] * b[3] + a[13] * b[2] - a[14] * b[1] + a[15] * b[0];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::Mul<&ConformalMultivector2<S>> for ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: &ConformalMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1       = a[0] * b[0] - a[1] * b[4] + a[2] * b[2] + a[3] * b[3] - a[4] * b[1] - a[5] * b[8] - a[6] * b[9] - a[7] * b[7] - a[8] * b[5] - a[9] * b[6] + a[10] * b[10] + a[11] * b[14] + a[12] * b[12] + a[13] * b[13] + a[14] * b[11] - a[15] * b[15];
+        let result_e0      = a[0] * b[1] + a[1] * b[0] + a[1] * b[10] - a[2] * b[5] - a[3] * b[6] + a[5] * b[2] - a[5] * b[12] + a[6] * b[3] - a[6] * b[13] - a[7] * b[11] - a[10] * b[1] - a[11] * b[7] - a[11] * b[15] - a[12] * b[5] - a[13] * b[6] + a[15] * b[11];
+        let result_e1      = a[0] * b[2] + a[1] * b[8] + a[2] * b[0] - a[3] * b[7] - a[4] * b[5] + a[5] * b[4] - a[6] * b[14] + a[7] * b[3] - a[8] * b[1] + a[9] * b[11] - a[10] * b[12] + a[11] * b[9] - a[12] * b[10] + a[13] * b[15] - a[14] * b[6] - a[15] * b[13];
+        let result_e2      = a[0] * b[3] + a[1] * b[9] + a[2] * b[7] + a[3] * b[0] - a[4] * b[6] + a[5] * b[14] + a[6] * b[4] - a[7] * b[2] - a[8] * b[11] - a[9] * b[1] - a[10] * b[13] - a[11] * b[8] - a[12] * b[15] - a[13] * b[10] + a[14] * b[5] + a[15] * b[12];
+        let result_einf    = a[0] * b[4] + a[2] * b[8] + a[3] * b[9] + a[4] * b[0] - a[4] * b[10] - a[7] * b[14] - a[8] * b[2] - a[8] * b[12] - a[9] * b[3] - a[9] * b[13] + a[10] * b[4] - a[12] * b[8] - a[13] * b[9] - a[14] * b[7] + a[14] * b[15] - a[15] * b[14];
+        let result_e01     = a[0] * b[5] + a[1] * b[2] - a[1] * b[12] - a[2] * b[1] + a[3] * b[11] + a[5] * b[0] + a[5] * b[10] - a[6] * b[7] - a[6] * b[15] + a[7] * b[6] - a[10] * b[5] + a[11] * b[3] - a[11] * b[13] - a[12] * b[1] + a[13] * b[11] - a[15] * b[6];
+        let result_e02     = a[0] * b[6] + a[1] * b[3] - a[1] * b[13] - a[2] * b[11] - a[3] * b[1] + a[5] * b[7] + a[5] * b[15] + a[6] * b[0] + a[6] * b[10] - a[7] * b[5] - a[10] * b[6] - a[11] * b[2] + a[11] * b[12] - a[12] * b[11] - a[13] * b[1] + a[15] * b[5];
+        let result_e12     = a[0] * b[7] - a[1] * b[14] + a[2] * b[3] - a[3] * b[2] - a[4] * b[11] - a[5] * b[9] + a[6] * b[8] + a[7] * b[0] - a[8] * b[6] + a[9] * b[5] + a[10] * b[15] - a[11] * b[4] + a[12] * b[13] - a[13] * b[12] - a[14] * b[1] + a[15] * b[10];
+        let result_e1inf   = a[0] * b[8] + a[2] * b[4] - a[3] * b[14] - a[4] * b[2] - a[4] * b[12] + a[7] * b[9] + a[8] * b[0] - a[8] * b[10] - a[9] * b[7] + a[9] * b[15] + a[10] * b[8] - a[12] * b[4] + a[13] * b[14] - a[14] * b[3] - a[14] * b[13] + a[15] * b[9];
+        let result_e2inf   = a[0] * b[9] + a[2] * b[14] + a[3] * b[4] - a[4] * b[3] - a[4] * b[13] - a[7] * b[8] + a[8] * b[7] - a[8] * b[15] + a[9] * b[0] - a[9] * b[10] + a[10] * b[9] - a[12] * b[14] - a[13] * b[4] + a[14] * b[2] + a[14] * b[12] - a[15] * b[8];
+        let result_e0inf   = a[0] * b[10] + a[1] * b[4] - a[2] * b[12] - a[3] * b[13] - a[4] * b[1] + a[5] * b[8] + a[6] * b[9] - a[7] * b[15] - a[8] * b[5] - a[9] * b[6] + a[10] * b[0] - a[11] * b[14] - a[12] * b[2] - a[13] * b[3] + a[14] * b[11] - a[15] * b[7];
+        let result_e012    = a[0] * b[11] + a[1] * b[7] + a[1] * b[15] - a[2] * b[6] + a[3] * b[5] + a[5] * b[3] - a[5] * b[13] - a[6] * b[2] + a[6] * b[12] + a[7] * b[1] - a[10] * b[11] + a[11] * b[0] + a[11] * b[10] - a[12] * b[6] + a[13] * b[5] - a[15] * b[1];
+        let result_e01inf  = a[0] * b[12] + a[1] * b[8] - a[2] * b[10] + a[3] * b[15] + a[4] * b[5] + a[5] * b[4] - a[6] * b[14] + a[7] * b[13] + a[8] * b[1] - a[9] * b[11] - a[10] * b[2] + a[11] * b[9] + a[12] * b[0] - a[13] * b[7] + a[14] * b[6] - a[15] * b[3];
+        let result_e02inf  = a[0] * b[13] + a[1] * b[9] - a[2] * b[15] - a[3] * b[10] + a[4] * b[6] + a[5] * b[14] + a[6] * b[4] - a[7] * b[12] + a[8] * b[11] + a[9] * b[1] - a[10] * b[3] - a[11] * b[8] + a[12] * b[7] + a[13] * b[0] - a[14] * b[5] + a[15] * b[2];
+        let result_e12inf  = a[0] * b[14] + a[2] * b[9] - a[3] * b[8] + a[4] * b[7] - a[4] * b[15] + a[7] * b[4] - a[8] * b[3] - a[8] * b[13] + a[9] * b[2] + a[9] * b[12] + a[10] * b[14] - a[12] * b[9] + a[13] * b[8] + a[14] * b[0] - a[14] * b[10] + a[15] * b[4];
+        let result_e012inf = a[0] * b[15] + a[1] * b[14] - a[2] * b[13] + a[3] * b[12] - a[4] * b[11] + a[5] * b[9] - a[6] * b[8] + a[7] * b[10] - a[8] * b[6] + a[9] * b[5] + a[10] * b[7] + a[11] * b[4] - a[12] * b[3] + a[13] * b[2] - a[14] * b[1] + a[15] * b[0];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::Mul<ConformalMultivector2<S>> for &ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: ConformalMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1       = a[0] * b[0] - a[1] * b[4] + a[2] * b[2] + a[3] * b[3] - a[4] * b[1] - a[5] * b[8] - a[6] * b[9] - a[7] * b[7] - a[8] * b[5] - a[9] * b[6] + a[10] * b[10] + a[11] * b[14] + a[12] * b[12] + a[13] * b[13] + a[14] * b[11] - a[15] * b[15];
+        let result_e0      = a[0] * b[1] + a[1] * b[0] + a[1] * b[10] - a[2] * b[5] - a[3] * b[6] + a[5] * b[2] - a[5] * b[12] + a[6] * b[3] - a[6] * b[13] - a[7] * b[11] - a[10] * b[1] - a[11] * b[7] - a[11] * b[15] - a[12] * b[5] - a[13] * b[6] + a[15] * b[11];
+        let result_e1      = a[0] * b[2] + a[1] * b[8] + a[2] * b[0] - a[3] * b[7] - a[4] * b[5] + a[5] * b[4] - a[6] * b[14] + a[7] * b[3] - a[8] * b[1] + a[9] * b[11] - a[10] * b[12] + a[11] * b[9] - a[12] * b[10] + a[13] * b[15] - a[14] * b[6] - a[15] * b[13];
+        let result_e2      = a[0] * b[3] + a[1] * b[9] + a[2] * b[7] + a[3] * b[0] - a[4] * b[6] + a[5] * b[14] + a[6] * b[4] - a[7] * b[2] - a[8] * b[11] - a[9] * b[1] - a[10] * b[13] - a[11] * b[8] - a[12] * b[15] - a[13] * b[10] + a[14] * b[5] + a[15] * b[12];
+        let result_einf    = a[0] * b[4] + a[2] * b[8] + a[3] * b[9] + a[4] * b[0] - a[4] * b[10] - a[7] * b[14] - a[8] * b[2] - a[8] * b[12] - a[9] * b[3] - a[9] * b[13] + a[10] * b[4] - a[12] * b[8] - a[13] * b[9] - a[14] * b[7] + a[14] * b[15] - a[15] * b[14];
+        let result_e01     = a[0] * b[5] + a[1] * b[2] - a[1] * b[12] - a[2] * b[1] + a[3] * b[11] + a[5] * b[0] + a[5] * b[10] - a[6] * b[7] - a[6] * b[15] + a[7] * b[6] - a[10] * b[5] + a[11] * b[3] - a[11] * b[13] - a[12] * b[1] + a[13] * b[11] - a[15] * b[6];
+        let result_e02     = a[0] * b[6] + a[1] * b[3] - a[1] * b[13] - a[2] * b[11] - a[3] * b[1] + a[5] * b[7] + a[5] * b[15] + a[6] * b[0] + a[6] * b[10] - a[7] * b[5] - a[10] * b[6] - a[11] * b[2] + a[11] * b[12] - a[12] * b[11] - a[13] * b[1] + a[15] * b[5];
+        let result_e12     = a[0] * b[7] - a[1] * b[14] + a[2] * b[3] - a[3] * b[2] - a[4] * b[11] - a[5] * b[9] + a[6] * b[8] + a[7] * b[0] - a[8] * b[6] + a[9] * b[5] + a[10] * b[15] - a[11] * b[4] + a[12] * b[13] - a[13] * b[12] - a[14] * b[1] + a[15] * b[10];
+        let result_e1inf   = a[0] * b[8] + a[2] * b[4] - a[3] * b[14] - a[4] * b[2] - a[4] * b[12] + a[7] * b[9] + a[8] * b[0] - a[8] * b[10] - a[9] * b[7] + a[9] * b[15] + a[10] * b[8] - a[12] * b[4] + a[13] * b[14] - a[14] * b[3] - a[14] * b[13] + a[15] * b[9];
+        let result_e2inf   = a[0] * b[9] + a[2] * b[14] + a[3] * b[4] - a[4] * b[3] - a[4] * b[13] - a[7] * b[8] + a[8] * b[7] - a[8] * b[15] + a[9] * b[0] - a[9] * b[10] + a[10] * b[9] - a[12] * b[14] - a[13] * b[4] + a[14] * b[2] + a[14] * b[12] - a[15] * b[8];
+        let result_e0inf   = a[0] * b[10] + a[1] * b[4] - a[2] * b[12] - a[3] * b[13] - a[4] * b[1] + a[5] * b[8] + a[6] * b[9] - a[7] * b[15] - a[8] * b[5] - a[9] * b[6] + a[10] * b[0] - a[11] * b[14] - a[12] * b[2] - a[13] * b[3] + a[14] * b[11] - a[15] * b[7];
+        let result_e012    = a[0] * b[11] + a[1] * b[7] + a[1] * b[15] - a[2] * b[6] + a[3] * b[5] + a[5] * b[3] - a[5] * b[13] - a[6] * b[2] + a[6] * b[12] + a[7] * b[1] - a[10] * b[11] + a[11] * b[0] + a[11] * b[10] - a[12] * b[6] + a[13] * b[5] - a[15] * b[1];
+        let result_e01inf  = a[0] * b[12] + a[1] * b[8] - a[2] * b[10] + a[3] * b[15] + a[4] * b[5] + a[5] * b[4] - a[6] * b[14] + a[7] * b[13] + a[8] * b[1] - a[9] * b[11] - a[10] * b[2] + a[11] * b[9] + a[12] * b[0] - a[13] * b[7] + a[14] * b[6] - a[15] * b[3];
+        let result_e02inf  = a[0] * b[13] + a[1] * b[9] - a[2] * b[15] - a[3] * b[10] + a[4] * b[6] + a[5] * b[14] + a[6] * b[4] - a[7] * b[12] + a[8] * b[11] + a[9] * b[1] - a[10] * b[3] - a[11] * b[8] + a[12] * b[7] + a[13] * b[0] - a[14] * b[5] + a[15] * b[2];
+        let result_e12inf  = a[0] * b[14] + a[2] * b[9] - a[3] * b[8] + a[4] * b[7] - a[4] * b[15] + a[7] * b[4] - a[8] * b[3] - a[8] * b[13] + a[9] * b[2] + a[9] * b[12] + a[10] * b[14] - a[12] * b[9] + a[13] * b[8] + a[14] * b[0] - a[14] * b[10] + a[15] * b[4];
+        let result_e012inf = a[0] * b[15] + a[1] * b[14] - a[2] * b[13] + a[3] * b[12] - a[4] * b[11] + a[5] * b[9] - a[6] * b[8] + a[7] * b[10] - a[8] * b[6] + a[9] * b[5] + a[10] * b[7] + a[11] * b[4] - a[12] * b[3] + a[13] * b[2] - a[14] * b[1] + a[15] * b[0];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::Mul<&ConformalMultivector2<S>> for &ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: &ConformalMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1       = a[0] * b[0] - a[1] * b[4] + a[2] * b[2] + a[3] * b[3] - a[4] * b[1] - a[5] * b[8] - a[6] * b[9] - a[7] * b[7] - a[8] * b[5] - a[9] * b[6] + a[10] * b[10] + a[11] * b[14] + a[12] * b[12] + a[13] * b[13] + a[14] * b[11] - a[15] * b[15];
+        let result_e0      = a[0] * b[1] + a[1] * b[0] + a[1] * b[10] - a[2] * b[5] - a[3] * b[6] + a[5] * b[2] - a[5] * b[12] + a[6] * b[3] - a[6] * b[13] - a[7] * b[11] - a[10] * b[1] - a[11] * b[7] - a[11] * b[15] - a[12] * b[5] - a[13] * b[6] + a[15] * b[11];
+        let result_e1      = a[0] * b[2] + a[1] * b[8] + a[2] * b[0] - a[3] * b[7] - a[4] * b[5] + a[5] * b[4] - a[6] * b[14] + a[7] * b[3] - a[8] * b[1] + a[9] * b[11] - a[10] * b[12] + a[11] * b[9] - a[12] * b[10] + a[13] * b[15] - a[14] * b[6] - a[15] * b[13];
+        let result_e2      = a[0] * b[3] + a[1] * b[9] + a[2] * b[7] + a[3] * b[0] - a[4] * b[6] + a[5] * b[14] + a[6] * b[4] - a[7] * b[2] - a[8] * b[11] - a[9] * b[1] - a[10] * b[13] - a[11] * b[8] - a[12] * b[15] - a[13] * b[10] + a[14] * b[5] + a[15] * b[12];
+        let result_einf    = a[0] * b[4] + a[2] * b[8] + a[3] * b[9] + a[4] * b[0] - a[4] * b[10] - a[7] * b[14] - a[8] * b[2] - a[8] * b[12] - a[9] * b[3] - a[9] * b[13] + a[10] * b[4] - a[12] * b[8] - a[13] * b[9] - a[14] * b[7] + a[14] * b[15] - a[15] * b[14];
+        let result_e01     = a[0] * b[5] + a[1] * b[2] - a[1] * b[12] - a[2] * b[1] + a[3] * b[11] + a[5] * b[0] + a[5] * b[10] - a[6] * b[7] - a[6] * b[15] + a[7] * b[6] - a[10] * b[5] + a[11] * b[3] - a[11] * b[13] - a[12] * b[1] + a[13] * b[11] - a[15] * b[6];
+        let result_e02     = a[0] * b[6] + a[1] * b[3] - a[1] * b[13] - a[2] * b[11] - a[3] * b[1] + a[5] * b[7] + a[5] * b[15] + a[6] * b[0] + a[6] * b[10] - a[7] * b[5] - a[10] * b[6] - a[11] * b[2] + a[11] * b[12] - a[12] * b[11] - a[13] * b[1] + a[15] * b[5];
+        let result_e12     = a[0] * b[7] - a[1] * b[14] + a[2] * b[3] - a[3] * b[2] - a[4] * b[11] - a[5] * b[9] + a[6] * b[8] + a[7] * b[0] - a[8] * b[6] + a[9] * b[5] + a[10] * b[15] - a[11] * b[4] + a[12] * b[13] - a[13] * b[12] - a[14] * b[1] + a[15] * b[10];
+        let result_e1inf   = a[0] * b[8] + a[2] * b[4] - a[3] * b[14] - a[4] * b[2] - a[4] * b[12] + a[7] * b[9] + a[8] * b[0] - a[8] * b[10] - a[9] * b[7] + a[9] * b[15] + a[10] * b[8] - a[12] * b[4] + a[13] * b[14] - a[14] * b[3] - a[14] * b[13] + a[15] * b[9];
+        let result_e2inf   = a[0] * b[9] + a[2] * b[14] + a[3] * b[4] - a[4] * b[3] - a[4] * b[13] - a[7] * b[8] + a[8] * b[7] - a[8] * b[15] + a[9] * b[0] - a[9] * b[10] + a[10] * b[9] - a[12] * b[14] - a[13] * b[4] + a[14] * b[2] + a[14] * b[12] - a[15] * b[8];
+        let result_e0inf   = a[0] * b[10] + a[1] * b[4] - a[2] * b[12] - a[3] * b[13] - a[4] * b[1] + a[5] * b[8] + a[6] * b[9] - a[7] * b[15] - a[8] * b[5] - a[9] * b[6] + a[10] * b[0] - a[11] * b[14] - a[12] * b[2] - a[13] * b[3] + a[14] * b[11] - a[15] * b[7];
+        let result_e012    = a[0] * b[11] + a[1] * b[7] + a[1] * b[15] - a[2] * b[6] + a[3] * b[5] + a[5] * b[3] - a[5] * b[13] - a[6] * b[2] + a[6] * b[12] + a[7] * b[1] - a[10] * b[11] + a[11] * b[0] + a[11] * b[10] - a[12] * b[6] + a[13] * b[5] - a[15] * b[1];
+        let result_e01inf  = a[0] * b[12] + a[1] * b[8] - a[2] * b[10] + a[3] * b[15] + a[4] * b[5] + a[5] * b[4] - a[6] * b[14] + a[7] * b[13] + a[8] * b[1] - a[9] * b[11] - a[10] * b[2] + a[11] * b[9] + a[12] * b[0] - a[13] * b[7] + a[14] * b[6] - a[15] * b[3];
+        let result_e02inf  = a[0] * b[13] + a[1] * b[9] - a[2] * b[15] - a[3] * b[10] + a[4] * b[6] + a[5] * b[14] + a[6] * b[4] - a[7] * b[12] + a[8] * b[11] + a[9] * b[1] - a[10] * b[3] - a[11] * b[8] + a[12] * b[7] + a[13] * b[0] - a[14] * b[5] + a[15] * b[2];
+        let result_e12inf  = a[0] * b[14] + a[2] * b[9] - a[3] * b[8] + a[4] * b[7] - a[4] * b[15] + a[7] * b[4] - a[8] * b[3] - a[8] * b[13] + a[9] * b[2] + a[9] * b[12] + a[10] * b[14] - a[12] * b[9] + a[13] * b[8] + a[14] * b[0] - a[14] * b[10] + a[15] * b[4];
+        let result_e012inf = a[0] * b[15] + a[1] * b[14] - a[2] * b[13] + a[3] * b[12] - a[4] * b[11] + a[5] * b[9] - a[6] * b[8] + a[7] * b[10] - a[8] * b[6] + a[9] * b[5] + a[10] * b[7] + a[11] * b[4] - a[12] * b[3] + a[13] * b[2] - a[14] * b[1] + a[15] * b[0];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::Mul<S> for ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1       = a[0] * other;
+        let result_e0      = a[1] * other;
+        let result_e1      = a[2] * other;
+        let result_e2      = a[3] * other;
+        let result_einf    = a[4] * other;
+        let result_e01     = a[5] * other;
+        let result_e02     = a[6] * other;
+        let result_e12     = a[7] * other;
+        let result_e1inf   = a[8] * other;
+        let result_e2inf   = a[9] * other;
+        let result_e0inf   = a[10] * other;
+        let result_e012    = a[11] * other;
+        let result_e01inf  = a[12] * other;
+        let result_e02inf  = a[13] * other;
+        let result_e12inf  = a[14] * other;
+        let result_e012inf = a[15] * other;
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::Mul<S> for &ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1       = a[0] * other;
+        let result_e0      = a[1] * other;
+        let result_e1      = a[2] * other;
+        let result_e2      = a[3] * other;
+        let result_einf    = a[4] * other;
+        let result_e01     = a[5] * other;
+        let result_e02     = a[6] * other;
+        let result_e12     = a[7] * other;
+        let result_e1inf   = a[8] * other;
+        let result_e2inf   = a[9] * other;
+        let result_e0inf   = a[10] * other;
+        let result_e012    = a[11] * other;
+        let result_e01inf  = a[12] * other;
+        let result_e02inf  = a[13] * other;
+        let result_e12inf  = a[14] * other;
+        let result_e012inf = a[15] * other;
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::BitXor<ConformalMultivector2<S>> for ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: ConformalMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1       = a[0] * b[0];
+        let result_e0      = a[0] * b[1] + a[1] * b[0];
+        let result_e1      = a[0] * b[2] + a[2] * b[0];
+        let result_e2      = a[0] * b[3] + a[3] * b[0];
+        let result_einf    = a[0] * b[4] + a[4] * b[0];
+        let result_e01     = a[0] * b[5] + a[1] * b[2] - a[2] * b[1] + a[5] * b[0];
+        let result_e02     = a[0] * b[6] + a[1] * b[3] - a[3] * b[1] + a[6] * b[0];
+        let result_e12     = a[0] * b[7] + a[2] * b[3] - a[3] * b[2] + a[7] * b[0];
+        let result_e1inf   = a[0] * b[8] + a[2] * b[4] - a[4] * b[2] + a[8] * b[0];
+        let result_e2inf   = a[0] * b[9] + a[3] * b[4] - a[4] * b[3] + a[9] * b[0];
+        let result_e0inf   = a[0] * b[10] + a[1] * b[4] - a[4] * b[1] + a[10] * b[0];
+        let result_e012    = a[0] * b[11] + a[1] * b[7] - a[2] * b[6] + a[3] * b[5] + a[5] * b[3] - a[6] * b[2] + a[7] * b[1] + a[11] * b[0];
+        let result_e01inf  = a[0] * b[12] + a[1] * b[8] - a[2] * b[10] + a[4] * b[5] + a[5] * b[4] + a[8] * b[1] - a[10] * b[2] + a[12] * b[0];
+        let result_e02inf  = a[0] * b[13] + a[1] * b[9] - a[3] * b[10] + a[4] * b[6] + a[6] * b[4] + a[9] * b[1] - a[10] * b[3] + a[13] * b[0];
+        let result_e12inf  = a[0] * b[14] + a[2] * b[9] - a[3] * b[8] + a[4] * b[7] + a[7] * b[4] - a[8] * b[3] + a[9] * b[2] + a[14] * b[0];
+        let result_e012inf = a[0] * b[15] + a[1] * b[14] - a[2] * b[13] + a[3] * b[12] - a[4] * b[11] + a[5] * b[9] - a[6] * b[8] + a[7] * b[10] - a[8] * b[6] + a[9] * b[5] + a[10] * b[7] + a[11] * b[4] - a[12] * b[3] + a[13] * b[2] - a[14] * b[1] + a[15] * b[0];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::BitXor<&ConformalMultivector2<S>> for ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: &ConformalMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1       = a[0] * b[0];
+        let result_e0      = a[0] * b[1] + a[1] * b[0];
+        let result_e1      = a[0] * b[2] + a[2] * b[0];
+        let result_e2      = a[0] * b[3] + a[3] * b[0];
+        let result_einf    = a[0] * b[4] + a[4] * b[0];
+        let result_e01     = a[0] * b[5] + a[1] * b[2] - a[2] * b[1] + a[5] * b[0];
+        let result_e02     = a[0] * b[6] + a[1] * b[3] - a[3] * b[1] + a[6] * b[0];
+        let result_e12     = a[0] * b[7] + a[2] * b[3] - a[3] * b[2] + a[7] * b[0];
+        let result_e1inf   = a[0] * b[8] + a[2] * b[4] - a[4] * b[2] + a[8] * b[0];
+        let result_e2inf   = a[0] * b[9] + a[3] * b[4] - a[4] * b[3] + a[9] * b[0];
+        let result_e0inf   = a[0] * b[10] + a[1] * b[4] - a[4] * b[1] + a[10] * b[0];
+        let result_e012    = a[0] * b[11] + a[1] * b[7] - a[2] * b[6] + a[3] * b[5] + a[5] * b[3] - a[6] * b[2] + a[7] * b[1] + a[11] * b[0];
+        let result_e01inf  = a[0] * b[12] + a[1] * b[8] - a[2] * b[10] + a[4] * b[5] + a[5] * b[4] + a[8] * b[1] - a[10] * b[2] + a[12] * b[0];
+        let result_e02inf  = a[0] * b[13] + a[1] * b[9] - a[3] * b[10] + a[4] * b[6] + a[6] * b[4] + a[9] * b[1] - a[10] * b[3] + a[13] * b[0];
+        let result_e12inf  = a[0] * b[14] + a[2] * b[9] - a[3] * b[8] + a[4] * b[7] + a[7] * b[4] - a[8] * b[3] + a[9] * b[2] + a[14] * b[0];
+        let result_e012inf = a[0] * b[15] + a[1] * b[14] - a[2] * b[13] + a[3] * b[12] - a[4] * b[11] + a[5] * b[9] - a[6] * b[8] + a[7] * b[10] - a[8] * b[6] + a[9] * b[5] + a[10] * b[7] + a[11] * b[4] - a[12] * b[3] + a[13] * b[2] - a[14] * b[1] + a[15] * b[0];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::BitXor<ConformalMultivector2<S>> for &ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: ConformalMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1       = a[0] * b[0];
+        let result_e0      = a[0] * b[1] + a[1] * b[0];
+        let result_e1      = a[0] * b[2] + a[2] * b[0];
+        let result_e2      = a[0] * b[3] + a[3] * b[0];
+        let result_einf    = a[0] * b[4] + a[4] * b[0];
+        let result_e01     = a[0] * b[5] + a[1] * b[2] - a[2] * b[1] + a[5] * b[0];
+        let result_e02     = a[0] * b[6] + a[1] * b[3] - a[3] * b[1] + a[6] * b[0];
+        let result_e12     = a[0] * b[7] + a[2] * b[3] - a[3] * b[2] + a[7] * b[0];
+        let result_e1inf   = a[0] * b[8] + a[2] * b[4] - a[4] * b[2] + a[8] * b[0];
+        let result_e2inf   = a[0] * b[9] + a[3] * b[4] - a[4] * b[3] + a[9] * b[0];
+        let result_e0inf   = a[0] * b[10] + a[1] * b[4] - a[4] * b[1] + a[10] * b[0];
+        let result_e012    = a[0] * b[11] + a[1] * b[7] - a[2] * b[6] + a[3] * b[5] + a[5] * b[3] - a[6] * b[2] + a[7] * b[1] + a[11] * b[0];
+        let result_e01inf  = a[0] * b[12] + a[1] * b[8] - a[2] * b[10] + a[4] * b[5] + a[5] * b[4] + a[8] * b[1] - a[10] * b[2] + a[12] * b[0];
+        let result_e02inf  = a[0] * b[13] + a[1] * b[9] - a[3] * b[10] + a[4] * b[6] + a[6] * b[4] + a[9] * b[1] - a[10] * b[3] + a[13] * b[0];
+        let result_e12inf  = a[0] * b[14] + a[2] * b[9] - a[3] * b[8] + a[4] * b[7] + a[7] * b[4] - a[8] * b[3] + a[9] * b[2] + a[14] * b[0];
+        let result_e012inf = a[0] * b[15] + a[1] * b[14] - a[2] * b[13] + a[3] * b[12] - a[4] * b[11] + a[5] * b[9] - a[6] * b[8] + a[7] * b[10] - a[8] * b[6] + a[9] * b[5] + a[10] * b[7] + a[11] * b[4] - a[12] * b[3] + a[13] * b[2] - a[14] * b[1] + a[15] * b[0];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::BitXor<&ConformalMultivector2<S>> for &ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: &ConformalMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1       = a[0] * b[0];
+        let result_e0      = a[0] * b[1] + a[1] * b[0];
+        let result_e1      = a[0] * b[2] + a[2] * b[0];
+        let result_e2      = a[0] * b[3] + a[3] * b[0];
+        let result_einf    = a[0] * b[4] + a[4] * b[0];
+        let result_e01     = a[0] * b[5] + a[1] * b[2] - a[2] * b[1] + a[5] * b[0];
+        let result_e02     = a[0] * b[6] + a[1] * b[3] - a[3] * b[1] + a[6] * b[0];
+        let result_e12     = a[0] * b[7] + a[2] * b[3] - a[3] * b[2] + a[7] * b[0];
+        let result_e1inf   = a[0] * b[8] + a[2] * b[4] - a[4] * b[2] + a[8] * b[0];
+        let result_e2inf   = a[0] * b[9] + a[3] * b[4] - a[4] * b[3] + a[9] * b[0];
+        let result_e0inf   = a[0] * b[10] + a[1] * b[4] - a[4] * b[1] + a[10] * b[0];
+        let result_e012    = a[0] * b[11] + a[1] * b[7] - a[2] * b[6] + a[3] * b[5] + a[5] * b[3] - a[6] * b[2] + a[7] * b[1] + a[11] * b[0];
+        let result_e01inf  = a[0] * b[12] + a[1] * b[8] - a[2] * b[10] + a[4] * b[5] + a[5] * b[4] + a[8] * b[1] - a[10] * b[2] + a[12] * b[0];
+        let result_e02inf  = a[0] * b[13] + a[1] * b[9] - a[3] * b[10] + a[4] * b[6] + a[6] * b[4] + a[9] * b[1] - a[10] * b[3] + a[13] * b[0];
+        let result_e12inf  = a[0] * b[14] + a[2] * b[9] - a[3] * b[8] + a[4] * b[7] + a[7] * b[4] - a[8] * b[3] + a[9] * b[2] + a[14] * b[0];
+        let result_e012inf = a[0] * b[15] + a[1] * b[14] - a[2] * b[13] + a[3] * b[12] - a[4] * b[11] + a[5] * b[9] - a[6] * b[8] + a[7] * b[10] - a[8] * b[6] + a[9] * b[5] + a[10] * b[7] + a[11] * b[4] - a[12] * b[3] + a[13] * b[2] - a[14] * b[1] + a[15] * b[0];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::BitXor<S> for ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1       = a[0] * other;
+        let result_e0      = a[1] * other;
+        let result_e1      = a[2] * other;
+        let result_e2      = a[3] * other;
+        let result_einf    = a[4] * other;
+        let result_e01     = a[5] * other;
+        let result_e02     = a[6] * other;
+        let result_e12     = a[7] * other;
+        let result_e1inf   = a[8] * other;
+        let result_e2inf   = a[9] * other;
+        let result_e0inf   = a[10] * other;
+        let result_e012    = a[11] * other;
+        let result_e01inf  = a[12] * other;
+        let result_e02inf  = a[13] * other;
+        let result_e12inf  = a[14] * other;
+        let result_e012inf = a[15] * other;
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::BitXor<S> for &ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1       = a[0] * other;
+        let result_e0      = a[1] * other;
+        let result_e1      = a[2] * other;
+        let result_e2      = a[3] * other;
+        let result_einf    = a[4] * other;
+        let result_e01     = a[5] * other;
+        let result_e02     = a[6] * other;
+        let result_e12     = a[7] * other;
+        let result_e1inf   = a[8] * other;
+        let result_e2inf   = a[9] * other;
+        let result_e0inf   = a[10] * other;
+        let result_e012    = a[11] * other;
+        let result_e01inf  = a[12] * other;
+        let result_e02inf  = a[13] * other;
+        let result_e12inf  = a[14] * other;
+        let result_e012inf = a[15] * other;
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::BitAnd<ConformalMultivector2<S>> for ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitand(self, other: ConformalMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1       = a[0] * b[15] - a[1] * b[14] + a[2] * b[13] - a[3] * b[12] + a[4] * b[11] + a[5] * b[9] - a[6] * b[8] + a[7] * b[10] - a[8] * b[6] + a[9] * b[5] + a[10] * b[7] - a[11] * b[4] + a[12] * b[3] - a[13] * b[2] + a[14] * b[1] + a[15] * b[0];
+        let result_e0      = a[1] * b[15] + a[5] * b[13] - a[6] * b[12] + a[10] * b[11] + a[11] * b[10] - a[12] * b[6] + a[13] * b[5] + a[15] * b[1];
+        let result_e1      = a[2] * b[15] + a[5] * b[14] - a[7] * b[12] + a[8] * b[11] + a[11] * b[8] - a[12] * b[7] + a[14] * b[5] + a[15] * b[2];
+        let result_e2      = a[3] * b[15] + a[6] * b[14] - a[7] * b[13] + a[9] * b[11] + a[11] * b[9] - a[13] * b[7] + a[14] * b[6] + a[15] * b[3];
+        let result_einf    = a[4] * b[15] - a[8] * b[13] + a[9] * b[12] + a[10] * b[14] + a[12] * b[9] - a[13] * b[8] + a[14] * b[10] + a[15] * b[4];
+        let result_e01     = a[5] * b[15] - a[11] * b[12] + a[12] * b[11] + a[15] * b[5];
+        let result_e02     = a[6] * b[15] - a[11] * b[13] + a[13] * b[11] + a[15] * b[6];
+        let result_e12     = a[7] * b[15] - a[11] * b[14] + a[14] * b[11] + a[15] * b[7];
+        let result_e1inf   = a[8] * b[15] - a[12] * b[14] + a[14] * b[12] + a[15] * b[8];
+        let result_e2inf   = a[9] * b[15] - a[13] * b[14] + a[14] * b[13] + a[15] * b[9];
+        let result_e0inf   = a[10] * b[15] - a[12] * b[13] + a[13] * b[12] + a[15] * b[10];
+        let result_e012    = a[11] * b[15] + a[15] * b[11];
+        let result_e01inf  = a[12] * b[15] + a[15] * b[12];
+        let result_e02inf  = a[13] * b[15] + a[15] * b[13];
+        let result_e12inf  = a[14] * b[15] + a[15] * b[14];
+        let result_e012inf = a[15] * b[15];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::BitAnd<&ConformalMultivector2<S>> for ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitand(self, other: &ConformalMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1       = a[0] * b[15] - a[1] * b[14] + a[2] * b[13] - a[3] * b[12] + a[4] * b[11] + a[5] * b[9] - a[6] * b[8] + a[7] * b[10] - a[8] * b[6] + a[9] * b[5] + a[10] * b[7] - a[11] * b[4] + a[12] * b[3] - a[13] * b[2] + a[14] * b[1] + a[15] * b[0];
+        let result_e0      = a[1] * b[15] + a[5] * b[13] - a[6] * b[12] + a[10] * b[11] + a[11] * b[10] - a[12] * b[6] + a[13] * b[5] + a[15] * b[1];
+        let result_e1      = a[2] * b[15] + a[5] * b[14] - a[7] * b[12] + a[8] * b[11] + a[11] * b[8] - a[12] * b[7] + a[14] * b[5] + a[15] * b[2];
+        let result_e2      = a[3] * b[15] + a[6] * b[14] - a[7] * b[13] + a[9] * b[11] + a[11] * b[9] - a[13] * b[7] + a[14] * b[6] + a[15] * b[3];
+        let result_einf    = a[4] * b[15] - a[8] * b[13] + a[9] * b[12] + a[10] * b[14] + a[12] * b[9] - a[13] * b[8] + a[14] * b[10] + a[15] * b[4];
+        let result_e01     = a[5] * b[15] - a[11] * b[12] + a[12] * b[11] + a[15] * b[5];
+        let result_e02     = a[6] * b[15] - a[11] * b[13] + a[13] * b[11] + a[15] * b[6];
+        let result_e12     = a[7] * b[15] - a[11] * b[14] + a[14] * b[11] + a[15] * b[7];
+        let result_e1inf   = a[8] * b[15] - a[12] * b[14] + a[14] * b[12] + a[15] * b[8];
+        let result_e2inf   = a[9] * b[15] - a[13] * b[14] + a[14] * b[13] + a[15] * b[9];
+        let result_e0inf   = a[10] * b[15] - a[12] * b[13] + a[13] * b[12] + a[15] * b[10];
+        let result_e012    = a[11] * b[15] + a[15] * b[11];
+        let result_e01inf  = a[12] * b[15] + a[15] * b[12];
+        let result_e02inf  = a[13] * b[15] + a[15] * b[13];
+        let result_e12inf  = a[14] * b[15] + a[15] * b[14];
+        let result_e012inf = a[15] * b[15];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::BitAnd<ConformalMultivector2<S>> for &ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitand(self, other: ConformalMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1       = a[0] * b[15] - a[1] * b[14] + a[2] * b[13] - a[3] * b[12] + a[4] * b[11] + a[5] * b[9] - a[6] * b[8] + a[7] * b[10] - a[8] * b[6] + a[9] * b[5] + a[10] * b[7] - a[11] * b[4] + a[12] * b[3] - a[13] * b[2] + a[14] * b[1] + a[15] * b[0];
+        let result_e0      = a[1] * b[15] + a[5] * b[13] - a[6] * b[12] + a[10] * b[11] + a[11] * b[10] - a[12] * b[6] + a[13] * b[5] + a[15] * b[1];
+        let result_e1      = a[2] * b[15] + a[5] * b[14] - a[7] * b[12] + a[8] * b[11] + a[11] * b[8] - a[12] * b[7] + a[14] * b[5] + a[15] * b[2];
+        let result_e2      = a[3] * b[15] + a[6] * b[14] - a[7] * b[13] + a[9] * b[11] + a[11] * b[9] - a[13] * b[7] + a[14] * b[6] + a[15] * b[3];
+        let result_einf    = a[4] * b[15] - a[8] * b[13] + a[9] * b[12] + a[10] * b[14] + a[12] * b[9] - a[13] * b[8] + a[14] * b[10] + a[15] * b[4];
+        let result_e01     = a[5] * b[15] - a[11] * b[12] + a[12] * b[11] + a[15] * b[5];
+        let result_e02     = a[6] * b[15] - a[11] * b[13] + a[13] * b[11] + a[15] * b[6];
+        let result_e12     = a[7] * b[15] - a[11] * b[14] + a[14] * b[11] + a[15] * b[7];
+        let result_e1inf   = a[8] * b[15] - a[12] * b[14] + a[14] * b[12] + a[15] * b[8];
+        let result_e2inf   = a[9] * b[15] - a[13] * b[14] + a[14] * b[13] + a[15] * b[9];
+        let result_e0inf   = a[10] * b[15] - a[12] * b[13] + a[13] * b[12] + a[15] * b[10];
+        let result_e012    = a[11] * b[15] + a[15] * b[11];
+        let result_e01inf  = a[12] * b[15] + a[15] * b[12];
+        let result_e02inf  = a[13] * b[15] + a[15] * b[13];
+        let result_e12inf  = a[14] * b[15] + a[15] * b[14];
+        let result_e012inf = a[15] * b[15];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::BitAnd<&ConformalMultivector2<S>> for &ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitand(self, other: &ConformalMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1       = a[0] * b[15] - a[1] * b[14] + a[2] * b[13] - a[3] * b[12] + a[4] * b[11] + a[5] * b[9] - a[6] * b[8] + a[7] * b[10] - a[8] * b[6] + a[9] * b[5] + a[10] * b[7] - a[11] * b[4] + a[12] * b[3] - a[13] * b[2] + a[14] * b[1] + a[15] * b[0];
+        let result_e0      = a[1] * b[15] + a[5] * b[13] - a[6] * b[12] + a[10] * b[11] + a[11] * b[10] - a[12] * b[6] + a[13] * b[5] + a[15] * b[1];
+        let result_e1      = a[2] * b[15] + a[5] * b[14] - a[7] * b[12] + a[8] * b[11] + a[11] * b[8] - a[12] * b[7] + a[14] * b[5] + a[15] * b[2];
+        let result_e2      = a[3] * b[15] + a[6] * b[14] - a[7] * b[13] + a[9] * b[11] + a[11] * b[9] - a[13] * b[7] + a[14] * b[6] + a[15] * b[3];
+        let result_einf    = a[4] * b[15] - a[8] * b[13] + a[9] * b[12] + a[10] * b[14] + a[12] * b[9] - a[13] * b[8] + a[14] * b[10] + a[15] * b[4];
+        let result_e01     = a[5] * b[15] - a[11] * b[12] + a[12] * b[11] + a[15] * b[5];
+        let result_e02     = a[6] * b[15] - a[11] * b[13] + a[13] * b[11] + a[15] * b[6];
+        let result_e12     = a[7] * b[15] - a[11] * b[14] + a[14] * b[11] + a[15] * b[7];
+        let result_e1inf   = a[8] * b[15] - a[12] * b[14] + a[14] * b[12] + a[15] * b[8];
+        let result_e2inf   = a[9] * b[15] - a[13] * b[14] + a[14] * b[13] + a[15] * b[9];
+        let result_e0inf   = a[10] * b[15] - a[12] * b[13] + a[13] * b[12] + a[15] * b[10];
+        let result_e012    = a[11] * b[15] + a[15] * b[11];
+        let result_e01inf  = a[12] * b[15] + a[15] * b[12];
+        let result_e02inf  = a[13] * b[15] + a[15] * b[13];
+        let result_e12inf  = a[14] * b[15] + a[15] * b[14];
+        let result_e012inf = a[15] * b[15];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::BitOr<ConformalMultivector2<S>> for ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitor(self, other: ConformalMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1 = a[0] * b[0] - a[1] * b[4] + a[2] * b[2] + a[3] * b[3] - a[4] * b[1] + a[5] * b[8] + a[6] * b[9] + a[7] * b[7] + a[8] * b[5] + a[9] * b[6] - a[10] * b[10] - a[11] * b[14] - a[12] * b[12] - a[13] * b[13] - a[14] * b[11] - a[15] * b[15];
+
+        ConformalMultivector2::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::BitOr<&ConformalMultivector2<S>> for ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitor(self, other: &ConformalMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1 = a[0] * b[0] - a[1] * b[4] + a[2] * b[2] + a[3] * b[3] - a[4] * b[1] + a[5] * b[8] + a[6] * b[9] + a[7] * b[7] + a[8] * b[5] + a[9] * b[6] - a[10] * b[10] - a[11] * b[14] - a[12] * b[12] - a[13] * b[13] - a[14] * b[11] - a[15] * b[15];
+
+        ConformalMultivector2::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::BitOr<ConformalMultivector2<S>> for &ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitor(self, other: ConformalMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1 = a[0] * b[0] - a[1] * b[4] + a[2] * b[2] + a[3] * b[3] - a[4] * b[1] + a[5] * b[8] + a[6] * b[9] + a[7] * b[7] + a[8] * b[5] + a[9] * b[6] - a[10] * b[10] - a[11] * b[14] - a[12] * b[12] - a[13] * b[13] - a[14] * b[11] - a[15] * b[15];
+
+        ConformalMultivector2::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::BitOr<&ConformalMultivector2<S>> for &ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitor(self, other: &ConformalMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1 = a[0] * b[0] - a[1] * b[4] + a[2] * b[2] + a[3] * b[3] - a[4] * b[1] + a[5] * b[8] + a[6] * b[9] + a[7] * b[7] + a[8] * b[5] + a[9] * b[6] - a[10] * b[10] - a[11] * b[14] - a[12] * b[12] - a[13] * b[13] - a[14] * b[11] - a[15] * b[15];
+
+        ConformalMultivector2::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::BitOr<S> for ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn bitor(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1 = a[0] * other;
+
+        ConformalMultivector2::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::BitOr<S> for &ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn bitor(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1 = a[0] * other;
+
+        ConformalMultivector2::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::Add<ConformalMultivector2<S>> for ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: ConformalMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1       = a[0] + b[0];
+        let result_e0      = a[1] + b[1];
+        let result_e1      = a[2] + b[2];
+        let result_e2      = a[3] + b[3];
+        let result_einf    = a[4] + b[4];
+        let result_e01     = a[5] + b[5];
+        let result_e02     = a[6] + b[6];
+        let result_e12     = a[7] + b[7];
+        let result_e1inf   = a[8] + b[8];
+        let result_e2inf   = a[9] + b[9];
+        let result_e0inf   = a[10] + b[10];
+        let result_e012    = a[11] + b[11];
+        let result_e01inf  = a[12] + b[12];
+        let result_e02inf  = a[13] + b[13];
+        let result_e12inf  = a[14] + b[14];
+        let result_e012inf = a[15] + b[15];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::Add<&ConformalMultivector2<S>> for ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: &ConformalMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1       = a[0] + b[0];
+        let result_e0      = a[1] + b[1];
+        let result_e1      = a[2] + b[2];
+        let result_e2      = a[3] + b[3];
+        let result_einf    = a[4] + b[4];
+        let result_e01     = a[5] + b[5];
+        let result_e02     = a[6] + b[6];
+        let result_e12     = a[7] + b[7];
+        let result_e1inf   = a[8] + b[8];
+        let result_e2inf   = a[9] + b[9];
+        let result_e0inf   = a[10] + b[10];
+        let result_e012    = a[11] + b[11];
+        let result_e01inf  = a[12] + b[12];
+        let result_e02inf  = a[13] + b[13];
+        let result_e12inf  = a[14] + b[14];
+        let result_e012inf = a[15] + b[15];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::Add<ConformalMultivector2<S>> for &ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: ConformalMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1       = a[0] + b[0];
+        let result_e0      = a[1] + b[1];
+        let result_e1      = a[2] + b[2];
+        let result_e2      = a[3] + b[3];
+        let result_einf    = a[4] + b[4];
+        let result_e01     = a[5] + b[5];
+        let result_e02     = a[6] + b[6];
+        let result_e12     = a[7] + b[7];
+        let result_e1inf   = a[8] + b[8];
+        let result_e2inf   = a[9] + b[9];
+        let result_e0inf   = a[10] + b[10];
+        let result_e012    = a[11] + b[11];
+        let result_e01inf  = a[12] + b[12];
+        let result_e02inf  = a[13] + b[13];
+        let result_e12inf  = a[14] + b[14];
+        let result_e012inf = a[15] + b[15];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::Add<&ConformalMultivector2<S>> for &ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: &ConformalMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1       = a[0] + b[0];
+        let result_e0      = a[1] + b[1];
+        let result_e1      = a[2] + b[2];
+        let result_e2      = a[3] + b[3];
+        let result_einf    = a[4] + b[4];
+        let result_e01     = a[5] + b[5];
+        let result_e02     = a[6] + b[6];
+        let result_e12     = a[7] + b[7];
+        let result_e1inf   = a[8] + b[8];
+        let result_e2inf   = a[9] + b[9];
+        let result_e0inf   = a[10] + b[10];
+        let result_e012    = a[11] + b[11];
+        let result_e01inf  = a[12] + b[12];
+        let result_e02inf  = a[13] + b[13];
+        let result_e12inf  = a[14] + b[14];
+        let result_e012inf = a[15] + b[15];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::Add<S> for ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1       = a[0] + other;
+        let result_e0      = a[1];
+        let result_e1      = a[2];
+        let result_e2      = a[3];
+        let result_einf    = a[4];
+        let result_e01     = a[5];
+        let result_e02     = a[6];
+        let result_e12     = a[7];
+        let result_e1inf   = a[8];
+        let result_e2inf   = a[9];
+        let result_e0inf   = a[10];
+        let result_e012    = a[11];
+        let result_e01inf  = a[12];
+        let result_e02inf  = a[13];
+        let result_e12inf  = a[14];
+        let result_e012inf = a[15];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::Add<S> for &ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1       = a[0] + other;
+        let result_e0      = a[1];
+        let result_e1      = a[2];
+        let result_e2      = a[3];
+        let result_einf    = a[4];
+        let result_e01     = a[5];
+        let result_e02     = a[6];
+        let result_e12     = a[7];
+        let result_e1inf   = a[8];
+        let result_e2inf   = a[9];
+        let result_e0inf   = a[10];
+        let result_e012    = a[11];
+        let result_e01inf  = a[12];
+        let result_e02inf  = a[13];
+        let result_e12inf  = a[14];
+        let result_e012inf = a[15];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::Sub<ConformalMultivector2<S>> for ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: ConformalMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1       = a[0] - b[0];
+        let result_e0      = a[1] - b[1];
+        let result_e1      = a[2] - b[2];
+        let result_e2      = a[3] - b[3];
+        let result_einf    = a[4] - b[4];
+        let result_e01     = a[5] - b[5];
+        let result_e02     = a[6] - b[6];
+        let result_e12     = a[7] - b[7];
+        let result_e1inf   = a[8] - b[8];
+        let result_e2inf   = a[9] - b[9];
+        let result_e0inf   = a[10] - b[10];
+        let result_e012    = a[11] - b[11];
+        let result_e01inf  = a[12] - b[12];
+        let result_e02inf  = a[13] - b[13];
+        let result_e12inf  = a[14] - b[14];
+        let result_e012inf = a[15] - b[15];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::Sub<&ConformalMultivector2<S>> for ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: &ConformalMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1       = a[0] - b[0];
+        let result_e0      = a[1] - b[1];
+        let result_e1      = a[2] - b[2];
+        let result_e2      = a[3] - b[3];
+        let result_einf    = a[4] - b[4];
+        let result_e01     = a[5] - b[5];
+        let result_e02     = a[6] - b[6];
+        let result_e12     = a[7] - b[7];
+        let result_e1inf   = a[8] - b[8];
+        let result_e2inf   = a[9] - b[9];
+        let result_e0inf   = a[10] - b[10];
+        let result_e012    = a[11] - b[11];
+        let result_e01inf  = a[12] - b[12];
+        let result_e02inf  = a[13] - b[13];
+        let result_e12inf  = a[14] - b[14];
+        let result_e012inf = a[15] - b[15];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::Sub<ConformalMultivector2<S>> for &ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: ConformalMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1       = a[0] - b[0];
+        let result_e0      = a[1] - b[1];
+        let result_e1      = a[2] - b[2];
+        let result_e2      = a[3] - b[3];
+        let result_einf    = a[4] - b[4];
+        let result_e01     = a[5] - b[5];
+        let result_e02     = a[6] - b[6];
+        let result_e12     = a[7] - b[7];
+        let result_e1inf   = a[8] - b[8];
+        let result_e2inf   = a[9] - b[9];
+        let result_e0inf   = a[10] - b[10];
+        let result_e012    = a[11] - b[11];
+        let result_e01inf  = a[12] - b[12];
+        let result_e02inf  = a[13] - b[13];
+        let result_e12inf  = a[14] - b[14];
+        let result_e012inf = a[15] - b[15];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::Sub<&ConformalMultivector2<S>> for &ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: &ConformalMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1       = a[0] - b[0];
+        let result_e0      = a[1] - b[1];
+        let result_e1      = a[2] - b[2];
+        let result_e2      = a[3] - b[3];
+        let result_einf    = a[4] - b[4];
+        let result_e01     = a[5] - b[5];
+        let result_e02     = a[6] - b[6];
+        let result_e12     = a[7] - b[7];
+        let result_e1inf   = a[8] - b[8];
+        let result_e2inf   = a[9] - b[9];
+        let result_e0inf   = a[10] - b[10];
+        let result_e012    = a[11] - b[11];
+        let result_e01inf  = a[12] - b[12];
+        let result_e02inf  = a[13] - b[13];
+        let result_e12inf  = a[14] - b[14];
+        let result_e012inf = a[15] - b[15];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::Sub<S> for ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1       = a[0] - other;
+        let result_e0      = a[1];
+        let result_e1      = a[2];
+        let result_e2      = a[3];
+        let result_einf    = a[4];
+        let result_e01     = a[5];
+        let result_e02     = a[6];
+        let result_e12     = a[7];
+        let result_e1inf   = a[8];
+        let result_e2inf   = a[9];
+        let result_e0inf   = a[10];
+        let result_e012    = a[11];
+        let result_e01inf  = a[12];
+        let result_e02inf  = a[13];
+        let result_e12inf  = a[14];
+        let result_e012inf = a[15];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::Sub<S> for &ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1       = a[0] - other;
+        let result_e0      = a[1];
+        let result_e1      = a[2];
+        let result_e2      = a[3];
+        let result_einf    = a[4];
+        let result_e01     = a[5];
+        let result_e02     = a[6];
+        let result_e12     = a[7];
+        let result_e1inf   = a[8];
+        let result_e2inf   = a[9];
+        let result_e0inf   = a[10];
+        let result_e012    = a[11];
+        let result_e01inf  = a[12];
+        let result_e02inf  = a[13];
+        let result_e12inf  = a[14];
+        let result_e012inf = a[15];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl_approx_cmp_ops!(ConformalMultivector2);
+
+impl<S> ConformalMultivector2<S>
+where
+    S: ScalarFloat,
+{
+    /// Calculate the squared magnitude of a multivector.
+    pub fn magnitude_squared(&self) -> S {
+        let scalar_part = (self * self.reverse())[0];
+
+        scalar_part.abs()
+    }
+
+    /// Calculate the magnitude of a multivector.
+    pub fn magnitude(&self) -> S {
+        self.magnitude_squared().sqrt()
+    }
+
+    /// Normalize a multivector to a unit multivector.
+    pub fn normalize(&self) -> Self {
+        self * (S::one() / self.magnitude())
+    }
+
+    /// Normalize a multivector to a specified magnitude.
+    pub fn normalize_to(&self, magnitude: S) -> Self {
+        self * (magnitude / self.magnitude())
+    }
+}
+
+impl<S> ops::Div<S> for ConformalMultivector2<S>
+where
+    S: ScalarFloat,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn div(self, other: S) -> Self::Output {
+        let one_over_other = S::one() / other;
+        let result_1       = self.data[0] * one_over_other;
+        let result_e0      = self.data[1] * one_over_other;
+        let result_e1      = self.data[2] * one_over_other;
+        let result_e2      = self.data[3] * one_over_other;
+        let result_einf    = self.data[4] * one_over_other;
+        let result_e01     = self.data[5] * one_over_other;
+        let result_e02     = self.data[6] * one_over_other;
+        let result_e12     = self.data[7] * one_over_other;
+        let result_e1inf   = self.data[8] * one_over_other;
+        let result_e2inf   = self.data[9] * one_over_other;
+        let result_e0inf   = self.data[10] * one_over_other;
+        let result_e012    = self.data[11] * one_over_other;
+        let result_e01inf  = self.data[12] * one_over_other;
+        let result_e02inf  = self.data[13] * one_over_other;
+        let result_e12inf  = self.data[14] * one_over_other;
+        let result_e012inf = self.data[15] * one_over_other;
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::Div<S> for &ConformalMultivector2<S>
+where
+    S: ScalarFloat,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn div(self, other: S) -> Self::Output {
+        let one_over_other = S::one() / other;
+        let result_1       = self.data[0] * one_over_other;
+        let result_e0      = self.data[1] * one_over_other;
+        let result_e1      = self.data[2] * one_over_other;
+        let result_e2      = self.data[3] * one_over_other;
+        let result_einf    = self.data[4] * one_over_other;
+        let result_e01     = self.data[5] * one_over_other;
+        let result_e02     = self.data[6] * one_over_other;
+        let result_e12     = self.data[7] * one_over_other;
+        let result_e1inf   = self.data[8] * one_over_other;
+        let result_e2inf   = self.data[9] * one_over_other;
+        let result_e0inf   = self.data[10] * one_over_other;
+        let result_e012    = self.data[11] * one_over_other;
+        let result_e01inf  = self.data[12] * one_over_other;
+        let result_e02inf  = self.data[13] * one_over_other;
+        let result_e12inf  = self.data[14] * one_over_other;
+        let result_e012inf = self.data[15] * one_over_other;
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::Shl<ConformalMultivector2<S>> for ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shl(self, other: ConformalMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1       = a[0] * b[0] - a[1] * b[4] + a[2] * b[2] + a[3] * b[3] - a[4] * b[1] - a[5] * b[8] - a[6] * b[9] - a[7] * b[7] - a[8] * b[5] - a[9] * b[6] + a[10] * b[10] + a[11] * b[14] + a[12] * b[12] + a[13] * b[13] + a[14] * b[11] - a[15] * b[15];
+        let result_e0      = a[0] * b[1] + a[1] * b[10] - a[2] * b[5] - a[3] * b[6] - a[5] * b[12] - a[6] * b[13] - a[7] * b[11] - a[11] * b[15];
+        let result_e1      = a[0] * b[2] + a[1] * b[8] - a[3] * b[7] - a[4] * b[5] - a[6] * b[14] + a[9] * b[11] - a[10] * b[12] + a[13] * b[15];
+        let result_e2      = a[0] * b[3] + a[1] * b[9] + a[2] * b[7] - a[4] * b[6] + a[5] * b[14] - a[8] * b[11] - a[10] * b[13] - a[12] * b[15];
+        let result_einf    = a[0] * b[4] + a[2] * b[8] + a[3] * b[9] - a[4] * b[10] - a[7] * b[14] - a[8] * b[12] - a[9] * b[13] + a[14] * b[15];
+        let result_e01     = a[0] * b[5] - a[1] * b[12] + a[3] * b[11] - a[6] * b[15];
+        let result_e02     = a[0] * b[6] - a[1] * b[13] - a[2] * b[11] + a[5] * b[15];
+        let result_e12     = a[0] * b[7] - a[1] * b[14] - a[4] * b[11] + a[10] * b[15];
+        let result_e1inf   = a[0] * b[8] - a[3] * b[14] - a[4] * b[12] + a[9] * b[15];
+        let result_e2inf   = a[0] * b[9] + a[2] * b[14] - a[4] * b[13] - a[8] * b[15];
+        let result_e0inf   = a[0] * b[10] - a[2] * b[12] - a[3] * b[13] - a[7] * b[15];
+        let result_e012    = a[0] * b[11] + a[1] * b[15];
+        let result_e01inf  = a[0] * b[12] + a[3] * b[15];
+        let result_e02inf  = a[0] * b[13] - a[2] * b[15];
+        let result_e12inf  = a[0] * b[14] - a[4] * b[15];
+        let result_e012inf = a[0] * b[15];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::Shl<&ConformalMultivector2<S>> for ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shl(self, other: &ConformalMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1       = a[0] * b[0] - a[1] * b[4] + a[2] * b[2] + a[3] * b[3] - a[4] * b[1] - a[5] * b[8] - a[6] * b[9] - a[7] * b[7] - a[8] * b[5] - a[9] * b[6] + a[10] * b[10] + a[11] * b[14] + a[12] * b[12] + a[13] * b[13] + a[14] * b[11] - a[15] * b[15];
+        let result_e0      = a[0] * b[1] + a[1] * b[10] - a[2] * b[5] - a[3] * b[6] - a[5] * b[12] - a[6] * b[13] - a[7] * b[11] - a[11] * b[15];
+        let result_e1      = a[0] * b[2] + a[1] * b[8] - a[3] * b[7] - a[4] * b[5] - a[6] * b[14] + a[9] * b[11] - a[10] * b[12] + a[13] * b[15];
+        let result_e2      = a[0] * b[3] + a[1] * b[9] + a[2] * b[7] - a[4] * b[6] + a[5] * b[14] - a[8] * b[11] - a[10] * b[13] - a[12] * b[15];
+        let result_einf    = a[0] * b[4] + a[2] * b[8] + a[3] * b[9] - a[4] * b[10] - a[7] * b[14] - a[8] * b[12] - a[9] * b[13] + a[14] * b[15];
+        let result_e01     = a[0] * b[5] - a[1] * b[12] + a[3] * b[11] - a[6] * b[15];
+        let result_e02     = a[0] * b[6] - a[1] * b[13] - a[2] * b[11] + a[5] * b[15];
+        let result_e12     = a[0] * b[7] - a[1] * b[14] - a[4] * b[11] + a[10] * b[15];
+        let result_e1inf   = a[0] * b[8] - a[3] * b[14] - a[4] * b[12] + a[9] * b[15];
+        let result_e2inf   = a[0] * b[9] + a[2] * b[14] - a[4] * b[13] - a[8] * b[15];
+        let result_e0inf   = a[0] * b[10] - a[2] * b[12] - a[3] * b[13] - a[7] * b[15];
+        let result_e012    = a[0] * b[11] + a[1] * b[15];
+        let result_e01inf  = a[0] * b[12] + a[3] * b[15];
+        let result_e02inf  = a[0] * b[13] - a[2] * b[15];
+        let result_e12inf  = a[0] * b[14] - a[4] * b[15];
+        let result_e012inf = a[0] * b[15];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::Shl<ConformalMultivector2<S>> for &ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shl(self, other: ConformalMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1       = a[0] * b[0] - a[1] * b[4] + a[2] * b[2] + a[3] * b[3] - a[4] * b[1] - a[5] * b[8] - a[6] * b[9] - a[7] * b[7] - a[8] * b[5] - a[9] * b[6] + a[10] * b[10] + a[11] * b[14] + a[12] * b[12] + a[13] * b[13] + a[14] * b[11] - a[15] * b[15];
+        let result_e0      = a[0] * b[1] + a[1] * b[10] - a[2] * b[5] - a[3] * b[6] - a[5] * b[12] - a[6] * b[13] - a[7] * b[11] - a[11] * b[15];
+        let result_e1      = a[0] * b[2] + a[1] * b[8] - a[3] * b[7] - a[4] * b[5] - a[6] * b[14] + a[9] * b[11] - a[10] * b[12] + a[13] * b[15];
+        let result_e2      = a[0] * b[3] + a[1] * b[9] + a[2] * b[7] - a[4] * b[6] + a[5] * b[14] - a[8] * b[11] - a[10] * b[13] - a[12] * b[15];
+        let result_einf    = a[0] * b[4] + a[2] * b[8] + a[3] * b[9] - a[4] * b[10] - a[7] * b[14] - a[8] * b[12] - a[9] * b[13] + a[14] * b[15];
+        let result_e01     = a[0] * b[5] - a[1] * b[12] + a[3] * b[11] - a[6] * b[15];
+        let result_e02     = a[0] * b[6] - a[1] * b[13] - a[2] * b[11] + a[5] * b[15];
+        let result_e12     = a[0] * b[7] - a[1] * b[14] - a[4] * b[11] + a[10] * b[15];
+        let result_e1inf   = a[0] * b[8] - a[3] * b[14] - a[4] * b[12] + a[9] * b[15];
+        let result_e2inf   = a[0] * b[9] + a[2] * b[14] - a[4] * b[13] - a[8] * b[15];
+        let result_e0inf   = a[0] * b[10] - a[2] * b[12] - a[3] * b[13] - a[7] * b[15];
+        let result_e012    = a[0] * b[11] + a[1] * b[15];
+        let result_e01inf  = a[0] * b[12] + a[3] * b[15];
+        let result_e02inf  = a[0] * b[13] - a[2] * b[15];
+        let result_e12inf  = a[0] * b[14] - a[4] * b[15];
+        let result_e012inf = a[0] * b[15];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::Shl<&ConformalMultivector2<S>> for &ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shl(self, other: &ConformalMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1       = a[0] * b[0] - a[1] * b[4] + a[2] * b[2] + a[3] * b[3] - a[4] * b[1] - a[5] * b[8] - a[6] * b[9] - a[7] * b[7] - a[8] * b[5] - a[9] * b[6] + a[10] * b[10] + a[11] * b[14] + a[12] * b[12] + a[13] * b[13] + a[14] * b[11] - a[15] * b[15];
+        let result_e0      = a[0] * b[1] + a[1] * b[10] - a[2] * b[5] - a[3] * b[6] - a[5] * b[12] - a[6] * b[13] - a[7] * b[11] - a[11] * b[15];
+        let result_e1      = a[0] * b[2] + a[1] * b[8] - a[3] * b[7] - a[4] * b[5] - a[6] * b[14] + a[9] * b[11] - a[10] * b[12] + a[13] * b[15];
+        let result_e2      = a[0] * b[3] + a[1] * b[9] + a[2] * b[7] - a[4] * b[6] + a[5] * b[14] - a[8] * b[11] - a[10] * b[13] - a[12] * b[15];
+        let result_einf    = a[0] * b[4] + a[2] * b[8] + a[3] * b[9] - a[4] * b[10] - a[7] * b[14] - a[8] * b[12] - a[9] * b[13] + a[14] * b[15];
+        let result_e01     = a[0] * b[5] - a[1] * b[12] + a[3] * b[11] - a[6] * b[15];
+        let result_e02     = a[0] * b[6] - a[1] * b[13] - a[2] * b[11] + a[5] * b[15];
+        let result_e12     = a[0] * b[7] - a[1] * b[14] - a[4] * b[11] + a[10] * b[15];
+        let result_e1inf   = a[0] * b[8] - a[3] * b[14] - a[4] * b[12] + a[9] * b[15];
+        let result_e2inf   = a[0] * b[9] + a[2] * b[14] - a[4] * b[13] - a[8] * b[15];
+        let result_e0inf   = a[0] * b[10] - a[2] * b[12] - a[3] * b[13] - a[7] * b[15];
+        let result_e012    = a[0] * b[11] + a[1] * b[15];
+        let result_e01inf  = a[0] * b[12] + a[3] * b[15];
+        let result_e02inf  = a[0] * b[13] - a[2] * b[15];
+        let result_e12inf  = a[0] * b[14] - a[4] * b[15];
+        let result_e012inf = a[0] * b[15];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::Shl<S> for ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn shl(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1 = a[0] * other;
+
+        ConformalMultivector2::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::Shl<S> for &ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn shl(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1 = a[0] * other;
+
+        ConformalMultivector2::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::Shr<ConformalMultivector2<S>> for ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: ConformalMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1       = a[0] * b[0] - a[1] * b[4] + a[2] * b[2] + a[3] * b[3] - a[4] * b[1] - a[5] * b[8] - a[6] * b[9] - a[7] * b[7] - a[8] * b[5] - a[9] * b[6] + a[10] * b[10] + a[11] * b[14] + a[12] * b[12] + a[13] * b[13] + a[14] * b[11] - a[15] * b[15];
+        let result_e0      = a[1] * b[0] + a[5] * b[2] + a[6] * b[3] - a[10] * b[1] - a[11] * b[7] - a[12] * b[5] - a[13] * b[6] + a[15] * b[11];
+        let result_e1      = a[2] * b[0] + a[5] * b[4] + a[7] * b[3] - a[8] * b[1] + a[11] * b[9] - a[12] * b[10] - a[14] * b[6] - a[15] * b[13];
+        let result_e2      = a[3] * b[0] + a[6] * b[4] - a[7] * b[2] - a[9] * b[1] - a[11] * b[8] - a[13] * b[10] + a[14] * b[5] + a[15] * b[12];
+        let result_einf    = a[4] * b[0] - a[8] * b[2] - a[9] * b[3] + a[10] * b[4] - a[12] * b[8] - a[13] * b[9] - a[14] * b[7] - a[15] * b[14];
+        let result_e01     = a[5] * b[0] + a[11] * b[3] - a[12] * b[1] - a[15] * b[6];
+        let result_e02     = a[6] * b[0] - a[11] * b[2] - a[13] * b[1] + a[15] * b[5];
+        let result_e12     = a[7] * b[0] - a[11] * b[4] - a[14] * b[1] + a[15] * b[10];
+        let result_e1inf   = a[8] * b[0] - a[12] * b[4] - a[14] * b[3] + a[15] * b[9];
+        let result_e2inf   = a[9] * b[0] - a[13] * b[4] + a[14] * b[2] - a[15] * b[8];
+        let result_e0inf   = a[10] * b[0] - a[12] * b[2] - a[13] * b[3] - a[15] * b[7];
+        let result_e012    = a[11] * b[0] - a[15] * b[1];
+        let result_e01inf  = a[12] * b[0] - a[15] * b[3];
+        let result_e02inf  = a[13] * b[0] + a[15] * b[2];
+        let result_e12inf  = a[14] * b[0] + a[15] * b[4];
+        let result_e012inf = a[15] * b[0];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::Shr<&ConformalMultivector2<S>> for ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: &ConformalMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1       = a[0] * b[0] - a[1] * b[4] + a[2] * b[2] + a[3] * b[3] - a[4] * b[1] - a[5] * b[8] - a[6] * b[9] - a[7] * b[7] - a[8] * b[5] - a[9] * b[6] + a[10] * b[10] + a[11] * b[14] + a[12] * b[12] + a[13] * b[13] + a[14] * b[11] - a[15] * b[15];
+        let result_e0      = a[1] * b[0] + a[5] * b[2] + a[6] * b[3] - a[10] * b[1] - a[11] * b[7] - a[12] * b[5] - a[13] * b[6] + a[15] * b[11];
+        let result_e1      = a[2] * b[0] + a[5] * b[4] + a[7] * b[3] - a[8] * b[1] + a[11] * b[9] - a[12] * b[10] - a[14] * b[6] - a[15] * b[13];
+        let result_e2      = a[3] * b[0] + a[6] * b[4] - a[7] * b[2] - a[9] * b[1] - a[11] * b[8] - a[13] * b[10] + a[14] * b[5] + a[15] * b[12];
+        let result_einf    = a[4] * b[0] - a[8] * b[2] - a[9] * b[3] + a[10] * b[4] - a[12] * b[8] - a[13] * b[9] - a[14] * b[7] - a[15] * b[14];
+        let result_e01     = a[5] * b[0] + a[11] * b[3] - a[12] * b[1] - a[15] * b[6];
+        let result_e02     = a[6] * b[0] - a[11] * b[2] - a[13] * b[1] + a[15] * b[5];
+        let result_e12     = a[7] * b[0] - a[11] * b[4] - a[14] * b[1] + a[15] * b[10];
+        let result_e1inf   = a[8] * b[0] - a[12] * b[4] - a[14] * b[3] + a[15] * b[9];
+        let result_e2inf   = a[9] * b[0] - a[13] * b[4] + a[14] * b[2] - a[15] * b[8];
+        let result_e0inf   = a[10] * b[0] - a[12] * b[2] - a[13] * b[3] - a[15] * b[7];
+        let result_e012    = a[11] * b[0] - a[15] * b[1];
+        let result_e01inf  = a[12] * b[0] - a[15] * b[3];
+        let result_e02inf  = a[13] * b[0] + a[15] * b[2];
+        let result_e12inf  = a[14] * b[0] + a[15] * b[4];
+        let result_e012inf = a[15] * b[0];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::Shr<ConformalMultivector2<S>> for &ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: ConformalMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1       = a[0] * b[0] - a[1] * b[4] + a[2] * b[2] + a[3] * b[3] - a[4] * b[1] - a[5] * b[8] - a[6] * b[9] - a[7] * b[7] - a[8] * b[5] - a[9] * b[6] + a[10] * b[10] + a[11] * b[14] + a[12] * b[12] + a[13] * b[13] + a[14] * b[11] - a[15] * b[15];
+        let result_e0      = a[1] * b[0] + a[5] * b[2] + a[6] * b[3] - a[10] * b[1] - a[11] * b[7] - a[12] * b[5] - a[13] * b[6] + a[15] * b[11];
+        let result_e1      = a[2] * b[0] + a[5] * b[4] + a[7] * b[3] - a[8] * b[1] + a[11] * b[9] - a[12] * b[10] - a[14] * b[6] - a[15] * b[13];
+        let result_e2      = a[3] * b[0] + a[6] * b[4] - a[7] * b[2] - a[9] * b[1] - a[11] * b[8] - a[13] * b[10] + a[14] * b[5] + a[15] * b[12];
+        let result_einf    = a[4] * b[0] - a[8] * b[2] - a[9] * b[3] + a[10] * b[4] - a[12] * b[8] - a[13] * b[9] - a[14] * b[7] - a[15] * b[14];
+        let result_e01     = a[5] * b[0] + a[11] * b[3] - a[12] * b[1] - a[15] * b[6];
+        let result_e02     = a[6] * b[0] - a[11] * b[2] - a[13] * b[1] + a[15] * b[5];
+        let result_e12     = a[7] * b[0] - a[11] * b[4] - a[14] * b[1] + a[15] * b[10];
+        let result_e1inf   = a[8] * b[0] - a[12] * b[4] - a[14] * b[3] + a[15] * b[9];
+        let result_e2inf   = a[9] * b[0] - a[13] * b[4] + a[14] * b[2] - a[15] * b[8];
+        let result_e0inf   = a[10] * b[0] - a[12] * b[2] - a[13] * b[3] - a[15] * b[7];
+        let result_e012    = a[11] * b[0] - a[15] * b[1];
+        let result_e01inf  = a[12] * b[0] - a[15] * b[3];
+        let result_e02inf  = a[13] * b[0] + a[15] * b[2];
+        let result_e12inf  = a[14] * b[0] + a[15] * b[4];
+        let result_e012inf = a[15] * b[0];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::Shr<&ConformalMultivector2<S>> for &ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: &ConformalMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1       = a[0] * b[0] - a[1] * b[4] + a[2] * b[2] + a[3] * b[3] - a[4] * b[1] - a[5] * b[8] - a[6] * b[9] - a[7] * b[7] - a[8] * b[5] - a[9] * b[6] + a[10] * b[10] + a[11] * b[14] + a[12] * b[12] + a[13] * b[13] + a[14] * b[11] - a[15] * b[15];
+        let result_e0      = a[1] * b[0] + a[5] * b[2] + a[6] * b[3] - a[10] * b[1] - a[11] * b[7] - a[12] * b[5] - a[13] * b[6] + a[15] * b[11];
+        let result_e1      = a[2] * b[0] + a[5] * b[4] + a[7] * b[3] - a[8] * b[1] + a[11] * b[9] - a[12] * b[10] - a[14] * b[6] - a[15] * b[13];
+        let result_e2      = a[3] * b[0] + a[6] * b[4] - a[7] * b[2] - a[9] * b[1] - a[11] * b[8] - a[13] * b[10] + a[14] * b[5] + a[15] * b[12];
+        let result_einf    = a[4] * b[0] - a[8] * b[2] - a[9] * b[3] + a[10] * b[4] - a[12] * b[8] - a[13] * b[9] - a[14] * b[7] - a[15] * b[14];
+        let result_e01     = a[5] * b[0] + a[11] * b[3] - a[12] * b[1] - a[15] * b[6];
+        let result_e02     = a[6] * b[0] - a[11] * b[2] - a[13] * b[1] + a[15] * b[5];
+        let result_e12     = a[7] * b[0] - a[11] * b[4] - a[14] * b[1] + a[15] * b[10];
+        let result_e1inf   = a[8] * b[0] - a[12] * b[4] - a[14] * b[3] + a[15] * b[9];
+        let result_e2inf   = a[9] * b[0] - a[13] * b[4] + a[14] * b[2] - a[15] * b[8];
+        let result_e0inf   = a[10] * b[0] - a[12] * b[2] - a[13] * b[3] - a[15] * b[7];
+        let result_e012    = a[11] * b[0] - a[15] * b[1];
+        let result_e01inf  = a[12] * b[0] - a[15] * b[3];
+        let result_e02inf  = a[13] * b[0] + a[15] * b[2];
+        let result_e12inf  = a[14] * b[0] + a[15] * b[4];
+        let result_e012inf = a[15] * b[0];
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::Shr<S> for ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1       = a[0] * other;
+        let result_e0      = a[1] * other;
+        let result_e1      = a[2] * other;
+        let result_e2      = a[3] * other;
+        let result_einf    = a[4] * other;
+        let result_e01     = a[5] * other;
+        let result_e02     = a[6] * other;
+        let result_e12     = a[7] * other;
+        let result_e1inf   = a[8] * other;
+        let result_e2inf   = a[9] * other;
+        let result_e0inf   = a[10] * other;
+        let result_e012    = a[11] * other;
+        let result_e01inf  = a[12] * other;
+        let result_e02inf  = a[13] * other;
+        let result_e12inf  = a[14] * other;
+        let result_e012inf = a[15] * other;
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ops::Shr<S> for &ConformalMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1       = a[0] * other;
+        let result_e0      = a[1] * other;
+        let result_e1      = a[2] * other;
+        let result_e2      = a[3] * other;
+        let result_einf    = a[4] * other;
+        let result_e01     = a[5] * other;
+        let result_e02     = a[6] * other;
+        let result_e12     = a[7] * other;
+        let result_e1inf   = a[8] * other;
+        let result_e2inf   = a[9] * other;
+        let result_e0inf   = a[10] * other;
+        let result_e012    = a[11] * other;
+        let result_e01inf  = a[12] * other;
+        let result_e02inf  = a[13] * other;
+        let result_e12inf  = a[14] * other;
+        let result_e012inf = a[15] * other;
+
+        ConformalMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e12,
+            result_e1inf,
+            result_e2inf,
+            result_e0inf,
+            result_e012,
+            result_e01inf,
+            result_e02inf,
+            result_e12inf,
+            result_e012inf,
+        )
+    }
+}
+
+impl<S> ConformalMultivector2<S>
+where
+    S: ScalarFloat,
+{
+    /// Embed the grade one part of a Euclidean multivector as a conformal vector.
+    #[inline]
+    fn from_euclidean_vector(vector: &EuclideanMultivector2<S>) -> Self {
+        let mut result = Self::zero();
+        result.data[2] = vector[1];
+        result.data[3] = vector[2];
+
+        result
+    }
+
+    /// Embed a Euclidean vector into conformal space as a point.
+    ///
+    /// The conformal point of the Euclidean vector `x` is the null vector
+    /// ```text
+    /// up(x) := e0 + x + (1 / 2) * (x | x) * einf
+    /// ```
+    /// Only the grade one part of `vector` is used. The scalar product of two
+    /// conformal points encodes the squared Euclidean distance between them
+    /// ```text
+    /// up(x) | up(y) == -(1 / 2) * |x - y|^2
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// #
+    /// let x = EuclideanMultivector2::new(0_f64, 3_f64, 4_f64, 0_f64);
+    /// let y = EuclideanMultivector2::new(0_f64, 6_f64, 8_f64, 0_f64);
+    /// let point_x = ConformalMultivector2::up(&x);
+    /// let point_y = ConformalMultivector2::up(&y);
+    ///
+    /// assert_eq!(point_x.e0, 1_f64);
+    /// assert_eq!(point_x.einf, 12.5_f64);
+    /// assert!((point_x | point_x).is_zero());
+    /// assert_relative_eq!((point_x | point_y)[0], -12.5_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn up(vector: &EuclideanMultivector2<S>) -> Self {
+        let one_half = S::one() / (S::one() + S::one());
+        let mut result = Self::from_euclidean_vector(vector);
+        let norm_squared = vector[1] * vector[1] + vector[2] * vector[2];
+        result.data[1] = S::one();
+        result.data[4] = one_half * norm_squared;
+
+        result
+    }
+
+    /// Project a conformal point down to a Euclidean vector.
+    ///
+    /// A conformal point `P` is a null vector whose weight `-(P | einf)`
+    /// is its `e0` component. The Euclidean vector of the point is its
+    /// Euclidean part divided by its weight, so that `down(up(x)) == x`,
+    /// and so that points of any nonzero weight project to the same
+    /// Euclidean vector. The function returns `None` when the weight of
+    /// `self` vanishes up to roundoff relative to its Euclidean part, as for
+    /// directions and other points at infinity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// #
+    /// let x = EuclideanMultivector2::new(0_f64, 1_f64, 2_f64, 0_f64);
+    /// let point = ConformalMultivector2::up(&x) * 5_f64;
+    ///
+    /// assert_relative_eq!(point.down().unwrap(), x, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    ///
+    /// let direction = ConformalMultivector2::unit_e1() * 2_f64 - ConformalMultivector2::unit_e2();
+    ///
+    /// assert!(direction.down().is_none());
+    /// ```
+    pub fn down(&self) -> Option<EuclideanMultivector2<S>> {
+        let weight = self.data[1];
+        let euclidean_norm = (self.data[2] * self.data[2] + self.data[3] * self.data[3]).sqrt();
+        let is_finite = ulps_ne!(
+            weight,
+            S::zero(),
+            abs_diff_all <= S::default_epsilon() * euclidean_norm,
+            ulps_all <= S::default_max_ulps()
+        );
+        if !is_finite {
+            return None;
+        }
+
+        let one_over_weight = S::one() / weight;
+        let x = self.data[2] * one_over_weight;
+        let y = self.data[3] * one_over_weight;
+
+        Some(EuclideanMultivector2::new(S::zero(), x, y, S::zero()))
+    }
+
+    /// Construct the circle with center `center` and radius `radius`.
+    ///
+    /// The circle is the undual of the dual circle
+    /// ```text
+    /// up(center) - (1 / 2) * radius^2 * einf
+    /// ```
+    /// which is the three-blade spanned by any three distinct points on the
+    /// circle, up to scale. Only the grade one part of `center` is used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// #
+    /// let center = EuclideanMultivector2::new(0_f64, 1_f64, 2_f64, 0_f64);
+    /// let circle = ConformalMultivector2::from_circle(&center, 2_f64);
+    /// let on_circle = EuclideanMultivector2::new(0_f64, 1_f64, 4_f64, 0_f64);
+    /// let zero: ConformalMultivector2<f64> = ConformalMultivector2::zero();
+    ///
+    /// assert_relative_eq!(ConformalMultivector2::up(&on_circle) ^ circle, zero, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert!(!(ConformalMultivector2::up(&center) ^ circle).is_zero());
+    /// ```
+    pub fn from_circle(center: &EuclideanMultivector2<S>, radius: S) -> Self {
+        let one_half = S::one() / (S::one() + S::one());
+        let mut dual_circle = Self::up(center);
+        dual_circle.data[4] -= one_half * radius * radius;
+
+        dual_circle.undual()
+    }
+
+    /// Construct the circle through the points `p`, `q` and `r`.
+    ///
+    /// The circle is the three-blade
+    /// ```text
+    /// up(p) ^ up(q) ^ up(r)
+    /// ```
+    /// When the points are collinear, the result is the line through the
+    /// points, since a line is a circle through the point at infinity. Only
+    /// the grade one parts of `p`, `q` and `r` are used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// #
+    /// let p = EuclideanMultivector2::new(0_f64, 4_f64, 2_f64, 0_f64);
+    /// let q = EuclideanMultivector2::new(0_f64, 1_f64, 5_f64, 0_f64);
+    /// let r = EuclideanMultivector2::new(0_f64, -2_f64, 2_f64, 0_f64);
+    /// let circle = ConformalMultivector2::from_three_points(&p, &q, &r);
+    /// let (center, radius) = circle.center_radius().unwrap();
+    ///
+    /// assert_relative_eq!(center, EuclideanMultivector2::new(0_f64, 1_f64, 2_f64, 0_f64), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(radius, 3_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn from_three_points(p: &EuclideanMultivector2<S>, q: &EuclideanMultivector2<S>, r: &EuclideanMultivector2<S>) -> Self {
+        Self::up(p) ^ Self::up(q) ^ Self::up(r)
+    }
+
+    /// Construct the line through the point `point` with direction `direction`.
+    ///
+    /// The line is the three-blade
+    /// ```text
+    /// up(point) ^ direction ^ einf
+    /// ```
+    /// which is the three-blade spanned by any two distinct points on the
+    /// line and the point at infinity, up to scale. Only the grade one parts of
+    /// `point` and `direction` are used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// #
+    /// let point = EuclideanMultivector2::new(0_f64, 1_f64, 1_f64, 0_f64);
+    /// let direction = EuclideanMultivector2::new(0_f64, 1_f64, 2_f64, 0_f64);
+    /// let line = ConformalMultivector2::from_line(&point, &direction);
+    /// let on_line = EuclideanMultivector2::new(0_f64, 3_f64, 5_f64, 0_f64);
+    /// let zero: ConformalMultivector2<f64> = ConformalMultivector2::zero();
+    ///
+    /// assert_relative_eq!(ConformalMultivector2::up(&on_line) ^ line, zero, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn from_line(point: &EuclideanMultivector2<S>, direction: &EuclideanMultivector2<S>) -> Self {
+        Self::up(point) ^ Self::from_euclidean_vector(direction) ^ Self::unit_einf()
+    }
+
+    /// Construct the point pair consisting of the points `p` and `q`.
+    ///
+    /// The point pair is the two-blade
+    /// ```text
+    /// up(p) ^ up(q)
+    /// ```
+    /// Only the grade one parts of `p` and `q` are used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// #
+    /// let p = EuclideanMultivector2::new(0_f64, 1_f64, 2_f64, 0_f64);
+    /// let q = EuclideanMultivector2::new(0_f64, 4_f64, -1_f64, 0_f64);
+    /// let point_pair = ConformalMultivector2::from_point_pair(&p, &q);
+    /// let (result_p, result_q) = point_pair.split_point_pair().unwrap();
+    ///
+    /// assert_relative_eq!(result_p, p, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(result_q, q, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn from_point_pair(p: &EuclideanMultivector2<S>, q: &EuclideanMultivector2<S>) -> Self {
+        Self::up(p) ^ Self::up(q)
+    }
+
+    /// Construct the flat point at the point `point`.
+    ///
+    /// The flat point is the two-blade
+    /// ```text
+    /// up(point) ^ einf
+    /// ```
+    /// which is the point pair consisting of `point` and the point at infinity.
+    /// Flat points are the meets of intersecting lines. Only the grade one
+    /// part of `point` is used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// #
+    /// let line1 = ConformalMultivector2::from_line(&EuclideanMultivector2::new(0_f64, 0_f64, 2_f64, 0_f64), &EuclideanMultivector2::new(0_f64, 1_f64, 0_f64, 0_f64));
+    /// let line2 = ConformalMultivector2::from_line(&EuclideanMultivector2::new(0_f64, 3_f64, 0_f64, 0_f64), &EuclideanMultivector2::new(0_f64, 0_f64, 1_f64, 0_f64));
+    /// let flat_point = line1.meet(&line2);
+    /// let expected = ConformalMultivector2::from_flat_point(&EuclideanMultivector2::new(0_f64, 3_f64, 2_f64, 0_f64));
+    /// let scale = flat_point.e0inf / expected.e0inf;
+    ///
+    /// assert_relative_eq!(flat_point, expected * scale, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn from_flat_point(point: &EuclideanMultivector2<S>) -> Self {
+        Self::up(point) ^ Self::unit_einf()
+    }
+
+    /// Compute the center and the radius of a circle.
+    ///
+    /// The dual of a circle is, up to the weight `w == -(dual(circle) | einf)`,
+    /// the dual circle
+    /// ```text
+    /// dual(circle) / w == up(center) - (1 / 2) * radius^2 * einf
+    /// ```
+    /// whose square is `radius^2`. The function returns `None` when `self`
+    /// is a line, whose weight vanishes up to roundoff relative to the other
+    /// components of its dual, or when `self` is an imaginary circle, which
+    /// has a negative squared radius.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// #
+    /// let center = EuclideanMultivector2::new(0_f64, 1_f64, 2_f64, 0_f64);
+    /// let circle = ConformalMultivector2::from_circle(&center, 3_f64) * 4_f64;
+    /// let (result_center, result_radius) = circle.center_radius().unwrap();
+    ///
+    /// assert_relative_eq!(result_center, center, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(result_radius, 3_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn center_radius(&self) -> Option<(EuclideanMultivector2<S>, S)> {
+        let dual_circle = self.dual();
+        let weight = dual_circle.data[1];
+        // For a circle through three points, the weight, the vector part and the
+        // `einf` part of the dual circle are homogeneous of degrees two, three and
+        // four in the coordinates of the points, so the tolerance is scaled by a
+        // combination of the latter two with degree two. The weight of a line
+        // only vanishes up to roundoff, which grows with the distance of the
+        // points from the origin, hence the margin over the machine epsilon.
+        let vector_part_squared = dual_circle.data[2] * dual_circle.data[2] + dual_circle.data[3] * dual_circle.data[3];
+        let scale = vector_part_squared.cbrt() + dual_circle.data[4].abs().sqrt();
+        let margin: S = num_traits::cast(1024_i32).unwrap();
+        let tolerance = margin * S::default_epsilon() * scale;
+        let is_circle = ulps_ne!(
+            weight,
+            S::zero(),
+            abs_diff_all <= tolerance,
+            ulps_all <= S::default_max_ulps()
+        );
+        if !is_circle {
+            return None;
+        }
+
+        let radius_squared = (dual_circle * dual_circle)[0] / (weight * weight);
+        if radius_squared < S::zero() {
+            return None;
+        }
+
+        Some((dual_circle.down()?, radius_squared.sqrt()))
+    }
+
+    /// Construct the line tangent to a circle or a line at the point `point`.
+    ///
+    /// The tangent of a blade `A` at a point `X` on `A` is the two-blade
+    /// `X << A`, and the tangent line is
+    /// ```text
+    /// (up(point) << self) ^ einf
+    /// ```
+    /// The point `point` is assumed to lie on `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// #
+    /// let circle = ConformalMultivector2::from_circle(&EuclideanMultivector2::new(0_f64, 1_f64, 2_f64, 0_f64), 3_f64);
+    /// let tangent_line = circle.tangent_line(&EuclideanMultivector2::new(0_f64, 4_f64, 2_f64, 0_f64));
+    /// let expected = ConformalMultivector2::from_line(&EuclideanMultivector2::new(0_f64, 4_f64, 2_f64, 0_f64), &EuclideanMultivector2::new(0_f64, 0_f64, 1_f64, 0_f64));
+    /// let scale = tangent_line.e02inf / expected.e02inf;
+    ///
+    /// assert_relative_eq!(tangent_line, expected * scale, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn tangent_line(&self, point: &EuclideanMultivector2<S>) -> Self {
+        (Self::up(point) << self) ^ Self::unit_einf()
+    }
+
+    /// Construct the circle tangent to a circle or a line at the point `point`
+    /// that passes through the point `through`.
+    ///
+    /// The circle is the three-blade
+    /// ```text
+    /// (up(point) << self) ^ up(through)
+    /// ```
+    /// The point `point` is assumed to lie on `self`. When `through` lies on
+    /// the tangent line at `point`, the result is the tangent line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// #
+    /// let circle = ConformalMultivector2::from_circle(&EuclideanMultivector2::new(0_f64, 1_f64, 2_f64, 0_f64), 3_f64);
+    /// let tangent_circle = circle.tangent_circle(&EuclideanMultivector2::new(0_f64, 4_f64, 2_f64, 0_f64), &EuclideanMultivector2::new(0_f64, 8_f64, 2_f64, 0_f64));
+    /// let (center, radius) = tangent_circle.center_radius().unwrap();
+    ///
+    /// assert_relative_eq!(center, EuclideanMultivector2::new(0_f64, 6_f64, 2_f64, 0_f64), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(radius, 2_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn tangent_circle(&self, point: &EuclideanMultivector2<S>, through: &EuclideanMultivector2<S>) -> Self {
+        (Self::up(point) << self) ^ Self::up(through)
+    }
+
+    /// Split a point pair into its two points.
+    ///
+    /// For a point pair `T == up(p) ^ up(q)` the points are recovered from
+    /// ```text
+    /// (T - sqrt(T * T)) * (einf << T) ~ up(p)
+    /// (T + sqrt(T * T)) * (einf << T) ~ up(q)
+    /// ```
+    /// where `~` denotes equality up to scale. The points are returned in the
+    /// order `(p, q)` when `self` is a positive multiple of `up(p) ^ up(q)`. The
+    /// function returns `None` when `self` is an imaginary point pair, i.e.
+    /// when `T * T` is negative, as for the meet of two disjoint circles, when
+    /// one of the points is at infinity, or when `self` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// #
+    /// let circle1 = ConformalMultivector2::from_circle(&EuclideanMultivector2::new(0_f64, 0_f64, 0_f64, 0_f64), 5_f64);
+    /// let circle2 = ConformalMultivector2::from_circle(&EuclideanMultivector2::new(0_f64, 6_f64, 0_f64, 0_f64), 5_f64);
+    /// let (p, q) = circle1.meet(&circle2).split_point_pair().unwrap();
+    ///
+    /// assert_relative_eq!(p[1], 3_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(q[1], 3_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(p[2].abs(), 4_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(p[2], -q[2], abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn split_point_pair(&self) -> Option<(EuclideanMultivector2<S>, EuclideanMultivector2<S>)> {
+        let square = (self * self)[0];
+        if square < S::zero() || self.is_zero() {
+            return None;
+        }
+
+        let root = Self::from_scalar(square.sqrt());
+        let direction = Self::unit_einf() << self;
+        let p = (self - root) * direction;
+        let q = (self + root) * direction;
+
+        Some((p.down()?, q.down()?))
+    }
+
+    /// Compute the meet of two geometric objects.
+    ///
+    /// The meet is the intersection of two geometric objects, and is given by
+    /// the regressive product
+    /// ```text
+    /// meet(A, B) := A & B
+    /// ```
+    /// For example, two circles meet in a point pair, a circle and a line meet
+    /// in a point pair, and two lines meet in a flat point.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// #
+    /// let circle = ConformalMultivector2::from_circle(&EuclideanMultivector2::new(0_f64, 0_f64, 0_f64, 0_f64), 2_f64);
+    /// let line = ConformalMultivector2::from_line(&EuclideanMultivector2::new(0_f64, 0_f64, 0_f64, 0_f64), &EuclideanMultivector2::new(0_f64, 1_f64, 0_f64, 0_f64));
+    /// let (p, q) = circle.meet(&line).split_point_pair().unwrap();
+    ///
+    /// assert_relative_eq!((p - q).magnitude(), 4_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(p[2], 0_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn meet(&self, other: &Self) -> Self {
+        self & other
+    }
+
+    /// Compute the join of two geometric objects.
+    ///
+    /// The join is the smallest geometric object containing both geometric
+    /// objects, and is given by the outer product
+    /// ```text
+    /// join(A, B) := A ^ B
+    /// ```
+    /// For example, two points join into a point pair, and three points join
+    /// into a circle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::cga2::ConformalMultivector2;
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// #
+    /// let p = ConformalMultivector2::up(&EuclideanMultivector2::new(0_f64, 1_f64, 0_f64, 0_f64));
+    /// let q = ConformalMultivector2::up(&EuclideanMultivector2::new(0_f64, 0_f64, 1_f64, 0_f64));
+    /// let r = ConformalMultivector2::up(&EuclideanMultivector2::new(0_f64, -1_f64, 0_f64, 0_f64));
+    /// let circle = p.join(&q).join(&r);
+    /// let expected = ConformalMultivector2::from_circle(&EuclideanMultivector2::zero(), 1_f64);
+    ///
+    /// // The join and the constructed circle agree up to scale.
+    /// let scale = circle.e012 / expected.e012;
+    /// assert_relative_eq!(circle, expected * scale, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn join(&self, other: &Self) -> Self {
+        self ^ other
+    }
+}
+
+
+impl_coords!(ViewC2, { scalar, e0, e1, e2, einf, e01, e02, e12, e1inf, e2inf, e0inf, e012, e01inf, e02inf, e12inf, e012inf });
+impl_coords_deref!(ConformalMultivector2, ViewC2);
+
+
+macro_rules! impl_scalar_multivector_add_ops {
+    ($Lhs:ty => $Rhs:ty => $Output:ty, { $scalar_index:expr }, { $($other_index:expr),* }) => {
+        impl ops::Add<$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn add(self, other: $Rhs) -> $Output {
+                Self::Output::new(self + other[$scalar_index], $(other[$other_index]),* )
+            }
+        }
+
+        impl ops::Add<&$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn add(self, other: &$Rhs) -> $Output {
+                Self::Output::new(self + other[$scalar_index], $(other[$other_index]),* )
+            }
+        }
+    }
+}
+
+impl_scalar_multivector_add_ops!(u8    => ConformalMultivector2<u8>    => ConformalMultivector2<u8>,    {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(u16   => ConformalMultivector2<u16>   => ConformalMultivector2<u16>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(u32   => ConformalMultivector2<u32>   => ConformalMultivector2<u32>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(u64   => ConformalMultivector2<u64>   => ConformalMultivector2<u64>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(u128  => ConformalMultivector2<u128>  => ConformalMultivector2<u128>,  {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(usize => ConformalMultivector2<usize> => ConformalMultivector2<usize>, {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(i8    => ConformalMultivector2<i8>    => ConformalMultivector2<i8>,    {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(i16   => ConformalMultivector2<i16>   => ConformalMultivector2<i16>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(i32   => ConformalMultivector2<i32>   => ConformalMultivector2<i32>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(i64   => ConformalMultivector2<i64>   => ConformalMultivector2<i64>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(i128  => ConformalMultivector2<i128>  => ConformalMultivector2<i128>,  {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(isize => ConformalMultivector2<isize> => ConformalMultivector2<isize>, {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(f32   => ConformalMultivector2<f32>   => ConformalMultivector2<f32>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(f64   => ConformalMultivector2<f64>   => ConformalMultivector2<f64>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+
+
+macro_rules! impl_scalar_multivector_sub_ops {
+    ($Lhs:ty => $Rhs:ty => $Output:ty, { $scalar_index:expr }, { $($other_index:expr),* }) => {
+        impl ops::Sub<$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn sub(self, other: $Rhs) -> Self::Output {
+                Self::Output::new(self - other[$scalar_index], $(-other[$other_index]),* )
+            }
+        }
+
+        impl ops::Sub<&$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn sub(self, other: &$Rhs) -> Self::Output {
+                Self::Output::new(self - other[$scalar_index], $(-other[$other_index]),* )
+            }
+        }
+    }
+}
+
+impl_scalar_multivector_sub_ops!(i8    => ConformalMultivector2<i8>    => ConformalMultivector2<i8>,    {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_sub_ops!(i16   => ConformalMultivector2<i16>   => ConformalMultivector2<i16>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_sub_ops!(i32   => ConformalMultivector2<i32>   => ConformalMultivector2<i32>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_sub_ops!(i64   => ConformalMultivector2<i64>   => ConformalMultivector2<i64>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_sub_ops!(i128  => ConformalMultivector2<i128>  => ConformalMultivector2<i128>,  {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_sub_ops!(isize => ConformalMultivector2<isize> => ConformalMultivector2<isize>, {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_sub_ops!(f32   => ConformalMultivector2<f32>   => ConformalMultivector2<f32>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_sub_ops!(f64   => ConformalMultivector2<f64>   => ConformalMultivector2<f64>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+
+
+macro_rules! impl_scalar_multivector_mul_ops {
+    ($Lhs:ty => $Rhs:ty => $Output:ty, { $($index:expr),* }) => {
+        impl ops::Mul<$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn mul(self, other: $Rhs) -> Self::Output {
+                Self::Output::new( $(self * other[$index]),* )
+            }
+        }
+
+        impl ops::Mul<&$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn mul(self, other: &$Rhs) -> Self::Output {
+                Self::Output::new( $(self * other[$index]),* )
+            }
+        }
+    }
+}
+
+impl_scalar_multivector_mul_ops!(u8    => ConformalMultivector2<u8>    => ConformalMultivector2<u8>,    {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(u16   => ConformalMultivector2<u16>   => ConformalMultivector2<u16>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(u32   => ConformalMultivector2<u32>   => ConformalMultivector2<u32>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(u64   => ConformalMultivector2<u64>   => ConformalMultivector2<u64>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(u128  => ConformalMultivector2<u128>  => ConformalMultivector2<u128>,  {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(usize => ConformalMultivector2<usize> => ConformalMultivector2<usize>, {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(i8    => ConformalMultivector2<i8>    => ConformalMultivector2<i8>,    {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(i16   => ConformalMultivector2<i16>   => ConformalMultivector2<i16>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(i32   => ConformalMultivector2<i32>   => ConformalMultivector2<i32>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(i64   => ConformalMultivector2<i64>   => ConformalMultivector2<i64>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(i128  => ConformalMultivector2<i128>  => ConformalMultivector2<i128>,  {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(isize => ConformalMultivector2<isize> => ConformalMultivector2<isize>, {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(f32   => ConformalMultivector2<f32>   => ConformalMultivector2<f32>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(f64   => ConformalMultivector2<f64>   => ConformalMultivector2<f64>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+
+
+macro_rules! impl_scalar_multivector_bitor_ops {
+    ($Lhs:ty) => {
+        impl ops::BitOr<ConformalMultivector2<$Lhs>> for $Lhs {
+            type Output = ConformalMultivector2<$Lhs>;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            #[inline]
+            fn bitor(self, other: ConformalMultivector2<$Lhs>) -> Self::Output {
+                let mut result = Self::Output::zero();
+                result[0] = self * other[0];
+
+                result
+            }
+        }
+
+        impl ops::BitOr<&ConformalMultivector2<$Lhs>> for $Lhs {
+            type Output = ConformalMultivector2<$Lhs>;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            #[inline]
+            fn bitor(self, other: &ConformalMultivector2<$Lhs>) -> Self::Output {
+                let mut result = Self::Output::zero();
+                result[0] = self * other[0];
+
+                result
+            }
+        }
+    };
+}
+
+impl_scalar_multivector_bitor_ops!(u8);
+impl_scalar_multivector_bitor_ops!(u16);
+impl_scalar_multivector_bitor_ops!(u32);
+impl_scalar_multivector_bitor_ops!(u64);
+impl_scalar_multivector_bitor_ops!(u128);
+impl_scalar_multivector_bitor_ops!(usize);
+impl_scalar_multivector_bitor_ops!(i8);
+impl_scalar_multivector_bitor_ops!(i16);
+impl_scalar_multivector_bitor_ops!(i32);
+impl_scalar_multivector_bitor_ops!(i64);
+impl_scalar_multivector_bitor_ops!(i128);
+impl_scalar_multivector_bitor_ops!(isize);
+impl_scalar_multivector_bitor_ops!(f32);
+impl_scalar_multivector_bitor_ops!(f64);
+
+
+macro_rules! impl_scalar_multivector_bitxor_ops {
+    ($Lhs:ty) => {
+        impl ops::BitXor<ConformalMultivector2<$Lhs>> for $Lhs {
+            type Output = ConformalMultivector2<$Lhs>;
+
+            #[inline]
+            fn bitxor(self, other: ConformalMultivector2<$Lhs>) -> Self::Output {
+                let mut result = Self::Output::zero();
+                result[0] = self * other[0];
+                result[1] = self * other[1];
+                result[2] = self * other[2];
+                result[3] = self * other[3];
+                result[4] = self * other[4];
+                result[5] = self * other[5];
+                result[6] = self * other[6];
+                result[7] = self * other[7];
+                result[8] = self * other[8];
+                result[9] = self * other[9];
+                result[10] = self * other[10];
+                result[11] = self * other[11];
+                result[12] = self * other[12];
+                result[13] = self * other[13];
+                result[14] = self * other[14];
+                result[15] = self * other[15];
+
+                result
+            }
+        }
+
+        impl ops::BitXor<&ConformalMultivector2<$Lhs>> for $Lhs {
+            type Output = ConformalMultivector2<$Lhs>;
+
+            #[inline]
+            fn bitxor(self, other: &ConformalMultivector2<$Lhs>) -> Self::Output {
+                let mut result = Self::Output::zero();
+                result[0] = self * other[0];
+                result[1] = self * other[1];
+                result[2] = self * other[2];
+                result[3] = self * other[3];
+                result[4] = self * other[4];
+                result[5] = self * other[5];
+                result[6] = self * other[6];
+                result[7] = self * other[7];
+                result[8] = self * other[8];
+                result[9] = self * other[9];
+                result[10] = self * other[10];
+                result[11] = self * other[11];
+                result[12] = self * other[12];
+                result[13] = self * other[13];
+                result[14] = self * other[14];
+                result[15] = self * other[15];
+
+                result
+            }
+        }
+    };
+}
+
+impl_scalar_multivector_bitxor_ops!(u8);
+impl_scalar_multivector_bitxor_ops!(u16);
+impl_scalar_multivector_bitxor_ops!(u32);
+impl_scalar_multivector_bitxor_ops!(u64);
+impl_scalar_multivector_bitxor_ops!(u128);
+impl_scalar_multivector_bitxor_ops!(usize);
+impl_scalar_multivector_bitxor_ops!(i8);
+impl_scalar_multivector_bitxor_ops!(i16);
+impl_scalar_multivector_bitxor_ops!(i32);
+impl_scalar_multivector_bitxor_ops!(i64);
+impl_scalar_multivector_bitxor_ops!(i128);
+impl_scalar_multivector_bitxor_ops!(isize);
+impl_scalar_multivector_bitxor_ops!(f32);
+impl_scalar_multivector_bitxor_ops!(f64);
@@ -0,0 +1,6312 @@
+use crate::e3ga::{
+    EuclideanMultivector3,
+    Rotor3,
+};
+use crate::scalar::{
+    Scalar,
+    ScalarFloat,
+    ScalarSigned,
+};
+use crate::{
+    impl_approx_cmp_ops,
+    impl_coords,
+    impl_coords_deref,
+};
+use approx_cmp::ulps_ne;
+use core::fmt;
+use core::ops;
+
+
+/// A stack-allocated, three-dimensional conformal multivector in the basis
+/// `{1, e0, e1, e2, e3, einf, e01, e02, e03, e12, e23, e31, e1inf, e2inf, e3inf, e0inf,
+/// e012, e023, e031, e01inf, e02inf, e03inf, e123, e12inf, e23inf, e31inf,
+/// e0123, e012inf, e023inf, e031inf, e123inf, e0123inf}`.
+///
+/// This is the geometric algebra `Cl(4, 1)`, written in a null basis. The basis
+/// vectors `e1`, `e2`, `e3` are orthonormal, and they are orthogonal to the null
+/// basis vectors `e0` and `einf`, which represent the origin and the point at
+/// infinity, and satisfy
+/// ```text
+/// e0 * e0 == einf * einf == 0
+/// e0 | einf == -1
+/// ```
+/// The basis blades are the outer products of the basis vectors. Geometric objects
+/// are represented by blades spanned by the points they contain, so that the outer
+/// product `^` computes the join of geometric objects, and the regressive product
+/// `&` computes their meet (intersection). Translations, rotations, dilations and
+/// inversions are all versors in conformal geometric algebra.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ConformalMultivector3<S> {
+    data: [S; 32],
+}
+
+impl<S> ConformalMultivector3<S> {
+    /// Construct a new general multivector.
+    #[allow(clippy::too_many_arguments)]
+    #[inline]
+    pub const fn new(
+        scalar: S,
+        e0: S,
+        e1: S,
+        e2: S,
+        e3: S,
+        einf: S,
+        e01: S,
+        e02: S,
+        e03: S,
+        e12: S,
+        e23: S,
+        e31: S,
+        e1inf: S,
+        e2inf: S,
+        e3inf: S,
+        e0inf: S,
+        e012: S,
+        e023: S,
+        e031: S,
+        e01inf: S,
+        e02inf: S,
+        e03inf: S,
+        e123: S,
+        e12inf: S,
+        e23inf: S,
+        e31inf: S,
+        e0123: S,
+        e012inf: S,
+        e023inf: S,
+        e031inf: S,
+        e123inf: S,
+        e0123inf: S,
+    ) -> Self {
+        Self {
+            data: [
+                scalar, e0, e1, e2, e3, einf, e01, e02, e03, e12, e23, e31, e1inf, e2inf, e3inf, e0inf, e012, e023, e031, e01inf, e02inf,
+                e03inf, e123, e12inf, e23inf, e31inf, e0123, e012inf, e023inf, e031inf, e123inf, e0123inf,
+            ],
+        }
+    }
+
+    /// Returns the number of components in a multivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let mv = ConformalMultivector3::new(
+    ///     1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32,
+    ///     1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32,
+    ///     1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32,
+    ///     1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32
+    /// );
+    ///
+    /// assert_eq!(mv.len(), 32);
+    /// ```
+    #[inline]
+    pub const fn len(&self) -> usize {
+        32
+    }
+
+    /// Get a pointer to the underlying component array.
+    #[inline]
+    pub const fn as_ptr(&self) -> *const S {
+        &self.data[0]
+    }
+
+    /// Get a mutable pointer to the underlying component array.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut S {
+        &mut self.data[0]
+    }
+
+    /// Get a slice of the underlying elements of the data type.
+    #[inline]
+    pub fn as_slice(&self) -> &[S] {
+        <Self as AsRef<[S; 32]>>::as_ref(self)
+    }
+}
+
+impl<S> ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    /// Construct the additive unit (zero) multivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let mv = ConformalMultivector3::new(
+    ///     1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64,
+    ///     9_f64, 10_f64, 11_f64, 12_f64, 13_f64, 14_f64, 15_f64, 16_f64,
+    ///     17_f64, 18_f64, 19_f64, 20_f64, 21_f64, 22_f64, 23_f64, 24_f64,
+    ///     25_f64, 26_f64, 27_f64, 28_f64, 29_f64, 30_f64, 31_f64, 32_f64
+    /// );
+    /// let zero: ConformalMultivector3<f64> = ConformalMultivector3::zero();
+    ///
+    /// assert_eq!(mv + zero, mv);
+    /// assert_eq!(zero + mv, mv);
+    /// ```
+    #[inline]
+    pub fn zero() -> Self {
+        Self { data: [S::zero(); 32] }
+    }
+
+    /// Determine whether a multivector is the zero mutlivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let zero: ConformalMultivector3<f64> = ConformalMultivector3::zero();
+    ///
+    /// assert!(zero.is_zero());
+    ///
+    /// let mv = ConformalMultivector3::new(
+    ///     1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64,
+    ///     9_f64, 10_f64, 11_f64, 12_f64, 13_f64, 14_f64, 15_f64, 16_f64,
+    ///     17_f64, 18_f64, 19_f64, 20_f64, 21_f64, 22_f64, 23_f64, 24_f64,
+    ///     25_f64, 26_f64, 27_f64, 28_f64, 29_f64, 30_f64, 31_f64, 32_f64
+    /// );
+    ///
+    /// assert!(!mv.is_zero());
+    /// ```
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.data.iter().all(|component| component.is_zero())
+    }
+
+    /// Construct a new multivector from the scalar part only.
+    ///
+    /// A scalar is a multivector whose vector, bivector, etc. components are
+    /// all zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let scalar_part = 2;
+    /// let scalar = ConformalMultivector3::from_scalar(scalar_part);
+    ///
+    /// assert_eq!(scalar.scalar, scalar_part);
+    /// assert_eq!(scalar - scalar_part, ConformalMultivector3::zero());
+    /// ```
+    #[inline]
+    pub fn from_scalar(scalar: S) -> Self {
+        let mut result = Self::zero();
+        result.data[0] = scalar;
+
+        result
+    }
+
+    /// Returns the unit scalar.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let unit_scalar: ConformalMultivector3<isize> = ConformalMultivector3::unit_scalar();
+    ///
+    /// let expected = ConformalMultivector3::new(
+    ///     1_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    ///
+    /// assert_eq!(unit_scalar.scalar, 1);
+    /// assert_eq!(unit_scalar, expected);
+    /// ```
+    #[inline]
+    pub fn unit_scalar() -> Self {
+        let mut result = Self::zero();
+        result.data[0] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e0` vector, which represents the origin.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let unit_e0: ConformalMultivector3<isize> = ConformalMultivector3::unit_e0();
+    ///
+    /// let expected = ConformalMultivector3::new(
+    ///     0_isize, 1_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    ///
+    /// assert_eq!(unit_e0.e0, 1);
+    /// assert_eq!(unit_e0, expected);
+    /// ```
+    #[inline]
+    pub fn unit_e0() -> Self {
+        let mut result = Self::zero();
+        result.data[1] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `x`-axis vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let unit_e1: ConformalMultivector3<isize> = ConformalMultivector3::unit_e1();
+    ///
+    /// let expected = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 1_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    ///
+    /// assert_eq!(unit_e1.e1, 1);
+    /// assert_eq!(unit_e1, expected);
+    /// ```
+    #[inline]
+    pub fn unit_e1() -> Self {
+        let mut result = Self::zero();
+        result.data[2] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `y`-axis vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let unit_e2: ConformalMultivector3<isize> = ConformalMultivector3::unit_e2();
+    ///
+    /// let expected = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 1_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    ///
+    /// assert_eq!(unit_e2.e2, 1);
+    /// assert_eq!(unit_e2, expected);
+    /// ```
+    #[inline]
+    pub fn unit_e2() -> Self {
+        let mut result = Self::zero();
+        result.data[3] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `z`-axis vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let unit_e3: ConformalMultivector3<isize> = ConformalMultivector3::unit_e3();
+    ///
+    /// let expected = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 1_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    ///
+    /// assert_eq!(unit_e3.e3, 1);
+    /// assert_eq!(unit_e3, expected);
+    /// ```
+    #[inline]
+    pub fn unit_e3() -> Self {
+        let mut result = Self::zero();
+        result.data[4] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `einf` vector, which represents the point at infinity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let unit_einf: ConformalMultivector3<isize> = ConformalMultivector3::unit_einf();
+    ///
+    /// let expected = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 1_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    ///
+    /// assert_eq!(unit_einf.einf, 1);
+    /// assert_eq!(unit_einf, expected);
+    /// ```
+    #[inline]
+    pub fn unit_einf() -> Self {
+        let mut result = Self::zero();
+        result.data[5] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e01` bivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let unit_e01: ConformalMultivector3<isize> = ConformalMultivector3::unit_e01();
+    ///
+    /// let expected = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 1_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    ///
+    /// assert_eq!(unit_e01.e01, 1);
+    /// assert_eq!(unit_e01, expected);
+    /// ```
+    #[inline]
+    pub fn unit_e01() -> Self {
+        let mut result = Self::zero();
+        result.data[6] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e02` bivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let unit_e02: ConformalMultivector3<isize> = ConformalMultivector3::unit_e02();
+    ///
+    /// let expected = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 1_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    ///
+    /// assert_eq!(unit_e02.e02, 1);
+    /// assert_eq!(unit_e02, expected);
+    /// ```
+    #[inline]
+    pub fn unit_e02() -> Self {
+        let mut result = Self::zero();
+        result.data[7] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e03` bivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let unit_e03: ConformalMultivector3<isize> = ConformalMultivector3::unit_e03();
+    ///
+    /// let expected = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     1_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    ///
+    /// assert_eq!(unit_e03.e03, 1);
+    /// assert_eq!(unit_e03, expected);
+    /// ```
+    #[inline]
+    pub fn unit_e03() -> Self {
+        let mut result = Self::zero();
+        result.data[8] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e12` bivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let unit_e12: ConformalMultivector3<isize> = ConformalMultivector3::unit_e12();
+    ///
+    /// let expected = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 1_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    ///
+    /// assert_eq!(unit_e12.e12, 1);
+    /// assert_eq!(unit_e12, expected);
+    /// ```
+    #[inline]
+    pub fn unit_e12() -> Self {
+        let mut result = Self::zero();
+        result.data[9] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e23` bivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let unit_e23: ConformalMultivector3<isize> = ConformalMultivector3::unit_e23();
+    ///
+    /// let expected = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 1_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    ///
+    /// assert_eq!(unit_e23.e23, 1);
+    /// assert_eq!(unit_e23, expected);
+    /// ```
+    #[inline]
+    pub fn unit_e23() -> Self {
+        let mut result = Self::zero();
+        result.data[10] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e31` bivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let unit_e31: ConformalMultivector3<isize> = ConformalMultivector3::unit_e31();
+    ///
+    /// let expected = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 1_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    ///
+    /// assert_eq!(unit_e31.e31, 1);
+    /// assert_eq!(unit_e31, expected);
+    /// ```
+    #[inline]
+    pub fn unit_e31() -> Self {
+        let mut result = Self::zero();
+        result.data[11] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e1inf` bivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let unit_e1inf: ConformalMultivector3<isize> = ConformalMultivector3::unit_e1inf();
+    ///
+    /// let expected = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 1_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    ///
+    /// assert_eq!(unit_e1inf.e1inf, 1);
+    /// assert_eq!(unit_e1inf, expected);
+    /// ```
+    #[inline]
+    pub fn unit_e1inf() -> Self {
+        let mut result = Self::zero();
+        result.data[12] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e2inf` bivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let unit_e2inf: ConformalMultivector3<isize> = ConformalMultivector3::unit_e2inf();
+    ///
+    /// let expected = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 1_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    ///
+    /// assert_eq!(unit_e2inf.e2inf, 1);
+    /// assert_eq!(unit_e2inf, expected);
+    /// ```
+    #[inline]
+    pub fn unit_e2inf() -> Self {
+        let mut result = Self::zero();
+        result.data[13] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e3inf` bivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let unit_e3inf: ConformalMultivector3<isize> = ConformalMultivector3::unit_e3inf();
+    ///
+    /// let expected = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 1_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    ///
+    /// assert_eq!(unit_e3inf.e3inf, 1);
+    /// assert_eq!(unit_e3inf, expected);
+    /// ```
+    #[inline]
+    pub fn unit_e3inf() -> Self {
+        let mut result = Self::zero();
+        result.data[14] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e0inf` bivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let unit_e0inf: ConformalMultivector3<isize> = ConformalMultivector3::unit_e0inf();
+    ///
+    /// let expected = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 1_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    ///
+    /// assert_eq!(unit_e0inf.e0inf, 1);
+    /// assert_eq!(unit_e0inf, expected);
+    /// ```
+    #[inline]
+    pub fn unit_e0inf() -> Self {
+        let mut result = Self::zero();
+        result.data[15] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e012` trivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let unit_e012: ConformalMultivector3<isize> = ConformalMultivector3::unit_e012();
+    ///
+    /// let expected = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     1_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    ///
+    /// assert_eq!(unit_e012.e012, 1);
+    /// assert_eq!(unit_e012, expected);
+    /// ```
+    #[inline]
+    pub fn unit_e012() -> Self {
+        let mut result = Self::zero();
+        result.data[16] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e023` trivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let unit_e023: ConformalMultivector3<isize> = ConformalMultivector3::unit_e023();
+    ///
+    /// let expected = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 1_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    ///
+    /// assert_eq!(unit_e023.e023, 1);
+    /// assert_eq!(unit_e023, expected);
+    /// ```
+    #[inline]
+    pub fn unit_e023() -> Self {
+        let mut result = Self::zero();
+        result.data[17] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e031` trivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let unit_e031: ConformalMultivector3<isize> = ConformalMultivector3::unit_e031();
+    ///
+    /// let expected = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 1_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    ///
+    /// assert_eq!(unit_e031.e031, 1);
+    /// assert_eq!(unit_e031, expected);
+    /// ```
+    #[inline]
+    pub fn unit_e031() -> Self {
+        let mut result = Self::zero();
+        result.data[18] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e01inf` trivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let unit_e01inf: ConformalMultivector3<isize> = ConformalMultivector3::unit_e01inf();
+    ///
+    /// let expected = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 1_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    ///
+    /// assert_eq!(unit_e01inf.e01inf, 1);
+    /// assert_eq!(unit_e01inf, expected);
+    /// ```
+    #[inline]
+    pub fn unit_e01inf() -> Self {
+        let mut result = Self::zero();
+        result.data[19] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e02inf` trivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let unit_e02inf: ConformalMultivector3<isize> = ConformalMultivector3::unit_e02inf();
+    ///
+    /// let expected = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 1_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    ///
+    /// assert_eq!(unit_e02inf.e02inf, 1);
+    /// assert_eq!(unit_e02inf, expected);
+    /// ```
+    #[inline]
+    pub fn unit_e02inf() -> Self {
+        let mut result = Self::zero();
+        result.data[20] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e03inf` trivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let unit_e03inf: ConformalMultivector3<isize> = ConformalMultivector3::unit_e03inf();
+    ///
+    /// let expected = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 1_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    ///
+    /// assert_eq!(unit_e03inf.e03inf, 1);
+    /// assert_eq!(unit_e03inf, expected);
+    /// ```
+    #[inline]
+    pub fn unit_e03inf() -> Self {
+        let mut result = Self::zero();
+        result.data[21] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e123` trivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let unit_e123: ConformalMultivector3<isize> = ConformalMultivector3::unit_e123();
+    ///
+    /// let expected = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 1_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    ///
+    /// assert_eq!(unit_e123.e123, 1);
+    /// assert_eq!(unit_e123, expected);
+    /// ```
+    #[inline]
+    pub fn unit_e123() -> Self {
+        let mut result = Self::zero();
+        result.data[22] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e12inf` trivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let unit_e12inf: ConformalMultivector3<isize> = ConformalMultivector3::unit_e12inf();
+    ///
+    /// let expected = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 1_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    ///
+    /// assert_eq!(unit_e12inf.e12inf, 1);
+    /// assert_eq!(unit_e12inf, expected);
+    /// ```
+    #[inline]
+    pub fn unit_e12inf() -> Self {
+        let mut result = Self::zero();
+        result.data[23] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e23inf` trivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let unit_e23inf: ConformalMultivector3<isize> = ConformalMultivector3::unit_e23inf();
+    ///
+    /// let expected = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     1_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    ///
+    /// assert_eq!(unit_e23inf.e23inf, 1);
+    /// assert_eq!(unit_e23inf, expected);
+    /// ```
+    #[inline]
+    pub fn unit_e23inf() -> Self {
+        let mut result = Self::zero();
+        result.data[24] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e31inf` trivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let unit_e31inf: ConformalMultivector3<isize> = ConformalMultivector3::unit_e31inf();
+    ///
+    /// let expected = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 1_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    ///
+    /// assert_eq!(unit_e31inf.e31inf, 1);
+    /// assert_eq!(unit_e31inf, expected);
+    /// ```
+    #[inline]
+    pub fn unit_e31inf() -> Self {
+        let mut result = Self::zero();
+        result.data[25] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e0123` four-vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let unit_e0123: ConformalMultivector3<isize> = ConformalMultivector3::unit_e0123();
+    ///
+    /// let expected = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 1_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    ///
+    /// assert_eq!(unit_e0123.e0123, 1);
+    /// assert_eq!(unit_e0123, expected);
+    /// ```
+    #[inline]
+    pub fn unit_e0123() -> Self {
+        let mut result = Self::zero();
+        result.data[26] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e012inf` four-vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let unit_e012inf: ConformalMultivector3<isize> = ConformalMultivector3::unit_e012inf();
+    ///
+    /// let expected = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 1_isize, 0_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    ///
+    /// assert_eq!(unit_e012inf.e012inf, 1);
+    /// assert_eq!(unit_e012inf, expected);
+    /// ```
+    #[inline]
+    pub fn unit_e012inf() -> Self {
+        let mut result = Self::zero();
+        result.data[27] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e023inf` four-vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let unit_e023inf: ConformalMultivector3<isize> = ConformalMultivector3::unit_e023inf();
+    ///
+    /// let expected = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 1_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    ///
+    /// assert_eq!(unit_e023inf.e023inf, 1);
+    /// assert_eq!(unit_e023inf, expected);
+    /// ```
+    #[inline]
+    pub fn unit_e023inf() -> Self {
+        let mut result = Self::zero();
+        result.data[28] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e031inf` four-vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let unit_e031inf: ConformalMultivector3<isize> = ConformalMultivector3::unit_e031inf();
+    ///
+    /// let expected = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 1_isize, 0_isize, 0_isize
+    /// );
+    ///
+    /// assert_eq!(unit_e031inf.e031inf, 1);
+    /// assert_eq!(unit_e031inf, expected);
+    /// ```
+    #[inline]
+    pub fn unit_e031inf() -> Self {
+        let mut result = Self::zero();
+        result.data[29] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e123inf` four-vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let unit_e123inf: ConformalMultivector3<isize> = ConformalMultivector3::unit_e123inf();
+    ///
+    /// let expected = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 1_isize, 0_isize
+    /// );
+    ///
+    /// assert_eq!(unit_e123inf.e123inf, 1);
+    /// assert_eq!(unit_e123inf, expected);
+    /// ```
+    #[inline]
+    pub fn unit_e123inf() -> Self {
+        let mut result = Self::zero();
+        result.data[30] = S::one();
+
+        result
+    }
+
+    /// Returns the unit volume element `e0123inf`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let unit_e0123inf: ConformalMultivector3<isize> = ConformalMultivector3::unit_e0123inf();
+    ///
+    /// let expected = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 1_isize
+    /// );
+    ///
+    /// assert_eq!(unit_e0123inf.e0123inf, 1);
+    /// assert_eq!(unit_e0123inf, expected);
+    /// ```
+    #[inline]
+    pub fn unit_e0123inf() -> Self {
+        let mut result = Self::zero();
+        result.data[31] = S::one();
+
+        result
+    }
+
+    /// Returns the unit volume element for three-dimensional conformal geometric algebra.
+    ///
+    /// This is a synonym for `unit_e0123inf`.
+    #[inline(always)]
+    pub fn pseudoscalar() -> Self {
+        Self::unit_e0123inf()
+    }
+
+    /// Project the multivector onto the grade `grade`.
+    ///
+    /// Return a multivector where the components of each grade other than
+    /// input grade are zero. For each grade larger than the dimension of the
+    /// underlying vector space, the grade projection is always zero. In this
+    /// case, any grade projection onto a grade larger than 5 will be zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let mv: ConformalMultivector3<isize> = ConformalMultivector3::new(
+    ///     1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize,
+    ///     1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize,
+    ///     1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize,
+    ///     1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize
+    /// );
+    /// let expected_0 = ConformalMultivector3::new(
+    ///     1_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    /// let mv_0 = mv.grade(0);
+    /// let expected_1 = ConformalMultivector3::new(
+    ///     0_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    /// let mv_1 = mv.grade(1);
+    /// let expected_2 = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 1_isize, 1_isize,
+    ///     1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    /// let mv_2 = mv.grade(2);
+    /// let expected_3 = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize,
+    ///     1_isize, 1_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize
+    /// );
+    /// let mv_3 = mv.grade(3);
+    /// let expected_4 = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 0_isize
+    /// );
+    /// let mv_4 = mv.grade(4);
+    /// let expected_5 = ConformalMultivector3::new(
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize,
+    ///     0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 1_isize
+    /// );
+    /// let mv_5 = mv.grade(5);
+    ///
+    /// assert_eq!(mv_0, expected_0);
+    /// assert_eq!(mv_1, expected_1);
+    /// assert_eq!(mv_2, expected_2);
+    /// assert_eq!(mv_3, expected_3);
+    /// assert_eq!(mv_4, expected_4);
+    /// assert_eq!(mv_5, expected_5);
+    ///
+    /// // Any grade larger than 5 should be zero.
+    /// let zero: ConformalMultivector3<isize> = ConformalMultivector3::zero();
+    /// assert_eq!(mv.grade(6), zero);
+    /// assert_eq!(mv.grade(usize::MAX), zero);
+    /// ```
+    #[inline]
+    pub fn grade(&self, grade: usize) -> Self {
+        let mut result = Self::zero();
+        match grade {
+            0 => {
+                result.data[0] = self.data[0];
+            }
+            1 => {
+                result.data[1] = self.data[1];
+                result.data[2] = self.data[2];
+                result.data[3] = self.data[3];
+                result.data[4] = self.data[4];
+                result.data[5] = self.data[5];
+            }
+            2 => {
+                result.data[6] = self.data[6];
+                result.data[7] = self.data[7];
+                result.data[8] = self.data[8];
+                result.data[9] = self.data[9];
+                result.data[10] = self.data[10];
+                result.data[11] = self.data[11];
+                result.data[12] = self.data[12];
+                result.data[13] = self.data[13];
+                result.data[14] = self.data[14];
+                result.data[15] = self.data[15];
+            }
+            3 => {
+                result.data[16] = self.data[16];
+                result.data[17] = self.data[17];
+                result.data[18] = self.data[18];
+                result.data[19] = self.data[19];
+                result.data[20] = self.data[20];
+                result.data[21] = self.data[21];
+                result.data[22] = self.data[22];
+                result.data[23] = self.data[23];
+                result.data[24] = self.data[24];
+                result.data[25] = self.data[25];
+            }
+            4 => {
+                result.data[26] = self.data[26];
+                result.data[27] = self.data[27];
+                result.data[28] = self.data[28];
+                result.data[29] = self.data[29];
+                result.data[30] = self.data[30];
+            }
+            5 => {
+                result.data[31] = self.data[31];
+            }
+            _ => {}
+        }
+
+        result
+    }
+
+    /// Compute the left contraction of `self` with `other`.
+    ///
+    /// This is a synonym for the `<<` operator.
+    #[inline]
+    pub fn left_contract(&self, other: &Self) -> Self {
+        self << other
+    }
+
+    /// Compute the right contraction of `self` with `other`.
+    ///
+    /// This is a synonym for the `>>` operator.
+    #[inline]
+    pub fn right_contract(&self, other: &Self) -> Self {
+        self >> other
+    }
+
+    /// Compute the scalar product of `self` and `other`.
+    ///
+    /// This is a synonym for the `|` operator.
+    #[inline]
+    pub fn scalar_product(&self, other: &Self) -> Self {
+        self | other
+    }
+
+    /// Compute the outer product of `self` and `other`.
+    ///
+    /// This is a synonym for the `^` operator.
+    #[inline]
+    pub fn outer_product(&self, other: &Self) -> Self {
+        self ^ other
+    }
+
+    /// Compute the regressive product of `self` and `other`.
+    ///
+    /// The regressive product is the outer product of the duals, mapped back
+    /// by the inverse of the duality map
+    /// ```text
+    /// mv1 & mv2 := undual(dual(mv1) ^ dual(mv2))
+    /// ```
+    /// In conformal geometric algebra, the regressive product computes the
+    /// meet of geometric objects. For instance, the meet of two spheres is
+    /// their circle of intersection.
+    ///
+    /// This is a synonym for the `&` operator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let e0123: ConformalMultivector3<i32> = ConformalMultivector3::unit_e0123();
+    /// let e123inf: ConformalMultivector3<i32> = ConformalMultivector3::unit_e123inf();
+    /// let result = e0123.regressive_product(&e123inf);
+    ///
+    /// assert_eq!(result, (e0123.dual() ^ e123inf.dual()).undual());
+    /// assert_eq!(result, -ConformalMultivector3::unit_e123());
+    /// ```
+    #[inline]
+    pub fn regressive_product(&self, other: &Self) -> Self {
+        self & other
+    }
+}
+
+impl<S> ops::Index<usize> for ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = S;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.data[index]
+    }
+}
+
+impl<S> ops::IndexMut<usize> for ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.data[index]
+    }
+}
+
+impl<S> AsRef<[S; 32]> for ConformalMultivector3<S> {
+    #[inline]
+    fn as_ref(&self) -> &[S; 32] {
+        unsafe { &*(self as *const ConformalMultivector3<S> as *const [S; 32]) }
+    }
+}
+
+impl<S> AsMut<[S; 32]> for ConformalMultivector3<S> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [S; 32] {
+        unsafe { &mut *(self as *mut ConformalMultivector3<S> as *mut [S; 32]) }
+    }
+}
+
+impl<S> fmt::Display for ConformalMultivector3<S>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "{} + {}^e0 + {}^e1 + {}^e2 + {}^e3 + {}^einf + {}^e01 + {}^e02 + {}^e03 + {}^e12 + {}^e23 + {}^e31 + {}^e1inf + {}^e2inf + {}^e3inf + {}^e0inf + {}^e012 + {}^e023 + {}^e031 + {}^e01inf + {}^e02inf + {}^e03inf + {}^e123 + {}^e12inf + {}^e23inf + {}^e31inf + {}^e0123 + {}^e012inf + {}^e023inf + {}^e031inf + {}^e123inf + {}^e0123inf",
+            self.data[0],
+            self.data[1],
+            self.data[2],
+            self.data[3],
+            self.data[4],
+            self.data[5],
+            self.data[6],
+            self.data[7],
+            self.data[8],
+            self.data[9],
+            self.data[10],
+            self.data[11],
+            self.data[12],
+            self.data[13],
+            self.data[14],
+            self.data[15],
+            self.data[16],
+            self.data[17],
+            self.data[18],
+            self.data[19],
+            self.data[20],
+            self.data[21],
+            self.data[22],
+            self.data[23],
+            self.data[24],
+            self.data[25],
+            self.data[26],
+            self.data[27],
+            self.data[28],
+            self.data[29],
+            self.data[30],
+            self.data[31],
+        )
+    }
+}
+
+
+impl<S> ConformalMultivector3<S>
+where
+    S: ScalarSigned,
+{
+    /// Compute the reverse of a multivector.
+    ///
+    /// The reverse of a multivector reverses the order of the vectors in each
+    /// basis blade. For each grade of multivector it is given by
+    /// ```text
+    /// When mv has grade 0, rev(mv) := mv
+    /// When mv has grade 1, rev(mv) := mv
+    /// When mv has grade 2, rev(mv) := -mv
+    /// When mv has grade 3, rev(mv) := -mv
+    /// When mv has grade 4, rev(mv) := mv
+    /// When mv has grade 5, rev(mv) := mv
+    /// ```
+    /// The reverse of each basis blade is
+    /// ```text
+    /// rev(1)        = 1
+    /// rev(e0)       = e0
+    /// rev(e1)       = e1
+    /// rev(e2)       = e2
+    /// rev(e3)       = e3
+    /// rev(einf)     = einf
+    /// rev(e01)      = -e01
+    /// rev(e02)      = -e02
+    /// rev(e03)      = -e03
+    /// rev(e12)      = -e12
+    /// rev(e23)      = -e23
+    /// rev(e31)      = -e31
+    /// rev(e1inf)    = -e1inf
+    /// rev(e2inf)    = -e2inf
+    /// rev(e3inf)    = -e3inf
+    /// rev(e0inf)    = -e0inf
+    /// rev(e012)     = -e012
+    /// rev(e023)     = -e023
+    /// rev(e031)     = -e031
+    /// rev(e01inf)   = -e01inf
+    /// rev(e02inf)   = -e02inf
+    /// rev(e03inf)   = -e03inf
+    /// rev(e123)     = -e123
+    /// rev(e12inf)   = -e12inf
+    /// rev(e23inf)   = -e23inf
+    /// rev(e31inf)   = -e31inf
+    /// rev(e0123)    = e0123
+    /// rev(e012inf)  = e012inf
+    /// rev(e023inf)  = e023inf
+    /// rev(e031inf)  = e031inf
+    /// rev(e123inf)  = e123inf
+    /// rev(e0123inf) = e0123inf
+    /// ```
+    /// and the reverse of a general multivector follows by linearity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let mv = ConformalMultivector3::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+    ///     17_i32, 18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32,
+    ///     25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32
+    /// );
+    /// let expected = ConformalMultivector3::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, -7_i32, -8_i32,
+    ///     -9_i32, -10_i32, -11_i32, -12_i32, -13_i32, -14_i32, -15_i32, -16_i32,
+    ///     -17_i32, -18_i32, -19_i32, -20_i32, -21_i32, -22_i32, -23_i32, -24_i32,
+    ///     -25_i32, -26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32
+    /// );
+    /// let result = mv.reverse();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn reverse(&self) -> Self {
+        let mut result = *self;
+        result.reverse_mut();
+
+        result
+    }
+
+    /// Compute the reverse of a multivector mutably in place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let mut result = ConformalMultivector3::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+    ///     17_i32, 18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32,
+    ///     25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32
+    /// );
+    /// let expected = ConformalMultivector3::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, -7_i32, -8_i32,
+    ///     -9_i32, -10_i32, -11_i32, -12_i32, -13_i32, -14_i32, -15_i32, -16_i32,
+    ///     -17_i32, -18_i32, -19_i32, -20_i32, -21_i32, -22_i32, -23_i32, -24_i32,
+    ///     -25_i32, -26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32
+    /// );
+    /// result.reverse_mut();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn reverse_mut(&mut self) {
+        self.data[6] = -self.data[6];
+        self.data[7] = -self.data[7];
+        self.data[8] = -self.data[8];
+        self.data[9] = -self.data[9];
+        self.data[10] = -self.data[10];
+        self.data[11] = -self.data[11];
+        self.data[12] = -self.data[12];
+        self.data[13] = -self.data[13];
+        self.data[14] = -self.data[14];
+        self.data[15] = -self.data[15];
+        self.data[16] = -self.data[16];
+        self.data[17] = -self.data[17];
+        self.data[18] = -self.data[18];
+        self.data[19] = -self.data[19];
+        self.data[20] = -self.data[20];
+        self.data[21] = -self.data[21];
+        self.data[22] = -self.data[22];
+        self.data[23] = -self.data[23];
+        self.data[24] = -self.data[24];
+        self.data[25] = -self.data[25];
+    }
+
+    /// Compute the conjugate of a multivector.
+    ///
+    /// The conjugate of a multivector is the composition of the reverse and the
+    /// grade involution. For each grade of multivector it is given by
+    /// ```text
+    /// When mv has grade 0, conj(mv) := mv
+    /// When mv has grade 1, conj(mv) := -mv
+    /// When mv has grade 2, conj(mv) := -mv
+    /// When mv has grade 3, conj(mv) := mv
+    /// When mv has grade 4, conj(mv) := mv
+    /// When mv has grade 5, conj(mv) := -mv
+    /// ```
+    /// The conjugate of each basis blade is
+    /// ```text
+    /// conj(1)        = 1
+    /// conj(e0)       = -e0
+    /// conj(e1)       = -e1
+    /// conj(e2)       = -e2
+    /// conj(e3)       = -e3
+    /// conj(einf)     = -einf
+    /// conj(e01)      = -e01
+    /// conj(e02)      = -e02
+    /// conj(e03)      = -e03
+    /// conj(e12)      = -e12
+    /// conj(e23)      = -e23
+    /// conj(e31)      = -e31
+    /// conj(e1inf)    = -e1inf
+    /// conj(e2inf)    = -e2inf
+    /// conj(e3inf)    = -e3inf
+    /// conj(e0inf)    = -e0inf
+    /// conj(e012)     = e012
+    /// conj(e023)     = e023
+    /// conj(e031)     = e031
+    /// conj(e01inf)   = e01inf
+    /// conj(e02inf)   = e02inf
+    /// conj(e03inf)   = e03inf
+    /// conj(e123)     = e123
+    /// conj(e12inf)   = e12inf
+    /// conj(e23inf)   = e23inf
+    /// conj(e31inf)   = e31inf
+    /// conj(e0123)    = e0123
+    /// conj(e012inf)  = e012inf
+    /// conj(e023inf)  = e023inf
+    /// conj(e031inf)  = e031inf
+    /// conj(e123inf)  = e123inf
+    /// conj(e0123inf) = -e0123inf
+    /// ```
+    /// and the conjugate of a general multivector follows by linearity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let mv = ConformalMultivector3::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+    ///     17_i32, 18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32,
+    ///     25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32
+    /// );
+    /// let expected = ConformalMultivector3::new(
+    ///     1_i32, -2_i32, -3_i32, -4_i32, -5_i32, -6_i32, -7_i32, -8_i32,
+    ///     -9_i32, -10_i32, -11_i32, -12_i32, -13_i32, -14_i32, -15_i32, -16_i32,
+    ///     17_i32, 18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32,
+    ///     25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, -32_i32
+    /// );
+    /// let result = mv.conjugate();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn conjugate(&self) -> Self {
+        let mut result = *self;
+        result.conjugate_mut();
+
+        result
+    }
+
+    /// Compute the conjugate of a multivector mutably in place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let mut result = ConformalMultivector3::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+    ///     17_i32, 18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32,
+    ///     25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32
+    /// );
+    /// let expected = ConformalMultivector3::new(
+    ///     1_i32, -2_i32, -3_i32, -4_i32, -5_i32, -6_i32, -7_i32, -8_i32,
+    ///     -9_i32, -10_i32, -11_i32, -12_i32, -13_i32, -14_i32, -15_i32, -16_i32,
+    ///     17_i32, 18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32,
+    ///     25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, -32_i32
+    /// );
+    /// result.conjugate_mut();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn conjugate_mut(&mut self) {
+        self.data[1] = -self.data[1];
+        self.data[2] = -self.data[2];
+        self.data[3] = -self.data[3];
+        self.data[4] = -self.data[4];
+        self.data[5] = -self.data[5];
+        self.data[6] = -self.data[6];
+        self.data[7] = -self.data[7];
+        self.data[8] = -self.data[8];
+        self.data[9] = -self.data[9];
+        self.data[10] = -self.data[10];
+        self.data[11] = -self.data[11];
+        self.data[12] = -self.data[12];
+        self.data[13] = -self.data[13];
+        self.data[14] = -self.data[14];
+        self.data[15] = -self.data[15];
+        self.data[31] = -self.data[31];
+    }
+
+    /// Compute the grade involution of a multivector.
+    ///
+    /// The grade involution of a multivector negates the components of odd grade.
+    /// For each grade of multivector it is given by
+    /// ```text
+    /// When mv has grade 0, invol(mv) := mv
+    /// When mv has grade 1, invol(mv) := -mv
+    /// When mv has grade 2, invol(mv) := mv
+    /// When mv has grade 3, invol(mv) := -mv
+    /// When mv has grade 4, invol(mv) := mv
+    /// When mv has grade 5, invol(mv) := -mv
+    /// ```
+    /// The grade involution of each basis blade is
+    /// ```text
+    /// invol(1)        = 1
+    /// invol(e0)       = -e0
+    /// invol(e1)       = -e1
+    /// invol(e2)       = -e2
+    /// invol(e3)       = -e3
+    /// invol(einf)     = -einf
+    /// invol(e01)      = e01
+    /// invol(e02)      = e02
+    /// invol(e03)      = e03
+    /// invol(e12)      = e12
+    /// invol(e23)      = e23
+    /// invol(e31)      = e31
+    /// invol(e1inf)    = e1inf
+    /// invol(e2inf)    = e2inf
+    /// invol(e3inf)    = e3inf
+    /// invol(e0inf)    = e0inf
+    /// invol(e012)     = -e012
+    /// invol(e023)     = -e023
+    /// invol(e031)     = -e031
+    /// invol(e01inf)   = -e01inf
+    /// invol(e02inf)   = -e02inf
+    /// invol(e03inf)   = -e03inf
+    /// invol(e123)     = -e123
+    /// invol(e12inf)   = -e12inf
+    /// invol(e23inf)   = -e23inf
+    /// invol(e31inf)   = -e31inf
+    /// invol(e0123)    = e0123
+    /// invol(e012inf)  = e012inf
+    /// invol(e023inf)  = e023inf
+    /// invol(e031inf)  = e031inf
+    /// invol(e123inf)  = e123inf
+    /// invol(e0123inf) = -e0123inf
+    /// ```
+    /// and the grade involution of a general multivector follows by linearity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let mv = ConformalMultivector3::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+    ///     17_i32, 18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32,
+    ///     25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32
+    /// );
+    /// let expected = ConformalMultivector3::new(
+    ///     1_i32, -2_i32, -3_i32, -4_i32, -5_i32, -6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+    ///     -17_i32, -18_i32, -19_i32, -20_i32, -21_i32, -22_i32, -23_i32, -24_i32,
+    ///     -25_i32, -26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, -32_i32
+    /// );
+    /// let result = mv.involute();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn involute(&self) -> Self {
+        let mut result = *self;
+        result.involute_mut();
+
+        result
+    }
+
+    /// Compute the grade involution of a multivector mutably in place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let mut result = ConformalMultivector3::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+    ///     17_i32, 18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32,
+    ///     25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32
+    /// );
+    /// let expected = ConformalMultivector3::new(
+    ///     1_i32, -2_i32, -3_i32, -4_i32, -5_i32, -6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+    ///     -17_i32, -18_i32, -19_i32, -20_i32, -21_i32, -22_i32, -23_i32, -24_i32,
+    ///     -25_i32, -26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, -32_i32
+    /// );
+    /// result.involute_mut();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn involute_mut(&mut self) {
+        self.data[1] = -self.data[1];
+        self.data[2] = -self.data[2];
+        self.data[3] = -self.data[3];
+        self.data[4] = -self.data[4];
+        self.data[5] = -self.data[5];
+        self.data[16] = -self.data[16];
+        self.data[17] = -self.data[17];
+        self.data[18] = -self.data[18];
+        self.data[19] = -self.data[19];
+        self.data[20] = -self.data[20];
+        self.data[21] = -self.data[21];
+        self.data[22] = -self.data[22];
+        self.data[23] = -self.data[23];
+        self.data[24] = -self.data[24];
+        self.data[25] = -self.data[25];
+        self.data[31] = -self.data[31];
+    }
+
+    /// Compute the dual of a multivector.
+    ///
+    /// The dual of a multivector is also known as the orthogonal complement.
+    /// The dual of a multivector `mv` is defined by
+    /// ```text
+    /// dual(mv) := mv * inv(e0123inf)
+    /// ```
+    /// where `inv(e0123inf) == -e0123inf`. The dual maps a blade representing a
+    /// geometric object by the points it contains, such as a sphere through four
+    /// points, to the blade representing the object by the points orthogonal to it,
+    /// such as the dual sphere `c - (1 / 2) * r^2 * einf`.
+    ///
+    /// The dual of each basis blade is
+    /// ```text
+    /// dual(1)        = -e0123inf
+    /// dual(e0)       = e0123
+    /// dual(e1)       = e023inf
+    /// dual(e2)       = e031inf
+    /// dual(e3)       = e012inf
+    /// dual(einf)     = e123inf
+    /// dual(e01)      = e023
+    /// dual(e02)      = e031
+    /// dual(e03)      = e012
+    /// dual(e12)      = e03inf
+    /// dual(e23)      = e01inf
+    /// dual(e31)      = e02inf
+    /// dual(e1inf)    = e23inf
+    /// dual(e2inf)    = e31inf
+    /// dual(e3inf)    = e12inf
+    /// dual(e0inf)    = e123
+    /// dual(e012)     = -e03
+    /// dual(e023)     = -e01
+    /// dual(e031)     = -e02
+    /// dual(e01inf)   = -e23
+    /// dual(e02inf)   = -e31
+    /// dual(e03inf)   = -e12
+    /// dual(e123)     = -e0inf
+    /// dual(e12inf)   = -e3inf
+    /// dual(e23inf)   = -e1inf
+    /// dual(e31inf)   = -e2inf
+    /// dual(e0123)    = -e0
+    /// dual(e012inf)  = -e3
+    /// dual(e023inf)  = -e1
+    /// dual(e031inf)  = -e2
+    /// dual(e123inf)  = -einf
+    /// dual(e0123inf) = 1
+    /// ```
+    /// and the dual of a general multivector follows by linearity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let mv = ConformalMultivector3::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+    ///     17_i32, 18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32,
+    ///     25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32
+    /// );
+    /// let expected = ConformalMultivector3::new(
+    ///     32_i32, -27_i32, -29_i32, -30_i32, -28_i32, -31_i32, -18_i32, -19_i32,
+    ///     -17_i32, -22_i32, -20_i32, -21_i32, -25_i32, -26_i32, -24_i32, -23_i32,
+    ///     9_i32, 7_i32, 8_i32, 11_i32, 12_i32, 10_i32, 16_i32, 15_i32,
+    ///     13_i32, 14_i32, 2_i32, 5_i32, 3_i32, 4_i32, 6_i32, -1_i32
+    /// );
+    /// let result = mv.dual();
+    ///
+    /// assert_eq!(result, expected);
+    /// assert_eq!(result.undual(), mv);
+    /// ```
+    #[rustfmt::skip]
+    pub fn dual(&self) -> Self {
+        let mut result = Self::zero();
+        result.data[0]  =  self.data[31];
+        result.data[1]  = -self.data[26];
+        result.data[2]  = -self.data[28];
+        result.data[3]  = -self.data[29];
+        result.data[4]  = -self.data[27];
+        result.data[5]  = -self.data[30];
+        result.data[6]  = -self.data[17];
+        result.data[7]  = -self.data[18];
+        result.data[8]  = -self.data[16];
+        result.data[9]  = -self.data[21];
+        result.data[10] = -self.data[19];
+        result.data[11] = -self.data[20];
+        result.data[12] = -self.data[24];
+        result.data[13] = -self.data[25];
+        result.data[14] = -self.data[23];
+        result.data[15] = -self.data[22];
+        result.data[16] =  self.data[8];
+        result.data[17] =  self.data[6];
+        result.data[18] =  self.data[7];
+        result.data[19] =  self.data[10];
+        result.data[20] =  self.data[11];
+        result.data[21] =  self.data[9];
+        result.data[22] =  self.data[15];
+        result.data[23] =  self.data[14];
+        result.data[24] =  self.data[12];
+        result.data[25] =  self.data[13];
+        result.data[26] =  self.data[1];
+        result.data[27] =  self.data[4];
+        result.data[28] =  self.data[2];
+        result.data[29] =  self.data[3];
+        result.data[30] =  self.data[5];
+        result.data[31] = -self.data[0];
+
+        result
+    }
+
+    /// Compute the dual of a multivector mutably in place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let mut result = ConformalMultivector3::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+    ///     17_i32, 18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32,
+    ///     25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32
+    /// );
+    /// let expected = ConformalMultivector3::new(
+    ///     32_i32, -27_i32, -29_i32, -30_i32, -28_i32, -31_i32, -18_i32, -19_i32,
+    ///     -17_i32, -22_i32, -20_i32, -21_i32, -25_i32, -26_i32, -24_i32, -23_i32,
+    ///     9_i32, 7_i32, 8_i32, 11_i32, 12_i32, 10_i32, 16_i32, 15_i32,
+    ///     13_i32, 14_i32, 2_i32, 5_i32, 3_i32, 4_i32, 6_i32, -1_i32
+    /// );
+    /// result.dual_mut();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn dual_mut(&mut self) {
+        *self = self.dual();
+    }
+
+    /// Compute the undual of a multivector.
+    ///
+    /// The undual is the inverse of the dual, i.e. `undual(dual(mv)) == mv`
+    /// and `dual(undual(mv)) == mv` for every multivector `mv`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let mv = ConformalMultivector3::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+    ///     17_i32, 18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32,
+    ///     25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32
+    /// );
+    ///
+    /// assert_eq!(mv.dual().undual(), mv);
+    /// assert_eq!(mv.undual().dual(), mv);
+    /// ```
+    #[rustfmt::skip]
+    pub fn undual(&self) -> Self {
+        let mut result = Self::zero();
+        result.data[0]  = -self.data[31];
+        result.data[1]  =  self.data[26];
+        result.data[2]  =  self.data[28];
+        result.data[3]  =  self.data[29];
+        result.data[4]  =  self.data[27];
+        result.data[5]  =  self.data[30];
+        result.data[6]  =  self.data[17];
+        result.data[7]  =  self.data[18];
+        result.data[8]  =  self.data[16];
+        result.data[9]  =  self.data[21];
+        result.data[10] =  self.data[19];
+        result.data[11] =  self.data[20];
+        result.data[12] =  self.data[24];
+        result.data[13] =  self.data[25];
+        result.data[14] =  self.data[23];
+        result.data[15] =  self.data[22];
+        result.data[16] = -self.data[8];
+        result.data[17] = -self.data[6];
+        result.data[18] = -self.data[7];
+        result.data[19] = -self.data[10];
+        result.data[20] = -self.data[11];
+        result.data[21] = -self.data[9];
+        result.data[22] = -self.data[15];
+        result.data[23] = -self.data[14];
+        result.data[24] = -self.data[12];
+        result.data[25] = -self.data[13];
+        result.data[26] = -self.data[1];
+        result.data[27] = -self.data[4];
+        result.data[28] = -self.data[2];
+        result.data[29] = -self.data[3];
+        result.data[30] = -self.data[5];
+        result.data[31] =  self.data[0];
+
+        result
+    }
+
+    /// Construct the inverse pseudoscalar of the geometric algebra.
+    ///
+    /// In the case of the three-dimensional conformal geometric algebra, the
+    /// inverse of the pseudoscalar is the five-blade `inv(e0123inf) = -e0123inf`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// #
+    /// let ps: ConformalMultivector3<f64> = ConformalMultivector3::pseudoscalar();
+    /// let ps_inv: ConformalMultivector3<f64> = ConformalMultivector3::inv_pseudoscalar();
+    /// let one: ConformalMultivector3<f64> = ConformalMultivector3::unit_scalar();
+    ///
+    /// assert_eq!(ps * ps_inv, one);
+    /// assert_eq!(ps_inv * ps, one);
+    /// ```
+    #[inline]
+    pub fn inv_pseudoscalar() -> Self {
+        -Self::unit_e0123inf()
+    }
+}
+
+impl<S> ops::Not for ConformalMultivector3<S>
+where
+    S: ScalarSigned,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        self.dual()
+    }
+}
+
+impl<S> ops::Not for &ConformalMultivector3<S>
+where
+    S: ScalarSigned,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        self.dual()
+    }
+}
+
+impl<S> ops::Neg for ConformalMultivector3<S>
+where
+    S: ScalarSigned,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn neg(self) -> Self::Output {
+        let result_1        = -self.data[0];
+        let result_e0       = -self.data[1];
+        let result_e1       = -self.data[2];
+        let result_e2       = -self.data[3];
+        let result_e3       = -self.data[4];
+        let result_einf     = -self.data[5];
+        let result_e01      = -self.data[6];
+        let result_e02      = -self.data[7];
+        let result_e03      = -self.data[8];
+        let result_e12      = -self.data[9];
+        let result_e23      = -self.data[10];
+        let result_e31      = -self.data[11];
+        let result_e1inf    = -self.data[12];
+        let result_e2inf    = -self.data[13];
+        let result_e3inf    = -self.data[14];
+        let result_e0inf    = -self.data[15];
+        let result_e012     = -self.data[16];
+        let result_e023     = -self.data[17];
+        let result_e031     = -self.data[18];
+        let result_e01inf   = -self.data[19];
+        let result_e02inf   = -self.data[20];
+        let result_e03inf   = -self.data[21];
+        let result_e123     = -self.data[22];
+        let result_e12inf   = -self.data[23];
+        let result_e23inf   = -self.data[24];
+        let result_e31inf   = -self.data[25];
+        let result_e0123    = -self.data[26];
+        let result_e012inf  = -self.data[27];
+        let result_e023inf  = -self.data[28];
+        let result_e031inf  = -self.data[29];
+        let result_e123inf  = -self.data[30];
+        let result_e0123inf = -self.data[31];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::Neg for &ConformalMultivector3<S>
+where
+    S: ScalarSigned,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn neg(self) -> Self::Output {
+        let result_1        = -self.data[0];
+        let result_e0       = -self.data[1];
+        let result_e1       = -self.data[2];
+        let result_e2       = -self.data[3];
+        let result_e3       = -self.data[4];
+        let result_einf     = -self.data[5];
+        let result_e01      = -self.data[6];
+        let result_e02      = -self.data[7];
+        let result_e03      = -self.data[8];
+        let result_e12      = -self.data[9];
+        let result_e23      = -self.data[10];
+        let result_e31      = -self.data[11];
+        let result_e1inf    = -self.data[12];
+        let result_e2inf    = -self.data[13];
+        let result_e3inf    = -self.data[14];
+        let result_e0inf    = -self.data[15];
+        let result_e012     = -self.data[16];
+        let result_e023     = -self.data[17];
+        let result_e031     = -self.data[18];
+        let result_e01inf   = -self.data[19];
+        let result_e02inf   = -self.data[20];
+        let result_e03inf   = -self.data[21];
+        let result_e123     = -self.data[22];
+        let result_e12inf   = -self.data[23];
+        let result_e23inf   = -self.data[24];
+        let result_e31inf   = -self.data[25];
+        let result_e0123    = -self.data[26];
+        let result_e012inf  = -self.data[27];
+        let result_e023inf  = -self.data[28];
+        let result_e031inf  = -self.data[29];
+        let result_e123inf  = -self.data[30];
+        let result_e0123inf = -self.data[31];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::Mul<ConformalMultivector3<S>> for ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: ConformalMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1        = a[0] * b[0] - a[1] * b[5] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[5] * b[1] - a[6] * b[12] - a[7] * b[13] - a[8] * b[14] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[6] - a[13] * b[7] - a[14] * b[8] + a[15] * b[15] + a[16] * b[23] + a[17] * b[24] + a[18] * b[25] + a[19] * b[19] + a[20] * b[20] + a[21] * b[21] - a[22] * b[22] + a[23] * b[16] + a[24] * b[17] + a[25] * b[18] + a[26] * b[30] - a[27] * b[27] - a[28] * b[28] - a[29] * b[29] + a[30] * b[26] - a[31] * b[31];
+        let result_e0       = a[0] * b[1] + a[1] * b[0] + a[1] * b[15] - a[2] * b[6] - a[3] * b[7] - a[4] * b[8] + a[6] * b[2] - a[6] * b[19] + a[7] * b[3] - a[7] * b[20] + a[8] * b[4] - a[8] * b[21] - a[9] * b[16] - a[10] * b[17] - a[11] * b[18] - a[15] * b[1] - a[16] * b[9] - a[16] * b[27] - a[17] * b[10] - a[17] * b[28] - a[18] * b[11] - a[18] * b[29] - a[19] * b[6] - a[20] * b[7] - a[21] * b[8] + a[22] * b[26] - a[26] * b[22] + a[26] * b[31] + a[27] * b[16] + a[28] * b[17] + a[29] * b[18] + a[31] * b[26];
+        let result_e1       = a[0] * b[2] + a[1] * b[12] + a[2] * b[0] - a[3] * b[9] + a[4] * b[11] - a[5] * b[6] + a[6] * b[5] - a[7] * b[23] + a[8] * b[25] + a[9] * b[3] - a[10] * b[22] - a[11] * b[4] - a[12] * b[1] + a[13] * b[16] - a[14] * b[18] - a[15] * b[19] + a[16] * b[13] - a[17] * b[30] - a[18] * b[14] - a[19] * b[15] + a[20] * b[27] - a[21] * b[29] - a[22] * b[10] - a[23] * b[7] + a[24] * b[26] + a[25] * b[8] - a[26] * b[24] - a[27] * b[20] + a[28] * b[31] + a[29] * b[21] + a[30] * b[17] + a[31] * b[28];
+        let result_e2       = a[0] * b[3] + a[1] * b[13] + a[2] * b[9] + a[3] * b[0] - a[4] * b[10] - a[5] * b[7] + a[6] * b[23] + a[7] * b[5] - a[8] * b[24] - a[9] * b[2] + a[10] * b[4] - a[11] * b[22] - a[12] * b[16] - a[13] * b[1] + a[14] * b[17] - a[15] * b[20] - a[16] * b[12] + a[17] * b[14] - a[18] * b[30] - a[19] * b[27] - a[20] * b[15] + a[21] * b[28] - a[22] * b[11] + a[23] * b[6] - a[24] * b[8] + a[25] * b[26] - a[26] * b[25] + a[27] * b[19] - a[28] * b[21] + a[29] * b[31] + a[30] * b[18] + a[31] * b[29];
+        let result_e3       = a[0] * b[4] + a[1] * b[14] - a[2] * b[11] + a[3] * b[10] + a[4] * b[0] - a[5] * b[8] - a[6] * b[25] + a[7] * b[24] + a[8] * b[5] - a[9] * b[22] - a[10] * b[3] + a[11] * b[2] + a[12] * b[18] - a[13] * b[17] - a[14] * b[1] - a[15] * b[21] - a[16] * b[30] - a[17] * b[13] + a[18] * b[12] + a[19] * b[29] - a[20] * b[28] - a[21] * b[15] - a[22] * b[9] + a[23] * b[26] + a[24] * b[7] - a[25] * b[6] - a[26] * b[23] + a[27] * b[31] + a[28] * b[20] - a[29] * b[19] + a[30] * b[16] + a[31] * b[27];
+        let result_einf     = a[0] * b[5] + a[2] * b[12] + a[3] * b[13] + a[4] * b[14] + a[5] * b[0] - a[5] * b[15] - a[9] * b[23] - a[10] * b[24] - a[11] * b[25] - a[12] * b[2] - a[12] * b[19] - a[13] * b[3] - a[13] * b[20] - a[14] * b[4] - a[14] * b[21] + a[15] * b[5] - a[19] * b[12] - a[20] * b[13] - a[21] * b[14] - a[22] * b[30] - a[23] * b[9] + a[23] * b[27] - a[24] * b[10] + a[24] * b[28] - a[25] * b[11] + a[25] * b[29] - a[27] * b[23] - a[28] * b[24] - a[29] * b[25] + a[30] * b[22] + a[30] * b[31] + a[31] * b[30];
+        let result_e01      = a[0] * b[6] + a[1] * b[2] - a[1] * b[19] - a[2] * b[1] + a[3] * b[16] - a[4] * b[18] + a[6] * b[0] + a[6] * b[15] - a[7] * b[9] - a[7] * b[27] + a[8] * b[11] + a[8] * b[29] + a[9] * b[7] - a[10] * b[26] - a[11] * b[8] - a[15] * b[6] + a[16] * b[3] - a[16] * b[20] - a[17] * b[22] + a[17] * b[31] - a[18] * b[4] + a[18] * b[21] - a[19] * b[1] + a[20] * b[16] - a[21] * b[18] + a[22] * b[17] - a[26] * b[10] - a[26] * b[28] - a[27] * b[7] + a[28] * b[26] + a[29] * b[8] + a[31] * b[17];
+        let result_e02      = a[0] * b[7] + a[1] * b[3] - a[1] * b[20] - a[2] * b[16] - a[3] * b[1] + a[4] * b[17] + a[6] * b[9] + a[6] * b[27] + a[7] * b[0] + a[7] * b[15] - a[8] * b[10] - a[8] * b[28] - a[9] * b[6] + a[10] * b[8] - a[11] * b[26] - a[15] * b[7] - a[16] * b[2] + a[16] * b[19] + a[17] * b[4] - a[17] * b[21] - a[18] * b[22] + a[18] * b[31] - a[19] * b[16] - a[20] * b[1] + a[21] * b[17] + a[22] * b[18] - a[26] * b[11] - a[26] * b[29] + a[27] * b[6] - a[28] * b[8] + a[29] * b[26] + a[31] * b[18];
+        let result_e03      = a[0] * b[8] + a[1] * b[4] - a[1] * b[21] + a[2] * b[18] - a[3] * b[17] - a[4] * b[1] - a[6] * b[11] - a[6] * b[29] + a[7] * b[10] + a[7] * b[28] + a[8] * b[0] + a[8] * b[15] - a[9] * b[26] - a[10] * b[7] + a[11] * b[6] - a[15] * b[8] - a[16] * b[22] + a[16] * b[31] - a[17] * b[3] + a[17] * b[20] + a[18] * b[2] - a[18] * b[19] + a[19] * b[18] - a[20] * b[17] - a[21] * b[1] + a[22] * b[16] - a[26] * b[9] - a[26] * b[27] + a[27] * b[26] + a[28] * b[7] - a[29] * b[6] + a[31] * b[16];
+        let result_e12      = a[0] * b[9] - a[1] * b[23] + a[2] * b[3] - a[3] * b[2] + a[4] * b[22] - a[5] * b[16] - a[6] * b[13] + a[7] * b[12] - a[8] * b[30] + a[9] * b[0] - a[10] * b[11] + a[11] * b[10] - a[12] * b[7] + a[13] * b[6] - a[14] * b[26] + a[15] * b[27] - a[16] * b[5] + a[17] * b[25] - a[18] * b[24] + a[19] * b[20] - a[20] * b[19] + a[21] * b[31] + a[22] * b[4] - a[23] * b[1] + a[24] * b[18] - a[25] * b[17] - a[26] * b[14] + a[27] * b[15] - a[28] * b[29] + a[29] * b[28] - a[30] * b[8] + a[31] * b[21];
+        let result_e23      = a[0] * b[10] - a[1] * b[24] + a[2] * b[22] + a[3] * b[4] - a[4] * b[3] - a[5] * b[17] - a[6] * b[30] - a[7] * b[14] + a[8] * b[13] + a[9] * b[11] + a[10] * b[0] - a[11] * b[9] - a[12] * b[26] - a[13] * b[8] + a[14] * b[7] + a[15] * b[28] - a[16] * b[25] - a[17] * b[5] + a[18] * b[23] + a[19] * b[31] + a[20] * b[21] - a[21] * b[20] + a[22] * b[2] - a[23] * b[18] - a[24] * b[1] + a[25] * b[16] - a[26] * b[12] + a[27] * b[29] + a[28] * b[15] - a[29] * b[27] - a[30] * b[6] + a[31] * b[19];
+        let result_e31      = a[0] * b[11] - a[1] * b[25] - a[2] * b[4] + a[3] * b[22] + a[4] * b[2] - a[5] * b[18] + a[6] * b[14] - a[7] * b[30] - a[8] * b[12] - a[9] * b[10] + a[10] * b[9] + a[11] * b[0] + a[12] * b[8] - a[13] * b[26] - a[14] * b[6] + a[15] * b[29] + a[16] * b[24] - a[17] * b[23] - a[18] * b[5] - a[19] * b[21] + a[20] * b[31] + a[21] * b[19] + a[22] * b[3] + a[23] * b[17] - a[24] * b[16] - a[25] * b[1] - a[26] * b[13] - a[27] * b[28] + a[28] * b[27] + a[29] * b[15] - a[30] * b[7] + a[31] * b[20];
+        let result_e1inf    = a[0] * b[12] + a[2] * b[5] - a[3] * b[23] + a[4] * b[25] - a[5] * b[2] - a[5] * b[19] + a[9] * b[13] - a[10] * b[30] - a[11] * b[14] + a[12] * b[0] - a[12] * b[15] - a[13] * b[9] + a[13] * b[27] + a[14] * b[11] - a[14] * b[29] + a[15] * b[12] - a[19] * b[5] + a[20] * b[23] - a[21] * b[25] - a[22] * b[24] - a[23] * b[3] - a[23] * b[20] + a[24] * b[22] + a[24] * b[31] + a[25] * b[4] + a[25] * b[21] + a[27] * b[13] - a[28] * b[30] - a[29] * b[14] - a[30] * b[10] + a[30] * b[28] + a[31] * b[24];
+        let result_e2inf    = a[0] * b[13] + a[2] * b[23] + a[3] * b[5] - a[4] * b[24] - a[5] * b[3] - a[5] * b[20] - a[9] * b[12] + a[10] * b[14] - a[11] * b[30] + a[12] * b[9] - a[12] * b[27] + a[13] * b[0] - a[13] * b[15] - a[14] * b[10] + a[14] * b[28] + a[15] * b[13] - a[19] * b[23] - a[20] * b[5] + a[21] * b[24] - a[22] * b[25] + a[23] * b[2] + a[23] * b[19] - a[24] * b[4] - a[24] * b[21] + a[25] * b[22] + a[25] * b[31] - a[27] * b[12] + a[28] * b[14] - a[29] * b[30] - a[30] * b[11] + a[30] * b[29] + a[31] * b[25];
+        let result_e3inf    = a[0] * b[14] - a[2] * b[25] + a[3] * b[24] + a[4] * b[5] - a[5] * b[4] - a[5] * b[21] - a[9] * b[30] - a[10] * b[13] + a[11] * b[12] - a[12] * b[11] + a[12] * b[29] + a[13] * b[10] - a[13] * b[28] + a[14] * b[0] - a[14] * b[15] + a[15] * b[14] + a[19] * b[25] - a[20] * b[24] - a[21] * b[5] - a[22] * b[23] + a[23] * b[22] + a[23] * b[31] + a[24] * b[3] + a[24] * b[20] - a[25] * b[2] - a[25] * b[19] - a[27] * b[30] - a[28] * b[13] + a[29] * b[12] - a[30] * b[9] + a[30] * b[27] + a[31] * b[23];
+        let result_e0inf    = a[0] * b[15] + a[1] * b[5] - a[2] * b[19] - a[3] * b[20] - a[4] * b[21] - a[5] * b[1] + a[6] * b[12] + a[7] * b[13] + a[8] * b[14] - a[9] * b[27] - a[10] * b[28] - a[11] * b[29] - a[12] * b[6] - a[13] * b[7] - a[14] * b[8] + a[15] * b[0] - a[16] * b[23] - a[17] * b[24] - a[18] * b[25] - a[19] * b[2] - a[20] * b[3] - a[21] * b[4] + a[22] * b[31] + a[23] * b[16] + a[24] * b[17] + a[25] * b[18] - a[26] * b[30] - a[27] * b[9] - a[28] * b[10] - a[29] * b[11] + a[30] * b[26] + a[31] * b[22];
+        let result_e012     = a[0] * b[16] + a[1] * b[9] + a[1] * b[27] - a[2] * b[7] + a[3] * b[6] - a[4] * b[26] + a[6] * b[3] - a[6] * b[20] - a[7] * b[2] + a[7] * b[19] + a[8] * b[22] - a[8] * b[31] + a[9] * b[1] - a[10] * b[18] + a[11] * b[17] - a[15] * b[16] + a[16] * b[0] + a[16] * b[15] - a[17] * b[11] - a[17] * b[29] + a[18] * b[10] + a[18] * b[28] - a[19] * b[7] + a[20] * b[6] - a[21] * b[26] - a[22] * b[8] + a[26] * b[4] - a[26] * b[21] - a[27] * b[1] + a[28] * b[18] - a[29] * b[17] - a[31] * b[8];
+        let result_e023     = a[0] * b[17] + a[1] * b[10] + a[1] * b[28] - a[2] * b[26] - a[3] * b[8] + a[4] * b[7] + a[6] * b[22] - a[6] * b[31] + a[7] * b[4] - a[7] * b[21] - a[8] * b[3] + a[8] * b[20] + a[9] * b[18] + a[10] * b[1] - a[11] * b[16] - a[15] * b[17] + a[16] * b[11] + a[16] * b[29] + a[17] * b[0] + a[17] * b[15] - a[18] * b[9] - a[18] * b[27] - a[19] * b[26] - a[20] * b[8] + a[21] * b[7] - a[22] * b[6] + a[26] * b[2] - a[26] * b[19] - a[27] * b[18] - a[28] * b[1] + a[29] * b[16] - a[31] * b[6];
+        let result_e031     = a[0] * b[18] + a[1] * b[11] + a[1] * b[29] + a[2] * b[8] - a[3] * b[26] - a[4] * b[6] - a[6] * b[4] + a[6] * b[21] + a[7] * b[22] - a[7] * b[31] + a[8] * b[2] - a[8] * b[19] - a[9] * b[17] + a[10] * b[16] + a[11] * b[1] - a[15] * b[18] - a[16] * b[10] - a[16] * b[28] + a[17] * b[9] + a[17] * b[27] + a[18] * b[0] + a[18] * b[15] + a[19] * b[8] - a[20] * b[26] - a[21] * b[6] - a[22] * b[7] + a[26] * b[3] - a[26] * b[20] + a[27] * b[17] - a[28] * b[16] - a[29] * b[1] - a[31] * b[7];
+        let result_e01inf   = a[0] * b[19] + a[1] * b[12] - a[2] * b[15] + a[3] * b[27] - a[4] * b[29] + a[5] * b[6] + a[6] * b[5] - a[7] * b[23] + a[8] * b[25] + a[9] * b[20] - a[10] * b[31] - a[11] * b[21] + a[12] * b[1] - a[13] * b[16] + a[14] * b[18] - a[15] * b[2] + a[16] * b[13] - a[17] * b[30] - a[18] * b[14] + a[19] * b[0] - a[20] * b[9] + a[21] * b[11] + a[22] * b[28] + a[23] * b[7] - a[24] * b[26] - a[25] * b[8] - a[26] * b[24] - a[27] * b[3] + a[28] * b[22] + a[29] * b[4] - a[30] * b[17] - a[31] * b[10];
+        let result_e02inf   = a[0] * b[20] + a[1] * b[13] - a[2] * b[27] - a[3] * b[15] + a[4] * b[28] + a[5] * b[7] + a[6] * b[23] + a[7] * b[5] - a[8] * b[24] - a[9] * b[19] + a[10] * b[21] - a[11] * b[31] + a[12] * b[16] + a[13] * b[1] - a[14] * b[17] - a[15] * b[3] - a[16] * b[12] + a[17] * b[14] - a[18] * b[30] + a[19] * b[9] + a[20] * b[0] - a[21] * b[10] + a[22] * b[29] - a[23] * b[6] + a[24] * b[8] - a[25] * b[26] - a[26] * b[25] + a[27] * b[2] - a[28] * b[4] + a[29] * b[22] - a[30] * b[18] - a[31] * b[11];
+        let result_e03inf   = a[0] * b[21] + a[1] * b[14] + a[2] * b[29] - a[3] * b[28] - a[4] * b[15] + a[5] * b[8] - a[6] * b[25] + a[7] * b[24] + a[8] * b[5] - a[9] * b[31] - a[10] * b[20] + a[11] * b[19] - a[12] * b[18] + a[13] * b[17] + a[14] * b[1] - a[15] * b[4] - a[16] * b[30] - a[17] * b[13] + a[18] * b[12] - a[19] * b[11] + a[20] * b[10] + a[21] * b[0] + a[22] * b[27] - a[23] * b[26] - a[24] * b[7] + a[25] * b[6] - a[26] * b[23] + a[27] * b[22] + a[28] * b[3] - a[29] * b[2] - a[30] * b[16] - a[31] * b[9];
+        let result_e123     = a[0] * b[22] + a[1] * b[30] + a[2] * b[10] + a[3] * b[11] + a[4] * b[9] - a[5] * b[26] + a[6] * b[24] + a[7] * b[25] + a[8] * b[23] + a[9] * b[4] + a[10] * b[2] + a[11] * b[3] - a[12] * b[17] - a[13] * b[18] - a[14] * b[16] - a[15] * b[31] + a[16] * b[14] + a[17] * b[12] + a[18] * b[13] - a[19] * b[28] - a[20] * b[29] - a[21] * b[27] + a[22] * b[0] - a[23] * b[8] - a[24] * b[6] - a[25] * b[7] + a[26] * b[5] - a[27] * b[21] - a[28] * b[19] - a[29] * b[20] - a[30] * b[1] - a[31] * b[15];
+        let result_e12inf   = a[0] * b[23] + a[2] * b[13] - a[3] * b[12] + a[4] * b[30] + a[5] * b[9] - a[5] * b[27] + a[9] * b[5] - a[10] * b[25] + a[11] * b[24] - a[12] * b[3] - a[12] * b[20] + a[13] * b[2] + a[13] * b[19] - a[14] * b[22] - a[14] * b[31] + a[15] * b[23] - a[19] * b[13] + a[20] * b[12] - a[21] * b[30] + a[22] * b[14] + a[23] * b[0] - a[23] * b[15] - a[24] * b[11] + a[24] * b[29] + a[25] * b[10] - a[25] * b[28] + a[27] * b[5] - a[28] * b[25] + a[29] * b[24] - a[30] * b[4] - a[30] * b[21] - a[31] * b[14];
+        let result_e23inf   = a[0] * b[24] + a[2] * b[30] + a[3] * b[14] - a[4] * b[13] + a[5] * b[10] - a[5] * b[28] + a[9] * b[25] + a[10] * b[5] - a[11] * b[23] - a[12] * b[22] - a[12] * b[31] - a[13] * b[4] - a[13] * b[21] + a[14] * b[3] + a[14] * b[20] + a[15] * b[24] - a[19] * b[30] - a[20] * b[14] + a[21] * b[13] + a[22] * b[12] + a[23] * b[11] - a[23] * b[29] + a[24] * b[0] - a[24] * b[15] - a[25] * b[9] + a[25] * b[27] + a[27] * b[25] + a[28] * b[5] - a[29] * b[23] - a[30] * b[2] - a[30] * b[19] - a[31] * b[12];
+        let result_e31inf   = a[0] * b[25] - a[2] * b[14] + a[3] * b[30] + a[4] * b[12] + a[5] * b[11] - a[5] * b[29] - a[9] * b[24] + a[10] * b[23] + a[11] * b[5] + a[12] * b[4] + a[12] * b[21] - a[13] * b[22] - a[13] * b[31] - a[14] * b[2] - a[14] * b[19] + a[15] * b[25] + a[19] * b[14] - a[20] * b[30] - a[21] * b[12] + a[22] * b[13] - a[23] * b[10] + a[23] * b[28] + a[24] * b[9] - a[24] * b[27] + a[25] * b[0] - a[25] * b[15] - a[27] * b[24] + a[28] * b[23] + a[29] * b[5] - a[30] * b[3] - a[30] * b[20] - a[31] * b[13];
+        let result_e0123    = a[0] * b[26] + a[1] * b[22] - a[1] * b[31] - a[2] * b[17] - a[3] * b[18] - a[4] * b[16] + a[6] * b[10] + a[6] * b[28] + a[7] * b[11] + a[7] * b[29] + a[8] * b[9] + a[8] * b[27] + a[9] * b[8] + a[10] * b[6] + a[11] * b[7] - a[15] * b[26] + a[16] * b[4] - a[16] * b[21] + a[17] * b[2] - a[17] * b[19] + a[18] * b[3] - a[18] * b[20] - a[19] * b[17] - a[20] * b[18] - a[21] * b[16] - a[22] * b[1] + a[26] * b[0] + a[26] * b[15] - a[27] * b[8] - a[28] * b[6] - a[29] * b[7] - a[31] * b[1];
+        let result_e012inf  = a[0] * b[27] + a[1] * b[23] - a[2] * b[20] + a[3] * b[19] - a[4] * b[31] - a[5] * b[16] + a[6] * b[13] - a[7] * b[12] + a[8] * b[30] + a[9] * b[15] - a[10] * b[29] + a[11] * b[28] - a[12] * b[7] + a[13] * b[6] - a[14] * b[26] + a[15] * b[9] + a[16] * b[5] - a[17] * b[25] + a[18] * b[24] - a[19] * b[3] + a[20] * b[2] - a[21] * b[22] - a[22] * b[21] - a[23] * b[1] + a[24] * b[18] - a[25] * b[17] + a[26] * b[14] + a[27] * b[0] - a[28] * b[11] + a[29] * b[10] - a[30] * b[8] - a[31] * b[4];
+        let result_e023inf  = a[0] * b[28] + a[1] * b[24] - a[2] * b[31] - a[3] * b[21] + a[4] * b[20] - a[5] * b[17] + a[6] * b[30] + a[7] * b[14] - a[8] * b[13] + a[9] * b[29] + a[10] * b[15] - a[11] * b[27] - a[12] * b[26] - a[13] * b[8] + a[14] * b[7] + a[15] * b[10] + a[16] * b[25] + a[17] * b[5] - a[18] * b[23] - a[19] * b[22] - a[20] * b[4] + a[21] * b[3] - a[22] * b[19] - a[23] * b[18] - a[24] * b[1] + a[25] * b[16] + a[26] * b[12] + a[27] * b[11] + a[28] * b[0] - a[29] * b[9] - a[30] * b[6] - a[31] * b[2];
+        let result_e031inf  = a[0] * b[29] + a[1] * b[25] + a[2] * b[21] - a[3] * b[31] - a[4] * b[19] - a[5] * b[18] - a[6] * b[14] + a[7] * b[30] + a[8] * b[12] - a[9] * b[28] + a[10] * b[27] + a[11] * b[15] + a[12] * b[8] - a[13] * b[26] - a[14] * b[6] + a[15] * b[11] - a[16] * b[24] + a[17] * b[23] + a[18] * b[5] + a[19] * b[4] - a[20] * b[22] - a[21] * b[2] - a[22] * b[20] + a[23] * b[17] - a[24] * b[16] - a[25] * b[1] + a[26] * b[13] - a[27] * b[10] + a[28] * b[9] + a[29] * b[0] - a[30] * b[7] - a[31] * b[3];
+        let result_e123inf  = a[0] * b[30] + a[2] * b[24] + a[3] * b[25] + a[4] * b[23] - a[5] * b[22] - a[5] * b[31] + a[9] * b[14] + a[10] * b[12] + a[11] * b[13] + a[12] * b[10] - a[12] * b[28] + a[13] * b[11] - a[13] * b[29] + a[14] * b[9] - a[14] * b[27] + a[15] * b[30] - a[19] * b[24] - a[20] * b[25] - a[21] * b[23] + a[22] * b[5] - a[23] * b[4] - a[23] * b[21] - a[24] * b[2] - a[24] * b[19] - a[25] * b[3] - a[25] * b[20] + a[27] * b[14] + a[28] * b[12] + a[29] * b[13] + a[30] * b[0] - a[30] * b[15] - a[31] * b[5];
+        let result_e0123inf = a[0] * b[31] + a[1] * b[30] - a[2] * b[28] - a[3] * b[29] - a[4] * b[27] + a[5] * b[26] + a[6] * b[24] + a[7] * b[25] + a[8] * b[23] + a[9] * b[21] + a[10] * b[19] + a[11] * b[20] + a[12] * b[17] + a[13] * b[18] + a[14] * b[16] - a[15] * b[22] + a[16] * b[14] + a[17] * b[12] + a[18] * b[13] + a[19] * b[10] + a[20] * b[11] + a[21] * b[9] - a[22] * b[15] + a[23] * b[8] + a[24] * b[6] + a[25] * b[7] + a[26] * b[5] - a[27] * b[4] - a[28] * b[2] - a[29] * b[3] + a[30] * b[1] + a[31] * b[0];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::Mul<&ConformalMultivector3<S>> for ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: &ConformalMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1        = a[0] * b[0] - a[1] * b[5] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[5] * b[1] - a[6] * b[12] - a[7] * b[13] - a[8] * b[14] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[6] - a[13] * b[7] - a[14] * b[8] + a[15] * b[15] + a[16] * b[23] + a[17] * b[24] + a[18] * b[25] + a[19] * b[19] + a[20] * b[20] + a[21] * b[21] - a[22] * b[22] + a[23] * b[16] + a[24] * b[17] + a[25] * b[18] + a[26] * b[30] - a[27] * b[27] - a[28] * b[28] - a[29] * b[29] + a[30] * b[26] - a[31] * b[31];
+        let result_e0       = a[0] * b[1] + a[1] * b[0] + a[1] * b[15] - a[2] * b[6] - a[3] * b[7] - a[4] * b[8] + a[6] * b[2] - a[6] * b[19] + a[7] * b[3] - a[7] * b[20] + a[8] * b[4] - a[8] * b[21] - a[9] * b[16] - a[10] * b[17] - a[11] * b[18] - a[15] * b[1] - a[16] * b[9] - a[16] * b[27] - a[17] * b[10] - a[17] * b[28] - a[18] * b[11] - a[18] * b[29] - a[19] * b[6] - a[20] * b[7] - a[21] * b[8] + a[22] * b[26] - a[26] * b[22] + a[26] * b[31] + a[27] * b[16] + a[28] * b[17] + a[29] * b[18] + a[31] * b[26];
+        let result_e1       = a[0] * b[2] + a[1] * b[12] + a[2] * b[0] - a[3] * b[9] + a[4] * b[11] - a[5] * b[6] + a[6] * b[5] - a[7] * b[23] + a[8] * b[25] + a[9] * b[3] - a[10] * b[22] - a[11] * b[4] - a[12] * b[1] + a[13] * b[16] - a[14] * b[18] - a[15] * b[19] + a[16] * b[13] - a[17] * b[30] - a[18] * b[14] - a[19] * b[15] + a[20] * b[27] - a[21] * b[29] - a[22] * b[10] - a[23] * b[7] + a[24] * b[26] + a[25] * b[8] - a[26] * b[24] - a[27] * b[20] + a[28] * b[31] + a[29] * b[21] + a[30] * b[17] + a[31] * b[28];
+        let result_e2       = a[0] * b[3] + a[1] * b[13] + a[2] * b[9] + a[3] * b[0] - a[4] * b[10] - a[5] * b[7] + a[6] * b[23] + a[7] * b[5] - a[8] * b[24] - a[9] * b[2] + a[10] * b[4] - a[11] * b[22] - a[12] * b[16] - a[13] * b[1] + a[14] * b[17] - a[15] * b[20] - a[16] * b[12] + a[17] * b[14] - a[18] * b[30] - a[19] * b[27] - a[20] * b[15] + a[21] * b[28] - a[22] * b[11] + a[23] * b[6] - a[24] * b[8] + a[25] * b[26] - a[26] * b[25] + a[27] * b[19] - a[28] * b[21] + a[29] * b[31] + a[30] * b[18] + a[31] * b[29];
+        let result_e3       = a[0] * b[4] + a[1] * b[14] - a[2] * b[11] + a[3] * b[10] + a[4] * b[0] - a[5] * b[8] - a[6] * b[25] + a[7] * b[24] + a[8] * b[5] - a[9] * b[22] - a[10] * b[3] + a[11] * b[2] + a[12] * b[18] - a[13] * b[17] - a[14] * b[1] - a[15] * b[21] - a[16] * b[30] - a[17] * b[13] + a[18] * b[12] + a[19] * b[29] - a[20] * b[28] - a[21] * b[15] - a[22] * b[9] + a[23] * b[26] + a[24] * b[7] - a[25] * b[6] - a[26] * b[23] + a[27] * b[31] + a[28] * b[20] - a[29] * b[19] + a[30] * b[16] + a[31] * b[27];
+        let result_einf     = a[0] * b[5] + a[2] * b[12] + a[3] * b[13] + a[4] * b[14] + a[5] * b[0] - a[5] * b[15] - a[9] * b[23] - a[10] * b[24] - a[11] * b[25] - a[12] * b[2] - a[12] * b[19] - a[13] * b[3] - a[13] * b[20] - a[14] * b[4] - a[14] * b[21] + a[15] * b[5] - a[19] * b[12] - a[20] * b[13] - a[21] * b[14] - a[22] * b[30] - a[23] * b[9] + a[23] * b[27] - a[24] * b[10] + a[24] * b[28] - a[25] * b[11] + a[25] * b[29] - a[27] * b[23] - a[28] * b[24] - a[29] * b[25] + a[30] * b[22] + a[30] * b[31] + a[31] * b[30];
+        let result_e01      = a[0] * b[6] + a[1] * b[2] - a[1] * b[19] - a[2] * b[1] + a[3] * b[16] - a[4] * b[18] + a[6] * b[0] + a[6] * b[15] - a[7] * b[9] - a[7] * b[27] + a[8] * b[11] + a[8] * b[29] + a[9] * b[7] - a[10] * b[26] - a[11] * b[8] - a[15] * b[6] + a[16] * b[3] - a[16] * b[20] - a[17] * b[22] + a[17] * b[31] - a[18] * b[4] + a[18] * b[21] - a[19] * b[1] + a[20] * b[16] - a[21] * b[18] + a[22] * b[17] - a[26] * b[10] - a[26] * b[28] - a[27] * b[7] + a[28] * b[26] + a[29] * b[8] + a[31] * b[17];
+        let result_e02      = a[0] * b[7] + a[1] * b[3] - a[1] * b[20] - a[2] * b[16] - a[3] * b[1] + a[4] * b[17] + a[6] * b[9] + a[6] * b[27] + a[7] * b[0] + a[7] * b[15] - a[8] * b[10] - a[8] * b[28] - a[9] * b[6] + a[10] * b[8] - a[11] * b[26] - a[15] * b[7] - a[16] * b[2] + a[16] * b[19] + a[17] * b[4] - a[17] * b[21] - a[18] * b[22] + a[18] * b[31] - a[19] * b[16] - a[20] * b[1] + a[21] * b[17] + a[22] * b[18] - a[26] * b[11] - a[26] * b[29] + a[27] * b[6] - a[28] * b[8] + a[29] * b[26] + a[31] * b[18];
+        let result_e03      = a[0] * b[8] + a[1] * b[4] - a[1] * b[21] + a[2] * b[18] - a[3] * b[17] - a[4] * b[1] - a[6] * b[11] - a[6] * b[29] + a[7] * b[10] + a[7] * b[28] + a[8] * b[0] + a[8] * b[15] - a[9] * b[26] - a[10] * b[7] + a[11] * b[6] - a[15] * b[8] - a[16] * b[22] + a[16] * b[31] - a[17] * b[3] + a[17] * b[20] + a[18] * b[2] - a[18] * b[19] + a[19] * b[18] - a[20] * b[17] - a[21] * b[1] + a[22] * b[16] - a[26] * b[9] - a[26] * b[27] + a[27] * b[26] + a[28] * b[7] - a[29] * b[6] + a[31] * b[16];
+        let result_e12      = a[0] * b[9] - a[1] * b[23] + a[2] * b[3] - a[3] * b[2] + a[4] * b[22] - a[5] * b[16] - a[6] * b[13] + a[7] * b[12] - a[8] * b[30] + a[9] * b[0] - a[10] * b[11] + a[11] * b[10] - a[12] * b[7] + a[13] * b[6] - a[14] * b[26] + a[15] * b[27] - a[16] * b[5] + a[17] * b[25] - a[18] * b[24] + a[19] * b[20] - a[20] * b[19] + a[21] * b[31] + a[22] * b[4] - a[23] * b[1] + a[24] * b[18] - a[25] * b[17] - a[26] * b[14] + a[27] * b[15] - a[28] * b[29] + a[29] * b[28] - a[30] * b[8] + a[31] * b[21];
+        let result_e23      = a[0] * b[10] - a[1] * b[24] + a[2] * b[22] + a[3] * b[4] - a[4] * b[3] - a[5] * b[17] - a[6] * b[30] - a[7] * b[14] + a[8] * b[13] + a[9] * b[11] + a[10] * b[0] - a[11] * b[9] - a[12] * b[26] - a[13] * b[8] + a[14] * b[7] + a[15] * b[28] - a[16] * b[25] - a[17] * b[5] + a[18] * b[23] + a[19] * b[31] + a[20] * b[21] - a[21] * b[20] + a[22] * b[2] - a[23] * b[18] - a[24] * b[1] + a[25] * b[16] - a[26] * b[12] + a[27] * b[29] + a[28] * b[15] - a[29] * b[27] - a[30] * b[6] + a[31] * b[19];
+        let result_e31      = a[0] * b[11] - a[1] * b[25] - a[2] * b[4] + a[3] * b[22] + a[4] * b[2] - a[5] * b[18] + a[6] * b[14] - a[7] * b[30] - a[8] * b[12] - a[9] * b[10] + a[10] * b[9] + a[11] * b[0] + a[12] * b[8] - a[13] * b[26] - a[14] * b[6] + a[15] * b[29] + a[16] * b[24] - a[17] * b[23] - a[18] * b[5] - a[19] * b[21] + a[20] * b[31] + a[21] * b[19] + a[22] * b[3] + a[23] * b[17] - a[24] * b[16] - a[25] * b[1] - a[26] * b[13] - a[27] * b[28] + a[28] * b[27] + a[29] * b[15] - a[30] * b[7] + a[31] * b[20];
+        let result_e1inf    = a[0] * b[12] + a[2] * b[5] - a[3] * b[23] + a[4] * b[25] - a[5] * b[2] - a[5] * b[19] + a[9] * b[13] - a[10] * b[30] - a[11] * b[14] + a[12] * b[0] - a[12] * b[15] - a[13] * b[9] + a[13] * b[27] + a[14] * b[11] - a[14] * b[29] + a[15] * b[12] - a[19] * b[5] + a[20] * b[23] - a[21] * b[25] - a[22] * b[24] - a[23] * b[3] - a[23] * b[20] + a[24] * b[22] + a[24] * b[31] + a[25] * b[4] + a[25] * b[21] + a[27] * b[13] - a[28] * b[30] - a[29] * b[14] - a[30] * b[10] + a[30] * b[28] + a[31] * b[24];
+        let result_e2inf    = a[0] * b[13] + a[2] * b[23] + a[3] * b[5] - a[4] * b[24] - a[5] * b[3] - a[5] * b[20] - a[9] * b[12] + a[10] * b[14] - a[11] * b[30] + a[12] * b[9] - a[12] * b[27] + a[13] * b[0] - a[13] * b[15] - a[14] * b[10] + a[14] * b[28] + a[15] * b[13] - a[19] * b[23] - a[20] * b[5] + a[21] * b[24] - a[22] * b[25] + a[23] * b[2] + a[23] * b[19] - a[24] * b[4] - a[24] * b[21] + a[25] * b[22] + a[25] * b[31] - a[27] * b[12] + a[28] * b[14] - a[29] * b[30] - a[30] * b[11] + a[30] * b[29] + a[31] * b[25];
+        let result_e3inf    = a[0] * b[14] - a[2] * b[25] + a[3] * b[24] + a[4] * b[5] - a[5] * b[4] - a[5] * b[21] - a[9] * b[30] - a[10] * b[13] + a[11] * b[12] - a[12] * b[11] + a[12] * b[29] + a[13] * b[10] - a[13] * b[28] + a[14] * b[0] - a[14] * b[15] + a[15] * b[14] + a[19] * b[25] - a[20] * b[24] - a[21] * b[5] - a[22] * b[23] + a[23] * b[22] + a[23] * b[31] + a[24] * b[3] + a[24] * b[20] - a[25] * b[2] - a[25] * b[19] - a[27] * b[30] - a[28] * b[13] + a[29] * b[12] - a[30] * b[9] + a[30] * b[27] + a[31] * b[23];
+        let result_e0inf    = a[0] * b[15] + a[1] * b[5] - a[2] * b[19] - a[3] * b[20] - a[4] * b[21] - a[5] * b[1] + a[6] * b[12] + a[7] * b[13] + a[8] * b[14] - a[9] * b[27] - a[10] * b[28] - a[11] * b[29] - a[12] * b[6] - a[13] * b[7] - a[14] * b[8] + a[15] * b[0] - a[16] * b[23] - a[17] * b[24] - a[18] * b[25] - a[19] * b[2] - a[20] * b[3] - a[21] * b[4] + a[22] * b[31] + a[23] * b[16] + a[24] * b[17] + a[25] * b[18] - a[26] * b[30] - a[27] * b[9] - a[28] * b[10] - a[29] * b[11] + a[30] * b[26] + a[31] * b[22];
+        let result_e012     = a[0] * b[16] + a[1] * b[9] + a[1] * b[27] - a[2] * b[7] + a[3] * b[6] - a[4] * b[26] + a[6] * b[3] - a[6] * b[20] - a[7] * b[2] + a[7] * b[19] + a[8] * b[22] - a[8] * b[31] + a[9] * b[1] - a[10] * b[18] + a[11] * b[17] - a[15] * b[16] + a[16] * b[0] + a[16] * b[15] - a[17] * b[11] - a[17] * b[29] + a[18] * b[10] + a[18] * b[28] - a[19] * b[7] + a[20] * b[6] - a[21] * b[26] - a[22] * b[8] + a[26] * b[4] - a[26] * b[21] - a[27] * b[1] + a[28] * b[18] - a[29] * b[17] - a[31] * b[8];
+        let result_e023     = a[0] * b[17] + a[1] * b[10] + a[1] * b[28] - a[2] * b[26] - a[3] * b[8] + a[4] * b[7] + a[6] * b[22] - a[6] * b[31] + a[7] * b[4] - a[7] * b[21] - a[8] * b[3] + a[8] * b[20] + a[9] * b[18] + a[10] * b[1] - a[11] * b[16] - a[15] * b[17] + a[16] * b[11] + a[16] * b[29] + a[17] * b[0] + a[17] * b[15] - a[18] * b[9] - a[18] * b[27] - a[19] * b[26] - a[20] * b[8] + a[21] * b[7] - a[22] * b[6] + a[26] * b[2] - a[26] * b[19] - a[27] * b[18] - a[28] * b[1] + a[29] * b[16] - a[31] * b[6];
+        let result_e031     = a[0] * b[18] + a[1] * b[11] + a[1] * b[29] + a[2] * b[8] - a[3] * b[26] - a[4] * b[6] - a[6] * b[4] + a[6] * b[21] + a[7] * b[22] - a[7] * b[31] + a[8] * b[2] - a[8] * b[19] - a[9] * b[17] + a[10] * b[16] + a[11] * b[1] - a[15] * b[18] - a[16] * b[10] - a[16] * b[28] + a[17] * b[9] + a[17] * b[27] + a[18] * b[0] + a[18] * b[15] + a[19] * b[8] - a[20] * b[26] - a[21] * b[6] - a[22] * b[7] + a[26] * b[3] - a[26] * b[20] + a[27] * b[17] - a[28] * b[16] - a[29] * b[1] - a[31] * b[7];
+        let result_e01inf   = a[0] * b[19] + a[1] * b[12] - a[2] * b[15] + a[3] * b[27] - a[4] * b[29] + a[5] * b[6] + a[6] * b[5] - a[7] * b[23] + a[8] * b[25] + a[9] * b[20] - a[10] * b[31] - a[11] * b[21] + a[12] * b[1] - a[13] * b[16] + a[14] * b[18] - a[15] * b[2] + a[16] * b[13] - a[17] * b[30] - a[18] * b[14] + a[19] * b[0] - a[20] * b[9] + a[21] * b[11] + a[22] * b[28] + a[23] * b[7] - a[24] * b[26] - a[25] * b[8] - a[26] * b[24] - a[27] * b[3] + a[28] * b[22] + a[29] * b[4] - a[30] * b[17] - a[31] * b[10];
+        let result_e02inf   = a[0] * b[20] + a[1] * b[13] - a[2] * b[27] - a[3] * b[15] + a[4] * b[28] + a[5] * b[7] + a[6] * b[23] + a[7] * b[5] - a[8] * b[24] - a[9] * b[19] + a[10] * b[21] - a[11] * b[31] + a[12] * b[16] + a[13] * b[1] - a[14] * b[17] - a[15] * b[3] - a[16] * b[12] + a[17] * b[14] - a[18] * b[30] + a[19] * b[9] + a[20] * b[0] - a[21] * b[10] + a[22] * b[29] - a[23] * b[6] + a[24] * b[8] - a[25] * b[26] - a[26] * b[25] + a[27] * b[2] - a[28] * b[4] + a[29] * b[22] - a[30] * b[18] - a[31] * b[11];
+        let result_e03inf   = a[0] * b[21] + a[1] * b[14] + a[2] * b[29] - a[3] * b[28] - a[4] * b[15] + a[5] * b[8] - a[6] * b[25] + a[7] * b[24] + a[8] * b[5] - a[9] * b[31] - a[10] * b[20] + a[11] * b[19] - a[12] * b[18] + a[13] * b[17] + a[14] * b[1] - a[15] * b[4] - a[16] * b[30] - a[17] * b[13] + a[18] * b[12] - a[19] * b[11] + a[20] * b[10] + a[21] * b[0] + a[22] * b[27] - a[23] * b[26] - a[24] * b[7] + a[25] * b[6] - a[26] * b[23] + a[27] * b[22] + a[28] * b[3] - a[29] * b[2] - a[30] * b[16] - a[31] * b[9];
+        let result_e123     = a[0] * b[22] + a[1] * b[30] + a[2] * b[10] + a[3] * b[11] + a[4] * b[9] - a[5] * b[26] + a[6] * b[24] + a[7] * b[25] + a[8] * b[23] + a[9] * b[4] + a[10] * b[2] + a[11] * b[3] - a[12] * b[17] - a[13] * b[18] - a[14] * b[16] - a[15] * b[31] + a[16] * b[14] + a[17] * b[12] + a[18] * b[13] - a[19] * b[28] - a[20] * b[29] - a[21] * b[27] + a[22] * b[0] - a[23] * b[8] - a[24] * b[6] - a[25] * b[7] + a[26] * b[5] - a[27] * b[21] - a[28] * b[19] - a[29] * b[20] - a[30] * b[1] - a[31] * b[15];
+        let result_e12inf   = a[0] * b[23] + a[2] * b[13] - a[3] * b[12] + a[4] * b[30] + a[5] * b[9] - a[5] * b[27] + a[9] * b[5] - a[10] * b[25] + a[11] * b[24] - a[12] * b[3] - a[12] * b[20] + a[13] * b[2] + a[13] * b[19] - a[14] * b[22] - a[14] * b[31] + a[15] * b[23] - a[19] * b[13] + a[20] * b[12] - a[21] * b[30] + a[22] * b[14] + a[23] * b[0] - a[23] * b[15] - a[24] * b[11] + a[24] * b[29] + a[25] * b[10] - a[25] * b[28] + a[27] * b[5] - a[28] * b[25] + a[29] * b[24] - a[30] * b[4] - a[30] * b[21] - a[31] * b[14];
+        let result_e23inf   = a[0] * b[24] + a[2] * b[30] + a[3] * b[14] - a[4] * b[13] + a[5] * b[10] - a[5] * b[28] + a[9] * b[25] + a[10] * b[5] - a[11] * b[23] - a[12] * b[22] - a[12] * b[31] - a[13] * b[4] - a[13] * b[21] + a[14] * b[3] + a[14] * b[20] + a[15] * b[24] - a[19] * b[30] - a[20] * b[14] + a[21] * b[13] + a[22] * b[12] + a[23] * b[11] - a[23] * b[29] + a[24] * b[0] - a[24] * b[15] - a[25] * b[9] + a[25] * b[27] + a[27] * b[25] + a[28] * b[5] - a[29] * b[23] - a[30] * b[2] - a[30] * b[19] - a[31] * b[12];
+        let result_e31inf   = a[0] * b[25] - a[2] * b[14] + a[3] * b[30] + a[4] * b[12] + a[5] * b[11] - a[5] * b[29] - a[9] * b[24] + a[10] * b[23] + a[11] * b[5] + a[12] * b[4] + a[12] * b[21] - a[13] * b[22] - a[13] * b[31] - a[14] * b[2] - a[14] * b[19] + a[15] * b[25] + a[19] * b[14] - a[20] * b[30] - a[21] * b[12] + a[22] * b[13] - a[23] * b[10] + a[23] * b[28] + a[24] * b[9] - a[24] * b[27] + a[25] * b[0] - a[25] * b[15] - a[27] * b[24] + a[28] * b[23] + a[29] * b[5] - a[30] * b[3] - a[30] * b[20] - a[31] * b[13];
+        let result_e0123    = a[0] * b[26] + a[1] * b[22] - a[1] * b[31] - a[2] * b[17] - a[3] * b[18] - a[4] * b[16] + a[6] * b[10] + a[6] * b[28] + a[7] * b[11] + a[7] * b[29] + a[8] * b[9] + a[8] * b[27] + a[9] * b[8] + a[10] * b[6] + a[11] * b[7] - a[15] * b[26] + a[16] * b[4] - a[16] * b[21] + a[17] * b[2] - a[17] * b[19] + a[18] * b[3] - a[18] * b[20] - a[19] * b[17] - a[20] * b[18] - a[21] * b[16] - a[22] * b[1] + a[26] * b[0] + a[26] * b[15] - a[27] * b[8] - a[28] * b[6] - a[29] * b[7] - a[31] * b[1];
+        let result_e012inf  = a[0] * b[27] + a[1] * b[23] - a[2] * b[20] + a[3] * b[19] - a[4] * b[31] - a[5] * b[16] + a[6] * b[13] - a[7] * b[12] + a[8] * b[30] + a[9] * b[15] - a[10] * b[29] + a[11] * b[28] - a[12] * b[7] + a[13] * b[6] - a[14] * b[26] + a[15] * b[9] + a[16] * b[5] - a[17] * b[25] + a[18] * b[24] - a[19] * b[3] + a[20] * b[2] - a[21] * b[22] - a[22] * b[21] - a[23] * b[1] + a[24] * b[18] - a[25] * b[17] + a[26] * b[14] + a[27] * b[0] - a[28] * b[11] + a[29] * b[10] - a[30] * b[8] - a[31] * b[4];
+        let result_e023inf  = a[0] * b[28] + a[1] * b[24] - a[2] * b[31] - a[3] * b[21] + a[4] * b[20] - a[5] * b[17] + a[6] * b[30] + a[7] * b[14] - a[8] * b[13] + a[9] * b[29] + a[10] * b[15] - a[11] * b[27] - a[12] * b[26] - a[13] * b[8] + a[14] * b[7] + a[15] * b[10] + a[16] * b[25] + a[17] * b[5] - a[18] * b[23] - a[19] * b[22] - a[20] * b[4] + a[21] * b[3] - a[22] * b[19] - a[23] * b[18] - a[24] * b[1] + a[25] * b[16] + a[26] * b[12] + a[27] * b[11] + a[28] * b[0] - a[29] * b[9] - a[30] * b[6] - a[31] * b[2];
+        let result_e031inf  = a[0] * b[29] + a[1] * b[25] + a[2] * b[21] - a[3] * b[31] - a[4] * b[19] - a[5] * b[18] - a[6] * b[14] + a[7] * b[30] + a[8] * b[12] - a[9] * b[28] + a[10] * b[27] + a[11] * b[15] + a[12] * b[8] - a[13] * b[26] - a[14] * b[6] + a[15] * b[11] - a[16] * b[24] + a[17] * b[23] + a[18] * b[5] + a[19] * b[4] - a[20] * b[22] - a[21] * b[2] - a[22] * b[20] + a[23] * b[17] - a[24] * b[16] - a[25] * b[1] + a[26] * b[13] - a[27] * b[10] + a[28] * b[9] + a[29] * b[0] - a[30] * b[7] - a[31] * b[3];
+        let result_e123inf  = a[0] * b[30] + a[2] * b[24] + a[3] * b[25] + a[4] * b[23] - a[5] * b[22] - a[5] * b[31] + a[9] * b[14] + a[10] * b[12] + a[11] * b[13] + a[12] * b[10] - a[12] * b[28] + a[13] * b[11] - a[13] * b[29] + a[14] * b[9] - a[14] * b[27] + a[15] * b[30] - a[19] * b[24] - a[20] * b[25] - a[21] * b[23] + a[22] * b[5] - a[23] * b[4] - a[23] * b[21] - a[24] * b[2] - a[24] * b[19] - a[25] * b[3] - a[25] * b[20] + a[27] * b[14] + a[28] * b[12] + a[29] * b[13] + a[30] * b[0] - a[30] * b[15] - a[31] * b[5];
+        let result_e0123inf = a[0] * b[31] + a[1] * b[30] - a[2] * b[28] - a[3] * b[29] - a[4] * b[27] + a[5] * b[26] + a[6] * b[24] + a[7] * b[25] + a[8] * b[23] + a[9] * b[21] + a[10] * b[19] + a[11] * b[20] + a[12] * b[17] + a[13] * b[18] + a[14] * b[16] - a[15] * b[22] + a[16] * b[14] + a[17] * b[12] + a[18] * b[13] + a[19] * b[10] + a[20] * b[11] + a[21] * b[9] - a[22] * b[15] + a[23] * b[8] + a[24] * b[6] + a[25] * b[7] + a[26] * b[5] - a[27] * b[4] - a[28] * b[2] - a[29] * b[3] + a[30] * b[1] + a[31] * b[0];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::Mul<ConformalMultivector3<S>> for &ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: ConformalMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1        = a[0] * b[0] - a[1] * b[5] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[5] * b[1] - a[6] * b[12] - a[7] * b[13] - a[8] * b[14] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[6] - a[13] * b[7] - a[14] * b[8] + a[15] * b[15] + a[16] * b[23] + a[17] * b[24] + a[18] * b[25] + a[19] * b[19] + a[20] * b[20] + a[21] * b[21] - a[22] * b[22] + a[23] * b[16] + a[24] * b[17] + a[25] * b[18] + a[26] * b[30] - a[27] * b[27] - a[28] * b[28] - a[29] * b[29] + a[30] * b[26] - a[31] * b[31];
+        let result_e0       = a[0] * b[1] + a[1] * b[0] + a[1] * b[15] - a[2] * b[6] - a[3] * b[7] - a[4] * b[8] + a[6] * b[2] - a[6] * b[19] + a[7] * b[3] - a[7] * b[20] + a[8] * b[4] - a[8] * b[21] - a[9] * b[16] - a[10] * b[17] - a[11] * b[18] - a[15] * b[1] - a[16] * b[9] - a[16] * b[27] - a[17] * b[10] - a[17] * b[28] - a[18] * b[11] - a[18] * b[29] - a[19] * b[6] - a[20] * b[7] - a[21] * b[8] + a[22] * b[26] - a[26] * b[22] + a[26] * b[31] + a[27] * b[16] + a[28] * b[17] + a[29] * b[18] + a[31] * b[26];
+        let result_e1       = a[0] * b[2] + a[1] * b[12] + a[2] * b[0] - a[3] * b[9] + a[4] * b[11] - a[5] * b[6] + a[6] * b[5] - a[7] * b[23] + a[8] * b[25] + a[9] * b[3] - a[10] * b[22] - a[11] * b[4] - a[12] * b[1] + a[13] * b[16] - a[14] * b[18] - a[15] * b[19] + a[16] * b[13] - a[17] * b[30] - a[18] * b[14] - a[19] * b[15] + a[20] * b[27] - a[21] * b[29] - a[22] * b[10] - a[23] * b[7] + a[24] * b[26] + a[25] * b[8] - a[26] * b[24] - a[27] * b[20] + a[28] * b[31] + a[29] * b[21] + a[30] * b[17] + a[31] * b[28];
+        let result_e2       = a[0] * b[3] + a[1] * b[13] + a[2] * b[9] + a[3] * b[0] - a[4] * b[10] - a[5] * b[7] + a[6] * b[23] + a[7] * b[5] - a[8] * b[24] - a[9] * b[2] + a[10] * b[4] - a[11] * b[22] - a[12] * b[16] - a[13] * b[1] + a[14] * b[17] - a[15] * b[20] - a[16] * b[12] + a[17] * b[14] - a[18] * b[30] - a[19] * b[27] - a[20] * b[15] + a[21] * b[28] - a[22] * b[11] + a[23] * b[6] - a[24] * b[8] + a[25] * b[26] - a[26] * b[25] + a[27] * b[19] - a[28] * b[21] + a[29] * b[31] + a[30] * b[18] + a[31] * b[29];
+        let result_e3       = a[0] * b[4] + a[1] * b[14] - a[2] * b[11] + a[3] * b[10] + a[4] * b[0] - a[5] * b[8] - a[6] * b[25] + a[7] * b[24] + a[8] * b[5] - a[9] * b[22] - a[10] * b[3] + a[11] * b[2] + a[12] * b[18] - a[13] * b[17] - a[14] * b[1] - a[15] * b[21] - a[16] * b[30] - a[17] * b[13] + a[18] * b[12] + a[19] * b[29] - a[20] * b[28] - a[21] * b[15] - a[22] * b[9] + a[23] * b[26] + a[24] * b[7] - a[25] * b[6] - a[26] * b[23] + a[27] * b[31] + a[28] * b[20] - a[29] * b[19] + a[30] * b[16] + a[31] * b[27];
+        let result_einf     = a[0] * b[5] + a[2] * b[12] + a[3] * b[13] + a[4] * b[14] + a[5] * b[0] - a[5] * b[15] - a[9] * b[23] - a[10] * b[24] - a[11] * b[25] - a[12] * b[2] - a[12] * b[19] - a[13] * b[3] - a[13] * b[20] - a[14] * b[4] - a[14] * b[21] + a[15] * b[5] - a[19] * b[12] - a[20] * b[13] - a[21] * b[14] - a[22] * b[30] - a[23] * b[9] + a[23] * b[27] - a[24] * b[10] + a[24] * b[28] - a[25] * b[11] + a[25] * b[29] - a[27] * b[23] - a[28] * b[24] - a[29] * b[25] + a[30] * b[22] + a[30] * b[31] + a[31] * b[30];
+        let result_e01      = a[0] * b[6] + a[1] * b[2] - a[1] * b[19] - a[2] * b[1] + a[3] * b[16] - a[4] * b[18] + a[6] * b[0] + a[6] * b[15] - a[7] * b[9] - a[7] * b[27] + a[8] * b[11] + a[8] * b[29] + a[9] * b[7] - a[10] * b[26] - a[11] * b[8] - a[15] * b[6] + a[16] * b[3] - a[16] * b[20] - a[17] * b[22] + a[17] * b[31] - a[18] * b[4] + a[18] * b[21] - a[19] * b[1] + a[20] * b[16] - a[21] * b[18] + a[22] * b[17] - a[26] * b[10] - a[26] * b[28] - a[27] * b[7] + a[28] * b[26] + a[29] * b[8] + a[31] * b[17];
+        let result_e02      = a[0] * b[7] + a[1] * b[3] - a[1] * b[20] - a[2] * b[16] - a[3] * b[1] + a[4] * b[17] + a[6] * b[9] + a[6] * b[27] + a[7] * b[0] + a[7] * b[15] - a[8] * b[10] - a[8] * b[28] - a[9] * b[6] + a[10] * b[8] - a[11] * b[26] - a[15] * b[7] - a[16] * b[2] + a[16] * b[19] + a[17] * b[4] - a[17] * b[21] - a[18] * b[22] + a[18] * b[31] - a[19] * b[16] - a[20] * b[1] + a[21] * b[17] + a[22] * b[18] - a[26] * b[11] - a[26] * b[29] + a[27] * b[6] - a[28] * b[8] + a[29] * b[26] + a[31] * b[18];
+        let result_e03      = a[0] * b[8] + a[1] * b[4] - a[1] * b[21] + a[2] * b[18] - a[3] * b[17] - a[4] * b[1] - a[6] * b[11] - a[6] * b[29] + a[7] * b[10] + a[7] * b[28] + a[8] * b[0] + a[8] * b[15] - a[9] * b[26] - a[10] * b[7] + a[11] * b[6] - a[15] * b[8] - a[16] * b[22] + a[16] * b[31] - a[17] * b[3] + a[17] * b[20] + a[18] * b[2] - a[18] * b[19] + a[19] * b[18] - a[20] * b[17] - a[21] * b[1] + a[22] * b[16] - a[26] * b[9] - a[26] * b[27] + a[27] * b[26] + a[28] * b[7] - a[29] * b[6] + a[31] * b[16];
+        let result_e12      = a[0] * b[9] - a[1] * b[23] + a[2] * b[3] - a[3] * b[2] + a[4] * b[22] - a[5] * b[16] - a[6] * b[13] + a[7] * b[12] - a[8] * b[30] + a[9] * b[0] - a[10] * b[11] + a[11] * b[10] - a[12] * b[7] + a[13] * b[6] - a[14] * b[26] + a[15] * b[27] - a[16] * b[5] + a[17] * b[25] - a[18] * b[24] + a[19] * b[20] - a[20] * b[19] + a[21] * b[31] + a[22] * b[4] - a[23] * b[1] + a[24] * b[18] - a[25] * b[17] - a[26] * b[14] + a[27] * b[15] - a[28] * b[29] + a[29] * b[28] - a[30] * b[8] + a[31] * b[21];
+        let result_e23      = a[0] * b[10] - a[1] * b[24] + a[2] * b[22] + a[3] * b[4] - a[4] * b[3] - a[5] * b[17] - a[6] * b[30] - a[7] * b[14] + a[8] * b[13] + a[9] * b[11] + a[10] * b[0] - a[11] * b[9] - a[12] * b[26] - a[13] * b[8] + a[14] * b[7] + a[15] * b[28] - a[16] * b[25] - a[17] * b[5] + a[18] * b[23] + a[19] * b[31] + a[20] * b[21] - a[21] * b[20] + a[22] * b[2] - a[23] * b[18] - a[24] * b[1] + a[25] * b[16] - a[26] * b[12] + a[27] * b[29] + a[28] * b[15] - a[29] * b[27] - a[30] * b[6] + a[31] * b[19];
+        let result_e31      = a[0] * b[11] - a[1] * b[25] - a[2] * b[4] + a[3] * b[22] + a[4] * b[2] - a[5] * b[18] + a[6] * b[14] - a[7] * b[30] - a[8] * b[12] - a[9] * b[10] + a[10] * b[9] + a[11] * b[0] + a[12] * b[8] - a[13] * b[26] - a[14] * b[6] + a[15] * b[29] + a[16] * b[24] - a[17] * b[23] - a[18] * b[5] - a[19] * b[21] + a[20] * b[31] + a[21] * b[19] + a[22] * b[3] + a[23] * b[17] - a[24] * b[16] - a[25] * b[1] - a[26] * b[13] - a[27] * b[28] + a[28] * b[27] + a[29] * b[15] - a[30] * b[7] + a[31] * b[20];
+        let result_e1inf    = a[0] * b[12] + a[2] * b[5] - a[3] * b[23] + a[4] * b[25] - a[5] * b[2] - a[5] * b[19] + a[9] * b[13] - a[10] * b[30] - a[11] * b[14] + a[12] * b[0] - a[12] * b[15] - a[13] * b[9] + a[13] * b[27] + a[14] * b[11] - a[14] * b[29] + a[15] * b[12] - a[19] * b[5] + a[20] * b[23] - a[21] * b[25] - a[22] * b[24] - a[23] * b[3] - a[23] * b[20] + a[24] * b[22] + a[24] * b[31] + a[25] * b[4] + a[25] * b[21] + a[27] * b[13] - a[28] * b[30] - a[29] * b[14] - a[30] * b[10] + a[30] * b[28] + a[31] * b[24];
+        let result_e2inf    = a[0] * b[13] + a[2] * b[23] + a[3] * b[5] - a[4] * b[24] - a[5] * b[3] - a[5] * b[20] - a[9] * b[12] + a[10] * b[14] - a[11] * b[30] + a[12] * b[9] - a[12] * b[27] + a[13] * b[0] - a[13] * b[15] - a[14] * b[10] + a[14] * b[28] + a[15] * b[13] - a[19] * b[23] - a[20] * b[5] + a[21] * b[24] - a[22] * b[25] + a[23] * b[2] + a[23] * b[19] - a[24] * b[4] - a[24] * b[21] + a[25] * b[22] + a[25] * b[31] - a[27] * b[12] + a[28] * b[14] - a[29] * b[30] - a[30] * b[11] + a[30] * b[29] + a[31] * b[25];
+        let result_e3inf    = a[0] * b[14] - a[2] * b[25] + a[3] * b[24] + a[4] * b[5] - a[5] * b[4] - a[5] * b[21] - a[9] * b[30] - a[10] * b[13] + a[11] * b[12] - a[12] * b[11] + a[12] * b[29] + a[13] * b[10] - a[13] * b[28] + a[14] * b[0] - a[14] * b[15] + a[15] * b[14] + a[19] * b[25] - a[20] * b[24] - a[21] * b[5] - a[22] * b[23] + a[23] * b[22] + a[23] * b[31] + a[24] * b[3] + a[24] * b[20] - a[25] * b[2] - a[25] * b[19] - a[27] * b[30] - a[28] * b[13] + a[29] * b[12] - a[30] * b[9] + a[30] * b[27] + a[31] * b[23];
+        let result_e0inf    = a[0] * b[15] + a[1] * b[5] - a[2] * b[19] - a[3] * b[20] - a[4] * b[21] - a[5] * b[1] + a[6] * b[12] + a[7] * b[13] + a[8] * b[14] - a[9] * b[27] - a[10] * b[28] - a[11] * b[29] - a[12] * b[6] - a[13] * b[7] - a[14] * b[8] + a[15] * b[0] - a[16] * b[23] - a[17] * b[24] - a[18] * b[25] - a[19] * b[2] - a[20] * b[3] - a[21] * b[4] + a[22] * b[31] + a[23] * b[16] + a[24] * b[17] + a[25] * b[18] - a[26] * b[30] - a[27] * b[9] - a[28] * b[10] - a[29] * b[11] + a[30] * b[26] + a[31] * b[22];
+        let result_e012     = a[0] * b[16] + a[1] * b[9] + a[1] * b[27] - a[2] * b[7] + a[3] * b[6] - a[4] * b[26] + a[6] * b[3] - a[6] * b[20] - a[7] * b[2] + a[7] * b[19] + a[8] * b[22] - a[8] * b[31] + a[9] * b[1] - a[10] * b[18] + a[11] * b[17] - a[15] * b[16] + a[16] * b[0] + a[16] * b[15] - a[17] * b[11] - a[17] * b[29] + a[18] * b[10] + a[18] * b[28] - a[19] * b[7] + a[20] * b[6] - a[21] * b[26] - a[22] * b[8] + a[26] * b[4] - a[26] * b[21] - a[27] * b[1] + a[28] * b[18] - a[29] * b[17] - a[31] * b[8];
+        let result_e023     = a[0] * b[17] + a[1] * b[10] + a[1] * b[28] - a[2] * b[26] - a[3] * b[8] + a[4] * b[7] + a[6] * b[22] - a[6] * b[31] + a[7] * b[4] - a[7] * b[21] - a[8] * b[3] + a[8] * b[20] + a[9] * b[18] + a[10] * b[1] - a[11] * b[16] - a[15] * b[17] + a[16] * b[11] + a[16] * b[29] + a[17] * b[0] + a[17] * b[15] - a[18] * b[9] - a[18] * b[27] - a[19] * b[26] - a[20] * b[8] + a[21] * b[7] - a[22] * b[6] + a[26] * b[2] - a[26] * b[19] - a[27] * b[18] - a[28] * b[1] + a[29] * b[16] - a[31] * b[6];
+        let result_e031     = a[0] * b[18] + a[1] * b[11] + a[1] * b[29] + a[2] * b[8] - a[3] * b[26] - a[4] * b[6] - a[6] * b[4] + a[6] * b[21] + a[7] * b[22] - a[7] * b[31] + a[8] * b[2] - a[8] * b[19] - a[9] * b[17] + a[10] * b[16] + a[11] * b[1] - a[15] * b[18] - a[16] * b[10] - a[16] * b[28] + a[17] * b[9] + a[17] * b[27] + a[18] * b[0] + a[18] * b[15] + a[19] * b[8] - a[20] * b[26] - a[21] * b[6] - a[22] * b[7] + a[26] * b[3] - a[26] * b[20] + a[27] * b[17] - a[28] * b[16] - a[29] * b[1] - a[31] * b[7];
+        let result_e01inf   = a[0] * b[19] + a[1] * b[12] - a[2] * b[15] + a[3] * b[27] - a[4] * b[29] + a[5] * b[6] + a[6] * b[5] - a[7] * b[23] + a[8] * b[25] + a[9] * b[20] - a[10] * b[31] - a[11] * b[21] + a[12] * b[1] - a[13] * b[16] + a[14] * b[18] - a[15] * b[2] + a[16] * b[13] - a[17] * b[30] - a[18] * b[14] + a[19] * b[0] - a[20] * b[9] + a[21] * b[11] + a[22] * b[28] + a[23] * b[7] - a[24] * b[26] - a[25] * b[8] - a[26] * b[24] - a[27] * b[3] + a[28] * b[22] + a[29] * b[4] - a[30] * b[17] - a[31] * b[10];
+        let result_e02inf   = a[0] * b[20] + a[1] * b[13] - a[2] * b[27] - a[3] * b[15] + a[4] * b[28] + a[5] * b[7] + a[6] * b[23] + a[7] * b[5] - a[8] * b[24] - a[9] * b[19] + a[10] * b[21] - a[11] * b[31] + a[12] * b[16] + a[13] * b[1] - a[14] * b[17] - a[15] * b[3] - a[16] * b[12] + a[17] * b[14] - a[18] * b[30] + a[19] * b[9] + a[20] * b[0] - a[21] * b[10] + a[22] * b[29] - a[23] * b[6] + a[24] * b[8] - a[25] * b[26] - a[26] * b[25] + a[27] * b[2] - a[28] * b[4] + a[29] * b[22] - a[30] * b[18] - a[31] * b[11];
+        let result_e03inf   = a[0] * b[21] + a[1] * b[14] + a[2] * b[29] - a[3] * b[28] - a[4] * b[15] + a[5] * b[8] - a[6] * b[25] + a[7] * b[24] + a[8] * b[5] - a[9] * b[31] - a[10] * b[20] + a[11] * b[19] - a[12] * b[18] + a[13] * b[17] + a[14] * b[1] - a[15] * b[4] - a[16] * b[30] - a[17] * b[13] + a[18] * b[12] - a[19] * b[11] + a[20] * b[10] + a[21] * b[0] + a[22] * b[27] - a[23] * b[26] - a[24] * b[7] + a[25] * b[6] - a[26] * b[23] + a[27] * b[22] + a[28] * b[3] - a[29] * b[2] - a[30] * b[16] - a[31] * b[9];
+        let result_e123     = a[0] * b[22] + a[1] * b[30] + a[2] * b[10] + a[3] * b[11] + a[4] * b[9] - a[5] * b[26] + a[6] * b[24] + a[7] * b[25] + a[8] * b[23] + a[9] * b[4] + a[10] * b[2] + a[11] * b[3] - a[12] * b[17] - a[13] * b[18] - a[14] * b[16] - a[15] * b[31] + a[16] * b[14] + a[17] * b[12] + a[18] * b[13] - a[19] * b[28] - a[20] * b[29] - a[21] * b[27] + a[22] * b[0] - a[23] * b[8] - a[24] * b[6] - a[25] * b[7] + a[26] * b[5] - a[27] * b[21] - a[28] * b[19] - a[29] * b[20] - a[30] * b[1] - a[31] * b[15];
+        let result_e12inf   = a[0] * b[23] + a[2] * b[13] - a[3] * b[12] + a[4] * b[30] + a[5] * b[9] - a[5] * b[27] + a[9] * b[5] - a[10] * b[25] + a[11] * b[24] - a[12] * b[3] - a[12] * b[20] + a[13] * b[2] + a[13] * b[19] - a[14] * b[22] - a[14] * b[31] + a[15] * b[23] - a[19] * b[13] + a[20] * b[12] - a[21] * b[30] + a[22] * b[14] + a[23] * b[0] - a[23] * b[15] - a[24] * b[11] + a[24] * b[29] + a[25] * b[10] - a[25] * b[28] + a[27] * b[5] - a[28] * b[25] + a[29] * b[24] - a[30] * b[4] - a[30] * b[21] - a[31] * b[14];
+        let result_e23inf   = a[0] * b[24] + a[2] * b[30] + a[3] * b[14] - a[4] * b[13] + a[5] * b[10] - a[5] * b[28] + a[9] * b[25] + a[10] * b[5] - a[11] * b[23] - a[12] * b[22] - a[12] * b[31] - a[13] * b[4] - a[13] * b[21] + a[14] * b[3] + a[14] * b[20] + a[15] * b[24] - a[19] * b[30] - a[20] * b[14] + a[21] * b[13] + a[22] * b[12] + a[23] * b[11] - a[23] * b[29] + a[24] * b[0] - a[24] * b[15] - a[25] * b[9] + a[25] * b[27] + a[27] * b[25] + a[28] * b[5] - a[29] * b[23] - a[30] * b[2] - a[30] * b[19] - a[31] * b[12];
+        let result_e31inf   = a[0] * b[25] - a[2] * b[14] + a[3] * b[30] + a[4] * b[12] + a[5] * b[11] - a[5] * b[29] - a[9] * b[24] + a[10] * b[23] + a[11] * b[5] + a[12] * b[4] + a[12] * b[21] - a[13] * b[22] - a[13] * b[31] - a[14] * b[2] - a[14] * b[19] + a[15] * b[25] + a[19] * b[14] - a[20] * b[30] - a[21] * b[12] + a[22] * b[13] - a[23] * b[10] + a[23] * b[28] + a[24] * b[9] - a[24] * b[27] + a[25] * b[0] - a[25] * b[15] - a[27] * b[24] + a[28] * b[23] + a[29] * b[5] - a[30] * b[3] - a[30] * b[20] - a[31] * b[13];
+        let result_e0123    = a[0] * b[26] + a[1] * b[22] - a[1] * b[31] - a[2] * b[17] - a[3] * b[18] - a[4] * b[16] + a[6] * b[10] + a[6] * b[28] + a[7] * b[11] + a[7] * b[29] + a[8] * b[9] + a[8] * b[27] + a[9] * b[8] + a[10] * b[6] + a[11] * b[7] - a[15] * b[26] + a[16] * b[4] - a[16] * b[21] + a[17] * b[2] - a[17] * b[19] + a[18] * b[3] - a[18] * b[20] - a[19] * b[17] - a[20] * b[18] - a[21] * b[16] - a[22] * b[1] + a[26] * b[0] + a[26] * b[15] - a[27] * b[8] - a[28] * b[6] - a[29] * b[7] - a[31] * b[1];
+        let result_e012inf  = a[0] * b[27] + a[1] * b[23] - a[2] * b[20] + a[3] * b[19] - a[4] * b[31] - a[5] * b[16] + a[6] * b[13] - a[7] * b[12] + a[8] * b[30] + a[9] * b[15] - a[10] * b[29] + a[11] * b[28] - a[12] * b[7] + a[13] * b[6] - a[14] * b[26] + a[15] * b[9] + a[16] * b[5] - a[17] * b[25] + a[18] * b[24] - a[19] * b[3] + a[20] * b[2] - a[21] * b[22] - a[22] * b[21] - a[23] * b[1] + a[24] * b[18] - a[25] * b[17] + a[26] * b[14] + a[27] * b[0] - a[28] * b[11] + a[29] * b[10] - a[30] * b[8] - a[31] * b[4];
+        let result_e023inf  = a[0] * b[28] + a[1] * b[24] - a[2] * b[31] - a[3] * b[21] + a[4] * b[20] - a[5] * b[17] + a[6] * b[30] + a[7] * b[14] - a[8] * b[13] + a[9] * b[29] + a[10] * b[15] - a[11] * b[27] - a[12] * b[26] - a[13] * b[8] + a[14] * b[7] + a[15] * b[10] + a[16] * b[25] + a[17] * b[5] - a[18] * b[23] - a[19] * b[22] - a[20] * b[4] + a[21] * b[3] - a[22] * b[19] - a[23] * b[18] - a[24] * b[1] + a[25] * b[16] + a[26] * b[12] + a[27] * b[11] + a[28] * b[0] - a[29] * b[9] - a[30] * b[6] - a[31] * b[2];
+        let result_e031inf  = a[0] * b[29] + a[1] * b[25] + a[2] * b[21] - a[3] * b[31] - a[4] * b[19] - a[5] * b[18] - a[6] * b[14] + a[7] * b[30] + a[8] * b[12] - a[9] * b[28] + a[10] * b[27] + a[11] * b[15] + a[12] * b[8] - a[13] * b[26] - a[14] * b[6] + a[15] * b[11] - a[16] * b[24] + a[17] * b[23] + a[18] * b[5] + a[19] * b[4] - a[20] * b[22] - a[21] * b[2] - a[22] * b[20] + a[23] * b[17] - a[24] * b[16] - a[25] * b[1] + a[26] * b[13] - a[27] * b[10] + a[28] * b[9] + a[29] * b[0] - a[30] * b[7] - a[31] * b[3];
+        let result_e123inf  = a[0] * b[30] + a[2] * b[24] + a[3] * b[25] + a[4] * b[23] - a[5] * b[22] - a[5] * b[31] + a[9] * b[14] + a[10] * b[12] + a[11] * b[13] + a[12] * b[10] - a[12] * b[28] + a[13] * b[11] - a[13] * b[29] + a[14] * b[9] - a[14] * b[27] + a[15] * b[30] - a[19] * b[24] - a[20] * b[25] - a[21] * b[23] + a[22] * b[5] - a[23] * b[4] - a[23] * b[21] - a[24] * b[2] - a[24] * b[19] - a[25] * b[3] - a[25] * b[20] + a[27] * b[14] + a[28] * b[12] + a[29] * b[13] + a[30] * b[0] - a[30] * b[15] - a[31] * b[5];
+        let result_e0123inf = a[0] * b[31] + a[1] * b[30] - a[2] * b[28] - a[3] * b[29] - a[4] * b[27] + a[5] * b[26] + a[6] * b[24] + a[7] * b[25] + a[8] * b[23] + a[9] * b[21] + a[10] * b[19] + a[11] * b[20] + a[12] * b[17] + a[13] * b[18] + a[14] * b[16] - a[15] * b[22] + a[16] * b[14] + a[17] * b[12] + a[18] * b[13] + a[19] * b[10] + a[20] * b[11] + a[21] * b[9] - a[22] * b[15] + a[23] * b[8] + a[24] * b[6] + a[25] * b[7] + a[26] * b[5] - a[27] * b[4] - a[28] * b[2] - a[29] * b[3] + a[30] * b[1] + a[31] * b[0];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::Mul<&ConformalMultivector3<S>> for &ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: &ConformalMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1        = a[0] * b[0] - a[1] * b[5] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[5] * b[1] - a[6] * b[12] - a[7] * b[13] - a[8] * b[14] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[6] - a[13] * b[7] - a[14] * b[8] + a[15] * b[15] + a[16] * b[23] + a[17] * b[24] + a[18] * b[25] + a[19] * b[19] + a[20] * b[20] + a[21] * b[21] - a[22] * b[22] + a[23] * b[16] + a[24] * b[17] + a[25] * b[18] + a[26] * b[30] - a[27] * b[27] - a[28] * b[28] - a[29] * b[29] + a[30] * b[26] - a[31] * b[31];
+        let result_e0       = a[0] * b[1] + a[1] * b[0] + a[1] * b[15] - a[2] * b[6] - a[3] * b[7] - a[4] * b[8] + a[6] * b[2] - a[6] * b[19] + a[7] * b[3] - a[7] * b[20] + a[8] * b[4] - a[8] * b[21] - a[9] * b[16] - a[10] * b[17] - a[11] * b[18] - a[15] * b[1] - a[16] * b[9] - a[16] * b[27] - a[17] * b[10] - a[17] * b[28] - a[18] * b[11] - a[18] * b[29] - a[19] * b[6] - a[20] * b[7] - a[21] * b[8] + a[22] * b[26] - a[26] * b[22] + a[26] * b[31] + a[27] * b[16] + a[28] * b[17] + a[29] * b[18] + a[31] * b[26];
+        let result_e1       = a[0] * b[2] + a[1] * b[12] + a[2] * b[0] - a[3] * b[9] + a[4] * b[11] - a[5] * b[6] + a[6] * b[5] - a[7] * b[23] + a[8] * b[25] + a[9] * b[3] - a[10] * b[22] - a[11] * b[4] - a[12] * b[1] + a[13] * b[16] - a[14] * b[18] - a[15] * b[19] + a[16] * b[13] - a[17] * b[30] - a[18] * b[14] - a[19] * b[15] + a[20] * b[27] - a[21] * b[29] - a[22] * b[10] - a[23] * b[7] + a[24] * b[26] + a[25] * b[8] - a[26] * b[24] - a[27] * b[20] + a[28] * b[31] + a[29] * b[21] + a[30] * b[17] + a[31] * b[28];
+        let result_e2       = a[0] * b[3] + a[1] * b[13] + a[2] * b[9] + a[3] * b[0] - a[4] * b[10] - a[5] * b[7] + a[6] * b[23] + a[7] * b[5] - a[8] * b[24] - a[9] * b[2] + a[10] * b[4] - a[11] * b[22] - a[12] * b[16] - a[13] * b[1] + a[14] * b[17] - a[15] * b[20] - a[16] * b[12] + a[17] * b[14] - a[18] * b[30] - a[19] * b[27] - a[20] * b[15] + a[21] * b[28] - a[22] * b[11] + a[23] * b[6] - a[24] * b[8] + a[25] * b[26] - a[26] * b[25] + a[27] * b[19] - a[28] * b[21] + a[29] * b[31] + a[30] * b[18] + a[31] * b[29];
+        let result_e3       = a[0] * b[4] + a[1] * b[14] - a[2] * b[11] + a[3] * b[10] + a[4] * b[0] - a[5] * b[8] - a[6] * b[25] + a[7] * b[24] + a[8] * b[5] - a[9] * b[22] - a[10] * b[3] + a[11] * b[2] + a[12] * b[18] - a[13] * b[17] - a[14] * b[1] - a[15] * b[21] - a[16] * b[30] - a[17] * b[13] + a[18] * b[12] + a[19] * b[29] - a[20] * b[28] - a[21] * b[15] - a[22] * b[9] + a[23] * b[26] + a[24] * b[7] - a[25] * b[6] - a[26] * b[23] + a[27] * b[31] + a[28] * b[20] - a[29] * b[19] + a[30] * b[16] + a[31] * b[27];
+        let result_einf     = a[0] * b[5] + a[2] * b[12] + a[3] * b[13] + a[4] * b[14] + a[5] * b[0] - a[5] * b[15] - a[9] * b[23] - a[10] * b[24] - a[11] * b[25] - a[12] * b[2] - a[12] * b[19] - a[13] * b[3] - a[13] * b[20] - a[14] * b[4] - a[14] * b[21] + a[15] * b[5] - a[19] * b[12] - a[20] * b[13] - a[21] * b[14] - a[22] * b[30] - a[23] * b[9] + a[23] * b[27] - a[24] * b[10] + a[24] * b[28] - a[25] * b[11] + a[25] * b[29] - a[27] * b[23] - a[28] * b[24] - a[29] * b[25] + a[30] * b[22] + a[30] * b[31] + a[31] * b[30];
+        let result_e01      = a[0] * b[6] + a[1] * b[2] - a[1] * b[19] - a[2] * b[1] + a[3] * b[16] - a[4] * b[18] + a[6] * b[0] + a[6] * b[15] - a[7] * b[9] - a[7] * b[27] + a[8] * b[11] + a[8] * b[29] + a[9] * b[7] - a[10] * b[26] - a[11] * b[8] - a[15] * b[6] + a[16] * b[3] - a[16] * b[20] - a[17] * b[22] + a[17] * b[31] - a[18] * b[4] + a[18] * b[21] - a[19] * b[1] + a[20] * b[16] - a[21] * b[18] + a[22] * b[17] - a[26] * b[10] - a[26] * b[28] - a[27] * b[7] + a[28] * b[26] + a[29] * b[8] + a[31] * b[17];
+        let result_e02      = a[0] * b[7] + a[1] * b[3] - a[1] * b[20] - a[2] * b[16] - a[3] * b[1] + a[4] * b[17] + a[6] * b[9] + a[6] * b[27] + a[7] * b[0] + a[7] * b[15] - a[8] * b[10] - a[8] * b[28] - a[9] * b[6] + a[10] * b[8] - a[11] * b[26] - a[15] * b[7] - a[16] * b[2] + a[16] * b[19] + a[17] * b[4] - a[17] * b[21] - a[18] * b[22] + a[18] * b[31] - a[19] * b[16] - a[20] * b[1] + a[21] * b[17] + a[22] * b[18] - a[26] * b[11] - a[26] * b[29] + a[27] * b[6] - a[28] * b[8] + a[29] * b[26] + a[31] * b[18];
+        let result_e03      = a[0] * b[8] + a[1] * b[4] - a[1] * b[21] + a[2] * b[18] - a[3] * b[17] - a[4] * b[1] - a[6] * b[11] - a[6] * b[29] + a[7] * b[10] + a[7] * b[28] + a[8] * b[0] + a[8] * b[15] - a[9] * b[26] - a[10] * b[7] + a[11] * b[6] - a[15] * b[8] - a[16] * b[22] + a[16] * b[31] - a[17] * b[3] + a[17] * b[20] + a[18] * b[2] - a[18] * b[19] + a[19] * b[18] - a[20] * b[17] - a[21] * b[1] + a[22] * b[16] - a[26] * b[9] - a[26] * b[27] + a[27] * b[26] + a[28] * b[7] - a[29] * b[6] + a[31] * b[16];
+        let result_e12      = a[0] * b[9] - a[1] * b[23] + a[2] * b[3] - a[3] * b[2] + a[4] * b[22] - a[5] * b[16] - a[6] * b[13] + a[7] * b[12] - a[8] * b[30] + a[9] * b[0] - a[10] * b[11] + a[11] * b[10] - a[12] * b[7] + a[13] * b[6] - a[14] * b[26] + a[15] * b[27] - a[16] * b[5] + a[17] * b[25] - a[18] * b[24] + a[19] * b[20] - a[20] * b[19] + a[21] * b[31] + a[22] * b[4] - a[23] * b[1] + a[24] * b[18] - a[25] * b[17] - a[26] * b[14] + a[27] * b[15] - a[28] * b[29] + a[29] * b[28] - a[30] * b[8] + a[31] * b[21];
+        let result_e23      = a[0] * b[10] - a[1] * b[24] + a[2] * b[22] + a[3] * b[4] - a[4] * b[3] - a[5] * b[17] - a[6] * b[30] - a[7] * b[14] + a[8] * b[13] + a[9] * b[11] + a[10] * b[0] - a[11] * b[9] - a[12] * b[26] - a[13] * b[8] + a[14] * b[7] + a[15] * b[28] - a[16] * b[25] - a[17] * b[5] + a[18] * b[23] + a[19] * b[31] + a[20] * b[21] - a[21] * b[20] + a[22] * b[2] - a[23] * b[18] - a[24] * b[1] + a[25] * b[16] - a[26] * b[12] + a[27] * b[29] + a[28] * b[15] - a[29] * b[27] - a[30] * b[6] + a[31] * b[19];
+        let result_e31      = a[0] * b[11] - a[1] * b[25] - a[2] * b[4] + a[3] * b[22] + a[4] * b[2] - a[5] * b[18] + a[6] * b[14] - a[7] * b[30] - a[8] * b[12] - a[9] * b[10] + a[10] * b[9] + a[11] * b[0] + a[12] * b[8] - a[13] * b[26] - a[14] * b[6] + a[15] * b[29] + a[16] * b[24] - a[17] * b[23] - a[18] * b[5] - a[19] * b[21] + a[20] * b[31] + a[21] * b[19] + a[22] * b[3] + a[23] * b[17] - a[24] * b[16] - a[25] * b[1] - a[26] * b[13] - a[27] * b[28] + a[28] * b[27] + a[29] * b[15] - a[30] * b[7] + a[31] * b[20];
+        let result_e1inf    = a[0] * b[12] + a[2] * b[5] - a[3] * b[23] + a[4] * b[25] - a[5] * b[2] - a[5] * b[19] + a[9] * b[13] - a[10] * b[30] - a[11] * b[14] + a[12] * b[0] - a[12] * b[15] - a[13] * b[9] + a[13] * b[27] + a[14] * b[11] - a[14] * b[29] + a[15] * b[12] - a[19] * b[5] + a[20] * b[23] - a[21] * b[25] - a[22] * b[24] - a[23] * b[3] - a[23] * b[20] + a[24] * b[22] + a[24] * b[31] + a[25] * b[4] + a[25] * b[21] + a[27] * b[13] - a[28] * b[30] - a[29] * b[14] - a[30] * b[10] + a[30] * b[28] + a[31] * b[24];
+        let result_e2inf    = a[0] * b[13] + a[2] * b[23] + a[3] * b[5] - a[4] * b[24] - a[5] * b[3] - a[5] * b[20] - a[9] * b[12] + a[10] * b[14] - a[11] * b[30] + a[12] * b[9] - a[12] * b[27] + a[13] * b[0] - a[13] * b[15] - a[14] * b[10] + a[14] * b[28] + a[15] * b[13] - a[19] * b[23] - a[20] * b[5] + a[21] * b[24] - a[22] * b[25] + a[23] * b[2] + a[23] * b[19] - a[24] * b[4] - a[24] * b[21] + a[25] * b[22] + a[25] * b[31] - a[27] * b[12] + a[28] * b[14] - a[29] * b[30] - a[30] * b[11] + a[30] * b[29] + a[31] * b[25];
+        let result_e3inf    = a[0] * b[14] - a[2] * b[25] + a[3] * b[24] + a[4] * b[5] - a[5] * b[4] - a[5] * b[21] - a[9] * b[30] - a[10] * b[13] + a[11] * b[12] - a[12] * b[11] + a[12] * b[29] + a[13] * b[10] - a[13] * b[28] + a[14] * b[0] - a[14] * b[15] + a[15] * b[14] + a[19] * b[25] - a[20] * b[24] - a[21] * b[5] - a[22] * b[23] + a[23] * b[22] + a[23] * b[31] + a[24] * b[3] + a[24] * b[20] - a[25] * b[2] - a[25] * b[19] - a[27] * b[30] - a[28] * b[13] + a[29] * b[12] - a[30] * b[9] + a[30] * b[27] + a[31] * b[23];
+        let result_e0inf    = a[0] * b[15] + a[1] * b[5] - a[2] * b[19] - a[3] * b[20] - a[4] * b[21] - a[5] * b[1] + a[6] * b[12] + a[7] * b[13] + a[8] * b[14] - a[9] * b[27] - a[10] * b[28] - a[11] * b[29] - a[12] * b[6] - a[13] * b[7] - a[14] * b[8] + a[15] * b[0] - a[16] * b[23] - a[17] * b[24] - a[18] * b[25] - a[19] * b[2] - a[20] * b[3] - a[21] * b[4] + a[22] * b[31] + a[23] * b[16] + a[24] * b[17] + a[25] * b[18] - a[26] * b[30] - a[27] * b[9] - a[28] * b[10] - a[29] * b[11] + a[30] * b[26] + a[31] * b[22];
+        let result_e012     = a[0] * b[16] + a[1] * b[9] + a[1] * b[27] - a[2] * b[7] + a[3] * b[6] - a[4] * b[26] + a[6] * b[3] - a[6] * b[20] - a[7] * b[2] + a[7] * b[19] + a[8] * b[22] - a[8] * b[31] + a[9] * b[1] - a[10] * b[18] + a[11] * b[17] - a[15] * b[16] + a[16] * b[0] + a[16] * b[15] - a[17] * b[11] - a[17] * b[29] + a[18] * b[10] + a[18] * b[28] - a[19] * b[7] + a[20] * b[6] - a[21] * b[26] - a[22] * b[8] + a[26] * b[4] - a[26] * b[21] - a[27] * b[1] + a[28] * b[18] - a[29] * b[17] - a[31] * b[8];
+        let result_e023     = a[0] * b[17] + a[1] * b[10] + a[1] * b[28] - a[2] * b[26] - a[3] * b[8] + a[4] * b[7] + a[6] * b[22] - a[6] * b[31] + a[7] * b[4] - a[7] * b[21] - a[8] * b[3] + a[8] * b[20] + a[9] * b[18] + a[10] * b[1] - a[11] * b[16] - a[15] * b[17] + a[16] * b[11] + a[16] * b[29] + a[17] * b[0] + a[17] * b[15] - a[18] * b[9] - a[18] * b[27] - a[19] * b[26] - a[20] * b[8] + a[21] * b[7] - a[22] * b[6] + a[26] * b[2] - a[26] * b[19] - a[27] * b[18] - a[28] * b[1] + a[29] * b[16] - a[31] * b[6];
+        let result_e031     = a[0] * b[18] + a[1] * b[11] + a[1] * b[29] + a[2] * b[8] - a[3] * b[26] - a[4] * b[6] - a[6] * b[4] + a[6] * b[21] + a[7] * b[22] - a[7] * b[31] + a[8] * b[2] - a[8] * b[19] - a[9] * b[17] + a[10] * b[16] + a[11] * b[1] - a[15] * b[18] - a[16] * b[10] - a[16] * b[28] + a[17] * b[9] + a[17] * b[27] + a[18] * b[0] + a[18] * b[15] + a[19] * b[8] - a[20] * b[26] - a[21] * b[6] - a[22] * b[7] + a[26] * b[3] - a[26] * b[20] + a[27] * b[17] - a[28] * b[16] - a[29] * b[1] - a[31] * b[7];
+        let result_e01inf   = a[0] * b[19] + a[1] * b[12] - a[2] * b[15] + a[3] * b[27] - a[4] * b[29] + a[5] * b[6] + a[6] * b[5] - a[7] * b[23] + a[8] * b[25] + a[9] * b[20] - a[10] * b[31] - a[11] * b[21] + a[12] * b[1] - a[13] * b[16] + a[14] * b[18] - a[15] * b[2] + a[16] * b[13] - a[17] * b[30] - a[18] * b[14] + a[19] * b[0] - a[20] * b[9] + a[21] * b[11] + a[22] * b[28] + a[23] * b[7] - a[24] * b[26] - a[25] * b[8] - a[26] * b[24] - a[27] * b[3] + a[28] * b[22] + a[29] * b[4] - a[30] * b[17] - a[31] * b[10];
+        let result_e02inf   = a[0] * b[20] + a[1] * b[13] - a[2] * b[27] - a[3] * b[15] + a[4] * b[28] + a[5] * b[7] + a[6] * b[23] + a[7] * b[5] - a[8] * b[24] - a[9] * b[19] + a[10] * b[21] - a[11] * b[31] + a[12] * b[16] + a[13] * b[1] - a[14] * b[17] - a[15] * b[3] - a[16] * b[12] + a[17] * b[14] - a[18] * b[30] + a[19] * b[9] + a[20] * b[0] - a[21] * b[10] + a[22] * b[29] - a[23] * b[6] + a[24] * b[8] - a[25] * b[26] - a[26] * b[25] + a[27] * b[2] - a[28] * b[4] + a[29] * b[22] - a[30] * b[18] - a[31] * b[11];
+        let result_e03inf   = a[0] * b[21] + a[1] * b[14] + a[2] * b[29] - a[3] * b[28] - a[4] * b[15] + a[5] * b[8] - a[6] * b[25] + a[7] * b[24] + a[8] * b[5] - a[9] * b[31] - a[10] * b[20] + a[11] * b[19] - a[12] * b[18] + a[13] * b[17] + a[14] * b[1] - a[15] * b[4] - a[16] * b[30] - a[17] * b[13] + a[18] * b[12] - a[19] * b[11] + a[20] * b[10] + a[21] * b[0] + a[22] * b[27] - a[23] * b[26] - a[24] * b[7] + a[25] * b[6] - a[26] * b[23] + a[27] * b[22] + a[28] * b[3] - a[29] * b[2] - a[30] * b[16] - a[31] * b[9];
+        let result_e123     = a[0] * b[22] + a[1] * b[30] + a[2] * b[10] + a[3] * b[11] + a[4] * b[9] - a[5] * b[26] + a[6] * b[24] + a[7] * b[25] + a[8] * b[23] + a[9] * b[4] + a[10] * b[2] + a[11] * b[3] - a[12] * b[17] - a[13] * b[18] - a[14] * b[16] - a[15] * b[31] + a[16] * b[14] + a[17] * b[12] + a[18] * b[13] - a[19] * b[28] - a[20] * b[29] - a[21] * b[27] + a[22] * b[0] - a[23] * b[8] - a[24] * b[6] - a[25] * b[7] + a[26] * b[5] - a[27] * b[21] - a[28] * b[19] - a[29] * b[20] - a[30] * b[1] - a[31] * b[15];
+        let result_e12inf   = a[0] * b[23] + a[2] * b[13] - a[3] * b[12] + a[4] * b[30] + a[5] * b[9] - a[5] * b[27] + a[9] * b[5] - a[10] * b[25] + a[11] * b[24] - a[12] * b[3] - a[12] * b[20] + a[13] * b[2] + a[13] * b[19] - a[14] * b[22] - a[14] * b[31] + a[15] * b[23] - a[19] * b[13] + a[20] * b[12] - a[21] * b[30] + a[22] * b[14] + a[23] * b[0] - a[23] * b[15] - a[24] * b[11] + a[24] * b[29] + a[25] * b[10] - a[25] * b[28] + a[27] * b[5] - a[28] * b[25] + a[29] * b[24] - a[30] * b[4] - a[30] * b[21] - a[31] * b[14];
+        let result_e23inf   = a[0] * b[24] + a[2] * b[30] + a[3] * b[14] - a[4] * b[13] + a[5] * b[10] - a[5] * b[28] + a[9] * b[25] + a[10] * b[5] - a[11] * b[23] - a[12] * b[22] - a[12] * b[31] - a[13] * b[4] - a[13] * b[21] + a[14] * b[3] + a[14] * b[20] + a[15] * b[24] - a[19] * b[30] - a[20] * b[14] + a[21] * b[13] + a[22] * b[12] + a[23] * b[11] - a[23] * b[29] + a[24] * b[0] - a[24] * b[15] - a[25] * b[9] + a[25] * b[27] + a[27] * b[25] + a[28] * b[5] - a[29] * b[23] - a[30] * b[2] - a[30] * b[19] - a[31] * b[12];
+        let result_e31inf   = a[0] * b[25] - a[2] * b[14] + a[3] * b[30] + a[4] * b[12] + a[5] * b[11] - a[5] * b[29] - a[9] * b[24] + a[10] * b[23] + a[11] * b[5] + a[12] * b[4] + a[12] * b[21] - a[13] * b[22] - a[13] * b[31] - a[14] * b[2] - a[14] * b[19] + a[15] * b[25] + a[19] * b[14] - a[20] * b[30] - a[21] * b[12] + a[22] * b[13] - a[23] * b[10] + a[23] * b[28] + a[24] * b[9] - a[24] * b[27] + a[25] * b[0] - a[25] * b[15] - a[27] * b[24] + a[28] * b[23] + a[29] * b[5] - a[30] * b[3] - a[30] * b[20] - a[31] * b[13];
+        let result_e0123    = a[0] * b[26] + a[1] * b[22] - a[1] * b[31] - a[2] * b[17] - a[3] * b[18] - a[4] * b[16] + a[6] * b[10] + a[6] * b[28] + a[7] * b[11] + a[7] * b[29] + a[8] * b[9] + a[8] * b[27] + a[9] * b[8] + a[10] * b[6] + a[11] * b[7] - a[15] * b[26] + a[16] * b[4] - a[16] * b[21] + a[17] * b[2] - a[17] * b[19] + a[18] * b[3] - a[18] * b[20] - a[19] * b[17] - a[20] * b[18] - a[21] * b[16] - a[22] * b[1] + a[26] * b[0] + a[26] * b[15] - a[27] * b[8] - a[28] * b[6] - a[29] * b[7] - a[31] * b[1];
+        let result_e012inf  = a[0] * b[27] + a[1] * b[23] - a[2] * b[20] + a[3] * b[19] - a[4] * b[31] - a[5] * b[16] + a[6] * b[13] - a[7] * b[12] + a[8] * b[30] + a[9] * b[15] - a[10] * b[29] + a[11] * b[28] - a[12] * b[7] + a[13] * b[6] - a[14] * b[26] + a[15] * b[9] + a[16] * b[5] - a[17] * b[25] + a[18] * b[24] - a[19] * b[3] + a[20] * b[2] - a[21] * b[22] - a[22] * b[21] - a[23] * b[1] + a[24] * b[18] - a[25] * b[17] + a[26] * b[14] + a[27] * b[0] - a[28] * b[11] + a[29] * b[10] - a[30] * b[8] - a[31] * b[4];
+        let result_e023inf  = a[0] * b[28] + a[1] * b[24] - a[2] * b[31] - a[3] * b[21] + a[4] * b[20] - a[5] * b[17] + a[6] * b[30] + a[7] * b[14] - a[8] * b[13] + a[9] * b[29] + a[10] * b[15] - a[11] * b[27] - a[12] * b[26] - a[13] * b[8] + a[14] * b[7] + a[15] * b[10] + a[16] * b[25] + a[17] * b[5] - a[18] * b[23] - a[19] * b[22] - a[20] * b[4] + a[21] * b[3] - a[22] * b[19] - a[23] * b[18] - a[24] * b[1] + a[25] * b[16] + a[26] * b[12] + a[27] * b[11] + a[28] * b[0] - a[29] * b[9] - a[30] * b[6] - a[31] * b[2];
+        let result_e031inf  = a[0] * b[29] + a[1] * b[25] + a[2] * b[21] - a[3] * b[31] - a[4] * b[19] - a[5] * b[18] - a[6] * b[14] + a[7] * b[30] + a[8] * b[12] - a[9] * b[28] + a[10] * b[27] + a[11] * b[15] + a[12] * b[8] - a[13] * b[26] - a[14] * b[6] + a[15] * b[11] - a[16] * b[24] + a[17] * b[23] + a[18] * b[5] + a[19] * b[4] - a[20] * b[22] - a[21] * b[2] - a[22] * b[20] + a[23] * b[17] - a[24] * b[16] - a[25] * b[1] + a[26] * b[13] - a[27] * b[10] + a[28] * b[9] + a[29] * b[0] - a[30] * b[7] - a[31] * b[3];
+        let result_e123inf  = a[0] * b[30] + a[2] * b[24] + a[3] * b[25] + a[4] * b[23] - a[5] * b[22] - a[5] * b[31] + a[9] * b[14] + a[10] * b[12] + a[11] * b[13] + a[12] * b[10] - a[12] * b[28] + a[13] * b[11] - a[13] * b[29] + a[14] * b[9] - a[14] * b[27] + a[15] * b[30] - a[19] * b[24] - a[20] * b[25] - a[21] * b[23] + a[22] * b[5] - a[23] * b[4] - a[23] * b[21] - a[24] * b[2] - a[24] * b[19] - a[25] * b[3] - a[25] * b[20] + a[27] * b[14] + a[28] * b[12] + a[29] * b[13] + a[30] * b[0] - a[30] * b[15] - a[31] * b[5];
+        let result_e0123inf = a[0] * b[31] + a[1] * b[30] - a[2] * b[28] - a[3] * b[29] - a[4] * b[27] + a[5] * b[26] + a[6] * b[24] + a[7] * b[25] + a[8] * b[23] + a[9] * b[21] + a[10] * b[19] + a[11] * b[20] + a[12] * b[17] + a[13] * b[18] + a[14] * b[16] - a[15] * b[22] + a[16] * b[14] + a[17] * b[12] + a[18] * b[13] + a[19] * b[10] + a[20] * b[11] + a[21] * b[9] - a[22] * b[15] + a[23] * b[8] + a[24] * b[6] + a[25] * b[7] + a[26] * b[5] - a[27] * b[4] - a[28] * b[2] - a[29] * b[3] + a[30] * b[1] + a[31] * b[0];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::Mul<S> for ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1        = a[0] * other;
+        let result_e0       = a[1] * other;
+        let result_e1       = a[2] * other;
+        let result_e2       = a[3] * other;
+        let result_e3       = a[4] * other;
+        let result_einf     = a[5] * other;
+        let result_e01      = a[6] * other;
+        let result_e02      = a[7] * other;
+        let result_e03      = a[8] * other;
+        let result_e12      = a[9] * other;
+        let result_e23      = a[10] * other;
+        let result_e31      = a[11] * other;
+        let result_e1inf    = a[12] * other;
+        let result_e2inf    = a[13] * other;
+        let result_e3inf    = a[14] * other;
+        let result_e0inf    = a[15] * other;
+        let result_e012     = a[16] * other;
+        let result_e023     = a[17] * other;
+        let result_e031     = a[18] * other;
+        let result_e01inf   = a[19] * other;
+        let result_e02inf   = a[20] * other;
+        let result_e03inf   = a[21] * other;
+        let result_e123     = a[22] * other;
+        let result_e12inf   = a[23] * other;
+        let result_e23inf   = a[24] * other;
+        let result_e31inf   = a[25] * other;
+        let result_e0123    = a[26] * other;
+        let result_e012inf  = a[27] * other;
+        let result_e023inf  = a[28] * other;
+        let result_e031inf  = a[29] * other;
+        let result_e123inf  = a[30] * other;
+        let result_e0123inf = a[31] * other;
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::Mul<S> for &ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1        = a[0] * other;
+        let result_e0       = a[1] * other;
+        let result_e1       = a[2] * other;
+        let result_e2       = a[3] * other;
+        let result_e3       = a[4] * other;
+        let result_einf     = a[5] * other;
+        let result_e01      = a[6] * other;
+        let result_e02      = a[7] * other;
+        let result_e03      = a[8] * other;
+        let result_e12      = a[9] * other;
+        let result_e23      = a[10] * other;
+        let result_e31      = a[11] * other;
+        let result_e1inf    = a[12] * other;
+        let result_e2inf    = a[13] * other;
+        let result_e3inf    = a[14] * other;
+        let result_e0inf    = a[15] * other;
+        let result_e012     = a[16] * other;
+        let result_e023     = a[17] * other;
+        let result_e031     = a[18] * other;
+        let result_e01inf   = a[19] * other;
+        let result_e02inf   = a[20] * other;
+        let result_e03inf   = a[21] * other;
+        let result_e123     = a[22] * other;
+        let result_e12inf   = a[23] * other;
+        let result_e23inf   = a[24] * other;
+        let result_e31inf   = a[25] * other;
+        let result_e0123    = a[26] * other;
+        let result_e012inf  = a[27] * other;
+        let result_e023inf  = a[28] * other;
+        let result_e031inf  = a[29] * other;
+        let result_e123inf  = a[30] * other;
+        let result_e0123inf = a[31] * other;
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::BitXor<ConformalMultivector3<S>> for ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: ConformalMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1        = a[0] * b[0];
+        let result_e0       = a[0] * b[1] + a[1] * b[0];
+        let result_e1       = a[0] * b[2] + a[2] * b[0];
+        let result_e2       = a[0] * b[3] + a[3] * b[0];
+        let result_e3       = a[0] * b[4] + a[4] * b[0];
+        let result_einf     = a[0] * b[5] + a[5] * b[0];
+        let result_e01      = a[0] * b[6] + a[1] * b[2] - a[2] * b[1] + a[6] * b[0];
+        let result_e02      = a[0] * b[7] + a[1] * b[3] - a[3] * b[1] + a[7] * b[0];
+        let result_e03      = a[0] * b[8] + a[1] * b[4] - a[4] * b[1] + a[8] * b[0];
+        let result_e12      = a[0] * b[9] + a[2] * b[3] - a[3] * b[2] + a[9] * b[0];
+        let result_e23      = a[0] * b[10] + a[3] * b[4] - a[4] * b[3] + a[10] * b[0];
+        let result_e31      = a[0] * b[11] - a[2] * b[4] + a[4] * b[2] + a[11] * b[0];
+        let result_e1inf    = a[0] * b[12] + a[2] * b[5] - a[5] * b[2] + a[12] * b[0];
+        let result_e2inf    = a[0] * b[13] + a[3] * b[5] - a[5] * b[3] + a[13] * b[0];
+        let result_e3inf    = a[0] * b[14] + a[4] * b[5] - a[5] * b[4] + a[14] * b[0];
+        let result_e0inf    = a[0] * b[15] + a[1] * b[5] - a[5] * b[1] + a[15] * b[0];
+        let result_e012     = a[0] * b[16] + a[1] * b[9] - a[2] * b[7] + a[3] * b[6] + a[6] * b[3] - a[7] * b[2] + a[9] * b[1] + a[16] * b[0];
+        let result_e023     = a[0] * b[17] + a[1] * b[10] - a[3] * b[8] + a[4] * b[7] + a[7] * b[4] - a[8] * b[3] + a[10] * b[1] + a[17] * b[0];
+        let result_e031     = a[0] * b[18] + a[1] * b[11] + a[2] * b[8] - a[4] * b[6] - a[6] * b[4] + a[8] * b[2] + a[11] * b[1] + a[18] * b[0];
+        let result_e01inf   = a[0] * b[19] + a[1] * b[12] - a[2] * b[15] + a[5] * b[6] + a[6] * b[5] + a[12] * b[1] - a[15] * b[2] + a[19] * b[0];
+        let result_e02inf   = a[0] * b[20] + a[1] * b[13] - a[3] * b[15] + a[5] * b[7] + a[7] * b[5] + a[13] * b[1] - a[15] * b[3] + a[20] * b[0];
+        let result_e03inf   = a[0] * b[21] + a[1] * b[14] - a[4] * b[15] + a[5] * b[8] + a[8] * b[5] + a[14] * b[1] - a[15] * b[4] + a[21] * b[0];
+        let result_e123     = a[0] * b[22] + a[2] * b[10] + a[3] * b[11] + a[4] * b[9] + a[9] * b[4] + a[10] * b[2] + a[11] * b[3] + a[22] * b[0];
+        let result_e12inf   = a[0] * b[23] + a[2] * b[13] - a[3] * b[12] + a[5] * b[9] + a[9] * b[5] - a[12] * b[3] + a[13] * b[2] + a[23] * b[0];
+        let result_e23inf   = a[0] * b[24] + a[3] * b[14] - a[4] * b[13] + a[5] * b[10] + a[10] * b[5] - a[13] * b[4] + a[14] * b[3] + a[24] * b[0];
+        let result_e31inf   = a[0] * b[25] - a[2] * b[14] + a[4] * b[12] + a[5] * b[11] + a[11] * b[5] + a[12] * b[4] - a[14] * b[2] + a[25] * b[0];
+        let result_e0123    = a[0] * b[26] + a[1] * b[22] - a[2] * b[17] - a[3] * b[18] - a[4] * b[16] + a[6] * b[10] + a[7] * b[11] + a[8] * b[9] + a[9] * b[8] + a[10] * b[6] + a[11] * b[7] + a[16] * b[4] + a[17] * b[2] + a[18] * b[3] - a[22] * b[1] + a[26] * b[0];
+        let result_e012inf  = a[0] * b[27] + a[1] * b[23] - a[2] * b[20] + a[3] * b[19] - a[5] * b[16] + a[6] * b[13] - a[7] * b[12] + a[9] * b[15] - a[12] * b[7] + a[13] * b[6] + a[15] * b[9] + a[16] * b[5] - a[19] * b[3] + a[20] * b[2] - a[23] * b[1] + a[27] * b[0];
+        let result_e023inf  = a[0] * b[28] + a[1] * b[24] - a[3] * b[21] + a[4] * b[20] - a[5] * b[17] + a[7] * b[14] - a[8] * b[13] + a[10] * b[15] - a[13] * b[8] + a[14] * b[7] + a[15] * b[10] + a[17] * b[5] - a[20] * b[4] + a[21] * b[3] - a[24] * b[1] + a[28] * b[0];
+        let result_e031inf  = a[0] * b[29] + a[1] * b[25] + a[2] * b[21] - a[4] * b[19] - a[5] * b[18] - a[6] * b[14] + a[8] * b[12] + a[11] * b[15] + a[12] * b[8] - a[14] * b[6] + a[15] * b[11] + a[18] * b[5] + a[19] * b[4] - a[21] * b[2] - a[25] * b[1] + a[29] * b[0];
+        let result_e123inf  = a[0] * b[30] + a[2] * b[24] + a[3] * b[25] + a[4] * b[23] - a[5] * b[22] + a[9] * b[14] + a[10] * b[12] + a[11] * b[13] + a[12] * b[10] + a[13] * b[11] + a[14] * b[9] + a[22] * b[5] - a[23] * b[4] - a[24] * b[2] - a[25] * b[3] + a[30] * b[0];
+        let result_e0123inf = a[0] * b[31] + a[1] * b[30] - a[2] * b[28] - a[3] * b[29] - a[4] * b[27] + a[5] * b[26] + a[6] * b[24] + a[7] * b[25] + a[8] * b[23] + a[9] * b[21] + a[10] * b[19] + a[11] * b[20] + a[12] * b[17] + a[13] * b[18] + a[14] * b[16] - a[15] * b[22] + a[16] * b[14] + a[17] * b[12] + a[18] * b[13] + a[19] * b[10] + a[20] * b[11] + a[21] * b[9] - a[22] * b[15] + a[23] * b[8] + a[24] * b[6] + a[25] * b[7] + a[26] * b[5] - a[27] * b[4] - a[28] * b[2] - a[29] * b[3] + a[30] * b[1] + a[31] * b[0];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::BitXor<&ConformalMultivector3<S>> for ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: &ConformalMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1        = a[0] * b[0];
+        let result_e0       = a[0] * b[1] + a[1] * b[0];
+        let result_e1       = a[0] * b[2] + a[2] * b[0];
+        let result_e2       = a[0] * b[3] + a[3] * b[0];
+        let result_e3       = a[0] * b[4] + a[4] * b[0];
+        let result_einf     = a[0] * b[5] + a[5] * b[0];
+        let result_e01      = a[0] * b[6] + a[1] * b[2] - a[2] * b[1] + a[6] * b[0];
+        let result_e02      = a[0] * b[7] + a[1] * b[3] - a[3] * b[1] + a[7] * b[0];
+        let result_e03      = a[0] * b[8] + a[1] * b[4] - a[4] * b[1] + a[8] * b[0];
+        let result_e12      = a[0] * b[9] + a[2] * b[3] - a[3] * b[2] + a[9] * b[0];
+        let result_e23      = a[0] * b[10] + a[3] * b[4] - a[4] * b[3] + a[10] * b[0];
+        let result_e31      = a[0] * b[11] - a[2] * b[4] + a[4] * b[2] + a[11] * b[0];
+        let result_e1inf    = a[0] * b[12] + a[2] * b[5] - a[5] * b[2] + a[12] * b[0];
+        let result_e2inf    = a[0] * b[13] + a[3] * b[5] - a[5] * b[3] + a[13] * b[0];
+        let result_e3inf    = a[0] * b[14] + a[4] * b[5] - a[5] * b[4] + a[14] * b[0];
+        let result_e0inf    = a[0] * b[15] + a[1] * b[5] - a[5] * b[1] + a[15] * b[0];
+        let result_e012     = a[0] * b[16] + a[1] * b[9] - a[2] * b[7] + a[3] * b[6] + a[6] * b[3] - a[7] * b[2] + a[9] * b[1] + a[16] * b[0];
+        let result_e023     = a[0] * b[17] + a[1] * b[10] - a[3] * b[8] + a[4] * b[7] + a[7] * b[4] - a[8] * b[3] + a[10] * b[1] + a[17] * b[0];
+        let result_e031     = a[0] * b[18] + a[1] * b[11] + a[2] * b[8] - a[4] * b[6] - a[6] * b[4] + a[8] * b[2] + a[11] * b[1] + a[18] * b[0];
+        let result_e01inf   = a[0] * b[19] + a[1] * b[12] - a[2] * b[15] + a[5] * b[6] + a[6] * b[5] + a[12] * b[1] - a[15] * b[2] + a[19] * b[0];
+        let result_e02inf   = a[0] * b[20] + a[1] * b[13] - a[3] * b[15] + a[5] * b[7] + a[7] * b[5] + a[13] * b[1] - a[15] * b[3] + a[20] * b[0];
+        let result_e03inf   = a[0] * b[21] + a[1] * b[14] - a[4] * b[15] + a[5] * b[8] + a[8] * b[5] + a[14] * b[1] - a[15] * b[4] + a[21] * b[0];
+        let result_e123     = a[0] * b[22] + a[2] * b[10] + a[3] * b[11] + a[4] * b[9] + a[9] * b[4] + a[10] * b[2] + a[11] * b[3] + a[22] * b[0];
+        let result_e12inf   = a[0] * b[23] + a[2] * b[13] - a[3] * b[12] + a[5] * b[9] + a[9] * b[5] - a[12] * b[3] + a[13] * b[2] + a[23] * b[0];
+        let result_e23inf   = a[0] * b[24] + a[3] * b[14] - a[4] * b[13] + a[5] * b[10] + a[10] * b[5] - a[13] * b[4] + a[14] * b[3] + a[24] * b[0];
+        let result_e31inf   = a[0] * b[25] - a[2] * b[14] + a[4] * b[12] + a[5] * b[11] + a[11] * b[5] + a[12] * b[4] - a[14] * b[2] + a[25] * b[0];
+        let result_e0123    = a[0] * b[26] + a[1] * b[22] - a[2] * b[17] - a[3] * b[18] - a[4] * b[16] + a[6] * b[10] + a[7] * b[11] + a[8] * b[9] + a[9] * b[8] + a[10] * b[6] + a[11] * b[7] + a[16] * b[4] + a[17] * b[2] + a[18] * b[3] - a[22] * b[1] + a[26] * b[0];
+        let result_e012inf  = a[0] * b[27] + a[1] * b[23] - a[2] * b[20] + a[3] * b[19] - a[5] * b[16] + a[6] * b[13] - a[7] * b[12] + a[9] * b[15] - a[12] * b[7] + a[13] * b[6] + a[15] * b[9] + a[16] * b[5] - a[19] * b[3] + a[20] * b[2] - a[23] * b[1] + a[27] * b[0];
+        let result_e023inf  = a[0] * b[28] + a[1] * b[24] - a[3] * b[21] + a[4] * b[20] - a[5] * b[17] + a[7] * b[14] - a[8] * b[13] + a[10] * b[15] - a[13] * b[8] + a[14] * b[7] + a[15] * b[10] + a[17] * b[5] - a[20] * b[4] + a[21] * b[3] - a[24] * b[1] + a[28] * b[0];
+        let result_e031inf  = a[0] * b[29] + a[1] * b[25] + a[2] * b[21] - a[4] * b[19] - a[5] * b[18] - a[6] * b[14] + a[8] * b[12] + a[11] * b[15] + a[12] * b[8] - a[14] * b[6] + a[15] * b[11] + a[18] * b[5] + a[19] * b[4] - a[21] * b[2] - a[25] * b[1] + a[29] * b[0];
+        let result_e123inf  = a[0] * b[30] + a[2] * b[24] + a[3] * b[25] + a[4] * b[23] - a[5] * b[22] + a[9] * b[14] + a[10] * b[12] + a[11] * b[13] + a[12] * b[10] + a[13] * b[11] + a[14] * b[9] + a[22] * b[5] - a[23] * b[4] - a[24] * b[2] - a[25] * b[3] + a[30] * b[0];
+        let result_e0123inf = a[0] * b[31] + a[1] * b[30] - a[2] * b[28] - a[3] * b[29] - a[4] * b[27] + a[5] * b[26] + a[6] * b[24] + a[7] * b[25] + a[8] * b[23] + a[9] * b[21] + a[10] * b[19] + a[11] * b[20] + a[12] * b[17] + a[13] * b[18] + a[14] * b[16] - a[15] * b[22] + a[16] * b[14] + a[17] * b[12] + a[18] * b[13] + a[19] * b[10] + a[20] * b[11] + a[21] * b[9] - a[22] * b[15] + a[23] * b[8] + a[24] * b[6] + a[25] * b[7] + a[26] * b[5] - a[27] * b[4] - a[28] * b[2] - a[29] * b[3] + a[30] * b[1] + a[31] * b[0];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::BitXor<ConformalMultivector3<S>> for &ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: ConformalMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1        = a[0] * b[0];
+        let result_e0       = a[0] * b[1] + a[1] * b[0];
+        let result_e1       = a[0] * b[2] + a[2] * b[0];
+        let result_e2       = a[0] * b[3] + a[3] * b[0];
+        let result_e3       = a[0] * b[4] + a[4] * b[0];
+        let result_einf     = a[0] * b[5] + a[5] * b[0];
+        let result_e01      = a[0] * b[6] + a[1] * b[2] - a[2] * b[1] + a[6] * b[0];
+        let result_e02      = a[0] * b[7] + a[1] * b[3] - a[3] * b[1] + a[7] * b[0];
+        let result_e03      = a[0] * b[8] + a[1] * b[4] - a[4] * b[1] + a[8] * b[0];
+        let result_e12      = a[0] * b[9] + a[2] * b[3] - a[3] * b[2] + a[9] * b[0];
+        let result_e23      = a[0] * b[10] + a[3] * b[4] - a[4] * b[3] + a[10] * b[0];
+        let result_e31      = a[0] * b[11] - a[2] * b[4] + a[4] * b[2] + a[11] * b[0];
+        let result_e1inf    = a[0] * b[12] + a[2] * b[5] - a[5] * b[2] + a[12] * b[0];
+        let result_e2inf    = a[0] * b[13] + a[3] * b[5] - a[5] * b[3] + a[13] * b[0];
+        let result_e3inf    = a[0] * b[14] + a[4] * b[5] - a[5] * b[4] + a[14] * b[0];
+        let result_e0inf    = a[0] * b[15] + a[1] * b[5] - a[5] * b[1] + a[15] * b[0];
+        let result_e012     = a[0] * b[16] + a[1] * b[9] - a[2] * b[7] + a[3] * b[6] + a[6] * b[3] - a[7] * b[2] + a[9] * b[1] + a[16] * b[0];
+        let result_e023     = a[0] * b[17] + a[1] * b[10] - a[3] * b[8] + a[4] * b[7] + a[7] * b[4] - a[8] * b[3] + a[10] * b[1] + a[17] * b[0];
+        let result_e031     = a[0] * b[18] + a[1] * b[11] + a[2] * b[8] - a[4] * b[6] - a[6] * b[4] + a[8] * b[2] + a[11] * b[1] + a[18] * b[0];
+        let result_e01inf   = a[0] * b[19] + a[1] * b[12] - a[2] * b[15] + a[5] * b[6] + a[6] * b[5] + a[12] * b[1] - a[15] * b[2] + a[19] * b[0];
+        let result_e02inf   = a[0] * b[20] + a[1] * b[13] - a[3] * b[15] + a[5] * b[7] + a[7] * b[5] + a[13] * b[1] - a[15] * b[3] + a[20] * b[0];
+        let result_e03inf   = a[0] * b[21] + a[1] * b[14] - a[4] * b[15] + a[5] * b[8] + a[8] * b[5] + a[14] * b[1] - a[15] * b[4] + a[21] * b[0];
+        let result_e123     = a[0] * b[22] + a[2] * b[10] + a[3] * b[11] + a[4] * b[9] + a[9] * b[4] + a[10] * b[2] + a[11] * b[3] + a[22] * b[0];
+        let result_e12inf   = a[0] * b[23] + a[2] * b[13] - a[3] * b[12] + a[5] * b[9] + a[9] * b[5] - a[12] * b[3] + a[13] * b[2] + a[23] * b[0];
+        let result_e23inf   = a[0] * b[24] + a[3] * b[14] - a[4] * b[13] + a[5] * b[10] + a[10] * b[5] - a[13] * b[4] + a[14] * b[3] + a[24] * b[0];
+        let result_e31inf   = a[0] * b[25] - a[2] * b[14] + a[4] * b[12] + a[5] * b[11] + a[11] * b[5] + a[12] * b[4] - a[14] * b[2] + a[25] * b[0];
+        let result_e0123    = a[0] * b[26] + a[1] * b[22] - a[2] * b[17] - a[3] * b[18] - a[4] * b[16] + a[6] * b[10] + a[7] * b[11] + a[8] * b[9] + a[9] * b[8] + a[10] * b[6] + a[11] * b[7] + a[16] * b[4] + a[17] * b[2] + a[18] * b[3] - a[22] * b[1] + a[26] * b[0];
+        let result_e012inf  = a[0] * b[27] + a[1] * b[23] - a[2] * b[20] + a[3] * b[19] - a[5] * b[16] + a[6] * b[13] - a[7] * b[12] + a[9] * b[15] - a[12] * b[7] + a[13] * b[6] + a[15] * b[9] + a[16] * b[5] - a[19] * b[3] + a[20] * b[2] - a[23] * b[1] + a[27] * b[0];
+        let result_e023inf  = a[0] * b[28] + a[1] * b[24] - a[3] * b[21] + a[4] * b[20] - a[5] * b[17] + a[7] * b[14] - a[8] * b[13] + a[10] * b[15] - a[13] * b[8] + a[14] * b[7] + a[15] * b[10] + a[17] * b[5] - a[20] * b[4] + a[21] * b[3] - a[24] * b[1] + a[28] * b[0];
+        let result_e031inf  = a[0] * b[29] + a[1] * b[25] + a[2] * b[21] - a[4] * b[19] - a[5] * b[18] - a[6] * b[14] + a[8] * b[12] + a[11] * b[15] + a[12] * b[8] - a[14] * b[6] + a[15] * b[11] + a[18] * b[5] + a[19] * b[4] - a[21] * b[2] - a[25] * b[1] + a[29] * b[0];
+        let result_e123inf  = a[0] * b[30] + a[2] * b[24] + a[3] * b[25] + a[4] * b[23] - a[5] * b[22] + a[9] * b[14] + a[10] * b[12] + a[11] * b[13] + a[12] * b[10] + a[13] * b[11] + a[14] * b[9] + a[22] * b[5] - a[23] * b[4] - a[24] * b[2] - a[25] * b[3] + a[30] * b[0];
+        let result_e0123inf = a[0] * b[31] + a[1] * b[30] - a[2] * b[28] - a[3] * b[29] - a[4] * b[27] + a[5] * b[26] + a[6] * b[24] + a[7] * b[25] + a[8] * b[23] + a[9] * b[21] + a[10] * b[19] + a[11] * b[20] + a[12] * b[17] + a[13] * b[18] + a[14] * b[16] - a[15] * b[22] + a[16] * b[14] + a[17] * b[12] + a[18] * b[13] + a[19] * b[10] + a[20] * b[11] + a[21] * b[9] - a[22] * b[15] + a[23] * b[8] + a[24] * b[6] + a[25] * b[7] + a[26] * b[5] - a[27] * b[4] - a[28] * b[2] - a[29] * b[3] + a[30] * b[1] + a[31] * b[0];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::BitXor<&ConformalMultivector3<S>> for &ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: &ConformalMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1        = a[0] * b[0];
+        let result_e0       = a[0] * b[1] + a[1] * b[0];
+        let result_e1       = a[0] * b[2] + a[2] * b[0];
+        let result_e2       = a[0] * b[3] + a[3] * b[0];
+        let result_e3       = a[0] * b[4] + a[4] * b[0];
+        let result_einf     = a[0] * b[5] + a[5] * b[0];
+        let result_e01      = a[0] * b[6] + a[1] * b[2] - a[2] * b[1] + a[6] * b[0];
+        let result_e02      = a[0] * b[7] + a[1] * b[3] - a[3] * b[1] + a[7] * b[0];
+        let result_e03      = a[0] * b[8] + a[1] * b[4] - a[4] * b[1] + a[8] * b[0];
+        let result_e12      = a[0] * b[9] + a[2] * b[3] - a[3] * b[2] + a[9] * b[0];
+        let result_e23      = a[0] * b[10] + a[3] * b[4] - a[4] * b[3] + a[10] * b[0];
+        let result_e31      = a[0] * b[11] - a[2] * b[4] + a[4] * b[2] + a[11] * b[0];
+        let result_e1inf    = a[0] * b[12] + a[2] * b[5] - a[5] * b[2] + a[12] * b[0];
+        let result_e2inf    = a[0] * b[13] + a[3] * b[5] - a[5] * b[3] + a[13] * b[0];
+        let result_e3inf    = a[0] * b[14] + a[4] * b[5] - a[5] * b[4] + a[14] * b[0];
+        let result_e0inf    = a[0] * b[15] + a[1] * b[5] - a[5] * b[1] + a[15] * b[0];
+        let result_e012     = a[0] * b[16] + a[1] * b[9] - a[2] * b[7] + a[3] * b[6] + a[6] * b[3] - a[7] * b[2] + a[9] * b[1] + a[16] * b[0];
+        let result_e023     = a[0] * b[17] + a[1] * b[10] - a[3] * b[8] + a[4] * b[7] + a[7] * b[4] - a[8] * b[3] + a[10] * b[1] + a[17] * b[0];
+        let result_e031     = a[0] * b[18] + a[1] * b[11] + a[2] * b[8] - a[4] * b[6] - a[6] * b[4] + a[8] * b[2] + a[11] * b[1] + a[18] * b[0];
+        let result_e01inf   = a[0] * b[19] + a[1] * b[12] - a[2] * b[15] + a[5] * b[6] + a[6] * b[5] + a[12] * b[1] - a[15] * b[2] + a[19] * b[0];
+        let result_e02inf   = a[0] * b[20] + a[1] * b[13] - a[3] * b[15] + a[5] * b[7] + a[7] * b[5] + a[13] * b[1] - a[15] * b[3] + a[20] * b[0];
+        let result_e03inf   = a[0] * b[21] + a[1] * b[14] - a[4] * b[15] + a[5] * b[8] + a[8] * b[5] + a[14] * b[1] - a[15] * b[4] + a[21] * b[0];
+        let result_e123     = a[0] * b[22] + a[2] * b[10] + a[3] * b[11] + a[4] * b[9] + a[9] * b[4] + a[10] * b[2] + a[11] * b[3] + a[22] * b[0];
+        let result_e12inf   = a[0] * b[23] + a[2] * b[13] - a[3] * b[12] + a[5] * b[9] + a[9] * b[5] - a[12] * b[3] + a[13] * b[2] + a[23] * b[0];
+        let result_e23inf   = a[0] * b[24] + a[3] * b[14] - a[4] * b[13] + a[5] * b[10] + a[10] * b[5] - a[13] * b[4] + a[14] * b[3] + a[24] * b[0];
+        let result_e31inf   = a[0] * b[25] - a[2] * b[14] + a[4] * b[12] + a[5] * b[11] + a[11] * b[5] + a[12] * b[4] - a[14] * b[2] + a[25] * b[0];
+        let result_e0123    = a[0] * b[26] + a[1] * b[22] - a[2] * b[17] - a[3] * b[18] - a[4] * b[16] + a[6] * b[10] + a[7] * b[11] + a[8] * b[9] + a[9] * b[8] + a[10] * b[6] + a[11] * b[7] + a[16] * b[4] + a[17] * b[2] + a[18] * b[3] - a[22] * b[1] + a[26] * b[0];
+        let result_e012inf  = a[0] * b[27] + a[1] * b[23] - a[2] * b[20] + a[3] * b[19] - a[5] * b[16] + a[6] * b[13] - a[7] * b[12] + a[9] * b[15] - a[12] * b[7] + a[13] * b[6] + a[15] * b[9] + a[16] * b[5] - a[19] * b[3] + a[20] * b[2] - a[23] * b[1] + a[27] * b[0];
+        let result_e023inf  = a[0] * b[28] + a[1] * b[24] - a[3] * b[21] + a[4] * b[20] - a[5] * b[17] + a[7] * b[14] - a[8] * b[13] + a[10] * b[15] - a[13] * b[8] + a[14] * b[7] + a[15] * b[10] + a[17] * b[5] - a[20] * b[4] + a[21] * b[3] - a[24] * b[1] + a[28] * b[0];
+        let result_e031inf  = a[0] * b[29] + a[1] * b[25] + a[2] * b[21] - a[4] * b[19] - a[5] * b[18] - a[6] * b[14] + a[8] * b[12] + a[11] * b[15] + a[12] * b[8] - a[14] * b[6] + a[15] * b[11] + a[18] * b[5] + a[19] * b[4] - a[21] * b[2] - a[25] * b[1] + a[29] * b[0];
+        let result_e123inf  = a[0] * b[30] + a[2] * b[24] + a[3] * b[25] + a[4] * b[23] - a[5] * b[22] + a[9] * b[14] + a[10] * b[12] + a[11] * b[13] + a[12] * b[10] + a[13] * b[11] + a[14] * b[9] + a[22] * b[5] - a[23] * b[4] - a[24] * b[2] - a[25] * b[3] + a[30] * b[0];
+        let result_e0123inf = a[0] * b[31] + a[1] * b[30] - a[2] * b[28] - a[3] * b[29] - a[4] * b[27] + a[5] * b[26] + a[6] * b[24] + a[7] * b[25] + a[8] * b[23] + a[9] * b[21] + a[10] * b[19] + a[11] * b[20] + a[12] * b[17] + a[13] * b[18] + a[14] * b[16] - a[15] * b[22] + a[16] * b[14] + a[17] * b[12] + a[18] * b[13] + a[19] * b[10] + a[20] * b[11] + a[21] * b[9] - a[22] * b[15] + a[23] * b[8] + a[24] * b[6] + a[25] * b[7] + a[26] * b[5] - a[27] * b[4] - a[28] * b[2] - a[29] * b[3] + a[30] * b[1] + a[31] * b[0];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::BitXor<S> for ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1        = a[0] * other;
+        let result_e0       = a[1] * other;
+        let result_e1       = a[2] * other;
+        let result_e2       = a[3] * other;
+        let result_e3       = a[4] * other;
+        let result_einf     = a[5] * other;
+        let result_e01      = a[6] * other;
+        let result_e02      = a[7] * other;
+        let result_e03      = a[8] * other;
+        let result_e12      = a[9] * other;
+        let result_e23      = a[10] * other;
+        let result_e31      = a[11] * other;
+        let result_e1inf    = a[12] * other;
+        let result_e2inf    = a[13] * other;
+        let result_e3inf    = a[14] * other;
+        let result_e0inf    = a[15] * other;
+        let result_e012     = a[16] * other;
+        let result_e023     = a[17] * other;
+        let result_e031     = a[18] * other;
+        let result_e01inf   = a[19] * other;
+        let result_e02inf   = a[20] * other;
+        let result_e03inf   = a[21] * other;
+        let result_e123     = a[22] * other;
+        let result_e12inf   = a[23] * other;
+        let result_e23inf   = a[24] * other;
+        let result_e31inf   = a[25] * other;
+        let result_e0123    = a[26] * other;
+        let result_e012inf  = a[27] * other;
+        let result_e023inf  = a[28] * other;
+        let result_e031inf  = a[29] * other;
+        let result_e123inf  = a[30] * other;
+        let result_e0123inf = a[31] * other;
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::BitXor<S> for &ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1        = a[0] * other;
+        let result_e0       = a[1] * other;
+        let result_e1       = a[2] * other;
+        let result_e2       = a[3] * other;
+        let result_e3       = a[4] * other;
+        let result_einf     = a[5] * other;
+        let result_e01      = a[6] * other;
+        let result_e02      = a[7] * other;
+        let result_e03      = a[8] * other;
+        let result_e12      = a[9] * other;
+        let result_e23      = a[10] * other;
+        let result_e31      = a[11] * other;
+        let result_e1inf    = a[12] * other;
+        let result_e2inf    = a[13] * other;
+        let result_e3inf    = a[14] * other;
+        let result_e0inf    = a[15] * other;
+        let result_e012     = a[16] * other;
+        let result_e023     = a[17] * other;
+        let result_e031     = a[18] * other;
+        let result_e01inf   = a[19] * other;
+        let result_e02inf   = a[20] * other;
+        let result_e03inf   = a[21] * other;
+        let result_e123     = a[22] * other;
+        let result_e12inf   = a[23] * other;
+        let result_e23inf   = a[24] * other;
+        let result_e31inf   = a[25] * other;
+        let result_e0123    = a[26] * other;
+        let result_e012inf  = a[27] * other;
+        let result_e023inf  = a[28] * other;
+        let result_e031inf  = a[29] * other;
+        let result_e123inf  = a[30] * other;
+        let result_e0123inf = a[31] * other;
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::BitAnd<ConformalMultivector3<S>> for ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitand(self, other: ConformalMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1        = a[0] * b[31] + a[1] * b[30] - a[2] * b[28] - a[3] * b[29] - a[4] * b[27] + a[5] * b[26] + a[6] * b[24] + a[7] * b[25] + a[8] * b[23] + a[9] * b[21] + a[10] * b[19] + a[11] * b[20] + a[12] * b[17] + a[13] * b[18] + a[14] * b[16] - a[15] * b[22] + a[16] * b[14] + a[17] * b[12] + a[18] * b[13] + a[19] * b[10] + a[20] * b[11] + a[21] * b[9] - a[22] * b[15] + a[23] * b[8] + a[24] * b[6] + a[25] * b[7] + a[26] * b[5] - a[27] * b[4] - a[28] * b[2] - a[29] * b[3] + a[30] * b[1] + a[31] * b[0];
+        let result_e0       = a[1] * b[31] - a[6] * b[28] - a[7] * b[29] - a[8] * b[27] + a[15] * b[26] + a[16] * b[21] + a[17] * b[19] + a[18] * b[20] + a[19] * b[17] + a[20] * b[18] + a[21] * b[16] - a[26] * b[15] + a[27] * b[8] + a[28] * b[6] + a[29] * b[7] + a[31] * b[1];
+        let result_e1       = a[2] * b[31] - a[6] * b[30] - a[9] * b[29] + a[11] * b[27] + a[12] * b[26] - a[16] * b[25] + a[18] * b[23] + a[19] * b[22] + a[22] * b[19] + a[23] * b[18] - a[25] * b[16] - a[26] * b[12] - a[27] * b[11] + a[29] * b[9] + a[30] * b[6] + a[31] * b[2];
+        let result_e2       = a[3] * b[31] - a[7] * b[30] + a[9] * b[28] - a[10] * b[27] + a[13] * b[26] + a[16] * b[24] - a[17] * b[23] + a[20] * b[22] + a[22] * b[20] - a[23] * b[17] + a[24] * b[16] - a[26] * b[13] + a[27] * b[10] - a[28] * b[9] + a[30] * b[7] + a[31] * b[3];
+        let result_e3       = a[4] * b[31] - a[8] * b[30] + a[10] * b[29] - a[11] * b[28] + a[14] * b[26] + a[17] * b[25] - a[18] * b[24] + a[21] * b[22] + a[22] * b[21] - a[24] * b[18] + a[25] * b[17] - a[26] * b[14] + a[28] * b[11] - a[29] * b[10] + a[30] * b[8] + a[31] * b[4];
+        let result_einf     = a[5] * b[31] + a[12] * b[28] + a[13] * b[29] + a[14] * b[27] - a[15] * b[30] + a[19] * b[24] + a[20] * b[25] + a[21] * b[23] + a[23] * b[21] + a[24] * b[19] + a[25] * b[20] - a[27] * b[14] - a[28] * b[12] - a[29] * b[13] + a[30] * b[15] + a[31] * b[5];
+        let result_e01      = a[6] * b[31] - a[16] * b[29] + a[18] * b[27] + a[19] * b[26] + a[26] * b[19] + a[27] * b[18] - a[29] * b[16] + a[31] * b[6];
+        let result_e02      = a[7] * b[31] + a[16] * b[28] - a[17] * b[27] + a[20] * b[26] + a[26] * b[20] - a[27] * b[17] + a[28] * b[16] + a[31] * b[7];
+        let result_e03      = a[8] * b[31] + a[17] * b[29] - a[18] * b[28] + a[21] * b[26] + a[26] * b[21] - a[28] * b[18] + a[29] * b[17] + a[31] * b[8];
+        let result_e12      = a[9] * b[31] + a[16] * b[30] - a[22] * b[27] + a[23] * b[26] + a[26] * b[23] - a[27] * b[22] + a[30] * b[16] + a[31] * b[9];
+        let result_e23      = a[10] * b[31] + a[17] * b[30] - a[22] * b[28] + a[24] * b[26] + a[26] * b[24] - a[28] * b[22] + a[30] * b[17] + a[31] * b[10];
+        let result_e31      = a[11] * b[31] + a[18] * b[30] - a[22] * b[29] + a[25] * b[26] + a[26] * b[25] - a[29] * b[22] + a[30] * b[18] + a[31] * b[11];
+        let result_e1inf    = a[12] * b[31] + a[19] * b[30] + a[23] * b[29] - a[25] * b[27] - a[27] * b[25] + a[29] * b[23] + a[30] * b[19] + a[31] * b[12];
+        let result_e2inf    = a[13] * b[31] + a[20] * b[30] - a[23] * b[28] + a[24] * b[27] + a[27] * b[24] - a[28] * b[23] + a[30] * b[20] + a[31] * b[13];
+        let result_e3inf    = a[14] * b[31] + a[21] * b[30] - a[24] * b[29] + a[25] * b[28] + a[28] * b[25] - a[29] * b[24] + a[30] * b[21] + a[31] * b[14];
+        let result_e0inf    = a[15] * b[31] + a[19] * b[28] + a[20] * b[29] + a[21] * b[27] + a[27] * b[21] + a[28] * b[19] + a[29] * b[20] + a[31] * b[15];
+        let result_e012     = a[16] * b[31] - a[26] * b[27] + a[27] * b[26] + a[31] * b[16];
+        let result_e023     = a[17] * b[31] - a[26] * b[28] + a[28] * b[26] + a[31] * b[17];
+        let result_e031     = a[18] * b[31] - a[26] * b[29] + a[29] * b[26] + a[31] * b[18];
+        let result_e01inf   = a[19] * b[31] + a[27] * b[29] - a[29] * b[27] + a[31] * b[19];
+        let result_e02inf   = a[20] * b[31] - a[27] * b[28] + a[28] * b[27] + a[31] * b[20];
+        let result_e03inf   = a[21] * b[31] - a[28] * b[29] + a[29] * b[28] + a[31] * b[21];
+        let result_e123     = a[22] * b[31] - a[26] * b[30] + a[30] * b[26] + a[31] * b[22];
+        let result_e12inf   = a[23] * b[31] - a[27] * b[30] + a[30] * b[27] + a[31] * b[23];
+        let result_e23inf   = a[24] * b[31] - a[28] * b[30] + a[30] * b[28] + a[31] * b[24];
+        let result_e31inf   = a[25] * b[31] - a[29] * b[30] + a[30] * b[29] + a[31] * b[25];
+        let result_e0123    = a[26] * b[31] + a[31] * b[26];
+        let result_e012inf  = a[27] * b[31] + a[31] * b[27];
+        let result_e023inf  = a[28] * b[31] + a[31] * b[28];
+        let result_e031inf  = a[29] * b[31] + a[31] * b[29];
+        let result_e123inf  = a[30] * b[31] + a[31] * b[30];
+        let result_e0123inf = a[31] * b[31];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::BitAnd<&ConformalMultivector3<S>> for ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitand(self, other: &ConformalMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1        = a[0] * b[31] + a[1] * b[30] - a[2] * b[28] - a[3] * b[29] - a[4] * b[27] + a[5] * b[26] + a[6] * b[24] + a[7] * b[25] + a[8] * b[23] + a[9] * b[21] + a[10] * b[19] + a[11] * b[20] + a[12] * b[17] + a[13] * b[18] + a[14] * b[16] - a[15] * b[22] + a[16] * b[14] + a[17] * b[12] + a[18] * b[13] + a[19] * b[10] + a[20] * b[11] + a[21] * b[9] - a[22] * b[15] + a[23] * b[8] + a[24] * b[6] + a[25] * b[7] + a[26] * b[5] - a[27] * b[4] - a[28] * b[2] - a[29] * b[3] + a[30] * b[1] + a[31] * b[0];
+        let result_e0       = a[1] * b[31] - a[6] * b[28] - a[7] * b[29] - a[8] * b[27] + a[15] * b[26] + a[16] * b[21] + a[17] * b[19] + a[18] * b[20] + a[19] * b[17] + a[20] * b[18] + a[21] * b[16] - a[26] * b[15] + a[27] * b[8] + a[28] * b[6] + a[29] * b[7] + a[31] * b[1];
+        let result_e1       = a[2] * b[31] - a[6] * b[30] - a[9] * b[29] + a[11] * b[27] + a[12] * b[26] - a[16] * b[25] + a[18] * b[23] + a[19] * b[22] + a[22] * b[19] + a[23] * b[18] - a[25] * b[16] - a[26] * b[12] - a[27] * b[11] + a[29] * b[9] + a[30] * b[6] + a[31] * b[2];
+        let result_e2       = a[3] * b[31] - a[7] * b[30] + a[9] * b[28] - a[10] * b[27] + a[13] * b[26] + a[16] * b[24] - a[17] * b[23] + a[20] * b[22] + a[22] * b[20] - a[23] * b[17] + a[24] * b[16] - a[26] * b[13] + a[27] * b[10] - a[28] * b[9] + a[30] * b[7] + a[31] * b[3];
+        let result_e3       = a[4] * b[31] - a[8] * b[30] + a[10] * b[29] - a[11] * b[28] + a[14] * b[26] + a[17] * b[25] - a[18] * b[24] + a[21] * b[22] + a[22] * b[21] - a[24] * b[18] + a[25] * b[17] - a[26] * b[14] + a[28] * b[11] - a[29] * b[10] + a[30] * b[8] + a[31] * b[4];
+        let result_einf     = a[5] * b[31] + a[12] * b[28] + a[13] * b[29] + a[14] * b[27] - a[15] * b[30] + a[19] * b[24] + a[20] * b[25] + a[21] * b[23] + a[23] * b[21] + a[24] * b[19] + a[25] * b[20] - a[27] * b[14] - a[28] * b[12] - a[29] * b[13] + a[30] * b[15] + a[31] * b[5];
+        let result_e01      = a[6] * b[31] - a[16] * b[29] + a[18] * b[27] + a[19] * b[26] + a[26] * b[19] + a[27] * b[18] - a[29] * b[16] + a[31] * b[6];
+        let result_e02      = a[7] * b[31] + a[16] * b[28] - a[17] * b[27] + a[20] * b[26] + a[26] * b[20] - a[27] * b[17] + a[28] * b[16] + a[31] * b[7];
+        let result_e03      = a[8] * b[31] + a[17] * b[29] - a[18] * b[28] + a[21] * b[26] + a[26] * b[21] - a[28] * b[18] + a[29] * b[17] + a[31] * b[8];
+        let result_e12      = a[9] * b[31] + a[16] * b[30] - a[22] * b[27] + a[23] * b[26] + a[26] * b[23] - a[27] * b[22] + a[30] * b[16] + a[31] * b[9];
+        let result_e23      = a[10] * b[31] + a[17] * b[30] - a[22] * b[28] + a[24] * b[26] + a[26] * b[24] - a[28] * b[22] + a[30] * b[17] + a[31] * b[10];
+        let result_e31      = a[11] * b[31] + a[18] * b[30] - a[22] * b[29] + a[25] * b[26] + a[26] * b[25] - a[29] * b[22] + a[30] * b[18] + a[31] * b[11];
+        let result_e1inf    = a[12] * b[31] + a[19] * b[30] + a[23] * b[29] - a[25] * b[27] - a[27] * b[25] + a[29] * b[23] + a[30] * b[19] + a[31] * b[12];
+        let result_e2inf    = a[13] * b[31] + a[20] * b[30] - a[23] * b[28] + a[24] * b[27] + a[27] * b[24] - a[28] * b[23] + a[30] * b[20] + a[31] * b[13];
+        let result_e3inf    = a[14] * b[31] + a[21] * b[30] - a[24] * b[29] + a[25] * b[28] + a[28] * b[25] - a[29] * b[24] + a[30] * b[21] + a[31] * b[14];
+        let result_e0inf    = a[15] * b[31] + a[19] * b[28] + a[20] * b[29] + a[21] * b[27] + a[27] * b[21] + a[28] * b[19] + a[29] * b[20] + a[31] * b[15];
+        let result_e012     = a[16] * b[31] - a[26] * b[27] + a[27] * b[26] + a[31] * b[16];
+        let result_e023     = a[17] * b[31] - a[26] * b[28] + a[28] * b[26] + a[31] * b[17];
+        let result_e031     = a[18] * b[31] - a[26] * b[29] + a[29] * b[26] + a[31] * b[18];
+        let result_e01inf   = a[19] * b[31] + a[27] * b[29] - a[29] * b[27] + a[31] * b[19];
+        let result_e02inf   = a[20] * b[31] - a[27] * b[28] + a[28] * b[27] + a[31] * b[20];
+        let result_e03inf   = a[21] * b[31] - a[28] * b[29] + a[29] * b[28] + a[31] * b[21];
+        let result_e123     = a[22] * b[31] - a[26] * b[30] + a[30] * b[26] + a[31] * b[22];
+        let result_e12inf   = a[23] * b[31] - a[27] * b[30] + a[30] * b[27] + a[31] * b[23];
+        let result_e23inf   = a[24] * b[31] - a[28] * b[30] + a[30] * b[28] + a[31] * b[24];
+        let result_e31inf   = a[25] * b[31] - a[29] * b[30] + a[30] * b[29] + a[31] * b[25];
+        let result_e0123    = a[26] * b[31] + a[31] * b[26];
+        let result_e012inf  = a[27] * b[31] + a[31] * b[27];
+        let result_e023inf  = a[28] * b[31] + a[31] * b[28];
+        let result_e031inf  = a[29] * b[31] + a[31] * b[29];
+        let result_e123inf  = a[30] * b[31] + a[31] * b[30];
+        let result_e0123inf = a[31] * b[31];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::BitAnd<ConformalMultivector3<S>> for &ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitand(self, other: ConformalMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1        = a[0] * b[31] + a[1] * b[30] - a[2] * b[28] - a[3] * b[29] - a[4] * b[27] + a[5] * b[26] + a[6] * b[24] + a[7] * b[25] + a[8] * b[23] + a[9] * b[21] + a[10] * b[19] + a[11] * b[20] + a[12] * b[17] + a[13] * b[18] + a[14] * b[16] - a[15] * b[22] + a[16] * b[14] + a[17] * b[12] + a[18] * b[13] + a[19] * b[10] + a[20] * b[11] + a[21] * b[9] - a[22] * b[15] + a[23] * b[8] + a[24] * b[6] + a[25] * b[7] + a[26] * b[5] - a[27] * b[4] - a[28] * b[2] - a[29] * b[3] + a[30] * b[1] + a[31] * b[0];
+        let result_e0       = a[1] * b[31] - a[6] * b[28] - a[7] * b[29] - a[8] * b[27] + a[15] * b[26] + a[16] * b[21] + a[17] * b[19] + a[18] * b[20] + a[19] * b[17] + a[20] * b[18] + a[21] * b[16] - a[26] * b[15] + a[27] * b[8] + a[28] * b[6] + a[29] * b[7] + a[31] * b[1];
+        let result_e1       = a[2] * b[31] - a[6] * b[30] - a[9] * b[29] + a[11] * b[27] + a[12] * b[26] - a[16] * b[25] + a[18] * b[23] + a[19] * b[22] + a[22] * b[19] + a[23] * b[18] - a[25] * b[16] - a[26] * b[12] - a[27] * b[11] + a[29] * b[9] + a[30] * b[6] + a[31] * b[2];
+        let result_e2       = a[3] * b[31] - a[7] * b[30] + a[9] * b[28] - a[10] * b[27] + a[13] * b[26] + a[16] * b[24] - a[17] * b[23] + a[20] * b[22] + a[22] * b[20] - a[23] * b[17] + a[24] * b[16] - a[26] * b[13] + a[27] * b[10] - a[28] * b[9] + a[30] * b[7] + a[31] * b[3];
+        let result_e3       = a[4] * b[31] - a[8] * b[30] + a[10] * b[29] - a[11] * b[28] + a[14] * b[26] + a[17] * b[25] - a[18] * b[24] + a[21] * b[22] + a[22] * b[21] - a[24] * b[18] + a[25] * b[17] - a[26] * b[14] + a[28] * b[11] - a[29] * b[10] + a[30] * b[8] + a[31] * b[4];
+        let result_einf     = a[5] * b[31] + a[12] * b[28] + a[13] * b[29] + a[14] * b[27] - a[15] * b[30] + a[19] * b[24] + a[20] * b[25] + a[21] * b[23] + a[23] * b[21] + a[24] * b[19] + a[25] * b[20] - a[27] * b[14] - a[28] * b[12] - a[29] * b[13] + a[30] * b[15] + a[31] * b[5];
+        let result_e01      = a[6] * b[31] - a[16] * b[29] + a[18] * b[27] + a[19] * b[26] + a[26] * b[19] + a[27] * b[18] - a[29] * b[16] + a[31] * b[6];
+        let result_e02      = a[7] * b[31] + a[16] * b[28] - a[17] * b[27] + a[20] * b[26] + a[26] * b[20] - a[27] * b[17] + a[28] * b[16] + a[31] * b[7];
+        let result_e03      = a[8] * b[31] + a[17] * b[29] - a[18] * b[28] + a[21] * b[26] + a[26] * b[21] - a[28] * b[18] + a[29] * b[17] + a[31] * b[8];
+        let result_e12      = a[9] * b[31] + a[16] * b[30] - a[22] * b[27] + a[23] * b[26] + a[26] * b[23] - a[27] * b[22] + a[30] * b[16] + a[31] * b[9];
+        let result_e23      = a[10] * b[31] + a[17] * b[30] - a[22] * b[28] + a[24] * b[26] + a[26] * b[24] - a[28] * b[22] + a[30] * b[17] + a[31] * b[10];
+        let result_e31      = a[11] * b[31] + a[18] * b[30] - a[22] * b[29] + a[25] * b[26] + a[26] * b[25] - a[29] * b[22] + a[30] * b[18] + a[31] * b[11];
+        let result_e1inf    = a[12] * b[31] + a[19] * b[30] + a[23] * b[29] - a[25] * b[27] - a[27] * b[25] + a[29] * b[23] + a[30] * b[19] + a[31] * b[12];
+        let result_e2inf    = a[13] * b[31] + a[20] * b[30] - a[23] * b[28] + a[24] * b[27] + a[27] * b[24] - a[28] * b[23] + a[30] * b[20] + a[31] * b[13];
+        let result_e3inf    = a[14] * b[31] + a[21] * b[30] - a[24] * b[29] + a[25] * b[28] + a[28] * b[25] - a[29] * b[24] + a[30] * b[21] + a[31] * b[14];
+        let result_e0inf    = a[15] * b[31] + a[19] * b[28] + a[20] * b[29] + a[21] * b[27] + a[27] * b[21] + a[28] * b[19] + a[29] * b[20] + a[31] * b[15];
+        let result_e012     = a[16] * b[31] - a[26] * b[27] + a[27] * b[26] + a[31] * b[16];
+        let result_e023     = a[17] * b[31] - a[26] * b[28] + a[28] * b[26] + a[31] * b[17];
+        let result_e031     = a[18] * b[31] - a[26] * b[29] + a[29] * b[26] + a[31] * b[18];
+        let result_e01inf   = a[19] * b[31] + a[27] * b[29] - a[29] * b[27] + a[31] * b[19];
+        let result_e02inf   = a[20] * b[31] - a[27] * b[28] + a[28] * b[27] + a[31] * b[20];
+        let result_e03inf   = a[21] * b[31] - a[28] * b[29] + a[29] * b[28] + a[31] * b[21];
+        let result_e123     = a[22] * b[31] - a[26] * b[30] + a[30] * b[26] + a[31] * b[22];
+        let result_e12inf   = a[23] * b[31] - a[27] * b[30] + a[30] * b[27] + a[31] * b[23];
+        let result_e23inf   = a[24] * b[31] - a[28] * b[30] + a[30] * b[28] + a[31] * b[24];
+        let result_e31inf   = a[25] * b[31] - a[29] * b[30] + a[30] * b[29] + a[31] * b[25];
+        let result_e0123    = a[26] * b[31] + a[31] * b[26];
+        let result_e012inf  = a[27] * b[31] + a[31] * b[27];
+        let result_e023inf  = a[28] * b[31] + a[31] * b[28];
+        let result_e031inf  = a[29] * b[31] + a[31] * b[29];
+        let result_e123inf  = a[30] * b[31] + a[31] * b[30];
+        let result_e0123inf = a[31] * b[31];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::BitAnd<&ConformalMultivector3<S>> for &ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitand(self, other: &ConformalMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1        = a[0] * b[31] + a[1] * b[30] - a[2] * b[28] - a[3] * b[29] - a[4] * b[27] + a[5] * b[26] + a[6] * b[24] + a[7] * b[25] + a[8] * b[23] + a[9] * b[21] + a[10] * b[19] + a[11] * b[20] + a[12] * b[17] + a[13] * b[18] + a[14] * b[16] - a[15] * b[22] + a[16] * b[14] + a[17] * b[12] + a[18] * b[13] + a[19] * b[10] + a[20] * b[11] + a[21] * b[9] - a[22] * b[15] + a[23] * b[8] + a[24] * b[6] + a[25] * b[7] + a[26] * b[5] - a[27] * b[4] - a[28] * b[2] - a[29] * b[3] + a[30] * b[1] + a[31] * b[0];
+        let result_e0       = a[1] * b[31] - a[6] * b[28] - a[7] * b[29] - a[8] * b[27] + a[15] * b[26] + a[16] * b[21] + a[17] * b[19] + a[18] * b[20] + a[19] * b[17] + a[20] * b[18] + a[21] * b[16] - a[26] * b[15] + a[27] * b[8] + a[28] * b[6] + a[29] * b[7] + a[31] * b[1];
+        let result_e1       = a[2] * b[31] - a[6] * b[30] - a[9] * b[29] + a[11] * b[27] + a[12] * b[26] - a[16] * b[25] + a[18] * b[23] + a[19] * b[22] + a[22] * b[19] + a[23] * b[18] - a[25] * b[16] - a[26] * b[12] - a[27] * b[11] + a[29] * b[9] + a[30] * b[6] + a[31] * b[2];
+        let result_e2       = a[3] * b[31] - a[7] * b[30] + a[9] * b[28] - a[10] * b[27] + a[13] * b[26] + a[16] * b[24] - a[17] * b[23] + a[20] * b[22] + a[22] * b[20] - a[23] * b[17] + a[24] * b[16] - a[26] * b[13] + a[27] * b[10] - a[28] * b[9] + a[30] * b[7] + a[31] * b[3];
+        let result_e3       = a[4] * b[31] - a[8] * b[30] + a[10] * b[29] - a[11] * b[28] + a[14] * b[26] + a[17] * b[25] - a[18] * b[24] + a[21] * b[22] + a[22] * b[21] - a[24] * b[18] + a[25] * b[17] - a[26] * b[14] + a[28] * b[11] - a[29] * b[10] + a[30] * b[8] + a[31] * b[4];
+        let result_einf     = a[5] * b[31] + a[12] * b[28] + a[13] * b[29] + a[14] * b[27] - a[15] * b[30] + a[19] * b[24] + a[20] * b[25] + a[21] * b[23] + a[23] * b[21] + a[24] * b[19] + a[25] * b[20] - a[27] * b[14] - a[28] * b[12] - a[29] * b[13] + a[30] * b[15] + a[31] * b[5];
+        let result_e01      = a[6] * b[31] - a[16] * b[29] + a[18] * b[27] + a[19] * b[26] + a[26] * b[19] + a[27] * b[18] - a[29] * b[16] + a[31] * b[6];
+        let result_e02      = a[7] * b[31] + a[16] * b[28] - a[17] * b[27] + a[20] * b[26] + a[26] * b[20] - a[27] * b[17] + a[28] * b[16] + a[31] * b[7];
+        let result_e03      = a[8] * b[31] + a[17] * b[29] - a[18] * b[28] + a[21] * b[26] + a[26] * b[21] - a[28] * b[18] + a[29] * b[17] + a[31] * b[8];
+        let result_e12      = a[9] * b[31] + a[16] * b[30] - a[22] * b[27] + a[23] * b[26] + a[26] * b[23] - a[27] * b[22] + a[30] * b[16] + a[31] * b[9];
+        let result_e23      = a[10] * b[31] + a[17] * b[30] - a[22] * b[28] + a[24] * b[26] + a[26] * b[24] - a[28] * b[22] + a[30] * b[17] + a[31] * b[10];
+        let result_e31      = a[11] * b[31] + a[18] * b[30] - a[22] * b[29] + a[25] * b[26] + a[26] * b[25] - a[29] * b[22] + a[30] * b[18] + a[31] * b[11];
+        let result_e1inf    = a[12] * b[31] + a[19] * b[30] + a[23] * b[29] - a[25] * b[27] - a[27] * b[25] + a[29] * b[23] + a[30] * b[19] + a[31] * b[12];
+        let result_e2inf    = a[13] * b[31] + a[20] * b[30] - a[23] * b[28] + a[24] * b[27] + a[27] * b[24] - a[28] * b[23] + a[30] * b[20] + a[31] * b[13];
+        let result_e3inf    = a[14] * b[31] + a[21] * b[30] - a[24] * b[29] + a[25] * b[28] + a[28] * b[25] - a[29] * b[24] + a[30] * b[21] + a[31] * b[14];
+        let result_e0inf    = a[15] * b[31] + a[19] * b[28] + a[20] * b[29] + a[21] * b[27] + a[27] * b[21] + a[28] * b[19] + a[29] * b[20] + a[31] * b[15];
+        let result_e012     = a[16] * b[31] - a[26] * b[27] + a[27] * b[26] + a[31] * b[16];
+        let result_e023     = a[17] * b[31] - a[26] * b[28] + a[28] * b[26] + a[31] * b[17];
+        let result_e031     = a[18] * b[31] - a[26] * b[29] + a[29] * b[26] + a[31] * b[18];
+        let result_e01inf   = a[19] * b[31] + a[27] * b[29] - a[29] * b[27] + a[31] * b[19];
+        let result_e02inf   = a[20] * b[31] - a[27] * b[28] + a[28] * b[27] + a[31] * b[20];
+        let result_e03inf   = a[21] * b[31] - a[28] * b[29] + a[29] * b[28] + a[31] * b[21];
+        let result_e123     = a[22] * b[31] - a[26] * b[30] + a[30] * b[26] + a[31] * b[22];
+        let result_e12inf   = a[23] * b[31] - a[27] * b[30] + a[30] * b[27] + a[31] * b[23];
+        let result_e23inf   = a[24] * b[31] - a[28] * b[30] + a[30] * b[28] + a[31] * b[24];
+        let result_e31inf   = a[25] * b[31] - a[29] * b[30] + a[30] * b[29] + a[31] * b[25];
+        let result_e0123    = a[26] * b[31] + a[31] * b[26];
+        let result_e012inf  = a[27] * b[31] + a[31] * b[27];
+        let result_e023inf  = a[28] * b[31] + a[31] * b[28];
+        let result_e031inf  = a[29] * b[31] + a[31] * b[29];
+        let result_e123inf  = a[30] * b[31] + a[31] * b[30];
+        let result_e0123inf = a[31] * b[31];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::BitOr<ConformalMultivector3<S>> for ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitor(self, other: ConformalMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1 = a[0] * b[0] - a[1] * b[5] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[5] * b[1] + a[6] * b[12] + a[7] * b[13] + a[8] * b[14] + a[9] * b[9] + a[10] * b[10] + a[11] * b[11] + a[12] * b[6] + a[13] * b[7] + a[14] * b[8] - a[15] * b[15] - a[16] * b[23] - a[17] * b[24] - a[18] * b[25] - a[19] * b[19] - a[20] * b[20] - a[21] * b[21] + a[22] * b[22] - a[23] * b[16] - a[24] * b[17] - a[25] * b[18] + a[26] * b[30] - a[27] * b[27] - a[28] * b[28] - a[29] * b[29] + a[30] * b[26] - a[31] * b[31];
+
+        ConformalMultivector3::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::BitOr<&ConformalMultivector3<S>> for ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitor(self, other: &ConformalMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1 = a[0] * b[0] - a[1] * b[5] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[5] * b[1] + a[6] * b[12] + a[7] * b[13] + a[8] * b[14] + a[9] * b[9] + a[10] * b[10] + a[11] * b[11] + a[12] * b[6] + a[13] * b[7] + a[14] * b[8] - a[15] * b[15] - a[16] * b[23] - a[17] * b[24] - a[18] * b[25] - a[19] * b[19] - a[20] * b[20] - a[21] * b[21] + a[22] * b[22] - a[23] * b[16] - a[24] * b[17] - a[25] * b[18] + a[26] * b[30] - a[27] * b[27] - a[28] * b[28] - a[29] * b[29] + a[30] * b[26] - a[31] * b[31];
+
+        ConformalMultivector3::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::BitOr<ConformalMultivector3<S>> for &ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitor(self, other: ConformalMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1 = a[0] * b[0] - a[1] * b[5] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[5] * b[1] + a[6] * b[12] + a[7] * b[13] + a[8] * b[14] + a[9] * b[9] + a[10] * b[10] + a[11] * b[11] + a[12] * b[6] + a[13] * b[7] + a[14] * b[8] - a[15] * b[15] - a[16] * b[23] - a[17] * b[24] - a[18] * b[25] - a[19] * b[19] - a[20] * b[20] - a[21] * b[21] + a[22] * b[22] - a[23] * b[16] - a[24] * b[17] - a[25] * b[18] + a[26] * b[30] - a[27] * b[27] - a[28] * b[28] - a[29] * b[29] + a[30] * b[26] - a[31] * b[31];
+
+        ConformalMultivector3::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::BitOr<&ConformalMultivector3<S>> for &ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitor(self, other: &ConformalMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1 = a[0] * b[0] - a[1] * b[5] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[5] * b[1] + a[6] * b[12] + a[7] * b[13] + a[8] * b[14] + a[9] * b[9] + a[10] * b[10] + a[11] * b[11] + a[12] * b[6] + a[13] * b[7] + a[14] * b[8] - a[15] * b[15] - a[16] * b[23] - a[17] * b[24] - a[18] * b[25] - a[19] * b[19] - a[20] * b[20] - a[21] * b[21] + a[22] * b[22] - a[23] * b[16] - a[24] * b[17] - a[25] * b[18] + a[26] * b[30] - a[27] * b[27] - a[28] * b[28] - a[29] * b[29] + a[30] * b[26] - a[31] * b[31];
+
+        ConformalMultivector3::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::BitOr<S> for ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn bitor(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1 = a[0] * other;
+
+        ConformalMultivector3::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::BitOr<S> for &ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn bitor(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1 = a[0] * other;
+
+        ConformalMultivector3::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::Add<ConformalMultivector3<S>> for ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: ConformalMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1        = a[0] + b[0];
+        let result_e0       = a[1] + b[1];
+        let result_e1       = a[2] + b[2];
+        let result_e2       = a[3] + b[3];
+        let result_e3       = a[4] + b[4];
+        let result_einf     = a[5] + b[5];
+        let result_e01      = a[6] + b[6];
+        let result_e02      = a[7] + b[7];
+        let result_e03      = a[8] + b[8];
+        let result_e12      = a[9] + b[9];
+        let result_e23      = a[10] + b[10];
+        let result_e31      = a[11] + b[11];
+        let result_e1inf    = a[12] + b[12];
+        let result_e2inf    = a[13] + b[13];
+        let result_e3inf    = a[14] + b[14];
+        let result_e0inf    = a[15] + b[15];
+        let result_e012     = a[16] + b[16];
+        let result_e023     = a[17] + b[17];
+        let result_e031     = a[18] + b[18];
+        let result_e01inf   = a[19] + b[19];
+        let result_e02inf   = a[20] + b[20];
+        let result_e03inf   = a[21] + b[21];
+        let result_e123     = a[22] + b[22];
+        let result_e12inf   = a[23] + b[23];
+        let result_e23inf   = a[24] + b[24];
+        let result_e31inf   = a[25] + b[25];
+        let result_e0123    = a[26] + b[26];
+        let result_e012inf  = a[27] + b[27];
+        let result_e023inf  = a[28] + b[28];
+        let result_e031inf  = a[29] + b[29];
+        let result_e123inf  = a[30] + b[30];
+        let result_e0123inf = a[31] + b[31];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::Add<&ConformalMultivector3<S>> for ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: &ConformalMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1        = a[0] + b[0];
+        let result_e0       = a[1] + b[1];
+        let result_e1       = a[2] + b[2];
+        let result_e2       = a[3] + b[3];
+        let result_e3       = a[4] + b[4];
+        let result_einf     = a[5] + b[5];
+        let result_e01      = a[6] + b[6];
+        let result_e02      = a[7] + b[7];
+        let result_e03      = a[8] + b[8];
+        let result_e12      = a[9] + b[9];
+        let result_e23      = a[10] + b[10];
+        let result_e31      = a[11] + b[11];
+        let result_e1inf    = a[12] + b[12];
+        let result_e2inf    = a[13] + b[13];
+        let result_e3inf    = a[14] + b[14];
+        let result_e0inf    = a[15] + b[15];
+        let result_e012     = a[16] + b[16];
+        let result_e023     = a[17] + b[17];
+        let result_e031     = a[18] + b[18];
+        let result_e01inf   = a[19] + b[19];
+        let result_e02inf   = a[20] + b[20];
+        let result_e03inf   = a[21] + b[21];
+        let result_e123     = a[22] + b[22];
+        let result_e12inf   = a[23] + b[23];
+        let result_e23inf   = a[24] + b[24];
+        let result_e31inf   = a[25] + b[25];
+        let result_e0123    = a[26] + b[26];
+        let result_e012inf  = a[27] + b[27];
+        let result_e023inf  = a[28] + b[28];
+        let result_e031inf  = a[29] + b[29];
+        let result_e123inf  = a[30] + b[30];
+        let result_e0123inf = a[31] + b[31];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::Add<ConformalMultivector3<S>> for &ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: ConformalMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1        = a[0] + b[0];
+        let result_e0       = a[1] + b[1];
+        let result_e1       = a[2] + b[2];
+        let result_e2       = a[3] + b[3];
+        let result_e3       = a[4] + b[4];
+        let result_einf     = a[5] + b[5];
+        let result_e01      = a[6] + b[6];
+        let result_e02      = a[7] + b[7];
+        let result_e03      = a[8] + b[8];
+        let result_e12      = a[9] + b[9];
+        let result_e23      = a[10] + b[10];
+        let result_e31      = a[11] + b[11];
+        let result_e1inf    = a[12] + b[12];
+        let result_e2inf    = a[13] + b[13];
+        let result_e3inf    = a[14] + b[14];
+        let result_e0inf    = a[15] + b[15];
+        let result_e012     = a[16] + b[16];
+        let result_e023     = a[17] + b[17];
+        let result_e031     = a[18] + b[18];
+        let result_e01inf   = a[19] + b[19];
+        let result_e02inf   = a[20] + b[20];
+        let result_e03inf   = a[21] + b[21];
+        let result_e123     = a[22] + b[22];
+        let result_e12inf   = a[23] + b[23];
+        let result_e23inf   = a[24] + b[24];
+        let result_e31inf   = a[25] + b[25];
+        let result_e0123    = a[26] + b[26];
+        let result_e012inf  = a[27] + b[27];
+        let result_e023inf  = a[28] + b[28];
+        let result_e031inf  = a[29] + b[29];
+        let result_e123inf  = a[30] + b[30];
+        let result_e0123inf = a[31] + b[31];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::Add<&ConformalMultivector3<S>> for &ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: &ConformalMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1        = a[0] + b[0];
+        let result_e0       = a[1] + b[1];
+        let result_e1       = a[2] + b[2];
+        let result_e2       = a[3] + b[3];
+        let result_e3       = a[4] + b[4];
+        let result_einf     = a[5] + b[5];
+        let result_e01      = a[6] + b[6];
+        let result_e02      = a[7] + b[7];
+        let result_e03      = a[8] + b[8];
+        let result_e12      = a[9] + b[9];
+        let result_e23      = a[10] + b[10];
+        let result_e31      = a[11] + b[11];
+        let result_e1inf    = a[12] + b[12];
+        let result_e2inf    = a[13] + b[13];
+        let result_e3inf    = a[14] + b[14];
+        let result_e0inf    = a[15] + b[15];
+        let result_e012     = a[16] + b[16];
+        let result_e023     = a[17] + b[17];
+        let result_e031     = a[18] + b[18];
+        let result_e01inf   = a[19] + b[19];
+        let result_e02inf   = a[20] + b[20];
+        let result_e03inf   = a[21] + b[21];
+        let result_e123     = a[22] + b[22];
+        let result_e12inf   = a[23] + b[23];
+        let result_e23inf   = a[24] + b[24];
+        let result_e31inf   = a[25] + b[25];
+        let result_e0123    = a[26] + b[26];
+        let result_e012inf  = a[27] + b[27];
+        let result_e023inf  = a[28] + b[28];
+        let result_e031inf  = a[29] + b[29];
+        let result_e123inf  = a[30] + b[30];
+        let result_e0123inf = a[31] + b[31];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::Add<S> for ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1        = a[0] + other;
+        let result_e0       = a[1];
+        let result_e1       = a[2];
+        let result_e2       = a[3];
+        let result_e3       = a[4];
+        let result_einf     = a[5];
+        let result_e01      = a[6];
+        let result_e02      = a[7];
+        let result_e03      = a[8];
+        let result_e12      = a[9];
+        let result_e23      = a[10];
+        let result_e31      = a[11];
+        let result_e1inf    = a[12];
+        let result_e2inf    = a[13];
+        let result_e3inf    = a[14];
+        let result_e0inf    = a[15];
+        let result_e012     = a[16];
+        let result_e023     = a[17];
+        let result_e031     = a[18];
+        let result_e01inf   = a[19];
+        let result_e02inf   = a[20];
+        let result_e03inf   = a[21];
+        let result_e123     = a[22];
+        let result_e12inf   = a[23];
+        let result_e23inf   = a[24];
+        let result_e31inf   = a[25];
+        let result_e0123    = a[26];
+        let result_e012inf  = a[27];
+        let result_e023inf  = a[28];
+        let result_e031inf  = a[29];
+        let result_e123inf  = a[30];
+        let result_e0123inf = a[31];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::Add<S> for &ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1        = a[0] + other;
+        let result_e0       = a[1];
+        let result_e1       = a[2];
+        let result_e2       = a[3];
+        let result_e3       = a[4];
+        let result_einf     = a[5];
+        let result_e01      = a[6];
+        let result_e02      = a[7];
+        let result_e03      = a[8];
+        let result_e12      = a[9];
+        let result_e23      = a[10];
+        let result_e31      = a[11];
+        let result_e1inf    = a[12];
+        let result_e2inf    = a[13];
+        let result_e3inf    = a[14];
+        let result_e0inf    = a[15];
+        let result_e012     = a[16];
+        let result_e023     = a[17];
+        let result_e031     = a[18];
+        let result_e01inf   = a[19];
+        let result_e02inf   = a[20];
+        let result_e03inf   = a[21];
+        let result_e123     = a[22];
+        let result_e12inf   = a[23];
+        let result_e23inf   = a[24];
+        let result_e31inf   = a[25];
+        let result_e0123    = a[26];
+        let result_e012inf  = a[27];
+        let result_e023inf  = a[28];
+        let result_e031inf  = a[29];
+        let result_e123inf  = a[30];
+        let result_e0123inf = a[31];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::Sub<ConformalMultivector3<S>> for ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: ConformalMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1        = a[0] - b[0];
+        let result_e0       = a[1] - b[1];
+        let result_e1       = a[2] - b[2];
+        let result_e2       = a[3] - b[3];
+        let result_e3       = a[4] - b[4];
+        let result_einf     = a[5] - b[5];
+        let result_e01      = a[6] - b[6];
+        let result_e02      = a[7] - b[7];
+        let result_e03      = a[8] - b[8];
+        let result_e12      = a[9] - b[9];
+        let result_e23      = a[10] - b[10];
+        let result_e31      = a[11] - b[11];
+        let result_e1inf    = a[12] - b[12];
+        let result_e2inf    = a[13] - b[13];
+        let result_e3inf    = a[14] - b[14];
+        let result_e0inf    = a[15] - b[15];
+        let result_e012     = a[16] - b[16];
+        let result_e023     = a[17] - b[17];
+        let result_e031     = a[18] - b[18];
+        let result_e01inf   = a[19] - b[19];
+        let result_e02inf   = a[20] - b[20];
+        let result_e03inf   = a[21] - b[21];
+        let result_e123     = a[22] - b[22];
+        let result_e12inf   = a[23] - b[23];
+        let result_e23inf   = a[24] - b[24];
+        let result_e31inf   = a[25] - b[25];
+        let result_e0123    = a[26] - b[26];
+        let result_e012inf  = a[27] - b[27];
+        let result_e023inf  = a[28] - b[28];
+        let result_e031inf  = a[29] - b[29];
+        let result_e123inf  = a[30] - b[30];
+        let result_e0123inf = a[31] - b[31];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::Sub<&ConformalMultivector3<S>> for ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: &ConformalMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1        = a[0] - b[0];
+        let result_e0       = a[1] - b[1];
+        let result_e1       = a[2] - b[2];
+        let result_e2       = a[3] - b[3];
+        let result_e3       = a[4] - b[4];
+        let result_einf     = a[5] - b[5];
+        let result_e01      = a[6] - b[6];
+        let result_e02      = a[7] - b[7];
+        let result_e03      = a[8] - b[8];
+        let result_e12      = a[9] - b[9];
+        let result_e23      = a[10] - b[10];
+        let result_e31      = a[11] - b[11];
+        let result_e1inf    = a[12] - b[12];
+        let result_e2inf    = a[13] - b[13];
+        let result_e3inf    = a[14] - b[14];
+        let result_e0inf    = a[15] - b[15];
+        let result_e012     = a[16] - b[16];
+        let result_e023     = a[17] - b[17];
+        let result_e031     = a[18] - b[18];
+        let result_e01inf   = a[19] - b[19];
+        let result_e02inf   = a[20] - b[20];
+        let result_e03inf   = a[21] - b[21];
+        let result_e123     = a[22] - b[22];
+        let result_e12inf   = a[23] - b[23];
+        let result_e23inf   = a[24] - b[24];
+        let result_e31inf   = a[25] - b[25];
+        let result_e0123    = a[26] - b[26];
+        let result_e012inf  = a[27] - b[27];
+        let result_e023inf  = a[28] - b[28];
+        let result_e031inf  = a[29] - b[29];
+        let result_e123inf  = a[30] - b[30];
+        let result_e0123inf = a[31] - b[31];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::Sub<ConformalMultivector3<S>> for &ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: ConformalMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1        = a[0] - b[0];
+        let result_e0       = a[1] - b[1];
+        let result_e1       = a[2] - b[2];
+        let result_e2       = a[3] - b[3];
+        let result_e3       = a[4] - b[4];
+        let result_einf     = a[5] - b[5];
+        let result_e01      = a[6] - b[6];
+        let result_e02      = a[7] - b[7];
+        let result_e03      = a[8] - b[8];
+        let result_e12      = a[9] - b[9];
+        let result_e23      = a[10] - b[10];
+        let result_e31      = a[11] - b[11];
+        let result_e1inf    = a[12] - b[12];
+        let result_e2inf    = a[13] - b[13];
+        let result_e3inf    = a[14] - b[14];
+        let result_e0inf    = a[15] - b[15];
+        let result_e012     = a[16] - b[16];
+        let result_e023     = a[17] - b[17];
+        let result_e031     = a[18] - b[18];
+        let result_e01inf   = a[19] - b[19];
+        let result_e02inf   = a[20] - b[20];
+        let result_e03inf   = a[21] - b[21];
+        let result_e123     = a[22] - b[22];
+        let result_e12inf   = a[23] - b[23];
+        let result_e23inf   = a[24] - b[24];
+        let result_e31inf   = a[25] - b[25];
+        let result_e0123    = a[26] - b[26];
+        let result_e012inf  = a[27] - b[27];
+        let result_e023inf  = a[28] - b[28];
+        let result_e031inf  = a[29] - b[29];
+        let result_e123inf  = a[30] - b[30];
+        let result_e0123inf = a[31] - b[31];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::Sub<&ConformalMultivector3<S>> for &ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: &ConformalMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1        = a[0] - b[0];
+        let result_e0       = a[1] - b[1];
+        let result_e1       = a[2] - b[2];
+        let result_e2       = a[3] - b[3];
+        let result_e3       = a[4] - b[4];
+        let result_einf     = a[5] - b[5];
+        let result_e01      = a[6] - b[6];
+        let result_e02      = a[7] - b[7];
+        let result_e03      = a[8] - b[8];
+        let result_e12      = a[9] - b[9];
+        let result_e23      = a[10] - b[10];
+        let result_e31      = a[11] - b[11];
+        let result_e1inf    = a[12] - b[12];
+        let result_e2inf    = a[13] - b[13];
+        let result_e3inf    = a[14] - b[14];
+        let result_e0inf    = a[15] - b[15];
+        let result_e012     = a[16] - b[16];
+        let result_e023     = a[17] - b[17];
+        let result_e031     = a[18] - b[18];
+        let result_e01inf   = a[19] - b[19];
+        let result_e02inf   = a[20] - b[20];
+        let result_e03inf   = a[21] - b[21];
+        let result_e123     = a[22] - b[22];
+        let result_e12inf   = a[23] - b[23];
+        let result_e23inf   = a[24] - b[24];
+        let result_e31inf   = a[25] - b[25];
+        let result_e0123    = a[26] - b[26];
+        let result_e012inf  = a[27] - b[27];
+        let result_e023inf  = a[28] - b[28];
+        let result_e031inf  = a[29] - b[29];
+        let result_e123inf  = a[30] - b[30];
+        let result_e0123inf = a[31] - b[31];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::Sub<S> for ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1        = a[0] - other;
+        let result_e0       = a[1];
+        let result_e1       = a[2];
+        let result_e2       = a[3];
+        let result_e3       = a[4];
+        let result_einf     = a[5];
+        let result_e01      = a[6];
+        let result_e02      = a[7];
+        let result_e03      = a[8];
+        let result_e12      = a[9];
+        let result_e23      = a[10];
+        let result_e31      = a[11];
+        let result_e1inf    = a[12];
+        let result_e2inf    = a[13];
+        let result_e3inf    = a[14];
+        let result_e0inf    = a[15];
+        let result_e012     = a[16];
+        let result_e023     = a[17];
+        let result_e031     = a[18];
+        let result_e01inf   = a[19];
+        let result_e02inf   = a[20];
+        let result_e03inf   = a[21];
+        let result_e123     = a[22];
+        let result_e12inf   = a[23];
+        let result_e23inf   = a[24];
+        let result_e31inf   = a[25];
+        let result_e0123    = a[26];
+        let result_e012inf  = a[27];
+        let result_e023inf  = a[28];
+        let result_e031inf  = a[29];
+        let result_e123inf  = a[30];
+        let result_e0123inf = a[31];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::Sub<S> for &ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1        = a[0] - other;
+        let result_e0       = a[1];
+        let result_e1       = a[2];
+        let result_e2       = a[3];
+        let result_e3       = a[4];
+        let result_einf     = a[5];
+        let result_e01      = a[6];
+        let result_e02      = a[7];
+        let result_e03      = a[8];
+        let result_e12      = a[9];
+        let result_e23      = a[10];
+        let result_e31      = a[11];
+        let result_e1inf    = a[12];
+        let result_e2inf    = a[13];
+        let result_e3inf    = a[14];
+        let result_e0inf    = a[15];
+        let result_e012     = a[16];
+        let result_e023     = a[17];
+        let result_e031     = a[18];
+        let result_e01inf   = a[19];
+        let result_e02inf   = a[20];
+        let result_e03inf   = a[21];
+        let result_e123     = a[22];
+        let result_e12inf   = a[23];
+        let result_e23inf   = a[24];
+        let result_e31inf   = a[25];
+        let result_e0123    = a[26];
+        let result_e012inf  = a[27];
+        let result_e023inf  = a[28];
+        let result_e031inf  = a[29];
+        let result_e123inf  = a[30];
+        let result_e0123inf = a[31];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl_approx_cmp_ops!(ConformalMultivector3);
+
+impl<S> ConformalMultivector3<S>
+where
+    S: ScalarFloat,
+{
+    /// Calculate the squared magnitude of a multivector.
+    pub fn magnitude_squared(&self) -> S {
+        let scalar_part = (self * self.reverse())[0];
+
+        scalar_part.abs()
+    }
+
+    /// Calculate the magnitude of a multivector.
+    pub fn magnitude(&self) -> S {
+        self.magnitude_squared().sqrt()
+    }
+
+    /// Normalize a multivector to a unit multivector.
+    pub fn normalize(&self) -> Self {
+        self * (S::one() / self.magnitude())
+    }
+
+    /// Normalize a multivector to a specified magnitude.
+    pub fn normalize_to(&self, magnitude: S) -> Self {
+        self * (magnitude / self.magnitude())
+    }
+}
+
+impl<S> ops::Div<S> for ConformalMultivector3<S>
+where
+    S: ScalarFloat,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn div(self, other: S) -> Self::Output {
+        let one_over_other = S::one() / other;
+        let result_1        = self.data[0] * one_over_other;
+        let result_e0       = self.data[1] * one_over_other;
+        let result_e1       = self.data[2] * one_over_other;
+        let result_e2       = self.data[3] * one_over_other;
+        let result_e3       = self.data[4] * one_over_other;
+        let result_einf     = self.data[5] * one_over_other;
+        let result_e01      = self.data[6] * one_over_other;
+        let result_e02      = self.data[7] * one_over_other;
+        let result_e03      = self.data[8] * one_over_other;
+        let result_e12      = self.data[9] * one_over_other;
+        let result_e23      = self.data[10] * one_over_other;
+        let result_e31      = self.data[11] * one_over_other;
+        let result_e1inf    = self.data[12] * one_over_other;
+        let result_e2inf    = self.data[13] * one_over_other;
+        let result_e3inf    = self.data[14] * one_over_other;
+        let result_e0inf    = self.data[15] * one_over_other;
+        let result_e012     = self.data[16] * one_over_other;
+        let result_e023     = self.data[17] * one_over_other;
+        let result_e031     = self.data[18] * one_over_other;
+        let result_e01inf   = self.data[19] * one_over_other;
+        let result_e02inf   = self.data[20] * one_over_other;
+        let result_e03inf   = self.data[21] * one_over_other;
+        let result_e123     = self.data[22] * one_over_other;
+        let result_e12inf   = self.data[23] * one_over_other;
+        let result_e23inf   = self.data[24] * one_over_other;
+        let result_e31inf   = self.data[25] * one_over_other;
+        let result_e0123    = self.data[26] * one_over_other;
+        let result_e012inf  = self.data[27] * one_over_other;
+        let result_e023inf  = self.data[28] * one_over_other;
+        let result_e031inf  = self.data[29] * one_over_other;
+        let result_e123inf  = self.data[30] * one_over_other;
+        let result_e0123inf = self.data[31] * one_over_other;
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::Div<S> for &ConformalMultivector3<S>
+where
+    S: ScalarFloat,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn div(self, other: S) -> Self::Output {
+        let one_over_other = S::one() / other;
+        let result_1        = self.data[0] * one_over_other;
+        let result_e0       = self.data[1] * one_over_other;
+        let result_e1       = self.data[2] * one_over_other;
+        let result_e2       = self.data[3] * one_over_other;
+        let result_e3       = self.data[4] * one_over_other;
+        let result_einf     = self.data[5] * one_over_other;
+        let result_e01      = self.data[6] * one_over_other;
+        let result_e02      = self.data[7] * one_over_other;
+        let result_e03      = self.data[8] * one_over_other;
+        let result_e12      = self.data[9] * one_over_other;
+        let result_e23      = self.data[10] * one_over_other;
+        let result_e31      = self.data[11] * one_over_other;
+        let result_e1inf    = self.data[12] * one_over_other;
+        let result_e2inf    = self.data[13] * one_over_other;
+        let result_e3inf    = self.data[14] * one_over_other;
+        let result_e0inf    = self.data[15] * one_over_other;
+        let result_e012     = self.data[16] * one_over_other;
+        let result_e023     = self.data[17] * one_over_other;
+        let result_e031     = self.data[18] * one_over_other;
+        let result_e01inf   = self.data[19] * one_over_other;
+        let result_e02inf   = self.data[20] * one_over_other;
+        let result_e03inf   = self.data[21] * one_over_other;
+        let result_e123     = self.data[22] * one_over_other;
+        let result_e12inf   = self.data[23] * one_over_other;
+        let result_e23inf   = self.data[24] * one_over_other;
+        let result_e31inf   = self.data[25] * one_over_other;
+        let result_e0123    = self.data[26] * one_over_other;
+        let result_e012inf  = self.data[27] * one_over_other;
+        let result_e023inf  = self.data[28] * one_over_other;
+        let result_e031inf  = self.data[29] * one_over_other;
+        let result_e123inf  = self.data[30] * one_over_other;
+        let result_e0123inf = self.data[31] * one_over_other;
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::Shl<ConformalMultivector3<S>> for ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shl(self, other: ConformalMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1        = a[0] * b[0] - a[1] * b[5] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[5] * b[1] - a[6] * b[12] - a[7] * b[13] - a[8] * b[14] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[6] - a[13] * b[7] - a[14] * b[8] + a[15] * b[15] + a[16] * b[23] + a[17] * b[24] + a[18] * b[25] + a[19] * b[19] + a[20] * b[20] + a[21] * b[21] - a[22] * b[22] + a[23] * b[16] + a[24] * b[17] + a[25] * b[18] + a[26] * b[30] - a[27] * b[27] - a[28] * b[28] - a[29] * b[29] + a[30] * b[26] - a[31] * b[31];
+        let result_e0       = a[0] * b[1] + a[1] * b[15] - a[2] * b[6] - a[3] * b[7] - a[4] * b[8] - a[6] * b[19] - a[7] * b[20] - a[8] * b[21] - a[9] * b[16] - a[10] * b[17] - a[11] * b[18] - a[16] * b[27] - a[17] * b[28] - a[18] * b[29] + a[22] * b[26] + a[26] * b[31];
+        let result_e1       = a[0] * b[2] + a[1] * b[12] - a[3] * b[9] + a[4] * b[11] - a[5] * b[6] - a[7] * b[23] + a[8] * b[25] - a[10] * b[22] + a[13] * b[16] - a[14] * b[18] - a[15] * b[19] - a[17] * b[30] + a[20] * b[27] - a[21] * b[29] + a[24] * b[26] + a[28] * b[31];
+        let result_e2       = a[0] * b[3] + a[1] * b[13] + a[2] * b[9] - a[4] * b[10] - a[5] * b[7] + a[6] * b[23] - a[8] * b[24] - a[11] * b[22] - a[12] * b[16] + a[14] * b[17] - a[15] * b[20] - a[18] * b[30] - a[19] * b[27] + a[21] * b[28] + a[25] * b[26] + a[29] * b[31];
+        let result_e3       = a[0] * b[4] + a[1] * b[14] - a[2] * b[11] + a[3] * b[10] - a[5] * b[8] - a[6] * b[25] + a[7] * b[24] - a[9] * b[22] + a[12] * b[18] - a[13] * b[17] - a[15] * b[21] - a[16] * b[30] + a[19] * b[29] - a[20] * b[28] + a[23] * b[26] + a[27] * b[31];
+        let result_einf     = a[0] * b[5] + a[2] * b[12] + a[3] * b[13] + a[4] * b[14] - a[5] * b[15] - a[9] * b[23] - a[10] * b[24] - a[11] * b[25] - a[12] * b[19] - a[13] * b[20] - a[14] * b[21] - a[22] * b[30] + a[23] * b[27] + a[24] * b[28] + a[25] * b[29] + a[30] * b[31];
+        let result_e01      = a[0] * b[6] - a[1] * b[19] + a[3] * b[16] - a[4] * b[18] - a[7] * b[27] + a[8] * b[29] - a[10] * b[26] + a[17] * b[31];
+        let result_e02      = a[0] * b[7] - a[1] * b[20] - a[2] * b[16] + a[4] * b[17] + a[6] * b[27] - a[8] * b[28] - a[11] * b[26] + a[18] * b[31];
+        let result_e03      = a[0] * b[8] - a[1] * b[21] + a[2] * b[18] - a[3] * b[17] - a[6] * b[29] + a[7] * b[28] - a[9] * b[26] + a[16] * b[31];
+        let result_e12      = a[0] * b[9] - a[1] * b[23] + a[4] * b[22] - a[5] * b[16] - a[8] * b[30] - a[14] * b[26] + a[15] * b[27] + a[21] * b[31];
+        let result_e23      = a[0] * b[10] - a[1] * b[24] + a[2] * b[22] - a[5] * b[17] - a[6] * b[30] - a[12] * b[26] + a[15] * b[28] + a[19] * b[31];
+        let result_e31      = a[0] * b[11] - a[1] * b[25] + a[3] * b[22] - a[5] * b[18] - a[7] * b[30] - a[13] * b[26] + a[15] * b[29] + a[20] * b[31];
+        let result_e1inf    = a[0] * b[12] - a[3] * b[23] + a[4] * b[25] - a[5] * b[19] - a[10] * b[30] + a[13] * b[27] - a[14] * b[29] + a[24] * b[31];
+        let result_e2inf    = a[0] * b[13] + a[2] * b[23] - a[4] * b[24] - a[5] * b[20] - a[11] * b[30] - a[12] * b[27] + a[14] * b[28] + a[25] * b[31];
+        let result_e3inf    = a[0] * b[14] - a[2] * b[25] + a[3] * b[24] - a[5] * b[21] - a[9] * b[30] + a[12] * b[29] - a[13] * b[28] + a[23] * b[31];
+        let result_e0inf    = a[0] * b[15] - a[2] * b[19] - a[3] * b[20] - a[4] * b[21] - a[9] * b[27] - a[10] * b[28] - a[11] * b[29] + a[22] * b[31];
+        let result_e012     = a[0] * b[16] + a[1] * b[27] - a[4] * b[26] - a[8] * b[31];
+        let result_e023     = a[0] * b[17] + a[1] * b[28] - a[2] * b[26] - a[6] * b[31];
+        let result_e031     = a[0] * b[18] + a[1] * b[29] - a[3] * b[26] - a[7] * b[31];
+        let result_e01inf   = a[0] * b[19] + a[3] * b[27] - a[4] * b[29] - a[10] * b[31];
+        let result_e02inf   = a[0] * b[20] - a[2] * b[27] + a[4] * b[28] - a[11] * b[31];
+        let result_e03inf   = a[0] * b[21] + a[2] * b[29] - a[3] * b[28] - a[9] * b[31];
+        let result_e123     = a[0] * b[22] + a[1] * b[30] - a[5] * b[26] - a[15] * b[31];
+        let result_e12inf   = a[0] * b[23] + a[4] * b[30] - a[5] * b[27] - a[14] * b[31];
+        let result_e23inf   = a[0] * b[24] + a[2] * b[30] - a[5] * b[28] - a[12] * b[31];
+        let result_e31inf   = a[0] * b[25] + a[3] * b[30] - a[5] * b[29] - a[13] * b[31];
+        let result_e0123    = a[0] * b[26] - a[1] * b[31];
+        let result_e012inf  = a[0] * b[27] - a[4] * b[31];
+        let result_e023inf  = a[0] * b[28] - a[2] * b[31];
+        let result_e031inf  = a[0] * b[29] - a[3] * b[31];
+        let result_e123inf  = a[0] * b[30] - a[5] * b[31];
+        let result_e0123inf = a[0] * b[31];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::Shl<&ConformalMultivector3<S>> for ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shl(self, other: &ConformalMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1        = a[0] * b[0] - a[1] * b[5] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[5] * b[1] - a[6] * b[12] - a[7] * b[13] - a[8] * b[14] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[6] - a[13] * b[7] - a[14] * b[8] + a[15] * b[15] + a[16] * b[23] + a[17] * b[24] + a[18] * b[25] + a[19] * b[19] + a[20] * b[20] + a[21] * b[21] - a[22] * b[22] + a[23] * b[16] + a[24] * b[17] + a[25] * b[18] + a[26] * b[30] - a[27] * b[27] - a[28] * b[28] - a[29] * b[29] + a[30] * b[26] - a[31] * b[31];
+        let result_e0       = a[0] * b[1] + a[1] * b[15] - a[2] * b[6] - a[3] * b[7] - a[4] * b[8] - a[6] * b[19] - a[7] * b[20] - a[8] * b[21] - a[9] * b[16] - a[10] * b[17] - a[11] * b[18] - a[16] * b[27] - a[17] * b[28] - a[18] * b[29] + a[22] * b[26] + a[26] * b[31];
+        let result_e1       = a[0] * b[2] + a[1] * b[12] - a[3] * b[9] + a[4] * b[11] - a[5] * b[6] - a[7] * b[23] + a[8] * b[25] - a[10] * b[22] + a[13] * b[16] - a[14] * b[18] - a[15] * b[19] - a[17] * b[30] + a[20] * b[27] - a[21] * b[29] + a[24] * b[26] + a[28] * b[31];
+        let result_e2       = a[0] * b[3] + a[1] * b[13] + a[2] * b[9] - a[4] * b[10] - a[5] * b[7] + a[6] * b[23] - a[8] * b[24] - a[11] * b[22] - a[12] * b[16] + a[14] * b[17] - a[15] * b[20] - a[18] * b[30] - a[19] * b[27] + a[21] * b[28] + a[25] * b[26] + a[29] * b[31];
+        let result_e3       = a[0] * b[4] + a[1] * b[14] - a[2] * b[11] + a[3] * b[10] - a[5] * b[8] - a[6] * b[25] + a[7] * b[24] - a[9] * b[22] + a[12] * b[18] - a[13] * b[17] - a[15] * b[21] - a[16] * b[30] + a[19] * b[29] - a[20] * b[28] + a[23] * b[26] + a[27] * b[31];
+        let result_einf     = a[0] * b[5] + a[2] * b[12] + a[3] * b[13] + a[4] * b[14] - a[5] * b[15] - a[9] * b[23] - a[10] * b[24] - a[11] * b[25] - a[12] * b[19] - a[13] * b[20] - a[14] * b[21] - a[22] * b[30] + a[23] * b[27] + a[24] * b[28] + a[25] * b[29] + a[30] * b[31];
+        let result_e01      = a[0] * b[6] - a[1] * b[19] + a[3] * b[16] - a[4] * b[18] - a[7] * b[27] + a[8] * b[29] - a[10] * b[26] + a[17] * b[31];
+        let result_e02      = a[0] * b[7] - a[1] * b[20] - a[2] * b[16] + a[4] * b[17] + a[6] * b[27] - a[8] * b[28] - a[11] * b[26] + a[18] * b[31];
+        let result_e03      = a[0] * b[8] - a[1] * b[21] + a[2] * b[18] - a[3] * b[17] - a[6] * b[29] + a[7] * b[28] - a[9] * b[26] + a[16] * b[31];
+        let result_e12      = a[0] * b[9] - a[1] * b[23] + a[4] * b[22] - a[5] * b[16] - a[8] * b[30] - a[14] * b[26] + a[15] * b[27] + a[21] * b[31];
+        let result_e23      = a[0] * b[10] - a[1] * b[24] + a[2] * b[22] - a[5] * b[17] - a[6] * b[30] - a[12] * b[26] + a[15] * b[28] + a[19] * b[31];
+        let result_e31      = a[0] * b[11] - a[1] * b[25] + a[3] * b[22] - a[5] * b[18] - a[7] * b[30] - a[13] * b[26] + a[15] * b[29] + a[20] * b[31];
+        let result_e1inf    = a[0] * b[12] - a[3] * b[23] + a[4] * b[25] - a[5] * b[19] - a[10] * b[30] + a[13] * b[27] - a[14] * b[29] + a[24] * b[31];
+        let result_e2inf    = a[0] * b[13] + a[2] * b[23] - a[4] * b[24] - a[5] * b[20] - a[11] * b[30] - a[12] * b[27] + a[14] * b[28] + a[25] * b[31];
+        let result_e3inf    = a[0] * b[14] - a[2] * b[25] + a[3] * b[24] - a[5] * b[21] - a[9] * b[30] + a[12] * b[29] - a[13] * b[28] + a[23] * b[31];
+        let result_e0inf    = a[0] * b[15] - a[2] * b[19] - a[3] * b[20] - a[4] * b[21] - a[9] * b[27] - a[10] * b[28] - a[11] * b[29] + a[22] * b[31];
+        let result_e012     = a[0] * b[16] + a[1] * b[27] - a[4] * b[26] - a[8] * b[31];
+        let result_e023     = a[0] * b[17] + a[1] * b[28] - a[2] * b[26] - a[6] * b[31];
+        let result_e031     = a[0] * b[18] + a[1] * b[29] - a[3] * b[26] - a[7] * b[31];
+        let result_e01inf   = a[0] * b[19] + a[3] * b[27] - a[4] * b[29] - a[10] * b[31];
+        let result_e02inf   = a[0] * b[20] - a[2] * b[27] + a[4] * b[28] - a[11] * b[31];
+        let result_e03inf   = a[0] * b[21] + a[2] * b[29] - a[3] * b[28] - a[9] * b[31];
+        let result_e123     = a[0] * b[22] + a[1] * b[30] - a[5] * b[26] - a[15] * b[31];
+        let result_e12inf   = a[0] * b[23] + a[4] * b[30] - a[5] * b[27] - a[14] * b[31];
+        let result_e23inf   = a[0] * b[24] + a[2] * b[30] - a[5] * b[28] - a[12] * b[31];
+        let result_e31inf   = a[0] * b[25] + a[3] * b[30] - a[5] * b[29] - a[13] * b[31];
+        let result_e0123    = a[0] * b[26] - a[1] * b[31];
+        let result_e012inf  = a[0] * b[27] - a[4] * b[31];
+        let result_e023inf  = a[0] * b[28] - a[2] * b[31];
+        let result_e031inf  = a[0] * b[29] - a[3] * b[31];
+        let result_e123inf  = a[0] * b[30] - a[5] * b[31];
+        let result_e0123inf = a[0] * b[31];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::Shl<ConformalMultivector3<S>> for &ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shl(self, other: ConformalMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1        = a[0] * b[0] - a[1] * b[5] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[5] * b[1] - a[6] * b[12] - a[7] * b[13] - a[8] * b[14] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[6] - a[13] * b[7] - a[14] * b[8] + a[15] * b[15] + a[16] * b[23] + a[17] * b[24] + a[18] * b[25] + a[19] * b[19] + a[20] * b[20] + a[21] * b[21] - a[22] * b[22] + a[23] * b[16] + a[24] * b[17] + a[25] * b[18] + a[26] * b[30] - a[27] * b[27] - a[28] * b[28] - a[29] * b[29] + a[30] * b[26] - a[31] * b[31];
+        let result_e0       = a[0] * b[1] + a[1] * b[15] - a[2] * b[6] - a[3] * b[7] - a[4] * b[8] - a[6] * b[19] - a[7] * b[20] - a[8] * b[21] - a[9] * b[16] - a[10] * b[17] - a[11] * b[18] - a[16] * b[27] - a[17] * b[28] - a[18] * b[29] + a[22] * b[26] + a[26] * b[31];
+        let result_e1       = a[0] * b[2] + a[1] * b[12] - a[3] * b[9] + a[4] * b[11] - a[5] * b[6] - a[7] * b[23] + a[8] * b[25] - a[10] * b[22] + a[13] * b[16] - a[14] * b[18] - a[15] * b[19] - a[17] * b[30] + a[20] * b[27] - a[21] * b[29] + a[24] * b[26] + a[28] * b[31];
+        let result_e2       = a[0] * b[3] + a[1] * b[13] + a[2] * b[9] - a[4] * b[10] - a[5] * b[7] + a[6] * b[23] - a[8] * b[24] - a[11] * b[22] - a[12] * b[16] + a[14] * b[17] - a[15] * b[20] - a[18] * b[30] - a[19] * b[27] + a[21] * b[28] + a[25] * b[26] + a[29] * b[31];
+        let result_e3       = a[0] * b[4] + a[1] * b[14] - a[2] * b[11] + a[3] * b[10] - a[5] * b[8] - a[6] * b[25] + a[7] * b[24] - a[9] * b[22] + a[12] * b[18] - a[13] * b[17] - a[15] * b[21] - a[16] * b[30] + a[19] * b[29] - a[20] * b[28] + a[23] * b[26] + a[27] * b[31];
+        let result_einf     = a[0] * b[5] + a[2] * b[12] + a[3] * b[13] + a[4] * b[14] - a[5] * b[15] - a[9] * b[23] - a[10] * b[24] - a[11] * b[25] - a[12] * b[19] - a[13] * b[20] - a[14] * b[21] - a[22] * b[30] + a[23] * b[27] + a[24] * b[28] + a[25] * b[29] + a[30] * b[31];
+        let result_e01      = a[0] * b[6] - a[1] * b[19] + a[3] * b[16] - a[4] * b[18] - a[7] * b[27] + a[8] * b[29] - a[10] * b[26] + a[17] * b[31];
+        let result_e02      = a[0] * b[7] - a[1] * b[20] - a[2] * b[16] + a[4] * b[17] + a[6] * b[27] - a[8] * b[28] - a[11] * b[26] + a[18] * b[31];
+        let result_e03      = a[0] * b[8] - a[1] * b[21] + a[2] * b[18] - a[3] * b[17] - a[6] * b[29] + a[7] * b[28] - a[9] * b[26] + a[16] * b[31];
+        let result_e12      = a[0] * b[9] - a[1] * b[23] + a[4] * b[22] - a[5] * b[16] - a[8] * b[30] - a[14] * b[26] + a[15] * b[27] + a[21] * b[31];
+        let result_e23      = a[0] * b[10] - a[1] * b[24] + a[2] * b[22] - a[5] * b[17] - a[6] * b[30] - a[12] * b[26] + a[15] * b[28] + a[19] * b[31];
+        let result_e31      = a[0] * b[11] - a[1] * b[25] + a[3] * b[22] - a[5] * b[18] - a[7] * b[30] - a[13] * b[26] + a[15] * b[29] + a[20] * b[31];
+        let result_e1inf    = a[0] * b[12] - a[3] * b[23] + a[4] * b[25] - a[5] * b[19] - a[10] * b[30] + a[13] * b[27] - a[14] * b[29] + a[24] * b[31];
+        let result_e2inf    = a[0] * b[13] + a[2] * b[23] - a[4] * b[24] - a[5] * b[20] - a[11] * b[30] - a[12] * b[27] + a[14] * b[28] + a[25] * b[31];
+        let result_e3inf    = a[0] * b[14] - a[2] * b[25] + a[3] * b[24] - a[5] * b[21] - a[9] * b[30] + a[12] * b[29] - a[13] * b[28] + a[23] * b[31];
+        let result_e0inf    = a[0] * b[15] - a[2] * b[19] - a[3] * b[20] - a[4] * b[21] - a[9] * b[27] - a[10] * b[28] - a[11] * b[29] + a[22] * b[31];
+        let result_e012     = a[0] * b[16] + a[1] * b[27] - a[4] * b[26] - a[8] * b[31];
+        let result_e023     = a[0] * b[17] + a[1] * b[28] - a[2] * b[26] - a[6] * b[31];
+        let result_e031     = a[0] * b[18] + a[1] * b[29] - a[3] * b[26] - a[7] * b[31];
+        let result_e01inf   = a[0] * b[19] + a[3] * b[27] - a[4] * b[29] - a[10] * b[31];
+        let result_e02inf   = a[0] * b[20] - a[2] * b[27] + a[4] * b[28] - a[11] * b[31];
+        let result_e03inf   = a[0] * b[21] + a[2] * b[29] - a[3] * b[28] - a[9] * b[31];
+        let result_e123     = a[0] * b[22] + a[1] * b[30] - a[5] * b[26] - a[15] * b[31];
+        let result_e12inf   = a[0] * b[23] + a[4] * b[30] - a[5] * b[27] - a[14] * b[31];
+        let result_e23inf   = a[0] * b[24] + a[2] * b[30] - a[5] * b[28] - a[12] * b[31];
+        let result_e31inf   = a[0] * b[25] + a[3] * b[30] - a[5] * b[29] - a[13] * b[31];
+        let result_e0123    = a[0] * b[26] - a[1] * b[31];
+        let result_e012inf  = a[0] * b[27] - a[4] * b[31];
+        let result_e023inf  = a[0] * b[28] - a[2] * b[31];
+        let result_e031inf  = a[0] * b[29] - a[3] * b[31];
+        let result_e123inf  = a[0] * b[30] - a[5] * b[31];
+        let result_e0123inf = a[0] * b[31];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::Shl<&ConformalMultivector3<S>> for &ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shl(self, other: &ConformalMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1        = a[0] * b[0] - a[1] * b[5] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[5] * b[1] - a[6] * b[12] - a[7] * b[13] - a[8] * b[14] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[6] - a[13] * b[7] - a[14] * b[8] + a[15] * b[15] + a[16] * b[23] + a[17] * b[24] + a[18] * b[25] + a[19] * b[19] + a[20] * b[20] + a[21] * b[21] - a[22] * b[22] + a[23] * b[16] + a[24] * b[17] + a[25] * b[18] + a[26] * b[30] - a[27] * b[27] - a[28] * b[28] - a[29] * b[29] + a[30] * b[26] - a[31] * b[31];
+        let result_e0       = a[0] * b[1] + a[1] * b[15] - a[2] * b[6] - a[3] * b[7] - a[4] * b[8] - a[6] * b[19] - a[7] * b[20] - a[8] * b[21] - a[9] * b[16] - a[10] * b[17] - a[11] * b[18] - a[16] * b[27] - a[17] * b[28] - a[18] * b[29] + a[22] * b[26] + a[26] * b[31];
+        let result_e1       = a[0] * b[2] + a[1] * b[12] - a[3] * b[9] + a[4] * b[11] - a[5] * b[6] - a[7] * b[23] + a[8] * b[25] - a[10] * b[22] + a[13] * b[16] - a[14] * b[18] - a[15] * b[19] - a[17] * b[30] + a[20] * b[27] - a[21] * b[29] + a[24] * b[26] + a[28] * b[31];
+        let result_e2       = a[0] * b[3] + a[1] * b[13] + a[2] * b[9] - a[4] * b[10] - a[5] * b[7] + a[6] * b[23] - a[8] * b[24] - a[11] * b[22] - a[12] * b[16] + a[14] * b[17] - a[15] * b[20] - a[18] * b[30] - a[19] * b[27] + a[21] * b[28] + a[25] * b[26] + a[29] * b[31];
+        let result_e3       = a[0] * b[4] + a[1] * b[14] - a[2] * b[11] + a[3] * b[10] - a[5] * b[8] - a[6] * b[25] + a[7] * b[24] - a[9] * b[22] + a[12] * b[18] - a[13] * b[17] - a[15] * b[21] - a[16] * b[30] + a[19] * b[29] - a[20] * b[28] + a[23] * b[26] + a[27] * b[31];
+        let result_einf     = a[0] * b[5] + a[2] * b[12] + a[3] * b[13] + a[4] * b[14] - a[5] * b[15] - a[9] * b[23] - a[10] * b[24] - a[11] * b[25] - a[12] * b[19] - a[13] * b[20] - a[14] * b[21] - a[22] * b[30] + a[23] * b[27] + a[24] * b[28] + a[25] * b[29] + a[30] * b[31];
+        let result_e01      = a[0] * b[6] - a[1] * b[19] + a[3] * b[16] - a[4] * b[18] - a[7] * b[27] + a[8] * b[29] - a[10] * b[26] + a[17] * b[31];
+        let result_e02      = a[0] * b[7] - a[1] * b[20] - a[2] * b[16] + a[4] * b[17] + a[6] * b[27] - a[8] * b[28] - a[11] * b[26] + a[18] * b[31];
+        let result_e03      = a[0] * b[8] - a[1] * b[21] + a[2] * b[18] - a[3] * b[17] - a[6] * b[29] + a[7] * b[28] - a[9] * b[26] + a[16] * b[31];
+        let result_e12      = a[0] * b[9] - a[1] * b[23] + a[4] * b[22] - a[5] * b[16] - a[8] * b[30] - a[14] * b[26] + a[15] * b[27] + a[21] * b[31];
+        let result_e23      = a[0] * b[10] - a[1] * b[24] + a[2] * b[22] - a[5] * b[17] - a[6] * b[30] - a[12] * b[26] + a[15] * b[28] + a[19] * b[31];
+        let result_e31      = a[0] * b[11] - a[1] * b[25] + a[3] * b[22] - a[5] * b[18] - a[7] * b[30] - a[13] * b[26] + a[15] * b[29] + a[20] * b[31];
+        let result_e1inf    = a[0] * b[12] - a[3] * b[23] + a[4] * b[25] - a[5] * b[19] - a[10] * b[30] + a[13] * b[27] - a[14] * b[29] + a[24] * b[31];
+        let result_e2inf    = a[0] * b[13] + a[2] * b[23] - a[4] * b[24] - a[5] * b[20] - a[11] * b[30] - a[12] * b[27] + a[14] * b[28] + a[25] * b[31];
+        let result_e3inf    = a[0] * b[14] - a[2] * b[25] + a[3] * b[24] - a[5] * b[21] - a[9] * b[30] + a[12] * b[29] - a[13] * b[28] + a[23] * b[31];
+        let result_e0inf    = a[0] * b[15] - a[2] * b[19] - a[3] * b[20] - a[4] * b[21] - a[9] * b[27] - a[10] * b[28] - a[11] * b[29] + a[22] * b[31];
+        let result_e012     = a[0] * b[16] + a[1] * b[27] - a[4] * b[26] - a[8] * b[31];
+        let result_e023     = a[0] * b[17] + a[1] * b[28] - a[2] * b[26] - a[6] * b[31];
+        let result_e031     = a[0] * b[18] + a[1] * b[29] - a[3] * b[26] - a[7] * b[31];
+        let result_e01inf   = a[0] * b[19] + a[3] * b[27] - a[4] * b[29] - a[10] * b[31];
+        let result_e02inf   = a[0] * b[20] - a[2] * b[27] + a[4] * b[28] - a[11] * b[31];
+        let result_e03inf   = a[0] * b[21] + a[2] * b[29] - a[3] * b[28] - a[9] * b[31];
+        let result_e123     = a[0] * b[22] + a[1] * b[30] - a[5] * b[26] - a[15] * b[31];
+        let result_e12inf   = a[0] * b[23] + a[4] * b[30] - a[5] * b[27] - a[14] * b[31];
+        let result_e23inf   = a[0] * b[24] + a[2] * b[30] - a[5] * b[28] - a[12] * b[31];
+        let result_e31inf   = a[0] * b[25] + a[3] * b[30] - a[5] * b[29] - a[13] * b[31];
+        let result_e0123    = a[0] * b[26] - a[1] * b[31];
+        let result_e012inf  = a[0] * b[27] - a[4] * b[31];
+        let result_e023inf  = a[0] * b[28] - a[2] * b[31];
+        let result_e031inf  = a[0] * b[29] - a[3] * b[31];
+        let result_e123inf  = a[0] * b[30] - a[5] * b[31];
+        let result_e0123inf = a[0] * b[31];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::Shl<S> for ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn shl(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1 = a[0] * other;
+
+        ConformalMultivector3::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::Shl<S> for &ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn shl(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1 = a[0] * other;
+
+        ConformalMultivector3::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::Shr<ConformalMultivector3<S>> for ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: ConformalMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1        = a[0] * b[0] - a[1] * b[5] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[5] * b[1] - a[6] * b[12] - a[7] * b[13] - a[8] * b[14] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[6] - a[13] * b[7] - a[14] * b[8] + a[15] * b[15] + a[16] * b[23] + a[17] * b[24] + a[18] * b[25] + a[19] * b[19] + a[20] * b[20] + a[21] * b[21] - a[22] * b[22] + a[23] * b[16] + a[24] * b[17] + a[25] * b[18] + a[26] * b[30] - a[27] * b[27] - a[28] * b[28] - a[29] * b[29] + a[30] * b[26] - a[31] * b[31];
+        let result_e0       = a[1] * b[0] + a[6] * b[2] + a[7] * b[3] + a[8] * b[4] - a[15] * b[1] - a[16] * b[9] - a[17] * b[10] - a[18] * b[11] - a[19] * b[6] - a[20] * b[7] - a[21] * b[8] - a[26] * b[22] + a[27] * b[16] + a[28] * b[17] + a[29] * b[18] + a[31] * b[26];
+        let result_e1       = a[2] * b[0] + a[6] * b[5] + a[9] * b[3] - a[11] * b[4] - a[12] * b[1] + a[16] * b[13] - a[18] * b[14] - a[19] * b[15] - a[22] * b[10] - a[23] * b[7] + a[25] * b[8] - a[26] * b[24] - a[27] * b[20] + a[29] * b[21] + a[30] * b[17] + a[31] * b[28];
+        let result_e2       = a[3] * b[0] + a[7] * b[5] - a[9] * b[2] + a[10] * b[4] - a[13] * b[1] - a[16] * b[12] + a[17] * b[14] - a[20] * b[15] - a[22] * b[11] + a[23] * b[6] - a[24] * b[8] - a[26] * b[25] + a[27] * b[19] - a[28] * b[21] + a[30] * b[18] + a[31] * b[29];
+        let result_e3       = a[4] * b[0] + a[8] * b[5] - a[10] * b[3] + a[11] * b[2] - a[14] * b[1] - a[17] * b[13] + a[18] * b[12] - a[21] * b[15] - a[22] * b[9] + a[24] * b[7] - a[25] * b[6] - a[26] * b[23] + a[28] * b[20] - a[29] * b[19] + a[30] * b[16] + a[31] * b[27];
+        let result_einf     = a[5] * b[0] - a[12] * b[2] - a[13] * b[3] - a[14] * b[4] + a[15] * b[5] - a[19] * b[12] - a[20] * b[13] - a[21] * b[14] - a[23] * b[9] - a[24] * b[10] - a[25] * b[11] - a[27] * b[23] - a[28] * b[24] - a[29] * b[25] + a[30] * b[22] + a[31] * b[30];
+        let result_e01      = a[6] * b[0] + a[16] * b[3] - a[18] * b[4] - a[19] * b[1] - a[26] * b[10] - a[27] * b[7] + a[29] * b[8] + a[31] * b[17];
+        let result_e02      = a[7] * b[0] - a[16] * b[2] + a[17] * b[4] - a[20] * b[1] - a[26] * b[11] + a[27] * b[6] - a[28] * b[8] + a[31] * b[18];
+        let result_e03      = a[8] * b[0] - a[17] * b[3] + a[18] * b[2] - a[21] * b[1] - a[26] * b[9] + a[28] * b[7] - a[29] * b[6] + a[31] * b[16];
+        let result_e12      = a[9] * b[0] - a[16] * b[5] + a[22] * b[4] - a[23] * b[1] - a[26] * b[14] + a[27] * b[15] - a[30] * b[8] + a[31] * b[21];
+        let result_e23      = a[10] * b[0] - a[17] * b[5] + a[22] * b[2] - a[24] * b[1] - a[26] * b[12] + a[28] * b[15] - a[30] * b[6] + a[31] * b[19];
+        let result_e31      = a[11] * b[0] - a[18] * b[5] + a[22] * b[3] - a[25] * b[1] - a[26] * b[13] + a[29] * b[15] - a[30] * b[7] + a[31] * b[20];
+        let result_e1inf    = a[12] * b[0] - a[19] * b[5] - a[23] * b[3] + a[25] * b[4] + a[27] * b[13] - a[29] * b[14] - a[30] * b[10] + a[31] * b[24];
+        let result_e2inf    = a[13] * b[0] - a[20] * b[5] + a[23] * b[2] - a[24] * b[4] - a[27] * b[12] + a[28] * b[14] - a[30] * b[11] + a[31] * b[25];
+        let result_e3inf    = a[14] * b[0] - a[21] * b[5] + a[24] * b[3] - a[25] * b[2] - a[28] * b[13] + a[29] * b[12] - a[30] * b[9] + a[31] * b[23];
+        let result_e0inf    = a[15] * b[0] - a[19] * b[2] - a[20] * b[3] - a[21] * b[4] - a[27] * b[9] - a[28] * b[10] - a[29] * b[11] + a[31] * b[22];
+        let result_e012     = a[16] * b[0] + a[26] * b[4] - a[27] * b[1] - a[31] * b[8];
+        let result_e023     = a[17] * b[0] + a[26] * b[2] - a[28] * b[1] - a[31] * b[6];
+        let result_e031     = a[18] * b[0] + a[26] * b[3] - a[29] * b[1] - a[31] * b[7];
+        let result_e01inf   = a[19] * b[0] - a[27] * b[3] + a[29] * b[4] - a[31] * b[10];
+        let result_e02inf   = a[20] * b[0] + a[27] * b[2] - a[28] * b[4] - a[31] * b[11];
+        let result_e03inf   = a[21] * b[0] + a[28] * b[3] - a[29] * b[2] - a[31] * b[9];
+        let result_e123     = a[22] * b[0] + a[26] * b[5] - a[30] * b[1] - a[31] * b[15];
+        let result_e12inf   = a[23] * b[0] + a[27] * b[5] - a[30] * b[4] - a[31] * b[14];
+        let result_e23inf   = a[24] * b[0] + a[28] * b[5] - a[30] * b[2] - a[31] * b[12];
+        let result_e31inf   = a[25] * b[0] + a[29] * b[5] - a[30] * b[3] - a[31] * b[13];
+        let result_e0123    = a[26] * b[0] - a[31] * b[1];
+        let result_e012inf  = a[27] * b[0] - a[31] * b[4];
+        let result_e023inf  = a[28] * b[0] - a[31] * b[2];
+        let result_e031inf  = a[29] * b[0] - a[31] * b[3];
+        let result_e123inf  = a[30] * b[0] - a[31] * b[5];
+        let result_e0123inf = a[31] * b[0];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::Shr<&ConformalMultivector3<S>> for ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: &ConformalMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1        = a[0] * b[0] - a[1] * b[5] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[5] * b[1] - a[6] * b[12] - a[7] * b[13] - a[8] * b[14] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[6] - a[13] * b[7] - a[14] * b[8] + a[15] * b[15] + a[16] * b[23] + a[17] * b[24] + a[18] * b[25] + a[19] * b[19] + a[20] * b[20] + a[21] * b[21] - a[22] * b[22] + a[23] * b[16] + a[24] * b[17] + a[25] * b[18] + a[26] * b[30] - a[27] * b[27] - a[28] * b[28] - a[29] * b[29] + a[30] * b[26] - a[31] * b[31];
+        let result_e0       = a[1] * b[0] + a[6] * b[2] + a[7] * b[3] + a[8] * b[4] - a[15] * b[1] - a[16] * b[9] - a[17] * b[10] - a[18] * b[11] - a[19] * b[6] - a[20] * b[7] - a[21] * b[8] - a[26] * b[22] + a[27] * b[16] + a[28] * b[17] + a[29] * b[18] + a[31] * b[26];
+        let result_e1       = a[2] * b[0] + a[6] * b[5] + a[9] * b[3] - a[11] * b[4] - a[12] * b[1] + a[16] * b[13] - a[18] * b[14] - a[19] * b[15] - a[22] * b[10] - a[23] * b[7] + a[25] * b[8] - a[26] * b[24] - a[27] * b[20] + a[29] * b[21] + a[30] * b[17] + a[31] * b[28];
+        let result_e2       = a[3] * b[0] + a[7] * b[5] - a[9] * b[2] + a[10] * b[4] - a[13] * b[1] - a[16] * b[12] + a[17] * b[14] - a[20] * b[15] - a[22] * b[11] + a[23] * b[6] - a[24] * b[8] - a[26] * b[25] + a[27] * b[19] - a[28] * b[21] + a[30] * b[18] + a[31] * b[29];
+        let result_e3       = a[4] * b[0] + a[8] * b[5] - a[10] * b[3] + a[11] * b[2] - a[14] * b[1] - a[17] * b[13] + a[18] * b[12] - a[21] * b[15] - a[22] * b[9] + a[24] * b[7] - a[25] * b[6] - a[26] * b[23] + a[28] * b[20] - a[29] * b[19] + a[30] * b[16] + a[31] * b[27];
+        let result_einf     = a[5] * b[0] - a[12] * b[2] - a[13] * b[3] - a[14] * b[4] + a[15] * b[5] - a[19] * b[12] - a[20] * b[13] - a[21] * b[14] - a[23] * b[9] - a[24] * b[10] - a[25] * b[11] - a[27] * b[23] - a[28] * b[24] - a[29] * b[25] + a[30] * b[22] + a[31] * b[30];
+        let result_e01      = a[6] * b[0] + a[16] * b[3] - a[18] * b[4] - a[19] * b[1] - a[26] * b[10] - a[27] * b[7] + a[29] * b[8] + a[31] * b[17];
+        let result_e02      = a[7] * b[0] - a[16] * b[2] + a[17] * b[4] - a[20] * b[1] - a[26] * b[11] + a[27] * b[6] - a[28] * b[8] + a[31] * b[18];
+        let result_e03      = a[8] * b[0] - a[17] * b[3] + a[18] * b[2] - a[21] * b[1] - a[26] * b[9] + a[28] * b[7] - a[29] * b[6] + a[31] * b[16];
+        let result_e12      = a[9] * b[0] - a[16] * b[5] + a[22] * b[4] - a[23] * b[1] - a[26] * b[14] + a[27] * b[15] - a[30] * b[8] + a[31] * b[21];
+        let result_e23      = a[10] * b[0] - a[17] * b[5] + a[22] * b[2] - a[24] * b[1] - a[26] * b[12] + a[28] * b[15] - a[30] * b[6] + a[31] * b[19];
+        let result_e31      = a[11] * b[0] - a[18] * b[5] + a[22] * b[3] - a[25] * b[1] - a[26] * b[13] + a[29] * b[15] - a[30] * b[7] + a[31] * b[20];
+        let result_e1inf    = a[12] * b[0] - a[19] * b[5] - a[23] * b[3] + a[25] * b[4] + a[27] * b[13] - a[29] * b[14] - a[30] * b[10] + a[31] * b[24];
+        let result_e2inf    = a[13] * b[0] - a[20] * b[5] + a[23] * b[2] - a[24] * b[4] - a[27] * b[12] + a[28] * b[14] - a[30] * b[11] + a[31] * b[25];
+        let result_e3inf    = a[14] * b[0] - a[21] * b[5] + a[24] * b[3] - a[25] * b[2] - a[28] * b[13] + a[29] * b[12] - a[30] * b[9] + a[31] * b[23];
+        let result_e0inf    = a[15] * b[0] - a[19] * b[2] - a[20] * b[3] - a[21] * b[4] - a[27] * b[9] - a[28] * b[10] - a[29] * b[11] + a[31] * b[22];
+        let result_e012     = a[16] * b[0] + a[26] * b[4] - a[27] * b[1] - a[31] * b[8];
+        let result_e023     = a[17] * b[0] + a[26] * b[2] - a[28] * b[1] - a[31] * b[6];
+        let result_e031     = a[18] * b[0] + a[26] * b[3] - a[29] * b[1] - a[31] * b[7];
+        let result_e01inf   = a[19] * b[0] - a[27] * b[3] + a[29] * b[4] - a[31] * b[10];
+        let result_e02inf   = a[20] * b[0] + a[27] * b[2] - a[28] * b[4] - a[31] * b[11];
+        let result_e03inf   = a[21] * b[0] + a[28] * b[3] - a[29] * b[2] - a[31] * b[9];
+        let result_e123     = a[22] * b[0] + a[26] * b[5] - a[30] * b[1] - a[31] * b[15];
+        let result_e12inf   = a[23] * b[0] + a[27] * b[5] - a[30] * b[4] - a[31] * b[14];
+        let result_e23inf   = a[24] * b[0] + a[28] * b[5] - a[30] * b[2] - a[31] * b[12];
+        let result_e31inf   = a[25] * b[0] + a[29] * b[5] - a[30] * b[3] - a[31] * b[13];
+        let result_e0123    = a[26] * b[0] - a[31] * b[1];
+        let result_e012inf  = a[27] * b[0] - a[31] * b[4];
+        let result_e023inf  = a[28] * b[0] - a[31] * b[2];
+        let result_e031inf  = a[29] * b[0] - a[31] * b[3];
+        let result_e123inf  = a[30] * b[0] - a[31] * b[5];
+        let result_e0123inf = a[31] * b[0];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::Shr<ConformalMultivector3<S>> for &ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: ConformalMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1        = a[0] * b[0] - a[1] * b[5] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[5] * b[1] - a[6] * b[12] - a[7] * b[13] - a[8] * b[14] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[6] - a[13] * b[7] - a[14] * b[8] + a[15] * b[15] + a[16] * b[23] + a[17] * b[24] + a[18] * b[25] + a[19] * b[19] + a[20] * b[20] + a[21] * b[21] - a[22] * b[22] + a[23] * b[16] + a[24] * b[17] + a[25] * b[18] + a[26] * b[30] - a[27] * b[27] - a[28] * b[28] - a[29] * b[29] + a[30] * b[26] - a[31] * b[31];
+        let result_e0       = a[1] * b[0] + a[6] * b[2] + a[7] * b[3] + a[8] * b[4] - a[15] * b[1] - a[16] * b[9] - a[17] * b[10] - a[18] * b[11] - a[19] * b[6] - a[20] * b[7] - a[21] * b[8] - a[26] * b[22] + a[27] * b[16] + a[28] * b[17] + a[29] * b[18] + a[31] * b[26];
+        let result_e1       = a[2] * b[0] + a[6] * b[5] + a[9] * b[3] - a[11] * b[4] - a[12] * b[1] + a[16] * b[13] - a[18] * b[14] - a[19] * b[15] - a[22] * b[10] - a[23] * b[7] + a[25] * b[8] - a[26] * b[24] - a[27] * b[20] + a[29] * b[21] + a[30] * b[17] + a[31] * b[28];
+        let result_e2       = a[3] * b[0] + a[7] * b[5] - a[9] * b[2] + a[10] * b[4] - a[13] * b[1] - a[16] * b[12] + a[17] * b[14] - a[20] * b[15] - a[22] * b[11] + a[23] * b[6] - a[24] * b[8] - a[26] * b[25] + a[27] * b[19] - a[28] * b[21] + a[30] * b[18] + a[31] * b[29];
+        let result_e3       = a[4] * b[0] + a[8] * b[5] - a[10] * b[3] + a[11] * b[2] - a[14] * b[1] - a[17] * b[13] + a[18] * b[12] - a[21] * b[15] - a[22] * b[9] + a[24] * b[7] - a[25] * b[6] - a[26] * b[23] + a[28] * b[20] - a[29] * b[19] + a[30] * b[16] + a[31] * b[27];
+        let result_einf     = a[5] * b[0] - a[12] * b[2] - a[13] * b[3] - a[14] * b[4] + a[15] * b[5] - a[19] * b[12] - a[20] * b[13] - a[21] * b[14] - a[23] * b[9] - a[24] * b[10] - a[25] * b[11] - a[27] * b[23] - a[28] * b[24] - a[29] * b[25] + a[30] * b[22] + a[31] * b[30];
+        let result_e01      = a[6] * b[0] + a[16] * b[3] - a[18] * b[4] - a[19] * b[1] - a[26] * b[10] - a[27] * b[7] + a[29] * b[8] + a[31] * b[17];
+        let result_e02      = a[7] * b[0] - a[16] * b[2] + a[17] * b[4] - a[20] * b[1] - a[26] * b[11] + a[27] * b[6] - a[28] * b[8] + a[31] * b[18];
+        let result_e03      = a[8] * b[0] - a[17] * b[3] + a[18] * b[2] - a[21] * b[1] - a[26] * b[9] + a[28] * b[7] - a[29] * b[6] + a[31] * b[16];
+        let result_e12      = a[9] * b[0] - a[16] * b[5] + a[22] * b[4] - a[23] * b[1] - a[26] * b[14] + a[27] * b[15] - a[30] * b[8] + a[31] * b[21];
+        let result_e23      = a[10] * b[0] - a[17] * b[5] + a[22] * b[2] - a[24] * b[1] - a[26] * b[12] + a[28] * b[15] - a[30] * b[6] + a[31] * b[19];
+        let result_e31      = a[11] * b[0] - a[18] * b[5] + a[22] * b[3] - a[25] * b[1] - a[26] * b[13] + a[29] * b[15] - a[30] * b[7] + a[31] * b[20];
+        let result_e1inf    = a[12] * b[0] - a[19] * b[5] - a[23] * b[3] + a[25] * b[4] + a[27] * b[13] - a[29] * b[14] - a[30] * b[10] + a[31] * b[24];
+        let result_e2inf    = a[13] * b[0] - a[20] * b[5] + a[23] * b[2] - a[24] * b[4] - a[27] * b[12] + a[28] * b[14] - a[30] * b[11] + a[31] * b[25];
+        let result_e3inf    = a[14] * b[0] - a[21] * b[5] + a[24] * b[3] - a[25] * b[2] - a[28] * b[13] + a[29] * b[12] - a[30] * b[9] + a[31] * b[23];
+        let result_e0inf    = a[15] * b[0] - a[19] * b[2] - a[20] * b[3] - a[21] * b[4] - a[27] * b[9] - a[28] * b[10] - a[29] * b[11] + a[31] * b[22];
+        let result_e012     = a[16] * b[0] + a[26] * b[4] - a[27] * b[1] - a[31] * b[8];
+        let result_e023     = a[17] * b[0] + a[26] * b[2] - a[28] * b[1] - a[31] * b[6];
+        let result_e031     = a[18] * b[0] + a[26] * b[3] - a[29] * b[1] - a[31] * b[7];
+        let result_e01inf   = a[19] * b[0] - a[27] * b[3] + a[29] * b[4] - a[31] * b[10];
+        let result_e02inf   = a[20] * b[0] + a[27] * b[2] - a[28] * b[4] - a[31] * b[11];
+        let result_e03inf   = a[21] * b[0] + a[28] * b[3] - a[29] * b[2] - a[31] * b[9];
+        let result_e123     = a[22] * b[0] + a[26] * b[5] - a[30] * b[1] - a[31] * b[15];
+        let result_e12inf   = a[23] * b[0] + a[27] * b[5] - a[30] * b[4] - a[31] * b[14];
+        let result_e23inf   = a[24] * b[0] + a[28] * b[5] - a[30] * b[2] - a[31] * b[12];
+        let result_e31inf   = a[25] * b[0] + a[29] * b[5] - a[30] * b[3] - a[31] * b[13];
+        let result_e0123    = a[26] * b[0] - a[31] * b[1];
+        let result_e012inf  = a[27] * b[0] - a[31] * b[4];
+        let result_e023inf  = a[28] * b[0] - a[31] * b[2];
+        let result_e031inf  = a[29] * b[0] - a[31] * b[3];
+        let result_e123inf  = a[30] * b[0] - a[31] * b[5];
+        let result_e0123inf = a[31] * b[0];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::Shr<&ConformalMultivector3<S>> for &ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: &ConformalMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1        = a[0] * b[0] - a[1] * b[5] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[5] * b[1] - a[6] * b[12] - a[7] * b[13] - a[8] * b[14] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[6] - a[13] * b[7] - a[14] * b[8] + a[15] * b[15] + a[16] * b[23] + a[17] * b[24] + a[18] * b[25] + a[19] * b[19] + a[20] * b[20] + a[21] * b[21] - a[22] * b[22] + a[23] * b[16] + a[24] * b[17] + a[25] * b[18] + a[26] * b[30] - a[27] * b[27] - a[28] * b[28] - a[29] * b[29] + a[30] * b[26] - a[31] * b[31];
+        let result_e0       = a[1] * b[0] + a[6] * b[2] + a[7] * b[3] + a[8] * b[4] - a[15] * b[1] - a[16] * b[9] - a[17] * b[10] - a[18] * b[11] - a[19] * b[6] - a[20] * b[7] - a[21] * b[8] - a[26] * b[22] + a[27] * b[16] + a[28] * b[17] + a[29] * b[18] + a[31] * b[26];
+        let result_e1       = a[2] * b[0] + a[6] * b[5] + a[9] * b[3] - a[11] * b[4] - a[12] * b[1] + a[16] * b[13] - a[18] * b[14] - a[19] * b[15] - a[22] * b[10] - a[23] * b[7] + a[25] * b[8] - a[26] * b[24] - a[27] * b[20] + a[29] * b[21] + a[30] * b[17] + a[31] * b[28];
+        let result_e2       = a[3] * b[0] + a[7] * b[5] - a[9] * b[2] + a[10] * b[4] - a[13] * b[1] - a[16] * b[12] + a[17] * b[14] - a[20] * b[15] - a[22] * b[11] + a[23] * b[6] - a[24] * b[8] - a[26] * b[25] + a[27] * b[19] - a[28] * b[21] + a[30] * b[18] + a[31] * b[29];
+        let result_e3       = a[4] * b[0] + a[8] * b[5] - a[10] * b[3] + a[11] * b[2] - a[14] * b[1] - a[17] * b[13] + a[18] * b[12] - a[21] * b[15] - a[22] * b[9] + a[24] * b[7] - a[25] * b[6] - a[26] * b[23] + a[28] * b[20] - a[29] * b[19] + a[30] * b[16] + a[31] * b[27];
+        let result_einf     = a[5] * b[0] - a[12] * b[2] - a[13] * b[3] - a[14] * b[4] + a[15] * b[5] - a[19] * b[12] - a[20] * b[13] - a[21] * b[14] - a[23] * b[9] - a[24] * b[10] - a[25] * b[11] - a[27] * b[23] - a[28] * b[24] - a[29] * b[25] + a[30] * b[22] + a[31] * b[30];
+        let result_e01      = a[6] * b[0] + a[16] * b[3] - a[18] * b[4] - a[19] * b[1] - a[26] * b[10] - a[27] * b[7] + a[29] * b[8] + a[31] * b[17];
+        let result_e02      = a[7] * b[0] - a[16] * b[2] + a[17] * b[4] - a[20] * b[1] - a[26] * b[11] + a[27] * b[6] - a[28] * b[8] + a[31] * b[18];
+        let result_e03      = a[8] * b[0] - a[17] * b[3] + a[18] * b[2] - a[21] * b[1] - a[26] * b[9] + a[28] * b[7] - a[29] * b[6] + a[31] * b[16];
+        let result_e12      = a[9] * b[0] - a[16] * b[5] + a[22] * b[4] - a[23] * b[1] - a[26] * b[14] + a[27] * b[15] - a[30] * b[8] + a[31] * b[21];
+        let result_e23      = a[10] * b[0] - a[17] * b[5] + a[22] * b[2] - a[24] * b[1] - a[26] * b[12] + a[28] * b[15] - a[30] * b[6] + a[31] * b[19];
+        let result_e31      = a[11] * b[0] - a[18] * b[5] + a[22] * b[3] - a[25] * b[1] - a[26] * b[13] + a[29] * b[15] - a[30] * b[7] + a[31] * b[20];
+        let result_e1inf    = a[12] * b[0] - a[19] * b[5] - a[23] * b[3] + a[25] * b[4] + a[27] * b[13] - a[29] * b[14] - a[30] * b[10] + a[31] * b[24];
+        let result_e2inf    = a[13] * b[0] - a[20] * b[5] + a[23] * b[2] - a[24] * b[4] - a[27] * b[12] + a[28] * b[14] - a[30] * b[11] + a[31] * b[25];
+        let result_e3inf    = a[14] * b[0] - a[21] * b[5] + a[24] * b[3] - a[25] * b[2] - a[28] * b[13] + a[29] * b[12] - a[30] * b[9] + a[31] * b[23];
+        let result_e0inf    = a[15] * b[0] - a[19] * b[2] - a[20] * b[3] - a[21] * b[4] - a[27] * b[9] - a[28] * b[10] - a[29] * b[11] + a[31] * b[22];
+        let result_e012     = a[16] * b[0] + a[26] * b[4] - a[27] * b[1] - a[31] * b[8];
+        let result_e023     = a[17] * b[0] + a[26] * b[2] - a[28] * b[1] - a[31] * b[6];
+        let result_e031     = a[18] * b[0] + a[26] * b[3] - a[29] * b[1] - a[31] * b[7];
+        let result_e01inf   = a[19] * b[0] - a[27] * b[3] + a[29] * b[4] - a[31] * b[10];
+        let result_e02inf   = a[20] * b[0] + a[27] * b[2] - a[28] * b[4] - a[31] * b[11];
+        let result_e03inf   = a[21] * b[0] + a[28] * b[3] - a[29] * b[2] - a[31] * b[9];
+        let result_e123     = a[22] * b[0] + a[26] * b[5] - a[30] * b[1] - a[31] * b[15];
+        let result_e12inf   = a[23] * b[0] + a[27] * b[5] - a[30] * b[4] - a[31] * b[14];
+        let result_e23inf   = a[24] * b[0] + a[28] * b[5] - a[30] * b[2] - a[31] * b[12];
+        let result_e31inf   = a[25] * b[0] + a[29] * b[5] - a[30] * b[3] - a[31] * b[13];
+        let result_e0123    = a[26] * b[0] - a[31] * b[1];
+        let result_e012inf  = a[27] * b[0] - a[31] * b[4];
+        let result_e023inf  = a[28] * b[0] - a[31] * b[2];
+        let result_e031inf  = a[29] * b[0] - a[31] * b[3];
+        let result_e123inf  = a[30] * b[0] - a[31] * b[5];
+        let result_e0123inf = a[31] * b[0];
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::Shr<S> for ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1        = a[0] * other;
+        let result_e0       = a[1] * other;
+        let result_e1       = a[2] * other;
+        let result_e2       = a[3] * other;
+        let result_e3       = a[4] * other;
+        let result_einf     = a[5] * other;
+        let result_e01      = a[6] * other;
+        let result_e02      = a[7] * other;
+        let result_e03      = a[8] * other;
+        let result_e12      = a[9] * other;
+        let result_e23      = a[10] * other;
+        let result_e31      = a[11] * other;
+        let result_e1inf    = a[12] * other;
+        let result_e2inf    = a[13] * other;
+        let result_e3inf    = a[14] * other;
+        let result_e0inf    = a[15] * other;
+        let result_e012     = a[16] * other;
+        let result_e023     = a[17] * other;
+        let result_e031     = a[18] * other;
+        let result_e01inf   = a[19] * other;
+        let result_e02inf   = a[20] * other;
+        let result_e03inf   = a[21] * other;
+        let result_e123     = a[22] * other;
+        let result_e12inf   = a[23] * other;
+        let result_e23inf   = a[24] * other;
+        let result_e31inf   = a[25] * other;
+        let result_e0123    = a[26] * other;
+        let result_e012inf  = a[27] * other;
+        let result_e023inf  = a[28] * other;
+        let result_e031inf  = a[29] * other;
+        let result_e123inf  = a[30] * other;
+        let result_e0123inf = a[31] * other;
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ops::Shr<S> for &ConformalMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ConformalMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1        = a[0] * other;
+        let result_e0       = a[1] * other;
+        let result_e1       = a[2] * other;
+        let result_e2       = a[3] * other;
+        let result_e3       = a[4] * other;
+        let result_einf     = a[5] * other;
+        let result_e01      = a[6] * other;
+        let result_e02      = a[7] * other;
+        let result_e03      = a[8] * other;
+        let result_e12      = a[9] * other;
+        let result_e23      = a[10] * other;
+        let result_e31      = a[11] * other;
+        let result_e1inf    = a[12] * other;
+        let result_e2inf    = a[13] * other;
+        let result_e3inf    = a[14] * other;
+        let result_e0inf    = a[15] * other;
+        let result_e012     = a[16] * other;
+        let result_e023     = a[17] * other;
+        let result_e031     = a[18] * other;
+        let result_e01inf   = a[19] * other;
+        let result_e02inf   = a[20] * other;
+        let result_e03inf   = a[21] * other;
+        let result_e123     = a[22] * other;
+        let result_e12inf   = a[23] * other;
+        let result_e23inf   = a[24] * other;
+        let result_e31inf   = a[25] * other;
+        let result_e0123    = a[26] * other;
+        let result_e012inf  = a[27] * other;
+        let result_e023inf  = a[28] * other;
+        let result_e031inf  = a[29] * other;
+        let result_e123inf  = a[30] * other;
+        let result_e0123inf = a[31] * other;
+
+        ConformalMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_einf,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e1inf,
+            result_e2inf,
+            result_e3inf,
+            result_e0inf,
+            result_e012,
+            result_e023,
+            result_e031,
+            result_e01inf,
+            result_e02inf,
+            result_e03inf,
+            result_e123,
+            result_e12inf,
+            result_e23inf,
+            result_e31inf,
+            result_e0123,
+            result_e012inf,
+            result_e023inf,
+            result_e031inf,
+            result_e123inf,
+            result_e0123inf,
+        )
+    }
+}
+
+impl<S> ConformalMultivector3<S>
+where
+    S: ScalarFloat,
+{
+    /// Embed the grade one part of a Euclidean multivector as a conformal vector.
+    #[inline]
+    fn from_euclidean_vector(vector: &EuclideanMultivector3<S>) -> Self {
+        let mut result = Self::zero();
+        result.data[2] = vector[1];
+        result.data[3] = vector[2];
+        result.data[4] = vector[3];
+
+        result
+    }
+
+    /// Embed a Euclidean vector into conformal space as a point.
+    ///
+    /// The conformal point of the Euclidean vector `x` is the null vector
+    /// ```text
+    /// up(x) := e0 + x + (1 / 2) * (x | x) * einf
+    /// ```
+    /// Only the grade one part of `vector` is used. The scalar product of two
+    /// conformal points encodes the squared Euclidean distance between them
+    /// ```text
+    /// up(x) | up(y) == -(1 / 2) * |x - y|^2
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let x = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let y = EuclideanMultivector3::new(0_f64, 4_f64, 6_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let point_x = ConformalMultivector3::up(&x);
+    /// let point_y = ConformalMultivector3::up(&y);
+    ///
+    /// assert_eq!(point_x.e0, 1_f64);
+    /// assert_eq!(point_x.einf, 7_f64);
+    /// assert!((point_x | point_x).is_zero());
+    /// assert_relative_eq!((point_x | point_y)[0], -12.5_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn up(vector: &EuclideanMultivector3<S>) -> Self {
+        let one_half = S::one() / (S::one() + S::one());
+        let mut result = Self::from_euclidean_vector(vector);
+        let norm_squared = vector[1] * vector[1] + vector[2] * vector[2] + vector[3] * vector[3];
+        result.data[1] = S::one();
+        result.data[5] = one_half * norm_squared;
+
+        result
+    }
+
+    /// Project a conformal point down to a Euclidean vector.
+    ///
+    /// A conformal point `P` is a null vector whose weight `-(P | einf)`
+    /// is its `e0` component. The Euclidean vector of the point is its
+    /// Euclidean part divided by its weight, so that `down(up(x)) == x`,
+    /// and so that points of any nonzero weight project to the same
+    /// Euclidean vector. The function returns `None` when the weight of
+    /// `self` vanishes up to roundoff relative to its Euclidean part, as for
+    /// directions and other points at infinity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let x = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let point = ConformalMultivector3::up(&x) * 5_f64;
+    ///
+    /// assert_relative_eq!(point.down().unwrap(), x, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    ///
+    /// let direction = ConformalMultivector3::unit_e1() * 2_f64 - ConformalMultivector3::unit_e3();
+    ///
+    /// assert!(direction.down().is_none());
+    /// ```
+    pub fn down(&self) -> Option<EuclideanMultivector3<S>> {
+        let weight = self.data[1];
+        let euclidean_norm = (self.data[2] * self.data[2] + self.data[3] * self.data[3] + self.data[4] * self.data[4]).sqrt();
+        let is_finite = ulps_ne!(
+            weight,
+            S::zero(),
+            abs_diff_all <= S::default_epsilon() * euclidean_norm,
+            ulps_all <= S::default_max_ulps()
+        );
+        if !is_finite {
+            return None;
+        }
+
+        let one_over_weight = S::one() / weight;
+        let x = self.data[2] * one_over_weight;
+        let y = self.data[3] * one_over_weight;
+        let z = self.data[4] * one_over_weight;
+
+        Some(EuclideanMultivector3::new(S::zero(), x, y, z, S::zero(), S::zero(), S::zero(), S::zero()))
+    }
+
+    /// Construct the sphere with center `center` and radius `radius`.
+    ///
+    /// The sphere is the undual of the dual sphere
+    /// ```text
+    /// up(center) - (1 / 2) * radius^2 * einf
+    /// ```
+    /// which is the four-blade spanned by any four distinct points on the
+    /// sphere, up to scale. Only the grade one part of `center` is used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let center = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let sphere = ConformalMultivector3::from_sphere(&center, 2_f64);
+    /// let on_sphere = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 5_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let zero: ConformalMultivector3<f64> = ConformalMultivector3::zero();
+    ///
+    /// assert_relative_eq!(ConformalMultivector3::up(&on_sphere) ^ sphere, zero, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert!(!(ConformalMultivector3::up(&center) ^ sphere).is_zero());
+    /// ```
+    pub fn from_sphere(center: &EuclideanMultivector3<S>, radius: S) -> Self {
+        let one_half = S::one() / (S::one() + S::one());
+        let mut dual_sphere = Self::up(center);
+        dual_sphere.data[5] -= one_half * radius * radius;
+
+        dual_sphere.undual()
+    }
+
+    /// Construct the plane with unit normal vector `normal` at the signed
+    /// distance `distance` from the origin.
+    ///
+    /// The plane contains the points `x` satisfying `normal | x == distance`.
+    /// It is the undual of the dual plane
+    /// ```text
+    /// normal + distance * einf
+    /// ```
+    /// which is the four-blade spanned by any three distinct points on the
+    /// plane and the point at infinity, up to scale. Only the grade one part
+    /// of `normal` is used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let normal = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let plane = ConformalMultivector3::from_plane(&normal, 2_f64);
+    /// let on_plane = EuclideanMultivector3::new(0_f64, 5_f64, -1_f64, 2_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let zero: ConformalMultivector3<f64> = ConformalMultivector3::zero();
+    ///
+    /// assert_relative_eq!(ConformalMultivector3::up(&on_plane) ^ plane, zero, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn from_plane(normal: &EuclideanMultivector3<S>, distance: S) -> Self {
+        let mut dual_plane = Self::from_euclidean_vector(normal);
+        dual_plane.data[5] = distance;
+
+        dual_plane.undual()
+    }
+
+    /// Construct the circle with center `center`, unit normal vector `normal`,
+    /// and radius `radius`.
+    ///
+    /// The circle is the meet of the sphere with center `center` and radius
+    /// `radius`, and the plane through `center` orthogonal to `normal`. It is
+    /// the three-blade spanned by any three distinct points on the circle, up
+    /// to scale. Only the grade one parts of `center` and `normal` are used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let center = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let normal = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let circle = ConformalMultivector3::from_circle(&center, &normal, 2_f64);
+    /// let on_circle = EuclideanMultivector3::new(0_f64, 1_f64, 4_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let off_circle = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 5_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let zero: ConformalMultivector3<f64> = ConformalMultivector3::zero();
+    ///
+    /// assert_relative_eq!(ConformalMultivector3::up(&on_circle) ^ circle, zero, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert!(!(ConformalMultivector3::up(&off_circle) ^ circle).is_zero());
+    /// ```
+    pub fn from_circle(center: &EuclideanMultivector3<S>, normal: &EuclideanMultivector3<S>, radius: S) -> Self {
+        let distance = normal[1] * center[1] + normal[2] * center[2] + normal[3] * center[3];
+        let sphere = Self::from_sphere(center, radius);
+        let plane = Self::from_plane(normal, distance);
+
+        sphere & plane
+    }
+
+    /// Construct the line through the point `point` with direction `direction`.
+    ///
+    /// The line is the three-blade
+    /// ```text
+    /// up(point) ^ direction ^ einf
+    /// ```
+    /// which is the three-blade spanned by any two distinct points on the
+    /// line and the point at infinity, up to scale. Only the grade one parts of
+    /// `point` and `direction` are used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let point = EuclideanMultivector3::new(0_f64, 1_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let direction = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let line = ConformalMultivector3::from_line(&point, &direction);
+    /// let on_line = EuclideanMultivector3::new(0_f64, 1_f64, 1_f64, 7_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let zero: ConformalMultivector3<f64> = ConformalMultivector3::zero();
+    ///
+    /// assert_relative_eq!(ConformalMultivector3::up(&on_line) ^ line, zero, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn from_line(point: &EuclideanMultivector3<S>, direction: &EuclideanMultivector3<S>) -> Self {
+        Self::up(point) ^ Self::from_euclidean_vector(direction) ^ Self::unit_einf()
+    }
+
+    /// Construct the point pair consisting of the points `p` and `q`.
+    ///
+    /// The point pair is the two-blade
+    /// ```text
+    /// up(p) ^ up(q)
+    /// ```
+    /// Only the grade one parts of `p` and `q` are used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let p = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let q = EuclideanMultivector3::new(0_f64, 4_f64, -1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let point_pair = ConformalMultivector3::from_point_pair(&p, &q);
+    /// let (result_p, result_q) = point_pair.split_point_pair().unwrap();
+    ///
+    /// assert_relative_eq!(result_p, p, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(result_q, q, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn from_point_pair(p: &EuclideanMultivector3<S>, q: &EuclideanMultivector3<S>) -> Self {
+        Self::up(p) ^ Self::up(q)
+    }
+
+    /// Split a point pair into its two points.
+    ///
+    /// For a point pair `T == up(p) ^ up(q)` the points are recovered from
+    /// ```text
+    /// (T - sqrt(T * T)) * (einf << T) ~ up(p)
+    /// (T + sqrt(T * T)) * (einf << T) ~ up(q)
+    /// ```
+    /// where `~` denotes equality up to scale. The points are returned in the
+    /// order `(p, q)` when `self` is a positive multiple of `up(p) ^ up(q)`. The
+    /// function returns `None` when `self` is an imaginary point pair, i.e.
+    /// when `T * T` is negative, as for the meet of two disjoint spheres, when
+    /// one of the points is at infinity, or when `self` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let center = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let direction = EuclideanMultivector3::new(0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let sphere = ConformalMultivector3::from_sphere(&center, 2_f64);
+    /// let line = ConformalMultivector3::from_line(&center, &direction);
+    /// let (p, q) = sphere.meet(&line).split_point_pair().unwrap();
+    ///
+    /// assert_relative_eq!((p - q).magnitude(), 4_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(p.magnitude(), 2_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(q.magnitude(), 2_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn split_point_pair(&self) -> Option<(EuclideanMultivector3<S>, EuclideanMultivector3<S>)> {
+        let square = (self * self)[0];
+        if square < S::zero() || self.is_zero() {
+            return None;
+        }
+
+        let root = Self::from_scalar(square.sqrt());
+        let direction = Self::unit_einf() << self;
+        let p = (self - root) * direction;
+        let q = (self + root) * direction;
+
+        Some((p.down()?, q.down()?))
+    }
+
+    /// Compute the meet of two geometric objects.
+    ///
+    /// The meet is the intersection of two geometric objects, and is given by
+    /// the regressive product
+    /// ```text
+    /// meet(A, B) := A & B
+    /// ```
+    /// For example, two spheres meet in a circle, a sphere and a line meet in
+    /// a point pair, and a line and a plane meet in a flat point.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let center = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let normal = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let sphere = ConformalMultivector3::from_sphere(&center, 1_f64);
+    /// let plane = ConformalMultivector3::from_plane(&normal, 0_f64);
+    /// let expected = ConformalMultivector3::from_circle(&center, &normal, 1_f64);
+    /// let result = sphere.meet(&plane);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn meet(&self, other: &Self) -> Self {
+        self & other
+    }
+
+    /// Compute the join of two geometric objects.
+    ///
+    /// The join is the smallest geometric object containing both geometric
+    /// objects, and is given by the outer product
+    /// ```text
+    /// join(A, B) := A ^ B
+    /// ```
+    /// For example, three points join into a circle, and four points join
+    /// into a sphere.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let e1 = EuclideanMultivector3::new(0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let e2 = EuclideanMultivector3::new(0_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let p = ConformalMultivector3::up(&e1);
+    /// let q = ConformalMultivector3::up(&e2);
+    /// let r = ConformalMultivector3::up(&-e1);
+    /// let circle = p.join(&q).join(&r);
+    /// let center = EuclideanMultivector3::zero();
+    /// let normal = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let expected = ConformalMultivector3::from_circle(&center, &normal, 1_f64);
+    ///
+    /// // The join and the constructed circle agree up to scale.
+    /// let scale = circle[16] / expected[16];
+    /// assert_relative_eq!(circle, expected * scale, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn join(&self, other: &Self) -> Self {
+        self ^ other
+    }
+
+    /// Construct the translator that translates by the Euclidean vector
+    /// `displacement`.
+    ///
+    /// The translator is the versor
+    /// ```text
+    /// T = 1 - (1 / 2) * displacement * einf
+    /// ```
+    /// Only the grade one part of `displacement` is used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let displacement = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let translator = ConformalMultivector3::translator(&displacement);
+    /// let origin = ConformalMultivector3::up(&EuclideanMultivector3::zero());
+    /// let result = origin.apply_versor(&translator).unwrap();
+    ///
+    /// assert_relative_eq!(result.down().unwrap(), displacement, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn translator(displacement: &EuclideanMultivector3<S>) -> Self {
+        let one_half = S::one() / (S::one() + S::one());
+        let mut result = Self::unit_scalar();
+        result.data[12] = -one_half * displacement[1];
+        result.data[13] = -one_half * displacement[2];
+        result.data[14] = -one_half * displacement[3];
+
+        result
+    }
+
+    /// Construct the conformal rotor of a Euclidean rotor.
+    ///
+    /// The scalar and bivector parts of `rotor` are embedded in the conformal
+    /// algebra. The rotor rotates about the origin.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// # use cggeomalg::e3ga::{
+    /// #     EuclideanMultivector3,
+    /// #     Rotor3,
+    /// # };
+    /// # use core::f64;
+    /// #
+    /// let half_angle = f64::consts::FRAC_PI_4;
+    /// let rotor = Rotor3::new(half_angle.cos(), -half_angle.sin(), 0_f64, 0_f64);
+    /// let x = EuclideanMultivector3::new(0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let expected = EuclideanMultivector3::new(0_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let result = ConformalMultivector3::up(&x).apply_versor(&ConformalMultivector3::from_rotor(&rotor)).unwrap();
+    ///
+    /// assert_relative_eq!(result.down().unwrap(), expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn from_rotor(rotor: &Rotor3<S>) -> Self {
+        let mut result = Self::zero();
+        result.data[0] = rotor[0];
+        result.data[9] = rotor[1];
+        result.data[10] = rotor[2];
+        result.data[11] = rotor[3];
+
+        result
+    }
+
+    /// Construct the dilator that scales about the origin by the factor `scale`.
+    ///
+    /// The dilator is the versor
+    /// ```text
+    /// D = cosh(ln(scale) / 2) + sinh(ln(scale) / 2) * e0inf
+    /// ```
+    /// where `scale` is positive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let x = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let dilator = ConformalMultivector3::dilator(3_f64);
+    /// let result = ConformalMultivector3::up(&x).apply_versor(&dilator).unwrap();
+    ///
+    /// assert_relative_eq!(result.down().unwrap(), x * 3_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn dilator(scale: S) -> Self {
+        let one_half = S::one() / (S::one() + S::one());
+        let half_log_scale = scale.ln() * one_half;
+        let mut result = Self::from_scalar(half_log_scale.cosh());
+        result.data[15] = half_log_scale.sinh();
+
+        result
+    }
+
+    /// Construct the versor of the inversion in the sphere with center `center`
+    /// and radius `radius`.
+    ///
+    /// The versor is the dual sphere
+    /// ```text
+    /// up(center) - (1 / 2) * radius^2 * einf
+    /// ```
+    /// which is an odd versor. The inversion maps a point at distance `d`
+    /// from `center` to the point at distance `radius^2 / d` along the same
+    /// ray. Only the grade one part of `center` is used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let center = EuclideanMultivector3::zero();
+    /// let inversion = ConformalMultivector3::inversion(&center, 2_f64);
+    /// let x = EuclideanMultivector3::new(0_f64, 8_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let expected = EuclideanMultivector3::new(0_f64, 0.5_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let result = ConformalMultivector3::up(&x).apply_versor(&inversion).unwrap();
+    ///
+    /// assert_relative_eq!(result.down().unwrap(), expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn inversion(center: &EuclideanMultivector3<S>, radius: S) -> Self {
+        let one_half = S::one() / (S::one() + S::one());
+        let mut result = Self::up(center);
+        result.data[5] -= one_half * radius * radius;
+
+        result
+    }
+
+    /// Apply a versor to a multivector.
+    ///
+    /// Every even versor `V` acts on a multivector `mv` by
+    /// ```text
+    /// V * mv * inv(V)
+    /// ```
+    /// and every odd versor acts by
+    /// ```text
+    /// V * involute(mv) * inv(V)
+    /// ```
+    /// where `inv(V) == rev(V) / (V * rev(V))` for a versor `V`. The function
+    /// returns `None` when the versor has both even and odd parts, or when
+    /// `V * rev(V)` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::cga3::ConformalMultivector3;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let center = EuclideanMultivector3::new(0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let sphere = ConformalMultivector3::from_sphere(&center, 1_f64);
+    /// let displacement = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 5_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let translator = ConformalMultivector3::translator(&displacement);
+    /// let expected = ConformalMultivector3::from_sphere(&(center + displacement), 1_f64);
+    /// let result = sphere.apply_versor(&translator).unwrap();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn apply_versor(&self, versor: &Self) -> Option<Self> {
+        let even_part = versor.grade(0) + versor.grade(2) + versor.grade(4);
+        let odd_part = versor.grade(1) + versor.grade(3) + versor.grade(5);
+        let mv = if odd_part.is_zero() {
+            *self
+        } else if even_part.is_zero() {
+            self.involute()
+        } else {
+            return None;
+        };
+        let versor_reverse = versor.reverse();
+        let norm_squared = (versor * versor_reverse)[0];
+        if norm_squared.is_zero() {
+            return None;
+        }
+
+        Some(versor * mv * versor_reverse / norm_squared)
+    }
+}
+
+
+impl_coords!(ViewC3, { scalar, e0, e1, e2, e3, einf, e01, e02, e03, e12, e23, e31, e1inf, e2inf, e3inf, e0inf, e012, e023, e031, e01inf, e02inf, e03inf, e123, e12inf, e23inf, e31inf, e0123, e012inf, e023inf, e031inf, e123inf, e0123inf });
+impl_coords_deref!(ConformalMultivector3, ViewC3);
+
+
+macro_rules! impl_scalar_multivector_add_ops {
+    ($Lhs:ty => $Rhs:ty => $Output:ty, { $scalar_index:expr }, { $($other_index:expr),* }) => {
+        impl ops::Add<$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn add(self, other: $Rhs) -> $Output {
+                Self::Output::new(self + other[$scalar_index], $(other[$other_index]),* )
+            }
+        }
+
+        impl ops::Add<&$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn add(self, other: &$Rhs) -> $Output {
+                Self::Output::new(self + other[$scalar_index], $(other[$other_index]),* )
+            }
+        }
+    }
+}
+
+impl_scalar_multivector_add_ops!(u8    => ConformalMultivector3<u8>    => ConformalMultivector3<u8>,    {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_add_ops!(u16   => ConformalMultivector3<u16>   => ConformalMultivector3<u16>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_add_ops!(u32   => ConformalMultivector3<u32>   => ConformalMultivector3<u32>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_add_ops!(u64   => ConformalMultivector3<u64>   => ConformalMultivector3<u64>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_add_ops!(u128  => ConformalMultivector3<u128>  => ConformalMultivector3<u128>,  {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_add_ops!(usize => ConformalMultivector3<usize> => ConformalMultivector3<usize>, {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_add_ops!(i8    => ConformalMultivector3<i8>    => ConformalMultivector3<i8>,    {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_add_ops!(i16   => ConformalMultivector3<i16>   => ConformalMultivector3<i16>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_add_ops!(i32   => ConformalMultivector3<i32>   => ConformalMultivector3<i32>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_add_ops!(i64   => ConformalMultivector3<i64>   => ConformalMultivector3<i64>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_add_ops!(i128  => ConformalMultivector3<i128>  => ConformalMultivector3<i128>,  {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_add_ops!(isize => ConformalMultivector3<isize> => ConformalMultivector3<isize>, {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_add_ops!(f32   => ConformalMultivector3<f32>   => ConformalMultivector3<f32>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_add_ops!(f64   => ConformalMultivector3<f64>   => ConformalMultivector3<f64>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+
+
+macro_rules! impl_scalar_multivector_sub_ops {
+    ($Lhs:ty => $Rhs:ty => $Output:ty, { $scalar_index:expr }, { $($other_index:expr),* }) => {
+        impl ops::Sub<$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn sub(self, other: $Rhs) -> Self::Output {
+                Self::Output::new(self - other[$scalar_index], $(-other[$other_index]),* )
+            }
+        }
+
+        impl ops::Sub<&$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn sub(self, other: &$Rhs) -> Self::Output {
+                Self::Output::new(self - other[$scalar_index], $(-other[$other_index]),* )
+            }
+        }
+    }
+}
+
+impl_scalar_multivector_sub_ops!(i8    => ConformalMultivector3<i8>    => ConformalMultivector3<i8>,    {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_sub_ops!(i16   => ConformalMultivector3<i16>   => ConformalMultivector3<i16>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_sub_ops!(i32   => ConformalMultivector3<i32>   => ConformalMultivector3<i32>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_sub_ops!(i64   => ConformalMultivector3<i64>   => ConformalMultivector3<i64>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_sub_ops!(i128  => ConformalMultivector3<i128>  => ConformalMultivector3<i128>,  {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_sub_ops!(isize => ConformalMultivector3<isize> => ConformalMultivector3<isize>, {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_sub_ops!(f32   => ConformalMultivector3<f32>   => ConformalMultivector3<f32>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_sub_ops!(f64   => ConformalMultivector3<f64>   => ConformalMultivector3<f64>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+
+
+macro_rules! impl_scalar_multivector_mul_ops {
+    ($Lhs:ty => $Rhs:ty => $Output:ty, { $($index:expr),* }) => {
+        impl ops::Mul<$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn mul(self, other: $Rhs) -> Self::Output {
+                Self::Output::new( $(self * other[$index]),* )
+            }
+        }
+
+        impl ops::Mul<&$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn mul(self, other: &$Rhs) -> Self::Output {
+                Self::Output::new( $(self * other[$index]),* )
+            }
+        }
+    }
+}
+
+impl_scalar_multivector_mul_ops!(u8    => ConformalMultivector3<u8>    => ConformalMultivector3<u8>,    {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_mul_ops!(u16   => ConformalMultivector3<u16>   => ConformalMultivector3<u16>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_mul_ops!(u32   => ConformalMultivector3<u32>   => ConformalMultivector3<u32>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_mul_ops!(u64   => ConformalMultivector3<u64>   => ConformalMultivector3<u64>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_mul_ops!(u128  => ConformalMultivector3<u128>  => ConformalMultivector3<u128>,  {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_mul_ops!(usize => ConformalMultivector3<usize> => ConformalMultivector3<usize>, {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_mul_ops!(i8    => ConformalMultivector3<i8>    => ConformalMultivector3<i8>,    {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_mul_ops!(i16   => ConformalMultivector3<i16>   => ConformalMultivector3<i16>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_mul_ops!(i32   => ConformalMultivector3<i32>   => ConformalMultivector3<i32>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_mul_ops!(i64   => ConformalMultivector3<i64>   => ConformalMultivector3<i64>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_mul_ops!(i128  => ConformalMultivector3<i128>  => ConformalMultivector3<i128>,  {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_mul_ops!(isize => ConformalMultivector3<isize> => ConformalMultivector3<isize>, {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_mul_ops!(f32   => ConformalMultivector3<f32>   => ConformalMultivector3<f32>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+impl_scalar_multivector_mul_ops!(f64   => ConformalMultivector3<f64>   => ConformalMultivector3<f64>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31});
+
+
+macro_rules! impl_scalar_multivector_bitor_ops {
+    ($Lhs:ty) => {
+        impl ops::BitOr<ConformalMultivector3<$Lhs>> for $Lhs {
+            type Output = ConformalMultivector3<$Lhs>;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            #[inline]
+            fn bitor(self, other: ConformalMultivector3<$Lhs>) -> Self::Output {
+                let mut result = Self::Output::zero();
+                result[0] = self * other[0];
+
+                result
+            }
+        }
+
+        impl ops::BitOr<&ConformalMultivector3<$Lhs>> for $Lhs {
+            type Output = ConformalMultivector3<$Lhs>;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            #[inline]
+            fn bitor(self, other: &ConformalMultivector3<$Lhs>) -> Self::Output {
+                let mut result = Self::Output::zero();
+                result[0] = self * other[0];
+
+                result
+            }
+        }
+    };
+}
+
+impl_scalar_multivector_bitor_ops!(u8);
+impl_scalar_multivector_bitor_ops!(u16);
+impl_scalar_multivector_bitor_ops!(u32);
+impl_scalar_multivector_bitor_ops!(u64);
+impl_scalar_multivector_bitor_ops!(u128);
+impl_scalar_multivector_bitor_ops!(usize);
+impl_scalar_multivector_bitor_ops!(i8);
+impl_scalar_multivector_bitor_ops!(i16);
+impl_scalar_multivector_bitor_ops!(i32);
+impl_scalar_multivector_bitor_ops!(i64);
+impl_scalar_multivector_bitor_ops!(i128);
+impl_scalar_multivector_bitor_ops!(isize);
+impl_scalar_multivector_bitor_ops!(f32);
+impl_scalar_multivector_bitor_ops!(f64);
+
+
+macro_rules! impl_scalar_multivector_bitxor_ops {
+    ($Lhs:ty) => {
+        impl ops::BitXor<ConformalMultivector3<$Lhs>> for $Lhs {
+            type Output = ConformalMultivector3<$Lhs>;
+
+            #[inline]
+            fn bitxor(self, other: ConformalMultivector3<$Lhs>) -> Self::Output {
+                let mut result = Self::Output::zero();
+                result[0] = self * other[0];
+                result[1] = self * other[1];
+                result[2] = self * other[2];
+                result[3] = self * other[3];
+                result[4] = self * other[4];
+                result[5] = self * other[5];
+                result[6] = self * other[6];
+                result[7] = self * other[7];
+                result[8] = self * other[8];
+                result[9] = self * other[9];
+                result[10] = self * other[10];
+                result[11] = self * other[11];
+                result[12] = self * other[12];
+                result[13] = self * other[13];
+                result[14] = self * other[14];
+                result[15] = self * other[15];
+                result[16] = self * other[16];
+                result[17] = self * other[17];
+                result[18] = self * other[18];
+                result[19] = self * other[19];
+                result[20] = self * other[20];
+                result[21] = self * other[21];
+                result[22] = self * other[22];
+                result[23] = self * other[23];
+                result[24] = self * other[24];
+                result[25] = self * other[25];
+                result[26] = self * other[26];
+                result[27] = self * other[27];
+                result[28] = self * other[28];
+                result[29] = self * other[29];
+                result[30] = self * other[30];
+                result[31] = self * other[31];
+
+                result
+            }
+        }
+
+        impl ops::BitXor<&ConformalMultivector3<$Lhs>> for $Lhs {
+            type Output = ConformalMultivector3<$Lhs>;
+
+            #[inline]
+            fn bitxor(self, other: &ConformalMultivector3<$Lhs>) -> Self::Output {
+                let mut result = Self::Output::zero();
+                result[0] = self * other[0];
+                result[1] = self * other[1];
+                result[2] = self * other[2];
+                result[3] = self * other[3];
+                result[4] = self * other[4];
+                result[5] = self * other[5];
+                result[6] = self * other[6];
+                result[7] = self * other[7];
+                result[8] = self * other[8];
+                result[9] = self * other[9];
+                result[10] = self * other[10];
+                result[11] = self * other[11];
+                result[12] = self * other[12];
+                result[13] = self * other[13];
+                result[14] = self * other[14];
+                result[15] = self * other[15];
+                result[16] = self * other[16];
+                result[17] = self * other[17];
+                result[18] = self * other[18];
+                result[19] = self * other[19];
+                result[20] = self * other[20];
+                result[21] = self * other[21];
+                result[22] = self * other[22];
+                result[23] = self * other[23];
+                result[24] = self * other[24];
+                result[25] = self * other[25];
+                result[26] = self * other[26];
+                result[27] = self * other[27];
+                result[28] = self * other[28];
+                result[29] = self * other[29];
+                result[30] = self * other[30];
+                result[31] = self * other[31];
+
+                result
+            }
+        }
+    };
+}
+
+impl_scalar_multivector_bitxor_ops!(u8);
+impl_scalar_multivector_bitxor_ops!(u16);
+impl_scalar_multivector_bitxor_ops!(u32);
+impl_scalar_multivector_bitxor_ops!(u64);
+impl_scalar_multivector_bitxor_ops!(u128);
+impl_scalar_multivector_bitxor_ops!(usize);
+impl_scalar_multivector_bitxor_ops!(i8);
+impl_scalar_multivector_bitxor_ops!(i16);
+impl_scalar_multivector_bitxor_ops!(i32);
+impl_scalar_multivector_bitxor_ops!(i64);
+impl_scalar_multivector_bitxor_ops!(i128);
+impl_scalar_multivector_bitxor_ops!(isize);
+impl_scalar_multivector_bitxor_ops!(f32);
+impl_scalar_multivector_bitxor_ops!(f64);
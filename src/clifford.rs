@@ -0,0 +1,1460 @@
+use crate::e2ga::EuclideanMultivector2;
+use crate::e3ga::EuclideanMultivector3;
+use crate::e4ga::EuclideanMultivector4;
+use crate::impl_approx_cmp_ops;
+use crate::scalar::{
+    Scalar,
+    ScalarFloat,
+    ScalarSigned,
+};
+use crate::sta::SpacetimeMultivector;
+use approx_cmp::ulps_ne;
+use core::cmp;
+use core::fmt;
+use core::ops;
+
+
+/// A stack-allocated multivector in the geometric algebra `Cl(P, Q, R)` of
+/// a vector space of dimension `P + Q + R`.
+///
+/// The generators `e1, e2, ..., e{P + Q + R}` of the algebra satisfy
+/// ```text
+/// ei * ei == 1    for 1 <= i <= P
+/// ei * ei == -1   for P < i <= P + Q
+/// ei * ei == 0    for P + Q < i <= P + Q + R
+/// ```
+/// and anticommute pairwise. The multivector stores one component per basis blade,
+/// so the number of components `N` must be `2^(P + Q + R)`. Stable Rust cannot
+/// compute an array length from the other const parameters, so `N` is given
+/// explicitly, and every operation that constructs a multivector checks it at
+/// compile time.
+///
+/// The basis blades are indexed by bitmask. Bit `k` of the index of a blade is
+/// set if and only if the generator `e{k + 1}` appears in the blade, and the
+/// generators in a blade appear in ascending order. For instance, in the
+/// three-dimensional algebra, the component with index `0b101 == 5` is the
+/// coefficient of `e13`, and the basis is
+/// `{1, e1, e2, e12, e3, e13, e23, e123}`. The products are computed from the
+/// bitmask representation of the blades, so this type covers every signature at
+/// once, at the price of being slower than the hand-unrolled multivectors such as
+/// `EuclideanMultivector3`.
+///
+/// # Example
+///
+/// ```
+/// # use cggeomalg::clifford::Multivector;
+/// #
+/// // The quaternions are the even subalgebra of `Cl(0, 3)`, or equivalently, `Cl(0, 2)`.
+/// type Quaternion = Multivector<i32, 0, 2, 0, 4>;
+///
+/// let i = Quaternion::unit_blade(0b01);
+/// let j = Quaternion::unit_blade(0b10);
+/// let k = Quaternion::unit_blade(0b11);
+/// let minus_one = -Quaternion::unit_scalar();
+///
+/// assert_eq!(i * i, minus_one);
+/// assert_eq!(j * j, minus_one);
+/// assert_eq!(k * k, minus_one);
+/// assert_eq!(i * j * k, minus_one);
+/// ```
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Multivector<S, const P: usize, const Q: usize, const R: usize, const N: usize> {
+    data: [S; N],
+}
+
+impl<S, const P: usize, const Q: usize, const R: usize, const N: usize> Multivector<S, P, Q, R, N> {
+    /// The dimension of the underlying vector space.
+    const DIMENSION: usize = {
+        assert!(
+            N == 1 << (P + Q + R),
+            "The number of components of a multivector in Cl(P, Q, R) must be 2^(P + Q + R)"
+        );
+        P + Q + R
+    };
+
+    /// The bitmask of the generators that square to `-1`.
+    const NEGATIVE_MASK: usize = ((1 << Q) - 1) << P;
+
+    /// The bitmask of the generators that square to `0`.
+    const NULL_MASK: usize = ((1 << R) - 1) << (P + Q);
+
+    /// The bitmask of the pseudoscalar.
+    const PSEUDOSCALAR_MASK: usize = N - 1;
+
+    /// Construct a new general multivector from its components in the bitmask
+    /// ordered basis.
+    #[inline]
+    pub const fn new(data: [S; N]) -> Self {
+        let _ = Self::DIMENSION;
+
+        Self { data }
+    }
+
+    /// Returns the number of components in a multivector.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns the dimension of the vector space underlying the geometric algebra.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::clifford::Multivector;
+    /// #
+    /// let mv: Multivector<f64, 1, 3, 0, 16> = Multivector::zero();
+    ///
+    /// assert_eq!(mv.dimension(), 4);
+    /// assert_eq!(mv.len(), 16);
+    /// ```
+    #[inline]
+    pub const fn dimension(&self) -> usize {
+        Self::DIMENSION
+    }
+
+    /// Get a pointer to the underlying component array.
+    #[inline]
+    pub const fn as_ptr(&self) -> *const S {
+        &self.data[0]
+    }
+
+    /// Get a mutable pointer to the underlying component array.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut S {
+        &mut self.data[0]
+    }
+
+    /// Get a slice of the underlying elements of the data type.
+    #[inline]
+    pub fn as_slice(&self) -> &[S] {
+        &self.data
+    }
+
+    /// Determine the sign of the outer product of the disjoint basis blades with
+    /// bitmasks `a` and `b`, i.e. the parity of the number of transpositions
+    /// needed to bring the generators of `e{a} ^ e{b}` into ascending order.
+    fn reordering_sign(a: usize, b: usize) -> i8 {
+        let mut swaps = 0;
+        let mut shifted = a >> 1;
+        while shifted != 0 {
+            swaps += (shifted & b).count_ones();
+            shifted >>= 1;
+        }
+
+        if swaps & 1 == 0 { 1 } else { -1 }
+    }
+
+    /// Determine the sign of the geometric product of the basis blades with
+    /// bitmasks `a` and `b`.
+    ///
+    /// The product of two basis blades is `sign * e{a ^ b}`, where the sign
+    /// accounts for the reordering of the generators of the product, and for the
+    /// squares of the generators the two blades share.
+    fn blade_product_sign(a: usize, b: usize) -> i8 {
+        let common = a & b;
+        if common & Self::NULL_MASK != 0 {
+            return 0;
+        }
+
+        let reordering_sign = Self::reordering_sign(a, b);
+        if (common & Self::NEGATIVE_MASK).count_ones() & 1 == 0 {
+            reordering_sign
+        } else {
+            -reordering_sign
+        }
+    }
+
+    /// Determine the sign `s` such that `inv(I) == s * I` in a nondegenerate
+    /// algebra.
+    fn inv_pseudoscalar_sign() -> i8 {
+        let dimension = Self::DIMENSION;
+        let reverse_sign = if (dimension * dimension.saturating_sub(1) / 2) & 1 == 0 {
+            1
+        } else {
+            -1
+        };
+        let square_sign = if Q & 1 == 0 { 1 } else { -1 };
+
+        reverse_sign * square_sign
+    }
+
+    /// Determine the sign `s` such that the dual of the basis blade with
+    /// bitmask `blade` is `s * e{!blade}`.
+    fn dual_sign(blade: usize) -> i8 {
+        if R == 0 {
+            Self::blade_product_sign(blade, Self::PSEUDOSCALAR_MASK) * Self::inv_pseudoscalar_sign()
+        } else {
+            Self::reordering_sign(blade, Self::PSEUDOSCALAR_MASK ^ blade)
+        }
+    }
+
+    /// Determine whether the reverse of a blade of grade `grade` changes its sign.
+    #[inline]
+    const fn reverse_negates(grade: u32) -> bool {
+        grade % 4 >= 2
+    }
+
+    /// Determine whether the grade involution of a blade of grade `grade`
+    /// changes its sign.
+    #[inline]
+    const fn involute_negates(grade: u32) -> bool {
+        grade & 1 == 1
+    }
+
+    /// Determine whether the Clifford conjugate of a blade of grade `grade`
+    /// changes its sign.
+    #[inline]
+    const fn conjugate_negates(grade: u32) -> bool {
+        grade % 4 == 1 || grade % 4 == 2
+    }
+}
+
+impl<S, const P: usize, const Q: usize, const R: usize, const N: usize> Multivector<S, P, Q, R, N>
+where
+    S: Scalar,
+{
+    /// Construct the additive unit (zero) multivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::clifford::Multivector;
+    /// #
+    /// let mv: Multivector<f64, 2, 0, 0, 4> = Multivector::new([1_f64, 2_f64, 3_f64, 4_f64]);
+    /// let zero: Multivector<f64, 2, 0, 0, 4> = Multivector::zero();
+    ///
+    /// assert_eq!(mv + zero, mv);
+    /// assert_eq!(zero + mv, mv);
+    /// ```
+    #[inline]
+    pub fn zero() -> Self {
+        Self::new([S::zero(); N])
+    }
+
+    /// Determine whether a multivector is the zero mutlivector.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.data.iter().all(|component| component.is_zero())
+    }
+
+    /// Construct a new multivector from the scalar part only.
+    ///
+    /// A scalar is a multivector whose vector, bivector, etc. components are
+    /// all zero.
+    #[inline]
+    pub fn from_scalar(scalar: S) -> Self {
+        let mut result = Self::zero();
+        result.data[0] = scalar;
+
+        result
+    }
+
+    /// Construct the multiplicative unit of the geometric algebra.
+    #[inline]
+    pub fn unit_scalar() -> Self {
+        Self::from_scalar(S::one())
+    }
+
+    /// Construct the unit basis blade with bitmask `blade`.
+    ///
+    /// Bit `k` of `blade` selects the generator `e{k + 1}`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `blade` is not smaller than `N`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::clifford::Multivector;
+    /// #
+    /// let e1: Multivector<i32, 3, 0, 0, 8> = Multivector::unit_blade(0b001);
+    /// let e3: Multivector<i32, 3, 0, 0, 8> = Multivector::unit_blade(0b100);
+    /// let e13: Multivector<i32, 3, 0, 0, 8> = Multivector::unit_blade(0b101);
+    ///
+    /// assert_eq!(e1 * e3, e13);
+    /// assert_eq!(e3 * e1, -e13);
+    /// ```
+    #[inline]
+    pub fn unit_blade(blade: usize) -> Self {
+        let mut result = Self::zero();
+        result.data[blade] = S::one();
+
+        result
+    }
+
+    /// Construct the unit basis vector `e{index + 1}`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `index` is not smaller than `P + Q + R`.
+    #[inline]
+    pub fn unit_vector(index: usize) -> Self {
+        assert!(index < Self::DIMENSION, "Basis vector index out of range: {}", index);
+
+        Self::unit_blade(1 << index)
+    }
+
+    /// Construct the unit pseudoscalar `e{1...n}` of the geometric algebra.
+    #[inline]
+    pub fn pseudoscalar() -> Self {
+        Self::unit_blade(Self::PSEUDOSCALAR_MASK)
+    }
+
+    /// Project the multivector onto the grade `grade`.
+    ///
+    /// Return a multivector where the components of each grade other than
+    /// input grade are zero. For each grade larger than the dimension of the
+    /// underlying vector space, the grade projection is always zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::clifford::Multivector;
+    /// #
+    /// let mv: Multivector<i32, 2, 0, 0, 4> = Multivector::new([1, 2, 3, 4]);
+    ///
+    /// assert_eq!(mv.grade(0), Multivector::new([1, 0, 0, 0]));
+    /// assert_eq!(mv.grade(1), Multivector::new([0, 2, 3, 0]));
+    /// assert_eq!(mv.grade(2), Multivector::new([0, 0, 0, 4]));
+    /// assert_eq!(mv.grade(3), Multivector::zero());
+    /// ```
+    #[inline]
+    pub fn grade(&self, grade: usize) -> Self {
+        let mut result = Self::zero();
+        for (blade, component) in result.data.iter_mut().enumerate() {
+            if blade.count_ones() as usize == grade {
+                *component = self.data[blade];
+            }
+        }
+
+        result
+    }
+
+    /// Compute the product of two multivectors that keeps the products of the pairs
+    /// of basis blades accepted by `include`.
+    fn product<F>(&self, other: &Self, include: F) -> Self
+    where
+        F: Fn(usize, usize) -> bool,
+    {
+        let mut result = Self::zero();
+        for (a, &lhs) in self.data.iter().enumerate() {
+            if lhs.is_zero() {
+                continue;
+            }
+            for (b, &rhs) in other.data.iter().enumerate() {
+                if include(a, b) {
+                    match Self::blade_product_sign(a, b) {
+                        1 => result.data[a ^ b] += lhs * rhs,
+                        -1 => result.data[a ^ b] -= lhs * rhs,
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    fn geometric_product(&self, other: &Self) -> Self {
+        self.product(other, |_, _| true)
+    }
+
+    /// Compute the left contraction of `self` with `other`.
+    ///
+    /// This is a synonym for the `<<` operator.
+    #[inline]
+    pub fn left_contract(&self, other: &Self) -> Self {
+        self.product(other, |a, b| a & b == a)
+    }
+
+    /// Compute the right contraction of `self` with `other`.
+    ///
+    /// This is a synonym for the `>>` operator.
+    #[inline]
+    pub fn right_contract(&self, other: &Self) -> Self {
+        self.product(other, |a, b| a & b == b)
+    }
+
+    /// Compute the scalar product of `self` and `other`.
+    ///
+    /// The scalar product is given by
+    /// ```text
+    /// mv1 | mv2 := <mv1 * rev(mv2)>_0
+    /// ```
+    /// where `<_>_0` denotes the projection onto grade zero.
+    ///
+    /// This is a synonym for the `|` operator.
+    pub fn scalar_product(&self, other: &Self) -> Self {
+        let mut result = S::zero();
+        for (blade, (&lhs, &rhs)) in self.data.iter().zip(other.data.iter()).enumerate() {
+            let sign = if Self::reverse_negates(blade.count_ones()) {
+                -Self::blade_product_sign(blade, blade)
+            } else {
+                Self::blade_product_sign(blade, blade)
+            };
+            match sign {
+                1 => result += lhs * rhs,
+                -1 => result -= lhs * rhs,
+                _ => {}
+            }
+        }
+
+        Self::from_scalar(result)
+    }
+
+    /// Compute the outer product of `self` and `other`.
+    ///
+    /// This is a synonym for the `^` operator.
+    #[inline]
+    pub fn outer_product(&self, other: &Self) -> Self {
+        self.product(other, |a, b| a & b == 0)
+    }
+
+    /// Compute the regressive product of `self` and `other`.
+    ///
+    /// The regressive product is the outer product of the duals, mapped back
+    /// by the inverse of the duality map
+    /// ```text
+    /// mv1 & mv2 := undual(dual(mv1) ^ dual(mv2))
+    /// ```
+    /// For blades whose subspaces together span the whole space, the regressive
+    /// product computes the intersection of the subspaces.
+    ///
+    /// This is a synonym for the `&` operator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::clifford::Multivector;
+    /// #
+    /// // The regressive product of two bivectors in `Cl(2, 0, 1)` is a vector.
+    /// type Pga2 = Multivector<i32, 2, 0, 1, 8>;
+    ///
+    /// let e12: Pga2 = Multivector::unit_blade(0b011);
+    /// let e23: Pga2 = Multivector::unit_blade(0b110);
+    /// let e2: Pga2 = Multivector::unit_blade(0b010);
+    ///
+    /// assert_eq!(e12.regressive_product(&e23), e2);
+    /// ```
+    pub fn regressive_product(&self, other: &Self) -> Self {
+        let mut result = Self::zero();
+        for (a, &lhs) in self.data.iter().enumerate() {
+            if lhs.is_zero() {
+                continue;
+            }
+            for (b, &rhs) in other.data.iter().enumerate() {
+                // The duals of the blades must not overlap.
+                if a | b == Self::PSEUDOSCALAR_MASK {
+                    let dual_a = Self::PSEUDOSCALAR_MASK ^ a;
+                    let dual_b = Self::PSEUDOSCALAR_MASK ^ b;
+                    let blade = Self::PSEUDOSCALAR_MASK ^ dual_a ^ dual_b;
+                    let sign = Self::dual_sign(a) * Self::dual_sign(b) * Self::reordering_sign(dual_a, dual_b) * Self::dual_sign(blade);
+                    match sign {
+                        1 => result.data[blade] += lhs * rhs,
+                        -1 => result.data[blade] -= lhs * rhs,
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        result
+    }
+}
+
+impl<S, const P: usize, const Q: usize, const R: usize, const N: usize> ops::Index<usize> for Multivector<S, P, Q, R, N>
+where
+    S: Scalar,
+{
+    type Output = S;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.data[index]
+    }
+}
+
+impl<S, const P: usize, const Q: usize, const R: usize, const N: usize> ops::IndexMut<usize> for Multivector<S, P, Q, R, N>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.data[index]
+    }
+}
+
+impl<S, const P: usize, const Q: usize, const R: usize, const N: usize> AsRef<[S; N]> for Multivector<S, P, Q, R, N> {
+    #[inline]
+    fn as_ref(&self) -> &[S; N] {
+        &self.data
+    }
+}
+
+impl<S, const P: usize, const Q: usize, const R: usize, const N: usize> AsMut<[S; N]> for Multivector<S, P, Q, R, N> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [S; N] {
+        &mut self.data
+    }
+}
+
+impl<S, const P: usize, const Q: usize, const R: usize, const N: usize> fmt::Display for Multivector<S, P, Q, R, N>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{}", self.data[0])?;
+        for (blade, component) in self.data.iter().enumerate().skip(1) {
+            write!(formatter, " + {}^e", component)?;
+            for generator in 0..Self::DIMENSION {
+                if blade & (1 << generator) != 0 {
+                    write!(formatter, "{}", generator + 1)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<S, const P: usize, const Q: usize, const R: usize, const N: usize> Multivector<S, P, Q, R, N>
+where
+    S: ScalarSigned,
+{
+    /// Negate the components of the blades whose grades are selected by `negates`.
+    fn negate_grades<F>(&self, negates: F) -> Self
+    where
+        F: Fn(u32) -> bool,
+    {
+        let mut result = *self;
+        for (blade, component) in result.data.iter_mut().enumerate() {
+            if negates(blade.count_ones()) {
+                *component = -*component;
+            }
+        }
+
+        result
+    }
+
+    /// Compute the reverse of a multivector.
+    ///
+    /// The reverse of a multivector reverses the order of the vectors in each
+    /// basis blade. A blade of grade `k` picks up the sign `(-1)^(k * (k - 1) / 2)`,
+    /// and the reverse of a general multivector follows by linearity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::clifford::Multivector;
+    /// #
+    /// let mv: Multivector<i32, 3, 0, 0, 8> = Multivector::new([1, 2, 3, 4, 5, 6, 7, 8]);
+    /// let expected = Multivector::new([1, 2, 3, -4, 5, -6, -7, -8]);
+    ///
+    /// assert_eq!(mv.reverse(), expected);
+    /// ```
+    pub fn reverse(&self) -> Self {
+        self.negate_grades(Self::reverse_negates)
+    }
+
+    /// Compute the reverse of a multivector mutably in place.
+    pub fn reverse_mut(&mut self) {
+        *self = self.reverse();
+    }
+
+    /// Compute the Clifford conjugate of a multivector.
+    ///
+    /// The conjugate is the composition of the reverse and the grade involution.
+    /// A blade of grade `k` picks up the sign `(-1)^(k * (k + 1) / 2)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::clifford::Multivector;
+    /// #
+    /// let mv: Multivector<i32, 3, 0, 0, 8> = Multivector::new([1, 2, 3, 4, 5, 6, 7, 8]);
+    /// let expected = Multivector::new([1, -2, -3, -4, -5, -6, -7, 8]);
+    ///
+    /// assert_eq!(mv.conjugate(), expected);
+    /// assert_eq!(mv.conjugate(), mv.reverse().involute());
+    /// ```
+    pub fn conjugate(&self) -> Self {
+        self.negate_grades(Self::conjugate_negates)
+    }
+
+    /// Compute the Clifford conjugate of a multivector mutably in place.
+    pub fn conjugate_mut(&mut self) {
+        *self = self.conjugate();
+    }
+
+    /// Compute the grade involution of a multivector.
+    ///
+    /// The grade involution negates the odd grade components of a multivector.
+    /// A blade of grade `k` picks up the sign `(-1)^k`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::clifford::Multivector;
+    /// #
+    /// let mv: Multivector<i32, 3, 0, 0, 8> = Multivector::new([1, 2, 3, 4, 5, 6, 7, 8]);
+    /// let expected = Multivector::new([1, -2, -3, 4, -5, 6, 7, -8]);
+    ///
+    /// assert_eq!(mv.involute(), expected);
+    /// ```
+    pub fn involute(&self) -> Self {
+        self.negate_grades(Self::involute_negates)
+    }
+
+    /// Compute the grade involution of a multivector mutably in place.
+    pub fn involute_mut(&mut self) {
+        *self = self.involute();
+    }
+
+    /// Compute the dual of a multivector.
+    ///
+    /// When the algebra is nondegenerate, i.e. `R == 0`, the dual is the right
+    /// multiplication by the inverse pseudoscalar
+    /// ```text
+    /// dual(mv) := mv * inv(I)
+    /// ```
+    /// which agrees with the dual of the hand-unrolled multivectors such as
+    /// `EuclideanMultivector3`. When the algebra is degenerate, the pseudoscalar
+    /// is not invertible, and the dual is instead the right complement, which maps
+    /// each basis blade `eA` to the blade `dual(eA)` with `eA ^ dual(eA) == I`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::clifford::Multivector;
+    /// #
+    /// let mv: Multivector<i32, 1, 3, 0, 16> = Multivector::new([
+    ///     1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+    /// ]);
+    /// let ps_inv = Multivector::inv_pseudoscalar().unwrap();
+    ///
+    /// assert_eq!(mv.dual(), mv * ps_inv);
+    /// assert_eq!(mv.dual().undual(), mv);
+    /// ```
+    pub fn dual(&self) -> Self {
+        let mut result = Self::zero();
+        for (blade, &component) in self.data.iter().enumerate() {
+            let sign = Self::dual_sign(blade);
+            result.data[Self::PSEUDOSCALAR_MASK ^ blade] = if sign < 0 { -component } else { component };
+        }
+
+        result
+    }
+
+    /// Compute the dual of a multivector mutably in place.
+    pub fn dual_mut(&mut self) {
+        *self = self.dual();
+    }
+
+    /// Compute the undual of a multivector.
+    ///
+    /// The undual is the inverse of the dual, i.e. `undual(dual(mv)) == mv`
+    /// and `dual(undual(mv)) == mv` for every multivector `mv`. When the algebra
+    /// is nondegenerate, the undual is the right multiplication by the pseudoscalar.
+    pub fn undual(&self) -> Self {
+        let mut result = Self::zero();
+        for (blade, component) in result.data.iter_mut().enumerate() {
+            let dual_component = self.data[Self::PSEUDOSCALAR_MASK ^ blade];
+            *component = if Self::dual_sign(blade) < 0 {
+                -dual_component
+            } else {
+                dual_component
+            };
+        }
+
+        result
+    }
+
+    /// Construct the inverse pseudoscalar of the geometric algebra.
+    ///
+    /// The inverse pseudoscalar exists only when the algebra is nondegenerate,
+    /// i.e. `R == 0`. In that case, it is `inv(I) == rev(I) / (I * rev(I))`,
+    /// which is plus or minus the pseudoscalar `I`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::clifford::Multivector;
+    /// #
+    /// let ps: Multivector<f64, 3, 0, 0, 8> = Multivector::pseudoscalar();
+    /// let ps_inv: Multivector<f64, 3, 0, 0, 8> = Multivector::inv_pseudoscalar().unwrap();
+    /// let one: Multivector<f64, 3, 0, 0, 8> = Multivector::unit_scalar();
+    ///
+    /// assert_eq!(ps * ps_inv, one);
+    /// assert_eq!(ps_inv * ps, one);
+    ///
+    /// // The pseudoscalar of a degenerate algebra is not invertible.
+    /// assert!(Multivector::<f64, 3, 0, 1, 16>::inv_pseudoscalar().is_none());
+    /// ```
+    pub fn inv_pseudoscalar() -> Option<Self> {
+        if R == 0 {
+            let pseudoscalar = Self::pseudoscalar();
+            if Self::inv_pseudoscalar_sign() < 0 {
+                Some(-pseudoscalar)
+            } else {
+                Some(pseudoscalar)
+            }
+        } else {
+            None
+        }
+    }
+}
+
+impl<S, const P: usize, const Q: usize, const R: usize, const N: usize> ops::Not for Multivector<S, P, Q, R, N>
+where
+    S: ScalarSigned,
+{
+    type Output = Multivector<S, P, Q, R, N>;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        self.dual()
+    }
+}
+
+impl<S, const P: usize, const Q: usize, const R: usize, const N: usize> ops::Not for &Multivector<S, P, Q, R, N>
+where
+    S: ScalarSigned,
+{
+    type Output = Multivector<S, P, Q, R, N>;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        self.dual()
+    }
+}
+
+impl<S, const P: usize, const Q: usize, const R: usize, const N: usize> ops::Neg for Multivector<S, P, Q, R, N>
+where
+    S: ScalarSigned,
+{
+    type Output = Multivector<S, P, Q, R, N>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Multivector::new(self.data.map(|component| -component))
+    }
+}
+
+impl<S, const P: usize, const Q: usize, const R: usize, const N: usize> ops::Neg for &Multivector<S, P, Q, R, N>
+where
+    S: ScalarSigned,
+{
+    type Output = Multivector<S, P, Q, R, N>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Multivector::new(self.data.map(|component| -component))
+    }
+}
+
+
+macro_rules! impl_multivector_binary_ops {
+    ($OpType:ident, $op:ident, |$a:ident, $b:ident| $body:expr) => {
+        impl<S, const P: usize, const Q: usize, const R: usize, const N: usize> ops::$OpType<Multivector<S, P, Q, R, N>>
+            for Multivector<S, P, Q, R, N>
+        where
+            S: Scalar,
+        {
+            type Output = Multivector<S, P, Q, R, N>;
+
+            #[inline]
+            fn $op(self, other: Multivector<S, P, Q, R, N>) -> Self::Output {
+                let $a = &self;
+                let $b = &other;
+
+                $body
+            }
+        }
+
+        impl<S, const P: usize, const Q: usize, const R: usize, const N: usize> ops::$OpType<&Multivector<S, P, Q, R, N>>
+            for Multivector<S, P, Q, R, N>
+        where
+            S: Scalar,
+        {
+            type Output = Multivector<S, P, Q, R, N>;
+
+            #[inline]
+            fn $op(self, other: &Multivector<S, P, Q, R, N>) -> Self::Output {
+                let $a = &self;
+                let $b = other;
+
+                $body
+            }
+        }
+
+        impl<S, const P: usize, const Q: usize, const R: usize, const N: usize> ops::$OpType<Multivector<S, P, Q, R, N>>
+            for &Multivector<S, P, Q, R, N>
+        where
+            S: Scalar,
+        {
+            type Output = Multivector<S, P, Q, R, N>;
+
+            #[inline]
+            fn $op(self, other: Multivector<S, P, Q, R, N>) -> Self::Output {
+                let $a = self;
+                let $b = &other;
+
+                $body
+            }
+        }
+
+        impl<S, const P: usize, const Q: usize, const R: usize, const N: usize> ops::$OpType<&Multivector<S, P, Q, R, N>>
+            for &Multivector<S, P, Q, R, N>
+        where
+            S: Scalar,
+        {
+            type Output = Multivector<S, P, Q, R, N>;
+
+            #[inline]
+            fn $op(self, other: &Multivector<S, P, Q, R, N>) -> Self::Output {
+                let $a = self;
+                let $b = other;
+
+                $body
+            }
+        }
+    };
+}
+
+impl_multivector_binary_ops!(Add, add, |a, b| {
+    let mut result = *a;
+    for (component, &other_component) in result.data.iter_mut().zip(b.data.iter()) {
+        *component += other_component;
+    }
+
+    result
+});
+impl_multivector_binary_ops!(Sub, sub, |a, b| {
+    let mut result = *a;
+    for (component, &other_component) in result.data.iter_mut().zip(b.data.iter()) {
+        *component -= other_component;
+    }
+
+    result
+});
+impl_multivector_binary_ops!(Mul, mul, |a, b| a.geometric_product(b));
+impl_multivector_binary_ops!(BitXor, bitxor, |a, b| a.outer_product(b));
+impl_multivector_binary_ops!(BitAnd, bitand, |a, b| a.regressive_product(b));
+impl_multivector_binary_ops!(BitOr, bitor, |a, b| a.scalar_product(b));
+impl_multivector_binary_ops!(Shl, shl, |a, b| a.left_contract(b));
+impl_multivector_binary_ops!(Shr, shr, |a, b| a.right_contract(b));
+
+
+macro_rules! impl_multivector_scalar_ops {
+    ($OpType:ident, $op:ident, |$a:ident, $b:ident| $body:expr) => {
+        impl<S, const P: usize, const Q: usize, const R: usize, const N: usize> ops::$OpType<S> for Multivector<S, P, Q, R, N>
+        where
+            S: Scalar,
+        {
+            type Output = Multivector<S, P, Q, R, N>;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            #[inline]
+            fn $op(self, other: S) -> Self::Output {
+                let $a = &self;
+                let $b = other;
+
+                $body
+            }
+        }
+
+        impl<S, const P: usize, const Q: usize, const R: usize, const N: usize> ops::$OpType<S> for &Multivector<S, P, Q, R, N>
+        where
+            S: Scalar,
+        {
+            type Output = Multivector<S, P, Q, R, N>;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            #[inline]
+            fn $op(self, other: S) -> Self::Output {
+                let $a = self;
+                let $b = other;
+
+                $body
+            }
+        }
+    };
+}
+
+impl_multivector_scalar_ops!(Add, add, |a, b| {
+    let mut result = *a;
+    result.data[0] += b;
+
+    result
+});
+impl_multivector_scalar_ops!(Sub, sub, |a, b| {
+    let mut result = *a;
+    result.data[0] -= b;
+
+    result
+});
+impl_multivector_scalar_ops!(Mul, mul, |a, b| Multivector::new(a.data.map(|component| component * b)));
+impl_multivector_scalar_ops!(BitXor, bitxor, |a, b| Multivector::new(a.data.map(|component| component * b)));
+impl_multivector_scalar_ops!(BitOr, bitor, |a, b| Multivector::from_scalar(a.data[0] * b));
+impl_multivector_scalar_ops!(Shl, shl, |a, b| Multivector::from_scalar(a.data[0] * b));
+impl_multivector_scalar_ops!(Shr, shr, |a, b| Multivector::new(a.data.map(|component| component * b)));
+
+impl_approx_cmp_ops!(Multivector<S, const P: usize, const Q: usize, const R: usize, const N: usize>);
+
+impl<S, const P: usize, const Q: usize, const R: usize, const N: usize> Multivector<S, P, Q, R, N>
+where
+    S: ScalarFloat,
+{
+    /// Calculate the squared magnitude of a multivector.
+    pub fn magnitude_squared(&self) -> S {
+        let scalar_part = self.scalar_product(self)[0];
+
+        scalar_part.abs()
+    }
+
+    /// Calculate the magnitude of a multivector.
+    pub fn magnitude(&self) -> S {
+        self.magnitude_squared().sqrt()
+    }
+
+    /// Normalize a multivector to a unit multivector.
+    pub fn normalize(&self) -> Self {
+        self * (S::one() / self.magnitude())
+    }
+
+    /// Normalize a multivector to a specified magnitude.
+    pub fn normalize_to(&self, magnitude: S) -> Self {
+        self * (magnitude / self.magnitude())
+    }
+
+    /// Calculate the squared Euclidean distance between two multivectors.
+    pub fn distance_squared(&self, other: &Self) -> S {
+        (self - other).magnitude_squared()
+    }
+
+    /// Calculate the Euclidean distance between two multivectors.
+    pub fn distance(&self, other: &Self) -> S {
+        (self - other).magnitude()
+    }
+}
+
+impl<S, const P: usize, const Q: usize, const R: usize, const N: usize> ops::Div<S> for Multivector<S, P, Q, R, N>
+where
+    S: ScalarFloat,
+{
+    type Output = Multivector<S, P, Q, R, N>;
+
+    #[inline]
+    fn div(self, other: S) -> Self::Output {
+        let one_over_other = S::one() / other;
+
+        self * one_over_other
+    }
+}
+
+impl<S, const P: usize, const Q: usize, const R: usize, const N: usize> ops::Div<S> for &Multivector<S, P, Q, R, N>
+where
+    S: ScalarFloat,
+{
+    type Output = Multivector<S, P, Q, R, N>;
+
+    #[inline]
+    fn div(self, other: S) -> Self::Output {
+        let one_over_other = S::one() / other;
+
+        self * one_over_other
+    }
+}
+
+impl<S, const P: usize, const Q: usize, const R: usize, const N: usize> Multivector<S, P, Q, R, N>
+where
+    S: ScalarFloat,
+{
+    /// Determine whether a multivector is invertible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::clifford::Multivector;
+    /// #
+    /// let e1: Multivector<f64, 2, 1, 0, 8> = Multivector::unit_vector(0);
+    /// let e3: Multivector<f64, 2, 1, 0, 8> = Multivector::unit_vector(2);
+    ///
+    /// assert!(e1.is_invertible());
+    /// assert!(e3.is_invertible());
+    ///
+    /// // The null vector `e1 + e3` is a zero divisor.
+    /// assert!(!(e1 + e3).is_invertible());
+    /// ```
+    #[inline]
+    pub fn is_invertible(&self) -> bool {
+        let (_, min_pivot) = self.inverse_and_min_pivot();
+
+        Self::is_nonzero_pivot(min_pivot)
+    }
+
+    /// Compute the multiplicative inverse of a multivector.
+    ///
+    /// The inverse of a multivector `mv` is a multivector `mv_inv`
+    /// such that
+    /// ```text
+    /// mv * mv_inv = mv_inv * mv = 1
+    /// ```
+    /// Closed-form inverses exist in low dimensions, but not for a general
+    /// signature. Instead, the inverse is found by solving the linear system
+    /// `mv * mv_inv == 1`, whose matrix is the matrix of the left multiplication by
+    /// `mv`, with Gaussian elimination with partial pivoting. The multivector is
+    /// not invertible precisely when this matrix is singular.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::clifford::Multivector;
+    /// #
+    /// let mv: Multivector<f64, 1, 3, 0, 16> = Multivector::new([
+    ///     13_f64, -4_f64, 98_f64, 4_f64, 7_f64, -10_f64, 30_f64, 2_f64,
+    ///     1_f64, 5_f64, -6_f64, 3_f64, 8_f64, -9_f64, 11_f64, 12_f64,
+    /// ]);
+    /// let mv_inv = mv.inverse().unwrap();
+    /// let one: Multivector<f64, 1, 3, 0, 16> = Multivector::unit_scalar();
+    ///
+    /// assert_relative_eq!(mv * mv_inv, one, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(mv_inv * mv, one, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn inverse(&self) -> Option<Self> {
+        let (inverse, min_pivot) = self.inverse_and_min_pivot();
+        if Self::is_nonzero_pivot(min_pivot) { Some(inverse) } else { None }
+    }
+
+    fn is_nonzero_pivot(pivot: S) -> bool {
+        ulps_ne!(
+            pivot,
+            S::zero(),
+            abs_diff_all <= S::default_epsilon(),
+            ulps_all <= S::default_max_ulps()
+        )
+    }
+
+    fn inverse_unchecked(&self) -> Self {
+        let (inverse, _) = self.inverse_and_min_pivot();
+
+        inverse
+    }
+
+    /// Solve `self * inverse == 1` by Gauss-Jordan elimination, and return
+    /// the solution together with the pivot of smallest magnitude.
+    fn inverse_and_min_pivot(&self) -> (Self, S) {
+        // Column `b` of the matrix holds the components of `self * e{b}`.
+        let mut matrix = [[S::zero(); N]; N];
+        for (a, &component) in self.data.iter().enumerate() {
+            if component.is_zero() {
+                continue;
+            }
+            for b in 0..N {
+                match Self::blade_product_sign(a, b) {
+                    1 => matrix[a ^ b][b] += component,
+                    -1 => matrix[a ^ b][b] -= component,
+                    _ => {}
+                }
+            }
+        }
+
+        let mut solution = Self::unit_scalar().data;
+        let mut min_pivot = S::infinity();
+        for column in 0..N {
+            let pivot_row = (column..N)
+                .max_by(|&i, &j| {
+                    let lhs = matrix[i][column].abs();
+                    let rhs = matrix[j][column].abs();
+
+                    lhs.partial_cmp(&rhs).unwrap_or(cmp::Ordering::Equal)
+                })
+                .unwrap_or(column);
+            matrix.swap(column, pivot_row);
+            solution.swap(column, pivot_row);
+
+            let pivot_values = matrix[column];
+            let pivot = pivot_values[column];
+            min_pivot = min_pivot.min(pivot.abs());
+            for (row, row_values) in matrix.iter_mut().enumerate() {
+                if row == column || row_values[column].is_zero() {
+                    continue;
+                }
+                let factor = row_values[column] / pivot;
+                for (value, &pivot_value) in row_values.iter_mut().zip(pivot_values.iter()).skip(column) {
+                    *value -= factor * pivot_value;
+                }
+                solution[row] -= factor * solution[column];
+            }
+        }
+
+        for (row, component) in solution.iter_mut().enumerate() {
+            *component /= matrix[row][row];
+        }
+
+        (Self::new(solution), min_pivot)
+    }
+
+    /// Compute the commutator of two multivectors.
+    ///
+    /// The commutator of multivectors `mv1` and `mv2` is given by
+    /// ```text
+    /// comm(mv1, mv2) := (mv1 * mv2 - mv2 * mv1) / 2
+    /// ```
+    /// where `*` denotes the geometric product.
+    pub fn commutator(&self, other: &Self) -> Self {
+        let self_times_other = self * other;
+        let other_times_self = other * self;
+        let one_over_two = S::one() / (S::one() + S::one());
+
+        (self_times_other - other_times_self) * one_over_two
+    }
+
+    /// Compute the commutator of two multivectors.
+    ///
+    /// This is a synonym for `commutator`.
+    #[inline(always)]
+    pub fn x(&self, other: &Self) -> Self {
+        self.commutator(other)
+    }
+
+    /// Compute the anticommutator of two multivectors.
+    ///
+    /// The anticommutator of multivectors `mv1` and `mv2` is given by
+    /// ```text
+    /// anticomm(mv1, mv2) := (mv1 * mv2 + mv2 * mv1) / 2
+    /// ```
+    /// where `*` denotes the geometric product.
+    pub fn anticommutator(&self, other: &Self) -> Self {
+        let self_times_other = self * other;
+        let other_times_self = other * self;
+        let one_over_two = S::one() / (S::one() + S::one());
+
+        (self_times_other + other_times_self) * one_over_two
+    }
+}
+
+impl<S, const P: usize, const Q: usize, const R: usize, const N: usize> ops::Div<Multivector<S, P, Q, R, N>> for Multivector<S, P, Q, R, N>
+where
+    S: ScalarFloat,
+{
+    type Output = Multivector<S, P, Q, R, N>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn div(self, other: Multivector<S, P, Q, R, N>) -> Self::Output {
+        self * other.inverse_unchecked()
+    }
+}
+
+impl<S, const P: usize, const Q: usize, const R: usize, const N: usize> ops::Div<&Multivector<S, P, Q, R, N>> for Multivector<S, P, Q, R, N>
+where
+    S: ScalarFloat,
+{
+    type Output = Multivector<S, P, Q, R, N>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn div(self, other: &Multivector<S, P, Q, R, N>) -> Self::Output {
+        self * other.inverse_unchecked()
+    }
+}
+
+impl<S, const P: usize, const Q: usize, const R: usize, const N: usize> ops::Div<Multivector<S, P, Q, R, N>> for &Multivector<S, P, Q, R, N>
+where
+    S: ScalarFloat,
+{
+    type Output = Multivector<S, P, Q, R, N>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn div(self, other: Multivector<S, P, Q, R, N>) -> Self::Output {
+        self * other.inverse_unchecked()
+    }
+}
+
+impl<S, const P: usize, const Q: usize, const R: usize, const N: usize> ops::Div<&Multivector<S, P, Q, R, N>>
+    for &Multivector<S, P, Q, R, N>
+where
+    S: ScalarFloat,
+{
+    type Output = Multivector<S, P, Q, R, N>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn div(self, other: &Multivector<S, P, Q, R, N>) -> Self::Output {
+        self * other.inverse_unchecked()
+    }
+}
+
+impl<S> From<EuclideanMultivector2<S>> for Multivector<S, 2, 0, 0, 4>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn from(mv: EuclideanMultivector2<S>) -> Self {
+        Multivector::new([mv[0], mv[1], mv[2], mv[3]])
+    }
+}
+
+impl<S> From<Multivector<S, 2, 0, 0, 4>> for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn from(mv: Multivector<S, 2, 0, 0, 4>) -> Self {
+        EuclideanMultivector2::new(mv[0], mv[1], mv[2], mv[3])
+    }
+}
+
+impl<S> From<EuclideanMultivector3<S>> for Multivector<S, 3, 0, 0, 8>
+where
+    S: ScalarSigned,
+{
+    /// Convert from the basis `{1, e1, e2, e3, e12, e23, e31, e123}` to the
+    /// bitmask ordered basis `{1, e1, e2, e12, e3, e13, e23, e123}`, using
+    /// `e31 == -e13`.
+    #[inline]
+    fn from(mv: EuclideanMultivector3<S>) -> Self {
+        Multivector::new([mv[0], mv[1], mv[2], mv[4], mv[3], -mv[6], mv[5], mv[7]])
+    }
+}
+
+impl<S> From<Multivector<S, 3, 0, 0, 8>> for EuclideanMultivector3<S>
+where
+    S: ScalarSigned,
+{
+    #[inline]
+    fn from(mv: Multivector<S, 3, 0, 0, 8>) -> Self {
+        EuclideanMultivector3::new(mv[0], mv[1], mv[2], mv[4], mv[3], mv[6], -mv[5], mv[7])
+    }
+}
+
+/// The bitmasks of the basis blades of the four-dimensional algebras in
+/// lexicographic order, which is the component order of `EuclideanMultivector4`
+/// and `SpacetimeMultivector`.
+const LEXICOGRAPHIC_BLADES_4: [usize; 16] = [
+    0b0000, 0b0001, 0b0010, 0b0100, 0b1000, 0b0011, 0b0101, 0b1001, 0b0110, 0b1010, 0b1100, 0b0111, 0b1011, 0b1101, 0b1110, 0b1111,
+];
+
+impl<S> From<EuclideanMultivector4<S>> for Multivector<S, 4, 0, 0, 16>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn from(mv: EuclideanMultivector4<S>) -> Self {
+        let mut result = Multivector::zero();
+        for (index, &blade) in LEXICOGRAPHIC_BLADES_4.iter().enumerate() {
+            result[blade] = mv[index];
+        }
+
+        result
+    }
+}
+
+impl<S> From<Multivector<S, 4, 0, 0, 16>> for EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn from(mv: Multivector<S, 4, 0, 0, 16>) -> Self {
+        let mut result = EuclideanMultivector4::zero();
+        for (index, &blade) in LEXICOGRAPHIC_BLADES_4.iter().enumerate() {
+            result[index] = mv[blade];
+        }
+
+        result
+    }
+}
+
+impl<S> From<SpacetimeMultivector<S>> for Multivector<S, 1, 3, 0, 16>
+where
+    S: Scalar,
+{
+    /// Convert from the basis `{gamma0, gamma1, gamma2, gamma3}` to the generators
+    /// `{e1, e2, e3, e4}` with `e1 * e1 == 1` and `e2 * e2 == e3 * e3 == e4 * e4 == -1`.
+    #[inline]
+    fn from(mv: SpacetimeMultivector<S>) -> Self {
+        let mut result = Multivector::zero();
+        for (index, &blade) in LEXICOGRAPHIC_BLADES_4.iter().enumerate() {
+            result[blade] = mv[index];
+        }
+
+        result
+    }
+}
+
+impl<S> From<Multivector<S, 1, 3, 0, 16>> for SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn from(mv: Multivector<S, 1, 3, 0, 16>) -> Self {
+        let mut result = SpacetimeMultivector::zero();
+        for (index, &blade) in LEXICOGRAPHIC_BLADES_4.iter().enumerate() {
+            result[index] = mv[blade];
+        }
+
+        result
+    }
+}
+
+
+macro_rules! impl_scalar_multivector_add_ops {
+    ($($Lhs:ty),* $(,)?) => {$(
+        impl<const P: usize, const Q: usize, const R: usize, const N: usize> ops::Add<Multivector<$Lhs, P, Q, R, N>> for $Lhs {
+            type Output = Multivector<$Lhs, P, Q, R, N>;
+
+            #[inline]
+            fn add(self, other: Multivector<$Lhs, P, Q, R, N>) -> Self::Output {
+                other + self
+            }
+        }
+
+        impl<const P: usize, const Q: usize, const R: usize, const N: usize> ops::Add<&Multivector<$Lhs, P, Q, R, N>> for $Lhs {
+            type Output = Multivector<$Lhs, P, Q, R, N>;
+
+            #[inline]
+            fn add(self, other: &Multivector<$Lhs, P, Q, R, N>) -> Self::Output {
+                other + self
+            }
+        }
+    )*};
+}
+
+impl_scalar_multivector_add_ops!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+
+macro_rules! impl_scalar_multivector_sub_ops {
+    ($($Lhs:ty),* $(,)?) => {$(
+        impl<const P: usize, const Q: usize, const R: usize, const N: usize> ops::Sub<Multivector<$Lhs, P, Q, R, N>> for $Lhs {
+            type Output = Multivector<$Lhs, P, Q, R, N>;
+
+            #[inline]
+            fn sub(self, other: Multivector<$Lhs, P, Q, R, N>) -> Self::Output {
+                -other + self
+            }
+        }
+
+        impl<const P: usize, const Q: usize, const R: usize, const N: usize> ops::Sub<&Multivector<$Lhs, P, Q, R, N>> for $Lhs {
+            type Output = Multivector<$Lhs, P, Q, R, N>;
+
+            #[inline]
+            fn sub(self, other: &Multivector<$Lhs, P, Q, R, N>) -> Self::Output {
+                -other + self
+            }
+        }
+    )*};
+}
+
+impl_scalar_multivector_sub_ops!(i8, i16, i32, i64, i128, isize, f32, f64);
+
+
+macro_rules! impl_scalar_multivector_mul_ops {
+    ($($Lhs:ty),* $(,)?) => {$(
+        impl<const P: usize, const Q: usize, const R: usize, const N: usize> ops::Mul<Multivector<$Lhs, P, Q, R, N>> for $Lhs {
+            type Output = Multivector<$Lhs, P, Q, R, N>;
+
+            #[inline]
+            fn mul(self, other: Multivector<$Lhs, P, Q, R, N>) -> Self::Output {
+                Multivector::new(other.data.map(|component| self * component))
+            }
+        }
+
+        impl<const P: usize, const Q: usize, const R: usize, const N: usize> ops::Mul<&Multivector<$Lhs, P, Q, R, N>> for $Lhs {
+            type Output = Multivector<$Lhs, P, Q, R, N>;
+
+            #[inline]
+            fn mul(self, other: &Multivector<$Lhs, P, Q, R, N>) -> Self::Output {
+                Multivector::new(other.data.map(|component| self * component))
+            }
+        }
+    )*};
+}
+
+impl_scalar_multivector_mul_ops!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+
+macro_rules! impl_scalar_multivector_bitor_ops {
+    ($($Lhs:ty),* $(,)?) => {$(
+        impl<const P: usize, const Q: usize, const R: usize, const N: usize> ops::BitOr<Multivector<$Lhs, P, Q, R, N>> for $Lhs {
+            type Output = Multivector<$Lhs, P, Q, R, N>;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            #[inline]
+            fn bitor(self, other: Multivector<$Lhs, P, Q, R, N>) -> Self::Output {
+                Multivector::from_scalar(self * other[0])
+            }
+        }
+
+        impl<const P: usize, const Q: usize, const R: usize, const N: usize> ops::BitOr<&Multivector<$Lhs, P, Q, R, N>> for $Lhs {
+            type Output = Multivector<$Lhs, P, Q, R, N>;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            #[inline]
+            fn bitor(self, other: &Multivector<$Lhs, P, Q, R, N>) -> Self::Output {
+                Multivector::from_scalar(self * other[0])
+            }
+        }
+    )*};
+}
+
+impl_scalar_multivector_bitor_ops!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+
+macro_rules! impl_scalar_multivector_bitxor_ops {
+    ($($Lhs:ty),* $(,)?) => {$(
+        impl<const P: usize, const Q: usize, const R: usize, const N: usize> ops::BitXor<Multivector<$Lhs, P, Q, R, N>> for $Lhs {
+            type Output = Multivector<$Lhs, P, Q, R, N>;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            #[inline]
+            fn bitxor(self, other: Multivector<$Lhs, P, Q, R, N>) -> Self::Output {
+                Multivector::new(other.data.map(|component| self * component))
+            }
+        }
+
+        impl<const P: usize, const Q: usize, const R: usize, const N: usize> ops::BitXor<&Multivector<$Lhs, P, Q, R, N>> for $Lhs {
+            type Output = Multivector<$Lhs, P, Q, R, N>;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            #[inline]
+            fn bitxor(self, other: &Multivector<$Lhs, P, Q, R, N>) -> Self::Output {
+                Multivector::new(other.data.map(|component| self * component))
+            }
+        }
+    )*};
+}
+
+impl_scalar_multivector_bitxor_ops!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+
+macro_rules! impl_scalar_multivector_div_ops {
+    ($($Lhs:ty),* $(,)?) => {$(
+        impl<const P: usize, const Q: usize, const R: usize, const N: usize> ops::Div<Multivector<$Lhs, P, Q, R, N>> for $Lhs {
+            type Output = Multivector<$Lhs, P, Q, R, N>;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            #[inline]
+            fn div(self, other: Multivector<$Lhs, P, Q, R, N>) -> Self::Output {
+                Multivector::new(other.inverse_unchecked().data.map(|component| self * component))
+            }
+        }
+
+        impl<const P: usize, const Q: usize, const R: usize, const N: usize> ops::Div<&Multivector<$Lhs, P, Q, R, N>> for $Lhs {
+            type Output = Multivector<$Lhs, P, Q, R, N>;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            #[inline]
+            fn div(self, other: &Multivector<$Lhs, P, Q, R, N>) -> Self::Output {
+                Multivector::new(other.inverse_unchecked().data.map(|component| self * component))
+            }
+        }
+    )*};
+}
+
+impl_scalar_multivector_div_ops!(f32, f64);
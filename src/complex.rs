@@ -0,0 +1,266 @@
+use crate::scalar::ScalarFloat;
+use core::ops;
+
+
+/*
+ * A minimal complex number type used internally for evaluating closed-form
+ * functions of multivectors.
+ *
+ * In several geometric algebras the pseudoscalar is central and squares to
+ * `-1`, so the subalgebra spanned by `{1, I}` is isomorphic to the complex
+ * numbers. Every multivector then splits into a complex-valued scalar part
+ * and a part that squares to a complex scalar, and functions such as the
+ * exponential and logarithm reduce to functions of complex numbers.
+ */
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct Complex<S> {
+    pub(crate) re: S,
+    pub(crate) im: S,
+}
+
+impl<S> Complex<S>
+where
+    S: ScalarFloat,
+{
+    #[inline]
+    pub(crate) const fn new(re: S, im: S) -> Self {
+        Self { re, im }
+    }
+
+    #[inline]
+    pub(crate) fn from_real(re: S) -> Self {
+        Self::new(re, S::zero())
+    }
+
+    #[inline]
+    pub(crate) fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+
+    #[inline]
+    pub(crate) fn norm_squared(&self) -> S {
+        self.re * self.re + self.im * self.im
+    }
+
+    #[inline]
+    pub(crate) fn norm(&self) -> S {
+        self.re.hypot(self.im)
+    }
+
+    #[inline]
+    pub(crate) fn scale(&self, factor: S) -> Self {
+        Self::new(self.re * factor, self.im * factor)
+    }
+
+    #[inline]
+    pub(crate) fn exp(&self) -> Self {
+        let exp_re = self.re.exp();
+        let (sin_im, cos_im) = self.im.sin_cos();
+
+        Self::new(exp_re * cos_im, exp_re * sin_im)
+    }
+
+    /// The principal branch of the natural logarithm.
+    #[inline]
+    pub(crate) fn ln(&self) -> Self {
+        Self::new(self.norm().ln(), self.im.atan2(self.re))
+    }
+
+    /// The principal branch of the square root.
+    pub(crate) fn sqrt(&self) -> Self {
+        if self.is_zero() {
+            return *self;
+        }
+
+        let one_half = S::one() / (S::one() + S::one());
+        let norm = self.norm();
+        if self.re >= S::zero() {
+            let t = ((norm + self.re) * one_half).sqrt();
+
+            Self::new(t, self.im / (t + t))
+        } else {
+            let t = ((norm - self.re) * one_half).sqrt();
+
+            Self::new(self.im.abs() / (t + t), t.copysign(self.im))
+        }
+    }
+
+    #[inline]
+    pub(crate) fn sinh(&self) -> Self {
+        let (sin_im, cos_im) = self.im.sin_cos();
+
+        Self::new(self.re.sinh() * cos_im, self.re.cosh() * sin_im)
+    }
+
+    #[inline]
+    pub(crate) fn cosh(&self) -> Self {
+        let (sin_im, cos_im) = self.im.sin_cos();
+
+        Self::new(self.re.cosh() * cos_im, self.re.sinh() * sin_im)
+    }
+
+    #[inline]
+    pub(crate) fn sin(&self) -> Self {
+        let (sin_re, cos_re) = self.re.sin_cos();
+
+        Self::new(sin_re * self.im.cosh(), cos_re * self.im.sinh())
+    }
+
+    #[inline]
+    pub(crate) fn cos(&self) -> Self {
+        let (sin_re, cos_re) = self.re.sin_cos();
+
+        Self::new(cos_re * self.im.cosh(), -sin_re * self.im.sinh())
+    }
+
+    /// Compute `(cosh(c), sinh(c) / c)` for `c == sqrt(c_squared)`.
+    ///
+    /// Both functions are even in `c`, so the result does not depend on the
+    /// choice of square root.
+    pub(crate) fn cosh_sinhc_sqrt(c_squared: &Self) -> (Self, Self) {
+        let c = c_squared.sqrt();
+        if c.is_zero() {
+            return (Self::from_real(S::one()), Self::from_real(S::one()));
+        }
+
+        (c.cosh(), c.sinh() / c)
+    }
+
+    /// Compute the coefficients `(alpha, beta)` such that
+    /// `sqrt(z + f) == alpha + beta * f` for any element `f` that commutes with `z`
+    /// and satisfies `f * f == c_squared`.
+    ///
+    /// The principal square roots of the eigenvalues `z + c` and `z - c` are
+    /// used. Their sum vanishes only when both eigenvalues vanish, in which
+    /// case `z + f` is nilpotent or zero and the function returns `None`.
+    pub(crate) fn sqrt_split(z: &Self, c_squared: &Self) -> Option<(Self, Self)> {
+        let one_half = S::one() / (S::one() + S::one());
+        let c = c_squared.sqrt();
+        let sqrt_z_plus_c = (*z + c).sqrt();
+        let sqrt_z_minus_c = (*z - c).sqrt();
+
+        let sum = sqrt_z_plus_c + sqrt_z_minus_c;
+        if sum.is_zero() {
+            return None;
+        }
+
+        // Since `(sqrt(z + c) - sqrt(z - c)) * (sqrt(z + c) + sqrt(z - c)) == 2 * c`,
+        // the coefficient of `f` can be computed without cancellation.
+        let alpha = sum.scale(one_half);
+        let beta = Self::from_real(S::one()) / sum;
+
+        Some((alpha, beta))
+    }
+
+    /// Compute the coefficients `(alpha, beta)` such that
+    /// `ln(z + f) == alpha + beta * f` for any element `f` that commutes with `z`
+    /// and satisfies `f * f == c_squared`.
+    ///
+    /// The eigenvalues of `z + f` are `z + c` and `z - c` with `c == sqrt(c_squared)`,
+    /// and a logarithm exists whenever both are nonzero. Returns `None` otherwise.
+    pub(crate) fn ln_split(z: &Self, c_squared: &Self) -> Option<(Self, Self)> {
+        let one = Self::from_real(S::one());
+        let one_half = S::one() / (S::one() + S::one());
+        let threshold: S = num_traits::cast(0.01_f64).unwrap();
+        if !z.is_zero() {
+            let u_squared = *c_squared / (*z * *z);
+            if u_squared.norm() < threshold {
+                // For small `u == c / z`, evaluate `atanh(u) / u` by its Taylor series
+                // `sum(u^(2 * k) / (2 * k + 1))` to avoid the cancellation in
+                // `ln(z + c) - ln(z - c)`.
+                let two = S::one() + S::one();
+                let mut atanhc = Self::from_real(S::zero());
+                for k in (0..=8).rev() {
+                    let denominator = two * num_traits::cast::<i32, S>(k).unwrap() + S::one();
+                    atanhc = atanhc * u_squared + Self::from_real(S::one() / denominator);
+                }
+
+                let alpha = z.ln() + (one - u_squared).ln().scale(one_half);
+                let beta = atanhc / *z;
+
+                return Some((alpha, beta));
+            }
+        }
+
+        let c = c_squared.sqrt();
+        let z_plus_c = *z + c;
+        let z_minus_c = *z - c;
+        if z_plus_c.is_zero() || z_minus_c.is_zero() {
+            return None;
+        }
+
+        let ln_z_plus_c = z_plus_c.ln();
+        let ln_z_minus_c = z_minus_c.ln();
+        let alpha = (ln_z_plus_c + ln_z_minus_c).scale(one_half);
+        let beta = (ln_z_plus_c - ln_z_minus_c) / (c + c);
+
+        Some((alpha, beta))
+    }
+}
+
+impl<S> ops::Add<Complex<S>> for Complex<S>
+where
+    S: ScalarFloat,
+{
+    type Output = Complex<S>;
+
+    #[inline]
+    fn add(self, other: Complex<S>) -> Self::Output {
+        Complex::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl<S> ops::Sub<Complex<S>> for Complex<S>
+where
+    S: ScalarFloat,
+{
+    type Output = Complex<S>;
+
+    #[inline]
+    fn sub(self, other: Complex<S>) -> Self::Output {
+        Complex::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl<S> ops::Mul<Complex<S>> for Complex<S>
+where
+    S: ScalarFloat,
+{
+    type Output = Complex<S>;
+
+    #[inline]
+    fn mul(self, other: Complex<S>) -> Self::Output {
+        Complex::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+}
+
+impl<S> ops::Div<Complex<S>> for Complex<S>
+where
+    S: ScalarFloat,
+{
+    type Output = Complex<S>;
+
+    #[inline]
+    fn div(self, other: Complex<S>) -> Self::Output {
+        let one_over_norm_squared = S::one() / other.norm_squared();
+        let re = (self.re * other.re + self.im * other.im) * one_over_norm_squared;
+        let im = (self.im * other.re - self.re * other.im) * one_over_norm_squared;
+
+        Complex::new(re, im)
+    }
+}
+
+impl<S> ops::Neg for Complex<S>
+where
+    S: ScalarFloat,
+{
+    type Output = Complex<S>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Complex::new(-self.re, -self.im)
+    }
+}
@@ -1,3 +1,5 @@
+use crate::complex::Complex;
+use crate::grade::GradeMask;
 use crate::scalar::{
     Scalar,
     ScalarFloat,
@@ -9,8 +11,15 @@ use crate::{
 };
 use approx_cmp::ulps_ne;
 use core::fmt;
+use core::iter;
 use core::ops;
 
+mod blade;
+mod rotor;
+
+pub use self::blade::*;
+pub use self::rotor::*;
+
 
 /// A stack-allocated, two-dimensional Euclidean multivector
 /// in the basis the orthonormal basis `{1, e1, e2, e12}`.
@@ -62,6 +71,58 @@ impl<S> EuclideanMultivector2<S> {
     pub fn as_slice(&self) -> &[S] {
         <Self as AsRef<[S; 4]>>::as_ref(self)
     }
+
+    /// Returns an iterator over the components of a multivector.
+    ///
+    /// The components appear in the same order as the basis blades
+    /// in `BasisBlade::ALL`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// #
+    /// let mv = EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32);
+    /// let sum: i32 = mv.iter().sum();
+    ///
+    /// assert_eq!(sum, 10);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> core::slice::Iter<'_, S> {
+        self.data.iter()
+    }
+
+    /// Returns an iterator over the components of a multivector that allows
+    /// modifying each component.
+    #[inline]
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, S> {
+        self.data.iter_mut()
+    }
+
+    /// Returns an iterator over the basis blades of a multivector paired
+    /// with their components.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e2ga::{
+    /// #     BasisBlade,
+    /// #     EuclideanMultivector2,
+    /// # };
+    /// #
+    /// let mv = EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32);
+    /// let mut blades = mv.iter_blades();
+    ///
+    /// assert_eq!(blades.next(), Some((BasisBlade::Scalar, 1)));
+    /// assert_eq!(blades.next(), Some((BasisBlade::E1, 2)));
+    /// ```
+    #[inline]
+    pub fn iter_blades(&self) -> impl Iterator<Item = (BasisBlade, S)>
+    where
+        S: Copy,
+    {
+        BasisBlade::ALL.into_iter().zip(self.data)
+    }
 }
 
 impl<S> EuclideanMultivector2<S>
@@ -251,6 +312,73 @@ where
         }
     }
 
+    /// Project the multivector onto the grades in the grade mask `mask`.
+    ///
+    /// Return a multivector where the components of each grade outside of
+    /// `mask` are zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// # use cggeomalg::grade::GradeMask;
+    /// #
+    /// let mv = EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32);
+    /// let expected = EuclideanMultivector2::new(1_i32, 0_i32, 0_i32, 4_i32);
+    /// let result = mv.grades(GradeMask::SCALAR | GradeMask::BIVECTOR);
+    ///
+    /// assert_eq!(result, expected);
+    /// assert_eq!(mv.grades(GradeMask::all()), mv);
+    /// assert_eq!(mv.grades(GradeMask::empty()), EuclideanMultivector2::zero());
+    /// ```
+    pub fn grades(&self, mask: GradeMask) -> Self {
+        let mut result = Self::zero();
+        for blade in BasisBlade::ALL {
+            if mask.contains_grade(blade.grade()) {
+                result[blade] = self[blade];
+            }
+        }
+
+        result
+    }
+
+    /// Compute the even part of a multivector, i.e. its projection onto the
+    /// even grades.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// #
+    /// let mv = EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32);
+    /// let expected = EuclideanMultivector2::new(1_i32, 0_i32, 0_i32, 4_i32);
+    ///
+    /// assert_eq!(mv.even_part(), expected);
+    /// assert_eq!(mv.even_part() + mv.odd_part(), mv);
+    /// ```
+    #[inline]
+    pub fn even_part(&self) -> Self {
+        self.grades(GradeMask::EVEN)
+    }
+
+    /// Compute the odd part of a multivector, i.e. its projection onto the
+    /// odd grades.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// #
+    /// let mv = EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32);
+    /// let expected = EuclideanMultivector2::new(0_i32, 2_i32, 3_i32, 0_i32);
+    ///
+    /// assert_eq!(mv.odd_part(), expected);
+    /// ```
+    #[inline]
+    pub fn odd_part(&self) -> Self {
+        self.grades(GradeMask::ODD)
+    }
+
     /// Compute the left contraction of `self` with `other`.
     ///
     /// This is a synonym for the `<<` operator.
@@ -306,6 +434,28 @@ where
     }
 }
 
+impl<S> ops::Index<BasisBlade> for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = S;
+
+    #[inline]
+    fn index(&self, blade: BasisBlade) -> &Self::Output {
+        &self.data[blade.index()]
+    }
+}
+
+impl<S> ops::IndexMut<BasisBlade> for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn index_mut(&mut self, blade: BasisBlade) -> &mut Self::Output {
+        &mut self.data[blade.index()]
+    }
+}
+
 impl<S> AsRef<[S; 4]> for EuclideanMultivector2<S> {
     #[inline]
     fn as_ref(&self) -> &[S; 4] {
@@ -334,6 +484,109 @@ impl<S> AsMut<(S, S, S, S)> for EuclideanMultivector2<S> {
     }
 }
 
+impl<S> IntoIterator for EuclideanMultivector2<S> {
+    type Item = S;
+    type IntoIter = core::array::IntoIter<S, 4>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, S> IntoIterator for &'a EuclideanMultivector2<S> {
+    type Item = &'a S;
+    type IntoIter = core::slice::Iter<'a, S>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+impl<'a, S> IntoIterator for &'a mut EuclideanMultivector2<S> {
+    type Item = &'a mut S;
+    type IntoIter = core::slice::IterMut<'a, S>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter_mut()
+    }
+}
+
+impl<S> iter::Sum for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        let mut result = Self::zero();
+        for mv in iter {
+            result += mv;
+        }
+
+        result
+    }
+}
+
+impl<'a, S> iter::Sum<&'a EuclideanMultivector2<S>> for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a EuclideanMultivector2<S>>,
+    {
+        let mut result = Self::zero();
+        for mv in iter {
+            result += mv;
+        }
+
+        result
+    }
+}
+
+/// The product of the multivectors of an iterator.
+///
+/// The geometric product is not commutative, so the multivectors are multiplied
+/// in iteration order, i.e. the product of `[mv1, mv2, mv3]` is `mv1 * mv2 * mv3`.
+/// The product of an empty iterator is the multiplicative unit.
+impl<S> iter::Product for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        let mut result = Self::unit_scalar();
+        for mv in iter {
+            result *= mv;
+        }
+
+        result
+    }
+}
+
+impl<'a, S> iter::Product<&'a EuclideanMultivector2<S>> for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a EuclideanMultivector2<S>>,
+    {
+        let mut result = Self::unit_scalar();
+        for mv in iter {
+            result *= mv;
+        }
+
+        result
+    }
+}
+
 impl<S> fmt::Display for EuclideanMultivector2<S>
 where
     S: fmt::Display,
@@ -1711,97 +1964,885 @@ where
     }
 }
 
-impl<S> ops::Div<S> for EuclideanMultivector2<S>
+impl<S> EuclideanMultivector2<S>
 where
     S: ScalarFloat,
 {
-    type Output = EuclideanMultivector2<S>;
+    /// Split a multivector into its scalar part and its nonscalar part.
+    ///
+    /// The nonscalar part `f` of a multivector squares to a scalar
+    /// `c_squared`. This function returns the pair `(scalar, c_squared)`.
+    fn split_scalar(&self) -> (S, S) {
+        let scalar = self.data[0];
+        let c_squared = self.data[1] * self.data[1] + self.data[2] * self.data[2] - self.data[3] * self.data[3];
 
-    #[rustfmt::skip]
-    #[inline]
-    fn div(self, other: S) -> Self::Output {
-        let one_over_other = S::one() / other;
-        let result_1   = self.data[0] * one_over_other;
-        let result_e1  = self.data[1] * one_over_other;
-        let result_e2  = self.data[2] * one_over_other;
-        let result_e12 = self.data[3] * one_over_other;
+        (scalar, c_squared)
+    }
 
-        EuclideanMultivector2::new(result_1, result_e1, result_e2, result_e12)
+    /// Construct the multivector `alpha + beta * f`, where `f` is the
+    /// nonscalar part of `self`.
+    fn with_scalar_parts(&self, alpha: S, beta: S) -> Self {
+        Self::new(alpha, beta * self.data[1], beta * self.data[2], beta * self.data[3])
     }
-}
 
-impl<S> ops::Div<S> for &EuclideanMultivector2<S>
-where
-    S: ScalarFloat,
-{
-    type Output = EuclideanMultivector2<S>;
+    /// Compute `(cosh(c), sinh(c) / c)` for `c == sqrt(c_squared)`.
+    ///
+    /// Both functions are even in `c`, so the result is real even when
+    /// `c_squared` is negative.
+    fn cosh_sinhc_sqrt(c_squared: S) -> (S, S) {
+        if c_squared > S::zero() {
+            let c = c_squared.sqrt();
 
-    #[rustfmt::skip]
-    #[inline]
-    fn div(self, other: S) -> Self::Output {
-        let one_over_other = S::one() / other;
-        let result_1   = self.data[0] * one_over_other;
-        let result_e1  = self.data[1] * one_over_other;
-        let result_e2  = self.data[2] * one_over_other;
-        let result_e12 = self.data[3] * one_over_other;
+            (c.cosh(), c.sinh() / c)
+        } else if c_squared < S::zero() {
+            let c = (-c_squared).sqrt();
 
-        EuclideanMultivector2::new(result_1, result_e1, result_e2, result_e12)
+            (c.cos(), c.sin() / c)
+        } else {
+            (S::one(), S::one())
+        }
     }
-}
 
-impl<S> ops::Div<EuclideanMultivector2<S>> for EuclideanMultivector2<S>
-where
-    S: ScalarFloat,
-{
-    type Output = EuclideanMultivector2<S>;
+    /// Compute the exponential of a multivector.
+    ///
+    /// The exponential is evaluated in closed form. A multivector `mv` splits
+    /// into its scalar part `s` and a vector plus bivector part `f`. The
+    /// scalar part commutes with `f`, and `f * f == c^2` is a scalar, so
+    /// ```text
+    /// exp(mv) == exp(s) * (cosh(c) + (sinh(c) / c) * f)
+    /// ```
+    /// where the hyperbolic functions become circular functions when `c^2 < 0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// # use core::f64;
+    /// #
+    /// let angle = f64::consts::FRAC_PI_3;
+    /// let mv = EuclideanMultivector2::unit_e12() * angle;
+    /// let expected = EuclideanMultivector2::new(angle.cos(), 0_f64, 0_f64, angle.sin());
+    /// let result = mv.exp();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn exp(&self) -> Self {
+        let (scalar, c_squared) = self.split_scalar();
+        let exp_scalar = scalar.exp();
+        let (cosh_c, sinhc_c) = Self::cosh_sinhc_sqrt(c_squared);
 
-    #[allow(clippy::suspicious_arithmetic_impl)]
-    #[inline]
-    fn div(self, other: EuclideanMultivector2<S>) -> Self::Output {
-        self * other.inverse_unchecked()
+        self.with_scalar_parts(exp_scalar * cosh_c, exp_scalar * sinhc_c)
     }
-}
 
-impl<S> ops::Div<&EuclideanMultivector2<S>> for EuclideanMultivector2<S>
-where
-    S: ScalarFloat,
-{
-    type Output = EuclideanMultivector2<S>;
+    /// Compute the natural logarithm of a multivector.
+    ///
+    /// This function returns a multivector `ln_mv` such that
+    /// `ln_mv.exp() == mv`. The logarithm is evaluated in closed form using the
+    /// same splitting of the multivector into commuting parts as `exp`.
+    /// The two-dimensional Euclidean geometric algebra is isomorphic to the
+    /// algebra of real `2 x 2` matrices, so not every multivector has a
+    /// real logarithm. The function returns `None` when the multivector has
+    /// no logarithm in the algebra.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// #
+    /// let mv = EuclideanMultivector2::new(2_f64, 1_f64, -1_f64, 3_f64);
+    /// let ln_mv = mv.ln().unwrap();
+    ///
+    /// assert_relative_eq!(ln_mv.exp(), mv, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    ///
+    /// let zero_divisor = EuclideanMultivector2::new(1_f64, 1_f64, 0_f64, 0_f64);
+    ///
+    /// assert!(zero_divisor.ln().is_none());
+    /// ```
+    pub fn ln(&self) -> Option<Self> {
+        let (scalar, c_squared) = self.split_scalar();
+        let one_half = S::one() / (S::one() + S::one());
+        if c_squared > S::zero() {
+            // The eigenvalues `scalar +/- c` are real and must both be positive.
+            let c = c_squared.sqrt();
+            if scalar <= c {
+                return None;
+            }
 
-    #[allow(clippy::suspicious_arithmetic_impl)]
-    #[inline]
-    fn div(self, other: &EuclideanMultivector2<S>) -> Self::Output {
-        self * other.inverse_unchecked()
+            let alpha = ((scalar + c).ln() + (scalar - c).ln()) * one_half;
+            let beta = (c / scalar).atanh() / c;
+
+            Some(self.with_scalar_parts(alpha, beta))
+        } else if c_squared < S::zero() {
+            // The eigenvalues `scalar +/- i * c` form a complex conjugate pair.
+            let c = (-c_squared).sqrt();
+            let alpha = scalar.hypot(c).ln();
+            let beta = c.atan2(scalar) / c;
+
+            Some(self.with_scalar_parts(alpha, beta))
+        } else if self.data[1].is_zero() && self.data[2].is_zero() && self.data[3].is_zero() {
+            // A pure scalar. Negative scalars are rotations by a half turn.
+            if scalar > S::zero() {
+                Some(Self::from_scalar(scalar.ln()))
+            } else if scalar < S::zero() {
+                let pi: S = num_traits::cast(core::f64::consts::PI).unwrap();
+
+                Some(Self::new((-scalar).ln(), S::zero(), S::zero(), pi))
+            } else {
+                None
+            }
+        } else {
+            // The nonscalar part is nilpotent.
+            if scalar > S::zero() {
+                Some(self.with_scalar_parts(scalar.ln(), S::one() / scalar))
+            } else {
+                None
+            }
+        }
     }
-}
-
-impl<S> ops::Div<EuclideanMultivector2<S>> for &EuclideanMultivector2<S>
-where
-    S: ScalarFloat,
-{
-    type Output = EuclideanMultivector2<S>;
 
-    #[allow(clippy::suspicious_arithmetic_impl)]
-    #[inline]
-    fn div(self, other: EuclideanMultivector2<S>) -> Self::Output {
-        self * other.inverse_unchecked()
+    /// Compute the natural logarithm of a multivector.
+    ///
+    /// This is a synonym for `ln`.
+    #[inline(always)]
+    pub fn log(&self) -> Option<Self> {
+        self.ln()
     }
-}
 
-impl<'a, 'b, S> ops::Div<&'b EuclideanMultivector2<S>> for &'a EuclideanMultivector2<S>
-where
-    S: ScalarFloat,
-{
-    type Output = EuclideanMultivector2<S>;
+    /// Compute the hyperbolic sine of a multivector.
+    ///
+    /// The hyperbolic sine is evaluated in closed form using the same
+    /// splitting of the multivector into commuting parts as `exp`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// #
+    /// let mv = EuclideanMultivector2::new(2_f64, 1_f64, -1_f64, 3_f64);
+    /// let expected = (mv.exp() - (-mv).exp()) * 0.5_f64;
+    /// let result = mv.sinh();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    /// ```
+    pub fn sinh(&self) -> Self {
+        let (scalar, c_squared) = self.split_scalar();
+        let (cosh_c, sinhc_c) = Self::cosh_sinhc_sqrt(c_squared);
 
-    #[allow(clippy::suspicious_arithmetic_impl)]
-    #[inline]
-    fn div(self, other: &'b EuclideanMultivector2<S>) -> Self::Output {
-        self * other.inverse_unchecked()
+        self.with_scalar_parts(scalar.sinh() * cosh_c, scalar.cosh() * sinhc_c)
     }
-}
 
-impl<S> ops::Shl<EuclideanMultivector2<S>> for EuclideanMultivector2<S>
+    /// Compute the hyperbolic cosine of a multivector.
+    ///
+    /// The hyperbolic cosine is evaluated in closed form using the same
+    /// splitting of the multivector into commuting parts as `exp`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// #
+    /// let mv = EuclideanMultivector2::new(2_f64, 1_f64, -1_f64, 3_f64);
+    /// let expected = (mv.exp() + (-mv).exp()) * 0.5_f64;
+    /// let result = mv.cosh();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    /// ```
+    pub fn cosh(&self) -> Self {
+        let (scalar, c_squared) = self.split_scalar();
+        let (cosh_c, sinhc_c) = Self::cosh_sinhc_sqrt(c_squared);
+
+        self.with_scalar_parts(scalar.cosh() * cosh_c, scalar.sinh() * sinhc_c)
+    }
+
+    /// Compute the sine of a multivector.
+    ///
+    /// The sine is evaluated in closed form using the same splitting of the
+    /// multivector into commuting parts as `exp`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// #
+    /// let mv = EuclideanMultivector2::from_scalar(2_f64);
+    /// let expected = EuclideanMultivector2::from_scalar(2_f64.sin());
+    /// let result = mv.sin();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn sin(&self) -> Self {
+        let (scalar, c_squared) = self.split_scalar();
+        let (cos_c, sinc_c) = Self::cosh_sinhc_sqrt(-c_squared);
+
+        self.with_scalar_parts(scalar.sin() * cos_c, scalar.cos() * sinc_c)
+    }
+
+    /// Compute the cosine of a multivector.
+    ///
+    /// The cosine is evaluated in closed form using the same splitting of the
+    /// multivector into commuting parts as `exp`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// #
+    /// let mv = EuclideanMultivector2::from_scalar(2_f64);
+    /// let expected = EuclideanMultivector2::from_scalar(2_f64.cos());
+    /// let result = mv.cos();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn cos(&self) -> Self {
+        let (scalar, c_squared) = self.split_scalar();
+        let (cos_c, sinc_c) = Self::cosh_sinhc_sqrt(-c_squared);
+
+        self.with_scalar_parts(scalar.cos() * cos_c, -(scalar.sin() * sinc_c))
+    }
+
+    /// Compute the square root of a multivector.
+    ///
+    /// This function returns a multivector `sqrt_mv` such that
+    /// `sqrt_mv * sqrt_mv == mv`. The square root is evaluated in closed form
+    /// using the same splitting of the multivector into commuting parts as `exp`.
+    /// The two-dimensional Euclidean geometric algebra is isomorphic to the
+    /// algebra of real `2 x 2` matrices, so not every multivector has a
+    /// square root. The function returns `None` when the multivector has no
+    /// square root in the algebra.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// # use core::f64;
+    /// #
+    /// let angle = f64::consts::FRAC_PI_2;
+    /// let rotor = EuclideanMultivector2::new(angle.cos(), 0_f64, 0_f64, -angle.sin());
+    /// let half_angle = angle / 2_f64;
+    /// let expected = EuclideanMultivector2::new(half_angle.cos(), 0_f64, 0_f64, -half_angle.sin());
+    /// let result = rotor.sqrt().unwrap();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    ///
+    /// let nilpotent = EuclideanMultivector2::new(0_f64, 1_f64, 0_f64, 1_f64);
+    ///
+    /// assert!(nilpotent.sqrt().is_none());
+    /// ```
+    pub fn sqrt(&self) -> Option<Self> {
+        let (scalar, c_squared) = self.split_scalar();
+        let one_half = S::one() / (S::one() + S::one());
+        if c_squared > S::zero() {
+            // The eigenvalues `scalar +/- c` are real and must both be nonnegative.
+            let c = c_squared.sqrt();
+            if scalar < c {
+                return None;
+            }
+
+            let sum = (scalar + c).sqrt() + (scalar - c).sqrt();
+
+            Some(self.with_scalar_parts(sum * one_half, S::one() / sum))
+        } else if c_squared < S::zero() {
+            // The eigenvalues `scalar +/- i * c` form a complex conjugate pair.
+            let c = (-c_squared).sqrt();
+            let sqrt_eigenvalue = Complex::new(scalar, c).sqrt();
+
+            Some(self.with_scalar_parts(sqrt_eigenvalue.re, one_half / sqrt_eigenvalue.re))
+        } else if self.data[1].is_zero() && self.data[2].is_zero() && self.data[3].is_zero() {
+            // A pure scalar. Negative scalars have a square root in the bivector
+            // part since `e12 * e12 == -1`.
+            if scalar >= S::zero() {
+                Some(Self::from_scalar(scalar.sqrt()))
+            } else {
+                Some(Self::new(S::zero(), S::zero(), S::zero(), (-scalar).sqrt()))
+            }
+        } else {
+            // The nonscalar part is nilpotent.
+            if scalar > S::zero() {
+                let sqrt_scalar = scalar.sqrt();
+
+                Some(self.with_scalar_parts(sqrt_scalar, one_half / sqrt_scalar))
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Raise a multivector to a real power.
+    ///
+    /// The power is defined through the logarithm by
+    /// ```text
+    /// mv.powf(exponent) := exp(exponent * ln(mv))
+    /// ```
+    /// so the function returns `None` exactly when `ln` does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// # use core::f64;
+    /// #
+    /// let angle = f64::consts::FRAC_PI_2;
+    /// let rotor = EuclideanMultivector2::new(angle.cos(), 0_f64, 0_f64, -angle.sin());
+    /// let fraction = 1_f64 / 3_f64;
+    /// let expected = EuclideanMultivector2::new(
+    ///     (fraction * angle).cos(), 0_f64, 0_f64, -(fraction * angle).sin()
+    /// );
+    /// let result = rotor.powf(fraction).unwrap();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn powf(&self, exponent: S) -> Option<Self> {
+        self.ln().map(|ln_self| (ln_self * exponent).exp())
+    }
+
+    /// Raise a multivector to an integer power.
+    ///
+    /// The power is computed exactly by repeated squaring. Negative powers are
+    /// powers of the inverse, so the function returns `None` when the exponent
+    /// is negative and the multivector is not invertible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// #
+    /// let mv = EuclideanMultivector2::new(2_f64, 1_f64, -1_f64, 3_f64);
+    /// let expected = mv * mv * mv;
+    /// let result = mv.powi(3).unwrap();
+    ///
+    /// assert_eq!(result, expected);
+    ///
+    /// let expected = (mv * mv * mv).inverse().unwrap();
+    /// let result = mv.powi(-3).unwrap();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    /// ```
+    pub fn powi(&self, exponent: i32) -> Option<Self> {
+        if exponent < 0 {
+            self.inverse().map(|inverse| inverse.powu(exponent.unsigned_abs()))
+        } else {
+            Some(self.powu(exponent.unsigned_abs()))
+        }
+    }
+}
+
+impl<S> EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    /// Raise a multivector to an unsigned integer power by repeated squaring.
+    fn powu(&self, exponent: u32) -> Self {
+        let mut base = *self;
+        let mut remaining = exponent;
+        let mut result = Self::unit_scalar();
+        while remaining > 0 {
+            if remaining & 1 == 1 {
+                result *= base;
+            }
+
+            remaining >>= 1;
+            if remaining > 0 {
+                base = base * base;
+            }
+        }
+
+        result
+    }
+}
+
+impl<S> EuclideanMultivector2<S>
+where
+    S: ScalarFloat,
+{
+    /// Compute the sandwich product `v * self * rev(v)` with the even
+    /// multivector `v == s + b * e12`, without normalizing by the magnitude
+    /// of `v`.
+    ///
+    /// Conjugation by an even multivector rotates the vector part, and only
+    /// scales the scalar and bivector parts.
+    #[rustfmt::skip]
+    fn sandwich_even(&self, s: S, b: S) -> Self {
+        let two = S::one() + S::one();
+        let norm_squared = s * s + b * b;
+        let cos_term = s * s - b * b;
+        let sin_term = two * b * s;
+        let a = self;
+        let result_1   = norm_squared * a[0];
+        let result_e1  = cos_term * a[1] + sin_term * a[2];
+        let result_e2  = -sin_term * a[1] + cos_term * a[2];
+        let result_e12 = norm_squared * a[3];
+
+        Self::new(result_1, result_e1, result_e2, result_e12)
+    }
+
+    /// Compute the sandwich product `v * self * rev(v)` with the vector
+    /// `v == v1 * e1 + v2 * e2`, without normalizing by the magnitude of `v`.
+    ///
+    /// Conjugation by a vector reflects the vector part in the line spanned
+    /// by `v`, scales the scalar part, and scales and negates the bivector part.
+    #[rustfmt::skip]
+    fn sandwich_odd(&self, v1: S, v2: S) -> Self {
+        let two = S::one() + S::one();
+        let norm_squared = v1 * v1 + v2 * v2;
+        let cos_term = v1 * v1 - v2 * v2;
+        let sin_term = two * v1 * v2;
+        let a = self;
+        let result_1   = norm_squared * a[0];
+        let result_e1  = cos_term * a[1] + sin_term * a[2];
+        let result_e2  = sin_term * a[1] - cos_term * a[2];
+        let result_e12 = -norm_squared * a[3];
+
+        Self::new(result_1, result_e1, result_e2, result_e12)
+    }
+
+    /// Apply a versor to a multivector.
+    ///
+    /// A versor is a geometric product of invertible vectors. Every even
+    /// versor `V` acts on a multivector `mv` by
+    /// ```text
+    /// V * mv * inv(V)
+    /// ```
+    /// and every odd versor acts by
+    /// ```text
+    /// V * involute(mv) * inv(V)
+    /// ```
+    /// so that an odd versor acts as a reflection on every grade. In two
+    /// dimensions every multivector that is either purely even or purely odd
+    /// is a versor up to scale. The function returns `None` when the versor has
+    /// both even and odd parts, or when it is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// #
+    /// // The vector `e1` is a versor reflecting in the line orthogonal to `e1`.
+    /// let versor: EuclideanMultivector2<f64> = EuclideanMultivector2::unit_e1();
+    /// let mv = EuclideanMultivector2::new(1_f64, 2_f64, 3_f64, 4_f64);
+    /// let expected = EuclideanMultivector2::new(1_f64, -2_f64, 3_f64, -4_f64);
+    /// let result = mv.apply_versor(&versor).unwrap();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    ///
+    /// let mixed = EuclideanMultivector2::new(1_f64, 1_f64, 0_f64, 0_f64);
+    ///
+    /// assert!(mv.apply_versor(&mixed).is_none());
+    /// ```
+    pub fn apply_versor(&self, versor: &Self) -> Option<Self> {
+        let v = versor;
+        let is_even = v[1].is_zero() && v[2].is_zero();
+        let is_odd = v[0].is_zero() && v[3].is_zero();
+        if is_even {
+            let norm_squared = v[0] * v[0] + v[3] * v[3];
+            if norm_squared.is_zero() {
+                return None;
+            }
+
+            Some(self.sandwich_even(v[0], v[3]) / norm_squared)
+        } else if is_odd {
+            let norm_squared = v[1] * v[1] + v[2] * v[2];
+
+            Some(self.involute().sandwich_odd(v[1], v[2]) / norm_squared)
+        } else {
+            None
+        }
+    }
+
+    /// Reflect a multivector in the line spanned by a vector.
+    ///
+    /// The reflection of a multivector `mv` in the line spanned by the
+    /// vector `a` is given by
+    /// ```text
+    /// a * mv * inv(a)
+    /// ```
+    /// Only the grade one part of `vector` is used. The function returns
+    /// `None` when the vector is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// #
+    /// let vector: EuclideanMultivector2<f64> = EuclideanMultivector2::unit_e1() * 2_f64;
+    /// let mv = EuclideanMultivector2::new(0_f64, 1_f64, 1_f64, 0_f64);
+    /// let expected = EuclideanMultivector2::new(0_f64, 1_f64, -1_f64, 0_f64);
+    /// let result = mv.reflect_in_vector(&vector).unwrap();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn reflect_in_vector(&self, vector: &Self) -> Option<Self> {
+        let (a1, a2) = (vector[1], vector[2]);
+        let norm_squared = a1 * a1 + a2 * a2;
+        if norm_squared.is_zero() {
+            return None;
+        }
+
+        Some(self.sandwich_odd(a1, a2) / norm_squared)
+    }
+
+    /// Reflect a multivector in the plane represented by a bivector.
+    ///
+    /// The reflection of a multivector `mv` in the plane represented by the
+    /// bivector `B` is given by
+    /// ```text
+    /// B * involute(mv) * inv(B)
+    /// ```
+    /// Only the grade two part of `plane` is used. In two dimensions every
+    /// nonzero bivector represents the whole plane, so the reflection leaves
+    /// every multivector unchanged. To reflect in a line, use `apply_versor`
+    /// with the normal vector of the line, or `reflect_in_vector` with its
+    /// direction vector. The function returns `None` when the bivector is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// #
+    /// let plane: EuclideanMultivector2<f64> = EuclideanMultivector2::unit_e12();
+    /// let mv = EuclideanMultivector2::new(1_f64, 2_f64, 3_f64, 4_f64);
+    /// let result = mv.reflect_in_plane(&plane).unwrap();
+    ///
+    /// assert_relative_eq!(result, mv, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn reflect_in_plane(&self, plane: &Self) -> Option<Self> {
+        let b12 = plane[3];
+        let norm_squared = b12 * b12;
+        if norm_squared.is_zero() {
+            return None;
+        }
+
+        Some(self.involute().sandwich_even(S::zero(), b12) / norm_squared)
+    }
+
+    /// Rotate a multivector by a rotor.
+    ///
+    /// The rotated multivector is the sandwich product `R * mv * rev(R)`.
+    /// Only the even part of `rotor` is used, and the rotor is assumed to have
+    /// unit magnitude, so that its reverse is its inverse.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// # use core::f64;
+    /// #
+    /// let half_angle = f64::consts::FRAC_PI_4;
+    /// let rotor = EuclideanMultivector2::new(half_angle.cos(), 0_f64, 0_f64, -half_angle.sin());
+    /// let e1: EuclideanMultivector2<f64> = EuclideanMultivector2::unit_e1();
+    /// let expected: EuclideanMultivector2<f64> = EuclideanMultivector2::unit_e2();
+    /// let result = e1.rotate(&rotor);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn rotate(&self, rotor: &Self) -> Self {
+        self.sandwich_even(rotor[0], rotor[3])
+    }
+
+    /// Project a multivector onto a blade.
+    ///
+    /// The projection of a multivector `mv` onto an invertible blade `B` is
+    /// given by
+    /// ```text
+    /// project(mv, B) := (mv << B) << inv(B)
+    /// ```
+    /// where `<<` denotes the left contraction. Both contractions are left
+    /// contractions: the first one removes the components of `mv` that are
+    /// orthogonal to `B`, and the second one maps the result back into the
+    /// subspace of `B`. The function returns `None` when the blade is not
+    /// invertible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// #
+    /// let line: EuclideanMultivector2<f64> = EuclideanMultivector2::unit_e1();
+    /// let vector = EuclideanMultivector2::new(0_f64, 1_f64, 2_f64, 0_f64);
+    /// let expected = EuclideanMultivector2::new(0_f64, 1_f64, 0_f64, 0_f64);
+    /// let result = vector.project_onto(&line).unwrap();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn project_onto(&self, blade: &Self) -> Option<Self> {
+        let blade_inverse = blade.inverse()?;
+
+        Some((self << blade) << blade_inverse)
+    }
+
+    /// Reject a multivector from a blade.
+    ///
+    /// The rejection of a multivector `mv` from an invertible blade `B` is
+    /// the part of `mv` that remains after removing its projection onto `B`
+    /// ```text
+    /// reject(mv, B) := mv - project(mv, B)
+    /// ```
+    /// For a vector, this is the component orthogonal to the subspace of `B`,
+    /// so that a vector splits into its tangential and normal parts
+    /// ```text
+    /// v == project(v, B) + reject(v, B)
+    /// ```
+    /// The function returns `None` when the blade is not invertible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// #
+    /// let line: EuclideanMultivector2<f64> = EuclideanMultivector2::unit_e1();
+    /// let vector = EuclideanMultivector2::new(0_f64, 1_f64, 2_f64, 0_f64);
+    /// let expected = EuclideanMultivector2::new(0_f64, 0_f64, 2_f64, 0_f64);
+    /// let result = vector.reject_from(&line).unwrap();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn reject_from(&self, blade: &Self) -> Option<Self> {
+        self.project_onto(blade).map(|projection| self - projection)
+    }
+}
+
+impl<S> ops::Div<S> for EuclideanMultivector2<S>
+where
+    S: ScalarFloat,
+{
+    type Output = EuclideanMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn div(self, other: S) -> Self::Output {
+        let one_over_other = S::one() / other;
+        let result_1   = self.data[0] * one_over_other;
+        let result_e1  = self.data[1] * one_over_other;
+        let result_e2  = self.data[2] * one_over_other;
+        let result_e12 = self.data[3] * one_over_other;
+
+        EuclideanMultivector2::new(result_1, result_e1, result_e2, result_e12)
+    }
+}
+
+impl<S> ops::Div<S> for &EuclideanMultivector2<S>
+where
+    S: ScalarFloat,
+{
+    type Output = EuclideanMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn div(self, other: S) -> Self::Output {
+        let one_over_other = S::one() / other;
+        let result_1   = self.data[0] * one_over_other;
+        let result_e1  = self.data[1] * one_over_other;
+        let result_e2  = self.data[2] * one_over_other;
+        let result_e12 = self.data[3] * one_over_other;
+
+        EuclideanMultivector2::new(result_1, result_e1, result_e2, result_e12)
+    }
+}
+
+impl<S> ops::Div<EuclideanMultivector2<S>> for EuclideanMultivector2<S>
+where
+    S: ScalarFloat,
+{
+    type Output = EuclideanMultivector2<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn div(self, other: EuclideanMultivector2<S>) -> Self::Output {
+        self * other.inverse_unchecked()
+    }
+}
+
+impl<S> ops::Div<&EuclideanMultivector2<S>> for EuclideanMultivector2<S>
+where
+    S: ScalarFloat,
+{
+    type Output = EuclideanMultivector2<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn div(self, other: &EuclideanMultivector2<S>) -> Self::Output {
+        self * other.inverse_unchecked()
+    }
+}
+
+impl<S> ops::Div<EuclideanMultivector2<S>> for &EuclideanMultivector2<S>
+where
+    S: ScalarFloat,
+{
+    type Output = EuclideanMultivector2<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn div(self, other: EuclideanMultivector2<S>) -> Self::Output {
+        self * other.inverse_unchecked()
+    }
+}
+
+impl<'a, 'b, S> ops::Div<&'b EuclideanMultivector2<S>> for &'a EuclideanMultivector2<S>
+where
+    S: ScalarFloat,
+{
+    type Output = EuclideanMultivector2<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn div(self, other: &'b EuclideanMultivector2<S>) -> Self::Output {
+        self * other.inverse_unchecked()
+    }
+}
+
+impl<S> num_traits::Zero for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn zero() -> Self {
+        Self::zero()
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        Self::is_zero(self)
+    }
+}
+
+impl<S> num_traits::One for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn one() -> Self {
+        Self::unit_scalar()
+    }
+}
+
+impl<S> num_traits::Inv for EuclideanMultivector2<S>
+where
+    S: ScalarFloat,
+{
+    type Output = EuclideanMultivector2<S>;
+
+    /// Compute the multiplicative inverse of a multivector.
+    ///
+    /// Like division, this does not check for invertibility, so the result
+    /// has NaN components when `self` is not invertible. Use
+    /// [`EuclideanMultivector2::inverse`] for the checked inverse.
+    #[inline]
+    fn inv(self) -> Self::Output {
+        self.inverse_unchecked()
+    }
+}
+
+impl<S> num_traits::Inv for &EuclideanMultivector2<S>
+where
+    S: ScalarFloat,
+{
+    type Output = EuclideanMultivector2<S>;
+
+    /// Compute the multiplicative inverse of a multivector.
+    ///
+    /// Like division, this does not check for invertibility, so the result
+    /// has NaN components when `self` is not invertible. Use
+    /// [`EuclideanMultivector2::inverse`] for the checked inverse.
+    #[inline]
+    fn inv(self) -> Self::Output {
+        self.inverse_unchecked()
+    }
+}
+
+impl<S> num_traits::Pow<i32> for EuclideanMultivector2<S>
+where
+    S: ScalarFloat,
+{
+    type Output = EuclideanMultivector2<S>;
+
+    /// Raise a multivector to an integer power.
+    ///
+    /// Negative powers are powers of the unchecked inverse, so the result has
+    /// NaN components when the exponent is negative and `self` is not
+    /// invertible. Use [`EuclideanMultivector2::powi`] for the checked power.
+    #[inline]
+    fn pow(self, exponent: i32) -> Self::Output {
+        if exponent < 0 {
+            self.inverse_unchecked().powu(exponent.unsigned_abs())
+        } else {
+            self.powu(exponent.unsigned_abs())
+        }
+    }
+}
+
+impl<S> num_traits::Pow<i32> for &EuclideanMultivector2<S>
+where
+    S: ScalarFloat,
+{
+    type Output = EuclideanMultivector2<S>;
+
+    /// Raise a multivector to an integer power.
+    ///
+    /// Negative powers are powers of the unchecked inverse, so the result has
+    /// NaN components when the exponent is negative and `self` is not
+    /// invertible. Use [`EuclideanMultivector2::powi`] for the checked power.
+    #[inline]
+    fn pow(self, exponent: i32) -> Self::Output {
+        if exponent < 0 {
+            self.inverse_unchecked().powu(exponent.unsigned_abs())
+        } else {
+            self.powu(exponent.unsigned_abs())
+        }
+    }
+}
+
+impl<S> num_traits::Pow<u32> for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector2<S>;
+
+    #[inline]
+    fn pow(self, exponent: u32) -> Self::Output {
+        self.powu(exponent)
+    }
+}
+
+impl<S> num_traits::Pow<u32> for &EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector2<S>;
+
+    #[inline]
+    fn pow(self, exponent: u32) -> Self::Output {
+        self.powu(exponent)
+    }
+}
+
+impl<S> num_traits::MulAdd for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector2<S>;
+
+    #[inline]
+    fn mul_add(self, a: EuclideanMultivector2<S>, b: EuclideanMultivector2<S>) -> Self::Output {
+        self * a + b
+    }
+}
+
+impl<S> ops::Shl<EuclideanMultivector2<S>> for EuclideanMultivector2<S>
 where
     S: Scalar,
 {
@@ -1832,7 +2873,7 @@ where
     fn shl(self, other: &EuclideanMultivector2<S>) -> Self::Output {
         let a = self;
         let b = other;
-        let result_1   = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3];
+        let result_1   = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] - a[3] * b[3];
         let result_e1  = a[0] * b[1] - a[2] * b[3];
         let result_e2  = a[0] * b[2] + a[1] * b[3];
         let result_e12 = a[0] * b[3];
@@ -1852,7 +2893,7 @@ where
     fn shl(self, other: EuclideanMultivector2<S>) -> Self::Output {
         let a = self;
         let b = other;
-        let result_1   = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3];
+        let result_1   = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] - a[3] * b[3];
         let result_e1  = a[0] * b[1] - a[2] * b[3];
         let result_e2  = a[0] * b[2] + a[1] * b[3];
         let result_e12 = a[0] * b[3];
@@ -1872,7 +2913,7 @@ where
     fn shl(self, other: &'b EuclideanMultivector2<S>) -> Self::Output {
         let a = self;
         let b = other;
-        let result_1   = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3];
+        let result_1   = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] - a[3] * b[3];
         let result_e1  = a[0] * b[1] - a[2] * b[3];
         let result_e2  = a[0] * b[2] + a[1] * b[3];
         let result_e12 = a[0] * b[3];
@@ -2029,6 +3070,389 @@ where
     }
 }
 
+impl<S> ops::AddAssign<EuclideanMultivector2<S>> for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    #[rustfmt::skip]
+    #[inline]
+    fn add_assign(&mut self, other: EuclideanMultivector2<S>) {
+        self.data[0] += other.data[0];
+        self.data[1] += other.data[1];
+        self.data[2] += other.data[2];
+        self.data[3] += other.data[3];
+    }
+}
+
+impl<S> ops::AddAssign<&EuclideanMultivector2<S>> for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    #[rustfmt::skip]
+    #[inline]
+    fn add_assign(&mut self, other: &EuclideanMultivector2<S>) {
+        self.data[0] += other.data[0];
+        self.data[1] += other.data[1];
+        self.data[2] += other.data[2];
+        self.data[3] += other.data[3];
+    }
+}
+
+impl<S> ops::AddAssign<S> for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn add_assign(&mut self, other: S) {
+        self.data[0] += other;
+    }
+}
+
+impl<S> ops::SubAssign<EuclideanMultivector2<S>> for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    #[rustfmt::skip]
+    #[inline]
+    fn sub_assign(&mut self, other: EuclideanMultivector2<S>) {
+        self.data[0] -= other.data[0];
+        self.data[1] -= other.data[1];
+        self.data[2] -= other.data[2];
+        self.data[3] -= other.data[3];
+    }
+}
+
+impl<S> ops::SubAssign<&EuclideanMultivector2<S>> for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    #[rustfmt::skip]
+    #[inline]
+    fn sub_assign(&mut self, other: &EuclideanMultivector2<S>) {
+        self.data[0] -= other.data[0];
+        self.data[1] -= other.data[1];
+        self.data[2] -= other.data[2];
+        self.data[3] -= other.data[3];
+    }
+}
+
+impl<S> ops::SubAssign<S> for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn sub_assign(&mut self, other: S) {
+        self.data[0] -= other;
+    }
+}
+
+impl<S> ops::MulAssign<EuclideanMultivector2<S>> for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn mul_assign(&mut self, other: EuclideanMultivector2<S>) {
+        *self = *self * other;
+    }
+}
+
+impl<S> ops::MulAssign<&EuclideanMultivector2<S>> for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn mul_assign(&mut self, other: &EuclideanMultivector2<S>) {
+        *self = *self * other;
+    }
+}
+
+impl<S> ops::MulAssign<S> for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    #[rustfmt::skip]
+    #[inline]
+    fn mul_assign(&mut self, other: S) {
+        self.data[0] *= other;
+        self.data[1] *= other;
+        self.data[2] *= other;
+        self.data[3] *= other;
+    }
+}
+
+impl<S> ops::DivAssign<EuclideanMultivector2<S>> for EuclideanMultivector2<S>
+where
+    S: ScalarFloat,
+{
+    #[inline]
+    fn div_assign(&mut self, other: EuclideanMultivector2<S>) {
+        *self = *self / other;
+    }
+}
+
+impl<S> ops::DivAssign<&EuclideanMultivector2<S>> for EuclideanMultivector2<S>
+where
+    S: ScalarFloat,
+{
+    #[inline]
+    fn div_assign(&mut self, other: &EuclideanMultivector2<S>) {
+        *self = *self / other;
+    }
+}
+
+impl<S> ops::DivAssign<S> for EuclideanMultivector2<S>
+where
+    S: ScalarFloat,
+{
+    #[rustfmt::skip]
+    #[inline]
+    fn div_assign(&mut self, other: S) {
+        let one_over_other = S::one() / other;
+        self.data[0] *= one_over_other;
+        self.data[1] *= one_over_other;
+        self.data[2] *= one_over_other;
+        self.data[3] *= one_over_other;
+    }
+}
+
+impl<S> ops::BitXorAssign<EuclideanMultivector2<S>> for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn bitxor_assign(&mut self, other: EuclideanMultivector2<S>) {
+        *self = *self ^ other;
+    }
+}
+
+impl<S> ops::BitXorAssign<&EuclideanMultivector2<S>> for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn bitxor_assign(&mut self, other: &EuclideanMultivector2<S>) {
+        *self = *self ^ other;
+    }
+}
+
+impl<S> ops::BitXorAssign<S> for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor_assign(&mut self, other: S) {
+        self.data[0] *= other;
+        self.data[1] *= other;
+        self.data[2] *= other;
+        self.data[3] *= other;
+    }
+}
+
+impl<S> ops::BitOrAssign<EuclideanMultivector2<S>> for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn bitor_assign(&mut self, other: EuclideanMultivector2<S>) {
+        *self = *self | other;
+    }
+}
+
+impl<S> ops::BitOrAssign<&EuclideanMultivector2<S>> for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn bitor_assign(&mut self, other: &EuclideanMultivector2<S>) {
+        *self = *self | other;
+    }
+}
+
+impl<S> ops::BitOrAssign<S> for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn bitor_assign(&mut self, other: S) {
+        *self = *self | other;
+    }
+}
+
+impl<S> ops::ShlAssign<EuclideanMultivector2<S>> for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn shl_assign(&mut self, other: EuclideanMultivector2<S>) {
+        *self = *self << other;
+    }
+}
+
+impl<S> ops::ShlAssign<&EuclideanMultivector2<S>> for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn shl_assign(&mut self, other: &EuclideanMultivector2<S>) {
+        *self = *self << other;
+    }
+}
+
+impl<S> ops::ShlAssign<S> for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn shl_assign(&mut self, other: S) {
+        *self = *self << other;
+    }
+}
+
+impl<S> ops::ShrAssign<EuclideanMultivector2<S>> for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn shr_assign(&mut self, other: EuclideanMultivector2<S>) {
+        *self = *self >> other;
+    }
+}
+
+impl<S> ops::ShrAssign<&EuclideanMultivector2<S>> for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn shr_assign(&mut self, other: &EuclideanMultivector2<S>) {
+        *self = *self >> other;
+    }
+}
+
+impl<S> ops::ShrAssign<S> for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    #[rustfmt::skip]
+    #[inline]
+    fn shr_assign(&mut self, other: S) {
+        self.data[0] *= other;
+        self.data[1] *= other;
+        self.data[2] *= other;
+        self.data[3] *= other;
+    }
+}
+
+impl<S> EuclideanMultivector2<S>
+where
+    S: ScalarFloat,
+{
+    /// Determine which grades of a multivector are nonzero.
+    ///
+    /// A grade belongs to the grade mask when at least one of its components
+    /// differs from zero by more than the default tolerance of the scalar type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// # use cggeomalg::grade::GradeMask;
+    /// #
+    /// let mv: EuclideanMultivector2<f64> = EuclideanMultivector2::from_scalar(2_f64) + EuclideanMultivector2::unit_e12() * 3_f64;
+    ///
+    /// assert_eq!(mv.grade_mask(), GradeMask::SCALAR | GradeMask::BIVECTOR);
+    /// assert_eq!(EuclideanMultivector2::<f64>::zero().grade_mask(), GradeMask::empty());
+    /// ```
+    pub fn grade_mask(&self) -> GradeMask {
+        let mut result = GradeMask::empty();
+        for (blade, component) in self.iter_blades() {
+            let is_nonzero = ulps_ne!(
+                component,
+                S::zero(),
+                abs_diff_all <= S::default_epsilon(),
+                ulps_all <= S::default_max_ulps()
+            );
+            if is_nonzero {
+                result |= GradeMask::from_bits(1 << blade.grade());
+            }
+        }
+
+        result
+    }
+
+    /// Determine whether a multivector is homogeneous, i.e. whether at most one
+    /// of its grades is nonzero.
+    ///
+    /// The zero multivector is homogeneous, since it belongs to every grade.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// #
+    /// let e1: EuclideanMultivector2<f64> = EuclideanMultivector2::unit_e1();
+    /// let e12: EuclideanMultivector2<f64> = EuclideanMultivector2::unit_e12();
+    ///
+    /// assert!(e1.is_homogeneous());
+    /// assert!((e1 * 2_f64).is_homogeneous());
+    /// assert!(!(e1 + e12).is_homogeneous());
+    /// ```
+    #[inline]
+    pub fn is_homogeneous(&self) -> bool {
+        self.grade_mask().len() <= 1
+    }
+
+    /// Determine whether a multivector is a blade, i.e. the outer product of
+    /// vectors.
+    ///
+    /// In two dimensions, every homogeneous multivector is a blade, so this
+    /// is equivalent to `is_homogeneous`.
+    #[inline]
+    pub fn is_blade(&self) -> bool {
+        self.is_homogeneous()
+    }
+
+    /// Determine whether a multivector is a versor, i.e. the geometric product
+    /// of invertible vectors.
+    ///
+    /// A multivector `mv` is a versor if and only if it is nonzero and has a single
+    /// parity, `mv * rev(mv)` is a scalar, and the sandwich product
+    /// `mv * v * rev(mv)` maps every vector `v` to a vector. These are the
+    /// multivectors that the sandwich product methods such as `apply_versor` expect.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e2ga::EuclideanMultivector2;
+    /// #
+    /// let e1: EuclideanMultivector2<f64> = EuclideanMultivector2::unit_e1();
+    /// let e2: EuclideanMultivector2<f64> = EuclideanMultivector2::unit_e2();
+    /// let one: EuclideanMultivector2<f64> = EuclideanMultivector2::unit_scalar();
+    ///
+    /// assert!(e1.is_versor());
+    /// assert!((e1 * (e1 + e2)).is_versor());
+    /// assert!(!(one + e1).is_versor());
+    /// assert!(!EuclideanMultivector2::<f64>::zero().is_versor());
+    /// ```
+    pub fn is_versor(&self) -> bool {
+        let mask = self.grade_mask();
+        if mask.is_empty() || (mask.intersects(GradeMask::EVEN) && mask.intersects(GradeMask::ODD)) {
+            return false;
+        }
+
+        let versor = self / self.magnitude();
+        let versor_reverse = versor.reverse();
+        if (versor * versor_reverse).grade_mask() != GradeMask::SCALAR {
+            return false;
+        }
+
+        let unit_vectors: [Self; 2] = [Self::unit_e1(), Self::unit_e2()];
+
+        unit_vectors
+            .iter()
+            .all(|vector| GradeMask::VECTOR.contains((versor * vector * versor_reverse).grade_mask()))
+    }
+}
 
 impl_coords!(ViewG2, { scalar, e1, e2, e12 });
 impl_coords_deref!(EuclideanMultivector2, ViewG2);
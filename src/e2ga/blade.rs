@@ -0,0 +1,103 @@
+use core::fmt;
+
+
+/// The basis blades of the two-dimensional Euclidean geometric algebra.
+///
+/// The variants appear in the component order of `EuclideanMultivector2`,
+/// i.e. in the basis `{1, e1, e2, e12}`, so that a basis blade indexes the
+/// matching component of a multivector.
+///
+/// # Example
+///
+/// ```
+/// # use cggeomalg::e2ga::{
+/// #     BasisBlade,
+/// #     EuclideanMultivector2,
+/// # };
+/// #
+/// let mut mv: EuclideanMultivector2<i32> = EuclideanMultivector2::zero();
+/// for blade in BasisBlade::ALL {
+///     mv[blade] = blade.grade() as i32;
+/// }
+///
+/// assert_eq!(mv[BasisBlade::E12], 2);
+/// assert_eq!(mv.grade(2), EuclideanMultivector2::unit_e12() * 2);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub enum BasisBlade {
+    Scalar,
+    E1,
+    E2,
+    E12,
+}
+
+impl BasisBlade {
+    /// The basis blades in the component order of `EuclideanMultivector2`.
+    pub const ALL: [BasisBlade; 4] = [BasisBlade::Scalar, BasisBlade::E1, BasisBlade::E2, BasisBlade::E12];
+
+    /// The grade of the basis blade, i.e. the number of basis vectors
+    /// in the blade.
+    #[inline]
+    pub const fn grade(self) -> usize {
+        match self {
+            BasisBlade::Scalar => 0,
+            BasisBlade::E1 => 1,
+            BasisBlade::E2 => 1,
+            BasisBlade::E12 => 2,
+        }
+    }
+
+    /// The index of the component of the basis blade in `EuclideanMultivector2`.
+    #[inline]
+    pub const fn index(self) -> usize {
+        match self {
+            BasisBlade::Scalar => 0,
+            BasisBlade::E1 => 1,
+            BasisBlade::E2 => 2,
+            BasisBlade::E12 => 3,
+        }
+    }
+
+    /// The name of the basis blade, which is also the name of its
+    /// component in the coordinate view of `EuclideanMultivector2`.
+    #[inline]
+    pub const fn name(self) -> &'static str {
+        match self {
+            BasisBlade::Scalar => "scalar",
+            BasisBlade::E1 => "e1",
+            BasisBlade::E2 => "e2",
+            BasisBlade::E12 => "e12",
+        }
+    }
+
+    /// The sign of the square of the basis blade under the geometric product.
+    ///
+    /// The square of every basis blade is either `1` or `-1`.
+    #[inline]
+    pub const fn square(self) -> i8 {
+        match self {
+            BasisBlade::Scalar => 1,
+            BasisBlade::E1 => 1,
+            BasisBlade::E2 => 1,
+            BasisBlade::E12 => -1,
+        }
+    }
+
+    /// The sign that the reverse of the basis blade picks up, i.e.
+    /// `rev(blade) == reverse_sign * blade`.
+    #[inline]
+    pub const fn reverse_sign(self) -> i8 {
+        match self {
+            BasisBlade::Scalar => 1,
+            BasisBlade::E1 => 1,
+            BasisBlade::E2 => 1,
+            BasisBlade::E12 => -1,
+        }
+    }
+}
+
+impl fmt::Display for BasisBlade {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(self.name())
+    }
+}
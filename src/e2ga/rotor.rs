@@ -0,0 +1,654 @@
+use crate::e2ga::EuclideanMultivector2;
+use crate::scalar::{
+    Scalar,
+    ScalarFloat,
+    ScalarSigned,
+};
+use crate::{
+    impl_approx_cmp_ops,
+    impl_coords,
+    impl_coords_deref,
+};
+use core::fmt;
+use core::ops;
+
+
+/// A stack-allocated rotor in two-dimensional Euclidean space.
+///
+/// A rotor is a unit element of the even subalgebra of the two-dimensional
+/// Euclidean geometric algebra. It is stored in the basis `{1, e12}`, so only
+/// the scalar and bivector parts of a rotor are represented. Since
+/// `e12 * e12 == -1`, the even subalgebra is isomorphic to the complex
+/// numbers, and a rotor is a unit complex number.
+///
+/// A rotor `R` rotates a vector `v` by the sandwich product
+/// ```text
+/// rotate(R, v) := R * v * rev(R)
+/// ```
+/// where `rev` denotes the reverse. The rotor that rotates by an angle `angle`
+/// is given by
+/// ```text
+/// R := exp(-(angle / 2) * e12) == cos(angle / 2) - sin(angle / 2) * e12
+/// ```
+/// so that a positive angle rotates `e1` towards `e2`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Rotor2<S> {
+    data: [S; 2],
+}
+
+impl<S> Rotor2<S> {
+    /// Construct a new rotor from its components.
+    ///
+    /// The components are not normalized, so the caller is responsible for
+    /// ensuring that the resulting rotor has unit magnitude.
+    #[inline]
+    pub const fn new(scalar: S, e12: S) -> Self {
+        Self { data: [scalar, e12] }
+    }
+
+    /// Returns the number of components in a rotor.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e2ga::Rotor2;
+    /// #
+    /// let rotor = Rotor2::new(1_f64, 0_f64);
+    ///
+    /// assert_eq!(rotor.len(), 2);
+    /// ```
+    #[inline]
+    pub const fn len(&self) -> usize {
+        2
+    }
+
+    /// Get a pointer to the underlying component array.
+    #[inline]
+    pub const fn as_ptr(&self) -> *const S {
+        &self.data[0]
+    }
+
+    /// Get a mutable pointer to the underlying component array.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut S {
+        &mut self.data[0]
+    }
+
+    /// Get a slice of the underlying elements of the data type.
+    #[inline]
+    pub fn as_slice(&self) -> &[S] {
+        <Self as AsRef<[S; 2]>>::as_ref(self)
+    }
+}
+
+impl<S> Rotor2<S>
+where
+    S: Scalar,
+{
+    /// Construct the identity rotor.
+    ///
+    /// The identity rotor leaves every vector unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e2ga::{
+    /// #     EuclideanMultivector2,
+    /// #     Rotor2,
+    /// # };
+    /// #
+    /// let identity: Rotor2<f64> = Rotor2::identity();
+    /// let vector = EuclideanMultivector2::new(0_f64, 1_f64, 2_f64, 0_f64);
+    ///
+    /// assert_eq!(identity.rotate_vector(&vector), vector);
+    /// ```
+    #[inline]
+    pub fn identity() -> Self {
+        Self::new(S::one(), S::zero())
+    }
+}
+
+impl<S> Rotor2<S>
+where
+    S: ScalarSigned,
+{
+    /// Compute the reverse of a rotor.
+    ///
+    /// The reverse of a unit rotor is its inverse, i.e. it performs the
+    /// opposite rotation. It corresponds to complex conjugation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e2ga::Rotor2;
+    /// #
+    /// let rotor = Rotor2::new(1_i32, 2_i32);
+    /// let expected = Rotor2::new(1_i32, -2_i32);
+    /// let result = rotor.reverse();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn reverse(&self) -> Self {
+        Self::new(self.data[0], -self.data[1])
+    }
+}
+
+impl<S> Rotor2<S>
+where
+    S: ScalarFloat,
+{
+    /// Construct the rotor that rotates by the angle `angle`.
+    ///
+    /// A positive angle rotates `e1` towards `e2`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e2ga::{
+    /// #     EuclideanMultivector2,
+    /// #     Rotor2,
+    /// # };
+    /// # use core::f64;
+    /// #
+    /// let e1: EuclideanMultivector2<f64> = EuclideanMultivector2::unit_e1();
+    /// let e2: EuclideanMultivector2<f64> = EuclideanMultivector2::unit_e2();
+    /// let rotor = Rotor2::from_angle(f64::consts::FRAC_PI_2);
+    /// let result = rotor.rotate_vector(&e1);
+    ///
+    /// assert_relative_eq!(result, e2, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn from_angle(angle: S) -> Self {
+        let one_half = S::one() / (S::one() + S::one());
+        let (sin_half_angle, cos_half_angle) = (angle * one_half).sin_cos();
+
+        Self::new(cos_half_angle, -sin_half_angle)
+    }
+
+    /// Compute the angle that a rotor rotates by.
+    ///
+    /// The angle is in the range `(-pi, pi]`, and a positive angle rotates
+    /// `e1` towards `e2`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e2ga::Rotor2;
+    /// # use core::f64;
+    /// #
+    /// let rotor = Rotor2::from_angle(f64::consts::FRAC_PI_3);
+    ///
+    /// assert_relative_eq!(rotor.angle(), f64::consts::FRAC_PI_3, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn angle(&self) -> S {
+        let two = S::one() + S::one();
+        let cos_angle = self.data[0] * self.data[0] - self.data[1] * self.data[1];
+        let sin_angle = -two * self.data[0] * self.data[1];
+
+        sin_angle.atan2(cos_angle)
+    }
+
+    /// Compute the exponential of a bivector.
+    ///
+    /// Only the grade two part of `bivector` is used. Since `e12 * e12 == -1`,
+    /// the exponential has the closed form
+    /// ```text
+    /// exp(phi * e12) == cos(phi) + sin(phi) * e12
+    /// ```
+    /// which is always a unit rotor. In particular, the rotor that rotates by
+    /// an angle `angle` is `exp(-(angle / 2) * e12)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e2ga::{
+    /// #     EuclideanMultivector2,
+    /// #     Rotor2,
+    /// # };
+    /// # use core::f64;
+    /// #
+    /// let e12: EuclideanMultivector2<f64> = EuclideanMultivector2::unit_e12();
+    /// let angle = f64::consts::FRAC_PI_3;
+    /// let expected = Rotor2::from_angle(angle);
+    /// let result = Rotor2::exp(&(e12 * (-angle / 2_f64)));
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn exp(bivector: &EuclideanMultivector2<S>) -> Self {
+        let (sin_phi, cos_phi) = bivector[3].sin_cos();
+
+        Self::new(cos_phi, sin_phi)
+    }
+
+    /// Compute the logarithm of a rotor.
+    ///
+    /// The logarithm is the bivector `phi * e12` with `-pi < phi <= pi` such
+    /// that `exp(phi * e12) == R`, where `R` is the normalized rotor.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e2ga::{
+    /// #     EuclideanMultivector2,
+    /// #     Rotor2,
+    /// # };
+    /// #
+    /// let bivector = EuclideanMultivector2::new(0_f64, 0_f64, 0_f64, 0.7_f64);
+    /// let rotor = Rotor2::exp(&bivector);
+    /// let result = rotor.log();
+    ///
+    /// assert_relative_eq!(result, bivector, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn log(&self) -> EuclideanMultivector2<S> {
+        let zero = S::zero();
+        let phi = self.data[1].atan2(self.data[0]);
+
+        EuclideanMultivector2::new(zero, zero, zero, phi)
+    }
+
+    /// Rotate a vector by a rotor.
+    ///
+    /// The rotated vector is the sandwich product `R * v * rev(R)`. Only the
+    /// grade one part of `vector` is used, and the result is always a vector.
+    /// The rotor is assumed to have unit magnitude.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e2ga::{
+    /// #     EuclideanMultivector2,
+    /// #     Rotor2,
+    /// # };
+    /// # use core::f64;
+    /// #
+    /// let rotor = Rotor2::from_angle(f64::consts::FRAC_PI_2);
+    /// let vector = EuclideanMultivector2::new(0_f64, 1_f64, 1_f64, 0_f64);
+    /// let expected = EuclideanMultivector2::new(0_f64, -1_f64, 1_f64, 0_f64);
+    /// let result = rotor.rotate_vector(&vector);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn rotate_vector(&self, vector: &EuclideanMultivector2<S>) -> EuclideanMultivector2<S> {
+        let zero = S::zero();
+        let two = S::one() + S::one();
+        let cos_angle = self.data[0] * self.data[0] - self.data[1] * self.data[1];
+        let sin_angle = -two * self.data[0] * self.data[1];
+        let result_e1 = cos_angle * vector[1] - sin_angle * vector[2];
+        let result_e2 = sin_angle * vector[1] + cos_angle * vector[2];
+
+        EuclideanMultivector2::new(zero, result_e1, result_e2, zero)
+    }
+
+    /// Spherically interpolate between two rotors.
+    ///
+    /// The interpolated rotor rotates by a constant angular velocity along the
+    /// shortest arc from `self` at `amount == 0` to `other` at `amount == 1`.
+    /// Both rotors are assumed to have unit magnitude. The rotors `R` and `-R`
+    /// perform the same rotation, so `other` is negated when necessary to take
+    /// the shortest arc.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e2ga::Rotor2;
+    /// # use core::f64;
+    /// #
+    /// let rotor1 = Rotor2::from_angle(0_f64);
+    /// let rotor2 = Rotor2::from_angle(f64::consts::FRAC_PI_2);
+    /// let expected = Rotor2::from_angle(f64::consts::FRAC_PI_4);
+    /// let result = rotor1.slerp(&rotor2, 0.5_f64);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn slerp(&self, other: &Self, amount: S) -> Self {
+        let other = self.same_hemisphere(other);
+        let cos_half_theta = self.dot(&other);
+        let threshold = num_traits::cast(0.9995_f64).unwrap();
+        if cos_half_theta > threshold {
+            // The rotors are nearly parallel, so the linear interpolation is
+            // accurate and avoids dividing by a vanishing `sin(half_theta)`.
+            return self.lerp(&other, amount).normalize();
+        }
+
+        let half_theta = cos_half_theta.acos();
+        let sin_half_theta = half_theta.sin();
+        let a = ((S::one() - amount) * half_theta).sin() / sin_half_theta;
+        let b = (amount * half_theta).sin() / sin_half_theta;
+
+        Self::new(self.data[0] * a + other.data[0] * b, self.data[1] * a + other.data[1] * b)
+    }
+
+    /// Linearly interpolate between two rotors and normalize the result.
+    ///
+    /// Normalized linear interpolation is cheaper than [`Rotor2::slerp`] and
+    /// follows the same path, but it does not rotate at a constant angular
+    /// velocity. Both rotors are assumed to have unit magnitude, and `other`
+    /// is negated when necessary to take the shortest arc.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e2ga::Rotor2;
+    /// # use core::f64;
+    /// #
+    /// let rotor1 = Rotor2::from_angle(0_f64);
+    /// let rotor2 = Rotor2::from_angle(f64::consts::FRAC_PI_2);
+    /// let expected = Rotor2::from_angle(f64::consts::FRAC_PI_4);
+    /// let result = rotor1.nlerp(&rotor2, 0.5_f64);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn nlerp(&self, other: &Self, amount: S) -> Self {
+        let other = self.same_hemisphere(other);
+
+        self.lerp(&other, amount).normalize()
+    }
+
+    /// Interpolate between two rotors with spherical quadrangle interpolation.
+    ///
+    /// Squad interpolates along a smooth spline from `self` at `amount == 0`
+    /// to `other` at `amount == 1` by
+    /// ```text
+    /// squad(q1, q2, s1, s2, t) == slerp(slerp(q1, q2, t), slerp(s1, s2, t), 2 * t * (1 - t))
+    /// ```
+    /// where `control1` and `control2` are the control points `s1` and `s2` of
+    /// `self` and `other`, computed with [`Rotor2::squad_control_point`].
+    /// Interpolating a sequence of keyframes segment by segment with squad
+    /// gives a curve whose angular velocity is continuous at the keyframes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e2ga::Rotor2;
+    /// #
+    /// let keyframes = [
+    ///     Rotor2::from_angle(0_f64),
+    ///     Rotor2::from_angle(0.5_f64),
+    ///     Rotor2::from_angle(1.5_f64),
+    ///     Rotor2::from_angle(2_f64),
+    /// ];
+    /// let control1 = Rotor2::squad_control_point(&keyframes[0], &keyframes[1], &keyframes[2]);
+    /// let control2 = Rotor2::squad_control_point(&keyframes[1], &keyframes[2], &keyframes[3]);
+    /// let result = keyframes[1].squad(&keyframes[2], &control1, &control2, 1_f64);
+    ///
+    /// assert_relative_eq!(result, keyframes[2], abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn squad(&self, other: &Self, control1: &Self, control2: &Self, amount: S) -> Self {
+        let two = S::one() + S::one();
+        let rotor = self.slerp(other, amount);
+        let control = control1.slerp(control2, amount);
+
+        rotor.slerp(&control, two * amount * (S::one() - amount))
+    }
+
+    /// Compute the squad control point of a keyframe rotor.
+    ///
+    /// The control point of the keyframe `current` with neighboring keyframes
+    /// `previous` and `next` is given by
+    /// ```text
+    /// s == q * exp(-(log(inv(q) * q_next) + log(inv(q) * q_previous)) / 4)
+    /// ```
+    /// The neighboring keyframes are negated when necessary so that they lie
+    /// in the same hemisphere as `current`. For the first and last keyframes
+    /// of a sequence, pass the keyframe itself as the missing neighbor.
+    pub fn squad_control_point(previous: &Self, current: &Self, next: &Self) -> Self {
+        let one_fourth = S::one() / num_traits::cast(4_i32).unwrap();
+        let previous = current.same_hemisphere(previous);
+        let next = current.same_hemisphere(next);
+        let current_inv = current.inverse();
+        let log_next = (current_inv * next).log();
+        let log_previous = (current_inv * previous).log();
+        let tangent = (log_next + log_previous) * (-one_fourth);
+
+        current * Self::exp(&tangent)
+    }
+
+    #[inline]
+    fn dot(&self, other: &Self) -> S {
+        self.data[0] * other.data[0] + self.data[1] * other.data[1]
+    }
+
+    #[inline]
+    fn same_hemisphere(&self, other: &Self) -> Self {
+        if self.dot(other) < S::zero() { -other } else { *other }
+    }
+
+    #[inline]
+    fn lerp(&self, other: &Self, amount: S) -> Self {
+        let one_minus_amount = S::one() - amount;
+
+        Self::new(
+            self.data[0] * one_minus_amount + other.data[0] * amount,
+            self.data[1] * one_minus_amount + other.data[1] * amount,
+        )
+    }
+
+    /// Calculate the squared magnitude of a rotor.
+    #[inline]
+    pub fn magnitude_squared(&self) -> S {
+        self.data[0] * self.data[0] + self.data[1] * self.data[1]
+    }
+
+    /// Calculate the magnitude of a rotor.
+    #[inline]
+    pub fn magnitude(&self) -> S {
+        self.magnitude_squared().sqrt()
+    }
+
+    /// Normalize a rotor to a unit rotor.
+    ///
+    /// Repeated composition of rotors accumulates rounding errors that cause
+    /// the result to drift away from unit magnitude. Normalizing the rotor
+    /// projects it back onto the set of rotors.
+    #[inline]
+    pub fn normalize(&self) -> Self {
+        let one_over_magnitude = S::one() / self.magnitude();
+
+        Self::new(self.data[0] * one_over_magnitude, self.data[1] * one_over_magnitude)
+    }
+
+    /// Compute the inverse of a unit rotor.
+    ///
+    /// The inverse of a unit rotor is its reverse.
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        self.reverse()
+    }
+}
+
+impl<S> ops::Index<usize> for Rotor2<S>
+where
+    S: Scalar,
+{
+    type Output = S;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.data[index]
+    }
+}
+
+impl<S> ops::IndexMut<usize> for Rotor2<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.data[index]
+    }
+}
+
+impl<S> AsRef<[S; 2]> for Rotor2<S> {
+    #[inline]
+    fn as_ref(&self) -> &[S; 2] {
+        unsafe { &*(self as *const Rotor2<S> as *const [S; 2]) }
+    }
+}
+
+impl<S> AsMut<[S; 2]> for Rotor2<S> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [S; 2] {
+        unsafe { &mut *(self as *mut Rotor2<S> as *mut [S; 2]) }
+    }
+}
+
+impl<S> fmt::Display for Rotor2<S>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{} + {}^e12", self.data[0], self.data[1])
+    }
+}
+
+impl<S> From<Rotor2<S>> for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn from(rotor: Rotor2<S>) -> EuclideanMultivector2<S> {
+        let zero = S::zero();
+
+        EuclideanMultivector2::new(rotor.data[0], zero, zero, rotor.data[1])
+    }
+}
+
+impl<S> From<&Rotor2<S>> for EuclideanMultivector2<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn from(rotor: &Rotor2<S>) -> EuclideanMultivector2<S> {
+        let zero = S::zero();
+
+        EuclideanMultivector2::new(rotor.data[0], zero, zero, rotor.data[1])
+    }
+}
+
+/// Convert a rotor into a 2x2 rotation matrix.
+///
+/// The matrix is stored in column-major order, i.e. `matrix[column][row]`, so
+/// that the columns of the matrix are the images of `e1` and `e2` under the
+/// rotation. The rotor is assumed to have unit magnitude.
+impl<S> From<Rotor2<S>> for [[S; 2]; 2]
+where
+    S: ScalarSigned,
+{
+    #[inline]
+    fn from(rotor: Rotor2<S>) -> [[S; 2]; 2] {
+        From::from(&rotor)
+    }
+}
+
+/// Convert a rotor into a 2x2 rotation matrix.
+///
+/// The matrix is stored in column-major order, i.e. `matrix[column][row]`, so
+/// that the columns of the matrix are the images of `e1` and `e2` under the
+/// rotation. The rotor is assumed to have unit magnitude.
+impl<S> From<&Rotor2<S>> for [[S; 2]; 2]
+where
+    S: ScalarSigned,
+{
+    #[inline]
+    fn from(rotor: &Rotor2<S>) -> [[S; 2]; 2] {
+        let two = S::one() + S::one();
+        let cos_angle = rotor.data[0] * rotor.data[0] - rotor.data[1] * rotor.data[1];
+        let sin_angle = -two * rotor.data[0] * rotor.data[1];
+
+        [[cos_angle, sin_angle], [-sin_angle, cos_angle]]
+    }
+}
+
+macro_rules! impl_rotor_mul_ops {
+    ($Lhs:ty, $Rhs:ty) => {
+        impl<S> ops::Mul<$Rhs> for $Lhs
+        where
+            S: Scalar,
+        {
+            type Output = Rotor2<S>;
+
+            #[inline]
+            fn mul(self, other: $Rhs) -> Self::Output {
+                let a = self;
+                let b = other;
+                let result_1 = a[0] * b[0] - a[1] * b[1];
+                let result_e12 = a[0] * b[1] + a[1] * b[0];
+
+                Rotor2::new(result_1, result_e12)
+            }
+        }
+    };
+}
+
+impl_rotor_mul_ops!(Rotor2<S>, Rotor2<S>);
+impl_rotor_mul_ops!(Rotor2<S>, &Rotor2<S>);
+impl_rotor_mul_ops!(&Rotor2<S>, Rotor2<S>);
+impl_rotor_mul_ops!(&Rotor2<S>, &Rotor2<S>);
+
+impl<S> ops::Mul<S> for Rotor2<S>
+where
+    S: Scalar,
+{
+    type Output = Rotor2<S>;
+
+    #[inline]
+    fn mul(self, other: S) -> Self::Output {
+        Rotor2::new(self.data[0] * other, self.data[1] * other)
+    }
+}
+
+impl<S> ops::Mul<S> for &Rotor2<S>
+where
+    S: Scalar,
+{
+    type Output = Rotor2<S>;
+
+    #[inline]
+    fn mul(self, other: S) -> Self::Output {
+        Rotor2::new(self.data[0] * other, self.data[1] * other)
+    }
+}
+
+impl<S> ops::Neg for Rotor2<S>
+where
+    S: ScalarSigned,
+{
+    type Output = Rotor2<S>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Rotor2::new(-self.data[0], -self.data[1])
+    }
+}
+
+impl<S> ops::Neg for &Rotor2<S>
+where
+    S: ScalarSigned,
+{
+    type Output = Rotor2<S>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Rotor2::new(-self.data[0], -self.data[1])
+    }
+}
+
+
+impl_coords!(ViewR2, { scalar, e12 });
+impl_coords_deref!(Rotor2, ViewR2);
+
+impl_approx_cmp_ops!(Rotor2);
@@ -1,3 +1,5 @@
+use crate::complex::Complex;
+use crate::grade::GradeMask;
 use crate::scalar::{
     Scalar,
     ScalarFloat,
@@ -9,8 +11,21 @@ use crate::{
 };
 use approx_cmp::ulps_ne;
 use core::fmt;
+use core::iter;
 use core::ops;
 
+mod blade;
+mod even;
+mod graded;
+mod orientation;
+mod rotor;
+
+pub use self::blade::*;
+pub use self::even::*;
+pub use self::graded::*;
+pub use self::orientation::*;
+pub use self::rotor::*;
+
 
 /// A stack-allocated, three-dimensional Euclidean multivector
 /// in the basis the basis orthonormal `{1, e1, e2, e3, e12, e23, e31, e123}`.
@@ -62,6 +77,58 @@ impl<S> EuclideanMultivector3<S> {
     pub fn as_slice(&self) -> &[S] {
         <Self as AsRef<[S; 8]>>::as_ref(self)
     }
+
+    /// Returns an iterator over the components of a multivector.
+    ///
+    /// The components appear in the same order as the basis blades
+    /// in `BasisBlade::ALL`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let mv = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+    /// let sum: i32 = mv.iter().sum();
+    ///
+    /// assert_eq!(sum, 36);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> core::slice::Iter<'_, S> {
+        self.data.iter()
+    }
+
+    /// Returns an iterator over the components of a multivector that allows
+    /// modifying each component.
+    #[inline]
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, S> {
+        self.data.iter_mut()
+    }
+
+    /// Returns an iterator over the basis blades of a multivector paired
+    /// with their components.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e3ga::{
+    /// #     BasisBlade,
+    /// #     EuclideanMultivector3,
+    /// # };
+    /// #
+    /// let mv = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+    /// let mut blades = mv.iter_blades();
+    ///
+    /// assert_eq!(blades.next(), Some((BasisBlade::Scalar, 1)));
+    /// assert_eq!(blades.next(), Some((BasisBlade::E1, 2)));
+    /// ```
+    #[inline]
+    pub fn iter_blades(&self) -> impl Iterator<Item = (BasisBlade, S)>
+    where
+        S: Copy,
+    {
+        BasisBlade::ALL.into_iter().zip(self.data)
+    }
 }
 
 impl<S> EuclideanMultivector3<S>
@@ -491,6 +558,73 @@ where
         }
     }
 
+    /// Project the multivector onto the grades in the grade mask `mask`.
+    ///
+    /// Return a multivector where the components of each grade outside of
+    /// `mask` are zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// # use cggeomalg::grade::GradeMask;
+    /// #
+    /// let mv = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+    /// let expected = EuclideanMultivector3::new(1_i32, 0_i32, 0_i32, 0_i32, 5_i32, 6_i32, 7_i32, 0_i32);
+    /// let result = mv.grades(GradeMask::SCALAR | GradeMask::BIVECTOR);
+    ///
+    /// assert_eq!(result, expected);
+    /// assert_eq!(mv.grades(GradeMask::all()), mv);
+    /// assert_eq!(mv.grades(GradeMask::empty()), EuclideanMultivector3::zero());
+    /// ```
+    pub fn grades(&self, mask: GradeMask) -> Self {
+        let mut result = Self::zero();
+        for blade in BasisBlade::ALL {
+            if mask.contains_grade(blade.grade()) {
+                result[blade] = self[blade];
+            }
+        }
+
+        result
+    }
+
+    /// Compute the even part of a multivector, i.e. its projection onto the
+    /// even grades.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let mv = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+    /// let expected = EuclideanMultivector3::new(1_i32, 0_i32, 0_i32, 0_i32, 5_i32, 6_i32, 7_i32, 0_i32);
+    ///
+    /// assert_eq!(mv.even_part(), expected);
+    /// assert_eq!(mv.even_part() + mv.odd_part(), mv);
+    /// ```
+    #[inline]
+    pub fn even_part(&self) -> Self {
+        self.grades(GradeMask::EVEN)
+    }
+
+    /// Compute the odd part of a multivector, i.e. its projection onto the
+    /// odd grades.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let mv = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+    /// let expected = EuclideanMultivector3::new(0_i32, 2_i32, 3_i32, 4_i32, 0_i32, 0_i32, 0_i32, 8_i32);
+    ///
+    /// assert_eq!(mv.odd_part(), expected);
+    /// ```
+    #[inline]
+    pub fn odd_part(&self) -> Self {
+        self.grades(GradeMask::ODD)
+    }
+
     /// Compute the left contraction of `self` with `other`.
     ///
     /// This is a synonym for the `<<` operator.
@@ -522,6 +656,37 @@ where
     pub fn outer_product(&self, other: &Self) -> Self {
         self ^ other
     }
+
+    /// Compute the regressive product of `self` and `other`.
+    ///
+    /// The regressive product is the outer product of the duals, mapped back
+    /// by the inverse of the duality map
+    /// ```text
+    /// mv1 & mv2 := undual(dual(mv1) ^ dual(mv2))
+    /// ```
+    /// where `undual(mv) := mv * e123 == -dual(mv)`. For blades whose
+    /// subspaces together span the whole space, the regressive product
+    /// computes the intersection of the subspaces.
+    ///
+    /// This is a synonym for the `&` operator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let e12: EuclideanMultivector3<i32> = EuclideanMultivector3::unit_e12();
+    /// let e23: EuclideanMultivector3<i32> = EuclideanMultivector3::unit_e23();
+    /// let expected = -EuclideanMultivector3::unit_e2();
+    /// let result = e12.regressive_product(&e23);
+    ///
+    /// assert_eq!(result, expected);
+    /// assert_eq!(result, -(e12.dual() ^ e23.dual()).dual());
+    /// ```
+    #[inline]
+    pub fn regressive_product(&self, other: &Self) -> Self {
+        self & other
+    }
 }
 
 impl<S> ops::Index<usize> for EuclideanMultivector3<S>
@@ -546,6 +711,28 @@ where
     }
 }
 
+impl<S> ops::Index<BasisBlade> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = S;
+
+    #[inline]
+    fn index(&self, blade: BasisBlade) -> &Self::Output {
+        &self.data[blade.index()]
+    }
+}
+
+impl<S> ops::IndexMut<BasisBlade> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn index_mut(&mut self, blade: BasisBlade) -> &mut Self::Output {
+        &mut self.data[blade.index()]
+    }
+}
+
 impl<S> AsRef<[S; 8]> for EuclideanMultivector3<S> {
     #[inline]
     fn as_ref(&self) -> &[S; 8] {
@@ -574,6 +761,109 @@ impl<S> AsMut<(S, S, S, S, S, S, S, S)> for EuclideanMultivector3<S> {
     }
 }
 
+impl<S> IntoIterator for EuclideanMultivector3<S> {
+    type Item = S;
+    type IntoIter = core::array::IntoIter<S, 8>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, S> IntoIterator for &'a EuclideanMultivector3<S> {
+    type Item = &'a S;
+    type IntoIter = core::slice::Iter<'a, S>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+impl<'a, S> IntoIterator for &'a mut EuclideanMultivector3<S> {
+    type Item = &'a mut S;
+    type IntoIter = core::slice::IterMut<'a, S>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter_mut()
+    }
+}
+
+impl<S> iter::Sum for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        let mut result = Self::zero();
+        for mv in iter {
+            result += mv;
+        }
+
+        result
+    }
+}
+
+impl<'a, S> iter::Sum<&'a EuclideanMultivector3<S>> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a EuclideanMultivector3<S>>,
+    {
+        let mut result = Self::zero();
+        for mv in iter {
+            result += mv;
+        }
+
+        result
+    }
+}
+
+/// The product of the multivectors of an iterator.
+///
+/// The geometric product is not commutative, so the multivectors are multiplied
+/// in iteration order, i.e. the product of `[mv1, mv2, mv3]` is `mv1 * mv2 * mv3`.
+/// The product of an empty iterator is the multiplicative unit.
+impl<S> iter::Product for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        let mut result = Self::unit_scalar();
+        for mv in iter {
+            result *= mv;
+        }
+
+        result
+    }
+}
+
+impl<'a, S> iter::Product<&'a EuclideanMultivector3<S>> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a EuclideanMultivector3<S>>,
+    {
+        let mut result = Self::unit_scalar();
+        for mv in iter {
+            result *= mv;
+        }
+
+        result
+    }
+}
+
 impl<S> fmt::Display for EuclideanMultivector3<S>
 where
     S: fmt::Display,
@@ -1544,6 +1834,138 @@ where
     }
 }
 
+impl<S> ops::BitAnd<EuclideanMultivector3<S>> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitand(self, other: EuclideanMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1    = a[0] * b[7] + a[1] * b[5] + a[2] * b[6] + a[3] * b[4] + a[4] * b[3] + a[5] * b[1] + a[6] * b[2] + a[7] * b[0];
+        let result_e1   = a[1] * b[7] + a[4] * b[6] - a[6] * b[4] + a[7] * b[1];
+        let result_e2   = a[2] * b[7] - a[4] * b[5] + a[5] * b[4] + a[7] * b[2];
+        let result_e3   = a[3] * b[7] - a[5] * b[6] + a[6] * b[5] + a[7] * b[3];
+        let result_e12  = a[4] * b[7] + a[7] * b[4];
+        let result_e23  = a[5] * b[7] + a[7] * b[5];
+        let result_e31  = a[6] * b[7] + a[7] * b[6];
+        let result_e123 = a[7] * b[7];
+
+        EuclideanMultivector3::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e123,
+        )
+    }
+}
+
+impl<S> ops::BitAnd<&EuclideanMultivector3<S>> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitand(self, other: &EuclideanMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1    = a[0] * b[7] + a[1] * b[5] + a[2] * b[6] + a[3] * b[4] + a[4] * b[3] + a[5] * b[1] + a[6] * b[2] + a[7] * b[0];
+        let result_e1   = a[1] * b[7] + a[4] * b[6] - a[6] * b[4] + a[7] * b[1];
+        let result_e2   = a[2] * b[7] - a[4] * b[5] + a[5] * b[4] + a[7] * b[2];
+        let result_e3   = a[3] * b[7] - a[5] * b[6] + a[6] * b[5] + a[7] * b[3];
+        let result_e12  = a[4] * b[7] + a[7] * b[4];
+        let result_e23  = a[5] * b[7] + a[7] * b[5];
+        let result_e31  = a[6] * b[7] + a[7] * b[6];
+        let result_e123 = a[7] * b[7];
+
+        EuclideanMultivector3::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e123,
+        )
+    }
+}
+
+impl<S> ops::BitAnd<EuclideanMultivector3<S>> for &EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitand(self, other: EuclideanMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1    = a[0] * b[7] + a[1] * b[5] + a[2] * b[6] + a[3] * b[4] + a[4] * b[3] + a[5] * b[1] + a[6] * b[2] + a[7] * b[0];
+        let result_e1   = a[1] * b[7] + a[4] * b[6] - a[6] * b[4] + a[7] * b[1];
+        let result_e2   = a[2] * b[7] - a[4] * b[5] + a[5] * b[4] + a[7] * b[2];
+        let result_e3   = a[3] * b[7] - a[5] * b[6] + a[6] * b[5] + a[7] * b[3];
+        let result_e12  = a[4] * b[7] + a[7] * b[4];
+        let result_e23  = a[5] * b[7] + a[7] * b[5];
+        let result_e31  = a[6] * b[7] + a[7] * b[6];
+        let result_e123 = a[7] * b[7];
+
+        EuclideanMultivector3::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e123,
+        )
+    }
+}
+
+impl<S> ops::BitAnd<&EuclideanMultivector3<S>> for &EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitand(self, other: &EuclideanMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1    = a[0] * b[7] + a[1] * b[5] + a[2] * b[6] + a[3] * b[4] + a[4] * b[3] + a[5] * b[1] + a[6] * b[2] + a[7] * b[0];
+        let result_e1   = a[1] * b[7] + a[4] * b[6] - a[6] * b[4] + a[7] * b[1];
+        let result_e2   = a[2] * b[7] - a[4] * b[5] + a[5] * b[4] + a[7] * b[2];
+        let result_e3   = a[3] * b[7] - a[5] * b[6] + a[6] * b[5] + a[7] * b[3];
+        let result_e12  = a[4] * b[7] + a[7] * b[4];
+        let result_e23  = a[5] * b[7] + a[7] * b[5];
+        let result_e31  = a[6] * b[7] + a[7] * b[6];
+        let result_e123 = a[7] * b[7];
+
+        EuclideanMultivector3::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e123,
+        )
+    }
+}
+
 impl<S> ops::BitOr<EuclideanMultivector3<S>> for EuclideanMultivector3<S>
 where
     S: Scalar,
@@ -2432,21 +2854,728 @@ where
     }
 }
 
-impl<S> ops::Div<S> for EuclideanMultivector3<S>
+impl<S> EuclideanMultivector3<S>
 where
     S: ScalarFloat,
 {
-    type Output = EuclideanMultivector3<S>;
+    /// Split a multivector into its central part and its noncentral part.
+    ///
+    /// The scalar and pseudoscalar parts of a multivector commute with every
+    /// multivector, and `e123 * e123 == -1`, so together they behave like a
+    /// complex number `z`. The remaining vector and bivector part `f` squares
+    /// to another complex number `c_squared`. This function returns the pair
+    /// `(z, c_squared)`.
+    fn split_center(&self) -> (Complex<S>, Complex<S>) {
+        let z = Complex::new(self.data[0], self.data[7]);
+        let vector_squared = self.data[1] * self.data[1] + self.data[2] * self.data[2] + self.data[3] * self.data[3];
+        let bivector_squared = self.data[4] * self.data[4] + self.data[5] * self.data[5] + self.data[6] * self.data[6];
+        let vector_dot_bivector = self.data[1] * self.data[5] + self.data[2] * self.data[6] + self.data[3] * self.data[4];
+        let c_squared = Complex::new(vector_squared - bivector_squared, vector_dot_bivector + vector_dot_bivector);
+
+        (z, c_squared)
+    }
 
+    /// Construct the multivector `alpha + beta * f`, where `f` is the
+    /// noncentral part of `self`, and the complex numbers `alpha` and `beta`
+    /// are embedded into the algebra as `re + im * e123`.
     #[rustfmt::skip]
-    #[inline]
-    fn div(self, other: S) -> Self::Output {
-        let one_over_other = S::one() / other;
-        let result_1    = self.data[0] * one_over_other;
-        let result_e1   = self.data[1] * one_over_other;
-        let result_e2   = self.data[2] * one_over_other;
-        let result_e3   = self.data[3] * one_over_other;
-        let result_e12  = self.data[4] * one_over_other;
+    fn with_center_parts(&self, alpha: Complex<S>, beta: Complex<S>) -> Self {
+        let a = self;
+        let result_1   = alpha.re;
+        let result_e1  = beta.re * a[1] - beta.im * a[5];
+        let result_e2  = beta.re * a[2] - beta.im * a[6];
+        let result_e3  = beta.re * a[3] - beta.im * a[4];
+        let result_e12 = beta.re * a[4] + beta.im * a[3];
+        let result_e23 = beta.re * a[5] + beta.im * a[1];
+        let result_e31 = beta.re * a[6] + beta.im * a[2];
+        let result_e123 = alpha.im;
+
+        Self::new(
+            result_1, result_e1, result_e2, result_e3,
+            result_e12, result_e23, result_e31, result_e123
+        )
+    }
+
+    /// Compute the exponential of a multivector.
+    ///
+    /// The exponential is evaluated in closed form. A multivector `mv` splits
+    /// into a central part `z`, made of its scalar and pseudoscalar parts, and
+    /// a vector plus bivector part `f` that commutes with `z`. Since
+    /// `f * f == c^2` for a complex scalar `c` in the span of `{1, e123}`, we have
+    /// ```text
+    /// exp(mv) == exp(z) * (cosh(c) + (sinh(c) / c) * f)
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// # use core::f64;
+    /// #
+    /// let angle = f64::consts::FRAC_PI_3;
+    /// let mv = EuclideanMultivector3::unit_e12() * angle;
+    /// let expected = EuclideanMultivector3::new(
+    ///     angle.cos(), 0_f64, 0_f64, 0_f64, angle.sin(), 0_f64, 0_f64, 0_f64
+    /// );
+    /// let result = mv.exp();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn exp(&self) -> Self {
+        let (z, c_squared) = self.split_center();
+        let exp_z = z.exp();
+        let (cosh_c, sinhc_c) = Complex::cosh_sinhc_sqrt(&c_squared);
+
+        self.with_center_parts(exp_z * cosh_c, exp_z * sinhc_c)
+    }
+
+    /// Compute the natural logarithm of a multivector.
+    ///
+    /// This function returns a multivector `ln_mv` such that
+    /// `ln_mv.exp() == mv`. The logarithm is evaluated in closed form using the
+    /// same splitting of the multivector into commuting parts as `exp`, with
+    /// the principal branch of the complex logarithm for the central part.
+    /// The function returns `None` when the multivector has no logarithm. This
+    /// happens exactly when the multivector is a zero divisor, i.e. when it
+    /// is not invertible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let mv = EuclideanMultivector3::new(
+    ///     1_f64, 2_f64, -1_f64, 3_f64, -2_f64, 1_f64, 4_f64, -3_f64
+    /// );
+    /// let ln_mv = mv.ln().unwrap();
+    ///
+    /// assert_relative_eq!(ln_mv.exp(), mv, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    ///
+    /// let zero_divisor = EuclideanMultivector3::new(
+    ///     1_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64
+    /// );
+    ///
+    /// assert!(zero_divisor.ln().is_none());
+    /// ```
+    pub fn ln(&self) -> Option<Self> {
+        let (z, c_squared) = self.split_center();
+        let (alpha, beta) = Complex::ln_split(&z, &c_squared)?;
+
+        Some(self.with_center_parts(alpha, beta))
+    }
+
+    /// Compute the natural logarithm of a multivector.
+    ///
+    /// This is a synonym for `ln`.
+    #[inline(always)]
+    pub fn log(&self) -> Option<Self> {
+        self.ln()
+    }
+
+    /// Compute the hyperbolic sine of a multivector.
+    ///
+    /// The hyperbolic sine is evaluated in closed form using the same
+    /// splitting of the multivector into commuting parts as `exp`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let mv = EuclideanMultivector3::new(
+    ///     1_f64, 2_f64, -1_f64, 3_f64, -2_f64, 1_f64, 4_f64, -3_f64
+    /// );
+    /// let expected = (mv.exp() - (-mv).exp()) * 0.5_f64;
+    /// let result = mv.sinh();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    /// ```
+    pub fn sinh(&self) -> Self {
+        let (z, c_squared) = self.split_center();
+        let (cosh_c, sinhc_c) = Complex::cosh_sinhc_sqrt(&c_squared);
+
+        self.with_center_parts(z.sinh() * cosh_c, z.cosh() * sinhc_c)
+    }
+
+    /// Compute the hyperbolic cosine of a multivector.
+    ///
+    /// The hyperbolic cosine is evaluated in closed form using the same
+    /// splitting of the multivector into commuting parts as `exp`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let mv = EuclideanMultivector3::new(
+    ///     1_f64, 2_f64, -1_f64, 3_f64, -2_f64, 1_f64, 4_f64, -3_f64
+    /// );
+    /// let expected = (mv.exp() + (-mv).exp()) * 0.5_f64;
+    /// let result = mv.cosh();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    /// ```
+    pub fn cosh(&self) -> Self {
+        let (z, c_squared) = self.split_center();
+        let (cosh_c, sinhc_c) = Complex::cosh_sinhc_sqrt(&c_squared);
+
+        self.with_center_parts(z.cosh() * cosh_c, z.sinh() * sinhc_c)
+    }
+
+    /// Compute the sine of a multivector.
+    ///
+    /// The sine is evaluated in closed form using the same splitting of the
+    /// multivector into commuting parts as `exp`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let mv = EuclideanMultivector3::from_scalar(2_f64);
+    /// let expected = EuclideanMultivector3::from_scalar(2_f64.sin());
+    /// let result = mv.sin();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn sin(&self) -> Self {
+        let (z, c_squared) = self.split_center();
+        let (cos_c, sinc_c) = Complex::cosh_sinhc_sqrt(&(-c_squared));
+
+        self.with_center_parts(z.sin() * cos_c, z.cos() * sinc_c)
+    }
+
+    /// Compute the cosine of a multivector.
+    ///
+    /// The cosine is evaluated in closed form using the same splitting of the
+    /// multivector into commuting parts as `exp`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let mv = EuclideanMultivector3::from_scalar(2_f64);
+    /// let expected = EuclideanMultivector3::from_scalar(2_f64.cos());
+    /// let result = mv.cos();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn cos(&self) -> Self {
+        let (z, c_squared) = self.split_center();
+        let (cos_c, sinc_c) = Complex::cosh_sinhc_sqrt(&(-c_squared));
+
+        self.with_center_parts(z.cos() * cos_c, -(z.sin() * sinc_c))
+    }
+
+    /// Compute the square root of a multivector.
+    ///
+    /// This function returns a multivector `sqrt_mv` such that
+    /// `sqrt_mv * sqrt_mv == mv`. The square root is evaluated in closed form
+    /// using the same splitting of the multivector into commuting parts as `exp`.
+    /// The function returns `None` when the multivector has no square root. This
+    /// happens exactly when the multivector is nonzero and nilpotent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// # use core::f64;
+    /// #
+    /// let angle = f64::consts::FRAC_PI_2;
+    /// let rotor = EuclideanMultivector3::new(
+    ///     angle.cos(), 0_f64, 0_f64, 0_f64, -angle.sin(), 0_f64, 0_f64, 0_f64
+    /// );
+    /// let half_angle = angle / 2_f64;
+    /// let expected = EuclideanMultivector3::new(
+    ///     half_angle.cos(), 0_f64, 0_f64, 0_f64, -half_angle.sin(), 0_f64, 0_f64, 0_f64
+    /// );
+    /// let result = rotor.sqrt().unwrap();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    ///
+    /// let nilpotent = EuclideanMultivector3::new(
+    ///     0_f64, 1_f64, 0_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64
+    /// );
+    ///
+    /// assert!(nilpotent.sqrt().is_none());
+    /// ```
+    pub fn sqrt(&self) -> Option<Self> {
+        if self.is_zero() {
+            return Some(Self::zero());
+        }
+
+        let (z, c_squared) = self.split_center();
+        let (alpha, beta) = Complex::sqrt_split(&z, &c_squared)?;
+
+        Some(self.with_center_parts(alpha, beta))
+    }
+
+    /// Raise a multivector to a real power.
+    ///
+    /// The power is defined through the logarithm by
+    /// ```text
+    /// mv.powf(exponent) := exp(exponent * ln(mv))
+    /// ```
+    /// so the function returns `None` exactly when `ln` does, i.e. when the
+    /// multivector is not invertible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// # use core::f64;
+    /// #
+    /// let angle = f64::consts::FRAC_PI_2;
+    /// let rotor = EuclideanMultivector3::new(
+    ///     angle.cos(), 0_f64, 0_f64, 0_f64, 0_f64, -angle.sin(), 0_f64, 0_f64
+    /// );
+    /// let fraction = 1_f64 / 3_f64;
+    /// let expected = EuclideanMultivector3::new(
+    ///     (fraction * angle).cos(), 0_f64, 0_f64, 0_f64, 0_f64, -(fraction * angle).sin(), 0_f64, 0_f64
+    /// );
+    /// let result = rotor.powf(fraction).unwrap();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn powf(&self, exponent: S) -> Option<Self> {
+        self.ln().map(|ln_self| (ln_self * exponent).exp())
+    }
+
+    /// Raise a multivector to an integer power.
+    ///
+    /// The power is computed exactly by repeated squaring. Negative powers are
+    /// powers of the inverse, so the function returns `None` when the exponent
+    /// is negative and the multivector is not invertible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let mv = EuclideanMultivector3::new(
+    ///     1_f64, 2_f64, -1_f64, 3_f64, -2_f64, 1_f64, 4_f64, -3_f64
+    /// );
+    /// let expected = mv * mv * mv;
+    /// let result = mv.powi(3).unwrap();
+    ///
+    /// assert_eq!(result, expected);
+    ///
+    /// let expected = (mv * mv * mv).inverse().unwrap();
+    /// let result = mv.powi(-3).unwrap();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    /// ```
+    pub fn powi(&self, exponent: i32) -> Option<Self> {
+        if exponent < 0 {
+            self.inverse().map(|inverse| inverse.powu(exponent.unsigned_abs()))
+        } else {
+            Some(self.powu(exponent.unsigned_abs()))
+        }
+    }
+}
+
+impl<S> EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    /// Raise a multivector to an unsigned integer power by repeated squaring.
+    fn powu(&self, exponent: u32) -> Self {
+        let mut base = *self;
+        let mut remaining = exponent;
+        let mut result = Self::unit_scalar();
+        while remaining > 0 {
+            if remaining & 1 == 1 {
+                result *= base;
+            }
+
+            remaining >>= 1;
+            if remaining > 0 {
+                base = base * base;
+            }
+        }
+
+        result
+    }
+}
+
+impl<S> EuclideanMultivector3<S>
+where
+    S: ScalarFloat,
+{
+    /// Compute the sandwich product `v * self * rev(v)` with the even
+    /// multivector `v == s + b12 * e12 + b23 * e23 + b31 * e31`, without
+    /// normalizing by the magnitude of `v`.
+    ///
+    /// Conjugation by an even multivector preserves grades. The vector part and
+    /// the bivector part transform by the same rotation matrix, and the scalar
+    /// and pseudoscalar parts are only scaled, so the terms that are known to
+    /// vanish are never computed.
+    #[rustfmt::skip]
+    fn sandwich_even(&self, s: S, b12: S, b23: S, b31: S) -> Self {
+        let two = S::one() + S::one();
+        let norm_squared = s * s + b12 * b12 + b23 * b23 + b31 * b31;
+        let m11 = s * s - b12 * b12 + b23 * b23 - b31 * b31;
+        let m12 = two * (b12 * s + b23 * b31);
+        let m13 = two * (b12 * b23 - b31 * s);
+        let m21 = two * (b23 * b31 - b12 * s);
+        let m22 = s * s - b12 * b12 - b23 * b23 + b31 * b31;
+        let m23 = two * (b12 * b31 + b23 * s);
+        let m31 = two * (b12 * b23 + b31 * s);
+        let m32 = two * (b12 * b31 - b23 * s);
+        let m33 = s * s + b12 * b12 - b23 * b23 - b31 * b31;
+        let a = self;
+        let result_1    = norm_squared * a[0];
+        let result_e1   = m11 * a[1] + m12 * a[2] + m13 * a[3];
+        let result_e2   = m21 * a[1] + m22 * a[2] + m23 * a[3];
+        let result_e3   = m31 * a[1] + m32 * a[2] + m33 * a[3];
+        let result_e12  = m31 * a[5] + m32 * a[6] + m33 * a[4];
+        let result_e23  = m11 * a[5] + m12 * a[6] + m13 * a[4];
+        let result_e31  = m21 * a[5] + m22 * a[6] + m23 * a[4];
+        let result_e123 = norm_squared * a[7];
+
+        Self::new(
+            result_1, result_e1, result_e2, result_e3,
+            result_e12, result_e23, result_e31, result_e123
+        )
+    }
+
+    /// Apply a versor to a multivector.
+    ///
+    /// A versor is a geometric product of invertible vectors. Every even
+    /// versor `V` acts on a multivector `mv` by
+    /// ```text
+    /// V * mv * inv(V)
+    /// ```
+    /// and every odd versor acts by
+    /// ```text
+    /// V * involute(mv) * inv(V)
+    /// ```
+    /// so that an odd versor acts as a reflection on every grade. In three
+    /// dimensions every multivector that is either purely even or purely odd
+    /// is a versor up to scale. The function returns `None` when the versor has
+    /// both even and odd parts, or when it is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// // The vector `e1` is a versor reflecting in the plane orthogonal to `e1`.
+    /// let versor: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e1();
+    /// let mv = EuclideanMultivector3::new(
+    ///     1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64
+    /// );
+    /// let expected = EuclideanMultivector3::new(
+    ///     1_f64, -2_f64, 3_f64, 4_f64, -5_f64, 6_f64, -7_f64, -8_f64
+    /// );
+    /// let result = mv.apply_versor(&versor).unwrap();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    ///
+    /// let mixed = EuclideanMultivector3::new(
+    ///     1_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64
+    /// );
+    ///
+    /// assert!(mv.apply_versor(&mixed).is_none());
+    /// ```
+    pub fn apply_versor(&self, versor: &Self) -> Option<Self> {
+        let v = versor;
+        let is_even = v[1].is_zero() && v[2].is_zero() && v[3].is_zero() && v[7].is_zero();
+        let is_odd = v[0].is_zero() && v[4].is_zero() && v[5].is_zero() && v[6].is_zero();
+        // An odd versor `V` factors as `V == W * e123` with `W == -V * e123`
+        // even. The pseudoscalar is central, so `V` and `W` have the same
+        // action on the grade involution of a multivector.
+        let (mv, s, b12, b23, b31) = if is_even {
+            (*self, v[0], v[4], v[5], v[6])
+        } else if is_odd {
+            (self.involute(), v[7], -v[3], -v[1], -v[2])
+        } else {
+            return None;
+        };
+        let norm_squared = s * s + b12 * b12 + b23 * b23 + b31 * b31;
+        if norm_squared.is_zero() {
+            return None;
+        }
+
+        Some(mv.sandwich_even(s, b12, b23, b31) / norm_squared)
+    }
+
+    /// Reflect a multivector in the line spanned by a vector.
+    ///
+    /// The reflection of a multivector `mv` in the line spanned by the
+    /// vector `a` is given by
+    /// ```text
+    /// a * mv * inv(a)
+    /// ```
+    /// Only the grade one part of `vector` is used. The function returns
+    /// `None` when the vector is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let vector: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e1() * 2_f64;
+    /// let mv = EuclideanMultivector3::new(
+    ///     0_f64, 1_f64, 1_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64
+    /// );
+    /// let expected = EuclideanMultivector3::new(
+    ///     0_f64, 1_f64, -1_f64, -1_f64, 0_f64, 0_f64, 0_f64, 0_f64
+    /// );
+    /// let result = mv.reflect_in_vector(&vector).unwrap();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn reflect_in_vector(&self, vector: &Self) -> Option<Self> {
+        // Since `a * e123` is even and the pseudoscalar is central,
+        // `a * mv * inv(a) == (a * e123) * mv * inv(a * e123)`.
+        let (a1, a2, a3) = (vector[1], vector[2], vector[3]);
+        let norm_squared = a1 * a1 + a2 * a2 + a3 * a3;
+        if norm_squared.is_zero() {
+            return None;
+        }
+
+        Some(self.sandwich_even(S::zero(), a3, a1, a2) / norm_squared)
+    }
+
+    /// Reflect a multivector in the plane represented by a bivector.
+    ///
+    /// The reflection of a multivector `mv` in the plane represented by the
+    /// bivector `B` is given by
+    /// ```text
+    /// B * involute(mv) * inv(B)
+    /// ```
+    /// which is the same as the reflection by the odd versor given by the normal
+    /// vector of the plane. Only the grade two part of `plane` is used. The
+    /// function returns `None` when the bivector is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let plane: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e12();
+    /// let mv = EuclideanMultivector3::new(
+    ///     0_f64, 1_f64, 1_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64
+    /// );
+    /// let expected = EuclideanMultivector3::new(
+    ///     0_f64, 1_f64, 1_f64, -1_f64, 0_f64, 0_f64, 0_f64, 0_f64
+    /// );
+    /// let result = mv.reflect_in_plane(&plane).unwrap();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn reflect_in_plane(&self, plane: &Self) -> Option<Self> {
+        let (b12, b23, b31) = (plane[4], plane[5], plane[6]);
+        let norm_squared = b12 * b12 + b23 * b23 + b31 * b31;
+        if norm_squared.is_zero() {
+            return None;
+        }
+
+        Some(self.involute().sandwich_even(S::zero(), b12, b23, b31) / norm_squared)
+    }
+
+    /// Rotate a multivector by a rotor.
+    ///
+    /// The rotated multivector is the sandwich product `R * mv * rev(R)`.
+    /// Only the even part of `rotor` is used, and the rotor is assumed to have
+    /// unit magnitude, so that its reverse is its inverse.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// # use core::f64;
+    /// #
+    /// let half_angle = f64::consts::FRAC_PI_4;
+    /// let rotor = EuclideanMultivector3::new(
+    ///     half_angle.cos(), 0_f64, 0_f64, 0_f64, -half_angle.sin(), 0_f64, 0_f64, 0_f64
+    /// );
+    /// let e1: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e1();
+    /// let expected: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e2();
+    /// let result = e1.rotate(&rotor);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn rotate(&self, rotor: &Self) -> Self {
+        self.sandwich_even(rotor[0], rotor[4], rotor[5], rotor[6])
+    }
+
+    /// Project a multivector onto a blade.
+    ///
+    /// The projection of a multivector `mv` onto an invertible blade `B` is
+    /// given by
+    /// ```text
+    /// project(mv, B) := (mv << B) << inv(B)
+    /// ```
+    /// where `<<` denotes the left contraction. Both contractions are left
+    /// contractions: the first one removes the components of `mv` that are
+    /// orthogonal to `B`, and the second one maps the result back into the
+    /// subspace of `B`. The function returns `None` when the blade is not
+    /// invertible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let plane: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e12();
+    /// let vector = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let expected = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let result = vector.project_onto(&plane).unwrap();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn project_onto(&self, blade: &Self) -> Option<Self> {
+        let blade_inverse = blade.inverse()?;
+
+        Some((self << blade) << blade_inverse)
+    }
+
+    /// Reject a multivector from a blade.
+    ///
+    /// The rejection of a multivector `mv` from an invertible blade `B` is
+    /// the part of `mv` that remains after removing its projection onto `B`
+    /// ```text
+    /// reject(mv, B) := mv - project(mv, B)
+    /// ```
+    /// For a vector, this is the component orthogonal to the subspace of `B`,
+    /// so that a vector splits into its tangential and normal parts
+    /// ```text
+    /// v == project(v, B) + reject(v, B)
+    /// ```
+    /// The function returns `None` when the blade is not invertible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let plane: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e12();
+    /// let vector = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let expected = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let result = vector.reject_from(&plane).unwrap();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn reject_from(&self, blade: &Self) -> Option<Self> {
+        self.project_onto(blade).map(|projection| self - projection)
+    }
+
+    /// Determine whether the product of two blades vanishes relative to the
+    /// magnitudes of the blades.
+    fn is_degenerate_product(product: &Self, blade1: &Self, blade2: &Self) -> bool {
+        let tolerance = S::default_epsilon() * blade1.magnitude_squared() * blade2.magnitude_squared();
+
+        product.magnitude_squared() <= tolerance
+    }
+
+    /// Compute the join of two blades.
+    ///
+    /// The join of two blades is the blade representing the smallest subspace
+    /// containing the subspaces of both blades. For blades whose subspaces
+    /// intersect only in the origin, the join is their outer product
+    /// ```text
+    /// join(A, B) := A ^ B
+    /// ```
+    /// The function returns `None` when the blades are degenerate, i.e. when
+    /// their subspaces share a common direction, as for coincident lines, or
+    /// for a line lying in a plane. In that case the outer product vanishes,
+    /// and the join is not determined by the outer product alone.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let e1: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e1();
+    /// let e2: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e2();
+    /// let e12: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e12();
+    /// let result = e1.join(&e2).unwrap();
+    ///
+    /// assert_relative_eq!(result, e12, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert!(e1.join(&e12).is_none());
+    /// ```
+    pub fn join(&self, other: &Self) -> Option<Self> {
+        let result = self ^ other;
+        if Self::is_degenerate_product(&result, self, other) {
+            None
+        } else {
+            Some(result)
+        }
+    }
+
+    /// Compute the meet of two blades.
+    ///
+    /// The meet of two blades is the blade representing the intersection of
+    /// their subspaces. For blades whose subspaces together span the whole
+    /// space, the meet is their regressive product
+    /// ```text
+    /// meet(A, B) := A & B
+    /// ```
+    /// For example, two distinct planes through the origin meet in a line,
+    /// and a line not lying in a plane meets the plane in the origin, which is
+    /// represented by a scalar. The function returns `None` when the blades are
+    /// degenerate, i.e. when their subspaces do not span the whole space, as
+    /// for parallel planes, or for a line lying in a plane. In that case the
+    /// regressive product vanishes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let e12: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e12();
+    /// let e31: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e31();
+    /// let e1: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e1();
+    /// let result = e12.meet(&e31).unwrap();
+    ///
+    /// assert_relative_eq!(result, e1, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert!(e12.meet(&(e12 * 2_f64)).is_none());
+    /// ```
+    pub fn meet(&self, other: &Self) -> Option<Self> {
+        let result = self & other;
+        if Self::is_degenerate_product(&result, self, other) {
+            None
+        } else {
+            Some(result)
+        }
+    }
+}
+
+impl<S> ops::Div<S> for EuclideanMultivector3<S>
+where
+    S: ScalarFloat,
+{
+    type Output = EuclideanMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn div(self, other: S) -> Self::Output {
+        let one_over_other = S::one() / other;
+        let result_1    = self.data[0] * one_over_other;
+        let result_e1   = self.data[1] * one_over_other;
+        let result_e2   = self.data[2] * one_over_other;
+        let result_e3   = self.data[3] * one_over_other;
+        let result_e12  = self.data[4] * one_over_other;
         let result_e23  = self.data[5] * one_over_other;
         let result_e31  = self.data[6] * one_over_other;
         let result_e123 = self.data[7] * one_over_other;
@@ -2522,29 +3651,166 @@ where
     }
 }
 
-impl<S> ops::Div<EuclideanMultivector3<S>> for &EuclideanMultivector3<S>
+impl<S> ops::Div<EuclideanMultivector3<S>> for &EuclideanMultivector3<S>
+where
+    S: ScalarFloat,
+{
+    type Output = EuclideanMultivector3<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn div(self, other: EuclideanMultivector3<S>) -> Self::Output {
+        self * other.inverse_unchecked()
+    }
+}
+
+impl<'a, 'b, S> ops::Div<&'b EuclideanMultivector3<S>> for &'a EuclideanMultivector3<S>
+where
+    S: ScalarFloat,
+{
+    type Output = EuclideanMultivector3<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn div(self, other: &'b EuclideanMultivector3<S>) -> Self::Output {
+        self * other.inverse_unchecked()
+    }
+}
+
+impl<S> num_traits::Zero for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn zero() -> Self {
+        Self::zero()
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        Self::is_zero(self)
+    }
+}
+
+impl<S> num_traits::One for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn one() -> Self {
+        Self::unit_scalar()
+    }
+}
+
+impl<S> num_traits::Inv for EuclideanMultivector3<S>
+where
+    S: ScalarFloat,
+{
+    type Output = EuclideanMultivector3<S>;
+
+    /// Compute the multiplicative inverse of a multivector.
+    ///
+    /// Like division, this does not check for invertibility, so the result
+    /// has NaN components when `self` is not invertible. Use
+    /// [`EuclideanMultivector3::inverse`] for the checked inverse.
+    #[inline]
+    fn inv(self) -> Self::Output {
+        self.inverse_unchecked()
+    }
+}
+
+impl<S> num_traits::Inv for &EuclideanMultivector3<S>
+where
+    S: ScalarFloat,
+{
+    type Output = EuclideanMultivector3<S>;
+
+    /// Compute the multiplicative inverse of a multivector.
+    ///
+    /// Like division, this does not check for invertibility, so the result
+    /// has NaN components when `self` is not invertible. Use
+    /// [`EuclideanMultivector3::inverse`] for the checked inverse.
+    #[inline]
+    fn inv(self) -> Self::Output {
+        self.inverse_unchecked()
+    }
+}
+
+impl<S> num_traits::Pow<i32> for EuclideanMultivector3<S>
+where
+    S: ScalarFloat,
+{
+    type Output = EuclideanMultivector3<S>;
+
+    /// Raise a multivector to an integer power.
+    ///
+    /// Negative powers are powers of the unchecked inverse, so the result has
+    /// NaN components when the exponent is negative and `self` is not
+    /// invertible. Use [`EuclideanMultivector3::powi`] for the checked power.
+    #[inline]
+    fn pow(self, exponent: i32) -> Self::Output {
+        if exponent < 0 {
+            self.inverse_unchecked().powu(exponent.unsigned_abs())
+        } else {
+            self.powu(exponent.unsigned_abs())
+        }
+    }
+}
+
+impl<S> num_traits::Pow<i32> for &EuclideanMultivector3<S>
+where
+    S: ScalarFloat,
+{
+    type Output = EuclideanMultivector3<S>;
+
+    /// Raise a multivector to an integer power.
+    ///
+    /// Negative powers are powers of the unchecked inverse, so the result has
+    /// NaN components when the exponent is negative and `self` is not
+    /// invertible. Use [`EuclideanMultivector3::powi`] for the checked power.
+    #[inline]
+    fn pow(self, exponent: i32) -> Self::Output {
+        if exponent < 0 {
+            self.inverse_unchecked().powu(exponent.unsigned_abs())
+        } else {
+            self.powu(exponent.unsigned_abs())
+        }
+    }
+}
+
+impl<S> num_traits::Pow<u32> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector3<S>;
+
+    #[inline]
+    fn pow(self, exponent: u32) -> Self::Output {
+        self.powu(exponent)
+    }
+}
+
+impl<S> num_traits::Pow<u32> for &EuclideanMultivector3<S>
 where
-    S: ScalarFloat,
+    S: Scalar,
 {
     type Output = EuclideanMultivector3<S>;
 
-    #[allow(clippy::suspicious_arithmetic_impl)]
     #[inline]
-    fn div(self, other: EuclideanMultivector3<S>) -> Self::Output {
-        self * other.inverse_unchecked()
+    fn pow(self, exponent: u32) -> Self::Output {
+        self.powu(exponent)
     }
 }
 
-impl<'a, 'b, S> ops::Div<&'b EuclideanMultivector3<S>> for &'a EuclideanMultivector3<S>
+impl<S> num_traits::MulAdd for EuclideanMultivector3<S>
 where
-    S: ScalarFloat,
+    S: Scalar,
 {
     type Output = EuclideanMultivector3<S>;
 
-    #[allow(clippy::suspicious_arithmetic_impl)]
     #[inline]
-    fn div(self, other: &'b EuclideanMultivector3<S>) -> Self::Output {
-        self * other.inverse_unchecked()
+    fn mul_add(self, a: EuclideanMultivector3<S>, b: EuclideanMultivector3<S>) -> Self::Output {
+        self * a + b
     }
 }
 
@@ -2907,6 +4173,441 @@ where
     }
 }
 
+impl<S> ops::AddAssign<EuclideanMultivector3<S>> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    #[rustfmt::skip]
+    #[inline]
+    fn add_assign(&mut self, other: EuclideanMultivector3<S>) {
+        self.data[0] += other.data[0];
+        self.data[1] += other.data[1];
+        self.data[2] += other.data[2];
+        self.data[3] += other.data[3];
+        self.data[4] += other.data[4];
+        self.data[5] += other.data[5];
+        self.data[6] += other.data[6];
+        self.data[7] += other.data[7];
+    }
+}
+
+impl<S> ops::AddAssign<&EuclideanMultivector3<S>> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    #[rustfmt::skip]
+    #[inline]
+    fn add_assign(&mut self, other: &EuclideanMultivector3<S>) {
+        self.data[0] += other.data[0];
+        self.data[1] += other.data[1];
+        self.data[2] += other.data[2];
+        self.data[3] += other.data[3];
+        self.data[4] += other.data[4];
+        self.data[5] += other.data[5];
+        self.data[6] += other.data[6];
+        self.data[7] += other.data[7];
+    }
+}
+
+impl<S> ops::AddAssign<S> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn add_assign(&mut self, other: S) {
+        self.data[0] += other;
+    }
+}
+
+impl<S> ops::SubAssign<EuclideanMultivector3<S>> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    #[rustfmt::skip]
+    #[inline]
+    fn sub_assign(&mut self, other: EuclideanMultivector3<S>) {
+        self.data[0] -= other.data[0];
+        self.data[1] -= other.data[1];
+        self.data[2] -= other.data[2];
+        self.data[3] -= other.data[3];
+        self.data[4] -= other.data[4];
+        self.data[5] -= other.data[5];
+        self.data[6] -= other.data[6];
+        self.data[7] -= other.data[7];
+    }
+}
+
+impl<S> ops::SubAssign<&EuclideanMultivector3<S>> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    #[rustfmt::skip]
+    #[inline]
+    fn sub_assign(&mut self, other: &EuclideanMultivector3<S>) {
+        self.data[0] -= other.data[0];
+        self.data[1] -= other.data[1];
+        self.data[2] -= other.data[2];
+        self.data[3] -= other.data[3];
+        self.data[4] -= other.data[4];
+        self.data[5] -= other.data[5];
+        self.data[6] -= other.data[6];
+        self.data[7] -= other.data[7];
+    }
+}
+
+impl<S> ops::SubAssign<S> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn sub_assign(&mut self, other: S) {
+        self.data[0] -= other;
+    }
+}
+
+impl<S> ops::MulAssign<EuclideanMultivector3<S>> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn mul_assign(&mut self, other: EuclideanMultivector3<S>) {
+        *self = *self * other;
+    }
+}
+
+impl<S> ops::MulAssign<&EuclideanMultivector3<S>> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn mul_assign(&mut self, other: &EuclideanMultivector3<S>) {
+        *self = *self * other;
+    }
+}
+
+impl<S> ops::MulAssign<S> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    #[rustfmt::skip]
+    #[inline]
+    fn mul_assign(&mut self, other: S) {
+        self.data[0] *= other;
+        self.data[1] *= other;
+        self.data[2] *= other;
+        self.data[3] *= other;
+        self.data[4] *= other;
+        self.data[5] *= other;
+        self.data[6] *= other;
+        self.data[7] *= other;
+    }
+}
+
+impl<S> ops::DivAssign<EuclideanMultivector3<S>> for EuclideanMultivector3<S>
+where
+    S: ScalarFloat,
+{
+    #[inline]
+    fn div_assign(&mut self, other: EuclideanMultivector3<S>) {
+        *self = *self / other;
+    }
+}
+
+impl<S> ops::DivAssign<&EuclideanMultivector3<S>> for EuclideanMultivector3<S>
+where
+    S: ScalarFloat,
+{
+    #[inline]
+    fn div_assign(&mut self, other: &EuclideanMultivector3<S>) {
+        *self = *self / other;
+    }
+}
+
+impl<S> ops::DivAssign<S> for EuclideanMultivector3<S>
+where
+    S: ScalarFloat,
+{
+    #[rustfmt::skip]
+    #[inline]
+    fn div_assign(&mut self, other: S) {
+        let one_over_other = S::one() / other;
+        self.data[0] *= one_over_other;
+        self.data[1] *= one_over_other;
+        self.data[2] *= one_over_other;
+        self.data[3] *= one_over_other;
+        self.data[4] *= one_over_other;
+        self.data[5] *= one_over_other;
+        self.data[6] *= one_over_other;
+        self.data[7] *= one_over_other;
+    }
+}
+
+impl<S> ops::BitXorAssign<EuclideanMultivector3<S>> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn bitxor_assign(&mut self, other: EuclideanMultivector3<S>) {
+        *self = *self ^ other;
+    }
+}
+
+impl<S> ops::BitXorAssign<&EuclideanMultivector3<S>> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn bitxor_assign(&mut self, other: &EuclideanMultivector3<S>) {
+        *self = *self ^ other;
+    }
+}
+
+impl<S> ops::BitXorAssign<S> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor_assign(&mut self, other: S) {
+        self.data[0] *= other;
+        self.data[1] *= other;
+        self.data[2] *= other;
+        self.data[3] *= other;
+        self.data[4] *= other;
+        self.data[5] *= other;
+        self.data[6] *= other;
+        self.data[7] *= other;
+    }
+}
+
+impl<S> ops::BitOrAssign<EuclideanMultivector3<S>> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn bitor_assign(&mut self, other: EuclideanMultivector3<S>) {
+        *self = *self | other;
+    }
+}
+
+impl<S> ops::BitOrAssign<&EuclideanMultivector3<S>> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn bitor_assign(&mut self, other: &EuclideanMultivector3<S>) {
+        *self = *self | other;
+    }
+}
+
+impl<S> ops::BitOrAssign<S> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn bitor_assign(&mut self, other: S) {
+        *self = *self | other;
+    }
+}
+
+impl<S> ops::BitAndAssign<EuclideanMultivector3<S>> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn bitand_assign(&mut self, other: EuclideanMultivector3<S>) {
+        *self = *self & other;
+    }
+}
+
+impl<S> ops::BitAndAssign<&EuclideanMultivector3<S>> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn bitand_assign(&mut self, other: &EuclideanMultivector3<S>) {
+        *self = *self & other;
+    }
+}
+
+impl<S> ops::ShlAssign<EuclideanMultivector3<S>> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn shl_assign(&mut self, other: EuclideanMultivector3<S>) {
+        *self = *self << other;
+    }
+}
+
+impl<S> ops::ShlAssign<&EuclideanMultivector3<S>> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn shl_assign(&mut self, other: &EuclideanMultivector3<S>) {
+        *self = *self << other;
+    }
+}
+
+impl<S> ops::ShlAssign<S> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn shl_assign(&mut self, other: S) {
+        *self = *self << other;
+    }
+}
+
+impl<S> ops::ShrAssign<EuclideanMultivector3<S>> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn shr_assign(&mut self, other: EuclideanMultivector3<S>) {
+        *self = *self >> other;
+    }
+}
+
+impl<S> ops::ShrAssign<&EuclideanMultivector3<S>> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn shr_assign(&mut self, other: &EuclideanMultivector3<S>) {
+        *self = *self >> other;
+    }
+}
+
+impl<S> ops::ShrAssign<S> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    #[rustfmt::skip]
+    #[inline]
+    fn shr_assign(&mut self, other: S) {
+        self.data[0] *= other;
+        self.data[1] *= other;
+        self.data[2] *= other;
+        self.data[3] *= other;
+        self.data[4] *= other;
+        self.data[5] *= other;
+        self.data[6] *= other;
+        self.data[7] *= other;
+    }
+}
+
+impl<S> EuclideanMultivector3<S>
+where
+    S: ScalarFloat,
+{
+    /// Determine which grades of a multivector are nonzero.
+    ///
+    /// A grade belongs to the grade mask when at least one of its components
+    /// differs from zero by more than the default tolerance of the scalar type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// # use cggeomalg::grade::GradeMask;
+    /// #
+    /// let mv: EuclideanMultivector3<f64> = EuclideanMultivector3::from_scalar(2_f64) + EuclideanMultivector3::unit_e12() * 3_f64;
+    ///
+    /// assert_eq!(mv.grade_mask(), GradeMask::SCALAR | GradeMask::BIVECTOR);
+    /// assert_eq!(EuclideanMultivector3::<f64>::zero().grade_mask(), GradeMask::empty());
+    /// ```
+    pub fn grade_mask(&self) -> GradeMask {
+        let mut result = GradeMask::empty();
+        for (blade, component) in self.iter_blades() {
+            let is_nonzero = ulps_ne!(
+                component,
+                S::zero(),
+                abs_diff_all <= S::default_epsilon(),
+                ulps_all <= S::default_max_ulps()
+            );
+            if is_nonzero {
+                result |= GradeMask::from_bits(1 << blade.grade());
+            }
+        }
+
+        result
+    }
+
+    /// Determine whether a multivector is homogeneous, i.e. whether at most one
+    /// of its grades is nonzero.
+    ///
+    /// The zero multivector is homogeneous, since it belongs to every grade.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let e1: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e1();
+    /// let e12: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e12();
+    ///
+    /// assert!(e1.is_homogeneous());
+    /// assert!((e1 * 2_f64).is_homogeneous());
+    /// assert!(!(e1 + e12).is_homogeneous());
+    /// ```
+    #[inline]
+    pub fn is_homogeneous(&self) -> bool {
+        self.grade_mask().len() <= 1
+    }
+
+    /// Determine whether a multivector is a blade, i.e. the outer product of
+    /// vectors.
+    ///
+    /// In three dimensions, every homogeneous multivector is a blade, so this
+    /// is equivalent to `is_homogeneous`.
+    #[inline]
+    pub fn is_blade(&self) -> bool {
+        self.is_homogeneous()
+    }
+
+    /// Determine whether a multivector is a versor, i.e. the geometric product
+    /// of invertible vectors.
+    ///
+    /// A multivector `mv` is a versor if and only if it is nonzero and has a single
+    /// parity, `mv * rev(mv)` is a scalar, and the sandwich product
+    /// `mv * v * rev(mv)` maps every vector `v` to a vector. These are the
+    /// multivectors that the sandwich product methods such as `apply_versor` expect.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let e1: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e1();
+    /// let e2: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e2();
+    /// let one: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_scalar();
+    ///
+    /// assert!(e1.is_versor());
+    /// assert!((e1 * (e1 + e2)).is_versor());
+    /// assert!(!(one + e1).is_versor());
+    /// assert!(!EuclideanMultivector3::<f64>::zero().is_versor());
+    /// ```
+    pub fn is_versor(&self) -> bool {
+        let mask = self.grade_mask();
+        if mask.is_empty() || (mask.intersects(GradeMask::EVEN) && mask.intersects(GradeMask::ODD)) {
+            return false;
+        }
+
+        let versor = self / self.magnitude();
+        let versor_reverse = versor.reverse();
+        if (versor * versor_reverse).grade_mask() != GradeMask::SCALAR {
+            return false;
+        }
+
+        let unit_vectors: [Self; 3] = [Self::unit_e1(), Self::unit_e2(), Self::unit_e3()];
+
+        unit_vectors
+            .iter()
+            .all(|vector| GradeMask::VECTOR.contains((versor * vector * versor_reverse).grade_mask()))
+    }
+}
 
 impl_coords!(ViewG3, { scalar, e1, e2, e3, e12, e23, e31, e123 });
 impl_coords_deref!(EuclideanMultivector3, ViewG3);
@@ -0,0 +1,136 @@
+use core::fmt;
+
+
+/// The basis blades of the three-dimensional Euclidean geometric algebra.
+///
+/// The variants appear in the component order of `EuclideanMultivector3`,
+/// i.e. in the basis `{1, e1, e2, e3, e12, e23, e31, e123}`, so that a basis blade indexes the
+/// matching component of a multivector.
+///
+/// # Example
+///
+/// ```
+/// # use cggeomalg::e3ga::{
+/// #     BasisBlade,
+/// #     EuclideanMultivector3,
+/// # };
+/// #
+/// let mut mv: EuclideanMultivector3<i32> = EuclideanMultivector3::zero();
+/// for blade in BasisBlade::ALL {
+///     mv[blade] = blade.grade() as i32;
+/// }
+///
+/// assert_eq!(mv[BasisBlade::E123], 3);
+/// assert_eq!(mv.grade(3), EuclideanMultivector3::unit_e123() * 3);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub enum BasisBlade {
+    Scalar,
+    E1,
+    E2,
+    E3,
+    E12,
+    E23,
+    E31,
+    E123,
+}
+
+impl BasisBlade {
+    /// The basis blades in the component order of `EuclideanMultivector3`.
+    pub const ALL: [BasisBlade; 8] = [
+        BasisBlade::Scalar,
+        BasisBlade::E1,
+        BasisBlade::E2,
+        BasisBlade::E3,
+        BasisBlade::E12,
+        BasisBlade::E23,
+        BasisBlade::E31,
+        BasisBlade::E123,
+    ];
+
+    /// The grade of the basis blade, i.e. the number of basis vectors
+    /// in the blade.
+    #[inline]
+    pub const fn grade(self) -> usize {
+        match self {
+            BasisBlade::Scalar => 0,
+            BasisBlade::E1 => 1,
+            BasisBlade::E2 => 1,
+            BasisBlade::E3 => 1,
+            BasisBlade::E12 => 2,
+            BasisBlade::E23 => 2,
+            BasisBlade::E31 => 2,
+            BasisBlade::E123 => 3,
+        }
+    }
+
+    /// The index of the component of the basis blade in `EuclideanMultivector3`.
+    #[inline]
+    pub const fn index(self) -> usize {
+        match self {
+            BasisBlade::Scalar => 0,
+            BasisBlade::E1 => 1,
+            BasisBlade::E2 => 2,
+            BasisBlade::E3 => 3,
+            BasisBlade::E12 => 4,
+            BasisBlade::E23 => 5,
+            BasisBlade::E31 => 6,
+            BasisBlade::E123 => 7,
+        }
+    }
+
+    /// The name of the basis blade, which is also the name of its
+    /// component in the coordinate view of `EuclideanMultivector3`.
+    #[inline]
+    pub const fn name(self) -> &'static str {
+        match self {
+            BasisBlade::Scalar => "scalar",
+            BasisBlade::E1 => "e1",
+            BasisBlade::E2 => "e2",
+            BasisBlade::E3 => "e3",
+            BasisBlade::E12 => "e12",
+            BasisBlade::E23 => "e23",
+            BasisBlade::E31 => "e31",
+            BasisBlade::E123 => "e123",
+        }
+    }
+
+    /// The sign of the square of the basis blade under the geometric product.
+    ///
+    /// The square of every basis blade is either `1` or `-1`.
+    #[inline]
+    pub const fn square(self) -> i8 {
+        match self {
+            BasisBlade::Scalar => 1,
+            BasisBlade::E1 => 1,
+            BasisBlade::E2 => 1,
+            BasisBlade::E3 => 1,
+            BasisBlade::E12 => -1,
+            BasisBlade::E23 => -1,
+            BasisBlade::E31 => -1,
+            BasisBlade::E123 => -1,
+        }
+    }
+
+    /// The sign that the reverse of the basis blade picks up, i.e.
+    /// `rev(blade) == reverse_sign * blade`.
+    #[inline]
+    pub const fn reverse_sign(self) -> i8 {
+        match self {
+            BasisBlade::Scalar => 1,
+            BasisBlade::E1 => 1,
+            BasisBlade::E2 => 1,
+            BasisBlade::E3 => 1,
+            BasisBlade::E12 => -1,
+            BasisBlade::E23 => -1,
+            BasisBlade::E31 => -1,
+            BasisBlade::E123 => -1,
+        }
+    }
+}
+
+impl fmt::Display for BasisBlade {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(self.name())
+    }
+}
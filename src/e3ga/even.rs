@@ -0,0 +1,476 @@
+use crate::e3ga::{
+    EuclideanMultivector3,
+    Rotor3,
+};
+use crate::scalar::{
+    Scalar,
+    ScalarFloat,
+    ScalarSigned,
+};
+use crate::{
+    impl_approx_cmp_ops,
+    impl_coords,
+    impl_coords_deref,
+};
+use core::fmt;
+use core::ops;
+
+
+/// A stack-allocated element of the even subalgebra of the three-dimensional
+/// Euclidean geometric algebra.
+///
+/// An even multivector is stored in the basis `{1, e12, e23, e31}`, so only
+/// the scalar and bivector parts are represented. The odd-grade parts of an
+/// even multivector are always zero. The geometric product of two vectors is
+/// an even multivector.
+///
+/// The even subalgebra is closed under the geometric product, i.e. the product
+/// of two even multivectors is again an even multivector, and it is isomorphic
+/// to the quaternions. Composing a long chain of rotations as even multivectors never
+/// produces odd-grade parts, so no rounding noise accumulates in them as it does
+/// with the general [`EuclideanMultivector3`] product.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Even3<S> {
+    data: [S; 4],
+}
+
+impl<S> Even3<S> {
+    /// Construct a new even multivector from its components.
+    #[inline]
+    pub const fn new(scalar: S, e12: S, e23: S, e31: S) -> Self {
+        Self {
+            data: [scalar, e12, e23, e31],
+        }
+    }
+
+    /// Returns the number of components in an even multivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e3ga::Even3;
+    /// #
+    /// let even = Even3::new(1_f64, 2_f64, 3_f64, 4_f64);
+    ///
+    /// assert_eq!(even.len(), 4);
+    /// ```
+    #[inline]
+    pub const fn len(&self) -> usize {
+        4
+    }
+
+    /// Get a pointer to the underlying component array.
+    #[inline]
+    pub const fn as_ptr(&self) -> *const S {
+        &self.data[0]
+    }
+
+    /// Get a mutable pointer to the underlying component array.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut S {
+        &mut self.data[0]
+    }
+
+    /// Get a slice of the underlying elements of the data type.
+    #[inline]
+    pub fn as_slice(&self) -> &[S] {
+        <Self as AsRef<[S; 4]>>::as_ref(self)
+    }
+}
+
+impl<S> Even3<S>
+where
+    S: Scalar,
+{
+    /// Construct the additive unit (zero) even multivector.
+    #[inline]
+    pub fn zero() -> Self {
+        Self { data: [S::zero(); 4] }
+    }
+
+    /// Construct the multiplicative unit even multivector.
+    #[inline]
+    pub fn unit_scalar() -> Self {
+        Self::new(S::one(), S::zero(), S::zero(), S::zero())
+    }
+
+    /// Construct an even multivector from the even part of a multivector.
+    ///
+    /// The vector and trivector parts of `mv` are discarded, so the result is
+    /// the projection of `mv` onto the even grades.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e3ga::{
+    /// #     Even3,
+    /// #     EuclideanMultivector3,
+    /// # };
+    /// #
+    /// let mv = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+    /// let even = Even3::from_even_part(&mv);
+    ///
+    /// assert_eq!(even, Even3::new(1_i32, 5_i32, 6_i32, 7_i32));
+    /// assert_eq!(EuclideanMultivector3::from(even), mv.even_part());
+    /// ```
+    #[inline]
+    pub fn from_even_part(mv: &EuclideanMultivector3<S>) -> Self {
+        Self::new(mv[0], mv[4], mv[5], mv[6])
+    }
+
+    /// Determine whether an even multivector is the zero even multivector.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.data[0].is_zero() && self.data[1].is_zero() && self.data[2].is_zero() && self.data[3].is_zero()
+    }
+
+    /// Calculate the squared magnitude of an even multivector.
+    ///
+    /// The squared magnitude is the scalar part of `x * rev(x)`, which is the
+    /// sum of the squares of the components.
+    #[inline]
+    pub fn magnitude_squared(&self) -> S {
+        self.data[0] * self.data[0] + self.data[1] * self.data[1] + self.data[2] * self.data[2] + self.data[3] * self.data[3]
+    }
+}
+
+impl<S> Even3<S>
+where
+    S: ScalarSigned,
+{
+    /// Compute the reverse of an even multivector.
+    ///
+    /// Reversion leaves the scalar part unchanged and flips the sign of the
+    /// bivector part. It corresponds to quaternion conjugation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e3ga::{
+    /// #     EuclideanMultivector3,
+    /// #     Even3,
+    /// # };
+    /// #
+    /// let even = Even3::new(1_i32, 2_i32, 3_i32, 4_i32);
+    /// let expected = EuclideanMultivector3::from(even).reverse();
+    /// let result = EuclideanMultivector3::from(even.reverse());
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn reverse(&self) -> Self {
+        Self::new(self.data[0], -self.data[1], -self.data[2], -self.data[3])
+    }
+
+    /// Compute the reverse of an even multivector mutably in place.
+    #[inline]
+    pub fn reverse_mut(&mut self) {
+        self.data[1] = -self.data[1];
+        self.data[2] = -self.data[2];
+        self.data[3] = -self.data[3];
+    }
+}
+
+impl<S> Even3<S>
+where
+    S: ScalarFloat,
+{
+    /// Calculate the magnitude of an even multivector.
+    #[inline]
+    pub fn magnitude(&self) -> S {
+        self.magnitude_squared().sqrt()
+    }
+
+    /// Normalize an even multivector to unit magnitude.
+    ///
+    /// A unit even multivector is a rotor. Normalizing after a long chain of
+    /// products projects the result back onto the set of rotors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::Even3;
+    /// #
+    /// let even = Even3::new(1_f64, 2_f64, 3_f64, 4_f64);
+    /// let result = even.normalize();
+    ///
+    /// assert_relative_eq!(result.magnitude(), 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn normalize(&self) -> Self {
+        self * (S::one() / self.magnitude())
+    }
+
+    /// Determine whether an even multivector is invertible.
+    #[inline]
+    pub fn is_invertible(&self) -> bool {
+        !self.magnitude_squared().is_zero()
+    }
+
+    /// Compute the multiplicative inverse of an even multivector.
+    ///
+    /// The inverse of a nonzero even multivector `x` is given by
+    /// ```text
+    /// inv(x) == rev(x) / (x * rev(x))
+    /// ```
+    /// where `x * rev(x)` is a positive scalar. The inverse of the zero even
+    /// multivector does not exist, in which case this function returns `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::Even3;
+    /// #
+    /// let even = Even3::new(3_f64, -1_f64, 2_f64, 5_f64);
+    /// let even_inv = even.inverse().unwrap();
+    /// let one: Even3<f64> = Even3::unit_scalar();
+    ///
+    /// assert_relative_eq!(even * even_inv, one, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(even_inv * even, one, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn inverse(&self) -> Option<Self> {
+        let magnitude_squared = self.magnitude_squared();
+        if magnitude_squared.is_zero() {
+            None
+        } else {
+            Some(self.reverse() * (S::one() / magnitude_squared))
+        }
+    }
+}
+
+impl<S> ops::Index<usize> for Even3<S>
+where
+    S: Scalar,
+{
+    type Output = S;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.data[index]
+    }
+}
+
+impl<S> ops::IndexMut<usize> for Even3<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.data[index]
+    }
+}
+
+impl<S> AsRef<[S; 4]> for Even3<S> {
+    #[inline]
+    fn as_ref(&self) -> &[S; 4] {
+        unsafe { &*(self as *const Even3<S> as *const [S; 4]) }
+    }
+}
+
+impl<S> AsMut<[S; 4]> for Even3<S> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [S; 4] {
+        unsafe { &mut *(self as *mut Even3<S> as *mut [S; 4]) }
+    }
+}
+
+impl<S> fmt::Display for Even3<S>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "{} + {}^e12 + {}^e23 + {}^e31",
+            self.data[0], self.data[1], self.data[2], self.data[3]
+        )
+    }
+}
+
+impl<S> From<Even3<S>> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn from(even: Even3<S>) -> EuclideanMultivector3<S> {
+        let zero = S::zero();
+
+        EuclideanMultivector3::new(even.data[0], zero, zero, zero, even.data[1], even.data[2], even.data[3], zero)
+    }
+}
+
+impl<S> From<&Even3<S>> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn from(even: &Even3<S>) -> EuclideanMultivector3<S> {
+        let zero = S::zero();
+
+        EuclideanMultivector3::new(even.data[0], zero, zero, zero, even.data[1], even.data[2], even.data[3], zero)
+    }
+}
+
+impl<S> From<Rotor3<S>> for Even3<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn from(rotor: Rotor3<S>) -> Even3<S> {
+        Even3::new(rotor[0], rotor[1], rotor[2], rotor[3])
+    }
+}
+
+impl<S> From<&Rotor3<S>> for Even3<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn from(rotor: &Rotor3<S>) -> Even3<S> {
+        Even3::new(rotor[0], rotor[1], rotor[2], rotor[3])
+    }
+}
+
+impl<S> From<Even3<S>> for Rotor3<S>
+where
+    S: Scalar,
+{
+    /// Convert an even multivector into a rotor.
+    ///
+    /// The components are not normalized, as with [`Rotor3::new`], so the
+    /// caller is responsible for ensuring that `even` has unit magnitude.
+    #[inline]
+    fn from(even: Even3<S>) -> Rotor3<S> {
+        Rotor3::new(even.data[0], even.data[1], even.data[2], even.data[3])
+    }
+}
+
+impl<S> From<&Even3<S>> for Rotor3<S>
+where
+    S: Scalar,
+{
+    /// Convert an even multivector into a rotor.
+    ///
+    /// The components are not normalized, as with [`Rotor3::new`], so the
+    /// caller is responsible for ensuring that `even` has unit magnitude.
+    #[inline]
+    fn from(even: &Even3<S>) -> Rotor3<S> {
+        Rotor3::new(even.data[0], even.data[1], even.data[2], even.data[3])
+    }
+}
+
+macro_rules! impl_even_mul_ops {
+    ($Lhs:ty, $Rhs:ty) => {
+        impl<S> ops::Mul<$Rhs> for $Lhs
+        where
+            S: Scalar,
+        {
+            type Output = Even3<S>;
+
+            #[rustfmt::skip]
+            #[inline]
+            fn mul(self, other: $Rhs) -> Self::Output {
+                let a = self;
+                let b = other;
+                let result_1   = a[0] * b[0] - a[1] * b[1] - a[2] * b[2] - a[3] * b[3];
+                let result_e12 = a[0] * b[1] + a[1] * b[0] - a[2] * b[3] + a[3] * b[2];
+                let result_e23 = a[0] * b[2] + a[1] * b[3] + a[2] * b[0] - a[3] * b[1];
+                let result_e31 = a[0] * b[3] - a[1] * b[2] + a[2] * b[1] + a[3] * b[0];
+
+                Even3::new(result_1, result_e12, result_e23, result_e31)
+            }
+        }
+    };
+}
+
+impl_even_mul_ops!(Even3<S>, Even3<S>);
+impl_even_mul_ops!(Even3<S>, &Even3<S>);
+impl_even_mul_ops!(&Even3<S>, Even3<S>);
+impl_even_mul_ops!(&Even3<S>, &Even3<S>);
+
+macro_rules! impl_even_add_sub_ops {
+    ($OpTrait:ident, $op:ident, $Lhs:ty, $Rhs:ty) => {
+        impl<S> ops::$OpTrait<$Rhs> for $Lhs
+        where
+            S: Scalar,
+        {
+            type Output = Even3<S>;
+
+            #[inline]
+            fn $op(self, other: $Rhs) -> Self::Output {
+                Even3::new(
+                    ops::$OpTrait::$op(self.data[0], other.data[0]),
+                    ops::$OpTrait::$op(self.data[1], other.data[1]),
+                    ops::$OpTrait::$op(self.data[2], other.data[2]),
+                    ops::$OpTrait::$op(self.data[3], other.data[3]),
+                )
+            }
+        }
+    };
+}
+
+impl_even_add_sub_ops!(Add, add, Even3<S>, Even3<S>);
+impl_even_add_sub_ops!(Add, add, Even3<S>, &Even3<S>);
+impl_even_add_sub_ops!(Add, add, &Even3<S>, Even3<S>);
+impl_even_add_sub_ops!(Add, add, &Even3<S>, &Even3<S>);
+impl_even_add_sub_ops!(Sub, sub, Even3<S>, Even3<S>);
+impl_even_add_sub_ops!(Sub, sub, Even3<S>, &Even3<S>);
+impl_even_add_sub_ops!(Sub, sub, &Even3<S>, Even3<S>);
+impl_even_add_sub_ops!(Sub, sub, &Even3<S>, &Even3<S>);
+
+impl<S> ops::Mul<S> for Even3<S>
+where
+    S: Scalar,
+{
+    type Output = Even3<S>;
+
+    #[inline]
+    fn mul(self, other: S) -> Self::Output {
+        Even3::new(self.data[0] * other, self.data[1] * other, self.data[2] * other, self.data[3] * other)
+    }
+}
+
+impl<S> ops::Mul<S> for &Even3<S>
+where
+    S: Scalar,
+{
+    type Output = Even3<S>;
+
+    #[inline]
+    fn mul(self, other: S) -> Self::Output {
+        Even3::new(self.data[0] * other, self.data[1] * other, self.data[2] * other, self.data[3] * other)
+    }
+}
+
+impl<S> ops::Neg for Even3<S>
+where
+    S: ScalarSigned,
+{
+    type Output = Even3<S>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Even3::new(-self.data[0], -self.data[1], -self.data[2], -self.data[3])
+    }
+}
+
+impl<S> ops::Neg for &Even3<S>
+where
+    S: ScalarSigned,
+{
+    type Output = Even3<S>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Even3::new(-self.data[0], -self.data[1], -self.data[2], -self.data[3])
+    }
+}
+
+
+impl_coords!(ViewEven3, { scalar, e12, e23, e31 });
+impl_coords_deref!(Even3, ViewEven3);
+
+impl_approx_cmp_ops!(Even3);
@@ -0,0 +1,711 @@
+use crate::e3ga::{
+    EuclideanMultivector3,
+    Even3,
+};
+use crate::scalar::{
+    Scalar,
+    ScalarFloat,
+    ScalarSigned,
+};
+use crate::{
+    impl_approx_cmp_ops,
+    impl_coords,
+    impl_coords_deref,
+};
+use core::fmt;
+use core::ops;
+
+
+/// A stack-allocated vector in three-dimensional Euclidean space.
+///
+/// A vector is a multivector of pure grade one, stored in the basis
+/// `{e1, e2, e3}`. The grade of a vector is known at the type level, so the
+/// products of vectors with the other graded types only compute the
+/// components that can be nonzero, and return the narrowest type that
+/// holds the result. For example, the outer product of two vectors is a
+/// [`Bivector3`], and the geometric product of two vectors is an [`Even3`].
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Vector3<S> {
+    data: [S; 3],
+}
+
+/// A stack-allocated bivector in three-dimensional Euclidean space.
+///
+/// A bivector is a multivector of pure grade two, stored in the basis
+/// `{e12, e23, e31}`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Bivector3<S> {
+    data: [S; 3],
+}
+
+/// A stack-allocated trivector in three-dimensional Euclidean space.
+///
+/// A trivector is a multivector of pure grade three, i.e. a multiple of the
+/// unit pseudoscalar `e123`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Trivector3<S> {
+    data: [S; 1],
+}
+
+impl<S> Vector3<S> {
+    /// Construct a new vector from its components.
+    #[inline]
+    pub const fn new(e1: S, e2: S, e3: S) -> Self {
+        Self { data: [e1, e2, e3] }
+    }
+
+    /// Returns the number of components in a vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e3ga::Vector3;
+    /// #
+    /// let vector = Vector3::new(1_f64, 2_f64, 3_f64);
+    ///
+    /// assert_eq!(vector.len(), 3);
+    /// ```
+    #[inline]
+    pub const fn len(&self) -> usize {
+        3
+    }
+
+    /// Get a pointer to the underlying component array.
+    #[inline]
+    pub const fn as_ptr(&self) -> *const S {
+        &self.data[0]
+    }
+
+    /// Get a mutable pointer to the underlying component array.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut S {
+        &mut self.data[0]
+    }
+
+    /// Get a slice of the underlying elements of the data type.
+    #[inline]
+    pub fn as_slice(&self) -> &[S] {
+        <Self as AsRef<[S; 3]>>::as_ref(self)
+    }
+}
+
+impl<S> Bivector3<S> {
+    /// Construct a new bivector from its components.
+    #[inline]
+    pub const fn new(e12: S, e23: S, e31: S) -> Self {
+        Self { data: [e12, e23, e31] }
+    }
+
+    /// Returns the number of components in a bivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e3ga::Bivector3;
+    /// #
+    /// let bivector = Bivector3::new(1_f64, 2_f64, 3_f64);
+    ///
+    /// assert_eq!(bivector.len(), 3);
+    /// ```
+    #[inline]
+    pub const fn len(&self) -> usize {
+        3
+    }
+
+    /// Get a pointer to the underlying component array.
+    #[inline]
+    pub const fn as_ptr(&self) -> *const S {
+        &self.data[0]
+    }
+
+    /// Get a mutable pointer to the underlying component array.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut S {
+        &mut self.data[0]
+    }
+
+    /// Get a slice of the underlying elements of the data type.
+    #[inline]
+    pub fn as_slice(&self) -> &[S] {
+        <Self as AsRef<[S; 3]>>::as_ref(self)
+    }
+}
+
+impl<S> Trivector3<S> {
+    /// Construct a new trivector from its component.
+    #[inline]
+    pub const fn new(e123: S) -> Self {
+        Self { data: [e123] }
+    }
+
+    /// Returns the number of components in a trivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e3ga::Trivector3;
+    /// #
+    /// let trivector = Trivector3::new(1_f64);
+    ///
+    /// assert_eq!(trivector.len(), 1);
+    /// ```
+    #[inline]
+    pub const fn len(&self) -> usize {
+        1
+    }
+
+    /// Get a pointer to the underlying component array.
+    #[inline]
+    pub const fn as_ptr(&self) -> *const S {
+        &self.data[0]
+    }
+
+    /// Get a mutable pointer to the underlying component array.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut S {
+        &mut self.data[0]
+    }
+
+    /// Get a slice of the underlying elements of the data type.
+    #[inline]
+    pub fn as_slice(&self) -> &[S] {
+        <Self as AsRef<[S; 1]>>::as_ref(self)
+    }
+}
+
+impl<S> Vector3<S>
+where
+    S: Scalar,
+{
+    /// Construct the zero vector.
+    #[inline]
+    pub fn zero() -> Self {
+        Self { data: [S::zero(); 3] }
+    }
+
+    /// Determine whether a vector is the zero vector.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.data[0].is_zero() && self.data[1].is_zero() && self.data[2].is_zero()
+    }
+
+    /// Returns the unit vector `e1`.
+    #[inline]
+    pub fn unit_e1() -> Self {
+        Self::new(S::one(), S::zero(), S::zero())
+    }
+
+    /// Returns the unit vector `e2`.
+    #[inline]
+    pub fn unit_e2() -> Self {
+        Self::new(S::zero(), S::one(), S::zero())
+    }
+
+    /// Returns the unit vector `e3`.
+    #[inline]
+    pub fn unit_e3() -> Self {
+        Self::new(S::zero(), S::zero(), S::one())
+    }
+
+    /// Calculate the squared magnitude of a vector.
+    #[inline]
+    pub fn magnitude_squared(&self) -> S {
+        self.data[0] * self.data[0] + self.data[1] * self.data[1] + self.data[2] * self.data[2]
+    }
+}
+
+impl<S> Bivector3<S>
+where
+    S: Scalar,
+{
+    /// Construct the zero bivector.
+    #[inline]
+    pub fn zero() -> Self {
+        Self { data: [S::zero(); 3] }
+    }
+
+    /// Determine whether a bivector is the zero bivector.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.data[0].is_zero() && self.data[1].is_zero() && self.data[2].is_zero()
+    }
+
+    /// Returns the unit bivector `e12`.
+    #[inline]
+    pub fn unit_e12() -> Self {
+        Self::new(S::one(), S::zero(), S::zero())
+    }
+
+    /// Returns the unit bivector `e23`.
+    #[inline]
+    pub fn unit_e23() -> Self {
+        Self::new(S::zero(), S::one(), S::zero())
+    }
+
+    /// Returns the unit bivector `e31`.
+    #[inline]
+    pub fn unit_e31() -> Self {
+        Self::new(S::zero(), S::zero(), S::one())
+    }
+
+    /// Calculate the squared magnitude of a bivector.
+    #[inline]
+    pub fn magnitude_squared(&self) -> S {
+        self.data[0] * self.data[0] + self.data[1] * self.data[1] + self.data[2] * self.data[2]
+    }
+}
+
+impl<S> Trivector3<S>
+where
+    S: Scalar,
+{
+    /// Construct the zero trivector.
+    #[inline]
+    pub fn zero() -> Self {
+        Self { data: [S::zero()] }
+    }
+
+    /// Determine whether a trivector is the zero trivector.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.data[0].is_zero()
+    }
+
+    /// Returns the unit trivector `e123`.
+    #[inline]
+    pub fn unit_e123() -> Self {
+        Self::new(S::one())
+    }
+
+    /// Calculate the squared magnitude of a trivector.
+    #[inline]
+    pub fn magnitude_squared(&self) -> S {
+        self.data[0] * self.data[0]
+    }
+}
+
+impl<S> Vector3<S>
+where
+    S: ScalarSigned,
+{
+    /// Compute the reverse of a vector.
+    ///
+    /// Vectors are invariant under reversion.
+    #[inline]
+    pub fn reverse(&self) -> Self {
+        *self
+    }
+
+    /// Compute the dual of a vector.
+    ///
+    /// The dual of a vector is the bivector `v * inv(e123)`, which uses the
+    /// same convention as [`EuclideanMultivector3::dual`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e3ga::{
+    /// #     Bivector3,
+    /// #     EuclideanMultivector3,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let vector = Vector3::new(1_i32, 2_i32, 3_i32);
+    /// let expected = EuclideanMultivector3::from(vector).dual();
+    /// let result = EuclideanMultivector3::from(vector.dual());
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn dual(&self) -> Bivector3<S> {
+        Bivector3::new(-self.data[2], -self.data[0], -self.data[1])
+    }
+}
+
+impl<S> Bivector3<S>
+where
+    S: ScalarSigned,
+{
+    /// Compute the reverse of a bivector.
+    ///
+    /// Reversion flips the sign of a bivector.
+    #[inline]
+    pub fn reverse(&self) -> Self {
+        Self::new(-self.data[0], -self.data[1], -self.data[2])
+    }
+
+    /// Compute the dual of a bivector.
+    ///
+    /// The dual of a bivector is the vector `B * inv(e123)`, which uses the
+    /// same convention as [`EuclideanMultivector3::dual`].
+    #[inline]
+    pub fn dual(&self) -> Vector3<S> {
+        Vector3::new(self.data[1], self.data[2], self.data[0])
+    }
+}
+
+impl<S> Trivector3<S>
+where
+    S: ScalarSigned,
+{
+    /// Compute the reverse of a trivector.
+    ///
+    /// Reversion flips the sign of a trivector.
+    #[inline]
+    pub fn reverse(&self) -> Self {
+        Self::new(-self.data[0])
+    }
+
+    /// Compute the dual of a trivector.
+    ///
+    /// The dual of a trivector is the scalar `T * inv(e123)`, which uses the
+    /// same convention as [`EuclideanMultivector3::dual`].
+    #[inline]
+    pub fn dual(&self) -> S {
+        self.data[0]
+    }
+}
+
+impl<S> Vector3<S>
+where
+    S: ScalarFloat,
+{
+    /// Calculate the magnitude of a vector.
+    #[inline]
+    pub fn magnitude(&self) -> S {
+        self.magnitude_squared().sqrt()
+    }
+
+    /// Normalize a vector to a unit vector.
+    #[inline]
+    pub fn normalize(&self) -> Self {
+        self * (S::one() / self.magnitude())
+    }
+}
+
+impl<S> Bivector3<S>
+where
+    S: ScalarFloat,
+{
+    /// Calculate the magnitude of a bivector.
+    #[inline]
+    pub fn magnitude(&self) -> S {
+        self.magnitude_squared().sqrt()
+    }
+
+    /// Normalize a bivector to a unit bivector.
+    #[inline]
+    pub fn normalize(&self) -> Self {
+        self * (S::one() / self.magnitude())
+    }
+}
+
+impl<S> Trivector3<S>
+where
+    S: ScalarFloat,
+{
+    /// Calculate the magnitude of a trivector.
+    #[inline]
+    pub fn magnitude(&self) -> S {
+        self.data[0].abs()
+    }
+}
+
+macro_rules! impl_graded_common {
+    ($T:ident, $n:expr, $fmt:literal, { $($index:expr),* }) => {
+        impl<S> ops::Index<usize> for $T<S>
+        where
+            S: Scalar,
+        {
+            type Output = S;
+
+            #[inline]
+            fn index(&self, index: usize) -> &Self::Output {
+                &self.data[index]
+            }
+        }
+
+        impl<S> ops::IndexMut<usize> for $T<S>
+        where
+            S: Scalar,
+        {
+            #[inline]
+            fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+                &mut self.data[index]
+            }
+        }
+
+        impl<S> AsRef<[S; $n]> for $T<S> {
+            #[inline]
+            fn as_ref(&self) -> &[S; $n] {
+                unsafe { &*(self as *const $T<S> as *const [S; $n]) }
+            }
+        }
+
+        impl<S> AsMut<[S; $n]> for $T<S> {
+            #[inline]
+            fn as_mut(&mut self) -> &mut [S; $n] {
+                unsafe { &mut *(self as *mut $T<S> as *mut [S; $n]) }
+            }
+        }
+
+        impl<S> fmt::Display for $T<S>
+        where
+            S: fmt::Display,
+        {
+            fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, $fmt, $(self.data[$index]),*)
+            }
+        }
+    };
+}
+
+impl_graded_common!(Vector3, 3, "{}^e1 + {}^e2 + {}^e3", { 0, 1, 2 });
+impl_graded_common!(Bivector3, 3, "{}^e12 + {}^e23 + {}^e31", { 0, 1, 2 });
+impl_graded_common!(Trivector3, 1, "{}^e123", { 0 });
+
+macro_rules! impl_graded_into_multivector {
+    ($T:ident, |$value:ident, $zero:ident| $body:block) => {
+        impl<S> From<$T<S>> for EuclideanMultivector3<S>
+        where
+            S: Scalar,
+        {
+            #[inline]
+            fn from($value: $T<S>) -> EuclideanMultivector3<S> {
+                let $zero = S::zero();
+
+                $body
+            }
+        }
+
+        impl<S> From<&$T<S>> for EuclideanMultivector3<S>
+        where
+            S: Scalar,
+        {
+            #[inline]
+            fn from($value: &$T<S>) -> EuclideanMultivector3<S> {
+                let $zero = S::zero();
+
+                $body
+            }
+        }
+    };
+}
+
+impl_graded_into_multivector!(Vector3, |vector, zero| {
+    EuclideanMultivector3::new(zero, vector.data[0], vector.data[1], vector.data[2], zero, zero, zero, zero)
+});
+impl_graded_into_multivector!(Bivector3, |bivector, zero| {
+    EuclideanMultivector3::new(zero, zero, zero, zero, bivector.data[0], bivector.data[1], bivector.data[2], zero)
+});
+impl_graded_into_multivector!(Trivector3, |trivector, zero| {
+    EuclideanMultivector3::new(zero, zero, zero, zero, zero, zero, zero, trivector.data[0])
+});
+
+/*
+ * Generate a binary operator on graded types for every combination of
+ * owned and borrowed operands. The body computes only the components of
+ * the result that can be nonzero for operands of the given grades.
+ */
+macro_rules! impl_graded_binary_op {
+    ($OpTrait:ident, $op:ident, $Bound:ident, $Lhs:ident, $Rhs:ident, $Output:ty, |$a:ident, $b:ident| $body:block) => {
+        impl_graded_binary_op!(@impl $OpTrait, $op, $Bound, $Lhs<S>, $Rhs<S>, $Output, |$a, $b| $body);
+        impl_graded_binary_op!(@impl $OpTrait, $op, $Bound, $Lhs<S>, &$Rhs<S>, $Output, |$a, $b| $body);
+        impl_graded_binary_op!(@impl $OpTrait, $op, $Bound, &$Lhs<S>, $Rhs<S>, $Output, |$a, $b| $body);
+        impl_graded_binary_op!(@impl $OpTrait, $op, $Bound, &$Lhs<S>, &$Rhs<S>, $Output, |$a, $b| $body);
+    };
+    (@impl $OpTrait:ident, $op:ident, $Bound:ident, $Lhs:ty, $Rhs:ty, $Output:ty, |$a:ident, $b:ident| $body:block) => {
+        impl<S> ops::$OpTrait<$Rhs> for $Lhs
+        where
+            S: $Bound,
+        {
+            type Output = $Output;
+
+            #[rustfmt::skip]
+            #[inline]
+            fn $op(self, other: $Rhs) -> Self::Output {
+                let $a = self;
+                let $b = other;
+
+                $body
+            }
+        }
+    };
+}
+
+/*
+ * Generate the componentwise vector space operations for a graded type.
+ */
+macro_rules! impl_graded_vector_space_ops {
+    ($T:ident, { $($index:expr),* }) => {
+        impl_graded_binary_op!(Add, add, Scalar, $T, $T, $T<S>, |a, b| {
+            $T { data: [$(a.data[$index] + b.data[$index]),*] }
+        });
+        impl_graded_binary_op!(Sub, sub, Scalar, $T, $T, $T<S>, |a, b| {
+            $T { data: [$(a.data[$index] - b.data[$index]),*] }
+        });
+
+        impl<S> ops::Mul<S> for $T<S>
+        where
+            S: Scalar,
+        {
+            type Output = $T<S>;
+
+            #[inline]
+            fn mul(self, other: S) -> Self::Output {
+                $T { data: [$(self.data[$index] * other),*] }
+            }
+        }
+
+        impl<S> ops::Mul<S> for &$T<S>
+        where
+            S: Scalar,
+        {
+            type Output = $T<S>;
+
+            #[inline]
+            fn mul(self, other: S) -> Self::Output {
+                $T { data: [$(self.data[$index] * other),*] }
+            }
+        }
+
+        impl<S> ops::Div<S> for $T<S>
+        where
+            S: Scalar,
+        {
+            type Output = $T<S>;
+
+            #[inline]
+            fn div(self, other: S) -> Self::Output {
+                $T { data: [$(self.data[$index] / other),*] }
+            }
+        }
+
+        impl<S> ops::Div<S> for &$T<S>
+        where
+            S: Scalar,
+        {
+            type Output = $T<S>;
+
+            #[inline]
+            fn div(self, other: S) -> Self::Output {
+                $T { data: [$(self.data[$index] / other),*] }
+            }
+        }
+
+        impl<S> ops::Neg for $T<S>
+        where
+            S: ScalarSigned,
+        {
+            type Output = $T<S>;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                $T { data: [$(-self.data[$index]),*] }
+            }
+        }
+
+        impl<S> ops::Neg for &$T<S>
+        where
+            S: ScalarSigned,
+        {
+            type Output = $T<S>;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                $T { data: [$(-self.data[$index]),*] }
+            }
+        }
+    };
+}
+
+impl_graded_vector_space_ops!(Vector3, { 0, 1, 2 });
+impl_graded_vector_space_ops!(Bivector3, { 0, 1, 2 });
+impl_graded_vector_space_ops!(Trivector3, { 0 });
+
+// Geometric products.
+impl_graded_binary_op!(Mul, mul, Scalar, Vector3, Vector3, Even3<S>, |a, b| {
+    let result_1 = a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+    let result_e12 = a[0] * b[1] - a[1] * b[0];
+    let result_e23 = a[1] * b[2] - a[2] * b[1];
+    let result_e31 = a[2] * b[0] - a[0] * b[2];
+
+    Even3::new(result_1, result_e12, result_e23, result_e31)
+});
+impl_graded_binary_op!(Mul, mul, Scalar, Vector3, Bivector3, EuclideanMultivector3<S>, |a, b| {
+    let zero = S::zero();
+    let result_e1 = a[2] * b[2] - a[1] * b[0];
+    let result_e2 = a[0] * b[0] - a[2] * b[1];
+    let result_e3 = a[1] * b[1] - a[0] * b[2];
+    let result_e123 = a[0] * b[1] + a[1] * b[2] + a[2] * b[0];
+
+    EuclideanMultivector3::new(zero, result_e1, result_e2, result_e3, zero, zero, zero, result_e123)
+});
+impl_graded_binary_op!(Mul, mul, Scalar, Vector3, Trivector3, Bivector3<S>, |a, b| {
+    Bivector3::new(a[2] * b[0], a[0] * b[0], a[1] * b[0])
+});
+impl_graded_binary_op!(Mul, mul, Scalar, Bivector3, Vector3, EuclideanMultivector3<S>, |a, b| {
+    let zero = S::zero();
+    let result_e1 = a[0] * b[1] - a[2] * b[2];
+    let result_e2 = a[1] * b[2] - a[0] * b[0];
+    let result_e3 = a[2] * b[0] - a[1] * b[1];
+    let result_e123 = a[0] * b[2] + a[1] * b[0] + a[2] * b[1];
+
+    EuclideanMultivector3::new(zero, result_e1, result_e2, result_e3, zero, zero, zero, result_e123)
+});
+impl_graded_binary_op!(Mul, mul, ScalarSigned, Bivector3, Bivector3, Even3<S>, |a, b| {
+    let result_1 = -(a[0] * b[0] + a[1] * b[1] + a[2] * b[2]);
+    let result_e12 = a[2] * b[1] - a[1] * b[2];
+    let result_e23 = a[0] * b[2] - a[2] * b[0];
+    let result_e31 = a[1] * b[0] - a[0] * b[1];
+
+    Even3::new(result_1, result_e12, result_e23, result_e31)
+});
+impl_graded_binary_op!(Mul, mul, ScalarSigned, Bivector3, Trivector3, Vector3<S>, |a, b| {
+    Vector3::new(-a[1] * b[0], -a[2] * b[0], -a[0] * b[0])
+});
+impl_graded_binary_op!(Mul, mul, Scalar, Trivector3, Vector3, Bivector3<S>, |a, b| {
+    Bivector3::new(a[0] * b[2], a[0] * b[0], a[0] * b[1])
+});
+impl_graded_binary_op!(Mul, mul, ScalarSigned, Trivector3, Bivector3, Vector3<S>, |a, b| {
+    Vector3::new(-a[0] * b[1], -a[0] * b[2], -a[0] * b[0])
+});
+impl_graded_binary_op!(Mul, mul, ScalarSigned, Trivector3, Trivector3, S, |a, b| { -a[0] * b[0] });
+
+// Outer products. The outer products whose grade exceeds three vanish identically,
+// so they are omitted.
+impl_graded_binary_op!(BitXor, bitxor, Scalar, Vector3, Vector3, Bivector3<S>, |a, b| {
+    let result_e12 = a[0] * b[1] - a[1] * b[0];
+    let result_e23 = a[1] * b[2] - a[2] * b[1];
+    let result_e31 = a[2] * b[0] - a[0] * b[2];
+
+    Bivector3::new(result_e12, result_e23, result_e31)
+});
+impl_graded_binary_op!(BitXor, bitxor, Scalar, Vector3, Bivector3, Trivector3<S>, |a, b| {
+    Trivector3::new(a[0] * b[1] + a[1] * b[2] + a[2] * b[0])
+});
+impl_graded_binary_op!(BitXor, bitxor, Scalar, Bivector3, Vector3, Trivector3<S>, |a, b| {
+    Trivector3::new(a[0] * b[2] + a[1] * b[0] + a[2] * b[1])
+});
+
+// Scalar products.
+impl_graded_binary_op!(BitOr, bitor, Scalar, Vector3, Vector3, S, |a, b| {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+});
+
+
+impl_coords!(ViewV3, { e1, e2, e3 });
+impl_coords_deref!(Vector3, ViewV3);
+
+impl_coords!(ViewB3, { e12, e23, e31 });
+impl_coords_deref!(Bivector3, ViewB3);
+
+impl_coords!(ViewT3, { e123 });
+impl_coords_deref!(Trivector3, ViewT3);
+
+impl_approx_cmp_ops!(Vector3);
+impl_approx_cmp_ops!(Bivector3);
+impl_approx_cmp_ops!(Trivector3);
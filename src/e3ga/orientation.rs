@@ -0,0 +1,320 @@
+use crate::e3ga::EuclideanMultivector3;
+use crate::scalar::ScalarFloat;
+
+
+/// The order of the axes of rotation in a set of Euler angles.
+///
+/// The axes `X`, `Y` and `Z` are the basis vectors `e1`, `e2` and `e3`. The
+/// six sequences with three distinct axes are the Tait-Bryan angles, and the
+/// six sequences whose first and last axes coincide are the proper Euler angles.
+/// The first angle of a set of Euler angles is always the angle of rotation about
+/// the first axis of the sequence.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum EulerSequence {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ,
+}
+
+impl EulerSequence {
+    /// The indices of the axes of the sequence, where `0`, `1` and `2` denote
+    /// `e1`, `e2` and `e3` respectively.
+    const fn axes(self) -> [usize; 3] {
+        match self {
+            EulerSequence::XYZ => [0, 1, 2],
+            EulerSequence::XZY => [0, 2, 1],
+            EulerSequence::YXZ => [1, 0, 2],
+            EulerSequence::YZX => [1, 2, 0],
+            EulerSequence::ZXY => [2, 0, 1],
+            EulerSequence::ZYX => [2, 1, 0],
+            EulerSequence::XYX => [0, 1, 0],
+            EulerSequence::XZX => [0, 2, 0],
+            EulerSequence::YXY => [1, 0, 1],
+            EulerSequence::YZY => [1, 2, 1],
+            EulerSequence::ZXZ => [2, 0, 2],
+            EulerSequence::ZYZ => [2, 1, 2],
+        }
+    }
+
+    /// Determine whether the sequence is a proper Euler sequence, i.e. whether
+    /// its first and last axes coincide.
+    pub const fn is_proper(self) -> bool {
+        let axes = self.axes();
+
+        axes[0] == axes[2]
+    }
+}
+
+/// The frame about whose axes the elementary rotations of a set of Euler angles
+/// are applied.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum EulerFrame {
+    /// Each rotation is applied about an axis of the frame rotated by the
+    /// previous rotations. The intrinsic rotation with angles `(a, b, c)` in
+    /// the sequence `XYZ` is the rotor `R_x(a) * R_y(b) * R_z(c)`.
+    Intrinsic,
+    /// Each rotation is applied about an axis of the fixed frame. The extrinsic
+    /// rotation with angles `(a, b, c)` in the sequence `XYZ` is the rotor
+    /// `R_z(c) * R_y(b) * R_x(a)`.
+    Extrinsic,
+}
+
+impl<S> EuclideanMultivector3<S>
+where
+    S: ScalarFloat,
+{
+    /// Construct the rotor of a rotation by an angle about an axis.
+    ///
+    /// Only the grade one part of `axis` is used, and it must be nonzero. The
+    /// plane of rotation is the dual of the normalized axis, so that the
+    /// rotor is
+    /// ```text
+    /// R := cos(angle / 2) + sin(angle / 2) * dual(axis / |axis|)
+    /// ```
+    /// and the rotation follows the right-hand rule: a positive angle about `e3`
+    /// rotates `e1` towards `e2`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// # use core::f64;
+    /// #
+    /// let e3: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e3();
+    /// let rotor = EuclideanMultivector3::from_axis_angle(&e3, f64::consts::FRAC_PI_2);
+    /// let e1: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e1();
+    /// let expected: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e2();
+    /// let result = e1.rotate(&rotor);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn from_axis_angle(axis: &Self, angle: S) -> Self {
+        let zero = S::zero();
+        let magnitude = (axis.data[1] * axis.data[1] + axis.data[2] * axis.data[2] + axis.data[3] * axis.data[3]).sqrt();
+        let unit_axis = Self::new(zero, axis.data[1], axis.data[2], axis.data[3], zero, zero, zero, zero) / magnitude;
+        let half_angle = angle / (S::one() + S::one());
+        let (sin_half_angle, cos_half_angle) = half_angle.sin_cos();
+        let plane = unit_axis.dual();
+
+        Self::new(
+            cos_half_angle,
+            zero,
+            zero,
+            zero,
+            plane.data[4] * sin_half_angle,
+            plane.data[5] * sin_half_angle,
+            plane.data[6] * sin_half_angle,
+            zero,
+        )
+    }
+
+    /// Compute the axis and the angle of the rotation described by a rotor.
+    ///
+    /// Only the even part of the rotor is used. This is the inverse of
+    /// [`from_axis_angle`](Self::from_axis_angle): the axis is a unit vector,
+    /// and the angle lies in the range `[0, pi]`. Since `R` and `-R` describe
+    /// the same rotation, a rotor with a negative scalar part is negated first.
+    /// When the rotor is the identity, the axis of rotation is not determined,
+    /// and this function returns the axis `e3` with the angle zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let axis = EuclideanMultivector3::new(0_f64, 1_f64, -2_f64, 2_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let rotor = EuclideanMultivector3::from_axis_angle(&axis, 2_f64);
+    /// let (result_axis, result_angle) = rotor.to_axis_angle();
+    ///
+    /// assert_relative_eq!(result_axis, axis.normalize(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(result_angle, 2_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn to_axis_angle(&self) -> (Self, S) {
+        let zero = S::zero();
+        let two = S::one() + S::one();
+        let even = if self.data[0] < zero {
+            Self::new(-self.data[0], zero, zero, zero, -self.data[4], -self.data[5], -self.data[6], zero)
+        } else {
+            Self::new(self.data[0], zero, zero, zero, self.data[4], self.data[5], self.data[6], zero)
+        };
+        let sin_half_angle = (even.data[4] * even.data[4] + even.data[5] * even.data[5] + even.data[6] * even.data[6]).sqrt();
+        if sin_half_angle.is_zero() {
+            return (Self::unit_e3(), zero);
+        }
+
+        let angle = two * sin_half_angle.atan2(even.data[0]);
+        // The dual maps a vector `v` to the bivector `dual(v)`, and maps `dual(v)` to `-v`.
+        let axis = -even.dual() / sin_half_angle;
+
+        let axis = Self::new(zero, axis.data[1], axis.data[2], axis.data[3], zero, zero, zero, zero);
+
+        (axis, angle)
+    }
+
+    /// Construct the rotor of a rotation about one of the basis vectors `e1`,
+    /// `e2` or `e3`.
+    fn from_basis_axis_angle(axis: usize, angle: S) -> Self {
+        let zero = S::zero();
+        let half_angle = angle / (S::one() + S::one());
+        let (sin_half_angle, cos_half_angle) = half_angle.sin_cos();
+        // The duals of `e1`, `e2` and `e3` are `-e23`, `-e31` and `-e12`.
+        match axis {
+            0 => Self::new(cos_half_angle, zero, zero, zero, zero, -sin_half_angle, zero, zero),
+            1 => Self::new(cos_half_angle, zero, zero, zero, zero, zero, -sin_half_angle, zero),
+            _ => Self::new(cos_half_angle, zero, zero, zero, -sin_half_angle, zero, zero, zero),
+        }
+    }
+
+    /// Construct the rotor of a rotation from a set of Euler angles.
+    ///
+    /// The angle `angles[n]` is the angle of rotation about the axis in
+    /// position `n` of `sequence`, and each elementary rotation is the rotation
+    /// constructed by [`from_axis_angle`](Self::from_axis_angle). The `frame`
+    /// determines whether the elementary rotations are applied about the axes
+    /// of the rotating frame or of the fixed frame.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::{
+    /// #     EuclideanMultivector3,
+    /// #     EulerFrame,
+    /// #     EulerSequence,
+    /// # };
+    /// #
+    /// let e1: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e1();
+    /// let e2: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e2();
+    /// let e3: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e3();
+    /// let angles = [0.1_f64, 0.2_f64, 0.3_f64];
+    /// let expected = EuclideanMultivector3::from_axis_angle(&e3, angles[2])
+    ///     * EuclideanMultivector3::from_axis_angle(&e2, angles[1])
+    ///     * EuclideanMultivector3::from_axis_angle(&e1, angles[0]);
+    /// let result = EuclideanMultivector3::from_euler(&angles, EulerSequence::XYZ, EulerFrame::Extrinsic);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn from_euler(angles: &[S; 3], sequence: EulerSequence, frame: EulerFrame) -> Self {
+        let axes = sequence.axes();
+        let rotor0 = Self::from_basis_axis_angle(axes[0], angles[0]);
+        let rotor1 = Self::from_basis_axis_angle(axes[1], angles[1]);
+        let rotor2 = Self::from_basis_axis_angle(axes[2], angles[2]);
+
+        match frame {
+            EulerFrame::Intrinsic => rotor0 * rotor1 * rotor2,
+            EulerFrame::Extrinsic => rotor2 * rotor1 * rotor0,
+        }
+    }
+
+    /// Compute a set of Euler angles for the rotation described by a rotor.
+    ///
+    /// Only the even part of the rotor is used, and the rotor is assumed to
+    /// have unit magnitude. This is the inverse of [`from_euler`](Self::from_euler)
+    /// for the same `sequence` and `frame`. The first and third angles lie in the
+    /// range `[-pi, pi]`. The second angle lies in the range `[-pi / 2, pi / 2]` for
+    /// Tait-Bryan sequences, and in the range `[0, pi]` for proper Euler sequences.
+    ///
+    /// # Gimbal Lock
+    ///
+    /// When the second angle is at an end of its range, the first and third
+    /// axes of rotation line up, and only the sum or the difference of the first
+    /// and third angles is determined by the rotation. In that case the third
+    /// angle is set to zero, and the first angle carries the whole rotation about
+    /// the common axis.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::{
+    /// #     EuclideanMultivector3,
+    /// #     EulerFrame,
+    /// #     EulerSequence,
+    /// # };
+    /// #
+    /// let angles = [0.1_f64, -0.2_f64, 0.3_f64];
+    /// let rotor = EuclideanMultivector3::from_euler(&angles, EulerSequence::ZYX, EulerFrame::Intrinsic);
+    /// let result = rotor.to_euler(EulerSequence::ZYX, EulerFrame::Intrinsic);
+    ///
+    /// assert_relative_eq!(result, angles, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn to_euler(&self, sequence: EulerSequence, frame: EulerFrame) -> [S; 3] {
+        let zero = S::zero();
+        let two = S::one() + S::one();
+        let pi: S = num_traits::cast(core::f64::consts::PI).unwrap();
+        let frac_pi_2 = pi / two;
+        let tolerance = S::default_epsilon().sqrt();
+        // The components of the corresponding unit quaternion `w + x * i + y * j + z * k`.
+        let w = self.data[0];
+        let xyz = [-self.data[5], -self.data[6], -self.data[4]];
+        // Angles in the intrinsic frame are the angles in the extrinsic frame for the
+        // reversed sequence, listed in reverse order.
+        let axes = sequence.axes();
+        let (i, j, k) = match frame {
+            EulerFrame::Extrinsic => (axes[0], axes[1], axes[2]),
+            EulerFrame::Intrinsic => (axes[2], axes[1], axes[0]),
+        };
+        let is_proper = i == k;
+        let k = if is_proper { 3 - i - j } else { k };
+        // The parity of the permutation `(i, j, k)` of the axes `(0, 1, 2)`.
+        let sign = if (i + 1) % 3 == j { S::one() } else { -S::one() };
+        let (a, b, c, d) = if is_proper {
+            (w, xyz[i], xyz[j], xyz[k] * sign)
+        } else {
+            (w - xyz[j], xyz[i] + xyz[k] * sign, xyz[j] + w, xyz[k] * sign - xyz[i])
+        };
+
+        let angle2 = two * c.hypot(d).atan2(a.hypot(b));
+        let half_sum = b.atan2(a);
+        let half_difference = d.atan2(c);
+        let (angle1, angle3) = if angle2.abs() <= tolerance {
+            match frame {
+                EulerFrame::Extrinsic => (two * half_sum, zero),
+                EulerFrame::Intrinsic => (zero, two * half_sum),
+            }
+        } else if (angle2 - pi).abs() <= tolerance {
+            match frame {
+                EulerFrame::Extrinsic => (-two * half_difference, zero),
+                EulerFrame::Intrinsic => (zero, two * half_difference),
+            }
+        } else {
+            (half_sum - half_difference, half_sum + half_difference)
+        };
+        // The Tait-Bryan angles are recovered from the proper Euler angles of the
+        // permuted axes by shifting the second angle and flipping the third one.
+        let (angle2, angle3) = if is_proper {
+            (angle2, angle3)
+        } else {
+            (angle2 - frac_pi_2, angle3 * sign)
+        };
+
+        let wrap = |angle: S| {
+            if angle > pi {
+                angle - two * pi
+            } else if angle < -pi {
+                angle + two * pi
+            } else {
+                angle
+            }
+        };
+        let angle1 = wrap(angle1);
+        let angle3 = wrap(angle3);
+
+        match frame {
+            EulerFrame::Extrinsic => [angle1, angle2, angle3],
+            EulerFrame::Intrinsic => [angle3, angle2, angle1],
+        }
+    }
+}
@@ -0,0 +1,821 @@
+use crate::e3ga::EuclideanMultivector3;
+use crate::scalar::{
+    Scalar,
+    ScalarFloat,
+    ScalarSigned,
+};
+use crate::{
+    impl_approx_cmp_ops,
+    impl_coords,
+    impl_coords_deref,
+};
+use core::fmt;
+use core::ops;
+
+
+/// A stack-allocated rotor in three-dimensional Euclidean space.
+///
+/// A rotor is a unit element of the even subalgebra of the three-dimensional
+/// Euclidean geometric algebra. It is stored in the basis `{1, e12, e23, e31}`,
+/// so only the scalar and bivector parts of a rotor are represented. The
+/// odd-grade parts of a rotor are always zero.
+///
+/// A rotor `R` rotates a vector `v` by the sandwich product
+/// ```text
+/// rotate(R, v) := R * v * rev(R)
+/// ```
+/// where `rev` denotes the reverse. The rotor that rotates by an angle `angle`
+/// in the plane of the unit bivector `B` is given by
+/// ```text
+/// R := exp(-(angle / 2) * B) == cos(angle / 2) - sin(angle / 2) * B
+/// ```
+/// so that the unit bivector `e12` rotates `e1` towards `e2`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Rotor3<S> {
+    data: [S; 4],
+}
+
+impl<S> Rotor3<S> {
+    /// Construct a new rotor from its components.
+    ///
+    /// The components are not normalized, so the caller is responsible for
+    /// ensuring that the resulting rotor has unit magnitude.
+    #[inline]
+    pub const fn new(scalar: S, e12: S, e23: S, e31: S) -> Self {
+        Self {
+            data: [scalar, e12, e23, e31],
+        }
+    }
+
+    /// Returns the number of components in a rotor.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e3ga::Rotor3;
+    /// #
+    /// let rotor = Rotor3::new(1_f64, 0_f64, 0_f64, 0_f64);
+    ///
+    /// assert_eq!(rotor.len(), 4);
+    /// ```
+    #[inline]
+    pub const fn len(&self) -> usize {
+        4
+    }
+
+    /// Get a pointer to the underlying component array.
+    #[inline]
+    pub const fn as_ptr(&self) -> *const S {
+        &self.data[0]
+    }
+
+    /// Get a mutable pointer to the underlying component array.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut S {
+        &mut self.data[0]
+    }
+
+    /// Get a slice of the underlying elements of the data type.
+    #[inline]
+    pub fn as_slice(&self) -> &[S] {
+        <Self as AsRef<[S; 4]>>::as_ref(self)
+    }
+}
+
+impl<S> Rotor3<S>
+where
+    S: Scalar,
+{
+    /// Construct the identity rotor.
+    ///
+    /// The identity rotor leaves every vector unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e3ga::{
+    /// #     EuclideanMultivector3,
+    /// #     Rotor3,
+    /// # };
+    /// #
+    /// let identity: Rotor3<f64> = Rotor3::identity();
+    /// let vector = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    ///
+    /// assert_eq!(identity.rotate_vector(&vector), vector);
+    /// ```
+    #[inline]
+    pub fn identity() -> Self {
+        Self::new(S::one(), S::zero(), S::zero(), S::zero())
+    }
+}
+
+impl<S> Rotor3<S>
+where
+    S: ScalarSigned,
+{
+    /// Compute the reverse of a rotor.
+    ///
+    /// The reverse of a unit rotor is its inverse, i.e. it performs the
+    /// opposite rotation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e3ga::Rotor3;
+    /// #
+    /// let rotor = Rotor3::new(1_i32, 2_i32, 3_i32, 4_i32);
+    /// let expected = Rotor3::new(1_i32, -2_i32, -3_i32, -4_i32);
+    /// let result = rotor.reverse();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn reverse(&self) -> Self {
+        Self::new(self.data[0], -self.data[1], -self.data[2], -self.data[3])
+    }
+}
+
+impl<S> Rotor3<S>
+where
+    S: ScalarFloat,
+{
+    /// Construct the rotor that rotates by the angle `angle` in the plane of
+    /// the bivector `bivector`.
+    ///
+    /// Only the grade two part of `bivector` is used, and it is normalized
+    /// before constructing the rotor, so it must be nonzero. The rotation is
+    /// oriented by the bivector: the rotor constructed from `e12` and a
+    /// positive angle rotates `e1` towards `e2`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::{
+    /// #     EuclideanMultivector3,
+    /// #     Rotor3,
+    /// # };
+    /// # use core::f64;
+    /// #
+    /// let e12: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e12();
+    /// let e1: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e1();
+    /// let e2: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e2();
+    /// let rotor = Rotor3::from_bivector_angle(&(e12 * 2_f64), f64::consts::FRAC_PI_2);
+    /// let result = rotor.rotate_vector(&e1);
+    ///
+    /// assert_relative_eq!(result, e2, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn from_bivector_angle(bivector: &EuclideanMultivector3<S>, angle: S) -> Self {
+        let one_half = S::one() / (S::one() + S::one());
+        let magnitude = (bivector[4] * bivector[4] + bivector[5] * bivector[5] + bivector[6] * bivector[6]).sqrt();
+        let (sin_half_angle, cos_half_angle) = (angle * one_half).sin_cos();
+        let factor = -sin_half_angle / magnitude;
+
+        Self::new(cos_half_angle, bivector[4] * factor, bivector[5] * factor, bivector[6] * factor)
+    }
+
+    /// Construct the minimal rotor that rotates the direction of `a` onto the
+    /// direction of `b`.
+    ///
+    /// Only the grade one parts of `a` and `b` are used, and they must be
+    /// nonzero. The rotor rotates in the plane `a ^ b` by the angle between
+    /// the two vectors, so it is the rotation with the smallest angle that
+    /// takes `a` to `b`. When `a` and `b` are antiparallel, every plane
+    /// containing `a` gives a minimal rotation by `pi`; in that case this
+    /// function deterministically picks the plane spanned by `a` and the
+    /// basis vector least aligned with `a`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::{
+    /// #     EuclideanMultivector3,
+    /// #     Rotor3,
+    /// # };
+    /// #
+    /// let a = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let b = EuclideanMultivector3::new(0_f64, -3_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let rotor = Rotor3::between_vectors(&a, &b);
+    /// let expected = b.normalize();
+    /// let result = rotor.rotate_vector(&a.normalize());
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn between_vectors(a: &EuclideanMultivector3<S>, b: &EuclideanMultivector3<S>) -> Self {
+        let zero = S::zero();
+        let one_over_magnitude_a = S::one() / (a[1] * a[1] + a[2] * a[2] + a[3] * a[3]).sqrt();
+        let one_over_magnitude_b = S::one() / (b[1] * b[1] + b[2] * b[2] + b[3] * b[3]).sqrt();
+        let a1 = a[1] * one_over_magnitude_a;
+        let a2 = a[2] * one_over_magnitude_a;
+        let a3 = a[3] * one_over_magnitude_a;
+        let b1 = b[1] * one_over_magnitude_b;
+        let b2 = b[2] * one_over_magnitude_b;
+        let b3 = b[3] * one_over_magnitude_b;
+        let dot = a1 * b1 + a2 * b2 + a3 * b3;
+        let wedge_e12 = a1 * b2 - a2 * b1;
+        let wedge_e23 = a2 * b3 - a3 * b2;
+        let wedge_e31 = a3 * b1 - a1 * b3;
+        let wedge_squared = wedge_e12 * wedge_e12 + wedge_e23 * wedge_e23 + wedge_e31 * wedge_e31;
+        if dot < zero && wedge_squared <= S::default_epsilon() {
+            // The vectors are antiparallel, so the plane of rotation is not determined
+            // by `a ^ b`. Rotate by `pi` in the plane of `a` and the basis vector that
+            // is least aligned with `a`.
+            let (c1, c2, c3) = if a1.abs() <= a2.abs() && a1.abs() <= a3.abs() {
+                (S::one(), zero, zero)
+            } else if a2.abs() <= a3.abs() {
+                (zero, S::one(), zero)
+            } else {
+                (zero, zero, S::one())
+            };
+            let plane_e12 = a1 * c2 - a2 * c1;
+            let plane_e23 = a2 * c3 - a3 * c2;
+            let plane_e31 = a3 * c1 - a1 * c3;
+            let one_over_magnitude_plane = S::one() / (plane_e12 * plane_e12 + plane_e23 * plane_e23 + plane_e31 * plane_e31).sqrt();
+
+            return Self::new(
+                zero,
+                -plane_e12 * one_over_magnitude_plane,
+                -plane_e23 * one_over_magnitude_plane,
+                -plane_e31 * one_over_magnitude_plane,
+            );
+        }
+
+        Self::new(S::one() + dot, -wedge_e12, -wedge_e23, -wedge_e31).normalize()
+    }
+
+    /// Construct a rotor from a 3x3 rotation matrix.
+    ///
+    /// The matrix is stored in column-major order, i.e. `matrix[column][row]`,
+    /// so that the columns of the matrix are the images of `e1`, `e2` and `e3`
+    /// under the rotation. The matrix is assumed to be a proper rotation
+    /// matrix, i.e. orthogonal with determinant one. The rotor is computed
+    /// from the largest of the diagonal combinations of the matrix entries to
+    /// avoid dividing by a small number, and normalized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::{
+    /// #     EuclideanMultivector3,
+    /// #     Rotor3,
+    /// # };
+    /// #
+    /// let bivector = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 1_f64, 2_f64, 3_f64, 0_f64);
+    /// let rotor = Rotor3::from_bivector_angle(&bivector, 2_f64);
+    /// let matrix = rotor.to_rotation_matrix();
+    /// let result = Rotor3::from_rotation_matrix(&matrix);
+    ///
+    /// assert_relative_eq!(result, rotor, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[rustfmt::skip]
+    pub fn from_rotation_matrix(matrix: &[[S; 3]; 3]) -> Self {
+        let one_fourth = S::one() / num_traits::cast(4_i32).unwrap();
+        let two = S::one() + S::one();
+        let m00 = matrix[0][0];
+        let m11 = matrix[1][1];
+        let m22 = matrix[2][2];
+        let trace = m00 + m11 + m22;
+        // The components of the rotor in the order `(scalar, -e23, -e31, -e12)`, i.e. the
+        // components of the corresponding unit quaternion `w + x * i + y * j + z * k`.
+        let (w, x, y, z) = if trace > S::zero() {
+            let t = (trace + S::one()).sqrt() * two;
+            (t * one_fourth, (matrix[1][2] - matrix[2][1]) / t, (matrix[2][0] - matrix[0][2]) / t, (matrix[0][1] - matrix[1][0]) / t)
+        } else if m00 > m11 && m00 > m22 {
+            let t = (S::one() + m00 - m11 - m22).sqrt() * two;
+            ((matrix[1][2] - matrix[2][1]) / t, t * one_fourth, (matrix[1][0] + matrix[0][1]) / t, (matrix[2][0] + matrix[0][2]) / t)
+        } else if m11 > m22 {
+            let t = (S::one() + m11 - m00 - m22).sqrt() * two;
+            ((matrix[2][0] - matrix[0][2]) / t, (matrix[1][0] + matrix[0][1]) / t, t * one_fourth, (matrix[2][1] + matrix[1][2]) / t)
+        } else {
+            let t = (S::one() + m22 - m00 - m11).sqrt() * two;
+            ((matrix[0][1] - matrix[1][0]) / t, (matrix[2][0] + matrix[0][2]) / t, (matrix[2][1] + matrix[1][2]) / t, t * one_fourth)
+        };
+
+        Self::new(w, -z, -x, -y).normalize()
+    }
+
+    /// Convert a rotor into a 3x3 rotation matrix.
+    ///
+    /// The matrix is stored in column-major order, i.e. `matrix[column][row]`,
+    /// so that the columns of the matrix are the images of `e1`, `e2` and `e3`
+    /// under the rotation. The rotor is assumed to have unit magnitude.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::{
+    /// #     EuclideanMultivector3,
+    /// #     Rotor3,
+    /// # };
+    /// # use core::f64;
+    /// #
+    /// let e12: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e12();
+    /// let rotor = Rotor3::from_bivector_angle(&e12, f64::consts::FRAC_PI_2);
+    /// let matrix = rotor.to_rotation_matrix();
+    ///
+    /// // The image of `e1` is `e2`.
+    /// assert_relative_eq!(matrix[0][0], 0_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(matrix[0][1], 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(matrix[0][2], 0_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[rustfmt::skip]
+    pub fn to_rotation_matrix(&self) -> [[S; 3]; 3] {
+        let two = S::one() + S::one();
+        let s   = self.data[0];
+        let b12 = self.data[1];
+        let b23 = self.data[2];
+        let b31 = self.data[3];
+        let c0r0 = s * s - b12 * b12 + b23 * b23 - b31 * b31;
+        let c0r1 = two * (b23 * b31 - b12 * s);
+        let c0r2 = two * (b12 * b23 + b31 * s);
+        let c1r0 = two * (b12 * s + b23 * b31);
+        let c1r1 = s * s - b12 * b12 - b23 * b23 + b31 * b31;
+        let c1r2 = two * (b12 * b31 - b23 * s);
+        let c2r0 = two * (b12 * b23 - b31 * s);
+        let c2r1 = two * (b12 * b31 + b23 * s);
+        let c2r2 = s * s + b12 * b12 - b23 * b23 - b31 * b31;
+
+        [
+            [c0r0, c0r1, c0r2],
+            [c1r0, c1r1, c1r2],
+            [c2r0, c2r1, c2r2],
+        ]
+    }
+
+    /// Compute the exponential of a bivector.
+    ///
+    /// Only the grade two part of `bivector` is used. Every bivector in three
+    /// dimensions is a 2-blade, so it squares to a non-positive scalar, and
+    /// its exponential has the closed form
+    /// ```text
+    /// exp(B) == cos(|B|) + (sin(|B|) / |B|) * B
+    /// ```
+    /// which is always a unit rotor. In particular, the rotor that rotates by
+    /// an angle `angle` in the plane of the unit bivector `B` is
+    /// `exp(-(angle / 2) * B)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::{
+    /// #     EuclideanMultivector3,
+    /// #     Rotor3,
+    /// # };
+    /// # use core::f64;
+    /// #
+    /// let e12: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e12();
+    /// let angle = f64::consts::FRAC_PI_3;
+    /// let expected = Rotor3::from_bivector_angle(&e12, angle);
+    /// let result = Rotor3::exp(&(e12 * (-angle / 2_f64)));
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn exp(bivector: &EuclideanMultivector3<S>) -> Self {
+        let magnitude = (bivector[4] * bivector[4] + bivector[5] * bivector[5] + bivector[6] * bivector[6]).sqrt();
+        if magnitude.is_zero() {
+            return Self::identity();
+        }
+
+        let (sin_magnitude, cos_magnitude) = magnitude.sin_cos();
+        let factor = sin_magnitude / magnitude;
+
+        Self::new(cos_magnitude, bivector[4] * factor, bivector[5] * factor, bivector[6] * factor)
+    }
+
+    /// Compute the logarithm of a rotor.
+    ///
+    /// The logarithm is the bivector `B` with `|B| <= pi` such that
+    /// `exp(B) == R`, where `R` is the normalized rotor. The rotor `-1`
+    /// has infinitely many logarithms, one in every plane; in that case this
+    /// function returns the logarithm in the `e12` plane.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::{
+    /// #     EuclideanMultivector3,
+    /// #     Rotor3,
+    /// # };
+    /// #
+    /// let bivector = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 0.1_f64, 0.2_f64, 0.3_f64, 0_f64);
+    /// let rotor = Rotor3::exp(&bivector);
+    /// let result = rotor.log();
+    ///
+    /// assert_relative_eq!(result, bivector, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn log(&self) -> EuclideanMultivector3<S> {
+        let zero = S::zero();
+        let magnitude_bivector = (self.data[1] * self.data[1] + self.data[2] * self.data[2] + self.data[3] * self.data[3]).sqrt();
+        if magnitude_bivector.is_zero() {
+            return if self.data[0] >= zero {
+                EuclideanMultivector3::zero()
+            } else {
+                EuclideanMultivector3::unit_e12() * num_traits::cast::<f64, S>(core::f64::consts::PI).unwrap()
+            };
+        }
+
+        let angle = magnitude_bivector.atan2(self.data[0]);
+        let factor = angle / magnitude_bivector;
+
+        EuclideanMultivector3::new(
+            zero,
+            zero,
+            zero,
+            zero,
+            self.data[1] * factor,
+            self.data[2] * factor,
+            self.data[3] * factor,
+            zero,
+        )
+    }
+
+    /// Rotate a vector by a rotor.
+    ///
+    /// The rotated vector is the sandwich product `R * v * rev(R)`. Only the
+    /// grade one part of `vector` is used, and the result is always a vector.
+    /// The rotor is assumed to have unit magnitude.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::{
+    /// #     EuclideanMultivector3,
+    /// #     Rotor3,
+    /// # };
+    /// # use core::f64;
+    /// #
+    /// let e23: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e23();
+    /// let rotor = Rotor3::from_bivector_angle(&e23, f64::consts::FRAC_PI_2);
+    /// let vector = EuclideanMultivector3::new(0_f64, 1_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let expected = EuclideanMultivector3::new(0_f64, 1_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let result = rotor.rotate_vector(&vector);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[rustfmt::skip]
+    pub fn rotate_vector(&self, vector: &EuclideanMultivector3<S>) -> EuclideanMultivector3<S> {
+        let zero = S::zero();
+        let two = S::one() + S::one();
+        let s   = self.data[0];
+        let b12 = self.data[1];
+        let b23 = self.data[2];
+        let b31 = self.data[3];
+        let v1  = vector[1];
+        let v2  = vector[2];
+        let v3  = vector[3];
+        let result_e1 = (s * s - b12 * b12 + b23 * b23 - b31 * b31) * v1
+            + two * (b12 * s + b23 * b31) * v2
+            + two * (b12 * b23 - b31 * s) * v3;
+        let result_e2 = two * (b23 * b31 - b12 * s) * v1
+            + (s * s - b12 * b12 - b23 * b23 + b31 * b31) * v2
+            + two * (b12 * b31 + b23 * s) * v3;
+        let result_e3 = two * (b12 * b23 + b31 * s) * v1
+            + two * (b12 * b31 - b23 * s) * v2
+            + (s * s + b12 * b12 - b23 * b23 - b31 * b31) * v3;
+
+        EuclideanMultivector3::new(zero, result_e1, result_e2, result_e3, zero, zero, zero, zero)
+    }
+
+    /// Spherically interpolate between two rotors.
+    ///
+    /// The interpolated rotor rotates by a constant angular velocity along the
+    /// shortest arc from `self` at `amount == 0` to `other` at `amount == 1`.
+    /// Both rotors are assumed to have unit magnitude. The rotors `R` and `-R`
+    /// perform the same rotation, so `other` is negated when the two rotors lie
+    /// in opposite hemispheres to take the shortest arc.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::{
+    /// #     EuclideanMultivector3,
+    /// #     Rotor3,
+    /// # };
+    /// # use core::f64;
+    /// #
+    /// let e23: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e23();
+    /// let rotor1: Rotor3<f64> = Rotor3::identity();
+    /// let rotor2 = Rotor3::from_bivector_angle(&e23, f64::consts::FRAC_PI_2);
+    /// let expected = Rotor3::from_bivector_angle(&e23, f64::consts::FRAC_PI_4);
+    /// let result = rotor1.slerp(&rotor2, 0.5_f64);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn slerp(&self, other: &Self, amount: S) -> Self {
+        let other = self.same_hemisphere(other);
+        let cos_half_theta = self.dot(&other);
+        let threshold = num_traits::cast(0.9995_f64).unwrap();
+        if cos_half_theta > threshold {
+            // The rotors are nearly parallel, so the linear interpolation is
+            // accurate and avoids dividing by a vanishing `sin(half_theta)`.
+            return self.lerp(&other, amount).normalize();
+        }
+
+        let half_theta = cos_half_theta.acos();
+        let sin_half_theta = half_theta.sin();
+        let a = ((S::one() - amount) * half_theta).sin() / sin_half_theta;
+        let b = (amount * half_theta).sin() / sin_half_theta;
+
+        Self::new(
+            self.data[0] * a + other.data[0] * b,
+            self.data[1] * a + other.data[1] * b,
+            self.data[2] * a + other.data[2] * b,
+            self.data[3] * a + other.data[3] * b,
+        )
+    }
+
+    /// Linearly interpolate between two rotors and normalize the result.
+    ///
+    /// Normalized linear interpolation is cheaper than [`Rotor3::slerp`] and
+    /// follows the same path, but it does not rotate at a constant angular
+    /// velocity. Both rotors are assumed to have unit magnitude, and `other`
+    /// is negated when necessary to take the shortest arc.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::{
+    /// #     EuclideanMultivector3,
+    /// #     Rotor3,
+    /// # };
+    /// # use core::f64;
+    /// #
+    /// let e12: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e12();
+    /// let rotor1 = Rotor3::from_bivector_angle(&e12, -f64::consts::FRAC_PI_3);
+    /// let rotor2 = Rotor3::from_bivector_angle(&e12, f64::consts::FRAC_PI_3);
+    /// let expected: Rotor3<f64> = Rotor3::identity();
+    /// let result = rotor1.nlerp(&rotor2, 0.5_f64);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn nlerp(&self, other: &Self, amount: S) -> Self {
+        let other = self.same_hemisphere(other);
+
+        self.lerp(&other, amount).normalize()
+    }
+
+    /// Interpolate between two rotors with spherical quadrangle interpolation.
+    ///
+    /// Squad interpolates along a smooth spline from `self` at `amount == 0`
+    /// to `other` at `amount == 1` by
+    /// ```text
+    /// squad(q1, q2, s1, s2, t) == slerp(slerp(q1, q2, t), slerp(s1, s2, t), 2 * t * (1 - t))
+    /// ```
+    /// where `control1` and `control2` are the control points `s1` and `s2` of
+    /// `self` and `other`, computed with [`Rotor3::squad_control_point`].
+    /// Interpolating a sequence of keyframes segment by segment with squad
+    /// gives a curve whose angular velocity is continuous at the keyframes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::{
+    /// #     EuclideanMultivector3,
+    /// #     Rotor3,
+    /// # };
+    /// #
+    /// let e12: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e12();
+    /// let e23: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e23();
+    /// let keyframes = [
+    ///     Rotor3::identity(),
+    ///     Rotor3::from_bivector_angle(&e12, 0.5_f64),
+    ///     Rotor3::from_bivector_angle(&e23, 1_f64),
+    ///     Rotor3::from_bivector_angle(&e12, 1.5_f64),
+    /// ];
+    /// let control1 = Rotor3::squad_control_point(&keyframes[0], &keyframes[1], &keyframes[2]);
+    /// let control2 = Rotor3::squad_control_point(&keyframes[1], &keyframes[2], &keyframes[3]);
+    /// let result = keyframes[1].squad(&keyframes[2], &control1, &control2, 0_f64);
+    ///
+    /// assert_relative_eq!(result, keyframes[1], abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn squad(&self, other: &Self, control1: &Self, control2: &Self, amount: S) -> Self {
+        let two = S::one() + S::one();
+        let rotor = self.slerp(other, amount);
+        let control = control1.slerp(control2, amount);
+
+        rotor.slerp(&control, two * amount * (S::one() - amount))
+    }
+
+    /// Compute the squad control point of a keyframe rotor.
+    ///
+    /// The control point of the keyframe `current` with neighboring keyframes
+    /// `previous` and `next` is given by
+    /// ```text
+    /// s == q * exp(-(log(inv(q) * q_next) + log(inv(q) * q_previous)) / 4)
+    /// ```
+    /// The neighboring keyframes are negated when necessary so that they lie
+    /// in the same hemisphere as `current`. For the first and last keyframes
+    /// of a sequence, pass the keyframe itself as the missing neighbor.
+    pub fn squad_control_point(previous: &Self, current: &Self, next: &Self) -> Self {
+        let one_fourth = S::one() / num_traits::cast(4_i32).unwrap();
+        let previous = current.same_hemisphere(previous);
+        let next = current.same_hemisphere(next);
+        let current_inv = current.inverse();
+        let log_next = (current_inv * next).log();
+        let log_previous = (current_inv * previous).log();
+        let tangent = (log_next + log_previous) * (-one_fourth);
+
+        current * Self::exp(&tangent)
+    }
+
+    #[inline]
+    fn dot(&self, other: &Self) -> S {
+        self.data[0] * other.data[0] + self.data[1] * other.data[1] + self.data[2] * other.data[2] + self.data[3] * other.data[3]
+    }
+
+    #[inline]
+    fn same_hemisphere(&self, other: &Self) -> Self {
+        if self.dot(other) < S::zero() { -other } else { *other }
+    }
+
+    #[inline]
+    fn lerp(&self, other: &Self, amount: S) -> Self {
+        let one_minus_amount = S::one() - amount;
+
+        Self::new(
+            self.data[0] * one_minus_amount + other.data[0] * amount,
+            self.data[1] * one_minus_amount + other.data[1] * amount,
+            self.data[2] * one_minus_amount + other.data[2] * amount,
+            self.data[3] * one_minus_amount + other.data[3] * amount,
+        )
+    }
+
+    /// Calculate the squared magnitude of a rotor.
+    #[inline]
+    pub fn magnitude_squared(&self) -> S {
+        self.data[0] * self.data[0] + self.data[1] * self.data[1] + self.data[2] * self.data[2] + self.data[3] * self.data[3]
+    }
+
+    /// Calculate the magnitude of a rotor.
+    #[inline]
+    pub fn magnitude(&self) -> S {
+        self.magnitude_squared().sqrt()
+    }
+
+    /// Normalize a rotor to a unit rotor.
+    ///
+    /// Repeated composition of rotors accumulates rounding errors that cause
+    /// the result to drift away from unit magnitude. Normalizing the rotor
+    /// projects it back onto the set of rotors.
+    #[inline]
+    pub fn normalize(&self) -> Self {
+        let one_over_magnitude = S::one() / self.magnitude();
+
+        Self::new(
+            self.data[0] * one_over_magnitude,
+            self.data[1] * one_over_magnitude,
+            self.data[2] * one_over_magnitude,
+            self.data[3] * one_over_magnitude,
+        )
+    }
+
+    /// Compute the inverse of a unit rotor.
+    ///
+    /// The inverse of a unit rotor is its reverse.
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        self.reverse()
+    }
+}
+
+impl<S> ops::Index<usize> for Rotor3<S>
+where
+    S: Scalar,
+{
+    type Output = S;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.data[index]
+    }
+}
+
+impl<S> ops::IndexMut<usize> for Rotor3<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.data[index]
+    }
+}
+
+impl<S> AsRef<[S; 4]> for Rotor3<S> {
+    #[inline]
+    fn as_ref(&self) -> &[S; 4] {
+        unsafe { &*(self as *const Rotor3<S> as *const [S; 4]) }
+    }
+}
+
+impl<S> AsMut<[S; 4]> for Rotor3<S> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [S; 4] {
+        unsafe { &mut *(self as *mut Rotor3<S> as *mut [S; 4]) }
+    }
+}
+
+impl<S> fmt::Display for Rotor3<S>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "{} + {}^e12 + {}^e23 + {}^e31",
+            self.data[0], self.data[1], self.data[2], self.data[3]
+        )
+    }
+}
+
+impl<S> From<Rotor3<S>> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn from(rotor: Rotor3<S>) -> EuclideanMultivector3<S> {
+        let zero = S::zero();
+
+        EuclideanMultivector3::new(rotor.data[0], zero, zero, zero, rotor.data[1], rotor.data[2], rotor.data[3], zero)
+    }
+}
+
+impl<S> From<&Rotor3<S>> for EuclideanMultivector3<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn from(rotor: &Rotor3<S>) -> EuclideanMultivector3<S> {
+        let zero = S::zero();
+
+        EuclideanMultivector3::new(rotor.data[0], zero, zero, zero, rotor.data[1], rotor.data[2], rotor.data[3], zero)
+    }
+}
+
+macro_rules! impl_rotor_mul_ops {
+    ($Lhs:ty, $Rhs:ty) => {
+        impl<S> ops::Mul<$Rhs> for $Lhs
+        where
+            S: Scalar,
+        {
+            type Output = Rotor3<S>;
+
+            #[rustfmt::skip]
+            #[inline]
+            fn mul(self, other: $Rhs) -> Self::Output {
+                let a = self;
+                let b = other;
+                let result_1   = a[0] * b[0] - a[1] * b[1] - a[2] * b[2] - a[3] * b[3];
+                let result_e12 = a[0] * b[1] + a[1] * b[0] - a[2] * b[3] + a[3] * b[2];
+                let result_e23 = a[0] * b[2] + a[1] * b[3] + a[2] * b[0] - a[3] * b[1];
+                let result_e31 = a[0] * b[3] - a[1] * b[2] + a[2] * b[1] + a[3] * b[0];
+
+                Rotor3::new(result_1, result_e12, result_e23, result_e31)
+            }
+        }
+    };
+}
+
+impl_rotor_mul_ops!(Rotor3<S>, Rotor3<S>);
+impl_rotor_mul_ops!(Rotor3<S>, &Rotor3<S>);
+impl_rotor_mul_ops!(&Rotor3<S>, Rotor3<S>);
+impl_rotor_mul_ops!(&Rotor3<S>, &Rotor3<S>);
+
+impl<S> ops::Neg for Rotor3<S>
+where
+    S: ScalarSigned,
+{
+    type Output = Rotor3<S>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Rotor3::new(-self.data[0], -self.data[1], -self.data[2], -self.data[3])
+    }
+}
+
+impl<S> ops::Neg for &Rotor3<S>
+where
+    S: ScalarSigned,
+{
+    type Output = Rotor3<S>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Rotor3::new(-self.data[0], -self.data[1], -self.data[2], -self.data[3])
+    }
+}
+
+
+impl_coords!(ViewR3, { scalar, e12, e23, e31 });
+impl_coords_deref!(Rotor3, ViewR3);
+
+impl_approx_cmp_ops!(Rotor3);
@@ -0,0 +1,4041 @@
+use crate::scalar::{
+    Scalar,
+    ScalarFloat,
+    ScalarSigned,
+};
+use crate::{
+    impl_approx_cmp_ops,
+    impl_coords,
+    impl_coords_deref,
+};
+use approx_cmp::ulps_ne;
+use core::fmt;
+use core::ops;
+
+mod rotor;
+
+pub use self::rotor::*;
+
+
+/// A stack-allocated, four-dimensional Euclidean multivector in the orthonormal basis
+/// `{1, e1, e2, e3, e4, e12, e13, e14, e23, e24, e34, e123, e124, e134, e234, e1234}`.
+///
+/// The basis vectors satisfy `e1 * e1 == e2 * e2 == e3 * e3 == e4 * e4 == 1`, i.e.
+/// this is the geometric algebra `Cl(4, 0)`. Unlike in two and three dimensions,
+/// not every bivector in four dimensions is a 2-blade. A general bivector is the
+/// sum of two orthogonal 2-blades, and it generates a double rotation in two
+/// completely orthogonal planes. The pseudoscalar `e1234` squares to one and
+/// commutes with every element of the even subalgebra.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct EuclideanMultivector4<S> {
+    data: [S; 16],
+}
+
+impl<S> EuclideanMultivector4<S> {
+    /// Construct a new general multivector.
+    #[inline]
+    pub const fn new(
+        scalar: S,
+        e1: S,
+        e2: S,
+        e3: S,
+        e4: S,
+        e12: S,
+        e13: S,
+        e14: S,
+        e23: S,
+        e24: S,
+        e34: S,
+        e123: S,
+        e124: S,
+        e134: S,
+        e234: S,
+        e1234: S,
+    ) -> Self {
+        Self {
+            data: [scalar, e1, e2, e3, e4, e12, e13, e14, e23, e24, e34, e123, e124, e134, e234, e1234],
+        }
+    }
+
+    /// Returns the number of components in a multivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let mv = EuclideanMultivector4::new(1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32);
+    ///
+    /// assert_eq!(mv.len(), 16);
+    /// ```
+    #[inline]
+    pub const fn len(&self) -> usize {
+        16
+    }
+
+    /// Get a pointer to the underlying component array.
+    #[inline]
+    pub const fn as_ptr(&self) -> *const S {
+        &self.data[0]
+    }
+
+    /// Get a mutable pointer to the underlying component array.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut S {
+        &mut self.data[0]
+    }
+
+    /// Get a slice of the underlying elements of the data type.
+    #[inline]
+    pub fn as_slice(&self) -> &[S] {
+        <Self as AsRef<[S; 16]>>::as_ref(self)
+    }
+}
+
+impl<S> EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    /// Construct the additive unit (zero) multivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let mv = EuclideanMultivector4::new(
+    ///     1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64,
+    ///     9_f64, 10_f64, 11_f64, 12_f64, 13_f64, 14_f64, 15_f64, 16_f64
+    /// );
+    /// let zero: EuclideanMultivector4<f64> = EuclideanMultivector4::zero();
+    ///
+    /// assert_eq!(mv + zero, mv);
+    /// assert_eq!(zero + mv, mv);
+    /// ```
+    #[inline]
+    pub fn zero() -> Self {
+        Self { data: [S::zero(); 16] }
+    }
+
+    /// Determine whether a multivector is the zero mutlivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let zero: EuclideanMultivector4<f64> = EuclideanMultivector4::zero();
+    ///
+    /// assert!(zero.is_zero());
+    ///
+    /// let mv = EuclideanMultivector4::new(
+    ///     1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64,
+    ///     9_f64, 10_f64, 11_f64, 12_f64, 13_f64, 14_f64, 15_f64, 16_f64
+    /// );
+    ///
+    /// assert!(!mv.is_zero());
+    /// ```
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.data.iter().all(|component| component.is_zero())
+    }
+
+    /// Construct a new multivector from the scalar part only.
+    ///
+    /// A scalar is a multivector whose vector, bivector, etc. components are
+    /// all zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let scalar_part = 2;
+    /// let scalar = EuclideanMultivector4::from_scalar(scalar_part);
+    ///
+    /// assert_eq!(scalar.scalar, scalar_part);
+    /// assert_eq!(scalar.e1, 0);
+    /// assert_eq!(scalar.e2, 0);
+    /// assert_eq!(scalar.e3, 0);
+    /// assert_eq!(scalar.e4, 0);
+    /// assert_eq!(scalar.e12, 0);
+    /// assert_eq!(scalar.e13, 0);
+    /// assert_eq!(scalar.e14, 0);
+    /// assert_eq!(scalar.e23, 0);
+    /// assert_eq!(scalar.e24, 0);
+    /// assert_eq!(scalar.e34, 0);
+    /// assert_eq!(scalar.e123, 0);
+    /// assert_eq!(scalar.e124, 0);
+    /// assert_eq!(scalar.e134, 0);
+    /// assert_eq!(scalar.e234, 0);
+    /// assert_eq!(scalar.e1234, 0);
+    /// ```
+    #[inline]
+    pub fn from_scalar(scalar: S) -> Self {
+        let mut result = Self::zero();
+        result.data[0] = scalar;
+
+        result
+    }
+
+    /// Returns the unit scalar multivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let unit_scalar: EuclideanMultivector4<isize> = EuclideanMultivector4::unit_scalar();
+    ///
+    /// assert_eq!(unit_scalar.scalar, 1);
+    /// assert_eq!(unit_scalar.e1, 0);
+    /// assert_eq!(unit_scalar.e2, 0);
+    /// assert_eq!(unit_scalar.e3, 0);
+    /// assert_eq!(unit_scalar.e4, 0);
+    /// assert_eq!(unit_scalar.e12, 0);
+    /// assert_eq!(unit_scalar.e13, 0);
+    /// assert_eq!(unit_scalar.e14, 0);
+    /// assert_eq!(unit_scalar.e23, 0);
+    /// assert_eq!(unit_scalar.e24, 0);
+    /// assert_eq!(unit_scalar.e34, 0);
+    /// assert_eq!(unit_scalar.e123, 0);
+    /// assert_eq!(unit_scalar.e124, 0);
+    /// assert_eq!(unit_scalar.e134, 0);
+    /// assert_eq!(unit_scalar.e234, 0);
+    /// assert_eq!(unit_scalar.e1234, 0);
+    /// ```
+    #[inline]
+    pub fn unit_scalar() -> Self {
+        let mut result = Self::zero();
+        result.data[0] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `x`-axis vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let unit_e1: EuclideanMultivector4<isize> = EuclideanMultivector4::unit_e1();
+    ///
+    /// assert_eq!(unit_e1.scalar, 0);
+    /// assert_eq!(unit_e1.e1, 1);
+    /// assert_eq!(unit_e1.e2, 0);
+    /// assert_eq!(unit_e1.e3, 0);
+    /// assert_eq!(unit_e1.e4, 0);
+    /// assert_eq!(unit_e1.e12, 0);
+    /// assert_eq!(unit_e1.e13, 0);
+    /// assert_eq!(unit_e1.e14, 0);
+    /// assert_eq!(unit_e1.e23, 0);
+    /// assert_eq!(unit_e1.e24, 0);
+    /// assert_eq!(unit_e1.e34, 0);
+    /// assert_eq!(unit_e1.e123, 0);
+    /// assert_eq!(unit_e1.e124, 0);
+    /// assert_eq!(unit_e1.e134, 0);
+    /// assert_eq!(unit_e1.e234, 0);
+    /// assert_eq!(unit_e1.e1234, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e1() -> Self {
+        let mut result = Self::zero();
+        result.data[1] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `y`-axis vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let unit_e2: EuclideanMultivector4<isize> = EuclideanMultivector4::unit_e2();
+    ///
+    /// assert_eq!(unit_e2.scalar, 0);
+    /// assert_eq!(unit_e2.e1, 0);
+    /// assert_eq!(unit_e2.e2, 1);
+    /// assert_eq!(unit_e2.e3, 0);
+    /// assert_eq!(unit_e2.e4, 0);
+    /// assert_eq!(unit_e2.e12, 0);
+    /// assert_eq!(unit_e2.e13, 0);
+    /// assert_eq!(unit_e2.e14, 0);
+    /// assert_eq!(unit_e2.e23, 0);
+    /// assert_eq!(unit_e2.e24, 0);
+    /// assert_eq!(unit_e2.e34, 0);
+    /// assert_eq!(unit_e2.e123, 0);
+    /// assert_eq!(unit_e2.e124, 0);
+    /// assert_eq!(unit_e2.e134, 0);
+    /// assert_eq!(unit_e2.e234, 0);
+    /// assert_eq!(unit_e2.e1234, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e2() -> Self {
+        let mut result = Self::zero();
+        result.data[2] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `z`-axis vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let unit_e3: EuclideanMultivector4<isize> = EuclideanMultivector4::unit_e3();
+    ///
+    /// assert_eq!(unit_e3.scalar, 0);
+    /// assert_eq!(unit_e3.e1, 0);
+    /// assert_eq!(unit_e3.e2, 0);
+    /// assert_eq!(unit_e3.e3, 1);
+    /// assert_eq!(unit_e3.e4, 0);
+    /// assert_eq!(unit_e3.e12, 0);
+    /// assert_eq!(unit_e3.e13, 0);
+    /// assert_eq!(unit_e3.e14, 0);
+    /// assert_eq!(unit_e3.e23, 0);
+    /// assert_eq!(unit_e3.e24, 0);
+    /// assert_eq!(unit_e3.e34, 0);
+    /// assert_eq!(unit_e3.e123, 0);
+    /// assert_eq!(unit_e3.e124, 0);
+    /// assert_eq!(unit_e3.e134, 0);
+    /// assert_eq!(unit_e3.e234, 0);
+    /// assert_eq!(unit_e3.e1234, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e3() -> Self {
+        let mut result = Self::zero();
+        result.data[3] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `w`-axis vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let unit_e4: EuclideanMultivector4<isize> = EuclideanMultivector4::unit_e4();
+    ///
+    /// assert_eq!(unit_e4.scalar, 0);
+    /// assert_eq!(unit_e4.e1, 0);
+    /// assert_eq!(unit_e4.e2, 0);
+    /// assert_eq!(unit_e4.e3, 0);
+    /// assert_eq!(unit_e4.e4, 1);
+    /// assert_eq!(unit_e4.e12, 0);
+    /// assert_eq!(unit_e4.e13, 0);
+    /// assert_eq!(unit_e4.e14, 0);
+    /// assert_eq!(unit_e4.e23, 0);
+    /// assert_eq!(unit_e4.e24, 0);
+    /// assert_eq!(unit_e4.e34, 0);
+    /// assert_eq!(unit_e4.e123, 0);
+    /// assert_eq!(unit_e4.e124, 0);
+    /// assert_eq!(unit_e4.e134, 0);
+    /// assert_eq!(unit_e4.e234, 0);
+    /// assert_eq!(unit_e4.e1234, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e4() -> Self {
+        let mut result = Self::zero();
+        result.data[4] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `xy`-plane bivector for four-dimensional Euclidean space.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let unit_e12: EuclideanMultivector4<isize> = EuclideanMultivector4::unit_e12();
+    ///
+    /// assert_eq!(unit_e12.scalar, 0);
+    /// assert_eq!(unit_e12.e1, 0);
+    /// assert_eq!(unit_e12.e2, 0);
+    /// assert_eq!(unit_e12.e3, 0);
+    /// assert_eq!(unit_e12.e4, 0);
+    /// assert_eq!(unit_e12.e12, 1);
+    /// assert_eq!(unit_e12.e13, 0);
+    /// assert_eq!(unit_e12.e14, 0);
+    /// assert_eq!(unit_e12.e23, 0);
+    /// assert_eq!(unit_e12.e24, 0);
+    /// assert_eq!(unit_e12.e34, 0);
+    /// assert_eq!(unit_e12.e123, 0);
+    /// assert_eq!(unit_e12.e124, 0);
+    /// assert_eq!(unit_e12.e134, 0);
+    /// assert_eq!(unit_e12.e234, 0);
+    /// assert_eq!(unit_e12.e1234, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e12() -> Self {
+        let mut result = Self::zero();
+        result.data[5] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `xz`-plane bivector for four-dimensional Euclidean space.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let unit_e13: EuclideanMultivector4<isize> = EuclideanMultivector4::unit_e13();
+    ///
+    /// assert_eq!(unit_e13.scalar, 0);
+    /// assert_eq!(unit_e13.e1, 0);
+    /// assert_eq!(unit_e13.e2, 0);
+    /// assert_eq!(unit_e13.e3, 0);
+    /// assert_eq!(unit_e13.e4, 0);
+    /// assert_eq!(unit_e13.e12, 0);
+    /// assert_eq!(unit_e13.e13, 1);
+    /// assert_eq!(unit_e13.e14, 0);
+    /// assert_eq!(unit_e13.e23, 0);
+    /// assert_eq!(unit_e13.e24, 0);
+    /// assert_eq!(unit_e13.e34, 0);
+    /// assert_eq!(unit_e13.e123, 0);
+    /// assert_eq!(unit_e13.e124, 0);
+    /// assert_eq!(unit_e13.e134, 0);
+    /// assert_eq!(unit_e13.e234, 0);
+    /// assert_eq!(unit_e13.e1234, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e13() -> Self {
+        let mut result = Self::zero();
+        result.data[6] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `xw`-plane bivector for four-dimensional Euclidean space.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let unit_e14: EuclideanMultivector4<isize> = EuclideanMultivector4::unit_e14();
+    ///
+    /// assert_eq!(unit_e14.scalar, 0);
+    /// assert_eq!(unit_e14.e1, 0);
+    /// assert_eq!(unit_e14.e2, 0);
+    /// assert_eq!(unit_e14.e3, 0);
+    /// assert_eq!(unit_e14.e4, 0);
+    /// assert_eq!(unit_e14.e12, 0);
+    /// assert_eq!(unit_e14.e13, 0);
+    /// assert_eq!(unit_e14.e14, 1);
+    /// assert_eq!(unit_e14.e23, 0);
+    /// assert_eq!(unit_e14.e24, 0);
+    /// assert_eq!(unit_e14.e34, 0);
+    /// assert_eq!(unit_e14.e123, 0);
+    /// assert_eq!(unit_e14.e124, 0);
+    /// assert_eq!(unit_e14.e134, 0);
+    /// assert_eq!(unit_e14.e234, 0);
+    /// assert_eq!(unit_e14.e1234, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e14() -> Self {
+        let mut result = Self::zero();
+        result.data[7] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `yz`-plane bivector for four-dimensional Euclidean space.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let unit_e23: EuclideanMultivector4<isize> = EuclideanMultivector4::unit_e23();
+    ///
+    /// assert_eq!(unit_e23.scalar, 0);
+    /// assert_eq!(unit_e23.e1, 0);
+    /// assert_eq!(unit_e23.e2, 0);
+    /// assert_eq!(unit_e23.e3, 0);
+    /// assert_eq!(unit_e23.e4, 0);
+    /// assert_eq!(unit_e23.e12, 0);
+    /// assert_eq!(unit_e23.e13, 0);
+    /// assert_eq!(unit_e23.e14, 0);
+    /// assert_eq!(unit_e23.e23, 1);
+    /// assert_eq!(unit_e23.e24, 0);
+    /// assert_eq!(unit_e23.e34, 0);
+    /// assert_eq!(unit_e23.e123, 0);
+    /// assert_eq!(unit_e23.e124, 0);
+    /// assert_eq!(unit_e23.e134, 0);
+    /// assert_eq!(unit_e23.e234, 0);
+    /// assert_eq!(unit_e23.e1234, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e23() -> Self {
+        let mut result = Self::zero();
+        result.data[8] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `yw`-plane bivector for four-dimensional Euclidean space.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let unit_e24: EuclideanMultivector4<isize> = EuclideanMultivector4::unit_e24();
+    ///
+    /// assert_eq!(unit_e24.scalar, 0);
+    /// assert_eq!(unit_e24.e1, 0);
+    /// assert_eq!(unit_e24.e2, 0);
+    /// assert_eq!(unit_e24.e3, 0);
+    /// assert_eq!(unit_e24.e4, 0);
+    /// assert_eq!(unit_e24.e12, 0);
+    /// assert_eq!(unit_e24.e13, 0);
+    /// assert_eq!(unit_e24.e14, 0);
+    /// assert_eq!(unit_e24.e23, 0);
+    /// assert_eq!(unit_e24.e24, 1);
+    /// assert_eq!(unit_e24.e34, 0);
+    /// assert_eq!(unit_e24.e123, 0);
+    /// assert_eq!(unit_e24.e124, 0);
+    /// assert_eq!(unit_e24.e134, 0);
+    /// assert_eq!(unit_e24.e234, 0);
+    /// assert_eq!(unit_e24.e1234, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e24() -> Self {
+        let mut result = Self::zero();
+        result.data[9] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `zw`-plane bivector for four-dimensional Euclidean space.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let unit_e34: EuclideanMultivector4<isize> = EuclideanMultivector4::unit_e34();
+    ///
+    /// assert_eq!(unit_e34.scalar, 0);
+    /// assert_eq!(unit_e34.e1, 0);
+    /// assert_eq!(unit_e34.e2, 0);
+    /// assert_eq!(unit_e34.e3, 0);
+    /// assert_eq!(unit_e34.e4, 0);
+    /// assert_eq!(unit_e34.e12, 0);
+    /// assert_eq!(unit_e34.e13, 0);
+    /// assert_eq!(unit_e34.e14, 0);
+    /// assert_eq!(unit_e34.e23, 0);
+    /// assert_eq!(unit_e34.e24, 0);
+    /// assert_eq!(unit_e34.e34, 1);
+    /// assert_eq!(unit_e34.e123, 0);
+    /// assert_eq!(unit_e34.e124, 0);
+    /// assert_eq!(unit_e34.e134, 0);
+    /// assert_eq!(unit_e34.e234, 0);
+    /// assert_eq!(unit_e34.e1234, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e34() -> Self {
+        let mut result = Self::zero();
+        result.data[10] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `xyz`-volume trivector for four-dimensional Euclidean space.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let unit_e123: EuclideanMultivector4<isize> = EuclideanMultivector4::unit_e123();
+    ///
+    /// assert_eq!(unit_e123.scalar, 0);
+    /// assert_eq!(unit_e123.e1, 0);
+    /// assert_eq!(unit_e123.e2, 0);
+    /// assert_eq!(unit_e123.e3, 0);
+    /// assert_eq!(unit_e123.e4, 0);
+    /// assert_eq!(unit_e123.e12, 0);
+    /// assert_eq!(unit_e123.e13, 0);
+    /// assert_eq!(unit_e123.e14, 0);
+    /// assert_eq!(unit_e123.e23, 0);
+    /// assert_eq!(unit_e123.e24, 0);
+    /// assert_eq!(unit_e123.e34, 0);
+    /// assert_eq!(unit_e123.e123, 1);
+    /// assert_eq!(unit_e123.e124, 0);
+    /// assert_eq!(unit_e123.e134, 0);
+    /// assert_eq!(unit_e123.e234, 0);
+    /// assert_eq!(unit_e123.e1234, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e123() -> Self {
+        let mut result = Self::zero();
+        result.data[11] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `xyw`-volume trivector for four-dimensional Euclidean space.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let unit_e124: EuclideanMultivector4<isize> = EuclideanMultivector4::unit_e124();
+    ///
+    /// assert_eq!(unit_e124.scalar, 0);
+    /// assert_eq!(unit_e124.e1, 0);
+    /// assert_eq!(unit_e124.e2, 0);
+    /// assert_eq!(unit_e124.e3, 0);
+    /// assert_eq!(unit_e124.e4, 0);
+    /// assert_eq!(unit_e124.e12, 0);
+    /// assert_eq!(unit_e124.e13, 0);
+    /// assert_eq!(unit_e124.e14, 0);
+    /// assert_eq!(unit_e124.e23, 0);
+    /// assert_eq!(unit_e124.e24, 0);
+    /// assert_eq!(unit_e124.e34, 0);
+    /// assert_eq!(unit_e124.e123, 0);
+    /// assert_eq!(unit_e124.e124, 1);
+    /// assert_eq!(unit_e124.e134, 0);
+    /// assert_eq!(unit_e124.e234, 0);
+    /// assert_eq!(unit_e124.e1234, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e124() -> Self {
+        let mut result = Self::zero();
+        result.data[12] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `xzw`-volume trivector for four-dimensional Euclidean space.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let unit_e134: EuclideanMultivector4<isize> = EuclideanMultivector4::unit_e134();
+    ///
+    /// assert_eq!(unit_e134.scalar, 0);
+    /// assert_eq!(unit_e134.e1, 0);
+    /// assert_eq!(unit_e134.e2, 0);
+    /// assert_eq!(unit_e134.e3, 0);
+    /// assert_eq!(unit_e134.e4, 0);
+    /// assert_eq!(unit_e134.e12, 0);
+    /// assert_eq!(unit_e134.e13, 0);
+    /// assert_eq!(unit_e134.e14, 0);
+    /// assert_eq!(unit_e134.e23, 0);
+    /// assert_eq!(unit_e134.e24, 0);
+    /// assert_eq!(unit_e134.e34, 0);
+    /// assert_eq!(unit_e134.e123, 0);
+    /// assert_eq!(unit_e134.e124, 0);
+    /// assert_eq!(unit_e134.e134, 1);
+    /// assert_eq!(unit_e134.e234, 0);
+    /// assert_eq!(unit_e134.e1234, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e134() -> Self {
+        let mut result = Self::zero();
+        result.data[13] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `yzw`-volume trivector for four-dimensional Euclidean space.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let unit_e234: EuclideanMultivector4<isize> = EuclideanMultivector4::unit_e234();
+    ///
+    /// assert_eq!(unit_e234.scalar, 0);
+    /// assert_eq!(unit_e234.e1, 0);
+    /// assert_eq!(unit_e234.e2, 0);
+    /// assert_eq!(unit_e234.e3, 0);
+    /// assert_eq!(unit_e234.e4, 0);
+    /// assert_eq!(unit_e234.e12, 0);
+    /// assert_eq!(unit_e234.e13, 0);
+    /// assert_eq!(unit_e234.e14, 0);
+    /// assert_eq!(unit_e234.e23, 0);
+    /// assert_eq!(unit_e234.e24, 0);
+    /// assert_eq!(unit_e234.e34, 0);
+    /// assert_eq!(unit_e234.e123, 0);
+    /// assert_eq!(unit_e234.e124, 0);
+    /// assert_eq!(unit_e234.e134, 0);
+    /// assert_eq!(unit_e234.e234, 1);
+    /// assert_eq!(unit_e234.e1234, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e234() -> Self {
+        let mut result = Self::zero();
+        result.data[14] = S::one();
+
+        result
+    }
+
+    /// Returns the unit volume element for four-dimensional Euclidean space.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let unit_e1234: EuclideanMultivector4<isize> = EuclideanMultivector4::unit_e1234();
+    ///
+    /// assert_eq!(unit_e1234.scalar, 0);
+    /// assert_eq!(unit_e1234.e1, 0);
+    /// assert_eq!(unit_e1234.e2, 0);
+    /// assert_eq!(unit_e1234.e3, 0);
+    /// assert_eq!(unit_e1234.e4, 0);
+    /// assert_eq!(unit_e1234.e12, 0);
+    /// assert_eq!(unit_e1234.e13, 0);
+    /// assert_eq!(unit_e1234.e14, 0);
+    /// assert_eq!(unit_e1234.e23, 0);
+    /// assert_eq!(unit_e1234.e24, 0);
+    /// assert_eq!(unit_e1234.e34, 0);
+    /// assert_eq!(unit_e1234.e123, 0);
+    /// assert_eq!(unit_e1234.e124, 0);
+    /// assert_eq!(unit_e1234.e134, 0);
+    /// assert_eq!(unit_e1234.e234, 0);
+    /// assert_eq!(unit_e1234.e1234, 1);
+    /// ```
+    #[inline]
+    pub fn unit_e1234() -> Self {
+        let mut result = Self::zero();
+        result.data[15] = S::one();
+
+        result
+    }
+
+    /// Returns the unit volume element for four-dimensional Euclidean space.
+    ///
+    /// This is a synonym for `unit_e1234`.
+    #[inline(always)]
+    pub fn pseudoscalar() -> Self {
+        Self::unit_e1234()
+    }
+
+    /// Project the multivector onto the grade `grade`.
+    ///
+    /// Return a multivector where the components of each grade other than
+    /// input grade are zero. For each grade larger than the dimension of the
+    /// underlying vector space, the grade projection is always zero. In this
+    /// case, any grade projection onto a grade larger than 4 will be zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let mv: EuclideanMultivector4<isize> = EuclideanMultivector4::new(1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize);
+    /// let expected_0 = EuclideanMultivector4::new(1_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize);
+    /// let mv_0 = mv.grade(0);
+    /// let expected_1 = EuclideanMultivector4::new(0_isize, 1_isize, 1_isize, 1_isize, 1_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize);
+    /// let mv_1 = mv.grade(1);
+    /// let expected_2 = EuclideanMultivector4::new(0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize);
+    /// let mv_2 = mv.grade(2);
+    /// let expected_3 = EuclideanMultivector4::new(0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 1_isize, 1_isize, 1_isize, 1_isize, 0_isize);
+    /// let mv_3 = mv.grade(3);
+    /// let expected_4 = EuclideanMultivector4::new(0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 1_isize);
+    /// let mv_4 = mv.grade(4);
+    ///
+    /// assert_eq!(mv_0, expected_0);
+    /// assert_eq!(mv_1, expected_1);
+    /// assert_eq!(mv_2, expected_2);
+    /// assert_eq!(mv_3, expected_3);
+    /// assert_eq!(mv_4, expected_4);
+    ///
+    /// // Any grade larger than 4 should be zero.
+    /// let zero: EuclideanMultivector4<isize> = EuclideanMultivector4::zero();
+    /// assert_eq!(mv.grade(5), zero);
+    /// assert_eq!(mv.grade(usize::MAX), zero);
+    /// ```
+    #[inline]
+    pub fn grade(&self, grade: usize) -> Self {
+        let mut result = Self::zero();
+        match grade {
+            0 => {
+                result.data[0] = self.data[0];
+            }
+            1 => {
+                result.data[1] = self.data[1];
+                result.data[2] = self.data[2];
+                result.data[3] = self.data[3];
+                result.data[4] = self.data[4];
+            }
+            2 => {
+                result.data[5] = self.data[5];
+                result.data[6] = self.data[6];
+                result.data[7] = self.data[7];
+                result.data[8] = self.data[8];
+                result.data[9] = self.data[9];
+                result.data[10] = self.data[10];
+            }
+            3 => {
+                result.data[11] = self.data[11];
+                result.data[12] = self.data[12];
+                result.data[13] = self.data[13];
+                result.data[14] = self.data[14];
+            }
+            4 => {
+                result.data[15] = self.data[15];
+            }
+            _ => {}
+        }
+
+        result
+    }
+
+    /// Compute the left contraction of `self` with `other`.
+    ///
+    /// This is a synonym for the `<<` operator.
+    #[inline]
+    pub fn left_contract(&self, other: &Self) -> Self {
+        self << other
+    }
+
+    /// Compute the right contraction of `self` with `other`.
+    ///
+    /// This is a synonym for the `>>` operator.
+    #[inline]
+    pub fn right_contract(&self, other: &Self) -> Self {
+        self >> other
+    }
+
+    /// Compute the scalar product of `self` and `other`.
+    ///
+    /// This is a synonym for the `|` operator.
+    #[inline]
+    pub fn scalar_product(&self, other: &Self) -> Self {
+        self | other
+    }
+
+    /// Compute the outer product of `self` and `other`.
+    ///
+    /// This is a synonym for the `^` operator.
+    #[inline]
+    pub fn outer_product(&self, other: &Self) -> Self {
+        self ^ other
+    }
+
+    /// Compute the regressive product of `self` and `other`.
+    ///
+    /// The regressive product is the outer product of the duals, mapped back
+    /// by the inverse of the duality map
+    /// ```text
+    /// mv1 & mv2 := undual(dual(mv1) ^ dual(mv2))
+    /// ```
+    /// where `undual(mv) := mv * e1234 == dual(mv)`. For blades whose
+    /// subspaces together span the whole space, the regressive product
+    /// computes the intersection of the subspaces.
+    ///
+    /// This is a synonym for the `&` operator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let e123: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e123();
+    /// let e234: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e234();
+    /// let expected = -EuclideanMultivector4::unit_e23();
+    /// let result = e123.regressive_product(&e234);
+    ///
+    /// assert_eq!(result, expected);
+    /// assert_eq!(result, (e123.dual() ^ e234.dual()).undual());
+    /// ```
+    #[inline]
+    pub fn regressive_product(&self, other: &Self) -> Self {
+        self & other
+    }
+}
+
+impl<S> ops::Index<usize> for EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = S;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.data[index]
+    }
+}
+
+impl<S> ops::IndexMut<usize> for EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.data[index]
+    }
+}
+
+impl<S> AsRef<[S; 16]> for EuclideanMultivector4<S> {
+    #[inline]
+    fn as_ref(&self) -> &[S; 16] {
+        unsafe { &*(self as *const EuclideanMultivector4<S> as *const [S; 16]) }
+    }
+}
+
+impl<S> AsMut<[S; 16]> for EuclideanMultivector4<S> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [S; 16] {
+        unsafe { &mut *(self as *mut EuclideanMultivector4<S> as *mut [S; 16]) }
+    }
+}
+
+impl<S> fmt::Display for EuclideanMultivector4<S>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "{} + {}^e1 + {}^e2 + {}^e3 + {}^e4 + {}^e12 + {}^e13 + {}^e14 + {}^e23 + {}^e24 + {}^e34 + {}^e123 + {}^e124 + {}^e134 + {}^e234 + {}^e1234",
+            self.data[0],
+            self.data[1],
+            self.data[2],
+            self.data[3],
+            self.data[4],
+            self.data[5],
+            self.data[6],
+            self.data[7],
+            self.data[8],
+            self.data[9],
+            self.data[10],
+            self.data[11],
+            self.data[12],
+            self.data[13],
+            self.data[14],
+            self.data[15],
+        )
+    }
+}
+
+
+impl<S> EuclideanMultivector4<S>
+where
+    S: ScalarSigned,
+{
+    /// Compute the reverse of a multivector.
+    ///
+    /// The reverse of a multivector reverses the order of the vectors in each
+    /// basis blade. For each grade of multivector it is given by
+    /// ```text
+    /// When mv has grade 0, rev(mv) := mv
+    /// When mv has grade 1, rev(mv) := mv
+    /// When mv has grade 2, rev(mv) := -mv
+    /// When mv has grade 3, rev(mv) := -mv
+    /// When mv has grade 4, rev(mv) := mv
+    /// ```
+    /// The reverse of each basis blade is
+    /// ```text
+    /// rev(1)     = 1
+    /// rev(e1)    = e1
+    /// rev(e2)    = e2
+    /// rev(e3)    = e3
+    /// rev(e4)    = e4
+    /// rev(e12)   = -e12
+    /// rev(e13)   = -e13
+    /// rev(e14)   = -e14
+    /// rev(e23)   = -e23
+    /// rev(e24)   = -e24
+    /// rev(e34)   = -e34
+    /// rev(e123)  = -e123
+    /// rev(e124)  = -e124
+    /// rev(e134)  = -e134
+    /// rev(e234)  = -e234
+    /// rev(e1234) = e1234
+    /// ```
+    /// and the reverse of a general multivector follows by linearity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let mv = EuclideanMultivector4::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = EuclideanMultivector4::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, -6_i32, -7_i32, -8_i32,
+    ///     -9_i32, -10_i32, -11_i32, -12_i32, -13_i32, -14_i32, -15_i32, 16_i32
+    /// );
+    /// let result = mv.reverse();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn reverse(&self) -> Self {
+        let mut result = *self;
+        result.reverse_mut();
+
+        result
+    }
+
+    /// Compute the reverse of a multivector mutably in place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let mut result = EuclideanMultivector4::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = EuclideanMultivector4::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, -6_i32, -7_i32, -8_i32,
+    ///     -9_i32, -10_i32, -11_i32, -12_i32, -13_i32, -14_i32, -15_i32, 16_i32
+    /// );
+    /// result.reverse_mut();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn reverse_mut(&mut self) {
+        self.data[5] = -self.data[5];
+        self.data[6] = -self.data[6];
+        self.data[7] = -self.data[7];
+        self.data[8] = -self.data[8];
+        self.data[9] = -self.data[9];
+        self.data[10] = -self.data[10];
+        self.data[11] = -self.data[11];
+        self.data[12] = -self.data[12];
+        self.data[13] = -self.data[13];
+        self.data[14] = -self.data[14];
+    }
+
+    /// Compute the conjugate of a multivector.
+    ///
+    /// The conjugate of a multivector is the composition of the reverse and the
+    /// grade involution. For each grade of multivector it is given by
+    /// ```text
+    /// When mv has grade 0, conj(mv) := mv
+    /// When mv has grade 1, conj(mv) := -mv
+    /// When mv has grade 2, conj(mv) := -mv
+    /// When mv has grade 3, conj(mv) := mv
+    /// When mv has grade 4, conj(mv) := mv
+    /// ```
+    /// The conjugate of each basis blade is
+    /// ```text
+    /// conj(1)     = 1
+    /// conj(e1)    = -e1
+    /// conj(e2)    = -e2
+    /// conj(e3)    = -e3
+    /// conj(e4)    = -e4
+    /// conj(e12)   = -e12
+    /// conj(e13)   = -e13
+    /// conj(e14)   = -e14
+    /// conj(e23)   = -e23
+    /// conj(e24)   = -e24
+    /// conj(e34)   = -e34
+    /// conj(e123)  = e123
+    /// conj(e124)  = e124
+    /// conj(e134)  = e134
+    /// conj(e234)  = e234
+    /// conj(e1234) = e1234
+    /// ```
+    /// and the conjugate of a general multivector follows by linearity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let mv = EuclideanMultivector4::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = EuclideanMultivector4::new(
+    ///     1_i32, -2_i32, -3_i32, -4_i32, -5_i32, -6_i32, -7_i32, -8_i32,
+    ///     -9_i32, -10_i32, -11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let result = mv.conjugate();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn conjugate(&self) -> Self {
+        let mut result = *self;
+        result.conjugate_mut();
+
+        result
+    }
+
+    /// Compute the conjugate of a multivector mutably in place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let mut result = EuclideanMultivector4::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = EuclideanMultivector4::new(
+    ///     1_i32, -2_i32, -3_i32, -4_i32, -5_i32, -6_i32, -7_i32, -8_i32,
+    ///     -9_i32, -10_i32, -11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// result.conjugate_mut();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn conjugate_mut(&mut self) {
+        self.data[1] = -self.data[1];
+        self.data[2] = -self.data[2];
+        self.data[3] = -self.data[3];
+        self.data[4] = -self.data[4];
+        self.data[5] = -self.data[5];
+        self.data[6] = -self.data[6];
+        self.data[7] = -self.data[7];
+        self.data[8] = -self.data[8];
+        self.data[9] = -self.data[9];
+        self.data[10] = -self.data[10];
+    }
+
+    /// Compute the grade involution of a multivector.
+    ///
+    /// The grade involution of a multivector negates the components of odd grade.
+    /// For each grade of multivector it is given by
+    /// ```text
+    /// When mv has grade 0, invol(mv) := mv
+    /// When mv has grade 1, invol(mv) := -mv
+    /// When mv has grade 2, invol(mv) := mv
+    /// When mv has grade 3, invol(mv) := -mv
+    /// When mv has grade 4, invol(mv) := mv
+    /// ```
+    /// The grade involution of each basis blade is
+    /// ```text
+    /// invol(1)     = 1
+    /// invol(e1)    = -e1
+    /// invol(e2)    = -e2
+    /// invol(e3)    = -e3
+    /// invol(e4)    = -e4
+    /// invol(e12)   = e12
+    /// invol(e13)   = e13
+    /// invol(e14)   = e14
+    /// invol(e23)   = e23
+    /// invol(e24)   = e24
+    /// invol(e34)   = e34
+    /// invol(e123)  = -e123
+    /// invol(e124)  = -e124
+    /// invol(e134)  = -e134
+    /// invol(e234)  = -e234
+    /// invol(e1234) = e1234
+    /// ```
+    /// and the grade involution of a general multivector follows by linearity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let mv = EuclideanMultivector4::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = EuclideanMultivector4::new(
+    ///     1_i32, -2_i32, -3_i32, -4_i32, -5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, -12_i32, -13_i32, -14_i32, -15_i32, 16_i32
+    /// );
+    /// let result = mv.involute();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn involute(&self) -> Self {
+        let mut result = *self;
+        result.involute_mut();
+
+        result
+    }
+
+    /// Compute the grade involution of a multivector mutably in place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let mut result = EuclideanMultivector4::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = EuclideanMultivector4::new(
+    ///     1_i32, -2_i32, -3_i32, -4_i32, -5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, -12_i32, -13_i32, -14_i32, -15_i32, 16_i32
+    /// );
+    /// result.involute_mut();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn involute_mut(&mut self) {
+        self.data[1] = -self.data[1];
+        self.data[2] = -self.data[2];
+        self.data[3] = -self.data[3];
+        self.data[4] = -self.data[4];
+        self.data[11] = -self.data[11];
+        self.data[12] = -self.data[12];
+        self.data[13] = -self.data[13];
+        self.data[14] = -self.data[14];
+    }
+
+    /// Compute the dual of a multivector.
+    ///
+    /// The dual of a multivector is also known as the orthogonal complement.
+    /// The dual of a multivector `mv` is defined by
+    /// ```text
+    /// dual(mv) := mv * inv(e1234)
+    /// ```
+    /// where `inv(e1234) == e1234`. The dual of a blade is a blade representing
+    /// the orthogonal complement of its subspace. Because the pseudoscalar squares
+    /// to one in four dimensions, the dual coincides with the undual.
+    ///
+    /// The dual of each basis blade is
+    /// ```text
+    /// dual(1)     = e1234
+    /// dual(e1)    = e234
+    /// dual(e2)    = -e134
+    /// dual(e3)    = e124
+    /// dual(e4)    = -e123
+    /// dual(e12)   = -e34
+    /// dual(e13)   = e24
+    /// dual(e14)   = -e23
+    /// dual(e23)   = -e14
+    /// dual(e24)   = e13
+    /// dual(e34)   = -e12
+    /// dual(e123)  = -e4
+    /// dual(e124)  = e3
+    /// dual(e134)  = -e2
+    /// dual(e234)  = e1
+    /// dual(e1234) = 1
+    /// ```
+    /// and the dual of a general multivector follows by linearity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let mv = EuclideanMultivector4::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = EuclideanMultivector4::new(
+    ///     16_i32, 15_i32, -14_i32, 13_i32, -12_i32, -11_i32, 10_i32, -9_i32,
+    ///     -8_i32, 7_i32, -6_i32, -5_i32, 4_i32, -3_i32, 2_i32, 1_i32
+    /// );
+    /// let result = mv.dual();
+    ///
+    /// assert_eq!(result, expected);
+    /// assert_eq!(result.undual(), mv);
+    /// ```
+    #[rustfmt::skip]
+    pub fn dual(&self) -> Self {
+        let mut result = Self::zero();
+        result.data[0]  =  self.data[15];
+        result.data[1]  =  self.data[14];
+        result.data[2]  = -self.data[13];
+        result.data[3]  =  self.data[12];
+        result.data[4]  = -self.data[11];
+        result.data[5]  = -self.data[10];
+        result.data[6]  =  self.data[9];
+        result.data[7]  = -self.data[8];
+        result.data[8]  = -self.data[7];
+        result.data[9]  =  self.data[6];
+        result.data[10] = -self.data[5];
+        result.data[11] = -self.data[4];
+        result.data[12] =  self.data[3];
+        result.data[13] = -self.data[2];
+        result.data[14] =  self.data[1];
+        result.data[15] =  self.data[0];
+
+        result
+    }
+
+    /// Compute the dual of a multivector mutably in place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let mut result = EuclideanMultivector4::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = EuclideanMultivector4::new(
+    ///     16_i32, 15_i32, -14_i32, 13_i32, -12_i32, -11_i32, 10_i32, -9_i32,
+    ///     -8_i32, 7_i32, -6_i32, -5_i32, 4_i32, -3_i32, 2_i32, 1_i32
+    /// );
+    /// result.dual_mut();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn dual_mut(&mut self) {
+        *self = self.dual();
+    }
+
+    /// Compute the undual of a multivector.
+    ///
+    /// The undual is the inverse of the dual, i.e. `undual(dual(mv)) == mv`
+    /// and `dual(undual(mv)) == mv` for every multivector `mv`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let mv = EuclideanMultivector4::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    ///
+    /// assert_eq!(mv.dual().undual(), mv);
+    /// assert_eq!(mv.undual().dual(), mv);
+    /// ```
+    #[rustfmt::skip]
+    pub fn undual(&self) -> Self {
+        let mut result = Self::zero();
+        result.data[0]  =  self.data[15];
+        result.data[1]  =  self.data[14];
+        result.data[2]  = -self.data[13];
+        result.data[3]  =  self.data[12];
+        result.data[4]  = -self.data[11];
+        result.data[5]  = -self.data[10];
+        result.data[6]  =  self.data[9];
+        result.data[7]  = -self.data[8];
+        result.data[8]  = -self.data[7];
+        result.data[9]  =  self.data[6];
+        result.data[10] = -self.data[5];
+        result.data[11] = -self.data[4];
+        result.data[12] =  self.data[3];
+        result.data[13] = -self.data[2];
+        result.data[14] =  self.data[1];
+        result.data[15] =  self.data[0];
+
+        result
+    }
+
+    /// Construct the inverse pseudoscalar of the geometric algebra.
+    ///
+    /// In the case of the four-dimensional Euclidean geometric algebra, the
+    /// inverse of the pseudoscalar is the four-blade `inv(e1234) = e1234`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let ps: EuclideanMultivector4<f64> = EuclideanMultivector4::pseudoscalar();
+    /// let ps_inv: EuclideanMultivector4<f64> = EuclideanMultivector4::inv_pseudoscalar();
+    /// let one: EuclideanMultivector4<f64> = EuclideanMultivector4::unit_scalar();
+    ///
+    /// assert_eq!(ps * ps_inv, one);
+    /// assert_eq!(ps_inv * ps, one);
+    /// ```
+    #[inline]
+    pub fn inv_pseudoscalar() -> Self {
+        Self::unit_e1234()
+    }
+}
+
+impl<S> ops::Not for EuclideanMultivector4<S>
+where
+    S: ScalarSigned,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        self.dual()
+    }
+}
+
+impl<S> ops::Not for &EuclideanMultivector4<S>
+where
+    S: ScalarSigned,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        self.dual()
+    }
+}
+
+impl<S> ops::Neg for EuclideanMultivector4<S>
+where
+    S: ScalarSigned,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn neg(self) -> Self::Output {
+        let result_1     = -self.data[0];
+        let result_e1    = -self.data[1];
+        let result_e2    = -self.data[2];
+        let result_e3    = -self.data[3];
+        let result_e4    = -self.data[4];
+        let result_e12   = -self.data[5];
+        let result_e13   = -self.data[6];
+        let result_e14   = -self.data[7];
+        let result_e23   = -self.data[8];
+        let result_e24   = -self.data[9];
+        let result_e34   = -self.data[10];
+        let result_e123  = -self.data[11];
+        let result_e124  = -self.data[12];
+        let result_e134  = -self.data[13];
+        let result_e234  = -self.data[14];
+        let result_e1234 = -self.data[15];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::Neg for &EuclideanMultivector4<S>
+where
+    S: ScalarSigned,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn neg(self) -> Self::Output {
+        let result_1     = -self.data[0];
+        let result_e1    = -self.data[1];
+        let result_e2    = -self.data[2];
+        let result_e3    = -self.data[3];
+        let result_e4    = -self.data[4];
+        let result_e12   = -self.data[5];
+        let result_e13   = -self.data[6];
+        let result_e14   = -self.data[7];
+        let result_e23   = -self.data[8];
+        let result_e24   = -self.data[9];
+        let result_e34   = -self.data[10];
+        let result_e123  = -self.data[11];
+        let result_e124  = -self.data[12];
+        let result_e134  = -self.data[13];
+        let result_e234  = -self.data[14];
+        let result_e1234 = -self.data[15];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::Mul<EuclideanMultivector4<S>> for EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: EuclideanMultivector4<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[5] * b[5] - a[6] * b[6] - a[7] * b[7] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[12] - a[13] * b[13] - a[14] * b[14] + a[15] * b[15];
+        let result_e1    = a[0] * b[1] + a[1] * b[0] - a[2] * b[5] - a[3] * b[6] - a[4] * b[7] + a[5] * b[2] + a[6] * b[3] + a[7] * b[4] - a[8] * b[11] - a[9] * b[12] - a[10] * b[13] - a[11] * b[8] - a[12] * b[9] - a[13] * b[10] + a[14] * b[15] - a[15] * b[14];
+        let result_e2    = a[0] * b[2] + a[1] * b[5] + a[2] * b[0] - a[3] * b[8] - a[4] * b[9] - a[5] * b[1] + a[6] * b[11] + a[7] * b[12] + a[8] * b[3] + a[9] * b[4] - a[10] * b[14] + a[11] * b[6] + a[12] * b[7] - a[13] * b[15] - a[14] * b[10] + a[15] * b[13];
+        let result_e3    = a[0] * b[3] + a[1] * b[6] + a[2] * b[8] + a[3] * b[0] - a[4] * b[10] - a[5] * b[11] - a[6] * b[1] + a[7] * b[13] - a[8] * b[2] + a[9] * b[14] + a[10] * b[4] - a[11] * b[5] + a[12] * b[15] + a[13] * b[7] + a[14] * b[9] - a[15] * b[12];
+        let result_e4    = a[0] * b[4] + a[1] * b[7] + a[2] * b[9] + a[3] * b[10] + a[4] * b[0] - a[5] * b[12] - a[6] * b[13] - a[7] * b[1] - a[8] * b[14] - a[9] * b[2] - a[10] * b[3] - a[11] * b[15] - a[12] * b[5] - a[13] * b[6] - a[14] * b[8] + a[15] * b[11];
+        let result_e12   = a[0] * b[5] + a[1] * b[2] - a[2] * b[1] + a[3] * b[11] + a[4] * b[12] + a[5] * b[0] - a[6] * b[8] - a[7] * b[9] + a[8] * b[6] + a[9] * b[7] - a[10] * b[15] + a[11] * b[3] + a[12] * b[4] - a[13] * b[14] + a[14] * b[13] - a[15] * b[10];
+        let result_e13   = a[0] * b[6] + a[1] * b[3] - a[2] * b[11] - a[3] * b[1] + a[4] * b[13] + a[5] * b[8] + a[6] * b[0] - a[7] * b[10] - a[8] * b[5] + a[9] * b[15] + a[10] * b[7] - a[11] * b[2] + a[12] * b[14] + a[13] * b[4] - a[14] * b[12] + a[15] * b[9];
+        let result_e14   = a[0] * b[7] + a[1] * b[4] - a[2] * b[12] - a[3] * b[13] - a[4] * b[1] + a[5] * b[9] + a[6] * b[10] + a[7] * b[0] - a[8] * b[15] - a[9] * b[5] - a[10] * b[6] - a[11] * b[14] - a[12] * b[2] - a[13] * b[3] + a[14] * b[11] - a[15] * b[8];
+        let result_e23   = a[0] * b[8] + a[1] * b[11] + a[2] * b[3] - a[3] * b[2] + a[4] * b[14] - a[5] * b[6] + a[6] * b[5] - a[7] * b[15] + a[8] * b[0] - a[9] * b[10] + a[10] * b[9] + a[11] * b[1] - a[12] * b[13] + a[13] * b[12] + a[14] * b[4] - a[15] * b[7];
+        let result_e24   = a[0] * b[9] + a[1] * b[12] + a[2] * b[4] - a[3] * b[14] - a[4] * b[2] - a[5] * b[7] + a[6] * b[15] + a[7] * b[5] + a[8] * b[10] + a[9] * b[0] - a[10] * b[8] + a[11] * b[13] + a[12] * b[1] - a[13] * b[11] - a[14] * b[3] + a[15] * b[6];
+        let result_e34   = a[0] * b[10] + a[1] * b[13] + a[2] * b[14] + a[3] * b[4] - a[4] * b[3] - a[5] * b[15] - a[6] * b[7] + a[7] * b[6] - a[8] * b[9] + a[9] * b[8] + a[10] * b[0] - a[11] * b[12] + a[12] * b[11] + a[13] * b[1] + a[14] * b[2] - a[15] * b[5];
+        let result_e123  = a[0] * b[11] + a[1] * b[8] - a[2] * b[6] + a[3] * b[5] - a[4] * b[15] + a[5] * b[3] - a[6] * b[2] + a[7] * b[14] + a[8] * b[1] - a[9] * b[13] + a[10] * b[12] + a[11] * b[0] - a[12] * b[10] + a[13] * b[9] - a[14] * b[7] + a[15] * b[4];
+        let result_e124  = a[0] * b[12] + a[1] * b[9] - a[2] * b[7] + a[3] * b[15] + a[4] * b[5] + a[5] * b[4] - a[6] * b[14] - a[7] * b[2] + a[8] * b[13] + a[9] * b[1] - a[10] * b[11] + a[11] * b[10] + a[12] * b[0] - a[13] * b[8] + a[14] * b[6] - a[15] * b[3];
+        let result_e134  = a[0] * b[13] + a[1] * b[10] - a[2] * b[15] - a[3] * b[7] + a[4] * b[6] + a[5] * b[14] + a[6] * b[4] - a[7] * b[3] - a[8] * b[12] + a[9] * b[11] + a[10] * b[1] - a[11] * b[9] + a[12] * b[8] + a[13] * b[0] - a[14] * b[5] + a[15] * b[2];
+        let result_e234  = a[0] * b[14] + a[1] * b[15] + a[2] * b[10] - a[3] * b[9] + a[4] * b[8] - a[5] * b[13] + a[6] * b[12] - a[7] * b[11] + a[8] * b[4] - a[9] * b[3] + a[10] * b[2] + a[11] * b[7] - a[12] * b[6] + a[13] * b[5] + a[14] * b[0] - a[15] * b[1];
+        let result_e1234 = a[0] * b[15] + a[1] * b[14] - a[2] * b[13] + a[3] * b[12] - a[4] * b[11] + a[5] * b[10] - a[6] * b[9] + a[7] * b[8] + a[8] * b[7] - a[9] * b[6] + a[10] * b[5] + a[11] * b[4] - a[12] * b[3] + a[13] * b[2] - a[14] * b[1] + a[15] * b[0];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::Mul<&EuclideanMultivector4<S>> for EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: &EuclideanMultivector4<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[5] * b[5] - a[6] * b[6] - a[7] * b[7] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[12] - a[13] * b[13] - a[14] * b[14] + a[15] * b[15];
+        let result_e1    = a[0] * b[1] + a[1] * b[0] - a[2] * b[5] - a[3] * b[6] - a[4] * b[7] + a[5] * b[2] + a[6] * b[3] + a[7] * b[4] - a[8] * b[11] - a[9] * b[12] - a[10] * b[13] - a[11] * b[8] - a[12] * b[9] - a[13] * b[10] + a[14] * b[15] - a[15] * b[14];
+        let result_e2    = a[0] * b[2] + a[1] * b[5] + a[2] * b[0] - a[3] * b[8] - a[4] * b[9] - a[5] * b[1] + a[6] * b[11] + a[7] * b[12] + a[8] * b[3] + a[9] * b[4] - a[10] * b[14] + a[11] * b[6] + a[12] * b[7] - a[13] * b[15] - a[14] * b[10] + a[15] * b[13];
+        let result_e3    = a[0] * b[3] + a[1] * b[6] + a[2] * b[8] + a[3] * b[0] - a[4] * b[10] - a[5] * b[11] - a[6] * b[1] + a[7] * b[13] - a[8] * b[2] + a[9] * b[14] + a[10] * b[4] - a[11] * b[5] + a[12] * b[15] + a[13] * b[7] + a[14] * b[9] - a[15] * b[12];
+        let result_e4    = a[0] * b[4] + a[1] * b[7] + a[2] * b[9] + a[3] * b[10] + a[4] * b[0] - a[5] * b[12] - a[6] * b[13] - a[7] * b[1] - a[8] * b[14] - a[9] * b[2] - a[10] * b[3] - a[11] * b[15] - a[12] * b[5] - a[13] * b[6] - a[14] * b[8] + a[15] * b[11];
+        let result_e12   = a[0] * b[5] + a[1] * b[2] - a[2] * b[1] + a[3] * b[11] + a[4] * b[12] + a[5] * b[0] - a[6] * b[8] - a[7] * b[9] + a[8] * b[6] + a[9] * b[7] - a[10] * b[15] + a[11] * b[3] + a[12] * b[4] - a[13] * b[14] + a[14] * b[13] - a[15] * b[10];
+        let result_e13   = a[0] * b[6] + a[1] * b[3] - a[2] * b[11] - a[3] * b[1] + a[4] * b[13] + a[5] * b[8] + a[6] * b[0] - a[7] * b[10] - a[8] * b[5] + a[9] * b[15] + a[10] * b[7] - a[11] * b[2] + a[12] * b[14] + a[13] * b[4] - a[14] * b[12] + a[15] * b[9];
+        let result_e14   = a[0] * b[7] + a[1] * b[4] - a[2] * b[12] - a[3] * b[13] - a[4] * b[1] + a[5] * b[9] + a[6] * b[10] + a[7] * b[0] - a[8] * b[15] - a[9] * b[5] - a[10] * b[6] - a[11] * b[14] - a[12] * b[2] - a[13] * b[3] + a[14] * b[11] - a[15] * b[8];
+        let result_e23   = a[0] * b[8] + a[1] * b[11] + a[2] * b[3] - a[3] * b[2] + a[4] * b[14] - a[5] * b[6] + a[6] * b[5] - a[7] * b[15] + a[8] * b[0] - a[9] * b[10] + a[10] * b[9] + a[11] * b[1] - a[12] * b[13] + a[13] * b[12] + a[14] * b[4] - a[15] * b[7];
+        let result_e24   = a[0] * b[9] + a[1] * b[12] + a[2] * b[4] - a[3] * b[14] - a[4] * b[2] - a[5] * b[7] + a[6] * b[15] + a[7] * b[5] + a[8] * b[10] + a[9] * b[0] - a[10] * b[8] + a[11] * b[13] + a[12] * b[1] - a[13] * b[11] - a[14] * b[3] + a[15] * b[6];
+        let result_e34   = a[0] * b[10] + a[1] * b[13] + a[2] * b[14] + a[3] * b[4] - a[4] * b[3] - a[5] * b[15] - a[6] * b[7] + a[7] * b[6] - a[8] * b[9] + a[9] * b[8] + a[10] * b[0] - a[11] * b[12] + a[12] * b[11] + a[13] * b[1] + a[14] * b[2] - a[15] * b[5];
+        let result_e123  = a[0] * b[11] + a[1] * b[8] - a[2] * b[6] + a[3] * b[5] - a[4] * b[15] + a[5] * b[3] - a[6] * b[2] + a[7] * b[14] + a[8] * b[1] - a[9] * b[13] + a[10] * b[12] + a[11] * b[0] - a[12] * b[10] + a[13] * b[9] - a[14] * b[7] + a[15] * b[4];
+        let result_e124  = a[0] * b[12] + a[1] * b[9] - a[2] * b[7] + a[3] * b[15] + a[4] * b[5] + a[5] * b[4] - a[6] * b[14] - a[7] * b[2] + a[8] * b[13] + a[9] * b[1] - a[10] * b[11] + a[11] * b[10] + a[12] * b[0] - a[13] * b[8] + a[14] * b[6] - a[15] * b[3];
+        let result_e134  = a[0] * b[13] + a[1] * b[10] - a[2] * b[15] - a[3] * b[7] + a[4] * b[6] + a[5] * b[14] + a[6] * b[4] - a[7] * b[3] - a[8] * b[12] + a[9] * b[11] + a[10] * b[1] - a[11] * b[9] + a[12] * b[8] + a[13] * b[0] - a[14] * b[5] + a[15] * b[2];
+        let result_e234  = a[0] * b[14] + a[1] * b[15] + a[2] * b[10] - a[3] * b[9] + a[4] * b[8] - a[5] * b[13] + a[6] * b[12] - a[7] * b[11] + a[8] * b[4] - a[9] * b[3] + a[10] * b[2] + a[11] * b[7] - a[12] * b[6] + a[13] * b[5] + a[14] * b[0] - a[15] * b[1];
+        let result_e1234 = a[0] * b[15] + a[1] * b[14] - a[2] * b[13] + a[3] * b[12] - a[4] * b[11] + a[5] * b[10] - a[6] * b[9] + a[7] * b[8] + a[8] * b[7] - a[9] * b[6] + a[10] * b[5] + a[11] * b[4] - a[12] * b[3] + a[13] * b[2] - a[14] * b[1] + a[15] * b[0];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::Mul<EuclideanMultivector4<S>> for &EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: EuclideanMultivector4<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[5] * b[5] - a[6] * b[6] - a[7] * b[7] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[12] - a[13] * b[13] - a[14] * b[14] + a[15] * b[15];
+        let result_e1    = a[0] * b[1] + a[1] * b[0] - a[2] * b[5] - a[3] * b[6] - a[4] * b[7] + a[5] * b[2] + a[6] * b[3] + a[7] * b[4] - a[8] * b[11] - a[9] * b[12] - a[10] * b[13] - a[11] * b[8] - a[12] * b[9] - a[13] * b[10] + a[14] * b[15] - a[15] * b[14];
+        let result_e2    = a[0] * b[2] + a[1] * b[5] + a[2] * b[0] - a[3] * b[8] - a[4] * b[9] - a[5] * b[1] + a[6] * b[11] + a[7] * b[12] + a[8] * b[3] + a[9] * b[4] - a[10] * b[14] + a[11] * b[6] + a[12] * b[7] - a[13] * b[15] - a[14] * b[10] + a[15] * b[13];
+        let result_e3    = a[0] * b[3] + a[1] * b[6] + a[2] * b[8] + a[3] * b[0] - a[4] * b[10] - a[5] * b[11] - a[6] * b[1] + a[7] * b[13] - a[8] * b[2] + a[9] * b[14] + a[10] * b[4] - a[11] * b[5] + a[12] * b[15] + a[13] * b[7] + a[14] * b[9] - a[15] * b[12];
+        let result_e4    = a[0] * b[4] + a[1] * b[7] + a[2] * b[9] + a[3] * b[10] + a[4] * b[0] - a[5] * b[12] - a[6] * b[13] - a[7] * b[1] - a[8] * b[14] - a[9] * b[2] - a[10] * b[3] - a[11] * b[15] - a[12] * b[5] - a[13] * b[6] - a[14] * b[8] + a[15] * b[11];
+        let result_e12   = a[0] * b[5] + a[1] * b[2] - a[2] * b[1] + a[3] * b[11] + a[4] * b[12] + a[5] * b[0] - a[6] * b[8] - a[7] * b[9] + a[8] * b[6] + a[9] * b[7] - a[10] * b[15] + a[11] * b[3] + a[12] * b[4] - a[13] * b[14] + a[14] * b[13] - a[15] * b[10];
+        let result_e13   = a[0] * b[6] + a[1] * b[3] - a[2] * b[11] - a[3] * b[1] + a[4] * b[13] + a[5] * b[8] + a[6] * b[0] - a[7] * b[10] - a[8] * b[5] + a[9] * b[15] + a[10] * b[7] - a[11] * b[2] + a[12] * b[14] + a[13] * b[4] - a[14] * b[12] + a[15] * b[9];
+        let result_e14   = a[0] * b[7] + a[1] * b[4] - a[2] * b[12] - a[3] * b[13] - a[4] * b[1] + a[5] * b[9] + a[6] * b[10] + a[7] * b[0] - a[8] * b[15] - a[9] * b[5] - a[10] * b[6] - a[11] * b[14] - a[12] * b[2] - a[13] * b[3] + a[14] * b[11] - a[15] * b[8];
+        let result_e23   = a[0] * b[8] + a[1] * b[11] + a[2] * b[3] - a[3] * b[2] + a[4] * b[14] - a[5] * b[6] + a[6] * b[5] - a[7] * b[15] + a[8] * b[0] - a[9] * b[10] + a[10] * b[9] + a[11] * b[1] - a[12] * b[13] + a[13] * b[12] + a[14] * b[4] - a[15] * b[7];
+        let result_e24   = a[0] * b[9] + a[1] * b[12] + a[2] * b[4] - a[3] * b[14] - a[4] * b[2] - a[5] * b[7] + a[6] * b[15] + a[7] * b[5] + a[8] * b[10] + a[9] * b[0] - a[10] * b[8] + a[11] * b[13] + a[12] * b[1] - a[13] * b[11] - a[14] * b[3] + a[15] * b[6];
+        let result_e34   = a[0] * b[10] + a[1] * b[13] + a[2] * b[14] + a[3] * b[4] - a[4] * b[3] - a[5] * b[15] - a[6] * b[7] + a[7] * b[6] - a[8] * b[9] + a[9] * b[8] + a[10] * b[0] - a[11] * b[12] + a[12] * b[11] + a[13] * b[1] + a[14] * b[2] - a[15] * b[5];
+        let result_e123  = a[0] * b[11] + a[1] * b[8] - a[2] * b[6] + a[3] * b[5] - a[4] * b[15] + a[5] * b[3] - a[6] * b[2] + a[7] * b[14] + a[8] * b[1] - a[9] * b[13] + a[10] * b[12] + a[11] * b[0] - a[12] * b[10] + a[13] * b[9] - a[14] * b[7] + a[15] * b[4];
+        let result_e124  = a[0] * b[12] + a[1] * b[9] - a[2] * b[7] + a[3] * b[15] + a[4] * b[5] + a[5] * b[4] - a[6] * b[14] - a[7] * b[2] + a[8] * b[13] + a[9] * b[1] - a[10] * b[11] + a[11] * b[10] + a[12] * b[0] - a[13] * b[8] + a[14] * b[6] - a[15] * b[3];
+        let result_e134  = a[0] * b[13] + a[1] * b[10] - a[2] * b[15] - a[3] * b[7] + a[4] * b[6] + a[5] * b[14] + a[6] * b[4] - a[7] * b[3] - a[8] * b[12] + a[9] * b[11] + a[10] * b[1] - a[11] * b[9] + a[12] * b[8] + a[13] * b[0] - a[14] * b[5] + a[15] * b[2];
+        let result_e234  = a[0] * b[14] + a[1] * b[15] + a[2] * b[10] - a[3] * b[9] + a[4] * b[8] - a[5] * b[13] + a[6] * b[12] - a[7] * b[11] + a[8] * b[4] - a[9] * b[3] + a[10] * b[2] + a[11] * b[7] - a[12] * b[6] + a[13] * b[5] + a[14] * b[0] - a[15] * b[1];
+        let result_e1234 = a[0] * b[15] + a[1] * b[14] - a[2] * b[13] + a[3] * b[12] - a[4] * b[11] + a[5] * b[10] - a[6] * b[9] + a[7] * b[8] + a[8] * b[7] - a[9] * b[6] + a[10] * b[5] + a[11] * b[4] - a[12] * b[3] + a[13] * b[2] - a[14] * b[1] + a[15] * b[0];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::Mul<&EuclideanMultivector4<S>> for &EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: &EuclideanMultivector4<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[5] * b[5] - a[6] * b[6] - a[7] * b[7] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[12] - a[13] * b[13] - a[14] * b[14] + a[15] * b[15];
+        let result_e1    = a[0] * b[1] + a[1] * b[0] - a[2] * b[5] - a[3] * b[6] - a[4] * b[7] + a[5] * b[2] + a[6] * b[3] + a[7] * b[4] - a[8] * b[11] - a[9] * b[12] - a[10] * b[13] - a[11] * b[8] - a[12] * b[9] - a[13] * b[10] + a[14] * b[15] - a[15] * b[14];
+        let result_e2    = a[0] * b[2] + a[1] * b[5] + a[2] * b[0] - a[3] * b[8] - a[4] * b[9] - a[5] * b[1] + a[6] * b[11] + a[7] * b[12] + a[8] * b[3] + a[9] * b[4] - a[10] * b[14] + a[11] * b[6] + a[12] * b[7] - a[13] * b[15] - a[14] * b[10] + a[15] * b[13];
+        let result_e3    = a[0] * b[3] + a[1] * b[6] + a[2] * b[8] + a[3] * b[0] - a[4] * b[10] - a[5] * b[11] - a[6] * b[1] + a[7] * b[13] - a[8] * b[2] + a[9] * b[14] + a[10] * b[4] - a[11] * b[5] + a[12] * b[15] + a[13] * b[7] + a[14] * b[9] - a[15] * b[12];
+        let result_e4    = a[0] * b[4] + a[1] * b[7] + a[2] * b[9] + a[3] * b[10] + a[4] * b[0] - a[5] * b[12] - a[6] * b[13] - a[7] * b[1] - a[8] * b[14] - a[9] * b[2] - a[10] * b[3] - a[11] * b[15] - a[12] * b[5] - a[13] * b[6] - a[14] * b[8] + a[15] * b[11];
+        let result_e12   = a[0] * b[5] + a[1] * b[2] - a[2] * b[1] + a[3] * b[11] + a[4] * b[12] + a[5] * b[0] - a[6] * b[8] - a[7] * b[9] + a[8] * b[6] + a[9] * b[7] - a[10] * b[15] + a[11] * b[3] + a[12] * b[4] - a[13] * b[14] + a[14] * b[13] - a[15] * b[10];
+        let result_e13   = a[0] * b[6] + a[1] * b[3] - a[2] * b[11] - a[3] * b[1] + a[4] * b[13] + a[5] * b[8] + a[6] * b[0] - a[7] * b[10] - a[8] * b[5] + a[9] * b[15] + a[10] * b[7] - a[11] * b[2] + a[12] * b[14] + a[13] * b[4] - a[14] * b[12] + a[15] * b[9];
+        let result_e14   = a[0] * b[7] + a[1] * b[4] - a[2] * b[12] - a[3] * b[13] - a[4] * b[1] + a[5] * b[9] + a[6] * b[10] + a[7] * b[0] - a[8] * b[15] - a[9] * b[5] - a[10] * b[6] - a[11] * b[14] - a[12] * b[2] - a[13] * b[3] + a[14] * b[11] - a[15] * b[8];
+        let result_e23   = a[0] * b[8] + a[1] * b[11] + a[2] * b[3] - a[3] * b[2] + a[4] * b[14] - a[5] * b[6] + a[6] * b[5] - a[7] * b[15] + a[8] * b[0] - a[9] * b[10] + a[10] * b[9] + a[11] * b[1] - a[12] * b[13] + a[13] * b[12] + a[14] * b[4] - a[15] * b[7];
+        let result_e24   = a[0] * b[9] + a[1] * b[12] + a[2] * b[4] - a[3] * b[14] - a[4] * b[2] - a[5] * b[7] + a[6] * b[15] + a[7] * b[5] + a[8] * b[10] + a[9] * b[0] - a[10] * b[8] + a[11] * b[13] + a[12] * b[1] - a[13] * b[11] - a[14] * b[3] + a[15] * b[6];
+        let result_e34   = a[0] * b[10] + a[1] * b[13] + a[2] * b[14] + a[3] * b[4] - a[4] * b[3] - a[5] * b[15] - a[6] * b[7] + a[7] * b[6] - a[8] * b[9] + a[9] * b[8] + a[10] * b[0] - a[11] * b[12] + a[12] * b[11] + a[13] * b[1] + a[14] * b[2] - a[15] * b[5];
+        let result_e123  = a[0] * b[11] + a[1] * b[8] - a[2] * b[6] + a[3] * b[5] - a[4] * b[15] + a[5] * b[3] - a[6] * b[2] + a[7] * b[14] + a[8] * b[1] - a[9] * b[13] + a[10] * b[12] + a[11] * b[0] - a[12] * b[10] + a[13] * b[9] - a[14] * b[7] + a[15] * b[4];
+        let result_e124  = a[0] * b[12] + a[1] * b[9] - a[2] * b[7] + a[3] * b[15] + a[4] * b[5] + a[5] * b[4] - a[6] * b[14] - a[7] * b[2] + a[8] * b[13] + a[9] * b[1] - a[10] * b[11] + a[11] * b[10] + a[12] * b[0] - a[13] * b[8] + a[14] * b[6] - a[15] * b[3];
+        let result_e134  = a[0] * b[13] + a[1] * b[10] - a[2] * b[15] - a[3] * b[7] + a[4] * b[6] + a[5] * b[14] + a[6] * b[4] - a[7] * b[3] - a[8] * b[12] + a[9] * b[11] + a[10] * b[1] - a[11] * b[9] + a[12] * b[8] + a[13] * b[0] - a[14] * b[5] + a[15] * b[2];
+        let result_e234  = a[0] * b[14] + a[1] * b[15] + a[2] * b[10] - a[3] * b[9] + a[4] * b[8] - a[5] * b[13] + a[6] * b[12] - a[7] * b[11] + a[8] * b[4] - a[9] * b[3] + a[10] * b[2] + a[11] * b[7] - a[12] * b[6] + a[13] * b[5] + a[14] * b[0] - a[15] * b[1];
+        let result_e1234 = a[0] * b[15] + a[1] * b[14] - a[2] * b[13] + a[3] * b[12] - a[4] * b[11] + a[5] * b[10] - a[6] * b[9] + a[7] * b[8] + a[8] * b[7] - a[9] * b[6] + a[10] * b[5] + a[11] * b[4] - a[12] * b[3] + a[13] * b[2] - a[14] * b[1] + a[15] * b[0];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::Mul<S> for EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1     = a[0] * other;
+        let result_e1    = a[1] * other;
+        let result_e2    = a[2] * other;
+        let result_e3    = a[3] * other;
+        let result_e4    = a[4] * other;
+        let result_e12   = a[5] * other;
+        let result_e13   = a[6] * other;
+        let result_e14   = a[7] * other;
+        let result_e23   = a[8] * other;
+        let result_e24   = a[9] * other;
+        let result_e34   = a[10] * other;
+        let result_e123  = a[11] * other;
+        let result_e124  = a[12] * other;
+        let result_e134  = a[13] * other;
+        let result_e234  = a[14] * other;
+        let result_e1234 = a[15] * other;
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::Mul<S> for &EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1     = a[0] * other;
+        let result_e1    = a[1] * other;
+        let result_e2    = a[2] * other;
+        let result_e3    = a[3] * other;
+        let result_e4    = a[4] * other;
+        let result_e12   = a[5] * other;
+        let result_e13   = a[6] * other;
+        let result_e14   = a[7] * other;
+        let result_e23   = a[8] * other;
+        let result_e24   = a[9] * other;
+        let result_e34   = a[10] * other;
+        let result_e123  = a[11] * other;
+        let result_e124  = a[12] * other;
+        let result_e134  = a[13] * other;
+        let result_e234  = a[14] * other;
+        let result_e1234 = a[15] * other;
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::BitXor<EuclideanMultivector4<S>> for EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: EuclideanMultivector4<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[0];
+        let result_e1    = a[0] * b[1] + a[1] * b[0];
+        let result_e2    = a[0] * b[2] + a[2] * b[0];
+        let result_e3    = a[0] * b[3] + a[3] * b[0];
+        let result_e4    = a[0] * b[4] + a[4] * b[0];
+        let result_e12   = a[0] * b[5] + a[1] * b[2] - a[2] * b[1] + a[5] * b[0];
+        let result_e13   = a[0] * b[6] + a[1] * b[3] - a[3] * b[1] + a[6] * b[0];
+        let result_e14   = a[0] * b[7] + a[1] * b[4] - a[4] * b[1] + a[7] * b[0];
+        let result_e23   = a[0] * b[8] + a[2] * b[3] - a[3] * b[2] + a[8] * b[0];
+        let result_e24   = a[0] * b[9] + a[2] * b[4] - a[4] * b[2] + a[9] * b[0];
+        let result_e34   = a[0] * b[10] + a[3] * b[4] - a[4] * b[3] + a[10] * b[0];
+        let result_e123  = a[0] * b[11] + a[1] * b[8] - a[2] * b[6] + a[3] * b[5] + a[5] * b[3] - a[6] * b[2] + a[8] * b[1] + a[11] * b[0];
+        let result_e124  = a[0] * b[12] + a[1] * b[9] - a[2] * b[7] + a[4] * b[5] + a[5] * b[4] - a[7] * b[2] + a[9] * b[1] + a[12] * b[0];
+        let result_e134  = a[0] * b[13] + a[1] * b[10] - a[3] * b[7] + a[4] * b[6] + a[6] * b[4] - a[7] * b[3] + a[10] * b[1] + a[13] * b[0];
+        let result_e234  = a[0] * b[14] + a[2] * b[10] - a[3] * b[9] + a[4] * b[8] + a[8] * b[4] - a[9] * b[3] + a[10] * b[2] + a[14] * b[0];
+        let result_e1234 = a[0] * b[15] + a[1] * b[14] - a[2] * b[13] + a[3] * b[12] - a[4] * b[11] + a[5] * b[10] - a[6] * b[9] + a[7] * b[8] + a[8] * b[7] - a[9] * b[6] + a[10] * b[5] + a[11] * b[4] - a[12] * b[3] + a[13] * b[2] - a[14] * b[1] + a[15] * b[0];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::BitXor<&EuclideanMultivector4<S>> for EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: &EuclideanMultivector4<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[0];
+        let result_e1    = a[0] * b[1] + a[1] * b[0];
+        let result_e2    = a[0] * b[2] + a[2] * b[0];
+        let result_e3    = a[0] * b[3] + a[3] * b[0];
+        let result_e4    = a[0] * b[4] + a[4] * b[0];
+        let result_e12   = a[0] * b[5] + a[1] * b[2] - a[2] * b[1] + a[5] * b[0];
+        let result_e13   = a[0] * b[6] + a[1] * b[3] - a[3] * b[1] + a[6] * b[0];
+        let result_e14   = a[0] * b[7] + a[1] * b[4] - a[4] * b[1] + a[7] * b[0];
+        let result_e23   = a[0] * b[8] + a[2] * b[3] - a[3] * b[2] + a[8] * b[0];
+        let result_e24   = a[0] * b[9] + a[2] * b[4] - a[4] * b[2] + a[9] * b[0];
+        let result_e34   = a[0] * b[10] + a[3] * b[4] - a[4] * b[3] + a[10] * b[0];
+        let result_e123  = a[0] * b[11] + a[1] * b[8] - a[2] * b[6] + a[3] * b[5] + a[5] * b[3] - a[6] * b[2] + a[8] * b[1] + a[11] * b[0];
+        let result_e124  = a[0] * b[12] + a[1] * b[9] - a[2] * b[7] + a[4] * b[5] + a[5] * b[4] - a[7] * b[2] + a[9] * b[1] + a[12] * b[0];
+        let result_e134  = a[0] * b[13] + a[1] * b[10] - a[3] * b[7] + a[4] * b[6] + a[6] * b[4] - a[7] * b[3] + a[10] * b[1] + a[13] * b[0];
+        let result_e234  = a[0] * b[14] + a[2] * b[10] - a[3] * b[9] + a[4] * b[8] + a[8] * b[4] - a[9] * b[3] + a[10] * b[2] + a[14] * b[0];
+        let result_e1234 = a[0] * b[15] + a[1] * b[14] - a[2] * b[13] + a[3] * b[12] - a[4] * b[11] + a[5] * b[10] - a[6] * b[9] + a[7] * b[8] + a[8] * b[7] - a[9] * b[6] + a[10] * b[5] + a[11] * b[4] - a[12] * b[3] + a[13] * b[2] - a[14] * b[1] + a[15] * b[0];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::BitXor<EuclideanMultivector4<S>> for &EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: EuclideanMultivector4<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[0];
+        let result_e1    = a[0] * b[1] + a[1] * b[0];
+        let result_e2    = a[0] * b[2] + a[2] * b[0];
+        let result_e3    = a[0] * b[3] + a[3] * b[0];
+        let result_e4    = a[0] * b[4] + a[4] * b[0];
+        let result_e12   = a[0] * b[5] + a[1] * b[2] - a[2] * b[1] + a[5] * b[0];
+        let result_e13   = a[0] * b[6] + a[1] * b[3] - a[3] * b[1] + a[6] * b[0];
+        let result_e14   = a[0] * b[7] + a[1] * b[4] - a[4] * b[1] + a[7] * b[0];
+        let result_e23   = a[0] * b[8] + a[2] * b[3] - a[3] * b[2] + a[8] * b[0];
+        let result_e24   = a[0] * b[9] + a[2] * b[4] - a[4] * b[2] + a[9] * b[0];
+        let result_e34   = a[0] * b[10] + a[3] * b[4] - a[4] * b[3] + a[10] * b[0];
+        let result_e123  = a[0] * b[11] + a[1] * b[8] - a[2] * b[6] + a[3] * b[5] + a[5] * b[3] - a[6] * b[2] + a[8] * b[1] + a[11] * b[0];
+        let result_e124  = a[0] * b[12] + a[1] * b[9] - a[2] * b[7] + a[4] * b[5] + a[5] * b[4] - a[7] * b[2] + a[9] * b[1] + a[12] * b[0];
+        let result_e134  = a[0] * b[13] + a[1] * b[10] - a[3] * b[7] + a[4] * b[6] + a[6] * b[4] - a[7] * b[3] + a[10] * b[1] + a[13] * b[0];
+        let result_e234  = a[0] * b[14] + a[2] * b[10] - a[3] * b[9] + a[4] * b[8] + a[8] * b[4] - a[9] * b[3] + a[10] * b[2] + a[14] * b[0];
+        let result_e1234 = a[0] * b[15] + a[1] * b[14] - a[2] * b[13] + a[3] * b[12] - a[4] * b[11] + a[5] * b[10] - a[6] * b[9] + a[7] * b[8] + a[8] * b[7] - a[9] * b[6] + a[10] * b[5] + a[11] * b[4] - a[12] * b[3] + a[13] * b[2] - a[14] * b[1] + a[15] * b[0];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::BitXor<&EuclideanMultivector4<S>> for &EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: &EuclideanMultivector4<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[0];
+        let result_e1    = a[0] * b[1] + a[1] * b[0];
+        let result_e2    = a[0] * b[2] + a[2] * b[0];
+        let result_e3    = a[0] * b[3] + a[3] * b[0];
+        let result_e4    = a[0] * b[4] + a[4] * b[0];
+        let result_e12   = a[0] * b[5] + a[1] * b[2] - a[2] * b[1] + a[5] * b[0];
+        let result_e13   = a[0] * b[6] + a[1] * b[3] - a[3] * b[1] + a[6] * b[0];
+        let result_e14   = a[0] * b[7] + a[1] * b[4] - a[4] * b[1] + a[7] * b[0];
+        let result_e23   = a[0] * b[8] + a[2] * b[3] - a[3] * b[2] + a[8] * b[0];
+        let result_e24   = a[0] * b[9] + a[2] * b[4] - a[4] * b[2] + a[9] * b[0];
+        let result_e34   = a[0] * b[10] + a[3] * b[4] - a[4] * b[3] + a[10] * b[0];
+        let result_e123  = a[0] * b[11] + a[1] * b[8] - a[2] * b[6] + a[3] * b[5] + a[5] * b[3] - a[6] * b[2] + a[8] * b[1] + a[11] * b[0];
+        let result_e124  = a[0] * b[12] + a[1] * b[9] - a[2] * b[7] + a[4] * b[5] + a[5] * b[4] - a[7] * b[2] + a[9] * b[1] + a[12] * b[0];
+        let result_e134  = a[0] * b[13] + a[1] * b[10] - a[3] * b[7] + a[4] * b[6] + a[6] * b[4] - a[7] * b[3] + a[10] * b[1] + a[13] * b[0];
+        let result_e234  = a[0] * b[14] + a[2] * b[10] - a[3] * b[9] + a[4] * b[8] + a[8] * b[4] - a[9] * b[3] + a[10] * b[2] + a[14] * b[0];
+        let result_e1234 = a[0] * b[15] + a[1] * b[14] - a[2] * b[13] + a[3] * b[12] - a[4] * b[11] + a[5] * b[10] - a[6] * b[9] + a[7] * b[8] + a[8] * b[7] - a[9] * b[6] + a[10] * b[5] + a[11] * b[4] - a[12] * b[3] + a[13] * b[2] - a[14] * b[1] + a[15] * b[0];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::BitXor<S> for EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1     = a[0] * other;
+        let result_e1    = a[1] * other;
+        let result_e2    = a[2] * other;
+        let result_e3    = a[3] * other;
+        let result_e4    = a[4] * other;
+        let result_e12   = a[5] * other;
+        let result_e13   = a[6] * other;
+        let result_e14   = a[7] * other;
+        let result_e23   = a[8] * other;
+        let result_e24   = a[9] * other;
+        let result_e34   = a[10] * other;
+        let result_e123  = a[11] * other;
+        let result_e124  = a[12] * other;
+        let result_e134  = a[13] * other;
+        let result_e234  = a[14] * other;
+        let result_e1234 = a[15] * other;
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::BitXor<S> for &EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1     = a[0] * other;
+        let result_e1    = a[1] * other;
+        let result_e2    = a[2] * other;
+        let result_e3    = a[3] * other;
+        let result_e4    = a[4] * other;
+        let result_e12   = a[5] * other;
+        let result_e13   = a[6] * other;
+        let result_e14   = a[7] * other;
+        let result_e23   = a[8] * other;
+        let result_e24   = a[9] * other;
+        let result_e34   = a[10] * other;
+        let result_e123  = a[11] * other;
+        let result_e124  = a[12] * other;
+        let result_e134  = a[13] * other;
+        let result_e234  = a[14] * other;
+        let result_e1234 = a[15] * other;
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::BitAnd<EuclideanMultivector4<S>> for EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitand(self, other: EuclideanMultivector4<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[15] - a[1] * b[14] + a[2] * b[13] - a[3] * b[12] + a[4] * b[11] + a[5] * b[10] - a[6] * b[9] + a[7] * b[8] + a[8] * b[7] - a[9] * b[6] + a[10] * b[5] - a[11] * b[4] + a[12] * b[3] - a[13] * b[2] + a[14] * b[1] + a[15] * b[0];
+        let result_e1    = a[1] * b[15] + a[5] * b[13] - a[6] * b[12] + a[7] * b[11] + a[11] * b[7] - a[12] * b[6] + a[13] * b[5] + a[15] * b[1];
+        let result_e2    = a[2] * b[15] + a[5] * b[14] - a[8] * b[12] + a[9] * b[11] + a[11] * b[9] - a[12] * b[8] + a[14] * b[5] + a[15] * b[2];
+        let result_e3    = a[3] * b[15] + a[6] * b[14] - a[8] * b[13] + a[10] * b[11] + a[11] * b[10] - a[13] * b[8] + a[14] * b[6] + a[15] * b[3];
+        let result_e4    = a[4] * b[15] + a[7] * b[14] - a[9] * b[13] + a[10] * b[12] + a[12] * b[10] - a[13] * b[9] + a[14] * b[7] + a[15] * b[4];
+        let result_e12   = a[5] * b[15] - a[11] * b[12] + a[12] * b[11] + a[15] * b[5];
+        let result_e13   = a[6] * b[15] - a[11] * b[13] + a[13] * b[11] + a[15] * b[6];
+        let result_e14   = a[7] * b[15] - a[12] * b[13] + a[13] * b[12] + a[15] * b[7];
+        let result_e23   = a[8] * b[15] - a[11] * b[14] + a[14] * b[11] + a[15] * b[8];
+        let result_e24   = a[9] * b[15] - a[12] * b[14] + a[14] * b[12] + a[15] * b[9];
+        let result_e34   = a[10] * b[15] - a[13] * b[14] + a[14] * b[13] + a[15] * b[10];
+        let result_e123  = a[11] * b[15] + a[15] * b[11];
+        let result_e124  = a[12] * b[15] + a[15] * b[12];
+        let result_e134  = a[13] * b[15] + a[15] * b[13];
+        let result_e234  = a[14] * b[15] + a[15] * b[14];
+        let result_e1234 = a[15] * b[15];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::BitAnd<&EuclideanMultivector4<S>> for EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitand(self, other: &EuclideanMultivector4<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[15] - a[1] * b[14] + a[2] * b[13] - a[3] * b[12] + a[4] * b[11] + a[5] * b[10] - a[6] * b[9] + a[7] * b[8] + a[8] * b[7] - a[9] * b[6] + a[10] * b[5] - a[11] * b[4] + a[12] * b[3] - a[13] * b[2] + a[14] * b[1] + a[15] * b[0];
+        let result_e1    = a[1] * b[15] + a[5] * b[13] - a[6] * b[12] + a[7] * b[11] + a[11] * b[7] - a[12] * b[6] + a[13] * b[5] + a[15] * b[1];
+        let result_e2    = a[2] * b[15] + a[5] * b[14] - a[8] * b[12] + a[9] * b[11] + a[11] * b[9] - a[12] * b[8] + a[14] * b[5] + a[15] * b[2];
+        let result_e3    = a[3] * b[15] + a[6] * b[14] - a[8] * b[13] + a[10] * b[11] + a[11] * b[10] - a[13] * b[8] + a[14] * b[6] + a[15] * b[3];
+        let result_e4    = a[4] * b[15] + a[7] * b[14] - a[9] * b[13] + a[10] * b[12] + a[12] * b[10] - a[13] * b[9] + a[14] * b[7] + a[15] * b[4];
+        let result_e12   = a[5] * b[15] - a[11] * b[12] + a[12] * b[11] + a[15] * b[5];
+        let result_e13   = a[6] * b[15] - a[11] * b[13] + a[13] * b[11] + a[15] * b[6];
+        let result_e14   = a[7] * b[15] - a[12] * b[13] + a[13] * b[12] + a[15] * b[7];
+        let result_e23   = a[8] * b[15] - a[11] * b[14] + a[14] * b[11] + a[15] * b[8];
+        let result_e24   = a[9] * b[15] - a[12] * b[14] + a[14] * b[12] + a[15] * b[9];
+        let result_e34   = a[10] * b[15] - a[13] * b[14] + a[14] * b[13] + a[15] * b[10];
+        let result_e123  = a[11] * b[15] + a[15] * b[11];
+        let result_e124  = a[12] * b[15] + a[15] * b[12];
+        let result_e134  = a[13] * b[15] + a[15] * b[13];
+        let result_e234  = a[14] * b[15] + a[15] * b[14];
+        let result_e1234 = a[15] * b[15];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::BitAnd<EuclideanMultivector4<S>> for &EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitand(self, other: EuclideanMultivector4<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[15] - a[1] * b[14] + a[2] * b[13] - a[3] * b[12] + a[4] * b[11] + a[5] * b[10] - a[6] * b[9] + a[7] * b[8] + a[8] * b[7] - a[9] * b[6] + a[10] * b[5] - a[11] * b[4] + a[12] * b[3] - a[13] * b[2] + a[14] * b[1] + a[15] * b[0];
+        let result_e1    = a[1] * b[15] + a[5] * b[13] - a[6] * b[12] + a[7] * b[11] + a[11] * b[7] - a[12] * b[6] + a[13] * b[5] + a[15] * b[1];
+        let result_e2    = a[2] * b[15] + a[5] * b[14] - a[8] * b[12] + a[9] * b[11] + a[11] * b[9] - a[12] * b[8] + a[14] * b[5] + a[15] * b[2];
+        let result_e3    = a[3] * b[15] + a[6] * b[14] - a[8] * b[13] + a[10] * b[11] + a[11] * b[10] - a[13] * b[8] + a[14] * b[6] + a[15] * b[3];
+        let result_e4    = a[4] * b[15] + a[7] * b[14] - a[9] * b[13] + a[10] * b[12] + a[12] * b[10] - a[13] * b[9] + a[14] * b[7] + a[15] * b[4];
+        let result_e12   = a[5] * b[15] - a[11] * b[12] + a[12] * b[11] + a[15] * b[5];
+        let result_e13   = a[6] * b[15] - a[11] * b[13] + a[13] * b[11] + a[15] * b[6];
+        let result_e14   = a[7] * b[15] - a[12] * b[13] + a[13] * b[12] + a[15] * b[7];
+        let result_e23   = a[8] * b[15] - a[11] * b[14] + a[14] * b[11] + a[15] * b[8];
+        let result_e24   = a[9] * b[15] - a[12] * b[14] + a[14] * b[12] + a[15] * b[9];
+        let result_e34   = a[10] * b[15] - a[13] * b[14] + a[14] * b[13] + a[15] * b[10];
+        let result_e123  = a[11] * b[15] + a[15] * b[11];
+        let result_e124  = a[12] * b[15] + a[15] * b[12];
+        let result_e134  = a[13] * b[15] + a[15] * b[13];
+        let result_e234  = a[14] * b[15] + a[15] * b[14];
+        let result_e1234 = a[15] * b[15];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::BitAnd<&EuclideanMultivector4<S>> for &EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitand(self, other: &EuclideanMultivector4<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[15] - a[1] * b[14] + a[2] * b[13] - a[3] * b[12] + a[4] * b[11] + a[5] * b[10] - a[6] * b[9] + a[7] * b[8] + a[8] * b[7] - a[9] * b[6] + a[10] * b[5] - a[11] * b[4] + a[12] * b[3] - a[13] * b[2] + a[14] * b[1] + a[15] * b[0];
+        let result_e1    = a[1] * b[15] + a[5] * b[13] - a[6] * b[12] + a[7] * b[11] + a[11] * b[7] - a[12] * b[6] + a[13] * b[5] + a[15] * b[1];
+        let result_e2    = a[2] * b[15] + a[5] * b[14] - a[8] * b[12] + a[9] * b[11] + a[11] * b[9] - a[12] * b[8] + a[14] * b[5] + a[15] * b[2];
+        let result_e3    = a[3] * b[15] + a[6] * b[14] - a[8] * b[13] + a[10] * b[11] + a[11] * b[10] - a[13] * b[8] + a[14] * b[6] + a[15] * b[3];
+        let result_e4    = a[4] * b[15] + a[7] * b[14] - a[9] * b[13] + a[10] * b[12] + a[12] * b[10] - a[13] * b[9] + a[14] * b[7] + a[15] * b[4];
+        let result_e12   = a[5] * b[15] - a[11] * b[12] + a[12] * b[11] + a[15] * b[5];
+        let result_e13   = a[6] * b[15] - a[11] * b[13] + a[13] * b[11] + a[15] * b[6];
+        let result_e14   = a[7] * b[15] - a[12] * b[13] + a[13] * b[12] + a[15] * b[7];
+        let result_e23   = a[8] * b[15] - a[11] * b[14] + a[14] * b[11] + a[15] * b[8];
+        let result_e24   = a[9] * b[15] - a[12] * b[14] + a[14] * b[12] + a[15] * b[9];
+        let result_e34   = a[10] * b[15] - a[13] * b[14] + a[14] * b[13] + a[15] * b[10];
+        let result_e123  = a[11] * b[15] + a[15] * b[11];
+        let result_e124  = a[12] * b[15] + a[15] * b[12];
+        let result_e134  = a[13] * b[15] + a[15] * b[13];
+        let result_e234  = a[14] * b[15] + a[15] * b[14];
+        let result_e1234 = a[15] * b[15];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::BitOr<EuclideanMultivector4<S>> for EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitor(self, other: EuclideanMultivector4<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1 = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] + a[5] * b[5] + a[6] * b[6] + a[7] * b[7] + a[8] * b[8] + a[9] * b[9] + a[10] * b[10] + a[11] * b[11] + a[12] * b[12] + a[13] * b[13] + a[14] * b[14] + a[15] * b[15];
+
+        EuclideanMultivector4::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::BitOr<&EuclideanMultivector4<S>> for EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitor(self, other: &EuclideanMultivector4<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1 = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] + a[5] * b[5] + a[6] * b[6] + a[7] * b[7] + a[8] * b[8] + a[9] * b[9] + a[10] * b[10] + a[11] * b[11] + a[12] * b[12] + a[13] * b[13] + a[14] * b[14] + a[15] * b[15];
+
+        EuclideanMultivector4::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::BitOr<EuclideanMultivector4<S>> for &EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitor(self, other: EuclideanMultivector4<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1 = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] + a[5] * b[5] + a[6] * b[6] + a[7] * b[7] + a[8] * b[8] + a[9] * b[9] + a[10] * b[10] + a[11] * b[11] + a[12] * b[12] + a[13] * b[13] + a[14] * b[14] + a[15] * b[15];
+
+        EuclideanMultivector4::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::BitOr<&EuclideanMultivector4<S>> for &EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitor(self, other: &EuclideanMultivector4<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1 = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] + a[5] * b[5] + a[6] * b[6] + a[7] * b[7] + a[8] * b[8] + a[9] * b[9] + a[10] * b[10] + a[11] * b[11] + a[12] * b[12] + a[13] * b[13] + a[14] * b[14] + a[15] * b[15];
+
+        EuclideanMultivector4::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::BitOr<S> for EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn bitor(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1 = a[0] * other;
+
+        EuclideanMultivector4::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::BitOr<S> for &EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn bitor(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1 = a[0] * other;
+
+        EuclideanMultivector4::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::Add<EuclideanMultivector4<S>> for EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: EuclideanMultivector4<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] + b[0];
+        let result_e1    = a[1] + b[1];
+        let result_e2    = a[2] + b[2];
+        let result_e3    = a[3] + b[3];
+        let result_e4    = a[4] + b[4];
+        let result_e12   = a[5] + b[5];
+        let result_e13   = a[6] + b[6];
+        let result_e14   = a[7] + b[7];
+        let result_e23   = a[8] + b[8];
+        let result_e24   = a[9] + b[9];
+        let result_e34   = a[10] + b[10];
+        let result_e123  = a[11] + b[11];
+        let result_e124  = a[12] + b[12];
+        let result_e134  = a[13] + b[13];
+        let result_e234  = a[14] + b[14];
+        let result_e1234 = a[15] + b[15];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::Add<&EuclideanMultivector4<S>> for EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: &EuclideanMultivector4<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] + b[0];
+        let result_e1    = a[1] + b[1];
+        let result_e2    = a[2] + b[2];
+        let result_e3    = a[3] + b[3];
+        let result_e4    = a[4] + b[4];
+        let result_e12   = a[5] + b[5];
+        let result_e13   = a[6] + b[6];
+        let result_e14   = a[7] + b[7];
+        let result_e23   = a[8] + b[8];
+        let result_e24   = a[9] + b[9];
+        let result_e34   = a[10] + b[10];
+        let result_e123  = a[11] + b[11];
+        let result_e124  = a[12] + b[12];
+        let result_e134  = a[13] + b[13];
+        let result_e234  = a[14] + b[14];
+        let result_e1234 = a[15] + b[15];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::Add<EuclideanMultivector4<S>> for &EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: EuclideanMultivector4<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] + b[0];
+        let result_e1    = a[1] + b[1];
+        let result_e2    = a[2] + b[2];
+        let result_e3    = a[3] + b[3];
+        let result_e4    = a[4] + b[4];
+        let result_e12   = a[5] + b[5];
+        let result_e13   = a[6] + b[6];
+        let result_e14   = a[7] + b[7];
+        let result_e23   = a[8] + b[8];
+        let result_e24   = a[9] + b[9];
+        let result_e34   = a[10] + b[10];
+        let result_e123  = a[11] + b[11];
+        let result_e124  = a[12] + b[12];
+        let result_e134  = a[13] + b[13];
+        let result_e234  = a[14] + b[14];
+        let result_e1234 = a[15] + b[15];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::Add<&EuclideanMultivector4<S>> for &EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: &EuclideanMultivector4<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] + b[0];
+        let result_e1    = a[1] + b[1];
+        let result_e2    = a[2] + b[2];
+        let result_e3    = a[3] + b[3];
+        let result_e4    = a[4] + b[4];
+        let result_e12   = a[5] + b[5];
+        let result_e13   = a[6] + b[6];
+        let result_e14   = a[7] + b[7];
+        let result_e23   = a[8] + b[8];
+        let result_e24   = a[9] + b[9];
+        let result_e34   = a[10] + b[10];
+        let result_e123  = a[11] + b[11];
+        let result_e124  = a[12] + b[12];
+        let result_e134  = a[13] + b[13];
+        let result_e234  = a[14] + b[14];
+        let result_e1234 = a[15] + b[15];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::Add<S> for EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1     = a[0] + other;
+        let result_e1    = a[1];
+        let result_e2    = a[2];
+        let result_e3    = a[3];
+        let result_e4    = a[4];
+        let result_e12   = a[5];
+        let result_e13   = a[6];
+        let result_e14   = a[7];
+        let result_e23   = a[8];
+        let result_e24   = a[9];
+        let result_e34   = a[10];
+        let result_e123  = a[11];
+        let result_e124  = a[12];
+        let result_e134  = a[13];
+        let result_e234  = a[14];
+        let result_e1234 = a[15];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::Add<S> for &EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1     = a[0] + other;
+        let result_e1    = a[1];
+        let result_e2    = a[2];
+        let result_e3    = a[3];
+        let result_e4    = a[4];
+        let result_e12   = a[5];
+        let result_e13   = a[6];
+        let result_e14   = a[7];
+        let result_e23   = a[8];
+        let result_e24   = a[9];
+        let result_e34   = a[10];
+        let result_e123  = a[11];
+        let result_e124  = a[12];
+        let result_e134  = a[13];
+        let result_e234  = a[14];
+        let result_e1234 = a[15];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::Sub<EuclideanMultivector4<S>> for EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: EuclideanMultivector4<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] - b[0];
+        let result_e1    = a[1] - b[1];
+        let result_e2    = a[2] - b[2];
+        let result_e3    = a[3] - b[3];
+        let result_e4    = a[4] - b[4];
+        let result_e12   = a[5] - b[5];
+        let result_e13   = a[6] - b[6];
+        let result_e14   = a[7] - b[7];
+        let result_e23   = a[8] - b[8];
+        let result_e24   = a[9] - b[9];
+        let result_e34   = a[10] - b[10];
+        let result_e123  = a[11] - b[11];
+        let result_e124  = a[12] - b[12];
+        let result_e134  = a[13] - b[13];
+        let result_e234  = a[14] - b[14];
+        let result_e1234 = a[15] - b[15];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::Sub<&EuclideanMultivector4<S>> for EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: &EuclideanMultivector4<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] - b[0];
+        let result_e1    = a[1] - b[1];
+        let result_e2    = a[2] - b[2];
+        let result_e3    = a[3] - b[3];
+        let result_e4    = a[4] - b[4];
+        let result_e12   = a[5] - b[5];
+        let result_e13   = a[6] - b[6];
+        let result_e14   = a[7] - b[7];
+        let result_e23   = a[8] - b[8];
+        let result_e24   = a[9] - b[9];
+        let result_e34   = a[10] - b[10];
+        let result_e123  = a[11] - b[11];
+        let result_e124  = a[12] - b[12];
+        let result_e134  = a[13] - b[13];
+        let result_e234  = a[14] - b[14];
+        let result_e1234 = a[15] - b[15];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::Sub<EuclideanMultivector4<S>> for &EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: EuclideanMultivector4<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] - b[0];
+        let result_e1    = a[1] - b[1];
+        let result_e2    = a[2] - b[2];
+        let result_e3    = a[3] - b[3];
+        let result_e4    = a[4] - b[4];
+        let result_e12   = a[5] - b[5];
+        let result_e13   = a[6] - b[6];
+        let result_e14   = a[7] - b[7];
+        let result_e23   = a[8] - b[8];
+        let result_e24   = a[9] - b[9];
+        let result_e34   = a[10] - b[10];
+        let result_e123  = a[11] - b[11];
+        let result_e124  = a[12] - b[12];
+        let result_e134  = a[13] - b[13];
+        let result_e234  = a[14] - b[14];
+        let result_e1234 = a[15] - b[15];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::Sub<&EuclideanMultivector4<S>> for &EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: &EuclideanMultivector4<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] - b[0];
+        let result_e1    = a[1] - b[1];
+        let result_e2    = a[2] - b[2];
+        let result_e3    = a[3] - b[3];
+        let result_e4    = a[4] - b[4];
+        let result_e12   = a[5] - b[5];
+        let result_e13   = a[6] - b[6];
+        let result_e14   = a[7] - b[7];
+        let result_e23   = a[8] - b[8];
+        let result_e24   = a[9] - b[9];
+        let result_e34   = a[10] - b[10];
+        let result_e123  = a[11] - b[11];
+        let result_e124  = a[12] - b[12];
+        let result_e134  = a[13] - b[13];
+        let result_e234  = a[14] - b[14];
+        let result_e1234 = a[15] - b[15];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::Sub<S> for EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1     = a[0] - other;
+        let result_e1    = a[1];
+        let result_e2    = a[2];
+        let result_e3    = a[3];
+        let result_e4    = a[4];
+        let result_e12   = a[5];
+        let result_e13   = a[6];
+        let result_e14   = a[7];
+        let result_e23   = a[8];
+        let result_e24   = a[9];
+        let result_e34   = a[10];
+        let result_e123  = a[11];
+        let result_e124  = a[12];
+        let result_e134  = a[13];
+        let result_e234  = a[14];
+        let result_e1234 = a[15];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::Sub<S> for &EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1     = a[0] - other;
+        let result_e1    = a[1];
+        let result_e2    = a[2];
+        let result_e3    = a[3];
+        let result_e4    = a[4];
+        let result_e12   = a[5];
+        let result_e13   = a[6];
+        let result_e14   = a[7];
+        let result_e23   = a[8];
+        let result_e24   = a[9];
+        let result_e34   = a[10];
+        let result_e123  = a[11];
+        let result_e124  = a[12];
+        let result_e134  = a[13];
+        let result_e234  = a[14];
+        let result_e1234 = a[15];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl_approx_cmp_ops!(EuclideanMultivector4);
+
+impl<S> EuclideanMultivector4<S>
+where
+    S: ScalarFloat,
+{
+    /// Calculate the squared magnitude of a multivector.
+    pub fn magnitude_squared(&self) -> S {
+        let scalar_part = (self * self.reverse())[0];
+
+        scalar_part.abs()
+    }
+
+    /// Calculate the magnitude of a multivector.
+    pub fn magnitude(&self) -> S {
+        self.magnitude_squared().sqrt()
+    }
+
+    /// Normalize a multivector to a unit multivector.
+    pub fn normalize(&self) -> Self {
+        self * (S::one() / self.magnitude())
+    }
+
+    /// Normalize a multivector to a specified magnitude.
+    pub fn normalize_to(&self, magnitude: S) -> Self {
+        self * (magnitude / self.magnitude())
+    }
+
+    /// Calculate the squared Euclidean distance between two multivectors.
+    pub fn distance_squared(&self, other: &Self) -> S {
+        (self - other).magnitude_squared()
+    }
+
+    /// Calculate the Euclidean distance between two multivectors.
+    pub fn distance(&self, other: &Self) -> S {
+        (self - other).magnitude()
+    }
+}
+
+impl<S> ops::Div<S> for EuclideanMultivector4<S>
+where
+    S: ScalarFloat,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn div(self, other: S) -> Self::Output {
+        let one_over_other = S::one() / other;
+        let result_1     = self.data[0] * one_over_other;
+        let result_e1    = self.data[1] * one_over_other;
+        let result_e2    = self.data[2] * one_over_other;
+        let result_e3    = self.data[3] * one_over_other;
+        let result_e4    = self.data[4] * one_over_other;
+        let result_e12   = self.data[5] * one_over_other;
+        let result_e13   = self.data[6] * one_over_other;
+        let result_e14   = self.data[7] * one_over_other;
+        let result_e23   = self.data[8] * one_over_other;
+        let result_e24   = self.data[9] * one_over_other;
+        let result_e34   = self.data[10] * one_over_other;
+        let result_e123  = self.data[11] * one_over_other;
+        let result_e124  = self.data[12] * one_over_other;
+        let result_e134  = self.data[13] * one_over_other;
+        let result_e234  = self.data[14] * one_over_other;
+        let result_e1234 = self.data[15] * one_over_other;
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::Div<S> for &EuclideanMultivector4<S>
+where
+    S: ScalarFloat,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn div(self, other: S) -> Self::Output {
+        let one_over_other = S::one() / other;
+        let result_1     = self.data[0] * one_over_other;
+        let result_e1    = self.data[1] * one_over_other;
+        let result_e2    = self.data[2] * one_over_other;
+        let result_e3    = self.data[3] * one_over_other;
+        let result_e4    = self.data[4] * one_over_other;
+        let result_e12   = self.data[5] * one_over_other;
+        let result_e13   = self.data[6] * one_over_other;
+        let result_e14   = self.data[7] * one_over_other;
+        let result_e23   = self.data[8] * one_over_other;
+        let result_e24   = self.data[9] * one_over_other;
+        let result_e34   = self.data[10] * one_over_other;
+        let result_e123  = self.data[11] * one_over_other;
+        let result_e124  = self.data[12] * one_over_other;
+        let result_e134  = self.data[13] * one_over_other;
+        let result_e234  = self.data[14] * one_over_other;
+        let result_e1234 = self.data[15] * one_over_other;
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::Shl<EuclideanMultivector4<S>> for EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shl(self, other: EuclideanMultivector4<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[5] * b[5] - a[6] * b[6] - a[7] * b[7] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[12] - a[13] * b[13] - a[14] * b[14] + a[15] * b[15];
+        let result_e1    = a[0] * b[1] - a[2] * b[5] - a[3] * b[6] - a[4] * b[7] - a[8] * b[11] - a[9] * b[12] - a[10] * b[13] + a[14] * b[15];
+        let result_e2    = a[0] * b[2] + a[1] * b[5] - a[3] * b[8] - a[4] * b[9] + a[6] * b[11] + a[7] * b[12] - a[10] * b[14] - a[13] * b[15];
+        let result_e3    = a[0] * b[3] + a[1] * b[6] + a[2] * b[8] - a[4] * b[10] - a[5] * b[11] + a[7] * b[13] + a[9] * b[14] + a[12] * b[15];
+        let result_e4    = a[0] * b[4] + a[1] * b[7] + a[2] * b[9] + a[3] * b[10] - a[5] * b[12] - a[6] * b[13] - a[8] * b[14] - a[11] * b[15];
+        let result_e12   = a[0] * b[5] + a[3] * b[11] + a[4] * b[12] - a[10] * b[15];
+        let result_e13   = a[0] * b[6] - a[2] * b[11] + a[4] * b[13] + a[9] * b[15];
+        let result_e14   = a[0] * b[7] - a[2] * b[12] - a[3] * b[13] - a[8] * b[15];
+        let result_e23   = a[0] * b[8] + a[1] * b[11] + a[4] * b[14] - a[7] * b[15];
+        let result_e24   = a[0] * b[9] + a[1] * b[12] - a[3] * b[14] + a[6] * b[15];
+        let result_e34   = a[0] * b[10] + a[1] * b[13] + a[2] * b[14] - a[5] * b[15];
+        let result_e123  = a[0] * b[11] - a[4] * b[15];
+        let result_e124  = a[0] * b[12] + a[3] * b[15];
+        let result_e134  = a[0] * b[13] - a[2] * b[15];
+        let result_e234  = a[0] * b[14] + a[1] * b[15];
+        let result_e1234 = a[0] * b[15];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::Shl<&EuclideanMultivector4<S>> for EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shl(self, other: &EuclideanMultivector4<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[5] * b[5] - a[6] * b[6] - a[7] * b[7] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[12] - a[13] * b[13] - a[14] * b[14] + a[15] * b[15];
+        let result_e1    = a[0] * b[1] - a[2] * b[5] - a[3] * b[6] - a[4] * b[7] - a[8] * b[11] - a[9] * b[12] - a[10] * b[13] + a[14] * b[15];
+        let result_e2    = a[0] * b[2] + a[1] * b[5] - a[3] * b[8] - a[4] * b[9] + a[6] * b[11] + a[7] * b[12] - a[10] * b[14] - a[13] * b[15];
+        let result_e3    = a[0] * b[3] + a[1] * b[6] + a[2] * b[8] - a[4] * b[10] - a[5] * b[11] + a[7] * b[13] + a[9] * b[14] + a[12] * b[15];
+        let result_e4    = a[0] * b[4] + a[1] * b[7] + a[2] * b[9] + a[3] * b[10] - a[5] * b[12] - a[6] * b[13] - a[8] * b[14] - a[11] * b[15];
+        let result_e12   = a[0] * b[5] + a[3] * b[11] + a[4] * b[12] - a[10] * b[15];
+        let result_e13   = a[0] * b[6] - a[2] * b[11] + a[4] * b[13] + a[9] * b[15];
+        let result_e14   = a[0] * b[7] - a[2] * b[12] - a[3] * b[13] - a[8] * b[15];
+        let result_e23   = a[0] * b[8] + a[1] * b[11] + a[4] * b[14] - a[7] * b[15];
+        let result_e24   = a[0] * b[9] + a[1] * b[12] - a[3] * b[14] + a[6] * b[15];
+        let result_e34   = a[0] * b[10] + a[1] * b[13] + a[2] * b[14] - a[5] * b[15];
+        let result_e123  = a[0] * b[11] - a[4] * b[15];
+        let result_e124  = a[0] * b[12] + a[3] * b[15];
+        let result_e134  = a[0] * b[13] - a[2] * b[15];
+        let result_e234  = a[0] * b[14] + a[1] * b[15];
+        let result_e1234 = a[0] * b[15];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::Shl<EuclideanMultivector4<S>> for &EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shl(self, other: EuclideanMultivector4<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[5] * b[5] - a[6] * b[6] - a[7] * b[7] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[12] - a[13] * b[13] - a[14] * b[14] + a[15] * b[15];
+        let result_e1    = a[0] * b[1] - a[2] * b[5] - a[3] * b[6] - a[4] * b[7] - a[8] * b[11] - a[9] * b[12] - a[10] * b[13] + a[14] * b[15];
+        let result_e2    = a[0] * b[2] + a[1] * b[5] - a[3] * b[8] - a[4] * b[9] + a[6] * b[11] + a[7] * b[12] - a[10] * b[14] - a[13] * b[15];
+        let result_e3    = a[0] * b[3] + a[1] * b[6] + a[2] * b[8] - a[4] * b[10] - a[5] * b[11] + a[7] * b[13] + a[9] * b[14] + a[12] * b[15];
+        let result_e4    = a[0] * b[4] + a[1] * b[7] + a[2] * b[9] + a[3] * b[10] - a[5] * b[12] - a[6] * b[13] - a[8] * b[14] - a[11] * b[15];
+        let result_e12   = a[0] * b[5] + a[3] * b[11] + a[4] * b[12] - a[10] * b[15];
+        let result_e13   = a[0] * b[6] - a[2] * b[11] + a[4] * b[13] + a[9] * b[15];
+        let result_e14   = a[0] * b[7] - a[2] * b[12] - a[3] * b[13] - a[8] * b[15];
+        let result_e23   = a[0] * b[8] + a[1] * b[11] + a[4] * b[14] - a[7] * b[15];
+        let result_e24   = a[0] * b[9] + a[1] * b[12] - a[3] * b[14] + a[6] * b[15];
+        let result_e34   = a[0] * b[10] + a[1] * b[13] + a[2] * b[14] - a[5] * b[15];
+        let result_e123  = a[0] * b[11] - a[4] * b[15];
+        let result_e124  = a[0] * b[12] + a[3] * b[15];
+        let result_e134  = a[0] * b[13] - a[2] * b[15];
+        let result_e234  = a[0] * b[14] + a[1] * b[15];
+        let result_e1234 = a[0] * b[15];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::Shl<&EuclideanMultivector4<S>> for &EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shl(self, other: &EuclideanMultivector4<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[5] * b[5] - a[6] * b[6] - a[7] * b[7] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[12] - a[13] * b[13] - a[14] * b[14] + a[15] * b[15];
+        let result_e1    = a[0] * b[1] - a[2] * b[5] - a[3] * b[6] - a[4] * b[7] - a[8] * b[11] - a[9] * b[12] - a[10] * b[13] + a[14] * b[15];
+        let result_e2    = a[0] * b[2] + a[1] * b[5] - a[3] * b[8] - a[4] * b[9] + a[6] * b[11] + a[7] * b[12] - a[10] * b[14] - a[13] * b[15];
+        let result_e3    = a[0] * b[3] + a[1] * b[6] + a[2] * b[8] - a[4] * b[10] - a[5] * b[11] + a[7] * b[13] + a[9] * b[14] + a[12] * b[15];
+        let result_e4    = a[0] * b[4] + a[1] * b[7] + a[2] * b[9] + a[3] * b[10] - a[5] * b[12] - a[6] * b[13] - a[8] * b[14] - a[11] * b[15];
+        let result_e12   = a[0] * b[5] + a[3] * b[11] + a[4] * b[12] - a[10] * b[15];
+        let result_e13   = a[0] * b[6] - a[2] * b[11] + a[4] * b[13] + a[9] * b[15];
+        let result_e14   = a[0] * b[7] - a[2] * b[12] - a[3] * b[13] - a[8] * b[15];
+        let result_e23   = a[0] * b[8] + a[1] * b[11] + a[4] * b[14] - a[7] * b[15];
+        let result_e24   = a[0] * b[9] + a[1] * b[12] - a[3] * b[14] + a[6] * b[15];
+        let result_e34   = a[0] * b[10] + a[1] * b[13] + a[2] * b[14] - a[5] * b[15];
+        let result_e123  = a[0] * b[11] - a[4] * b[15];
+        let result_e124  = a[0] * b[12] + a[3] * b[15];
+        let result_e134  = a[0] * b[13] - a[2] * b[15];
+        let result_e234  = a[0] * b[14] + a[1] * b[15];
+        let result_e1234 = a[0] * b[15];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::Shl<S> for EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn shl(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1 = a[0] * other;
+
+        EuclideanMultivector4::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::Shl<S> for &EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn shl(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1 = a[0] * other;
+
+        EuclideanMultivector4::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::Shr<EuclideanMultivector4<S>> for EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: EuclideanMultivector4<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[5] * b[5] - a[6] * b[6] - a[7] * b[7] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[12] - a[13] * b[13] - a[14] * b[14] + a[15] * b[15];
+        let result_e1    = a[1] * b[0] + a[5] * b[2] + a[6] * b[3] + a[7] * b[4] - a[11] * b[8] - a[12] * b[9] - a[13] * b[10] - a[15] * b[14];
+        let result_e2    = a[2] * b[0] - a[5] * b[1] + a[8] * b[3] + a[9] * b[4] + a[11] * b[6] + a[12] * b[7] - a[14] * b[10] + a[15] * b[13];
+        let result_e3    = a[3] * b[0] - a[6] * b[1] - a[8] * b[2] + a[10] * b[4] - a[11] * b[5] + a[13] * b[7] + a[14] * b[9] - a[15] * b[12];
+        let result_e4    = a[4] * b[0] - a[7] * b[1] - a[9] * b[2] - a[10] * b[3] - a[12] * b[5] - a[13] * b[6] - a[14] * b[8] + a[15] * b[11];
+        let result_e12   = a[5] * b[0] + a[11] * b[3] + a[12] * b[4] - a[15] * b[10];
+        let result_e13   = a[6] * b[0] - a[11] * b[2] + a[13] * b[4] + a[15] * b[9];
+        let result_e14   = a[7] * b[0] - a[12] * b[2] - a[13] * b[3] - a[15] * b[8];
+        let result_e23   = a[8] * b[0] + a[11] * b[1] + a[14] * b[4] - a[15] * b[7];
+        let result_e24   = a[9] * b[0] + a[12] * b[1] - a[14] * b[3] + a[15] * b[6];
+        let result_e34   = a[10] * b[0] + a[13] * b[1] + a[14] * b[2] - a[15] * b[5];
+        let result_e123  = a[11] * b[0] + a[15] * b[4];
+        let result_e124  = a[12] * b[0] - a[15] * b[3];
+        let result_e134  = a[13] * b[0] + a[15] * b[2];
+        let result_e234  = a[14] * b[0] - a[15] * b[1];
+        let result_e1234 = a[15] * b[0];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::Shr<&EuclideanMultivector4<S>> for EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: &EuclideanMultivector4<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[5] * b[5] - a[6] * b[6] - a[7] * b[7] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[12] - a[13] * b[13] - a[14] * b[14] + a[15] * b[15];
+        let result_e1    = a[1] * b[0] + a[5] * b[2] + a[6] * b[3] + a[7] * b[4] - a[11] * b[8] - a[12] * b[9] - a[13] * b[10] - a[15] * b[14];
+        let result_e2    = a[2] * b[0] - a[5] * b[1] + a[8] * b[3] + a[9] * b[4] + a[11] * b[6] + a[12] * b[7] - a[14] * b[10] + a[15] * b[13];
+        let result_e3    = a[3] * b[0] - a[6] * b[1] - a[8] * b[2] + a[10] * b[4] - a[11] * b[5] + a[13] * b[7] + a[14] * b[9] - a[15] * b[12];
+        let result_e4    = a[4] * b[0] - a[7] * b[1] - a[9] * b[2] - a[10] * b[3] - a[12] * b[5] - a[13] * b[6] - a[14] * b[8] + a[15] * b[11];
+        let result_e12   = a[5] * b[0] + a[11] * b[3] + a[12] * b[4] - a[15] * b[10];
+        let result_e13   = a[6] * b[0] - a[11] * b[2] + a[13] * b[4] + a[15] * b[9];
+        let result_e14   = a[7] * b[0] - a[12] * b[2] - a[13] * b[3] - a[15] * b[8];
+        let result_e23   = a[8] * b[0] + a[11] * b[1] + a[14] * b[4] - a[15] * b[7];
+        let result_e24   = a[9] * b[0] + a[12] * b[1] - a[14] * b[3] + a[15] * b[6];
+        let result_e34   = a[10] * b[0] + a[13] * b[1] + a[14] * b[2] - a[15] * b[5];
+        let result_e123  = a[11] * b[0] + a[15] * b[4];
+        let result_e124  = a[12] * b[0] - a[15] * b[3];
+        let result_e134  = a[13] * b[0] + a[15] * b[2];
+        let result_e234  = a[14] * b[0] - a[15] * b[1];
+        let result_e1234 = a[15] * b[0];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::Shr<EuclideanMultivector4<S>> for &EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: EuclideanMultivector4<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[5] * b[5] - a[6] * b[6] - a[7] * b[7] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[12] - a[13] * b[13] - a[14] * b[14] + a[15] * b[15];
+        let result_e1    = a[1] * b[0] + a[5] * b[2] + a[6] * b[3] + a[7] * b[4] - a[11] * b[8] - a[12] * b[9] - a[13] * b[10] - a[15] * b[14];
+        let result_e2    = a[2] * b[0] - a[5] * b[1] + a[8] * b[3] + a[9] * b[4] + a[11] * b[6] + a[12] * b[7] - a[14] * b[10] + a[15] * b[13];
+        let result_e3    = a[3] * b[0] - a[6] * b[1] - a[8] * b[2] + a[10] * b[4] - a[11] * b[5] + a[13] * b[7] + a[14] * b[9] - a[15] * b[12];
+        let result_e4    = a[4] * b[0] - a[7] * b[1] - a[9] * b[2] - a[10] * b[3] - a[12] * b[5] - a[13] * b[6] - a[14] * b[8] + a[15] * b[11];
+        let result_e12   = a[5] * b[0] + a[11] * b[3] + a[12] * b[4] - a[15] * b[10];
+        let result_e13   = a[6] * b[0] - a[11] * b[2] + a[13] * b[4] + a[15] * b[9];
+        let result_e14   = a[7] * b[0] - a[12] * b[2] - a[13] * b[3] - a[15] * b[8];
+        let result_e23   = a[8] * b[0] + a[11] * b[1] + a[14] * b[4] - a[15] * b[7];
+        let result_e24   = a[9] * b[0] + a[12] * b[1] - a[14] * b[3] + a[15] * b[6];
+        let result_e34   = a[10] * b[0] + a[13] * b[1] + a[14] * b[2] - a[15] * b[5];
+        let result_e123  = a[11] * b[0] + a[15] * b[4];
+        let result_e124  = a[12] * b[0] - a[15] * b[3];
+        let result_e134  = a[13] * b[0] + a[15] * b[2];
+        let result_e234  = a[14] * b[0] - a[15] * b[1];
+        let result_e1234 = a[15] * b[0];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::Shr<&EuclideanMultivector4<S>> for &EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: &EuclideanMultivector4<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[5] * b[5] - a[6] * b[6] - a[7] * b[7] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[12] - a[13] * b[13] - a[14] * b[14] + a[15] * b[15];
+        let result_e1    = a[1] * b[0] + a[5] * b[2] + a[6] * b[3] + a[7] * b[4] - a[11] * b[8] - a[12] * b[9] - a[13] * b[10] - a[15] * b[14];
+        let result_e2    = a[2] * b[0] - a[5] * b[1] + a[8] * b[3] + a[9] * b[4] + a[11] * b[6] + a[12] * b[7] - a[14] * b[10] + a[15] * b[13];
+        let result_e3    = a[3] * b[0] - a[6] * b[1] - a[8] * b[2] + a[10] * b[4] - a[11] * b[5] + a[13] * b[7] + a[14] * b[9] - a[15] * b[12];
+        let result_e4    = a[4] * b[0] - a[7] * b[1] - a[9] * b[2] - a[10] * b[3] - a[12] * b[5] - a[13] * b[6] - a[14] * b[8] + a[15] * b[11];
+        let result_e12   = a[5] * b[0] + a[11] * b[3] + a[12] * b[4] - a[15] * b[10];
+        let result_e13   = a[6] * b[0] - a[11] * b[2] + a[13] * b[4] + a[15] * b[9];
+        let result_e14   = a[7] * b[0] - a[12] * b[2] - a[13] * b[3] - a[15] * b[8];
+        let result_e23   = a[8] * b[0] + a[11] * b[1] + a[14] * b[4] - a[15] * b[7];
+        let result_e24   = a[9] * b[0] + a[12] * b[1] - a[14] * b[3] + a[15] * b[6];
+        let result_e34   = a[10] * b[0] + a[13] * b[1] + a[14] * b[2] - a[15] * b[5];
+        let result_e123  = a[11] * b[0] + a[15] * b[4];
+        let result_e124  = a[12] * b[0] - a[15] * b[3];
+        let result_e134  = a[13] * b[0] + a[15] * b[2];
+        let result_e234  = a[14] * b[0] - a[15] * b[1];
+        let result_e1234 = a[15] * b[0];
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::Shr<S> for EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1     = a[0] * other;
+        let result_e1    = a[1] * other;
+        let result_e2    = a[2] * other;
+        let result_e3    = a[3] * other;
+        let result_e4    = a[4] * other;
+        let result_e12   = a[5] * other;
+        let result_e13   = a[6] * other;
+        let result_e14   = a[7] * other;
+        let result_e23   = a[8] * other;
+        let result_e24   = a[9] * other;
+        let result_e34   = a[10] * other;
+        let result_e123  = a[11] * other;
+        let result_e124  = a[12] * other;
+        let result_e134  = a[13] * other;
+        let result_e234  = a[14] * other;
+        let result_e1234 = a[15] * other;
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> ops::Shr<S> for &EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1     = a[0] * other;
+        let result_e1    = a[1] * other;
+        let result_e2    = a[2] * other;
+        let result_e3    = a[3] * other;
+        let result_e4    = a[4] * other;
+        let result_e12   = a[5] * other;
+        let result_e13   = a[6] * other;
+        let result_e14   = a[7] * other;
+        let result_e23   = a[8] * other;
+        let result_e24   = a[9] * other;
+        let result_e34   = a[10] * other;
+        let result_e123  = a[11] * other;
+        let result_e124  = a[12] * other;
+        let result_e134  = a[13] * other;
+        let result_e234  = a[14] * other;
+        let result_e1234 = a[15] * other;
+
+        EuclideanMultivector4::new(
+            result_1,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e4,
+            result_e12,
+            result_e13,
+            result_e14,
+            result_e23,
+            result_e24,
+            result_e34,
+            result_e123,
+            result_e124,
+            result_e134,
+            result_e234,
+            result_e1234,
+        )
+    }
+}
+
+impl<S> EuclideanMultivector4<S>
+where
+    S: ScalarFloat,
+{
+    /// Determine whether a multivector is invertible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let e1234: EuclideanMultivector4<f64> = EuclideanMultivector4::unit_e1234();
+    ///
+    /// assert!(e1234.is_invertible());
+    ///
+    /// // The idempotent `(1 + e1234) / 2` is a zero divisor.
+    /// let idempotent = (EuclideanMultivector4::unit_scalar() + e1234) * 0.5_f64;
+    ///
+    /// assert!(!idempotent.is_invertible());
+    /// ```
+    #[inline]
+    pub fn is_invertible(&self) -> bool {
+        ulps_ne!(
+            self.inverse_denominator(),
+            S::zero(),
+            abs_diff_all <= S::default_epsilon(),
+            ulps_all <= S::default_max_ulps()
+        )
+    }
+
+    /// Compute the multiplicative inverse of a multivector.
+    ///
+    /// The inverse of a multivector `mv` is a multivector `mv_inv`
+    /// such that
+    /// ```text
+    /// mv * mv_inv = mv_inv * mv = 1
+    /// ```
+    /// In dimension four, the inverse is given by
+    /// ```text
+    /// inv(mv) == conj(mv) * neg34(mv * conj(mv)) / (mv * conj(mv) * neg34(mv * conj(mv)))
+    /// ```
+    /// where `conj` denotes the conjugate, and `neg34` negates the grade three
+    /// and grade four parts of a multivector. The denominator is always a scalar.
+    /// Unlike in dimension three, a nonzero multivector in dimension four
+    /// can fail to be invertible even when its magnitude is nonzero. For more
+    /// information on the inversion of multivectors in general, see [1].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let mv = EuclideanMultivector4::new(
+    ///     13_f64, -4_f64, 98_f64, 4_f64, 7_f64, -10_f64, 30_f64, 2_f64,
+    ///     1_f64, 5_f64, -6_f64, 3_f64, 8_f64, -9_f64, 11_f64, 12_f64,
+    /// );
+    /// let mv_inv = mv.inverse().unwrap();
+    /// let one: EuclideanMultivector4<f64> = EuclideanMultivector4::unit_scalar();
+    ///
+    /// assert_relative_eq!(mv * mv_inv, one, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(mv_inv * mv, one, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    ///
+    /// # References
+    ///
+    /// [1] _Eckhard Hitzer, Stephen Sangwine. Multivector and multivector matrix
+    ///     inverse in real Clifford algebras. Applied Mathematics and Computation
+    ///     (311) (2017) 375-389. Elsevier. DOI:10.1016/j.amc.2017.05.027._
+    pub fn inverse(&self) -> Option<Self> {
+        let numerator = self.inverse_numerator();
+        let denominator = (self * numerator)[0];
+        if denominator.is_zero() {
+            None
+        } else {
+            Some(numerator / denominator)
+        }
+    }
+
+    fn inverse_numerator(&self) -> Self {
+        let conjugate = self.conjugate();
+        let self_times_conjugate = self * conjugate;
+        let negated = self_times_conjugate.grade(0) - self_times_conjugate.grade(3) - self_times_conjugate.grade(4);
+
+        conjugate * negated
+    }
+
+    fn inverse_denominator(&self) -> S {
+        (self * self.inverse_numerator())[0]
+    }
+
+    fn inverse_unchecked(&self) -> Self {
+        let numerator = self.inverse_numerator();
+        let denominator = (self * numerator)[0];
+
+        numerator / denominator
+    }
+
+    /// Compute the commutator of two multivectors.
+    ///
+    /// The commutator of multivectors `mv1` and `mv2` is given by
+    /// ```text
+    /// comm(mv1, mv2) := (mv1 * mv2 - mv2 * mv1) / 2
+    /// ```
+    /// where `*` denotes the geometric product.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let mv1 = EuclideanMultivector4::from_scalar(2_f64);
+    /// let mv2 = EuclideanMultivector4::from_scalar(3_f64);
+    /// let expected: EuclideanMultivector4<f64> = EuclideanMultivector4::zero();
+    /// let result = mv1.commutator(&mv2);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn commutator(&self, other: &Self) -> Self {
+        let self_times_other = self * other;
+        let other_times_self = other * self;
+        let one_over_two = S::one() / (S::one() + S::one());
+
+        (self_times_other - other_times_self) * one_over_two
+    }
+
+    /// Compute the commutator of two multivectors.
+    ///
+    /// This is a synonym for `commutator`.
+    #[inline(always)]
+    pub fn x(&self, other: &Self) -> Self {
+        self.commutator(other)
+    }
+
+    /// Compute the anticommutator of two multivectors.
+    ///
+    /// The anticommutator of multivectors `mv1` and `mv2` is given by
+    /// ```text
+    /// anticomm(mv1, mv2) := (mv1 * mv2 + mv2 * mv1) / 2
+    /// ```
+    /// where `*` denotes the geometric product.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::EuclideanMultivector4;
+    /// #
+    /// let mv1 = EuclideanMultivector4::from_scalar(2_f64);
+    /// let mv2 = EuclideanMultivector4::from_scalar(3_f64);
+    /// let expected: EuclideanMultivector4<f64> = mv1 * mv2;
+    /// let result = mv1.anticommutator(&mv2);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn anticommutator(&self, other: &Self) -> Self {
+        let self_times_other = self * other;
+        let other_times_self = other * self;
+        let one_over_two = S::one() / (S::one() + S::one());
+
+        (self_times_other + other_times_self) * one_over_two
+    }
+}
+
+impl<S> ops::Div<EuclideanMultivector4<S>> for EuclideanMultivector4<S>
+where
+    S: ScalarFloat,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn div(self, other: EuclideanMultivector4<S>) -> Self::Output {
+        self * other.inverse_unchecked()
+    }
+}
+
+impl<S> ops::Div<&EuclideanMultivector4<S>> for EuclideanMultivector4<S>
+where
+    S: ScalarFloat,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn div(self, other: &EuclideanMultivector4<S>) -> Self::Output {
+        self * other.inverse_unchecked()
+    }
+}
+
+impl<S> ops::Div<EuclideanMultivector4<S>> for &EuclideanMultivector4<S>
+where
+    S: ScalarFloat,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn div(self, other: EuclideanMultivector4<S>) -> Self::Output {
+        self * other.inverse_unchecked()
+    }
+}
+
+impl<S> ops::Div<&EuclideanMultivector4<S>> for &EuclideanMultivector4<S>
+where
+    S: ScalarFloat,
+{
+    type Output = EuclideanMultivector4<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn div(self, other: &EuclideanMultivector4<S>) -> Self::Output {
+        self * other.inverse_unchecked()
+    }
+}
+
+
+impl_coords!(ViewG4, { scalar, e1, e2, e3, e4, e12, e13, e14, e23, e24, e34, e123, e124, e134, e234, e1234 });
+impl_coords_deref!(EuclideanMultivector4, ViewG4);
+
+
+macro_rules! impl_scalar_multivector_add_ops {
+    ($Lhs:ty => $Rhs:ty => $Output:ty, { $scalar_index:expr }, { $($other_index:expr),* }) => {
+        impl ops::Add<$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn add(self, other: $Rhs) -> $Output {
+                Self::Output::new(self + other[$scalar_index], $(other[$other_index]),* )
+            }
+        }
+
+        impl ops::Add<&$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn add(self, other: &$Rhs) -> $Output {
+                Self::Output::new(self + other[$scalar_index], $(other[$other_index]),* )
+            }
+        }
+    }
+}
+
+impl_scalar_multivector_add_ops!(u8    => EuclideanMultivector4<u8>    => EuclideanMultivector4<u8>,    {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(u16   => EuclideanMultivector4<u16>   => EuclideanMultivector4<u16>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(u32   => EuclideanMultivector4<u32>   => EuclideanMultivector4<u32>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(u64   => EuclideanMultivector4<u64>   => EuclideanMultivector4<u64>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(u128  => EuclideanMultivector4<u128>  => EuclideanMultivector4<u128>,  {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(usize => EuclideanMultivector4<usize> => EuclideanMultivector4<usize>, {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(i8    => EuclideanMultivector4<i8>    => EuclideanMultivector4<i8>,    {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(i16   => EuclideanMultivector4<i16>   => EuclideanMultivector4<i16>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(i32   => EuclideanMultivector4<i32>   => EuclideanMultivector4<i32>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(i64   => EuclideanMultivector4<i64>   => EuclideanMultivector4<i64>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(i128  => EuclideanMultivector4<i128>  => EuclideanMultivector4<i128>,  {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(isize => EuclideanMultivector4<isize> => EuclideanMultivector4<isize>, {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(f32   => EuclideanMultivector4<f32>   => EuclideanMultivector4<f32>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(f64   => EuclideanMultivector4<f64>   => EuclideanMultivector4<f64>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+
+
+macro_rules! impl_scalar_multivector_sub_ops {
+    ($Lhs:ty => $Rhs:ty => $Output:ty, { $scalar_index:expr }, { $($other_index:expr),* }) => {
+        impl ops::Sub<$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn sub(self, other: $Rhs) -> Self::Output {
+                Self::Output::new(self - other[$scalar_index], $(-other[$other_index]),* )
+            }
+        }
+
+        impl ops::Sub<&$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn sub(self, other: &$Rhs) -> Self::Output {
+                Self::Output::new(self - other[$scalar_index], $(-other[$other_index]),* )
+            }
+        }
+    }
+}
+
+impl_scalar_multivector_sub_ops!(i8    => EuclideanMultivector4<i8>    => EuclideanMultivector4<i8>,    {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_sub_ops!(i16   => EuclideanMultivector4<i16>   => EuclideanMultivector4<i16>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_sub_ops!(i32   => EuclideanMultivector4<i32>   => EuclideanMultivector4<i32>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_sub_ops!(i64   => EuclideanMultivector4<i64>   => EuclideanMultivector4<i64>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_sub_ops!(i128  => EuclideanMultivector4<i128>  => EuclideanMultivector4<i128>,  {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_sub_ops!(isize => EuclideanMultivector4<isize> => EuclideanMultivector4<isize>, {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_sub_ops!(f32   => EuclideanMultivector4<f32>   => EuclideanMultivector4<f32>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_sub_ops!(f64   => EuclideanMultivector4<f64>   => EuclideanMultivector4<f64>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+
+
+macro_rules! impl_scalar_multivector_mul_ops {
+    ($Lhs:ty => $Rhs:ty => $Output:ty, { $($index:expr),* }) => {
+        impl ops::Mul<$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn mul(self, other: $Rhs) -> Self::Output {
+                Self::Output::new( $(self * other[$index]),* )
+            }
+        }
+
+        impl ops::Mul<&$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn mul(self, other: &$Rhs) -> Self::Output {
+                Self::Output::new( $(self * other[$index]),* )
+            }
+        }
+    }
+}
+
+impl_scalar_multivector_mul_ops!(u8    => EuclideanMultivector4<u8>    => EuclideanMultivector4<u8>,    {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(u16   => EuclideanMultivector4<u16>   => EuclideanMultivector4<u16>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(u32   => EuclideanMultivector4<u32>   => EuclideanMultivector4<u32>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(u64   => EuclideanMultivector4<u64>   => EuclideanMultivector4<u64>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(u128  => EuclideanMultivector4<u128>  => EuclideanMultivector4<u128>,  {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(usize => EuclideanMultivector4<usize> => EuclideanMultivector4<usize>, {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(i8    => EuclideanMultivector4<i8>    => EuclideanMultivector4<i8>,    {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(i16   => EuclideanMultivector4<i16>   => EuclideanMultivector4<i16>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(i32   => EuclideanMultivector4<i32>   => EuclideanMultivector4<i32>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(i64   => EuclideanMultivector4<i64>   => EuclideanMultivector4<i64>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(i128  => EuclideanMultivector4<i128>  => EuclideanMultivector4<i128>,  {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(isize => EuclideanMultivector4<isize> => EuclideanMultivector4<isize>, {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(f32   => EuclideanMultivector4<f32>   => EuclideanMultivector4<f32>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(f64   => EuclideanMultivector4<f64>   => EuclideanMultivector4<f64>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+
+
+macro_rules! impl_scalar_multivector_bitor_ops {
+    ($Lhs:ty) => {
+        impl ops::BitOr<EuclideanMultivector4<$Lhs>> for $Lhs {
+            type Output = EuclideanMultivector4<$Lhs>;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            #[inline]
+            fn bitor(self, other: EuclideanMultivector4<$Lhs>) -> Self::Output {
+                let mut result = Self::Output::zero();
+                result[0] = self * other[0];
+
+                result
+            }
+        }
+
+        impl ops::BitOr<&EuclideanMultivector4<$Lhs>> for $Lhs {
+            type Output = EuclideanMultivector4<$Lhs>;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            #[inline]
+            fn bitor(self, other: &EuclideanMultivector4<$Lhs>) -> Self::Output {
+                let mut result = Self::Output::zero();
+                result[0] = self * other[0];
+
+                result
+            }
+        }
+    };
+}
+
+impl_scalar_multivector_bitor_ops!(u8);
+impl_scalar_multivector_bitor_ops!(u16);
+impl_scalar_multivector_bitor_ops!(u32);
+impl_scalar_multivector_bitor_ops!(u64);
+impl_scalar_multivector_bitor_ops!(u128);
+impl_scalar_multivector_bitor_ops!(usize);
+impl_scalar_multivector_bitor_ops!(i8);
+impl_scalar_multivector_bitor_ops!(i16);
+impl_scalar_multivector_bitor_ops!(i32);
+impl_scalar_multivector_bitor_ops!(i64);
+impl_scalar_multivector_bitor_ops!(i128);
+impl_scalar_multivector_bitor_ops!(isize);
+impl_scalar_multivector_bitor_ops!(f32);
+impl_scalar_multivector_bitor_ops!(f64);
+
+
+macro_rules! impl_scalar_multivector_bitxor_ops {
+    ($Lhs:ty) => {
+        impl ops::BitXor<EuclideanMultivector4<$Lhs>> for $Lhs {
+            type Output = EuclideanMultivector4<$Lhs>;
+
+            #[inline]
+            fn bitxor(self, other: EuclideanMultivector4<$Lhs>) -> Self::Output {
+                let mut result = Self::Output::zero();
+                result[0] = self * other[0];
+                result[1] = self * other[1];
+                result[2] = self * other[2];
+                result[3] = self * other[3];
+                result[4] = self * other[4];
+                result[5] = self * other[5];
+                result[6] = self * other[6];
+                result[7] = self * other[7];
+                result[8] = self * other[8];
+                result[9] = self * other[9];
+                result[10] = self * other[10];
+                result[11] = self * other[11];
+                result[12] = self * other[12];
+                result[13] = self * other[13];
+                result[14] = self * other[14];
+                result[15] = self * other[15];
+
+                result
+            }
+        }
+
+        impl ops::BitXor<&EuclideanMultivector4<$Lhs>> for $Lhs {
+            type Output = EuclideanMultivector4<$Lhs>;
+
+            #[inline]
+            fn bitxor(self, other: &EuclideanMultivector4<$Lhs>) -> Self::Output {
+                let mut result = Self::Output::zero();
+                result[0] = self * other[0];
+                result[1] = self * other[1];
+                result[2] = self * other[2];
+                result[3] = self * other[3];
+                result[4] = self * other[4];
+                result[5] = self * other[5];
+                result[6] = self * other[6];
+                result[7] = self * other[7];
+                result[8] = self * other[8];
+                result[9] = self * other[9];
+                result[10] = self * other[10];
+                result[11] = self * other[11];
+                result[12] = self * other[12];
+                result[13] = self * other[13];
+                result[14] = self * other[14];
+                result[15] = self * other[15];
+
+                result
+            }
+        }
+    };
+}
+
+impl_scalar_multivector_bitxor_ops!(u8);
+impl_scalar_multivector_bitxor_ops!(u16);
+impl_scalar_multivector_bitxor_ops!(u32);
+impl_scalar_multivector_bitxor_ops!(u64);
+impl_scalar_multivector_bitxor_ops!(u128);
+impl_scalar_multivector_bitxor_ops!(usize);
+impl_scalar_multivector_bitxor_ops!(i8);
+impl_scalar_multivector_bitxor_ops!(i16);
+impl_scalar_multivector_bitxor_ops!(i32);
+impl_scalar_multivector_bitxor_ops!(i64);
+impl_scalar_multivector_bitxor_ops!(i128);
+impl_scalar_multivector_bitxor_ops!(isize);
+impl_scalar_multivector_bitxor_ops!(f32);
+impl_scalar_multivector_bitxor_ops!(f64);
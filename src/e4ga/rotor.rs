@@ -0,0 +1,617 @@
+use crate::e4ga::EuclideanMultivector4;
+use crate::scalar::{
+    Scalar,
+    ScalarFloat,
+    ScalarSigned,
+};
+use crate::{
+    impl_approx_cmp_ops,
+    impl_coords,
+    impl_coords_deref,
+};
+use core::fmt;
+use core::ops;
+
+
+/// A stack-allocated rotor in four-dimensional Euclidean space.
+///
+/// A rotor is a unit element of the even subalgebra of the four-dimensional
+/// Euclidean geometric algebra. It is stored in the basis
+/// `{1, e12, e13, e14, e23, e24, e34, e1234}`, so only the scalar, bivector
+/// and pseudoscalar parts of a rotor are represented. The odd-grade parts of
+/// a rotor are always zero.
+///
+/// A rotor `R` rotates a vector `v` by the sandwich product
+/// ```text
+/// rotate(R, v) := R * v * rev(R)
+/// ```
+/// where `rev` denotes the reverse. Every rotation of four-dimensional space
+/// is a double rotation, i.e. it rotates by an angle `angle1` in the plane of
+/// a unit 2-blade `B1`, and by an angle `angle2` in the completely orthogonal
+/// plane of the unit 2-blade `B2 == dual(B1)`. Its rotor is given by
+/// ```text
+/// R := exp(-(angle1 / 2) * B1 - (angle2 / 2) * B2)
+/// ```
+/// A simple rotation has `angle2 == 0`, and an isoclinic rotation has
+/// `angle1 == angle2` or `angle1 == -angle2`. The rotors of isoclinic
+/// rotations are generated by self-dual and anti-self-dual bivectors, whose
+/// squares are not scalars.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Rotor4<S> {
+    data: [S; 8],
+}
+
+impl<S> Rotor4<S> {
+    /// Construct a new rotor from its components.
+    ///
+    /// The components are not normalized, so the caller is responsible for
+    /// ensuring that the resulting rotor has unit magnitude.
+    #[inline]
+    pub const fn new(scalar: S, e12: S, e13: S, e14: S, e23: S, e24: S, e34: S, e1234: S) -> Self {
+        Self {
+            data: [scalar, e12, e13, e14, e23, e24, e34, e1234],
+        }
+    }
+
+    /// Returns the number of components in a rotor.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::Rotor4;
+    /// #
+    /// let rotor = Rotor4::new(1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    ///
+    /// assert_eq!(rotor.len(), 8);
+    /// ```
+    #[inline]
+    pub const fn len(&self) -> usize {
+        8
+    }
+
+    /// Get a pointer to the underlying component array.
+    #[inline]
+    pub const fn as_ptr(&self) -> *const S {
+        &self.data[0]
+    }
+
+    /// Get a mutable pointer to the underlying component array.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut S {
+        &mut self.data[0]
+    }
+
+    /// Get a slice of the underlying elements of the data type.
+    #[inline]
+    pub fn as_slice(&self) -> &[S] {
+        <Self as AsRef<[S; 8]>>::as_ref(self)
+    }
+}
+
+impl<S> Rotor4<S>
+where
+    S: Scalar,
+{
+    /// Construct the identity rotor.
+    ///
+    /// The identity rotor leaves every vector unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::{
+    /// #     EuclideanMultivector4,
+    /// #     Rotor4,
+    /// # };
+    /// #
+    /// let identity: Rotor4<f64> = Rotor4::identity();
+    /// let vector: EuclideanMultivector4<f64> = EuclideanMultivector4::unit_e4();
+    ///
+    /// assert_eq!(identity.rotate_vector(&vector), vector);
+    /// ```
+    #[inline]
+    pub fn identity() -> Self {
+        let zero = S::zero();
+
+        Self::new(S::one(), zero, zero, zero, zero, zero, zero, zero)
+    }
+}
+
+impl<S> Rotor4<S>
+where
+    S: ScalarSigned,
+{
+    /// Compute the reverse of a rotor.
+    ///
+    /// The reverse of a unit rotor is its inverse, i.e. it performs the
+    /// opposite rotation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::e4ga::Rotor4;
+    /// #
+    /// let rotor = Rotor4::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+    /// let expected = Rotor4::new(1_i32, -2_i32, -3_i32, -4_i32, -5_i32, -6_i32, -7_i32, 8_i32);
+    /// let result = rotor.reverse();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn reverse(&self) -> Self {
+        Self::new(
+            self.data[0],
+            -self.data[1],
+            -self.data[2],
+            -self.data[3],
+            -self.data[4],
+            -self.data[5],
+            -self.data[6],
+            self.data[7],
+        )
+    }
+}
+
+impl<S> Rotor4<S>
+where
+    S: ScalarFloat,
+{
+    /// Compute the exponential of a bivector.
+    ///
+    /// Only the grade two part of `bivector` is used. A bivector `B` in four
+    /// dimensions squares to
+    /// ```text
+    /// B * B == -|B|^2 + beta * e1234
+    /// ```
+    /// where `beta == 0` exactly when `B` is a 2-blade. The idempotents
+    /// `P+ == (1 + e1234) / 2` and `P- == (1 - e1234) / 2` split `B` into its
+    /// self-dual part `P+ * B` and its anti-self-dual part `P- * B`, which
+    /// commute and square to `-(|B|^2 - beta) * P+` and `-(|B|^2 + beta) * P-`.
+    /// The exponential is therefore given in closed form by
+    /// ```text
+    /// exp(B) == P+ * (cos(a+) + (sin(a+) / a+) * B) + P- * (cos(a-) + (sin(a-) / a-) * B)
+    /// ```
+    /// where `a+ == sqrt(|B|^2 - beta)` and `a- == sqrt(|B|^2 + beta)`. This
+    /// holds for simple, isoclinic, and general double rotations alike. The
+    /// rotation angles in the two invariant planes are `a+ + a-` and `a+ - a-`,
+    /// up to sign.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e4ga::{
+    /// #     EuclideanMultivector4,
+    /// #     Rotor4,
+    /// # };
+    /// # use core::f64;
+    /// #
+    /// // An isoclinic rotation by a quarter turn in both the `xy`-plane and the `zw`-plane.
+    /// let e12: EuclideanMultivector4<f64> = EuclideanMultivector4::unit_e12();
+    /// let e34: EuclideanMultivector4<f64> = EuclideanMultivector4::unit_e34();
+    /// let bivector = (e12 + e34) * (-f64::consts::FRAC_PI_4);
+    /// let rotor = Rotor4::exp(&bivector);
+    /// let vector: EuclideanMultivector4<f64> = EuclideanMultivector4::unit_e1() + EuclideanMultivector4::unit_e3();
+    /// let expected: EuclideanMultivector4<f64> = EuclideanMultivector4::unit_e2() + EuclideanMultivector4::unit_e4();
+    /// let result = rotor.rotate_vector(&vector);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[rustfmt::skip]
+    pub fn exp(bivector: &EuclideanMultivector4<S>) -> Self {
+        let zero = S::zero();
+        let one_half = S::one() / (S::one() + S::one());
+        let b12 = bivector[5];
+        let b13 = bivector[6];
+        let b14 = bivector[7];
+        let b23 = bivector[8];
+        let b24 = bivector[9];
+        let b34 = bivector[10];
+        let magnitude_squared = b12 * b12 + b13 * b13 + b14 * b14 + b23 * b23 + b24 * b24 + b34 * b34;
+        let beta = (b12 * b34 - b13 * b24 + b14 * b23) * (S::one() + S::one());
+        let angle_plus = (magnitude_squared - beta).max(zero).sqrt();
+        let angle_minus = (magnitude_squared + beta).max(zero).sqrt();
+        let (sin_plus, cos_plus) = angle_plus.sin_cos();
+        let (sin_minus, cos_minus) = angle_minus.sin_cos();
+        let sinc_plus = if angle_plus.is_zero() { S::one() } else { sin_plus / angle_plus };
+        let sinc_minus = if angle_minus.is_zero() { S::one() } else { sin_minus / angle_minus };
+        let cos_sum = (cos_plus + cos_minus) * one_half;
+        let cos_difference = (cos_plus - cos_minus) * one_half;
+        let sinc_sum = (sinc_plus + sinc_minus) * one_half;
+        let sinc_difference = (sinc_plus - sinc_minus) * one_half;
+
+        // The dual bivector `e1234 * B` has components `(-b34, b24, -b23, -b14, b13, -b12)`.
+        Self::new(
+            cos_sum,
+            sinc_sum * b12 - sinc_difference * b34,
+            sinc_sum * b13 + sinc_difference * b24,
+            sinc_sum * b14 - sinc_difference * b23,
+            sinc_sum * b23 - sinc_difference * b14,
+            sinc_sum * b24 + sinc_difference * b13,
+            sinc_sum * b34 - sinc_difference * b12,
+            cos_difference,
+        )
+    }
+
+    /// Compute the logarithm of a rotor.
+    ///
+    /// The logarithm is the bivector `B` such that `exp(B) == R`, where `R`
+    /// is the normalized rotor. The logarithm is computed separately in the
+    /// self-dual and anti-self-dual halves of the even subalgebra, so that it
+    /// recovers both rotation angles of double rotations and isoclinic
+    /// rotations. When a half of the rotor is `-1`, its logarithm is not
+    /// unique, and this function returns the logarithm in the `e12` plane.
+    /// In particular, the logarithm of the rotor `-1` is `pi * e12`, as for
+    /// [`Rotor3::log`](crate::e3ga::Rotor3::log).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e4ga::{
+    /// #     EuclideanMultivector4,
+    /// #     Rotor4,
+    /// # };
+    /// #
+    /// let bivector = EuclideanMultivector4::new(
+    ///     0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0.1_f64, 0.2_f64, 0.3_f64,
+    ///     -0.4_f64, 0.5_f64, 0.6_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64,
+    /// );
+    /// let rotor = Rotor4::exp(&bivector);
+    /// let result = rotor.log();
+    ///
+    /// assert_relative_eq!(result, bivector, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[rustfmt::skip]
+    pub fn log(&self) -> EuclideanMultivector4<S> {
+        let zero = S::zero();
+        let one_half = S::one() / (S::one() + S::one());
+        let s   = self.data[0];
+        let b12 = self.data[1];
+        let b13 = self.data[2];
+        let b14 = self.data[3];
+        let b23 = self.data[4];
+        let b24 = self.data[5];
+        let b34 = self.data[6];
+        let ps  = self.data[7];
+        let magnitude_squared = b12 * b12 + b13 * b13 + b14 * b14 + b23 * b23 + b24 * b24 + b34 * b34;
+        let beta = (b12 * b34 - b13 * b24 + b14 * b23) * (S::one() + S::one());
+        let sin_plus = (magnitude_squared - beta).max(zero).sqrt();
+        let sin_minus = (magnitude_squared + beta).max(zero).sqrt();
+        let cos_plus = s + ps;
+        let cos_minus = s - ps;
+        let factor_plus = if sin_plus.is_zero() { S::one() } else { sin_plus.atan2(cos_plus) / sin_plus };
+        let factor_minus = if sin_minus.is_zero() { S::one() } else { sin_minus.atan2(cos_minus) / sin_minus };
+        let factor_sum = (factor_plus + factor_minus) * one_half;
+        let factor_difference = (factor_plus - factor_minus) * one_half;
+        let mut result = EuclideanMultivector4::new(
+            zero, zero, zero, zero, zero,
+            factor_sum * b12 - factor_difference * b34,
+            factor_sum * b13 + factor_difference * b24,
+            factor_sum * b14 - factor_difference * b23,
+            factor_sum * b23 - factor_difference * b14,
+            factor_sum * b24 + factor_difference * b13,
+            factor_sum * b34 - factor_difference * b12,
+            zero, zero, zero, zero, zero,
+        );
+
+        // A half of the rotor equal to `-1` is a rotation by `pi` in the self-dual
+        // plane `P+ * e12 == (e12 - e34) / 2`, or the anti-self-dual plane
+        // `P- * e12 == (e12 + e34) / 2`.
+        let half_pi = num_traits::cast::<f64, S>(core::f64::consts::FRAC_PI_2).unwrap();
+        if sin_plus.is_zero() && cos_plus < zero {
+            result[5] += half_pi;
+            result[10] -= half_pi;
+        }
+        if sin_minus.is_zero() && cos_minus < zero {
+            result[5] += half_pi;
+            result[10] += half_pi;
+        }
+
+        result
+    }
+
+    /// Rotate a vector by a rotor.
+    ///
+    /// The rotated vector is the sandwich product `R * v * rev(R)`. Only the
+    /// grade one part of `vector` is used, and the result is always a vector.
+    /// The rotor is assumed to have unit magnitude.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e4ga::{
+    /// #     EuclideanMultivector4,
+    /// #     Rotor4,
+    /// # };
+    /// # use core::f64;
+    /// #
+    /// let e14: EuclideanMultivector4<f64> = EuclideanMultivector4::unit_e14();
+    /// let rotor = Rotor4::exp(&(e14 * (-f64::consts::FRAC_PI_4)));
+    /// let vector: EuclideanMultivector4<f64> = EuclideanMultivector4::unit_e1();
+    /// let expected: EuclideanMultivector4<f64> = EuclideanMultivector4::unit_e4();
+    /// let result = rotor.rotate_vector(&vector);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[rustfmt::skip]
+    pub fn rotate_vector(&self, vector: &EuclideanMultivector4<S>) -> EuclideanMultivector4<S> {
+        let zero = S::zero();
+        let two = S::one() + S::one();
+        let s   = self.data[0];
+        let b12 = self.data[1];
+        let b13 = self.data[2];
+        let b14 = self.data[3];
+        let b23 = self.data[4];
+        let b24 = self.data[5];
+        let b34 = self.data[6];
+        let ps  = self.data[7];
+        let v1  = vector[1];
+        let v2  = vector[2];
+        let v3  = vector[3];
+        let v4  = vector[4];
+        let result_e1 = (s * s - b12 * b12 - b13 * b13 - b14 * b14 + b23 * b23 + b24 * b24 + b34 * b34 - ps * ps) * v1
+            + two * (b12 * s + b34 * ps - b13 * b23 - b14 * b24) * v2
+            + two * (b12 * b23 + b13 * s - b14 * b34 - b24 * ps) * v3
+            + two * (b12 * b24 + b13 * b34 + b14 * s + b23 * ps) * v4;
+        let result_e2 = (s * s - b12 * b12 + b13 * b13 + b14 * b14 - b23 * b23 - b24 * b24 + b34 * b34 - ps * ps) * v2
+            - two * (b12 * s + b13 * b23 + b14 * b24 + b34 * ps) * v1
+            + two * (b23 * s + b14 * ps - b12 * b13 - b24 * b34) * v3
+            + two * (b24 * s + b23 * b34 - b12 * b14 - b13 * ps) * v4;
+        let result_e3 = (s * s + b12 * b12 - b13 * b13 + b14 * b14 - b23 * b23 + b24 * b24 - b34 * b34 - ps * ps) * v3
+            + two * (b12 * b23 + b24 * ps - b13 * s - b14 * b34) * v1
+            - two * (b12 * b13 + b14 * ps + b23 * s + b24 * b34) * v2
+            + two * (b12 * ps + b34 * s - b13 * b14 - b23 * b24) * v4;
+        let result_e4 = (s * s + b12 * b12 + b13 * b13 - b14 * b14 + b23 * b23 - b24 * b24 - b34 * b34 - ps * ps) * v4
+            + two * (b12 * b24 + b13 * b34 - b14 * s - b23 * ps) * v1
+            + two * (b13 * ps + b23 * b34 - b12 * b14 - b24 * s) * v2
+            - two * (b12 * ps + b13 * b14 + b23 * b24 + b34 * s) * v3;
+
+        EuclideanMultivector4::new(
+            zero, result_e1, result_e2, result_e3, result_e4, zero, zero, zero,
+            zero, zero, zero, zero, zero, zero, zero, zero,
+        )
+    }
+
+    /// Calculate the squared magnitude of a rotor.
+    ///
+    /// The squared magnitude is the scalar part of `R * rev(R)`.
+    #[inline]
+    pub fn magnitude_squared(&self) -> S {
+        self.data.iter().fold(S::zero(), |acc, &component| acc + component * component)
+    }
+
+    /// Calculate the magnitude of a rotor.
+    #[inline]
+    pub fn magnitude(&self) -> S {
+        self.magnitude_squared().sqrt()
+    }
+
+    /// Normalize a rotor to a unit rotor.
+    ///
+    /// A unit rotor `R` satisfies `R * rev(R) == 1`. For a general even
+    /// element, `R * rev(R)` is `p + q * e1234`, which is not a scalar unless
+    /// `q == 0`. The rotor is normalized by multiplying with the inverse square
+    /// root of `p + q * e1234`, computed separately in the self-dual and
+    /// anti-self-dual halves of the even subalgebra. Repeated composition of
+    /// rotors accumulates rounding errors that cause the result to drift away
+    /// from the set of rotors. Normalizing the rotor projects it back onto the
+    /// set of rotors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e4ga::Rotor4;
+    /// #
+    /// let rotor = Rotor4::new(1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64);
+    /// let normalized = rotor.normalize();
+    /// let identity: Rotor4<f64> = Rotor4::identity();
+    /// let result = normalized * normalized.reverse();
+    ///
+    /// assert_relative_eq!(result, identity, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[rustfmt::skip]
+    pub fn normalize(&self) -> Self {
+        let two = S::one() + S::one();
+        let s   = self.data[0];
+        let b12 = self.data[1];
+        let b13 = self.data[2];
+        let b14 = self.data[3];
+        let b23 = self.data[4];
+        let b24 = self.data[5];
+        let b34 = self.data[6];
+        let ps  = self.data[7];
+        let p = self.magnitude_squared();
+        let q = two * (s * ps + b13 * b24 - b12 * b34 - b14 * b23);
+        let one_over_sqrt_plus = S::one() / (p + q).sqrt();
+        let one_over_sqrt_minus = S::one() / (p - q).sqrt();
+        let a = (one_over_sqrt_plus + one_over_sqrt_minus) / two;
+        let b = (one_over_sqrt_plus - one_over_sqrt_minus) / two;
+
+        // Multiply by `a + b * e1234`, where `e1234 * R` has components
+        // `(ps, -b34, b24, -b23, -b14, b13, -b12, s)`.
+        Self::new(
+            a * s + b * ps,
+            a * b12 - b * b34,
+            a * b13 + b * b24,
+            a * b14 - b * b23,
+            a * b23 - b * b14,
+            a * b24 + b * b13,
+            a * b34 - b * b12,
+            a * ps + b * s,
+        )
+    }
+
+    /// Compute the inverse of a unit rotor.
+    ///
+    /// The inverse of a unit rotor is its reverse.
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        self.reverse()
+    }
+}
+
+impl<S> ops::Index<usize> for Rotor4<S>
+where
+    S: Scalar,
+{
+    type Output = S;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.data[index]
+    }
+}
+
+impl<S> ops::IndexMut<usize> for Rotor4<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.data[index]
+    }
+}
+
+impl<S> AsRef<[S; 8]> for Rotor4<S> {
+    #[inline]
+    fn as_ref(&self) -> &[S; 8] {
+        unsafe { &*(self as *const Rotor4<S> as *const [S; 8]) }
+    }
+}
+
+impl<S> AsMut<[S; 8]> for Rotor4<S> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [S; 8] {
+        unsafe { &mut *(self as *mut Rotor4<S> as *mut [S; 8]) }
+    }
+}
+
+impl<S> fmt::Display for Rotor4<S>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "{} + {}^e12 + {}^e13 + {}^e14 + {}^e23 + {}^e24 + {}^e34 + {}^e1234",
+            self.data[0], self.data[1], self.data[2], self.data[3], self.data[4], self.data[5], self.data[6], self.data[7]
+        )
+    }
+}
+
+impl<S> From<Rotor4<S>> for EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    #[rustfmt::skip]
+    #[inline]
+    fn from(rotor: Rotor4<S>) -> EuclideanMultivector4<S> {
+        let zero = S::zero();
+
+        EuclideanMultivector4::new(
+            rotor.data[0], zero, zero, zero, zero, rotor.data[1], rotor.data[2], rotor.data[3],
+            rotor.data[4], rotor.data[5], rotor.data[6], zero, zero, zero, zero, rotor.data[7],
+        )
+    }
+}
+
+impl<S> From<&Rotor4<S>> for EuclideanMultivector4<S>
+where
+    S: Scalar,
+{
+    #[rustfmt::skip]
+    #[inline]
+    fn from(rotor: &Rotor4<S>) -> EuclideanMultivector4<S> {
+        let zero = S::zero();
+
+        EuclideanMultivector4::new(
+            rotor.data[0], zero, zero, zero, zero, rotor.data[1], rotor.data[2], rotor.data[3],
+            rotor.data[4], rotor.data[5], rotor.data[6], zero, zero, zero, zero, rotor.data[7],
+        )
+    }
+}
+
+macro_rules! impl_rotor_mul_ops {
+    ($Lhs:ty, $Rhs:ty) => {
+        impl<S> ops::Mul<$Rhs> for $Lhs
+        where
+            S: Scalar,
+        {
+            type Output = Rotor4<S>;
+
+            #[rustfmt::skip]
+            #[inline]
+            fn mul(self, other: $Rhs) -> Self::Output {
+                let a = self;
+                let b = other;
+                let result_1     = a[0] * b[0] - a[1] * b[1] - a[2] * b[2] - a[3] * b[3] - a[4] * b[4] - a[5] * b[5] - a[6] * b[6] + a[7] * b[7];
+                let result_e12   = a[0] * b[1] + a[1] * b[0] - a[2] * b[4] - a[3] * b[5] + a[4] * b[2] + a[5] * b[3] - a[6] * b[7] - a[7] * b[6];
+                let result_e13   = a[0] * b[2] + a[1] * b[4] + a[2] * b[0] - a[3] * b[6] - a[4] * b[1] + a[5] * b[7] + a[6] * b[3] + a[7] * b[5];
+                let result_e14   = a[0] * b[3] + a[1] * b[5] + a[2] * b[6] + a[3] * b[0] - a[4] * b[7] - a[5] * b[1] - a[6] * b[2] - a[7] * b[4];
+                let result_e23   = a[0] * b[4] - a[1] * b[2] + a[2] * b[1] - a[3] * b[7] + a[4] * b[0] - a[5] * b[6] + a[6] * b[5] - a[7] * b[3];
+                let result_e24   = a[0] * b[5] - a[1] * b[3] + a[2] * b[7] + a[3] * b[1] + a[4] * b[6] + a[5] * b[0] - a[6] * b[4] + a[7] * b[2];
+                let result_e34   = a[0] * b[6] - a[1] * b[7] - a[2] * b[3] + a[3] * b[2] - a[4] * b[5] + a[5] * b[4] + a[6] * b[0] - a[7] * b[1];
+                let result_e1234 = a[0] * b[7] + a[1] * b[6] - a[2] * b[5] + a[3] * b[4] + a[4] * b[3] - a[5] * b[2] + a[6] * b[1] + a[7] * b[0];
+
+                Rotor4::new(result_1, result_e12, result_e13, result_e14, result_e23, result_e24, result_e34, result_e1234)
+            }
+        }
+    };
+}
+
+impl_rotor_mul_ops!(Rotor4<S>, Rotor4<S>);
+impl_rotor_mul_ops!(Rotor4<S>, &Rotor4<S>);
+impl_rotor_mul_ops!(&Rotor4<S>, Rotor4<S>);
+impl_rotor_mul_ops!(&Rotor4<S>, &Rotor4<S>);
+
+impl<S> ops::Neg for Rotor4<S>
+where
+    S: ScalarSigned,
+{
+    type Output = Rotor4<S>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Rotor4::new(
+            -self.data[0],
+            -self.data[1],
+            -self.data[2],
+            -self.data[3],
+            -self.data[4],
+            -self.data[5],
+            -self.data[6],
+            -self.data[7],
+        )
+    }
+}
+
+impl<S> ops::Neg for &Rotor4<S>
+where
+    S: ScalarSigned,
+{
+    type Output = Rotor4<S>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Rotor4::new(
+            -self.data[0],
+            -self.data[1],
+            -self.data[2],
+            -self.data[3],
+            -self.data[4],
+            -self.data[5],
+            -self.data[6],
+            -self.data[7],
+        )
+    }
+}
+
+
+impl_coords!(ViewR4, { scalar, e12, e13, e14, e23, e24, e34, e1234 });
+impl_coords_deref!(Rotor4, ViewR4);
+
+impl_approx_cmp_ops!(Rotor4);
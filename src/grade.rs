@@ -0,0 +1,236 @@
+use core::fmt;
+use core::ops;
+
+
+/// A set of grades of a multivector, stored as a bit set.
+///
+/// Bit `k` of the mask is set if and only if grade `k` belongs to the set,
+/// so a mask holds any combination of the grades `0` through `31`. Grade masks
+/// combine with the usual set operations `|` (union), `&` (intersection),
+/// `^` (symmetric difference), `-` (difference) and `!` (complement).
+///
+/// # Example
+///
+/// ```
+/// # use cggeomalg::grade::GradeMask;
+/// #
+/// let mask = GradeMask::SCALAR | GradeMask::BIVECTOR;
+///
+/// assert!(mask.contains_grade(0));
+/// assert!(!mask.contains_grade(1));
+/// assert!(mask.contains_grade(2));
+/// assert!(GradeMask::EVEN.contains(mask));
+/// assert_eq!(mask.len(), 2);
+/// ```
+#[repr(transparent)]
+#[derive(Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct GradeMask {
+    bits: u32,
+}
+
+impl GradeMask {
+    /// The grade mask containing only grade zero.
+    pub const SCALAR: Self = Self { bits: 1 << 0 };
+
+    /// The grade mask containing only grade one.
+    pub const VECTOR: Self = Self { bits: 1 << 1 };
+
+    /// The grade mask containing only grade two.
+    pub const BIVECTOR: Self = Self { bits: 1 << 2 };
+
+    /// The grade mask containing only grade three.
+    pub const TRIVECTOR: Self = Self { bits: 1 << 3 };
+
+    /// The grade mask containing every even grade.
+    pub const EVEN: Self = Self { bits: 0x5555_5555 };
+
+    /// The grade mask containing every odd grade.
+    pub const ODD: Self = Self { bits: 0xAAAA_AAAA };
+
+    /// Construct the empty grade mask.
+    #[inline]
+    pub const fn empty() -> Self {
+        Self { bits: 0 }
+    }
+
+    /// Construct the grade mask containing every grade.
+    #[inline]
+    pub const fn all() -> Self {
+        Self { bits: u32::MAX }
+    }
+
+    /// Construct a grade mask from its underlying bit set.
+    #[inline]
+    pub const fn from_bits(bits: u32) -> Self {
+        Self { bits }
+    }
+
+    /// Returns the underlying bit set of a grade mask.
+    #[inline]
+    pub const fn bits(self) -> u32 {
+        self.bits
+    }
+
+    /// Construct the grade mask containing only the grade `grade`.
+    ///
+    /// Returns `None` if `grade` does not fit into a grade mask.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::grade::GradeMask;
+    /// #
+    /// assert_eq!(GradeMask::from_grade(2), Some(GradeMask::BIVECTOR));
+    /// assert_eq!(GradeMask::from_grade(32), None);
+    /// ```
+    #[inline]
+    pub const fn from_grade(grade: usize) -> Option<Self> {
+        if grade < u32::BITS as usize {
+            Some(Self { bits: 1 << grade })
+        } else {
+            None
+        }
+    }
+
+    /// Determine whether a grade mask contains no grades.
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.bits == 0
+    }
+
+    /// Returns the number of grades in a grade mask.
+    #[inline]
+    pub const fn len(self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    /// Determine whether a grade mask contains the grade `grade`.
+    #[inline]
+    pub const fn contains_grade(self, grade: usize) -> bool {
+        grade < u32::BITS as usize && self.bits & (1 << grade) != 0
+    }
+
+    /// Determine whether a grade mask contains every grade of `other`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.bits & other.bits == other.bits
+    }
+
+    /// Determine whether two grade masks have a grade in common.
+    #[inline]
+    pub const fn intersects(self, other: Self) -> bool {
+        self.bits & other.bits != 0
+    }
+
+    /// Add the grades of `other` to a grade mask.
+    #[inline]
+    pub fn insert(&mut self, other: Self) {
+        self.bits |= other.bits;
+    }
+
+    /// Remove the grades of `other` from a grade mask.
+    #[inline]
+    pub fn remove(&mut self, other: Self) {
+        self.bits &= !other.bits;
+    }
+
+    /// Returns an iterator over the grades in a grade mask in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::grade::GradeMask;
+    /// #
+    /// let mask = GradeMask::VECTOR | GradeMask::TRIVECTOR;
+    /// let mut grades = mask.iter();
+    ///
+    /// assert_eq!(grades.next(), Some(1));
+    /// assert_eq!(grades.next(), Some(3));
+    /// assert_eq!(grades.next(), None);
+    /// ```
+    #[inline]
+    pub fn iter(self) -> impl Iterator<Item = usize> {
+        (0..u32::BITS as usize).filter(move |&grade| self.contains_grade(grade))
+    }
+}
+
+impl fmt::Debug for GradeMask {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("GradeMask(")?;
+        formatter.debug_set().entries(self.iter()).finish()?;
+        formatter.write_str(")")
+    }
+}
+
+impl ops::BitOr for GradeMask {
+    type Output = GradeMask;
+
+    #[inline]
+    fn bitor(self, other: GradeMask) -> Self::Output {
+        GradeMask::from_bits(self.bits | other.bits)
+    }
+}
+
+impl ops::BitAnd for GradeMask {
+    type Output = GradeMask;
+
+    #[inline]
+    fn bitand(self, other: GradeMask) -> Self::Output {
+        GradeMask::from_bits(self.bits & other.bits)
+    }
+}
+
+impl ops::BitXor for GradeMask {
+    type Output = GradeMask;
+
+    #[inline]
+    fn bitxor(self, other: GradeMask) -> Self::Output {
+        GradeMask::from_bits(self.bits ^ other.bits)
+    }
+}
+
+impl ops::Sub for GradeMask {
+    type Output = GradeMask;
+
+    #[inline]
+    fn sub(self, other: GradeMask) -> Self::Output {
+        GradeMask::from_bits(self.bits & !other.bits)
+    }
+}
+
+impl ops::Not for GradeMask {
+    type Output = GradeMask;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        GradeMask::from_bits(!self.bits)
+    }
+}
+
+impl ops::BitOrAssign for GradeMask {
+    #[inline]
+    fn bitor_assign(&mut self, other: GradeMask) {
+        self.bits |= other.bits;
+    }
+}
+
+impl ops::BitAndAssign for GradeMask {
+    #[inline]
+    fn bitand_assign(&mut self, other: GradeMask) {
+        self.bits &= other.bits;
+    }
+}
+
+impl ops::BitXorAssign for GradeMask {
+    #[inline]
+    fn bitxor_assign(&mut self, other: GradeMask) {
+        self.bits ^= other.bits;
+    }
+}
+
+impl ops::SubAssign for GradeMask {
+    #[inline]
+    fn sub_assign(&mut self, other: GradeMask) {
+        self.bits &= !other.bits;
+    }
+}
@@ -14,8 +14,18 @@ extern crate approx_cmp;
 extern crate num_traits;
 
 
+mod approx;
+mod complex;
 mod coordinates;
 
+pub mod cga2;
+pub mod cga3;
+pub mod clifford;
 pub mod e2ga;
 pub mod e3ga;
+pub mod e4ga;
+pub mod grade;
+pub mod pga2;
+pub mod pga3;
 pub mod scalar;
+pub mod sta;
@@ -0,0 +1,2978 @@
+use crate::scalar::{
+    Scalar,
+    ScalarFloat,
+    ScalarSigned,
+};
+use crate::{
+    impl_approx_cmp_ops,
+    impl_coords,
+    impl_coords_deref,
+};
+use core::fmt;
+use core::ops;
+
+
+/// A stack-allocated, two-dimensional projective multivector in the basis
+/// `{1, e0, e1, e2, e01, e20, e12, e012}`.
+///
+/// The basis vectors satisfy `e1 * e1 == e2 * e2 == 1` and `e0 * e0 == 0`,
+/// i.e. this is the geometric algebra `Cl(2, 0, 1)`. The null basis vector
+/// `e0` represents the line at infinity. Vectors represent lines, and
+/// bivectors represent points, so that the outer product `^` computes the
+/// meet (intersection) of geometric objects, and the regressive product `&`
+/// computes their join. Because `e0` is null, translations are versors in
+/// projective geometric algebra, just like rotations, and their products are
+/// the motors, i.e. the rigid motions of the plane.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ProjectiveMultivector2<S> {
+    data: [S; 8],
+}
+
+impl<S> ProjectiveMultivector2<S> {
+    /// Construct a new general multivector.
+    #[inline]
+    pub const fn new(scalar: S, e0: S, e1: S, e2: S, e01: S, e20: S, e12: S, e012: S) -> Self {
+        Self {
+            data: [scalar, e0, e1, e2, e01, e20, e12, e012],
+        }
+    }
+
+    /// Returns the number of components in a multivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// #
+    /// let mv = ProjectiveMultivector2::new(1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32);
+    ///
+    /// assert_eq!(mv.len(), 8);
+    /// ```
+    #[inline]
+    pub const fn len(&self) -> usize {
+        8
+    }
+
+    /// Get a pointer to the underlying component array.
+    #[inline]
+    pub const fn as_ptr(&self) -> *const S {
+        &self.data[0]
+    }
+
+    /// Get a mutable pointer to the underlying component array.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut S {
+        &mut self.data[0]
+    }
+
+    /// Get a slice of the underlying elements of the data type.
+    #[inline]
+    pub fn as_slice(&self) -> &[S] {
+        <Self as AsRef<[S; 8]>>::as_ref(self)
+    }
+}
+
+impl<S> ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    /// Construct the additive unit (zero) multivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// #
+    /// let mv = ProjectiveMultivector2::new(
+    ///     1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64
+    /// );
+    /// let zero: ProjectiveMultivector2<f64> = ProjectiveMultivector2::zero();
+    ///
+    /// assert_eq!(mv + zero, mv);
+    /// assert_eq!(zero + mv, mv);
+    /// ```
+    #[inline]
+    pub fn zero() -> Self {
+        Self { data: [S::zero(); 8] }
+    }
+
+    /// Determine whether a multivector is the zero mutlivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// #
+    /// let zero: ProjectiveMultivector2<f64> = ProjectiveMultivector2::zero();
+    ///
+    /// assert!(zero.is_zero());
+    ///
+    /// let mv = ProjectiveMultivector2::new(
+    ///     1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64
+    /// );
+    ///
+    /// assert!(!mv.is_zero());
+    /// ```
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.data.iter().all(|component| component.is_zero())
+    }
+
+    /// Construct a new multivector from the scalar part only.
+    ///
+    /// A scalar is a multivector whose vector, bivector, etc. components are
+    /// all zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// #
+    /// let scalar_part = 2;
+    /// let scalar = ProjectiveMultivector2::from_scalar(scalar_part);
+    ///
+    /// assert_eq!(scalar.scalar, scalar_part);
+    /// assert_eq!(scalar.e0, 0);
+    /// assert_eq!(scalar.e1, 0);
+    /// assert_eq!(scalar.e2, 0);
+    /// assert_eq!(scalar.e01, 0);
+    /// assert_eq!(scalar.e20, 0);
+    /// assert_eq!(scalar.e12, 0);
+    /// assert_eq!(scalar.e012, 0);
+    /// ```
+    #[inline]
+    pub fn from_scalar(scalar: S) -> Self {
+        let mut result = Self::zero();
+        result.data[0] = scalar;
+
+        result
+    }
+
+    /// Returns the unit scalar.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// #
+    /// let unit_scalar: ProjectiveMultivector2<isize> = ProjectiveMultivector2::unit_scalar();
+    ///
+    /// assert_eq!(unit_scalar.scalar, 1);
+    /// assert_eq!(unit_scalar.e0, 0);
+    /// assert_eq!(unit_scalar.e1, 0);
+    /// assert_eq!(unit_scalar.e2, 0);
+    /// assert_eq!(unit_scalar.e01, 0);
+    /// assert_eq!(unit_scalar.e20, 0);
+    /// assert_eq!(unit_scalar.e12, 0);
+    /// assert_eq!(unit_scalar.e012, 0);
+    /// ```
+    #[inline]
+    pub fn unit_scalar() -> Self {
+        let mut result = Self::zero();
+        result.data[0] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e0` vector, which represents the line at infinity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// #
+    /// let unit_e0: ProjectiveMultivector2<isize> = ProjectiveMultivector2::unit_e0();
+    ///
+    /// assert_eq!(unit_e0.scalar, 0);
+    /// assert_eq!(unit_e0.e0, 1);
+    /// assert_eq!(unit_e0.e1, 0);
+    /// assert_eq!(unit_e0.e2, 0);
+    /// assert_eq!(unit_e0.e01, 0);
+    /// assert_eq!(unit_e0.e20, 0);
+    /// assert_eq!(unit_e0.e12, 0);
+    /// assert_eq!(unit_e0.e012, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e0() -> Self {
+        let mut result = Self::zero();
+        result.data[1] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `x`-axis vector, which represents the line `x == 0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// #
+    /// let unit_e1: ProjectiveMultivector2<isize> = ProjectiveMultivector2::unit_e1();
+    ///
+    /// assert_eq!(unit_e1.scalar, 0);
+    /// assert_eq!(unit_e1.e0, 0);
+    /// assert_eq!(unit_e1.e1, 1);
+    /// assert_eq!(unit_e1.e2, 0);
+    /// assert_eq!(unit_e1.e01, 0);
+    /// assert_eq!(unit_e1.e20, 0);
+    /// assert_eq!(unit_e1.e12, 0);
+    /// assert_eq!(unit_e1.e012, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e1() -> Self {
+        let mut result = Self::zero();
+        result.data[2] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `y`-axis vector, which represents the line `y == 0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// #
+    /// let unit_e2: ProjectiveMultivector2<isize> = ProjectiveMultivector2::unit_e2();
+    ///
+    /// assert_eq!(unit_e2.scalar, 0);
+    /// assert_eq!(unit_e2.e0, 0);
+    /// assert_eq!(unit_e2.e1, 0);
+    /// assert_eq!(unit_e2.e2, 1);
+    /// assert_eq!(unit_e2.e01, 0);
+    /// assert_eq!(unit_e2.e20, 0);
+    /// assert_eq!(unit_e2.e12, 0);
+    /// assert_eq!(unit_e2.e012, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e2() -> Self {
+        let mut result = Self::zero();
+        result.data[3] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e01` bivector, which represents the `y`-axis direction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// #
+    /// let unit_e01: ProjectiveMultivector2<isize> = ProjectiveMultivector2::unit_e01();
+    ///
+    /// assert_eq!(unit_e01.scalar, 0);
+    /// assert_eq!(unit_e01.e0, 0);
+    /// assert_eq!(unit_e01.e1, 0);
+    /// assert_eq!(unit_e01.e2, 0);
+    /// assert_eq!(unit_e01.e01, 1);
+    /// assert_eq!(unit_e01.e20, 0);
+    /// assert_eq!(unit_e01.e12, 0);
+    /// assert_eq!(unit_e01.e012, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e01() -> Self {
+        let mut result = Self::zero();
+        result.data[4] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e20` bivector, which represents the `x`-axis direction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// #
+    /// let unit_e20: ProjectiveMultivector2<isize> = ProjectiveMultivector2::unit_e20();
+    ///
+    /// assert_eq!(unit_e20.scalar, 0);
+    /// assert_eq!(unit_e20.e0, 0);
+    /// assert_eq!(unit_e20.e1, 0);
+    /// assert_eq!(unit_e20.e2, 0);
+    /// assert_eq!(unit_e20.e01, 0);
+    /// assert_eq!(unit_e20.e20, 1);
+    /// assert_eq!(unit_e20.e12, 0);
+    /// assert_eq!(unit_e20.e012, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e20() -> Self {
+        let mut result = Self::zero();
+        result.data[5] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e12` bivector, which represents the origin.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// #
+    /// let unit_e12: ProjectiveMultivector2<isize> = ProjectiveMultivector2::unit_e12();
+    ///
+    /// assert_eq!(unit_e12.scalar, 0);
+    /// assert_eq!(unit_e12.e0, 0);
+    /// assert_eq!(unit_e12.e1, 0);
+    /// assert_eq!(unit_e12.e2, 0);
+    /// assert_eq!(unit_e12.e01, 0);
+    /// assert_eq!(unit_e12.e20, 0);
+    /// assert_eq!(unit_e12.e12, 1);
+    /// assert_eq!(unit_e12.e012, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e12() -> Self {
+        let mut result = Self::zero();
+        result.data[6] = S::one();
+
+        result
+    }
+
+    /// Returns the unit volume element `e012`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// #
+    /// let unit_e012: ProjectiveMultivector2<isize> = ProjectiveMultivector2::unit_e012();
+    ///
+    /// assert_eq!(unit_e012.scalar, 0);
+    /// assert_eq!(unit_e012.e0, 0);
+    /// assert_eq!(unit_e012.e1, 0);
+    /// assert_eq!(unit_e012.e2, 0);
+    /// assert_eq!(unit_e012.e01, 0);
+    /// assert_eq!(unit_e012.e20, 0);
+    /// assert_eq!(unit_e012.e12, 0);
+    /// assert_eq!(unit_e012.e012, 1);
+    /// ```
+    #[inline]
+    pub fn unit_e012() -> Self {
+        let mut result = Self::zero();
+        result.data[7] = S::one();
+
+        result
+    }
+
+    /// Returns the unit volume element for two-dimensional projective geometric algebra.
+    ///
+    /// This is a synonym for `unit_e012`.
+    #[inline(always)]
+    pub fn pseudoscalar() -> Self {
+        Self::unit_e012()
+    }
+
+    /// Project the multivector onto the grade `grade`.
+    ///
+    /// Return a multivector where the components of each grade other than
+    /// input grade are zero. For each grade larger than the dimension of the
+    /// underlying vector space, the grade projection is always zero. In this
+    /// case, any grade projection onto a grade larger than 3 will be zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// #
+    /// let mv: ProjectiveMultivector2<isize> = ProjectiveMultivector2::new(1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize);
+    /// let expected_0 = ProjectiveMultivector2::new(1_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize);
+    /// let mv_0 = mv.grade(0);
+    /// let expected_1 = ProjectiveMultivector2::new(0_isize, 1_isize, 1_isize, 1_isize, 0_isize, 0_isize, 0_isize, 0_isize);
+    /// let mv_1 = mv.grade(1);
+    /// let expected_2 = ProjectiveMultivector2::new(0_isize, 0_isize, 0_isize, 0_isize, 1_isize, 1_isize, 1_isize, 0_isize);
+    /// let mv_2 = mv.grade(2);
+    /// let expected_3 = ProjectiveMultivector2::new(0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 1_isize);
+    /// let mv_3 = mv.grade(3);
+    ///
+    /// assert_eq!(mv_0, expected_0);
+    /// assert_eq!(mv_1, expected_1);
+    /// assert_eq!(mv_2, expected_2);
+    /// assert_eq!(mv_3, expected_3);
+    ///
+    /// // Any grade larger than 3 should be zero.
+    /// let zero: ProjectiveMultivector2<isize> = ProjectiveMultivector2::zero();
+    /// assert_eq!(mv.grade(4), zero);
+    /// assert_eq!(mv.grade(usize::MAX), zero);
+    /// ```
+    #[inline]
+    pub fn grade(&self, grade: usize) -> Self {
+        let mut result = Self::zero();
+        match grade {
+            0 => {
+                result.data[0] = self.data[0];
+            }
+            1 => {
+                result.data[1] = self.data[1];
+                result.data[2] = self.data[2];
+                result.data[3] = self.data[3];
+            }
+            2 => {
+                result.data[4] = self.data[4];
+                result.data[5] = self.data[5];
+                result.data[6] = self.data[6];
+            }
+            3 => {
+                result.data[7] = self.data[7];
+            }
+            _ => {}
+        }
+
+        result
+    }
+
+    /// Compute the left contraction of `self` with `other`.
+    ///
+    /// This is a synonym for the `<<` operator.
+    #[inline]
+    pub fn left_contract(&self, other: &Self) -> Self {
+        self << other
+    }
+
+    /// Compute the right contraction of `self` with `other`.
+    ///
+    /// This is a synonym for the `>>` operator.
+    #[inline]
+    pub fn right_contract(&self, other: &Self) -> Self {
+        self >> other
+    }
+
+    /// Compute the scalar product of `self` and `other`.
+    ///
+    /// This is a synonym for the `|` operator.
+    #[inline]
+    pub fn scalar_product(&self, other: &Self) -> Self {
+        self | other
+    }
+
+    /// Compute the outer product of `self` and `other`.
+    ///
+    /// This is a synonym for the `^` operator.
+    #[inline]
+    pub fn outer_product(&self, other: &Self) -> Self {
+        self ^ other
+    }
+
+    /// Compute the regressive product of `self` and `other`.
+    ///
+    /// The regressive product is the outer product of the duals, mapped back
+    /// by the inverse of the duality map
+    /// ```text
+    /// mv1 & mv2 := undual(dual(mv1) ^ dual(mv2))
+    /// ```
+    /// In projective geometric algebra, the regressive product computes the
+    /// join of geometric objects. For instance, the join of two points is the
+    /// line through both points.
+    ///
+    /// This is a synonym for the `&` operator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// #
+    /// let e12: ProjectiveMultivector2<i32> = ProjectiveMultivector2::unit_e12();
+    /// let e20: ProjectiveMultivector2<i32> = ProjectiveMultivector2::unit_e20();
+    /// let expected: ProjectiveMultivector2<i32> = ProjectiveMultivector2::unit_e2();
+    /// let result = e12.regressive_product(&e20);
+    ///
+    /// assert_eq!(result, expected);
+    /// assert_eq!(result, (e12.dual() ^ e20.dual()).undual());
+    /// ```
+    #[inline]
+    pub fn regressive_product(&self, other: &Self) -> Self {
+        self & other
+    }
+}
+
+impl<S> ops::Index<usize> for ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = S;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.data[index]
+    }
+}
+
+impl<S> ops::IndexMut<usize> for ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.data[index]
+    }
+}
+
+impl<S> AsRef<[S; 8]> for ProjectiveMultivector2<S> {
+    #[inline]
+    fn as_ref(&self) -> &[S; 8] {
+        unsafe { &*(self as *const ProjectiveMultivector2<S> as *const [S; 8]) }
+    }
+}
+
+impl<S> AsMut<[S; 8]> for ProjectiveMultivector2<S> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [S; 8] {
+        unsafe { &mut *(self as *mut ProjectiveMultivector2<S> as *mut [S; 8]) }
+    }
+}
+
+impl<S> fmt::Display for ProjectiveMultivector2<S>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "{} + {}^e0 + {}^e1 + {}^e2 + {}^e01 + {}^e20 + {}^e12 + {}^e012",
+            self.data[0], self.data[1], self.data[2], self.data[3], self.data[4], self.data[5], self.data[6], self.data[7]
+        )
+    }
+}
+
+
+impl<S> ProjectiveMultivector2<S>
+where
+    S: ScalarSigned,
+{
+    /// Compute the reverse of a multivector.
+    ///
+    /// The reverse of a multivector reverses the order of the vectors in each
+    /// basis blade. For each grade of multivector it is given by
+    /// ```text
+    /// When mv has grade 0, rev(mv) := mv
+    /// When mv has grade 1, rev(mv) := mv
+    /// When mv has grade 2, rev(mv) := -mv
+    /// When mv has grade 3, rev(mv) := -mv
+    /// ```
+    /// The reverse of each basis blade is
+    /// ```text
+    /// rev(1)    = 1
+    /// rev(e0)   = e0
+    /// rev(e1)   = e1
+    /// rev(e2)   = e2
+    /// rev(e01)  = -e01
+    /// rev(e20)  = -e20
+    /// rev(e12)  = -e12
+    /// rev(e012) = -e012
+    /// ```
+    /// and the reverse of a general multivector follows by linearity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// #
+    /// let mv = ProjectiveMultivector2::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32
+    /// );
+    /// let expected = ProjectiveMultivector2::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, -5_i32, -6_i32, -7_i32, -8_i32
+    /// );
+    /// let result = mv.reverse();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn reverse(&self) -> Self {
+        let mut result = *self;
+        result.reverse_mut();
+
+        result
+    }
+
+    /// Compute the reverse of a multivector mutably in place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// #
+    /// let mut result = ProjectiveMultivector2::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32
+    /// );
+    /// let expected = ProjectiveMultivector2::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, -5_i32, -6_i32, -7_i32, -8_i32
+    /// );
+    /// result.reverse_mut();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn reverse_mut(&mut self) {
+        self.data[4] = -self.data[4];
+        self.data[5] = -self.data[5];
+        self.data[6] = -self.data[6];
+        self.data[7] = -self.data[7];
+    }
+
+    /// Compute the conjugate of a multivector.
+    ///
+    /// The conjugate of a multivector is the composition of the reverse and the
+    /// grade involution. For each grade of multivector it is given by
+    /// ```text
+    /// When mv has grade 0, conj(mv) := mv
+    /// When mv has grade 1, conj(mv) := -mv
+    /// When mv has grade 2, conj(mv) := -mv
+    /// When mv has grade 3, conj(mv) := mv
+    /// ```
+    /// The conjugate of each basis blade is
+    /// ```text
+    /// conj(1)    = 1
+    /// conj(e0)   = -e0
+    /// conj(e1)   = -e1
+    /// conj(e2)   = -e2
+    /// conj(e01)  = -e01
+    /// conj(e20)  = -e20
+    /// conj(e12)  = -e12
+    /// conj(e012) = e012
+    /// ```
+    /// and the conjugate of a general multivector follows by linearity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// #
+    /// let mv = ProjectiveMultivector2::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32
+    /// );
+    /// let expected = ProjectiveMultivector2::new(
+    ///     1_i32, -2_i32, -3_i32, -4_i32, -5_i32, -6_i32, -7_i32, 8_i32
+    /// );
+    /// let result = mv.conjugate();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn conjugate(&self) -> Self {
+        let mut result = *self;
+        result.conjugate_mut();
+
+        result
+    }
+
+    /// Compute the conjugate of a multivector mutably in place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// #
+    /// let mut result = ProjectiveMultivector2::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32
+    /// );
+    /// let expected = ProjectiveMultivector2::new(
+    ///     1_i32, -2_i32, -3_i32, -4_i32, -5_i32, -6_i32, -7_i32, 8_i32
+    /// );
+    /// result.conjugate_mut();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn conjugate_mut(&mut self) {
+        self.data[1] = -self.data[1];
+        self.data[2] = -self.data[2];
+        self.data[3] = -self.data[3];
+        self.data[4] = -self.data[4];
+        self.data[5] = -self.data[5];
+        self.data[6] = -self.data[6];
+    }
+
+    /// Compute the grade involution of a multivector.
+    ///
+    /// The grade involution of a multivector negates the components of odd grade.
+    /// For each grade of multivector it is given by
+    /// ```text
+    /// When mv has grade 0, invol(mv) := mv
+    /// When mv has grade 1, invol(mv) := -mv
+    /// When mv has grade 2, invol(mv) := mv
+    /// When mv has grade 3, invol(mv) := -mv
+    /// ```
+    /// The grade involution of each basis blade is
+    /// ```text
+    /// invol(1)    = 1
+    /// invol(e0)   = -e0
+    /// invol(e1)   = -e1
+    /// invol(e2)   = -e2
+    /// invol(e01)  = e01
+    /// invol(e20)  = e20
+    /// invol(e12)  = e12
+    /// invol(e012) = -e012
+    /// ```
+    /// and the grade involution of a general multivector follows by linearity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// #
+    /// let mv = ProjectiveMultivector2::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32
+    /// );
+    /// let expected = ProjectiveMultivector2::new(
+    ///     1_i32, -2_i32, -3_i32, -4_i32, 5_i32, 6_i32, 7_i32, -8_i32
+    /// );
+    /// let result = mv.involute();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn involute(&self) -> Self {
+        let mut result = *self;
+        result.involute_mut();
+
+        result
+    }
+
+    /// Compute the grade involution of a multivector mutably in place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// #
+    /// let mut result = ProjectiveMultivector2::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32
+    /// );
+    /// let expected = ProjectiveMultivector2::new(
+    ///     1_i32, -2_i32, -3_i32, -4_i32, 5_i32, 6_i32, 7_i32, -8_i32
+    /// );
+    /// result.involute_mut();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn involute_mut(&mut self) {
+        self.data[1] = -self.data[1];
+        self.data[2] = -self.data[2];
+        self.data[3] = -self.data[3];
+        self.data[7] = -self.data[7];
+    }
+
+    /// Compute the dual of a multivector.
+    ///
+    /// The dual of a multivector is also known as the orthogonal complement.
+    /// In projective geometric algebra the pseudoscalar `e012` is not invertible,
+    /// so the dual is not defined by the geometric product with the inverse
+    /// pseudoscalar as in the Euclidean algebras. Instead, the dual of each basis
+    /// blade is its right complement, i.e. the basis blade spanning the remaining
+    /// basis directions, with its sign chosen such that the outer product of the
+    /// basis blade and its dual is the pseudoscalar. The dual maps lines to
+    /// points and points to lines, and is used to define the regressive product.
+    ///
+    /// The dual of each basis blade is
+    /// ```text
+    /// dual(1)    = e012
+    /// dual(e0)   = e12
+    /// dual(e1)   = e20
+    /// dual(e2)   = e01
+    /// dual(e01)  = e2
+    /// dual(e20)  = e1
+    /// dual(e12)  = e0
+    /// dual(e012) = 1
+    /// ```
+    /// so that `e_A ^ dual(e_A) == e012` for every basis blade `e_A`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// #
+    /// let mv = ProjectiveMultivector2::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32
+    /// );
+    /// let expected = ProjectiveMultivector2::new(
+    ///     8_i32, 7_i32, 6_i32, 5_i32, 4_i32, 3_i32, 2_i32, 1_i32
+    /// );
+    /// let result = mv.dual();
+    ///
+    /// assert_eq!(result, expected);
+    /// assert_eq!(result.undual(), mv);
+    /// ```
+    #[rustfmt::skip]
+    pub fn dual(&self) -> Self {
+        let mut result = Self::zero();
+        result.data[0]  =  self.data[7];
+        result.data[1]  =  self.data[6];
+        result.data[2]  =  self.data[5];
+        result.data[3]  =  self.data[4];
+        result.data[4]  =  self.data[3];
+        result.data[5]  =  self.data[2];
+        result.data[6]  =  self.data[1];
+        result.data[7]  =  self.data[0];
+
+        result
+    }
+
+    /// Compute the dual of a multivector mutably in place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// #
+    /// let mut result = ProjectiveMultivector2::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32
+    /// );
+    /// let expected = ProjectiveMultivector2::new(
+    ///     8_i32, 7_i32, 6_i32, 5_i32, 4_i32, 3_i32, 2_i32, 1_i32
+    /// );
+    /// result.dual_mut();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn dual_mut(&mut self) {
+        *self = self.dual();
+    }
+
+    /// Compute the undual of a multivector.
+    ///
+    /// The undual is the inverse of the dual, i.e. `undual(dual(mv)) == mv`
+    /// and `dual(undual(mv)) == mv` for every multivector `mv`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// #
+    /// let mv = ProjectiveMultivector2::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32
+    /// );
+    ///
+    /// assert_eq!(mv.dual().undual(), mv);
+    /// assert_eq!(mv.undual().dual(), mv);
+    /// ```
+    #[rustfmt::skip]
+    pub fn undual(&self) -> Self {
+        let mut result = Self::zero();
+        result.data[0]  =  self.data[7];
+        result.data[1]  =  self.data[6];
+        result.data[2]  =  self.data[5];
+        result.data[3]  =  self.data[4];
+        result.data[4]  =  self.data[3];
+        result.data[5]  =  self.data[2];
+        result.data[6]  =  self.data[1];
+        result.data[7]  =  self.data[0];
+
+        result
+    }
+}
+
+impl<S> ops::Not for ProjectiveMultivector2<S>
+where
+    S: ScalarSigned,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        self.dual()
+    }
+}
+
+impl<S> ops::Not for &ProjectiveMultivector2<S>
+where
+    S: ScalarSigned,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        self.dual()
+    }
+}
+
+impl<S> ops::Neg for ProjectiveMultivector2<S>
+where
+    S: ScalarSigned,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn neg(self) -> Self::Output {
+        let result_1    = -self.data[0];
+        let result_e0   = -self.data[1];
+        let result_e1   = -self.data[2];
+        let result_e2   = -self.data[3];
+        let result_e01  = -self.data[4];
+        let result_e20  = -self.data[5];
+        let result_e12  = -self.data[6];
+        let result_e012 = -self.data[7];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::Neg for &ProjectiveMultivector2<S>
+where
+    S: ScalarSigned,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn neg(self) -> Self::Output {
+        let result_1    = -self.data[0];
+        let result_e0   = -self.data[1];
+        let result_e1   = -self.data[2];
+        let result_e2   = -self.data[3];
+        let result_e01  = -self.data[4];
+        let result_e20  = -self.data[5];
+        let result_e12  = -self.data[6];
+        let result_e012 = -self.data[7];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::Mul<ProjectiveMultivector2<S>> for ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: ProjectiveMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1    = a[0] * b[0] + a[2] * b[2] + a[3] * b[3] - a[6] * b[6];
+        let result_e0   = a[0] * b[1] + a[1] * b[0] - a[2] * b[4] + a[3] * b[5] + a[4] * b[2] - a[5] * b[3] - a[6] * b[7] - a[7] * b[6];
+        let result_e1   = a[0] * b[2] + a[2] * b[0] - a[3] * b[6] + a[6] * b[3];
+        let result_e2   = a[0] * b[3] + a[2] * b[6] + a[3] * b[0] - a[6] * b[2];
+        let result_e01  = a[0] * b[4] + a[1] * b[2] - a[2] * b[1] + a[3] * b[7] + a[4] * b[0] + a[5] * b[6] - a[6] * b[5] + a[7] * b[3];
+        let result_e20  = a[0] * b[5] - a[1] * b[3] + a[2] * b[7] + a[3] * b[1] - a[4] * b[6] + a[5] * b[0] + a[6] * b[4] + a[7] * b[2];
+        let result_e12  = a[0] * b[6] + a[2] * b[3] - a[3] * b[2] + a[6] * b[0];
+        let result_e012 = a[0] * b[7] + a[1] * b[6] + a[2] * b[5] + a[3] * b[4] + a[4] * b[3] + a[5] * b[2] + a[6] * b[1] + a[7] * b[0];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::Mul<&ProjectiveMultivector2<S>> for ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: &ProjectiveMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1    = a[0] * b[0] + a[2] * b[2] + a[3] * b[3] - a[6] * b[6];
+        let result_e0   = a[0] * b[1] + a[1] * b[0] - a[2] * b[4] + a[3] * b[5] + a[4] * b[2] - a[5] * b[3] - a[6] * b[7] - a[7] * b[6];
+        let result_e1   = a[0] * b[2] + a[2] * b[0] - a[3] * b[6] + a[6] * b[3];
+        let result_e2   = a[0] * b[3] + a[2] * b[6] + a[3] * b[0] - a[6] * b[2];
+        let result_e01  = a[0] * b[4] + a[1] * b[2] - a[2] * b[1] + a[3] * b[7] + a[4] * b[0] + a[5] * b[6] - a[6] * b[5] + a[7] * b[3];
+        let result_e20  = a[0] * b[5] - a[1] * b[3] + a[2] * b[7] + a[3] * b[1] - a[4] * b[6] + a[5] * b[0] + a[6] * b[4] + a[7] * b[2];
+        let result_e12  = a[0] * b[6] + a[2] * b[3] - a[3] * b[2] + a[6] * b[0];
+        let result_e012 = a[0] * b[7] + a[1] * b[6] + a[2] * b[5] + a[3] * b[4] + a[4] * b[3] + a[5] * b[2] + a[6] * b[1] + a[7] * b[0];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::Mul<ProjectiveMultivector2<S>> for &ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: ProjectiveMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1    = a[0] * b[0] + a[2] * b[2] + a[3] * b[3] - a[6] * b[6];
+        let result_e0   = a[0] * b[1] + a[1] * b[0] - a[2] * b[4] + a[3] * b[5] + a[4] * b[2] - a[5] * b[3] - a[6] * b[7] - a[7] * b[6];
+        let result_e1   = a[0] * b[2] + a[2] * b[0] - a[3] * b[6] + a[6] * b[3];
+        let result_e2   = a[0] * b[3] + a[2] * b[6] + a[3] * b[0] - a[6] * b[2];
+        let result_e01  = a[0] * b[4] + a[1] * b[2] - a[2] * b[1] + a[3] * b[7] + a[4] * b[0] + a[5] * b[6] - a[6] * b[5] + a[7] * b[3];
+        let result_e20  = a[0] * b[5] - a[1] * b[3] + a[2] * b[7] + a[3] * b[1] - a[4] * b[6] + a[5] * b[0] + a[6] * b[4] + a[7] * b[2];
+        let result_e12  = a[0] * b[6] + a[2] * b[3] - a[3] * b[2] + a[6] * b[0];
+        let result_e012 = a[0] * b[7] + a[1] * b[6] + a[2] * b[5] + a[3] * b[4] + a[4] * b[3] + a[5] * b[2] + a[6] * b[1] + a[7] * b[0];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::Mul<&ProjectiveMultivector2<S>> for &ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: &ProjectiveMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1    = a[0] * b[0] + a[2] * b[2] + a[3] * b[3] - a[6] * b[6];
+        let result_e0   = a[0] * b[1] + a[1] * b[0] - a[2] * b[4] + a[3] * b[5] + a[4] * b[2] - a[5] * b[3] - a[6] * b[7] - a[7] * b[6];
+        let result_e1   = a[0] * b[2] + a[2] * b[0] - a[3] * b[6] + a[6] * b[3];
+        let result_e2   = a[0] * b[3] + a[2] * b[6] + a[3] * b[0] - a[6] * b[2];
+        let result_e01  = a[0] * b[4] + a[1] * b[2] - a[2] * b[1] + a[3] * b[7] + a[4] * b[0] + a[5] * b[6] - a[6] * b[5] + a[7] * b[3];
+        let result_e20  = a[0] * b[5] - a[1] * b[3] + a[2] * b[7] + a[3] * b[1] - a[4] * b[6] + a[5] * b[0] + a[6] * b[4] + a[7] * b[2];
+        let result_e12  = a[0] * b[6] + a[2] * b[3] - a[3] * b[2] + a[6] * b[0];
+        let result_e012 = a[0] * b[7] + a[1] * b[6] + a[2] * b[5] + a[3] * b[4] + a[4] * b[3] + a[5] * b[2] + a[6] * b[1] + a[7] * b[0];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::Mul<S> for ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1    = a[0] * other;
+        let result_e0   = a[1] * other;
+        let result_e1   = a[2] * other;
+        let result_e2   = a[3] * other;
+        let result_e01  = a[4] * other;
+        let result_e20  = a[5] * other;
+        let result_e12  = a[6] * other;
+        let result_e012 = a[7] * other;
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::Mul<S> for &ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1    = a[0] * other;
+        let result_e0   = a[1] * other;
+        let result_e1   = a[2] * other;
+        let result_e2   = a[3] * other;
+        let result_e01  = a[4] * other;
+        let result_e20  = a[5] * other;
+        let result_e12  = a[6] * other;
+        let result_e012 = a[7] * other;
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::BitXor<ProjectiveMultivector2<S>> for ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: ProjectiveMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1    = a[0] * b[0];
+        let result_e0   = a[0] * b[1] + a[1] * b[0];
+        let result_e1   = a[0] * b[2] + a[2] * b[0];
+        let result_e2   = a[0] * b[3] + a[3] * b[0];
+        let result_e01  = a[0] * b[4] + a[1] * b[2] - a[2] * b[1] + a[4] * b[0];
+        let result_e20  = a[0] * b[5] - a[1] * b[3] + a[3] * b[1] + a[5] * b[0];
+        let result_e12  = a[0] * b[6] + a[2] * b[3] - a[3] * b[2] + a[6] * b[0];
+        let result_e012 = a[0] * b[7] + a[1] * b[6] + a[2] * b[5] + a[3] * b[4] + a[4] * b[3] + a[5] * b[2] + a[6] * b[1] + a[7] * b[0];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::BitXor<&ProjectiveMultivector2<S>> for ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: &ProjectiveMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1    = a[0] * b[0];
+        let result_e0   = a[0] * b[1] + a[1] * b[0];
+        let result_e1   = a[0] * b[2] + a[2] * b[0];
+        let result_e2   = a[0] * b[3] + a[3] * b[0];
+        let result_e01  = a[0] * b[4] + a[1] * b[2] - a[2] * b[1] + a[4] * b[0];
+        let result_e20  = a[0] * b[5] - a[1] * b[3] + a[3] * b[1] + a[5] * b[0];
+        let result_e12  = a[0] * b[6] + a[2] * b[3] - a[3] * b[2] + a[6] * b[0];
+        let result_e012 = a[0] * b[7] + a[1] * b[6] + a[2] * b[5] + a[3] * b[4] + a[4] * b[3] + a[5] * b[2] + a[6] * b[1] + a[7] * b[0];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::BitXor<ProjectiveMultivector2<S>> for &ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: ProjectiveMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1    = a[0] * b[0];
+        let result_e0   = a[0] * b[1] + a[1] * b[0];
+        let result_e1   = a[0] * b[2] + a[2] * b[0];
+        let result_e2   = a[0] * b[3] + a[3] * b[0];
+        let result_e01  = a[0] * b[4] + a[1] * b[2] - a[2] * b[1] + a[4] * b[0];
+        let result_e20  = a[0] * b[5] - a[1] * b[3] + a[3] * b[1] + a[5] * b[0];
+        let result_e12  = a[0] * b[6] + a[2] * b[3] - a[3] * b[2] + a[6] * b[0];
+        let result_e012 = a[0] * b[7] + a[1] * b[6] + a[2] * b[5] + a[3] * b[4] + a[4] * b[3] + a[5] * b[2] + a[6] * b[1] + a[7] * b[0];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::BitXor<&ProjectiveMultivector2<S>> for &ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: &ProjectiveMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1    = a[0] * b[0];
+        let result_e0   = a[0] * b[1] + a[1] * b[0];
+        let result_e1   = a[0] * b[2] + a[2] * b[0];
+        let result_e2   = a[0] * b[3] + a[3] * b[0];
+        let result_e01  = a[0] * b[4] + a[1] * b[2] - a[2] * b[1] + a[4] * b[0];
+        let result_e20  = a[0] * b[5] - a[1] * b[3] + a[3] * b[1] + a[5] * b[0];
+        let result_e12  = a[0] * b[6] + a[2] * b[3] - a[3] * b[2] + a[6] * b[0];
+        let result_e012 = a[0] * b[7] + a[1] * b[6] + a[2] * b[5] + a[3] * b[4] + a[4] * b[3] + a[5] * b[2] + a[6] * b[1] + a[7] * b[0];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::BitXor<S> for ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1    = a[0] * other;
+        let result_e0   = a[1] * other;
+        let result_e1   = a[2] * other;
+        let result_e2   = a[3] * other;
+        let result_e01  = a[4] * other;
+        let result_e20  = a[5] * other;
+        let result_e12  = a[6] * other;
+        let result_e012 = a[7] * other;
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::BitXor<S> for &ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1    = a[0] * other;
+        let result_e0   = a[1] * other;
+        let result_e1   = a[2] * other;
+        let result_e2   = a[3] * other;
+        let result_e01  = a[4] * other;
+        let result_e20  = a[5] * other;
+        let result_e12  = a[6] * other;
+        let result_e012 = a[7] * other;
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::BitAnd<ProjectiveMultivector2<S>> for ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitand(self, other: ProjectiveMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1    = a[0] * b[7] + a[1] * b[6] + a[2] * b[5] + a[3] * b[4] + a[4] * b[3] + a[5] * b[2] + a[6] * b[1] + a[7] * b[0];
+        let result_e0   = a[1] * b[7] - a[4] * b[5] + a[5] * b[4] + a[7] * b[1];
+        let result_e1   = a[2] * b[7] + a[4] * b[6] - a[6] * b[4] + a[7] * b[2];
+        let result_e2   = a[3] * b[7] - a[5] * b[6] + a[6] * b[5] + a[7] * b[3];
+        let result_e01  = a[4] * b[7] + a[7] * b[4];
+        let result_e20  = a[5] * b[7] + a[7] * b[5];
+        let result_e12  = a[6] * b[7] + a[7] * b[6];
+        let result_e012 = a[7] * b[7];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::BitAnd<&ProjectiveMultivector2<S>> for ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitand(self, other: &ProjectiveMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1    = a[0] * b[7] + a[1] * b[6] + a[2] * b[5] + a[3] * b[4] + a[4] * b[3] + a[5] * b[2] + a[6] * b[1] + a[7] * b[0];
+        let result_e0   = a[1] * b[7] - a[4] * b[5] + a[5] * b[4] + a[7] * b[1];
+        let result_e1   = a[2] * b[7] + a[4] * b[6] - a[6] * b[4] + a[7] * b[2];
+        let result_e2   = a[3] * b[7] - a[5] * b[6] + a[6] * b[5] + a[7] * b[3];
+        let result_e01  = a[4] * b[7] + a[7] * b[4];
+        let result_e20  = a[5] * b[7] + a[7] * b[5];
+        let result_e12  = a[6] * b[7] + a[7] * b[6];
+        let result_e012 = a[7] * b[7];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::BitAnd<ProjectiveMultivector2<S>> for &ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitand(self, other: ProjectiveMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1    = a[0] * b[7] + a[1] * b[6] + a[2] * b[5] + a[3] * b[4] + a[4] * b[3] + a[5] * b[2] + a[6] * b[1] + a[7] * b[0];
+        let result_e0   = a[1] * b[7] - a[4] * b[5] + a[5] * b[4] + a[7] * b[1];
+        let result_e1   = a[2] * b[7] + a[4] * b[6] - a[6] * b[4] + a[7] * b[2];
+        let result_e2   = a[3] * b[7] - a[5] * b[6] + a[6] * b[5] + a[7] * b[3];
+        let result_e01  = a[4] * b[7] + a[7] * b[4];
+        let result_e20  = a[5] * b[7] + a[7] * b[5];
+        let result_e12  = a[6] * b[7] + a[7] * b[6];
+        let result_e012 = a[7] * b[7];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::BitAnd<&ProjectiveMultivector2<S>> for &ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitand(self, other: &ProjectiveMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1    = a[0] * b[7] + a[1] * b[6] + a[2] * b[5] + a[3] * b[4] + a[4] * b[3] + a[5] * b[2] + a[6] * b[1] + a[7] * b[0];
+        let result_e0   = a[1] * b[7] - a[4] * b[5] + a[5] * b[4] + a[7] * b[1];
+        let result_e1   = a[2] * b[7] + a[4] * b[6] - a[6] * b[4] + a[7] * b[2];
+        let result_e2   = a[3] * b[7] - a[5] * b[6] + a[6] * b[5] + a[7] * b[3];
+        let result_e01  = a[4] * b[7] + a[7] * b[4];
+        let result_e20  = a[5] * b[7] + a[7] * b[5];
+        let result_e12  = a[6] * b[7] + a[7] * b[6];
+        let result_e012 = a[7] * b[7];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::BitOr<ProjectiveMultivector2<S>> for ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitor(self, other: ProjectiveMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1 = a[0] * b[0] + a[2] * b[2] + a[3] * b[3] + a[6] * b[6];
+
+        ProjectiveMultivector2::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::BitOr<&ProjectiveMultivector2<S>> for ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitor(self, other: &ProjectiveMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1 = a[0] * b[0] + a[2] * b[2] + a[3] * b[3] + a[6] * b[6];
+
+        ProjectiveMultivector2::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::BitOr<ProjectiveMultivector2<S>> for &ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitor(self, other: ProjectiveMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1 = a[0] * b[0] + a[2] * b[2] + a[3] * b[3] + a[6] * b[6];
+
+        ProjectiveMultivector2::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::BitOr<&ProjectiveMultivector2<S>> for &ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitor(self, other: &ProjectiveMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1 = a[0] * b[0] + a[2] * b[2] + a[3] * b[3] + a[6] * b[6];
+
+        ProjectiveMultivector2::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::BitOr<S> for ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn bitor(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1 = a[0] * other;
+
+        ProjectiveMultivector2::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::BitOr<S> for &ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn bitor(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1 = a[0] * other;
+
+        ProjectiveMultivector2::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::Add<ProjectiveMultivector2<S>> for ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: ProjectiveMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1    = a[0] + b[0];
+        let result_e0   = a[1] + b[1];
+        let result_e1   = a[2] + b[2];
+        let result_e2   = a[3] + b[3];
+        let result_e01  = a[4] + b[4];
+        let result_e20  = a[5] + b[5];
+        let result_e12  = a[6] + b[6];
+        let result_e012 = a[7] + b[7];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::Add<&ProjectiveMultivector2<S>> for ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: &ProjectiveMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1    = a[0] + b[0];
+        let result_e0   = a[1] + b[1];
+        let result_e1   = a[2] + b[2];
+        let result_e2   = a[3] + b[3];
+        let result_e01  = a[4] + b[4];
+        let result_e20  = a[5] + b[5];
+        let result_e12  = a[6] + b[6];
+        let result_e012 = a[7] + b[7];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::Add<ProjectiveMultivector2<S>> for &ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: ProjectiveMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1    = a[0] + b[0];
+        let result_e0   = a[1] + b[1];
+        let result_e1   = a[2] + b[2];
+        let result_e2   = a[3] + b[3];
+        let result_e01  = a[4] + b[4];
+        let result_e20  = a[5] + b[5];
+        let result_e12  = a[6] + b[6];
+        let result_e012 = a[7] + b[7];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::Add<&ProjectiveMultivector2<S>> for &ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: &ProjectiveMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1    = a[0] + b[0];
+        let result_e0   = a[1] + b[1];
+        let result_e1   = a[2] + b[2];
+        let result_e2   = a[3] + b[3];
+        let result_e01  = a[4] + b[4];
+        let result_e20  = a[5] + b[5];
+        let result_e12  = a[6] + b[6];
+        let result_e012 = a[7] + b[7];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::Add<S> for ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1    = a[0] + other;
+        let result_e0   = a[1];
+        let result_e1   = a[2];
+        let result_e2   = a[3];
+        let result_e01  = a[4];
+        let result_e20  = a[5];
+        let result_e12  = a[6];
+        let result_e012 = a[7];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::Add<S> for &ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1    = a[0] + other;
+        let result_e0   = a[1];
+        let result_e1   = a[2];
+        let result_e2   = a[3];
+        let result_e01  = a[4];
+        let result_e20  = a[5];
+        let result_e12  = a[6];
+        let result_e012 = a[7];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::Sub<ProjectiveMultivector2<S>> for ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: ProjectiveMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1    = a[0] - b[0];
+        let result_e0   = a[1] - b[1];
+        let result_e1   = a[2] - b[2];
+        let result_e2   = a[3] - b[3];
+        let result_e01  = a[4] - b[4];
+        let result_e20  = a[5] - b[5];
+        let result_e12  = a[6] - b[6];
+        let result_e012 = a[7] - b[7];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::Sub<&ProjectiveMultivector2<S>> for ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: &ProjectiveMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1    = a[0] - b[0];
+        let result_e0   = a[1] - b[1];
+        let result_e1   = a[2] - b[2];
+        let result_e2   = a[3] - b[3];
+        let result_e01  = a[4] - b[4];
+        let result_e20  = a[5] - b[5];
+        let result_e12  = a[6] - b[6];
+        let result_e012 = a[7] - b[7];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::Sub<ProjectiveMultivector2<S>> for &ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: ProjectiveMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1    = a[0] - b[0];
+        let result_e0   = a[1] - b[1];
+        let result_e1   = a[2] - b[2];
+        let result_e2   = a[3] - b[3];
+        let result_e01  = a[4] - b[4];
+        let result_e20  = a[5] - b[5];
+        let result_e12  = a[6] - b[6];
+        let result_e012 = a[7] - b[7];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::Sub<&ProjectiveMultivector2<S>> for &ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: &ProjectiveMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1    = a[0] - b[0];
+        let result_e0   = a[1] - b[1];
+        let result_e1   = a[2] - b[2];
+        let result_e2   = a[3] - b[3];
+        let result_e01  = a[4] - b[4];
+        let result_e20  = a[5] - b[5];
+        let result_e12  = a[6] - b[6];
+        let result_e012 = a[7] - b[7];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::Sub<S> for ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1    = a[0] - other;
+        let result_e0   = a[1];
+        let result_e1   = a[2];
+        let result_e2   = a[3];
+        let result_e01  = a[4];
+        let result_e20  = a[5];
+        let result_e12  = a[6];
+        let result_e012 = a[7];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::Sub<S> for &ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1    = a[0] - other;
+        let result_e0   = a[1];
+        let result_e1   = a[2];
+        let result_e2   = a[3];
+        let result_e01  = a[4];
+        let result_e20  = a[5];
+        let result_e12  = a[6];
+        let result_e012 = a[7];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl_approx_cmp_ops!(ProjectiveMultivector2);
+
+impl<S> ProjectiveMultivector2<S>
+where
+    S: ScalarFloat,
+{
+    /// Calculate the squared magnitude of a multivector.
+    ///
+    /// The magnitude of a projective multivector is the Euclidean magnitude of
+    /// the components that do not contain the null basis vector `e0`, since
+    /// every component containing `e0` squares to zero. This is also known as
+    /// the bulk norm.
+    pub fn magnitude_squared(&self) -> S {
+        let scalar_part = (self * self.reverse())[0];
+
+        scalar_part.abs()
+    }
+
+    /// Calculate the magnitude of a multivector.
+    pub fn magnitude(&self) -> S {
+        self.magnitude_squared().sqrt()
+    }
+
+    /// Normalize a multivector to a unit multivector.
+    pub fn normalize(&self) -> Self {
+        self * (S::one() / self.magnitude())
+    }
+
+    /// Normalize a multivector to a specified magnitude.
+    pub fn normalize_to(&self, magnitude: S) -> Self {
+        self * (magnitude / self.magnitude())
+    }
+}
+
+impl<S> ops::Div<S> for ProjectiveMultivector2<S>
+where
+    S: ScalarFloat,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn div(self, other: S) -> Self::Output {
+        let one_over_other = S::one() / other;
+        let result_1    = self.data[0] * one_over_other;
+        let result_e0   = self.data[1] * one_over_other;
+        let result_e1   = self.data[2] * one_over_other;
+        let result_e2   = self.data[3] * one_over_other;
+        let result_e01  = self.data[4] * one_over_other;
+        let result_e20  = self.data[5] * one_over_other;
+        let result_e12  = self.data[6] * one_over_other;
+        let result_e012 = self.data[7] * one_over_other;
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::Div<S> for &ProjectiveMultivector2<S>
+where
+    S: ScalarFloat,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn div(self, other: S) -> Self::Output {
+        let one_over_other = S::one() / other;
+        let result_1    = self.data[0] * one_over_other;
+        let result_e0   = self.data[1] * one_over_other;
+        let result_e1   = self.data[2] * one_over_other;
+        let result_e2   = self.data[3] * one_over_other;
+        let result_e01  = self.data[4] * one_over_other;
+        let result_e20  = self.data[5] * one_over_other;
+        let result_e12  = self.data[6] * one_over_other;
+        let result_e012 = self.data[7] * one_over_other;
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::Shl<ProjectiveMultivector2<S>> for ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shl(self, other: ProjectiveMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1    = a[0] * b[0] + a[2] * b[2] + a[3] * b[3] - a[6] * b[6];
+        let result_e0   = a[0] * b[1] - a[2] * b[4] + a[3] * b[5] - a[6] * b[7];
+        let result_e1   = a[0] * b[2] - a[3] * b[6];
+        let result_e2   = a[0] * b[3] + a[2] * b[6];
+        let result_e01  = a[0] * b[4] + a[3] * b[7];
+        let result_e20  = a[0] * b[5] + a[2] * b[7];
+        let result_e12  = a[0] * b[6];
+        let result_e012 = a[0] * b[7];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::Shl<&ProjectiveMultivector2<S>> for ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shl(self, other: &ProjectiveMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1    = a[0] * b[0] + a[2] * b[2] + a[3] * b[3] - a[6] * b[6];
+        let result_e0   = a[0] * b[1] - a[2] * b[4] + a[3] * b[5] - a[6] * b[7];
+        let result_e1   = a[0] * b[2] - a[3] * b[6];
+        let result_e2   = a[0] * b[3] + a[2] * b[6];
+        let result_e01  = a[0] * b[4] + a[3] * b[7];
+        let result_e20  = a[0] * b[5] + a[2] * b[7];
+        let result_e12  = a[0] * b[6];
+        let result_e012 = a[0] * b[7];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::Shl<ProjectiveMultivector2<S>> for &ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shl(self, other: ProjectiveMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1    = a[0] * b[0] + a[2] * b[2] + a[3] * b[3] - a[6] * b[6];
+        let result_e0   = a[0] * b[1] - a[2] * b[4] + a[3] * b[5] - a[6] * b[7];
+        let result_e1   = a[0] * b[2] - a[3] * b[6];
+        let result_e2   = a[0] * b[3] + a[2] * b[6];
+        let result_e01  = a[0] * b[4] + a[3] * b[7];
+        let result_e20  = a[0] * b[5] + a[2] * b[7];
+        let result_e12  = a[0] * b[6];
+        let result_e012 = a[0] * b[7];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::Shl<&ProjectiveMultivector2<S>> for &ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shl(self, other: &ProjectiveMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1    = a[0] * b[0] + a[2] * b[2] + a[3] * b[3] - a[6] * b[6];
+        let result_e0   = a[0] * b[1] - a[2] * b[4] + a[3] * b[5] - a[6] * b[7];
+        let result_e1   = a[0] * b[2] - a[3] * b[6];
+        let result_e2   = a[0] * b[3] + a[2] * b[6];
+        let result_e01  = a[0] * b[4] + a[3] * b[7];
+        let result_e20  = a[0] * b[5] + a[2] * b[7];
+        let result_e12  = a[0] * b[6];
+        let result_e012 = a[0] * b[7];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::Shl<S> for ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn shl(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1 = a[0] * other;
+
+        ProjectiveMultivector2::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::Shl<S> for &ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn shl(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1 = a[0] * other;
+
+        ProjectiveMultivector2::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::Shr<ProjectiveMultivector2<S>> for ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: ProjectiveMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1    = a[0] * b[0] + a[2] * b[2] + a[3] * b[3] - a[6] * b[6];
+        let result_e0   = a[1] * b[0] + a[4] * b[2] - a[5] * b[3] - a[7] * b[6];
+        let result_e1   = a[2] * b[0] + a[6] * b[3];
+        let result_e2   = a[3] * b[0] - a[6] * b[2];
+        let result_e01  = a[4] * b[0] + a[7] * b[3];
+        let result_e20  = a[5] * b[0] + a[7] * b[2];
+        let result_e12  = a[6] * b[0];
+        let result_e012 = a[7] * b[0];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::Shr<&ProjectiveMultivector2<S>> for ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: &ProjectiveMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1    = a[0] * b[0] + a[2] * b[2] + a[3] * b[3] - a[6] * b[6];
+        let result_e0   = a[1] * b[0] + a[4] * b[2] - a[5] * b[3] - a[7] * b[6];
+        let result_e1   = a[2] * b[0] + a[6] * b[3];
+        let result_e2   = a[3] * b[0] - a[6] * b[2];
+        let result_e01  = a[4] * b[0] + a[7] * b[3];
+        let result_e20  = a[5] * b[0] + a[7] * b[2];
+        let result_e12  = a[6] * b[0];
+        let result_e012 = a[7] * b[0];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::Shr<ProjectiveMultivector2<S>> for &ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: ProjectiveMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1    = a[0] * b[0] + a[2] * b[2] + a[3] * b[3] - a[6] * b[6];
+        let result_e0   = a[1] * b[0] + a[4] * b[2] - a[5] * b[3] - a[7] * b[6];
+        let result_e1   = a[2] * b[0] + a[6] * b[3];
+        let result_e2   = a[3] * b[0] - a[6] * b[2];
+        let result_e01  = a[4] * b[0] + a[7] * b[3];
+        let result_e20  = a[5] * b[0] + a[7] * b[2];
+        let result_e12  = a[6] * b[0];
+        let result_e012 = a[7] * b[0];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::Shr<&ProjectiveMultivector2<S>> for &ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: &ProjectiveMultivector2<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1    = a[0] * b[0] + a[2] * b[2] + a[3] * b[3] - a[6] * b[6];
+        let result_e0   = a[1] * b[0] + a[4] * b[2] - a[5] * b[3] - a[7] * b[6];
+        let result_e1   = a[2] * b[0] + a[6] * b[3];
+        let result_e2   = a[3] * b[0] - a[6] * b[2];
+        let result_e01  = a[4] * b[0] + a[7] * b[3];
+        let result_e20  = a[5] * b[0] + a[7] * b[2];
+        let result_e12  = a[6] * b[0];
+        let result_e012 = a[7] * b[0];
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::Shr<S> for ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1    = a[0] * other;
+        let result_e0   = a[1] * other;
+        let result_e1   = a[2] * other;
+        let result_e2   = a[3] * other;
+        let result_e01  = a[4] * other;
+        let result_e20  = a[5] * other;
+        let result_e12  = a[6] * other;
+        let result_e012 = a[7] * other;
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ops::Shr<S> for &ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector2<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1    = a[0] * other;
+        let result_e0   = a[1] * other;
+        let result_e1   = a[2] * other;
+        let result_e2   = a[3] * other;
+        let result_e01  = a[4] * other;
+        let result_e20  = a[5] * other;
+        let result_e12  = a[6] * other;
+        let result_e012 = a[7] * other;
+
+        ProjectiveMultivector2::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e01,
+            result_e20,
+            result_e12,
+            result_e012,
+        )
+    }
+}
+
+impl<S> ProjectiveMultivector2<S>
+where
+    S: Scalar,
+{
+    /// Construct the line with the equation `a * x + b * y + c == 0`.
+    ///
+    /// In two-dimensional projective geometric algebra, lines are represented
+    /// by vectors. The line `a * x + b * y + c == 0` is the vector
+    /// ```text
+    /// a * e1 + b * e2 + c * e0
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// #
+    /// // The line `x + 2 * y == 3`.
+    /// let line = ProjectiveMultivector2::from_line(1, 2, -3);
+    /// let point = ProjectiveMultivector2::from_point(1, 1);
+    ///
+    /// assert_eq!(line.e1, 1);
+    /// assert_eq!(line.e2, 2);
+    /// assert_eq!(line.e0, -3);
+    /// assert!((line ^ point).is_zero());
+    /// ```
+    #[inline]
+    pub fn from_line(a: S, b: S, c: S) -> Self {
+        let mut result = Self::zero();
+        result.data[1] = c;
+        result.data[2] = a;
+        result.data[3] = b;
+
+        result
+    }
+
+    /// Construct the point with coordinates `(x, y)`.
+    ///
+    /// In two-dimensional projective geometric algebra, points are represented
+    /// by bivectors. The point `(x, y)` is the bivector
+    /// ```text
+    /// x * e20 + y * e01 + e12
+    /// ```
+    /// which is the meet of the lines `x == x` and `y == y`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// #
+    /// let line_x = ProjectiveMultivector2::from_line(1, 0, -3);
+    /// let line_y = ProjectiveMultivector2::from_line(0, 1, -4);
+    /// let expected = ProjectiveMultivector2::from_point(3, 4);
+    /// let result = line_x ^ line_y;
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn from_point(x: S, y: S) -> Self {
+        let mut result = Self::zero();
+        result.data[4] = y;
+        result.data[5] = x;
+        result.data[6] = S::one();
+
+        result
+    }
+
+    /// Construct the direction `(x, y)`.
+    ///
+    /// A direction is a point at infinity, also known as an ideal point. It is
+    /// represented by the bivector
+    /// ```text
+    /// x * e20 + y * e01
+    /// ```
+    /// which has no `e12` component. Directions are invariant under
+    /// translations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// #
+    /// let direction = ProjectiveMultivector2::from_direction(1, 2);
+    ///
+    /// assert_eq!(direction.e20, 1);
+    /// assert_eq!(direction.e01, 2);
+    /// assert_eq!(direction.e12, 0);
+    /// ```
+    #[inline]
+    pub fn from_direction(x: S, y: S) -> Self {
+        let mut result = Self::zero();
+        result.data[4] = y;
+        result.data[5] = x;
+
+        result
+    }
+
+    /// Compute the meet of two geometric objects.
+    ///
+    /// The meet is the intersection of two geometric objects, and is given by
+    /// the outer product
+    /// ```text
+    /// meet(A, B) := A ^ B
+    /// ```
+    /// For example, two lines meet in a point. Parallel lines meet in a point
+    /// at infinity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// #
+    /// let line1 = ProjectiveMultivector2::from_line(1, -1, 0);
+    /// let line2 = ProjectiveMultivector2::from_line(1, 1, -4);
+    /// let expected = ProjectiveMultivector2::from_point(2, 2) * 2;
+    /// let result = line1.meet(&line2);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn meet(&self, other: &Self) -> Self {
+        self ^ other
+    }
+
+    /// Compute the join of two geometric objects.
+    ///
+    /// The join is the smallest geometric object containing both geometric
+    /// objects, and is given by the regressive product
+    /// ```text
+    /// join(A, B) := A & B
+    /// ```
+    /// For example, two points join into the line through both points.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// #
+    /// let p = ProjectiveMultivector2::from_point(1, 2);
+    /// let q = ProjectiveMultivector2::from_point(4, 6);
+    /// let line = p.join(&q);
+    ///
+    /// assert_eq!(line, ProjectiveMultivector2::from_line(-4, 3, -2));
+    /// ```
+    #[inline]
+    pub fn join(&self, other: &Self) -> Self {
+        self & other
+    }
+}
+
+impl<S> ProjectiveMultivector2<S>
+where
+    S: ScalarFloat,
+{
+    /// Compute the Euclidean distance between two points.
+    ///
+    /// Both `self` and `other` are assumed to be finite points, i.e. bivectors
+    /// with a nonzero `e12` component. The distance is the magnitude of the
+    /// line joining the normalized points.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// #
+    /// let p = ProjectiveMultivector2::from_point(1_f64, 2_f64);
+    /// let q = ProjectiveMultivector2::from_point(4_f64, 6_f64);
+    ///
+    /// assert_relative_eq!(p.distance_to_point(&q), 5_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn distance_to_point(&self, other: &Self) -> S {
+        (self & other).magnitude() / (self.magnitude() * other.magnitude())
+    }
+
+    /// Compute the signed Euclidean distance from a point to a line.
+    ///
+    /// The point `self` is assumed to be a finite point, and `line` a line
+    /// that is not the line at infinity. The distance is positive when the
+    /// point lies on the side of the line that the normal vector `(a, b)` of
+    /// the line `a * x + b * y + c == 0` points to, and negative otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// #
+    /// let line = ProjectiveMultivector2::from_line(3_f64, 4_f64, -5_f64);
+    /// let point = ProjectiveMultivector2::from_point(3_f64, 4_f64);
+    ///
+    /// assert_relative_eq!(point.distance_to_line(&line), 4_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn distance_to_line(&self, line: &Self) -> S {
+        (line ^ self)[7] / (line.magnitude() * self[6])
+    }
+
+    /// Compute the angle between two lines.
+    ///
+    /// The angle is the angle between the directions of the oriented lines
+    /// `self` and `other`, in the range `[0, pi]`. Parallel lines with the same
+    /// orientation have angle zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// # use core::f64;
+    /// #
+    /// let line1 = ProjectiveMultivector2::from_line(1_f64, 0_f64, -2_f64);
+    /// let line2 = ProjectiveMultivector2::from_line(1_f64, 1_f64, 3_f64);
+    /// let expected = f64::consts::FRAC_PI_4;
+    ///
+    /// assert_relative_eq!(line1.angle_to_line(&line2), expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn angle_to_line(&self, other: &Self) -> S {
+        let cosine = (self | other)[0];
+        let sine = (self ^ other)[6];
+
+        sine.abs().atan2(cosine)
+    }
+
+    /// Construct the rotor that rotates by the angle `angle` about the origin.
+    ///
+    /// The rotor is the versor
+    /// ```text
+    /// R = cos(angle / 2) - sin(angle / 2) * e12
+    /// ```
+    /// which rotates a geometric object counterclockwise by the sandwich
+    /// product `R * X * rev(R)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// # use core::f64;
+    /// #
+    /// let rotor = ProjectiveMultivector2::rotor(f64::consts::FRAC_PI_2);
+    /// let point = ProjectiveMultivector2::from_point(1_f64, 0_f64);
+    /// let expected = ProjectiveMultivector2::from_point(0_f64, 1_f64);
+    /// let result = point.transform(&rotor);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn rotor(angle: S) -> Self {
+        Self::rotor_about(S::zero(), S::zero(), angle)
+    }
+
+    /// Construct the rotor that rotates by the angle `angle` about the point
+    /// `(x, y)`.
+    ///
+    /// The rotor is the versor
+    /// ```text
+    /// R = cos(angle / 2) - sin(angle / 2) * P
+    /// ```
+    /// where `P = x * e20 + y * e01 + e12` is the center of rotation. It
+    /// rotates a geometric object counterclockwise about `P` by the sandwich
+    /// product `R * X * rev(R)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// # use core::f64;
+    /// #
+    /// let rotor = ProjectiveMultivector2::rotor_about(1_f64, 1_f64, f64::consts::PI);
+    /// let point = ProjectiveMultivector2::from_point(2_f64, 1_f64);
+    /// let expected = ProjectiveMultivector2::from_point(0_f64, 1_f64);
+    /// let result = point.transform(&rotor);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn rotor_about(x: S, y: S, angle: S) -> Self {
+        let one_half = S::one() / (S::one() + S::one());
+        let (sin_half_angle, cos_half_angle) = (angle * one_half).sin_cos();
+        let center = Self::from_point(x, y);
+
+        Self::from_scalar(cos_half_angle) - center * sin_half_angle
+    }
+
+    /// Construct the translator that translates by the displacement `(x, y)`.
+    ///
+    /// The translator is the versor
+    /// ```text
+    /// T = 1 + (1 / 2) * (y * e20 - x * e01)
+    /// ```
+    /// which translates a geometric object `X` by the sandwich product
+    /// `T * X * rev(T)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// #
+    /// let translator = ProjectiveMultivector2::translator(3_f64, -2_f64);
+    /// let point = ProjectiveMultivector2::from_point(1_f64, 1_f64);
+    /// let expected = ProjectiveMultivector2::from_point(4_f64, -1_f64);
+    /// let result = point.transform(&translator);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn translator(x: S, y: S) -> Self {
+        let one_half = S::one() / (S::one() + S::one());
+        let mut result = Self::unit_scalar();
+        result.data[4] = -x * one_half;
+        result.data[5] = y * one_half;
+
+        result
+    }
+
+    /// Transform a geometric object by a versor.
+    ///
+    /// The transformed object is the sandwich product `V * mv * rev(V)`. The
+    /// versor `V` is assumed to be normalized, so that its reverse is its
+    /// inverse. Rotors, translators, and their products, the motors, all act
+    /// on points and lines this way.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::pga2::ProjectiveMultivector2;
+    /// # use core::f64;
+    /// #
+    /// let rotor = ProjectiveMultivector2::rotor(f64::consts::FRAC_PI_2);
+    /// let translator = ProjectiveMultivector2::translator(1_f64, 0_f64);
+    /// // Rotate first, then translate.
+    /// let motor = translator * rotor;
+    /// let point = ProjectiveMultivector2::from_point(1_f64, 0_f64);
+    /// let expected = ProjectiveMultivector2::from_point(1_f64, 1_f64);
+    /// let result = point.transform(&motor);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn transform(&self, versor: &Self) -> Self {
+        versor * self * versor.reverse()
+    }
+}
+
+
+impl_coords!(ViewP2, { scalar, e0, e1, e2, e01, e20, e12, e012 });
+impl_coords_deref!(ProjectiveMultivector2, ViewP2);
+
+
+macro_rules! impl_scalar_multivector_add_ops {
+    ($Lhs:ty => $Rhs:ty => $Output:ty, { $scalar_index:expr }, { $($other_index:expr),* }) => {
+        impl ops::Add<$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn add(self, other: $Rhs) -> $Output {
+                Self::Output::new(self + other[$scalar_index], $(other[$other_index]),* )
+            }
+        }
+
+        impl ops::Add<&$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn add(self, other: &$Rhs) -> $Output {
+                Self::Output::new(self + other[$scalar_index], $(other[$other_index]),* )
+            }
+        }
+    }
+}
+
+impl_scalar_multivector_add_ops!(u8    => ProjectiveMultivector2<u8>    => ProjectiveMultivector2<u8>,    {0}, {1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_add_ops!(u16   => ProjectiveMultivector2<u16>   => ProjectiveMultivector2<u16>,   {0}, {1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_add_ops!(u32   => ProjectiveMultivector2<u32>   => ProjectiveMultivector2<u32>,   {0}, {1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_add_ops!(u64   => ProjectiveMultivector2<u64>   => ProjectiveMultivector2<u64>,   {0}, {1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_add_ops!(u128  => ProjectiveMultivector2<u128>  => ProjectiveMultivector2<u128>,  {0}, {1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_add_ops!(usize => ProjectiveMultivector2<usize> => ProjectiveMultivector2<usize>, {0}, {1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_add_ops!(i8    => ProjectiveMultivector2<i8>    => ProjectiveMultivector2<i8>,    {0}, {1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_add_ops!(i16   => ProjectiveMultivector2<i16>   => ProjectiveMultivector2<i16>,   {0}, {1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_add_ops!(i32   => ProjectiveMultivector2<i32>   => ProjectiveMultivector2<i32>,   {0}, {1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_add_ops!(i64   => ProjectiveMultivector2<i64>   => ProjectiveMultivector2<i64>,   {0}, {1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_add_ops!(i128  => ProjectiveMultivector2<i128>  => ProjectiveMultivector2<i128>,  {0}, {1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_add_ops!(isize => ProjectiveMultivector2<isize> => ProjectiveMultivector2<isize>, {0}, {1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_add_ops!(f32   => ProjectiveMultivector2<f32>   => ProjectiveMultivector2<f32>,   {0}, {1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_add_ops!(f64   => ProjectiveMultivector2<f64>   => ProjectiveMultivector2<f64>,   {0}, {1, 2, 3, 4, 5, 6, 7});
+
+
+macro_rules! impl_scalar_multivector_sub_ops {
+    ($Lhs:ty => $Rhs:ty => $Output:ty, { $scalar_index:expr }, { $($other_index:expr),* }) => {
+        impl ops::Sub<$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn sub(self, other: $Rhs) -> Self::Output {
+                Self::Output::new(self - other[$scalar_index], $(-other[$other_index]),* )
+            }
+        }
+
+        impl ops::Sub<&$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn sub(self, other: &$Rhs) -> Self::Output {
+                Self::Output::new(self - other[$scalar_index], $(-other[$other_index]),* )
+            }
+        }
+    }
+}
+
+impl_scalar_multivector_sub_ops!(i8    => ProjectiveMultivector2<i8>    => ProjectiveMultivector2<i8>,    {0}, {1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_sub_ops!(i16   => ProjectiveMultivector2<i16>   => ProjectiveMultivector2<i16>,   {0}, {1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_sub_ops!(i32   => ProjectiveMultivector2<i32>   => ProjectiveMultivector2<i32>,   {0}, {1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_sub_ops!(i64   => ProjectiveMultivector2<i64>   => ProjectiveMultivector2<i64>,   {0}, {1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_sub_ops!(i128  => ProjectiveMultivector2<i128>  => ProjectiveMultivector2<i128>,  {0}, {1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_sub_ops!(isize => ProjectiveMultivector2<isize> => ProjectiveMultivector2<isize>, {0}, {1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_sub_ops!(f32   => ProjectiveMultivector2<f32>   => ProjectiveMultivector2<f32>,   {0}, {1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_sub_ops!(f64   => ProjectiveMultivector2<f64>   => ProjectiveMultivector2<f64>,   {0}, {1, 2, 3, 4, 5, 6, 7});
+
+
+macro_rules! impl_scalar_multivector_mul_ops {
+    ($Lhs:ty => $Rhs:ty => $Output:ty, { $($index:expr),* }) => {
+        impl ops::Mul<$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn mul(self, other: $Rhs) -> Self::Output {
+                Self::Output::new( $(self * other[$index]),* )
+            }
+        }
+
+        impl ops::Mul<&$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn mul(self, other: &$Rhs) -> Self::Output {
+                Self::Output::new( $(self * other[$index]),* )
+            }
+        }
+    }
+}
+
+impl_scalar_multivector_mul_ops!(u8    => ProjectiveMultivector2<u8>    => ProjectiveMultivector2<u8>,    {0, 1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_mul_ops!(u16   => ProjectiveMultivector2<u16>   => ProjectiveMultivector2<u16>,   {0, 1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_mul_ops!(u32   => ProjectiveMultivector2<u32>   => ProjectiveMultivector2<u32>,   {0, 1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_mul_ops!(u64   => ProjectiveMultivector2<u64>   => ProjectiveMultivector2<u64>,   {0, 1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_mul_ops!(u128  => ProjectiveMultivector2<u128>  => ProjectiveMultivector2<u128>,  {0, 1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_mul_ops!(usize => ProjectiveMultivector2<usize> => ProjectiveMultivector2<usize>, {0, 1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_mul_ops!(i8    => ProjectiveMultivector2<i8>    => ProjectiveMultivector2<i8>,    {0, 1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_mul_ops!(i16   => ProjectiveMultivector2<i16>   => ProjectiveMultivector2<i16>,   {0, 1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_mul_ops!(i32   => ProjectiveMultivector2<i32>   => ProjectiveMultivector2<i32>,   {0, 1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_mul_ops!(i64   => ProjectiveMultivector2<i64>   => ProjectiveMultivector2<i64>,   {0, 1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_mul_ops!(i128  => ProjectiveMultivector2<i128>  => ProjectiveMultivector2<i128>,  {0, 1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_mul_ops!(isize => ProjectiveMultivector2<isize> => ProjectiveMultivector2<isize>, {0, 1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_mul_ops!(f32   => ProjectiveMultivector2<f32>   => ProjectiveMultivector2<f32>,   {0, 1, 2, 3, 4, 5, 6, 7});
+impl_scalar_multivector_mul_ops!(f64   => ProjectiveMultivector2<f64>   => ProjectiveMultivector2<f64>,   {0, 1, 2, 3, 4, 5, 6, 7});
+
+
+macro_rules! impl_scalar_multivector_bitor_ops {
+    ($Lhs:ty) => {
+        impl ops::BitOr<ProjectiveMultivector2<$Lhs>> for $Lhs {
+            type Output = ProjectiveMultivector2<$Lhs>;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            #[inline]
+            fn bitor(self, other: ProjectiveMultivector2<$Lhs>) -> Self::Output {
+                let mut result = Self::Output::zero();
+                result[0] = self * other[0];
+
+                result
+            }
+        }
+
+        impl ops::BitOr<&ProjectiveMultivector2<$Lhs>> for $Lhs {
+            type Output = ProjectiveMultivector2<$Lhs>;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            #[inline]
+            fn bitor(self, other: &ProjectiveMultivector2<$Lhs>) -> Self::Output {
+                let mut result = Self::Output::zero();
+                result[0] = self * other[0];
+
+                result
+            }
+        }
+    };
+}
+
+impl_scalar_multivector_bitor_ops!(u8);
+impl_scalar_multivector_bitor_ops!(u16);
+impl_scalar_multivector_bitor_ops!(u32);
+impl_scalar_multivector_bitor_ops!(u64);
+impl_scalar_multivector_bitor_ops!(u128);
+impl_scalar_multivector_bitor_ops!(usize);
+impl_scalar_multivector_bitor_ops!(i8);
+impl_scalar_multivector_bitor_ops!(i16);
+impl_scalar_multivector_bitor_ops!(i32);
+impl_scalar_multivector_bitor_ops!(i64);
+impl_scalar_multivector_bitor_ops!(i128);
+impl_scalar_multivector_bitor_ops!(isize);
+impl_scalar_multivector_bitor_ops!(f32);
+impl_scalar_multivector_bitor_ops!(f64);
+
+
+macro_rules! impl_scalar_multivector_bitxor_ops {
+    ($Lhs:ty) => {
+        impl ops::BitXor<ProjectiveMultivector2<$Lhs>> for $Lhs {
+            type Output = ProjectiveMultivector2<$Lhs>;
+
+            #[inline]
+            fn bitxor(self, other: ProjectiveMultivector2<$Lhs>) -> Self::Output {
+                let mut result = Self::Output::zero();
+                result[0] = self * other[0];
+                result[1] = self * other[1];
+                result[2] = self * other[2];
+                result[3] = self * other[3];
+                result[4] = self * other[4];
+                result[5] = self * other[5];
+                result[6] = self * other[6];
+                result[7] = self * other[7];
+
+                result
+            }
+        }
+
+        impl ops::BitXor<&ProjectiveMultivector2<$Lhs>> for $Lhs {
+            type Output = ProjectiveMultivector2<$Lhs>;
+
+            #[inline]
+            fn bitxor(self, other: &ProjectiveMultivector2<$Lhs>) -> Self::Output {
+                let mut result = Self::Output::zero();
+                result[0] = self * other[0];
+                result[1] = self * other[1];
+                result[2] = self * other[2];
+                result[3] = self * other[3];
+                result[4] = self * other[4];
+                result[5] = self * other[5];
+                result[6] = self * other[6];
+                result[7] = self * other[7];
+
+                result
+            }
+        }
+    };
+}
+
+impl_scalar_multivector_bitxor_ops!(u8);
+impl_scalar_multivector_bitxor_ops!(u16);
+impl_scalar_multivector_bitxor_ops!(u32);
+impl_scalar_multivector_bitxor_ops!(u64);
+impl_scalar_multivector_bitxor_ops!(u128);
+impl_scalar_multivector_bitxor_ops!(usize);
+impl_scalar_multivector_bitxor_ops!(i8);
+impl_scalar_multivector_bitxor_ops!(i16);
+impl_scalar_multivector_bitxor_ops!(i32);
+impl_scalar_multivector_bitxor_ops!(i64);
+impl_scalar_multivector_bitxor_ops!(i128);
+impl_scalar_multivector_bitxor_ops!(isize);
+impl_scalar_multivector_bitxor_ops!(f32);
+impl_scalar_multivector_bitxor_ops!(f64);
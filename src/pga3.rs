@@ -0,0 +1,4048 @@
+use crate::scalar::{
+    Scalar,
+    ScalarFloat,
+    ScalarSigned,
+};
+use crate::{
+    impl_approx_cmp_ops,
+    impl_coords,
+    impl_coords_deref,
+};
+use core::fmt;
+use core::ops;
+
+mod motor;
+
+pub use self::motor::*;
+
+
+/// A stack-allocated, three-dimensional projective multivector in the basis
+/// `{1, e0, e1, e2, e3, e01, e02, e03, e12, e23, e31, e032, e013, e021, e123, e0123}`.
+///
+/// The basis vectors satisfy `e1 * e1 == e2 * e2 == e3 * e3 == 1` and
+/// `e0 * e0 == 0`, i.e. this is the geometric algebra `Cl(3, 0, 1)`. The null
+/// basis vector `e0` represents the plane at infinity. Vectors represent
+/// planes, bivectors represent lines, and trivectors represent points, so
+/// that the outer product `^` computes the meet (intersection) of geometric
+/// objects, and the regressive product `&` computes their join. Because `e0`
+/// is null, translations are versors in projective geometric algebra, just
+/// like rotations.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ProjectiveMultivector3<S> {
+    data: [S; 16],
+}
+
+impl<S> ProjectiveMultivector3<S> {
+    /// Construct a new general multivector.
+    #[inline]
+    pub const fn new(
+        scalar: S,
+        e0: S,
+        e1: S,
+        e2: S,
+        e3: S,
+        e01: S,
+        e02: S,
+        e03: S,
+        e12: S,
+        e23: S,
+        e31: S,
+        e032: S,
+        e013: S,
+        e021: S,
+        e123: S,
+        e0123: S,
+    ) -> Self {
+        Self {
+            data: [scalar, e0, e1, e2, e3, e01, e02, e03, e12, e23, e31, e032, e013, e021, e123, e0123],
+        }
+    }
+
+    /// Returns the number of components in a multivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let mv = ProjectiveMultivector3::new(1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32);
+    ///
+    /// assert_eq!(mv.len(), 16);
+    /// ```
+    #[inline]
+    pub const fn len(&self) -> usize {
+        16
+    }
+
+    /// Get a pointer to the underlying component array.
+    #[inline]
+    pub const fn as_ptr(&self) -> *const S {
+        &self.data[0]
+    }
+
+    /// Get a mutable pointer to the underlying component array.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut S {
+        &mut self.data[0]
+    }
+
+    /// Get a slice of the underlying elements of the data type.
+    #[inline]
+    pub fn as_slice(&self) -> &[S] {
+        <Self as AsRef<[S; 16]>>::as_ref(self)
+    }
+}
+
+impl<S> ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    /// Construct the additive unit (zero) multivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let mv = ProjectiveMultivector3::new(
+    ///     1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64,
+    ///     9_f64, 10_f64, 11_f64, 12_f64, 13_f64, 14_f64, 15_f64, 16_f64
+    /// );
+    /// let zero: ProjectiveMultivector3<f64> = ProjectiveMultivector3::zero();
+    ///
+    /// assert_eq!(mv + zero, mv);
+    /// assert_eq!(zero + mv, mv);
+    /// ```
+    #[inline]
+    pub fn zero() -> Self {
+        Self { data: [S::zero(); 16] }
+    }
+
+    /// Determine whether a multivector is the zero mutlivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let zero: ProjectiveMultivector3<f64> = ProjectiveMultivector3::zero();
+    ///
+    /// assert!(zero.is_zero());
+    ///
+    /// let mv = ProjectiveMultivector3::new(
+    ///     1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64,
+    ///     9_f64, 10_f64, 11_f64, 12_f64, 13_f64, 14_f64, 15_f64, 16_f64
+    /// );
+    ///
+    /// assert!(!mv.is_zero());
+    /// ```
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.data.iter().all(|component| component.is_zero())
+    }
+
+    /// Construct a new multivector from the scalar part only.
+    ///
+    /// A scalar is a multivector whose vector, bivector, etc. components are
+    /// all zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let scalar_part = 2;
+    /// let scalar = ProjectiveMultivector3::from_scalar(scalar_part);
+    ///
+    /// assert_eq!(scalar.scalar, scalar_part);
+    /// assert_eq!(scalar.e0, 0);
+    /// assert_eq!(scalar.e1, 0);
+    /// assert_eq!(scalar.e2, 0);
+    /// assert_eq!(scalar.e3, 0);
+    /// assert_eq!(scalar.e01, 0);
+    /// assert_eq!(scalar.e02, 0);
+    /// assert_eq!(scalar.e03, 0);
+    /// assert_eq!(scalar.e12, 0);
+    /// assert_eq!(scalar.e23, 0);
+    /// assert_eq!(scalar.e31, 0);
+    /// assert_eq!(scalar.e032, 0);
+    /// assert_eq!(scalar.e013, 0);
+    /// assert_eq!(scalar.e021, 0);
+    /// assert_eq!(scalar.e123, 0);
+    /// assert_eq!(scalar.e0123, 0);
+    /// ```
+    #[inline]
+    pub fn from_scalar(scalar: S) -> Self {
+        let mut result = Self::zero();
+        result.data[0] = scalar;
+
+        result
+    }
+
+    /// Returns the unit scalar.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let unit_scalar: ProjectiveMultivector3<isize> = ProjectiveMultivector3::unit_scalar();
+    ///
+    /// assert_eq!(unit_scalar.scalar, 1);
+    /// assert_eq!(unit_scalar.e0, 0);
+    /// assert_eq!(unit_scalar.e1, 0);
+    /// assert_eq!(unit_scalar.e2, 0);
+    /// assert_eq!(unit_scalar.e3, 0);
+    /// assert_eq!(unit_scalar.e01, 0);
+    /// assert_eq!(unit_scalar.e02, 0);
+    /// assert_eq!(unit_scalar.e03, 0);
+    /// assert_eq!(unit_scalar.e12, 0);
+    /// assert_eq!(unit_scalar.e23, 0);
+    /// assert_eq!(unit_scalar.e31, 0);
+    /// assert_eq!(unit_scalar.e032, 0);
+    /// assert_eq!(unit_scalar.e013, 0);
+    /// assert_eq!(unit_scalar.e021, 0);
+    /// assert_eq!(unit_scalar.e123, 0);
+    /// assert_eq!(unit_scalar.e0123, 0);
+    /// ```
+    #[inline]
+    pub fn unit_scalar() -> Self {
+        let mut result = Self::zero();
+        result.data[0] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e0` vector, which represents the plane at infinity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let unit_e0: ProjectiveMultivector3<isize> = ProjectiveMultivector3::unit_e0();
+    ///
+    /// assert_eq!(unit_e0.scalar, 0);
+    /// assert_eq!(unit_e0.e0, 1);
+    /// assert_eq!(unit_e0.e1, 0);
+    /// assert_eq!(unit_e0.e2, 0);
+    /// assert_eq!(unit_e0.e3, 0);
+    /// assert_eq!(unit_e0.e01, 0);
+    /// assert_eq!(unit_e0.e02, 0);
+    /// assert_eq!(unit_e0.e03, 0);
+    /// assert_eq!(unit_e0.e12, 0);
+    /// assert_eq!(unit_e0.e23, 0);
+    /// assert_eq!(unit_e0.e31, 0);
+    /// assert_eq!(unit_e0.e032, 0);
+    /// assert_eq!(unit_e0.e013, 0);
+    /// assert_eq!(unit_e0.e021, 0);
+    /// assert_eq!(unit_e0.e123, 0);
+    /// assert_eq!(unit_e0.e0123, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e0() -> Self {
+        let mut result = Self::zero();
+        result.data[1] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `x`-axis vector, which represents the plane `x == 0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let unit_e1: ProjectiveMultivector3<isize> = ProjectiveMultivector3::unit_e1();
+    ///
+    /// assert_eq!(unit_e1.scalar, 0);
+    /// assert_eq!(unit_e1.e0, 0);
+    /// assert_eq!(unit_e1.e1, 1);
+    /// assert_eq!(unit_e1.e2, 0);
+    /// assert_eq!(unit_e1.e3, 0);
+    /// assert_eq!(unit_e1.e01, 0);
+    /// assert_eq!(unit_e1.e02, 0);
+    /// assert_eq!(unit_e1.e03, 0);
+    /// assert_eq!(unit_e1.e12, 0);
+    /// assert_eq!(unit_e1.e23, 0);
+    /// assert_eq!(unit_e1.e31, 0);
+    /// assert_eq!(unit_e1.e032, 0);
+    /// assert_eq!(unit_e1.e013, 0);
+    /// assert_eq!(unit_e1.e021, 0);
+    /// assert_eq!(unit_e1.e123, 0);
+    /// assert_eq!(unit_e1.e0123, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e1() -> Self {
+        let mut result = Self::zero();
+        result.data[2] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `y`-axis vector, which represents the plane `y == 0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let unit_e2: ProjectiveMultivector3<isize> = ProjectiveMultivector3::unit_e2();
+    ///
+    /// assert_eq!(unit_e2.scalar, 0);
+    /// assert_eq!(unit_e2.e0, 0);
+    /// assert_eq!(unit_e2.e1, 0);
+    /// assert_eq!(unit_e2.e2, 1);
+    /// assert_eq!(unit_e2.e3, 0);
+    /// assert_eq!(unit_e2.e01, 0);
+    /// assert_eq!(unit_e2.e02, 0);
+    /// assert_eq!(unit_e2.e03, 0);
+    /// assert_eq!(unit_e2.e12, 0);
+    /// assert_eq!(unit_e2.e23, 0);
+    /// assert_eq!(unit_e2.e31, 0);
+    /// assert_eq!(unit_e2.e032, 0);
+    /// assert_eq!(unit_e2.e013, 0);
+    /// assert_eq!(unit_e2.e021, 0);
+    /// assert_eq!(unit_e2.e123, 0);
+    /// assert_eq!(unit_e2.e0123, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e2() -> Self {
+        let mut result = Self::zero();
+        result.data[3] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `z`-axis vector, which represents the plane `z == 0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let unit_e3: ProjectiveMultivector3<isize> = ProjectiveMultivector3::unit_e3();
+    ///
+    /// assert_eq!(unit_e3.scalar, 0);
+    /// assert_eq!(unit_e3.e0, 0);
+    /// assert_eq!(unit_e3.e1, 0);
+    /// assert_eq!(unit_e3.e2, 0);
+    /// assert_eq!(unit_e3.e3, 1);
+    /// assert_eq!(unit_e3.e01, 0);
+    /// assert_eq!(unit_e3.e02, 0);
+    /// assert_eq!(unit_e3.e03, 0);
+    /// assert_eq!(unit_e3.e12, 0);
+    /// assert_eq!(unit_e3.e23, 0);
+    /// assert_eq!(unit_e3.e31, 0);
+    /// assert_eq!(unit_e3.e032, 0);
+    /// assert_eq!(unit_e3.e013, 0);
+    /// assert_eq!(unit_e3.e021, 0);
+    /// assert_eq!(unit_e3.e123, 0);
+    /// assert_eq!(unit_e3.e0123, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e3() -> Self {
+        let mut result = Self::zero();
+        result.data[4] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e01` bivector, which represents the ideal line orthogonal to the `x`-axis.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let unit_e01: ProjectiveMultivector3<isize> = ProjectiveMultivector3::unit_e01();
+    ///
+    /// assert_eq!(unit_e01.scalar, 0);
+    /// assert_eq!(unit_e01.e0, 0);
+    /// assert_eq!(unit_e01.e1, 0);
+    /// assert_eq!(unit_e01.e2, 0);
+    /// assert_eq!(unit_e01.e3, 0);
+    /// assert_eq!(unit_e01.e01, 1);
+    /// assert_eq!(unit_e01.e02, 0);
+    /// assert_eq!(unit_e01.e03, 0);
+    /// assert_eq!(unit_e01.e12, 0);
+    /// assert_eq!(unit_e01.e23, 0);
+    /// assert_eq!(unit_e01.e31, 0);
+    /// assert_eq!(unit_e01.e032, 0);
+    /// assert_eq!(unit_e01.e013, 0);
+    /// assert_eq!(unit_e01.e021, 0);
+    /// assert_eq!(unit_e01.e123, 0);
+    /// assert_eq!(unit_e01.e0123, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e01() -> Self {
+        let mut result = Self::zero();
+        result.data[5] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e02` bivector, which represents the ideal line orthogonal to the `y`-axis.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let unit_e02: ProjectiveMultivector3<isize> = ProjectiveMultivector3::unit_e02();
+    ///
+    /// assert_eq!(unit_e02.scalar, 0);
+    /// assert_eq!(unit_e02.e0, 0);
+    /// assert_eq!(unit_e02.e1, 0);
+    /// assert_eq!(unit_e02.e2, 0);
+    /// assert_eq!(unit_e02.e3, 0);
+    /// assert_eq!(unit_e02.e01, 0);
+    /// assert_eq!(unit_e02.e02, 1);
+    /// assert_eq!(unit_e02.e03, 0);
+    /// assert_eq!(unit_e02.e12, 0);
+    /// assert_eq!(unit_e02.e23, 0);
+    /// assert_eq!(unit_e02.e31, 0);
+    /// assert_eq!(unit_e02.e032, 0);
+    /// assert_eq!(unit_e02.e013, 0);
+    /// assert_eq!(unit_e02.e021, 0);
+    /// assert_eq!(unit_e02.e123, 0);
+    /// assert_eq!(unit_e02.e0123, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e02() -> Self {
+        let mut result = Self::zero();
+        result.data[6] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e03` bivector, which represents the ideal line orthogonal to the `z`-axis.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let unit_e03: ProjectiveMultivector3<isize> = ProjectiveMultivector3::unit_e03();
+    ///
+    /// assert_eq!(unit_e03.scalar, 0);
+    /// assert_eq!(unit_e03.e0, 0);
+    /// assert_eq!(unit_e03.e1, 0);
+    /// assert_eq!(unit_e03.e2, 0);
+    /// assert_eq!(unit_e03.e3, 0);
+    /// assert_eq!(unit_e03.e01, 0);
+    /// assert_eq!(unit_e03.e02, 0);
+    /// assert_eq!(unit_e03.e03, 1);
+    /// assert_eq!(unit_e03.e12, 0);
+    /// assert_eq!(unit_e03.e23, 0);
+    /// assert_eq!(unit_e03.e31, 0);
+    /// assert_eq!(unit_e03.e032, 0);
+    /// assert_eq!(unit_e03.e013, 0);
+    /// assert_eq!(unit_e03.e021, 0);
+    /// assert_eq!(unit_e03.e123, 0);
+    /// assert_eq!(unit_e03.e0123, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e03() -> Self {
+        let mut result = Self::zero();
+        result.data[7] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e12` bivector, which represents the `z`-axis.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let unit_e12: ProjectiveMultivector3<isize> = ProjectiveMultivector3::unit_e12();
+    ///
+    /// assert_eq!(unit_e12.scalar, 0);
+    /// assert_eq!(unit_e12.e0, 0);
+    /// assert_eq!(unit_e12.e1, 0);
+    /// assert_eq!(unit_e12.e2, 0);
+    /// assert_eq!(unit_e12.e3, 0);
+    /// assert_eq!(unit_e12.e01, 0);
+    /// assert_eq!(unit_e12.e02, 0);
+    /// assert_eq!(unit_e12.e03, 0);
+    /// assert_eq!(unit_e12.e12, 1);
+    /// assert_eq!(unit_e12.e23, 0);
+    /// assert_eq!(unit_e12.e31, 0);
+    /// assert_eq!(unit_e12.e032, 0);
+    /// assert_eq!(unit_e12.e013, 0);
+    /// assert_eq!(unit_e12.e021, 0);
+    /// assert_eq!(unit_e12.e123, 0);
+    /// assert_eq!(unit_e12.e0123, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e12() -> Self {
+        let mut result = Self::zero();
+        result.data[8] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e23` bivector, which represents the `x`-axis.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let unit_e23: ProjectiveMultivector3<isize> = ProjectiveMultivector3::unit_e23();
+    ///
+    /// assert_eq!(unit_e23.scalar, 0);
+    /// assert_eq!(unit_e23.e0, 0);
+    /// assert_eq!(unit_e23.e1, 0);
+    /// assert_eq!(unit_e23.e2, 0);
+    /// assert_eq!(unit_e23.e3, 0);
+    /// assert_eq!(unit_e23.e01, 0);
+    /// assert_eq!(unit_e23.e02, 0);
+    /// assert_eq!(unit_e23.e03, 0);
+    /// assert_eq!(unit_e23.e12, 0);
+    /// assert_eq!(unit_e23.e23, 1);
+    /// assert_eq!(unit_e23.e31, 0);
+    /// assert_eq!(unit_e23.e032, 0);
+    /// assert_eq!(unit_e23.e013, 0);
+    /// assert_eq!(unit_e23.e021, 0);
+    /// assert_eq!(unit_e23.e123, 0);
+    /// assert_eq!(unit_e23.e0123, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e23() -> Self {
+        let mut result = Self::zero();
+        result.data[9] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e31` bivector, which represents the `y`-axis.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let unit_e31: ProjectiveMultivector3<isize> = ProjectiveMultivector3::unit_e31();
+    ///
+    /// assert_eq!(unit_e31.scalar, 0);
+    /// assert_eq!(unit_e31.e0, 0);
+    /// assert_eq!(unit_e31.e1, 0);
+    /// assert_eq!(unit_e31.e2, 0);
+    /// assert_eq!(unit_e31.e3, 0);
+    /// assert_eq!(unit_e31.e01, 0);
+    /// assert_eq!(unit_e31.e02, 0);
+    /// assert_eq!(unit_e31.e03, 0);
+    /// assert_eq!(unit_e31.e12, 0);
+    /// assert_eq!(unit_e31.e23, 0);
+    /// assert_eq!(unit_e31.e31, 1);
+    /// assert_eq!(unit_e31.e032, 0);
+    /// assert_eq!(unit_e31.e013, 0);
+    /// assert_eq!(unit_e31.e021, 0);
+    /// assert_eq!(unit_e31.e123, 0);
+    /// assert_eq!(unit_e31.e0123, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e31() -> Self {
+        let mut result = Self::zero();
+        result.data[10] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e032` trivector, which represents the `x`-axis direction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let unit_e032: ProjectiveMultivector3<isize> = ProjectiveMultivector3::unit_e032();
+    ///
+    /// assert_eq!(unit_e032.scalar, 0);
+    /// assert_eq!(unit_e032.e0, 0);
+    /// assert_eq!(unit_e032.e1, 0);
+    /// assert_eq!(unit_e032.e2, 0);
+    /// assert_eq!(unit_e032.e3, 0);
+    /// assert_eq!(unit_e032.e01, 0);
+    /// assert_eq!(unit_e032.e02, 0);
+    /// assert_eq!(unit_e032.e03, 0);
+    /// assert_eq!(unit_e032.e12, 0);
+    /// assert_eq!(unit_e032.e23, 0);
+    /// assert_eq!(unit_e032.e31, 0);
+    /// assert_eq!(unit_e032.e032, 1);
+    /// assert_eq!(unit_e032.e013, 0);
+    /// assert_eq!(unit_e032.e021, 0);
+    /// assert_eq!(unit_e032.e123, 0);
+    /// assert_eq!(unit_e032.e0123, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e032() -> Self {
+        let mut result = Self::zero();
+        result.data[11] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e013` trivector, which represents the `y`-axis direction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let unit_e013: ProjectiveMultivector3<isize> = ProjectiveMultivector3::unit_e013();
+    ///
+    /// assert_eq!(unit_e013.scalar, 0);
+    /// assert_eq!(unit_e013.e0, 0);
+    /// assert_eq!(unit_e013.e1, 0);
+    /// assert_eq!(unit_e013.e2, 0);
+    /// assert_eq!(unit_e013.e3, 0);
+    /// assert_eq!(unit_e013.e01, 0);
+    /// assert_eq!(unit_e013.e02, 0);
+    /// assert_eq!(unit_e013.e03, 0);
+    /// assert_eq!(unit_e013.e12, 0);
+    /// assert_eq!(unit_e013.e23, 0);
+    /// assert_eq!(unit_e013.e31, 0);
+    /// assert_eq!(unit_e013.e032, 0);
+    /// assert_eq!(unit_e013.e013, 1);
+    /// assert_eq!(unit_e013.e021, 0);
+    /// assert_eq!(unit_e013.e123, 0);
+    /// assert_eq!(unit_e013.e0123, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e013() -> Self {
+        let mut result = Self::zero();
+        result.data[12] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e021` trivector, which represents the `z`-axis direction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let unit_e021: ProjectiveMultivector3<isize> = ProjectiveMultivector3::unit_e021();
+    ///
+    /// assert_eq!(unit_e021.scalar, 0);
+    /// assert_eq!(unit_e021.e0, 0);
+    /// assert_eq!(unit_e021.e1, 0);
+    /// assert_eq!(unit_e021.e2, 0);
+    /// assert_eq!(unit_e021.e3, 0);
+    /// assert_eq!(unit_e021.e01, 0);
+    /// assert_eq!(unit_e021.e02, 0);
+    /// assert_eq!(unit_e021.e03, 0);
+    /// assert_eq!(unit_e021.e12, 0);
+    /// assert_eq!(unit_e021.e23, 0);
+    /// assert_eq!(unit_e021.e31, 0);
+    /// assert_eq!(unit_e021.e032, 0);
+    /// assert_eq!(unit_e021.e013, 0);
+    /// assert_eq!(unit_e021.e021, 1);
+    /// assert_eq!(unit_e021.e123, 0);
+    /// assert_eq!(unit_e021.e0123, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e021() -> Self {
+        let mut result = Self::zero();
+        result.data[13] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `e123` trivector, which represents the origin.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let unit_e123: ProjectiveMultivector3<isize> = ProjectiveMultivector3::unit_e123();
+    ///
+    /// assert_eq!(unit_e123.scalar, 0);
+    /// assert_eq!(unit_e123.e0, 0);
+    /// assert_eq!(unit_e123.e1, 0);
+    /// assert_eq!(unit_e123.e2, 0);
+    /// assert_eq!(unit_e123.e3, 0);
+    /// assert_eq!(unit_e123.e01, 0);
+    /// assert_eq!(unit_e123.e02, 0);
+    /// assert_eq!(unit_e123.e03, 0);
+    /// assert_eq!(unit_e123.e12, 0);
+    /// assert_eq!(unit_e123.e23, 0);
+    /// assert_eq!(unit_e123.e31, 0);
+    /// assert_eq!(unit_e123.e032, 0);
+    /// assert_eq!(unit_e123.e013, 0);
+    /// assert_eq!(unit_e123.e021, 0);
+    /// assert_eq!(unit_e123.e123, 1);
+    /// assert_eq!(unit_e123.e0123, 0);
+    /// ```
+    #[inline]
+    pub fn unit_e123() -> Self {
+        let mut result = Self::zero();
+        result.data[14] = S::one();
+
+        result
+    }
+
+    /// Returns the unit volume element `e0123`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let unit_e0123: ProjectiveMultivector3<isize> = ProjectiveMultivector3::unit_e0123();
+    ///
+    /// assert_eq!(unit_e0123.scalar, 0);
+    /// assert_eq!(unit_e0123.e0, 0);
+    /// assert_eq!(unit_e0123.e1, 0);
+    /// assert_eq!(unit_e0123.e2, 0);
+    /// assert_eq!(unit_e0123.e3, 0);
+    /// assert_eq!(unit_e0123.e01, 0);
+    /// assert_eq!(unit_e0123.e02, 0);
+    /// assert_eq!(unit_e0123.e03, 0);
+    /// assert_eq!(unit_e0123.e12, 0);
+    /// assert_eq!(unit_e0123.e23, 0);
+    /// assert_eq!(unit_e0123.e31, 0);
+    /// assert_eq!(unit_e0123.e032, 0);
+    /// assert_eq!(unit_e0123.e013, 0);
+    /// assert_eq!(unit_e0123.e021, 0);
+    /// assert_eq!(unit_e0123.e123, 0);
+    /// assert_eq!(unit_e0123.e0123, 1);
+    /// ```
+    #[inline]
+    pub fn unit_e0123() -> Self {
+        let mut result = Self::zero();
+        result.data[15] = S::one();
+
+        result
+    }
+
+    /// Returns the unit volume element for three-dimensional projective geometric algebra.
+    ///
+    /// This is a synonym for `unit_e0123`.
+    #[inline(always)]
+    pub fn pseudoscalar() -> Self {
+        Self::unit_e0123()
+    }
+
+    /// Project the multivector onto the grade `grade`.
+    ///
+    /// Return a multivector where the components of each grade other than
+    /// input grade are zero. For each grade larger than the dimension of the
+    /// underlying vector space, the grade projection is always zero. In this
+    /// case, any grade projection onto a grade larger than 4 will be zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let mv: ProjectiveMultivector3<isize> = ProjectiveMultivector3::new(1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize);
+    /// let expected_0 = ProjectiveMultivector3::new(1_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize);
+    /// let mv_0 = mv.grade(0);
+    /// let expected_1 = ProjectiveMultivector3::new(0_isize, 1_isize, 1_isize, 1_isize, 1_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize);
+    /// let mv_1 = mv.grade(1);
+    /// let expected_2 = ProjectiveMultivector3::new(0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize);
+    /// let mv_2 = mv.grade(2);
+    /// let expected_3 = ProjectiveMultivector3::new(0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 1_isize, 1_isize, 1_isize, 1_isize, 0_isize);
+    /// let mv_3 = mv.grade(3);
+    /// let expected_4 = ProjectiveMultivector3::new(0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 1_isize);
+    /// let mv_4 = mv.grade(4);
+    ///
+    /// assert_eq!(mv_0, expected_0);
+    /// assert_eq!(mv_1, expected_1);
+    /// assert_eq!(mv_2, expected_2);
+    /// assert_eq!(mv_3, expected_3);
+    /// assert_eq!(mv_4, expected_4);
+    ///
+    /// // Any grade larger than 4 should be zero.
+    /// let zero: ProjectiveMultivector3<isize> = ProjectiveMultivector3::zero();
+    /// assert_eq!(mv.grade(5), zero);
+    /// assert_eq!(mv.grade(usize::MAX), zero);
+    /// ```
+    #[inline]
+    pub fn grade(&self, grade: usize) -> Self {
+        let mut result = Self::zero();
+        match grade {
+            0 => {
+                result.data[0] = self.data[0];
+            }
+            1 => {
+                result.data[1] = self.data[1];
+                result.data[2] = self.data[2];
+                result.data[3] = self.data[3];
+                result.data[4] = self.data[4];
+            }
+            2 => {
+                result.data[5] = self.data[5];
+                result.data[6] = self.data[6];
+                result.data[7] = self.data[7];
+                result.data[8] = self.data[8];
+                result.data[9] = self.data[9];
+                result.data[10] = self.data[10];
+            }
+            3 => {
+                result.data[11] = self.data[11];
+                result.data[12] = self.data[12];
+                result.data[13] = self.data[13];
+                result.data[14] = self.data[14];
+            }
+            4 => {
+                result.data[15] = self.data[15];
+            }
+            _ => {}
+        }
+
+        result
+    }
+
+    /// Compute the left contraction of `self` with `other`.
+    ///
+    /// This is a synonym for the `<<` operator.
+    #[inline]
+    pub fn left_contract(&self, other: &Self) -> Self {
+        self << other
+    }
+
+    /// Compute the right contraction of `self` with `other`.
+    ///
+    /// This is a synonym for the `>>` operator.
+    #[inline]
+    pub fn right_contract(&self, other: &Self) -> Self {
+        self >> other
+    }
+
+    /// Compute the scalar product of `self` and `other`.
+    ///
+    /// This is a synonym for the `|` operator.
+    #[inline]
+    pub fn scalar_product(&self, other: &Self) -> Self {
+        self | other
+    }
+
+    /// Compute the outer product of `self` and `other`.
+    ///
+    /// This is a synonym for the `^` operator.
+    #[inline]
+    pub fn outer_product(&self, other: &Self) -> Self {
+        self ^ other
+    }
+
+    /// Compute the regressive product of `self` and `other`.
+    ///
+    /// The regressive product is the outer product of the duals, mapped back
+    /// by the inverse of the duality map
+    /// ```text
+    /// mv1 & mv2 := undual(dual(mv1) ^ dual(mv2))
+    /// ```
+    /// In projective geometric algebra, the regressive product computes the
+    /// join of geometric objects. For instance, the join of two points is the
+    /// line through both points, and the join of a line and a point is the
+    /// plane containing both.
+    ///
+    /// This is a synonym for the `&` operator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let e123: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e123();
+    /// let e032: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e032();
+    /// let expected: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e23();
+    /// let result = e123.regressive_product(&e032);
+    ///
+    /// assert_eq!(result, expected);
+    /// assert_eq!(result, (e123.dual() ^ e032.dual()).undual());
+    /// ```
+    #[inline]
+    pub fn regressive_product(&self, other: &Self) -> Self {
+        self & other
+    }
+}
+
+impl<S> ops::Index<usize> for ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = S;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.data[index]
+    }
+}
+
+impl<S> ops::IndexMut<usize> for ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.data[index]
+    }
+}
+
+impl<S> AsRef<[S; 16]> for ProjectiveMultivector3<S> {
+    #[inline]
+    fn as_ref(&self) -> &[S; 16] {
+        unsafe { &*(self as *const ProjectiveMultivector3<S> as *const [S; 16]) }
+    }
+}
+
+impl<S> AsMut<[S; 16]> for ProjectiveMultivector3<S> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [S; 16] {
+        unsafe { &mut *(self as *mut ProjectiveMultivector3<S> as *mut [S; 16]) }
+    }
+}
+
+impl<S> fmt::Display for ProjectiveMultivector3<S>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "{} + {}^e0 + {}^e1 + {}^e2 + {}^e3 + {}^e01 + {}^e02 + {}^e03 + {}^e12 + {}^e23 + {}^e31 + {}^e032 + {}^e013 + {}^e021 + {}^e123 + {}^e0123",
+            self.data[0],
+            self.data[1],
+            self.data[2],
+            self.data[3],
+            self.data[4],
+            self.data[5],
+            self.data[6],
+            self.data[7],
+            self.data[8],
+            self.data[9],
+            self.data[10],
+            self.data[11],
+            self.data[12],
+            self.data[13],
+            self.data[14],
+            self.data[15],
+        )
+    }
+}
+
+
+impl<S> ProjectiveMultivector3<S>
+where
+    S: ScalarSigned,
+{
+    /// Compute the reverse of a multivector.
+    ///
+    /// The reverse of a multivector reverses the order of the vectors in each
+    /// basis blade. For each grade of multivector it is given by
+    /// ```text
+    /// When mv has grade 0, rev(mv) := mv
+    /// When mv has grade 1, rev(mv) := mv
+    /// When mv has grade 2, rev(mv) := -mv
+    /// When mv has grade 3, rev(mv) := -mv
+    /// When mv has grade 4, rev(mv) := mv
+    /// ```
+    /// The reverse of each basis blade is
+    /// ```text
+    /// rev(1)     = 1
+    /// rev(e0)    = e0
+    /// rev(e1)    = e1
+    /// rev(e2)    = e2
+    /// rev(e3)    = e3
+    /// rev(e01)   = -e01
+    /// rev(e02)   = -e02
+    /// rev(e03)   = -e03
+    /// rev(e12)   = -e12
+    /// rev(e23)   = -e23
+    /// rev(e31)   = -e31
+    /// rev(e032)  = -e032
+    /// rev(e013)  = -e013
+    /// rev(e021)  = -e021
+    /// rev(e123)  = -e123
+    /// rev(e0123) = e0123
+    /// ```
+    /// and the reverse of a general multivector follows by linearity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let mv = ProjectiveMultivector3::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = ProjectiveMultivector3::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, -6_i32, -7_i32, -8_i32,
+    ///     -9_i32, -10_i32, -11_i32, -12_i32, -13_i32, -14_i32, -15_i32, 16_i32
+    /// );
+    /// let result = mv.reverse();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn reverse(&self) -> Self {
+        let mut result = *self;
+        result.reverse_mut();
+
+        result
+    }
+
+    /// Compute the reverse of a multivector mutably in place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let mut result = ProjectiveMultivector3::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = ProjectiveMultivector3::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, -6_i32, -7_i32, -8_i32,
+    ///     -9_i32, -10_i32, -11_i32, -12_i32, -13_i32, -14_i32, -15_i32, 16_i32
+    /// );
+    /// result.reverse_mut();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn reverse_mut(&mut self) {
+        self.data[5] = -self.data[5];
+        self.data[6] = -self.data[6];
+        self.data[7] = -self.data[7];
+        self.data[8] = -self.data[8];
+        self.data[9] = -self.data[9];
+        self.data[10] = -self.data[10];
+        self.data[11] = -self.data[11];
+        self.data[12] = -self.data[12];
+        self.data[13] = -self.data[13];
+        self.data[14] = -self.data[14];
+    }
+
+    /// Compute the conjugate of a multivector.
+    ///
+    /// The conjugate of a multivector is the composition of the reverse and the
+    /// grade involution. For each grade of multivector it is given by
+    /// ```text
+    /// When mv has grade 0, conj(mv) := mv
+    /// When mv has grade 1, conj(mv) := -mv
+    /// When mv has grade 2, conj(mv) := -mv
+    /// When mv has grade 3, conj(mv) := mv
+    /// When mv has grade 4, conj(mv) := mv
+    /// ```
+    /// The conjugate of each basis blade is
+    /// ```text
+    /// conj(1)     = 1
+    /// conj(e0)    = -e0
+    /// conj(e1)    = -e1
+    /// conj(e2)    = -e2
+    /// conj(e3)    = -e3
+    /// conj(e01)   = -e01
+    /// conj(e02)   = -e02
+    /// conj(e03)   = -e03
+    /// conj(e12)   = -e12
+    /// conj(e23)   = -e23
+    /// conj(e31)   = -e31
+    /// conj(e032)  = e032
+    /// conj(e013)  = e013
+    /// conj(e021)  = e021
+    /// conj(e123)  = e123
+    /// conj(e0123) = e0123
+    /// ```
+    /// and the conjugate of a general multivector follows by linearity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let mv = ProjectiveMultivector3::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = ProjectiveMultivector3::new(
+    ///     1_i32, -2_i32, -3_i32, -4_i32, -5_i32, -6_i32, -7_i32, -8_i32,
+    ///     -9_i32, -10_i32, -11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let result = mv.conjugate();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn conjugate(&self) -> Self {
+        let mut result = *self;
+        result.conjugate_mut();
+
+        result
+    }
+
+    /// Compute the conjugate of a multivector mutably in place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let mut result = ProjectiveMultivector3::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = ProjectiveMultivector3::new(
+    ///     1_i32, -2_i32, -3_i32, -4_i32, -5_i32, -6_i32, -7_i32, -8_i32,
+    ///     -9_i32, -10_i32, -11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// result.conjugate_mut();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn conjugate_mut(&mut self) {
+        self.data[1] = -self.data[1];
+        self.data[2] = -self.data[2];
+        self.data[3] = -self.data[3];
+        self.data[4] = -self.data[4];
+        self.data[5] = -self.data[5];
+        self.data[6] = -self.data[6];
+        self.data[7] = -self.data[7];
+        self.data[8] = -self.data[8];
+        self.data[9] = -self.data[9];
+        self.data[10] = -self.data[10];
+    }
+
+    /// Compute the grade involution of a multivector.
+    ///
+    /// The grade involution of a multivector negates the components of odd grade.
+    /// For each grade of multivector it is given by
+    /// ```text
+    /// When mv has grade 0, invol(mv) := mv
+    /// When mv has grade 1, invol(mv) := -mv
+    /// When mv has grade 2, invol(mv) := mv
+    /// When mv has grade 3, invol(mv) := -mv
+    /// When mv has grade 4, invol(mv) := mv
+    /// ```
+    /// The grade involution of each basis blade is
+    /// ```text
+    /// invol(1)     = 1
+    /// invol(e0)    = -e0
+    /// invol(e1)    = -e1
+    /// invol(e2)    = -e2
+    /// invol(e3)    = -e3
+    /// invol(e01)   = e01
+    /// invol(e02)   = e02
+    /// invol(e03)   = e03
+    /// invol(e12)   = e12
+    /// invol(e23)   = e23
+    /// invol(e31)   = e31
+    /// invol(e032)  = -e032
+    /// invol(e013)  = -e013
+    /// invol(e021)  = -e021
+    /// invol(e123)  = -e123
+    /// invol(e0123) = e0123
+    /// ```
+    /// and the grade involution of a general multivector follows by linearity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let mv = ProjectiveMultivector3::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = ProjectiveMultivector3::new(
+    ///     1_i32, -2_i32, -3_i32, -4_i32, -5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, -12_i32, -13_i32, -14_i32, -15_i32, 16_i32
+    /// );
+    /// let result = mv.involute();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn involute(&self) -> Self {
+        let mut result = *self;
+        result.involute_mut();
+
+        result
+    }
+
+    /// Compute the grade involution of a multivector mutably in place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let mut result = ProjectiveMultivector3::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = ProjectiveMultivector3::new(
+    ///     1_i32, -2_i32, -3_i32, -4_i32, -5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, -12_i32, -13_i32, -14_i32, -15_i32, 16_i32
+    /// );
+    /// result.involute_mut();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn involute_mut(&mut self) {
+        self.data[1] = -self.data[1];
+        self.data[2] = -self.data[2];
+        self.data[3] = -self.data[3];
+        self.data[4] = -self.data[4];
+        self.data[11] = -self.data[11];
+        self.data[12] = -self.data[12];
+        self.data[13] = -self.data[13];
+        self.data[14] = -self.data[14];
+    }
+
+    /// Compute the dual of a multivector.
+    ///
+    /// The dual of a multivector is also known as the orthogonal complement.
+    /// In projective geometric algebra the pseudoscalar `e0123` is not invertible,
+    /// so the dual is not defined by the geometric product with the inverse
+    /// pseudoscalar as in the Euclidean algebras. Instead, the dual of each basis
+    /// blade is its right complement, i.e. the basis blade spanning the remaining
+    /// basis directions, with its sign chosen such that the outer product of the
+    /// basis blade and its dual is the pseudoscalar. The dual maps planes to
+    /// points and points to planes, and is used to define the regressive product.
+    ///
+    /// The dual of each basis blade is
+    /// ```text
+    /// dual(1)     = e0123
+    /// dual(e0)    = e123
+    /// dual(e1)    = e032
+    /// dual(e2)    = e013
+    /// dual(e3)    = e021
+    /// dual(e01)   = e23
+    /// dual(e02)   = e31
+    /// dual(e03)   = e12
+    /// dual(e12)   = e03
+    /// dual(e23)   = e01
+    /// dual(e31)   = e02
+    /// dual(e032)  = -e1
+    /// dual(e013)  = -e2
+    /// dual(e021)  = -e3
+    /// dual(e123)  = -e0
+    /// dual(e0123) = 1
+    /// ```
+    /// so that `e_A ^ dual(e_A) == e0123` for every basis blade `e_A`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let mv = ProjectiveMultivector3::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = ProjectiveMultivector3::new(
+    ///     16_i32, -15_i32, -12_i32, -13_i32, -14_i32, 10_i32, 11_i32, 9_i32,
+    ///     8_i32, 6_i32, 7_i32, 3_i32, 4_i32, 5_i32, 2_i32, 1_i32
+    /// );
+    /// let result = mv.dual();
+    ///
+    /// assert_eq!(result, expected);
+    /// assert_eq!(result.undual(), mv);
+    /// ```
+    #[rustfmt::skip]
+    pub fn dual(&self) -> Self {
+        let mut result = Self::zero();
+        result.data[0]  =  self.data[15];
+        result.data[1]  = -self.data[14];
+        result.data[2]  = -self.data[11];
+        result.data[3]  = -self.data[12];
+        result.data[4]  = -self.data[13];
+        result.data[5]  =  self.data[9];
+        result.data[6]  =  self.data[10];
+        result.data[7]  =  self.data[8];
+        result.data[8]  =  self.data[7];
+        result.data[9]  =  self.data[5];
+        result.data[10] =  self.data[6];
+        result.data[11] =  self.data[2];
+        result.data[12] =  self.data[3];
+        result.data[13] =  self.data[4];
+        result.data[14] =  self.data[1];
+        result.data[15] =  self.data[0];
+
+        result
+    }
+
+    /// Compute the dual of a multivector mutably in place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let mut result = ProjectiveMultivector3::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = ProjectiveMultivector3::new(
+    ///     16_i32, -15_i32, -12_i32, -13_i32, -14_i32, 10_i32, 11_i32, 9_i32,
+    ///     8_i32, 6_i32, 7_i32, 3_i32, 4_i32, 5_i32, 2_i32, 1_i32
+    /// );
+    /// result.dual_mut();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn dual_mut(&mut self) {
+        *self = self.dual();
+    }
+
+    /// Compute the undual of a multivector.
+    ///
+    /// The undual is the inverse of the dual, i.e. `undual(dual(mv)) == mv`
+    /// and `dual(undual(mv)) == mv` for every multivector `mv`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let mv = ProjectiveMultivector3::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    ///
+    /// assert_eq!(mv.dual().undual(), mv);
+    /// assert_eq!(mv.undual().dual(), mv);
+    /// ```
+    #[rustfmt::skip]
+    pub fn undual(&self) -> Self {
+        let mut result = Self::zero();
+        result.data[0]  =  self.data[15];
+        result.data[1]  =  self.data[14];
+        result.data[2]  =  self.data[11];
+        result.data[3]  =  self.data[12];
+        result.data[4]  =  self.data[13];
+        result.data[5]  =  self.data[9];
+        result.data[6]  =  self.data[10];
+        result.data[7]  =  self.data[8];
+        result.data[8]  =  self.data[7];
+        result.data[9]  =  self.data[5];
+        result.data[10] =  self.data[6];
+        result.data[11] = -self.data[2];
+        result.data[12] = -self.data[3];
+        result.data[13] = -self.data[4];
+        result.data[14] = -self.data[1];
+        result.data[15] =  self.data[0];
+
+        result
+    }
+}
+
+impl<S> ops::Not for ProjectiveMultivector3<S>
+where
+    S: ScalarSigned,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        self.dual()
+    }
+}
+
+impl<S> ops::Not for &ProjectiveMultivector3<S>
+where
+    S: ScalarSigned,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        self.dual()
+    }
+}
+
+impl<S> ops::Neg for ProjectiveMultivector3<S>
+where
+    S: ScalarSigned,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn neg(self) -> Self::Output {
+        let result_1     = -self.data[0];
+        let result_e0    = -self.data[1];
+        let result_e1    = -self.data[2];
+        let result_e2    = -self.data[3];
+        let result_e3    = -self.data[4];
+        let result_e01   = -self.data[5];
+        let result_e02   = -self.data[6];
+        let result_e03   = -self.data[7];
+        let result_e12   = -self.data[8];
+        let result_e23   = -self.data[9];
+        let result_e31   = -self.data[10];
+        let result_e032  = -self.data[11];
+        let result_e013  = -self.data[12];
+        let result_e021  = -self.data[13];
+        let result_e123  = -self.data[14];
+        let result_e0123 = -self.data[15];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::Neg for &ProjectiveMultivector3<S>
+where
+    S: ScalarSigned,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn neg(self) -> Self::Output {
+        let result_1     = -self.data[0];
+        let result_e0    = -self.data[1];
+        let result_e1    = -self.data[2];
+        let result_e2    = -self.data[3];
+        let result_e3    = -self.data[4];
+        let result_e01   = -self.data[5];
+        let result_e02   = -self.data[6];
+        let result_e03   = -self.data[7];
+        let result_e12   = -self.data[8];
+        let result_e23   = -self.data[9];
+        let result_e31   = -self.data[10];
+        let result_e032  = -self.data[11];
+        let result_e013  = -self.data[12];
+        let result_e021  = -self.data[13];
+        let result_e123  = -self.data[14];
+        let result_e0123 = -self.data[15];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::Mul<ProjectiveMultivector3<S>> for ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: ProjectiveMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[0] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[14] * b[14];
+        let result_e0    = a[0] * b[1] + a[1] * b[0] - a[2] * b[5] - a[3] * b[6] - a[4] * b[7] + a[5] * b[2] + a[6] * b[3] + a[7] * b[4] + a[8] * b[13] + a[9] * b[11] + a[10] * b[12] + a[11] * b[9] + a[12] * b[10] + a[13] * b[8] + a[14] * b[15] - a[15] * b[14];
+        let result_e1    = a[0] * b[2] + a[2] * b[0] - a[3] * b[8] + a[4] * b[10] + a[8] * b[3] - a[9] * b[14] - a[10] * b[4] - a[14] * b[9];
+        let result_e2    = a[0] * b[3] + a[2] * b[8] + a[3] * b[0] - a[4] * b[9] - a[8] * b[2] + a[9] * b[4] - a[10] * b[14] - a[14] * b[10];
+        let result_e3    = a[0] * b[4] - a[2] * b[10] + a[3] * b[9] + a[4] * b[0] - a[8] * b[14] - a[9] * b[3] + a[10] * b[2] - a[14] * b[8];
+        let result_e01   = a[0] * b[5] + a[1] * b[2] - a[2] * b[1] - a[3] * b[13] + a[4] * b[12] + a[5] * b[0] - a[6] * b[8] + a[7] * b[10] + a[8] * b[6] - a[9] * b[15] - a[10] * b[7] + a[11] * b[14] + a[12] * b[4] - a[13] * b[3] - a[14] * b[11] - a[15] * b[9];
+        let result_e02   = a[0] * b[6] + a[1] * b[3] + a[2] * b[13] - a[3] * b[1] - a[4] * b[11] + a[5] * b[8] + a[6] * b[0] - a[7] * b[9] - a[8] * b[5] + a[9] * b[7] - a[10] * b[15] - a[11] * b[4] + a[12] * b[14] + a[13] * b[2] - a[14] * b[12] - a[15] * b[10];
+        let result_e03   = a[0] * b[7] + a[1] * b[4] - a[2] * b[12] + a[3] * b[11] - a[4] * b[1] - a[5] * b[10] + a[6] * b[9] + a[7] * b[0] - a[8] * b[15] - a[9] * b[6] + a[10] * b[5] + a[11] * b[3] - a[12] * b[2] + a[13] * b[14] - a[14] * b[13] - a[15] * b[8];
+        let result_e12   = a[0] * b[8] + a[2] * b[3] - a[3] * b[2] + a[4] * b[14] + a[8] * b[0] - a[9] * b[10] + a[10] * b[9] + a[14] * b[4];
+        let result_e23   = a[0] * b[9] + a[2] * b[14] + a[3] * b[4] - a[4] * b[3] + a[8] * b[10] + a[9] * b[0] - a[10] * b[8] + a[14] * b[2];
+        let result_e31   = a[0] * b[10] - a[2] * b[4] + a[3] * b[14] + a[4] * b[2] - a[8] * b[9] + a[9] * b[8] + a[10] * b[0] + a[14] * b[3];
+        let result_e032  = a[0] * b[11] - a[1] * b[9] + a[2] * b[15] + a[3] * b[7] - a[4] * b[6] - a[5] * b[14] - a[6] * b[4] + a[7] * b[3] + a[8] * b[12] - a[9] * b[1] - a[10] * b[13] + a[11] * b[0] - a[12] * b[8] + a[13] * b[10] + a[14] * b[5] - a[15] * b[2];
+        let result_e013  = a[0] * b[12] - a[1] * b[10] - a[2] * b[7] + a[3] * b[15] + a[4] * b[5] + a[5] * b[4] - a[6] * b[14] - a[7] * b[2] - a[8] * b[11] + a[9] * b[13] - a[10] * b[1] + a[11] * b[8] + a[12] * b[0] - a[13] * b[9] + a[14] * b[6] - a[15] * b[3];
+        let result_e021  = a[0] * b[13] - a[1] * b[8] + a[2] * b[6] - a[3] * b[5] + a[4] * b[15] - a[5] * b[3] + a[6] * b[2] - a[7] * b[14] - a[8] * b[1] - a[9] * b[12] + a[10] * b[11] - a[11] * b[10] + a[12] * b[9] + a[13] * b[0] + a[14] * b[7] - a[15] * b[4];
+        let result_e123  = a[0] * b[14] + a[2] * b[9] + a[3] * b[10] + a[4] * b[8] + a[8] * b[4] + a[9] * b[2] + a[10] * b[3] + a[14] * b[0];
+        let result_e0123 = a[0] * b[15] + a[1] * b[14] + a[2] * b[11] + a[3] * b[12] + a[4] * b[13] + a[5] * b[9] + a[6] * b[10] + a[7] * b[8] + a[8] * b[7] + a[9] * b[5] + a[10] * b[6] - a[11] * b[2] - a[12] * b[3] - a[13] * b[4] - a[14] * b[1] + a[15] * b[0];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::Mul<&ProjectiveMultivector3<S>> for ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: &ProjectiveMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[0] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[14] * b[14];
+        let result_e0    = a[0] * b[1] + a[1] * b[0] - a[2] * b[5] - a[3] * b[6] - a[4] * b[7] + a[5] * b[2] + a[6] * b[3] + a[7] * b[4] + a[8] * b[13] + a[9] * b[11] + a[10] * b[12] + a[11] * b[9] + a[12] * b[10] + a[13] * b[8] + a[14] * b[15] - a[15] * b[14];
+        let result_e1    = a[0] * b[2] + a[2] * b[0] - a[3] * b[8] + a[4] * b[10] + a[8] * b[3] - a[9] * b[14] - a[10] * b[4] - a[14] * b[9];
+        let result_e2    = a[0] * b[3] + a[2] * b[8] + a[3] * b[0] - a[4] * b[9] - a[8] * b[2] + a[9] * b[4] - a[10] * b[14] - a[14] * b[10];
+        let result_e3    = a[0] * b[4] - a[2] * b[10] + a[3] * b[9] + a[4] * b[0] - a[8] * b[14] - a[9] * b[3] + a[10] * b[2] - a[14] * b[8];
+        let result_e01   = a[0] * b[5] + a[1] * b[2] - a[2] * b[1] - a[3] * b[13] + a[4] * b[12] + a[5] * b[0] - a[6] * b[8] + a[7] * b[10] + a[8] * b[6] - a[9] * b[15] - a[10] * b[7] + a[11] * b[14] + a[12] * b[4] - a[13] * b[3] - a[14] * b[11] - a[15] * b[9];
+        let result_e02   = a[0] * b[6] + a[1] * b[3] + a[2] * b[13] - a[3] * b[1] - a[4] * b[11] + a[5] * b[8] + a[6] * b[0] - a[7] * b[9] - a[8] * b[5] + a[9] * b[7] - a[10] * b[15] - a[11] * b[4] + a[12] * b[14] + a[13] * b[2] - a[14] * b[12] - a[15] * b[10];
+        let result_e03   = a[0] * b[7] + a[1] * b[4] - a[2] * b[12] + a[3] * b[11] - a[4] * b[1] - a[5] * b[10] + a[6] * b[9] + a[7] * b[0] - a[8] * b[15] - a[9] * b[6] + a[10] * b[5] + a[11] * b[3] - a[12] * b[2] + a[13] * b[14] - a[14] * b[13] - a[15] * b[8];
+        let result_e12   = a[0] * b[8] + a[2] * b[3] - a[3] * b[2] + a[4] * b[14] + a[8] * b[0] - a[9] * b[10] + a[10] * b[9] + a[14] * b[4];
+        let result_e23   = a[0] * b[9] + a[2] * b[14] + a[3] * b[4] - a[4] * b[3] + a[8] * b[10] + a[9] * b[0] - a[10] * b[8] + a[14] * b[2];
+        let result_e31   = a[0] * b[10] - a[2] * b[4] + a[3] * b[14] + a[4] * b[2] - a[8] * b[9] + a[9] * b[8] + a[10] * b[0] + a[14] * b[3];
+        let result_e032  = a[0] * b[11] - a[1] * b[9] + a[2] * b[15] + a[3] * b[7] - a[4] * b[6] - a[5] * b[14] - a[6] * b[4] + a[7] * b[3] + a[8] * b[12] - a[9] * b[1] - a[10] * b[13] + a[11] * b[0] - a[12] * b[8] + a[13] * b[10] + a[14] * b[5] - a[15] * b[2];
+        let result_e013  = a[0] * b[12] - a[1] * b[10] - a[2] * b[7] + a[3] * b[15] + a[4] * b[5] + a[5] * b[4] - a[6] * b[14] - a[7] * b[2] - a[8] * b[11] + a[9] * b[13] - a[10] * b[1] + a[11] * b[8] + a[12] * b[0] - a[13] * b[9] + a[14] * b[6] - a[15] * b[3];
+        let result_e021  = a[0] * b[13] - a[1] * b[8] + a[2] * b[6] - a[3] * b[5] + a[4] * b[15] - a[5] * b[3] + a[6] * b[2] - a[7] * b[14] - a[8] * b[1] - a[9] * b[12] + a[10] * b[11] - a[11] * b[10] + a[12] * b[9] + a[13] * b[0] + a[14] * b[7] - a[15] * b[4];
+        let result_e123  = a[0] * b[14] + a[2] * b[9] + a[3] * b[10] + a[4] * b[8] + a[8] * b[4] + a[9] * b[2] + a[10] * b[3] + a[14] * b[0];
+        let result_e0123 = a[0] * b[15] + a[1] * b[14] + a[2] * b[11] + a[3] * b[12] + a[4] * b[13] + a[5] * b[9] + a[6] * b[10] + a[7] * b[8] + a[8] * b[7] + a[9] * b[5] + a[10] * b[6] - a[11] * b[2] - a[12] * b[3] - a[13] * b[4] - a[14] * b[1] + a[15] * b[0];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::Mul<ProjectiveMultivector3<S>> for &ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: ProjectiveMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[0] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[14] * b[14];
+        let result_e0    = a[0] * b[1] + a[1] * b[0] - a[2] * b[5] - a[3] * b[6] - a[4] * b[7] + a[5] * b[2] + a[6] * b[3] + a[7] * b[4] + a[8] * b[13] + a[9] * b[11] + a[10] * b[12] + a[11] * b[9] + a[12] * b[10] + a[13] * b[8] + a[14] * b[15] - a[15] * b[14];
+        let result_e1    = a[0] * b[2] + a[2] * b[0] - a[3] * b[8] + a[4] * b[10] + a[8] * b[3] - a[9] * b[14] - a[10] * b[4] - a[14] * b[9];
+        let result_e2    = a[0] * b[3] + a[2] * b[8] + a[3] * b[0] - a[4] * b[9] - a[8] * b[2] + a[9] * b[4] - a[10] * b[14] - a[14] * b[10];
+        let result_e3    = a[0] * b[4] - a[2] * b[10] + a[3] * b[9] + a[4] * b[0] - a[8] * b[14] - a[9] * b[3] + a[10] * b[2] - a[14] * b[8];
+        let result_e01   = a[0] * b[5] + a[1] * b[2] - a[2] * b[1] - a[3] * b[13] + a[4] * b[12] + a[5] * b[0] - a[6] * b[8] + a[7] * b[10] + a[8] * b[6] - a[9] * b[15] - a[10] * b[7] + a[11] * b[14] + a[12] * b[4] - a[13] * b[3] - a[14] * b[11] - a[15] * b[9];
+        let result_e02   = a[0] * b[6] + a[1] * b[3] + a[2] * b[13] - a[3] * b[1] - a[4] * b[11] + a[5] * b[8] + a[6] * b[0] - a[7] * b[9] - a[8] * b[5] + a[9] * b[7] - a[10] * b[15] - a[11] * b[4] + a[12] * b[14] + a[13] * b[2] - a[14] * b[12] - a[15] * b[10];
+        let result_e03   = a[0] * b[7] + a[1] * b[4] - a[2] * b[12] + a[3] * b[11] - a[4] * b[1] - a[5] * b[10] + a[6] * b[9] + a[7] * b[0] - a[8] * b[15] - a[9] * b[6] + a[10] * b[5] + a[11] * b[3] - a[12] * b[2] + a[13] * b[14] - a[14] * b[13] - a[15] * b[8];
+        let result_e12   = a[0] * b[8] + a[2] * b[3] - a[3] * b[2] + a[4] * b[14] + a[8] * b[0] - a[9] * b[10] + a[10] * b[9] + a[14] * b[4];
+        let result_e23   = a[0] * b[9] + a[2] * b[14] + a[3] * b[4] - a[4] * b[3] + a[8] * b[10] + a[9] * b[0] - a[10] * b[8] + a[14] * b[2];
+        let result_e31   = a[0] * b[10] - a[2] * b[4] + a[3] * b[14] + a[4] * b[2] - a[8] * b[9] + a[9] * b[8] + a[10] * b[0] + a[14] * b[3];
+        let result_e032  = a[0] * b[11] - a[1] * b[9] + a[2] * b[15] + a[3] * b[7] - a[4] * b[6] - a[5] * b[14] - a[6] * b[4] + a[7] * b[3] + a[8] * b[12] - a[9] * b[1] - a[10] * b[13] + a[11] * b[0] - a[12] * b[8] + a[13] * b[10] + a[14] * b[5] - a[15] * b[2];
+        let result_e013  = a[0] * b[12] - a[1] * b[10] - a[2] * b[7] + a[3] * b[15] + a[4] * b[5] + a[5] * b[4] - a[6] * b[14] - a[7] * b[2] - a[8] * b[11] + a[9] * b[13] - a[10] * b[1] + a[11] * b[8] + a[12] * b[0] - a[13] * b[9] + a[14] * b[6] - a[15] * b[3];
+        let result_e021  = a[0] * b[13] - a[1] * b[8] + a[2] * b[6] - a[3] * b[5] + a[4] * b[15] - a[5] * b[3] + a[6] * b[2] - a[7] * b[14] - a[8] * b[1] - a[9] * b[12] + a[10] * b[11] - a[11] * b[10] + a[12] * b[9] + a[13] * b[0] + a[14] * b[7] - a[15] * b[4];
+        let result_e123  = a[0] * b[14] + a[2] * b[9] + a[3] * b[10] + a[4] * b[8] + a[8] * b[4] + a[9] * b[2] + a[10] * b[3] + a[14] * b[0];
+        let result_e0123 = a[0] * b[15] + a[1] * b[14] + a[2] * b[11] + a[3] * b[12] + a[4] * b[13] + a[5] * b[9] + a[6] * b[10] + a[7] * b[8] + a[8] * b[7] + a[9] * b[5] + a[10] * b[6] - a[11] * b[2] - a[12] * b[3] - a[13] * b[4] - a[14] * b[1] + a[15] * b[0];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::Mul<&ProjectiveMultivector3<S>> for &ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: &ProjectiveMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[0] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[14] * b[14];
+        let result_e0    = a[0] * b[1] + a[1] * b[0] - a[2] * b[5] - a[3] * b[6] - a[4] * b[7] + a[5] * b[2] + a[6] * b[3] + a[7] * b[4] + a[8] * b[13] + a[9] * b[11] + a[10] * b[12] + a[11] * b[9] + a[12] * b[10] + a[13] * b[8] + a[14] * b[15] - a[15] * b[14];
+        let result_e1    = a[0] * b[2] + a[2] * b[0] - a[3] * b[8] + a[4] * b[10] + a[8] * b[3] - a[9] * b[14] - a[10] * b[4] - a[14] * b[9];
+        let result_e2    = a[0] * b[3] + a[2] * b[8] + a[3] * b[0] - a[4] * b[9] - a[8] * b[2] + a[9] * b[4] - a[10] * b[14] - a[14] * b[10];
+        let result_e3    = a[0] * b[4] - a[2] * b[10] + a[3] * b[9] + a[4] * b[0] - a[8] * b[14] - a[9] * b[3] + a[10] * b[2] - a[14] * b[8];
+        let result_e01   = a[0] * b[5] + a[1] * b[2] - a[2] * b[1] - a[3] * b[13] + a[4] * b[12] + a[5] * b[0] - a[6] * b[8] + a[7] * b[10] + a[8] * b[6] - a[9] * b[15] - a[10] * b[7] + a[11] * b[14] + a[12] * b[4] - a[13] * b[3] - a[14] * b[11] - a[15] * b[9];
+        let result_e02   = a[0] * b[6] + a[1] * b[3] + a[2] * b[13] - a[3] * b[1] - a[4] * b[11] + a[5] * b[8] + a[6] * b[0] - a[7] * b[9] - a[8] * b[5] + a[9] * b[7] - a[10] * b[15] - a[11] * b[4] + a[12] * b[14] + a[13] * b[2] - a[14] * b[12] - a[15] * b[10];
+        let result_e03   = a[0] * b[7] + a[1] * b[4] - a[2] * b[12] + a[3] * b[11] - a[4] * b[1] - a[5] * b[10] + a[6] * b[9] + a[7] * b[0] - a[8] * b[15] - a[9] * b[6] + a[10] * b[5] + a[11] * b[3] - a[12] * b[2] + a[13] * b[14] - a[14] * b[13] - a[15] * b[8];
+        let result_e12   = a[0] * b[8] + a[2] * b[3] - a[3] * b[2] + a[4] * b[14] + a[8] * b[0] - a[9] * b[10] + a[10] * b[9] + a[14] * b[4];
+        let result_e23   = a[0] * b[9] + a[2] * b[14] + a[3] * b[4] - a[4] * b[3] + a[8] * b[10] + a[9] * b[0] - a[10] * b[8] + a[14] * b[2];
+        let result_e31   = a[0] * b[10] - a[2] * b[4] + a[3] * b[14] + a[4] * b[2] - a[8] * b[9] + a[9] * b[8] + a[10] * b[0] + a[14] * b[3];
+        let result_e032  = a[0] * b[11] - a[1] * b[9] + a[2] * b[15] + a[3] * b[7] - a[4] * b[6] - a[5] * b[14] - a[6] * b[4] + a[7] * b[3] + a[8] * b[12] - a[9] * b[1] - a[10] * b[13] + a[11] * b[0] - a[12] * b[8] + a[13] * b[10] + a[14] * b[5] - a[15] * b[2];
+        let result_e013  = a[0] * b[12] - a[1] * b[10] - a[2] * b[7] + a[3] * b[15] + a[4] * b[5] + a[5] * b[4] - a[6] * b[14] - a[7] * b[2] - a[8] * b[11] + a[9] * b[13] - a[10] * b[1] + a[11] * b[8] + a[12] * b[0] - a[13] * b[9] + a[14] * b[6] - a[15] * b[3];
+        let result_e021  = a[0] * b[13] - a[1] * b[8] + a[2] * b[6] - a[3] * b[5] + a[4] * b[15] - a[5] * b[3] + a[6] * b[2] - a[7] * b[14] - a[8] * b[1] - a[9] * b[12] + a[10] * b[11] - a[11] * b[10] + a[12] * b[9] + a[13] * b[0] + a[14] * b[7] - a[15] * b[4];
+        let result_e123  = a[0] * b[14] + a[2] * b[9] + a[3] * b[10] + a[4] * b[8] + a[8] * b[4] + a[9] * b[2] + a[10] * b[3] + a[14] * b[0];
+        let result_e0123 = a[0] * b[15] + a[1] * b[14] + a[2] * b[11] + a[3] * b[12] + a[4] * b[13] + a[5] * b[9] + a[6] * b[10] + a[7] * b[8] + a[8] * b[7] + a[9] * b[5] + a[10] * b[6] - a[11] * b[2] - a[12] * b[3] - a[13] * b[4] - a[14] * b[1] + a[15] * b[0];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::Mul<S> for ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1     = a[0] * other;
+        let result_e0    = a[1] * other;
+        let result_e1    = a[2] * other;
+        let result_e2    = a[3] * other;
+        let result_e3    = a[4] * other;
+        let result_e01   = a[5] * other;
+        let result_e02   = a[6] * other;
+        let result_e03   = a[7] * other;
+        let result_e12   = a[8] * other;
+        let result_e23   = a[9] * other;
+        let result_e31   = a[10] * other;
+        let result_e032  = a[11] * other;
+        let result_e013  = a[12] * other;
+        let result_e021  = a[13] * other;
+        let result_e123  = a[14] * other;
+        let result_e0123 = a[15] * other;
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::Mul<S> for &ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1     = a[0] * other;
+        let result_e0    = a[1] * other;
+        let result_e1    = a[2] * other;
+        let result_e2    = a[3] * other;
+        let result_e3    = a[4] * other;
+        let result_e01   = a[5] * other;
+        let result_e02   = a[6] * other;
+        let result_e03   = a[7] * other;
+        let result_e12   = a[8] * other;
+        let result_e23   = a[9] * other;
+        let result_e31   = a[10] * other;
+        let result_e032  = a[11] * other;
+        let result_e013  = a[12] * other;
+        let result_e021  = a[13] * other;
+        let result_e123  = a[14] * other;
+        let result_e0123 = a[15] * other;
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::BitXor<ProjectiveMultivector3<S>> for ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: ProjectiveMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[0];
+        let result_e0    = a[0] * b[1] + a[1] * b[0];
+        let result_e1    = a[0] * b[2] + a[2] * b[0];
+        let result_e2    = a[0] * b[3] + a[3] * b[0];
+        let result_e3    = a[0] * b[4] + a[4] * b[0];
+        let result_e01   = a[0] * b[5] + a[1] * b[2] - a[2] * b[1] + a[5] * b[0];
+        let result_e02   = a[0] * b[6] + a[1] * b[3] - a[3] * b[1] + a[6] * b[0];
+        let result_e03   = a[0] * b[7] + a[1] * b[4] - a[4] * b[1] + a[7] * b[0];
+        let result_e12   = a[0] * b[8] + a[2] * b[3] - a[3] * b[2] + a[8] * b[0];
+        let result_e23   = a[0] * b[9] + a[3] * b[4] - a[4] * b[3] + a[9] * b[0];
+        let result_e31   = a[0] * b[10] - a[2] * b[4] + a[4] * b[2] + a[10] * b[0];
+        let result_e032  = a[0] * b[11] - a[1] * b[9] + a[3] * b[7] - a[4] * b[6] - a[6] * b[4] + a[7] * b[3] - a[9] * b[1] + a[11] * b[0];
+        let result_e013  = a[0] * b[12] - a[1] * b[10] - a[2] * b[7] + a[4] * b[5] + a[5] * b[4] - a[7] * b[2] - a[10] * b[1] + a[12] * b[0];
+        let result_e021  = a[0] * b[13] - a[1] * b[8] + a[2] * b[6] - a[3] * b[5] - a[5] * b[3] + a[6] * b[2] - a[8] * b[1] + a[13] * b[0];
+        let result_e123  = a[0] * b[14] + a[2] * b[9] + a[3] * b[10] + a[4] * b[8] + a[8] * b[4] + a[9] * b[2] + a[10] * b[3] + a[14] * b[0];
+        let result_e0123 = a[0] * b[15] + a[1] * b[14] + a[2] * b[11] + a[3] * b[12] + a[4] * b[13] + a[5] * b[9] + a[6] * b[10] + a[7] * b[8] + a[8] * b[7] + a[9] * b[5] + a[10] * b[6] - a[11] * b[2] - a[12] * b[3] - a[13] * b[4] - a[14] * b[1] + a[15] * b[0];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::BitXor<&ProjectiveMultivector3<S>> for ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: &ProjectiveMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[0];
+        let result_e0    = a[0] * b[1] + a[1] * b[0];
+        let result_e1    = a[0] * b[2] + a[2] * b[0];
+        let result_e2    = a[0] * b[3] + a[3] * b[0];
+        let result_e3    = a[0] * b[4] + a[4] * b[0];
+        let result_e01   = a[0] * b[5] + a[1] * b[2] - a[2] * b[1] + a[5] * b[0];
+        let result_e02   = a[0] * b[6] + a[1] * b[3] - a[3] * b[1] + a[6] * b[0];
+        let result_e03   = a[0] * b[7] + a[1] * b[4] - a[4] * b[1] + a[7] * b[0];
+        let result_e12   = a[0] * b[8] + a[2] * b[3] - a[3] * b[2] + a[8] * b[0];
+        let result_e23   = a[0] * b[9] + a[3] * b[4] - a[4] * b[3] + a[9] * b[0];
+        let result_e31   = a[0] * b[10] - a[2] * b[4] + a[4] * b[2] + a[10] * b[0];
+        let result_e032  = a[0] * b[11] - a[1] * b[9] + a[3] * b[7] - a[4] * b[6] - a[6] * b[4] + a[7] * b[3] - a[9] * b[1] + a[11] * b[0];
+        let result_e013  = a[0] * b[12] - a[1] * b[10] - a[2] * b[7] + a[4] * b[5] + a[5] * b[4] - a[7] * b[2] - a[10] * b[1] + a[12] * b[0];
+        let result_e021  = a[0] * b[13] - a[1] * b[8] + a[2] * b[6] - a[3] * b[5] - a[5] * b[3] + a[6] * b[2] - a[8] * b[1] + a[13] * b[0];
+        let result_e123  = a[0] * b[14] + a[2] * b[9] + a[3] * b[10] + a[4] * b[8] + a[8] * b[4] + a[9] * b[2] + a[10] * b[3] + a[14] * b[0];
+        let result_e0123 = a[0] * b[15] + a[1] * b[14] + a[2] * b[11] + a[3] * b[12] + a[4] * b[13] + a[5] * b[9] + a[6] * b[10] + a[7] * b[8] + a[8] * b[7] + a[9] * b[5] + a[10] * b[6] - a[11] * b[2] - a[12] * b[3] - a[13] * b[4] - a[14] * b[1] + a[15] * b[0];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::BitXor<ProjectiveMultivector3<S>> for &ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: ProjectiveMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[0];
+        let result_e0    = a[0] * b[1] + a[1] * b[0];
+        let result_e1    = a[0] * b[2] + a[2] * b[0];
+        let result_e2    = a[0] * b[3] + a[3] * b[0];
+        let result_e3    = a[0] * b[4] + a[4] * b[0];
+        let result_e01   = a[0] * b[5] + a[1] * b[2] - a[2] * b[1] + a[5] * b[0];
+        let result_e02   = a[0] * b[6] + a[1] * b[3] - a[3] * b[1] + a[6] * b[0];
+        let result_e03   = a[0] * b[7] + a[1] * b[4] - a[4] * b[1] + a[7] * b[0];
+        let result_e12   = a[0] * b[8] + a[2] * b[3] - a[3] * b[2] + a[8] * b[0];
+        let result_e23   = a[0] * b[9] + a[3] * b[4] - a[4] * b[3] + a[9] * b[0];
+        let result_e31   = a[0] * b[10] - a[2] * b[4] + a[4] * b[2] + a[10] * b[0];
+        let result_e032  = a[0] * b[11] - a[1] * b[9] + a[3] * b[7] - a[4] * b[6] - a[6] * b[4] + a[7] * b[3] - a[9] * b[1] + a[11] * b[0];
+        let result_e013  = a[0] * b[12] - a[1] * b[10] - a[2] * b[7] + a[4] * b[5] + a[5] * b[4] - a[7] * b[2] - a[10] * b[1] + a[12] * b[0];
+        let result_e021  = a[0] * b[13] - a[1] * b[8] + a[2] * b[6] - a[3] * b[5] - a[5] * b[3] + a[6] * b[2] - a[8] * b[1] + a[13] * b[0];
+        let result_e123  = a[0] * b[14] + a[2] * b[9] + a[3] * b[10] + a[4] * b[8] + a[8] * b[4] + a[9] * b[2] + a[10] * b[3] + a[14] * b[0];
+        let result_e0123 = a[0] * b[15] + a[1] * b[14] + a[2] * b[11] + a[3] * b[12] + a[4] * b[13] + a[5] * b[9] + a[6] * b[10] + a[7] * b[8] + a[8] * b[7] + a[9] * b[5] + a[10] * b[6] - a[11] * b[2] - a[12] * b[3] - a[13] * b[4] - a[14] * b[1] + a[15] * b[0];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::BitXor<&ProjectiveMultivector3<S>> for &ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: &ProjectiveMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[0];
+        let result_e0    = a[0] * b[1] + a[1] * b[0];
+        let result_e1    = a[0] * b[2] + a[2] * b[0];
+        let result_e2    = a[0] * b[3] + a[3] * b[0];
+        let result_e3    = a[0] * b[4] + a[4] * b[0];
+        let result_e01   = a[0] * b[5] + a[1] * b[2] - a[2] * b[1] + a[5] * b[0];
+        let result_e02   = a[0] * b[6] + a[1] * b[3] - a[3] * b[1] + a[6] * b[0];
+        let result_e03   = a[0] * b[7] + a[1] * b[4] - a[4] * b[1] + a[7] * b[0];
+        let result_e12   = a[0] * b[8] + a[2] * b[3] - a[3] * b[2] + a[8] * b[0];
+        let result_e23   = a[0] * b[9] + a[3] * b[4] - a[4] * b[3] + a[9] * b[0];
+        let result_e31   = a[0] * b[10] - a[2] * b[4] + a[4] * b[2] + a[10] * b[0];
+        let result_e032  = a[0] * b[11] - a[1] * b[9] + a[3] * b[7] - a[4] * b[6] - a[6] * b[4] + a[7] * b[3] - a[9] * b[1] + a[11] * b[0];
+        let result_e013  = a[0] * b[12] - a[1] * b[10] - a[2] * b[7] + a[4] * b[5] + a[5] * b[4] - a[7] * b[2] - a[10] * b[1] + a[12] * b[0];
+        let result_e021  = a[0] * b[13] - a[1] * b[8] + a[2] * b[6] - a[3] * b[5] - a[5] * b[3] + a[6] * b[2] - a[8] * b[1] + a[13] * b[0];
+        let result_e123  = a[0] * b[14] + a[2] * b[9] + a[3] * b[10] + a[4] * b[8] + a[8] * b[4] + a[9] * b[2] + a[10] * b[3] + a[14] * b[0];
+        let result_e0123 = a[0] * b[15] + a[1] * b[14] + a[2] * b[11] + a[3] * b[12] + a[4] * b[13] + a[5] * b[9] + a[6] * b[10] + a[7] * b[8] + a[8] * b[7] + a[9] * b[5] + a[10] * b[6] - a[11] * b[2] - a[12] * b[3] - a[13] * b[4] - a[14] * b[1] + a[15] * b[0];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::BitXor<S> for ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1     = a[0] * other;
+        let result_e0    = a[1] * other;
+        let result_e1    = a[2] * other;
+        let result_e2    = a[3] * other;
+        let result_e3    = a[4] * other;
+        let result_e01   = a[5] * other;
+        let result_e02   = a[6] * other;
+        let result_e03   = a[7] * other;
+        let result_e12   = a[8] * other;
+        let result_e23   = a[9] * other;
+        let result_e31   = a[10] * other;
+        let result_e032  = a[11] * other;
+        let result_e013  = a[12] * other;
+        let result_e021  = a[13] * other;
+        let result_e123  = a[14] * other;
+        let result_e0123 = a[15] * other;
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::BitXor<S> for &ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1     = a[0] * other;
+        let result_e0    = a[1] * other;
+        let result_e1    = a[2] * other;
+        let result_e2    = a[3] * other;
+        let result_e3    = a[4] * other;
+        let result_e01   = a[5] * other;
+        let result_e02   = a[6] * other;
+        let result_e03   = a[7] * other;
+        let result_e12   = a[8] * other;
+        let result_e23   = a[9] * other;
+        let result_e31   = a[10] * other;
+        let result_e032  = a[11] * other;
+        let result_e013  = a[12] * other;
+        let result_e021  = a[13] * other;
+        let result_e123  = a[14] * other;
+        let result_e0123 = a[15] * other;
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::BitAnd<ProjectiveMultivector3<S>> for ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitand(self, other: ProjectiveMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[15] + a[1] * b[14] + a[2] * b[11] + a[3] * b[12] + a[4] * b[13] + a[5] * b[9] + a[6] * b[10] + a[7] * b[8] + a[8] * b[7] + a[9] * b[5] + a[10] * b[6] - a[11] * b[2] - a[12] * b[3] - a[13] * b[4] - a[14] * b[1] + a[15] * b[0];
+        let result_e0    = a[1] * b[15] - a[5] * b[11] - a[6] * b[12] - a[7] * b[13] - a[11] * b[5] - a[12] * b[6] - a[13] * b[7] + a[15] * b[1];
+        let result_e1    = a[2] * b[15] + a[5] * b[14] - a[8] * b[12] + a[10] * b[13] - a[12] * b[8] + a[13] * b[10] + a[14] * b[5] + a[15] * b[2];
+        let result_e2    = a[3] * b[15] + a[6] * b[14] + a[8] * b[11] - a[9] * b[13] + a[11] * b[8] - a[13] * b[9] + a[14] * b[6] + a[15] * b[3];
+        let result_e3    = a[4] * b[15] + a[7] * b[14] + a[9] * b[12] - a[10] * b[11] - a[11] * b[10] + a[12] * b[9] + a[14] * b[7] + a[15] * b[4];
+        let result_e01   = a[5] * b[15] + a[12] * b[13] - a[13] * b[12] + a[15] * b[5];
+        let result_e02   = a[6] * b[15] - a[11] * b[13] + a[13] * b[11] + a[15] * b[6];
+        let result_e03   = a[7] * b[15] + a[11] * b[12] - a[12] * b[11] + a[15] * b[7];
+        let result_e12   = a[8] * b[15] - a[13] * b[14] + a[14] * b[13] + a[15] * b[8];
+        let result_e23   = a[9] * b[15] - a[11] * b[14] + a[14] * b[11] + a[15] * b[9];
+        let result_e31   = a[10] * b[15] - a[12] * b[14] + a[14] * b[12] + a[15] * b[10];
+        let result_e032  = a[11] * b[15] + a[15] * b[11];
+        let result_e013  = a[12] * b[15] + a[15] * b[12];
+        let result_e021  = a[13] * b[15] + a[15] * b[13];
+        let result_e123  = a[14] * b[15] + a[15] * b[14];
+        let result_e0123 = a[15] * b[15];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::BitAnd<&ProjectiveMultivector3<S>> for ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitand(self, other: &ProjectiveMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[15] + a[1] * b[14] + a[2] * b[11] + a[3] * b[12] + a[4] * b[13] + a[5] * b[9] + a[6] * b[10] + a[7] * b[8] + a[8] * b[7] + a[9] * b[5] + a[10] * b[6] - a[11] * b[2] - a[12] * b[3] - a[13] * b[4] - a[14] * b[1] + a[15] * b[0];
+        let result_e0    = a[1] * b[15] - a[5] * b[11] - a[6] * b[12] - a[7] * b[13] - a[11] * b[5] - a[12] * b[6] - a[13] * b[7] + a[15] * b[1];
+        let result_e1    = a[2] * b[15] + a[5] * b[14] - a[8] * b[12] + a[10] * b[13] - a[12] * b[8] + a[13] * b[10] + a[14] * b[5] + a[15] * b[2];
+        let result_e2    = a[3] * b[15] + a[6] * b[14] + a[8] * b[11] - a[9] * b[13] + a[11] * b[8] - a[13] * b[9] + a[14] * b[6] + a[15] * b[3];
+        let result_e3    = a[4] * b[15] + a[7] * b[14] + a[9] * b[12] - a[10] * b[11] - a[11] * b[10] + a[12] * b[9] + a[14] * b[7] + a[15] * b[4];
+        let result_e01   = a[5] * b[15] + a[12] * b[13] - a[13] * b[12] + a[15] * b[5];
+        let result_e02   = a[6] * b[15] - a[11] * b[13] + a[13] * b[11] + a[15] * b[6];
+        let result_e03   = a[7] * b[15] + a[11] * b[12] - a[12] * b[11] + a[15] * b[7];
+        let result_e12   = a[8] * b[15] - a[13] * b[14] + a[14] * b[13] + a[15] * b[8];
+        let result_e23   = a[9] * b[15] - a[11] * b[14] + a[14] * b[11] + a[15] * b[9];
+        let result_e31   = a[10] * b[15] - a[12] * b[14] + a[14] * b[12] + a[15] * b[10];
+        let result_e032  = a[11] * b[15] + a[15] * b[11];
+        let result_e013  = a[12] * b[15] + a[15] * b[12];
+        let result_e021  = a[13] * b[15] + a[15] * b[13];
+        let result_e123  = a[14] * b[15] + a[15] * b[14];
+        let result_e0123 = a[15] * b[15];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::BitAnd<ProjectiveMultivector3<S>> for &ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitand(self, other: ProjectiveMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[15] + a[1] * b[14] + a[2] * b[11] + a[3] * b[12] + a[4] * b[13] + a[5] * b[9] + a[6] * b[10] + a[7] * b[8] + a[8] * b[7] + a[9] * b[5] + a[10] * b[6] - a[11] * b[2] - a[12] * b[3] - a[13] * b[4] - a[14] * b[1] + a[15] * b[0];
+        let result_e0    = a[1] * b[15] - a[5] * b[11] - a[6] * b[12] - a[7] * b[13] - a[11] * b[5] - a[12] * b[6] - a[13] * b[7] + a[15] * b[1];
+        let result_e1    = a[2] * b[15] + a[5] * b[14] - a[8] * b[12] + a[10] * b[13] - a[12] * b[8] + a[13] * b[10] + a[14] * b[5] + a[15] * b[2];
+        let result_e2    = a[3] * b[15] + a[6] * b[14] + a[8] * b[11] - a[9] * b[13] + a[11] * b[8] - a[13] * b[9] + a[14] * b[6] + a[15] * b[3];
+        let result_e3    = a[4] * b[15] + a[7] * b[14] + a[9] * b[12] - a[10] * b[11] - a[11] * b[10] + a[12] * b[9] + a[14] * b[7] + a[15] * b[4];
+        let result_e01   = a[5] * b[15] + a[12] * b[13] - a[13] * b[12] + a[15] * b[5];
+        let result_e02   = a[6] * b[15] - a[11] * b[13] + a[13] * b[11] + a[15] * b[6];
+        let result_e03   = a[7] * b[15] + a[11] * b[12] - a[12] * b[11] + a[15] * b[7];
+        let result_e12   = a[8] * b[15] - a[13] * b[14] + a[14] * b[13] + a[15] * b[8];
+        let result_e23   = a[9] * b[15] - a[11] * b[14] + a[14] * b[11] + a[15] * b[9];
+        let result_e31   = a[10] * b[15] - a[12] * b[14] + a[14] * b[12] + a[15] * b[10];
+        let result_e032  = a[11] * b[15] + a[15] * b[11];
+        let result_e013  = a[12] * b[15] + a[15] * b[12];
+        let result_e021  = a[13] * b[15] + a[15] * b[13];
+        let result_e123  = a[14] * b[15] + a[15] * b[14];
+        let result_e0123 = a[15] * b[15];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::BitAnd<&ProjectiveMultivector3<S>> for &ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitand(self, other: &ProjectiveMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[15] + a[1] * b[14] + a[2] * b[11] + a[3] * b[12] + a[4] * b[13] + a[5] * b[9] + a[6] * b[10] + a[7] * b[8] + a[8] * b[7] + a[9] * b[5] + a[10] * b[6] - a[11] * b[2] - a[12] * b[3] - a[13] * b[4] - a[14] * b[1] + a[15] * b[0];
+        let result_e0    = a[1] * b[15] - a[5] * b[11] - a[6] * b[12] - a[7] * b[13] - a[11] * b[5] - a[12] * b[6] - a[13] * b[7] + a[15] * b[1];
+        let result_e1    = a[2] * b[15] + a[5] * b[14] - a[8] * b[12] + a[10] * b[13] - a[12] * b[8] + a[13] * b[10] + a[14] * b[5] + a[15] * b[2];
+        let result_e2    = a[3] * b[15] + a[6] * b[14] + a[8] * b[11] - a[9] * b[13] + a[11] * b[8] - a[13] * b[9] + a[14] * b[6] + a[15] * b[3];
+        let result_e3    = a[4] * b[15] + a[7] * b[14] + a[9] * b[12] - a[10] * b[11] - a[11] * b[10] + a[12] * b[9] + a[14] * b[7] + a[15] * b[4];
+        let result_e01   = a[5] * b[15] + a[12] * b[13] - a[13] * b[12] + a[15] * b[5];
+        let result_e02   = a[6] * b[15] - a[11] * b[13] + a[13] * b[11] + a[15] * b[6];
+        let result_e03   = a[7] * b[15] + a[11] * b[12] - a[12] * b[11] + a[15] * b[7];
+        let result_e12   = a[8] * b[15] - a[13] * b[14] + a[14] * b[13] + a[15] * b[8];
+        let result_e23   = a[9] * b[15] - a[11] * b[14] + a[14] * b[11] + a[15] * b[9];
+        let result_e31   = a[10] * b[15] - a[12] * b[14] + a[14] * b[12] + a[15] * b[10];
+        let result_e032  = a[11] * b[15] + a[15] * b[11];
+        let result_e013  = a[12] * b[15] + a[15] * b[12];
+        let result_e021  = a[13] * b[15] + a[15] * b[13];
+        let result_e123  = a[14] * b[15] + a[15] * b[14];
+        let result_e0123 = a[15] * b[15];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::BitOr<ProjectiveMultivector3<S>> for ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitor(self, other: ProjectiveMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1 = a[0] * b[0] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] + a[8] * b[8] + a[9] * b[9] + a[10] * b[10] + a[14] * b[14];
+
+        ProjectiveMultivector3::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::BitOr<&ProjectiveMultivector3<S>> for ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitor(self, other: &ProjectiveMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1 = a[0] * b[0] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] + a[8] * b[8] + a[9] * b[9] + a[10] * b[10] + a[14] * b[14];
+
+        ProjectiveMultivector3::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::BitOr<ProjectiveMultivector3<S>> for &ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitor(self, other: ProjectiveMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1 = a[0] * b[0] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] + a[8] * b[8] + a[9] * b[9] + a[10] * b[10] + a[14] * b[14];
+
+        ProjectiveMultivector3::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::BitOr<&ProjectiveMultivector3<S>> for &ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitor(self, other: &ProjectiveMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1 = a[0] * b[0] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] + a[8] * b[8] + a[9] * b[9] + a[10] * b[10] + a[14] * b[14];
+
+        ProjectiveMultivector3::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::BitOr<S> for ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn bitor(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1 = a[0] * other;
+
+        ProjectiveMultivector3::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::BitOr<S> for &ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn bitor(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1 = a[0] * other;
+
+        ProjectiveMultivector3::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::Add<ProjectiveMultivector3<S>> for ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: ProjectiveMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] + b[0];
+        let result_e0    = a[1] + b[1];
+        let result_e1    = a[2] + b[2];
+        let result_e2    = a[3] + b[3];
+        let result_e3    = a[4] + b[4];
+        let result_e01   = a[5] + b[5];
+        let result_e02   = a[6] + b[6];
+        let result_e03   = a[7] + b[7];
+        let result_e12   = a[8] + b[8];
+        let result_e23   = a[9] + b[9];
+        let result_e31   = a[10] + b[10];
+        let result_e032  = a[11] + b[11];
+        let result_e013  = a[12] + b[12];
+        let result_e021  = a[13] + b[13];
+        let result_e123  = a[14] + b[14];
+        let result_e0123 = a[15] + b[15];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::Add<&ProjectiveMultivector3<S>> for ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: &ProjectiveMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] + b[0];
+        let result_e0    = a[1] + b[1];
+        let result_e1    = a[2] + b[2];
+        let result_e2    = a[3] + b[3];
+        let result_e3    = a[4] + b[4];
+        let result_e01   = a[5] + b[5];
+        let result_e02   = a[6] + b[6];
+        let result_e03   = a[7] + b[7];
+        let result_e12   = a[8] + b[8];
+        let result_e23   = a[9] + b[9];
+        let result_e31   = a[10] + b[10];
+        let result_e032  = a[11] + b[11];
+        let result_e013  = a[12] + b[12];
+        let result_e021  = a[13] + b[13];
+        let result_e123  = a[14] + b[14];
+        let result_e0123 = a[15] + b[15];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::Add<ProjectiveMultivector3<S>> for &ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: ProjectiveMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] + b[0];
+        let result_e0    = a[1] + b[1];
+        let result_e1    = a[2] + b[2];
+        let result_e2    = a[3] + b[3];
+        let result_e3    = a[4] + b[4];
+        let result_e01   = a[5] + b[5];
+        let result_e02   = a[6] + b[6];
+        let result_e03   = a[7] + b[7];
+        let result_e12   = a[8] + b[8];
+        let result_e23   = a[9] + b[9];
+        let result_e31   = a[10] + b[10];
+        let result_e032  = a[11] + b[11];
+        let result_e013  = a[12] + b[12];
+        let result_e021  = a[13] + b[13];
+        let result_e123  = a[14] + b[14];
+        let result_e0123 = a[15] + b[15];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::Add<&ProjectiveMultivector3<S>> for &ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: &ProjectiveMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] + b[0];
+        let result_e0    = a[1] + b[1];
+        let result_e1    = a[2] + b[2];
+        let result_e2    = a[3] + b[3];
+        let result_e3    = a[4] + b[4];
+        let result_e01   = a[5] + b[5];
+        let result_e02   = a[6] + b[6];
+        let result_e03   = a[7] + b[7];
+        let result_e12   = a[8] + b[8];
+        let result_e23   = a[9] + b[9];
+        let result_e31   = a[10] + b[10];
+        let result_e032  = a[11] + b[11];
+        let result_e013  = a[12] + b[12];
+        let result_e021  = a[13] + b[13];
+        let result_e123  = a[14] + b[14];
+        let result_e0123 = a[15] + b[15];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::Add<S> for ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1     = a[0] + other;
+        let result_e0    = a[1];
+        let result_e1    = a[2];
+        let result_e2    = a[3];
+        let result_e3    = a[4];
+        let result_e01   = a[5];
+        let result_e02   = a[6];
+        let result_e03   = a[7];
+        let result_e12   = a[8];
+        let result_e23   = a[9];
+        let result_e31   = a[10];
+        let result_e032  = a[11];
+        let result_e013  = a[12];
+        let result_e021  = a[13];
+        let result_e123  = a[14];
+        let result_e0123 = a[15];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::Add<S> for &ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1     = a[0] + other;
+        let result_e0    = a[1];
+        let result_e1    = a[2];
+        let result_e2    = a[3];
+        let result_e3    = a[4];
+        let result_e01   = a[5];
+        let result_e02   = a[6];
+        let result_e03   = a[7];
+        let result_e12   = a[8];
+        let result_e23   = a[9];
+        let result_e31   = a[10];
+        let result_e032  = a[11];
+        let result_e013  = a[12];
+        let result_e021  = a[13];
+        let result_e123  = a[14];
+        let result_e0123 = a[15];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::Sub<ProjectiveMultivector3<S>> for ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: ProjectiveMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] - b[0];
+        let result_e0    = a[1] - b[1];
+        let result_e1    = a[2] - b[2];
+        let result_e2    = a[3] - b[3];
+        let result_e3    = a[4] - b[4];
+        let result_e01   = a[5] - b[5];
+        let result_e02   = a[6] - b[6];
+        let result_e03   = a[7] - b[7];
+        let result_e12   = a[8] - b[8];
+        let result_e23   = a[9] - b[9];
+        let result_e31   = a[10] - b[10];
+        let result_e032  = a[11] - b[11];
+        let result_e013  = a[12] - b[12];
+        let result_e021  = a[13] - b[13];
+        let result_e123  = a[14] - b[14];
+        let result_e0123 = a[15] - b[15];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::Sub<&ProjectiveMultivector3<S>> for ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: &ProjectiveMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] - b[0];
+        let result_e0    = a[1] - b[1];
+        let result_e1    = a[2] - b[2];
+        let result_e2    = a[3] - b[3];
+        let result_e3    = a[4] - b[4];
+        let result_e01   = a[5] - b[5];
+        let result_e02   = a[6] - b[6];
+        let result_e03   = a[7] - b[7];
+        let result_e12   = a[8] - b[8];
+        let result_e23   = a[9] - b[9];
+        let result_e31   = a[10] - b[10];
+        let result_e032  = a[11] - b[11];
+        let result_e013  = a[12] - b[12];
+        let result_e021  = a[13] - b[13];
+        let result_e123  = a[14] - b[14];
+        let result_e0123 = a[15] - b[15];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::Sub<ProjectiveMultivector3<S>> for &ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: ProjectiveMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] - b[0];
+        let result_e0    = a[1] - b[1];
+        let result_e1    = a[2] - b[2];
+        let result_e2    = a[3] - b[3];
+        let result_e3    = a[4] - b[4];
+        let result_e01   = a[5] - b[5];
+        let result_e02   = a[6] - b[6];
+        let result_e03   = a[7] - b[7];
+        let result_e12   = a[8] - b[8];
+        let result_e23   = a[9] - b[9];
+        let result_e31   = a[10] - b[10];
+        let result_e032  = a[11] - b[11];
+        let result_e013  = a[12] - b[12];
+        let result_e021  = a[13] - b[13];
+        let result_e123  = a[14] - b[14];
+        let result_e0123 = a[15] - b[15];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::Sub<&ProjectiveMultivector3<S>> for &ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: &ProjectiveMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] - b[0];
+        let result_e0    = a[1] - b[1];
+        let result_e1    = a[2] - b[2];
+        let result_e2    = a[3] - b[3];
+        let result_e3    = a[4] - b[4];
+        let result_e01   = a[5] - b[5];
+        let result_e02   = a[6] - b[6];
+        let result_e03   = a[7] - b[7];
+        let result_e12   = a[8] - b[8];
+        let result_e23   = a[9] - b[9];
+        let result_e31   = a[10] - b[10];
+        let result_e032  = a[11] - b[11];
+        let result_e013  = a[12] - b[12];
+        let result_e021  = a[13] - b[13];
+        let result_e123  = a[14] - b[14];
+        let result_e0123 = a[15] - b[15];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::Sub<S> for ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1     = a[0] - other;
+        let result_e0    = a[1];
+        let result_e1    = a[2];
+        let result_e2    = a[3];
+        let result_e3    = a[4];
+        let result_e01   = a[5];
+        let result_e02   = a[6];
+        let result_e03   = a[7];
+        let result_e12   = a[8];
+        let result_e23   = a[9];
+        let result_e31   = a[10];
+        let result_e032  = a[11];
+        let result_e013  = a[12];
+        let result_e021  = a[13];
+        let result_e123  = a[14];
+        let result_e0123 = a[15];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::Sub<S> for &ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1     = a[0] - other;
+        let result_e0    = a[1];
+        let result_e1    = a[2];
+        let result_e2    = a[3];
+        let result_e3    = a[4];
+        let result_e01   = a[5];
+        let result_e02   = a[6];
+        let result_e03   = a[7];
+        let result_e12   = a[8];
+        let result_e23   = a[9];
+        let result_e31   = a[10];
+        let result_e032  = a[11];
+        let result_e013  = a[12];
+        let result_e021  = a[13];
+        let result_e123  = a[14];
+        let result_e0123 = a[15];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl_approx_cmp_ops!(ProjectiveMultivector3);
+
+impl<S> ProjectiveMultivector3<S>
+where
+    S: ScalarFloat,
+{
+    /// Calculate the squared magnitude of a multivector.
+    ///
+    /// The magnitude of a projective multivector is the Euclidean magnitude of
+    /// the components that do not contain the null basis vector `e0`, since
+    /// every component containing `e0` squares to zero. This is also known as
+    /// the bulk norm.
+    pub fn magnitude_squared(&self) -> S {
+        let scalar_part = (self * self.reverse())[0];
+
+        scalar_part.abs()
+    }
+
+    /// Calculate the magnitude of a multivector.
+    pub fn magnitude(&self) -> S {
+        self.magnitude_squared().sqrt()
+    }
+
+    /// Normalize a multivector to a unit multivector.
+    pub fn normalize(&self) -> Self {
+        self * (S::one() / self.magnitude())
+    }
+
+    /// Normalize a multivector to a specified magnitude.
+    pub fn normalize_to(&self, magnitude: S) -> Self {
+        self * (magnitude / self.magnitude())
+    }
+}
+
+impl<S> ops::Div<S> for ProjectiveMultivector3<S>
+where
+    S: ScalarFloat,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn div(self, other: S) -> Self::Output {
+        let one_over_other = S::one() / other;
+        let result_1     = self.data[0] * one_over_other;
+        let result_e0    = self.data[1] * one_over_other;
+        let result_e1    = self.data[2] * one_over_other;
+        let result_e2    = self.data[3] * one_over_other;
+        let result_e3    = self.data[4] * one_over_other;
+        let result_e01   = self.data[5] * one_over_other;
+        let result_e02   = self.data[6] * one_over_other;
+        let result_e03   = self.data[7] * one_over_other;
+        let result_e12   = self.data[8] * one_over_other;
+        let result_e23   = self.data[9] * one_over_other;
+        let result_e31   = self.data[10] * one_over_other;
+        let result_e032  = self.data[11] * one_over_other;
+        let result_e013  = self.data[12] * one_over_other;
+        let result_e021  = self.data[13] * one_over_other;
+        let result_e123  = self.data[14] * one_over_other;
+        let result_e0123 = self.data[15] * one_over_other;
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::Div<S> for &ProjectiveMultivector3<S>
+where
+    S: ScalarFloat,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn div(self, other: S) -> Self::Output {
+        let one_over_other = S::one() / other;
+        let result_1     = self.data[0] * one_over_other;
+        let result_e0    = self.data[1] * one_over_other;
+        let result_e1    = self.data[2] * one_over_other;
+        let result_e2    = self.data[3] * one_over_other;
+        let result_e3    = self.data[4] * one_over_other;
+        let result_e01   = self.data[5] * one_over_other;
+        let result_e02   = self.data[6] * one_over_other;
+        let result_e03   = self.data[7] * one_over_other;
+        let result_e12   = self.data[8] * one_over_other;
+        let result_e23   = self.data[9] * one_over_other;
+        let result_e31   = self.data[10] * one_over_other;
+        let result_e032  = self.data[11] * one_over_other;
+        let result_e013  = self.data[12] * one_over_other;
+        let result_e021  = self.data[13] * one_over_other;
+        let result_e123  = self.data[14] * one_over_other;
+        let result_e0123 = self.data[15] * one_over_other;
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::Shl<ProjectiveMultivector3<S>> for ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shl(self, other: ProjectiveMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[0] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[14] * b[14];
+        let result_e0    = a[0] * b[1] - a[2] * b[5] - a[3] * b[6] - a[4] * b[7] + a[8] * b[13] + a[9] * b[11] + a[10] * b[12] + a[14] * b[15];
+        let result_e1    = a[0] * b[2] - a[3] * b[8] + a[4] * b[10] - a[9] * b[14];
+        let result_e2    = a[0] * b[3] + a[2] * b[8] - a[4] * b[9] - a[10] * b[14];
+        let result_e3    = a[0] * b[4] - a[2] * b[10] + a[3] * b[9] - a[8] * b[14];
+        let result_e01   = a[0] * b[5] - a[3] * b[13] + a[4] * b[12] - a[9] * b[15];
+        let result_e02   = a[0] * b[6] + a[2] * b[13] - a[4] * b[11] - a[10] * b[15];
+        let result_e03   = a[0] * b[7] - a[2] * b[12] + a[3] * b[11] - a[8] * b[15];
+        let result_e12   = a[0] * b[8] + a[4] * b[14];
+        let result_e23   = a[0] * b[9] + a[2] * b[14];
+        let result_e31   = a[0] * b[10] + a[3] * b[14];
+        let result_e032  = a[0] * b[11] + a[2] * b[15];
+        let result_e013  = a[0] * b[12] + a[3] * b[15];
+        let result_e021  = a[0] * b[13] + a[4] * b[15];
+        let result_e123  = a[0] * b[14];
+        let result_e0123 = a[0] * b[15];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::Shl<&ProjectiveMultivector3<S>> for ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shl(self, other: &ProjectiveMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[0] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[14] * b[14];
+        let result_e0    = a[0] * b[1] - a[2] * b[5] - a[3] * b[6] - a[4] * b[7] + a[8] * b[13] + a[9] * b[11] + a[10] * b[12] + a[14] * b[15];
+        let result_e1    = a[0] * b[2] - a[3] * b[8] + a[4] * b[10] - a[9] * b[14];
+        let result_e2    = a[0] * b[3] + a[2] * b[8] - a[4] * b[9] - a[10] * b[14];
+        let result_e3    = a[0] * b[4] - a[2] * b[10] + a[3] * b[9] - a[8] * b[14];
+        let result_e01   = a[0] * b[5] - a[3] * b[13] + a[4] * b[12] - a[9] * b[15];
+        let result_e02   = a[0] * b[6] + a[2] * b[13] - a[4] * b[11] - a[10] * b[15];
+        let result_e03   = a[0] * b[7] - a[2] * b[12] + a[3] * b[11] - a[8] * b[15];
+        let result_e12   = a[0] * b[8] + a[4] * b[14];
+        let result_e23   = a[0] * b[9] + a[2] * b[14];
+        let result_e31   = a[0] * b[10] + a[3] * b[14];
+        let result_e032  = a[0] * b[11] + a[2] * b[15];
+        let result_e013  = a[0] * b[12] + a[3] * b[15];
+        let result_e021  = a[0] * b[13] + a[4] * b[15];
+        let result_e123  = a[0] * b[14];
+        let result_e0123 = a[0] * b[15];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::Shl<ProjectiveMultivector3<S>> for &ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shl(self, other: ProjectiveMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[0] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[14] * b[14];
+        let result_e0    = a[0] * b[1] - a[2] * b[5] - a[3] * b[6] - a[4] * b[7] + a[8] * b[13] + a[9] * b[11] + a[10] * b[12] + a[14] * b[15];
+        let result_e1    = a[0] * b[2] - a[3] * b[8] + a[4] * b[10] - a[9] * b[14];
+        let result_e2    = a[0] * b[3] + a[2] * b[8] - a[4] * b[9] - a[10] * b[14];
+        let result_e3    = a[0] * b[4] - a[2] * b[10] + a[3] * b[9] - a[8] * b[14];
+        let result_e01   = a[0] * b[5] - a[3] * b[13] + a[4] * b[12] - a[9] * b[15];
+        let result_e02   = a[0] * b[6] + a[2] * b[13] - a[4] * b[11] - a[10] * b[15];
+        let result_e03   = a[0] * b[7] - a[2] * b[12] + a[3] * b[11] - a[8] * b[15];
+        let result_e12   = a[0] * b[8] + a[4] * b[14];
+        let result_e23   = a[0] * b[9] + a[2] * b[14];
+        let result_e31   = a[0] * b[10] + a[3] * b[14];
+        let result_e032  = a[0] * b[11] + a[2] * b[15];
+        let result_e013  = a[0] * b[12] + a[3] * b[15];
+        let result_e021  = a[0] * b[13] + a[4] * b[15];
+        let result_e123  = a[0] * b[14];
+        let result_e0123 = a[0] * b[15];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::Shl<&ProjectiveMultivector3<S>> for &ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shl(self, other: &ProjectiveMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[0] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[14] * b[14];
+        let result_e0    = a[0] * b[1] - a[2] * b[5] - a[3] * b[6] - a[4] * b[7] + a[8] * b[13] + a[9] * b[11] + a[10] * b[12] + a[14] * b[15];
+        let result_e1    = a[0] * b[2] - a[3] * b[8] + a[4] * b[10] - a[9] * b[14];
+        let result_e2    = a[0] * b[3] + a[2] * b[8] - a[4] * b[9] - a[10] * b[14];
+        let result_e3    = a[0] * b[4] - a[2] * b[10] + a[3] * b[9] - a[8] * b[14];
+        let result_e01   = a[0] * b[5] - a[3] * b[13] + a[4] * b[12] - a[9] * b[15];
+        let result_e02   = a[0] * b[6] + a[2] * b[13] - a[4] * b[11] - a[10] * b[15];
+        let result_e03   = a[0] * b[7] - a[2] * b[12] + a[3] * b[11] - a[8] * b[15];
+        let result_e12   = a[0] * b[8] + a[4] * b[14];
+        let result_e23   = a[0] * b[9] + a[2] * b[14];
+        let result_e31   = a[0] * b[10] + a[3] * b[14];
+        let result_e032  = a[0] * b[11] + a[2] * b[15];
+        let result_e013  = a[0] * b[12] + a[3] * b[15];
+        let result_e021  = a[0] * b[13] + a[4] * b[15];
+        let result_e123  = a[0] * b[14];
+        let result_e0123 = a[0] * b[15];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::Shl<S> for ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn shl(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1 = a[0] * other;
+
+        ProjectiveMultivector3::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::Shl<S> for &ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn shl(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1 = a[0] * other;
+
+        ProjectiveMultivector3::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::Shr<ProjectiveMultivector3<S>> for ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: ProjectiveMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[0] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[14] * b[14];
+        let result_e0    = a[1] * b[0] + a[5] * b[2] + a[6] * b[3] + a[7] * b[4] + a[11] * b[9] + a[12] * b[10] + a[13] * b[8] - a[15] * b[14];
+        let result_e1    = a[2] * b[0] + a[8] * b[3] - a[10] * b[4] - a[14] * b[9];
+        let result_e2    = a[3] * b[0] - a[8] * b[2] + a[9] * b[4] - a[14] * b[10];
+        let result_e3    = a[4] * b[0] - a[9] * b[3] + a[10] * b[2] - a[14] * b[8];
+        let result_e01   = a[5] * b[0] + a[12] * b[4] - a[13] * b[3] - a[15] * b[9];
+        let result_e02   = a[6] * b[0] - a[11] * b[4] + a[13] * b[2] - a[15] * b[10];
+        let result_e03   = a[7] * b[0] + a[11] * b[3] - a[12] * b[2] - a[15] * b[8];
+        let result_e12   = a[8] * b[0] + a[14] * b[4];
+        let result_e23   = a[9] * b[0] + a[14] * b[2];
+        let result_e31   = a[10] * b[0] + a[14] * b[3];
+        let result_e032  = a[11] * b[0] - a[15] * b[2];
+        let result_e013  = a[12] * b[0] - a[15] * b[3];
+        let result_e021  = a[13] * b[0] - a[15] * b[4];
+        let result_e123  = a[14] * b[0];
+        let result_e0123 = a[15] * b[0];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::Shr<&ProjectiveMultivector3<S>> for ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: &ProjectiveMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[0] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[14] * b[14];
+        let result_e0    = a[1] * b[0] + a[5] * b[2] + a[6] * b[3] + a[7] * b[4] + a[11] * b[9] + a[12] * b[10] + a[13] * b[8] - a[15] * b[14];
+        let result_e1    = a[2] * b[0] + a[8] * b[3] - a[10] * b[4] - a[14] * b[9];
+        let result_e2    = a[3] * b[0] - a[8] * b[2] + a[9] * b[4] - a[14] * b[10];
+        let result_e3    = a[4] * b[0] - a[9] * b[3] + a[10] * b[2] - a[14] * b[8];
+        let result_e01   = a[5] * b[0] + a[12] * b[4] - a[13] * b[3] - a[15] * b[9];
+        let result_e02   = a[6] * b[0] - a[11] * b[4] + a[13] * b[2] - a[15] * b[10];
+        let result_e03   = a[7] * b[0] + a[11] * b[3] - a[12] * b[2] - a[15] * b[8];
+        let result_e12   = a[8] * b[0] + a[14] * b[4];
+        let result_e23   = a[9] * b[0] + a[14] * b[2];
+        let result_e31   = a[10] * b[0] + a[14] * b[3];
+        let result_e032  = a[11] * b[0] - a[15] * b[2];
+        let result_e013  = a[12] * b[0] - a[15] * b[3];
+        let result_e021  = a[13] * b[0] - a[15] * b[4];
+        let result_e123  = a[14] * b[0];
+        let result_e0123 = a[15] * b[0];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::Shr<ProjectiveMultivector3<S>> for &ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: ProjectiveMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[0] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[14] * b[14];
+        let result_e0    = a[1] * b[0] + a[5] * b[2] + a[6] * b[3] + a[7] * b[4] + a[11] * b[9] + a[12] * b[10] + a[13] * b[8] - a[15] * b[14];
+        let result_e1    = a[2] * b[0] + a[8] * b[3] - a[10] * b[4] - a[14] * b[9];
+        let result_e2    = a[3] * b[0] - a[8] * b[2] + a[9] * b[4] - a[14] * b[10];
+        let result_e3    = a[4] * b[0] - a[9] * b[3] + a[10] * b[2] - a[14] * b[8];
+        let result_e01   = a[5] * b[0] + a[12] * b[4] - a[13] * b[3] - a[15] * b[9];
+        let result_e02   = a[6] * b[0] - a[11] * b[4] + a[13] * b[2] - a[15] * b[10];
+        let result_e03   = a[7] * b[0] + a[11] * b[3] - a[12] * b[2] - a[15] * b[8];
+        let result_e12   = a[8] * b[0] + a[14] * b[4];
+        let result_e23   = a[9] * b[0] + a[14] * b[2];
+        let result_e31   = a[10] * b[0] + a[14] * b[3];
+        let result_e032  = a[11] * b[0] - a[15] * b[2];
+        let result_e013  = a[12] * b[0] - a[15] * b[3];
+        let result_e021  = a[13] * b[0] - a[15] * b[4];
+        let result_e123  = a[14] * b[0];
+        let result_e0123 = a[15] * b[0];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::Shr<&ProjectiveMultivector3<S>> for &ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: &ProjectiveMultivector3<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1     = a[0] * b[0] + a[2] * b[2] + a[3] * b[3] + a[4] * b[4] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[14] * b[14];
+        let result_e0    = a[1] * b[0] + a[5] * b[2] + a[6] * b[3] + a[7] * b[4] + a[11] * b[9] + a[12] * b[10] + a[13] * b[8] - a[15] * b[14];
+        let result_e1    = a[2] * b[0] + a[8] * b[3] - a[10] * b[4] - a[14] * b[9];
+        let result_e2    = a[3] * b[0] - a[8] * b[2] + a[9] * b[4] - a[14] * b[10];
+        let result_e3    = a[4] * b[0] - a[9] * b[3] + a[10] * b[2] - a[14] * b[8];
+        let result_e01   = a[5] * b[0] + a[12] * b[4] - a[13] * b[3] - a[15] * b[9];
+        let result_e02   = a[6] * b[0] - a[11] * b[4] + a[13] * b[2] - a[15] * b[10];
+        let result_e03   = a[7] * b[0] + a[11] * b[3] - a[12] * b[2] - a[15] * b[8];
+        let result_e12   = a[8] * b[0] + a[14] * b[4];
+        let result_e23   = a[9] * b[0] + a[14] * b[2];
+        let result_e31   = a[10] * b[0] + a[14] * b[3];
+        let result_e032  = a[11] * b[0] - a[15] * b[2];
+        let result_e013  = a[12] * b[0] - a[15] * b[3];
+        let result_e021  = a[13] * b[0] - a[15] * b[4];
+        let result_e123  = a[14] * b[0];
+        let result_e0123 = a[15] * b[0];
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::Shr<S> for ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1     = a[0] * other;
+        let result_e0    = a[1] * other;
+        let result_e1    = a[2] * other;
+        let result_e2    = a[3] * other;
+        let result_e3    = a[4] * other;
+        let result_e01   = a[5] * other;
+        let result_e02   = a[6] * other;
+        let result_e03   = a[7] * other;
+        let result_e12   = a[8] * other;
+        let result_e23   = a[9] * other;
+        let result_e31   = a[10] * other;
+        let result_e032  = a[11] * other;
+        let result_e013  = a[12] * other;
+        let result_e021  = a[13] * other;
+        let result_e123  = a[14] * other;
+        let result_e0123 = a[15] * other;
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ops::Shr<S> for &ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    type Output = ProjectiveMultivector3<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1     = a[0] * other;
+        let result_e0    = a[1] * other;
+        let result_e1    = a[2] * other;
+        let result_e2    = a[3] * other;
+        let result_e3    = a[4] * other;
+        let result_e01   = a[5] * other;
+        let result_e02   = a[6] * other;
+        let result_e03   = a[7] * other;
+        let result_e12   = a[8] * other;
+        let result_e23   = a[9] * other;
+        let result_e31   = a[10] * other;
+        let result_e032  = a[11] * other;
+        let result_e013  = a[12] * other;
+        let result_e021  = a[13] * other;
+        let result_e123  = a[14] * other;
+        let result_e0123 = a[15] * other;
+
+        ProjectiveMultivector3::new(
+            result_1,
+            result_e0,
+            result_e1,
+            result_e2,
+            result_e3,
+            result_e01,
+            result_e02,
+            result_e03,
+            result_e12,
+            result_e23,
+            result_e31,
+            result_e032,
+            result_e013,
+            result_e021,
+            result_e123,
+            result_e0123,
+        )
+    }
+}
+
+impl<S> ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    /// Construct the plane with the equation `a * x + b * y + c * z + d == 0`.
+    ///
+    /// In projective geometric algebra, planes are represented by vectors.
+    /// The plane `a * x + b * y + c * z + d == 0` is the vector
+    /// ```text
+    /// a * e1 + b * e2 + c * e3 + d * e0
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// // The plane `x == 1`.
+    /// let plane = ProjectiveMultivector3::from_plane(1, 0, 0, -1);
+    /// let point = ProjectiveMultivector3::from_point(1, 5, -3);
+    ///
+    /// assert_eq!(plane.e1, 1);
+    /// assert_eq!(plane.e0, -1);
+    /// assert!((plane ^ point).is_zero());
+    /// ```
+    #[inline]
+    pub fn from_plane(a: S, b: S, c: S, d: S) -> Self {
+        let mut result = Self::zero();
+        result.data[1] = d;
+        result.data[2] = a;
+        result.data[3] = b;
+        result.data[4] = c;
+
+        result
+    }
+
+    /// Construct the point with coordinates `(x, y, z)`.
+    ///
+    /// In projective geometric algebra, points are represented by trivectors.
+    /// The point `(x, y, z)` is the trivector
+    /// ```text
+    /// x * e032 + y * e013 + z * e021 + e123
+    /// ```
+    /// which is the meet of the planes `x == x`, `y == y`, and `z == z`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let plane_x = ProjectiveMultivector3::from_plane(1, 0, 0, -1);
+    /// let plane_y = ProjectiveMultivector3::from_plane(0, 1, 0, -2);
+    /// let plane_z = ProjectiveMultivector3::from_plane(0, 0, 1, -3);
+    /// let expected = ProjectiveMultivector3::from_point(1, 2, 3);
+    /// let result = plane_x ^ plane_y ^ plane_z;
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn from_point(x: S, y: S, z: S) -> Self {
+        let mut result = Self::zero();
+        result.data[11] = x;
+        result.data[12] = y;
+        result.data[13] = z;
+        result.data[14] = S::one();
+
+        result
+    }
+
+    /// Construct the direction `(x, y, z)`.
+    ///
+    /// A direction is a point at infinity, also known as an ideal point. It is
+    /// represented by the trivector
+    /// ```text
+    /// x * e032 + y * e013 + z * e021
+    /// ```
+    /// which has no `e123` component. Directions are invariant under
+    /// translations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let direction = ProjectiveMultivector3::from_direction(1, 2, 3);
+    ///
+    /// assert_eq!(direction.e032, 1);
+    /// assert_eq!(direction.e013, 2);
+    /// assert_eq!(direction.e021, 3);
+    /// assert_eq!(direction.e123, 0);
+    /// ```
+    #[inline]
+    pub fn from_direction(x: S, y: S, z: S) -> Self {
+        let mut result = Self::zero();
+        result.data[11] = x;
+        result.data[12] = y;
+        result.data[13] = z;
+
+        result
+    }
+
+    /// Construct the line through the point `(px, py, pz)` with the direction
+    /// `(dx, dy, dz)`.
+    ///
+    /// In projective geometric algebra, lines are represented by bivectors.
+    /// The line is the join of the point and the direction
+    /// ```text
+    /// from_point(px, py, pz) & from_direction(dx, dy, dz)
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let x_axis = ProjectiveMultivector3::from_line(0, 0, 0, 1, 0, 0);
+    /// let expected: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e23();
+    ///
+    /// assert_eq!(x_axis, expected);
+    ///
+    /// let line = ProjectiveMultivector3::from_line(1, 2, 3, 0, 0, 1);
+    /// let point = ProjectiveMultivector3::from_point(1, 2, 7);
+    ///
+    /// assert!((line & point).is_zero());
+    /// ```
+    #[inline]
+    pub fn from_line(px: S, py: S, pz: S, dx: S, dy: S, dz: S) -> Self {
+        Self::from_point(px, py, pz) & Self::from_direction(dx, dy, dz)
+    }
+
+    /// Compute the meet of two geometric objects.
+    ///
+    /// The meet is the intersection of two geometric objects, and is given by
+    /// the outer product
+    /// ```text
+    /// meet(A, B) := A ^ B
+    /// ```
+    /// For example, two planes meet in a line, and a line meets a plane in a
+    /// point. Parallel objects meet at infinity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let x_axis = ProjectiveMultivector3::from_line(0, 0, 0, 1, 0, 0);
+    /// let plane = ProjectiveMultivector3::from_plane(1, 0, 0, -4);
+    /// let expected = ProjectiveMultivector3::from_point(4, 0, 0);
+    /// let result = x_axis.meet(&plane);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn meet(&self, other: &Self) -> Self {
+        self ^ other
+    }
+
+    /// Compute the join of two geometric objects.
+    ///
+    /// The join is the smallest geometric object containing both geometric
+    /// objects, and is given by the regressive product
+    /// ```text
+    /// join(A, B) := A & B
+    /// ```
+    /// For example, two points join into a line, and three points join into a
+    /// plane.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let p = ProjectiveMultivector3::from_point(1, 2, 3);
+    /// let q = ProjectiveMultivector3::from_point(4, 2, 3);
+    /// let r = ProjectiveMultivector3::from_point(1, 5, 3);
+    /// let plane = p.join(&q).join(&r);
+    ///
+    /// // The points lie in the plane `z == 3`.
+    /// assert_eq!(plane, ProjectiveMultivector3::from_plane(0, 0, 9, -27));
+    /// ```
+    #[inline]
+    pub fn join(&self, other: &Self) -> Self {
+        self & other
+    }
+}
+
+impl<S> ProjectiveMultivector3<S>
+where
+    S: ScalarFloat,
+{
+    /// Construct the translator that translates by the displacement `(x, y, z)`.
+    ///
+    /// The translator is the versor
+    /// ```text
+    /// T = 1 - (1 / 2) * (x * e01 + y * e02 + z * e03)
+    /// ```
+    /// which translates a geometric object `X` by the sandwich product
+    /// `T * X * rev(T)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let translator = ProjectiveMultivector3::translator(1_f64, 2_f64, 3_f64);
+    /// let origin = ProjectiveMultivector3::from_point(0_f64, 0_f64, 0_f64);
+    /// let expected = ProjectiveMultivector3::from_point(1_f64, 2_f64, 3_f64);
+    /// let result = origin.transform(&translator);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn translator(x: S, y: S, z: S) -> Self {
+        let one_half = S::one() / (S::one() + S::one());
+        let mut result = Self::unit_scalar();
+        result.data[5] = -x * one_half;
+        result.data[6] = -y * one_half;
+        result.data[7] = -z * one_half;
+
+        result
+    }
+
+    /// Transform a geometric object by a versor.
+    ///
+    /// The transformed object is the sandwich product `V * mv * rev(V)`. The
+    /// versor `V` is assumed to be normalized, so that its reverse is its
+    /// inverse. Rotors, translators, and their products all act on points,
+    /// lines and planes this way.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::pga3::ProjectiveMultivector3;
+    /// #
+    /// let translator = ProjectiveMultivector3::translator(0_f64, 0_f64, 5_f64);
+    /// let plane = ProjectiveMultivector3::from_plane(0_f64, 0_f64, 1_f64, -1_f64);
+    /// let expected = ProjectiveMultivector3::from_plane(0_f64, 0_f64, 1_f64, -6_f64);
+    /// let result = plane.transform(&translator);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn transform(&self, versor: &Self) -> Self {
+        versor * self * versor.reverse()
+    }
+}
+
+
+impl_coords!(ViewP3, { scalar, e0, e1, e2, e3, e01, e02, e03, e12, e23, e31, e032, e013, e021, e123, e0123 });
+impl_coords_deref!(ProjectiveMultivector3, ViewP3);
+
+
+macro_rules! impl_scalar_multivector_add_ops {
+    ($Lhs:ty => $Rhs:ty => $Output:ty, { $scalar_index:expr }, { $($other_index:expr),* }) => {
+        impl ops::Add<$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn add(self, other: $Rhs) -> $Output {
+                Self::Output::new(self + other[$scalar_index], $(other[$other_index]),* )
+            }
+        }
+
+        impl ops::Add<&$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn add(self, other: &$Rhs) -> $Output {
+                Self::Output::new(self + other[$scalar_index], $(other[$other_index]),* )
+            }
+        }
+    }
+}
+
+impl_scalar_multivector_add_ops!(u8    => ProjectiveMultivector3<u8>    => ProjectiveMultivector3<u8>,    {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(u16   => ProjectiveMultivector3<u16>   => ProjectiveMultivector3<u16>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(u32   => ProjectiveMultivector3<u32>   => ProjectiveMultivector3<u32>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(u64   => ProjectiveMultivector3<u64>   => ProjectiveMultivector3<u64>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(u128  => ProjectiveMultivector3<u128>  => ProjectiveMultivector3<u128>,  {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(usize => ProjectiveMultivector3<usize> => ProjectiveMultivector3<usize>, {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(i8    => ProjectiveMultivector3<i8>    => ProjectiveMultivector3<i8>,    {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(i16   => ProjectiveMultivector3<i16>   => ProjectiveMultivector3<i16>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(i32   => ProjectiveMultivector3<i32>   => ProjectiveMultivector3<i32>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(i64   => ProjectiveMultivector3<i64>   => ProjectiveMultivector3<i64>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(i128  => ProjectiveMultivector3<i128>  => ProjectiveMultivector3<i128>,  {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(isize => ProjectiveMultivector3<isize> => ProjectiveMultivector3<isize>, {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(f32   => ProjectiveMultivector3<f32>   => ProjectiveMultivector3<f32>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(f64   => ProjectiveMultivector3<f64>   => ProjectiveMultivector3<f64>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+
+
+macro_rules! impl_scalar_multivector_sub_ops {
+    ($Lhs:ty => $Rhs:ty => $Output:ty, { $scalar_index:expr }, { $($other_index:expr),* }) => {
+        impl ops::Sub<$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn sub(self, other: $Rhs) -> Self::Output {
+                Self::Output::new(self - other[$scalar_index], $(-other[$other_index]),* )
+            }
+        }
+
+        impl ops::Sub<&$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn sub(self, other: &$Rhs) -> Self::Output {
+                Self::Output::new(self - other[$scalar_index], $(-other[$other_index]),* )
+            }
+        }
+    }
+}
+
+impl_scalar_multivector_sub_ops!(i8    => ProjectiveMultivector3<i8>    => ProjectiveMultivector3<i8>,    {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_sub_ops!(i16   => ProjectiveMultivector3<i16>   => ProjectiveMultivector3<i16>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_sub_ops!(i32   => ProjectiveMultivector3<i32>   => ProjectiveMultivector3<i32>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_sub_ops!(i64   => ProjectiveMultivector3<i64>   => ProjectiveMultivector3<i64>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_sub_ops!(i128  => ProjectiveMultivector3<i128>  => ProjectiveMultivector3<i128>,  {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_sub_ops!(isize => ProjectiveMultivector3<isize> => ProjectiveMultivector3<isize>, {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_sub_ops!(f32   => ProjectiveMultivector3<f32>   => ProjectiveMultivector3<f32>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_sub_ops!(f64   => ProjectiveMultivector3<f64>   => ProjectiveMultivector3<f64>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+
+
+macro_rules! impl_scalar_multivector_mul_ops {
+    ($Lhs:ty => $Rhs:ty => $Output:ty, { $($index:expr),* }) => {
+        impl ops::Mul<$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn mul(self, other: $Rhs) -> Self::Output {
+                Self::Output::new( $(self * other[$index]),* )
+            }
+        }
+
+        impl ops::Mul<&$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn mul(self, other: &$Rhs) -> Self::Output {
+                Self::Output::new( $(self * other[$index]),* )
+            }
+        }
+    }
+}
+
+impl_scalar_multivector_mul_ops!(u8    => ProjectiveMultivector3<u8>    => ProjectiveMultivector3<u8>,    {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(u16   => ProjectiveMultivector3<u16>   => ProjectiveMultivector3<u16>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(u32   => ProjectiveMultivector3<u32>   => ProjectiveMultivector3<u32>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(u64   => ProjectiveMultivector3<u64>   => ProjectiveMultivector3<u64>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(u128  => ProjectiveMultivector3<u128>  => ProjectiveMultivector3<u128>,  {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(usize => ProjectiveMultivector3<usize> => ProjectiveMultivector3<usize>, {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(i8    => ProjectiveMultivector3<i8>    => ProjectiveMultivector3<i8>,    {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(i16   => ProjectiveMultivector3<i16>   => ProjectiveMultivector3<i16>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(i32   => ProjectiveMultivector3<i32>   => ProjectiveMultivector3<i32>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(i64   => ProjectiveMultivector3<i64>   => ProjectiveMultivector3<i64>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(i128  => ProjectiveMultivector3<i128>  => ProjectiveMultivector3<i128>,  {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(isize => ProjectiveMultivector3<isize> => ProjectiveMultivector3<isize>, {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(f32   => ProjectiveMultivector3<f32>   => ProjectiveMultivector3<f32>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(f64   => ProjectiveMultivector3<f64>   => ProjectiveMultivector3<f64>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+
+
+macro_rules! impl_scalar_multivector_bitor_ops {
+    ($Lhs:ty) => {
+        impl ops::BitOr<ProjectiveMultivector3<$Lhs>> for $Lhs {
+            type Output = ProjectiveMultivector3<$Lhs>;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            #[inline]
+            fn bitor(self, other: ProjectiveMultivector3<$Lhs>) -> Self::Output {
+                let mut result = Self::Output::zero();
+                result[0] = self * other[0];
+
+                result
+            }
+        }
+
+        impl ops::BitOr<&ProjectiveMultivector3<$Lhs>> for $Lhs {
+            type Output = ProjectiveMultivector3<$Lhs>;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            #[inline]
+            fn bitor(self, other: &ProjectiveMultivector3<$Lhs>) -> Self::Output {
+                let mut result = Self::Output::zero();
+                result[0] = self * other[0];
+
+                result
+            }
+        }
+    };
+}
+
+impl_scalar_multivector_bitor_ops!(u8);
+impl_scalar_multivector_bitor_ops!(u16);
+impl_scalar_multivector_bitor_ops!(u32);
+impl_scalar_multivector_bitor_ops!(u64);
+impl_scalar_multivector_bitor_ops!(u128);
+impl_scalar_multivector_bitor_ops!(usize);
+impl_scalar_multivector_bitor_ops!(i8);
+impl_scalar_multivector_bitor_ops!(i16);
+impl_scalar_multivector_bitor_ops!(i32);
+impl_scalar_multivector_bitor_ops!(i64);
+impl_scalar_multivector_bitor_ops!(i128);
+impl_scalar_multivector_bitor_ops!(isize);
+impl_scalar_multivector_bitor_ops!(f32);
+impl_scalar_multivector_bitor_ops!(f64);
+
+
+macro_rules! impl_scalar_multivector_bitxor_ops {
+    ($Lhs:ty) => {
+        impl ops::BitXor<ProjectiveMultivector3<$Lhs>> for $Lhs {
+            type Output = ProjectiveMultivector3<$Lhs>;
+
+            #[inline]
+            fn bitxor(self, other: ProjectiveMultivector3<$Lhs>) -> Self::Output {
+                let mut result = Self::Output::zero();
+                result[0] = self * other[0];
+                result[1] = self * other[1];
+                result[2] = self * other[2];
+                result[3] = self * other[3];
+                result[4] = self * other[4];
+                result[5] = self * other[5];
+                result[6] = self * other[6];
+                result[7] = self * other[7];
+                result[8] = self * other[8];
+                result[9] = self * other[9];
+                result[10] = self * other[10];
+                result[11] = self * other[11];
+                result[12] = self * other[12];
+                result[13] = self * other[13];
+                result[14] = self * other[14];
+                result[15] = self * other[15];
+
+                result
+            }
+        }
+
+        impl ops::BitXor<&ProjectiveMultivector3<$Lhs>> for $Lhs {
+            type Output = ProjectiveMultivector3<$Lhs>;
+
+            #[inline]
+            fn bitxor(self, other: &ProjectiveMultivector3<$Lhs>) -> Self::Output {
+                let mut result = Self::Output::zero();
+                result[0] = self * other[0];
+                result[1] = self * other[1];
+                result[2] = self * other[2];
+                result[3] = self * other[3];
+                result[4] = self * other[4];
+                result[5] = self * other[5];
+                result[6] = self * other[6];
+                result[7] = self * other[7];
+                result[8] = self * other[8];
+                result[9] = self * other[9];
+                result[10] = self * other[10];
+                result[11] = self * other[11];
+                result[12] = self * other[12];
+                result[13] = self * other[13];
+                result[14] = self * other[14];
+                result[15] = self * other[15];
+
+                result
+            }
+        }
+    };
+}
+
+impl_scalar_multivector_bitxor_ops!(u8);
+impl_scalar_multivector_bitxor_ops!(u16);
+impl_scalar_multivector_bitxor_ops!(u32);
+impl_scalar_multivector_bitxor_ops!(u64);
+impl_scalar_multivector_bitxor_ops!(u128);
+impl_scalar_multivector_bitxor_ops!(usize);
+impl_scalar_multivector_bitxor_ops!(i8);
+impl_scalar_multivector_bitxor_ops!(i16);
+impl_scalar_multivector_bitxor_ops!(i32);
+impl_scalar_multivector_bitxor_ops!(i64);
+impl_scalar_multivector_bitxor_ops!(i128);
+impl_scalar_multivector_bitxor_ops!(isize);
+impl_scalar_multivector_bitxor_ops!(f32);
+impl_scalar_multivector_bitxor_ops!(f64);
@@ -0,0 +1,939 @@
+use crate::e3ga::{
+    EuclideanMultivector3,
+    Rotor3,
+};
+use crate::pga3::ProjectiveMultivector3;
+use crate::scalar::{
+    Scalar,
+    ScalarFloat,
+    ScalarSigned,
+};
+use crate::{
+    impl_approx_cmp_ops,
+    impl_coords,
+    impl_coords_deref,
+};
+use core::fmt;
+use core::ops;
+
+
+/// A stack-allocated motor in three-dimensional projective geometric algebra.
+///
+/// A motor is a unit element of the even subalgebra of the three-dimensional
+/// projective geometric algebra. It is stored in the basis
+/// `{1, e01, e02, e03, e12, e23, e31, e0123}`, so only the scalar, bivector and
+/// pseudoscalar parts of a motor are represented. The odd-grade parts of a
+/// motor are always zero.
+///
+/// Motors represent the rigid body motions of three-dimensional Euclidean
+/// space, i.e. the compositions of rotations and translations. A motor `M`
+/// transforms a point, a line or a plane `X` by the sandwich product
+/// ```text
+/// transform(M, X) := M * X * rev(M)
+/// ```
+/// where `rev` denotes the reverse. The product `M2 * M1` of two motors is the
+/// motor that applies `M1` first, and then applies `M2`. Motors play the same
+/// role in projective geometric algebra that unit dual quaternions play in
+/// the quaternion algebra.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Motor3<S> {
+    data: [S; 8],
+}
+
+impl<S> Motor3<S> {
+    /// Construct a new motor from its components.
+    ///
+    /// The components are not normalized, so the caller is responsible for
+    /// ensuring that the resulting motor has unit magnitude.
+    #[inline]
+    pub const fn new(scalar: S, e01: S, e02: S, e03: S, e12: S, e23: S, e31: S, e0123: S) -> Self {
+        Self {
+            data: [scalar, e01, e02, e03, e12, e23, e31, e0123],
+        }
+    }
+
+    /// Returns the number of components in a motor.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::Motor3;
+    /// #
+    /// let motor = Motor3::new(1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    ///
+    /// assert_eq!(motor.len(), 8);
+    /// ```
+    #[inline]
+    pub const fn len(&self) -> usize {
+        8
+    }
+
+    /// Get a pointer to the underlying component array.
+    #[inline]
+    pub const fn as_ptr(&self) -> *const S {
+        &self.data[0]
+    }
+
+    /// Get a mutable pointer to the underlying component array.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut S {
+        &mut self.data[0]
+    }
+
+    /// Get a slice of the underlying elements of the data type.
+    #[inline]
+    pub fn as_slice(&self) -> &[S] {
+        <Self as AsRef<[S; 8]>>::as_ref(self)
+    }
+}
+
+impl<S> Motor3<S>
+where
+    S: Scalar,
+{
+    /// Construct the identity motor.
+    ///
+    /// The identity motor leaves every point, line and plane unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::{
+    /// #     Motor3,
+    /// #     ProjectiveMultivector3,
+    /// # };
+    /// #
+    /// let identity: Motor3<f64> = Motor3::identity();
+    /// let point = ProjectiveMultivector3::from_point(1_f64, 2_f64, 3_f64);
+    ///
+    /// assert_eq!(identity.transform_point(&point), point);
+    /// ```
+    #[inline]
+    pub fn identity() -> Self {
+        let zero = S::zero();
+
+        Self::new(S::one(), zero, zero, zero, zero, zero, zero, zero)
+    }
+
+    /// Embed the even part of a Euclidean multivector as a motor.
+    ///
+    /// The scalar and bivector parts of `rotor` are embedded in the projective
+    /// algebra, and the other parts are ignored. The resulting motor rotates
+    /// about the origin exactly as `rotor` rotates vectors in the Euclidean
+    /// algebra.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::e3ga::{
+    /// #     EuclideanMultivector3,
+    /// #     Rotor3,
+    /// # };
+    /// # use cggeomalg::pga3::{
+    /// #     Motor3,
+    /// #     ProjectiveMultivector3,
+    /// # };
+    /// # use core::f64;
+    /// #
+    /// let e12: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e12();
+    /// let rotor = EuclideanMultivector3::from(Rotor3::from_bivector_angle(&e12, f64::consts::FRAC_PI_2));
+    /// let motor = Motor3::from_rotor(&rotor);
+    /// let point = ProjectiveMultivector3::from_point(1_f64, 0_f64, 3_f64);
+    /// let expected = ProjectiveMultivector3::from_point(0_f64, 1_f64, 3_f64);
+    /// let result = motor.transform_point(&point);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn from_rotor(rotor: &EuclideanMultivector3<S>) -> Self {
+        let zero = S::zero();
+
+        Self::new(rotor[0], zero, zero, zero, rotor[4], rotor[5], rotor[6], zero)
+    }
+}
+
+impl<S> Motor3<S>
+where
+    S: ScalarSigned,
+{
+    /// Compute the reverse of a motor.
+    ///
+    /// The reverse of a unit motor is its inverse, i.e. it performs the
+    /// opposite rigid body motion.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::pga3::Motor3;
+    /// #
+    /// let motor = Motor3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+    /// let expected = Motor3::new(1_i32, -2_i32, -3_i32, -4_i32, -5_i32, -6_i32, -7_i32, 8_i32);
+    /// let result = motor.reverse();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn reverse(&self) -> Self {
+        Self::new(
+            self.data[0],
+            -self.data[1],
+            -self.data[2],
+            -self.data[3],
+            -self.data[4],
+            -self.data[5],
+            -self.data[6],
+            self.data[7],
+        )
+    }
+}
+
+impl<S> Motor3<S>
+where
+    S: ScalarFloat,
+{
+    /// Construct the motor that translates by the displacement `(x, y, z)`.
+    ///
+    /// The motor is the translator
+    /// ```text
+    /// T = 1 - (1 / 2) * (x * e01 + y * e02 + z * e03)
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::pga3::{
+    /// #     Motor3,
+    /// #     ProjectiveMultivector3,
+    /// # };
+    /// #
+    /// let motor = Motor3::from_translation(1_f64, 2_f64, 3_f64);
+    /// let point = ProjectiveMultivector3::from_point(4_f64, 5_f64, 6_f64);
+    /// let expected = ProjectiveMultivector3::from_point(5_f64, 7_f64, 9_f64);
+    /// let result = motor.transform_point(&point);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn from_translation(x: S, y: S, z: S) -> Self {
+        let zero = S::zero();
+        let one_half = S::one() / (S::one() + S::one());
+
+        Self::new(S::one(), -x * one_half, -y * one_half, -z * one_half, zero, zero, zero, zero)
+    }
+
+    /// Construct a motor from a 4x4 homogeneous transformation matrix.
+    ///
+    /// The matrix is stored in column-major order, i.e. `matrix[column][row]`.
+    /// The upper left 3x3 block of the matrix is assumed to be a proper
+    /// rotation matrix, the first three rows of the last column hold the
+    /// translation, and the last row is `[0, 0, 0, 1]`. The resulting motor
+    /// rotates first, and then translates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::pga3::{
+    /// #     Motor3,
+    /// #     ProjectiveMultivector3,
+    /// # };
+    /// #
+    /// let bivector = ProjectiveMultivector3::new(
+    ///     0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0.1_f64, -0.2_f64, 0.3_f64,
+    ///     0.4_f64, 0.5_f64, 0.6_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64,
+    /// );
+    /// let motor = Motor3::exp(&bivector);
+    /// let matrix = motor.to_matrix();
+    /// let result = Motor3::from_matrix(&matrix);
+    ///
+    /// assert_relative_eq!(result, motor, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn from_matrix(matrix: &[[S; 4]; 4]) -> Self {
+        let rotation_matrix = [
+            [matrix[0][0], matrix[0][1], matrix[0][2]],
+            [matrix[1][0], matrix[1][1], matrix[1][2]],
+            [matrix[2][0], matrix[2][1], matrix[2][2]],
+        ];
+        let rotor = Motor3::from(Rotor3::from_rotation_matrix(&rotation_matrix));
+        let translator = Self::from_translation(matrix[3][0], matrix[3][1], matrix[3][2]);
+
+        translator * rotor
+    }
+
+    /// Convert a motor into a 4x4 homogeneous transformation matrix.
+    ///
+    /// The matrix is stored in column-major order, i.e. `matrix[column][row]`,
+    /// so that the columns of the matrix are the images of `e1`, `e2`, `e3`
+    /// and the origin under the rigid body motion. The motor is assumed to
+    /// have unit magnitude.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::pga3::Motor3;
+    /// #
+    /// let motor = Motor3::from_translation(1_f64, 2_f64, 3_f64);
+    /// let matrix = motor.to_matrix();
+    /// let expected = [
+    ///     [1_f64, 0_f64, 0_f64, 0_f64],
+    ///     [0_f64, 1_f64, 0_f64, 0_f64],
+    ///     [0_f64, 0_f64, 1_f64, 0_f64],
+    ///     [1_f64, 2_f64, 3_f64, 1_f64],
+    /// ];
+    ///
+    /// assert_eq!(matrix, expected);
+    /// ```
+    #[rustfmt::skip]
+    pub fn to_matrix(&self) -> [[S; 4]; 4] {
+        let zero = S::zero();
+        let two = S::one() + S::one();
+        let s   = self.data[0];
+        let b01 = self.data[1];
+        let b02 = self.data[2];
+        let b03 = self.data[3];
+        let b12 = self.data[4];
+        let b23 = self.data[5];
+        let b31 = self.data[6];
+        let ps  = self.data[7];
+        let rotation_matrix = Rotor3::new(s, b12, b23, b31).to_rotation_matrix();
+        let c3r0 = two * (b03 * b31 - b01 * s - b02 * b12 - b23 * ps);
+        let c3r1 = two * (b01 * b12 - b02 * s - b03 * b23 - b31 * ps);
+        let c3r2 = two * (b02 * b23 - b01 * b31 - b03 * s - b12 * ps);
+
+        [
+            [rotation_matrix[0][0], rotation_matrix[0][1], rotation_matrix[0][2], zero],
+            [rotation_matrix[1][0], rotation_matrix[1][1], rotation_matrix[1][2], zero],
+            [rotation_matrix[2][0], rotation_matrix[2][1], rotation_matrix[2][2], zero],
+            [c3r0, c3r1, c3r2, S::one()],
+        ]
+    }
+
+    /// Compute the exponential of a bivector.
+    ///
+    /// Only the grade two part of `bivector` is used. A bivector `B` in
+    /// projective geometric algebra describes a screw motion, i.e. a rotation
+    /// about an axis combined with a translation along the same axis. Its
+    /// square is the dual number
+    /// ```text
+    /// B * B == -l + 2 * m * e0123
+    /// ```
+    /// and its exponential has the closed form
+    /// ```text
+    /// exp(B) == cos(a) + (sin(a) / a) * B + (m / l) * (cos(a) - sin(a) / a) * e0123 * B + m * (sin(a) / a) * e0123
+    /// ```
+    /// where `a == sqrt(l)`. When `l == 0`, the bivector is an ideal line and
+    /// the exponential is the translator `1 + B`. In particular, the motor that
+    /// rotates by an angle `angle` about the unit line `L` is `exp(-(angle / 2) * L)`,
+    /// and the motor that translates by the displacement `(x, y, z)` is
+    /// `exp(-(1 / 2) * (x * e01 + y * e02 + z * e03))`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::pga3::{
+    /// #     Motor3,
+    /// #     ProjectiveMultivector3,
+    /// # };
+    /// # use core::f64;
+    /// #
+    /// // The line through `(1, 0, 0)` in the direction of the `z`-axis.
+    /// let axis = ProjectiveMultivector3::from_line(1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 1_f64);
+    /// let motor = Motor3::exp(&(axis * (-f64::consts::FRAC_PI_4)));
+    /// let point = ProjectiveMultivector3::from_point(2_f64, 0_f64, 5_f64);
+    /// let expected = ProjectiveMultivector3::from_point(1_f64, 1_f64, 5_f64);
+    /// let result = motor.transform_point(&point);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[rustfmt::skip]
+    pub fn exp(bivector: &ProjectiveMultivector3<S>) -> Self {
+        let zero = S::zero();
+        let b01 = bivector[5];
+        let b02 = bivector[6];
+        let b03 = bivector[7];
+        let b12 = bivector[8];
+        let b23 = bivector[9];
+        let b31 = bivector[10];
+        let l = b12 * b12 + b23 * b23 + b31 * b31;
+        if l.is_zero() {
+            return Self::new(S::one(), b01, b02, b03, zero, zero, zero, zero);
+        }
+
+        let m = b01 * b23 + b02 * b31 + b03 * b12;
+        let a = l.sqrt();
+        let (sin_a, cos_a) = a.sin_cos();
+        let sinc_a = sin_a / a;
+        let t = (m / l) * (cos_a - sinc_a);
+
+        Self::new(
+            cos_a,
+            sinc_a * b01 + t * b23,
+            sinc_a * b02 + t * b31,
+            sinc_a * b03 + t * b12,
+            sinc_a * b12,
+            sinc_a * b23,
+            sinc_a * b31,
+            m * sinc_a,
+        )
+    }
+
+    /// Compute the logarithm of a motor.
+    ///
+    /// The logarithm is the bivector `B` such that `exp(B) == M`, where `M` is
+    /// the motor, which is assumed to have unit magnitude. The rotational part
+    /// of the logarithm has magnitude at most `pi`. The motors `M` and `-M`
+    /// perform the same rigid body motion, so when the rotational part of the
+    /// motor vanishes, this function returns the logarithm of the pure
+    /// translation, which is exact up to the sign of the motor.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::pga3::{
+    /// #     Motor3,
+    /// #     ProjectiveMultivector3,
+    /// # };
+    /// #
+    /// let bivector = ProjectiveMultivector3::new(
+    ///     0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0.1_f64, -0.2_f64, 0.3_f64,
+    ///     0.4_f64, 0.5_f64, 0.6_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64,
+    /// );
+    /// let motor = Motor3::exp(&bivector);
+    /// let result = motor.log();
+    ///
+    /// assert_relative_eq!(result, bivector, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[rustfmt::skip]
+    pub fn log(&self) -> ProjectiveMultivector3<S> {
+        let zero = S::zero();
+        let s   = self.data[0];
+        let b01 = self.data[1];
+        let b02 = self.data[2];
+        let b03 = self.data[3];
+        let b12 = self.data[4];
+        let b23 = self.data[5];
+        let b31 = self.data[6];
+        let ps  = self.data[7];
+        let sin_a = (b12 * b12 + b23 * b23 + b31 * b31).sqrt();
+        if sin_a.is_zero() {
+            // The motor is a pure translation `s * (1 + B)` with `s == 1` or `s == -1`.
+            let one_over_s = S::one() / s;
+
+            return ProjectiveMultivector3::new(
+                zero, zero, zero, zero, zero,
+                b01 * one_over_s, b02 * one_over_s, b03 * one_over_s,
+                zero, zero, zero,
+                zero, zero, zero, zero, zero,
+            );
+        }
+
+        let a = sin_a.atan2(s);
+        let factor = a / sin_a;
+        // The pseudoscalar part of the logarithm scales the rotational part into
+        // the translational part along the screw axis.
+        let t = (ps / (sin_a * sin_a)) * (S::one() - factor * s);
+
+        ProjectiveMultivector3::new(
+            zero, zero, zero, zero, zero,
+            factor * b01 + t * b23, factor * b02 + t * b31, factor * b03 + t * b12,
+            factor * b12, factor * b23, factor * b31,
+            zero, zero, zero, zero, zero,
+        )
+    }
+
+    /// Interpolate between two motors along a screw motion.
+    ///
+    /// Screw linear interpolation moves with constant linear and angular
+    /// velocity along the screw motion from `self` at `amount == 0` to `other`
+    /// at `amount == 1`, by
+    /// ```text
+    /// sclerp(M1, M2, t) == M1 * exp(t * log(rev(M1) * M2))
+    /// ```
+    /// Both motors are assumed to have unit magnitude. The motors `M` and `-M`
+    /// perform the same rigid body motion, so `other` is negated when
+    /// necessary to take the shortest path.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::pga3::{
+    /// #     Motor3,
+    /// #     ProjectiveMultivector3,
+    /// # };
+    /// #
+    /// let motor1 = Motor3::from_translation(0_f64, 0_f64, 0_f64);
+    /// let motor2 = Motor3::from_translation(2_f64, 4_f64, 6_f64);
+    /// let expected = Motor3::from_translation(1_f64, 2_f64, 3_f64);
+    /// let result = motor1.sclerp(&motor2, 0.5_f64);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn sclerp(&self, other: &Self, amount: S) -> Self {
+        let difference = self.reverse() * other;
+        let difference = if difference.data[0] < S::zero() { -difference } else { difference };
+        let log_difference = difference.log();
+
+        self * Self::exp(&(log_difference * amount))
+    }
+
+    /// Transform a point by a motor.
+    ///
+    /// The transformed point is the sandwich product `M * P * rev(M)`. Only
+    /// the grade three part of `point` is used, and the result is always a
+    /// trivector. Directions, i.e. points at infinity, are rotated but not
+    /// translated. The motor is assumed to have unit magnitude.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::pga3::{
+    /// #     Motor3,
+    /// #     ProjectiveMultivector3,
+    /// # };
+    /// #
+    /// let motor = Motor3::from_translation(1_f64, 2_f64, 3_f64);
+    /// let direction = ProjectiveMultivector3::from_direction(1_f64, 0_f64, 0_f64);
+    /// let result = motor.transform_point(&direction);
+    ///
+    /// assert_relative_eq!(result, direction, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[rustfmt::skip]
+    pub fn transform_point(&self, point: &ProjectiveMultivector3<S>) -> ProjectiveMultivector3<S> {
+        let zero = S::zero();
+        let two = S::one() + S::one();
+        let s   = self.data[0];
+        let b01 = self.data[1];
+        let b02 = self.data[2];
+        let b03 = self.data[3];
+        let b12 = self.data[4];
+        let b23 = self.data[5];
+        let b31 = self.data[6];
+        let ps  = self.data[7];
+        let x   = point[11];
+        let y   = point[12];
+        let z   = point[13];
+        let w   = point[14];
+        let result_e032 = (s * s - b12 * b12 + b23 * b23 - b31 * b31) * x
+            + two * (b12 * s + b23 * b31) * y
+            + two * (b12 * b23 - b31 * s) * z
+            + two * (b03 * b31 - b01 * s - b02 * b12 - b23 * ps) * w;
+        let result_e013 = two * (b23 * b31 - b12 * s) * x
+            + (s * s - b12 * b12 - b23 * b23 + b31 * b31) * y
+            + two * (b12 * b31 + b23 * s) * z
+            + two * (b01 * b12 - b02 * s - b03 * b23 - b31 * ps) * w;
+        let result_e021 = two * (b12 * b23 + b31 * s) * x
+            + two * (b12 * b31 - b23 * s) * y
+            + (s * s + b12 * b12 - b23 * b23 - b31 * b31) * z
+            + two * (b02 * b23 - b01 * b31 - b03 * s - b12 * ps) * w;
+        let result_e123 = (s * s + b12 * b12 + b23 * b23 + b31 * b31) * w;
+
+        ProjectiveMultivector3::new(
+            zero, zero, zero, zero, zero, zero, zero, zero,
+            zero, zero, zero, result_e032, result_e013, result_e021, result_e123, zero,
+        )
+    }
+
+    /// Transform a line by a motor.
+    ///
+    /// The transformed line is the sandwich product `M * L * rev(M)`. Only
+    /// the grade two part of `line` is used, and the result is always a
+    /// bivector. The motor is assumed to have unit magnitude.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::pga3::{
+    /// #     Motor3,
+    /// #     ProjectiveMultivector3,
+    /// # };
+    /// #
+    /// let motor = Motor3::from_translation(0_f64, 3_f64, 0_f64);
+    /// let line = ProjectiveMultivector3::from_line(0_f64, 0_f64, 0_f64, 1_f64, 0_f64, 0_f64);
+    /// let expected = ProjectiveMultivector3::from_line(0_f64, 3_f64, 0_f64, 1_f64, 0_f64, 0_f64);
+    /// let result = motor.transform_line(&line);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[rustfmt::skip]
+    pub fn transform_line(&self, line: &ProjectiveMultivector3<S>) -> ProjectiveMultivector3<S> {
+        let zero = S::zero();
+        let two = S::one() + S::one();
+        let s   = self.data[0];
+        let b01 = self.data[1];
+        let b02 = self.data[2];
+        let b03 = self.data[3];
+        let b12 = self.data[4];
+        let b23 = self.data[5];
+        let b31 = self.data[6];
+        let ps  = self.data[7];
+        let l01 = line[5];
+        let l02 = line[6];
+        let l03 = line[7];
+        let l12 = line[8];
+        let l23 = line[9];
+        let l31 = line[10];
+        let result_e01 = (s * s - b12 * b12 + b23 * b23 - b31 * b31) * l01
+            + two * (b12 * s + b23 * b31) * l02
+            + two * (b12 * b23 - b31 * s) * l03
+            + two * (b01 * b12 + b03 * b23 + b31 * ps - b02 * s) * l12
+            + two * (b01 * b23 - b02 * b31 - b03 * b12 - s * ps) * l23
+            + two * (b01 * b31 + b02 * b23 + b03 * s - b12 * ps) * l31;
+        let result_e02 = two * (b23 * b31 - b12 * s) * l01
+            + (s * s - b12 * b12 - b23 * b23 + b31 * b31) * l02
+            + two * (b12 * b31 + b23 * s) * l03
+            + two * (b01 * s + b02 * b12 + b03 * b31 - b23 * ps) * l12
+            + two * (b01 * b31 + b02 * b23 + b12 * ps - b03 * s) * l23
+            + two * (b02 * b31 - b01 * b23 - b03 * b12 - s * ps) * l31;
+        let result_e03 = two * (b12 * b23 + b31 * s) * l01
+            + two * (b12 * b31 - b23 * s) * l02
+            + (s * s + b12 * b12 - b23 * b23 - b31 * b31) * l03
+            + two * (b03 * b12 - b01 * b23 - b02 * b31 - s * ps) * l12
+            + two * (b01 * b12 + b02 * s + b03 * b23 - b31 * ps) * l23
+            + two * (b02 * b12 + b03 * b31 + b23 * ps - b01 * s) * l31;
+        let result_e12 = (s * s + b12 * b12 - b23 * b23 - b31 * b31) * l12
+            + two * (b12 * b23 + b31 * s) * l23
+            + two * (b12 * b31 - b23 * s) * l31;
+        let result_e23 = two * (b12 * b23 - b31 * s) * l12
+            + (s * s - b12 * b12 + b23 * b23 - b31 * b31) * l23
+            + two * (b12 * s + b23 * b31) * l31;
+        let result_e31 = two * (b12 * b31 + b23 * s) * l12
+            + two * (b23 * b31 - b12 * s) * l23
+            + (s * s - b12 * b12 - b23 * b23 + b31 * b31) * l31;
+
+        ProjectiveMultivector3::new(
+            zero, zero, zero, zero, zero, result_e01, result_e02, result_e03,
+            result_e12, result_e23, result_e31, zero, zero, zero, zero, zero,
+        )
+    }
+
+    /// Transform a plane by a motor.
+    ///
+    /// The transformed plane is the sandwich product `M * p * rev(M)`. Only
+    /// the grade one part of `plane` is used, and the result is always a
+    /// vector. The motor is assumed to have unit magnitude.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::pga3::{
+    /// #     Motor3,
+    /// #     ProjectiveMultivector3,
+    /// # };
+    /// #
+    /// let motor = Motor3::from_translation(0_f64, 0_f64, 5_f64);
+    /// let plane = ProjectiveMultivector3::from_plane(0_f64, 0_f64, 1_f64, -1_f64);
+    /// let expected = ProjectiveMultivector3::from_plane(0_f64, 0_f64, 1_f64, -6_f64);
+    /// let result = motor.transform_plane(&plane);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[rustfmt::skip]
+    pub fn transform_plane(&self, plane: &ProjectiveMultivector3<S>) -> ProjectiveMultivector3<S> {
+        let zero = S::zero();
+        let two = S::one() + S::one();
+        let s   = self.data[0];
+        let b01 = self.data[1];
+        let b02 = self.data[2];
+        let b03 = self.data[3];
+        let b12 = self.data[4];
+        let b23 = self.data[5];
+        let b31 = self.data[6];
+        let ps  = self.data[7];
+        let v0  = plane[1];
+        let v1  = plane[2];
+        let v2  = plane[3];
+        let v3  = plane[4];
+        let result_e0 = (s * s + b12 * b12 + b23 * b23 + b31 * b31) * v0
+            + two * (b01 * s + b03 * b31 + b23 * ps - b02 * b12) * v1
+            + two * (b01 * b12 + b02 * s + b31 * ps - b03 * b23) * v2
+            + two * (b02 * b23 + b03 * s + b12 * ps - b01 * b31) * v3;
+        let result_e1 = (s * s - b12 * b12 + b23 * b23 - b31 * b31) * v1
+            + two * (b12 * s + b23 * b31) * v2
+            + two * (b12 * b23 - b31 * s) * v3;
+        let result_e2 = two * (b23 * b31 - b12 * s) * v1
+            + (s * s - b12 * b12 - b23 * b23 + b31 * b31) * v2
+            + two * (b12 * b31 + b23 * s) * v3;
+        let result_e3 = two * (b12 * b23 + b31 * s) * v1
+            + two * (b12 * b31 - b23 * s) * v2
+            + (s * s + b12 * b12 - b23 * b23 - b31 * b31) * v3;
+
+        ProjectiveMultivector3::new(
+            zero, result_e0, result_e1, result_e2, result_e3, zero, zero, zero,
+            zero, zero, zero, zero, zero, zero, zero, zero,
+        )
+    }
+
+    /// Calculate the squared magnitude of a motor.
+    ///
+    /// The squared magnitude is the scalar part of `M * rev(M)`, i.e. the
+    /// squared magnitude of the rotational part of the motor. The
+    /// translational part of a motor does not contribute to its magnitude.
+    #[inline]
+    pub fn magnitude_squared(&self) -> S {
+        self.data[0] * self.data[0] + self.data[4] * self.data[4] + self.data[5] * self.data[5] + self.data[6] * self.data[6]
+    }
+
+    /// Calculate the magnitude of a motor.
+    #[inline]
+    pub fn magnitude(&self) -> S {
+        self.magnitude_squared().sqrt()
+    }
+
+    /// Normalize a motor to a unit motor.
+    ///
+    /// A unit motor `M` satisfies `M * rev(M) == 1`. For a general even
+    /// element, `M * rev(M)` is the dual number `p + q * e0123`, so the
+    /// motor is normalized by multiplying with the inverse square root
+    /// ```text
+    /// 1 / sqrt(p + q * e0123) == 1 / sqrt(p) - (q / (2 * p * sqrt(p))) * e0123
+    /// ```
+    /// Repeated composition of motors accumulates rounding errors that cause
+    /// the result to drift away from the set of motors. Normalizing the motor
+    /// projects it back onto the set of motors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::pga3::Motor3;
+    /// #
+    /// let motor = Motor3::new(1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64);
+    /// let normalized = motor.normalize();
+    /// let identity: Motor3<f64> = Motor3::identity();
+    /// let result = normalized * normalized.reverse();
+    ///
+    /// assert_relative_eq!(result, identity, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[rustfmt::skip]
+    pub fn normalize(&self) -> Self {
+        let two = S::one() + S::one();
+        let s   = self.data[0];
+        let b01 = self.data[1];
+        let b02 = self.data[2];
+        let b03 = self.data[3];
+        let b12 = self.data[4];
+        let b23 = self.data[5];
+        let b31 = self.data[6];
+        let ps  = self.data[7];
+        let p = self.magnitude_squared();
+        let q = two * (s * ps - b01 * b23 - b02 * b31 - b03 * b12);
+        let one_over_magnitude = S::one() / p.sqrt();
+        let a = one_over_magnitude;
+        let b = -q * one_over_magnitude / (two * p);
+
+        Self::new(
+            a * s,
+            a * b01 - b * b23,
+            a * b02 - b * b31,
+            a * b03 - b * b12,
+            a * b12,
+            a * b23,
+            a * b31,
+            a * ps + b * s,
+        )
+    }
+
+    /// Compute the inverse of a unit motor.
+    ///
+    /// The inverse of a unit motor is its reverse.
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        self.reverse()
+    }
+}
+
+impl<S> ops::Index<usize> for Motor3<S>
+where
+    S: Scalar,
+{
+    type Output = S;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.data[index]
+    }
+}
+
+impl<S> ops::IndexMut<usize> for Motor3<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.data[index]
+    }
+}
+
+impl<S> AsRef<[S; 8]> for Motor3<S> {
+    #[inline]
+    fn as_ref(&self) -> &[S; 8] {
+        unsafe { &*(self as *const Motor3<S> as *const [S; 8]) }
+    }
+}
+
+impl<S> AsMut<[S; 8]> for Motor3<S> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [S; 8] {
+        unsafe { &mut *(self as *mut Motor3<S> as *mut [S; 8]) }
+    }
+}
+
+impl<S> fmt::Display for Motor3<S>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "{} + {}^e01 + {}^e02 + {}^e03 + {}^e12 + {}^e23 + {}^e31 + {}^e0123",
+            self.data[0], self.data[1], self.data[2], self.data[3], self.data[4], self.data[5], self.data[6], self.data[7]
+        )
+    }
+}
+
+impl<S> From<Rotor3<S>> for Motor3<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn from(rotor: Rotor3<S>) -> Motor3<S> {
+        let zero = S::zero();
+
+        Motor3::new(rotor[0], zero, zero, zero, rotor[1], rotor[2], rotor[3], zero)
+    }
+}
+
+impl<S> From<&Rotor3<S>> for Motor3<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn from(rotor: &Rotor3<S>) -> Motor3<S> {
+        let zero = S::zero();
+
+        Motor3::new(rotor[0], zero, zero, zero, rotor[1], rotor[2], rotor[3], zero)
+    }
+}
+
+impl<S> From<Motor3<S>> for ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    #[rustfmt::skip]
+    #[inline]
+    fn from(motor: Motor3<S>) -> ProjectiveMultivector3<S> {
+        let zero = S::zero();
+
+        ProjectiveMultivector3::new(
+            motor.data[0], zero, zero, zero, zero, motor.data[1], motor.data[2], motor.data[3],
+            motor.data[4], motor.data[5], motor.data[6], zero, zero, zero, zero, motor.data[7],
+        )
+    }
+}
+
+impl<S> From<&Motor3<S>> for ProjectiveMultivector3<S>
+where
+    S: Scalar,
+{
+    #[rustfmt::skip]
+    #[inline]
+    fn from(motor: &Motor3<S>) -> ProjectiveMultivector3<S> {
+        let zero = S::zero();
+
+        ProjectiveMultivector3::new(
+            motor.data[0], zero, zero, zero, zero, motor.data[1], motor.data[2], motor.data[3],
+            motor.data[4], motor.data[5], motor.data[6], zero, zero, zero, zero, motor.data[7],
+        )
+    }
+}
+
+macro_rules! impl_motor_mul_ops {
+    ($Lhs:ty, $Rhs:ty) => {
+        impl<S> ops::Mul<$Rhs> for $Lhs
+        where
+            S: Scalar,
+        {
+            type Output = Motor3<S>;
+
+            #[rustfmt::skip]
+            #[inline]
+            fn mul(self, other: $Rhs) -> Self::Output {
+                let a = self;
+                let b = other;
+                let result_1     = a[0] * b[0] - a[4] * b[4] - a[5] * b[5] - a[6] * b[6];
+                let result_e01   = a[0] * b[1] + a[1] * b[0] - a[2] * b[4] + a[3] * b[6] + a[4] * b[2] - a[5] * b[7] - a[6] * b[3] - a[7] * b[5];
+                let result_e02   = a[0] * b[2] + a[1] * b[4] + a[2] * b[0] - a[3] * b[5] - a[4] * b[1] + a[5] * b[3] - a[6] * b[7] - a[7] * b[6];
+                let result_e03   = a[0] * b[3] - a[1] * b[6] + a[2] * b[5] + a[3] * b[0] - a[4] * b[7] - a[5] * b[2] + a[6] * b[1] - a[7] * b[4];
+                let result_e12   = a[0] * b[4] + a[4] * b[0] - a[5] * b[6] + a[6] * b[5];
+                let result_e23   = a[0] * b[5] + a[4] * b[6] + a[5] * b[0] - a[6] * b[4];
+                let result_e31   = a[0] * b[6] - a[4] * b[5] + a[5] * b[4] + a[6] * b[0];
+                let result_e0123 = a[0] * b[7] + a[1] * b[5] + a[2] * b[6] + a[3] * b[4] + a[4] * b[3] + a[5] * b[1] + a[6] * b[2] + a[7] * b[0];
+
+                Motor3::new(result_1, result_e01, result_e02, result_e03, result_e12, result_e23, result_e31, result_e0123)
+            }
+        }
+    };
+}
+
+impl_motor_mul_ops!(Motor3<S>, Motor3<S>);
+impl_motor_mul_ops!(Motor3<S>, &Motor3<S>);
+impl_motor_mul_ops!(&Motor3<S>, Motor3<S>);
+impl_motor_mul_ops!(&Motor3<S>, &Motor3<S>);
+
+impl<S> ops::Neg for Motor3<S>
+where
+    S: ScalarSigned,
+{
+    type Output = Motor3<S>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Motor3::new(
+            -self.data[0],
+            -self.data[1],
+            -self.data[2],
+            -self.data[3],
+            -self.data[4],
+            -self.data[5],
+            -self.data[6],
+            -self.data[7],
+        )
+    }
+}
+
+impl<S> ops::Neg for &Motor3<S>
+where
+    S: ScalarSigned,
+{
+    type Output = Motor3<S>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Motor3::new(
+            -self.data[0],
+            -self.data[1],
+            -self.data[2],
+            -self.data[3],
+            -self.data[4],
+            -self.data[5],
+            -self.data[6],
+            -self.data[7],
+        )
+    }
+}
+
+
+impl_coords!(ViewM3, { scalar, e01, e02, e03, e12, e23, e31, e0123 });
+impl_coords_deref!(Motor3, ViewM3);
+
+impl_approx_cmp_ops!(Motor3);
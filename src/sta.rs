@@ -0,0 +1,4329 @@
+use crate::e3ga::EuclideanMultivector3;
+use crate::scalar::{
+    Scalar,
+    ScalarFloat,
+    ScalarSigned,
+};
+use crate::{
+    impl_approx_cmp_ops,
+    impl_coords,
+    impl_coords_deref,
+};
+use approx_cmp::ulps_ne;
+use core::fmt;
+use core::ops;
+
+
+/// A stack-allocated spacetime multivector in the orthonormal basis
+/// `{1, gamma0, gamma1, gamma2, gamma3, gamma01, gamma02, gamma03, gamma12, gamma13,
+/// gamma23, gamma012, gamma013, gamma023, gamma123, gamma0123}`.
+///
+/// This is the spacetime algebra `Cl(1, 3)` of Minkowski spacetime. The basis
+/// vectors satisfy
+/// ```text
+/// gamma0 * gamma0 == 1
+/// gamma1 * gamma1 == gamma2 * gamma2 == gamma3 * gamma3 == -1
+/// ```
+/// so that the time axis `gamma0` is timelike, and the spatial axes `gamma1`,
+/// `gamma2` and `gamma3` are spacelike. The speed of light is one. The
+/// pseudoscalar `I == gamma0123` squares to minus one.
+///
+/// An observer with velocity `gamma0` splits spacetime into time and space. The
+/// relative vectors `sigma_k := gamma_k * gamma0` of the observer square to one,
+/// and they generate the even subalgebra, which is isomorphic to the
+/// three-dimensional Euclidean geometric algebra. Lorentz transformations,
+/// i.e. boosts, spatial rotations and their products, are rotors acting on
+/// multivectors by the sandwich product `R * mv * rev(R)`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct SpacetimeMultivector<S> {
+    data: [S; 16],
+}
+
+impl<S> SpacetimeMultivector<S> {
+    /// Construct a new general multivector.
+    #[inline]
+    pub const fn new(
+        scalar: S,
+        gamma0: S,
+        gamma1: S,
+        gamma2: S,
+        gamma3: S,
+        gamma01: S,
+        gamma02: S,
+        gamma03: S,
+        gamma12: S,
+        gamma13: S,
+        gamma23: S,
+        gamma012: S,
+        gamma013: S,
+        gamma023: S,
+        gamma123: S,
+        gamma0123: S,
+    ) -> Self {
+        Self {
+            data: [
+                scalar, gamma0, gamma1, gamma2, gamma3, gamma01, gamma02, gamma03, gamma12, gamma13, gamma23, gamma012, gamma013, gamma023,
+                gamma123, gamma0123,
+            ],
+        }
+    }
+
+    /// Returns the number of components in a multivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let mv = SpacetimeMultivector::new(1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32, 1_i32);
+    ///
+    /// assert_eq!(mv.len(), 16);
+    /// ```
+    #[inline]
+    pub const fn len(&self) -> usize {
+        16
+    }
+
+    /// Get a pointer to the underlying component array.
+    #[inline]
+    pub const fn as_ptr(&self) -> *const S {
+        &self.data[0]
+    }
+
+    /// Get a mutable pointer to the underlying component array.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut S {
+        &mut self.data[0]
+    }
+
+    /// Get a slice of the underlying elements of the data type.
+    #[inline]
+    pub fn as_slice(&self) -> &[S] {
+        <Self as AsRef<[S; 16]>>::as_ref(self)
+    }
+}
+
+impl<S> SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    /// Construct the additive unit (zero) multivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let mv = SpacetimeMultivector::new(
+    ///     1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64,
+    ///     9_f64, 10_f64, 11_f64, 12_f64, 13_f64, 14_f64, 15_f64, 16_f64
+    /// );
+    /// let zero: SpacetimeMultivector<f64> = SpacetimeMultivector::zero();
+    ///
+    /// assert_eq!(mv + zero, mv);
+    /// assert_eq!(zero + mv, mv);
+    /// ```
+    #[inline]
+    pub fn zero() -> Self {
+        Self { data: [S::zero(); 16] }
+    }
+
+    /// Determine whether a multivector is the zero mutlivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let zero: SpacetimeMultivector<f64> = SpacetimeMultivector::zero();
+    ///
+    /// assert!(zero.is_zero());
+    ///
+    /// let mv = SpacetimeMultivector::new(
+    ///     1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64,
+    ///     9_f64, 10_f64, 11_f64, 12_f64, 13_f64, 14_f64, 15_f64, 16_f64
+    /// );
+    ///
+    /// assert!(!mv.is_zero());
+    /// ```
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.data.iter().all(|component| component.is_zero())
+    }
+
+    /// Construct a new multivector from the scalar part only.
+    ///
+    /// A scalar is a multivector whose vector, bivector, etc. components are
+    /// all zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let scalar_part = 2;
+    /// let scalar = SpacetimeMultivector::from_scalar(scalar_part);
+    ///
+    /// assert_eq!(scalar.scalar, scalar_part);
+    /// assert_eq!(scalar.gamma0, 0);
+    /// assert_eq!(scalar.gamma1, 0);
+    /// assert_eq!(scalar.gamma2, 0);
+    /// assert_eq!(scalar.gamma3, 0);
+    /// assert_eq!(scalar.gamma01, 0);
+    /// assert_eq!(scalar.gamma02, 0);
+    /// assert_eq!(scalar.gamma03, 0);
+    /// assert_eq!(scalar.gamma12, 0);
+    /// assert_eq!(scalar.gamma13, 0);
+    /// assert_eq!(scalar.gamma23, 0);
+    /// assert_eq!(scalar.gamma012, 0);
+    /// assert_eq!(scalar.gamma013, 0);
+    /// assert_eq!(scalar.gamma023, 0);
+    /// assert_eq!(scalar.gamma123, 0);
+    /// assert_eq!(scalar.gamma0123, 0);
+    /// ```
+    #[inline]
+    pub fn from_scalar(scalar: S) -> Self {
+        let mut result = Self::zero();
+        result.data[0] = scalar;
+
+        result
+    }
+
+    /// Returns the unit scalar multivector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let unit_scalar: SpacetimeMultivector<isize> = SpacetimeMultivector::unit_scalar();
+    ///
+    /// assert_eq!(unit_scalar.scalar, 1);
+    /// assert_eq!(unit_scalar.gamma0, 0);
+    /// assert_eq!(unit_scalar.gamma1, 0);
+    /// assert_eq!(unit_scalar.gamma2, 0);
+    /// assert_eq!(unit_scalar.gamma3, 0);
+    /// assert_eq!(unit_scalar.gamma01, 0);
+    /// assert_eq!(unit_scalar.gamma02, 0);
+    /// assert_eq!(unit_scalar.gamma03, 0);
+    /// assert_eq!(unit_scalar.gamma12, 0);
+    /// assert_eq!(unit_scalar.gamma13, 0);
+    /// assert_eq!(unit_scalar.gamma23, 0);
+    /// assert_eq!(unit_scalar.gamma012, 0);
+    /// assert_eq!(unit_scalar.gamma013, 0);
+    /// assert_eq!(unit_scalar.gamma023, 0);
+    /// assert_eq!(unit_scalar.gamma123, 0);
+    /// assert_eq!(unit_scalar.gamma0123, 0);
+    /// ```
+    #[inline]
+    pub fn unit_scalar() -> Self {
+        let mut result = Self::zero();
+        result.data[0] = S::one();
+
+        result
+    }
+
+    /// Returns the unit timelike vector `gamma0`, the velocity of an observer at rest.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let unit_gamma0: SpacetimeMultivector<isize> = SpacetimeMultivector::unit_gamma0();
+    ///
+    /// assert_eq!(unit_gamma0.scalar, 0);
+    /// assert_eq!(unit_gamma0.gamma0, 1);
+    /// assert_eq!(unit_gamma0.gamma1, 0);
+    /// assert_eq!(unit_gamma0.gamma2, 0);
+    /// assert_eq!(unit_gamma0.gamma3, 0);
+    /// assert_eq!(unit_gamma0.gamma01, 0);
+    /// assert_eq!(unit_gamma0.gamma02, 0);
+    /// assert_eq!(unit_gamma0.gamma03, 0);
+    /// assert_eq!(unit_gamma0.gamma12, 0);
+    /// assert_eq!(unit_gamma0.gamma13, 0);
+    /// assert_eq!(unit_gamma0.gamma23, 0);
+    /// assert_eq!(unit_gamma0.gamma012, 0);
+    /// assert_eq!(unit_gamma0.gamma013, 0);
+    /// assert_eq!(unit_gamma0.gamma023, 0);
+    /// assert_eq!(unit_gamma0.gamma123, 0);
+    /// assert_eq!(unit_gamma0.gamma0123, 0);
+    /// ```
+    #[inline]
+    pub fn unit_gamma0() -> Self {
+        let mut result = Self::zero();
+        result.data[1] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `x`-axis vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let unit_gamma1: SpacetimeMultivector<isize> = SpacetimeMultivector::unit_gamma1();
+    ///
+    /// assert_eq!(unit_gamma1.scalar, 0);
+    /// assert_eq!(unit_gamma1.gamma0, 0);
+    /// assert_eq!(unit_gamma1.gamma1, 1);
+    /// assert_eq!(unit_gamma1.gamma2, 0);
+    /// assert_eq!(unit_gamma1.gamma3, 0);
+    /// assert_eq!(unit_gamma1.gamma01, 0);
+    /// assert_eq!(unit_gamma1.gamma02, 0);
+    /// assert_eq!(unit_gamma1.gamma03, 0);
+    /// assert_eq!(unit_gamma1.gamma12, 0);
+    /// assert_eq!(unit_gamma1.gamma13, 0);
+    /// assert_eq!(unit_gamma1.gamma23, 0);
+    /// assert_eq!(unit_gamma1.gamma012, 0);
+    /// assert_eq!(unit_gamma1.gamma013, 0);
+    /// assert_eq!(unit_gamma1.gamma023, 0);
+    /// assert_eq!(unit_gamma1.gamma123, 0);
+    /// assert_eq!(unit_gamma1.gamma0123, 0);
+    /// ```
+    #[inline]
+    pub fn unit_gamma1() -> Self {
+        let mut result = Self::zero();
+        result.data[2] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `y`-axis vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let unit_gamma2: SpacetimeMultivector<isize> = SpacetimeMultivector::unit_gamma2();
+    ///
+    /// assert_eq!(unit_gamma2.scalar, 0);
+    /// assert_eq!(unit_gamma2.gamma0, 0);
+    /// assert_eq!(unit_gamma2.gamma1, 0);
+    /// assert_eq!(unit_gamma2.gamma2, 1);
+    /// assert_eq!(unit_gamma2.gamma3, 0);
+    /// assert_eq!(unit_gamma2.gamma01, 0);
+    /// assert_eq!(unit_gamma2.gamma02, 0);
+    /// assert_eq!(unit_gamma2.gamma03, 0);
+    /// assert_eq!(unit_gamma2.gamma12, 0);
+    /// assert_eq!(unit_gamma2.gamma13, 0);
+    /// assert_eq!(unit_gamma2.gamma23, 0);
+    /// assert_eq!(unit_gamma2.gamma012, 0);
+    /// assert_eq!(unit_gamma2.gamma013, 0);
+    /// assert_eq!(unit_gamma2.gamma023, 0);
+    /// assert_eq!(unit_gamma2.gamma123, 0);
+    /// assert_eq!(unit_gamma2.gamma0123, 0);
+    /// ```
+    #[inline]
+    pub fn unit_gamma2() -> Self {
+        let mut result = Self::zero();
+        result.data[3] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `z`-axis vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let unit_gamma3: SpacetimeMultivector<isize> = SpacetimeMultivector::unit_gamma3();
+    ///
+    /// assert_eq!(unit_gamma3.scalar, 0);
+    /// assert_eq!(unit_gamma3.gamma0, 0);
+    /// assert_eq!(unit_gamma3.gamma1, 0);
+    /// assert_eq!(unit_gamma3.gamma2, 0);
+    /// assert_eq!(unit_gamma3.gamma3, 1);
+    /// assert_eq!(unit_gamma3.gamma01, 0);
+    /// assert_eq!(unit_gamma3.gamma02, 0);
+    /// assert_eq!(unit_gamma3.gamma03, 0);
+    /// assert_eq!(unit_gamma3.gamma12, 0);
+    /// assert_eq!(unit_gamma3.gamma13, 0);
+    /// assert_eq!(unit_gamma3.gamma23, 0);
+    /// assert_eq!(unit_gamma3.gamma012, 0);
+    /// assert_eq!(unit_gamma3.gamma013, 0);
+    /// assert_eq!(unit_gamma3.gamma023, 0);
+    /// assert_eq!(unit_gamma3.gamma123, 0);
+    /// assert_eq!(unit_gamma3.gamma0123, 0);
+    /// ```
+    #[inline]
+    pub fn unit_gamma3() -> Self {
+        let mut result = Self::zero();
+        result.data[4] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `tx`-plane bivector for spacetime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let unit_gamma01: SpacetimeMultivector<isize> = SpacetimeMultivector::unit_gamma01();
+    ///
+    /// assert_eq!(unit_gamma01.scalar, 0);
+    /// assert_eq!(unit_gamma01.gamma0, 0);
+    /// assert_eq!(unit_gamma01.gamma1, 0);
+    /// assert_eq!(unit_gamma01.gamma2, 0);
+    /// assert_eq!(unit_gamma01.gamma3, 0);
+    /// assert_eq!(unit_gamma01.gamma01, 1);
+    /// assert_eq!(unit_gamma01.gamma02, 0);
+    /// assert_eq!(unit_gamma01.gamma03, 0);
+    /// assert_eq!(unit_gamma01.gamma12, 0);
+    /// assert_eq!(unit_gamma01.gamma13, 0);
+    /// assert_eq!(unit_gamma01.gamma23, 0);
+    /// assert_eq!(unit_gamma01.gamma012, 0);
+    /// assert_eq!(unit_gamma01.gamma013, 0);
+    /// assert_eq!(unit_gamma01.gamma023, 0);
+    /// assert_eq!(unit_gamma01.gamma123, 0);
+    /// assert_eq!(unit_gamma01.gamma0123, 0);
+    /// ```
+    #[inline]
+    pub fn unit_gamma01() -> Self {
+        let mut result = Self::zero();
+        result.data[5] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `ty`-plane bivector for spacetime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let unit_gamma02: SpacetimeMultivector<isize> = SpacetimeMultivector::unit_gamma02();
+    ///
+    /// assert_eq!(unit_gamma02.scalar, 0);
+    /// assert_eq!(unit_gamma02.gamma0, 0);
+    /// assert_eq!(unit_gamma02.gamma1, 0);
+    /// assert_eq!(unit_gamma02.gamma2, 0);
+    /// assert_eq!(unit_gamma02.gamma3, 0);
+    /// assert_eq!(unit_gamma02.gamma01, 0);
+    /// assert_eq!(unit_gamma02.gamma02, 1);
+    /// assert_eq!(unit_gamma02.gamma03, 0);
+    /// assert_eq!(unit_gamma02.gamma12, 0);
+    /// assert_eq!(unit_gamma02.gamma13, 0);
+    /// assert_eq!(unit_gamma02.gamma23, 0);
+    /// assert_eq!(unit_gamma02.gamma012, 0);
+    /// assert_eq!(unit_gamma02.gamma013, 0);
+    /// assert_eq!(unit_gamma02.gamma023, 0);
+    /// assert_eq!(unit_gamma02.gamma123, 0);
+    /// assert_eq!(unit_gamma02.gamma0123, 0);
+    /// ```
+    #[inline]
+    pub fn unit_gamma02() -> Self {
+        let mut result = Self::zero();
+        result.data[6] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `tz`-plane bivector for spacetime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let unit_gamma03: SpacetimeMultivector<isize> = SpacetimeMultivector::unit_gamma03();
+    ///
+    /// assert_eq!(unit_gamma03.scalar, 0);
+    /// assert_eq!(unit_gamma03.gamma0, 0);
+    /// assert_eq!(unit_gamma03.gamma1, 0);
+    /// assert_eq!(unit_gamma03.gamma2, 0);
+    /// assert_eq!(unit_gamma03.gamma3, 0);
+    /// assert_eq!(unit_gamma03.gamma01, 0);
+    /// assert_eq!(unit_gamma03.gamma02, 0);
+    /// assert_eq!(unit_gamma03.gamma03, 1);
+    /// assert_eq!(unit_gamma03.gamma12, 0);
+    /// assert_eq!(unit_gamma03.gamma13, 0);
+    /// assert_eq!(unit_gamma03.gamma23, 0);
+    /// assert_eq!(unit_gamma03.gamma012, 0);
+    /// assert_eq!(unit_gamma03.gamma013, 0);
+    /// assert_eq!(unit_gamma03.gamma023, 0);
+    /// assert_eq!(unit_gamma03.gamma123, 0);
+    /// assert_eq!(unit_gamma03.gamma0123, 0);
+    /// ```
+    #[inline]
+    pub fn unit_gamma03() -> Self {
+        let mut result = Self::zero();
+        result.data[7] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `xy`-plane bivector for spacetime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let unit_gamma12: SpacetimeMultivector<isize> = SpacetimeMultivector::unit_gamma12();
+    ///
+    /// assert_eq!(unit_gamma12.scalar, 0);
+    /// assert_eq!(unit_gamma12.gamma0, 0);
+    /// assert_eq!(unit_gamma12.gamma1, 0);
+    /// assert_eq!(unit_gamma12.gamma2, 0);
+    /// assert_eq!(unit_gamma12.gamma3, 0);
+    /// assert_eq!(unit_gamma12.gamma01, 0);
+    /// assert_eq!(unit_gamma12.gamma02, 0);
+    /// assert_eq!(unit_gamma12.gamma03, 0);
+    /// assert_eq!(unit_gamma12.gamma12, 1);
+    /// assert_eq!(unit_gamma12.gamma13, 0);
+    /// assert_eq!(unit_gamma12.gamma23, 0);
+    /// assert_eq!(unit_gamma12.gamma012, 0);
+    /// assert_eq!(unit_gamma12.gamma013, 0);
+    /// assert_eq!(unit_gamma12.gamma023, 0);
+    /// assert_eq!(unit_gamma12.gamma123, 0);
+    /// assert_eq!(unit_gamma12.gamma0123, 0);
+    /// ```
+    #[inline]
+    pub fn unit_gamma12() -> Self {
+        let mut result = Self::zero();
+        result.data[8] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `xz`-plane bivector for spacetime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let unit_gamma13: SpacetimeMultivector<isize> = SpacetimeMultivector::unit_gamma13();
+    ///
+    /// assert_eq!(unit_gamma13.scalar, 0);
+    /// assert_eq!(unit_gamma13.gamma0, 0);
+    /// assert_eq!(unit_gamma13.gamma1, 0);
+    /// assert_eq!(unit_gamma13.gamma2, 0);
+    /// assert_eq!(unit_gamma13.gamma3, 0);
+    /// assert_eq!(unit_gamma13.gamma01, 0);
+    /// assert_eq!(unit_gamma13.gamma02, 0);
+    /// assert_eq!(unit_gamma13.gamma03, 0);
+    /// assert_eq!(unit_gamma13.gamma12, 0);
+    /// assert_eq!(unit_gamma13.gamma13, 1);
+    /// assert_eq!(unit_gamma13.gamma23, 0);
+    /// assert_eq!(unit_gamma13.gamma012, 0);
+    /// assert_eq!(unit_gamma13.gamma013, 0);
+    /// assert_eq!(unit_gamma13.gamma023, 0);
+    /// assert_eq!(unit_gamma13.gamma123, 0);
+    /// assert_eq!(unit_gamma13.gamma0123, 0);
+    /// ```
+    #[inline]
+    pub fn unit_gamma13() -> Self {
+        let mut result = Self::zero();
+        result.data[9] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `yz`-plane bivector for spacetime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let unit_gamma23: SpacetimeMultivector<isize> = SpacetimeMultivector::unit_gamma23();
+    ///
+    /// assert_eq!(unit_gamma23.scalar, 0);
+    /// assert_eq!(unit_gamma23.gamma0, 0);
+    /// assert_eq!(unit_gamma23.gamma1, 0);
+    /// assert_eq!(unit_gamma23.gamma2, 0);
+    /// assert_eq!(unit_gamma23.gamma3, 0);
+    /// assert_eq!(unit_gamma23.gamma01, 0);
+    /// assert_eq!(unit_gamma23.gamma02, 0);
+    /// assert_eq!(unit_gamma23.gamma03, 0);
+    /// assert_eq!(unit_gamma23.gamma12, 0);
+    /// assert_eq!(unit_gamma23.gamma13, 0);
+    /// assert_eq!(unit_gamma23.gamma23, 1);
+    /// assert_eq!(unit_gamma23.gamma012, 0);
+    /// assert_eq!(unit_gamma23.gamma013, 0);
+    /// assert_eq!(unit_gamma23.gamma023, 0);
+    /// assert_eq!(unit_gamma23.gamma123, 0);
+    /// assert_eq!(unit_gamma23.gamma0123, 0);
+    /// ```
+    #[inline]
+    pub fn unit_gamma23() -> Self {
+        let mut result = Self::zero();
+        result.data[10] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `txy`-volume trivector for spacetime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let unit_gamma012: SpacetimeMultivector<isize> = SpacetimeMultivector::unit_gamma012();
+    ///
+    /// assert_eq!(unit_gamma012.scalar, 0);
+    /// assert_eq!(unit_gamma012.gamma0, 0);
+    /// assert_eq!(unit_gamma012.gamma1, 0);
+    /// assert_eq!(unit_gamma012.gamma2, 0);
+    /// assert_eq!(unit_gamma012.gamma3, 0);
+    /// assert_eq!(unit_gamma012.gamma01, 0);
+    /// assert_eq!(unit_gamma012.gamma02, 0);
+    /// assert_eq!(unit_gamma012.gamma03, 0);
+    /// assert_eq!(unit_gamma012.gamma12, 0);
+    /// assert_eq!(unit_gamma012.gamma13, 0);
+    /// assert_eq!(unit_gamma012.gamma23, 0);
+    /// assert_eq!(unit_gamma012.gamma012, 1);
+    /// assert_eq!(unit_gamma012.gamma013, 0);
+    /// assert_eq!(unit_gamma012.gamma023, 0);
+    /// assert_eq!(unit_gamma012.gamma123, 0);
+    /// assert_eq!(unit_gamma012.gamma0123, 0);
+    /// ```
+    #[inline]
+    pub fn unit_gamma012() -> Self {
+        let mut result = Self::zero();
+        result.data[11] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `txz`-volume trivector for spacetime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let unit_gamma013: SpacetimeMultivector<isize> = SpacetimeMultivector::unit_gamma013();
+    ///
+    /// assert_eq!(unit_gamma013.scalar, 0);
+    /// assert_eq!(unit_gamma013.gamma0, 0);
+    /// assert_eq!(unit_gamma013.gamma1, 0);
+    /// assert_eq!(unit_gamma013.gamma2, 0);
+    /// assert_eq!(unit_gamma013.gamma3, 0);
+    /// assert_eq!(unit_gamma013.gamma01, 0);
+    /// assert_eq!(unit_gamma013.gamma02, 0);
+    /// assert_eq!(unit_gamma013.gamma03, 0);
+    /// assert_eq!(unit_gamma013.gamma12, 0);
+    /// assert_eq!(unit_gamma013.gamma13, 0);
+    /// assert_eq!(unit_gamma013.gamma23, 0);
+    /// assert_eq!(unit_gamma013.gamma012, 0);
+    /// assert_eq!(unit_gamma013.gamma013, 1);
+    /// assert_eq!(unit_gamma013.gamma023, 0);
+    /// assert_eq!(unit_gamma013.gamma123, 0);
+    /// assert_eq!(unit_gamma013.gamma0123, 0);
+    /// ```
+    #[inline]
+    pub fn unit_gamma013() -> Self {
+        let mut result = Self::zero();
+        result.data[12] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `tyz`-volume trivector for spacetime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let unit_gamma023: SpacetimeMultivector<isize> = SpacetimeMultivector::unit_gamma023();
+    ///
+    /// assert_eq!(unit_gamma023.scalar, 0);
+    /// assert_eq!(unit_gamma023.gamma0, 0);
+    /// assert_eq!(unit_gamma023.gamma1, 0);
+    /// assert_eq!(unit_gamma023.gamma2, 0);
+    /// assert_eq!(unit_gamma023.gamma3, 0);
+    /// assert_eq!(unit_gamma023.gamma01, 0);
+    /// assert_eq!(unit_gamma023.gamma02, 0);
+    /// assert_eq!(unit_gamma023.gamma03, 0);
+    /// assert_eq!(unit_gamma023.gamma12, 0);
+    /// assert_eq!(unit_gamma023.gamma13, 0);
+    /// assert_eq!(unit_gamma023.gamma23, 0);
+    /// assert_eq!(unit_gamma023.gamma012, 0);
+    /// assert_eq!(unit_gamma023.gamma013, 0);
+    /// assert_eq!(unit_gamma023.gamma023, 1);
+    /// assert_eq!(unit_gamma023.gamma123, 0);
+    /// assert_eq!(unit_gamma023.gamma0123, 0);
+    /// ```
+    #[inline]
+    pub fn unit_gamma023() -> Self {
+        let mut result = Self::zero();
+        result.data[13] = S::one();
+
+        result
+    }
+
+    /// Returns the unit `xyz`-volume trivector for spacetime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let unit_gamma123: SpacetimeMultivector<isize> = SpacetimeMultivector::unit_gamma123();
+    ///
+    /// assert_eq!(unit_gamma123.scalar, 0);
+    /// assert_eq!(unit_gamma123.gamma0, 0);
+    /// assert_eq!(unit_gamma123.gamma1, 0);
+    /// assert_eq!(unit_gamma123.gamma2, 0);
+    /// assert_eq!(unit_gamma123.gamma3, 0);
+    /// assert_eq!(unit_gamma123.gamma01, 0);
+    /// assert_eq!(unit_gamma123.gamma02, 0);
+    /// assert_eq!(unit_gamma123.gamma03, 0);
+    /// assert_eq!(unit_gamma123.gamma12, 0);
+    /// assert_eq!(unit_gamma123.gamma13, 0);
+    /// assert_eq!(unit_gamma123.gamma23, 0);
+    /// assert_eq!(unit_gamma123.gamma012, 0);
+    /// assert_eq!(unit_gamma123.gamma013, 0);
+    /// assert_eq!(unit_gamma123.gamma023, 0);
+    /// assert_eq!(unit_gamma123.gamma123, 1);
+    /// assert_eq!(unit_gamma123.gamma0123, 0);
+    /// ```
+    #[inline]
+    pub fn unit_gamma123() -> Self {
+        let mut result = Self::zero();
+        result.data[14] = S::one();
+
+        result
+    }
+
+    /// Returns the unit volume element for spacetime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let unit_gamma0123: SpacetimeMultivector<isize> = SpacetimeMultivector::unit_gamma0123();
+    ///
+    /// assert_eq!(unit_gamma0123.scalar, 0);
+    /// assert_eq!(unit_gamma0123.gamma0, 0);
+    /// assert_eq!(unit_gamma0123.gamma1, 0);
+    /// assert_eq!(unit_gamma0123.gamma2, 0);
+    /// assert_eq!(unit_gamma0123.gamma3, 0);
+    /// assert_eq!(unit_gamma0123.gamma01, 0);
+    /// assert_eq!(unit_gamma0123.gamma02, 0);
+    /// assert_eq!(unit_gamma0123.gamma03, 0);
+    /// assert_eq!(unit_gamma0123.gamma12, 0);
+    /// assert_eq!(unit_gamma0123.gamma13, 0);
+    /// assert_eq!(unit_gamma0123.gamma23, 0);
+    /// assert_eq!(unit_gamma0123.gamma012, 0);
+    /// assert_eq!(unit_gamma0123.gamma013, 0);
+    /// assert_eq!(unit_gamma0123.gamma023, 0);
+    /// assert_eq!(unit_gamma0123.gamma123, 0);
+    /// assert_eq!(unit_gamma0123.gamma0123, 1);
+    /// ```
+    #[inline]
+    pub fn unit_gamma0123() -> Self {
+        let mut result = Self::zero();
+        result.data[15] = S::one();
+
+        result
+    }
+
+    /// Returns the unit volume element for spacetime.
+    ///
+    /// This is a synonym for `unit_gamma0123`.
+    #[inline(always)]
+    pub fn pseudoscalar() -> Self {
+        Self::unit_gamma0123()
+    }
+
+    /// Project the multivector onto the grade `grade`.
+    ///
+    /// Return a multivector where the components of each grade other than
+    /// input grade are zero. For each grade larger than the dimension of the
+    /// underlying vector space, the grade projection is always zero. In this
+    /// case, any grade projection onto a grade larger than 4 will be zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let mv: SpacetimeMultivector<isize> = SpacetimeMultivector::new(1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize);
+    /// let expected_0 = SpacetimeMultivector::new(1_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize);
+    /// let mv_0 = mv.grade(0);
+    /// let expected_1 = SpacetimeMultivector::new(0_isize, 1_isize, 1_isize, 1_isize, 1_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize);
+    /// let mv_1 = mv.grade(1);
+    /// let expected_2 = SpacetimeMultivector::new(0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 1_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize);
+    /// let mv_2 = mv.grade(2);
+    /// let expected_3 = SpacetimeMultivector::new(0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 1_isize, 1_isize, 1_isize, 1_isize, 0_isize);
+    /// let mv_3 = mv.grade(3);
+    /// let expected_4 = SpacetimeMultivector::new(0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 0_isize, 1_isize);
+    /// let mv_4 = mv.grade(4);
+    ///
+    /// assert_eq!(mv_0, expected_0);
+    /// assert_eq!(mv_1, expected_1);
+    /// assert_eq!(mv_2, expected_2);
+    /// assert_eq!(mv_3, expected_3);
+    /// assert_eq!(mv_4, expected_4);
+    ///
+    /// // Any grade larger than 4 should be zero.
+    /// let zero: SpacetimeMultivector<isize> = SpacetimeMultivector::zero();
+    /// assert_eq!(mv.grade(5), zero);
+    /// assert_eq!(mv.grade(usize::MAX), zero);
+    /// ```
+    #[inline]
+    pub fn grade(&self, grade: usize) -> Self {
+        let mut result = Self::zero();
+        match grade {
+            0 => {
+                result.data[0] = self.data[0];
+            }
+            1 => {
+                result.data[1] = self.data[1];
+                result.data[2] = self.data[2];
+                result.data[3] = self.data[3];
+                result.data[4] = self.data[4];
+            }
+            2 => {
+                result.data[5] = self.data[5];
+                result.data[6] = self.data[6];
+                result.data[7] = self.data[7];
+                result.data[8] = self.data[8];
+                result.data[9] = self.data[9];
+                result.data[10] = self.data[10];
+            }
+            3 => {
+                result.data[11] = self.data[11];
+                result.data[12] = self.data[12];
+                result.data[13] = self.data[13];
+                result.data[14] = self.data[14];
+            }
+            4 => {
+                result.data[15] = self.data[15];
+            }
+            _ => {}
+        }
+
+        result
+    }
+
+    /// Compute the left contraction of `self` with `other`.
+    ///
+    /// This is a synonym for the `<<` operator.
+    #[inline]
+    pub fn left_contract(&self, other: &Self) -> Self {
+        self << other
+    }
+
+    /// Compute the right contraction of `self` with `other`.
+    ///
+    /// This is a synonym for the `>>` operator.
+    #[inline]
+    pub fn right_contract(&self, other: &Self) -> Self {
+        self >> other
+    }
+
+    /// Compute the scalar product of `self` and `other`.
+    ///
+    /// This is a synonym for the `|` operator.
+    #[inline]
+    pub fn scalar_product(&self, other: &Self) -> Self {
+        self | other
+    }
+
+    /// Compute the outer product of `self` and `other`.
+    ///
+    /// This is a synonym for the `^` operator.
+    #[inline]
+    pub fn outer_product(&self, other: &Self) -> Self {
+        self ^ other
+    }
+
+    /// Compute the regressive product of `self` and `other`.
+    ///
+    /// The regressive product is the outer product of the duals, mapped back
+    /// by the inverse of the duality map
+    /// ```text
+    /// mv1 & mv2 := undual(dual(mv1) ^ dual(mv2))
+    /// ```
+    /// where `undual(mv) := mv * gamma0123`. For blades whose subspaces together
+    /// span the whole of spacetime, the regressive product computes the
+    /// intersection of the subspaces.
+    ///
+    /// This is a synonym for the `&` operator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let gamma012: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma012();
+    /// let gamma123: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma123();
+    /// let result = gamma012.regressive_product(&gamma123);
+    ///
+    /// assert_eq!(result, (gamma012.dual() ^ gamma123.dual()).undual());
+    /// assert_eq!(result, -SpacetimeMultivector::unit_gamma12());
+    /// ```
+    #[inline]
+    pub fn regressive_product(&self, other: &Self) -> Self {
+        self & other
+    }
+}
+
+impl<S> ops::Index<usize> for SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = S;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.data[index]
+    }
+}
+
+impl<S> ops::IndexMut<usize> for SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.data[index]
+    }
+}
+
+impl<S> AsRef<[S; 16]> for SpacetimeMultivector<S> {
+    #[inline]
+    fn as_ref(&self) -> &[S; 16] {
+        unsafe { &*(self as *const SpacetimeMultivector<S> as *const [S; 16]) }
+    }
+}
+
+impl<S> AsMut<[S; 16]> for SpacetimeMultivector<S> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [S; 16] {
+        unsafe { &mut *(self as *mut SpacetimeMultivector<S> as *mut [S; 16]) }
+    }
+}
+
+impl<S> fmt::Display for SpacetimeMultivector<S>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "{} + {}^gamma0 + {}^gamma1 + {}^gamma2 + {}^gamma3 + {}^gamma01 + {}^gamma02 + {}^gamma03 + {}^gamma12 + {}^gamma13 + {}^gamma23 + {}^gamma012 + {}^gamma013 + {}^gamma023 + {}^gamma123 + {}^gamma0123",
+            self.data[0],
+            self.data[1],
+            self.data[2],
+            self.data[3],
+            self.data[4],
+            self.data[5],
+            self.data[6],
+            self.data[7],
+            self.data[8],
+            self.data[9],
+            self.data[10],
+            self.data[11],
+            self.data[12],
+            self.data[13],
+            self.data[14],
+            self.data[15],
+        )
+    }
+}
+
+
+impl<S> SpacetimeMultivector<S>
+where
+    S: ScalarSigned,
+{
+    /// Compute the reverse of a multivector.
+    ///
+    /// The reverse of a multivector reverses the order of the vectors in each
+    /// basis blade. For each grade of multivector it is given by
+    /// ```text
+    /// When mv has grade 0, rev(mv) := mv
+    /// When mv has grade 1, rev(mv) := mv
+    /// When mv has grade 2, rev(mv) := -mv
+    /// When mv has grade 3, rev(mv) := -mv
+    /// When mv has grade 4, rev(mv) := mv
+    /// ```
+    /// The reverse of each basis blade is
+    /// ```text
+    /// rev(1)         = 1
+    /// rev(gamma0)    = gamma0
+    /// rev(gamma1)    = gamma1
+    /// rev(gamma2)    = gamma2
+    /// rev(gamma3)    = gamma3
+    /// rev(gamma01)   = -gamma01
+    /// rev(gamma02)   = -gamma02
+    /// rev(gamma03)   = -gamma03
+    /// rev(gamma12)   = -gamma12
+    /// rev(gamma13)   = -gamma13
+    /// rev(gamma23)   = -gamma23
+    /// rev(gamma012)  = -gamma012
+    /// rev(gamma013)  = -gamma013
+    /// rev(gamma023)  = -gamma023
+    /// rev(gamma123)  = -gamma123
+    /// rev(gamma0123) = gamma0123
+    /// ```
+    /// and the reverse of a general multivector follows by linearity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let mv = SpacetimeMultivector::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = SpacetimeMultivector::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, -6_i32, -7_i32, -8_i32,
+    ///     -9_i32, -10_i32, -11_i32, -12_i32, -13_i32, -14_i32, -15_i32, 16_i32
+    /// );
+    /// let result = mv.reverse();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn reverse(&self) -> Self {
+        let mut result = *self;
+        result.reverse_mut();
+
+        result
+    }
+
+    /// Compute the reverse of a multivector mutably in place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let mut result = SpacetimeMultivector::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = SpacetimeMultivector::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, -6_i32, -7_i32, -8_i32,
+    ///     -9_i32, -10_i32, -11_i32, -12_i32, -13_i32, -14_i32, -15_i32, 16_i32
+    /// );
+    /// result.reverse_mut();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn reverse_mut(&mut self) {
+        self.data[5] = -self.data[5];
+        self.data[6] = -self.data[6];
+        self.data[7] = -self.data[7];
+        self.data[8] = -self.data[8];
+        self.data[9] = -self.data[9];
+        self.data[10] = -self.data[10];
+        self.data[11] = -self.data[11];
+        self.data[12] = -self.data[12];
+        self.data[13] = -self.data[13];
+        self.data[14] = -self.data[14];
+    }
+
+    /// Compute the conjugate of a multivector.
+    ///
+    /// The conjugate of a multivector is the composition of the reverse and the
+    /// grade involution. For each grade of multivector it is given by
+    /// ```text
+    /// When mv has grade 0, conj(mv) := mv
+    /// When mv has grade 1, conj(mv) := -mv
+    /// When mv has grade 2, conj(mv) := -mv
+    /// When mv has grade 3, conj(mv) := mv
+    /// When mv has grade 4, conj(mv) := mv
+    /// ```
+    /// The conjugate of each basis blade is
+    /// ```text
+    /// conj(1)         = 1
+    /// conj(gamma0)    = -gamma0
+    /// conj(gamma1)    = -gamma1
+    /// conj(gamma2)    = -gamma2
+    /// conj(gamma3)    = -gamma3
+    /// conj(gamma01)   = -gamma01
+    /// conj(gamma02)   = -gamma02
+    /// conj(gamma03)   = -gamma03
+    /// conj(gamma12)   = -gamma12
+    /// conj(gamma13)   = -gamma13
+    /// conj(gamma23)   = -gamma23
+    /// conj(gamma012)  = gamma012
+    /// conj(gamma013)  = gamma013
+    /// conj(gamma023)  = gamma023
+    /// conj(gamma123)  = gamma123
+    /// conj(gamma0123) = gamma0123
+    /// ```
+    /// and the conjugate of a general multivector follows by linearity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let mv = SpacetimeMultivector::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = SpacetimeMultivector::new(
+    ///     1_i32, -2_i32, -3_i32, -4_i32, -5_i32, -6_i32, -7_i32, -8_i32,
+    ///     -9_i32, -10_i32, -11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let result = mv.conjugate();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn conjugate(&self) -> Self {
+        let mut result = *self;
+        result.conjugate_mut();
+
+        result
+    }
+
+    /// Compute the conjugate of a multivector mutably in place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let mut result = SpacetimeMultivector::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = SpacetimeMultivector::new(
+    ///     1_i32, -2_i32, -3_i32, -4_i32, -5_i32, -6_i32, -7_i32, -8_i32,
+    ///     -9_i32, -10_i32, -11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// result.conjugate_mut();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn conjugate_mut(&mut self) {
+        self.data[1] = -self.data[1];
+        self.data[2] = -self.data[2];
+        self.data[3] = -self.data[3];
+        self.data[4] = -self.data[4];
+        self.data[5] = -self.data[5];
+        self.data[6] = -self.data[6];
+        self.data[7] = -self.data[7];
+        self.data[8] = -self.data[8];
+        self.data[9] = -self.data[9];
+        self.data[10] = -self.data[10];
+    }
+
+    /// Compute the grade involution of a multivector.
+    ///
+    /// The grade involution of a multivector negates the components of odd grade.
+    /// For each grade of multivector it is given by
+    /// ```text
+    /// When mv has grade 0, invol(mv) := mv
+    /// When mv has grade 1, invol(mv) := -mv
+    /// When mv has grade 2, invol(mv) := mv
+    /// When mv has grade 3, invol(mv) := -mv
+    /// When mv has grade 4, invol(mv) := mv
+    /// ```
+    /// The grade involution of each basis blade is
+    /// ```text
+    /// invol(1)         = 1
+    /// invol(gamma0)    = -gamma0
+    /// invol(gamma1)    = -gamma1
+    /// invol(gamma2)    = -gamma2
+    /// invol(gamma3)    = -gamma3
+    /// invol(gamma01)   = gamma01
+    /// invol(gamma02)   = gamma02
+    /// invol(gamma03)   = gamma03
+    /// invol(gamma12)   = gamma12
+    /// invol(gamma13)   = gamma13
+    /// invol(gamma23)   = gamma23
+    /// invol(gamma012)  = -gamma012
+    /// invol(gamma013)  = -gamma013
+    /// invol(gamma023)  = -gamma023
+    /// invol(gamma123)  = -gamma123
+    /// invol(gamma0123) = gamma0123
+    /// ```
+    /// and the grade involution of a general multivector follows by linearity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let mv = SpacetimeMultivector::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = SpacetimeMultivector::new(
+    ///     1_i32, -2_i32, -3_i32, -4_i32, -5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, -12_i32, -13_i32, -14_i32, -15_i32, 16_i32
+    /// );
+    /// let result = mv.involute();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn involute(&self) -> Self {
+        let mut result = *self;
+        result.involute_mut();
+
+        result
+    }
+
+    /// Compute the grade involution of a multivector mutably in place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let mut result = SpacetimeMultivector::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = SpacetimeMultivector::new(
+    ///     1_i32, -2_i32, -3_i32, -4_i32, -5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, -12_i32, -13_i32, -14_i32, -15_i32, 16_i32
+    /// );
+    /// result.involute_mut();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn involute_mut(&mut self) {
+        self.data[1] = -self.data[1];
+        self.data[2] = -self.data[2];
+        self.data[3] = -self.data[3];
+        self.data[4] = -self.data[4];
+        self.data[11] = -self.data[11];
+        self.data[12] = -self.data[12];
+        self.data[13] = -self.data[13];
+        self.data[14] = -self.data[14];
+    }
+
+    /// Compute the dual of a multivector.
+    ///
+    /// The dual of a multivector is also known as the orthogonal complement.
+    /// The dual of a multivector `mv` is defined by
+    /// ```text
+    /// dual(mv) := mv * inv(gamma0123)
+    /// ```
+    /// where `inv(gamma0123) == -gamma0123`. The dual of a blade is a blade
+    /// representing the orthogonal complement of its subspace with respect to the
+    /// Minkowski metric.
+    ///
+    /// The dual of each basis blade is
+    /// ```text
+    /// dual(1)         = -gamma0123
+    /// dual(gamma0)    = -gamma123
+    /// dual(gamma1)    = -gamma023
+    /// dual(gamma2)    = gamma013
+    /// dual(gamma3)    = -gamma012
+    /// dual(gamma01)   = -gamma23
+    /// dual(gamma02)   = gamma13
+    /// dual(gamma03)   = -gamma12
+    /// dual(gamma12)   = gamma03
+    /// dual(gamma13)   = -gamma02
+    /// dual(gamma23)   = gamma01
+    /// dual(gamma012)  = gamma3
+    /// dual(gamma013)  = -gamma2
+    /// dual(gamma023)  = gamma1
+    /// dual(gamma123)  = gamma0
+    /// dual(gamma0123) = 1
+    /// ```
+    /// and the dual of a general multivector follows by linearity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let mv = SpacetimeMultivector::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = SpacetimeMultivector::new(
+    ///     16_i32, 15_i32, 14_i32, -13_i32, 12_i32, 11_i32, -10_i32, 9_i32,
+    ///     -8_i32, 7_i32, -6_i32, -5_i32, 4_i32, -3_i32, -2_i32, -1_i32
+    /// );
+    /// let result = mv.dual();
+    ///
+    /// assert_eq!(result, expected);
+    /// assert_eq!(result.undual(), mv);
+    /// ```
+    #[rustfmt::skip]
+    pub fn dual(&self) -> Self {
+        let mut result = Self::zero();
+        result.data[0]  =  self.data[15];
+        result.data[1]  =  self.data[14];
+        result.data[2]  =  self.data[13];
+        result.data[3]  = -self.data[12];
+        result.data[4]  =  self.data[11];
+        result.data[5]  =  self.data[10];
+        result.data[6]  = -self.data[9];
+        result.data[7]  =  self.data[8];
+        result.data[8]  = -self.data[7];
+        result.data[9]  =  self.data[6];
+        result.data[10] = -self.data[5];
+        result.data[11] = -self.data[4];
+        result.data[12] =  self.data[3];
+        result.data[13] = -self.data[2];
+        result.data[14] = -self.data[1];
+        result.data[15] = -self.data[0];
+
+        result
+    }
+
+    /// Compute the dual of a multivector mutably in place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let mut result = SpacetimeMultivector::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    /// let expected = SpacetimeMultivector::new(
+    ///     16_i32, 15_i32, 14_i32, -13_i32, 12_i32, 11_i32, -10_i32, 9_i32,
+    ///     -8_i32, 7_i32, -6_i32, -5_i32, 4_i32, -3_i32, -2_i32, -1_i32
+    /// );
+    /// result.dual_mut();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn dual_mut(&mut self) {
+        *self = self.dual();
+    }
+
+    /// Compute the undual of a multivector.
+    ///
+    /// The undual is the inverse of the dual, i.e. `undual(dual(mv)) == mv`
+    /// and `dual(undual(mv)) == mv` for every multivector `mv`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let mv = SpacetimeMultivector::new(
+    ///     1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32,
+    ///     9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32
+    /// );
+    ///
+    /// assert_eq!(mv.dual().undual(), mv);
+    /// assert_eq!(mv.undual().dual(), mv);
+    /// ```
+    #[rustfmt::skip]
+    pub fn undual(&self) -> Self {
+        let mut result = Self::zero();
+        result.data[0]  = -self.data[15];
+        result.data[1]  = -self.data[14];
+        result.data[2]  = -self.data[13];
+        result.data[3]  =  self.data[12];
+        result.data[4]  = -self.data[11];
+        result.data[5]  = -self.data[10];
+        result.data[6]  =  self.data[9];
+        result.data[7]  = -self.data[8];
+        result.data[8]  =  self.data[7];
+        result.data[9]  = -self.data[6];
+        result.data[10] =  self.data[5];
+        result.data[11] =  self.data[4];
+        result.data[12] = -self.data[3];
+        result.data[13] =  self.data[2];
+        result.data[14] =  self.data[1];
+        result.data[15] =  self.data[0];
+
+        result
+    }
+
+    /// Construct the inverse pseudoscalar of the geometric algebra.
+    ///
+    /// In the case of the spacetime algebra, the inverse of the pseudoscalar
+    /// is the four-blade `inv(gamma0123) = -gamma0123`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let ps: SpacetimeMultivector<f64> = SpacetimeMultivector::pseudoscalar();
+    /// let ps_inv: SpacetimeMultivector<f64> = SpacetimeMultivector::inv_pseudoscalar();
+    /// let one: SpacetimeMultivector<f64> = SpacetimeMultivector::unit_scalar();
+    ///
+    /// assert_eq!(ps * ps_inv, one);
+    /// assert_eq!(ps_inv * ps, one);
+    /// ```
+    #[inline]
+    pub fn inv_pseudoscalar() -> Self {
+        -Self::unit_gamma0123()
+    }
+}
+
+impl<S> ops::Not for SpacetimeMultivector<S>
+where
+    S: ScalarSigned,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        self.dual()
+    }
+}
+
+impl<S> ops::Not for &SpacetimeMultivector<S>
+where
+    S: ScalarSigned,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        self.dual()
+    }
+}
+
+impl<S> ops::Neg for SpacetimeMultivector<S>
+where
+    S: ScalarSigned,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn neg(self) -> Self::Output {
+        let result_1         = -self.data[0];
+        let result_gamma0    = -self.data[1];
+        let result_gamma1    = -self.data[2];
+        let result_gamma2    = -self.data[3];
+        let result_gamma3    = -self.data[4];
+        let result_gamma01   = -self.data[5];
+        let result_gamma02   = -self.data[6];
+        let result_gamma03   = -self.data[7];
+        let result_gamma12   = -self.data[8];
+        let result_gamma13   = -self.data[9];
+        let result_gamma23   = -self.data[10];
+        let result_gamma012  = -self.data[11];
+        let result_gamma013  = -self.data[12];
+        let result_gamma023  = -self.data[13];
+        let result_gamma123  = -self.data[14];
+        let result_gamma0123 = -self.data[15];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::Neg for &SpacetimeMultivector<S>
+where
+    S: ScalarSigned,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn neg(self) -> Self::Output {
+        let result_1         = -self.data[0];
+        let result_gamma0    = -self.data[1];
+        let result_gamma1    = -self.data[2];
+        let result_gamma2    = -self.data[3];
+        let result_gamma3    = -self.data[4];
+        let result_gamma01   = -self.data[5];
+        let result_gamma02   = -self.data[6];
+        let result_gamma03   = -self.data[7];
+        let result_gamma12   = -self.data[8];
+        let result_gamma13   = -self.data[9];
+        let result_gamma23   = -self.data[10];
+        let result_gamma012  = -self.data[11];
+        let result_gamma013  = -self.data[12];
+        let result_gamma023  = -self.data[13];
+        let result_gamma123  = -self.data[14];
+        let result_gamma0123 = -self.data[15];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::Mul<SpacetimeMultivector<S>> for SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: SpacetimeMultivector<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1         = a[0] * b[0] + a[1] * b[1] - a[2] * b[2] - a[3] * b[3] - a[4] * b[4] + a[5] * b[5] + a[6] * b[6] + a[7] * b[7] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[12] - a[13] * b[13] + a[14] * b[14] - a[15] * b[15];
+        let result_gamma0    = a[0] * b[1] + a[1] * b[0] + a[2] * b[5] + a[3] * b[6] + a[4] * b[7] - a[5] * b[2] - a[6] * b[3] - a[7] * b[4] - a[8] * b[11] - a[9] * b[12] - a[10] * b[13] - a[11] * b[8] - a[12] * b[9] - a[13] * b[10] - a[14] * b[15] + a[15] * b[14];
+        let result_gamma1    = a[0] * b[2] + a[1] * b[5] + a[2] * b[0] + a[3] * b[8] + a[4] * b[9] - a[5] * b[1] - a[6] * b[11] - a[7] * b[12] - a[8] * b[3] - a[9] * b[4] - a[10] * b[14] - a[11] * b[6] - a[12] * b[7] - a[13] * b[15] - a[14] * b[10] + a[15] * b[13];
+        let result_gamma2    = a[0] * b[3] + a[1] * b[6] - a[2] * b[8] + a[3] * b[0] + a[4] * b[10] + a[5] * b[11] - a[6] * b[1] - a[7] * b[13] + a[8] * b[2] + a[9] * b[14] - a[10] * b[4] + a[11] * b[5] + a[12] * b[15] - a[13] * b[7] + a[14] * b[9] - a[15] * b[12];
+        let result_gamma3    = a[0] * b[4] + a[1] * b[7] - a[2] * b[9] - a[3] * b[10] + a[4] * b[0] + a[5] * b[12] + a[6] * b[13] - a[7] * b[1] - a[8] * b[14] + a[9] * b[2] + a[10] * b[3] - a[11] * b[15] + a[12] * b[5] + a[13] * b[6] - a[14] * b[8] + a[15] * b[11];
+        let result_gamma01   = a[0] * b[5] + a[1] * b[2] - a[2] * b[1] - a[3] * b[11] - a[4] * b[12] + a[5] * b[0] + a[6] * b[8] + a[7] * b[9] - a[8] * b[6] - a[9] * b[7] - a[10] * b[15] - a[11] * b[3] - a[12] * b[4] - a[13] * b[14] + a[14] * b[13] - a[15] * b[10];
+        let result_gamma02   = a[0] * b[6] + a[1] * b[3] + a[2] * b[11] - a[3] * b[1] - a[4] * b[13] - a[5] * b[8] + a[6] * b[0] + a[7] * b[10] + a[8] * b[5] + a[9] * b[15] - a[10] * b[7] + a[11] * b[2] + a[12] * b[14] - a[13] * b[4] - a[14] * b[12] + a[15] * b[9];
+        let result_gamma03   = a[0] * b[7] + a[1] * b[4] + a[2] * b[12] + a[3] * b[13] - a[4] * b[1] - a[5] * b[9] - a[6] * b[10] + a[7] * b[0] - a[8] * b[15] + a[9] * b[5] + a[10] * b[6] - a[11] * b[14] + a[12] * b[2] + a[13] * b[3] + a[14] * b[11] - a[15] * b[8];
+        let result_gamma12   = a[0] * b[8] + a[1] * b[11] + a[2] * b[3] - a[3] * b[2] - a[4] * b[14] - a[5] * b[6] + a[6] * b[5] + a[7] * b[15] + a[8] * b[0] + a[9] * b[10] - a[10] * b[9] + a[11] * b[1] + a[12] * b[13] - a[13] * b[12] - a[14] * b[4] + a[15] * b[7];
+        let result_gamma13   = a[0] * b[9] + a[1] * b[12] + a[2] * b[4] + a[3] * b[14] - a[4] * b[2] - a[5] * b[7] - a[6] * b[15] + a[7] * b[5] - a[8] * b[10] + a[9] * b[0] + a[10] * b[8] - a[11] * b[13] + a[12] * b[1] + a[13] * b[11] + a[14] * b[3] - a[15] * b[6];
+        let result_gamma23   = a[0] * b[10] + a[1] * b[13] - a[2] * b[14] + a[3] * b[4] - a[4] * b[3] + a[5] * b[15] - a[6] * b[7] + a[7] * b[6] + a[8] * b[9] - a[9] * b[8] + a[10] * b[0] + a[11] * b[12] - a[12] * b[11] + a[13] * b[1] - a[14] * b[2] + a[15] * b[5];
+        let result_gamma012  = a[0] * b[11] + a[1] * b[8] - a[2] * b[6] + a[3] * b[5] + a[4] * b[15] + a[5] * b[3] - a[6] * b[2] - a[7] * b[14] + a[8] * b[1] + a[9] * b[13] - a[10] * b[12] + a[11] * b[0] + a[12] * b[10] - a[13] * b[9] + a[14] * b[7] - a[15] * b[4];
+        let result_gamma013  = a[0] * b[12] + a[1] * b[9] - a[2] * b[7] - a[3] * b[15] + a[4] * b[5] + a[5] * b[4] + a[6] * b[14] - a[7] * b[2] - a[8] * b[13] + a[9] * b[1] + a[10] * b[11] - a[11] * b[10] + a[12] * b[0] + a[13] * b[8] - a[14] * b[6] + a[15] * b[3];
+        let result_gamma023  = a[0] * b[13] + a[1] * b[10] + a[2] * b[15] - a[3] * b[7] + a[4] * b[6] - a[5] * b[14] + a[6] * b[4] - a[7] * b[3] + a[8] * b[12] - a[9] * b[11] + a[10] * b[1] + a[11] * b[9] - a[12] * b[8] + a[13] * b[0] + a[14] * b[5] - a[15] * b[2];
+        let result_gamma123  = a[0] * b[14] + a[1] * b[15] + a[2] * b[10] - a[3] * b[9] + a[4] * b[8] - a[5] * b[13] + a[6] * b[12] - a[7] * b[11] + a[8] * b[4] - a[9] * b[3] + a[10] * b[2] + a[11] * b[7] - a[12] * b[6] + a[13] * b[5] + a[14] * b[0] - a[15] * b[1];
+        let result_gamma0123 = a[0] * b[15] + a[1] * b[14] - a[2] * b[13] + a[3] * b[12] - a[4] * b[11] + a[5] * b[10] - a[6] * b[9] + a[7] * b[8] + a[8] * b[7] - a[9] * b[6] + a[10] * b[5] + a[11] * b[4] - a[12] * b[3] + a[13] * b[2] - a[14] * b[1] + a[15] * b[0];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::Mul<&SpacetimeMultivector<S>> for SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: &SpacetimeMultivector<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1         = a[0] * b[0] + a[1] * b[1] - a[2] * b[2] - a[3] * b[3] - a[4] * b[4] + a[5] * b[5] + a[6] * b[6] + a[7] * b[7] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[12] - a[13] * b[13] + a[14] * b[14] - a[15] * b[15];
+        let result_gamma0    = a[0] * b[1] + a[1] * b[0] + a[2] * b[5] + a[3] * b[6] + a[4] * b[7] - a[5] * b[2] - a[6] * b[3] - a[7] * b[4] - a[8] * b[11] - a[9] * b[12] - a[10] * b[13] - a[11] * b[8] - a[12] * b[9] - a[13] * b[10] - a[14] * b[15] + a[15] * b[14];
+        let result_gamma1    = a[0] * b[2] + a[1] * b[5] + a[2] * b[0] + a[3] * b[8] + a[4] * b[9] - a[5] * b[1] - a[6] * b[11] - a[7] * b[12] - a[8] * b[3] - a[9] * b[4] - a[10] * b[14] - a[11] * b[6] - a[12] * b[7] - a[13] * b[15] - a[14] * b[10] + a[15] * b[13];
+        let result_gamma2    = a[0] * b[3] + a[1] * b[6] - a[2] * b[8] + a[3] * b[0] + a[4] * b[10] + a[5] * b[11] - a[6] * b[1] - a[7] * b[13] + a[8] * b[2] + a[9] * b[14] - a[10] * b[4] + a[11] * b[5] + a[12] * b[15] - a[13] * b[7] + a[14] * b[9] - a[15] * b[12];
+        let result_gamma3    = a[0] * b[4] + a[1] * b[7] - a[2] * b[9] - a[3] * b[10] + a[4] * b[0] + a[5] * b[12] + a[6] * b[13] - a[7] * b[1] - a[8] * b[14] + a[9] * b[2] + a[10] * b[3] - a[11] * b[15] + a[12] * b[5] + a[13] * b[6] - a[14] * b[8] + a[15] * b[11];
+        let result_gamma01   = a[0] * b[5] + a[1] * b[2] - a[2] * b[1] - a[3] * b[11] - a[4] * b[12] + a[5] * b[0] + a[6] * b[8] + a[7] * b[9] - a[8] * b[6] - a[9] * b[7] - a[10] * b[15] - a[11] * b[3] - a[12] * b[4] - a[13] * b[14] + a[14] * b[13] - a[15] * b[10];
+        let result_gamma02   = a[0] * b[6] + a[1] * b[3] + a[2] * b[11] - a[3] * b[1] - a[4] * b[13] - a[5] * b[8] + a[6] * b[0] + a[7] * b[10] + a[8] * b[5] + a[9] * b[15] - a[10] * b[7] + a[11] * b[2] + a[12] * b[14] - a[13] * b[4] - a[14] * b[12] + a[15] * b[9];
+        let result_gamma03   = a[0] * b[7] + a[1] * b[4] + a[2] * b[12] + a[3] * b[13] - a[4] * b[1] - a[5] * b[9] - a[6] * b[10] + a[7] * b[0] - a[8] * b[15] + a[9] * b[5] + a[10] * b[6] - a[11] * b[14] + a[12] * b[2] + a[13] * b[3] + a[14] * b[11] - a[15] * b[8];
+        let result_gamma12   = a[0] * b[8] + a[1] * b[11] + a[2] * b[3] - a[3] * b[2] - a[4] * b[14] - a[5] * b[6] + a[6] * b[5] + a[7] * b[15] + a[8] * b[0] + a[9] * b[10] - a[10] * b[9] + a[11] * b[1] + a[12] * b[13] - a[13] * b[12] - a[14] * b[4] + a[15] * b[7];
+        let result_gamma13   = a[0] * b[9] + a[1] * b[12] + a[2] * b[4] + a[3] * b[14] - a[4] * b[2] - a[5] * b[7] - a[6] * b[15] + a[7] * b[5] - a[8] * b[10] + a[9] * b[0] + a[10] * b[8] - a[11] * b[13] + a[12] * b[1] + a[13] * b[11] + a[14] * b[3] - a[15] * b[6];
+        let result_gamma23   = a[0] * b[10] + a[1] * b[13] - a[2] * b[14] + a[3] * b[4] - a[4] * b[3] + a[5] * b[15] - a[6] * b[7] + a[7] * b[6] + a[8] * b[9] - a[9] * b[8] + a[10] * b[0] + a[11] * b[12] - a[12] * b[11] + a[13] * b[1] - a[14] * b[2] + a[15] * b[5];
+        let result_gamma012  = a[0] * b[11] + a[1] * b[8] - a[2] * b[6] + a[3] * b[5] + a[4] * b[15] + a[5] * b[3] - a[6] * b[2] - a[7] * b[14] + a[8] * b[1] + a[9] * b[13] - a[10] * b[12] + a[11] * b[0] + a[12] * b[10] - a[13] * b[9] + a[14] * b[7] - a[15] * b[4];
+        let result_gamma013  = a[0] * b[12] + a[1] * b[9] - a[2] * b[7] - a[3] * b[15] + a[4] * b[5] + a[5] * b[4] + a[6] * b[14] - a[7] * b[2] - a[8] * b[13] + a[9] * b[1] + a[10] * b[11] - a[11] * b[10] + a[12] * b[0] + a[13] * b[8] - a[14] * b[6] + a[15] * b[3];
+        let result_gamma023  = a[0] * b[13] + a[1] * b[10] + a[2] * b[15] - a[3] * b[7] + a[4] * b[6] - a[5] * b[14] + a[6] * b[4] - a[7] * b[3] + a[8] * b[12] - a[9] * b[11] + a[10] * b[1] + a[11] * b[9] - a[12] * b[8] + a[13] * b[0] + a[14] * b[5] - a[15] * b[2];
+        let result_gamma123  = a[0] * b[14] + a[1] * b[15] + a[2] * b[10] - a[3] * b[9] + a[4] * b[8] - a[5] * b[13] + a[6] * b[12] - a[7] * b[11] + a[8] * b[4] - a[9] * b[3] + a[10] * b[2] + a[11] * b[7] - a[12] * b[6] + a[13] * b[5] + a[14] * b[0] - a[15] * b[1];
+        let result_gamma0123 = a[0] * b[15] + a[1] * b[14] - a[2] * b[13] + a[3] * b[12] - a[4] * b[11] + a[5] * b[10] - a[6] * b[9] + a[7] * b[8] + a[8] * b[7] - a[9] * b[6] + a[10] * b[5] + a[11] * b[4] - a[12] * b[3] + a[13] * b[2] - a[14] * b[1] + a[15] * b[0];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::Mul<SpacetimeMultivector<S>> for &SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: SpacetimeMultivector<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1         = a[0] * b[0] + a[1] * b[1] - a[2] * b[2] - a[3] * b[3] - a[4] * b[4] + a[5] * b[5] + a[6] * b[6] + a[7] * b[7] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[12] - a[13] * b[13] + a[14] * b[14] - a[15] * b[15];
+        let result_gamma0    = a[0] * b[1] + a[1] * b[0] + a[2] * b[5] + a[3] * b[6] + a[4] * b[7] - a[5] * b[2] - a[6] * b[3] - a[7] * b[4] - a[8] * b[11] - a[9] * b[12] - a[10] * b[13] - a[11] * b[8] - a[12] * b[9] - a[13] * b[10] - a[14] * b[15] + a[15] * b[14];
+        let result_gamma1    = a[0] * b[2] + a[1] * b[5] + a[2] * b[0] + a[3] * b[8] + a[4] * b[9] - a[5] * b[1] - a[6] * b[11] - a[7] * b[12] - a[8] * b[3] - a[9] * b[4] - a[10] * b[14] - a[11] * b[6] - a[12] * b[7] - a[13] * b[15] - a[14] * b[10] + a[15] * b[13];
+        let result_gamma2    = a[0] * b[3] + a[1] * b[6] - a[2] * b[8] + a[3] * b[0] + a[4] * b[10] + a[5] * b[11] - a[6] * b[1] - a[7] * b[13] + a[8] * b[2] + a[9] * b[14] - a[10] * b[4] + a[11] * b[5] + a[12] * b[15] - a[13] * b[7] + a[14] * b[9] - a[15] * b[12];
+        let result_gamma3    = a[0] * b[4] + a[1] * b[7] - a[2] * b[9] - a[3] * b[10] + a[4] * b[0] + a[5] * b[12] + a[6] * b[13] - a[7] * b[1] - a[8] * b[14] + a[9] * b[2] + a[10] * b[3] - a[11] * b[15] + a[12] * b[5] + a[13] * b[6] - a[14] * b[8] + a[15] * b[11];
+        let result_gamma01   = a[0] * b[5] + a[1] * b[2] - a[2] * b[1] - a[3] * b[11] - a[4] * b[12] + a[5] * b[0] + a[6] * b[8] + a[7] * b[9] - a[8] * b[6] - a[9] * b[7] - a[10] * b[15] - a[11] * b[3] - a[12] * b[4] - a[13] * b[14] + a[14] * b[13] - a[15] * b[10];
+        let result_gamma02   = a[0] * b[6] + a[1] * b[3] + a[2] * b[11] - a[3] * b[1] - a[4] * b[13] - a[5] * b[8] + a[6] * b[0] + a[7] * b[10] + a[8] * b[5] + a[9] * b[15] - a[10] * b[7] + a[11] * b[2] + a[12] * b[14] - a[13] * b[4] - a[14] * b[12] + a[15] * b[9];
+        let result_gamma03   = a[0] * b[7] + a[1] * b[4] + a[2] * b[12] + a[3] * b[13] - a[4] * b[1] - a[5] * b[9] - a[6] * b[10] + a[7] * b[0] - a[8] * b[15] + a[9] * b[5] + a[10] * b[6] - a[11] * b[14] + a[12] * b[2] + a[13] * b[3] + a[14] * b[11] - a[15] * b[8];
+        let result_gamma12   = a[0] * b[8] + a[1] * b[11] + a[2] * b[3] - a[3] * b[2] - a[4] * b[14] - a[5] * b[6] + a[6] * b[5] + a[7] * b[15] + a[8] * b[0] + a[9] * b[10] - a[10] * b[9] + a[11] * b[1] + a[12] * b[13] - a[13] * b[12] - a[14] * b[4] + a[15] * b[7];
+        let result_gamma13   = a[0] * b[9] + a[1] * b[12] + a[2] * b[4] + a[3] * b[14] - a[4] * b[2] - a[5] * b[7] - a[6] * b[15] + a[7] * b[5] - a[8] * b[10] + a[9] * b[0] + a[10] * b[8] - a[11] * b[13] + a[12] * b[1] + a[13] * b[11] + a[14] * b[3] - a[15] * b[6];
+        let result_gamma23   = a[0] * b[10] + a[1] * b[13] - a[2] * b[14] + a[3] * b[4] - a[4] * b[3] + a[5] * b[15] - a[6] * b[7] + a[7] * b[6] + a[8] * b[9] - a[9] * b[8] + a[10] * b[0] + a[11] * b[12] - a[12] * b[11] + a[13] * b[1] - a[14] * b[2] + a[15] * b[5];
+        let result_gamma012  = a[0] * b[11] + a[1] * b[8] - a[2] * b[6] + a[3] * b[5] + a[4] * b[15] + a[5] * b[3] - a[6] * b[2] - a[7] * b[14] + a[8] * b[1] + a[9] * b[13] - a[10] * b[12] + a[11] * b[0] + a[12] * b[10] - a[13] * b[9] + a[14] * b[7] - a[15] * b[4];
+        let result_gamma013  = a[0] * b[12] + a[1] * b[9] - a[2] * b[7] - a[3] * b[15] + a[4] * b[5] + a[5] * b[4] + a[6] * b[14] - a[7] * b[2] - a[8] * b[13] + a[9] * b[1] + a[10] * b[11] - a[11] * b[10] + a[12] * b[0] + a[13] * b[8] - a[14] * b[6] + a[15] * b[3];
+        let result_gamma023  = a[0] * b[13] + a[1] * b[10] + a[2] * b[15] - a[3] * b[7] + a[4] * b[6] - a[5] * b[14] + a[6] * b[4] - a[7] * b[3] + a[8] * b[12] - a[9] * b[11] + a[10] * b[1] + a[11] * b[9] - a[12] * b[8] + a[13] * b[0] + a[14] * b[5] - a[15] * b[2];
+        let result_gamma123  = a[0] * b[14] + a[1] * b[15] + a[2] * b[10] - a[3] * b[9] + a[4] * b[8] - a[5] * b[13] + a[6] * b[12] - a[7] * b[11] + a[8] * b[4] - a[9] * b[3] + a[10] * b[2] + a[11] * b[7] - a[12] * b[6] + a[13] * b[5] + a[14] * b[0] - a[15] * b[1];
+        let result_gamma0123 = a[0] * b[15] + a[1] * b[14] - a[2] * b[13] + a[3] * b[12] - a[4] * b[11] + a[5] * b[10] - a[6] * b[9] + a[7] * b[8] + a[8] * b[7] - a[9] * b[6] + a[10] * b[5] + a[11] * b[4] - a[12] * b[3] + a[13] * b[2] - a[14] * b[1] + a[15] * b[0];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::Mul<&SpacetimeMultivector<S>> for &SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: &SpacetimeMultivector<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1         = a[0] * b[0] + a[1] * b[1] - a[2] * b[2] - a[3] * b[3] - a[4] * b[4] + a[5] * b[5] + a[6] * b[6] + a[7] * b[7] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[12] - a[13] * b[13] + a[14] * b[14] - a[15] * b[15];
+        let result_gamma0    = a[0] * b[1] + a[1] * b[0] + a[2] * b[5] + a[3] * b[6] + a[4] * b[7] - a[5] * b[2] - a[6] * b[3] - a[7] * b[4] - a[8] * b[11] - a[9] * b[12] - a[10] * b[13] - a[11] * b[8] - a[12] * b[9] - a[13] * b[10] - a[14] * b[15] + a[15] * b[14];
+        let result_gamma1    = a[0] * b[2] + a[1] * b[5] + a[2] * b[0] + a[3] * b[8] + a[4] * b[9] - a[5] * b[1] - a[6] * b[11] - a[7] * b[12] - a[8] * b[3] - a[9] * b[4] - a[10] * b[14] - a[11] * b[6] - a[12] * b[7] - a[13] * b[15] - a[14] * b[10] + a[15] * b[13];
+        let result_gamma2    = a[0] * b[3] + a[1] * b[6] - a[2] * b[8] + a[3] * b[0] + a[4] * b[10] + a[5] * b[11] - a[6] * b[1] - a[7] * b[13] + a[8] * b[2] + a[9] * b[14] - a[10] * b[4] + a[11] * b[5] + a[12] * b[15] - a[13] * b[7] + a[14] * b[9] - a[15] * b[12];
+        let result_gamma3    = a[0] * b[4] + a[1] * b[7] - a[2] * b[9] - a[3] * b[10] + a[4] * b[0] + a[5] * b[12] + a[6] * b[13] - a[7] * b[1] - a[8] * b[14] + a[9] * b[2] + a[10] * b[3] - a[11] * b[15] + a[12] * b[5] + a[13] * b[6] - a[14] * b[8] + a[15] * b[11];
+        let result_gamma01   = a[0] * b[5] + a[1] * b[2] - a[2] * b[1] - a[3] * b[11] - a[4] * b[12] + a[5] * b[0] + a[6] * b[8] + a[7] * b[9] - a[8] * b[6] - a[9] * b[7] - a[10] * b[15] - a[11] * b[3] - a[12] * b[4] - a[13] * b[14] + a[14] * b[13] - a[15] * b[10];
+        let result_gamma02   = a[0] * b[6] + a[1] * b[3] + a[2] * b[11] - a[3] * b[1] - a[4] * b[13] - a[5] * b[8] + a[6] * b[0] + a[7] * b[10] + a[8] * b[5] + a[9] * b[15] - a[10] * b[7] + a[11] * b[2] + a[12] * b[14] - a[13] * b[4] - a[14] * b[12] + a[15] * b[9];
+        let result_gamma03   = a[0] * b[7] + a[1] * b[4] + a[2] * b[12] + a[3] * b[13] - a[4] * b[1] - a[5] * b[9] - a[6] * b[10] + a[7] * b[0] - a[8] * b[15] + a[9] * b[5] + a[10] * b[6] - a[11] * b[14] + a[12] * b[2] + a[13] * b[3] + a[14] * b[11] - a[15] * b[8];
+        let result_gamma12   = a[0] * b[8] + a[1] * b[11] + a[2] * b[3] - a[3] * b[2] - a[4] * b[14] - a[5] * b[6] + a[6] * b[5] + a[7] * b[15] + a[8] * b[0] + a[9] * b[10] - a[10] * b[9] + a[11] * b[1] + a[12] * b[13] - a[13] * b[12] - a[14] * b[4] + a[15] * b[7];
+        let result_gamma13   = a[0] * b[9] + a[1] * b[12] + a[2] * b[4] + a[3] * b[14] - a[4] * b[2] - a[5] * b[7] - a[6] * b[15] + a[7] * b[5] - a[8] * b[10] + a[9] * b[0] + a[10] * b[8] - a[11] * b[13] + a[12] * b[1] + a[13] * b[11] + a[14] * b[3] - a[15] * b[6];
+        let result_gamma23   = a[0] * b[10] + a[1] * b[13] - a[2] * b[14] + a[3] * b[4] - a[4] * b[3] + a[5] * b[15] - a[6] * b[7] + a[7] * b[6] + a[8] * b[9] - a[9] * b[8] + a[10] * b[0] + a[11] * b[12] - a[12] * b[11] + a[13] * b[1] - a[14] * b[2] + a[15] * b[5];
+        let result_gamma012  = a[0] * b[11] + a[1] * b[8] - a[2] * b[6] + a[3] * b[5] + a[4] * b[15] + a[5] * b[3] - a[6] * b[2] - a[7] * b[14] + a[8] * b[1] + a[9] * b[13] - a[10] * b[12] + a[11] * b[0] + a[12] * b[10] - a[13] * b[9] + a[14] * b[7] - a[15] * b[4];
+        let result_gamma013  = a[0] * b[12] + a[1] * b[9] - a[2] * b[7] - a[3] * b[15] + a[4] * b[5] + a[5] * b[4] + a[6] * b[14] - a[7] * b[2] - a[8] * b[13] + a[9] * b[1] + a[10] * b[11] - a[11] * b[10] + a[12] * b[0] + a[13] * b[8] - a[14] * b[6] + a[15] * b[3];
+        let result_gamma023  = a[0] * b[13] + a[1] * b[10] + a[2] * b[15] - a[3] * b[7] + a[4] * b[6] - a[5] * b[14] + a[6] * b[4] - a[7] * b[3] + a[8] * b[12] - a[9] * b[11] + a[10] * b[1] + a[11] * b[9] - a[12] * b[8] + a[13] * b[0] + a[14] * b[5] - a[15] * b[2];
+        let result_gamma123  = a[0] * b[14] + a[1] * b[15] + a[2] * b[10] - a[3] * b[9] + a[4] * b[8] - a[5] * b[13] + a[6] * b[12] - a[7] * b[11] + a[8] * b[4] - a[9] * b[3] + a[10] * b[2] + a[11] * b[7] - a[12] * b[6] + a[13] * b[5] + a[14] * b[0] - a[15] * b[1];
+        let result_gamma0123 = a[0] * b[15] + a[1] * b[14] - a[2] * b[13] + a[3] * b[12] - a[4] * b[11] + a[5] * b[10] - a[6] * b[9] + a[7] * b[8] + a[8] * b[7] - a[9] * b[6] + a[10] * b[5] + a[11] * b[4] - a[12] * b[3] + a[13] * b[2] - a[14] * b[1] + a[15] * b[0];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::Mul<S> for SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1         = a[0] * other;
+        let result_gamma0    = a[1] * other;
+        let result_gamma1    = a[2] * other;
+        let result_gamma2    = a[3] * other;
+        let result_gamma3    = a[4] * other;
+        let result_gamma01   = a[5] * other;
+        let result_gamma02   = a[6] * other;
+        let result_gamma03   = a[7] * other;
+        let result_gamma12   = a[8] * other;
+        let result_gamma13   = a[9] * other;
+        let result_gamma23   = a[10] * other;
+        let result_gamma012  = a[11] * other;
+        let result_gamma013  = a[12] * other;
+        let result_gamma023  = a[13] * other;
+        let result_gamma123  = a[14] * other;
+        let result_gamma0123 = a[15] * other;
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::Mul<S> for &SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn mul(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1         = a[0] * other;
+        let result_gamma0    = a[1] * other;
+        let result_gamma1    = a[2] * other;
+        let result_gamma2    = a[3] * other;
+        let result_gamma3    = a[4] * other;
+        let result_gamma01   = a[5] * other;
+        let result_gamma02   = a[6] * other;
+        let result_gamma03   = a[7] * other;
+        let result_gamma12   = a[8] * other;
+        let result_gamma13   = a[9] * other;
+        let result_gamma23   = a[10] * other;
+        let result_gamma012  = a[11] * other;
+        let result_gamma013  = a[12] * other;
+        let result_gamma023  = a[13] * other;
+        let result_gamma123  = a[14] * other;
+        let result_gamma0123 = a[15] * other;
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::BitXor<SpacetimeMultivector<S>> for SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: SpacetimeMultivector<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1         = a[0] * b[0];
+        let result_gamma0    = a[0] * b[1] + a[1] * b[0];
+        let result_gamma1    = a[0] * b[2] + a[2] * b[0];
+        let result_gamma2    = a[0] * b[3] + a[3] * b[0];
+        let result_gamma3    = a[0] * b[4] + a[4] * b[0];
+        let result_gamma01   = a[0] * b[5] + a[1] * b[2] - a[2] * b[1] + a[5] * b[0];
+        let result_gamma02   = a[0] * b[6] + a[1] * b[3] - a[3] * b[1] + a[6] * b[0];
+        let result_gamma03   = a[0] * b[7] + a[1] * b[4] - a[4] * b[1] + a[7] * b[0];
+        let result_gamma12   = a[0] * b[8] + a[2] * b[3] - a[3] * b[2] + a[8] * b[0];
+        let result_gamma13   = a[0] * b[9] + a[2] * b[4] - a[4] * b[2] + a[9] * b[0];
+        let result_gamma23   = a[0] * b[10] + a[3] * b[4] - a[4] * b[3] + a[10] * b[0];
+        let result_gamma012  = a[0] * b[11] + a[1] * b[8] - a[2] * b[6] + a[3] * b[5] + a[5] * b[3] - a[6] * b[2] + a[8] * b[1] + a[11] * b[0];
+        let result_gamma013  = a[0] * b[12] + a[1] * b[9] - a[2] * b[7] + a[4] * b[5] + a[5] * b[4] - a[7] * b[2] + a[9] * b[1] + a[12] * b[0];
+        let result_gamma023  = a[0] * b[13] + a[1] * b[10] - a[3] * b[7] + a[4] * b[6] + a[6] * b[4] - a[7] * b[3] + a[10] * b[1] + a[13] * b[0];
+        let result_gamma123  = a[0] * b[14] + a[2] * b[10] - a[3] * b[9] + a[4] * b[8] + a[8] * b[4] - a[9] * b[3] + a[10] * b[2] + a[14] * b[0];
+        let result_gamma0123 = a[0] * b[15] + a[1] * b[14] - a[2] * b[13] + a[3] * b[12] - a[4] * b[11] + a[5] * b[10] - a[6] * b[9] + a[7] * b[8] + a[8] * b[7] - a[9] * b[6] + a[10] * b[5] + a[11] * b[4] - a[12] * b[3] + a[13] * b[2] - a[14] * b[1] + a[15] * b[0];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::BitXor<&SpacetimeMultivector<S>> for SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: &SpacetimeMultivector<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1         = a[0] * b[0];
+        let result_gamma0    = a[0] * b[1] + a[1] * b[0];
+        let result_gamma1    = a[0] * b[2] + a[2] * b[0];
+        let result_gamma2    = a[0] * b[3] + a[3] * b[0];
+        let result_gamma3    = a[0] * b[4] + a[4] * b[0];
+        let result_gamma01   = a[0] * b[5] + a[1] * b[2] - a[2] * b[1] + a[5] * b[0];
+        let result_gamma02   = a[0] * b[6] + a[1] * b[3] - a[3] * b[1] + a[6] * b[0];
+        let result_gamma03   = a[0] * b[7] + a[1] * b[4] - a[4] * b[1] + a[7] * b[0];
+        let result_gamma12   = a[0] * b[8] + a[2] * b[3] - a[3] * b[2] + a[8] * b[0];
+        let result_gamma13   = a[0] * b[9] + a[2] * b[4] - a[4] * b[2] + a[9] * b[0];
+        let result_gamma23   = a[0] * b[10] + a[3] * b[4] - a[4] * b[3] + a[10] * b[0];
+        let result_gamma012  = a[0] * b[11] + a[1] * b[8] - a[2] * b[6] + a[3] * b[5] + a[5] * b[3] - a[6] * b[2] + a[8] * b[1] + a[11] * b[0];
+        let result_gamma013  = a[0] * b[12] + a[1] * b[9] - a[2] * b[7] + a[4] * b[5] + a[5] * b[4] - a[7] * b[2] + a[9] * b[1] + a[12] * b[0];
+        let result_gamma023  = a[0] * b[13] + a[1] * b[10] - a[3] * b[7] + a[4] * b[6] + a[6] * b[4] - a[7] * b[3] + a[10] * b[1] + a[13] * b[0];
+        let result_gamma123  = a[0] * b[14] + a[2] * b[10] - a[3] * b[9] + a[4] * b[8] + a[8] * b[4] - a[9] * b[3] + a[10] * b[2] + a[14] * b[0];
+        let result_gamma0123 = a[0] * b[15] + a[1] * b[14] - a[2] * b[13] + a[3] * b[12] - a[4] * b[11] + a[5] * b[10] - a[6] * b[9] + a[7] * b[8] + a[8] * b[7] - a[9] * b[6] + a[10] * b[5] + a[11] * b[4] - a[12] * b[3] + a[13] * b[2] - a[14] * b[1] + a[15] * b[0];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::BitXor<SpacetimeMultivector<S>> for &SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: SpacetimeMultivector<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1         = a[0] * b[0];
+        let result_gamma0    = a[0] * b[1] + a[1] * b[0];
+        let result_gamma1    = a[0] * b[2] + a[2] * b[0];
+        let result_gamma2    = a[0] * b[3] + a[3] * b[0];
+        let result_gamma3    = a[0] * b[4] + a[4] * b[0];
+        let result_gamma01   = a[0] * b[5] + a[1] * b[2] - a[2] * b[1] + a[5] * b[0];
+        let result_gamma02   = a[0] * b[6] + a[1] * b[3] - a[3] * b[1] + a[6] * b[0];
+        let result_gamma03   = a[0] * b[7] + a[1] * b[4] - a[4] * b[1] + a[7] * b[0];
+        let result_gamma12   = a[0] * b[8] + a[2] * b[3] - a[3] * b[2] + a[8] * b[0];
+        let result_gamma13   = a[0] * b[9] + a[2] * b[4] - a[4] * b[2] + a[9] * b[0];
+        let result_gamma23   = a[0] * b[10] + a[3] * b[4] - a[4] * b[3] + a[10] * b[0];
+        let result_gamma012  = a[0] * b[11] + a[1] * b[8] - a[2] * b[6] + a[3] * b[5] + a[5] * b[3] - a[6] * b[2] + a[8] * b[1] + a[11] * b[0];
+        let result_gamma013  = a[0] * b[12] + a[1] * b[9] - a[2] * b[7] + a[4] * b[5] + a[5] * b[4] - a[7] * b[2] + a[9] * b[1] + a[12] * b[0];
+        let result_gamma023  = a[0] * b[13] + a[1] * b[10] - a[3] * b[7] + a[4] * b[6] + a[6] * b[4] - a[7] * b[3] + a[10] * b[1] + a[13] * b[0];
+        let result_gamma123  = a[0] * b[14] + a[2] * b[10] - a[3] * b[9] + a[4] * b[8] + a[8] * b[4] - a[9] * b[3] + a[10] * b[2] + a[14] * b[0];
+        let result_gamma0123 = a[0] * b[15] + a[1] * b[14] - a[2] * b[13] + a[3] * b[12] - a[4] * b[11] + a[5] * b[10] - a[6] * b[9] + a[7] * b[8] + a[8] * b[7] - a[9] * b[6] + a[10] * b[5] + a[11] * b[4] - a[12] * b[3] + a[13] * b[2] - a[14] * b[1] + a[15] * b[0];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::BitXor<&SpacetimeMultivector<S>> for &SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: &SpacetimeMultivector<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1         = a[0] * b[0];
+        let result_gamma0    = a[0] * b[1] + a[1] * b[0];
+        let result_gamma1    = a[0] * b[2] + a[2] * b[0];
+        let result_gamma2    = a[0] * b[3] + a[3] * b[0];
+        let result_gamma3    = a[0] * b[4] + a[4] * b[0];
+        let result_gamma01   = a[0] * b[5] + a[1] * b[2] - a[2] * b[1] + a[5] * b[0];
+        let result_gamma02   = a[0] * b[6] + a[1] * b[3] - a[3] * b[1] + a[6] * b[0];
+        let result_gamma03   = a[0] * b[7] + a[1] * b[4] - a[4] * b[1] + a[7] * b[0];
+        let result_gamma12   = a[0] * b[8] + a[2] * b[3] - a[3] * b[2] + a[8] * b[0];
+        let result_gamma13   = a[0] * b[9] + a[2] * b[4] - a[4] * b[2] + a[9] * b[0];
+        let result_gamma23   = a[0] * b[10] + a[3] * b[4] - a[4] * b[3] + a[10] * b[0];
+        let result_gamma012  = a[0] * b[11] + a[1] * b[8] - a[2] * b[6] + a[3] * b[5] + a[5] * b[3] - a[6] * b[2] + a[8] * b[1] + a[11] * b[0];
+        let result_gamma013  = a[0] * b[12] + a[1] * b[9] - a[2] * b[7] + a[4] * b[5] + a[5] * b[4] - a[7] * b[2] + a[9] * b[1] + a[12] * b[0];
+        let result_gamma023  = a[0] * b[13] + a[1] * b[10] - a[3] * b[7] + a[4] * b[6] + a[6] * b[4] - a[7] * b[3] + a[10] * b[1] + a[13] * b[0];
+        let result_gamma123  = a[0] * b[14] + a[2] * b[10] - a[3] * b[9] + a[4] * b[8] + a[8] * b[4] - a[9] * b[3] + a[10] * b[2] + a[14] * b[0];
+        let result_gamma0123 = a[0] * b[15] + a[1] * b[14] - a[2] * b[13] + a[3] * b[12] - a[4] * b[11] + a[5] * b[10] - a[6] * b[9] + a[7] * b[8] + a[8] * b[7] - a[9] * b[6] + a[10] * b[5] + a[11] * b[4] - a[12] * b[3] + a[13] * b[2] - a[14] * b[1] + a[15] * b[0];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::BitXor<S> for SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1         = a[0] * other;
+        let result_gamma0    = a[1] * other;
+        let result_gamma1    = a[2] * other;
+        let result_gamma2    = a[3] * other;
+        let result_gamma3    = a[4] * other;
+        let result_gamma01   = a[5] * other;
+        let result_gamma02   = a[6] * other;
+        let result_gamma03   = a[7] * other;
+        let result_gamma12   = a[8] * other;
+        let result_gamma13   = a[9] * other;
+        let result_gamma23   = a[10] * other;
+        let result_gamma012  = a[11] * other;
+        let result_gamma013  = a[12] * other;
+        let result_gamma023  = a[13] * other;
+        let result_gamma123  = a[14] * other;
+        let result_gamma0123 = a[15] * other;
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::BitXor<S> for &SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitxor(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1         = a[0] * other;
+        let result_gamma0    = a[1] * other;
+        let result_gamma1    = a[2] * other;
+        let result_gamma2    = a[3] * other;
+        let result_gamma3    = a[4] * other;
+        let result_gamma01   = a[5] * other;
+        let result_gamma02   = a[6] * other;
+        let result_gamma03   = a[7] * other;
+        let result_gamma12   = a[8] * other;
+        let result_gamma13   = a[9] * other;
+        let result_gamma23   = a[10] * other;
+        let result_gamma012  = a[11] * other;
+        let result_gamma013  = a[12] * other;
+        let result_gamma023  = a[13] * other;
+        let result_gamma123  = a[14] * other;
+        let result_gamma0123 = a[15] * other;
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::BitAnd<SpacetimeMultivector<S>> for SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitand(self, other: SpacetimeMultivector<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1         = a[0] * b[15] - a[1] * b[14] + a[2] * b[13] - a[3] * b[12] + a[4] * b[11] + a[5] * b[10] - a[6] * b[9] + a[7] * b[8] + a[8] * b[7] - a[9] * b[6] + a[10] * b[5] - a[11] * b[4] + a[12] * b[3] - a[13] * b[2] + a[14] * b[1] + a[15] * b[0];
+        let result_gamma0    = a[1] * b[15] + a[5] * b[13] - a[6] * b[12] + a[7] * b[11] + a[11] * b[7] - a[12] * b[6] + a[13] * b[5] + a[15] * b[1];
+        let result_gamma1    = a[2] * b[15] + a[5] * b[14] - a[8] * b[12] + a[9] * b[11] + a[11] * b[9] - a[12] * b[8] + a[14] * b[5] + a[15] * b[2];
+        let result_gamma2    = a[3] * b[15] + a[6] * b[14] - a[8] * b[13] + a[10] * b[11] + a[11] * b[10] - a[13] * b[8] + a[14] * b[6] + a[15] * b[3];
+        let result_gamma3    = a[4] * b[15] + a[7] * b[14] - a[9] * b[13] + a[10] * b[12] + a[12] * b[10] - a[13] * b[9] + a[14] * b[7] + a[15] * b[4];
+        let result_gamma01   = a[5] * b[15] - a[11] * b[12] + a[12] * b[11] + a[15] * b[5];
+        let result_gamma02   = a[6] * b[15] - a[11] * b[13] + a[13] * b[11] + a[15] * b[6];
+        let result_gamma03   = a[7] * b[15] - a[12] * b[13] + a[13] * b[12] + a[15] * b[7];
+        let result_gamma12   = a[8] * b[15] - a[11] * b[14] + a[14] * b[11] + a[15] * b[8];
+        let result_gamma13   = a[9] * b[15] - a[12] * b[14] + a[14] * b[12] + a[15] * b[9];
+        let result_gamma23   = a[10] * b[15] - a[13] * b[14] + a[14] * b[13] + a[15] * b[10];
+        let result_gamma012  = a[11] * b[15] + a[15] * b[11];
+        let result_gamma013  = a[12] * b[15] + a[15] * b[12];
+        let result_gamma023  = a[13] * b[15] + a[15] * b[13];
+        let result_gamma123  = a[14] * b[15] + a[15] * b[14];
+        let result_gamma0123 = a[15] * b[15];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::BitAnd<&SpacetimeMultivector<S>> for SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitand(self, other: &SpacetimeMultivector<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1         = a[0] * b[15] - a[1] * b[14] + a[2] * b[13] - a[3] * b[12] + a[4] * b[11] + a[5] * b[10] - a[6] * b[9] + a[7] * b[8] + a[8] * b[7] - a[9] * b[6] + a[10] * b[5] - a[11] * b[4] + a[12] * b[3] - a[13] * b[2] + a[14] * b[1] + a[15] * b[0];
+        let result_gamma0    = a[1] * b[15] + a[5] * b[13] - a[6] * b[12] + a[7] * b[11] + a[11] * b[7] - a[12] * b[6] + a[13] * b[5] + a[15] * b[1];
+        let result_gamma1    = a[2] * b[15] + a[5] * b[14] - a[8] * b[12] + a[9] * b[11] + a[11] * b[9] - a[12] * b[8] + a[14] * b[5] + a[15] * b[2];
+        let result_gamma2    = a[3] * b[15] + a[6] * b[14] - a[8] * b[13] + a[10] * b[11] + a[11] * b[10] - a[13] * b[8] + a[14] * b[6] + a[15] * b[3];
+        let result_gamma3    = a[4] * b[15] + a[7] * b[14] - a[9] * b[13] + a[10] * b[12] + a[12] * b[10] - a[13] * b[9] + a[14] * b[7] + a[15] * b[4];
+        let result_gamma01   = a[5] * b[15] - a[11] * b[12] + a[12] * b[11] + a[15] * b[5];
+        let result_gamma02   = a[6] * b[15] - a[11] * b[13] + a[13] * b[11] + a[15] * b[6];
+        let result_gamma03   = a[7] * b[15] - a[12] * b[13] + a[13] * b[12] + a[15] * b[7];
+        let result_gamma12   = a[8] * b[15] - a[11] * b[14] + a[14] * b[11] + a[15] * b[8];
+        let result_gamma13   = a[9] * b[15] - a[12] * b[14] + a[14] * b[12] + a[15] * b[9];
+        let result_gamma23   = a[10] * b[15] - a[13] * b[14] + a[14] * b[13] + a[15] * b[10];
+        let result_gamma012  = a[11] * b[15] + a[15] * b[11];
+        let result_gamma013  = a[12] * b[15] + a[15] * b[12];
+        let result_gamma023  = a[13] * b[15] + a[15] * b[13];
+        let result_gamma123  = a[14] * b[15] + a[15] * b[14];
+        let result_gamma0123 = a[15] * b[15];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::BitAnd<SpacetimeMultivector<S>> for &SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitand(self, other: SpacetimeMultivector<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1         = a[0] * b[15] - a[1] * b[14] + a[2] * b[13] - a[3] * b[12] + a[4] * b[11] + a[5] * b[10] - a[6] * b[9] + a[7] * b[8] + a[8] * b[7] - a[9] * b[6] + a[10] * b[5] - a[11] * b[4] + a[12] * b[3] - a[13] * b[2] + a[14] * b[1] + a[15] * b[0];
+        let result_gamma0    = a[1] * b[15] + a[5] * b[13] - a[6] * b[12] + a[7] * b[11] + a[11] * b[7] - a[12] * b[6] + a[13] * b[5] + a[15] * b[1];
+        let result_gamma1    = a[2] * b[15] + a[5] * b[14] - a[8] * b[12] + a[9] * b[11] + a[11] * b[9] - a[12] * b[8] + a[14] * b[5] + a[15] * b[2];
+        let result_gamma2    = a[3] * b[15] + a[6] * b[14] - a[8] * b[13] + a[10] * b[11] + a[11] * b[10] - a[13] * b[8] + a[14] * b[6] + a[15] * b[3];
+        let result_gamma3    = a[4] * b[15] + a[7] * b[14] - a[9] * b[13] + a[10] * b[12] + a[12] * b[10] - a[13] * b[9] + a[14] * b[7] + a[15] * b[4];
+        let result_gamma01   = a[5] * b[15] - a[11] * b[12] + a[12] * b[11] + a[15] * b[5];
+        let result_gamma02   = a[6] * b[15] - a[11] * b[13] + a[13] * b[11] + a[15] * b[6];
+        let result_gamma03   = a[7] * b[15] - a[12] * b[13] + a[13] * b[12] + a[15] * b[7];
+        let result_gamma12   = a[8] * b[15] - a[11] * b[14] + a[14] * b[11] + a[15] * b[8];
+        let result_gamma13   = a[9] * b[15] - a[12] * b[14] + a[14] * b[12] + a[15] * b[9];
+        let result_gamma23   = a[10] * b[15] - a[13] * b[14] + a[14] * b[13] + a[15] * b[10];
+        let result_gamma012  = a[11] * b[15] + a[15] * b[11];
+        let result_gamma013  = a[12] * b[15] + a[15] * b[12];
+        let result_gamma023  = a[13] * b[15] + a[15] * b[13];
+        let result_gamma123  = a[14] * b[15] + a[15] * b[14];
+        let result_gamma0123 = a[15] * b[15];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::BitAnd<&SpacetimeMultivector<S>> for &SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitand(self, other: &SpacetimeMultivector<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1         = a[0] * b[15] - a[1] * b[14] + a[2] * b[13] - a[3] * b[12] + a[4] * b[11] + a[5] * b[10] - a[6] * b[9] + a[7] * b[8] + a[8] * b[7] - a[9] * b[6] + a[10] * b[5] - a[11] * b[4] + a[12] * b[3] - a[13] * b[2] + a[14] * b[1] + a[15] * b[0];
+        let result_gamma0    = a[1] * b[15] + a[5] * b[13] - a[6] * b[12] + a[7] * b[11] + a[11] * b[7] - a[12] * b[6] + a[13] * b[5] + a[15] * b[1];
+        let result_gamma1    = a[2] * b[15] + a[5] * b[14] - a[8] * b[12] + a[9] * b[11] + a[11] * b[9] - a[12] * b[8] + a[14] * b[5] + a[15] * b[2];
+        let result_gamma2    = a[3] * b[15] + a[6] * b[14] - a[8] * b[13] + a[10] * b[11] + a[11] * b[10] - a[13] * b[8] + a[14] * b[6] + a[15] * b[3];
+        let result_gamma3    = a[4] * b[15] + a[7] * b[14] - a[9] * b[13] + a[10] * b[12] + a[12] * b[10] - a[13] * b[9] + a[14] * b[7] + a[15] * b[4];
+        let result_gamma01   = a[5] * b[15] - a[11] * b[12] + a[12] * b[11] + a[15] * b[5];
+        let result_gamma02   = a[6] * b[15] - a[11] * b[13] + a[13] * b[11] + a[15] * b[6];
+        let result_gamma03   = a[7] * b[15] - a[12] * b[13] + a[13] * b[12] + a[15] * b[7];
+        let result_gamma12   = a[8] * b[15] - a[11] * b[14] + a[14] * b[11] + a[15] * b[8];
+        let result_gamma13   = a[9] * b[15] - a[12] * b[14] + a[14] * b[12] + a[15] * b[9];
+        let result_gamma23   = a[10] * b[15] - a[13] * b[14] + a[14] * b[13] + a[15] * b[10];
+        let result_gamma012  = a[11] * b[15] + a[15] * b[11];
+        let result_gamma013  = a[12] * b[15] + a[15] * b[12];
+        let result_gamma023  = a[13] * b[15] + a[15] * b[13];
+        let result_gamma123  = a[14] * b[15] + a[15] * b[14];
+        let result_gamma0123 = a[15] * b[15];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::BitOr<SpacetimeMultivector<S>> for SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitor(self, other: SpacetimeMultivector<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1 = a[0] * b[0] + a[1] * b[1] - a[2] * b[2] - a[3] * b[3] - a[4] * b[4] - a[5] * b[5] - a[6] * b[6] - a[7] * b[7] + a[8] * b[8] + a[9] * b[9] + a[10] * b[10] + a[11] * b[11] + a[12] * b[12] + a[13] * b[13] - a[14] * b[14] - a[15] * b[15];
+
+        SpacetimeMultivector::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::BitOr<&SpacetimeMultivector<S>> for SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitor(self, other: &SpacetimeMultivector<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1 = a[0] * b[0] + a[1] * b[1] - a[2] * b[2] - a[3] * b[3] - a[4] * b[4] - a[5] * b[5] - a[6] * b[6] - a[7] * b[7] + a[8] * b[8] + a[9] * b[9] + a[10] * b[10] + a[11] * b[11] + a[12] * b[12] + a[13] * b[13] - a[14] * b[14] - a[15] * b[15];
+
+        SpacetimeMultivector::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::BitOr<SpacetimeMultivector<S>> for &SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitor(self, other: SpacetimeMultivector<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1 = a[0] * b[0] + a[1] * b[1] - a[2] * b[2] - a[3] * b[3] - a[4] * b[4] - a[5] * b[5] - a[6] * b[6] - a[7] * b[7] + a[8] * b[8] + a[9] * b[9] + a[10] * b[10] + a[11] * b[11] + a[12] * b[12] + a[13] * b[13] - a[14] * b[14] - a[15] * b[15];
+
+        SpacetimeMultivector::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::BitOr<&SpacetimeMultivector<S>> for &SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn bitor(self, other: &SpacetimeMultivector<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1 = a[0] * b[0] + a[1] * b[1] - a[2] * b[2] - a[3] * b[3] - a[4] * b[4] - a[5] * b[5] - a[6] * b[6] - a[7] * b[7] + a[8] * b[8] + a[9] * b[9] + a[10] * b[10] + a[11] * b[11] + a[12] * b[12] + a[13] * b[13] - a[14] * b[14] - a[15] * b[15];
+
+        SpacetimeMultivector::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::BitOr<S> for SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn bitor(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1 = a[0] * other;
+
+        SpacetimeMultivector::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::BitOr<S> for &SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn bitor(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1 = a[0] * other;
+
+        SpacetimeMultivector::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::Add<SpacetimeMultivector<S>> for SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: SpacetimeMultivector<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1         = a[0] + b[0];
+        let result_gamma0    = a[1] + b[1];
+        let result_gamma1    = a[2] + b[2];
+        let result_gamma2    = a[3] + b[3];
+        let result_gamma3    = a[4] + b[4];
+        let result_gamma01   = a[5] + b[5];
+        let result_gamma02   = a[6] + b[6];
+        let result_gamma03   = a[7] + b[7];
+        let result_gamma12   = a[8] + b[8];
+        let result_gamma13   = a[9] + b[9];
+        let result_gamma23   = a[10] + b[10];
+        let result_gamma012  = a[11] + b[11];
+        let result_gamma013  = a[12] + b[12];
+        let result_gamma023  = a[13] + b[13];
+        let result_gamma123  = a[14] + b[14];
+        let result_gamma0123 = a[15] + b[15];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::Add<&SpacetimeMultivector<S>> for SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: &SpacetimeMultivector<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1         = a[0] + b[0];
+        let result_gamma0    = a[1] + b[1];
+        let result_gamma1    = a[2] + b[2];
+        let result_gamma2    = a[3] + b[3];
+        let result_gamma3    = a[4] + b[4];
+        let result_gamma01   = a[5] + b[5];
+        let result_gamma02   = a[6] + b[6];
+        let result_gamma03   = a[7] + b[7];
+        let result_gamma12   = a[8] + b[8];
+        let result_gamma13   = a[9] + b[9];
+        let result_gamma23   = a[10] + b[10];
+        let result_gamma012  = a[11] + b[11];
+        let result_gamma013  = a[12] + b[12];
+        let result_gamma023  = a[13] + b[13];
+        let result_gamma123  = a[14] + b[14];
+        let result_gamma0123 = a[15] + b[15];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::Add<SpacetimeMultivector<S>> for &SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: SpacetimeMultivector<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1         = a[0] + b[0];
+        let result_gamma0    = a[1] + b[1];
+        let result_gamma1    = a[2] + b[2];
+        let result_gamma2    = a[3] + b[3];
+        let result_gamma3    = a[4] + b[4];
+        let result_gamma01   = a[5] + b[5];
+        let result_gamma02   = a[6] + b[6];
+        let result_gamma03   = a[7] + b[7];
+        let result_gamma12   = a[8] + b[8];
+        let result_gamma13   = a[9] + b[9];
+        let result_gamma23   = a[10] + b[10];
+        let result_gamma012  = a[11] + b[11];
+        let result_gamma013  = a[12] + b[12];
+        let result_gamma023  = a[13] + b[13];
+        let result_gamma123  = a[14] + b[14];
+        let result_gamma0123 = a[15] + b[15];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::Add<&SpacetimeMultivector<S>> for &SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: &SpacetimeMultivector<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1         = a[0] + b[0];
+        let result_gamma0    = a[1] + b[1];
+        let result_gamma1    = a[2] + b[2];
+        let result_gamma2    = a[3] + b[3];
+        let result_gamma3    = a[4] + b[4];
+        let result_gamma01   = a[5] + b[5];
+        let result_gamma02   = a[6] + b[6];
+        let result_gamma03   = a[7] + b[7];
+        let result_gamma12   = a[8] + b[8];
+        let result_gamma13   = a[9] + b[9];
+        let result_gamma23   = a[10] + b[10];
+        let result_gamma012  = a[11] + b[11];
+        let result_gamma013  = a[12] + b[12];
+        let result_gamma023  = a[13] + b[13];
+        let result_gamma123  = a[14] + b[14];
+        let result_gamma0123 = a[15] + b[15];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::Add<S> for SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1         = a[0] + other;
+        let result_gamma0    = a[1];
+        let result_gamma1    = a[2];
+        let result_gamma2    = a[3];
+        let result_gamma3    = a[4];
+        let result_gamma01   = a[5];
+        let result_gamma02   = a[6];
+        let result_gamma03   = a[7];
+        let result_gamma12   = a[8];
+        let result_gamma13   = a[9];
+        let result_gamma23   = a[10];
+        let result_gamma012  = a[11];
+        let result_gamma013  = a[12];
+        let result_gamma023  = a[13];
+        let result_gamma123  = a[14];
+        let result_gamma0123 = a[15];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::Add<S> for &SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn add(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1         = a[0] + other;
+        let result_gamma0    = a[1];
+        let result_gamma1    = a[2];
+        let result_gamma2    = a[3];
+        let result_gamma3    = a[4];
+        let result_gamma01   = a[5];
+        let result_gamma02   = a[6];
+        let result_gamma03   = a[7];
+        let result_gamma12   = a[8];
+        let result_gamma13   = a[9];
+        let result_gamma23   = a[10];
+        let result_gamma012  = a[11];
+        let result_gamma013  = a[12];
+        let result_gamma023  = a[13];
+        let result_gamma123  = a[14];
+        let result_gamma0123 = a[15];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::Sub<SpacetimeMultivector<S>> for SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: SpacetimeMultivector<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1         = a[0] - b[0];
+        let result_gamma0    = a[1] - b[1];
+        let result_gamma1    = a[2] - b[2];
+        let result_gamma2    = a[3] - b[3];
+        let result_gamma3    = a[4] - b[4];
+        let result_gamma01   = a[5] - b[5];
+        let result_gamma02   = a[6] - b[6];
+        let result_gamma03   = a[7] - b[7];
+        let result_gamma12   = a[8] - b[8];
+        let result_gamma13   = a[9] - b[9];
+        let result_gamma23   = a[10] - b[10];
+        let result_gamma012  = a[11] - b[11];
+        let result_gamma013  = a[12] - b[12];
+        let result_gamma023  = a[13] - b[13];
+        let result_gamma123  = a[14] - b[14];
+        let result_gamma0123 = a[15] - b[15];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::Sub<&SpacetimeMultivector<S>> for SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: &SpacetimeMultivector<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1         = a[0] - b[0];
+        let result_gamma0    = a[1] - b[1];
+        let result_gamma1    = a[2] - b[2];
+        let result_gamma2    = a[3] - b[3];
+        let result_gamma3    = a[4] - b[4];
+        let result_gamma01   = a[5] - b[5];
+        let result_gamma02   = a[6] - b[6];
+        let result_gamma03   = a[7] - b[7];
+        let result_gamma12   = a[8] - b[8];
+        let result_gamma13   = a[9] - b[9];
+        let result_gamma23   = a[10] - b[10];
+        let result_gamma012  = a[11] - b[11];
+        let result_gamma013  = a[12] - b[12];
+        let result_gamma023  = a[13] - b[13];
+        let result_gamma123  = a[14] - b[14];
+        let result_gamma0123 = a[15] - b[15];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::Sub<SpacetimeMultivector<S>> for &SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: SpacetimeMultivector<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1         = a[0] - b[0];
+        let result_gamma0    = a[1] - b[1];
+        let result_gamma1    = a[2] - b[2];
+        let result_gamma2    = a[3] - b[3];
+        let result_gamma3    = a[4] - b[4];
+        let result_gamma01   = a[5] - b[5];
+        let result_gamma02   = a[6] - b[6];
+        let result_gamma03   = a[7] - b[7];
+        let result_gamma12   = a[8] - b[8];
+        let result_gamma13   = a[9] - b[9];
+        let result_gamma23   = a[10] - b[10];
+        let result_gamma012  = a[11] - b[11];
+        let result_gamma013  = a[12] - b[12];
+        let result_gamma023  = a[13] - b[13];
+        let result_gamma123  = a[14] - b[14];
+        let result_gamma0123 = a[15] - b[15];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::Sub<&SpacetimeMultivector<S>> for &SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: &SpacetimeMultivector<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1         = a[0] - b[0];
+        let result_gamma0    = a[1] - b[1];
+        let result_gamma1    = a[2] - b[2];
+        let result_gamma2    = a[3] - b[3];
+        let result_gamma3    = a[4] - b[4];
+        let result_gamma01   = a[5] - b[5];
+        let result_gamma02   = a[6] - b[6];
+        let result_gamma03   = a[7] - b[7];
+        let result_gamma12   = a[8] - b[8];
+        let result_gamma13   = a[9] - b[9];
+        let result_gamma23   = a[10] - b[10];
+        let result_gamma012  = a[11] - b[11];
+        let result_gamma013  = a[12] - b[12];
+        let result_gamma023  = a[13] - b[13];
+        let result_gamma123  = a[14] - b[14];
+        let result_gamma0123 = a[15] - b[15];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::Sub<S> for SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1         = a[0] - other;
+        let result_gamma0    = a[1];
+        let result_gamma1    = a[2];
+        let result_gamma2    = a[3];
+        let result_gamma3    = a[4];
+        let result_gamma01   = a[5];
+        let result_gamma02   = a[6];
+        let result_gamma03   = a[7];
+        let result_gamma12   = a[8];
+        let result_gamma13   = a[9];
+        let result_gamma23   = a[10];
+        let result_gamma012  = a[11];
+        let result_gamma013  = a[12];
+        let result_gamma023  = a[13];
+        let result_gamma123  = a[14];
+        let result_gamma0123 = a[15];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::Sub<S> for &SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn sub(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1         = a[0] - other;
+        let result_gamma0    = a[1];
+        let result_gamma1    = a[2];
+        let result_gamma2    = a[3];
+        let result_gamma3    = a[4];
+        let result_gamma01   = a[5];
+        let result_gamma02   = a[6];
+        let result_gamma03   = a[7];
+        let result_gamma12   = a[8];
+        let result_gamma13   = a[9];
+        let result_gamma23   = a[10];
+        let result_gamma012  = a[11];
+        let result_gamma013  = a[12];
+        let result_gamma023  = a[13];
+        let result_gamma123  = a[14];
+        let result_gamma0123 = a[15];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl_approx_cmp_ops!(SpacetimeMultivector);
+
+impl<S> SpacetimeMultivector<S>
+where
+    S: ScalarFloat,
+{
+    /// Calculate the squared magnitude of a multivector.
+    pub fn magnitude_squared(&self) -> S {
+        let scalar_part = (self * self.reverse())[0];
+
+        scalar_part.abs()
+    }
+
+    /// Calculate the magnitude of a multivector.
+    pub fn magnitude(&self) -> S {
+        self.magnitude_squared().sqrt()
+    }
+
+    /// Normalize a multivector to a unit multivector.
+    pub fn normalize(&self) -> Self {
+        self * (S::one() / self.magnitude())
+    }
+
+    /// Normalize a multivector to a specified magnitude.
+    pub fn normalize_to(&self, magnitude: S) -> Self {
+        self * (magnitude / self.magnitude())
+    }
+}
+
+impl<S> ops::Div<S> for SpacetimeMultivector<S>
+where
+    S: ScalarFloat,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn div(self, other: S) -> Self::Output {
+        let one_over_other = S::one() / other;
+        let result_1         = self.data[0] * one_over_other;
+        let result_gamma0    = self.data[1] * one_over_other;
+        let result_gamma1    = self.data[2] * one_over_other;
+        let result_gamma2    = self.data[3] * one_over_other;
+        let result_gamma3    = self.data[4] * one_over_other;
+        let result_gamma01   = self.data[5] * one_over_other;
+        let result_gamma02   = self.data[6] * one_over_other;
+        let result_gamma03   = self.data[7] * one_over_other;
+        let result_gamma12   = self.data[8] * one_over_other;
+        let result_gamma13   = self.data[9] * one_over_other;
+        let result_gamma23   = self.data[10] * one_over_other;
+        let result_gamma012  = self.data[11] * one_over_other;
+        let result_gamma013  = self.data[12] * one_over_other;
+        let result_gamma023  = self.data[13] * one_over_other;
+        let result_gamma123  = self.data[14] * one_over_other;
+        let result_gamma0123 = self.data[15] * one_over_other;
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::Div<S> for &SpacetimeMultivector<S>
+where
+    S: ScalarFloat,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn div(self, other: S) -> Self::Output {
+        let one_over_other = S::one() / other;
+        let result_1         = self.data[0] * one_over_other;
+        let result_gamma0    = self.data[1] * one_over_other;
+        let result_gamma1    = self.data[2] * one_over_other;
+        let result_gamma2    = self.data[3] * one_over_other;
+        let result_gamma3    = self.data[4] * one_over_other;
+        let result_gamma01   = self.data[5] * one_over_other;
+        let result_gamma02   = self.data[6] * one_over_other;
+        let result_gamma03   = self.data[7] * one_over_other;
+        let result_gamma12   = self.data[8] * one_over_other;
+        let result_gamma13   = self.data[9] * one_over_other;
+        let result_gamma23   = self.data[10] * one_over_other;
+        let result_gamma012  = self.data[11] * one_over_other;
+        let result_gamma013  = self.data[12] * one_over_other;
+        let result_gamma023  = self.data[13] * one_over_other;
+        let result_gamma123  = self.data[14] * one_over_other;
+        let result_gamma0123 = self.data[15] * one_over_other;
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::Shl<SpacetimeMultivector<S>> for SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shl(self, other: SpacetimeMultivector<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1         = a[0] * b[0] + a[1] * b[1] - a[2] * b[2] - a[3] * b[3] - a[4] * b[4] + a[5] * b[5] + a[6] * b[6] + a[7] * b[7] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[12] - a[13] * b[13] + a[14] * b[14] - a[15] * b[15];
+        let result_gamma0    = a[0] * b[1] + a[2] * b[5] + a[3] * b[6] + a[4] * b[7] - a[8] * b[11] - a[9] * b[12] - a[10] * b[13] - a[14] * b[15];
+        let result_gamma1    = a[0] * b[2] + a[1] * b[5] + a[3] * b[8] + a[4] * b[9] - a[6] * b[11] - a[7] * b[12] - a[10] * b[14] - a[13] * b[15];
+        let result_gamma2    = a[0] * b[3] + a[1] * b[6] - a[2] * b[8] + a[4] * b[10] + a[5] * b[11] - a[7] * b[13] + a[9] * b[14] + a[12] * b[15];
+        let result_gamma3    = a[0] * b[4] + a[1] * b[7] - a[2] * b[9] - a[3] * b[10] + a[5] * b[12] + a[6] * b[13] - a[8] * b[14] - a[11] * b[15];
+        let result_gamma01   = a[0] * b[5] - a[3] * b[11] - a[4] * b[12] - a[10] * b[15];
+        let result_gamma02   = a[0] * b[6] + a[2] * b[11] - a[4] * b[13] + a[9] * b[15];
+        let result_gamma03   = a[0] * b[7] + a[2] * b[12] + a[3] * b[13] - a[8] * b[15];
+        let result_gamma12   = a[0] * b[8] + a[1] * b[11] - a[4] * b[14] + a[7] * b[15];
+        let result_gamma13   = a[0] * b[9] + a[1] * b[12] + a[3] * b[14] - a[6] * b[15];
+        let result_gamma23   = a[0] * b[10] + a[1] * b[13] - a[2] * b[14] + a[5] * b[15];
+        let result_gamma012  = a[0] * b[11] + a[4] * b[15];
+        let result_gamma013  = a[0] * b[12] - a[3] * b[15];
+        let result_gamma023  = a[0] * b[13] + a[2] * b[15];
+        let result_gamma123  = a[0] * b[14] + a[1] * b[15];
+        let result_gamma0123 = a[0] * b[15];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::Shl<&SpacetimeMultivector<S>> for SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shl(self, other: &SpacetimeMultivector<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1         = a[0] * b[0] + a[1] * b[1] - a[2] * b[2] - a[3] * b[3] - a[4] * b[4] + a[5] * b[5] + a[6] * b[6] + a[7] * b[7] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[12] - a[13] * b[13] + a[14] * b[14] - a[15] * b[15];
+        let result_gamma0    = a[0] * b[1] + a[2] * b[5] + a[3] * b[6] + a[4] * b[7] - a[8] * b[11] - a[9] * b[12] - a[10] * b[13] - a[14] * b[15];
+        let result_gamma1    = a[0] * b[2] + a[1] * b[5] + a[3] * b[8] + a[4] * b[9] - a[6] * b[11] - a[7] * b[12] - a[10] * b[14] - a[13] * b[15];
+        let result_gamma2    = a[0] * b[3] + a[1] * b[6] - a[2] * b[8] + a[4] * b[10] + a[5] * b[11] - a[7] * b[13] + a[9] * b[14] + a[12] * b[15];
+        let result_gamma3    = a[0] * b[4] + a[1] * b[7] - a[2] * b[9] - a[3] * b[10] + a[5] * b[12] + a[6] * b[13] - a[8] * b[14] - a[11] * b[15];
+        let result_gamma01   = a[0] * b[5] - a[3] * b[11] - a[4] * b[12] - a[10] * b[15];
+        let result_gamma02   = a[0] * b[6] + a[2] * b[11] - a[4] * b[13] + a[9] * b[15];
+        let result_gamma03   = a[0] * b[7] + a[2] * b[12] + a[3] * b[13] - a[8] * b[15];
+        let result_gamma12   = a[0] * b[8] + a[1] * b[11] - a[4] * b[14] + a[7] * b[15];
+        let result_gamma13   = a[0] * b[9] + a[1] * b[12] + a[3] * b[14] - a[6] * b[15];
+        let result_gamma23   = a[0] * b[10] + a[1] * b[13] - a[2] * b[14] + a[5] * b[15];
+        let result_gamma012  = a[0] * b[11] + a[4] * b[15];
+        let result_gamma013  = a[0] * b[12] - a[3] * b[15];
+        let result_gamma023  = a[0] * b[13] + a[2] * b[15];
+        let result_gamma123  = a[0] * b[14] + a[1] * b[15];
+        let result_gamma0123 = a[0] * b[15];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::Shl<SpacetimeMultivector<S>> for &SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shl(self, other: SpacetimeMultivector<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1         = a[0] * b[0] + a[1] * b[1] - a[2] * b[2] - a[3] * b[3] - a[4] * b[4] + a[5] * b[5] + a[6] * b[6] + a[7] * b[7] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[12] - a[13] * b[13] + a[14] * b[14] - a[15] * b[15];
+        let result_gamma0    = a[0] * b[1] + a[2] * b[5] + a[3] * b[6] + a[4] * b[7] - a[8] * b[11] - a[9] * b[12] - a[10] * b[13] - a[14] * b[15];
+        let result_gamma1    = a[0] * b[2] + a[1] * b[5] + a[3] * b[8] + a[4] * b[9] - a[6] * b[11] - a[7] * b[12] - a[10] * b[14] - a[13] * b[15];
+        let result_gamma2    = a[0] * b[3] + a[1] * b[6] - a[2] * b[8] + a[4] * b[10] + a[5] * b[11] - a[7] * b[13] + a[9] * b[14] + a[12] * b[15];
+        let result_gamma3    = a[0] * b[4] + a[1] * b[7] - a[2] * b[9] - a[3] * b[10] + a[5] * b[12] + a[6] * b[13] - a[8] * b[14] - a[11] * b[15];
+        let result_gamma01   = a[0] * b[5] - a[3] * b[11] - a[4] * b[12] - a[10] * b[15];
+        let result_gamma02   = a[0] * b[6] + a[2] * b[11] - a[4] * b[13] + a[9] * b[15];
+        let result_gamma03   = a[0] * b[7] + a[2] * b[12] + a[3] * b[13] - a[8] * b[15];
+        let result_gamma12   = a[0] * b[8] + a[1] * b[11] - a[4] * b[14] + a[7] * b[15];
+        let result_gamma13   = a[0] * b[9] + a[1] * b[12] + a[3] * b[14] - a[6] * b[15];
+        let result_gamma23   = a[0] * b[10] + a[1] * b[13] - a[2] * b[14] + a[5] * b[15];
+        let result_gamma012  = a[0] * b[11] + a[4] * b[15];
+        let result_gamma013  = a[0] * b[12] - a[3] * b[15];
+        let result_gamma023  = a[0] * b[13] + a[2] * b[15];
+        let result_gamma123  = a[0] * b[14] + a[1] * b[15];
+        let result_gamma0123 = a[0] * b[15];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::Shl<&SpacetimeMultivector<S>> for &SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shl(self, other: &SpacetimeMultivector<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1         = a[0] * b[0] + a[1] * b[1] - a[2] * b[2] - a[3] * b[3] - a[4] * b[4] + a[5] * b[5] + a[6] * b[6] + a[7] * b[7] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[12] - a[13] * b[13] + a[14] * b[14] - a[15] * b[15];
+        let result_gamma0    = a[0] * b[1] + a[2] * b[5] + a[3] * b[6] + a[4] * b[7] - a[8] * b[11] - a[9] * b[12] - a[10] * b[13] - a[14] * b[15];
+        let result_gamma1    = a[0] * b[2] + a[1] * b[5] + a[3] * b[8] + a[4] * b[9] - a[6] * b[11] - a[7] * b[12] - a[10] * b[14] - a[13] * b[15];
+        let result_gamma2    = a[0] * b[3] + a[1] * b[6] - a[2] * b[8] + a[4] * b[10] + a[5] * b[11] - a[7] * b[13] + a[9] * b[14] + a[12] * b[15];
+        let result_gamma3    = a[0] * b[4] + a[1] * b[7] - a[2] * b[9] - a[3] * b[10] + a[5] * b[12] + a[6] * b[13] - a[8] * b[14] - a[11] * b[15];
+        let result_gamma01   = a[0] * b[5] - a[3] * b[11] - a[4] * b[12] - a[10] * b[15];
+        let result_gamma02   = a[0] * b[6] + a[2] * b[11] - a[4] * b[13] + a[9] * b[15];
+        let result_gamma03   = a[0] * b[7] + a[2] * b[12] + a[3] * b[13] - a[8] * b[15];
+        let result_gamma12   = a[0] * b[8] + a[1] * b[11] - a[4] * b[14] + a[7] * b[15];
+        let result_gamma13   = a[0] * b[9] + a[1] * b[12] + a[3] * b[14] - a[6] * b[15];
+        let result_gamma23   = a[0] * b[10] + a[1] * b[13] - a[2] * b[14] + a[5] * b[15];
+        let result_gamma012  = a[0] * b[11] + a[4] * b[15];
+        let result_gamma013  = a[0] * b[12] - a[3] * b[15];
+        let result_gamma023  = a[0] * b[13] + a[2] * b[15];
+        let result_gamma123  = a[0] * b[14] + a[1] * b[15];
+        let result_gamma0123 = a[0] * b[15];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::Shl<S> for SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn shl(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1 = a[0] * other;
+
+        SpacetimeMultivector::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::Shl<S> for &SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn shl(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1 = a[0] * other;
+
+        SpacetimeMultivector::from_scalar(result_1)
+    }
+}
+
+impl<S> ops::Shr<SpacetimeMultivector<S>> for SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: SpacetimeMultivector<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1         = a[0] * b[0] + a[1] * b[1] - a[2] * b[2] - a[3] * b[3] - a[4] * b[4] + a[5] * b[5] + a[6] * b[6] + a[7] * b[7] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[12] - a[13] * b[13] + a[14] * b[14] - a[15] * b[15];
+        let result_gamma0    = a[1] * b[0] - a[5] * b[2] - a[6] * b[3] - a[7] * b[4] - a[11] * b[8] - a[12] * b[9] - a[13] * b[10] + a[15] * b[14];
+        let result_gamma1    = a[2] * b[0] - a[5] * b[1] - a[8] * b[3] - a[9] * b[4] - a[11] * b[6] - a[12] * b[7] - a[14] * b[10] + a[15] * b[13];
+        let result_gamma2    = a[3] * b[0] - a[6] * b[1] + a[8] * b[2] - a[10] * b[4] + a[11] * b[5] - a[13] * b[7] + a[14] * b[9] - a[15] * b[12];
+        let result_gamma3    = a[4] * b[0] - a[7] * b[1] + a[9] * b[2] + a[10] * b[3] + a[12] * b[5] + a[13] * b[6] - a[14] * b[8] + a[15] * b[11];
+        let result_gamma01   = a[5] * b[0] - a[11] * b[3] - a[12] * b[4] - a[15] * b[10];
+        let result_gamma02   = a[6] * b[0] + a[11] * b[2] - a[13] * b[4] + a[15] * b[9];
+        let result_gamma03   = a[7] * b[0] + a[12] * b[2] + a[13] * b[3] - a[15] * b[8];
+        let result_gamma12   = a[8] * b[0] + a[11] * b[1] - a[14] * b[4] + a[15] * b[7];
+        let result_gamma13   = a[9] * b[0] + a[12] * b[1] + a[14] * b[3] - a[15] * b[6];
+        let result_gamma23   = a[10] * b[0] + a[13] * b[1] - a[14] * b[2] + a[15] * b[5];
+        let result_gamma012  = a[11] * b[0] - a[15] * b[4];
+        let result_gamma013  = a[12] * b[0] + a[15] * b[3];
+        let result_gamma023  = a[13] * b[0] - a[15] * b[2];
+        let result_gamma123  = a[14] * b[0] - a[15] * b[1];
+        let result_gamma0123 = a[15] * b[0];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::Shr<&SpacetimeMultivector<S>> for SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: &SpacetimeMultivector<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1         = a[0] * b[0] + a[1] * b[1] - a[2] * b[2] - a[3] * b[3] - a[4] * b[4] + a[5] * b[5] + a[6] * b[6] + a[7] * b[7] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[12] - a[13] * b[13] + a[14] * b[14] - a[15] * b[15];
+        let result_gamma0    = a[1] * b[0] - a[5] * b[2] - a[6] * b[3] - a[7] * b[4] - a[11] * b[8] - a[12] * b[9] - a[13] * b[10] + a[15] * b[14];
+        let result_gamma1    = a[2] * b[0] - a[5] * b[1] - a[8] * b[3] - a[9] * b[4] - a[11] * b[6] - a[12] * b[7] - a[14] * b[10] + a[15] * b[13];
+        let result_gamma2    = a[3] * b[0] - a[6] * b[1] + a[8] * b[2] - a[10] * b[4] + a[11] * b[5] - a[13] * b[7] + a[14] * b[9] - a[15] * b[12];
+        let result_gamma3    = a[4] * b[0] - a[7] * b[1] + a[9] * b[2] + a[10] * b[3] + a[12] * b[5] + a[13] * b[6] - a[14] * b[8] + a[15] * b[11];
+        let result_gamma01   = a[5] * b[0] - a[11] * b[3] - a[12] * b[4] - a[15] * b[10];
+        let result_gamma02   = a[6] * b[0] + a[11] * b[2] - a[13] * b[4] + a[15] * b[9];
+        let result_gamma03   = a[7] * b[0] + a[12] * b[2] + a[13] * b[3] - a[15] * b[8];
+        let result_gamma12   = a[8] * b[0] + a[11] * b[1] - a[14] * b[4] + a[15] * b[7];
+        let result_gamma13   = a[9] * b[0] + a[12] * b[1] + a[14] * b[3] - a[15] * b[6];
+        let result_gamma23   = a[10] * b[0] + a[13] * b[1] - a[14] * b[2] + a[15] * b[5];
+        let result_gamma012  = a[11] * b[0] - a[15] * b[4];
+        let result_gamma013  = a[12] * b[0] + a[15] * b[3];
+        let result_gamma023  = a[13] * b[0] - a[15] * b[2];
+        let result_gamma123  = a[14] * b[0] - a[15] * b[1];
+        let result_gamma0123 = a[15] * b[0];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::Shr<SpacetimeMultivector<S>> for &SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: SpacetimeMultivector<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1         = a[0] * b[0] + a[1] * b[1] - a[2] * b[2] - a[3] * b[3] - a[4] * b[4] + a[5] * b[5] + a[6] * b[6] + a[7] * b[7] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[12] - a[13] * b[13] + a[14] * b[14] - a[15] * b[15];
+        let result_gamma0    = a[1] * b[0] - a[5] * b[2] - a[6] * b[3] - a[7] * b[4] - a[11] * b[8] - a[12] * b[9] - a[13] * b[10] + a[15] * b[14];
+        let result_gamma1    = a[2] * b[0] - a[5] * b[1] - a[8] * b[3] - a[9] * b[4] - a[11] * b[6] - a[12] * b[7] - a[14] * b[10] + a[15] * b[13];
+        let result_gamma2    = a[3] * b[0] - a[6] * b[1] + a[8] * b[2] - a[10] * b[4] + a[11] * b[5] - a[13] * b[7] + a[14] * b[9] - a[15] * b[12];
+        let result_gamma3    = a[4] * b[0] - a[7] * b[1] + a[9] * b[2] + a[10] * b[3] + a[12] * b[5] + a[13] * b[6] - a[14] * b[8] + a[15] * b[11];
+        let result_gamma01   = a[5] * b[0] - a[11] * b[3] - a[12] * b[4] - a[15] * b[10];
+        let result_gamma02   = a[6] * b[0] + a[11] * b[2] - a[13] * b[4] + a[15] * b[9];
+        let result_gamma03   = a[7] * b[0] + a[12] * b[2] + a[13] * b[3] - a[15] * b[8];
+        let result_gamma12   = a[8] * b[0] + a[11] * b[1] - a[14] * b[4] + a[15] * b[7];
+        let result_gamma13   = a[9] * b[0] + a[12] * b[1] + a[14] * b[3] - a[15] * b[6];
+        let result_gamma23   = a[10] * b[0] + a[13] * b[1] - a[14] * b[2] + a[15] * b[5];
+        let result_gamma012  = a[11] * b[0] - a[15] * b[4];
+        let result_gamma013  = a[12] * b[0] + a[15] * b[3];
+        let result_gamma023  = a[13] * b[0] - a[15] * b[2];
+        let result_gamma123  = a[14] * b[0] - a[15] * b[1];
+        let result_gamma0123 = a[15] * b[0];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::Shr<&SpacetimeMultivector<S>> for &SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: &SpacetimeMultivector<S>) -> Self::Output {
+        let a = self;
+        let b = other;
+        let result_1         = a[0] * b[0] + a[1] * b[1] - a[2] * b[2] - a[3] * b[3] - a[4] * b[4] + a[5] * b[5] + a[6] * b[6] + a[7] * b[7] - a[8] * b[8] - a[9] * b[9] - a[10] * b[10] - a[11] * b[11] - a[12] * b[12] - a[13] * b[13] + a[14] * b[14] - a[15] * b[15];
+        let result_gamma0    = a[1] * b[0] - a[5] * b[2] - a[6] * b[3] - a[7] * b[4] - a[11] * b[8] - a[12] * b[9] - a[13] * b[10] + a[15] * b[14];
+        let result_gamma1    = a[2] * b[0] - a[5] * b[1] - a[8] * b[3] - a[9] * b[4] - a[11] * b[6] - a[12] * b[7] - a[14] * b[10] + a[15] * b[13];
+        let result_gamma2    = a[3] * b[0] - a[6] * b[1] + a[8] * b[2] - a[10] * b[4] + a[11] * b[5] - a[13] * b[7] + a[14] * b[9] - a[15] * b[12];
+        let result_gamma3    = a[4] * b[0] - a[7] * b[1] + a[9] * b[2] + a[10] * b[3] + a[12] * b[5] + a[13] * b[6] - a[14] * b[8] + a[15] * b[11];
+        let result_gamma01   = a[5] * b[0] - a[11] * b[3] - a[12] * b[4] - a[15] * b[10];
+        let result_gamma02   = a[6] * b[0] + a[11] * b[2] - a[13] * b[4] + a[15] * b[9];
+        let result_gamma03   = a[7] * b[0] + a[12] * b[2] + a[13] * b[3] - a[15] * b[8];
+        let result_gamma12   = a[8] * b[0] + a[11] * b[1] - a[14] * b[4] + a[15] * b[7];
+        let result_gamma13   = a[9] * b[0] + a[12] * b[1] + a[14] * b[3] - a[15] * b[6];
+        let result_gamma23   = a[10] * b[0] + a[13] * b[1] - a[14] * b[2] + a[15] * b[5];
+        let result_gamma012  = a[11] * b[0] - a[15] * b[4];
+        let result_gamma013  = a[12] * b[0] + a[15] * b[3];
+        let result_gamma023  = a[13] * b[0] - a[15] * b[2];
+        let result_gamma123  = a[14] * b[0] - a[15] * b[1];
+        let result_gamma0123 = a[15] * b[0];
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::Shr<S> for SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1         = a[0] * other;
+        let result_gamma0    = a[1] * other;
+        let result_gamma1    = a[2] * other;
+        let result_gamma2    = a[3] * other;
+        let result_gamma3    = a[4] * other;
+        let result_gamma01   = a[5] * other;
+        let result_gamma02   = a[6] * other;
+        let result_gamma03   = a[7] * other;
+        let result_gamma12   = a[8] * other;
+        let result_gamma13   = a[9] * other;
+        let result_gamma23   = a[10] * other;
+        let result_gamma012  = a[11] * other;
+        let result_gamma013  = a[12] * other;
+        let result_gamma023  = a[13] * other;
+        let result_gamma123  = a[14] * other;
+        let result_gamma0123 = a[15] * other;
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> ops::Shr<S> for &SpacetimeMultivector<S>
+where
+    S: Scalar,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn shr(self, other: S) -> Self::Output {
+        let a = self;
+        let result_1         = a[0] * other;
+        let result_gamma0    = a[1] * other;
+        let result_gamma1    = a[2] * other;
+        let result_gamma2    = a[3] * other;
+        let result_gamma3    = a[4] * other;
+        let result_gamma01   = a[5] * other;
+        let result_gamma02   = a[6] * other;
+        let result_gamma03   = a[7] * other;
+        let result_gamma12   = a[8] * other;
+        let result_gamma13   = a[9] * other;
+        let result_gamma23   = a[10] * other;
+        let result_gamma012  = a[11] * other;
+        let result_gamma013  = a[12] * other;
+        let result_gamma023  = a[13] * other;
+        let result_gamma123  = a[14] * other;
+        let result_gamma0123 = a[15] * other;
+
+        SpacetimeMultivector::new(
+            result_1,
+            result_gamma0,
+            result_gamma1,
+            result_gamma2,
+            result_gamma3,
+            result_gamma01,
+            result_gamma02,
+            result_gamma03,
+            result_gamma12,
+            result_gamma13,
+            result_gamma23,
+            result_gamma012,
+            result_gamma013,
+            result_gamma023,
+            result_gamma123,
+            result_gamma0123,
+        )
+    }
+}
+
+impl<S> SpacetimeMultivector<S>
+where
+    S: ScalarFloat,
+{
+    /// Determine whether a multivector is invertible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let gamma0123: SpacetimeMultivector<f64> = SpacetimeMultivector::unit_gamma0123();
+    ///
+    /// assert!(gamma0123.is_invertible());
+    ///
+    /// // The idempotent `(1 + gamma0) / 2` is a zero divisor.
+    /// let idempotent = (SpacetimeMultivector::unit_scalar() + SpacetimeMultivector::unit_gamma0()) * 0.5_f64;
+    ///
+    /// assert!(!idempotent.is_invertible());
+    /// ```
+    #[inline]
+    pub fn is_invertible(&self) -> bool {
+        ulps_ne!(
+            self.inverse_denominator(),
+            S::zero(),
+            abs_diff_all <= S::default_epsilon(),
+            ulps_all <= S::default_max_ulps()
+        )
+    }
+
+    /// Compute the multiplicative inverse of a multivector.
+    ///
+    /// The inverse of a multivector `mv` is a multivector `mv_inv`
+    /// such that
+    /// ```text
+    /// mv * mv_inv = mv_inv * mv = 1
+    /// ```
+    /// In dimension four, the inverse is given by
+    /// ```text
+    /// inv(mv) == conj(mv) * neg34(mv * conj(mv)) / (mv * conj(mv) * neg34(mv * conj(mv)))
+    /// ```
+    /// where `conj` denotes the conjugate, and `neg34` negates the grade three
+    /// and grade four parts of a multivector. The denominator is always a scalar.
+    /// A nonzero multivector can fail to be invertible. For instance, every
+    /// nonzero null vector, such as `gamma0 + gamma1`, squares to zero. For more
+    /// information on the inversion of multivectors in general, see [1].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let mv = SpacetimeMultivector::new(
+    ///     13_f64, -4_f64, 98_f64, 4_f64, 7_f64, -10_f64, 30_f64, 2_f64,
+    ///     1_f64, 5_f64, -6_f64, 3_f64, 8_f64, -9_f64, 11_f64, 12_f64,
+    /// );
+    /// let mv_inv = mv.inverse().unwrap();
+    /// let one: SpacetimeMultivector<f64> = SpacetimeMultivector::unit_scalar();
+    ///
+    /// assert_relative_eq!(mv * mv_inv, one, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(mv_inv * mv, one, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    ///
+    /// # References
+    ///
+    /// [1] _Eckhard Hitzer, Stephen Sangwine. Multivector and multivector matrix
+    ///     inverse in real Clifford algebras. Applied Mathematics and Computation
+    ///     (311) (2017) 375-389. Elsevier. DOI:10.1016/j.amc.2017.05.027._
+    pub fn inverse(&self) -> Option<Self> {
+        let numerator = self.inverse_numerator();
+        let denominator = (self * numerator)[0];
+        if denominator.is_zero() {
+            None
+        } else {
+            Some(numerator / denominator)
+        }
+    }
+
+    fn inverse_numerator(&self) -> Self {
+        let conjugate = self.conjugate();
+        let self_times_conjugate = self * conjugate;
+        let negated = self_times_conjugate.grade(0) - self_times_conjugate.grade(3) - self_times_conjugate.grade(4);
+
+        conjugate * negated
+    }
+
+    fn inverse_denominator(&self) -> S {
+        (self * self.inverse_numerator())[0]
+    }
+
+    fn inverse_unchecked(&self) -> Self {
+        let numerator = self.inverse_numerator();
+        let denominator = (self * numerator)[0];
+
+        numerator / denominator
+    }
+
+    /// Compute the commutator of two multivectors.
+    ///
+    /// The commutator of multivectors `mv1` and `mv2` is given by
+    /// ```text
+    /// comm(mv1, mv2) := (mv1 * mv2 - mv2 * mv1) / 2
+    /// ```
+    /// where `*` denotes the geometric product.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let mv1 = SpacetimeMultivector::from_scalar(2_f64);
+    /// let mv2 = SpacetimeMultivector::from_scalar(3_f64);
+    /// let expected: SpacetimeMultivector<f64> = SpacetimeMultivector::zero();
+    /// let result = mv1.commutator(&mv2);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn commutator(&self, other: &Self) -> Self {
+        let self_times_other = self * other;
+        let other_times_self = other * self;
+        let one_over_two = S::one() / (S::one() + S::one());
+
+        (self_times_other - other_times_self) * one_over_two
+    }
+
+    /// Compute the commutator of two multivectors.
+    ///
+    /// This is a synonym for `commutator`.
+    #[inline(always)]
+    pub fn x(&self, other: &Self) -> Self {
+        self.commutator(other)
+    }
+
+    /// Compute the anticommutator of two multivectors.
+    ///
+    /// The anticommutator of multivectors `mv1` and `mv2` is given by
+    /// ```text
+    /// anticomm(mv1, mv2) := (mv1 * mv2 + mv2 * mv1) / 2
+    /// ```
+    /// where `*` denotes the geometric product.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// #
+    /// let mv1 = SpacetimeMultivector::from_scalar(2_f64);
+    /// let mv2 = SpacetimeMultivector::from_scalar(3_f64);
+    /// let expected: SpacetimeMultivector<f64> = mv1 * mv2;
+    /// let result = mv1.anticommutator(&mv2);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn anticommutator(&self, other: &Self) -> Self {
+        let self_times_other = self * other;
+        let other_times_self = other * self;
+        let one_over_two = S::one() / (S::one() + S::one());
+
+        (self_times_other + other_times_self) * one_over_two
+    }
+}
+
+impl<S> ops::Div<SpacetimeMultivector<S>> for SpacetimeMultivector<S>
+where
+    S: ScalarFloat,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn div(self, other: SpacetimeMultivector<S>) -> Self::Output {
+        self * other.inverse_unchecked()
+    }
+}
+
+impl<S> ops::Div<&SpacetimeMultivector<S>> for SpacetimeMultivector<S>
+where
+    S: ScalarFloat,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn div(self, other: &SpacetimeMultivector<S>) -> Self::Output {
+        self * other.inverse_unchecked()
+    }
+}
+
+impl<S> ops::Div<SpacetimeMultivector<S>> for &SpacetimeMultivector<S>
+where
+    S: ScalarFloat,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn div(self, other: SpacetimeMultivector<S>) -> Self::Output {
+        self * other.inverse_unchecked()
+    }
+}
+
+impl<S> ops::Div<&SpacetimeMultivector<S>> for &SpacetimeMultivector<S>
+where
+    S: ScalarFloat,
+{
+    type Output = SpacetimeMultivector<S>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn div(self, other: &SpacetimeMultivector<S>) -> Self::Output {
+        self * other.inverse_unchecked()
+    }
+}
+
+impl<S> SpacetimeMultivector<S>
+where
+    S: ScalarFloat,
+{
+    /// Split a spacetime vector into its time and its relative position with
+    /// respect to the observer `gamma0`.
+    ///
+    /// The spacetime split of a vector `x` is the even multivector
+    /// ```text
+    /// x * gamma0 == (x | gamma0) + (x ^ gamma0) == t + x_rel
+    /// ```
+    /// where `t` is the time of the event `x` measured by the observer, and
+    /// `x_rel` is a relative vector in the basis `sigma_k := gamma_k * gamma0`.
+    /// This function returns the time together with the relative vector,
+    /// mapped to a Euclidean vector by `sigma_k -> e_k`. Only the grade one
+    /// part of `self` is used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let event = SpacetimeMultivector::new(
+    ///     4_f64, 5_f64, 1_f64, 2_f64, 3_f64, 0_f64, 0_f64, 0_f64,
+    ///     0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64,
+    /// );
+    /// let (time, position) = event.spacetime_split();
+    ///
+    /// assert_eq!(time, 5_f64);
+    /// assert_eq!(position, EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64));
+    /// assert_eq!(SpacetimeMultivector::from_spacetime_split(time, &position), event.grade(1));
+    /// ```
+    pub fn spacetime_split(&self) -> (S, EuclideanMultivector3<S>) {
+        let zero = S::zero();
+        let position = EuclideanMultivector3::new(zero, self.data[2], self.data[3], self.data[4], zero, zero, zero, zero);
+
+        (self.data[1], position)
+    }
+
+    /// Construct the spacetime vector of an event from its time and its
+    /// relative position with respect to the observer `gamma0`.
+    ///
+    /// This is the inverse of [`spacetime_split`](Self::spacetime_split). The
+    /// event is the vector
+    /// ```text
+    /// x := time * gamma0 + position[1] * gamma1 + position[2] * gamma2 + position[3] * gamma3
+    /// ```
+    /// Only the grade one part of `position` is used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let position = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let event = SpacetimeMultivector::from_spacetime_split(5_f64, &position);
+    ///
+    /// // The spacetime interval of the event.
+    /// assert_eq!((event * event)[0], 25_f64 - 14_f64);
+    /// ```
+    pub fn from_spacetime_split(time: S, position: &EuclideanMultivector3<S>) -> Self {
+        let mut result = Self::zero();
+        result.data[1] = time;
+        result.data[2] = position[1];
+        result.data[3] = position[2];
+        result.data[4] = position[3];
+
+        result
+    }
+
+    /// Map the even part of a spacetime multivector to the three-dimensional
+    /// Euclidean geometric algebra of the observer `gamma0`.
+    ///
+    /// The even subalgebra of the spacetime algebra is generated by the relative
+    /// vectors `sigma_k := gamma_k * gamma0`, which satisfy the same relations
+    /// as the Euclidean basis vectors `e_k`. This function applies the algebra
+    /// isomorphism
+    /// ```text
+    /// sigma_1 -> e1, sigma_2 -> e2, sigma_3 -> e3, gamma0123 -> e123
+    /// ```
+    /// so that products of even multivectors map to products of Euclidean
+    /// multivectors. In particular, relative vectors map to Euclidean vectors,
+    /// the bivector `E + I * B` of a field maps to `E + e123 * B`, and spatial
+    /// rotations map to Euclidean rotors. The odd part of `self` is ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let gamma0: SpacetimeMultivector<f64> = SpacetimeMultivector::unit_gamma0();
+    /// let gamma1: SpacetimeMultivector<f64> = SpacetimeMultivector::unit_gamma1();
+    /// let sigma1 = gamma1 * gamma0;
+    /// let expected: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e1();
+    ///
+    /// assert_eq!(sigma1.to_relative(), expected);
+    /// assert_eq!(SpacetimeMultivector::from_relative(&expected), sigma1);
+    /// ```
+    pub fn to_relative(&self) -> EuclideanMultivector3<S> {
+        EuclideanMultivector3::new(
+            self.data[0],
+            -self.data[5],
+            -self.data[6],
+            -self.data[7],
+            -self.data[8],
+            -self.data[10],
+            self.data[9],
+            self.data[15],
+        )
+    }
+
+    /// Map a three-dimensional Euclidean multivector to the even subalgebra of
+    /// the spacetime algebra of the observer `gamma0`.
+    ///
+    /// This is the inverse of [`to_relative`](Self::to_relative). It applies the
+    /// algebra isomorphism
+    /// ```text
+    /// e1 -> sigma_1, e2 -> sigma_2, e3 -> sigma_3, e123 -> gamma0123
+    /// ```
+    /// where `sigma_k := gamma_k * gamma0` are the relative vectors of the observer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let mv1 = EuclideanMultivector3::new(1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64);
+    /// let mv2 = EuclideanMultivector3::new(-2_f64, 1_f64, 0_f64, 3_f64, -1_f64, 2_f64, 4_f64, -3_f64);
+    /// let expected = SpacetimeMultivector::from_relative(&(mv1 * mv2));
+    /// let result = SpacetimeMultivector::from_relative(&mv1) * SpacetimeMultivector::from_relative(&mv2);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn from_relative(mv: &EuclideanMultivector3<S>) -> Self {
+        let mut result = Self::zero();
+        result.data[0] = mv[0];
+        result.data[5] = -mv[1];
+        result.data[6] = -mv[2];
+        result.data[7] = -mv[3];
+        result.data[8] = -mv[4];
+        result.data[9] = mv[6];
+        result.data[10] = -mv[5];
+        result.data[15] = mv[7];
+
+        result
+    }
+
+    /// Construct the rotor of a Lorentz boost with rapidity `rapidity` in the
+    /// direction `direction` relative to the observer `gamma0`.
+    ///
+    /// The boost is the rotor
+    /// ```text
+    /// R := exp((rapidity / 2) * n) == cosh(rapidity / 2) + sinh(rapidity / 2) * n
+    /// ```
+    /// where `n` is the relative vector of the normalized direction. The boost
+    /// takes the observer `gamma0` to the observer moving with speed
+    /// `tanh(rapidity)` in the direction `direction`. Only the grade one part of
+    /// `direction` is used, and it must be nonzero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let direction: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e1();
+    /// let rapidity = 0.5_f64;
+    /// let boost = SpacetimeMultivector::boost(&direction, rapidity);
+    /// let gamma0: SpacetimeMultivector<f64> = SpacetimeMultivector::unit_gamma0();
+    /// let (time, position) = gamma0.transform(&boost).spacetime_split();
+    ///
+    /// assert_relative_eq!(time, rapidity.cosh(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(position, direction * rapidity.sinh(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn boost(direction: &EuclideanMultivector3<S>, rapidity: S) -> Self {
+        let magnitude = (direction[1] * direction[1] + direction[2] * direction[2] + direction[3] * direction[3]).sqrt();
+        let half_rapidity = rapidity / (S::one() + S::one());
+        let factor = half_rapidity.sinh() / magnitude;
+        let mut result = Self::from_scalar(half_rapidity.cosh());
+        result.data[5] = -direction[1] * factor;
+        result.data[6] = -direction[2] * factor;
+        result.data[7] = -direction[3] * factor;
+
+        result
+    }
+
+    /// Construct the rotor of the Lorentz boost that takes the observer `gamma0`
+    /// to an observer moving with the relative velocity `velocity`.
+    ///
+    /// The speed of the velocity is measured in units of the speed of light, so
+    /// the rapidity of the boost is `atanh(|velocity|)`. The function returns
+    /// `None` when the speed is not less than the speed of light. Only the
+    /// grade one part of `velocity` is used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let velocity = EuclideanMultivector3::new(0_f64, 0.3_f64, 0_f64, 0.4_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let boost = SpacetimeMultivector::boost_from_velocity(&velocity).unwrap();
+    /// let gamma0: SpacetimeMultivector<f64> = SpacetimeMultivector::unit_gamma0();
+    /// let (time, position) = gamma0.transform(&boost).spacetime_split();
+    ///
+    /// // The moving observer has the relative velocity `velocity`.
+    /// assert_relative_eq!(position / time, velocity, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert!(SpacetimeMultivector::boost_from_velocity(&(velocity * 2_f64)).is_none());
+    /// ```
+    pub fn boost_from_velocity(velocity: &EuclideanMultivector3<S>) -> Option<Self> {
+        let speed = (velocity[1] * velocity[1] + velocity[2] * velocity[2] + velocity[3] * velocity[3]).sqrt();
+        if speed >= S::one() {
+            return None;
+        }
+
+        if speed.is_zero() {
+            return Some(Self::unit_scalar());
+        }
+
+        Some(Self::boost(velocity, speed.atanh()))
+    }
+
+    /// Construct the rotor of a spatial rotation by an angle about an axis
+    /// relative to the observer `gamma0`.
+    ///
+    /// The rotor is the Euclidean rotor
+    /// [`EuclideanMultivector3::from_axis_angle`] mapped to the even subalgebra
+    /// by [`from_relative`](Self::from_relative). The rotation follows the
+    /// right-hand rule, and leaves `gamma0` fixed. Only the grade one part of
+    /// `axis` is used, and it must be nonzero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// # use core::f64;
+    /// #
+    /// let axis: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e3();
+    /// let rotation = SpacetimeMultivector::rotation(&axis, f64::consts::FRAC_PI_2);
+    /// let gamma0: SpacetimeMultivector<f64> = SpacetimeMultivector::unit_gamma0();
+    /// let gamma1: SpacetimeMultivector<f64> = SpacetimeMultivector::unit_gamma1();
+    /// let gamma2: SpacetimeMultivector<f64> = SpacetimeMultivector::unit_gamma2();
+    ///
+    /// assert_relative_eq!(gamma1.transform(&rotation), gamma2, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(gamma0.transform(&rotation), gamma0, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn rotation(axis: &EuclideanMultivector3<S>, angle: S) -> Self {
+        Self::from_relative(&EuclideanMultivector3::from_axis_angle(axis, angle))
+    }
+
+    /// Apply a Lorentz transformation to a multivector.
+    ///
+    /// The transformed multivector is the sandwich product `R * mv * rev(R)`.
+    /// The rotor `R` is assumed to be normalized, so that its reverse is its
+    /// inverse. Boosts, spatial rotations, and their products all act on
+    /// spacetime this way, and preserve the spacetime interval.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cggeomalg::sta::SpacetimeMultivector;
+    /// # use cggeomalg::e3ga::EuclideanMultivector3;
+    /// #
+    /// let direction: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e2();
+    /// let boost = SpacetimeMultivector::boost(&direction, 1.5_f64);
+    /// let event = SpacetimeMultivector::new(
+    ///     0_f64, 3_f64, 1_f64, -2_f64, 1_f64, 0_f64, 0_f64, 0_f64,
+    ///     0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64,
+    /// );
+    /// let result = event.transform(&boost);
+    ///
+    /// assert_relative_eq!((result * result)[0], (event * event)[0], abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn transform(&self, rotor: &Self) -> Self {
+        rotor * self * rotor.reverse()
+    }
+}
+
+
+impl_coords!(ViewST, { scalar, gamma0, gamma1, gamma2, gamma3, gamma01, gamma02, gamma03, gamma12, gamma13, gamma23, gamma012, gamma013, gamma023, gamma123, gamma0123 });
+impl_coords_deref!(SpacetimeMultivector, ViewST);
+
+
+macro_rules! impl_scalar_multivector_add_ops {
+    ($Lhs:ty => $Rhs:ty => $Output:ty, { $scalar_index:expr }, { $($other_index:expr),* }) => {
+        impl ops::Add<$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn add(self, other: $Rhs) -> $Output {
+                Self::Output::new(self + other[$scalar_index], $(other[$other_index]),* )
+            }
+        }
+
+        impl ops::Add<&$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn add(self, other: &$Rhs) -> $Output {
+                Self::Output::new(self + other[$scalar_index], $(other[$other_index]),* )
+            }
+        }
+    }
+}
+
+impl_scalar_multivector_add_ops!(u8    => SpacetimeMultivector<u8>    => SpacetimeMultivector<u8>,    {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(u16   => SpacetimeMultivector<u16>   => SpacetimeMultivector<u16>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(u32   => SpacetimeMultivector<u32>   => SpacetimeMultivector<u32>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(u64   => SpacetimeMultivector<u64>   => SpacetimeMultivector<u64>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(u128  => SpacetimeMultivector<u128>  => SpacetimeMultivector<u128>,  {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(usize => SpacetimeMultivector<usize> => SpacetimeMultivector<usize>, {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(i8    => SpacetimeMultivector<i8>    => SpacetimeMultivector<i8>,    {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(i16   => SpacetimeMultivector<i16>   => SpacetimeMultivector<i16>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(i32   => SpacetimeMultivector<i32>   => SpacetimeMultivector<i32>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(i64   => SpacetimeMultivector<i64>   => SpacetimeMultivector<i64>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(i128  => SpacetimeMultivector<i128>  => SpacetimeMultivector<i128>,  {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(isize => SpacetimeMultivector<isize> => SpacetimeMultivector<isize>, {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(f32   => SpacetimeMultivector<f32>   => SpacetimeMultivector<f32>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_add_ops!(f64   => SpacetimeMultivector<f64>   => SpacetimeMultivector<f64>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+
+
+macro_rules! impl_scalar_multivector_sub_ops {
+    ($Lhs:ty => $Rhs:ty => $Output:ty, { $scalar_index:expr }, { $($other_index:expr),* }) => {
+        impl ops::Sub<$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn sub(self, other: $Rhs) -> Self::Output {
+                Self::Output::new(self - other[$scalar_index], $(-other[$other_index]),* )
+            }
+        }
+
+        impl ops::Sub<&$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn sub(self, other: &$Rhs) -> Self::Output {
+                Self::Output::new(self - other[$scalar_index], $(-other[$other_index]),* )
+            }
+        }
+    }
+}
+
+impl_scalar_multivector_sub_ops!(i8    => SpacetimeMultivector<i8>    => SpacetimeMultivector<i8>,    {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_sub_ops!(i16   => SpacetimeMultivector<i16>   => SpacetimeMultivector<i16>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_sub_ops!(i32   => SpacetimeMultivector<i32>   => SpacetimeMultivector<i32>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_sub_ops!(i64   => SpacetimeMultivector<i64>   => SpacetimeMultivector<i64>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_sub_ops!(i128  => SpacetimeMultivector<i128>  => SpacetimeMultivector<i128>,  {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_sub_ops!(isize => SpacetimeMultivector<isize> => SpacetimeMultivector<isize>, {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_sub_ops!(f32   => SpacetimeMultivector<f32>   => SpacetimeMultivector<f32>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_sub_ops!(f64   => SpacetimeMultivector<f64>   => SpacetimeMultivector<f64>,   {0}, {1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+
+
+macro_rules! impl_scalar_multivector_mul_ops {
+    ($Lhs:ty => $Rhs:ty => $Output:ty, { $($index:expr),* }) => {
+        impl ops::Mul<$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn mul(self, other: $Rhs) -> Self::Output {
+                Self::Output::new( $(self * other[$index]),* )
+            }
+        }
+
+        impl ops::Mul<&$Rhs> for $Lhs {
+            type Output = $Output;
+
+            #[inline]
+            fn mul(self, other: &$Rhs) -> Self::Output {
+                Self::Output::new( $(self * other[$index]),* )
+            }
+        }
+    }
+}
+
+impl_scalar_multivector_mul_ops!(u8    => SpacetimeMultivector<u8>    => SpacetimeMultivector<u8>,    {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(u16   => SpacetimeMultivector<u16>   => SpacetimeMultivector<u16>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(u32   => SpacetimeMultivector<u32>   => SpacetimeMultivector<u32>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(u64   => SpacetimeMultivector<u64>   => SpacetimeMultivector<u64>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(u128  => SpacetimeMultivector<u128>  => SpacetimeMultivector<u128>,  {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(usize => SpacetimeMultivector<usize> => SpacetimeMultivector<usize>, {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(i8    => SpacetimeMultivector<i8>    => SpacetimeMultivector<i8>,    {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(i16   => SpacetimeMultivector<i16>   => SpacetimeMultivector<i16>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(i32   => SpacetimeMultivector<i32>   => SpacetimeMultivector<i32>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(i64   => SpacetimeMultivector<i64>   => SpacetimeMultivector<i64>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(i128  => SpacetimeMultivector<i128>  => SpacetimeMultivector<i128>,  {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(isize => SpacetimeMultivector<isize> => SpacetimeMultivector<isize>, {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(f32   => SpacetimeMultivector<f32>   => SpacetimeMultivector<f32>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+impl_scalar_multivector_mul_ops!(f64   => SpacetimeMultivector<f64>   => SpacetimeMultivector<f64>,   {0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15});
+
+
+macro_rules! impl_scalar_multivector_bitor_ops {
+    ($Lhs:ty) => {
+        impl ops::BitOr<SpacetimeMultivector<$Lhs>> for $Lhs {
+            type Output = SpacetimeMultivector<$Lhs>;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            #[inline]
+            fn bitor(self, other: SpacetimeMultivector<$Lhs>) -> Self::Output {
+                let mut result = Self::Output::zero();
+                result[0] = self * other[0];
+
+                result
+            }
+        }
+
+        impl ops::BitOr<&SpacetimeMultivector<$Lhs>> for $Lhs {
+            type Output = SpacetimeMultivector<$Lhs>;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            #[inline]
+            fn bitor(self, other: &SpacetimeMultivector<$Lhs>) -> Self::Output {
+                let mut result = Self::Output::zero();
+                result[0] = self * other[0];
+
+                result
+            }
+        }
+    };
+}
+
+impl_scalar_multivector_bitor_ops!(u8);
+impl_scalar_multivector_bitor_ops!(u16);
+impl_scalar_multivector_bitor_ops!(u32);
+impl_scalar_multivector_bitor_ops!(u64);
+impl_scalar_multivector_bitor_ops!(u128);
+impl_scalar_multivector_bitor_ops!(usize);
+impl_scalar_multivector_bitor_ops!(i8);
+impl_scalar_multivector_bitor_ops!(i16);
+impl_scalar_multivector_bitor_ops!(i32);
+impl_scalar_multivector_bitor_ops!(i64);
+impl_scalar_multivector_bitor_ops!(i128);
+impl_scalar_multivector_bitor_ops!(isize);
+impl_scalar_multivector_bitor_ops!(f32);
+impl_scalar_multivector_bitor_ops!(f64);
+
+
+macro_rules! impl_scalar_multivector_bitxor_ops {
+    ($Lhs:ty) => {
+        impl ops::BitXor<SpacetimeMultivector<$Lhs>> for $Lhs {
+            type Output = SpacetimeMultivector<$Lhs>;
+
+            #[inline]
+            fn bitxor(self, other: SpacetimeMultivector<$Lhs>) -> Self::Output {
+                let mut result = Self::Output::zero();
+                result[0] = self * other[0];
+                result[1] = self * other[1];
+                result[2] = self * other[2];
+                result[3] = self * other[3];
+                result[4] = self * other[4];
+                result[5] = self * other[5];
+                result[6] = self * other[6];
+                result[7] = self * other[7];
+                result[8] = self * other[8];
+                result[9] = self * other[9];
+                result[10] = self * other[10];
+                result[11] = self * other[11];
+                result[12] = self * other[12];
+                result[13] = self * other[13];
+                result[14] = self * other[14];
+                result[15] = self * other[15];
+
+                result
+            }
+        }
+
+        impl ops::BitXor<&SpacetimeMultivector<$Lhs>> for $Lhs {
+            type Output = SpacetimeMultivector<$Lhs>;
+
+            #[inline]
+            fn bitxor(self, other: &SpacetimeMultivector<$Lhs>) -> Self::Output {
+                let mut result = Self::Output::zero();
+                result[0] = self * other[0];
+                result[1] = self * other[1];
+                result[2] = self * other[2];
+                result[3] = self * other[3];
+                result[4] = self * other[4];
+                result[5] = self * other[5];
+                result[6] = self * other[6];
+                result[7] = self * other[7];
+                result[8] = self * other[8];
+                result[9] = self * other[9];
+                result[10] = self * other[10];
+                result[11] = self * other[11];
+                result[12] = self * other[12];
+                result[13] = self * other[13];
+                result[14] = self * other[14];
+                result[15] = self * other[15];
+
+                result
+            }
+        }
+    };
+}
+
+impl_scalar_multivector_bitxor_ops!(u8);
+impl_scalar_multivector_bitxor_ops!(u16);
+impl_scalar_multivector_bitxor_ops!(u32);
+impl_scalar_multivector_bitxor_ops!(u64);
+impl_scalar_multivector_bitxor_ops!(u128);
+impl_scalar_multivector_bitxor_ops!(usize);
+impl_scalar_multivector_bitxor_ops!(i8);
+impl_scalar_multivector_bitxor_ops!(i16);
+impl_scalar_multivector_bitxor_ops!(i32);
+impl_scalar_multivector_bitxor_ops!(i64);
+impl_scalar_multivector_bitxor_ops!(i128);
+impl_scalar_multivector_bitxor_ops!(isize);
+impl_scalar_multivector_bitxor_ops!(f32);
+impl_scalar_multivector_bitxor_ops!(f64);
@@ -0,0 +1,1258 @@
+#[cfg(test)]
+mod cga2_component_tests {
+    use cggeomalg::cga2::ConformalMultivector2;
+
+
+    #[test]
+    fn test_components1() {
+        let mv = ConformalMultivector2::new(
+            1_isize, 2_isize, 3_isize, 4_isize, 5_isize, 6_isize, 7_isize, 8_isize, 9_isize, 10_isize, 11_isize, 12_isize, 13_isize,
+            14_isize, 15_isize, 16_isize,
+        );
+
+        assert_eq!(mv[0], 1);
+        assert_eq!(mv[1], 2);
+        assert_eq!(mv[2], 3);
+        assert_eq!(mv[3], 4);
+        assert_eq!(mv[4], 5);
+        assert_eq!(mv[5], 6);
+        assert_eq!(mv[6], 7);
+        assert_eq!(mv[7], 8);
+        assert_eq!(mv[8], 9);
+        assert_eq!(mv[9], 10);
+        assert_eq!(mv[10], 11);
+        assert_eq!(mv[11], 12);
+        assert_eq!(mv[12], 13);
+        assert_eq!(mv[13], 14);
+        assert_eq!(mv[14], 15);
+        assert_eq!(mv[15], 16);
+    }
+
+    #[test]
+    fn test_components2() {
+        let mv = ConformalMultivector2::new(
+            1_isize, 2_isize, 3_isize, 4_isize, 5_isize, 6_isize, 7_isize, 8_isize, 9_isize, 10_isize, 11_isize, 12_isize, 13_isize,
+            14_isize, 15_isize, 16_isize,
+        );
+
+        assert_eq!(mv.scalar, mv[0]);
+        assert_eq!(mv.e0, mv[1]);
+        assert_eq!(mv.e1, mv[2]);
+        assert_eq!(mv.e2, mv[3]);
+        assert_eq!(mv.einf, mv[4]);
+        assert_eq!(mv.e01, mv[5]);
+        assert_eq!(mv.e02, mv[6]);
+        assert_eq!(mv.e12, mv[7]);
+        assert_eq!(mv.e1inf, mv[8]);
+        assert_eq!(mv.e2inf, mv[9]);
+        assert_eq!(mv.e0inf, mv[10]);
+        assert_eq!(mv.e012, mv[11]);
+        assert_eq!(mv.e01inf, mv[12]);
+        assert_eq!(mv.e02inf, mv[13]);
+        assert_eq!(mv.e12inf, mv[14]);
+        assert_eq!(mv.e012inf, mv[15]);
+    }
+
+    #[test]
+    fn test_as_ref() {
+        let mv = ConformalMultivector2::new(
+            1_isize, 2_isize, 3_isize, 4_isize, 5_isize, 6_isize, 7_isize, 8_isize, 9_isize, 10_isize, 11_isize, 12_isize, 13_isize,
+            14_isize, 15_isize, 16_isize,
+        );
+        let v_ref: &[isize; 16] = mv.as_ref();
+
+        assert_eq!(v_ref, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+    }
+
+    #[test]
+    fn test_as_mut() {
+        let mut mv = ConformalMultivector2::new(
+            1_isize, 2_isize, 3_isize, 4_isize, 5_isize, 6_isize, 7_isize, 8_isize, 9_isize, 10_isize, 11_isize, 12_isize, 13_isize,
+            14_isize, 15_isize, 16_isize,
+        );
+        let v_ref: &mut [isize; 16] = mv.as_mut();
+
+        assert_eq!(v_ref, &mut [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_out_of_bounds_array_access() {
+        let mv = ConformalMultivector2::new(
+            1_isize, 2_isize, 3_isize, 4_isize, 5_isize, 6_isize, 7_isize, 8_isize, 9_isize, 10_isize, 11_isize, 12_isize, 13_isize,
+            14_isize, 15_isize, 16_isize,
+        );
+
+        assert_eq!(mv[16], mv[16]);
+    }
+}
+
+
+#[cfg(test)]
+mod cga2_addition_subtraction_tests {
+    use cggeomalg::cga2::ConformalMultivector2;
+
+
+    #[test]
+    fn test_multivector_addition() {
+        let mv1 = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ConformalMultivector2::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = ConformalMultivector2::new(
+            4_i32, 1_i32, 7_i32, 5_i32, 0_i32, 15_i32, 9_i32, 2_i32, 14_i32, 13_i32, 6_i32, 20_i32, 22_i32, 7_i32, 24_i32, 19_i32,
+        );
+        let result = mv1 + mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_multivector_subtraction() {
+        let mv1 = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ConformalMultivector2::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = ConformalMultivector2::new(
+            -2_i32, 3_i32, -1_i32, 3_i32, 10_i32, -3_i32, 5_i32, 14_i32, 4_i32, 7_i32, 16_i32, 4_i32, 4_i32, 21_i32, 6_i32, 13_i32,
+        );
+        let result = mv1 - mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_multivector_minus_multivector_is_zero() {
+        let mv = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let zero: ConformalMultivector2<i32> = ConformalMultivector2::zero();
+
+        assert_eq!(mv - mv, zero);
+    }
+
+    #[test]
+    fn test_scalar_minus_multivector() {
+        let mv = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let expected = ConformalMultivector2::new(
+            4_i32, -2_i32, -3_i32, -4_i32, -5_i32, -6_i32, -7_i32, -8_i32, -9_i32, -10_i32, -11_i32, -12_i32, -13_i32, -14_i32, -15_i32,
+            -16_i32,
+        );
+        let result = 5_i32 - mv;
+
+        assert_eq!(result, expected);
+        assert_eq!(5_i32 - mv, ConformalMultivector2::from_scalar(5_i32) - mv);
+    }
+
+    #[test]
+    fn test_scalar_plus_multivector() {
+        let mv = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+
+        assert_eq!(5_i32 + mv, mv + 5_i32);
+        assert_eq!(5_i32 + mv, ConformalMultivector2::from_scalar(5_i32) + mv);
+    }
+}
+
+
+#[cfg(test)]
+mod cga2_multiplication_division_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::cga2::ConformalMultivector2;
+
+
+    #[test]
+    fn test_scalar_multiplication() {
+        let mv = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let expected = ConformalMultivector2::new(
+            3_i32, 6_i32, 9_i32, 12_i32, 15_i32, 18_i32, 21_i32, 24_i32, 27_i32, 30_i32, 33_i32, 36_i32, 39_i32, 42_i32, 45_i32, 48_i32,
+        );
+
+        assert_eq!(mv * 3_i32, expected);
+        assert_eq!(3_i32 * mv, expected);
+    }
+
+    #[test]
+    fn test_scalar_division() {
+        let mv = ConformalMultivector2::new(
+            1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64, 9_f64, 10_f64, 11_f64, 12_f64, 13_f64, 14_f64, 15_f64, 16_f64,
+        );
+        let expected = mv * (1_f64 / 9_f64);
+        let result = mv / 9_f64;
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_geometric_product_euclidean_basis_squares() {
+        let one: ConformalMultivector2<i32> = ConformalMultivector2::unit_scalar();
+
+        assert_eq!(ConformalMultivector2::unit_e1() * ConformalMultivector2::unit_e1(), one);
+        assert_eq!(ConformalMultivector2::unit_e2() * ConformalMultivector2::unit_e2(), one);
+    }
+
+    #[test]
+    fn test_geometric_product_null_basis() {
+        let e0: ConformalMultivector2<i32> = ConformalMultivector2::unit_e0();
+        let einf: ConformalMultivector2<i32> = ConformalMultivector2::unit_einf();
+        let e0inf: ConformalMultivector2<i32> = ConformalMultivector2::unit_e0inf();
+        let one: ConformalMultivector2<i32> = ConformalMultivector2::unit_scalar();
+        let zero: ConformalMultivector2<i32> = ConformalMultivector2::zero();
+
+        assert_eq!(e0 * e0, zero);
+        assert_eq!(einf * einf, zero);
+        assert_eq!(e0 * einf, e0inf - one);
+        assert_eq!(einf * e0, -e0inf - one);
+        assert_eq!(e0inf * e0inf, one);
+    }
+
+    #[test]
+    fn test_geometric_product_pseudoscalar_squares_to_minus_one() {
+        let e012inf: ConformalMultivector2<i32> = ConformalMultivector2::unit_e012inf();
+        let one: ConformalMultivector2<i32> = ConformalMultivector2::unit_scalar();
+
+        assert_eq!(e012inf * e012inf, -one);
+    }
+
+    #[test]
+    fn test_geometric_product() {
+        let mv1 = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ConformalMultivector2::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = ConformalMultivector2::new(
+            74_i32, -48_i32, 132_i32, 243_i32, -238_i32, 149_i32, -14_i32, -153_i32, 459_i32, 100_i32, -8_i32, 111_i32, -18_i32, -149_i32,
+            267_i32, 33_i32,
+        );
+        let result = mv1 * mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_geometric_product_references() {
+        let mv1 = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ConformalMultivector2::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = mv1 * mv2;
+
+        assert_eq!(&mv1 * mv2, expected);
+        assert_eq!(mv1 * &mv2, expected);
+        assert_eq!(&mv1 * &mv2, expected);
+    }
+
+    #[test]
+    fn test_geometric_product_associative() {
+        let mv1 = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ConformalMultivector2::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let mv3 = ConformalMultivector2::new(
+            2_i32, 0_i32, -1_i32, 3_i32, 1_i32, -2_i32, 4_i32, 0_i32, 1_i32, -3_i32, 2_i32, 5_i32, -1_i32, 0_i32, 2_i32, -4_i32,
+        );
+
+        assert_eq!((mv1 * mv2) * mv3, mv1 * (mv2 * mv3));
+    }
+}
+
+
+#[cfg(test)]
+mod cga2_outer_product_tests {
+    use cggeomalg::cga2::ConformalMultivector2;
+
+
+    #[test]
+    fn test_outer_product_e1_e2() {
+        let e1: ConformalMultivector2<i32> = ConformalMultivector2::unit_e1();
+        let e2: ConformalMultivector2<i32> = ConformalMultivector2::unit_e2();
+        let e12: ConformalMultivector2<i32> = ConformalMultivector2::unit_e12();
+
+        assert_eq!(e1 ^ e2, e12);
+        assert_eq!(e2 ^ e1, -e12);
+    }
+
+    #[test]
+    fn test_outer_product_null_vectors() {
+        let e0: ConformalMultivector2<i32> = ConformalMultivector2::unit_e0();
+        let einf: ConformalMultivector2<i32> = ConformalMultivector2::unit_einf();
+        let e0inf: ConformalMultivector2<i32> = ConformalMultivector2::unit_e0inf();
+        let zero: ConformalMultivector2<i32> = ConformalMultivector2::zero();
+
+        assert_eq!(e0 ^ e0, zero);
+        assert_eq!(e0 ^ einf, e0inf);
+        assert_eq!(einf ^ e0, -e0inf);
+    }
+
+    #[test]
+    fn test_outer_product_vectors_pseudoscalar() {
+        let e0: ConformalMultivector2<i32> = ConformalMultivector2::unit_e0();
+        let e1: ConformalMultivector2<i32> = ConformalMultivector2::unit_e1();
+        let e2: ConformalMultivector2<i32> = ConformalMultivector2::unit_e2();
+        let einf: ConformalMultivector2<i32> = ConformalMultivector2::unit_einf();
+        let e012inf: ConformalMultivector2<i32> = ConformalMultivector2::unit_e012inf();
+
+        assert_eq!(e0 ^ e1 ^ e2 ^ einf, e012inf);
+    }
+
+    #[test]
+    fn test_outer_product() {
+        let mv1 = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ConformalMultivector2::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = ConformalMultivector2::new(
+            3_i32, 5_i32, 13_i32, 13_i32, 10_i32, 38_i32, 29_i32, 5_i32, -3_i32, 8_i32, 23_i32, 32_i32, 35_i32, 15_i32, 4_i32, 33_i32,
+        );
+        let result = mv1 ^ mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_outer_product_references() {
+        let mv1 = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ConformalMultivector2::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = mv1 ^ mv2;
+
+        assert_eq!(&mv1 ^ mv2, expected);
+        assert_eq!(mv1 ^ &mv2, expected);
+        assert_eq!(&mv1 ^ &mv2, expected);
+        assert_eq!(mv1.outer_product(&mv2), expected);
+    }
+
+    #[test]
+    fn test_outer_product_associative() {
+        let mv1 = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ConformalMultivector2::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let mv3 = ConformalMultivector2::new(
+            2_i32, 0_i32, -1_i32, 3_i32, 1_i32, -2_i32, 4_i32, 0_i32, 1_i32, -3_i32, 2_i32, 5_i32, -1_i32, 0_i32, 2_i32, -4_i32,
+        );
+
+        assert_eq!((mv1 ^ mv2) ^ mv3, mv1 ^ (mv2 ^ mv3));
+    }
+}
+
+
+#[cfg(test)]
+mod cga2_grade_tests {
+    use cggeomalg::cga2::ConformalMultivector2;
+
+
+    #[test]
+    fn test_grade_decomposition() {
+        let mv = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let result = mv.grade(0) + mv.grade(1) + mv.grade(2) + mv.grade(3) + mv.grade(4);
+
+        assert_eq!(result, mv);
+    }
+
+    #[test]
+    fn test_grade_projection() {
+        let mv = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+
+        assert_eq!(mv.grade(0), ConformalMultivector2::from_scalar(1_i32));
+        assert_eq!(
+            mv.grade(2),
+            ConformalMultivector2::new(
+                0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32,
+            )
+        );
+        assert_eq!(mv.grade(4), ConformalMultivector2::unit_e012inf() * 16_i32);
+        assert!(mv.grade(5).is_zero());
+    }
+
+    #[test]
+    fn test_reverse_reverse() {
+        let mv = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+
+        assert_eq!(mv.reverse().reverse(), mv);
+    }
+
+    #[test]
+    fn test_reverse_product() {
+        let mv1 = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ConformalMultivector2::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+
+        assert_eq!((mv1 * mv2).reverse(), mv2.reverse() * mv1.reverse());
+    }
+
+    #[test]
+    fn test_involute_product() {
+        let mv1 = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ConformalMultivector2::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+
+        assert_eq!((mv1 * mv2).involute(), mv1.involute() * mv2.involute());
+    }
+
+    #[test]
+    fn test_conjugate_is_reverse_of_involute() {
+        let mv = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+
+        assert_eq!(mv.conjugate(), mv.involute().reverse());
+    }
+}
+
+
+#[cfg(test)]
+mod cga2_scalar_product_tests {
+    use cggeomalg::cga2::ConformalMultivector2;
+
+
+    #[test]
+    fn test_scalar_product_null_basis() {
+        let e0: ConformalMultivector2<i32> = ConformalMultivector2::unit_e0();
+        let einf: ConformalMultivector2<i32> = ConformalMultivector2::unit_einf();
+        let zero: ConformalMultivector2<i32> = ConformalMultivector2::zero();
+
+        assert_eq!(e0 | e0, zero);
+        assert_eq!(einf | einf, zero);
+        assert_eq!(e0 | einf, ConformalMultivector2::from_scalar(-1_i32));
+        assert_eq!(einf | e0, ConformalMultivector2::from_scalar(-1_i32));
+    }
+
+    #[test]
+    fn test_scalar_product_is_scalar_part_of_geometric_product() {
+        let mv1 = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ConformalMultivector2::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = (mv1 * mv2.reverse()).grade(0);
+        let result = mv1 | mv2;
+
+        assert_eq!(result, expected);
+        assert_eq!(result, ConformalMultivector2::from_scalar(-102_i32));
+    }
+
+    #[test]
+    fn test_scalar_product_references() {
+        let mv1 = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ConformalMultivector2::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = mv1 | mv2;
+
+        assert_eq!(&mv1 | mv2, expected);
+        assert_eq!(mv1 | &mv2, expected);
+        assert_eq!(&mv1 | &mv2, expected);
+        assert_eq!(mv1.scalar_product(&mv2), expected);
+    }
+}
+
+
+#[cfg(test)]
+mod cga2_left_contraction_tests {
+    use cggeomalg::cga2::ConformalMultivector2;
+
+
+    #[test]
+    fn test_left_contraction_vector_bivector() {
+        let e0: ConformalMultivector2<i32> = ConformalMultivector2::unit_e0();
+        let e1: ConformalMultivector2<i32> = ConformalMultivector2::unit_e1();
+        let e2: ConformalMultivector2<i32> = ConformalMultivector2::unit_e2();
+        let einf: ConformalMultivector2<i32> = ConformalMultivector2::unit_einf();
+        let e12: ConformalMultivector2<i32> = ConformalMultivector2::unit_e12();
+        let e0inf: ConformalMultivector2<i32> = ConformalMultivector2::unit_e0inf();
+
+        assert_eq!(e1 << e12, e2);
+        assert_eq!(e2 << e12, -e1);
+        assert_eq!(e0 << e0inf, e0);
+        assert_eq!(einf << e0inf, -einf);
+    }
+
+    #[test]
+    fn test_left_contraction_higher_grade_is_zero() {
+        let e12: ConformalMultivector2<i32> = ConformalMultivector2::unit_e12();
+        let e1: ConformalMultivector2<i32> = ConformalMultivector2::unit_e1();
+        let zero: ConformalMultivector2<i32> = ConformalMultivector2::zero();
+
+        assert_eq!(e12 << e1, zero);
+    }
+
+    #[test]
+    fn test_left_contraction() {
+        let mv1 = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ConformalMultivector2::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = ConformalMultivector2::new(
+            74_i32, -151_i32, -47_i32, -1_i32, 9_i32, 2_i32, 10_i32, -31_i32, -46_i32, 38_i32, -28_i32, 14_i32, 21_i32, -16_i32, -6_i32,
+            3_i32,
+        );
+        let result = mv1 << mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_left_contraction_references() {
+        let mv1 = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ConformalMultivector2::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = mv1 << mv2;
+
+        assert_eq!(&mv1 << mv2, expected);
+        assert_eq!(mv1 << &mv2, expected);
+        assert_eq!(&mv1 << &mv2, expected);
+        assert_eq!(mv1.left_contract(&mv2), expected);
+    }
+}
+
+
+#[cfg(test)]
+mod cga2_right_contraction_tests {
+    use cggeomalg::cga2::ConformalMultivector2;
+
+
+    #[test]
+    fn test_right_contraction_bivector_vector() {
+        let e1: ConformalMultivector2<i32> = ConformalMultivector2::unit_e1();
+        let e2: ConformalMultivector2<i32> = ConformalMultivector2::unit_e2();
+        let e12: ConformalMultivector2<i32> = ConformalMultivector2::unit_e12();
+        let zero: ConformalMultivector2<i32> = ConformalMultivector2::zero();
+
+        assert_eq!(e12 >> e2, e1);
+        assert_eq!(e12 >> e1, -e2);
+        assert_eq!(e1 >> e12, zero);
+    }
+
+    #[test]
+    fn test_right_contraction_reverse() {
+        let mv1 = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ConformalMultivector2::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+
+        assert_eq!((mv1 >> mv2).reverse(), mv2.reverse() << mv1.reverse());
+    }
+
+    #[test]
+    fn test_right_contraction() {
+        let mv1 = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ConformalMultivector2::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = ConformalMultivector2::new(
+            74_i32, 103_i32, 179_i32, 244_i32, -247_i32, 11_i32, 131_i32, 19_i32, 125_i32, 80_i32, 63_i32, 52_i32, 23_i32, 106_i32,
+            -35_i32, 48_i32,
+        );
+        let result = mv1 >> mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_right_contraction_references() {
+        let mv1 = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ConformalMultivector2::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = mv1 >> mv2;
+
+        assert_eq!(&mv1 >> mv2, expected);
+        assert_eq!(mv1 >> &mv2, expected);
+        assert_eq!(&mv1 >> &mv2, expected);
+        assert_eq!(mv1.right_contract(&mv2), expected);
+    }
+}
+
+
+#[cfg(test)]
+mod cga2_duality_tests {
+    use cggeomalg::cga2::ConformalMultivector2;
+
+
+    #[test]
+    fn test_dual_is_product_with_inverse_pseudoscalar() {
+        let mv = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let inv_ps = -ConformalMultivector2::unit_e012inf();
+
+        assert_eq!(mv.dual(), mv * inv_ps);
+    }
+
+    #[test]
+    fn test_undual_is_product_with_pseudoscalar() {
+        let mv = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let ps: ConformalMultivector2<i32> = ConformalMultivector2::unit_e012inf();
+
+        assert_eq!(mv.undual(), mv * ps);
+    }
+
+    #[test]
+    fn test_dual_undual() {
+        let mv = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+
+        assert_eq!(mv.dual().undual(), mv);
+        assert_eq!(mv.undual().dual(), mv);
+    }
+
+    #[test]
+    fn test_not_is_dual() {
+        let mv = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+
+        assert_eq!(!mv, mv.dual());
+        assert_eq!(!&mv, mv.dual());
+    }
+
+    #[test]
+    fn test_dual_mut() {
+        let mv = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mut result = mv;
+        result.dual_mut();
+
+        assert_eq!(result, mv.dual());
+    }
+
+    #[test]
+    fn test_pseudoscalar_inverse() {
+        let ps: ConformalMultivector2<f64> = ConformalMultivector2::pseudoscalar();
+        let ps_inv: ConformalMultivector2<f64> = ConformalMultivector2::inv_pseudoscalar();
+        let one: ConformalMultivector2<f64> = ConformalMultivector2::unit_scalar();
+
+        assert_eq!(ps * ps_inv, one);
+    }
+}
+
+
+#[cfg(test)]
+mod cga2_regressive_product_tests {
+    use cggeomalg::cga2::ConformalMultivector2;
+
+
+    #[test]
+    fn test_regressive_product() {
+        let mv1 = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ConformalMultivector2::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = ConformalMultivector2::new(
+            -33_i32, 13_i32, 400_i32, 377_i32, 81_i32, 158_i32, 249_i32, -60_i32, 125_i32, -153_i32, 170_i32, 164_i32, 183_i32, -70_i32,
+            189_i32, 48_i32,
+        );
+        let result = mv1 & mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_regressive_product_references() {
+        let mv1 = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ConformalMultivector2::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = mv1 & mv2;
+
+        assert_eq!(&mv1 & mv2, expected);
+        assert_eq!(mv1 & &mv2, expected);
+        assert_eq!(&mv1 & &mv2, expected);
+        assert_eq!(mv1.regressive_product(&mv2), expected);
+    }
+
+    #[test]
+    fn test_regressive_product_dual_outer_product() {
+        let mv1 = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ConformalMultivector2::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = (mv1.dual() ^ mv2.dual()).undual();
+        let result = mv1 & mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_regressive_product_pseudoscalar_unit() {
+        let e012inf: ConformalMultivector2<i32> = ConformalMultivector2::unit_e012inf();
+        let mv = ConformalMultivector2::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+
+        assert_eq!(e012inf & mv, mv);
+        assert_eq!(mv & e012inf, mv);
+    }
+}
+
+
+#[cfg(test)]
+mod cga2_conformal_embedding_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::cga2::ConformalMultivector2;
+    use cggeomalg::e2ga::EuclideanMultivector2;
+
+
+    #[test]
+    fn test_up_is_null() {
+        let x = EuclideanMultivector2::new(0_f64, 1_f64, -2_f64, 0_f64);
+        let point = ConformalMultivector2::up(&x);
+        let zero: ConformalMultivector2<f64> = ConformalMultivector2::zero();
+
+        assert_eq!(point * point, zero);
+    }
+
+    #[test]
+    fn test_up_components() {
+        let x = EuclideanMultivector2::new(0_f64, 1_f64, -2_f64, 0_f64);
+        let expected = ConformalMultivector2::unit_e0() + ConformalMultivector2::unit_e1() - ConformalMultivector2::unit_e2() * 2_f64
+            + ConformalMultivector2::unit_einf() * 2.5_f64;
+        let result = ConformalMultivector2::up(&x);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_up_origin() {
+        let origin: EuclideanMultivector2<f64> = EuclideanMultivector2::zero();
+        let expected: ConformalMultivector2<f64> = ConformalMultivector2::unit_e0();
+
+        assert_eq!(ConformalMultivector2::up(&origin), expected);
+    }
+
+    #[test]
+    fn test_up_ignores_non_vector_parts() {
+        let x = EuclideanMultivector2::new(4_f64, 1_f64, 2_f64, 5_f64);
+        let expected = ConformalMultivector2::up(&EuclideanMultivector2::new(0_f64, 1_f64, 2_f64, 0_f64));
+
+        assert_eq!(ConformalMultivector2::up(&x), expected);
+    }
+
+    #[test]
+    fn test_down_up() {
+        let x = EuclideanMultivector2::new(0_f64, 1_f64, -2_f64, 0_f64);
+
+        assert_relative_eq!(
+            ConformalMultivector2::up(&x).down().unwrap(),
+            x,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_down_weighted_point() {
+        let x = EuclideanMultivector2::new(0_f64, 1_f64, -2_f64, 0_f64);
+        let point = ConformalMultivector2::up(&x) * -4_f64;
+
+        assert_relative_eq!(point.down().unwrap(), x, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_down_origin() {
+        let origin: ConformalMultivector2<f64> = ConformalMultivector2::unit_e0();
+        let zero = EuclideanMultivector2::zero();
+
+        assert_eq!(origin.down(), Some(zero));
+    }
+
+    #[test]
+    fn test_down_point_at_infinity() {
+        let direction = ConformalMultivector2::unit_e1() * 2_f64 - ConformalMultivector2::unit_e2();
+        let einf: ConformalMultivector2<f64> = ConformalMultivector2::unit_einf();
+
+        assert!(direction.down().is_none());
+        assert!(einf.down().is_none());
+        assert!(ConformalMultivector2::<f64>::zero().down().is_none());
+    }
+
+    #[test]
+    fn test_scalar_product_squared_distance() {
+        let x = EuclideanMultivector2::new(0_f64, 1_f64, 2_f64, 0_f64);
+        let y = EuclideanMultivector2::new(0_f64, -3_f64, 5_f64, 0_f64);
+        let expected = ConformalMultivector2::from_scalar(-12.5_f64);
+        let result = ConformalMultivector2::up(&x) | ConformalMultivector2::up(&y);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_scalar_product_point_infinity() {
+        let x = EuclideanMultivector2::new(0_f64, 1_f64, 2_f64, 0_f64);
+        let einf: ConformalMultivector2<f64> = ConformalMultivector2::unit_einf();
+        let expected = ConformalMultivector2::from_scalar(-1_f64);
+
+        assert_eq!(ConformalMultivector2::up(&x) | einf, expected);
+    }
+}
+
+
+#[cfg(test)]
+mod cga2_geometric_object_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::cga2::ConformalMultivector2;
+    use cggeomalg::e2ga::EuclideanMultivector2;
+
+
+    #[test]
+    fn test_circle_contains_points() {
+        let center = EuclideanMultivector2::new(0_f64, 1_f64, 2_f64, 0_f64);
+        let circle = ConformalMultivector2::from_circle(&center, 3_f64);
+        let zero: ConformalMultivector2<f64> = ConformalMultivector2::zero();
+
+        assert_eq!(circle.grade(3), circle);
+        assert_relative_eq!(
+            ConformalMultivector2::up(&EuclideanMultivector2::new(0_f64, 4_f64, 2_f64, 0_f64)) ^ circle,
+            zero,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            ConformalMultivector2::up(&EuclideanMultivector2::new(0_f64, 1_f64, -1_f64, 0_f64)) ^ circle,
+            zero,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert!(!(ConformalMultivector2::up(&EuclideanMultivector2::new(0_f64, 1_f64, 4_f64, 0_f64)) ^ circle).is_zero());
+    }
+
+    #[test]
+    fn test_circle_through_three_points() {
+        let circle = ConformalMultivector2::from_three_points(
+            &EuclideanMultivector2::new(0_f64, 4_f64, 2_f64, 0_f64),
+            &EuclideanMultivector2::new(0_f64, 1_f64, 5_f64, 0_f64),
+            &EuclideanMultivector2::new(0_f64, -2_f64, 2_f64, 0_f64),
+        );
+        let expected = ConformalMultivector2::from_circle(&EuclideanMultivector2::new(0_f64, 1_f64, 2_f64, 0_f64), 3_f64);
+        let scale = circle.e012 / expected.e012;
+
+        assert!(scale > 0_f64);
+        assert_relative_eq!(circle, expected * scale, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_circle_through_three_collinear_points_is_line() {
+        let circle = ConformalMultivector2::from_three_points(
+            &EuclideanMultivector2::new(0_f64, 0_f64, 1_f64, 0_f64),
+            &EuclideanMultivector2::new(0_f64, 1_f64, 2_f64, 0_f64),
+            &EuclideanMultivector2::new(0_f64, 2_f64, 3_f64, 0_f64),
+        );
+        let einf: ConformalMultivector2<f64> = ConformalMultivector2::unit_einf();
+        let zero: ConformalMultivector2<f64> = ConformalMultivector2::zero();
+
+        assert!(!circle.is_zero());
+        assert_relative_eq!(einf ^ circle, zero, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert!(circle.center_radius().is_none());
+    }
+
+    #[test]
+    fn test_circle_through_three_collinear_non_integer_points_is_line() {
+        let origin = EuclideanMultivector2::new(0_f64, 0.3_f64, -1.7_f64, 0_f64);
+        let direction = EuclideanMultivector2::new(0_f64, 0.71_f64, 0.13_f64, 0_f64);
+        for i in 0..7 {
+            for j in 0..7 {
+                let p = origin * (1_f64 + 0.37_f64 * i as f64);
+                let q = p + direction * (0.1_f64 + 0.53_f64 * j as f64);
+                let r = p - direction * (2.9_f64 - 0.29_f64 * j as f64);
+                let circle = ConformalMultivector2::from_three_points(&p, &q, &r);
+
+                assert!(circle.center_radius().is_none(), "i = {}, j = {}", i, j);
+            }
+        }
+    }
+
+    #[test]
+    fn test_center_radius_nearly_collinear_points() {
+        let p = EuclideanMultivector2::new(0_f64, 0.3_f64, -1.7_f64, 0_f64);
+        let q = EuclideanMultivector2::new(0_f64, 1.01_f64, -1.57_f64, 0_f64);
+        let r = EuclideanMultivector2::new(0_f64, 2.43_f64, -1.31_f64 + 1e-6_f64, 0_f64);
+        let circle = ConformalMultivector2::from_three_points(&p, &q, &r);
+
+        assert!(circle.center_radius().is_some());
+    }
+
+    #[test]
+    fn test_center_radius() {
+        let center = EuclideanMultivector2::new(0_f64, -3_f64, 4_f64, 0_f64);
+        let circle = ConformalMultivector2::from_circle(&center, 5_f64) * -2_f64;
+        let (result_center, result_radius) = circle.center_radius().unwrap();
+
+        assert_relative_eq!(result_center, center, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result_radius, 5_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_center_radius_imaginary_circle() {
+        let mut dual_circle = ConformalMultivector2::up(&EuclideanMultivector2::new(0_f64, 1_f64, 2_f64, 0_f64));
+        dual_circle.einf += 2_f64;
+        let circle = dual_circle.undual();
+
+        assert!(circle.center_radius().is_none());
+    }
+
+    #[test]
+    fn test_line_contains_points() {
+        let point = EuclideanMultivector2::new(0_f64, 1_f64, 2_f64, 0_f64);
+        let direction = EuclideanMultivector2::new(0_f64, 1_f64, 1_f64, 0_f64);
+        let line = ConformalMultivector2::from_line(&point, &direction);
+        let zero: ConformalMultivector2<f64> = ConformalMultivector2::zero();
+
+        assert_eq!(line.grade(3), line);
+        assert_relative_eq!(
+            ConformalMultivector2::up(&EuclideanMultivector2::new(0_f64, 4_f64, 5_f64, 0_f64)) ^ line,
+            zero,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert!(!(ConformalMultivector2::up(&EuclideanMultivector2::new(0_f64, 4_f64, 4_f64, 0_f64)) ^ line).is_zero());
+    }
+
+    #[test]
+    fn test_line_through_origin() {
+        let origin: EuclideanMultivector2<f64> = EuclideanMultivector2::zero();
+        let direction = EuclideanMultivector2::new(0_f64, 1_f64, 0_f64, 0_f64);
+        let line = ConformalMultivector2::from_line(&origin, &direction);
+        let expected: ConformalMultivector2<f64> = ConformalMultivector2::unit_e01inf();
+
+        assert_eq!(line, expected);
+    }
+
+    #[test]
+    fn test_flat_point() {
+        let flat_point = ConformalMultivector2::from_flat_point(&EuclideanMultivector2::new(0_f64, 1_f64, 2_f64, 0_f64));
+        let expected =
+            ConformalMultivector2::up(&EuclideanMultivector2::new(0_f64, 1_f64, 2_f64, 0_f64)) ^ ConformalMultivector2::unit_einf();
+
+        assert_eq!(flat_point.grade(2), flat_point);
+        assert_eq!(flat_point, expected);
+    }
+
+    #[test]
+    fn test_point_pair_split() {
+        let p = EuclideanMultivector2::new(0_f64, 1_f64, 2_f64, 0_f64);
+        let q = EuclideanMultivector2::new(0_f64, -4_f64, 5_f64, 0_f64);
+        let point_pair = ConformalMultivector2::from_point_pair(&p, &q);
+        let (result_p, result_q) = point_pair.split_point_pair().unwrap();
+
+        assert_relative_eq!(result_p, p, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result_q, q, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_point_pair_split_zero() {
+        let zero: ConformalMultivector2<f64> = ConformalMultivector2::zero();
+
+        assert!(zero.split_point_pair().is_none());
+    }
+}
+
+
+#[cfg(test)]
+mod cga2_incidence_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::cga2::ConformalMultivector2;
+    use cggeomalg::e2ga::EuclideanMultivector2;
+
+
+    #[test]
+    fn test_meet_circles() {
+        let circle1 = ConformalMultivector2::from_circle(&EuclideanMultivector2::new(0_f64, 0_f64, 0_f64, 0_f64), 5_f64);
+        let circle2 = ConformalMultivector2::from_circle(&EuclideanMultivector2::new(0_f64, 6_f64, 0_f64, 0_f64), 5_f64);
+        let point_pair = circle1.meet(&circle2);
+        let (p, q) = point_pair.split_point_pair().unwrap();
+        let expected_p = EuclideanMultivector2::new(0_f64, 3_f64, 4_f64, 0_f64);
+        let expected_q = EuclideanMultivector2::new(0_f64, 3_f64, -4_f64, 0_f64);
+
+        assert_eq!(point_pair.grade(2), point_pair);
+        assert!(
+            (p - expected_p).magnitude() < 1e-10 && (q - expected_q).magnitude() < 1e-10
+                || (p - expected_q).magnitude() < 1e-10 && (q - expected_p).magnitude() < 1e-10
+        );
+    }
+
+    #[test]
+    fn test_meet_disjoint_circles_is_imaginary() {
+        let circle1 = ConformalMultivector2::from_circle(&EuclideanMultivector2::new(0_f64, 0_f64, 0_f64, 0_f64), 1_f64);
+        let circle2 = ConformalMultivector2::from_circle(&EuclideanMultivector2::new(0_f64, 5_f64, 0_f64, 0_f64), 1_f64);
+        let point_pair = circle1 & circle2;
+
+        assert!(!point_pair.is_zero());
+        assert!(point_pair.split_point_pair().is_none());
+    }
+
+    #[test]
+    fn test_meet_tangent_circles() {
+        let circle1 = ConformalMultivector2::from_circle(&EuclideanMultivector2::new(0_f64, 0_f64, 0_f64, 0_f64), 1_f64);
+        let circle2 = ConformalMultivector2::from_circle(&EuclideanMultivector2::new(0_f64, 3_f64, 0_f64, 0_f64), 2_f64);
+        let point_pair = circle1 & circle2;
+
+        assert_relative_eq!(
+            (point_pair * point_pair)[0],
+            0_f64,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_meet_circle_line() {
+        let circle = ConformalMultivector2::from_circle(&EuclideanMultivector2::new(0_f64, 1_f64, 1_f64, 0_f64), 2_f64);
+        let line = ConformalMultivector2::from_line(
+            &EuclideanMultivector2::new(0_f64, 1_f64, 1_f64, 0_f64),
+            &EuclideanMultivector2::new(0_f64, 0_f64, 1_f64, 0_f64),
+        );
+        let point_pair = circle.meet(&line);
+        let (p, q) = point_pair.split_point_pair().unwrap();
+        let expected_p = EuclideanMultivector2::new(0_f64, 1_f64, 3_f64, 0_f64);
+        let expected_q = EuclideanMultivector2::new(0_f64, 1_f64, -1_f64, 0_f64);
+
+        assert!(
+            (p - expected_p).magnitude() < 1e-10 && (q - expected_q).magnitude() < 1e-10
+                || (p - expected_q).magnitude() < 1e-10 && (q - expected_p).magnitude() < 1e-10
+        );
+    }
+
+    #[test]
+    fn test_meet_lines_flat_point() {
+        let line1 = ConformalMultivector2::from_line(
+            &EuclideanMultivector2::new(0_f64, 0_f64, 2_f64, 0_f64),
+            &EuclideanMultivector2::new(0_f64, 1_f64, 0_f64, 0_f64),
+        );
+        let line2 = ConformalMultivector2::from_line(
+            &EuclideanMultivector2::new(0_f64, 3_f64, 0_f64, 0_f64),
+            &EuclideanMultivector2::new(0_f64, 0_f64, 1_f64, 0_f64),
+        );
+        let flat_point = line1 & line2;
+        let expected = ConformalMultivector2::from_flat_point(&EuclideanMultivector2::new(0_f64, 3_f64, 2_f64, 0_f64));
+        let scale = flat_point.e0inf / expected.e0inf;
+
+        assert!(!flat_point.is_zero());
+        assert_relative_eq!(flat_point, expected * scale, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_join_three_points_circle() {
+        let p = ConformalMultivector2::up(&EuclideanMultivector2::new(0_f64, 2_f64, 0_f64, 0_f64));
+        let q = ConformalMultivector2::up(&EuclideanMultivector2::new(0_f64, 0_f64, 2_f64, 0_f64));
+        let r = ConformalMultivector2::up(&EuclideanMultivector2::new(0_f64, -2_f64, 0_f64, 0_f64));
+        let circle = p.join(&q).join(&r);
+        let zero: ConformalMultivector2<f64> = ConformalMultivector2::zero();
+
+        assert_relative_eq!(
+            ConformalMultivector2::up(&EuclideanMultivector2::new(0_f64, 0_f64, -2_f64, 0_f64)) ^ circle,
+            zero,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert!(!(ConformalMultivector2::up(&EuclideanMultivector2::new(0_f64, 0_f64, 0_f64, 0_f64)) ^ circle).is_zero());
+    }
+}
+
+
+#[cfg(test)]
+mod cga2_tangent_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::cga2::ConformalMultivector2;
+    use cggeomalg::e2ga::EuclideanMultivector2;
+
+
+    #[test]
+    fn test_tangent_line_circle() {
+        let circle = ConformalMultivector2::from_circle(&EuclideanMultivector2::new(0_f64, 1_f64, 2_f64, 0_f64), 3_f64);
+        let tangent_line = circle.tangent_line(&EuclideanMultivector2::new(0_f64, 4_f64, 2_f64, 0_f64));
+        let zero: ConformalMultivector2<f64> = ConformalMultivector2::zero();
+
+        assert_relative_eq!(
+            ConformalMultivector2::up(&EuclideanMultivector2::new(0_f64, 4_f64, 7_f64, 0_f64)) ^ tangent_line,
+            zero,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            ConformalMultivector2::up(&EuclideanMultivector2::new(0_f64, 4_f64, -3_f64, 0_f64)) ^ tangent_line,
+            zero,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            ConformalMultivector2::unit_einf() ^ tangent_line,
+            zero,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_tangent_line_meets_circle_once() {
+        let circle = ConformalMultivector2::from_circle(&EuclideanMultivector2::new(0_f64, 0_f64, 0_f64, 0_f64), 5_f64);
+        let tangent_line = circle.tangent_line(&EuclideanMultivector2::new(0_f64, 3_f64, 4_f64, 0_f64));
+        let point_pair = circle & tangent_line;
+
+        assert_relative_eq!(
+            (point_pair * point_pair)[0],
+            0_f64,
+            abs_diff_all <= 1e-8,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_tangent_line_line() {
+        let line = ConformalMultivector2::from_line(
+            &EuclideanMultivector2::new(0_f64, 0_f64, 1_f64, 0_f64),
+            &EuclideanMultivector2::new(0_f64, 1_f64, 1_f64, 0_f64),
+        );
+        let tangent_line = line.tangent_line(&EuclideanMultivector2::new(0_f64, 2_f64, 3_f64, 0_f64));
+        let scale = tangent_line.e12inf / line.e12inf;
+
+        assert_relative_eq!(tangent_line, line * scale, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_tangent_circle() {
+        let circle = ConformalMultivector2::from_circle(&EuclideanMultivector2::new(0_f64, 1_f64, 2_f64, 0_f64), 3_f64);
+        let tangent_circle = circle.tangent_circle(
+            &EuclideanMultivector2::new(0_f64, 4_f64, 2_f64, 0_f64),
+            &EuclideanMultivector2::new(0_f64, 8_f64, 2_f64, 0_f64),
+        );
+        let (center, radius) = tangent_circle.center_radius().unwrap();
+
+        assert_relative_eq!(
+            center,
+            EuclideanMultivector2::new(0_f64, 6_f64, 2_f64, 0_f64),
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(radius, 2_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_tangent_circle_internal() {
+        let circle = ConformalMultivector2::from_circle(&EuclideanMultivector2::new(0_f64, 0_f64, 0_f64, 0_f64), 4_f64);
+        let tangent_circle = circle.tangent_circle(
+            &EuclideanMultivector2::new(0_f64, 0_f64, 4_f64, 0_f64),
+            &EuclideanMultivector2::new(0_f64, 0_f64, 0_f64, 0_f64),
+        );
+        let (center, radius) = tangent_circle.center_radius().unwrap();
+
+        assert_relative_eq!(
+            center,
+            EuclideanMultivector2::new(0_f64, 0_f64, 2_f64, 0_f64),
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(radius, 2_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_tangent_circle_line() {
+        let line = ConformalMultivector2::from_line(
+            &EuclideanMultivector2::new(0_f64, 0_f64, 0_f64, 0_f64),
+            &EuclideanMultivector2::new(0_f64, 1_f64, 0_f64, 0_f64),
+        );
+        let tangent_circle = line.tangent_circle(
+            &EuclideanMultivector2::new(0_f64, 3_f64, 0_f64, 0_f64),
+            &EuclideanMultivector2::new(0_f64, 3_f64, 4_f64, 0_f64),
+        );
+        let (center, radius) = tangent_circle.center_radius().unwrap();
+
+        assert_relative_eq!(
+            center,
+            EuclideanMultivector2::new(0_f64, 3_f64, 2_f64, 0_f64),
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(radius, 2_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_tangent_circle_through_tangent_line_point_is_line() {
+        let circle = ConformalMultivector2::from_circle(&EuclideanMultivector2::new(0_f64, 1_f64, 2_f64, 0_f64), 3_f64);
+        let tangent_circle = circle.tangent_circle(
+            &EuclideanMultivector2::new(0_f64, 4_f64, 2_f64, 0_f64),
+            &EuclideanMultivector2::new(0_f64, 4_f64, 9_f64, 0_f64),
+        );
+        let tangent_line = circle.tangent_line(&EuclideanMultivector2::new(0_f64, 4_f64, 2_f64, 0_f64));
+        let scale = tangent_circle.e02inf / tangent_line.e02inf;
+
+        assert!(tangent_circle.center_radius().is_none());
+        assert_relative_eq!(
+            tangent_circle,
+            tangent_line * scale,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_magnitude() {
+        let mv = ConformalMultivector2::unit_e1() * 3_f64 + ConformalMultivector2::unit_e2() * 4_f64;
+
+        assert_relative_eq!(mv.magnitude(), 5_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(
+            mv.normalize().magnitude(),
+            1_f64,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+}
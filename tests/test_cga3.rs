@@ -0,0 +1,1505 @@
+#[cfg(test)]
+mod cga3_component_tests {
+    use cggeomalg::cga3::ConformalMultivector3;
+
+
+    #[test]
+    fn test_components1() {
+        let mv = ConformalMultivector3::new(
+            1_isize, 2_isize, 3_isize, 4_isize, 5_isize, 6_isize, 7_isize, 8_isize, 9_isize, 10_isize, 11_isize, 12_isize, 13_isize,
+            14_isize, 15_isize, 16_isize, 17_isize, 18_isize, 19_isize, 20_isize, 21_isize, 22_isize, 23_isize, 24_isize, 25_isize,
+            26_isize, 27_isize, 28_isize, 29_isize, 30_isize, 31_isize, 32_isize,
+        );
+
+        assert_eq!(mv[0], 1);
+        assert_eq!(mv[1], 2);
+        assert_eq!(mv[2], 3);
+        assert_eq!(mv[3], 4);
+        assert_eq!(mv[4], 5);
+        assert_eq!(mv[5], 6);
+        assert_eq!(mv[6], 7);
+        assert_eq!(mv[7], 8);
+        assert_eq!(mv[8], 9);
+        assert_eq!(mv[9], 10);
+        assert_eq!(mv[10], 11);
+        assert_eq!(mv[11], 12);
+        assert_eq!(mv[12], 13);
+        assert_eq!(mv[13], 14);
+        assert_eq!(mv[14], 15);
+        assert_eq!(mv[15], 16);
+        assert_eq!(mv[16], 17);
+        assert_eq!(mv[17], 18);
+        assert_eq!(mv[18], 19);
+        assert_eq!(mv[19], 20);
+        assert_eq!(mv[20], 21);
+        assert_eq!(mv[21], 22);
+        assert_eq!(mv[22], 23);
+        assert_eq!(mv[23], 24);
+        assert_eq!(mv[24], 25);
+        assert_eq!(mv[25], 26);
+        assert_eq!(mv[26], 27);
+        assert_eq!(mv[27], 28);
+        assert_eq!(mv[28], 29);
+        assert_eq!(mv[29], 30);
+        assert_eq!(mv[30], 31);
+        assert_eq!(mv[31], 32);
+    }
+
+    #[test]
+    fn test_components2() {
+        let mv = ConformalMultivector3::new(
+            1_isize, 2_isize, 3_isize, 4_isize, 5_isize, 6_isize, 7_isize, 8_isize, 9_isize, 10_isize, 11_isize, 12_isize, 13_isize,
+            14_isize, 15_isize, 16_isize, 17_isize, 18_isize, 19_isize, 20_isize, 21_isize, 22_isize, 23_isize, 24_isize, 25_isize,
+            26_isize, 27_isize, 28_isize, 29_isize, 30_isize, 31_isize, 32_isize,
+        );
+
+        assert_eq!(mv.scalar, mv[0]);
+        assert_eq!(mv.e0, mv[1]);
+        assert_eq!(mv.e1, mv[2]);
+        assert_eq!(mv.e2, mv[3]);
+        assert_eq!(mv.e3, mv[4]);
+        assert_eq!(mv.einf, mv[5]);
+        assert_eq!(mv.e01, mv[6]);
+        assert_eq!(mv.e02, mv[7]);
+        assert_eq!(mv.e03, mv[8]);
+        assert_eq!(mv.e12, mv[9]);
+        assert_eq!(mv.e23, mv[10]);
+        assert_eq!(mv.e31, mv[11]);
+        assert_eq!(mv.e1inf, mv[12]);
+        assert_eq!(mv.e2inf, mv[13]);
+        assert_eq!(mv.e3inf, mv[14]);
+        assert_eq!(mv.e0inf, mv[15]);
+        assert_eq!(mv.e012, mv[16]);
+        assert_eq!(mv.e023, mv[17]);
+        assert_eq!(mv.e031, mv[18]);
+        assert_eq!(mv.e01inf, mv[19]);
+        assert_eq!(mv.e02inf, mv[20]);
+        assert_eq!(mv.e03inf, mv[21]);
+        assert_eq!(mv.e123, mv[22]);
+        assert_eq!(mv.e12inf, mv[23]);
+        assert_eq!(mv.e23inf, mv[24]);
+        assert_eq!(mv.e31inf, mv[25]);
+        assert_eq!(mv.e0123, mv[26]);
+        assert_eq!(mv.e012inf, mv[27]);
+        assert_eq!(mv.e023inf, mv[28]);
+        assert_eq!(mv.e031inf, mv[29]);
+        assert_eq!(mv.e123inf, mv[30]);
+        assert_eq!(mv.e0123inf, mv[31]);
+    }
+
+    #[test]
+    fn test_as_ref() {
+        let mv = ConformalMultivector3::new(
+            1_isize, 2_isize, 3_isize, 4_isize, 5_isize, 6_isize, 7_isize, 8_isize, 9_isize, 10_isize, 11_isize, 12_isize, 13_isize,
+            14_isize, 15_isize, 16_isize, 17_isize, 18_isize, 19_isize, 20_isize, 21_isize, 22_isize, 23_isize, 24_isize, 25_isize,
+            26_isize, 27_isize, 28_isize, 29_isize, 30_isize, 31_isize, 32_isize,
+        );
+        let v_ref: &[isize; 32] = mv.as_ref();
+
+        assert_eq!(
+            v_ref,
+            &[
+                1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32
+            ]
+        );
+    }
+
+    #[test]
+    fn test_as_mut() {
+        let mut mv = ConformalMultivector3::new(
+            1_isize, 2_isize, 3_isize, 4_isize, 5_isize, 6_isize, 7_isize, 8_isize, 9_isize, 10_isize, 11_isize, 12_isize, 13_isize,
+            14_isize, 15_isize, 16_isize, 17_isize, 18_isize, 19_isize, 20_isize, 21_isize, 22_isize, 23_isize, 24_isize, 25_isize,
+            26_isize, 27_isize, 28_isize, 29_isize, 30_isize, 31_isize, 32_isize,
+        );
+        let v_ref: &mut [isize; 32] = mv.as_mut();
+
+        assert_eq!(
+            v_ref,
+            &mut [
+                1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_out_of_bounds_array_access() {
+        let mv = ConformalMultivector3::new(
+            1_isize, 2_isize, 3_isize, 4_isize, 5_isize, 6_isize, 7_isize, 8_isize, 9_isize, 10_isize, 11_isize, 12_isize, 13_isize,
+            14_isize, 15_isize, 16_isize, 17_isize, 18_isize, 19_isize, 20_isize, 21_isize, 22_isize, 23_isize, 24_isize, 25_isize,
+            26_isize, 27_isize, 28_isize, 29_isize, 30_isize, 31_isize, 32_isize,
+        );
+
+        assert_eq!(mv[32], mv[32]);
+    }
+}
+
+
+#[cfg(test)]
+mod cga3_addition_subtraction_tests {
+    use cggeomalg::cga3::ConformalMultivector3;
+
+
+    #[test]
+    fn test_multivector_addition() {
+        let mv1 = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+        let mv2 = ConformalMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32, 2_i32,
+            -3_i32, 8_i32, 4_i32, -6_i32, 2_i32, 6_i32, -4_i32, 3_i32, 3_i32, -8_i32, 3_i32, 2_i32, 7_i32, -9_i32, 5_i32,
+        );
+        let expected = ConformalMultivector3::new(
+            4_i32, 1_i32, 7_i32, 5_i32, 0_i32, 15_i32, 9_i32, 2_i32, 14_i32, 13_i32, 6_i32, 20_i32, 22_i32, 7_i32, 24_i32, 19_i32, 19_i32,
+            15_i32, 27_i32, 24_i32, 15_i32, 24_i32, 29_i32, 20_i32, 28_i32, 29_i32, 19_i32, 31_i32, 31_i32, 37_i32, 22_i32, 37_i32,
+        );
+        let result = mv1 + mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_multivector_subtraction() {
+        let mv1 = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+        let mv2 = ConformalMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32, 2_i32,
+            -3_i32, 8_i32, 4_i32, -6_i32, 2_i32, 6_i32, -4_i32, 3_i32, 3_i32, -8_i32, 3_i32, 2_i32, 7_i32, -9_i32, 5_i32,
+        );
+        let expected = ConformalMultivector3::new(
+            -2_i32, 3_i32, -1_i32, 3_i32, 10_i32, -3_i32, 5_i32, 14_i32, 4_i32, 7_i32, 16_i32, 4_i32, 4_i32, 21_i32, 6_i32, 13_i32, 15_i32,
+            21_i32, 11_i32, 16_i32, 27_i32, 20_i32, 17_i32, 28_i32, 22_i32, 23_i32, 35_i32, 25_i32, 27_i32, 23_i32, 40_i32, 27_i32,
+        );
+        let result = mv1 - mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_multivector_minus_multivector_is_zero() {
+        let mv = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+        let zero: ConformalMultivector3<i32> = ConformalMultivector3::zero();
+
+        assert_eq!(mv - mv, zero);
+    }
+
+    #[test]
+    fn test_scalar_minus_multivector() {
+        let mv = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+        let expected = ConformalMultivector3::new(
+            4_i32, -2_i32, -3_i32, -4_i32, -5_i32, -6_i32, -7_i32, -8_i32, -9_i32, -10_i32, -11_i32, -12_i32, -13_i32, -14_i32, -15_i32,
+            -16_i32, -17_i32, -18_i32, -19_i32, -20_i32, -21_i32, -22_i32, -23_i32, -24_i32, -25_i32, -26_i32, -27_i32, -28_i32, -29_i32,
+            -30_i32, -31_i32, -32_i32,
+        );
+        let result = 5_i32 - mv;
+
+        assert_eq!(result, expected);
+        assert_eq!(5_i32 - mv, ConformalMultivector3::from_scalar(5_i32) - mv);
+    }
+
+    #[test]
+    fn test_scalar_plus_multivector() {
+        let mv = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+
+        assert_eq!(5_i32 + mv, mv + 5_i32);
+        assert_eq!(5_i32 + mv, ConformalMultivector3::from_scalar(5_i32) + mv);
+    }
+}
+
+
+#[cfg(test)]
+mod cga3_multiplication_division_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::cga3::ConformalMultivector3;
+
+
+    #[test]
+    fn test_scalar_multiplication() {
+        let mv = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+        let expected = ConformalMultivector3::new(
+            3_i32, 6_i32, 9_i32, 12_i32, 15_i32, 18_i32, 21_i32, 24_i32, 27_i32, 30_i32, 33_i32, 36_i32, 39_i32, 42_i32, 45_i32, 48_i32,
+            51_i32, 54_i32, 57_i32, 60_i32, 63_i32, 66_i32, 69_i32, 72_i32, 75_i32, 78_i32, 81_i32, 84_i32, 87_i32, 90_i32, 93_i32, 96_i32,
+        );
+
+        assert_eq!(mv * 3_i32, expected);
+        assert_eq!(3_i32 * mv, expected);
+    }
+
+    #[test]
+    fn test_scalar_division() {
+        let mv = ConformalMultivector3::new(
+            1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64, 9_f64, 10_f64, 11_f64, 12_f64, 13_f64, 14_f64, 15_f64, 16_f64, 17_f64,
+            18_f64, 19_f64, 20_f64, 21_f64, 22_f64, 23_f64, 24_f64, 25_f64, 26_f64, 27_f64, 28_f64, 29_f64, 30_f64, 31_f64, 32_f64,
+        );
+        let expected = mv * (1_f64 / 9_f64);
+        let result = mv / 9_f64;
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_geometric_product_euclidean_basis_squares() {
+        let one: ConformalMultivector3<i32> = ConformalMultivector3::unit_scalar();
+
+        assert_eq!(ConformalMultivector3::unit_e1() * ConformalMultivector3::unit_e1(), one);
+        assert_eq!(ConformalMultivector3::unit_e2() * ConformalMultivector3::unit_e2(), one);
+        assert_eq!(ConformalMultivector3::unit_e3() * ConformalMultivector3::unit_e3(), one);
+    }
+
+    #[test]
+    fn test_geometric_product_null_basis() {
+        let e0: ConformalMultivector3<i32> = ConformalMultivector3::unit_e0();
+        let einf: ConformalMultivector3<i32> = ConformalMultivector3::unit_einf();
+        let e0inf: ConformalMultivector3<i32> = ConformalMultivector3::unit_e0inf();
+        let one: ConformalMultivector3<i32> = ConformalMultivector3::unit_scalar();
+        let zero: ConformalMultivector3<i32> = ConformalMultivector3::zero();
+
+        assert_eq!(e0 * e0, zero);
+        assert_eq!(einf * einf, zero);
+        assert_eq!(e0 * einf, e0inf - one);
+        assert_eq!(einf * e0, -e0inf - one);
+        assert_eq!(e0inf * e0inf, one);
+    }
+
+    #[test]
+    fn test_geometric_product_pseudoscalar_squares_to_minus_one() {
+        let e0123inf: ConformalMultivector3<i32> = ConformalMultivector3::unit_e0123inf();
+        let one: ConformalMultivector3<i32> = ConformalMultivector3::unit_scalar();
+
+        assert_eq!(e0123inf * e0123inf, -one);
+    }
+
+    #[test]
+    fn test_geometric_product() {
+        let mv1 = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+        let mv2 = ConformalMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32, 2_i32,
+            -3_i32, 8_i32, 4_i32, -6_i32, 2_i32, 6_i32, -4_i32, 3_i32, 3_i32, -8_i32, 3_i32, 2_i32, 7_i32, -9_i32, 5_i32,
+        );
+        let expected = ConformalMultivector3::new(
+            -1065_i32, -685_i32, 203_i32, 185_i32, 275_i32, 292_i32, 160_i32, -189_i32, -361_i32, -82_i32, -41_i32, 781_i32, 125_i32,
+            927_i32, 802_i32, 282_i32, -29_i32, -127_i32, 948_i32, 185_i32, 624_i32, 856_i32, 70_i32, 522_i32, 51_i32, 387_i32, 303_i32,
+            335_i32, 339_i32, 259_i32, -1_i32, 461_i32,
+        );
+        let result = mv1 * mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_geometric_product_references() {
+        let mv1 = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+        let mv2 = ConformalMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32, 2_i32,
+            -3_i32, 8_i32, 4_i32, -6_i32, 2_i32, 6_i32, -4_i32, 3_i32, 3_i32, -8_i32, 3_i32, 2_i32, 7_i32, -9_i32, 5_i32,
+        );
+        let expected = mv1 * mv2;
+        let mv1_ref = &mv1;
+        let mv2_ref = &mv2;
+
+        assert_eq!(mv1_ref * mv2, expected);
+        assert_eq!(mv1 * mv2_ref, expected);
+        assert_eq!(mv1_ref * mv2_ref, expected);
+    }
+
+    #[test]
+    fn test_geometric_product_associative() {
+        let mv1 = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+        let mv2 = ConformalMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32, 2_i32,
+            -3_i32, 8_i32, 4_i32, -6_i32, 2_i32, 6_i32, -4_i32, 3_i32, 3_i32, -8_i32, 3_i32, 2_i32, 7_i32, -9_i32, 5_i32,
+        );
+        let mv3 = ConformalMultivector3::new(
+            2_i32, 0_i32, -1_i32, 3_i32, 1_i32, -2_i32, 4_i32, 0_i32, 1_i32, -3_i32, 2_i32, 5_i32, -1_i32, 0_i32, 2_i32, -4_i32, 0_i32,
+            1_i32, 3_i32, -2_i32, 1_i32, 0_i32, -1_i32, 2_i32, 4_i32, -3_i32, 1_i32, 0_i32, 2_i32, -1_i32, 3_i32, 1_i32,
+        );
+
+        assert_eq!((mv1 * mv2) * mv3, mv1 * (mv2 * mv3));
+    }
+}
+
+
+#[cfg(test)]
+mod cga3_outer_product_tests {
+    use cggeomalg::cga3::ConformalMultivector3;
+
+
+    #[test]
+    fn test_outer_product_e1_e2() {
+        let e1: ConformalMultivector3<i32> = ConformalMultivector3::unit_e1();
+        let e2: ConformalMultivector3<i32> = ConformalMultivector3::unit_e2();
+        let e12: ConformalMultivector3<i32> = ConformalMultivector3::unit_e12();
+
+        assert_eq!(e1 ^ e2, e12);
+        assert_eq!(e2 ^ e1, -e12);
+    }
+
+    #[test]
+    fn test_outer_product_null_vectors() {
+        let e0: ConformalMultivector3<i32> = ConformalMultivector3::unit_e0();
+        let einf: ConformalMultivector3<i32> = ConformalMultivector3::unit_einf();
+        let e0inf: ConformalMultivector3<i32> = ConformalMultivector3::unit_e0inf();
+        let zero: ConformalMultivector3<i32> = ConformalMultivector3::zero();
+
+        assert_eq!(e0 ^ e0, zero);
+        assert_eq!(e0 ^ einf, e0inf);
+        assert_eq!(einf ^ e0, -e0inf);
+    }
+
+    #[test]
+    fn test_outer_product_vectors_pseudoscalar() {
+        let e0: ConformalMultivector3<i32> = ConformalMultivector3::unit_e0();
+        let e1: ConformalMultivector3<i32> = ConformalMultivector3::unit_e1();
+        let e2: ConformalMultivector3<i32> = ConformalMultivector3::unit_e2();
+        let e3: ConformalMultivector3<i32> = ConformalMultivector3::unit_e3();
+        let einf: ConformalMultivector3<i32> = ConformalMultivector3::unit_einf();
+        let e0123inf: ConformalMultivector3<i32> = ConformalMultivector3::unit_e0123inf();
+
+        assert_eq!(e0 ^ e1 ^ e2 ^ e3 ^ einf, e0123inf);
+    }
+
+    #[test]
+    fn test_outer_product() {
+        let mv1 = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+        let mv2 = ConformalMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32, 2_i32,
+            -3_i32, 8_i32, 4_i32, -6_i32, 2_i32, 6_i32, -4_i32, 3_i32, 3_i32, -8_i32, 3_i32, 2_i32, 7_i32, -9_i32, 5_i32,
+        );
+        let expected = ConformalMultivector3::new(
+            3_i32, 5_i32, 13_i32, 13_i32, 10_i32, 27_i32, 34_i32, 24_i32, 27_i32, 20_i32, 3_i32, 79_i32, 51_i32, 65_i32, 129_i32, 75_i32,
+            50_i32, -69_i32, 145_i32, 71_i32, 37_i32, 247_i32, 113_i32, 162_i32, 303_i32, 130_i32, 137_i32, 405_i32, 317_i32, 267_i32,
+            447_i32, 461_i32,
+        );
+        let result = mv1 ^ mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_outer_product_references() {
+        let mv1 = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+        let mv2 = ConformalMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32, 2_i32,
+            -3_i32, 8_i32, 4_i32, -6_i32, 2_i32, 6_i32, -4_i32, 3_i32, 3_i32, -8_i32, 3_i32, 2_i32, 7_i32, -9_i32, 5_i32,
+        );
+        let expected = mv1 ^ mv2;
+        let mv1_ref = &mv1;
+        let mv2_ref = &mv2;
+
+        assert_eq!(mv1_ref ^ mv2, expected);
+        assert_eq!(mv1 ^ mv2_ref, expected);
+        assert_eq!(mv1_ref ^ mv2_ref, expected);
+        assert_eq!(mv1.outer_product(&mv2), expected);
+    }
+
+    #[test]
+    fn test_outer_product_associative() {
+        let mv1 = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+        let mv2 = ConformalMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32, 2_i32,
+            -3_i32, 8_i32, 4_i32, -6_i32, 2_i32, 6_i32, -4_i32, 3_i32, 3_i32, -8_i32, 3_i32, 2_i32, 7_i32, -9_i32, 5_i32,
+        );
+        let mv3 = ConformalMultivector3::new(
+            2_i32, 0_i32, -1_i32, 3_i32, 1_i32, -2_i32, 4_i32, 0_i32, 1_i32, -3_i32, 2_i32, 5_i32, -1_i32, 0_i32, 2_i32, -4_i32, 0_i32,
+            1_i32, 3_i32, -2_i32, 1_i32, 0_i32, -1_i32, 2_i32, 4_i32, -3_i32, 1_i32, 0_i32, 2_i32, -1_i32, 3_i32, 1_i32,
+        );
+
+        assert_eq!((mv1 ^ mv2) ^ mv3, mv1 ^ (mv2 ^ mv3));
+    }
+}
+
+
+#[cfg(test)]
+mod cga3_grade_tests {
+    use cggeomalg::cga3::ConformalMultivector3;
+
+
+    #[test]
+    fn test_grade_decomposition() {
+        let mv = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+        let result = mv.grade(0) + mv.grade(1) + mv.grade(2) + mv.grade(3) + mv.grade(4) + mv.grade(5);
+
+        assert_eq!(result, mv);
+    }
+
+    #[test]
+    fn test_grade_projection() {
+        let mv = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+
+        assert_eq!(mv.grade(0), ConformalMultivector3::from_scalar(1_i32));
+        assert_eq!(
+            mv.grade(2),
+            ConformalMultivector3::new(
+                0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+                0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32,
+            )
+        );
+        assert_eq!(mv.grade(5), ConformalMultivector3::unit_e0123inf() * 32_i32);
+        assert!(mv.grade(6).is_zero());
+    }
+
+    #[test]
+    fn test_reverse_reverse() {
+        let mv = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+
+        assert_eq!(mv.reverse().reverse(), mv);
+    }
+
+    #[test]
+    fn test_reverse_product() {
+        let mv1 = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+        let mv2 = ConformalMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32, 2_i32,
+            -3_i32, 8_i32, 4_i32, -6_i32, 2_i32, 6_i32, -4_i32, 3_i32, 3_i32, -8_i32, 3_i32, 2_i32, 7_i32, -9_i32, 5_i32,
+        );
+
+        assert_eq!((mv1 * mv2).reverse(), mv2.reverse() * mv1.reverse());
+    }
+
+    #[test]
+    fn test_involute_product() {
+        let mv1 = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+        let mv2 = ConformalMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32, 2_i32,
+            -3_i32, 8_i32, 4_i32, -6_i32, 2_i32, 6_i32, -4_i32, 3_i32, 3_i32, -8_i32, 3_i32, 2_i32, 7_i32, -9_i32, 5_i32,
+        );
+
+        assert_eq!((mv1 * mv2).involute(), mv1.involute() * mv2.involute());
+    }
+
+    #[test]
+    fn test_conjugate_is_reverse_of_involute() {
+        let mv = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+
+        assert_eq!(mv.conjugate(), mv.involute().reverse());
+    }
+}
+
+
+#[cfg(test)]
+mod cga3_scalar_product_tests {
+    use cggeomalg::cga3::ConformalMultivector3;
+
+
+    #[test]
+    fn test_scalar_product_null_basis() {
+        let e0: ConformalMultivector3<i32> = ConformalMultivector3::unit_e0();
+        let einf: ConformalMultivector3<i32> = ConformalMultivector3::unit_einf();
+        let zero: ConformalMultivector3<i32> = ConformalMultivector3::zero();
+
+        assert_eq!(e0 | e0, zero);
+        assert_eq!(einf | einf, zero);
+        assert_eq!(e0 | einf, ConformalMultivector3::from_scalar(-1_i32));
+        assert_eq!(einf | e0, ConformalMultivector3::from_scalar(-1_i32));
+    }
+
+    #[test]
+    fn test_scalar_product_is_scalar_part_of_geometric_product() {
+        let mv1 = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+        let mv2 = ConformalMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32, 2_i32,
+            -3_i32, 8_i32, 4_i32, -6_i32, 2_i32, 6_i32, -4_i32, 3_i32, 3_i32, -8_i32, 3_i32, 2_i32, 7_i32, -9_i32, 5_i32,
+        );
+        let expected = (mv1 * mv2.reverse()).grade(0);
+        let result = mv1 | mv2;
+
+        assert_eq!(result, expected);
+        assert_eq!(result, ConformalMultivector3::from_scalar(-977_i32));
+    }
+
+    #[test]
+    fn test_scalar_product_references() {
+        let mv1 = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+        let mv2 = ConformalMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32, 2_i32,
+            -3_i32, 8_i32, 4_i32, -6_i32, 2_i32, 6_i32, -4_i32, 3_i32, 3_i32, -8_i32, 3_i32, 2_i32, 7_i32, -9_i32, 5_i32,
+        );
+        let expected = mv1 | mv2;
+        let mv1_ref = &mv1;
+        let mv2_ref = &mv2;
+
+        assert_eq!(mv1_ref | mv2, expected);
+        assert_eq!(mv1 | mv2_ref, expected);
+        assert_eq!(mv1_ref | mv2_ref, expected);
+        assert_eq!(mv1.scalar_product(&mv2), expected);
+    }
+}
+
+
+#[cfg(test)]
+mod cga3_left_contraction_tests {
+    use cggeomalg::cga3::ConformalMultivector3;
+
+
+    #[test]
+    fn test_left_contraction_vector_bivector() {
+        let e0: ConformalMultivector3<i32> = ConformalMultivector3::unit_e0();
+        let e1: ConformalMultivector3<i32> = ConformalMultivector3::unit_e1();
+        let e2: ConformalMultivector3<i32> = ConformalMultivector3::unit_e2();
+        let einf: ConformalMultivector3<i32> = ConformalMultivector3::unit_einf();
+        let e12: ConformalMultivector3<i32> = ConformalMultivector3::unit_e12();
+        let e0inf: ConformalMultivector3<i32> = ConformalMultivector3::unit_e0inf();
+
+        assert_eq!(e1 << e12, e2);
+        assert_eq!(e2 << e12, -e1);
+        assert_eq!(e0 << e0inf, e0);
+        assert_eq!(einf << e0inf, -einf);
+    }
+
+    #[test]
+    fn test_left_contraction_higher_grade_is_zero() {
+        let e12: ConformalMultivector3<i32> = ConformalMultivector3::unit_e12();
+        let e1: ConformalMultivector3<i32> = ConformalMultivector3::unit_e1();
+        let zero: ConformalMultivector3<i32> = ConformalMultivector3::zero();
+
+        assert_eq!(e12 << e1, zero);
+    }
+
+    #[test]
+    fn test_left_contraction() {
+        let mv1 = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+        let mv2 = ConformalMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32, 2_i32,
+            -3_i32, 8_i32, 4_i32, -6_i32, 2_i32, 6_i32, -4_i32, 3_i32, 3_i32, -8_i32, 3_i32, 2_i32, 7_i32, -9_i32, 5_i32,
+        );
+        let expected = ConformalMultivector3::new(
+            -1065_i32, -352_i32, -109_i32, 52_i32, 195_i32, 674_i32, 179_i32, 179_i32, 169_i32, 388_i32, 324_i32, 379_i32, 177_i32,
+            231_i32, 273_i32, -16_i32, 3_i32, -10_i32, 14_i32, -74_i32, -65_i32, -35_i32, -44_i32, -142_i32, -101_i32, -145_i32, -18_i32,
+            -22_i32, -13_i32, -13_i32, -39_i32, 5_i32,
+        );
+        let result = mv1 << mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_left_contraction_references() {
+        let mv1 = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+        let mv2 = ConformalMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32, 2_i32,
+            -3_i32, 8_i32, 4_i32, -6_i32, 2_i32, 6_i32, -4_i32, 3_i32, 3_i32, -8_i32, 3_i32, 2_i32, 7_i32, -9_i32, 5_i32,
+        );
+        let expected = mv1 << mv2;
+        let mv1_ref = &mv1;
+        let mv2_ref = &mv2;
+
+        assert_eq!(mv1_ref << mv2, expected);
+        assert_eq!(mv1 << mv2_ref, expected);
+        assert_eq!(mv1_ref << mv2_ref, expected);
+        assert_eq!(mv1.left_contract(&mv2), expected);
+    }
+}
+
+
+#[cfg(test)]
+mod cga3_right_contraction_tests {
+    use cggeomalg::cga3::ConformalMultivector3;
+
+
+    #[test]
+    fn test_right_contraction_bivector_vector() {
+        let e1: ConformalMultivector3<i32> = ConformalMultivector3::unit_e1();
+        let e2: ConformalMultivector3<i32> = ConformalMultivector3::unit_e2();
+        let e12: ConformalMultivector3<i32> = ConformalMultivector3::unit_e12();
+        let zero: ConformalMultivector3<i32> = ConformalMultivector3::zero();
+
+        assert_eq!(e12 >> e2, e1);
+        assert_eq!(e12 >> e1, -e2);
+        assert_eq!(e1 >> e12, zero);
+    }
+
+    #[test]
+    fn test_right_contraction_reverse() {
+        let mv1 = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+        let mv2 = ConformalMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32, 2_i32,
+            -3_i32, 8_i32, 4_i32, -6_i32, 2_i32, 6_i32, -4_i32, 3_i32, 3_i32, -8_i32, 3_i32, 2_i32, 7_i32, -9_i32, 5_i32,
+        );
+
+        assert_eq!((mv1 >> mv2).reverse(), mv2.reverse() << mv1.reverse());
+    }
+
+    #[test]
+    fn test_right_contraction() {
+        let mv1 = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+        let mv2 = ConformalMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32, 2_i32,
+            -3_i32, 8_i32, 4_i32, -6_i32, 2_i32, 6_i32, -4_i32, 3_i32, 3_i32, -8_i32, 3_i32, 2_i32, 7_i32, -9_i32, 5_i32,
+        );
+        let expected = ConformalMultivector3::new(
+            -1065_i32, -333_i32, 312_i32, 133_i32, 80_i32, -382_i32, 510_i32, -162_i32, -144_i32, -464_i32, -102_i32, 187_i32, -510_i32,
+            -69_i32, 20_i32, 70_i32, -216_i32, 127_i32, 306_i32, 42_i32, 64_i32, -121_i32, 247_i32, 191_i32, -76_i32, 541_i32, 113_i32,
+            244_i32, -41_i32, 58_i32, -195_i32, 96_i32,
+        );
+        let result = mv1 >> mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_right_contraction_references() {
+        let mv1 = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+        let mv2 = ConformalMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32, 2_i32,
+            -3_i32, 8_i32, 4_i32, -6_i32, 2_i32, 6_i32, -4_i32, 3_i32, 3_i32, -8_i32, 3_i32, 2_i32, 7_i32, -9_i32, 5_i32,
+        );
+        let expected = mv1 >> mv2;
+        let mv1_ref = &mv1;
+        let mv2_ref = &mv2;
+
+        assert_eq!(mv1_ref >> mv2, expected);
+        assert_eq!(mv1 >> mv2_ref, expected);
+        assert_eq!(mv1_ref >> mv2_ref, expected);
+        assert_eq!(mv1.right_contract(&mv2), expected);
+    }
+}
+
+
+#[cfg(test)]
+mod cga3_duality_tests {
+    use cggeomalg::cga3::ConformalMultivector3;
+
+
+    #[test]
+    fn test_dual_is_product_with_inverse_pseudoscalar() {
+        let mv = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+        let inv_ps = -ConformalMultivector3::unit_e0123inf();
+
+        assert_eq!(mv.dual(), mv * inv_ps);
+    }
+
+    #[test]
+    fn test_undual_is_product_with_pseudoscalar() {
+        let mv = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+        let ps: ConformalMultivector3<i32> = ConformalMultivector3::unit_e0123inf();
+
+        assert_eq!(mv.undual(), mv * ps);
+    }
+
+    #[test]
+    fn test_dual_undual() {
+        let mv = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+
+        assert_eq!(mv.dual().undual(), mv);
+        assert_eq!(mv.undual().dual(), mv);
+    }
+
+    #[test]
+    fn test_not_is_dual() {
+        let mv = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+
+        assert_eq!(!mv, mv.dual());
+        assert_eq!(!&mv, mv.dual());
+    }
+
+    #[test]
+    fn test_dual_mut() {
+        let mv = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+        let mut result = mv;
+        result.dual_mut();
+
+        assert_eq!(result, mv.dual());
+    }
+
+    #[test]
+    fn test_pseudoscalar_inverse() {
+        let ps: ConformalMultivector3<f64> = ConformalMultivector3::pseudoscalar();
+        let ps_inv: ConformalMultivector3<f64> = ConformalMultivector3::inv_pseudoscalar();
+        let one: ConformalMultivector3<f64> = ConformalMultivector3::unit_scalar();
+
+        assert_eq!(ps * ps_inv, one);
+    }
+}
+
+
+#[cfg(test)]
+mod cga3_regressive_product_tests {
+    use cggeomalg::cga3::ConformalMultivector3;
+
+
+    #[test]
+    fn test_regressive_product() {
+        let mv1 = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+        let mv2 = ConformalMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32, 2_i32,
+            -3_i32, 8_i32, 4_i32, -6_i32, 2_i32, 6_i32, -4_i32, 3_i32, 3_i32, -8_i32, 3_i32, 2_i32, 7_i32, -9_i32, 5_i32,
+        );
+        let expected = ConformalMultivector3::new(
+            461_i32, -166_i32, -22_i32, 8_i32, 70_i32, 448_i32, 149_i32, -360_i32, -151_i32, -482_i32, -699_i32, -75_i32, 183_i32,
+            -302_i32, 101_i32, 421_i32, -156_i32, -292_i32, -78_i32, 334_i32, -56_i32, 31_i32, 302_i32, 337_i32, 544_i32, 713_i32,
+            -121_i32, 236_i32, 209_i32, 374_i32, -133_i32, 160_i32,
+        );
+        let result = mv1 & mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_regressive_product_references() {
+        let mv1 = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+        let mv2 = ConformalMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32, 2_i32,
+            -3_i32, 8_i32, 4_i32, -6_i32, 2_i32, 6_i32, -4_i32, 3_i32, 3_i32, -8_i32, 3_i32, 2_i32, 7_i32, -9_i32, 5_i32,
+        );
+        let expected = mv1 & mv2;
+        let mv1_ref = &mv1;
+        let mv2_ref = &mv2;
+
+        assert_eq!(mv1_ref & mv2, expected);
+        assert_eq!(mv1 & mv2_ref, expected);
+        assert_eq!(mv1_ref & mv2_ref, expected);
+        assert_eq!(mv1.regressive_product(&mv2), expected);
+    }
+
+    #[test]
+    fn test_regressive_product_dual_outer_product() {
+        let mv1 = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+        let mv2 = ConformalMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32, 2_i32,
+            -3_i32, 8_i32, 4_i32, -6_i32, 2_i32, 6_i32, -4_i32, 3_i32, 3_i32, -8_i32, 3_i32, 2_i32, 7_i32, -9_i32, 5_i32,
+        );
+        let expected = (mv1.dual() ^ mv2.dual()).undual();
+        let result = mv1 & mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_regressive_product_pseudoscalar_unit() {
+        let e0123inf: ConformalMultivector3<i32> = ConformalMultivector3::unit_e0123inf();
+        let mv = ConformalMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32, 17_i32,
+            18_i32, 19_i32, 20_i32, 21_i32, 22_i32, 23_i32, 24_i32, 25_i32, 26_i32, 27_i32, 28_i32, 29_i32, 30_i32, 31_i32, 32_i32,
+        );
+
+        assert_eq!(e0123inf & mv, mv);
+        assert_eq!(mv & e0123inf, mv);
+    }
+}
+
+
+#[cfg(test)]
+mod cga3_conformal_embedding_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::cga3::ConformalMultivector3;
+    use cggeomalg::e3ga::EuclideanMultivector3;
+
+
+    #[test]
+    fn test_up_is_null() {
+        let x = EuclideanMultivector3::new(0_f64, 1_f64, -2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let point = ConformalMultivector3::up(&x);
+        let zero: ConformalMultivector3<f64> = ConformalMultivector3::zero();
+
+        assert_eq!(point * point, zero);
+    }
+
+    #[test]
+    fn test_up_components() {
+        let x = EuclideanMultivector3::new(0_f64, 1_f64, -2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let expected = ConformalMultivector3::unit_e0() + ConformalMultivector3::unit_e1() - ConformalMultivector3::unit_e2() * 2_f64
+            + ConformalMultivector3::unit_e3() * 3_f64
+            + ConformalMultivector3::unit_einf() * 7_f64;
+        let result = ConformalMultivector3::up(&x);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_up_origin() {
+        let origin: EuclideanMultivector3<f64> = EuclideanMultivector3::zero();
+        let expected: ConformalMultivector3<f64> = ConformalMultivector3::unit_e0();
+
+        assert_eq!(ConformalMultivector3::up(&origin), expected);
+    }
+
+    #[test]
+    fn test_up_ignores_non_vector_parts() {
+        let x = EuclideanMultivector3::new(4_f64, 1_f64, 2_f64, 3_f64, 5_f64, 6_f64, 7_f64, 8_f64);
+        let expected = ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64));
+
+        assert_eq!(ConformalMultivector3::up(&x), expected);
+    }
+
+    #[test]
+    fn test_down_up() {
+        let x = EuclideanMultivector3::new(0_f64, 1_f64, -2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+
+        assert_relative_eq!(
+            ConformalMultivector3::up(&x).down().unwrap(),
+            x,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_down_weighted_point() {
+        let x = EuclideanMultivector3::new(0_f64, 1_f64, -2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let point = ConformalMultivector3::up(&x) * -4_f64;
+
+        assert_relative_eq!(point.down().unwrap(), x, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_down_origin() {
+        let origin: ConformalMultivector3<f64> = ConformalMultivector3::unit_e0();
+        let zero = EuclideanMultivector3::zero();
+
+        assert_eq!(origin.down(), Some(zero));
+    }
+
+    #[test]
+    fn test_down_point_at_infinity() {
+        let direction = ConformalMultivector3::unit_e1() * 2_f64 - ConformalMultivector3::unit_e3();
+        let einf: ConformalMultivector3<f64> = ConformalMultivector3::unit_einf();
+
+        assert!(direction.down().is_none());
+        assert!(einf.down().is_none());
+        assert!(ConformalMultivector3::<f64>::zero().down().is_none());
+    }
+
+    #[test]
+    fn test_scalar_product_squared_distance() {
+        let x = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let y = EuclideanMultivector3::new(0_f64, -3_f64, 4_f64, 7_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let expected = ConformalMultivector3::from_scalar(-18_f64);
+        let result = ConformalMultivector3::up(&x) | ConformalMultivector3::up(&y);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_scalar_product_point_infinity() {
+        let x = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let einf: ConformalMultivector3<f64> = ConformalMultivector3::unit_einf();
+        let expected = ConformalMultivector3::from_scalar(-1_f64);
+
+        assert_eq!(ConformalMultivector3::up(&x) | einf, expected);
+    }
+}
+
+
+#[cfg(test)]
+mod cga3_geometric_object_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::cga3::ConformalMultivector3;
+    use cggeomalg::e3ga::EuclideanMultivector3;
+
+
+    #[test]
+    fn test_sphere_contains_points() {
+        let center = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let sphere = ConformalMultivector3::from_sphere(&center, 3_f64);
+        let zero: ConformalMultivector3<f64> = ConformalMultivector3::zero();
+
+        assert_relative_eq!(
+            ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 4_f64, 2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64)) ^ sphere,
+            zero,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 1_f64, -1_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64)) ^ sphere,
+            zero,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 6_f64, 0_f64, 0_f64, 0_f64, 0_f64)) ^ sphere,
+            zero,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert!(
+            !(ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 5_f64, 0_f64, 0_f64, 0_f64, 0_f64)) ^ sphere)
+                .is_zero()
+        );
+    }
+
+    #[test]
+    fn test_sphere_through_four_points() {
+        let p1 = ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64));
+        let p2 = ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, -1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64));
+        let p3 = ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64));
+        let p4 = ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64));
+        let sphere = p1 ^ p2 ^ p3 ^ p4;
+        let expected = ConformalMultivector3::from_sphere(&EuclideanMultivector3::zero(), 1_f64);
+        let scale = sphere.e0123 / expected.e0123;
+
+        assert!(scale > 0_f64);
+        assert_relative_eq!(sphere, expected * scale, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_sphere_is_grade_four() {
+        let sphere = ConformalMultivector3::from_sphere(
+            &EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64),
+            2_f64,
+        );
+
+        assert_eq!(sphere.grade(4), sphere);
+    }
+
+    #[test]
+    fn test_plane_contains_points() {
+        let normal = EuclideanMultivector3::new(0_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let plane = ConformalMultivector3::from_plane(&normal, -2_f64);
+        let zero: ConformalMultivector3<f64> = ConformalMultivector3::zero();
+
+        assert_relative_eq!(
+            ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 0_f64, -2_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64)) ^ plane,
+            zero,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 5_f64, -2_f64, 7_f64, 0_f64, 0_f64, 0_f64, 0_f64)) ^ plane,
+            zero,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert!(
+            !(ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 5_f64, 2_f64, 7_f64, 0_f64, 0_f64, 0_f64, 0_f64)) ^ plane)
+                .is_zero()
+        );
+    }
+
+    #[test]
+    fn test_plane_contains_point_at_infinity() {
+        let normal = EuclideanMultivector3::new(0_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let plane = ConformalMultivector3::from_plane(&normal, -2_f64);
+        let einf: ConformalMultivector3<f64> = ConformalMultivector3::unit_einf();
+        let zero: ConformalMultivector3<f64> = ConformalMultivector3::zero();
+
+        assert_relative_eq!(einf ^ plane, zero, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_circle_contains_points() {
+        let center = EuclideanMultivector3::new(0_f64, 1_f64, 1_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let normal = EuclideanMultivector3::new(0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let circle = ConformalMultivector3::from_circle(&center, &normal, 2_f64);
+        let zero: ConformalMultivector3<f64> = ConformalMultivector3::zero();
+
+        assert_eq!(circle.grade(3), circle);
+        assert_relative_eq!(
+            ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 1_f64, 3_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64)) ^ circle,
+            zero,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 1_f64, 1_f64, -1_f64, 0_f64, 0_f64, 0_f64, 0_f64)) ^ circle,
+            zero,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert!(
+            !(ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 3_f64, 1_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64)) ^ circle)
+                .is_zero()
+        );
+    }
+
+    #[test]
+    fn test_line_contains_points() {
+        let point = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let direction = EuclideanMultivector3::new(0_f64, 1_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let line = ConformalMultivector3::from_line(&point, &direction);
+        let zero: ConformalMultivector3<f64> = ConformalMultivector3::zero();
+
+        assert_eq!(line.grade(3), line);
+        assert_relative_eq!(
+            ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 4_f64, 5_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64)) ^ line,
+            zero,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert!(
+            !(ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 4_f64, 5_f64, 4_f64, 0_f64, 0_f64, 0_f64, 0_f64)) ^ line)
+                .is_zero()
+        );
+    }
+
+    #[test]
+    fn test_line_through_origin() {
+        let origin: EuclideanMultivector3<f64> = EuclideanMultivector3::zero();
+        let direction = EuclideanMultivector3::new(0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let line = ConformalMultivector3::from_line(&origin, &direction);
+        let expected = ConformalMultivector3::unit_e0() ^ ConformalMultivector3::unit_e1() ^ ConformalMultivector3::unit_einf();
+
+        assert_eq!(line, expected);
+    }
+
+    #[test]
+    fn test_point_pair_split() {
+        let p = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let q = EuclideanMultivector3::new(0_f64, -4_f64, 0_f64, 5_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let point_pair = ConformalMultivector3::from_point_pair(&p, &q);
+        let (result_p, result_q) = point_pair.split_point_pair().unwrap();
+
+        assert_relative_eq!(result_p, p, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result_q, q, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_point_pair_split_imaginary() {
+        let sphere1 = ConformalMultivector3::from_sphere(
+            &EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64),
+            1_f64,
+        );
+        let sphere2 = ConformalMultivector3::from_sphere(
+            &EuclideanMultivector3::new(0_f64, 5_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64),
+            1_f64,
+        );
+        let circle = sphere1 & sphere2;
+        let point_pair = circle
+            & ConformalMultivector3::from_plane(
+                &EuclideanMultivector3::new(0_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64),
+                0_f64,
+            );
+
+        assert!(point_pair.split_point_pair().is_none());
+    }
+
+    #[test]
+    fn test_point_pair_split_zero() {
+        let zero: ConformalMultivector3<f64> = ConformalMultivector3::zero();
+
+        assert!(zero.split_point_pair().is_none());
+    }
+}
+
+
+#[cfg(test)]
+mod cga3_incidence_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::cga3::ConformalMultivector3;
+    use cggeomalg::e3ga::EuclideanMultivector3;
+
+
+    #[test]
+    fn test_meet_sphere_plane() {
+        let center = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let normal = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let sphere = ConformalMultivector3::from_sphere(&center, 2_f64);
+        let plane = ConformalMultivector3::from_plane(&normal, 3_f64);
+        let expected = ConformalMultivector3::from_circle(&center, &normal, 2_f64);
+        let result = sphere.meet(&plane);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_meet_spheres_circle() {
+        let sphere1 = ConformalMultivector3::from_sphere(
+            &EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64),
+            5_f64,
+        );
+        let sphere2 = ConformalMultivector3::from_sphere(
+            &EuclideanMultivector3::new(0_f64, 6_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64),
+            5_f64,
+        );
+        let circle = sphere1 & sphere2;
+        let zero: ConformalMultivector3<f64> = ConformalMultivector3::zero();
+
+        assert_eq!(circle.grade(3), circle);
+        assert_relative_eq!(
+            ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 3_f64, 4_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64)) ^ circle,
+            zero,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 3_f64, 0_f64, -4_f64, 0_f64, 0_f64, 0_f64, 0_f64)) ^ circle,
+            zero,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert!(
+            !(ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 3_f64, 5_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64)) ^ circle)
+                .is_zero()
+        );
+    }
+
+    #[test]
+    fn test_meet_sphere_line_point_pair() {
+        let sphere = ConformalMultivector3::from_sphere(
+            &EuclideanMultivector3::new(0_f64, 1_f64, 1_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64),
+            2_f64,
+        );
+        let line = ConformalMultivector3::from_line(
+            &EuclideanMultivector3::new(0_f64, 1_f64, 1_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64),
+            &EuclideanMultivector3::new(0_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64),
+        );
+        let point_pair = sphere.meet(&line);
+        let (p, q) = point_pair.split_point_pair().unwrap();
+        let expected_p = EuclideanMultivector3::new(0_f64, 1_f64, 3_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let expected_q = EuclideanMultivector3::new(0_f64, 1_f64, -1_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+
+        assert_eq!(point_pair.grade(2), point_pair);
+        assert!(
+            (p - expected_p).magnitude() < 1e-10 && (q - expected_q).magnitude() < 1e-10
+                || (p - expected_q).magnitude() < 1e-10 && (q - expected_p).magnitude() < 1e-10
+        );
+    }
+
+    #[test]
+    fn test_meet_line_plane_flat_point() {
+        let line = ConformalMultivector3::from_line(
+            &EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64),
+            &EuclideanMultivector3::new(0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64),
+        );
+        let plane = ConformalMultivector3::from_plane(
+            &EuclideanMultivector3::new(0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64),
+            2_f64,
+        );
+        let flat_point = line & plane;
+        let expected = ConformalMultivector3::unit_e0inf() + ConformalMultivector3::unit_e1inf() * 2_f64;
+        let scale = flat_point.e0inf;
+
+        assert!(!flat_point.is_zero());
+        assert_relative_eq!(flat_point, expected * scale, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_join_three_points_circle() {
+        let p = ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 2_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64));
+        let q = ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 0_f64, 2_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64));
+        let r = ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, -2_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64));
+        let circle = p.join(&q).join(&r);
+        let zero: ConformalMultivector3<f64> = ConformalMultivector3::zero();
+
+        assert_relative_eq!(
+            ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 0_f64, -2_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64)) ^ circle,
+            zero,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert!(
+            !(ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 2_f64, 0_f64, 0_f64, 0_f64, 0_f64)) ^ circle)
+                .is_zero()
+        );
+    }
+
+    #[test]
+    fn test_join_collinear_points_line() {
+        let p = ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64));
+        let q = ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 2_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64));
+        let r = ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64));
+        let line = p ^ q ^ r;
+        let einf: ConformalMultivector3<f64> = ConformalMultivector3::unit_einf();
+        let zero: ConformalMultivector3<f64> = ConformalMultivector3::zero();
+
+        assert_relative_eq!(einf ^ line, zero, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+
+#[cfg(test)]
+mod cga3_versor_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::cga3::ConformalMultivector3;
+    use cggeomalg::e3ga::{
+        EuclideanMultivector3,
+        Rotor3,
+    };
+
+
+    #[test]
+    fn test_translator_point() {
+        let translator =
+            ConformalMultivector3::translator(&EuclideanMultivector3::new(0_f64, 1_f64, -2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64));
+        let point = ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 4_f64, 5_f64, 6_f64, 0_f64, 0_f64, 0_f64, 0_f64));
+        let expected = ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 5_f64, 3_f64, 9_f64, 0_f64, 0_f64, 0_f64, 0_f64));
+        let result = point.apply_versor(&translator).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_translator_composition() {
+        let translator1 =
+            ConformalMultivector3::translator(&EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64));
+        let translator2 =
+            ConformalMultivector3::translator(&EuclideanMultivector3::new(0_f64, -4_f64, 5_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64));
+        let expected =
+            ConformalMultivector3::translator(&EuclideanMultivector3::new(0_f64, -3_f64, 7_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64));
+        let result = translator2 * translator1;
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_translator_sphere() {
+        let sphere = ConformalMultivector3::from_sphere(
+            &EuclideanMultivector3::new(0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64),
+            2_f64,
+        );
+        let translator =
+            ConformalMultivector3::translator(&EuclideanMultivector3::new(0_f64, 0_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64));
+        let expected = ConformalMultivector3::from_sphere(
+            &EuclideanMultivector3::new(0_f64, 1_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64),
+            2_f64,
+        );
+        let result = sphere.apply_versor(&translator).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotor_point() {
+        let half_angle = core::f64::consts::FRAC_PI_4;
+        let rotor = Rotor3::new(half_angle.cos(), -half_angle.sin(), 0_f64, 0_f64);
+        let versor = ConformalMultivector3::from_rotor(&rotor);
+        let point = ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 1_f64, 0_f64, 7_f64, 0_f64, 0_f64, 0_f64, 0_f64));
+        let expected = ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 0_f64, 1_f64, 7_f64, 0_f64, 0_f64, 0_f64, 0_f64));
+        let result = point.apply_versor(&versor).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotor_line() {
+        let half_angle = core::f64::consts::FRAC_PI_4;
+        let rotor = Rotor3::new(half_angle.cos(), -half_angle.sin(), 0_f64, 0_f64);
+        let versor = ConformalMultivector3::from_rotor(&rotor);
+        let line = ConformalMultivector3::from_line(
+            &EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64),
+            &EuclideanMultivector3::new(0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64),
+        );
+        let expected = ConformalMultivector3::from_line(
+            &EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64),
+            &EuclideanMultivector3::new(0_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64),
+        );
+        let result = line.apply_versor(&versor).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_dilator_point() {
+        let dilator = ConformalMultivector3::dilator(4_f64);
+        let point = ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 1_f64, -2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64));
+        let expected = EuclideanMultivector3::new(0_f64, 4_f64, -8_f64, 12_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let result = point.apply_versor(&dilator).unwrap();
+
+        assert_relative_eq!(result.down().unwrap(), expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_dilator_sphere() {
+        let dilator = ConformalMultivector3::dilator(2_f64);
+        let sphere = ConformalMultivector3::from_sphere(
+            &EuclideanMultivector3::new(0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64),
+            1_f64,
+        );
+        let expected = ConformalMultivector3::from_sphere(
+            &EuclideanMultivector3::new(0_f64, 2_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64),
+            2_f64,
+        );
+        let result = sphere.apply_versor(&dilator).unwrap();
+        let scale = result.e0123 / expected.e0123;
+
+        assert_relative_eq!(result, expected * scale, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_inversion_point() {
+        let inversion = ConformalMultivector3::inversion(
+            &EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64),
+            1_f64,
+        );
+        let point = ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 2_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64));
+        let expected = EuclideanMultivector3::new(0_f64, 0.5_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let result = point.apply_versor(&inversion).unwrap();
+
+        assert_relative_eq!(result.down().unwrap(), expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_inversion_off_center() {
+        let inversion = ConformalMultivector3::inversion(
+            &EuclideanMultivector3::new(0_f64, 1_f64, 1_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64),
+            2_f64,
+        );
+        let point = ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 1_f64, 1_f64, 9_f64, 0_f64, 0_f64, 0_f64, 0_f64));
+        let expected = EuclideanMultivector3::new(0_f64, 1_f64, 1_f64, 1.5_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let result = point.apply_versor(&inversion).unwrap();
+
+        assert_relative_eq!(result.down().unwrap(), expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_inversion_fixes_sphere() {
+        let inversion = ConformalMultivector3::inversion(
+            &EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64),
+            3_f64,
+        );
+        let point = ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 0_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64));
+        let result = point.apply_versor(&inversion).unwrap();
+
+        assert_relative_eq!(result.down().unwrap(), point.down().unwrap(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_inversion_maps_plane_to_sphere() {
+        let inversion = ConformalMultivector3::inversion(
+            &EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64),
+            1_f64,
+        );
+        let plane = ConformalMultivector3::from_plane(
+            &EuclideanMultivector3::new(0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64),
+            1_f64,
+        );
+        let result = plane.apply_versor(&inversion).unwrap();
+        let zero: ConformalMultivector3<f64> = ConformalMultivector3::zero();
+
+        // The plane x = 1 maps to the sphere through the origin with center (1 / 2, 0, 0).
+        assert_relative_eq!(
+            ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64)) ^ result,
+            zero,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64)) ^ result,
+            zero,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            ConformalMultivector3::up(&EuclideanMultivector3::new(
+                0_f64, 0.5_f64, 0.5_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64
+            )) ^ result,
+            zero,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_apply_versor_mixed_parity() {
+        let point = ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64));
+        let versor = ConformalMultivector3::unit_scalar() + ConformalMultivector3::unit_e1();
+
+        assert!(point.apply_versor(&versor).is_none());
+    }
+
+    #[test]
+    fn test_apply_versor_null() {
+        let point = ConformalMultivector3::up(&EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64));
+        let versor: ConformalMultivector3<f64> = ConformalMultivector3::unit_einf();
+
+        assert!(point.apply_versor(&versor).is_none());
+    }
+
+    #[test]
+    fn test_magnitude() {
+        let mv = ConformalMultivector3::unit_e1() * 3_f64 + ConformalMultivector3::unit_e2() * 4_f64;
+
+        assert_relative_eq!(mv.magnitude(), 5_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(
+            mv.normalize().magnitude(),
+            1_f64,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+}
@@ -0,0 +1,689 @@
+#[cfg(test)]
+mod clifford_component_tests {
+    use cggeomalg::clifford::Multivector;
+
+
+    #[test]
+    fn test_components() {
+        let mv: Multivector<i32, 3, 0, 0, 8> = Multivector::new([1, 2, 3, 4, 5, 6, 7, 8]);
+
+        assert_eq!(mv[0], 1);
+        assert_eq!(mv[1], 2);
+        assert_eq!(mv[2], 3);
+        assert_eq!(mv[3], 4);
+        assert_eq!(mv[4], 5);
+        assert_eq!(mv[5], 6);
+        assert_eq!(mv[6], 7);
+        assert_eq!(mv[7], 8);
+        assert_eq!(mv.as_slice(), &[1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_components_out_of_bounds() {
+        let mv: Multivector<i32, 3, 0, 0, 8> = Multivector::new([1, 2, 3, 4, 5, 6, 7, 8]);
+
+        assert_eq!(mv[8], mv[8]);
+    }
+
+    #[test]
+    fn test_len_dimension() {
+        let mv1: Multivector<f64, 0, 0, 0, 1> = Multivector::zero();
+        let mv2: Multivector<f64, 3, 0, 1, 16> = Multivector::zero();
+        let mv3: Multivector<f64, 4, 1, 0, 32> = Multivector::zero();
+
+        assert_eq!((mv1.len(), mv1.dimension()), (1, 0));
+        assert_eq!((mv2.len(), mv2.dimension()), (16, 4));
+        assert_eq!((mv3.len(), mv3.dimension()), (32, 5));
+    }
+
+    #[test]
+    fn test_unit_blade() {
+        let e13: Multivector<i32, 3, 0, 0, 8> = Multivector::unit_blade(0b101);
+        let expected = Multivector::new([0, 0, 0, 0, 0, 1, 0, 0]);
+
+        assert_eq!(e13, expected);
+    }
+
+    #[test]
+    fn test_unit_vector() {
+        let e1: Multivector<i32, 3, 0, 0, 8> = Multivector::unit_vector(0);
+        let e2: Multivector<i32, 3, 0, 0, 8> = Multivector::unit_vector(1);
+        let e3: Multivector<i32, 3, 0, 0, 8> = Multivector::unit_vector(2);
+
+        assert_eq!(e1, Multivector::unit_blade(0b001));
+        assert_eq!(e2, Multivector::unit_blade(0b010));
+        assert_eq!(e3, Multivector::unit_blade(0b100));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_unit_vector_out_of_bounds() {
+        let _: Multivector<i32, 3, 0, 0, 8> = Multivector::unit_vector(3);
+    }
+
+    #[test]
+    fn test_pseudoscalar() {
+        let e1: Multivector<i32, 2, 1, 1, 16> = Multivector::unit_vector(0);
+        let e2: Multivector<i32, 2, 1, 1, 16> = Multivector::unit_vector(1);
+        let e3: Multivector<i32, 2, 1, 1, 16> = Multivector::unit_vector(2);
+        let e4: Multivector<i32, 2, 1, 1, 16> = Multivector::unit_vector(3);
+
+        assert_eq!(e1 ^ e2 ^ e3 ^ e4, Multivector::pseudoscalar());
+    }
+
+    #[test]
+    fn test_display() {
+        let mv: Multivector<i32, 2, 0, 0, 4> = Multivector::new([1, 2, 3, 4]);
+        let expected = "1 + 2^e1 + 3^e2 + 4^e12";
+
+        assert_eq!(format!("{}", mv), expected);
+    }
+}
+
+
+#[cfg(test)]
+mod clifford_signature_tests {
+    use cggeomalg::clifford::Multivector;
+
+
+    #[test]
+    fn test_generator_squares() {
+        type Mv = Multivector<i32, 2, 2, 1, 32>;
+        let one = Mv::unit_scalar();
+        let zero = Mv::zero();
+
+        assert_eq!(Mv::unit_vector(0) * Mv::unit_vector(0), one);
+        assert_eq!(Mv::unit_vector(1) * Mv::unit_vector(1), one);
+        assert_eq!(Mv::unit_vector(2) * Mv::unit_vector(2), -one);
+        assert_eq!(Mv::unit_vector(3) * Mv::unit_vector(3), -one);
+        assert_eq!(Mv::unit_vector(4) * Mv::unit_vector(4), zero);
+    }
+
+    #[test]
+    fn test_generators_anticommute() {
+        type Mv = Multivector<i32, 2, 2, 1, 32>;
+
+        for i in 0..5 {
+            for j in 0..5 {
+                if i != j {
+                    let ei = Mv::unit_vector(i);
+                    let ej = Mv::unit_vector(j);
+
+                    assert_eq!(ei * ej, -(ej * ei));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_complex_numbers() {
+        type Complex = Multivector<i32, 0, 1, 0, 2>;
+        let z1 = Complex::new([1, 2]);
+        let z2 = Complex::new([3, -4]);
+        let expected = Complex::new([3 + 2 * 4, -4 + 2 * 3]);
+
+        assert_eq!(z1 * z2, expected);
+    }
+
+    #[test]
+    fn test_quaternions() {
+        type Quaternion = Multivector<i32, 0, 2, 0, 4>;
+        let i = Quaternion::unit_blade(0b01);
+        let j = Quaternion::unit_blade(0b10);
+        let k = Quaternion::unit_blade(0b11);
+
+        assert_eq!(i * j, k);
+        assert_eq!(j * k, i);
+        assert_eq!(k * i, j);
+        assert_eq!(i * j * k, -Quaternion::unit_scalar());
+    }
+
+    #[test]
+    fn test_dual_numbers() {
+        type Dual = Multivector<f64, 0, 0, 1, 2>;
+        let a = Dual::new([2_f64, 3_f64]);
+        let b = Dual::new([5_f64, 7_f64]);
+        let expected = Dual::new([10_f64, 2_f64 * 7_f64 + 3_f64 * 5_f64]);
+
+        assert_eq!(a * b, expected);
+    }
+
+    #[test]
+    fn test_degenerate_dual_undual() {
+        type Pga3 = Multivector<i32, 3, 0, 1, 16>;
+        let mv = Pga3::new([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+
+        assert_eq!(mv.dual().undual(), mv);
+        assert_eq!(mv.undual().dual(), mv);
+    }
+
+    #[test]
+    fn test_degenerate_dual_is_right_complement() {
+        type Pga3 = Multivector<i32, 3, 0, 1, 16>;
+        let ps = Pga3::pseudoscalar();
+
+        for blade in 0..16 {
+            let mv = Pga3::unit_blade(blade);
+
+            assert_eq!(mv ^ mv.dual(), ps);
+        }
+    }
+
+    #[test]
+    fn test_degenerate_regressive_product() {
+        type Pga3 = Multivector<i32, 3, 0, 1, 16>;
+        let e14 = Pga3::unit_blade(0b1001);
+        let e24 = Pga3::unit_blade(0b1010);
+        let e124 = Pga3::unit_blade(0b1011);
+
+        assert_eq!(e124 & Pga3::unit_blade(0b1101), e14);
+        assert_eq!((e124.dual() ^ Pga3::unit_blade(0b1101).dual()).undual(), e14);
+        assert_eq!(e14 & e24, (e14.dual() ^ e24.dual()).undual());
+    }
+
+    #[test]
+    fn test_degenerate_inverse() {
+        type Dual = Multivector<f64, 0, 0, 1, 2>;
+        let a = Dual::new([2_f64, 3_f64]);
+        let expected = Dual::new([1_f64 / 2_f64, -3_f64 / 4_f64]);
+
+        assert_eq!(a.inverse(), Some(expected));
+        assert_eq!(Dual::unit_vector(0).inverse(), None);
+    }
+
+    #[test]
+    fn test_null_vector_is_not_invertible() {
+        type Mv = Multivector<f64, 4, 1, 0, 32>;
+        let null = Mv::unit_vector(3) + Mv::unit_vector(4);
+
+        assert_eq!(null * null, Mv::zero());
+        assert!(!null.is_invertible());
+    }
+
+    #[test]
+    fn test_inverse_conformal() {
+        type Mv = Multivector<f64, 4, 1, 0, 32>;
+        let mut mv = Mv::zero();
+        for i in 0..32 {
+            mv[i] = ((i * 7 + 3) % 11) as f64 - 5_f64;
+        }
+        let mv_inv = mv.inverse().unwrap();
+        let one = Mv::unit_scalar();
+
+        assert!((mv * mv_inv).distance(&one) < 1e-10);
+        assert!((mv_inv * mv).distance(&one) < 1e-10);
+        assert!(((one / mv) - mv_inv).magnitude() < 1e-10);
+    }
+
+    #[test]
+    fn test_inv_pseudoscalar() {
+        fn check<const P: usize, const Q: usize, const N: usize>() {
+            let ps: Multivector<i32, P, Q, 0, N> = Multivector::pseudoscalar();
+            let ps_inv = Multivector::inv_pseudoscalar().unwrap();
+
+            assert_eq!(ps * ps_inv, Multivector::unit_scalar());
+        }
+
+        check::<1, 0, 2>();
+        check::<0, 1, 2>();
+        check::<2, 0, 4>();
+        check::<1, 1, 4>();
+        check::<0, 2, 4>();
+        check::<3, 0, 8>();
+        check::<1, 2, 8>();
+        check::<4, 0, 16>();
+        check::<1, 3, 16>();
+        check::<4, 1, 32>();
+        check::<0, 5, 32>();
+    }
+}
+
+
+#[cfg(test)]
+mod clifford_e2ga_cross_check_tests {
+    use cggeomalg::clifford::Multivector;
+    use cggeomalg::e2ga::EuclideanMultivector2;
+
+    type Mv = Multivector<i64, 2, 0, 0, 4>;
+
+    fn samples() -> [EuclideanMultivector2<i64>; 4] {
+        [
+            EuclideanMultivector2::new(1, 2, 3, 4),
+            EuclideanMultivector2::new(-5, 7, -11, 13),
+            EuclideanMultivector2::new(0, 1, -1, 0),
+            EuclideanMultivector2::new(17, 0, 19, -23),
+        ]
+    }
+
+    #[test]
+    fn test_conversion_round_trip() {
+        for mv in samples() {
+            assert_eq!(EuclideanMultivector2::from(Mv::from(mv)), mv);
+        }
+    }
+
+    #[test]
+    fn test_binary_operations() {
+        for a in samples() {
+            for b in samples() {
+                let ga = Mv::from(a);
+                let gb = Mv::from(b);
+
+                assert_eq!(ga + gb, Mv::from(a + b));
+                assert_eq!(ga - gb, Mv::from(a - b));
+                assert_eq!(ga * gb, Mv::from(a * b));
+                assert_eq!(ga ^ gb, Mv::from(a ^ b));
+                assert_eq!(ga | gb, Mv::from(a | b));
+                assert_eq!(ga << gb, Mv::from(a << b));
+                assert_eq!(ga >> gb, Mv::from(a >> b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_binary_operations_references() {
+        for a in samples() {
+            for b in samples() {
+                let ga = Mv::from(a);
+                let gb = Mv::from(b);
+                let a_ref = &a;
+                let b_ref = &b;
+
+                assert_eq!(Mv::from(a_ref + b_ref), ga + gb);
+                assert_eq!(Mv::from(a + b_ref), ga + gb);
+                assert_eq!(Mv::from(a_ref + b), ga + gb);
+                assert_eq!(Mv::from(a_ref - b_ref), ga - gb);
+                assert_eq!(Mv::from(a - b_ref), ga - gb);
+                assert_eq!(Mv::from(a_ref - b), ga - gb);
+                assert_eq!(Mv::from(a_ref * b_ref), ga * gb);
+                assert_eq!(Mv::from(a * b_ref), ga * gb);
+                assert_eq!(Mv::from(a_ref * b), ga * gb);
+                assert_eq!(Mv::from(a_ref ^ b_ref), ga ^ gb);
+                assert_eq!(Mv::from(a ^ b_ref), ga ^ gb);
+                assert_eq!(Mv::from(a_ref ^ b), ga ^ gb);
+                assert_eq!(Mv::from(a_ref | b_ref), ga | gb);
+                assert_eq!(Mv::from(a | b_ref), ga | gb);
+                assert_eq!(Mv::from(a_ref | b), ga | gb);
+                assert_eq!(Mv::from(a_ref << b_ref), ga << gb);
+                assert_eq!(Mv::from(a << b_ref), ga << gb);
+                assert_eq!(Mv::from(a_ref << b), ga << gb);
+                assert_eq!(Mv::from(a_ref >> b_ref), ga >> gb);
+                assert_eq!(Mv::from(a >> b_ref), ga >> gb);
+                assert_eq!(Mv::from(a_ref >> b), ga >> gb);
+            }
+        }
+    }
+
+    #[test]
+    fn test_scalar_operations_references() {
+        for a in samples() {
+            let ga = Mv::from(a);
+            let a_ref = &a;
+
+            assert_eq!(Mv::from(a_ref + 3), ga + 3);
+            assert_eq!(Mv::from(a_ref - 3), ga - 3);
+            assert_eq!(Mv::from(a_ref * 3), ga * 3);
+            assert_eq!(Mv::from(a_ref ^ 3), ga ^ 3);
+            assert_eq!(Mv::from(a_ref | 3), ga | 3);
+            assert_eq!(Mv::from(a_ref << 3), ga << 3);
+            assert_eq!(Mv::from(a_ref >> 3), ga >> 3);
+        }
+    }
+
+    #[test]
+    fn test_unary_operations() {
+        for a in samples() {
+            let ga = Mv::from(a);
+
+            assert_eq!(-ga, Mv::from(-a));
+            assert_eq!(ga.reverse(), Mv::from(a.reverse()));
+            assert_eq!(ga.conjugate(), Mv::from(a.conjugate()));
+            assert_eq!(ga.involute(), Mv::from(a.involute()));
+            assert_eq!(ga.dual(), Mv::from(a.dual()));
+            assert_eq!(!ga, Mv::from(!a));
+            assert_eq!(ga.grade(1), Mv::from(a.grade(1)));
+        }
+    }
+
+    #[test]
+    fn test_scalar_operations() {
+        for a in samples() {
+            let ga = Mv::from(a);
+
+            assert_eq!(ga + 3, Mv::from(a + 3));
+            assert_eq!(ga - 3, Mv::from(a - 3));
+            assert_eq!(ga * 3, Mv::from(a * 3));
+            assert_eq!(3 + ga, Mv::from(3 + a));
+            assert_eq!(3 - ga, -(ga - 3));
+            assert_eq!(3 * ga, Mv::from(3 * a));
+            assert_eq!(ga ^ 3, Mv::from(a ^ 3));
+            assert_eq!(ga | 3, Mv::from(a | 3));
+            assert_eq!(ga << 3, Mv::from(a << 3));
+            assert_eq!(ga >> 3, Mv::from(a >> 3));
+        }
+    }
+
+    #[test]
+    fn test_inverse() {
+        let a = EuclideanMultivector2::new(1_f64, 2_f64, 3_f64, 4_f64);
+        let ga: Multivector<f64, 2, 0, 0, 4> = Multivector::from(a);
+        let expected: Multivector<f64, 2, 0, 0, 4> = Multivector::from(a.inverse().unwrap());
+
+        assert!(ga.inverse().unwrap().distance(&expected) < 1e-12);
+    }
+}
+
+
+#[cfg(test)]
+mod clifford_e3ga_cross_check_tests {
+    use cggeomalg::clifford::Multivector;
+    use cggeomalg::e3ga::EuclideanMultivector3;
+
+    type Mv = Multivector<i64, 3, 0, 0, 8>;
+
+    fn samples() -> [EuclideanMultivector3<i64>; 4] {
+        [
+            EuclideanMultivector3::new(1, 2, 3, 4, 5, 6, 7, 8),
+            EuclideanMultivector3::new(-5, 7, -11, 13, 2, -3, 0, 9),
+            EuclideanMultivector3::new(0, 1, -1, 0, 0, 4, 0, 0),
+            EuclideanMultivector3::new(17, 0, 19, -23, 29, 0, -31, 37),
+        ]
+    }
+
+    #[test]
+    fn test_conversion() {
+        let e31 = EuclideanMultivector3::unit_e31();
+
+        assert_eq!(Mv::from(e31), -Mv::unit_blade(0b101));
+        for mv in samples() {
+            assert_eq!(EuclideanMultivector3::from(Mv::from(mv)), mv);
+        }
+    }
+
+    #[test]
+    fn test_binary_operations() {
+        for a in samples() {
+            for b in samples() {
+                let ga = Mv::from(a);
+                let gb = Mv::from(b);
+
+                assert_eq!(ga + gb, Mv::from(a + b));
+                assert_eq!(ga - gb, Mv::from(a - b));
+                assert_eq!(ga * gb, Mv::from(a * b));
+                assert_eq!(ga ^ gb, Mv::from(a ^ b));
+                assert_eq!(ga & gb, Mv::from(a & b));
+                assert_eq!(ga | gb, Mv::from(a | b));
+                assert_eq!(ga << gb, Mv::from(a << b));
+                assert_eq!(ga >> gb, Mv::from(a >> b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_binary_operations_references() {
+        for a in samples() {
+            for b in samples() {
+                let ga = Mv::from(a);
+                let gb = Mv::from(b);
+                let a_ref = &a;
+                let b_ref = &b;
+
+                assert_eq!(Mv::from(a_ref + b_ref), ga + gb);
+                assert_eq!(Mv::from(a + b_ref), ga + gb);
+                assert_eq!(Mv::from(a_ref + b), ga + gb);
+                assert_eq!(Mv::from(a_ref - b_ref), ga - gb);
+                assert_eq!(Mv::from(a - b_ref), ga - gb);
+                assert_eq!(Mv::from(a_ref - b), ga - gb);
+                assert_eq!(Mv::from(a_ref * b_ref), ga * gb);
+                assert_eq!(Mv::from(a * b_ref), ga * gb);
+                assert_eq!(Mv::from(a_ref * b), ga * gb);
+                assert_eq!(Mv::from(a_ref ^ b_ref), ga ^ gb);
+                assert_eq!(Mv::from(a ^ b_ref), ga ^ gb);
+                assert_eq!(Mv::from(a_ref ^ b), ga ^ gb);
+                assert_eq!(Mv::from(a_ref & b_ref), ga & gb);
+                assert_eq!(Mv::from(a & b_ref), ga & gb);
+                assert_eq!(Mv::from(a_ref & b), ga & gb);
+                assert_eq!(Mv::from(a_ref | b_ref), ga | gb);
+                assert_eq!(Mv::from(a | b_ref), ga | gb);
+                assert_eq!(Mv::from(a_ref | b), ga | gb);
+                assert_eq!(Mv::from(a_ref << b_ref), ga << gb);
+                assert_eq!(Mv::from(a << b_ref), ga << gb);
+                assert_eq!(Mv::from(a_ref << b), ga << gb);
+                assert_eq!(Mv::from(a_ref >> b_ref), ga >> gb);
+                assert_eq!(Mv::from(a >> b_ref), ga >> gb);
+                assert_eq!(Mv::from(a_ref >> b), ga >> gb);
+            }
+        }
+    }
+
+    #[test]
+    fn test_unary_operations() {
+        for a in samples() {
+            let ga = Mv::from(a);
+
+            assert_eq!(-ga, Mv::from(-a));
+            assert_eq!(ga.reverse(), Mv::from(a.reverse()));
+            assert_eq!(ga.conjugate(), Mv::from(a.conjugate()));
+            assert_eq!(ga.involute(), Mv::from(a.involute()));
+            assert_eq!(ga.dual(), Mv::from(a.dual()));
+            assert_eq!(!ga, Mv::from(!a));
+            assert_eq!(ga.grade(2), Mv::from(a.grade(2)));
+        }
+    }
+
+    #[test]
+    fn test_inv_pseudoscalar() {
+        assert_eq!(Mv::inv_pseudoscalar(), Some(Mv::from(EuclideanMultivector3::inv_pseudoscalar())));
+    }
+
+    #[test]
+    fn test_inverse() {
+        let a = EuclideanMultivector3::new(1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64);
+        let ga: Multivector<f64, 3, 0, 0, 8> = Multivector::from(a);
+        let expected: Multivector<f64, 3, 0, 0, 8> = Multivector::from(a.inverse().unwrap());
+
+        assert!(ga.inverse().unwrap().distance(&expected) < 1e-12);
+        assert_eq!(ga.magnitude(), a.magnitude());
+    }
+
+    #[test]
+    fn test_scalar_division() {
+        let a = EuclideanMultivector3::new(1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64);
+        let ga: Multivector<f64, 3, 0, 0, 8> = Multivector::from(a);
+        let expected: Multivector<f64, 3, 0, 0, 8> = Multivector::from(3_f64 / a);
+
+        assert!((3_f64 / ga).distance(&expected) < 1e-12);
+        assert!((3_f64 / &ga).distance(&expected) < 1e-12);
+    }
+
+    #[test]
+    fn test_scalar_division_f32() {
+        let a = EuclideanMultivector3::new(1_f32, 2_f32, 3_f32, 4_f32, 5_f32, 6_f32, 7_f32, 8_f32);
+        let ga: Multivector<f32, 3, 0, 0, 8> = Multivector::from(a);
+        let expected: Multivector<f32, 3, 0, 0, 8> = Multivector::from(2_f32 / a);
+
+        assert!((2_f32 / ga).distance(&expected) < 1e-5);
+    }
+}
+
+
+#[cfg(test)]
+mod clifford_e4ga_cross_check_tests {
+    use cggeomalg::clifford::Multivector;
+    use cggeomalg::e4ga::EuclideanMultivector4;
+
+    type Mv = Multivector<i64, 4, 0, 0, 16>;
+
+    fn samples() -> [EuclideanMultivector4<i64>; 3] {
+        [
+            EuclideanMultivector4::new(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16),
+            EuclideanMultivector4::new(-5, 7, -11, 13, 2, -3, 0, 9, 1, 0, -4, 6, 0, 8, -2, 3),
+            EuclideanMultivector4::new(17, 0, 19, -23, 29, 0, -31, 37, 0, 0, 41, 0, -43, 0, 0, 47),
+        ]
+    }
+
+    #[test]
+    fn test_binary_operations() {
+        for a in samples() {
+            for b in samples() {
+                let ga = Mv::from(a);
+                let gb = Mv::from(b);
+
+                assert_eq!(ga * gb, Mv::from(a * b));
+                assert_eq!(ga ^ gb, Mv::from(a ^ b));
+                assert_eq!(ga & gb, Mv::from(a & b));
+                assert_eq!(ga | gb, Mv::from(a | b));
+                assert_eq!(ga << gb, Mv::from(a << b));
+                assert_eq!(ga >> gb, Mv::from(a >> b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_binary_operations_references() {
+        for a in samples() {
+            for b in samples() {
+                let ga = Mv::from(a);
+                let gb = Mv::from(b);
+                let a_ref = &a;
+                let b_ref = &b;
+
+                assert_eq!(Mv::from(a_ref + b_ref), ga + gb);
+                assert_eq!(Mv::from(a + b_ref), ga + gb);
+                assert_eq!(Mv::from(a_ref + b), ga + gb);
+                assert_eq!(Mv::from(a_ref - b_ref), ga - gb);
+                assert_eq!(Mv::from(a - b_ref), ga - gb);
+                assert_eq!(Mv::from(a_ref - b), ga - gb);
+                assert_eq!(Mv::from(a_ref * b_ref), ga * gb);
+                assert_eq!(Mv::from(a * b_ref), ga * gb);
+                assert_eq!(Mv::from(a_ref * b), ga * gb);
+                assert_eq!(Mv::from(a_ref ^ b_ref), ga ^ gb);
+                assert_eq!(Mv::from(a ^ b_ref), ga ^ gb);
+                assert_eq!(Mv::from(a_ref ^ b), ga ^ gb);
+                assert_eq!(Mv::from(a_ref & b_ref), ga & gb);
+                assert_eq!(Mv::from(a & b_ref), ga & gb);
+                assert_eq!(Mv::from(a_ref & b), ga & gb);
+                assert_eq!(Mv::from(a_ref | b_ref), ga | gb);
+                assert_eq!(Mv::from(a | b_ref), ga | gb);
+                assert_eq!(Mv::from(a_ref | b), ga | gb);
+                assert_eq!(Mv::from(a_ref << b_ref), ga << gb);
+                assert_eq!(Mv::from(a << b_ref), ga << gb);
+                assert_eq!(Mv::from(a_ref << b), ga << gb);
+                assert_eq!(Mv::from(a_ref >> b_ref), ga >> gb);
+                assert_eq!(Mv::from(a >> b_ref), ga >> gb);
+                assert_eq!(Mv::from(a_ref >> b), ga >> gb);
+            }
+        }
+    }
+
+    #[test]
+    fn test_unary_operations() {
+        for a in samples() {
+            let ga = Mv::from(a);
+
+            assert_eq!(EuclideanMultivector4::from(ga), a);
+            assert_eq!(ga.reverse(), Mv::from(a.reverse()));
+            assert_eq!(ga.conjugate(), Mv::from(a.conjugate()));
+            assert_eq!(ga.involute(), Mv::from(a.involute()));
+            assert_eq!(ga.dual(), Mv::from(a.dual()));
+            assert_eq!(ga.undual(), Mv::from(a.undual()));
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod clifford_sta_cross_check_tests {
+    use cggeomalg::clifford::Multivector;
+    use cggeomalg::sta::SpacetimeMultivector;
+
+    type Mv = Multivector<i64, 1, 3, 0, 16>;
+
+    fn samples() -> [SpacetimeMultivector<i64>; 3] {
+        [
+            SpacetimeMultivector::new(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16),
+            SpacetimeMultivector::new(-5, 7, -11, 13, 2, -3, 0, 9, 1, 0, -4, 6, 0, 8, -2, 3),
+            SpacetimeMultivector::new(17, 0, 19, -23, 29, 0, -31, 37, 0, 0, 41, 0, -43, 0, 0, 47),
+        ]
+    }
+
+    #[test]
+    fn test_binary_operations() {
+        for a in samples() {
+            for b in samples() {
+                let ga = Mv::from(a);
+                let gb = Mv::from(b);
+
+                assert_eq!(ga * gb, Mv::from(a * b));
+                assert_eq!(ga ^ gb, Mv::from(a ^ b));
+                assert_eq!(ga & gb, Mv::from(a & b));
+                assert_eq!(ga | gb, Mv::from(a | b));
+                assert_eq!(ga << gb, Mv::from(a << b));
+                assert_eq!(ga >> gb, Mv::from(a >> b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_binary_operations_references() {
+        for a in samples() {
+            for b in samples() {
+                let ga = Mv::from(a);
+                let gb = Mv::from(b);
+                let a_ref = &a;
+                let b_ref = &b;
+
+                assert_eq!(Mv::from(a_ref + b_ref), ga + gb);
+                assert_eq!(Mv::from(a + b_ref), ga + gb);
+                assert_eq!(Mv::from(a_ref + b), ga + gb);
+                assert_eq!(Mv::from(a_ref - b_ref), ga - gb);
+                assert_eq!(Mv::from(a - b_ref), ga - gb);
+                assert_eq!(Mv::from(a_ref - b), ga - gb);
+                assert_eq!(Mv::from(a_ref * b_ref), ga * gb);
+                assert_eq!(Mv::from(a * b_ref), ga * gb);
+                assert_eq!(Mv::from(a_ref * b), ga * gb);
+                assert_eq!(Mv::from(a_ref ^ b_ref), ga ^ gb);
+                assert_eq!(Mv::from(a ^ b_ref), ga ^ gb);
+                assert_eq!(Mv::from(a_ref ^ b), ga ^ gb);
+                assert_eq!(Mv::from(a_ref & b_ref), ga & gb);
+                assert_eq!(Mv::from(a & b_ref), ga & gb);
+                assert_eq!(Mv::from(a_ref & b), ga & gb);
+                assert_eq!(Mv::from(a_ref | b_ref), ga | gb);
+                assert_eq!(Mv::from(a | b_ref), ga | gb);
+                assert_eq!(Mv::from(a_ref | b), ga | gb);
+                assert_eq!(Mv::from(a_ref << b_ref), ga << gb);
+                assert_eq!(Mv::from(a << b_ref), ga << gb);
+                assert_eq!(Mv::from(a_ref << b), ga << gb);
+                assert_eq!(Mv::from(a_ref >> b_ref), ga >> gb);
+                assert_eq!(Mv::from(a >> b_ref), ga >> gb);
+                assert_eq!(Mv::from(a_ref >> b), ga >> gb);
+            }
+        }
+    }
+
+    #[test]
+    fn test_unary_operations() {
+        for a in samples() {
+            let ga = Mv::from(a);
+
+            assert_eq!(SpacetimeMultivector::from(ga), a);
+            assert_eq!(ga.reverse(), Mv::from(a.reverse()));
+            assert_eq!(ga.conjugate(), Mv::from(a.conjugate()));
+            assert_eq!(ga.involute(), Mv::from(a.involute()));
+            assert_eq!(ga.dual(), Mv::from(a.dual()));
+            assert_eq!(ga.undual(), Mv::from(a.undual()));
+        }
+    }
+
+    #[test]
+    fn test_inverse() {
+        let a = SpacetimeMultivector::new(
+            13_f64, -4_f64, 98_f64, 4_f64, 7_f64, -10_f64, 30_f64, 2_f64, 1_f64, 5_f64, -6_f64, 3_f64, 8_f64, -9_f64, 11_f64, 12_f64,
+        );
+        let ga: Multivector<f64, 1, 3, 0, 16> = Multivector::from(a);
+        let expected: Multivector<f64, 1, 3, 0, 16> = Multivector::from(a.inverse().unwrap());
+
+        assert!(ga.inverse().unwrap().distance(&expected) < 1e-12);
+    }
+}
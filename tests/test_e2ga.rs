@@ -1433,3 +1433,1486 @@ mod e2ga_duality_tests {
         assert_eq!(e2 >> e2_dual, zero);
     }
 }
+
+
+#[cfg(test)]
+mod e2ga_exp_log_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::e2ga::EuclideanMultivector2;
+    use core::f64;
+
+
+    fn exp_series(mv: &EuclideanMultivector2<f64>) -> EuclideanMultivector2<f64> {
+        let mut term = EuclideanMultivector2::unit_scalar();
+        let mut result = EuclideanMultivector2::unit_scalar();
+        for k in 1..40 {
+            term = (term * mv) / (k as f64);
+            result += term;
+        }
+
+        result
+    }
+
+    #[test]
+    fn test_exp_zero() {
+        let zero: EuclideanMultivector2<f64> = EuclideanMultivector2::zero();
+        let expected = EuclideanMultivector2::unit_scalar();
+        let result = zero.exp();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_exp_scalar() {
+        let mv = EuclideanMultivector2::from_scalar(2_f64);
+        let expected = EuclideanMultivector2::from_scalar(2_f64.exp());
+        let result = mv.exp();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_exp_bivector() {
+        let angle = f64::consts::FRAC_PI_6;
+        let mv = EuclideanMultivector2::unit_e12() * angle;
+        let expected = EuclideanMultivector2::new(angle.cos(), 0_f64, 0_f64, angle.sin());
+        let result = mv.exp();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_exp_vector() {
+        let mv = EuclideanMultivector2::new(0_f64, 3_f64, 4_f64, 0_f64);
+        let expected = EuclideanMultivector2::new(5_f64.cosh(), 3_f64 / 5_f64 * 5_f64.sinh(), 4_f64 / 5_f64 * 5_f64.sinh(), 0_f64);
+        let result = mv.exp();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_exp_nilpotent() {
+        let mv = EuclideanMultivector2::new(0_f64, 1_f64, 0_f64, 1_f64);
+        let expected = EuclideanMultivector2::new(1_f64, 1_f64, 0_f64, 1_f64);
+        let result = mv.exp();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_exp_series() {
+        let mvs = [
+            EuclideanMultivector2::new(0.5_f64, 1_f64, -1_f64, 2_f64),
+            EuclideanMultivector2::new(-1_f64, 2_f64, 1_f64, 0.5_f64),
+            EuclideanMultivector2::new(0.25_f64, 0_f64, 0_f64, -3_f64),
+        ];
+        for mv in mvs.iter() {
+            let expected = exp_series(mv);
+            let result = mv.exp();
+
+            assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_exp_ln() {
+        let mvs = [
+            EuclideanMultivector2::new(2_f64, 1_f64, -1_f64, 3_f64),
+            EuclideanMultivector2::new(3_f64, 1_f64, 2_f64, 0_f64),
+            EuclideanMultivector2::new(-1_f64, 0.5_f64, 0_f64, 2_f64),
+            EuclideanMultivector2::new(1_f64, 1e-9_f64, 0_f64, 0_f64),
+        ];
+        for mv in mvs.iter() {
+            let ln_mv = mv.ln().unwrap();
+            let result = ln_mv.exp();
+
+            assert_relative_eq!(result, mv, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_ln_exp() {
+        let mv = EuclideanMultivector2::new(0.5_f64, 0.25_f64, -0.5_f64, 1_f64);
+        let expected = mv;
+        let result = mv.exp().ln().unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    }
+
+    #[test]
+    fn test_ln_negative_scalar() {
+        let mv = EuclideanMultivector2::from_scalar(-2_f64);
+        let ln_mv = mv.ln().unwrap();
+
+        assert_relative_eq!(ln_mv.exp(), mv, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_ln_nilpotent() {
+        let mv = EuclideanMultivector2::new(2_f64, 1_f64, 0_f64, 1_f64);
+        let expected = EuclideanMultivector2::new(2_f64.ln(), 0.5_f64, 0_f64, 0.5_f64);
+        let result = mv.ln().unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_ln_zero() {
+        let zero: EuclideanMultivector2<f64> = EuclideanMultivector2::zero();
+
+        assert!(zero.ln().is_none());
+    }
+
+    #[test]
+    fn test_ln_zero_divisor() {
+        let mv = EuclideanMultivector2::new(1_f64, 0_f64, 1_f64, 0_f64);
+
+        assert!(mv.ln().is_none());
+    }
+
+    #[test]
+    fn test_ln_negative_real_eigenvalues() {
+        let mv = EuclideanMultivector2::new(-3_f64, 1_f64, 0_f64, 0_f64);
+
+        assert!(mv.ln().is_none());
+    }
+
+    #[test]
+    fn test_log_synonym() {
+        let mv = EuclideanMultivector2::new(2_f64, 1_f64, -1_f64, 3_f64);
+
+        assert_eq!(mv.log(), mv.ln());
+    }
+
+    #[test]
+    fn test_sinh_cosh() {
+        let mv = EuclideanMultivector2::new(0.5_f64, 1_f64, -1_f64, 2_f64);
+        let exp_mv = mv.exp();
+        let exp_neg_mv = (-mv).exp();
+
+        assert_relative_eq!(mv.sinh(), (exp_mv - exp_neg_mv) * 0.5_f64, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+        assert_relative_eq!(mv.cosh(), (exp_mv + exp_neg_mv) * 0.5_f64, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+        assert_relative_eq!(mv.cosh() + mv.sinh(), exp_mv, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    }
+
+    #[test]
+    fn test_sin_cos_pythagorean_identity() {
+        let mv = EuclideanMultivector2::new(0.5_f64, 1_f64, -1_f64, 2_f64);
+        let sin_mv = mv.sin();
+        let cos_mv = mv.cos();
+        let expected = EuclideanMultivector2::unit_scalar();
+        let result = sin_mv * sin_mv + cos_mv * cos_mv;
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    }
+
+    #[test]
+    fn test_sin_cos_bivector() {
+        // The unit bivector squares to `-1`, so `sin(x * e12) == sinh(x) * e12`.
+        let mv = EuclideanMultivector2::unit_e12() * 2_f64;
+        let expected_sin = EuclideanMultivector2::unit_e12() * 2_f64.sinh();
+        let expected_cos = EuclideanMultivector2::from_scalar(2_f64.cosh());
+
+        assert_relative_eq!(mv.sin(), expected_sin, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(mv.cos(), expected_cos, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+
+#[cfg(test)]
+mod e2ga_power_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::e2ga::EuclideanMultivector2;
+    use core::f64;
+
+
+    #[test]
+    fn test_sqrt_squared() {
+        let mvs = [
+            EuclideanMultivector2::new(2_f64, 1_f64, -1_f64, 3_f64),
+            EuclideanMultivector2::new(3_f64, 1_f64, 2_f64, 0_f64),
+            EuclideanMultivector2::new(-1_f64, 0.5_f64, 0_f64, 2_f64),
+            EuclideanMultivector2::new(-4_f64, 0_f64, 0_f64, 1e-6_f64),
+            EuclideanMultivector2::new(1_f64, 1_f64, 0_f64, 0_f64),
+        ];
+        for mv in mvs.iter() {
+            let sqrt_mv = mv.sqrt().unwrap();
+            let result = sqrt_mv * sqrt_mv;
+
+            assert_relative_eq!(result, mv, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_sqrt_zero() {
+        let zero: EuclideanMultivector2<f64> = EuclideanMultivector2::zero();
+
+        assert_eq!(zero.sqrt(), Some(zero));
+    }
+
+    #[test]
+    fn test_sqrt_scalar() {
+        let mv = EuclideanMultivector2::from_scalar(4_f64);
+        let expected = EuclideanMultivector2::from_scalar(2_f64);
+        let result = mv.sqrt().unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_sqrt_negative_scalar() {
+        let mv = EuclideanMultivector2::from_scalar(-4_f64);
+        let expected = EuclideanMultivector2::unit_e12() * 2_f64;
+        let result = mv.sqrt().unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_sqrt_nilpotent() {
+        let mv = EuclideanMultivector2::new(4_f64, 1_f64, 0_f64, 1_f64);
+        let expected = EuclideanMultivector2::new(2_f64, 0.25_f64, 0_f64, 0.25_f64);
+        let result = mv.sqrt().unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_sqrt_undefined() {
+        let nilpotent = EuclideanMultivector2::new(0_f64, 1_f64, 0_f64, 1_f64);
+        let negative_eigenvalue = EuclideanMultivector2::new(1_f64, 2_f64, 0_f64, 0_f64);
+
+        assert!(nilpotent.sqrt().is_none());
+        assert!(negative_eigenvalue.sqrt().is_none());
+    }
+
+    #[test]
+    fn test_sqrt_rotor_half_angle() {
+        let angle = 5_f64 * f64::consts::FRAC_PI_6;
+        let rotor = (EuclideanMultivector2::unit_e12() * (-angle)).exp();
+        let expected = (EuclideanMultivector2::unit_e12() * (-angle / 2_f64)).exp();
+        let result = rotor.sqrt().unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_sqrt_versor_vector_to_vector() {
+        // The square root of `b * a` rotates the unit vector `a` onto the unit vector `b`.
+        let a = EuclideanMultivector2::new(0_f64, 1_f64, 0_f64, 0_f64);
+        let b = EuclideanMultivector2::new(0_f64, 3_f64 / 5_f64, 4_f64 / 5_f64, 0_f64);
+        let rotor = (b * a).sqrt().unwrap();
+        let result = rotor * a * rotor.reverse();
+
+        assert_relative_eq!(result, b, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_powf_sqrt() {
+        let mv = EuclideanMultivector2::new(2_f64, 1_f64, -1_f64, 3_f64);
+        let expected = mv.sqrt().unwrap();
+        let result = mv.powf(0.5_f64).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    }
+
+    #[test]
+    fn test_powf_integer() {
+        let mv = EuclideanMultivector2::new(2_f64, 1_f64, -1_f64, 0.5_f64);
+        let expected = mv.powi(3).unwrap();
+        let result = mv.powf(3_f64).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    }
+
+    #[test]
+    fn test_powf_undefined() {
+        let zero: EuclideanMultivector2<f64> = EuclideanMultivector2::zero();
+
+        assert!(zero.powf(0.5_f64).is_none());
+    }
+
+    #[test]
+    fn test_powi_zero_exponent() {
+        let mv = EuclideanMultivector2::new(2_f64, 1_f64, -1_f64, 3_f64);
+        let expected = EuclideanMultivector2::unit_scalar();
+        let result = mv.powi(0).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_powi() {
+        let mv = EuclideanMultivector2::new(2_f64, 1_f64, -1_f64, 3_f64);
+        let expected = mv * mv * mv * mv * mv;
+        let result = mv.powi(5).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_powi_negative() {
+        let mv = EuclideanMultivector2::new(2_f64, 1_f64, -1_f64, 3_f64);
+        let expected = EuclideanMultivector2::unit_scalar();
+        let result = mv.powi(-2).unwrap() * mv.powi(2).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    }
+
+    #[test]
+    fn test_powi_negative_not_invertible() {
+        let zero: EuclideanMultivector2<f64> = EuclideanMultivector2::zero();
+
+        assert!(zero.powi(-1).is_none());
+        assert_eq!(zero.powi(2), Some(zero));
+    }
+}
+
+
+#[cfg(test)]
+mod e2ga_versor_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::e2ga::EuclideanMultivector2;
+    use core::f64;
+
+
+    #[test]
+    fn test_apply_even_versor() {
+        let a = EuclideanMultivector2::new(0_f64, 1_f64, 2_f64, 0_f64);
+        let b = EuclideanMultivector2::new(0_f64, -3_f64, 1_f64, 0_f64);
+        let versor = a * b;
+        let mv = EuclideanMultivector2::new(1_f64, 2_f64, 3_f64, 4_f64);
+        let expected = versor * mv * versor.inverse().unwrap();
+        let result = mv.apply_versor(&versor).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    }
+
+    #[test]
+    fn test_apply_odd_versor() {
+        let versor = EuclideanMultivector2::new(0_f64, 1_f64, 2_f64, 0_f64);
+        let mv = EuclideanMultivector2::new(1_f64, 2_f64, 3_f64, 4_f64);
+        let expected = versor * mv.involute() * versor.inverse().unwrap();
+        let result = mv.apply_versor(&versor).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    }
+
+    #[test]
+    fn test_apply_odd_versor_reflects_vector_in_line() {
+        // Reflect in the line orthogonal to the normal vector `e1 + e2`.
+        let normal = EuclideanMultivector2::new(0_f64, 1_f64, 1_f64, 0_f64);
+        let vector = EuclideanMultivector2::new(0_f64, 1_f64, 0_f64, 0_f64);
+        let expected = EuclideanMultivector2::new(0_f64, 0_f64, -1_f64, 0_f64);
+        let result = vector.apply_versor(&normal).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_apply_versor_mixed_parity() {
+        let versor = EuclideanMultivector2::new(1_f64, 0_f64, 1_f64, 0_f64);
+        let mv = EuclideanMultivector2::new(1_f64, 2_f64, 3_f64, 4_f64);
+
+        assert!(mv.apply_versor(&versor).is_none());
+    }
+
+    #[test]
+    fn test_apply_versor_zero() {
+        let versor: EuclideanMultivector2<f64> = EuclideanMultivector2::zero();
+        let mv = EuclideanMultivector2::new(1_f64, 2_f64, 3_f64, 4_f64);
+
+        assert!(mv.apply_versor(&versor).is_none());
+    }
+
+    #[test]
+    fn test_reflect_in_vector() {
+        let a = EuclideanMultivector2::new(0_f64, 3_f64, -1_f64, 0_f64);
+        let mv = EuclideanMultivector2::new(1_f64, 2_f64, 3_f64, 4_f64);
+        let expected = a * mv * a.inverse().unwrap();
+        let result = mv.reflect_in_vector(&a).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    }
+
+    #[test]
+    fn test_reflect_in_vector_twice() {
+        let a = EuclideanMultivector2::new(0_f64, 3_f64, -1_f64, 0_f64);
+        let mv = EuclideanMultivector2::new(1_f64, 2_f64, 3_f64, 4_f64);
+        let result = mv.reflect_in_vector(&a).unwrap().reflect_in_vector(&a).unwrap();
+
+        assert_relative_eq!(result, mv, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    }
+
+    #[test]
+    fn test_reflect_in_zero_vector() {
+        let zero: EuclideanMultivector2<f64> = EuclideanMultivector2::zero();
+        let mv = EuclideanMultivector2::new(1_f64, 2_f64, 3_f64, 4_f64);
+
+        assert!(mv.reflect_in_vector(&zero).is_none());
+    }
+
+    #[test]
+    fn test_reflect_in_plane() {
+        let plane = EuclideanMultivector2::unit_e12() * 3_f64;
+        let mv = EuclideanMultivector2::new(1_f64, 2_f64, 3_f64, 4_f64);
+        let expected = plane * mv.involute() * plane.inverse().unwrap();
+        let result = mv.reflect_in_plane(&plane).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    }
+
+    #[test]
+    fn test_rotate() {
+        let angle = f64::consts::FRAC_PI_3;
+        let rotor = (EuclideanMultivector2::unit_e12() * (-angle / 2_f64)).exp();
+        let mv = EuclideanMultivector2::new(1_f64, 2_f64, 3_f64, 4_f64);
+        let expected = rotor * mv * rotor.reverse();
+        let result = mv.rotate(&rotor);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    }
+
+    #[test]
+    fn test_rotate_vector_angle() {
+        let angle = f64::consts::FRAC_PI_3;
+        let rotor = (EuclideanMultivector2::unit_e12() * (-angle / 2_f64)).exp();
+        let vector = EuclideanMultivector2::new(0_f64, 1_f64, 0_f64, 0_f64);
+        let expected = EuclideanMultivector2::new(0_f64, angle.cos(), angle.sin(), 0_f64);
+        let result = vector.rotate(&rotor);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+
+#[cfg(test)]
+mod e2ga_projection_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::e2ga::EuclideanMultivector2;
+
+
+    #[test]
+    fn test_project_vector_onto_vector() {
+        let line = EuclideanMultivector2::new(0_f64, 1_f64, 1_f64, 0_f64);
+        let vector = EuclideanMultivector2::new(0_f64, 3_f64, 1_f64, 0_f64);
+        let expected = EuclideanMultivector2::new(0_f64, 2_f64, 2_f64, 0_f64);
+        let result = vector.project_onto(&line).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_reject_vector_from_vector() {
+        let line = EuclideanMultivector2::new(0_f64, 1_f64, 1_f64, 0_f64);
+        let vector = EuclideanMultivector2::new(0_f64, 3_f64, 1_f64, 0_f64);
+        let expected = EuclideanMultivector2::new(0_f64, 1_f64, -1_f64, 0_f64);
+        let result = vector.reject_from(&line).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_project_vector_onto_plane() {
+        let plane = EuclideanMultivector2::unit_e12() * 2_f64;
+        let vector = EuclideanMultivector2::new(0_f64, 3_f64, 1_f64, 0_f64);
+        let result = vector.project_onto(&plane).unwrap();
+
+        assert_relative_eq!(result, vector, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_projection_plus_rejection() {
+        let line = EuclideanMultivector2::new(0_f64, -2_f64, 5_f64, 0_f64);
+        let vector = EuclideanMultivector2::new(0_f64, 3_f64, 1_f64, 0_f64);
+        let projection = vector.project_onto(&line).unwrap();
+        let rejection = vector.reject_from(&line).unwrap();
+
+        assert_relative_eq!(projection + rejection, vector, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rejection_orthogonal_to_blade() {
+        let line = EuclideanMultivector2::new(0_f64, -2_f64, 5_f64, 0_f64);
+        let vector = EuclideanMultivector2::new(0_f64, 3_f64, 1_f64, 0_f64);
+        let rejection = vector.reject_from(&line).unwrap();
+        let zero: EuclideanMultivector2<f64> = EuclideanMultivector2::zero();
+
+        assert_relative_eq!(rejection << line, zero, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_project_bivector_onto_plane() {
+        let plane: EuclideanMultivector2<f64> = EuclideanMultivector2::unit_e12();
+        let bivector = EuclideanMultivector2::unit_e12() * 3_f64;
+        let zero: EuclideanMultivector2<f64> = EuclideanMultivector2::zero();
+
+        assert_relative_eq!(plane.project_onto(&plane).unwrap(), plane, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(bivector.project_onto(&plane).unwrap(), bivector, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(bivector.reject_from(&plane).unwrap(), zero, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_project_multivector_onto_plane() {
+        let plane = EuclideanMultivector2::unit_e12() * -2_f64;
+        let mv = EuclideanMultivector2::new(4_f64, 3_f64, -1_f64, 5_f64);
+        let zero: EuclideanMultivector2<f64> = EuclideanMultivector2::zero();
+
+        assert_relative_eq!(mv.project_onto(&plane).unwrap(), mv, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(mv.reject_from(&plane).unwrap(), zero, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_project_onto_zero_blade() {
+        let zero: EuclideanMultivector2<f64> = EuclideanMultivector2::zero();
+        let vector = EuclideanMultivector2::new(0_f64, 3_f64, 1_f64, 0_f64);
+
+        assert!(vector.project_onto(&zero).is_none());
+        assert!(vector.reject_from(&zero).is_none());
+    }
+}
+
+
+#[cfg(test)]
+mod e2ga_rotor_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::e2ga::{
+        EuclideanMultivector2,
+        Rotor2,
+    };
+    use core::f64;
+
+
+    #[test]
+    fn test_identity_rotor_rotate_vector() {
+        let identity: Rotor2<f64> = Rotor2::identity();
+        let vector = EuclideanMultivector2::new(0_f64, 3_f64, -5_f64, 0_f64);
+        let expected = vector;
+        let result = identity.rotate_vector(&vector);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_rotor_from_angle_rotates_e1_towards_e2() {
+        let e1: EuclideanMultivector2<f64> = EuclideanMultivector2::unit_e1();
+        let e2: EuclideanMultivector2<f64> = EuclideanMultivector2::unit_e2();
+        let rotor = Rotor2::from_angle(f64::consts::FRAC_PI_2);
+        let expected = e2;
+        let result = rotor.rotate_vector(&e1);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotor_rotate_vector_sandwich_product() {
+        let rotor = Rotor2::from_angle(1.2_f64);
+        let rotor_mv = EuclideanMultivector2::from(rotor);
+        let vector = EuclideanMultivector2::new(0_f64, 4_f64, -6_f64, 0_f64);
+        let expected = rotor_mv * vector * rotor_mv.reverse();
+        let result = rotor.rotate_vector(&vector);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotor_angle() {
+        for angle in [-3_f64, -1.5_f64, -0.2_f64, 0_f64, 0.7_f64, 2_f64, 3.1_f64] {
+            let rotor = Rotor2::from_angle(angle);
+
+            assert_relative_eq!(rotor.angle(), angle, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_rotor_exp_log() {
+        let bivector = EuclideanMultivector2::new(0_f64, 0_f64, 0_f64, -1.3_f64);
+        let expected = bivector;
+        let result = Rotor2::exp(&bivector).log();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotor_exp_matches_multivector_exp() {
+        let bivector = EuclideanMultivector2::new(0_f64, 0_f64, 0_f64, 0.9_f64);
+        let expected = bivector.exp();
+        let result = EuclideanMultivector2::from(Rotor2::exp(&bivector));
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotor_composition() {
+        let rotor1 = Rotor2::from_angle(0.4_f64);
+        let rotor2 = Rotor2::from_angle(1.1_f64);
+        let expected = Rotor2::from_angle(1.5_f64);
+        let result = rotor2 * rotor1;
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotor_composition_matches_multivector_product() {
+        let rotor1 = Rotor2::from_angle(0.4_f64);
+        let rotor2 = Rotor2::from_angle(-2.3_f64);
+        let expected = EuclideanMultivector2::from(rotor1) * EuclideanMultivector2::from(rotor2);
+        let result = EuclideanMultivector2::from(rotor1 * rotor2);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotor_reverse_is_inverse() {
+        let rotor = Rotor2::from_angle(0.8_f64);
+        let identity: Rotor2<f64> = Rotor2::identity();
+        let result = rotor * rotor.inverse();
+
+        assert_relative_eq!(result, identity, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotor_slerp_endpoints() {
+        let rotor1 = Rotor2::from_angle(0.3_f64);
+        let rotor2 = Rotor2::from_angle(2.1_f64);
+
+        assert_relative_eq!(rotor1.slerp(&rotor2, 0_f64), rotor1, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(rotor1.slerp(&rotor2, 1_f64), rotor2, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotor_slerp_constant_angular_velocity() {
+        let rotor1 = Rotor2::from_angle(0.3_f64);
+        let rotor2 = Rotor2::from_angle(2.1_f64);
+        let expected = Rotor2::from_angle(0.3_f64 + 0.25_f64 * 1.8_f64);
+        let result = rotor1.slerp(&rotor2, 0.25_f64);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotor_slerp_shortest_arc() {
+        let rotor1 = Rotor2::from_angle(3_f64);
+        let rotor2 = Rotor2::from_angle(-3_f64);
+        let result = rotor1.slerp(&rotor2, 0.5_f64);
+
+        assert_relative_eq!(result.angle().abs(), f64::consts::PI, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotor_to_rotation_matrix() {
+        let angle = 0.6_f64;
+        let rotor = Rotor2::from_angle(angle);
+        let expected = [[angle.cos(), angle.sin()], [-angle.sin(), angle.cos()]];
+        let result: [[f64; 2]; 2] = rotor.into();
+
+        for column in 0..2 {
+            for row in 0..2 {
+                assert_relative_eq!(result[column][row], expected[column][row], abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rotor_into_multivector() {
+        let rotor = Rotor2::new(3_i32, -4_i32);
+        let expected = EuclideanMultivector2::new(3_i32, 0_i32, 0_i32, -4_i32);
+        let result = EuclideanMultivector2::from(rotor);
+
+        assert_eq!(result, expected);
+    }
+}
+
+
+#[cfg(test)]
+mod e2ga_rotor_interpolation_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::e2ga::Rotor2;
+    use core::f64;
+
+
+    #[test]
+    fn test_slerp_opposite_hemispheres() {
+        let rotor1 = Rotor2::from_angle(0.3_f64);
+        let rotor2 = Rotor2::from_angle(1.2_f64);
+        let expected = rotor1.slerp(&rotor2, 0.4_f64);
+        let result = rotor1.slerp(&(-rotor2), 0.4_f64);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_nlerp_midpoint() {
+        let rotor1 = Rotor2::from_angle(-0.7_f64);
+        let rotor2 = Rotor2::from_angle(0.7_f64);
+        let expected: Rotor2<f64> = Rotor2::identity();
+        let result = rotor1.nlerp(&rotor2, 0.5_f64);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_nlerp_opposite_hemispheres() {
+        let rotor1 = Rotor2::from_angle(0.3_f64);
+        let rotor2 = Rotor2::from_angle(1.2_f64);
+        let expected = rotor1.nlerp(&rotor2, 0.4_f64);
+        let result = rotor1.nlerp(&(-rotor2), 0.4_f64);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result.magnitude(), 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_squad_control_point_uniform_keyframes() {
+        let previous = Rotor2::from_angle(0.2_f64);
+        let current = Rotor2::from_angle(0.5_f64);
+        let next = Rotor2::from_angle(0.8_f64);
+        let expected = current;
+        let result = Rotor2::squad_control_point(&previous, &current, &next);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_squad_endpoints() {
+        let keyframes = [
+            Rotor2::from_angle(0_f64),
+            Rotor2::from_angle(0.5_f64),
+            Rotor2::from_angle(1.5_f64),
+            Rotor2::from_angle(2_f64),
+        ];
+        let control1 = Rotor2::squad_control_point(&keyframes[0], &keyframes[1], &keyframes[2]);
+        let control2 = Rotor2::squad_control_point(&keyframes[1], &keyframes[2], &keyframes[3]);
+
+        assert_relative_eq!(
+            keyframes[1].squad(&keyframes[2], &control1, &control2, 0_f64),
+            keyframes[1],
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            keyframes[1].squad(&keyframes[2], &control1, &control2, 1_f64),
+            keyframes[2],
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_squad_uniform_keyframes_matches_slerp() {
+        let keyframes = [
+            Rotor2::from_angle(0_f64),
+            Rotor2::from_angle(0.4_f64),
+            Rotor2::from_angle(0.8_f64),
+            Rotor2::from_angle(1.2_f64),
+        ];
+        let control1 = Rotor2::squad_control_point(&keyframes[0], &keyframes[1], &keyframes[2]);
+        let control2 = Rotor2::squad_control_point(&keyframes[1], &keyframes[2], &keyframes[3]);
+        let expected = keyframes[1].slerp(&keyframes[2], 0.3_f64);
+        let result = keyframes[1].squad(&keyframes[2], &control1, &control2, 0.3_f64);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+
+#[cfg(test)]
+mod e2ga_assignment_tests {
+    use cggeomalg::e2ga::EuclideanMultivector2;
+
+
+    #[test]
+    fn test_add_assign() {
+        let mv1 = EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32);
+        let mv2 = EuclideanMultivector2::new(-3_i32, 5_i32, 7_i32, -2_i32);
+        let expected = mv1 + mv2;
+        let mut result = mv1;
+        result += mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_add_assign_ref() {
+        let mv1 = EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32);
+        let mv2 = EuclideanMultivector2::new(-3_i32, 5_i32, 7_i32, -2_i32);
+        let expected = mv1 + mv2;
+        let mut result = mv1;
+        result += &mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_add_assign_scalar() {
+        let mv = EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32);
+        let expected = mv + 3_i32;
+        let mut result = mv;
+        result += 3_i32;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_sub_assign() {
+        let mv1 = EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32);
+        let mv2 = EuclideanMultivector2::new(-3_i32, 5_i32, 7_i32, -2_i32);
+        let expected = mv1 - mv2;
+        let mut result = mv1;
+        result -= mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_sub_assign_ref() {
+        let mv1 = EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32);
+        let mv2 = EuclideanMultivector2::new(-3_i32, 5_i32, 7_i32, -2_i32);
+        let expected = mv1 - mv2;
+        let mut result = mv1;
+        result -= &mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_sub_assign_scalar() {
+        let mv = EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32);
+        let expected = mv - 3_i32;
+        let mut result = mv;
+        result -= 3_i32;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_mul_assign() {
+        let mv1 = EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32);
+        let mv2 = EuclideanMultivector2::new(-3_i32, 5_i32, 7_i32, -2_i32);
+        let expected = mv1 * mv2;
+        let mut result = mv1;
+        result *= mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_mul_assign_ref() {
+        let mv1 = EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32);
+        let mv2 = EuclideanMultivector2::new(-3_i32, 5_i32, 7_i32, -2_i32);
+        let expected = mv1 * mv2;
+        let mut result = mv1;
+        result *= &mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_mul_assign_scalar() {
+        let mv = EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32);
+        let expected = mv * 3_i32;
+        let mut result = mv;
+        result *= 3_i32;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_bitxor_assign() {
+        let mv1 = EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32);
+        let mv2 = EuclideanMultivector2::new(-3_i32, 5_i32, 7_i32, -2_i32);
+        let expected = mv1 ^ mv2;
+        let mut result = mv1;
+        result ^= mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_bitxor_assign_ref() {
+        let mv1 = EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32);
+        let mv2 = EuclideanMultivector2::new(-3_i32, 5_i32, 7_i32, -2_i32);
+        let expected = mv1 ^ mv2;
+        let mut result = mv1;
+        result ^= &mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_bitxor_assign_scalar() {
+        let mv = EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32);
+        let expected = mv ^ 3_i32;
+        let mut result = mv;
+        result ^= 3_i32;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_bitor_assign() {
+        let mv1 = EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32);
+        let mv2 = EuclideanMultivector2::new(-3_i32, 5_i32, 7_i32, -2_i32);
+        let expected = mv1 | mv2;
+        let mut result = mv1;
+        result |= mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_bitor_assign_ref() {
+        let mv1 = EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32);
+        let mv2 = EuclideanMultivector2::new(-3_i32, 5_i32, 7_i32, -2_i32);
+        let expected = mv1 | mv2;
+        let mut result = mv1;
+        result |= &mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_bitor_assign_scalar() {
+        let mv = EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32);
+        let expected = mv | 3_i32;
+        let mut result = mv;
+        result |= 3_i32;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_shl_assign() {
+        let mv1 = EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32);
+        let mv2 = EuclideanMultivector2::new(-3_i32, 5_i32, 7_i32, -2_i32);
+        let expected = mv1 << mv2;
+        let mut result = mv1;
+        result <<= mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_shl_assign_ref() {
+        let mv1 = EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32);
+        let mv2 = EuclideanMultivector2::new(-3_i32, 5_i32, 7_i32, -2_i32);
+        let expected = mv1 << mv2;
+        let mut result = mv1;
+        result <<= &mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_shl_assign_scalar() {
+        let mv = EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32);
+        let expected = mv << 3_i32;
+        let mut result = mv;
+        result <<= 3_i32;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_shr_assign() {
+        let mv1 = EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32);
+        let mv2 = EuclideanMultivector2::new(-3_i32, 5_i32, 7_i32, -2_i32);
+        let expected = mv1 >> mv2;
+        let mut result = mv1;
+        result >>= mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_shr_assign_ref() {
+        let mv1 = EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32);
+        let mv2 = EuclideanMultivector2::new(-3_i32, 5_i32, 7_i32, -2_i32);
+        let expected = mv1 >> mv2;
+        let mut result = mv1;
+        result >>= &mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_shr_assign_scalar() {
+        let mv = EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32);
+        let expected = mv >> 3_i32;
+        let mut result = mv;
+        result >>= 3_i32;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_div_assign() {
+        let mv1 = EuclideanMultivector2::new(1_f64, 2_f64, 3_f64, 4_f64);
+        let mv2 = EuclideanMultivector2::new(-3_f64, 5_f64, 7_f64, -2_f64);
+        let expected = mv1 / mv2;
+        let mut result = mv1;
+        result /= mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_div_assign_ref() {
+        let mv1 = EuclideanMultivector2::new(1_f64, 2_f64, 3_f64, 4_f64);
+        let mv2 = EuclideanMultivector2::new(-3_f64, 5_f64, 7_f64, -2_f64);
+        let expected = mv1 / mv2;
+        let mut result = mv1;
+        result /= &mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_div_assign_scalar() {
+        let mv = EuclideanMultivector2::new(1_f64, 2_f64, 3_f64, 4_f64);
+        let expected = mv / 4_f64;
+        let mut result = mv;
+        result /= 4_f64;
+
+        assert_eq!(result, expected);
+    }
+}
+
+
+#[cfg(test)]
+mod e2ga_iterator_tests {
+    use cggeomalg::e2ga::{
+        BasisBlade,
+        EuclideanMultivector2,
+    };
+
+
+    #[test]
+    fn test_iter() {
+        let mv = EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32);
+        let components: Vec<i32> = mv.iter().copied().collect();
+
+        assert_eq!(components, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut mv = EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32);
+        for component in mv.iter_mut() {
+            *component *= 2;
+        }
+        let expected = EuclideanMultivector2::new(2_i32, 4_i32, 6_i32, 8_i32);
+
+        assert_eq!(mv, expected);
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let mv = EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32);
+        let by_value: Vec<i32> = mv.into_iter().collect();
+        let by_ref: Vec<i32> = (&mv).into_iter().copied().collect();
+
+        assert_eq!(by_value, by_ref);
+        assert_eq!(by_value, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_into_iter_mut() {
+        let mut mv = EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32);
+        for component in &mut mv {
+            *component = -*component;
+        }
+
+        assert_eq!(mv, -EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32));
+    }
+
+    #[test]
+    fn test_iter_blades() {
+        let mv = EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32);
+        let blades: Vec<(BasisBlade, i32)> = mv.iter_blades().collect();
+        let expected = vec![(BasisBlade::Scalar, 1), (BasisBlade::E1, 2), (BasisBlade::E2, 3), (BasisBlade::E12, 4)];
+
+        assert_eq!(blades, expected);
+    }
+
+    #[test]
+    fn test_basis_blades_in_component_order() {
+        assert!(BasisBlade::ALL.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_sum() {
+        let mvs = [EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32), EuclideanMultivector2::new(2_i32, -3_i32, 4_i32, -5_i32), EuclideanMultivector2::new(-1_i32, 2_i32, 0_i32, -2_i32)];
+        let expected = mvs[0] + mvs[1] + mvs[2];
+
+        assert_eq!(mvs.iter().sum::<EuclideanMultivector2<i32>>(), expected);
+        assert_eq!(mvs.into_iter().sum::<EuclideanMultivector2<i32>>(), expected);
+    }
+
+    #[test]
+    fn test_sum_empty() {
+        let mvs: [EuclideanMultivector2<i32>; 0] = [];
+
+        assert_eq!(mvs.iter().sum::<EuclideanMultivector2<i32>>(), EuclideanMultivector2::zero());
+    }
+
+    #[test]
+    fn test_product() {
+        let mvs = [EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32), EuclideanMultivector2::new(2_i32, -3_i32, 4_i32, -5_i32), EuclideanMultivector2::new(-1_i32, 2_i32, 0_i32, -2_i32)];
+        let expected = mvs[0] * mvs[1] * mvs[2];
+
+        assert_eq!(mvs.iter().product::<EuclideanMultivector2<i32>>(), expected);
+        assert_eq!(mvs.into_iter().product::<EuclideanMultivector2<i32>>(), expected);
+    }
+
+    #[test]
+    fn test_product_is_ordered() {
+        let e1: EuclideanMultivector2<i32> = EuclideanMultivector2::unit_e1();
+        let e2: EuclideanMultivector2<i32> = EuclideanMultivector2::unit_e2();
+
+        assert_eq!([e1, e2].iter().product::<EuclideanMultivector2<i32>>(), e1 * e2);
+        assert_eq!([e2, e1].iter().product::<EuclideanMultivector2<i32>>(), e2 * e1);
+        assert_ne!(e1 * e2, e2 * e1);
+    }
+
+    #[test]
+    fn test_product_empty() {
+        let mvs: [EuclideanMultivector2<i32>; 0] = [];
+
+        assert_eq!(mvs.iter().product::<EuclideanMultivector2<i32>>(), EuclideanMultivector2::unit_scalar());
+    }
+}
+
+
+#[cfg(test)]
+mod e2ga_basis_blade_tests {
+    use cggeomalg::e2ga::{
+        BasisBlade,
+        EuclideanMultivector2,
+    };
+
+
+    fn unit_blades() -> [EuclideanMultivector2<i32>; 4] {
+        [
+            EuclideanMultivector2::unit_scalar(),
+            EuclideanMultivector2::unit_e1(),
+            EuclideanMultivector2::unit_e2(),
+            EuclideanMultivector2::unit_e12(),
+        ]
+    }
+
+    #[test]
+    fn test_index() {
+        for (index, blade) in BasisBlade::ALL.into_iter().enumerate() {
+            assert_eq!(blade.index(), index);
+        }
+    }
+
+    #[test]
+    fn test_index_selects_unit_blade() {
+        for (blade, unit_blade) in BasisBlade::ALL.into_iter().zip(unit_blades()) {
+            assert_eq!(unit_blade[blade], 1);
+            assert_eq!(unit_blade.iter().sum::<i32>(), 1);
+        }
+    }
+
+    #[test]
+    fn test_grade() {
+        for (blade, unit_blade) in BasisBlade::ALL.into_iter().zip(unit_blades()) {
+            assert_eq!(unit_blade.grade(blade.grade()), unit_blade);
+        }
+    }
+
+    #[test]
+    fn test_square() {
+        for (blade, unit_blade) in BasisBlade::ALL.into_iter().zip(unit_blades()) {
+            let expected = EuclideanMultivector2::from_scalar(blade.square() as i32);
+
+            assert_eq!(unit_blade * unit_blade, expected);
+        }
+    }
+
+    #[test]
+    fn test_reverse_sign() {
+        for (blade, unit_blade) in BasisBlade::ALL.into_iter().zip(unit_blades()) {
+            assert_eq!(unit_blade.reverse(), unit_blade * blade.reverse_sign() as i32);
+        }
+    }
+
+    #[test]
+    fn test_name() {
+        let names: Vec<&str> = BasisBlade::ALL.into_iter().map(BasisBlade::name).collect();
+
+        assert_eq!(names, vec!["scalar", "e1", "e2", "e12"]);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", BasisBlade::E12), "e12");
+    }
+
+    #[test]
+    fn test_index_mut() {
+        let mut mv: EuclideanMultivector2<i32> = EuclideanMultivector2::zero();
+        mv[BasisBlade::E12] = 5;
+        mv[BasisBlade::Scalar] = 2;
+        let expected = EuclideanMultivector2::unit_e12() * 5 + 2;
+
+        assert_eq!(mv, expected);
+    }
+
+    #[test]
+    fn test_index_matches_view() {
+        let mv = EuclideanMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32);
+
+        assert_eq!(mv[BasisBlade::Scalar], mv.scalar);
+        assert_eq!(mv[BasisBlade::E1], mv.e1);
+        assert_eq!(mv[BasisBlade::E2], mv.e2);
+        assert_eq!(mv[BasisBlade::E12], mv.e12);
+    }
+}
+
+
+#[cfg(test)]
+mod e2ga_grade_mask_tests {
+    use cggeomalg::e2ga::EuclideanMultivector2;
+    use cggeomalg::grade::GradeMask;
+
+
+    #[test]
+    fn test_grades_matches_grade() {
+        let mv: EuclideanMultivector2<i32> = EuclideanMultivector2::new(1, 2, 3, 4);
+
+        for grade in 0..=3 {
+            let mask = GradeMask::from_grade(grade).unwrap();
+
+            assert_eq!(mv.grades(mask), mv.grade(grade));
+        }
+    }
+
+    #[test]
+    fn test_grades_union() {
+        let mv: EuclideanMultivector2<i32> = EuclideanMultivector2::new(1, 2, 3, 4);
+        let expected = mv.grade(0) + mv.grade(1);
+
+        assert_eq!(mv.grades(GradeMask::SCALAR | GradeMask::VECTOR), expected);
+    }
+
+    #[test]
+    fn test_even_odd_parts() {
+        let mv: EuclideanMultivector2<i32> = EuclideanMultivector2::new(1, 2, 3, 4);
+
+        assert_eq!(mv.even_part() + mv.odd_part(), mv);
+        assert_eq!(mv.even_part(), mv.grade(0) + mv.grade(2));
+        assert_eq!(mv.odd_part(), mv.grade(1));
+    }
+
+    #[test]
+    fn test_grade_mask() {
+        let mv: EuclideanMultivector2<f64> = EuclideanMultivector2::unit_e1() * 2_f64 + EuclideanMultivector2::unit_e12();
+
+        assert_eq!(mv.grade_mask(), GradeMask::VECTOR | GradeMask::BIVECTOR);
+    }
+
+    #[test]
+    fn test_grade_mask_ignores_roundoff() {
+        let mv: EuclideanMultivector2<f64> = EuclideanMultivector2::unit_e1() + EuclideanMultivector2::unit_e12() * 1e-20_f64;
+
+        assert_eq!(mv.grade_mask(), GradeMask::VECTOR);
+    }
+
+    #[test]
+    fn test_is_homogeneous() {
+        let e1: EuclideanMultivector2<f64> = EuclideanMultivector2::unit_e1();
+        let e12: EuclideanMultivector2<f64> = EuclideanMultivector2::unit_e12();
+
+        assert!(EuclideanMultivector2::<f64>::zero().is_homogeneous());
+        assert!((e1 + EuclideanMultivector2::unit_e2()).is_homogeneous());
+        assert!(!(e1 + e12).is_homogeneous());
+        assert!(!(e1 + 1_f64).is_homogeneous());
+    }
+
+    #[test]
+    fn test_is_blade() {
+        let e1: EuclideanMultivector2<f64> = EuclideanMultivector2::unit_e1();
+        let e12: EuclideanMultivector2<f64> = EuclideanMultivector2::unit_e12();
+
+        assert!((e1 * 3_f64 - EuclideanMultivector2::unit_e2()).is_blade());
+        assert!(e12.is_blade());
+        assert!(!(e12 + 1_f64).is_blade());
+    }
+
+    #[test]
+    fn test_is_versor_vectors() {
+        let e1: EuclideanMultivector2<f64> = EuclideanMultivector2::unit_e1();
+        let e2: EuclideanMultivector2<f64> = EuclideanMultivector2::unit_e2();
+
+        assert!(e1.is_versor());
+        assert!((e1 * 3_f64 + e2 * 4_f64).is_versor());
+    }
+
+    #[test]
+    fn test_is_versor_rotor() {
+        let e1: EuclideanMultivector2<f64> = EuclideanMultivector2::unit_e1();
+        let e2: EuclideanMultivector2<f64> = EuclideanMultivector2::unit_e2();
+        let rotor = e1 * (e1 + e2).normalize();
+
+        assert!(rotor.is_versor());
+        assert!((rotor * 5_f64).is_versor());
+        assert!((rotor * e1).is_versor());
+    }
+
+    #[test]
+    fn test_is_versor_mixed_parity() {
+        let e1: EuclideanMultivector2<f64> = EuclideanMultivector2::unit_e1();
+        let e12: EuclideanMultivector2<f64> = EuclideanMultivector2::unit_e12();
+
+        assert!(!(e1 + 1_f64).is_versor());
+        assert!(!(e1 + e12).is_versor());
+    }
+
+    #[test]
+    fn test_is_versor_zero() {
+        assert!(!EuclideanMultivector2::<f64>::zero().is_versor());
+    }
+}
+
+
+#[cfg(test)]
+mod e2ga_num_traits_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::e2ga::EuclideanMultivector2;
+    use num_traits::{
+        Inv,
+        MulAdd,
+        One,
+        Pow,
+        Zero,
+    };
+
+
+    fn horner<T>(coefficients: &[T], x: T) -> T
+    where
+        T: Copy + Zero + MulAdd<Output = T>,
+    {
+        coefficients.iter().rev().fold(T::zero(), |acc, &coefficient| acc.mul_add(x, coefficient))
+    }
+
+    #[test]
+    fn test_zero() {
+        let zero: EuclideanMultivector2<f64> = Zero::zero();
+
+        assert_eq!(zero, EuclideanMultivector2::zero());
+        assert!(Zero::is_zero(&zero));
+        assert!(!Zero::is_zero(&EuclideanMultivector2::<f64>::unit_e1()));
+    }
+
+    #[test]
+    fn test_one() {
+        let one: EuclideanMultivector2<f64> = One::one();
+        let mv: EuclideanMultivector2<f64> = EuclideanMultivector2::new(2_f64, 1_f64, -1_f64, 3_f64);
+
+        assert_eq!(one, EuclideanMultivector2::unit_scalar());
+        assert!(one.is_one());
+        assert_eq!(one * mv, mv);
+        assert_eq!(mv * one, mv);
+    }
+
+    #[test]
+    fn test_inv() {
+        let mv: EuclideanMultivector2<f64> = EuclideanMultivector2::new(2_f64, 1_f64, -1_f64, 3_f64);
+        let expected = mv.inverse().unwrap();
+
+        assert_eq!(mv.inv(), expected);
+        assert_eq!((&mv).inv(), expected);
+    }
+
+    #[test]
+    fn test_inv_not_invertible() {
+        let zero: EuclideanMultivector2<f64> = EuclideanMultivector2::zero();
+        let result = zero.inv();
+
+        assert!(result.iter().all(|component| component.is_nan()));
+    }
+
+    #[test]
+    fn test_pow() {
+        let mv: EuclideanMultivector2<f64> = EuclideanMultivector2::new(2_f64, 1_f64, -1_f64, 3_f64);
+
+        assert_eq!(mv.pow(0), EuclideanMultivector2::unit_scalar());
+        assert_eq!(mv.pow(1), mv);
+        assert_eq!(mv.pow(3), mv * mv * mv);
+        assert_eq!((&mv).pow(3), mv * mv * mv);
+    }
+
+    #[test]
+    fn test_pow_unsigned_integer() {
+        let mv: EuclideanMultivector2<i32> = EuclideanMultivector2::new(2_i32, 1_i32, -1_i32, 3_i32);
+
+        assert_eq!(mv.pow(0_u32), EuclideanMultivector2::unit_scalar());
+        assert_eq!(mv.pow(1_u32), mv);
+        assert_eq!(mv.pow(5_u32), mv * mv * mv * mv * mv);
+        assert_eq!((&mv).pow(5_u32), mv * mv * mv * mv * mv);
+    }
+
+    #[test]
+    fn test_pow_unsigned_matches_signed() {
+        let mv: EuclideanMultivector2<f64> = EuclideanMultivector2::new(2_f64, 1_f64, -1_f64, 3_f64);
+
+        for exponent in 0_u32..=6 {
+            assert_eq!(mv.pow(exponent), mv.pow(exponent as i32));
+        }
+    }
+
+    #[test]
+    fn test_pow_negative() {
+        let mv: EuclideanMultivector2<f64> = EuclideanMultivector2::new(2_f64, 1_f64, -1_f64, 3_f64);
+        let expected = mv.powi(-3).unwrap();
+
+        assert_relative_eq!(mv.pow(-3), expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+        assert_relative_eq!((&mv).pow(-3), expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    }
+
+    #[test]
+    fn test_pow_matches_powi() {
+        let mv: EuclideanMultivector2<f64> = EuclideanMultivector2::new(2_f64, 1_f64, -1_f64, 3_f64) * 0.25_f64;
+
+        for exponent in -6..=6 {
+            assert_relative_eq!(
+                mv.pow(exponent),
+                mv.powi(exponent).unwrap(),
+                abs_diff_all <= 1e-10,
+                relative_all <= 1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn test_mul_add() {
+        let mv1: EuclideanMultivector2<f64> = EuclideanMultivector2::new(2_f64, 1_f64, -1_f64, 3_f64);
+        let mv2: EuclideanMultivector2<f64> = EuclideanMultivector2::new(-1_f64, 4_f64, 2_f64, 1_f64);
+        let mv3: EuclideanMultivector2<f64> = EuclideanMultivector2::unit_e12();
+
+        assert_eq!(mv1.mul_add(mv2, mv3), mv1 * mv2 + mv3);
+    }
+
+    #[test]
+    fn test_horner_integer() {
+        let x: EuclideanMultivector2<i32> = EuclideanMultivector2::new(2_i32, 1_i32, -1_i32, 3_i32);
+        let one: EuclideanMultivector2<i32> = EuclideanMultivector2::unit_scalar();
+        let coefficients = [one * 2_i32, one * -3_i32, EuclideanMultivector2::unit_e1(), one];
+        let expected = coefficients[0] + coefficients[1] * x + coefficients[2] * x.pow(2_u32) + x.pow(3_u32);
+
+        assert_eq!(horner(&coefficients, x), expected);
+    }
+
+    #[test]
+    fn test_horner() {
+        let x: EuclideanMultivector2<f64> = EuclideanMultivector2::new(2_f64, 1_f64, -1_f64, 3_f64);
+        let one: EuclideanMultivector2<f64> = EuclideanMultivector2::unit_scalar();
+        let coefficients = [one * 2_f64, one * -3_f64, EuclideanMultivector2::unit_e1(), one];
+        let expected = coefficients[0] + coefficients[1] * x + coefficients[2] * x * x + x * x * x;
+
+        assert_eq!(horner(&coefficients, x), expected);
+    }
+}
@@ -4087,3 +4087,2544 @@ mod e3ga_duality_tests {
         assert_eq!(e31 >> e31_dual, zero);
     }
 }
+
+
+#[cfg(test)]
+mod e3ga_rotor_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::e3ga::{
+        EuclideanMultivector3,
+        Rotor3,
+    };
+    use core::f64;
+
+
+    #[test]
+    fn test_identity_rotor_rotate_vector() {
+        let identity: Rotor3<f64> = Rotor3::identity();
+        let vector = EuclideanMultivector3::new(0_f64, 3_f64, -5_f64, 7_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let expected = vector;
+        let result = identity.rotate_vector(&vector);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_rotor_from_bivector_angle_e12() {
+        let e12: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e12();
+        let e1: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e1();
+        let e2: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e2();
+        let rotor = Rotor3::from_bivector_angle(&e12, f64::consts::FRAC_PI_2);
+        let expected = e2;
+        let result = rotor.rotate_vector(&e1);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotor_from_bivector_angle_e23() {
+        let e23: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e23();
+        let e2: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e2();
+        let e3: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e3();
+        let rotor = Rotor3::from_bivector_angle(&e23, f64::consts::FRAC_PI_2);
+        let expected = e3;
+        let result = rotor.rotate_vector(&e2);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotor_from_bivector_angle_e31() {
+        let e31: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e31();
+        let e3: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e3();
+        let e1: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e1();
+        let rotor = Rotor3::from_bivector_angle(&e31, f64::consts::FRAC_PI_2);
+        let expected = e1;
+        let result = rotor.rotate_vector(&e3);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotor_rotate_vector_sandwich_product() {
+        let bivector = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 1_f64, -2_f64, 3_f64, 0_f64);
+        let rotor = Rotor3::from_bivector_angle(&bivector, 1.2_f64);
+        let rotor_mv = EuclideanMultivector3::from(rotor);
+        let vector = EuclideanMultivector3::new(0_f64, 4_f64, 5_f64, -6_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let expected = rotor_mv * vector * rotor_mv.reverse();
+        let result = rotor.rotate_vector(&vector);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotor_rotate_vector_preserves_magnitude() {
+        let bivector = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 0.3_f64, 0.5_f64, -0.7_f64, 0_f64);
+        let rotor = Rotor3::exp(&bivector);
+        let vector = EuclideanMultivector3::new(0_f64, 4_f64, 5_f64, -6_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let expected = vector.magnitude();
+        let result = rotor.rotate_vector(&vector).magnitude();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotor_exp_is_unit() {
+        let bivector = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 1_f64, 2_f64, 3_f64, 0_f64);
+        let rotor = Rotor3::exp(&bivector);
+
+        assert_relative_eq!(rotor.magnitude(), 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotor_exp_zero_bivector() {
+        let zero: EuclideanMultivector3<f64> = EuclideanMultivector3::zero();
+        let expected: Rotor3<f64> = Rotor3::identity();
+        let result = Rotor3::exp(&zero);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_rotor_exp_ignores_non_bivector_parts() {
+        let mv = EuclideanMultivector3::new(5_f64, 1_f64, 2_f64, 3_f64, 0.1_f64, 0.2_f64, 0.3_f64, 4_f64);
+        let expected = Rotor3::exp(&mv.grade(2));
+        let result = Rotor3::exp(&mv);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_rotor_log_exp() {
+        let bivector = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, -0.4_f64, 1.1_f64, 0.6_f64, 0_f64);
+        let expected = bivector;
+        let result = Rotor3::exp(&bivector).log();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotor_exp_log() {
+        let bivector = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 2_f64, 1_f64, -1_f64, 0_f64);
+        let rotor = Rotor3::from_bivector_angle(&bivector, 2.5_f64);
+        let expected = rotor;
+        let result = Rotor3::exp(&rotor.log());
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotor_log_identity() {
+        let identity: Rotor3<f64> = Rotor3::identity();
+        let expected: EuclideanMultivector3<f64> = EuclideanMultivector3::zero();
+        let result = identity.log();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_rotor_composition() {
+        let e12: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e12();
+        let e23: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e23();
+        let rotor1 = Rotor3::from_bivector_angle(&e12, 0.7_f64);
+        let rotor2 = Rotor3::from_bivector_angle(&e23, -1.3_f64);
+        let vector = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let expected = rotor2.rotate_vector(&rotor1.rotate_vector(&vector));
+        let result = (rotor2 * rotor1).rotate_vector(&vector);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotor_composition_matches_multivector_product() {
+        let rotor1 = Rotor3::exp(&EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 0.1_f64, 0.2_f64, 0.3_f64, 0_f64));
+        let rotor2 = Rotor3::exp(&EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, -0.5_f64, 0.4_f64, 0.9_f64, 0_f64));
+        let expected = EuclideanMultivector3::from(rotor1) * EuclideanMultivector3::from(rotor2);
+        let result = EuclideanMultivector3::from(rotor1 * rotor2);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotor_times_inverse() {
+        let rotor = Rotor3::exp(&EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 0.8_f64, -0.2_f64, 0.3_f64, 0_f64));
+        let expected: Rotor3<f64> = Rotor3::identity();
+        let result = rotor * rotor.inverse();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotor_normalize() {
+        let rotor = Rotor3::new(1_f64, 2_f64, 3_f64, 4_f64);
+        let expected = 1_f64;
+        let result = rotor.normalize().magnitude();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotor_components() {
+        let rotor = Rotor3::new(1_f64, 2_f64, 3_f64, 4_f64);
+
+        assert_eq!(rotor.scalar, rotor[0]);
+        assert_eq!(rotor.e12, rotor[1]);
+        assert_eq!(rotor.e23, rotor[2]);
+        assert_eq!(rotor.e31, rotor[3]);
+    }
+
+    #[test]
+    fn test_rotor_into_multivector() {
+        let rotor = Rotor3::new(1_f64, 2_f64, 3_f64, 4_f64);
+        let expected = EuclideanMultivector3::new(1_f64, 0_f64, 0_f64, 0_f64, 2_f64, 3_f64, 4_f64, 0_f64);
+        let result = EuclideanMultivector3::from(rotor);
+
+        assert_eq!(result, expected);
+    }
+}
+
+
+#[cfg(test)]
+mod e3ga_exp_log_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::e3ga::EuclideanMultivector3;
+    use core::f64;
+
+
+    fn exp_series(mv: &EuclideanMultivector3<f64>) -> EuclideanMultivector3<f64> {
+        let mut term = EuclideanMultivector3::unit_scalar();
+        let mut result = EuclideanMultivector3::unit_scalar();
+        for k in 1..40 {
+            term = (term * mv) / (k as f64);
+            result += term;
+        }
+
+        result
+    }
+
+    #[test]
+    fn test_exp_zero() {
+        let zero: EuclideanMultivector3<f64> = EuclideanMultivector3::zero();
+        let expected = EuclideanMultivector3::unit_scalar();
+        let result = zero.exp();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_exp_scalar() {
+        let mv = EuclideanMultivector3::from_scalar(2_f64);
+        let expected = EuclideanMultivector3::from_scalar(2_f64.exp());
+        let result = mv.exp();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_exp_pseudoscalar() {
+        let angle = f64::consts::FRAC_PI_3;
+        let mv = EuclideanMultivector3::unit_e123() * angle;
+        let expected = EuclideanMultivector3::new(
+            angle.cos(), 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, angle.sin()
+        );
+        let result = mv.exp();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_exp_bivector() {
+        let angle = f64::consts::FRAC_PI_6;
+        let mv = EuclideanMultivector3::unit_e23() * angle;
+        let expected = EuclideanMultivector3::new(
+            angle.cos(), 0_f64, 0_f64, 0_f64, 0_f64, angle.sin(), 0_f64, 0_f64
+        );
+        let result = mv.exp();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_exp_vector() {
+        let mv = EuclideanMultivector3::new(0_f64, 0_f64, 3_f64, 4_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let expected = EuclideanMultivector3::new(
+            5_f64.cosh(), 0_f64, 3_f64 / 5_f64 * 5_f64.sinh(), 4_f64 / 5_f64 * 5_f64.sinh(),
+            0_f64, 0_f64, 0_f64, 0_f64
+        );
+        let result = mv.exp();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_exp_nilpotent() {
+        let mv = EuclideanMultivector3::new(0_f64, 1_f64, 0_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64);
+        let expected = EuclideanMultivector3::new(1_f64, 1_f64, 0_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64);
+        let result = mv.exp();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_exp_series() {
+        let mvs = [
+            EuclideanMultivector3::new(0.5_f64, 1_f64, -1_f64, 0.5_f64, 2_f64, -0.5_f64, 1_f64, 0.25_f64),
+            EuclideanMultivector3::new(-1_f64, 0_f64, 1_f64, 0.5_f64, 0_f64, 1_f64, 0_f64, -1_f64),
+            EuclideanMultivector3::new(0.25_f64, 0_f64, 0_f64, 0_f64, -1_f64, 2_f64, 1_f64, 0_f64),
+        ];
+        for mv in mvs.iter() {
+            let expected = exp_series(mv);
+            let result = mv.exp();
+
+            assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_exp_commuting_sum() {
+        let mv1 = EuclideanMultivector3::new(0.5_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0.75_f64);
+        let mv2 = EuclideanMultivector3::new(0_f64, 1_f64, -0.5_f64, 0.25_f64, 0.5_f64, 1_f64, -1_f64, 0_f64);
+        let expected = mv1.exp() * mv2.exp();
+        let result = (mv1 + mv2).exp();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    }
+
+    #[test]
+    fn test_exp_ln() {
+        let mvs = [
+            EuclideanMultivector3::new(1_f64, 2_f64, -1_f64, 3_f64, -2_f64, 1_f64, 4_f64, -3_f64),
+            EuclideanMultivector3::new(3_f64, 1_f64, 0_f64, 0_f64, 0_f64, 1_f64, 0_f64, 0_f64),
+            EuclideanMultivector3::new(-2_f64, 0_f64, 0_f64, 0_f64, 0.5_f64, 0.5_f64, 0_f64, 0_f64),
+            EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64),
+            EuclideanMultivector3::new(1_f64, 1e-9_f64, 0_f64, 0_f64, 0_f64, 2e-9_f64, 0_f64, 0_f64),
+            EuclideanMultivector3::new(-1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0.01_f64, 0_f64),
+        ];
+        for mv in mvs.iter() {
+            let ln_mv = mv.ln().unwrap();
+            let result = ln_mv.exp();
+
+            assert_relative_eq!(result, mv, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_ln_exp() {
+        let mv = EuclideanMultivector3::new(0.5_f64, 0.25_f64, -0.5_f64, 0.1_f64, 0.3_f64, 0.2_f64, -0.4_f64, 0.5_f64);
+        let expected = mv;
+        let result = mv.exp().ln().unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    }
+
+    #[test]
+    fn test_ln_rotor() {
+        let angle = f64::consts::FRAC_PI_4;
+        let rotor = EuclideanMultivector3::new(
+            angle.cos(), 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, -angle.sin(), 0_f64
+        );
+        let expected = EuclideanMultivector3::unit_e31() * (-angle);
+        let result = rotor.ln().unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_ln_negative_scalar() {
+        let mv = EuclideanMultivector3::from_scalar(-2_f64);
+        let ln_mv = mv.ln().unwrap();
+
+        assert_relative_eq!(ln_mv.exp(), mv, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_ln_zero() {
+        let zero: EuclideanMultivector3<f64> = EuclideanMultivector3::zero();
+
+        assert!(zero.ln().is_none());
+    }
+
+    #[test]
+    fn test_ln_zero_divisor() {
+        let mv = EuclideanMultivector3::new(1_f64, 0_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+
+        assert!(mv.ln().is_none());
+    }
+
+    #[test]
+    fn test_log_synonym() {
+        let mv = EuclideanMultivector3::new(1_f64, 2_f64, -1_f64, 3_f64, -2_f64, 1_f64, 4_f64, -3_f64);
+
+        assert_eq!(mv.log(), mv.ln());
+    }
+
+    #[test]
+    fn test_sinh_cosh() {
+        let mv = EuclideanMultivector3::new(0.5_f64, 1_f64, -1_f64, 0.5_f64, 2_f64, -0.5_f64, 1_f64, 0.25_f64);
+        let exp_mv = mv.exp();
+        let exp_neg_mv = (-mv).exp();
+
+        assert_relative_eq!(mv.sinh(), (exp_mv - exp_neg_mv) * 0.5_f64, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+        assert_relative_eq!(mv.cosh(), (exp_mv + exp_neg_mv) * 0.5_f64, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+        assert_relative_eq!(mv.cosh() + mv.sinh(), exp_mv, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    }
+
+    #[test]
+    fn test_sin_cos_pythagorean_identity() {
+        let mv = EuclideanMultivector3::new(0.5_f64, 1_f64, -1_f64, 0.5_f64, 2_f64, -0.5_f64, 1_f64, 0.25_f64);
+        let sin_mv = mv.sin();
+        let cos_mv = mv.cos();
+        let expected = EuclideanMultivector3::unit_scalar();
+        let result = sin_mv * sin_mv + cos_mv * cos_mv;
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    }
+
+    #[test]
+    fn test_sin_cos_pseudoscalar() {
+        // The pseudoscalar is central and squares to `-1`, so it behaves like the imaginary unit.
+        let mv = EuclideanMultivector3::unit_e123() * 2_f64;
+        let expected_sin = EuclideanMultivector3::unit_e123() * 2_f64.sinh();
+        let expected_cos = EuclideanMultivector3::from_scalar(2_f64.cosh());
+
+        assert_relative_eq!(mv.sin(), expected_sin, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(mv.cos(), expected_cos, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+
+#[cfg(test)]
+mod e3ga_power_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::e3ga::EuclideanMultivector3;
+    use core::f64;
+
+
+    #[test]
+    fn test_sqrt_squared() {
+        let mvs = [
+            EuclideanMultivector3::new(1_f64, 2_f64, -1_f64, 3_f64, -2_f64, 1_f64, 4_f64, -3_f64),
+            EuclideanMultivector3::new(3_f64, 1_f64, 0_f64, 0_f64, 0_f64, 1_f64, 0_f64, 0_f64),
+            EuclideanMultivector3::new(-2_f64, 0_f64, 0_f64, 0_f64, 0.5_f64, 0.5_f64, 0_f64, 0_f64),
+            EuclideanMultivector3::new(-1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 1e-6_f64, 0_f64),
+            EuclideanMultivector3::new(1_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64),
+            EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 2_f64),
+        ];
+        for mv in mvs.iter() {
+            let sqrt_mv = mv.sqrt().unwrap();
+            let result = sqrt_mv * sqrt_mv;
+
+            assert_relative_eq!(result, mv, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_sqrt_zero() {
+        let zero: EuclideanMultivector3<f64> = EuclideanMultivector3::zero();
+
+        assert_eq!(zero.sqrt(), Some(zero));
+    }
+
+    #[test]
+    fn test_sqrt_scalar() {
+        let mv = EuclideanMultivector3::from_scalar(4_f64);
+        let expected = EuclideanMultivector3::from_scalar(2_f64);
+        let result = mv.sqrt().unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_sqrt_nilpotent() {
+        let nilpotent = EuclideanMultivector3::new(0_f64, 1_f64, 0_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64);
+
+        assert!(nilpotent.sqrt().is_none());
+    }
+
+    #[test]
+    fn test_sqrt_rotor_half_angle() {
+        let angle = 5_f64 * f64::consts::FRAC_PI_6;
+        let bivector = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 1_f64, 2_f64, -2_f64, 0_f64) / 3_f64;
+        let rotor = (bivector * (-angle)).exp();
+        let expected = (bivector * (-angle / 2_f64)).exp();
+        let result = rotor.sqrt().unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    }
+
+    #[test]
+    fn test_sqrt_versor_vector_to_vector() {
+        // The square root of `b * a` rotates the unit vector `a` onto the unit vector `b`.
+        let a = EuclideanMultivector3::new(0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let b = EuclideanMultivector3::new(0_f64, 2_f64 / 3_f64, -1_f64 / 3_f64, 2_f64 / 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let rotor = (b * a).sqrt().unwrap();
+        let result = rotor * a * rotor.reverse();
+
+        assert_relative_eq!(result, b, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    }
+
+    #[test]
+    fn test_powf_sqrt() {
+        let mv = EuclideanMultivector3::new(1_f64, 2_f64, -1_f64, 3_f64, -2_f64, 1_f64, 4_f64, -3_f64);
+        let expected = mv.sqrt().unwrap();
+        let result = mv.powf(0.5_f64).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    }
+
+    #[test]
+    fn test_powf_integer() {
+        let mv = EuclideanMultivector3::new(0.5_f64, 0.25_f64, -0.5_f64, 0.1_f64, 0.3_f64, 0.2_f64, -0.4_f64, 0.5_f64);
+        let expected = mv.powi(3).unwrap();
+        let result = mv.powf(3_f64).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    }
+
+    #[test]
+    fn test_powf_rotor_fraction() {
+        let angle = f64::consts::FRAC_PI_2;
+        let bivector = EuclideanMultivector3::unit_e31();
+        let rotor = (bivector * (-angle / 2_f64)).exp();
+        let expected = (bivector * (-angle / 8_f64)).exp();
+        let result = rotor.powf(0.25_f64).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_powf_undefined() {
+        let zero_divisor = EuclideanMultivector3::new(1_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+
+        assert!(zero_divisor.powf(0.5_f64).is_none());
+    }
+
+    #[test]
+    fn test_powi_zero_exponent() {
+        let mv = EuclideanMultivector3::new(1_f64, 2_f64, -1_f64, 3_f64, -2_f64, 1_f64, 4_f64, -3_f64);
+        let expected = EuclideanMultivector3::unit_scalar();
+        let result = mv.powi(0).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_powi() {
+        let mv = EuclideanMultivector3::new(1_f64, 2_f64, -1_f64, 3_f64, -2_f64, 1_f64, 4_f64, -3_f64);
+        let expected = mv * mv * mv * mv * mv;
+        let result = mv.powi(5).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_powi_negative() {
+        let mv = EuclideanMultivector3::new(1_f64, 2_f64, -1_f64, 3_f64, -2_f64, 1_f64, 4_f64, -3_f64);
+        let expected = EuclideanMultivector3::unit_scalar();
+        let result = mv.powi(-2).unwrap() * mv.powi(2).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    }
+
+    #[test]
+    fn test_powi_negative_not_invertible() {
+        let zero: EuclideanMultivector3<f64> = EuclideanMultivector3::zero();
+
+        assert!(zero.powi(-1).is_none());
+        assert_eq!(zero.powi(2), Some(zero));
+    }
+}
+
+
+#[cfg(test)]
+mod e3ga_versor_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::e3ga::EuclideanMultivector3;
+    use core::f64;
+
+
+    #[test]
+    fn test_apply_even_versor() {
+        let a = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, -1_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let b = EuclideanMultivector3::new(0_f64, -3_f64, 1_f64, 2_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let versor = a * b;
+        let mv = EuclideanMultivector3::new(1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64);
+        let expected = versor * mv * versor.inverse().unwrap();
+        let result = mv.apply_versor(&versor).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    }
+
+    #[test]
+    fn test_apply_odd_versor() {
+        let a = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, -1_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let b = EuclideanMultivector3::new(0_f64, -3_f64, 1_f64, 2_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let c = EuclideanMultivector3::new(0_f64, 0_f64, 1_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let versor = a * b * c;
+        let mv = EuclideanMultivector3::new(1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64);
+        let expected = versor * mv.involute() * versor.inverse().unwrap();
+        let result = mv.apply_versor(&versor).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    }
+
+    #[test]
+    fn test_apply_odd_versor_reflects_vector_in_plane() {
+        let normal = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 2_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let vector = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let expected = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, -3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let result = vector.apply_versor(&normal).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_apply_odd_versor_preserves_outer_product() {
+        let versor = EuclideanMultivector3::new(0_f64, 1_f64, -2_f64, 2_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let u = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let v = EuclideanMultivector3::new(0_f64, -1_f64, 0_f64, 4_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let expected = u.apply_versor(&versor).unwrap() ^ v.apply_versor(&versor).unwrap();
+        let result = (u ^ v).apply_versor(&versor).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    }
+
+    #[test]
+    fn test_apply_versor_mixed_parity() {
+        let versor = EuclideanMultivector3::new(1_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let mv = EuclideanMultivector3::new(1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64);
+
+        assert!(mv.apply_versor(&versor).is_none());
+    }
+
+    #[test]
+    fn test_apply_versor_zero() {
+        let versor: EuclideanMultivector3<f64> = EuclideanMultivector3::zero();
+        let mv = EuclideanMultivector3::new(1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64);
+
+        assert!(mv.apply_versor(&versor).is_none());
+    }
+
+    #[test]
+    fn test_reflect_in_vector() {
+        let a = EuclideanMultivector3::new(0_f64, 3_f64, -1_f64, 2_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let mv = EuclideanMultivector3::new(1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64);
+        let expected = a * mv * a.inverse().unwrap();
+        let result = mv.reflect_in_vector(&a).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    }
+
+    #[test]
+    fn test_reflect_in_vector_twice() {
+        let a = EuclideanMultivector3::new(0_f64, 3_f64, -1_f64, 2_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let mv = EuclideanMultivector3::new(1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64);
+        let result = mv.reflect_in_vector(&a).unwrap().reflect_in_vector(&a).unwrap();
+
+        assert_relative_eq!(result, mv, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    }
+
+    #[test]
+    fn test_reflect_in_zero_vector() {
+        let zero: EuclideanMultivector3<f64> = EuclideanMultivector3::zero();
+        let mv = EuclideanMultivector3::new(1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64);
+
+        assert!(mv.reflect_in_vector(&zero).is_none());
+    }
+
+    #[test]
+    fn test_reflect_in_plane() {
+        let plane = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 1_f64, -2_f64, 3_f64, 0_f64);
+        let mv = EuclideanMultivector3::new(1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64);
+        let expected = plane * mv.involute() * plane.inverse().unwrap();
+        let result = mv.reflect_in_plane(&plane).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    }
+
+    #[test]
+    fn test_reflect_in_plane_equals_reflection_by_normal() {
+        let plane = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 1_f64, -2_f64, 3_f64, 0_f64);
+        let normal = -plane.dual();
+        let mv = EuclideanMultivector3::new(1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64);
+        let expected = mv.apply_versor(&normal).unwrap();
+        let result = mv.reflect_in_plane(&plane).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    }
+
+    #[test]
+    fn test_reflect_in_zero_plane() {
+        let zero: EuclideanMultivector3<f64> = EuclideanMultivector3::zero();
+        let mv = EuclideanMultivector3::new(1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64);
+
+        assert!(mv.reflect_in_plane(&zero).is_none());
+    }
+
+    #[test]
+    fn test_rotate() {
+        let bivector = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 1_f64, 2_f64, -2_f64, 0_f64) / 3_f64;
+        let rotor = (bivector * (-f64::consts::FRAC_PI_6)).exp();
+        let mv = EuclideanMultivector3::new(1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64);
+        let expected = rotor * mv * rotor.reverse();
+        let result = mv.rotate(&rotor);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    }
+
+    #[test]
+    fn test_rotate_vector_angle() {
+        let angle = f64::consts::FRAC_PI_3;
+        let rotor = (EuclideanMultivector3::unit_e31() * (-angle / 2_f64)).exp();
+        let vector = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let expected = EuclideanMultivector3::new(0_f64, angle.sin(), 0_f64, angle.cos(), 0_f64, 0_f64, 0_f64, 0_f64);
+        let result = vector.rotate(&rotor);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+
+#[cfg(test)]
+mod e3ga_projection_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::e3ga::EuclideanMultivector3;
+
+
+    #[test]
+    fn test_project_vector_onto_vector() {
+        let line = EuclideanMultivector3::new(0_f64, 1_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let vector = EuclideanMultivector3::new(0_f64, 3_f64, 1_f64, 5_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let expected = EuclideanMultivector3::new(0_f64, 2_f64, 2_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let result = vector.project_onto(&line).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_project_vector_onto_plane() {
+        let plane = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 2_f64, 0_f64, 0_f64);
+        let vector = EuclideanMultivector3::new(0_f64, 3_f64, 1_f64, 5_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let expected = EuclideanMultivector3::new(0_f64, 0_f64, 1_f64, 5_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let result = vector.project_onto(&plane).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_reject_vector_from_plane() {
+        let plane = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 2_f64, 0_f64, 0_f64);
+        let vector = EuclideanMultivector3::new(0_f64, 3_f64, 1_f64, 5_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let expected = EuclideanMultivector3::new(0_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let result = vector.reject_from(&plane).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_project_vector_onto_oblique_plane() {
+        let u = EuclideanMultivector3::new(0_f64, 1_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let v = EuclideanMultivector3::new(0_f64, 0_f64, 1_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let plane = u ^ v;
+        let vector = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 4_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let projection = vector.project_onto(&plane).unwrap();
+        let rejection = vector.reject_from(&plane).unwrap();
+        let zero: EuclideanMultivector3<f64> = EuclideanMultivector3::zero();
+
+        assert_relative_eq!(projection ^ plane, zero, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(rejection << plane, zero, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(projection + rejection, vector, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_project_bivector_onto_itself() {
+        let plane = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 1_f64, -2_f64, 3_f64, 0_f64);
+        let result = plane.project_onto(&plane).unwrap();
+
+        assert_relative_eq!(result, plane, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_project_bivector_onto_vector() {
+        let line = EuclideanMultivector3::new(0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let plane = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 1_f64, -2_f64, 3_f64, 0_f64);
+        let expected: EuclideanMultivector3<f64> = EuclideanMultivector3::zero();
+        let result = plane.project_onto(&line).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_project_vector_onto_pseudoscalar() {
+        let pseudoscalar: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e123();
+        let vector = EuclideanMultivector3::new(0_f64, 3_f64, 1_f64, 5_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let result = vector.project_onto(&pseudoscalar).unwrap();
+
+        assert_relative_eq!(result, vector, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_project_onto_zero_blade() {
+        let zero: EuclideanMultivector3<f64> = EuclideanMultivector3::zero();
+        let vector = EuclideanMultivector3::new(0_f64, 3_f64, 1_f64, 5_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+
+        assert!(vector.project_onto(&zero).is_none());
+        assert!(vector.reject_from(&zero).is_none());
+    }
+}
+
+
+#[cfg(test)]
+mod e3ga_regressive_product_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::e3ga::EuclideanMultivector3;
+
+
+    #[test]
+    fn test_regressive_product_dual_outer_product() {
+        let mv1 = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = EuclideanMultivector3::new(-3_i32, 1_i32, 4_i32, -1_i32, 5_i32, -9_i32, 2_i32, 6_i32);
+        let expected = -(mv1.dual() ^ mv2.dual()).dual();
+        let result = mv1 & mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_regressive_product_references() {
+        let mv1 = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = EuclideanMultivector3::new(-3_i32, 1_i32, 4_i32, -1_i32, 5_i32, -9_i32, 2_i32, 6_i32);
+        let expected = mv1 & mv2;
+
+        assert_eq!(&mv1 & mv2, expected);
+        assert_eq!(mv1 & &mv2, expected);
+        assert_eq!(&mv1 & &mv2, expected);
+        assert_eq!(mv1.regressive_product(&mv2), expected);
+    }
+
+    #[test]
+    fn test_regressive_product_pseudoscalar_unit() {
+        let e123: EuclideanMultivector3<i32> = EuclideanMultivector3::unit_e123();
+        let mv = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+
+        assert_eq!(e123 & mv, mv);
+        assert_eq!(mv & e123, mv);
+    }
+
+    #[test]
+    fn test_regressive_product_planes() {
+        let e12: EuclideanMultivector3<i32> = EuclideanMultivector3::unit_e12();
+        let e23: EuclideanMultivector3<i32> = EuclideanMultivector3::unit_e23();
+        let e31: EuclideanMultivector3<i32> = EuclideanMultivector3::unit_e31();
+
+        assert_eq!(e23 & e31, EuclideanMultivector3::unit_e3() * -1_i32);
+        assert_eq!(e31 & e12, EuclideanMultivector3::unit_e1() * -1_i32);
+        assert_eq!(e12 & e23, EuclideanMultivector3::unit_e2() * -1_i32);
+        assert_eq!(e31 & e23, EuclideanMultivector3::unit_e3());
+        assert_eq!(e12 & e12, EuclideanMultivector3::zero());
+    }
+
+    #[test]
+    fn test_regressive_product_line_plane() {
+        let e1: EuclideanMultivector3<i32> = EuclideanMultivector3::unit_e1();
+        let e12: EuclideanMultivector3<i32> = EuclideanMultivector3::unit_e12();
+        let e23: EuclideanMultivector3<i32> = EuclideanMultivector3::unit_e23();
+
+        assert_eq!(e1 & e23, EuclideanMultivector3::unit_scalar());
+        assert_eq!(e1 & e12, EuclideanMultivector3::zero());
+    }
+
+    #[test]
+    fn test_regressive_product_vectors() {
+        let e1: EuclideanMultivector3<i32> = EuclideanMultivector3::unit_e1();
+        let e2: EuclideanMultivector3<i32> = EuclideanMultivector3::unit_e2();
+
+        assert_eq!(e1 & e2, EuclideanMultivector3::zero());
+    }
+
+    #[test]
+    fn test_meet_planes() {
+        let u = EuclideanMultivector3::new(0_f64, 1_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let v = EuclideanMultivector3::new(0_f64, 0_f64, 1_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let w = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let plane1 = u ^ v;
+        let plane2 = u ^ w;
+        let result = plane1.meet(&plane2).unwrap();
+        let zero: EuclideanMultivector3<f64> = EuclideanMultivector3::zero();
+
+        // The planes intersect in the line spanned by `u`.
+        assert_relative_eq!(result ^ u, zero, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert!(!result.is_zero());
+    }
+
+    #[test]
+    fn test_meet_line_plane() {
+        let line = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let plane: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e12();
+        let expected = EuclideanMultivector3::from_scalar(3_f64);
+        let result = line.meet(&plane).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_meet_parallel_planes() {
+        let plane1 = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 1_f64, -2_f64, 3_f64, 0_f64);
+        let plane2 = plane1 * -4_f64;
+
+        assert!(plane1.meet(&plane2).is_none());
+    }
+
+    #[test]
+    fn test_meet_line_in_plane() {
+        let line = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let plane: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e12();
+
+        assert!(line.meet(&plane).is_none());
+    }
+
+    #[test]
+    fn test_meet_zero_blade() {
+        let zero: EuclideanMultivector3<f64> = EuclideanMultivector3::zero();
+        let plane: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e12();
+
+        assert!(zero.meet(&plane).is_none());
+    }
+
+    #[test]
+    fn test_join_lines() {
+        let u = EuclideanMultivector3::new(0_f64, 1_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let v = EuclideanMultivector3::new(0_f64, 0_f64, 1_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let expected = u ^ v;
+        let result = u.join(&v).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_join_line_plane() {
+        let line: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e3();
+        let plane: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e12();
+        let expected: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e123();
+        let result = plane.join(&line).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_join_coincident_lines() {
+        let u = EuclideanMultivector3::new(0_f64, 1_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+
+        assert!(u.join(&(u * 3_f64)).is_none());
+    }
+
+    #[test]
+    fn test_join_coincident_planes() {
+        let plane: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e23();
+
+        assert!(plane.join(&plane).is_none());
+    }
+}
+
+
+#[cfg(test)]
+mod e3ga_graded_tests {
+    use cggeomalg::e3ga::{
+        Bivector3,
+        EuclideanMultivector3,
+        Even3,
+        Trivector3,
+        Vector3,
+    };
+
+
+    #[test]
+    fn test_vector_into_multivector() {
+        let vector = Vector3::new(1_i32, 2_i32, 3_i32);
+        let expected = EuclideanMultivector3::new(0_i32, 1_i32, 2_i32, 3_i32, 0_i32, 0_i32, 0_i32, 0_i32);
+        let result = EuclideanMultivector3::from(vector);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_bivector_into_multivector() {
+        let bivector = Bivector3::new(4_i32, 5_i32, 6_i32);
+        let expected = EuclideanMultivector3::new(0_i32, 0_i32, 0_i32, 0_i32, 4_i32, 5_i32, 6_i32, 0_i32);
+        let result = EuclideanMultivector3::from(bivector);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_trivector_into_multivector() {
+        let trivector = Trivector3::new(7_i32);
+        let expected = EuclideanMultivector3::new(0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 7_i32);
+        let result = EuclideanMultivector3::from(trivector);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_even_into_multivector() {
+        let even = Even3::new(1_i32, 4_i32, 5_i32, 6_i32);
+        let expected = EuclideanMultivector3::new(1_i32, 0_i32, 0_i32, 0_i32, 4_i32, 5_i32, 6_i32, 0_i32);
+        let result = EuclideanMultivector3::from(even);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_vector_times_vector() {
+        let a = Vector3::new(2_i32, -3_i32, 5_i32);
+        let b = Vector3::new(-7_i32, 11_i32, 13_i32);
+        let expected = EuclideanMultivector3::from(a) * EuclideanMultivector3::from(b);
+        let result: Even3<i32> = a * b;
+
+        assert_eq!(EuclideanMultivector3::from(result), expected);
+    }
+
+    #[test]
+    fn test_vector_times_bivector() {
+        let a = Vector3::new(2_i32, -3_i32, 5_i32);
+        let b = Bivector3::new(-7_i32, 11_i32, 13_i32);
+        let expected = EuclideanMultivector3::from(a) * EuclideanMultivector3::from(b);
+        let result = a * b;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_bivector_times_vector() {
+        let a = Bivector3::new(2_i32, -3_i32, 5_i32);
+        let b = Vector3::new(-7_i32, 11_i32, 13_i32);
+        let expected = EuclideanMultivector3::from(a) * EuclideanMultivector3::from(b);
+        let result = a * b;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_bivector_times_bivector() {
+        let a = Bivector3::new(2_i32, -3_i32, 5_i32);
+        let b = Bivector3::new(-7_i32, 11_i32, 13_i32);
+        let expected = EuclideanMultivector3::from(a) * EuclideanMultivector3::from(b);
+        let result: Even3<i32> = a * b;
+
+        assert_eq!(EuclideanMultivector3::from(result), expected);
+    }
+
+    #[test]
+    fn test_vector_times_trivector() {
+        let a = Vector3::new(2_i32, -3_i32, 5_i32);
+        let b = Trivector3::new(7_i32);
+        let expected = EuclideanMultivector3::from(a) * EuclideanMultivector3::from(b);
+        let result: Bivector3<i32> = a * b;
+
+        assert_eq!(EuclideanMultivector3::from(result), expected);
+        assert_eq!(EuclideanMultivector3::from(b * a), expected);
+    }
+
+    #[test]
+    fn test_bivector_times_trivector() {
+        let a = Bivector3::new(2_i32, -3_i32, 5_i32);
+        let b = Trivector3::new(7_i32);
+        let expected = EuclideanMultivector3::from(a) * EuclideanMultivector3::from(b);
+        let result: Vector3<i32> = a * b;
+
+        assert_eq!(EuclideanMultivector3::from(result), expected);
+        assert_eq!(EuclideanMultivector3::from(b * a), expected);
+    }
+
+    #[test]
+    fn test_trivector_times_trivector() {
+        let a = Trivector3::new(3_i32);
+        let b = Trivector3::new(7_i32);
+        let expected = (EuclideanMultivector3::from(a) * EuclideanMultivector3::from(b))[0];
+        let result: i32 = a * b;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_vector_outer_vector() {
+        let a = Vector3::new(2_i32, -3_i32, 5_i32);
+        let b = Vector3::new(-7_i32, 11_i32, 13_i32);
+        let expected = EuclideanMultivector3::from(a) ^ EuclideanMultivector3::from(b);
+        let result: Bivector3<i32> = a ^ b;
+
+        assert_eq!(EuclideanMultivector3::from(result), expected);
+    }
+
+    #[test]
+    fn test_vector_outer_bivector() {
+        let a = Vector3::new(2_i32, -3_i32, 5_i32);
+        let b = Bivector3::new(-7_i32, 11_i32, 13_i32);
+        let expected = EuclideanMultivector3::from(a) ^ EuclideanMultivector3::from(b);
+        let result: Trivector3<i32> = a ^ b;
+
+        assert_eq!(EuclideanMultivector3::from(result), expected);
+        assert_eq!(EuclideanMultivector3::from(b ^ a), expected);
+    }
+
+    #[test]
+    fn test_vector_scalar_product_vector() {
+        let a = Vector3::new(2_i32, -3_i32, 5_i32);
+        let b = Vector3::new(-7_i32, 11_i32, 13_i32);
+        let expected = (EuclideanMultivector3::from(a) | EuclideanMultivector3::from(b))[0];
+        let result: i32 = a | b;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_graded_dual() {
+        let vector = Vector3::new(2_i32, -3_i32, 5_i32);
+        let bivector = Bivector3::new(-7_i32, 11_i32, 13_i32);
+        let trivector = Trivector3::new(17_i32);
+
+        assert_eq!(EuclideanMultivector3::from(vector.dual()), EuclideanMultivector3::from(vector).dual());
+        assert_eq!(EuclideanMultivector3::from(bivector.dual()), EuclideanMultivector3::from(bivector).dual());
+        assert_eq!(trivector.dual(), EuclideanMultivector3::from(trivector).dual()[0]);
+    }
+
+    #[test]
+    fn test_graded_reverse() {
+        let bivector = Bivector3::new(-7_i32, 11_i32, 13_i32);
+        let trivector = Trivector3::new(17_i32);
+
+        assert_eq!(EuclideanMultivector3::from(bivector.reverse()), EuclideanMultivector3::from(bivector).reverse());
+        assert_eq!(EuclideanMultivector3::from(trivector.reverse()), EuclideanMultivector3::from(trivector).reverse());
+    }
+
+    #[test]
+    fn test_graded_addition_subtraction() {
+        let a = Vector3::new(2_i32, -3_i32, 5_i32);
+        let b = Vector3::new(-7_i32, 11_i32, 13_i32);
+
+        assert_eq!(a + b, Vector3::new(-5_i32, 8_i32, 18_i32));
+        assert_eq!(a - b, Vector3::new(9_i32, -14_i32, -8_i32));
+        assert_eq!(-a, Vector3::new(-2_i32, 3_i32, -5_i32));
+        assert_eq!(a * 2_i32, Vector3::new(4_i32, -6_i32, 10_i32));
+    }
+}
+
+
+#[cfg(test)]
+mod e3ga_even_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::e3ga::{
+        EuclideanMultivector3,
+        Even3,
+        Rotor3,
+    };
+    use core::f64;
+
+
+    #[test]
+    fn test_even_times_even() {
+        let a = Even3::new(2_i32, -3_i32, 5_i32, 7_i32);
+        let b = Even3::new(-11_i32, 13_i32, 17_i32, -19_i32);
+        let expected = EuclideanMultivector3::from(a) * EuclideanMultivector3::from(b);
+        let result = EuclideanMultivector3::from(a * b);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_even_from_multivector() {
+        let mv = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let expected = Even3::new(1_i32, 5_i32, 6_i32, 7_i32);
+        let result = Even3::from_even_part(&mv);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_even_multivector_round_trip() {
+        let even = Even3::new(1_i32, 5_i32, 6_i32, 7_i32);
+        let result = Even3::from_even_part(&EuclideanMultivector3::from(even));
+
+        assert_eq!(result, even);
+    }
+
+    #[test]
+    fn test_even_from_rotor() {
+        let rotor = Rotor3::new(0.5_f64, -0.5_f64, 0.5_f64, 0.5_f64);
+        let expected = Even3::new(0.5_f64, -0.5_f64, 0.5_f64, 0.5_f64);
+
+        assert_eq!(Even3::from(rotor), expected);
+        assert_eq!(Even3::from(&rotor), expected);
+    }
+
+    #[test]
+    fn test_even_rotor_round_trip() {
+        let rotor = Rotor3::new(0.5_f64, -0.5_f64, 0.5_f64, 0.5_f64);
+        let even = Even3::from(rotor);
+
+        assert_eq!(Rotor3::from(even), rotor);
+        assert_eq!(Rotor3::from(&even), rotor);
+    }
+
+    #[test]
+    fn test_even_rotor_product_agrees() {
+        let bivector1 = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 0.3_f64, -0.2_f64, 0.7_f64, 0_f64);
+        let bivector2 = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, -0.5_f64, 0.1_f64, 0.4_f64, 0_f64);
+        let rotor1 = Rotor3::exp(&bivector1);
+        let rotor2 = Rotor3::exp(&bivector2);
+        let expected = Even3::from(rotor1 * rotor2);
+        let result = Even3::from(rotor1) * Even3::from(rotor2);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_even_reverse() {
+        let even = Even3::new(2_i32, -3_i32, 5_i32, 7_i32);
+        let expected = EuclideanMultivector3::from(even).reverse();
+        let result = EuclideanMultivector3::from(even.reverse());
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_even_inverse() {
+        let even = Even3::new(2_f64, -3_f64, 5_f64, 7_f64);
+        let even_inv = even.inverse().unwrap();
+        let one: Even3<f64> = Even3::unit_scalar();
+
+        assert_relative_eq!(even * even_inv, one, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(even_inv * even, one, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_even_inverse_matches_multivector_inverse() {
+        let even = Even3::new(2_f64, -3_f64, 5_f64, 7_f64);
+        let expected = EuclideanMultivector3::from(even).inverse().unwrap();
+        let result = EuclideanMultivector3::from(even.inverse().unwrap());
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_even_zero_not_invertible() {
+        let zero: Even3<f64> = Even3::zero();
+
+        assert!(!zero.is_invertible());
+        assert!(zero.inverse().is_none());
+    }
+
+    #[test]
+    fn test_even_normalize() {
+        let even = Even3::new(2_f64, -3_f64, 5_f64, 7_f64);
+        let result = even.normalize();
+
+        assert_relative_eq!(result.magnitude(), 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_even_product_chain_stays_even() {
+        let bivector = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 0.3_f64, -0.2_f64, 0.7_f64, 0_f64);
+        let step = Even3::from(Rotor3::exp(&bivector));
+        let mut result: Even3<f64> = Even3::unit_scalar();
+        for _ in 0..1000 {
+            result = result * step;
+        }
+        let result_mv = EuclideanMultivector3::from(result);
+
+        assert_eq!(result_mv[1], 0_f64);
+        assert_eq!(result_mv[2], 0_f64);
+        assert_eq!(result_mv[3], 0_f64);
+        assert_eq!(result_mv[7], 0_f64);
+        assert_relative_eq!(result.magnitude(), 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+
+#[cfg(test)]
+mod e3ga_rotor_interpolation_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::e3ga::{
+        EuclideanMultivector3,
+        Rotor3,
+    };
+    use core::f64;
+
+
+    fn bivector() -> EuclideanMultivector3<f64> {
+        EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 1_f64, -2_f64, 3_f64, 0_f64)
+    }
+
+    #[test]
+    fn test_slerp_endpoints() {
+        let rotor1 = Rotor3::from_bivector_angle(&bivector(), 0.3_f64);
+        let rotor2 = Rotor3::from_bivector_angle(&EuclideanMultivector3::unit_e23(), 2.1_f64);
+
+        assert_relative_eq!(rotor1.slerp(&rotor2, 0_f64), rotor1, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(rotor1.slerp(&rotor2, 1_f64), rotor2, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_slerp_constant_angular_velocity() {
+        let rotor1 = Rotor3::from_bivector_angle(&bivector(), 0.3_f64);
+        let rotor2 = Rotor3::from_bivector_angle(&bivector(), 2.1_f64);
+        let expected = Rotor3::from_bivector_angle(&bivector(), 0.3_f64 + 0.25_f64 * 1.8_f64);
+        let result = rotor1.slerp(&rotor2, 0.25_f64);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_slerp_opposite_hemispheres() {
+        let rotor1 = Rotor3::from_bivector_angle(&bivector(), 0.3_f64);
+        let rotor2 = Rotor3::from_bivector_angle(&EuclideanMultivector3::unit_e31(), 1.2_f64);
+        let expected = rotor1.slerp(&rotor2, 0.4_f64);
+        let result = rotor1.slerp(&(-rotor2), 0.4_f64);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_slerp_takes_shortest_arc() {
+        let e12: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e12();
+        let rotor1 = Rotor3::from_bivector_angle(&e12, 3_f64);
+        let rotor2 = Rotor3::from_bivector_angle(&e12, -3_f64);
+        let expected = Rotor3::from_bivector_angle(&e12, f64::consts::PI);
+        let result = rotor1.slerp(&rotor2, 0.5_f64);
+        let e1: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e1();
+
+        assert_relative_eq!(result.rotate_vector(&e1), expected.rotate_vector(&e1), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_nlerp_midpoint() {
+        let rotor1 = Rotor3::from_bivector_angle(&bivector(), -0.7_f64);
+        let rotor2 = Rotor3::from_bivector_angle(&bivector(), 0.7_f64);
+        let expected: Rotor3<f64> = Rotor3::identity();
+        let result = rotor1.nlerp(&rotor2, 0.5_f64);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_nlerp_opposite_hemispheres() {
+        let rotor1 = Rotor3::from_bivector_angle(&bivector(), 0.3_f64);
+        let rotor2 = Rotor3::from_bivector_angle(&EuclideanMultivector3::unit_e31(), 1.2_f64);
+        let expected = rotor1.nlerp(&rotor2, 0.4_f64);
+        let result = rotor1.nlerp(&(-rotor2), 0.4_f64);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result.magnitude(), 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_squad_control_point_uniform_keyframes() {
+        let previous = Rotor3::from_bivector_angle(&bivector(), 0.2_f64);
+        let current = Rotor3::from_bivector_angle(&bivector(), 0.5_f64);
+        let next = Rotor3::from_bivector_angle(&bivector(), 0.8_f64);
+        let expected = current;
+        let result = Rotor3::squad_control_point(&previous, &current, &next);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_squad_endpoints() {
+        let keyframes = [
+            Rotor3::identity(),
+            Rotor3::from_bivector_angle(&EuclideanMultivector3::unit_e12(), 0.5_f64),
+            Rotor3::from_bivector_angle(&EuclideanMultivector3::unit_e23(), 1_f64),
+            Rotor3::from_bivector_angle(&bivector(), 1.5_f64),
+        ];
+        let control1 = Rotor3::squad_control_point(&keyframes[0], &keyframes[1], &keyframes[2]);
+        let control2 = Rotor3::squad_control_point(&keyframes[1], &keyframes[2], &keyframes[3]);
+
+        assert_relative_eq!(
+            keyframes[1].squad(&keyframes[2], &control1, &control2, 0_f64),
+            keyframes[1],
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            keyframes[1].squad(&keyframes[2], &control1, &control2, 1_f64),
+            keyframes[2],
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_squad_uniform_keyframes_matches_slerp() {
+        let keyframes = [
+            Rotor3::from_bivector_angle(&bivector(), 0_f64),
+            Rotor3::from_bivector_angle(&bivector(), 0.4_f64),
+            Rotor3::from_bivector_angle(&bivector(), 0.8_f64),
+            Rotor3::from_bivector_angle(&bivector(), 1.2_f64),
+        ];
+        let control1 = Rotor3::squad_control_point(&keyframes[0], &keyframes[1], &keyframes[2]);
+        let control2 = Rotor3::squad_control_point(&keyframes[1], &keyframes[2], &keyframes[3]);
+        let expected = keyframes[1].slerp(&keyframes[2], 0.3_f64);
+        let result = keyframes[1].squad(&keyframes[2], &control1, &control2, 0.3_f64);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+
+#[cfg(test)]
+mod e3ga_rotor_conversion_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::e3ga::{
+        EuclideanMultivector3,
+        Rotor3,
+    };
+    use core::f64;
+
+    fn vector(x: f64, y: f64, z: f64) -> EuclideanMultivector3<f64> {
+        EuclideanMultivector3::new(0_f64, x, y, z, 0_f64, 0_f64, 0_f64, 0_f64)
+    }
+
+    fn bivector() -> EuclideanMultivector3<f64> {
+        EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 0_f64, 3_f64, -1_f64, 2_f64, 0_f64)
+    }
+
+    #[test]
+    fn test_between_vectors_e1_e2() {
+        let e1 = vector(1_f64, 0_f64, 0_f64);
+        let e2 = vector(0_f64, 1_f64, 0_f64);
+        let expected = Rotor3::from_bivector_angle(&EuclideanMultivector3::unit_e12(), f64::consts::FRAC_PI_2);
+        let result = Rotor3::between_vectors(&e1, &e2);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_between_vectors_rotates_a_onto_b() {
+        let a = vector(1_f64, 2_f64, 3_f64);
+        let b = vector(-4_f64, 0.5_f64, 2_f64);
+        let rotor = Rotor3::between_vectors(&a, &b);
+        let expected = b.normalize();
+        let result = rotor.rotate_vector(&a.normalize());
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(rotor.magnitude(), 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_between_vectors_is_minimal() {
+        let a = vector(1_f64, 2_f64, 3_f64);
+        let b = vector(-4_f64, 0.5_f64, 2_f64);
+        let rotor = Rotor3::between_vectors(&a, &b);
+        let cos_angle = (a.normalize() | b.normalize())[0];
+        let expected = ((1_f64 + cos_angle) / 2_f64).sqrt();
+        let result = rotor[0];
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_between_vectors_parallel() {
+        let a = vector(1_f64, 2_f64, 3_f64);
+        let b = vector(2_f64, 4_f64, 6_f64);
+        let expected = Rotor3::identity();
+        let result = Rotor3::between_vectors(&a, &b);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_between_vectors_antiparallel() {
+        let vectors = [
+            vector(1_f64, 0_f64, 0_f64),
+            vector(0_f64, 1_f64, 0_f64),
+            vector(0_f64, 0_f64, 1_f64),
+            vector(1_f64, 2_f64, 3_f64),
+            vector(-3_f64, 0.25_f64, 1_f64),
+        ];
+        for a in vectors.iter() {
+            let b = -(a * 2_f64);
+            let rotor = Rotor3::between_vectors(a, &b);
+            let expected = b.normalize();
+            let result = rotor.rotate_vector(&a.normalize());
+
+            assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+            assert_relative_eq!(rotor.magnitude(), 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+            assert_relative_eq!(rotor[0], 0_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_between_vectors_antiparallel_deterministic() {
+        let a = vector(1_f64, 2_f64, 3_f64);
+        let b = vector(-1_f64, -2_f64, -3_f64);
+        let result1 = Rotor3::between_vectors(&a, &b);
+        let result2 = Rotor3::between_vectors(&a, &b);
+
+        assert_eq!(result1, result2);
+    }
+
+    #[test]
+    fn test_to_rotation_matrix_columns() {
+        let rotor = Rotor3::from_bivector_angle(&bivector(), 1.2_f64);
+        let matrix = rotor.to_rotation_matrix();
+        let basis = [vector(1_f64, 0_f64, 0_f64), vector(0_f64, 1_f64, 0_f64), vector(0_f64, 0_f64, 1_f64)];
+        for (column, basis_vector) in matrix.iter().zip(basis.iter()) {
+            let expected = rotor.rotate_vector(basis_vector);
+            let result = vector(column[0], column[1], column[2]);
+
+            assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_to_rotation_matrix_identity() {
+        let rotor: Rotor3<f64> = Rotor3::identity();
+        let expected = [[1_f64, 0_f64, 0_f64], [0_f64, 1_f64, 0_f64], [0_f64, 0_f64, 1_f64]];
+        let result = rotor.to_rotation_matrix();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_from_rotation_matrix_round_trip() {
+        let rotor = Rotor3::from_bivector_angle(&bivector(), 1.2_f64);
+        let matrix = rotor.to_rotation_matrix();
+        let result = Rotor3::from_rotation_matrix(&matrix);
+
+        assert_relative_eq!(result, rotor, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_from_rotation_matrix_half_turns() {
+        // Half turns have a zero scalar part and exercise every branch of the conversion.
+        let planes = [
+            EuclideanMultivector3::unit_e12(),
+            EuclideanMultivector3::unit_e23(),
+            EuclideanMultivector3::unit_e31(),
+            bivector(),
+        ];
+        for plane in planes.iter() {
+            let rotor = Rotor3::from_bivector_angle(plane, f64::consts::PI);
+            let matrix = rotor.to_rotation_matrix();
+            let result = Rotor3::from_rotation_matrix(&matrix);
+            let result_matrix = result.to_rotation_matrix();
+
+            assert_relative_eq!(result_matrix, matrix, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+            assert_relative_eq!(result.magnitude(), 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_from_rotation_matrix_matches_rotate_vector() {
+        // A rotation by 90 degrees in the e23 plane takes e2 to e3 and e3 to -e2.
+        let matrix = [[1_f64, 0_f64, 0_f64], [0_f64, 0_f64, 1_f64], [0_f64, -1_f64, 0_f64]];
+        let rotor = Rotor3::from_rotation_matrix(&matrix);
+        let expected = vector(0_f64, 0_f64, 1_f64);
+        let result = rotor.rotate_vector(&vector(0_f64, 1_f64, 0_f64));
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+
+#[cfg(test)]
+mod e3ga_orientation_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::e3ga::{
+        EuclideanMultivector3,
+        EulerFrame,
+        EulerSequence,
+        Rotor3,
+    };
+    use core::f64;
+
+    const SEQUENCES: [EulerSequence; 12] = [
+        EulerSequence::XYZ,
+        EulerSequence::XZY,
+        EulerSequence::YXZ,
+        EulerSequence::YZX,
+        EulerSequence::ZXY,
+        EulerSequence::ZYX,
+        EulerSequence::XYX,
+        EulerSequence::XZX,
+        EulerSequence::YXY,
+        EulerSequence::YZY,
+        EulerSequence::ZXZ,
+        EulerSequence::ZYZ,
+    ];
+
+    const FRAMES: [EulerFrame; 2] = [EulerFrame::Intrinsic, EulerFrame::Extrinsic];
+
+    fn vector(x: f64, y: f64, z: f64) -> EuclideanMultivector3<f64> {
+        EuclideanMultivector3::new(0_f64, x, y, z, 0_f64, 0_f64, 0_f64, 0_f64)
+    }
+
+    fn reversed(sequence: EulerSequence) -> EulerSequence {
+        match sequence {
+            EulerSequence::XYZ => EulerSequence::ZYX,
+            EulerSequence::XZY => EulerSequence::YZX,
+            EulerSequence::YXZ => EulerSequence::ZXY,
+            EulerSequence::YZX => EulerSequence::XZY,
+            EulerSequence::ZXY => EulerSequence::YXZ,
+            EulerSequence::ZYX => EulerSequence::XYZ,
+            proper => proper,
+        }
+    }
+
+    /// Two rotors describe the same rotation when they agree up to sign.
+    fn assert_same_rotation(result: &EuclideanMultivector3<f64>, expected: &EuclideanMultivector3<f64>) {
+        let expected = if (result | expected)[0] < 0_f64 { -expected } else { *expected };
+
+        assert_relative_eq!(*result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_from_axis_angle_right_hand_rule() {
+        let angle = f64::consts::FRAC_PI_2;
+        let cases = [
+            (
+                vector(1_f64, 0_f64, 0_f64),
+                vector(0_f64, 1_f64, 0_f64),
+                vector(0_f64, 0_f64, 1_f64),
+            ),
+            (
+                vector(0_f64, 1_f64, 0_f64),
+                vector(0_f64, 0_f64, 1_f64),
+                vector(1_f64, 0_f64, 0_f64),
+            ),
+            (
+                vector(0_f64, 0_f64, 1_f64),
+                vector(1_f64, 0_f64, 0_f64),
+                vector(0_f64, 1_f64, 0_f64),
+            ),
+        ];
+        for (axis, vector, expected) in cases.iter() {
+            let rotor = EuclideanMultivector3::from_axis_angle(axis, angle);
+            let result = vector.rotate(&rotor);
+
+            assert_relative_eq!(result, *expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_from_axis_angle_uses_dual_plane() {
+        let axis = vector(1_f64, -2_f64, 2_f64);
+        let angle = 1.3_f64;
+        let expected = EuclideanMultivector3::from(Rotor3::from_bivector_angle(&(-axis.dual()), angle));
+        let result = EuclideanMultivector3::from_axis_angle(&axis, angle);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_from_axis_angle_fixes_axis() {
+        let axis = vector(1_f64, 2_f64, 3_f64);
+        let rotor = EuclideanMultivector3::from_axis_angle(&axis, 2.5_f64);
+        let result = axis.rotate(&rotor);
+
+        assert_relative_eq!(result, axis, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_to_axis_angle_round_trip() {
+        let axis = vector(-3_f64, 1_f64, 0.5_f64);
+        for angle in [0.25_f64, 1_f64, 2_f64, 3_f64] {
+            let rotor = EuclideanMultivector3::from_axis_angle(&axis, angle);
+            let (result_axis, result_angle) = rotor.to_axis_angle();
+
+            assert_relative_eq!(result_axis, axis.normalize(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+            assert_relative_eq!(result_angle, angle, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_to_axis_angle_negative_scalar() {
+        let axis = vector(-3_f64, 1_f64, 0.5_f64);
+        let rotor = -EuclideanMultivector3::from_axis_angle(&axis, 1_f64);
+        let (result_axis, result_angle) = rotor.to_axis_angle();
+
+        assert_relative_eq!(result_axis, axis.normalize(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result_angle, 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_to_axis_angle_identity() {
+        let rotor: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_scalar();
+        let (result_axis, result_angle) = rotor.to_axis_angle();
+
+        assert_eq!(result_axis, EuclideanMultivector3::unit_e3());
+        assert_eq!(result_angle, 0_f64);
+    }
+
+    #[test]
+    fn test_from_euler_single_axis() {
+        let e1: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e1();
+        let expected = EuclideanMultivector3::from_axis_angle(&e1, 0.7_f64);
+        let result = EuclideanMultivector3::from_euler(&[0.7_f64, 0_f64, 0_f64], EulerSequence::XYZ, EulerFrame::Intrinsic);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_from_euler_intrinsic() {
+        let e1: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e1();
+        let e2: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e2();
+        let e3: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e3();
+        let angles = [0.4_f64, -1.1_f64, 2.3_f64];
+        let expected = EuclideanMultivector3::from_axis_angle(&e3, angles[0])
+            * EuclideanMultivector3::from_axis_angle(&e1, angles[1])
+            * EuclideanMultivector3::from_axis_angle(&e2, angles[2]);
+        let result = EuclideanMultivector3::from_euler(&angles, EulerSequence::ZXY, EulerFrame::Intrinsic);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_from_euler_intrinsic_is_reversed_extrinsic() {
+        let angles = [0.4_f64, -1.1_f64, 2.3_f64];
+        for sequence in SEQUENCES {
+            let expected = EuclideanMultivector3::from_euler(&[angles[2], angles[1], angles[0]], reversed(sequence), EulerFrame::Extrinsic);
+            let result = EuclideanMultivector3::from_euler(&angles, sequence, EulerFrame::Intrinsic);
+
+            assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_to_euler_round_trip_angles() {
+        let tait_bryan_angles = [0.4_f64, -1.1_f64, 2.3_f64];
+        let proper_angles = [-2.9_f64, 0.6_f64, 1.7_f64];
+        for sequence in SEQUENCES {
+            let angles = if sequence.is_proper() { proper_angles } else { tait_bryan_angles };
+            for frame in FRAMES {
+                let rotor = EuclideanMultivector3::from_euler(&angles, sequence, frame);
+                let result = rotor.to_euler(sequence, frame);
+
+                assert_relative_eq!(result, angles, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn test_to_euler_round_trip_rotors() {
+        let rotors = [
+            EuclideanMultivector3::from_axis_angle(&vector(1_f64, 2_f64, 3_f64), 0.3_f64),
+            EuclideanMultivector3::from_axis_angle(&vector(-1_f64, 0.5_f64, 2_f64), 2_f64),
+            EuclideanMultivector3::from_axis_angle(&vector(0_f64, -1_f64, 1_f64), 3.1_f64),
+            -EuclideanMultivector3::from_axis_angle(&vector(4_f64, 1_f64, -2_f64), 1.2_f64),
+        ];
+        for rotor in rotors.iter() {
+            for sequence in SEQUENCES {
+                for frame in FRAMES {
+                    let angles = rotor.to_euler(sequence, frame);
+                    let result = EuclideanMultivector3::from_euler(&angles, sequence, frame);
+
+                    assert_same_rotation(&result, rotor);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_to_euler_ranges() {
+        let rotor = EuclideanMultivector3::from_axis_angle(&vector(-1_f64, 0.5_f64, 2_f64), 2.5_f64);
+        for sequence in SEQUENCES {
+            for frame in FRAMES {
+                let angles = rotor.to_euler(sequence, frame);
+                let (min_angle2, max_angle2) = if sequence.is_proper() {
+                    (0_f64, f64::consts::PI)
+                } else {
+                    (-f64::consts::FRAC_PI_2, f64::consts::FRAC_PI_2)
+                };
+
+                assert!(angles[0].abs() <= f64::consts::PI);
+                assert!(angles[1] >= min_angle2 && angles[1] <= max_angle2);
+                assert!(angles[2].abs() <= f64::consts::PI);
+            }
+        }
+    }
+
+    #[test]
+    fn test_to_euler_gimbal_lock_tait_bryan() {
+        for sequence in SEQUENCES.iter().filter(|sequence| !sequence.is_proper()) {
+            for frame in FRAMES {
+                for angle2 in [f64::consts::FRAC_PI_2, -f64::consts::FRAC_PI_2] {
+                    let rotor = EuclideanMultivector3::from_euler(&[0.3_f64, angle2, 0.5_f64], *sequence, frame);
+                    let angles = rotor.to_euler(*sequence, frame);
+                    let result = EuclideanMultivector3::from_euler(&angles, *sequence, frame);
+
+                    assert_relative_eq!(angles[1], angle2, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+                    assert_eq!(angles[2], 0_f64);
+                    assert_same_rotation(&result, &rotor);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_to_euler_gimbal_lock_proper() {
+        for sequence in SEQUENCES.iter().filter(|sequence| sequence.is_proper()) {
+            for frame in FRAMES {
+                for angle2 in [0_f64, f64::consts::PI] {
+                    let rotor = EuclideanMultivector3::from_euler(&[0.3_f64, angle2, 0.5_f64], *sequence, frame);
+                    let angles = rotor.to_euler(*sequence, frame);
+                    let result = EuclideanMultivector3::from_euler(&angles, *sequence, frame);
+
+                    assert_relative_eq!(angles[1], angle2, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+                    assert_eq!(angles[2], 0_f64);
+                    assert_same_rotation(&result, &rotor);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_to_euler_gimbal_lock_deterministic() {
+        let rotor1 = EuclideanMultivector3::from_euler(
+            &[0.3_f64, f64::consts::FRAC_PI_2, 0.5_f64],
+            EulerSequence::XYZ,
+            EulerFrame::Intrinsic,
+        );
+        let rotor2 = EuclideanMultivector3::from_euler(
+            &[0.6_f64, f64::consts::FRAC_PI_2, 0.2_f64],
+            EulerSequence::XYZ,
+            EulerFrame::Intrinsic,
+        );
+        let result1 = rotor1.to_euler(EulerSequence::XYZ, EulerFrame::Intrinsic);
+        let result2 = rotor2.to_euler(EulerSequence::XYZ, EulerFrame::Intrinsic);
+
+        assert_relative_eq!(result1, result2, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+
+#[cfg(test)]
+mod e3ga_assignment_tests {
+    use cggeomalg::e3ga::EuclideanMultivector3;
+
+
+    #[test]
+    fn test_add_assign() {
+        let mv1 = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = EuclideanMultivector3::new(-3_i32, 5_i32, 7_i32, -2_i32, 11_i32, 1_i32, -6_i32, 4_i32);
+        let expected = mv1 + mv2;
+        let mut result = mv1;
+        result += mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_add_assign_ref() {
+        let mv1 = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = EuclideanMultivector3::new(-3_i32, 5_i32, 7_i32, -2_i32, 11_i32, 1_i32, -6_i32, 4_i32);
+        let expected = mv1 + mv2;
+        let mut result = mv1;
+        result += &mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_add_assign_scalar() {
+        let mv = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let expected = mv + 3_i32;
+        let mut result = mv;
+        result += 3_i32;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_sub_assign() {
+        let mv1 = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = EuclideanMultivector3::new(-3_i32, 5_i32, 7_i32, -2_i32, 11_i32, 1_i32, -6_i32, 4_i32);
+        let expected = mv1 - mv2;
+        let mut result = mv1;
+        result -= mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_sub_assign_ref() {
+        let mv1 = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = EuclideanMultivector3::new(-3_i32, 5_i32, 7_i32, -2_i32, 11_i32, 1_i32, -6_i32, 4_i32);
+        let expected = mv1 - mv2;
+        let mut result = mv1;
+        result -= &mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_sub_assign_scalar() {
+        let mv = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let expected = mv - 3_i32;
+        let mut result = mv;
+        result -= 3_i32;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_mul_assign() {
+        let mv1 = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = EuclideanMultivector3::new(-3_i32, 5_i32, 7_i32, -2_i32, 11_i32, 1_i32, -6_i32, 4_i32);
+        let expected = mv1 * mv2;
+        let mut result = mv1;
+        result *= mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_mul_assign_ref() {
+        let mv1 = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = EuclideanMultivector3::new(-3_i32, 5_i32, 7_i32, -2_i32, 11_i32, 1_i32, -6_i32, 4_i32);
+        let expected = mv1 * mv2;
+        let mut result = mv1;
+        result *= &mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_mul_assign_scalar() {
+        let mv = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let expected = mv * 3_i32;
+        let mut result = mv;
+        result *= 3_i32;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_bitxor_assign() {
+        let mv1 = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = EuclideanMultivector3::new(-3_i32, 5_i32, 7_i32, -2_i32, 11_i32, 1_i32, -6_i32, 4_i32);
+        let expected = mv1 ^ mv2;
+        let mut result = mv1;
+        result ^= mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_bitxor_assign_ref() {
+        let mv1 = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = EuclideanMultivector3::new(-3_i32, 5_i32, 7_i32, -2_i32, 11_i32, 1_i32, -6_i32, 4_i32);
+        let expected = mv1 ^ mv2;
+        let mut result = mv1;
+        result ^= &mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_bitxor_assign_scalar() {
+        let mv = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let expected = mv ^ 3_i32;
+        let mut result = mv;
+        result ^= 3_i32;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_bitor_assign() {
+        let mv1 = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = EuclideanMultivector3::new(-3_i32, 5_i32, 7_i32, -2_i32, 11_i32, 1_i32, -6_i32, 4_i32);
+        let expected = mv1 | mv2;
+        let mut result = mv1;
+        result |= mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_bitor_assign_ref() {
+        let mv1 = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = EuclideanMultivector3::new(-3_i32, 5_i32, 7_i32, -2_i32, 11_i32, 1_i32, -6_i32, 4_i32);
+        let expected = mv1 | mv2;
+        let mut result = mv1;
+        result |= &mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_bitor_assign_scalar() {
+        let mv = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let expected = mv | 3_i32;
+        let mut result = mv;
+        result |= 3_i32;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_bitand_assign() {
+        let mv1 = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = EuclideanMultivector3::new(-3_i32, 5_i32, 7_i32, -2_i32, 11_i32, 1_i32, -6_i32, 4_i32);
+        let expected = mv1 & mv2;
+        let mut result = mv1;
+        result &= mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_bitand_assign_ref() {
+        let mv1 = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = EuclideanMultivector3::new(-3_i32, 5_i32, 7_i32, -2_i32, 11_i32, 1_i32, -6_i32, 4_i32);
+        let expected = mv1 & mv2;
+        let mut result = mv1;
+        result &= &mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_shl_assign() {
+        let mv1 = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = EuclideanMultivector3::new(-3_i32, 5_i32, 7_i32, -2_i32, 11_i32, 1_i32, -6_i32, 4_i32);
+        let expected = mv1 << mv2;
+        let mut result = mv1;
+        result <<= mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_shl_assign_ref() {
+        let mv1 = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = EuclideanMultivector3::new(-3_i32, 5_i32, 7_i32, -2_i32, 11_i32, 1_i32, -6_i32, 4_i32);
+        let expected = mv1 << mv2;
+        let mut result = mv1;
+        result <<= &mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_shl_assign_scalar() {
+        let mv = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let expected = mv << 3_i32;
+        let mut result = mv;
+        result <<= 3_i32;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_shr_assign() {
+        let mv1 = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = EuclideanMultivector3::new(-3_i32, 5_i32, 7_i32, -2_i32, 11_i32, 1_i32, -6_i32, 4_i32);
+        let expected = mv1 >> mv2;
+        let mut result = mv1;
+        result >>= mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_shr_assign_ref() {
+        let mv1 = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = EuclideanMultivector3::new(-3_i32, 5_i32, 7_i32, -2_i32, 11_i32, 1_i32, -6_i32, 4_i32);
+        let expected = mv1 >> mv2;
+        let mut result = mv1;
+        result >>= &mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_shr_assign_scalar() {
+        let mv = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let expected = mv >> 3_i32;
+        let mut result = mv;
+        result >>= 3_i32;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_div_assign() {
+        let mv1 = EuclideanMultivector3::new(1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64);
+        let mv2 = EuclideanMultivector3::new(-3_f64, 5_f64, 7_f64, -2_f64, 11_f64, 1_f64, -6_f64, 4_f64);
+        let expected = mv1 / mv2;
+        let mut result = mv1;
+        result /= mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_div_assign_ref() {
+        let mv1 = EuclideanMultivector3::new(1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64);
+        let mv2 = EuclideanMultivector3::new(-3_f64, 5_f64, 7_f64, -2_f64, 11_f64, 1_f64, -6_f64, 4_f64);
+        let expected = mv1 / mv2;
+        let mut result = mv1;
+        result /= &mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_div_assign_scalar() {
+        let mv = EuclideanMultivector3::new(1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64);
+        let expected = mv / 4_f64;
+        let mut result = mv;
+        result /= 4_f64;
+
+        assert_eq!(result, expected);
+    }
+}
+
+
+#[cfg(test)]
+mod e3ga_iterator_tests {
+    use cggeomalg::e3ga::{
+        BasisBlade,
+        EuclideanMultivector3,
+    };
+
+
+    #[test]
+    fn test_iter() {
+        let mv = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let components: Vec<i32> = mv.iter().copied().collect();
+
+        assert_eq!(components, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut mv = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        for component in mv.iter_mut() {
+            *component *= 2;
+        }
+        let expected = EuclideanMultivector3::new(2_i32, 4_i32, 6_i32, 8_i32, 10_i32, 12_i32, 14_i32, 16_i32);
+
+        assert_eq!(mv, expected);
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let mv = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let by_value: Vec<i32> = mv.into_iter().collect();
+        let by_ref: Vec<i32> = (&mv).into_iter().copied().collect();
+
+        assert_eq!(by_value, by_ref);
+        assert_eq!(by_value, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn test_into_iter_mut() {
+        let mut mv = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        for component in &mut mv {
+            *component = -*component;
+        }
+
+        assert_eq!(mv, -EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32));
+    }
+
+    #[test]
+    fn test_iter_blades() {
+        let mv = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let blades: Vec<(BasisBlade, i32)> = mv.iter_blades().collect();
+        let expected = vec![(BasisBlade::Scalar, 1), (BasisBlade::E1, 2), (BasisBlade::E2, 3), (BasisBlade::E3, 4), (BasisBlade::E12, 5), (BasisBlade::E23, 6), (BasisBlade::E31, 7), (BasisBlade::E123, 8)];
+
+        assert_eq!(blades, expected);
+    }
+
+    #[test]
+    fn test_basis_blades_in_component_order() {
+        assert!(BasisBlade::ALL.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_sum() {
+        let mvs = [EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32), EuclideanMultivector3::new(2_i32, -3_i32, 4_i32, -5_i32, 6_i32, -7_i32, 8_i32, -9_i32), EuclideanMultivector3::new(-1_i32, 2_i32, 0_i32, -2_i32, 1_i32, -1_i32, 2_i32, 0_i32)];
+        let expected = mvs[0] + mvs[1] + mvs[2];
+
+        assert_eq!(mvs.iter().sum::<EuclideanMultivector3<i32>>(), expected);
+        assert_eq!(mvs.into_iter().sum::<EuclideanMultivector3<i32>>(), expected);
+    }
+
+    #[test]
+    fn test_sum_empty() {
+        let mvs: [EuclideanMultivector3<i32>; 0] = [];
+
+        assert_eq!(mvs.iter().sum::<EuclideanMultivector3<i32>>(), EuclideanMultivector3::zero());
+    }
+
+    #[test]
+    fn test_product() {
+        let mvs = [EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32), EuclideanMultivector3::new(2_i32, -3_i32, 4_i32, -5_i32, 6_i32, -7_i32, 8_i32, -9_i32), EuclideanMultivector3::new(-1_i32, 2_i32, 0_i32, -2_i32, 1_i32, -1_i32, 2_i32, 0_i32)];
+        let expected = mvs[0] * mvs[1] * mvs[2];
+
+        assert_eq!(mvs.iter().product::<EuclideanMultivector3<i32>>(), expected);
+        assert_eq!(mvs.into_iter().product::<EuclideanMultivector3<i32>>(), expected);
+    }
+
+    #[test]
+    fn test_product_is_ordered() {
+        let e1: EuclideanMultivector3<i32> = EuclideanMultivector3::unit_e1();
+        let e2: EuclideanMultivector3<i32> = EuclideanMultivector3::unit_e2();
+
+        assert_eq!([e1, e2].iter().product::<EuclideanMultivector3<i32>>(), e1 * e2);
+        assert_eq!([e2, e1].iter().product::<EuclideanMultivector3<i32>>(), e2 * e1);
+        assert_ne!(e1 * e2, e2 * e1);
+    }
+
+    #[test]
+    fn test_product_empty() {
+        let mvs: [EuclideanMultivector3<i32>; 0] = [];
+
+        assert_eq!(mvs.iter().product::<EuclideanMultivector3<i32>>(), EuclideanMultivector3::unit_scalar());
+    }
+}
+
+
+#[cfg(test)]
+mod e3ga_basis_blade_tests {
+    use cggeomalg::e3ga::{
+        BasisBlade,
+        EuclideanMultivector3,
+    };
+
+
+    fn unit_blades() -> [EuclideanMultivector3<i32>; 8] {
+        [
+            EuclideanMultivector3::unit_scalar(),
+            EuclideanMultivector3::unit_e1(),
+            EuclideanMultivector3::unit_e2(),
+            EuclideanMultivector3::unit_e3(),
+            EuclideanMultivector3::unit_e12(),
+            EuclideanMultivector3::unit_e23(),
+            EuclideanMultivector3::unit_e31(),
+            EuclideanMultivector3::unit_e123(),
+        ]
+    }
+
+    #[test]
+    fn test_index() {
+        for (index, blade) in BasisBlade::ALL.into_iter().enumerate() {
+            assert_eq!(blade.index(), index);
+        }
+    }
+
+    #[test]
+    fn test_index_selects_unit_blade() {
+        for (blade, unit_blade) in BasisBlade::ALL.into_iter().zip(unit_blades()) {
+            assert_eq!(unit_blade[blade], 1);
+            assert_eq!(unit_blade.iter().sum::<i32>(), 1);
+        }
+    }
+
+    #[test]
+    fn test_grade() {
+        for (blade, unit_blade) in BasisBlade::ALL.into_iter().zip(unit_blades()) {
+            assert_eq!(unit_blade.grade(blade.grade()), unit_blade);
+        }
+    }
+
+    #[test]
+    fn test_square() {
+        for (blade, unit_blade) in BasisBlade::ALL.into_iter().zip(unit_blades()) {
+            let expected = EuclideanMultivector3::from_scalar(blade.square() as i32);
+
+            assert_eq!(unit_blade * unit_blade, expected);
+        }
+    }
+
+    #[test]
+    fn test_reverse_sign() {
+        for (blade, unit_blade) in BasisBlade::ALL.into_iter().zip(unit_blades()) {
+            assert_eq!(unit_blade.reverse(), unit_blade * blade.reverse_sign() as i32);
+        }
+    }
+
+    #[test]
+    fn test_name() {
+        let names: Vec<&str> = BasisBlade::ALL.into_iter().map(BasisBlade::name).collect();
+
+        assert_eq!(names, vec!["scalar", "e1", "e2", "e3", "e12", "e23", "e31", "e123"]);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", BasisBlade::E123), "e123");
+    }
+
+    #[test]
+    fn test_index_mut() {
+        let mut mv: EuclideanMultivector3<i32> = EuclideanMultivector3::zero();
+        mv[BasisBlade::E123] = 5;
+        mv[BasisBlade::Scalar] = 2;
+        let expected = EuclideanMultivector3::unit_e123() * 5 + 2;
+
+        assert_eq!(mv, expected);
+    }
+
+    #[test]
+    fn test_index_matches_view() {
+        let mv = EuclideanMultivector3::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+
+        assert_eq!(mv[BasisBlade::Scalar], mv.scalar);
+        assert_eq!(mv[BasisBlade::E1], mv.e1);
+        assert_eq!(mv[BasisBlade::E2], mv.e2);
+        assert_eq!(mv[BasisBlade::E3], mv.e3);
+        assert_eq!(mv[BasisBlade::E12], mv.e12);
+        assert_eq!(mv[BasisBlade::E23], mv.e23);
+        assert_eq!(mv[BasisBlade::E31], mv.e31);
+        assert_eq!(mv[BasisBlade::E123], mv.e123);
+    }
+}
+
+
+#[cfg(test)]
+mod e3ga_grade_mask_tests {
+    use cggeomalg::e3ga::EuclideanMultivector3;
+    use cggeomalg::grade::GradeMask;
+
+
+    #[test]
+    fn test_grades_matches_grade() {
+        let mv: EuclideanMultivector3<i32> = EuclideanMultivector3::new(1, 2, 3, 4, 5, 6, 7, 8);
+
+        for grade in 0..=3 {
+            let mask = GradeMask::from_grade(grade).unwrap();
+
+            assert_eq!(mv.grades(mask), mv.grade(grade));
+        }
+    }
+
+    #[test]
+    fn test_grades_union() {
+        let mv: EuclideanMultivector3<i32> = EuclideanMultivector3::new(1, 2, 3, 4, 5, 6, 7, 8);
+        let expected = mv.grade(0) + mv.grade(1);
+
+        assert_eq!(mv.grades(GradeMask::SCALAR | GradeMask::VECTOR), expected);
+    }
+
+    #[test]
+    fn test_even_odd_parts() {
+        let mv: EuclideanMultivector3<i32> = EuclideanMultivector3::new(1, 2, 3, 4, 5, 6, 7, 8);
+
+        assert_eq!(mv.even_part() + mv.odd_part(), mv);
+        assert_eq!(mv.even_part(), mv.grade(0) + mv.grade(2));
+        assert_eq!(mv.odd_part(), mv.grade(1) + mv.grade(3));
+    }
+
+    #[test]
+    fn test_grade_mask() {
+        let mv: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e1() * 2_f64 + EuclideanMultivector3::unit_e12();
+
+        assert_eq!(mv.grade_mask(), GradeMask::VECTOR | GradeMask::BIVECTOR);
+    }
+
+    #[test]
+    fn test_grade_mask_ignores_roundoff() {
+        let mv: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e1() + EuclideanMultivector3::unit_e12() * 1e-20_f64;
+
+        assert_eq!(mv.grade_mask(), GradeMask::VECTOR);
+    }
+
+    #[test]
+    fn test_is_homogeneous() {
+        let e1: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e1();
+        let e12: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e12();
+
+        assert!(EuclideanMultivector3::<f64>::zero().is_homogeneous());
+        assert!((e1 + EuclideanMultivector3::unit_e3()).is_homogeneous());
+        assert!(!(e1 + e12).is_homogeneous());
+        assert!(!(e1 + 1_f64).is_homogeneous());
+    }
+
+    #[test]
+    fn test_is_blade() {
+        let e1: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e1();
+        let e12: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e12();
+
+        assert!((e1 * 3_f64 - EuclideanMultivector3::unit_e3()).is_blade());
+        assert!(e12.is_blade());
+        assert!(!(e12 + 1_f64).is_blade());
+    }
+
+    #[test]
+    fn test_is_versor_vectors() {
+        let e1: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e1();
+        let e2: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e2();
+
+        assert!(e1.is_versor());
+        assert!((e1 * 3_f64 + e2 * 4_f64).is_versor());
+    }
+
+    #[test]
+    fn test_is_versor_rotor() {
+        let e1: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e1();
+        let e2: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e2();
+        let rotor = e1 * (e1 + e2).normalize();
+
+        assert!(rotor.is_versor());
+        assert!((rotor * 5_f64).is_versor());
+        assert!((rotor * e1).is_versor());
+    }
+
+    #[test]
+    fn test_is_versor_mixed_parity() {
+        let e1: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e1();
+        let e12: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e12();
+
+        assert!(!(e1 + 1_f64).is_versor());
+        assert!(!(e1 + e12).is_versor());
+    }
+
+    #[test]
+    fn test_is_versor_zero() {
+        assert!(!EuclideanMultivector3::<f64>::zero().is_versor());
+    }
+}
+
+
+#[cfg(test)]
+mod e3ga_num_traits_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::e3ga::EuclideanMultivector3;
+    use num_traits::{
+        Inv,
+        MulAdd,
+        One,
+        Pow,
+        Zero,
+    };
+
+
+    fn horner<T>(coefficients: &[T], x: T) -> T
+    where
+        T: Copy + Zero + MulAdd<Output = T>,
+    {
+        coefficients.iter().rev().fold(T::zero(), |acc, &coefficient| acc.mul_add(x, coefficient))
+    }
+
+    #[test]
+    fn test_zero() {
+        let zero: EuclideanMultivector3<f64> = Zero::zero();
+
+        assert_eq!(zero, EuclideanMultivector3::zero());
+        assert!(Zero::is_zero(&zero));
+        assert!(!Zero::is_zero(&EuclideanMultivector3::<f64>::unit_e1()));
+    }
+
+    #[test]
+    fn test_one() {
+        let one: EuclideanMultivector3<f64> = One::one();
+        let mv: EuclideanMultivector3<f64> = EuclideanMultivector3::new(1_f64, 2_f64, -1_f64, 3_f64, -2_f64, 1_f64, 4_f64, -3_f64);
+
+        assert_eq!(one, EuclideanMultivector3::unit_scalar());
+        assert!(one.is_one());
+        assert_eq!(one * mv, mv);
+        assert_eq!(mv * one, mv);
+    }
+
+    #[test]
+    fn test_inv() {
+        let mv: EuclideanMultivector3<f64> = EuclideanMultivector3::new(1_f64, 2_f64, -1_f64, 3_f64, -2_f64, 1_f64, 4_f64, -3_f64);
+        let expected = mv.inverse().unwrap();
+
+        assert_eq!(mv.inv(), expected);
+        assert_eq!((&mv).inv(), expected);
+    }
+
+    #[test]
+    fn test_inv_not_invertible() {
+        let zero: EuclideanMultivector3<f64> = EuclideanMultivector3::zero();
+        let result = zero.inv();
+
+        assert!(result.iter().all(|component| component.is_nan()));
+    }
+
+    #[test]
+    fn test_pow() {
+        let mv: EuclideanMultivector3<f64> = EuclideanMultivector3::new(1_f64, 2_f64, -1_f64, 3_f64, -2_f64, 1_f64, 4_f64, -3_f64);
+
+        assert_eq!(mv.pow(0), EuclideanMultivector3::unit_scalar());
+        assert_eq!(mv.pow(1), mv);
+        assert_eq!(mv.pow(3), mv * mv * mv);
+        assert_eq!((&mv).pow(3), mv * mv * mv);
+    }
+
+    #[test]
+    fn test_pow_unsigned_integer() {
+        let mv: EuclideanMultivector3<i32> = EuclideanMultivector3::new(1_i32, 2_i32, -1_i32, 3_i32, -2_i32, 1_i32, 4_i32, -3_i32);
+
+        assert_eq!(mv.pow(0_u32), EuclideanMultivector3::unit_scalar());
+        assert_eq!(mv.pow(1_u32), mv);
+        assert_eq!(mv.pow(5_u32), mv * mv * mv * mv * mv);
+        assert_eq!((&mv).pow(5_u32), mv * mv * mv * mv * mv);
+    }
+
+    #[test]
+    fn test_pow_unsigned_matches_signed() {
+        let mv: EuclideanMultivector3<f64> = EuclideanMultivector3::new(1_f64, 2_f64, -1_f64, 3_f64, -2_f64, 1_f64, 4_f64, -3_f64);
+
+        for exponent in 0_u32..=6 {
+            assert_eq!(mv.pow(exponent), mv.pow(exponent as i32));
+        }
+    }
+
+    #[test]
+    fn test_pow_negative() {
+        let mv: EuclideanMultivector3<f64> = EuclideanMultivector3::new(1_f64, 2_f64, -1_f64, 3_f64, -2_f64, 1_f64, 4_f64, -3_f64);
+        let expected = mv.powi(-3).unwrap();
+
+        assert_relative_eq!(mv.pow(-3), expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+        assert_relative_eq!((&mv).pow(-3), expected, abs_diff_all <= 1e-10, relative_all <= 1e-12);
+    }
+
+    #[test]
+    fn test_pow_matches_powi() {
+        let mv: EuclideanMultivector3<f64> = EuclideanMultivector3::new(1_f64, 2_f64, -1_f64, 3_f64, -2_f64, 1_f64, 4_f64, -3_f64) * 0.25_f64;
+
+        for exponent in -6..=6 {
+            assert_relative_eq!(
+                mv.pow(exponent),
+                mv.powi(exponent).unwrap(),
+                abs_diff_all <= 1e-10,
+                relative_all <= 1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn test_mul_add() {
+        let mv1: EuclideanMultivector3<f64> = EuclideanMultivector3::new(1_f64, 2_f64, -1_f64, 3_f64, -2_f64, 1_f64, 4_f64, -3_f64);
+        let mv2: EuclideanMultivector3<f64> = EuclideanMultivector3::new(-1_f64, 4_f64, 2_f64, 1_f64, 3_f64, -2_f64, 1_f64, 5_f64);
+        let mv3: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e12();
+
+        assert_eq!(mv1.mul_add(mv2, mv3), mv1 * mv2 + mv3);
+    }
+
+    #[test]
+    fn test_horner_integer() {
+        let x: EuclideanMultivector3<i32> = EuclideanMultivector3::new(1_i32, 2_i32, -1_i32, 3_i32, -2_i32, 1_i32, 4_i32, -3_i32);
+        let one: EuclideanMultivector3<i32> = EuclideanMultivector3::unit_scalar();
+        let coefficients = [one * 2_i32, one * -3_i32, EuclideanMultivector3::unit_e1(), one];
+        let expected = coefficients[0] + coefficients[1] * x + coefficients[2] * x.pow(2_u32) + x.pow(3_u32);
+
+        assert_eq!(horner(&coefficients, x), expected);
+    }
+
+    #[test]
+    fn test_horner() {
+        let x: EuclideanMultivector3<f64> = EuclideanMultivector3::new(1_f64, 2_f64, -1_f64, 3_f64, -2_f64, 1_f64, 4_f64, -3_f64);
+        let one: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_scalar();
+        let coefficients = [one * 2_f64, one * -3_f64, EuclideanMultivector3::unit_e1(), one];
+        let expected = coefficients[0] + coefficients[1] * x + coefficients[2] * x * x + x * x * x;
+
+        assert_eq!(horner(&coefficients, x), expected);
+    }
+}
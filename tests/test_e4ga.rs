@@ -0,0 +1,1139 @@
+#[cfg(test)]
+mod e4ga_component_tests {
+    use cggeomalg::e4ga::EuclideanMultivector4;
+
+
+    #[test]
+    fn test_components1() {
+        let mv = EuclideanMultivector4::new(
+            1_isize, 2_isize, 3_isize, 4_isize, 5_isize, 6_isize, 7_isize, 8_isize, 9_isize, 10_isize, 11_isize, 12_isize, 13_isize,
+            14_isize, 15_isize, 16_isize,
+        );
+
+        assert_eq!(mv[0], 1);
+        assert_eq!(mv[1], 2);
+        assert_eq!(mv[2], 3);
+        assert_eq!(mv[3], 4);
+        assert_eq!(mv[4], 5);
+        assert_eq!(mv[5], 6);
+        assert_eq!(mv[6], 7);
+        assert_eq!(mv[7], 8);
+        assert_eq!(mv[8], 9);
+        assert_eq!(mv[9], 10);
+        assert_eq!(mv[10], 11);
+        assert_eq!(mv[11], 12);
+        assert_eq!(mv[12], 13);
+        assert_eq!(mv[13], 14);
+        assert_eq!(mv[14], 15);
+        assert_eq!(mv[15], 16);
+    }
+
+    #[test]
+    fn test_components2() {
+        let mv = EuclideanMultivector4::new(
+            1_isize, 2_isize, 3_isize, 4_isize, 5_isize, 6_isize, 7_isize, 8_isize, 9_isize, 10_isize, 11_isize, 12_isize, 13_isize,
+            14_isize, 15_isize, 16_isize,
+        );
+
+        assert_eq!(mv.scalar, mv[0]);
+        assert_eq!(mv.e1, mv[1]);
+        assert_eq!(mv.e2, mv[2]);
+        assert_eq!(mv.e3, mv[3]);
+        assert_eq!(mv.e4, mv[4]);
+        assert_eq!(mv.e12, mv[5]);
+        assert_eq!(mv.e13, mv[6]);
+        assert_eq!(mv.e14, mv[7]);
+        assert_eq!(mv.e23, mv[8]);
+        assert_eq!(mv.e24, mv[9]);
+        assert_eq!(mv.e34, mv[10]);
+        assert_eq!(mv.e123, mv[11]);
+        assert_eq!(mv.e124, mv[12]);
+        assert_eq!(mv.e134, mv[13]);
+        assert_eq!(mv.e234, mv[14]);
+        assert_eq!(mv.e1234, mv[15]);
+    }
+
+    #[test]
+    fn test_as_ref() {
+        let mv = EuclideanMultivector4::new(
+            1_isize, 2_isize, 3_isize, 4_isize, 5_isize, 6_isize, 7_isize, 8_isize, 9_isize, 10_isize, 11_isize, 12_isize, 13_isize,
+            14_isize, 15_isize, 16_isize,
+        );
+        let v_ref: &[isize; 16] = mv.as_ref();
+
+        assert_eq!(v_ref, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+    }
+
+    #[test]
+    fn test_as_mut() {
+        let mut mv = EuclideanMultivector4::new(
+            1_isize, 2_isize, 3_isize, 4_isize, 5_isize, 6_isize, 7_isize, 8_isize, 9_isize, 10_isize, 11_isize, 12_isize, 13_isize,
+            14_isize, 15_isize, 16_isize,
+        );
+        let v_ref: &mut [isize; 16] = mv.as_mut();
+
+        assert_eq!(v_ref, &mut [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_out_of_bounds_array_access() {
+        let mv = EuclideanMultivector4::new(
+            1_isize, 2_isize, 3_isize, 4_isize, 5_isize, 6_isize, 7_isize, 8_isize, 9_isize, 10_isize, 11_isize, 12_isize, 13_isize,
+            14_isize, 15_isize, 16_isize,
+        );
+
+        assert_eq!(mv[16], mv[16]);
+    }
+}
+
+
+#[cfg(test)]
+mod e4ga_addition_subtraction_tests {
+    use cggeomalg::e4ga::EuclideanMultivector4;
+
+
+    #[test]
+    fn test_multivector_addition() {
+        let mv1 = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = EuclideanMultivector4::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = EuclideanMultivector4::new(
+            4_i32, 1_i32, 7_i32, 5_i32, 0_i32, 15_i32, 9_i32, 2_i32, 14_i32, 13_i32, 6_i32, 20_i32, 22_i32, 7_i32, 24_i32, 19_i32,
+        );
+        let result = mv1 + mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_multivector_subtraction() {
+        let mv1 = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = EuclideanMultivector4::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = EuclideanMultivector4::new(
+            -2_i32, 3_i32, -1_i32, 3_i32, 10_i32, -3_i32, 5_i32, 14_i32, 4_i32, 7_i32, 16_i32, 4_i32, 4_i32, 21_i32, 6_i32, 13_i32,
+        );
+        let result = mv1 - mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_multivector_minus_multivector_is_zero() {
+        let mv = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let zero: EuclideanMultivector4<i32> = EuclideanMultivector4::zero();
+
+        assert_eq!(mv - mv, zero);
+    }
+
+    #[test]
+    fn test_scalar_minus_multivector() {
+        let mv = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let expected = EuclideanMultivector4::new(
+            4_i32, -2_i32, -3_i32, -4_i32, -5_i32, -6_i32, -7_i32, -8_i32, -9_i32, -10_i32, -11_i32, -12_i32, -13_i32, -14_i32, -15_i32,
+            -16_i32,
+        );
+        let result = 5_i32 - mv;
+
+        assert_eq!(result, expected);
+        assert_eq!(5_i32 - mv, EuclideanMultivector4::from_scalar(5_i32) - mv);
+    }
+
+    #[test]
+    fn test_scalar_plus_multivector() {
+        let mv = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+
+        assert_eq!(5_i32 + mv, mv + 5_i32);
+        assert_eq!(5_i32 + mv, EuclideanMultivector4::from_scalar(5_i32) + mv);
+    }
+}
+
+
+#[cfg(test)]
+mod e4ga_multiplication_division_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::e4ga::EuclideanMultivector4;
+
+
+    #[test]
+    fn test_scalar_multiplication() {
+        let mv = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let expected = EuclideanMultivector4::new(
+            3_i32, 6_i32, 9_i32, 12_i32, 15_i32, 18_i32, 21_i32, 24_i32, 27_i32, 30_i32, 33_i32, 36_i32, 39_i32, 42_i32, 45_i32, 48_i32,
+        );
+
+        assert_eq!(mv * 3_i32, expected);
+        assert_eq!(3_i32 * mv, expected);
+    }
+
+    #[test]
+    fn test_scalar_division() {
+        let mv = EuclideanMultivector4::new(
+            1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64, 9_f64, 10_f64, 11_f64, 12_f64, 13_f64, 14_f64, 15_f64, 16_f64,
+        );
+        let expected = mv * (1_f64 / 9_f64);
+        let result = mv / 9_f64;
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_geometric_product_euclidean_basis_squares() {
+        let one: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_scalar();
+
+        assert_eq!(EuclideanMultivector4::unit_e1() * EuclideanMultivector4::unit_e1(), one);
+        assert_eq!(EuclideanMultivector4::unit_e2() * EuclideanMultivector4::unit_e2(), one);
+        assert_eq!(EuclideanMultivector4::unit_e3() * EuclideanMultivector4::unit_e3(), one);
+        assert_eq!(EuclideanMultivector4::unit_e4() * EuclideanMultivector4::unit_e4(), one);
+    }
+
+    #[test]
+    fn test_geometric_product_anticommuting_basis_vectors() {
+        let e1: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e1();
+        let e4: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e4();
+        let e14: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e14();
+
+        assert_eq!(e1 * e4, e14);
+        assert_eq!(e4 * e1, -e14);
+    }
+
+    #[test]
+    fn test_geometric_product_bivectors_square_to_minus_one() {
+        let one: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_scalar();
+
+        assert_eq!(EuclideanMultivector4::unit_e12() * EuclideanMultivector4::unit_e12(), -one);
+        assert_eq!(EuclideanMultivector4::unit_e13() * EuclideanMultivector4::unit_e13(), -one);
+        assert_eq!(EuclideanMultivector4::unit_e14() * EuclideanMultivector4::unit_e14(), -one);
+        assert_eq!(EuclideanMultivector4::unit_e23() * EuclideanMultivector4::unit_e23(), -one);
+        assert_eq!(EuclideanMultivector4::unit_e24() * EuclideanMultivector4::unit_e24(), -one);
+        assert_eq!(EuclideanMultivector4::unit_e34() * EuclideanMultivector4::unit_e34(), -one);
+    }
+
+    #[test]
+    fn test_geometric_product_pseudoscalar_squares_to_one() {
+        let e1234: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e1234();
+        let one: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_scalar();
+
+        assert_eq!(e1234 * e1234, one);
+    }
+
+    #[test]
+    fn test_geometric_product_orthogonal_bivectors_commute() {
+        let e12: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e12();
+        let e34: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e34();
+        let e1234: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e1234();
+
+        assert_eq!(e12 * e34, e1234);
+        assert_eq!(e34 * e12, e1234);
+    }
+
+    #[test]
+    fn test_geometric_product_pseudoscalar_commutes_with_even_elements() {
+        let mv = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let even = mv.grade(0) + mv.grade(2) + mv.grade(4);
+        let odd = mv.grade(1) + mv.grade(3);
+        let e1234: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e1234();
+
+        assert_eq!(e1234 * even, even * e1234);
+        assert_eq!(e1234 * odd, -(odd * e1234));
+    }
+
+    #[test]
+    fn test_geometric_product() {
+        let mv1 = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = EuclideanMultivector4::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = EuclideanMultivector4::new(
+            -250_i32, -222_i32, -143_i32, -293_i32, -270_i32, -223_i32, -165_i32, -276_i32, 416_i32, -183_i32, -23_i32, 396_i32, -273_i32,
+            7_i32, 132_i32, 98_i32,
+        );
+        let result = mv1 * mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_geometric_product_references() {
+        let mv1 = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = EuclideanMultivector4::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = mv1 * mv2;
+
+        assert_eq!(&mv1 * mv2, expected);
+        assert_eq!(mv1 * &mv2, expected);
+        assert_eq!(&mv1 * &mv2, expected);
+    }
+
+    #[test]
+    fn test_geometric_product_associative() {
+        let mv1 = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = EuclideanMultivector4::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let mv3 = EuclideanMultivector4::new(
+            2_i32, 0_i32, -1_i32, 3_i32, 1_i32, -2_i32, 4_i32, 0_i32, 1_i32, -3_i32, 2_i32, 5_i32, -1_i32, 0_i32, 2_i32, -4_i32,
+        );
+
+        assert_eq!((mv1 * mv2) * mv3, mv1 * (mv2 * mv3));
+    }
+}
+
+
+#[cfg(test)]
+mod e4ga_outer_product_tests {
+    use cggeomalg::e4ga::EuclideanMultivector4;
+
+
+    #[test]
+    fn test_outer_product_e1_e2() {
+        let e1: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e1();
+        let e2: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e2();
+        let e12: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e12();
+
+        assert_eq!(e1 ^ e2, e12);
+        assert_eq!(e2 ^ e1, -e12);
+    }
+
+    #[test]
+    fn test_outer_product_vector_with_itself_is_zero() {
+        let e3: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e3();
+        let zero: EuclideanMultivector4<i32> = EuclideanMultivector4::zero();
+
+        assert_eq!(e3 ^ e3, zero);
+    }
+
+    #[test]
+    fn test_outer_product_vectors_pseudoscalar() {
+        let e1: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e1();
+        let e2: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e2();
+        let e3: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e3();
+        let e4: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e4();
+        let e1234: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e1234();
+
+        assert_eq!(e1 ^ e2 ^ e3 ^ e4, e1234);
+        assert_eq!(e2 ^ e1 ^ e3 ^ e4, -e1234);
+    }
+
+    #[test]
+    fn test_outer_product_non_simple_bivector() {
+        let e12: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e12();
+        let e34: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e34();
+        let bivector = e12 + e34;
+        let expected = EuclideanMultivector4::unit_e1234() * 2_i32;
+
+        assert_eq!(bivector ^ bivector, expected);
+    }
+
+    #[test]
+    fn test_outer_product() {
+        let mv1 = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = EuclideanMultivector4::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = EuclideanMultivector4::new(
+            3_i32, 5_i32, 13_i32, 13_i32, 10_i32, 38_i32, 29_i32, 13_i32, 19_i32, -2_i32, 3_i32, 53_i32, 45_i32, 5_i32, 41_i32, 98_i32,
+        );
+        let result = mv1 ^ mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_outer_product_references() {
+        let mv1 = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = EuclideanMultivector4::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = mv1 ^ mv2;
+
+        assert_eq!(&mv1 ^ mv2, expected);
+        assert_eq!(mv1 ^ &mv2, expected);
+        assert_eq!(&mv1 ^ &mv2, expected);
+        assert_eq!(mv1.outer_product(&mv2), expected);
+    }
+
+    #[test]
+    fn test_outer_product_associative() {
+        let mv1 = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = EuclideanMultivector4::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let mv3 = EuclideanMultivector4::new(
+            2_i32, 0_i32, -1_i32, 3_i32, 1_i32, -2_i32, 4_i32, 0_i32, 1_i32, -3_i32, 2_i32, 5_i32, -1_i32, 0_i32, 2_i32, -4_i32,
+        );
+
+        assert_eq!((mv1 ^ mv2) ^ mv3, mv1 ^ (mv2 ^ mv3));
+    }
+}
+
+
+#[cfg(test)]
+mod e4ga_grade_tests {
+    use cggeomalg::e4ga::EuclideanMultivector4;
+
+
+    #[test]
+    fn test_grade_decomposition() {
+        let mv = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let result = mv.grade(0) + mv.grade(1) + mv.grade(2) + mv.grade(3) + mv.grade(4);
+
+        assert_eq!(result, mv);
+    }
+
+    #[test]
+    fn test_grade_projection() {
+        let mv = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+
+        assert_eq!(mv.grade(0), EuclideanMultivector4::from_scalar(1_i32));
+        assert_eq!(
+            mv.grade(2),
+            EuclideanMultivector4::new(
+                0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32,
+            )
+        );
+        assert_eq!(mv.grade(4), EuclideanMultivector4::unit_e1234() * 16_i32);
+        assert!(mv.grade(5).is_zero());
+    }
+
+    #[test]
+    fn test_reverse_reverse() {
+        let mv = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+
+        assert_eq!(mv.reverse().reverse(), mv);
+    }
+
+    #[test]
+    fn test_reverse_product() {
+        let mv1 = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = EuclideanMultivector4::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+
+        assert_eq!((mv1 * mv2).reverse(), mv2.reverse() * mv1.reverse());
+    }
+
+    #[test]
+    fn test_involute_product() {
+        let mv1 = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = EuclideanMultivector4::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+
+        assert_eq!((mv1 * mv2).involute(), mv1.involute() * mv2.involute());
+    }
+
+    #[test]
+    fn test_conjugate_is_reverse_of_involute() {
+        let mv = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+
+        assert_eq!(mv.conjugate(), mv.involute().reverse());
+    }
+}
+
+
+#[cfg(test)]
+mod e4ga_scalar_product_tests {
+    use cggeomalg::e4ga::EuclideanMultivector4;
+
+
+    #[test]
+    fn test_scalar_product_orthonormal_basis() {
+        let e1: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e1();
+        let e4: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e4();
+        let e24: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e24();
+        let one: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_scalar();
+        let zero: EuclideanMultivector4<i32> = EuclideanMultivector4::zero();
+
+        assert_eq!(e4 | e4, one);
+        assert_eq!(e24 | e24, one);
+        assert_eq!(e1 | e4, zero);
+    }
+
+    #[test]
+    fn test_scalar_product_is_scalar_part_of_geometric_product() {
+        let mv1 = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = EuclideanMultivector4::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = (mv1 * mv2.reverse()).grade(0);
+        let result = mv1 | mv2;
+
+        assert_eq!(result, expected);
+        assert_eq!(result, EuclideanMultivector4::from_scalar(330_i32));
+    }
+
+    #[test]
+    fn test_scalar_product_references() {
+        let mv1 = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = EuclideanMultivector4::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = mv1 | mv2;
+
+        assert_eq!(&mv1 | mv2, expected);
+        assert_eq!(mv1 | &mv2, expected);
+        assert_eq!(&mv1 | &mv2, expected);
+        assert_eq!(mv1.scalar_product(&mv2), expected);
+    }
+}
+
+
+#[cfg(test)]
+mod e4ga_left_contraction_tests {
+    use cggeomalg::e4ga::EuclideanMultivector4;
+
+
+    #[test]
+    fn test_left_contraction_vector_bivector() {
+        let e1: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e1();
+        let e2: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e2();
+        let e4: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e4();
+        let e12: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e12();
+        let e24: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e24();
+
+        assert_eq!(e1 << e12, e2);
+        assert_eq!(e2 << e12, -e1);
+        assert_eq!(e4 << e24, -e2);
+    }
+
+    #[test]
+    fn test_left_contraction_higher_grade_is_zero() {
+        let e12: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e12();
+        let e1: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e1();
+        let zero: EuclideanMultivector4<i32> = EuclideanMultivector4::zero();
+
+        assert_eq!(e12 << e1, zero);
+    }
+
+    #[test]
+    fn test_left_contraction() {
+        let mv1 = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = EuclideanMultivector4::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = EuclideanMultivector4::new(
+            -250_i32, -46_i32, -26_i32, 70_i32, -150_i32, 53_i32, -27_i32, -32_i32, 42_i32, 6_i32, -10_i32, -7_i32, 21_i32, -16_i32,
+            15_i32, 3_i32,
+        );
+        let result = mv1 << mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_left_contraction_references() {
+        let mv1 = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = EuclideanMultivector4::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = mv1 << mv2;
+
+        assert_eq!(&mv1 << mv2, expected);
+        assert_eq!(mv1 << &mv2, expected);
+        assert_eq!(&mv1 << &mv2, expected);
+        assert_eq!(mv1.left_contract(&mv2), expected);
+    }
+}
+
+
+#[cfg(test)]
+mod e4ga_right_contraction_tests {
+    use cggeomalg::e4ga::EuclideanMultivector4;
+
+
+    #[test]
+    fn test_right_contraction_bivector_vector() {
+        let e1: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e1();
+        let e2: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e2();
+        let e12: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e12();
+        let zero: EuclideanMultivector4<i32> = EuclideanMultivector4::zero();
+
+        assert_eq!(e12 >> e2, e1);
+        assert_eq!(e12 >> e1, -e2);
+        assert_eq!(e1 >> e12, zero);
+    }
+
+    #[test]
+    fn test_right_contraction_reverse() {
+        let mv1 = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = EuclideanMultivector4::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+
+        assert_eq!((mv1 >> mv2).reverse(), mv2.reverse() << mv1.reverse());
+    }
+
+    #[test]
+    fn test_right_contraction() {
+        let mv1 = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = EuclideanMultivector4::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = EuclideanMultivector4::new(
+            -250_i32, -176_i32, -117_i32, -363_i32, -120_i32, 45_i32, -49_i32, -122_i32, 36_i32, 34_i32, -65_i32, -44_i32, 23_i32, 106_i32,
+            61_i32, 48_i32,
+        );
+        let result = mv1 >> mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_right_contraction_references() {
+        let mv1 = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = EuclideanMultivector4::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = mv1 >> mv2;
+
+        assert_eq!(&mv1 >> mv2, expected);
+        assert_eq!(mv1 >> &mv2, expected);
+        assert_eq!(&mv1 >> &mv2, expected);
+        assert_eq!(mv1.right_contract(&mv2), expected);
+    }
+}
+
+
+#[cfg(test)]
+mod e4ga_duality_tests {
+    use cggeomalg::e4ga::EuclideanMultivector4;
+
+
+    #[test]
+    fn test_dual_is_product_with_inverse_pseudoscalar() {
+        let mv = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let inv_ps: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e1234();
+
+        assert_eq!(mv.dual(), mv * inv_ps);
+    }
+
+    #[test]
+    fn test_undual_is_product_with_pseudoscalar() {
+        let mv = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let ps: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e1234();
+
+        assert_eq!(mv.undual(), mv * ps);
+        assert_eq!(mv.undual(), mv.dual());
+    }
+
+    #[test]
+    fn test_dual_undual() {
+        let mv = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+
+        assert_eq!(mv.dual().undual(), mv);
+        assert_eq!(mv.undual().dual(), mv);
+    }
+
+    #[test]
+    fn test_not_is_dual() {
+        let mv = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+
+        assert_eq!(!mv, mv.dual());
+        assert_eq!(!&mv, mv.dual());
+    }
+
+    #[test]
+    fn test_dual_mut() {
+        let mv = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mut result = mv;
+        result.dual_mut();
+
+        assert_eq!(result, mv.dual());
+    }
+
+    #[test]
+    fn test_pseudoscalar_inverse() {
+        let ps: EuclideanMultivector4<f64> = EuclideanMultivector4::pseudoscalar();
+        let ps_inv: EuclideanMultivector4<f64> = EuclideanMultivector4::inv_pseudoscalar();
+        let one: EuclideanMultivector4<f64> = EuclideanMultivector4::unit_scalar();
+
+        assert_eq!(ps * ps_inv, one);
+    }
+}
+
+
+#[cfg(test)]
+mod e4ga_regressive_product_tests {
+    use cggeomalg::e4ga::EuclideanMultivector4;
+
+
+    #[test]
+    fn test_regressive_product() {
+        let mv1 = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = EuclideanMultivector4::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = EuclideanMultivector4::new(
+            32_i32, -23_i32, 232_i32, 142_i32, -21_i32, 158_i32, 249_i32, 145_i32, 119_i32, 96_i32, -278_i32, 164_i32, 183_i32, -70_i32,
+            189_i32, 48_i32,
+        );
+        let result = mv1 & mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_regressive_product_references() {
+        let mv1 = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = EuclideanMultivector4::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = mv1 & mv2;
+
+        assert_eq!(&mv1 & mv2, expected);
+        assert_eq!(mv1 & &mv2, expected);
+        assert_eq!(&mv1 & &mv2, expected);
+        assert_eq!(mv1.regressive_product(&mv2), expected);
+    }
+
+    #[test]
+    fn test_regressive_product_dual_outer_product() {
+        let mv1 = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = EuclideanMultivector4::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = (mv1.dual() ^ mv2.dual()).undual();
+        let result = mv1 & mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_regressive_product_pseudoscalar_unit() {
+        let e1234: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e1234();
+        let mv = EuclideanMultivector4::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+
+        assert_eq!(e1234 & mv, mv);
+        assert_eq!(mv & e1234, mv);
+    }
+
+    #[test]
+    fn test_regressive_product_intersection_of_hyperplanes() {
+        let e123: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e123();
+        let e124: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e124();
+        let e12: EuclideanMultivector4<i32> = EuclideanMultivector4::unit_e12();
+
+        assert_eq!(e123 & e124, -e12);
+    }
+}
+
+
+#[cfg(test)]
+mod e4ga_inversion_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::e4ga::EuclideanMultivector4;
+
+
+    #[test]
+    fn test_inverse() {
+        let mv = EuclideanMultivector4::new(
+            1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64, 9_f64, 10_f64, 11_f64, 12_f64, 13_f64, 14_f64, 15_f64, 16_f64,
+        );
+        let mv_inv = mv.inverse().unwrap();
+        let one: EuclideanMultivector4<f64> = EuclideanMultivector4::unit_scalar();
+
+        assert_relative_eq!(mv * mv_inv, one, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(mv_inv * mv, one, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_inverse_vector() {
+        let vector = EuclideanMultivector4::unit_e1() * 2_f64 + EuclideanMultivector4::unit_e4() * 2_f64;
+        let expected = vector * (1_f64 / 8_f64);
+        let result = vector.inverse().unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_inverse_non_simple_bivector() {
+        let bivector = EuclideanMultivector4::new(
+            0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0.3_f64, -0.2_f64, 0.7_f64, 0.4_f64, 0.5_f64, -0.6_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64,
+        );
+        let bivector_inv = bivector.inverse().unwrap();
+        let one: EuclideanMultivector4<f64> = EuclideanMultivector4::unit_scalar();
+
+        assert_relative_eq!(bivector * bivector_inv, one, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_zero_divisor_is_not_invertible() {
+        let idempotent = (EuclideanMultivector4::unit_scalar() + EuclideanMultivector4::unit_e1234()) * 0.5_f64;
+
+        assert!(!idempotent.is_invertible());
+        assert!(idempotent.inverse().is_none());
+        assert_eq!(idempotent * idempotent, idempotent);
+    }
+
+    #[test]
+    fn test_isoclinic_bivector_is_not_invertible() {
+        let isoclinic: EuclideanMultivector4<f64> = EuclideanMultivector4::unit_e12() - EuclideanMultivector4::unit_e34();
+        let simple: EuclideanMultivector4<f64> = EuclideanMultivector4::unit_e12() * 2_f64;
+
+        // The square of an isoclinic bivector is a multiple of the zero divisor `1 + e1234`.
+        assert!(isoclinic.inverse().is_none());
+        assert!(simple.inverse().is_some());
+    }
+
+    #[test]
+    fn test_zero_is_not_invertible() {
+        let zero: EuclideanMultivector4<f64> = EuclideanMultivector4::zero();
+
+        assert!(zero.inverse().is_none());
+    }
+
+    #[test]
+    fn test_division() {
+        let mv1 = EuclideanMultivector4::new(
+            1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64, 9_f64, 10_f64, 11_f64, 12_f64, 13_f64, 14_f64, 15_f64, 16_f64,
+        );
+        let mv2 = EuclideanMultivector4::new(
+            3_f64, -1_f64, 4_f64, 1_f64, -5_f64, 9_f64, 2_f64, -6_f64, 5_f64, 3_f64, -5_f64, 8_f64, 9_f64, -7_f64, 9_f64, 3_f64,
+        );
+        let expected = mv1 * mv2.inverse().unwrap();
+        let result = mv1 / mv2;
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(&mv1 / mv2, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(mv1 / &mv2, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(&mv1 / &mv2, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+
+#[cfg(test)]
+mod e4ga_commutator_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::e4ga::EuclideanMultivector4;
+
+
+    #[test]
+    fn test_commutator_orthogonal_planes() {
+        let e12: EuclideanMultivector4<f64> = EuclideanMultivector4::unit_e12();
+        let e34: EuclideanMultivector4<f64> = EuclideanMultivector4::unit_e34();
+        let zero: EuclideanMultivector4<f64> = EuclideanMultivector4::zero();
+
+        assert_eq!(e12.commutator(&e34), zero);
+        assert_eq!(e12.x(&e34), zero);
+    }
+
+    #[test]
+    fn test_commutator_anticommutator_sum() {
+        let mv1 = EuclideanMultivector4::new(
+            1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64, 9_f64, 10_f64, 11_f64, 12_f64, 13_f64, 14_f64, 15_f64, 16_f64,
+        );
+        let mv2 = EuclideanMultivector4::new(
+            3_f64, -1_f64, 4_f64, 1_f64, -5_f64, 9_f64, 2_f64, -6_f64, 5_f64, 3_f64, -5_f64, 8_f64, 9_f64, -7_f64, 9_f64, 3_f64,
+        );
+
+        assert_eq!(mv1.commutator(&mv2) + mv1.anticommutator(&mv2), mv1 * mv2);
+    }
+
+    #[test]
+    fn test_magnitude() {
+        let mv = EuclideanMultivector4::unit_e1() * 3_f64 + EuclideanMultivector4::unit_e4() * 4_f64;
+
+        assert_relative_eq!(mv.magnitude(), 5_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(
+            mv.normalize().magnitude(),
+            1_f64,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            mv.distance(&EuclideanMultivector4::zero()),
+            5_f64,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+}
+
+
+#[cfg(test)]
+mod e4ga_rotor_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::e4ga::{EuclideanMultivector4, Rotor4};
+    use core::f64;
+
+    fn bivector() -> EuclideanMultivector4<f64> {
+        EuclideanMultivector4::new(
+            0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0.3_f64, -0.2_f64, 0.7_f64, 0.4_f64, 0.5_f64, -0.6_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64,
+        )
+    }
+
+    fn sandwich(rotor: &Rotor4<f64>, vector: &EuclideanMultivector4<f64>) -> EuclideanMultivector4<f64> {
+        let rotor = EuclideanMultivector4::from(rotor);
+
+        rotor * vector * rotor.reverse()
+    }
+
+    #[test]
+    fn test_identity_rotor_rotate_vector() {
+        let identity: Rotor4<f64> = Rotor4::identity();
+        let vector = EuclideanMultivector4::new(
+            0_f64, 3_f64, -5_f64, 7_f64, 2_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64,
+        );
+        let result = identity.rotate_vector(&vector);
+
+        assert_eq!(result, vector);
+    }
+
+    #[test]
+    fn test_exp_simple_rotation() {
+        let e12: EuclideanMultivector4<f64> = EuclideanMultivector4::unit_e12();
+        let angle = f64::consts::FRAC_PI_3;
+        let rotor = Rotor4::exp(&(e12 * (-angle / 2_f64)));
+        let expected = Rotor4::new(
+            (angle / 2_f64).cos(),
+            -(angle / 2_f64).sin(),
+            0_f64,
+            0_f64,
+            0_f64,
+            0_f64,
+            0_f64,
+            0_f64,
+        );
+
+        assert_relative_eq!(rotor, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_exp_matches_product_of_simple_rotations() {
+        let e13: EuclideanMultivector4<f64> = EuclideanMultivector4::unit_e13();
+        let e24: EuclideanMultivector4<f64> = EuclideanMultivector4::unit_e24();
+        let rotor1 = Rotor4::exp(&(e13 * 0.4_f64));
+        let rotor2 = Rotor4::exp(&(e24 * -1.1_f64));
+        let expected = rotor1 * rotor2;
+        let result = Rotor4::exp(&(e13 * 0.4_f64 + e24 * -1.1_f64));
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_exp_isoclinic_rotation() {
+        let e12: EuclideanMultivector4<f64> = EuclideanMultivector4::unit_e12();
+        let e34: EuclideanMultivector4<f64> = EuclideanMultivector4::unit_e34();
+        let angle = 0.9_f64;
+        let rotor = Rotor4::exp(&((e12 + e34) * (-angle / 2_f64)));
+        let vectors = [
+            EuclideanMultivector4::unit_e1(),
+            EuclideanMultivector4::unit_e2(),
+            EuclideanMultivector4::unit_e3(),
+            EuclideanMultivector4::unit_e4(),
+            EuclideanMultivector4::unit_e1() * 0.5_f64 - EuclideanMultivector4::unit_e3() * 2_f64 + EuclideanMultivector4::unit_e4(),
+        ];
+
+        // Every vector is rotated by the same angle in an isoclinic rotation.
+        for vector in vectors.iter() {
+            let rotated = rotor.rotate_vector(vector);
+            let cos_angle = (vector | rotated)[0] / (vector | vector)[0];
+
+            assert_relative_eq!(cos_angle, angle.cos(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_exp_is_unit() {
+        let rotor = Rotor4::exp(&bivector());
+        let identity: Rotor4<f64> = Rotor4::identity();
+
+        assert_relative_eq!(
+            rotor * rotor.reverse(),
+            identity,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_exp_log_round_trip() {
+        let bivector = bivector();
+        let result = Rotor4::exp(&bivector).log();
+
+        assert_relative_eq!(result, bivector, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_exp_log_round_trip_isoclinic() {
+        let bivector = (EuclideanMultivector4::unit_e13() - EuclideanMultivector4::unit_e24()) * 0.7_f64;
+        let result = Rotor4::exp(&bivector).log();
+
+        assert_relative_eq!(result, bivector, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_log_exp_round_trip() {
+        let rotor =
+            Rotor4::exp(&(EuclideanMultivector4::unit_e14() * 2.5_f64)) * Rotor4::exp(&(EuclideanMultivector4::unit_e23() * -0.3_f64));
+        let result = Rotor4::exp(&rotor.log());
+
+        assert_relative_eq!(result, rotor, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_log_identity() {
+        let identity: Rotor4<f64> = Rotor4::identity();
+        let zero: EuclideanMultivector4<f64> = EuclideanMultivector4::zero();
+
+        assert_eq!(identity.log(), zero);
+    }
+
+    #[test]
+    fn test_log_minus_identity() {
+        let rotor: Rotor4<f64> = -Rotor4::identity();
+        let expected = EuclideanMultivector4::unit_e12() * f64::consts::PI;
+        let result = rotor.log();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(Rotor4::exp(&result), rotor, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_log_central_rotor() {
+        let rotor = Rotor4::new(0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 1_f64);
+        let result = rotor.log();
+
+        assert_relative_eq!(Rotor4::exp(&result), rotor, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotate_vector_matches_sandwich() {
+        let rotor = Rotor4::exp(&bivector());
+        let vector = EuclideanMultivector4::new(
+            0_f64, 1_f64, -2_f64, 3_f64, 0.5_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64,
+        );
+        let expected = sandwich(&rotor, &vector);
+        let result = rotor.rotate_vector(&vector);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotate_vector_preserves_magnitude() {
+        let rotor = Rotor4::exp(&bivector());
+        let vector = EuclideanMultivector4::new(
+            0_f64, 1_f64, -2_f64, 3_f64, 0.5_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64,
+        );
+        let result = rotor.rotate_vector(&vector);
+
+        assert_relative_eq!(
+            result.magnitude(),
+            vector.magnitude(),
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_composition_matches_geometric_product() {
+        let rotor1 = Rotor4::exp(&bivector());
+        let rotor2 = Rotor4::exp(&(EuclideanMultivector4::unit_e12() * 0.25_f64 + EuclideanMultivector4::unit_e34() * 1.5_f64));
+        let expected = EuclideanMultivector4::from(rotor1) * EuclideanMultivector4::from(rotor2);
+        let result = EuclideanMultivector4::from(rotor1 * rotor2);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_normalize() {
+        let rotor = Rotor4::new(1_f64, 2_f64, -3_f64, 4_f64, 0.5_f64, -6_f64, 7_f64, 8_f64);
+        let normalized = rotor.normalize();
+        let identity: Rotor4<f64> = Rotor4::identity();
+        let result = normalized * normalized.reverse();
+
+        assert_relative_eq!(result, identity, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_normalize_unit_rotor() {
+        let rotor = Rotor4::exp(&bivector());
+        let result = rotor.normalize();
+
+        assert_relative_eq!(result, rotor, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_inverse() {
+        let rotor = Rotor4::exp(&bivector());
+        let vector: EuclideanMultivector4<f64> = EuclideanMultivector4::unit_e3();
+        let result = rotor.inverse().rotate_vector(&rotor.rotate_vector(&vector));
+
+        assert_relative_eq!(result, vector, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod grade_mask_tests {
+    use cggeomalg::grade::GradeMask;
+
+
+    #[test]
+    fn test_from_grade() {
+        assert_eq!(GradeMask::from_grade(0), Some(GradeMask::SCALAR));
+        assert_eq!(GradeMask::from_grade(1), Some(GradeMask::VECTOR));
+        assert_eq!(GradeMask::from_grade(2), Some(GradeMask::BIVECTOR));
+        assert_eq!(GradeMask::from_grade(3), Some(GradeMask::TRIVECTOR));
+        assert_eq!(GradeMask::from_grade(31).map(GradeMask::bits), Some(1 << 31));
+    }
+
+    #[test]
+    fn test_from_grade_out_of_range() {
+        assert_eq!(GradeMask::from_grade(32), None);
+        assert_eq!(GradeMask::from_grade(usize::MAX), None);
+    }
+
+    #[test]
+    fn test_contains_grade() {
+        let mask = GradeMask::VECTOR | GradeMask::TRIVECTOR;
+
+        assert!(!mask.contains_grade(0));
+        assert!(mask.contains_grade(1));
+        assert!(!mask.contains_grade(2));
+        assert!(mask.contains_grade(3));
+        assert!(!mask.contains_grade(32));
+        assert!(!mask.contains_grade(usize::MAX));
+    }
+
+    #[test]
+    fn test_empty_all() {
+        assert!(GradeMask::empty().is_empty());
+        assert_eq!(GradeMask::empty().len(), 0);
+        assert_eq!(GradeMask::all().len(), 32);
+        assert_eq!(GradeMask::default(), GradeMask::empty());
+        assert_eq!(!GradeMask::empty(), GradeMask::all());
+    }
+
+    #[test]
+    fn test_even_odd() {
+        assert_eq!(GradeMask::EVEN | GradeMask::ODD, GradeMask::all());
+        assert_eq!(GradeMask::EVEN & GradeMask::ODD, GradeMask::empty());
+        assert_eq!(!GradeMask::EVEN, GradeMask::ODD);
+        assert!(GradeMask::EVEN.contains(GradeMask::SCALAR | GradeMask::BIVECTOR));
+        assert!(GradeMask::ODD.contains(GradeMask::VECTOR | GradeMask::TRIVECTOR));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let mask1 = GradeMask::SCALAR | GradeMask::VECTOR;
+        let mask2 = GradeMask::VECTOR | GradeMask::BIVECTOR;
+
+        assert_eq!(mask1 & mask2, GradeMask::VECTOR);
+        assert_eq!(mask1 ^ mask2, GradeMask::SCALAR | GradeMask::BIVECTOR);
+        assert_eq!(mask1 - mask2, GradeMask::SCALAR);
+        assert!(mask1.intersects(mask2));
+        assert!(!mask1.intersects(GradeMask::TRIVECTOR));
+    }
+
+    #[test]
+    fn test_assign_operations() {
+        let mut mask = GradeMask::SCALAR;
+        mask |= GradeMask::BIVECTOR;
+        assert_eq!(mask, GradeMask::SCALAR | GradeMask::BIVECTOR);
+
+        mask -= GradeMask::SCALAR;
+        assert_eq!(mask, GradeMask::BIVECTOR);
+
+        mask ^= GradeMask::VECTOR;
+        assert_eq!(mask, GradeMask::VECTOR | GradeMask::BIVECTOR);
+
+        mask &= GradeMask::ODD;
+        assert_eq!(mask, GradeMask::VECTOR);
+    }
+
+    #[test]
+    fn test_insert_remove() {
+        let mut mask = GradeMask::empty();
+        mask.insert(GradeMask::TRIVECTOR);
+        mask.insert(GradeMask::SCALAR);
+        mask.remove(GradeMask::SCALAR);
+
+        assert_eq!(mask, GradeMask::TRIVECTOR);
+    }
+
+    #[test]
+    fn test_iter() {
+        let mask = GradeMask::SCALAR | GradeMask::BIVECTOR | GradeMask::from_bits(1 << 31);
+        let grades: Vec<usize> = mask.iter().collect();
+
+        assert_eq!(grades, vec![0, 2, 31]);
+    }
+
+    #[test]
+    fn test_debug() {
+        let mask = GradeMask::SCALAR | GradeMask::BIVECTOR;
+
+        assert_eq!(format!("{:?}", mask), "GradeMask({0, 2})");
+    }
+}
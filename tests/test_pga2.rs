@@ -0,0 +1,824 @@
+#[cfg(test)]
+mod pga2_component_tests {
+    use cggeomalg::pga2::ProjectiveMultivector2;
+
+
+    #[test]
+    fn test_components1() {
+        let mv = ProjectiveMultivector2::new(1, 2, 3, 4, 5, 6, 7, 8);
+
+        assert_eq!(mv[0], 1);
+        assert_eq!(mv[1], 2);
+        assert_eq!(mv[2], 3);
+        assert_eq!(mv[3], 4);
+        assert_eq!(mv[4], 5);
+        assert_eq!(mv[5], 6);
+        assert_eq!(mv[6], 7);
+        assert_eq!(mv[7], 8);
+    }
+
+    #[test]
+    fn test_components2() {
+        let mv = ProjectiveMultivector2::new(1, 2, 3, 4, 5, 6, 7, 8);
+
+        assert_eq!(mv.scalar, mv[0]);
+        assert_eq!(mv.e0, mv[1]);
+        assert_eq!(mv.e1, mv[2]);
+        assert_eq!(mv.e2, mv[3]);
+        assert_eq!(mv.e01, mv[4]);
+        assert_eq!(mv.e20, mv[5]);
+        assert_eq!(mv.e12, mv[6]);
+        assert_eq!(mv.e012, mv[7]);
+    }
+
+    #[test]
+    fn test_as_ref() {
+        let mv = ProjectiveMultivector2::new(1, 2, 3, 4, 5, 6, 7, 8);
+        let v_ref: &[isize; 8] = mv.as_ref();
+
+        assert_eq!(v_ref, &[1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn test_as_mut() {
+        let mut mv = ProjectiveMultivector2::new(1, 2, 3, 4, 5, 6, 7, 8);
+        let v_ref: &mut [isize; 8] = mv.as_mut();
+
+        assert_eq!(v_ref, &mut [1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_out_of_bounds_array_access() {
+        let mv = ProjectiveMultivector2::new(1, 2, 3, 4, 5, 6, 7, 8);
+
+        assert_eq!(mv[8], mv[8]);
+    }
+}
+
+
+#[cfg(test)]
+mod pga2_addition_subtraction_tests {
+    use cggeomalg::pga2::ProjectiveMultivector2;
+
+
+    #[test]
+    fn test_multivector_addition() {
+        let mv1 = ProjectiveMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = ProjectiveMultivector2::new(3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32);
+        let expected = ProjectiveMultivector2::new(4_i32, 1_i32, 7_i32, 5_i32, 0_i32, 15_i32, 9_i32, 2_i32);
+        let result = mv1 + mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_multivector_subtraction() {
+        let mv1 = ProjectiveMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = ProjectiveMultivector2::new(3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32);
+        let expected = ProjectiveMultivector2::new(-2_i32, 3_i32, -1_i32, 3_i32, 10_i32, -3_i32, 5_i32, 14_i32);
+        let result = mv1 - mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_multivector_minus_multivector_is_zero() {
+        let mv = ProjectiveMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let zero: ProjectiveMultivector2<i32> = ProjectiveMultivector2::zero();
+
+        assert_eq!(mv - mv, zero);
+    }
+
+    #[test]
+    fn test_scalar_minus_multivector() {
+        let mv = ProjectiveMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let expected = ProjectiveMultivector2::new(4_i32, -2_i32, -3_i32, -4_i32, -5_i32, -6_i32, -7_i32, -8_i32);
+        let result = 5_i32 - mv;
+
+        assert_eq!(result, expected);
+        assert_eq!(5_i32 - mv, ProjectiveMultivector2::from_scalar(5_i32) - mv);
+    }
+
+    #[test]
+    fn test_scalar_plus_multivector() {
+        let mv = ProjectiveMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+
+        assert_eq!(5_i32 + mv, mv + 5_i32);
+        assert_eq!(5_i32 + mv, ProjectiveMultivector2::from_scalar(5_i32) + mv);
+    }
+}
+
+
+#[cfg(test)]
+mod pga2_multiplication_division_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::pga2::ProjectiveMultivector2;
+
+
+    #[test]
+    fn test_scalar_multiplication() {
+        let mv = ProjectiveMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let expected = ProjectiveMultivector2::new(3_i32, 6_i32, 9_i32, 12_i32, 15_i32, 18_i32, 21_i32, 24_i32);
+
+        assert_eq!(mv * 3_i32, expected);
+        assert_eq!(3_i32 * mv, expected);
+    }
+
+    #[test]
+    fn test_scalar_division() {
+        let mv = ProjectiveMultivector2::new(1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64);
+        let expected = mv * (1_f64 / 9_f64);
+        let result = mv / 9_f64;
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_geometric_product_basis_squares() {
+        let zero: ProjectiveMultivector2<i32> = ProjectiveMultivector2::zero();
+        let one: ProjectiveMultivector2<i32> = ProjectiveMultivector2::unit_scalar();
+
+        assert_eq!(ProjectiveMultivector2::unit_e0() * ProjectiveMultivector2::unit_e0(), zero);
+        assert_eq!(ProjectiveMultivector2::unit_e1() * ProjectiveMultivector2::unit_e1(), one);
+        assert_eq!(ProjectiveMultivector2::unit_e2() * ProjectiveMultivector2::unit_e2(), one);
+        assert_eq!(ProjectiveMultivector2::unit_e01() * ProjectiveMultivector2::unit_e01(), zero);
+        assert_eq!(ProjectiveMultivector2::unit_e20() * ProjectiveMultivector2::unit_e20(), zero);
+        assert_eq!(ProjectiveMultivector2::unit_e12() * ProjectiveMultivector2::unit_e12(), -one);
+        assert_eq!(ProjectiveMultivector2::unit_e012() * ProjectiveMultivector2::unit_e012(), zero);
+    }
+
+    #[test]
+    fn test_geometric_product_e0_anticommutes() {
+        let e0: ProjectiveMultivector2<i32> = ProjectiveMultivector2::unit_e0();
+        let e1: ProjectiveMultivector2<i32> = ProjectiveMultivector2::unit_e1();
+        let e01: ProjectiveMultivector2<i32> = ProjectiveMultivector2::unit_e01();
+
+        assert_eq!(e0 * e1, e01);
+        assert_eq!(e1 * e0, -e01);
+    }
+
+    #[test]
+    fn test_geometric_product_pseudoscalar_squares_to_zero() {
+        let e012: ProjectiveMultivector2<i32> = ProjectiveMultivector2::unit_e012();
+        let zero: ProjectiveMultivector2<i32> = ProjectiveMultivector2::zero();
+
+        assert_eq!(e012 * e012, zero);
+    }
+
+    #[test]
+    fn test_geometric_product() {
+        let mv1 = ProjectiveMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = ProjectiveMultivector2::new(3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32);
+        let expected = ProjectiveMultivector2::new(5_i32, 96_i32, 12_i32, -9_i32, -46_i32, -10_i32, 10_i32, 51_i32);
+        let result = mv1 * mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_geometric_product_references() {
+        let mv1 = ProjectiveMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = ProjectiveMultivector2::new(3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32);
+        let expected = mv1 * mv2;
+
+        assert_eq!(&mv1 * mv2, expected);
+        assert_eq!(mv1 * &mv2, expected);
+        assert_eq!(&mv1 * &mv2, expected);
+    }
+
+    #[test]
+    fn test_geometric_product_associative() {
+        let mv1 = ProjectiveMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = ProjectiveMultivector2::new(3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32);
+        let mv3 = ProjectiveMultivector2::new(2_i32, 0_i32, -1_i32, 3_i32, 1_i32, -2_i32, 4_i32, 0_i32);
+
+        assert_eq!((mv1 * mv2) * mv3, mv1 * (mv2 * mv3));
+    }
+}
+
+
+#[cfg(test)]
+mod pga2_outer_product_tests {
+    use cggeomalg::pga2::ProjectiveMultivector2;
+
+
+    #[test]
+    fn test_outer_product_e1_e2() {
+        let e1: ProjectiveMultivector2<i32> = ProjectiveMultivector2::unit_e1();
+        let e2: ProjectiveMultivector2<i32> = ProjectiveMultivector2::unit_e2();
+        let e12: ProjectiveMultivector2<i32> = ProjectiveMultivector2::unit_e12();
+
+        assert_eq!(e1 ^ e2, e12);
+        assert_eq!(e2 ^ e1, -e12);
+    }
+
+    #[test]
+    fn test_outer_product_e0_e0() {
+        let e0: ProjectiveMultivector2<i32> = ProjectiveMultivector2::unit_e0();
+        let zero: ProjectiveMultivector2<i32> = ProjectiveMultivector2::zero();
+
+        assert_eq!(e0 ^ e0, zero);
+    }
+
+    #[test]
+    fn test_outer_product_vectors_pseudoscalar() {
+        let e0: ProjectiveMultivector2<i32> = ProjectiveMultivector2::unit_e0();
+        let e1: ProjectiveMultivector2<i32> = ProjectiveMultivector2::unit_e1();
+        let e2: ProjectiveMultivector2<i32> = ProjectiveMultivector2::unit_e2();
+        let e012: ProjectiveMultivector2<i32> = ProjectiveMultivector2::unit_e012();
+
+        assert_eq!(e0 ^ e1 ^ e2, e012);
+    }
+
+    #[test]
+    fn test_outer_product() {
+        let mv1 = ProjectiveMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = ProjectiveMultivector2::new(3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32);
+        let expected = ProjectiveMultivector2::new(3_i32, 5_i32, 13_i32, 13_i32, 21_i32, 21_i32, 10_i32, 51_i32);
+        let result = mv1 ^ mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_outer_product_references() {
+        let mv1 = ProjectiveMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = ProjectiveMultivector2::new(3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32);
+        let expected = mv1 ^ mv2;
+
+        assert_eq!(&mv1 ^ mv2, expected);
+        assert_eq!(mv1 ^ &mv2, expected);
+        assert_eq!(&mv1 ^ &mv2, expected);
+        assert_eq!(mv1.outer_product(&mv2), expected);
+    }
+}
+
+
+#[cfg(test)]
+mod pga2_grade_tests {
+    use cggeomalg::pga2::ProjectiveMultivector2;
+
+
+    #[test]
+    fn test_grade_decomposition() {
+        let mv = ProjectiveMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let result = mv.grade(0) + mv.grade(1) + mv.grade(2) + mv.grade(3);
+
+        assert_eq!(result, mv);
+    }
+
+    #[test]
+    fn test_grade_projection() {
+        let mv = ProjectiveMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+
+        assert_eq!(
+            mv.grade(0),
+            ProjectiveMultivector2::new(1_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32)
+        );
+        assert_eq!(
+            mv.grade(2),
+            ProjectiveMultivector2::new(0_i32, 0_i32, 0_i32, 0_i32, 5_i32, 6_i32, 7_i32, 0_i32)
+        );
+        assert_eq!(
+            mv.grade(3),
+            ProjectiveMultivector2::new(0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 8_i32)
+        );
+        assert!(mv.grade(4).is_zero());
+    }
+
+    #[test]
+    fn test_reverse_reverse() {
+        let mv = ProjectiveMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+
+        assert_eq!(mv.reverse().reverse(), mv);
+    }
+
+    #[test]
+    fn test_reverse_product() {
+        let mv1 = ProjectiveMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = ProjectiveMultivector2::new(3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32);
+
+        assert_eq!((mv1 * mv2).reverse(), mv2.reverse() * mv1.reverse());
+    }
+
+    #[test]
+    fn test_involute_product() {
+        let mv1 = ProjectiveMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = ProjectiveMultivector2::new(3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32);
+
+        assert_eq!((mv1 * mv2).involute(), mv1.involute() * mv2.involute());
+    }
+
+    #[test]
+    fn test_conjugate_is_reverse_of_involute() {
+        let mv = ProjectiveMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+
+        assert_eq!(mv.conjugate(), mv.involute().reverse());
+    }
+}
+
+
+#[cfg(test)]
+mod pga2_scalar_product_tests {
+    use cggeomalg::pga2::ProjectiveMultivector2;
+
+
+    #[test]
+    fn test_scalar_product_null_vector() {
+        let e0: ProjectiveMultivector2<i32> = ProjectiveMultivector2::unit_e0();
+        let e01: ProjectiveMultivector2<i32> = ProjectiveMultivector2::unit_e01();
+        let zero: ProjectiveMultivector2<i32> = ProjectiveMultivector2::zero();
+
+        assert_eq!(e0 | e0, zero);
+        assert_eq!(e01 | e01, zero);
+    }
+
+    #[test]
+    fn test_scalar_product_is_scalar_part_of_geometric_product() {
+        let mv1 = ProjectiveMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = ProjectiveMultivector2::new(3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32);
+        let expected = (mv1 * mv2.reverse()).grade(0);
+        let result = mv1 | mv2;
+
+        assert_eq!(result, expected);
+        assert_eq!(result, ProjectiveMultivector2::from_scalar(33_i32));
+    }
+
+    #[test]
+    fn test_scalar_product_references() {
+        let mv1 = ProjectiveMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = ProjectiveMultivector2::new(3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32);
+        let expected = mv1 | mv2;
+
+        assert_eq!(&mv1 | mv2, expected);
+        assert_eq!(mv1 | &mv2, expected);
+        assert_eq!(&mv1 | &mv2, expected);
+        assert_eq!(mv1.scalar_product(&mv2), expected);
+    }
+}
+
+
+#[cfg(test)]
+mod pga2_left_contraction_tests {
+    use cggeomalg::pga2::ProjectiveMultivector2;
+
+
+    #[test]
+    fn test_left_contraction_vector_bivector() {
+        let e1: ProjectiveMultivector2<i32> = ProjectiveMultivector2::unit_e1();
+        let e2: ProjectiveMultivector2<i32> = ProjectiveMultivector2::unit_e2();
+        let e12: ProjectiveMultivector2<i32> = ProjectiveMultivector2::unit_e12();
+        let e0: ProjectiveMultivector2<i32> = ProjectiveMultivector2::unit_e0();
+        let e01: ProjectiveMultivector2<i32> = ProjectiveMultivector2::unit_e01();
+        let zero: ProjectiveMultivector2<i32> = ProjectiveMultivector2::zero();
+
+        assert_eq!(e1 << e12, e2);
+        assert_eq!(e2 << e12, -e1);
+        assert_eq!(e0 << e01, zero);
+        assert_eq!(e1 << e01, -e0);
+    }
+
+    #[test]
+    fn test_left_contraction_higher_grade_is_zero() {
+        let e12: ProjectiveMultivector2<i32> = ProjectiveMultivector2::unit_e12();
+        let e1: ProjectiveMultivector2<i32> = ProjectiveMultivector2::unit_e1();
+        let zero: ProjectiveMultivector2<i32> = ProjectiveMultivector2::zero();
+
+        assert_eq!(e12 << e1, zero);
+    }
+
+    #[test]
+    fn test_left_contraction() {
+        let mv1 = ProjectiveMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = ProjectiveMultivector2::new(3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32);
+        let expected = ProjectiveMultivector2::new(5_i32, 92_i32, -4_i32, 7_i32, -29_i32, -9_i32, 2_i32, -6_i32);
+        let result = mv1 << mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_left_contraction_references() {
+        let mv1 = ProjectiveMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = ProjectiveMultivector2::new(3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32);
+        let expected = mv1 << mv2;
+
+        assert_eq!(&mv1 << mv2, expected);
+        assert_eq!(mv1 << &mv2, expected);
+        assert_eq!(&mv1 << &mv2, expected);
+        assert_eq!(mv1.left_contract(&mv2), expected);
+    }
+}
+
+
+#[cfg(test)]
+mod pga2_right_contraction_tests {
+    use cggeomalg::pga2::ProjectiveMultivector2;
+
+
+    #[test]
+    fn test_right_contraction_bivector_vector() {
+        let e1: ProjectiveMultivector2<i32> = ProjectiveMultivector2::unit_e1();
+        let e2: ProjectiveMultivector2<i32> = ProjectiveMultivector2::unit_e2();
+        let e12: ProjectiveMultivector2<i32> = ProjectiveMultivector2::unit_e12();
+        let zero: ProjectiveMultivector2<i32> = ProjectiveMultivector2::zero();
+
+        assert_eq!(e12 >> e2, e1);
+        assert_eq!(e12 >> e1, -e2);
+        assert_eq!(e1 >> e12, zero);
+    }
+
+    #[test]
+    fn test_right_contraction_reverse() {
+        let mv1 = ProjectiveMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = ProjectiveMultivector2::new(3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32);
+
+        assert_eq!((mv1 >> mv2).reverse(), mv2.reverse() << mv1.reverse());
+    }
+
+    #[test]
+    fn test_right_contraction() {
+        let mv1 = ProjectiveMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = ProjectiveMultivector2::new(3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32);
+        let expected = ProjectiveMultivector2::new(5_i32, 4_i32, 16_i32, -16_i32, 23_i32, 50_i32, 21_i32, 24_i32);
+        let result = mv1 >> mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_right_contraction_references() {
+        let mv1 = ProjectiveMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = ProjectiveMultivector2::new(3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32);
+        let expected = mv1 >> mv2;
+
+        assert_eq!(&mv1 >> mv2, expected);
+        assert_eq!(mv1 >> &mv2, expected);
+        assert_eq!(&mv1 >> &mv2, expected);
+        assert_eq!(mv1.right_contract(&mv2), expected);
+    }
+}
+
+
+#[cfg(test)]
+mod pga2_duality_tests {
+    use cggeomalg::pga2::ProjectiveMultivector2;
+
+
+    #[test]
+    fn test_dual_basis_blades() {
+        let e012: ProjectiveMultivector2<i32> = ProjectiveMultivector2::unit_e012();
+
+        let e = ProjectiveMultivector2::<i32>::unit_scalar();
+        assert_eq!(e ^ e.dual(), e012);
+        let e = ProjectiveMultivector2::<i32>::unit_e0();
+        assert_eq!(e ^ e.dual(), e012);
+        let e = ProjectiveMultivector2::<i32>::unit_e1();
+        assert_eq!(e ^ e.dual(), e012);
+        let e = ProjectiveMultivector2::<i32>::unit_e2();
+        assert_eq!(e ^ e.dual(), e012);
+        let e = ProjectiveMultivector2::<i32>::unit_e01();
+        assert_eq!(e ^ e.dual(), e012);
+        let e = ProjectiveMultivector2::<i32>::unit_e20();
+        assert_eq!(e ^ e.dual(), e012);
+        let e = ProjectiveMultivector2::<i32>::unit_e12();
+        assert_eq!(e ^ e.dual(), e012);
+        let e = ProjectiveMultivector2::<i32>::unit_e012();
+        assert_eq!(e ^ e.dual(), e012);
+    }
+
+    #[test]
+    fn test_dual_undual() {
+        let mv = ProjectiveMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+
+        assert_eq!(mv.dual().undual(), mv);
+        assert_eq!(mv.undual().dual(), mv);
+    }
+
+    #[test]
+    fn test_not_is_dual() {
+        let mv = ProjectiveMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+
+        assert_eq!(!mv, mv.dual());
+        assert_eq!(!&mv, mv.dual());
+    }
+
+    #[test]
+    fn test_dual_mut() {
+        let mv = ProjectiveMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mut result = mv;
+        result.dual_mut();
+
+        assert_eq!(result, mv.dual());
+    }
+
+    #[test]
+    fn test_dual_plane_is_point() {
+        let plane: ProjectiveMultivector2<i32> = ProjectiveMultivector2::unit_e0();
+        let origin = ProjectiveMultivector2::from_point(0_i32, 0_i32);
+
+        assert_eq!(plane.dual(), origin);
+    }
+}
+
+
+#[cfg(test)]
+mod pga2_regressive_product_tests {
+    use cggeomalg::pga2::ProjectiveMultivector2;
+
+
+    #[test]
+    fn test_regressive_product() {
+        let mv1 = ProjectiveMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = ProjectiveMultivector2::new(3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32);
+        let expected = ProjectiveMultivector2::new(51_i32, -95_i32, 59_i32, 35_i32, -70_i32, 36_i32, -26_i32, -48_i32);
+        let result = mv1 & mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_regressive_product_references() {
+        let mv1 = ProjectiveMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = ProjectiveMultivector2::new(3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32);
+        let expected = mv1 & mv2;
+
+        assert_eq!(&mv1 & mv2, expected);
+        assert_eq!(mv1 & &mv2, expected);
+        assert_eq!(&mv1 & &mv2, expected);
+        assert_eq!(mv1.regressive_product(&mv2), expected);
+    }
+
+    #[test]
+    fn test_regressive_product_dual_outer_product() {
+        let mv1 = ProjectiveMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+        let mv2 = ProjectiveMultivector2::new(3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32);
+        let expected = (mv1.dual() ^ mv2.dual()).undual();
+        let result = mv1 & mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_regressive_product_pseudoscalar_unit() {
+        let e012: ProjectiveMultivector2<i32> = ProjectiveMultivector2::unit_e012();
+        let mv = ProjectiveMultivector2::new(1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32);
+
+        assert_eq!(e012 & mv, mv);
+        assert_eq!(mv & e012, mv);
+    }
+}
+
+
+#[cfg(test)]
+mod pga2_incidence_tests {
+    use cggeomalg::pga2::ProjectiveMultivector2;
+
+
+    #[test]
+    fn test_point_on_line() {
+        let line = ProjectiveMultivector2::from_line(1_i32, 2_i32, -5_i32);
+        let on_line = ProjectiveMultivector2::from_point(1_i32, 2_i32);
+        let off_line = ProjectiveMultivector2::from_point(1_i32, 3_i32);
+        let e012: ProjectiveMultivector2<i32> = ProjectiveMultivector2::unit_e012();
+
+        assert!((line ^ on_line).is_zero());
+        assert_eq!(line ^ off_line, e012 * 2_i32);
+    }
+
+    #[test]
+    fn test_join_points() {
+        let p = ProjectiveMultivector2::from_point(0_i32, 0_i32);
+        let q = ProjectiveMultivector2::from_point(1_i32, 0_i32);
+        let expected = ProjectiveMultivector2::from_line(0_i32, 1_i32, 0_i32);
+
+        assert_eq!(p.join(&q), expected);
+        assert_eq!(q.join(&p), -expected);
+    }
+
+    #[test]
+    fn test_join_point_direction() {
+        let p = ProjectiveMultivector2::from_point(1_i32, 2_i32);
+        let direction = ProjectiveMultivector2::from_direction(3_i32, 4_i32);
+        let q = ProjectiveMultivector2::from_point(4_i32, 6_i32);
+
+        assert_eq!(p & direction, p & q);
+    }
+
+    #[test]
+    fn test_meet_lines() {
+        let line1 = ProjectiveMultivector2::from_line(1_i32, 0_i32, -3_i32);
+        let line2 = ProjectiveMultivector2::from_line(0_i32, 1_i32, 2_i32);
+        let expected = ProjectiveMultivector2::from_point(3_i32, -2_i32);
+
+        assert_eq!(line1.meet(&line2), expected);
+    }
+
+    #[test]
+    fn test_meet_parallel_lines_is_direction() {
+        let line1 = ProjectiveMultivector2::from_line(1_i32, 1_i32, 0_i32);
+        let line2 = ProjectiveMultivector2::from_line(1_i32, 1_i32, -2_i32);
+        let result = line1 ^ line2;
+
+        assert!(!result.is_zero());
+        assert_eq!(result.e12, 0);
+        assert!((result & ProjectiveMultivector2::from_direction(1_i32, -1_i32)).is_zero());
+    }
+}
+
+
+#[cfg(test)]
+mod pga2_metric_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::pga2::ProjectiveMultivector2;
+    use core::f64;
+
+
+    #[test]
+    fn test_distance_to_point() {
+        let p = ProjectiveMultivector2::from_point(-1_f64, 2_f64);
+        let q = ProjectiveMultivector2::from_point(5_f64, -6_f64);
+
+        assert_relative_eq!(p.distance_to_point(&q), 10_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(q.distance_to_point(&p), 10_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_distance_to_point_unnormalized() {
+        let p = ProjectiveMultivector2::from_point(-1_f64, 2_f64) * -3_f64;
+        let q = ProjectiveMultivector2::from_point(5_f64, -6_f64) * 2_f64;
+
+        assert_relative_eq!(p.distance_to_point(&q), 10_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_distance_to_line_sign() {
+        let line = ProjectiveMultivector2::from_line(0_f64, 2_f64, -2_f64);
+        let above = ProjectiveMultivector2::from_point(7_f64, 4_f64);
+        let below = ProjectiveMultivector2::from_point(7_f64, -4_f64);
+
+        assert_relative_eq!(
+            above.distance_to_line(&line),
+            3_f64,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            below.distance_to_line(&line),
+            -5_f64,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_distance_to_line_unnormalized_point() {
+        let line = ProjectiveMultivector2::from_line(3_f64, 4_f64, 0_f64);
+        let point = ProjectiveMultivector2::from_point(3_f64, 4_f64) * -2_f64;
+
+        assert_relative_eq!(
+            point.distance_to_line(&line),
+            5_f64,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_angle_to_line() {
+        let line1 = ProjectiveMultivector2::from_line(1_f64, 0_f64, 0_f64);
+        let line2 = ProjectiveMultivector2::from_line(0_f64, 1_f64, -7_f64);
+        let line3 = ProjectiveMultivector2::from_line(-1_f64, 0_f64, 2_f64);
+
+        assert_relative_eq!(
+            line1.angle_to_line(&line2),
+            f64::consts::FRAC_PI_2,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            line2.angle_to_line(&line1),
+            f64::consts::FRAC_PI_2,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            line1.angle_to_line(&line3),
+            f64::consts::PI,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            line1.angle_to_line(&line1),
+            0_f64,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+}
+
+
+#[cfg(test)]
+mod pga2_motor_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::pga2::ProjectiveMultivector2;
+    use core::f64;
+
+
+    #[test]
+    fn test_rotor_point() {
+        let rotor = ProjectiveMultivector2::rotor(f64::consts::FRAC_PI_3);
+        let point = ProjectiveMultivector2::from_point(2_f64, 0_f64);
+        let expected = ProjectiveMultivector2::from_point(1_f64, 3_f64.sqrt());
+        let result = point.transform(&rotor);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotor_about_center_is_fixed() {
+        let rotor = ProjectiveMultivector2::rotor_about(3_f64, -1_f64, 1.2_f64);
+        let center = ProjectiveMultivector2::from_point(3_f64, -1_f64);
+        let result = center.transform(&rotor);
+
+        assert_relative_eq!(result, center, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotor_about_is_conjugated_rotor() {
+        let angle = 0.7_f64;
+        let translator = ProjectiveMultivector2::translator(3_f64, -1_f64);
+        let expected = translator * ProjectiveMultivector2::rotor(angle) * translator.reverse();
+        let result = ProjectiveMultivector2::rotor_about(3_f64, -1_f64, angle);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotor_line() {
+        let rotor = ProjectiveMultivector2::rotor(f64::consts::FRAC_PI_2);
+        let line = ProjectiveMultivector2::from_line(1_f64, 0_f64, -1_f64);
+        let expected = ProjectiveMultivector2::from_line(0_f64, 1_f64, -1_f64);
+        let result = line.transform(&rotor);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_translator_point() {
+        let translator = ProjectiveMultivector2::translator(-2_f64, 5_f64);
+        let point = ProjectiveMultivector2::from_point(1_f64, 1_f64);
+        let expected = ProjectiveMultivector2::from_point(-1_f64, 6_f64);
+        let result = point.transform(&translator);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_translator_direction_invariant() {
+        let translator = ProjectiveMultivector2::translator(-2_f64, 5_f64);
+        let direction = ProjectiveMultivector2::from_direction(1_f64, 1_f64);
+        let result = direction.transform(&translator);
+
+        assert_relative_eq!(result, direction, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_translator_line() {
+        let translator = ProjectiveMultivector2::translator(0_f64, 2_f64);
+        let line = ProjectiveMultivector2::from_line(0_f64, 1_f64, -1_f64);
+        let expected = ProjectiveMultivector2::from_line(0_f64, 1_f64, -3_f64);
+        let result = line.transform(&translator);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_translator_composition() {
+        let translator1 = ProjectiveMultivector2::translator(1_f64, 2_f64);
+        let translator2 = ProjectiveMultivector2::translator(-4_f64, 5_f64);
+        let expected = ProjectiveMultivector2::translator(-3_f64, 7_f64);
+        let result = translator2 * translator1;
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_motor_preserves_distance() {
+        let motor = ProjectiveMultivector2::translator(4_f64, -3_f64) * ProjectiveMultivector2::rotor_about(1_f64, 2_f64, 2.5_f64);
+        let p = ProjectiveMultivector2::from_point(-1_f64, 2_f64);
+        let q = ProjectiveMultivector2::from_point(5_f64, -6_f64);
+        let result = p.transform(&motor).distance_to_point(&q.transform(&motor));
+
+        assert_relative_eq!(result, 10_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_motor_is_unit() {
+        let motor = ProjectiveMultivector2::translator(4_f64, -3_f64) * ProjectiveMultivector2::rotor(2.5_f64);
+        let one: ProjectiveMultivector2<f64> = ProjectiveMultivector2::unit_scalar();
+
+        assert_relative_eq!(motor * motor.reverse(), one, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
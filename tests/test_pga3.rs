@@ -0,0 +1,1160 @@
+#[cfg(test)]
+mod pga3_component_tests {
+    use cggeomalg::pga3::ProjectiveMultivector3;
+
+
+    #[test]
+    fn test_components1() {
+        let mv = ProjectiveMultivector3::new(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+
+        assert_eq!(mv[0], 1);
+        assert_eq!(mv[1], 2);
+        assert_eq!(mv[2], 3);
+        assert_eq!(mv[3], 4);
+        assert_eq!(mv[4], 5);
+        assert_eq!(mv[5], 6);
+        assert_eq!(mv[6], 7);
+        assert_eq!(mv[7], 8);
+        assert_eq!(mv[8], 9);
+        assert_eq!(mv[9], 10);
+        assert_eq!(mv[10], 11);
+        assert_eq!(mv[11], 12);
+        assert_eq!(mv[12], 13);
+        assert_eq!(mv[13], 14);
+        assert_eq!(mv[14], 15);
+        assert_eq!(mv[15], 16);
+    }
+
+    #[test]
+    fn test_components2() {
+        let mv = ProjectiveMultivector3::new(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+
+        assert_eq!(mv.scalar, mv[0]);
+        assert_eq!(mv.e0, mv[1]);
+        assert_eq!(mv.e1, mv[2]);
+        assert_eq!(mv.e2, mv[3]);
+        assert_eq!(mv.e3, mv[4]);
+        assert_eq!(mv.e01, mv[5]);
+        assert_eq!(mv.e02, mv[6]);
+        assert_eq!(mv.e03, mv[7]);
+        assert_eq!(mv.e12, mv[8]);
+        assert_eq!(mv.e23, mv[9]);
+        assert_eq!(mv.e31, mv[10]);
+        assert_eq!(mv.e032, mv[11]);
+        assert_eq!(mv.e013, mv[12]);
+        assert_eq!(mv.e021, mv[13]);
+        assert_eq!(mv.e123, mv[14]);
+        assert_eq!(mv.e0123, mv[15]);
+    }
+
+    #[test]
+    fn test_as_ref() {
+        let mv = ProjectiveMultivector3::new(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+        let v_ref: &[isize; 16] = mv.as_ref();
+
+        assert_eq!(v_ref, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+    }
+
+    #[test]
+    fn test_as_mut() {
+        let mut mv = ProjectiveMultivector3::new(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+        let v_ref: &mut [isize; 16] = mv.as_mut();
+
+        assert_eq!(v_ref, &mut [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_out_of_bounds_array_access() {
+        let mv = ProjectiveMultivector3::new(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+
+        assert_eq!(mv[16], mv[16]);
+    }
+}
+
+
+#[cfg(test)]
+mod pga3_addition_subtraction_tests {
+    use cggeomalg::pga3::ProjectiveMultivector3;
+
+
+    #[test]
+    fn test_multivector_addition() {
+        let mv1 = ProjectiveMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ProjectiveMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = ProjectiveMultivector3::new(
+            4_i32, 1_i32, 7_i32, 5_i32, 0_i32, 15_i32, 9_i32, 2_i32, 14_i32, 13_i32, 6_i32, 20_i32, 22_i32, 7_i32, 24_i32, 19_i32,
+        );
+        let result = mv1 + mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_multivector_subtraction() {
+        let mv1 = ProjectiveMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ProjectiveMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = ProjectiveMultivector3::new(
+            -2_i32, 3_i32, -1_i32, 3_i32, 10_i32, -3_i32, 5_i32, 14_i32, 4_i32, 7_i32, 16_i32, 4_i32, 4_i32, 21_i32, 6_i32, 13_i32,
+        );
+        let result = mv1 - mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_multivector_minus_multivector_is_zero() {
+        let mv = ProjectiveMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let zero: ProjectiveMultivector3<i32> = ProjectiveMultivector3::zero();
+
+        assert_eq!(mv - mv, zero);
+    }
+
+    #[test]
+    fn test_scalar_minus_multivector() {
+        let mv = ProjectiveMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let expected = ProjectiveMultivector3::new(
+            4_i32, -2_i32, -3_i32, -4_i32, -5_i32, -6_i32, -7_i32, -8_i32, -9_i32, -10_i32, -11_i32, -12_i32, -13_i32, -14_i32, -15_i32,
+            -16_i32,
+        );
+        let result = 5_i32 - mv;
+
+        assert_eq!(result, expected);
+        assert_eq!(5_i32 - mv, ProjectiveMultivector3::from_scalar(5_i32) - mv);
+    }
+
+    #[test]
+    fn test_scalar_plus_multivector() {
+        let mv = ProjectiveMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+
+        assert_eq!(5_i32 + mv, mv + 5_i32);
+        assert_eq!(5_i32 + mv, ProjectiveMultivector3::from_scalar(5_i32) + mv);
+    }
+}
+
+
+#[cfg(test)]
+mod pga3_multiplication_division_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::pga3::ProjectiveMultivector3;
+
+
+    #[test]
+    fn test_scalar_multiplication() {
+        let mv = ProjectiveMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let expected = ProjectiveMultivector3::new(
+            3_i32, 6_i32, 9_i32, 12_i32, 15_i32, 18_i32, 21_i32, 24_i32, 27_i32, 30_i32, 33_i32, 36_i32, 39_i32, 42_i32, 45_i32, 48_i32,
+        );
+
+        assert_eq!(mv * 3_i32, expected);
+        assert_eq!(3_i32 * mv, expected);
+    }
+
+    #[test]
+    fn test_scalar_division() {
+        let mv = ProjectiveMultivector3::new(
+            1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64, 9_f64, 10_f64, 11_f64, 12_f64, 13_f64, 14_f64, 15_f64, 16_f64,
+        );
+        let expected = mv * (1_f64 / 9_f64);
+        let result = mv / 9_f64;
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_geometric_product_basis_squares() {
+        let zero: ProjectiveMultivector3<i32> = ProjectiveMultivector3::zero();
+        let one: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_scalar();
+
+        assert_eq!(ProjectiveMultivector3::unit_e0() * ProjectiveMultivector3::unit_e0(), zero);
+        assert_eq!(ProjectiveMultivector3::unit_e1() * ProjectiveMultivector3::unit_e1(), one);
+        assert_eq!(ProjectiveMultivector3::unit_e2() * ProjectiveMultivector3::unit_e2(), one);
+        assert_eq!(ProjectiveMultivector3::unit_e3() * ProjectiveMultivector3::unit_e3(), one);
+        assert_eq!(ProjectiveMultivector3::unit_e01() * ProjectiveMultivector3::unit_e01(), zero);
+        assert_eq!(ProjectiveMultivector3::unit_e02() * ProjectiveMultivector3::unit_e02(), zero);
+        assert_eq!(ProjectiveMultivector3::unit_e03() * ProjectiveMultivector3::unit_e03(), zero);
+        assert_eq!(ProjectiveMultivector3::unit_e12() * ProjectiveMultivector3::unit_e12(), -one);
+        assert_eq!(ProjectiveMultivector3::unit_e23() * ProjectiveMultivector3::unit_e23(), -one);
+        assert_eq!(ProjectiveMultivector3::unit_e31() * ProjectiveMultivector3::unit_e31(), -one);
+        assert_eq!(ProjectiveMultivector3::unit_e032() * ProjectiveMultivector3::unit_e032(), zero);
+        assert_eq!(ProjectiveMultivector3::unit_e013() * ProjectiveMultivector3::unit_e013(), zero);
+        assert_eq!(ProjectiveMultivector3::unit_e021() * ProjectiveMultivector3::unit_e021(), zero);
+        assert_eq!(ProjectiveMultivector3::unit_e123() * ProjectiveMultivector3::unit_e123(), -one);
+        assert_eq!(ProjectiveMultivector3::unit_e0123() * ProjectiveMultivector3::unit_e0123(), zero);
+    }
+
+    #[test]
+    fn test_geometric_product_e0_anticommutes() {
+        let e0: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e0();
+        let e1: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e1();
+        let e01: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e01();
+
+        assert_eq!(e0 * e1, e01);
+        assert_eq!(e1 * e0, -e01);
+    }
+
+    #[test]
+    fn test_geometric_product_pseudoscalar_squares_to_zero() {
+        let e0123: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e0123();
+        let zero: ProjectiveMultivector3<i32> = ProjectiveMultivector3::zero();
+
+        assert_eq!(e0123 * e0123, zero);
+    }
+
+    #[test]
+    fn test_geometric_product() {
+        let mv1 = ProjectiveMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ProjectiveMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = ProjectiveMultivector3::new(
+            -161_i32, 49_i32, -103_i32, -97_i32, -85_i32, -49_i32, -22_i32, 232_i32, 72_i32, -5_i32, 137_i32, 106_i32, -91_i32, 56_i32,
+            74_i32, 199_i32,
+        );
+        let result = mv1 * mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_geometric_product_references() {
+        let mv1 = ProjectiveMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ProjectiveMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = mv1 * mv2;
+
+        assert_eq!(&mv1 * mv2, expected);
+        assert_eq!(mv1 * &mv2, expected);
+        assert_eq!(&mv1 * &mv2, expected);
+    }
+
+    #[test]
+    fn test_geometric_product_associative() {
+        let mv1 = ProjectiveMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ProjectiveMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let mv3 = ProjectiveMultivector3::new(
+            2_i32, 0_i32, -1_i32, 3_i32, 1_i32, -2_i32, 4_i32, 0_i32, 1_i32, -3_i32, 2_i32, 5_i32, -1_i32, 0_i32, 2_i32, -4_i32,
+        );
+
+        assert_eq!((mv1 * mv2) * mv3, mv1 * (mv2 * mv3));
+    }
+}
+
+
+#[cfg(test)]
+mod pga3_outer_product_tests {
+    use cggeomalg::pga3::ProjectiveMultivector3;
+
+
+    #[test]
+    fn test_outer_product_e1_e2() {
+        let e1: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e1();
+        let e2: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e2();
+        let e12: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e12();
+
+        assert_eq!(e1 ^ e2, e12);
+        assert_eq!(e2 ^ e1, -e12);
+    }
+
+    #[test]
+    fn test_outer_product_e0_e0() {
+        let e0: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e0();
+        let zero: ProjectiveMultivector3<i32> = ProjectiveMultivector3::zero();
+
+        assert_eq!(e0 ^ e0, zero);
+    }
+
+    #[test]
+    fn test_outer_product_vectors_pseudoscalar() {
+        let e0: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e0();
+        let e1: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e1();
+        let e2: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e2();
+        let e3: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e3();
+        let e0123: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e0123();
+
+        assert_eq!(e0 ^ e1 ^ e2 ^ e3, e0123);
+    }
+
+    #[test]
+    fn test_outer_product() {
+        let mv1 = ProjectiveMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ProjectiveMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = ProjectiveMultivector3::new(
+            3_i32, 5_i32, 13_i32, 13_i32, 10_i32, 38_i32, 29_i32, 13_i32, 19_i32, 8_i32, 63_i32, 57_i32, 70_i32, 26_i32, 74_i32, 199_i32,
+        );
+        let result = mv1 ^ mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_outer_product_references() {
+        let mv1 = ProjectiveMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ProjectiveMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = mv1 ^ mv2;
+
+        assert_eq!(&mv1 ^ mv2, expected);
+        assert_eq!(mv1 ^ &mv2, expected);
+        assert_eq!(&mv1 ^ &mv2, expected);
+        assert_eq!(mv1.outer_product(&mv2), expected);
+    }
+}
+
+
+#[cfg(test)]
+mod pga3_grade_tests {
+    use cggeomalg::pga3::ProjectiveMultivector3;
+
+
+    #[test]
+    fn test_grade_decomposition() {
+        let mv = ProjectiveMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let result = mv.grade(0) + mv.grade(1) + mv.grade(2) + mv.grade(3) + mv.grade(4);
+
+        assert_eq!(result, mv);
+    }
+
+    #[test]
+    fn test_grade_projection() {
+        let mv = ProjectiveMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+
+        assert_eq!(
+            mv.grade(0),
+            ProjectiveMultivector3::new(
+                1_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32,
+            )
+        );
+        assert_eq!(
+            mv.grade(2),
+            ProjectiveMultivector3::new(
+                0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32,
+            )
+        );
+        assert_eq!(
+            mv.grade(4),
+            ProjectiveMultivector3::new(
+                0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 16_i32,
+            )
+        );
+        assert!(mv.grade(5).is_zero());
+    }
+
+    #[test]
+    fn test_reverse_reverse() {
+        let mv = ProjectiveMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+
+        assert_eq!(mv.reverse().reverse(), mv);
+    }
+
+    #[test]
+    fn test_reverse_product() {
+        let mv1 = ProjectiveMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ProjectiveMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+
+        assert_eq!((mv1 * mv2).reverse(), mv2.reverse() * mv1.reverse());
+    }
+
+    #[test]
+    fn test_involute_product() {
+        let mv1 = ProjectiveMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ProjectiveMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+
+        assert_eq!((mv1 * mv2).involute(), mv1.involute() * mv2.involute());
+    }
+
+    #[test]
+    fn test_conjugate_is_reverse_of_involute() {
+        let mv = ProjectiveMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+
+        assert_eq!(mv.conjugate(), mv.involute().reverse());
+    }
+}
+
+
+#[cfg(test)]
+mod pga3_scalar_product_tests {
+    use cggeomalg::pga3::ProjectiveMultivector3;
+
+
+    #[test]
+    fn test_scalar_product_null_vector() {
+        let e0: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e0();
+        let e01: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e01();
+        let zero: ProjectiveMultivector3<i32> = ProjectiveMultivector3::zero();
+
+        assert_eq!(e0 | e0, zero);
+        assert_eq!(e01 | e01, zero);
+    }
+
+    #[test]
+    fn test_scalar_product_is_scalar_part_of_geometric_product() {
+        let mv1 = ProjectiveMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ProjectiveMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = (mv1 * mv2.reverse()).grade(0);
+        let result = mv1 | mv2;
+
+        assert_eq!(result, expected);
+        assert_eq!(result, ProjectiveMultivector3::from_scalar(149_i32));
+    }
+
+    #[test]
+    fn test_scalar_product_references() {
+        let mv1 = ProjectiveMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ProjectiveMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = mv1 | mv2;
+
+        assert_eq!(&mv1 | mv2, expected);
+        assert_eq!(mv1 | &mv2, expected);
+        assert_eq!(&mv1 | &mv2, expected);
+        assert_eq!(mv1.scalar_product(&mv2), expected);
+    }
+}
+
+
+#[cfg(test)]
+mod pga3_left_contraction_tests {
+    use cggeomalg::pga3::ProjectiveMultivector3;
+
+
+    #[test]
+    fn test_left_contraction_vector_bivector() {
+        let e1: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e1();
+        let e2: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e2();
+        let e12: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e12();
+        let e0: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e0();
+        let e01: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e01();
+        let zero: ProjectiveMultivector3<i32> = ProjectiveMultivector3::zero();
+
+        assert_eq!(e1 << e12, e2);
+        assert_eq!(e2 << e12, -e1);
+        assert_eq!(e0 << e01, zero);
+        assert_eq!(e1 << e01, -e0);
+    }
+
+    #[test]
+    fn test_left_contraction_higher_grade_is_zero() {
+        let e12: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e12();
+        let e1: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e1();
+        let zero: ProjectiveMultivector3<i32> = ProjectiveMultivector3::zero();
+
+        assert_eq!(e12 << e1, zero);
+    }
+
+    #[test]
+    fn test_left_contraction() {
+        let mv1 = ProjectiveMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ProjectiveMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = ProjectiveMultivector3::new(
+            -161_i32, 155_i32, -131_i32, -98_i32, -59_i32, 52_i32, -92_i32, -28_i32, 50_i32, 30_i32, 31_i32, 17_i32, 21_i32, 8_i32, 9_i32,
+            3_i32,
+        );
+        let result = mv1 << mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_left_contraction_references() {
+        let mv1 = ProjectiveMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ProjectiveMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = mv1 << mv2;
+
+        assert_eq!(&mv1 << mv2, expected);
+        assert_eq!(mv1 << &mv2, expected);
+        assert_eq!(&mv1 << &mv2, expected);
+        assert_eq!(mv1.left_contract(&mv2), expected);
+    }
+}
+
+
+#[cfg(test)]
+mod pga3_right_contraction_tests {
+    use cggeomalg::pga3::ProjectiveMultivector3;
+
+
+    #[test]
+    fn test_right_contraction_bivector_vector() {
+        let e1: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e1();
+        let e2: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e2();
+        let e12: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e12();
+        let zero: ProjectiveMultivector3<i32> = ProjectiveMultivector3::zero();
+
+        assert_eq!(e12 >> e2, e1);
+        assert_eq!(e12 >> e1, -e2);
+        assert_eq!(e1 >> e12, zero);
+    }
+
+    #[test]
+    fn test_right_contraction_reverse() {
+        let mv1 = ProjectiveMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ProjectiveMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+
+        assert_eq!((mv1 >> mv2).reverse(), mv2.reverse() << mv1.reverse());
+    }
+
+    #[test]
+    fn test_right_contraction() {
+        let mv1 = ProjectiveMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ProjectiveMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = ProjectiveMultivector3::new(
+            -161_i32, -106_i32, 28_i32, 1_i32, -26_i32, -109_i32, 217_i32, -96_i32, -48_i32, 90_i32, 48_i32, -28_i32, 23_i32, 122_i32,
+            45_i32, 48_i32,
+        );
+        let result = mv1 >> mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_right_contraction_references() {
+        let mv1 = ProjectiveMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ProjectiveMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = mv1 >> mv2;
+
+        assert_eq!(&mv1 >> mv2, expected);
+        assert_eq!(mv1 >> &mv2, expected);
+        assert_eq!(&mv1 >> &mv2, expected);
+        assert_eq!(mv1.right_contract(&mv2), expected);
+    }
+}
+
+
+#[cfg(test)]
+mod pga3_duality_tests {
+    use cggeomalg::pga3::ProjectiveMultivector3;
+
+
+    #[test]
+    fn test_dual_basis_blades() {
+        let e0123: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e0123();
+
+        let e = ProjectiveMultivector3::<i32>::unit_scalar();
+        assert_eq!(e ^ e.dual(), e0123);
+        let e = ProjectiveMultivector3::<i32>::unit_e0();
+        assert_eq!(e ^ e.dual(), e0123);
+        let e = ProjectiveMultivector3::<i32>::unit_e1();
+        assert_eq!(e ^ e.dual(), e0123);
+        let e = ProjectiveMultivector3::<i32>::unit_e2();
+        assert_eq!(e ^ e.dual(), e0123);
+        let e = ProjectiveMultivector3::<i32>::unit_e3();
+        assert_eq!(e ^ e.dual(), e0123);
+        let e = ProjectiveMultivector3::<i32>::unit_e01();
+        assert_eq!(e ^ e.dual(), e0123);
+        let e = ProjectiveMultivector3::<i32>::unit_e02();
+        assert_eq!(e ^ e.dual(), e0123);
+        let e = ProjectiveMultivector3::<i32>::unit_e03();
+        assert_eq!(e ^ e.dual(), e0123);
+        let e = ProjectiveMultivector3::<i32>::unit_e12();
+        assert_eq!(e ^ e.dual(), e0123);
+        let e = ProjectiveMultivector3::<i32>::unit_e23();
+        assert_eq!(e ^ e.dual(), e0123);
+        let e = ProjectiveMultivector3::<i32>::unit_e31();
+        assert_eq!(e ^ e.dual(), e0123);
+        let e = ProjectiveMultivector3::<i32>::unit_e032();
+        assert_eq!(e ^ e.dual(), e0123);
+        let e = ProjectiveMultivector3::<i32>::unit_e013();
+        assert_eq!(e ^ e.dual(), e0123);
+        let e = ProjectiveMultivector3::<i32>::unit_e021();
+        assert_eq!(e ^ e.dual(), e0123);
+        let e = ProjectiveMultivector3::<i32>::unit_e123();
+        assert_eq!(e ^ e.dual(), e0123);
+        let e = ProjectiveMultivector3::<i32>::unit_e0123();
+        assert_eq!(e ^ e.dual(), e0123);
+    }
+
+    #[test]
+    fn test_dual_undual() {
+        let mv = ProjectiveMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+
+        assert_eq!(mv.dual().undual(), mv);
+        assert_eq!(mv.undual().dual(), mv);
+    }
+
+    #[test]
+    fn test_not_is_dual() {
+        let mv = ProjectiveMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+
+        assert_eq!(!mv, mv.dual());
+        assert_eq!(!&mv, mv.dual());
+    }
+
+    #[test]
+    fn test_dual_mut() {
+        let mv = ProjectiveMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mut result = mv;
+        result.dual_mut();
+
+        assert_eq!(result, mv.dual());
+    }
+
+    #[test]
+    fn test_dual_plane_is_point() {
+        let plane: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e0();
+        let origin = ProjectiveMultivector3::from_point(0_i32, 0_i32, 0_i32);
+
+        assert_eq!(plane.dual(), origin);
+    }
+}
+
+
+#[cfg(test)]
+mod pga3_regressive_product_tests {
+    use cggeomalg::pga3::ProjectiveMultivector3;
+
+
+    #[test]
+    fn test_regressive_product() {
+        let mv1 = ProjectiveMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ProjectiveMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = ProjectiveMultivector3::new(
+            199_i32, -115_i32, -31_i32, 281_i32, 18_i32, -55_i32, 249_i32, -68_i32, -124_i32, 90_i32, -29_i32, 164_i32, 183_i32, -70_i32,
+            189_i32, 48_i32,
+        );
+        let result = mv1 & mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_regressive_product_references() {
+        let mv1 = ProjectiveMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ProjectiveMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = mv1 & mv2;
+
+        assert_eq!(&mv1 & mv2, expected);
+        assert_eq!(mv1 & &mv2, expected);
+        assert_eq!(&mv1 & &mv2, expected);
+        assert_eq!(mv1.regressive_product(&mv2), expected);
+    }
+
+    #[test]
+    fn test_regressive_product_dual_outer_product() {
+        let mv1 = ProjectiveMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = ProjectiveMultivector3::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = (mv1.dual() ^ mv2.dual()).undual();
+        let result = mv1 & mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_regressive_product_pseudoscalar_unit() {
+        let e0123: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e0123();
+        let mv = ProjectiveMultivector3::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+
+        assert_eq!(e0123 & mv, mv);
+        assert_eq!(mv & e0123, mv);
+    }
+}
+
+
+#[cfg(test)]
+mod pga3_incidence_tests {
+    use cggeomalg::pga3::ProjectiveMultivector3;
+
+
+    #[test]
+    fn test_point_on_plane() {
+        let plane = ProjectiveMultivector3::from_plane(1_i32, 2_i32, 3_i32, -14_i32);
+        let on_plane = ProjectiveMultivector3::from_point(1_i32, 2_i32, 3_i32);
+        let off_plane = ProjectiveMultivector3::from_point(1_i32, 2_i32, 4_i32);
+        let e0123: ProjectiveMultivector3<i32> = ProjectiveMultivector3::unit_e0123();
+
+        assert!((plane ^ on_plane).is_zero());
+        assert_eq!(plane ^ off_plane, e0123 * 3_i32);
+    }
+
+    #[test]
+    fn test_join_points_line() {
+        let p = ProjectiveMultivector3::from_point(1_i32, 2_i32, 3_i32);
+        let q = ProjectiveMultivector3::from_point(2_i32, 4_i32, 6_i32);
+        let line = p.join(&q);
+        let expected = ProjectiveMultivector3::from_line(1_i32, 2_i32, 3_i32, 1_i32, 2_i32, 3_i32);
+
+        assert_eq!(line, expected);
+        assert!((line & ProjectiveMultivector3::from_point(3_i32, 6_i32, 9_i32)).is_zero());
+        assert!(!(line & ProjectiveMultivector3::from_point(3_i32, 6_i32, 8_i32)).is_zero());
+    }
+
+    #[test]
+    fn test_join_points_plane() {
+        let p = ProjectiveMultivector3::from_point(1_i32, 0_i32, 0_i32);
+        let q = ProjectiveMultivector3::from_point(0_i32, 1_i32, 0_i32);
+        let r = ProjectiveMultivector3::from_point(0_i32, 0_i32, 1_i32);
+        let plane = p & q & r;
+        let expected = ProjectiveMultivector3::from_plane(1_i32, 1_i32, 1_i32, -1_i32);
+
+        assert_eq!(plane, expected);
+    }
+
+    #[test]
+    fn test_meet_planes_line() {
+        let plane_x = ProjectiveMultivector3::from_plane(1_i32, 0_i32, 0_i32, -1_i32);
+        let plane_y = ProjectiveMultivector3::from_plane(0_i32, 1_i32, 0_i32, -2_i32);
+        let line = plane_x.meet(&plane_y);
+        let expected = ProjectiveMultivector3::from_line(1_i32, 2_i32, 0_i32, 0_i32, 0_i32, 1_i32);
+
+        assert_eq!(line, expected);
+    }
+
+    #[test]
+    fn test_meet_planes_point() {
+        let plane_x = ProjectiveMultivector3::from_plane(1_i32, 0_i32, 0_i32, -1_i32);
+        let plane_y = ProjectiveMultivector3::from_plane(0_i32, 1_i32, 0_i32, -2_i32);
+        let plane_z = ProjectiveMultivector3::from_plane(0_i32, 0_i32, 1_i32, -3_i32);
+        let expected = ProjectiveMultivector3::from_point(1_i32, 2_i32, 3_i32);
+        let result = plane_x ^ plane_y ^ plane_z;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_meet_parallel_planes_is_ideal_line() {
+        let plane1 = ProjectiveMultivector3::from_plane(0_i32, 0_i32, 1_i32, -1_i32);
+        let plane2 = ProjectiveMultivector3::from_plane(0_i32, 0_i32, 1_i32, -2_i32);
+        let line = plane1 ^ plane2;
+
+        assert!(!line.is_zero());
+        assert_eq!(line.e12, 0);
+        assert_eq!(line.e23, 0);
+        assert_eq!(line.e31, 0);
+    }
+
+    #[test]
+    fn test_meet_line_plane() {
+        let line = ProjectiveMultivector3::from_line(1_i32, 1_i32, 0_i32, 0_i32, 0_i32, 1_i32);
+        let plane = ProjectiveMultivector3::from_plane(0_i32, 0_i32, 1_i32, -5_i32);
+        let expected = ProjectiveMultivector3::from_point(1_i32, 1_i32, 5_i32);
+        let result = line ^ plane;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_meet_join_direction() {
+        let direction = ProjectiveMultivector3::from_direction(1_i32, 0_i32, 0_i32);
+        let line = ProjectiveMultivector3::from_line(0_i32, 1_i32, 0_i32, 1_i32, 0_i32, 0_i32);
+
+        // Parallel lines meet in the common point at infinity.
+        assert!((line & direction).is_zero());
+    }
+}
+
+
+#[cfg(test)]
+mod pga3_transform_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::pga3::ProjectiveMultivector3;
+
+
+    #[test]
+    fn test_translator_point() {
+        let translator = ProjectiveMultivector3::translator(1_f64, -2_f64, 3_f64);
+        let point = ProjectiveMultivector3::from_point(4_f64, 5_f64, 6_f64);
+        let expected = ProjectiveMultivector3::from_point(5_f64, 3_f64, 9_f64);
+        let result = point.transform(&translator);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_translator_direction_invariant() {
+        let translator = ProjectiveMultivector3::translator(1_f64, -2_f64, 3_f64);
+        let direction = ProjectiveMultivector3::from_direction(4_f64, 5_f64, 6_f64);
+        let result = direction.transform(&translator);
+
+        assert_relative_eq!(result, direction, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_translator_line() {
+        let translator = ProjectiveMultivector3::translator(0_f64, 3_f64, 0_f64);
+        let line = ProjectiveMultivector3::from_line(0_f64, 0_f64, 0_f64, 1_f64, 0_f64, 0_f64);
+        let expected = ProjectiveMultivector3::from_line(0_f64, 3_f64, 0_f64, 1_f64, 0_f64, 0_f64);
+        let result = line.transform(&translator);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_translator_composition() {
+        let translator1 = ProjectiveMultivector3::translator(1_f64, 2_f64, 3_f64);
+        let translator2 = ProjectiveMultivector3::translator(-4_f64, 5_f64, 0_f64);
+        let expected = ProjectiveMultivector3::translator(-3_f64, 7_f64, 3_f64);
+        let result = translator2 * translator1;
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotor_point() {
+        let angle = core::f64::consts::FRAC_PI_2;
+        let rotor = ProjectiveMultivector3::from_scalar((angle / 2_f64).cos()) - ProjectiveMultivector3::unit_e12() * (angle / 2_f64).sin();
+        let point = ProjectiveMultivector3::from_point(1_f64, 0_f64, 7_f64);
+        let expected = ProjectiveMultivector3::from_point(0_f64, 1_f64, 7_f64);
+        let result = point.transform(&rotor);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_magnitude_ignores_null_components() {
+        let mv = ProjectiveMultivector3::new(
+            0_f64, 5_f64, 3_f64, 4_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64,
+        );
+
+        assert_relative_eq!(mv.magnitude(), 5_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(
+            mv.normalize().magnitude(),
+            1_f64,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+}
+
+
+#[cfg(test)]
+mod pga3_motor_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::e3ga::{
+        EuclideanMultivector3,
+        Rotor3,
+    };
+    use cggeomalg::pga3::{
+        Motor3,
+        ProjectiveMultivector3,
+    };
+    use core::f64;
+
+
+    fn screw_bivector() -> ProjectiveMultivector3<f64> {
+        ProjectiveMultivector3::new(
+            0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0.7_f64, -0.3_f64, 1.1_f64, 0.2_f64, -0.5_f64, 0.4_f64, 0_f64, 0_f64, 0_f64, 0_f64,
+            0_f64,
+        )
+    }
+
+    fn screw_motor() -> Motor3<f64> {
+        Motor3::exp(&screw_bivector())
+    }
+
+    #[test]
+    fn test_identity_transform_point() {
+        let identity: Motor3<f64> = Motor3::identity();
+        let point = ProjectiveMultivector3::from_point(3_f64, -5_f64, 7_f64);
+        let result = identity.transform_point(&point);
+
+        assert_eq!(result, point);
+    }
+
+    #[test]
+    fn test_from_translation_matches_translator() {
+        let motor = Motor3::from_translation(1_f64, -2_f64, 3_f64);
+        let expected = ProjectiveMultivector3::translator(1_f64, -2_f64, 3_f64);
+        let result = ProjectiveMultivector3::from(motor);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_from_rotor_matches_rotor3() {
+        let e23: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e23();
+        let rotor = Rotor3::from_bivector_angle(&e23, 0.8_f64);
+        let motor = Motor3::from_rotor(&EuclideanMultivector3::from(rotor));
+        let vector = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let rotated = rotor.rotate_vector(&vector);
+        let expected = ProjectiveMultivector3::from_point(rotated[1], rotated[2], rotated[3]);
+        let result = motor.transform_point(&ProjectiveMultivector3::from_point(1_f64, 2_f64, 3_f64));
+
+        assert_eq!(motor, Motor3::from(rotor));
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_composition_matches_geometric_product() {
+        let motor1 = screw_motor();
+        let motor2 = Motor3::from_translation(1_f64, 2_f64, 3_f64) * Motor3::exp(&(ProjectiveMultivector3::unit_e31() * 0.3_f64));
+        let expected = ProjectiveMultivector3::from(motor1) * ProjectiveMultivector3::from(motor2);
+        let result = ProjectiveMultivector3::from(motor1 * motor2);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_composition_applies_right_motor_first() {
+        let translation = Motor3::from_translation(1_f64, 0_f64, 0_f64);
+        let rotation = Motor3::exp(&(ProjectiveMultivector3::unit_e12() * (-f64::consts::FRAC_PI_4)));
+        let point = ProjectiveMultivector3::from_point(1_f64, 0_f64, 0_f64);
+        let expected = ProjectiveMultivector3::from_point(1_f64, 1_f64, 0_f64);
+        let result = (translation * rotation).transform_point(&point);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_transform_point_matches_sandwich() {
+        let motor = screw_motor();
+        let point = ProjectiveMultivector3::from_point(1_f64, -2_f64, 4_f64);
+        let expected = point.transform(&ProjectiveMultivector3::from(motor));
+        let result = motor.transform_point(&point);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_transform_line_matches_sandwich() {
+        let motor = screw_motor();
+        let line = ProjectiveMultivector3::from_line(1_f64, -2_f64, 4_f64, 0.5_f64, 1_f64, -1_f64);
+        let expected = line.transform(&ProjectiveMultivector3::from(motor));
+        let result = motor.transform_line(&line);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_transform_plane_matches_sandwich() {
+        let motor = screw_motor();
+        let plane = ProjectiveMultivector3::from_plane(1_f64, 2_f64, -2_f64, 3_f64);
+        let expected = plane.transform(&ProjectiveMultivector3::from(motor));
+        let result = motor.transform_plane(&plane);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_transform_preserves_incidence() {
+        let motor = screw_motor();
+        let point1 = ProjectiveMultivector3::from_point(1_f64, 2_f64, 3_f64);
+        let point2 = ProjectiveMultivector3::from_point(-1_f64, 0_f64, 5_f64);
+        let line = point1.join(&point2);
+        let expected = motor.transform_point(&point1).join(&motor.transform_point(&point2));
+        let result = motor.transform_line(&line);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_exp_is_unit() {
+        let motor = screw_motor();
+        let identity: Motor3<f64> = Motor3::identity();
+        let result = motor * motor.reverse();
+
+        assert_relative_eq!(result, identity, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_exp_pure_translation() {
+        let bivector = ProjectiveMultivector3::translator(2_f64, 4_f64, 6_f64) - ProjectiveMultivector3::from_scalar(1_f64);
+        let expected = Motor3::from_translation(2_f64, 4_f64, 6_f64);
+        let result = Motor3::exp(&bivector);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_exp_log_round_trip() {
+        let bivector = screw_bivector();
+        let result = Motor3::exp(&bivector).log();
+
+        assert_relative_eq!(result, bivector, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_log_exp_round_trip() {
+        let motor = Motor3::from_translation(1_f64, 2_f64, 3_f64) * Motor3::exp(&(ProjectiveMultivector3::unit_e23() * 1.2_f64));
+        let result = Motor3::exp(&motor.log());
+
+        assert_relative_eq!(result, motor, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_log_pure_translation() {
+        let motor = Motor3::from_translation(2_f64, 4_f64, 6_f64);
+        let expected = ProjectiveMultivector3::translator(2_f64, 4_f64, 6_f64) - ProjectiveMultivector3::from_scalar(1_f64);
+        let result = motor.log();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_sclerp_endpoints() {
+        let motor1 = Motor3::from_translation(1_f64, 0_f64, 0_f64);
+        let motor2 = screw_motor();
+        let result0 = motor1.sclerp(&motor2, 0_f64);
+        let result1 = motor1.sclerp(&motor2, 1_f64);
+
+        assert_relative_eq!(result0, motor1, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result1, motor2, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_sclerp_midpoint_screw() {
+        let identity: Motor3<f64> = Motor3::identity();
+        let bivector = screw_bivector();
+        let motor = Motor3::exp(&bivector);
+        let expected = Motor3::exp(&(bivector * 0.5_f64));
+        let result = identity.sclerp(&motor, 0.5_f64);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_sclerp_shortest_path() {
+        let identity: Motor3<f64> = Motor3::identity();
+        let bivector = screw_bivector();
+        let motor = Motor3::exp(&bivector);
+        let expected = Motor3::exp(&(bivector * 0.5_f64));
+        let result = identity.sclerp(&(-motor), 0.5_f64);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_to_matrix_transforms_points() {
+        let motor = screw_motor();
+        let matrix = motor.to_matrix();
+        let (x, y, z) = (1_f64, -2_f64, 4_f64);
+        let expected = motor.transform_point(&ProjectiveMultivector3::from_point(x, y, z));
+        let result = ProjectiveMultivector3::from_point(
+            matrix[0][0] * x + matrix[1][0] * y + matrix[2][0] * z + matrix[3][0],
+            matrix[0][1] * x + matrix[1][1] * y + matrix[2][1] * z + matrix[3][1],
+            matrix[0][2] * x + matrix[1][2] * y + matrix[2][2] * z + matrix[3][2],
+        );
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_matrix_round_trip() {
+        let motor = screw_motor();
+        let result = Motor3::from_matrix(&motor.to_matrix());
+        let result = if result[0] * motor[0] < 0_f64 { -result } else { result };
+
+        assert_relative_eq!(result, motor, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_normalize() {
+        let motor = Motor3::new(1_f64, 2_f64, -3_f64, 4_f64, 0.5_f64, -6_f64, 7_f64, 8_f64);
+        let identity: Motor3<f64> = Motor3::identity();
+        let normalized = motor.normalize();
+        let result = normalized * normalized.reverse();
+
+        assert_relative_eq!(result, identity, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_normalize_unit_motor() {
+        let motor = screw_motor();
+        let result = motor.normalize();
+
+        assert_relative_eq!(result, motor, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_inverse() {
+        let motor = screw_motor();
+        let point = ProjectiveMultivector3::from_point(1_f64, 2_f64, 3_f64);
+        let result = motor.inverse().transform_point(&motor.transform_point(&point));
+
+        assert_relative_eq!(result, point, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
@@ -0,0 +1,1308 @@
+#[cfg(test)]
+mod sta_component_tests {
+    use cggeomalg::sta::SpacetimeMultivector;
+
+
+    #[test]
+    fn test_components1() {
+        let mv = SpacetimeMultivector::new(
+            1_isize, 2_isize, 3_isize, 4_isize, 5_isize, 6_isize, 7_isize, 8_isize, 9_isize, 10_isize, 11_isize, 12_isize, 13_isize,
+            14_isize, 15_isize, 16_isize,
+        );
+
+        assert_eq!(mv[0], 1);
+        assert_eq!(mv[1], 2);
+        assert_eq!(mv[2], 3);
+        assert_eq!(mv[3], 4);
+        assert_eq!(mv[4], 5);
+        assert_eq!(mv[5], 6);
+        assert_eq!(mv[6], 7);
+        assert_eq!(mv[7], 8);
+        assert_eq!(mv[8], 9);
+        assert_eq!(mv[9], 10);
+        assert_eq!(mv[10], 11);
+        assert_eq!(mv[11], 12);
+        assert_eq!(mv[12], 13);
+        assert_eq!(mv[13], 14);
+        assert_eq!(mv[14], 15);
+        assert_eq!(mv[15], 16);
+    }
+
+    #[test]
+    fn test_components2() {
+        let mv = SpacetimeMultivector::new(
+            1_isize, 2_isize, 3_isize, 4_isize, 5_isize, 6_isize, 7_isize, 8_isize, 9_isize, 10_isize, 11_isize, 12_isize, 13_isize,
+            14_isize, 15_isize, 16_isize,
+        );
+
+        assert_eq!(mv.scalar, mv[0]);
+        assert_eq!(mv.gamma0, mv[1]);
+        assert_eq!(mv.gamma1, mv[2]);
+        assert_eq!(mv.gamma2, mv[3]);
+        assert_eq!(mv.gamma3, mv[4]);
+        assert_eq!(mv.gamma01, mv[5]);
+        assert_eq!(mv.gamma02, mv[6]);
+        assert_eq!(mv.gamma03, mv[7]);
+        assert_eq!(mv.gamma12, mv[8]);
+        assert_eq!(mv.gamma13, mv[9]);
+        assert_eq!(mv.gamma23, mv[10]);
+        assert_eq!(mv.gamma012, mv[11]);
+        assert_eq!(mv.gamma013, mv[12]);
+        assert_eq!(mv.gamma023, mv[13]);
+        assert_eq!(mv.gamma123, mv[14]);
+        assert_eq!(mv.gamma0123, mv[15]);
+    }
+
+    #[test]
+    fn test_as_ref() {
+        let mv = SpacetimeMultivector::new(
+            1_isize, 2_isize, 3_isize, 4_isize, 5_isize, 6_isize, 7_isize, 8_isize, 9_isize, 10_isize, 11_isize, 12_isize, 13_isize,
+            14_isize, 15_isize, 16_isize,
+        );
+        let v_ref: &[isize; 16] = mv.as_ref();
+
+        assert_eq!(v_ref, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+    }
+
+    #[test]
+    fn test_as_mut() {
+        let mut mv = SpacetimeMultivector::new(
+            1_isize, 2_isize, 3_isize, 4_isize, 5_isize, 6_isize, 7_isize, 8_isize, 9_isize, 10_isize, 11_isize, 12_isize, 13_isize,
+            14_isize, 15_isize, 16_isize,
+        );
+        let v_ref: &mut [isize; 16] = mv.as_mut();
+
+        assert_eq!(v_ref, &mut [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_out_of_bounds_array_access() {
+        let mv = SpacetimeMultivector::new(
+            1_isize, 2_isize, 3_isize, 4_isize, 5_isize, 6_isize, 7_isize, 8_isize, 9_isize, 10_isize, 11_isize, 12_isize, 13_isize,
+            14_isize, 15_isize, 16_isize,
+        );
+
+        assert_eq!(mv[16], mv[16]);
+    }
+}
+
+
+#[cfg(test)]
+mod sta_addition_subtraction_tests {
+    use cggeomalg::sta::SpacetimeMultivector;
+
+
+    #[test]
+    fn test_multivector_addition() {
+        let mv1 = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = SpacetimeMultivector::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = SpacetimeMultivector::new(
+            4_i32, 1_i32, 7_i32, 5_i32, 0_i32, 15_i32, 9_i32, 2_i32, 14_i32, 13_i32, 6_i32, 20_i32, 22_i32, 7_i32, 24_i32, 19_i32,
+        );
+        let result = mv1 + mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_multivector_subtraction() {
+        let mv1 = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = SpacetimeMultivector::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = SpacetimeMultivector::new(
+            -2_i32, 3_i32, -1_i32, 3_i32, 10_i32, -3_i32, 5_i32, 14_i32, 4_i32, 7_i32, 16_i32, 4_i32, 4_i32, 21_i32, 6_i32, 13_i32,
+        );
+        let result = mv1 - mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_multivector_minus_multivector_is_zero() {
+        let mv = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let zero: SpacetimeMultivector<i32> = SpacetimeMultivector::zero();
+
+        assert_eq!(mv - mv, zero);
+    }
+
+    #[test]
+    fn test_scalar_minus_multivector() {
+        let mv = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let expected = SpacetimeMultivector::new(
+            4_i32, -2_i32, -3_i32, -4_i32, -5_i32, -6_i32, -7_i32, -8_i32, -9_i32, -10_i32, -11_i32, -12_i32, -13_i32, -14_i32, -15_i32,
+            -16_i32,
+        );
+        let result = 5_i32 - mv;
+
+        assert_eq!(result, expected);
+        assert_eq!(5_i32 - mv, SpacetimeMultivector::from_scalar(5_i32) - mv);
+    }
+
+    #[test]
+    fn test_scalar_plus_multivector() {
+        let mv = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+
+        assert_eq!(5_i32 + mv, mv + 5_i32);
+        assert_eq!(5_i32 + mv, SpacetimeMultivector::from_scalar(5_i32) + mv);
+    }
+}
+
+
+#[cfg(test)]
+mod sta_multiplication_division_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::sta::SpacetimeMultivector;
+
+
+    #[test]
+    fn test_scalar_multiplication() {
+        let mv = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let expected = SpacetimeMultivector::new(
+            3_i32, 6_i32, 9_i32, 12_i32, 15_i32, 18_i32, 21_i32, 24_i32, 27_i32, 30_i32, 33_i32, 36_i32, 39_i32, 42_i32, 45_i32, 48_i32,
+        );
+
+        assert_eq!(mv * 3_i32, expected);
+        assert_eq!(3_i32 * mv, expected);
+    }
+
+    #[test]
+    fn test_scalar_division() {
+        let mv = SpacetimeMultivector::new(
+            1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64, 9_f64, 10_f64, 11_f64, 12_f64, 13_f64, 14_f64, 15_f64, 16_f64,
+        );
+        let expected = mv * (1_f64 / 9_f64);
+        let result = mv / 9_f64;
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_geometric_product_basis_vector_squares() {
+        let one: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_scalar();
+
+        assert_eq!(SpacetimeMultivector::unit_gamma0() * SpacetimeMultivector::unit_gamma0(), one);
+        assert_eq!(SpacetimeMultivector::unit_gamma1() * SpacetimeMultivector::unit_gamma1(), -one);
+        assert_eq!(SpacetimeMultivector::unit_gamma2() * SpacetimeMultivector::unit_gamma2(), -one);
+        assert_eq!(SpacetimeMultivector::unit_gamma3() * SpacetimeMultivector::unit_gamma3(), -one);
+    }
+
+    #[test]
+    fn test_geometric_product_anticommuting_basis_vectors() {
+        let gamma0: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma0();
+        let gamma3: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma3();
+        let gamma03: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma03();
+
+        assert_eq!(gamma0 * gamma3, gamma03);
+        assert_eq!(gamma3 * gamma0, -gamma03);
+    }
+
+    #[test]
+    fn test_geometric_product_bivector_squares() {
+        let one: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_scalar();
+
+        // Boost planes square to one, and rotation planes square to minus one.
+        assert_eq!(SpacetimeMultivector::unit_gamma01() * SpacetimeMultivector::unit_gamma01(), one);
+        assert_eq!(SpacetimeMultivector::unit_gamma02() * SpacetimeMultivector::unit_gamma02(), one);
+        assert_eq!(SpacetimeMultivector::unit_gamma03() * SpacetimeMultivector::unit_gamma03(), one);
+        assert_eq!(SpacetimeMultivector::unit_gamma12() * SpacetimeMultivector::unit_gamma12(), -one);
+        assert_eq!(SpacetimeMultivector::unit_gamma13() * SpacetimeMultivector::unit_gamma13(), -one);
+        assert_eq!(SpacetimeMultivector::unit_gamma23() * SpacetimeMultivector::unit_gamma23(), -one);
+    }
+
+    #[test]
+    fn test_geometric_product_pseudoscalar_squares_to_minus_one() {
+        let gamma0123: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma0123();
+        let one: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_scalar();
+
+        assert_eq!(gamma0123 * gamma0123, -one);
+    }
+
+    #[test]
+    fn test_geometric_product_orthogonal_bivectors_commute() {
+        let gamma01: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma01();
+        let gamma23: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma23();
+        let gamma0123: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma0123();
+
+        assert_eq!(gamma01 * gamma23, gamma0123);
+        assert_eq!(gamma23 * gamma01, gamma0123);
+    }
+
+    #[test]
+    fn test_geometric_product_pseudoscalar_commutes_with_even_elements() {
+        let mv = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let even = mv.grade(0) + mv.grade(2) + mv.grade(4);
+        let odd = mv.grade(1) + mv.grade(3);
+        let gamma0123: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma0123();
+
+        assert_eq!(gamma0123 * even, even * gamma0123);
+        assert_eq!(gamma0123 * odd, -(odd * gamma0123));
+    }
+
+    #[test]
+    fn test_geometric_product() {
+        let mv1 = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = SpacetimeMultivector::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = SpacetimeMultivector::new(
+            -18_i32, 4_i32, -139_i32, 401_i32, 154_i32, -69_i32, 343_i32, 112_i32, -268_i32, 405_i32, 89_i32, -290_i32, 363_i32, 3_i32,
+            132_i32, 98_i32,
+        );
+        let result = mv1 * mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_geometric_product_references() {
+        let mv1 = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = SpacetimeMultivector::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = mv1 * mv2;
+
+        assert_eq!(&mv1 * mv2, expected);
+        assert_eq!(mv1 * &mv2, expected);
+        assert_eq!(&mv1 * &mv2, expected);
+    }
+
+    #[test]
+    fn test_geometric_product_associative() {
+        let mv1 = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = SpacetimeMultivector::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let mv3 = SpacetimeMultivector::new(
+            2_i32, 0_i32, -1_i32, 3_i32, 1_i32, -2_i32, 4_i32, 0_i32, 1_i32, -3_i32, 2_i32, 5_i32, -1_i32, 0_i32, 2_i32, -4_i32,
+        );
+
+        assert_eq!((mv1 * mv2) * mv3, mv1 * (mv2 * mv3));
+    }
+}
+
+
+#[cfg(test)]
+mod sta_outer_product_tests {
+    use cggeomalg::sta::SpacetimeMultivector;
+
+
+    #[test]
+    fn test_outer_product_gamma0_gamma1() {
+        let gamma0: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma0();
+        let gamma1: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma1();
+        let gamma01: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma01();
+
+        assert_eq!(gamma0 ^ gamma1, gamma01);
+        assert_eq!(gamma1 ^ gamma0, -gamma01);
+    }
+
+    #[test]
+    fn test_outer_product_vector_with_itself_is_zero() {
+        let gamma0: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma0();
+        let gamma2: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma2();
+        let zero: SpacetimeMultivector<i32> = SpacetimeMultivector::zero();
+
+        assert_eq!(gamma0 ^ gamma0, zero);
+        assert_eq!(gamma2 ^ gamma2, zero);
+    }
+
+    #[test]
+    fn test_outer_product_vectors_pseudoscalar() {
+        let gamma0: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma0();
+        let gamma1: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma1();
+        let gamma2: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma2();
+        let gamma3: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma3();
+        let gamma0123: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma0123();
+
+        assert_eq!(gamma0 ^ gamma1 ^ gamma2 ^ gamma3, gamma0123);
+        assert_eq!(gamma1 ^ gamma0 ^ gamma2 ^ gamma3, -gamma0123);
+    }
+
+    #[test]
+    fn test_outer_product_electromagnetic_invariant() {
+        let gamma01: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma01();
+        let gamma23: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma23();
+        let field = gamma01 + gamma23;
+        let expected = SpacetimeMultivector::unit_gamma0123() * 2_i32;
+
+        assert_eq!(field ^ field, expected);
+    }
+
+    #[test]
+    fn test_outer_product() {
+        let mv1 = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = SpacetimeMultivector::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = SpacetimeMultivector::new(
+            3_i32, 5_i32, 13_i32, 13_i32, 10_i32, 38_i32, 29_i32, 13_i32, 19_i32, -2_i32, 3_i32, 53_i32, 45_i32, 5_i32, 41_i32, 98_i32,
+        );
+        let result = mv1 ^ mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_outer_product_references() {
+        let mv1 = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = SpacetimeMultivector::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = mv1 ^ mv2;
+
+        assert_eq!(&mv1 ^ mv2, expected);
+        assert_eq!(mv1 ^ &mv2, expected);
+        assert_eq!(&mv1 ^ &mv2, expected);
+        assert_eq!(mv1.outer_product(&mv2), expected);
+    }
+
+    #[test]
+    fn test_outer_product_associative() {
+        let mv1 = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = SpacetimeMultivector::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let mv3 = SpacetimeMultivector::new(
+            2_i32, 0_i32, -1_i32, 3_i32, 1_i32, -2_i32, 4_i32, 0_i32, 1_i32, -3_i32, 2_i32, 5_i32, -1_i32, 0_i32, 2_i32, -4_i32,
+        );
+
+        assert_eq!((mv1 ^ mv2) ^ mv3, mv1 ^ (mv2 ^ mv3));
+    }
+}
+
+
+#[cfg(test)]
+mod sta_grade_tests {
+    use cggeomalg::sta::SpacetimeMultivector;
+
+
+    #[test]
+    fn test_grade_decomposition() {
+        let mv = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let result = mv.grade(0) + mv.grade(1) + mv.grade(2) + mv.grade(3) + mv.grade(4);
+
+        assert_eq!(result, mv);
+    }
+
+    #[test]
+    fn test_grade_projection() {
+        let mv = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+
+        assert_eq!(mv.grade(0), SpacetimeMultivector::from_scalar(1_i32));
+        assert_eq!(
+            mv.grade(2),
+            SpacetimeMultivector::new(
+                0_i32, 0_i32, 0_i32, 0_i32, 0_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 0_i32, 0_i32, 0_i32, 0_i32, 0_i32,
+            )
+        );
+        assert_eq!(mv.grade(4), SpacetimeMultivector::unit_gamma0123() * 16_i32);
+        assert!(mv.grade(5).is_zero());
+    }
+
+    #[test]
+    fn test_reverse_reverse() {
+        let mv = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+
+        assert_eq!(mv.reverse().reverse(), mv);
+    }
+
+    #[test]
+    fn test_reverse_product() {
+        let mv1 = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = SpacetimeMultivector::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+
+        assert_eq!((mv1 * mv2).reverse(), mv2.reverse() * mv1.reverse());
+    }
+
+    #[test]
+    fn test_involute_product() {
+        let mv1 = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = SpacetimeMultivector::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+
+        assert_eq!((mv1 * mv2).involute(), mv1.involute() * mv2.involute());
+    }
+
+    #[test]
+    fn test_conjugate_is_reverse_of_involute() {
+        let mv = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+
+        assert_eq!(mv.conjugate(), mv.involute().reverse());
+    }
+}
+
+
+#[cfg(test)]
+mod sta_scalar_product_tests {
+    use cggeomalg::sta::SpacetimeMultivector;
+
+
+    #[test]
+    fn test_scalar_product_minkowski_metric() {
+        let gamma0: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma0();
+        let gamma1: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma1();
+        let gamma3: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma3();
+        let one: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_scalar();
+        let zero: SpacetimeMultivector<i32> = SpacetimeMultivector::zero();
+
+        assert_eq!(gamma0 | gamma0, one);
+        assert_eq!(gamma1 | gamma1, -one);
+        assert_eq!(gamma3 | gamma3, -one);
+        assert_eq!(gamma0 | gamma3, zero);
+    }
+
+    #[test]
+    fn test_scalar_product_null_vector() {
+        let light_ray = SpacetimeMultivector::unit_gamma0() + SpacetimeMultivector::unit_gamma1();
+        let zero: SpacetimeMultivector<i32> = SpacetimeMultivector::zero();
+
+        assert_eq!(light_ray | light_ray, zero);
+        assert_eq!(light_ray * light_ray, zero);
+    }
+
+    #[test]
+    fn test_scalar_product_is_scalar_part_of_geometric_product() {
+        let mv1 = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = SpacetimeMultivector::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = (mv1 * mv2.reverse()).grade(0);
+        let result = mv1 | mv2;
+
+        assert_eq!(result, expected);
+        assert_eq!(result, SpacetimeMultivector::from_scalar(-58_i32));
+    }
+
+    #[test]
+    fn test_scalar_product_references() {
+        let mv1 = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = SpacetimeMultivector::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = mv1 | mv2;
+
+        assert_eq!(&mv1 | mv2, expected);
+        assert_eq!(mv1 | &mv2, expected);
+        assert_eq!(&mv1 | &mv2, expected);
+        assert_eq!(mv1.scalar_product(&mv2), expected);
+    }
+}
+
+
+#[cfg(test)]
+mod sta_left_contraction_tests {
+    use cggeomalg::sta::SpacetimeMultivector;
+
+
+    #[test]
+    fn test_left_contraction_vector_bivector() {
+        let gamma0: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma0();
+        let gamma1: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma1();
+        let gamma2: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma2();
+        let gamma01: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma01();
+        let gamma12: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma12();
+
+        assert_eq!(gamma0 << gamma01, gamma1);
+        assert_eq!(gamma1 << gamma01, gamma0);
+        assert_eq!(gamma2 << gamma12, gamma1);
+    }
+
+    #[test]
+    fn test_left_contraction_higher_grade_is_zero() {
+        let gamma01: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma01();
+        let gamma0: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma0();
+        let zero: SpacetimeMultivector<i32> = SpacetimeMultivector::zero();
+
+        assert_eq!(gamma01 << gamma0, zero);
+    }
+
+    #[test]
+    fn test_left_contraction() {
+        let mv1 = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = SpacetimeMultivector::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = SpacetimeMultivector::new(
+            -18_i32, -126_i32, -212_i32, 198_i32, -118_i32, -101_i32, 91_i32, -34_i32, 0_i32, 36_i32, -28_i32, 23_i32, -3_i32, 2_i32,
+            15_i32, 3_i32,
+        );
+        let result = mv1 << mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_left_contraction_references() {
+        let mv1 = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = SpacetimeMultivector::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = mv1 << mv2;
+
+        assert_eq!(&mv1 << mv2, expected);
+        assert_eq!(mv1 << &mv2, expected);
+        assert_eq!(&mv1 << &mv2, expected);
+        assert_eq!(mv1.left_contract(&mv2), expected);
+    }
+}
+
+
+#[cfg(test)]
+mod sta_right_contraction_tests {
+    use cggeomalg::sta::SpacetimeMultivector;
+
+
+    #[test]
+    fn test_right_contraction_bivector_vector() {
+        let gamma0: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma0();
+        let gamma1: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma1();
+        let gamma01: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma01();
+        let zero: SpacetimeMultivector<i32> = SpacetimeMultivector::zero();
+
+        assert_eq!(gamma01 >> gamma1, -gamma0);
+        assert_eq!(gamma01 >> gamma0, -gamma1);
+        assert_eq!(gamma0 >> gamma01, zero);
+    }
+
+    #[test]
+    fn test_right_contraction_reverse() {
+        let mv1 = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = SpacetimeMultivector::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+
+        assert_eq!((mv1 >> mv2).reverse(), mv2.reverse() << mv1.reverse());
+    }
+
+    #[test]
+    fn test_right_contraction() {
+        let mv1 = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = SpacetimeMultivector::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = SpacetimeMultivector::new(
+            -18_i32, 130_i32, 73_i32, 203_i32, 272_i32, 151_i32, 187_i32, 10_i32, -6_i32, 0_i32, 103_i32, 116_i32, 55_i32, -22_i32, 61_i32,
+            48_i32,
+        );
+        let result = mv1 >> mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_right_contraction_references() {
+        let mv1 = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = SpacetimeMultivector::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = mv1 >> mv2;
+
+        assert_eq!(&mv1 >> mv2, expected);
+        assert_eq!(mv1 >> &mv2, expected);
+        assert_eq!(&mv1 >> &mv2, expected);
+        assert_eq!(mv1.right_contract(&mv2), expected);
+    }
+}
+
+
+#[cfg(test)]
+mod sta_duality_tests {
+    use cggeomalg::sta::SpacetimeMultivector;
+
+
+    #[test]
+    fn test_dual_is_product_with_inverse_pseudoscalar() {
+        let mv = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let inv_ps: SpacetimeMultivector<i32> = -SpacetimeMultivector::unit_gamma0123();
+
+        assert_eq!(mv.dual(), mv * inv_ps);
+    }
+
+    #[test]
+    fn test_undual_is_product_with_pseudoscalar() {
+        let mv = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let ps: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma0123();
+
+        assert_eq!(mv.undual(), mv * ps);
+        assert_eq!(mv.undual(), -mv.dual());
+    }
+
+    #[test]
+    fn test_dual_undual() {
+        let mv = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+
+        assert_eq!(mv.dual().undual(), mv);
+        assert_eq!(mv.undual().dual(), mv);
+    }
+
+    #[test]
+    fn test_dual_of_time_axis() {
+        let gamma0: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma0();
+        let gamma123: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma123();
+        let gamma0123: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma0123();
+
+        assert_eq!(gamma0.dual(), -gamma123);
+        assert_eq!(gamma0 ^ gamma0.dual(), -gamma0123);
+    }
+
+    #[test]
+    fn test_not_is_dual() {
+        let mv = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+
+        assert_eq!(!mv, mv.dual());
+        assert_eq!(!&mv, mv.dual());
+    }
+
+    #[test]
+    fn test_dual_mut() {
+        let mv = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mut result = mv;
+        result.dual_mut();
+
+        assert_eq!(result, mv.dual());
+    }
+
+    #[test]
+    fn test_pseudoscalar_inverse() {
+        let ps: SpacetimeMultivector<f64> = SpacetimeMultivector::pseudoscalar();
+        let ps_inv: SpacetimeMultivector<f64> = SpacetimeMultivector::inv_pseudoscalar();
+        let one: SpacetimeMultivector<f64> = SpacetimeMultivector::unit_scalar();
+
+        assert_eq!(ps * ps_inv, one);
+        assert_eq!(ps_inv * ps, one);
+    }
+}
+
+
+#[cfg(test)]
+mod sta_regressive_product_tests {
+    use cggeomalg::sta::SpacetimeMultivector;
+
+
+    #[test]
+    fn test_regressive_product() {
+        let mv1 = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = SpacetimeMultivector::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = SpacetimeMultivector::new(
+            32_i32, -23_i32, 232_i32, 142_i32, -21_i32, 158_i32, 249_i32, 145_i32, 119_i32, 96_i32, -278_i32, 164_i32, 183_i32, -70_i32,
+            189_i32, 48_i32,
+        );
+        let result = mv1 & mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_regressive_product_references() {
+        let mv1 = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = SpacetimeMultivector::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = mv1 & mv2;
+
+        assert_eq!(&mv1 & mv2, expected);
+        assert_eq!(mv1 & &mv2, expected);
+        assert_eq!(&mv1 & &mv2, expected);
+        assert_eq!(mv1.regressive_product(&mv2), expected);
+    }
+
+    #[test]
+    fn test_regressive_product_dual_outer_product() {
+        let mv1 = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+        let mv2 = SpacetimeMultivector::new(
+            3_i32, -1_i32, 4_i32, 1_i32, -5_i32, 9_i32, 2_i32, -6_i32, 5_i32, 3_i32, -5_i32, 8_i32, 9_i32, -7_i32, 9_i32, 3_i32,
+        );
+        let expected = (mv1.dual() ^ mv2.dual()).undual();
+        let result = mv1 & mv2;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_regressive_product_pseudoscalar_unit() {
+        let gamma0123: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma0123();
+        let mv = SpacetimeMultivector::new(
+            1_i32, 2_i32, 3_i32, 4_i32, 5_i32, 6_i32, 7_i32, 8_i32, 9_i32, 10_i32, 11_i32, 12_i32, 13_i32, 14_i32, 15_i32, 16_i32,
+        );
+
+        assert_eq!(gamma0123 & mv, mv);
+        assert_eq!(mv & gamma0123, mv);
+    }
+
+    #[test]
+    fn test_regressive_product_intersection_of_hyperplanes() {
+        let gamma012: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma012();
+        let gamma013: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma013();
+        let gamma01: SpacetimeMultivector<i32> = SpacetimeMultivector::unit_gamma01();
+
+        assert_eq!(gamma012 & gamma013, -gamma01);
+    }
+}
+
+
+#[cfg(test)]
+mod sta_inversion_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::sta::SpacetimeMultivector;
+
+
+    #[test]
+    fn test_inverse() {
+        let mv = SpacetimeMultivector::new(
+            1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64, 9_f64, 10_f64, 11_f64, 12_f64, 13_f64, 14_f64, 15_f64, 16_f64,
+        );
+        let mv_inv = mv.inverse().unwrap();
+        let one: SpacetimeMultivector<f64> = SpacetimeMultivector::unit_scalar();
+
+        assert_relative_eq!(mv * mv_inv, one, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(mv_inv * mv, one, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_inverse_timelike_vector() {
+        let vector = SpacetimeMultivector::new(
+            0_f64, 5_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64,
+        );
+        let expected = vector * (1_f64 / 16_f64);
+        let result = vector.inverse().unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_inverse_spacelike_vector() {
+        let vector = SpacetimeMultivector::new(
+            0_f64, 3_f64, 0_f64, 5_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64,
+        );
+        let expected = vector * (-1_f64 / 16_f64);
+        let result = vector.inverse().unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_null_vector_is_not_invertible() {
+        let light_ray = SpacetimeMultivector::new(
+            0_f64, 1_f64, 0_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64,
+        );
+
+        assert!(!light_ray.is_invertible());
+        assert!(light_ray.inverse().is_none());
+    }
+
+    #[test]
+    fn test_zero_divisor_is_not_invertible() {
+        let idempotent = (SpacetimeMultivector::unit_scalar() + SpacetimeMultivector::unit_gamma0()) * 0.5_f64;
+
+        assert!(!idempotent.is_invertible());
+        assert!(idempotent.inverse().is_none());
+        assert_eq!(idempotent * idempotent, idempotent);
+    }
+
+    #[test]
+    fn test_zero_is_not_invertible() {
+        let zero: SpacetimeMultivector<f64> = SpacetimeMultivector::zero();
+
+        assert!(zero.inverse().is_none());
+    }
+
+    #[test]
+    fn test_division() {
+        let mv1 = SpacetimeMultivector::new(
+            1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64, 9_f64, 10_f64, 11_f64, 12_f64, 13_f64, 14_f64, 15_f64, 16_f64,
+        );
+        let mv2 = SpacetimeMultivector::new(
+            3_f64, -1_f64, 4_f64, 1_f64, -5_f64, 9_f64, 2_f64, -6_f64, 5_f64, 3_f64, -5_f64, 8_f64, 9_f64, -7_f64, 9_f64, 3_f64,
+        );
+        let expected = mv1 * mv2.inverse().unwrap();
+        let result = mv1 / mv2;
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(&mv1 / mv2, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(mv1 / &mv2, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(&mv1 / &mv2, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+
+#[cfg(test)]
+mod sta_commutator_tests {
+    use cggeomalg::sta::SpacetimeMultivector;
+
+
+    #[test]
+    fn test_commutator_boost_generators() {
+        let gamma01: SpacetimeMultivector<f64> = SpacetimeMultivector::unit_gamma01();
+        let gamma02: SpacetimeMultivector<f64> = SpacetimeMultivector::unit_gamma02();
+        let gamma12: SpacetimeMultivector<f64> = SpacetimeMultivector::unit_gamma12();
+
+        // Two boosts in different directions do not close on boosts: their
+        // commutator generates a rotation (the Thomas rotation).
+        assert_eq!(gamma01.commutator(&gamma02), -gamma12);
+        assert_eq!(gamma01.x(&gamma02), -gamma12);
+    }
+
+    #[test]
+    fn test_commutator_anticommutator_sum() {
+        let mv1 = SpacetimeMultivector::new(
+            1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64, 9_f64, 10_f64, 11_f64, 12_f64, 13_f64, 14_f64, 15_f64, 16_f64,
+        );
+        let mv2 = SpacetimeMultivector::new(
+            3_f64, -1_f64, 4_f64, 1_f64, -5_f64, 9_f64, 2_f64, -6_f64, 5_f64, 3_f64, -5_f64, 8_f64, 9_f64, -7_f64, 9_f64, 3_f64,
+        );
+
+        assert_eq!(mv1.commutator(&mv2) + mv1.anticommutator(&mv2), mv1 * mv2);
+    }
+}
+
+
+#[cfg(test)]
+mod sta_spacetime_split_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::e3ga::EuclideanMultivector3;
+    use cggeomalg::sta::SpacetimeMultivector;
+
+
+    #[test]
+    fn test_spacetime_split() {
+        let event = SpacetimeMultivector::new(
+            0_f64, 5_f64, 1_f64, -2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64,
+        );
+        let (time, position) = event.spacetime_split();
+
+        assert_eq!(time, 5_f64);
+        assert_eq!(
+            position,
+            EuclideanMultivector3::new(0_f64, 1_f64, -2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64)
+        );
+    }
+
+    #[test]
+    fn test_spacetime_split_is_product_with_gamma0() {
+        let event = SpacetimeMultivector::new(
+            0_f64, 5_f64, 1_f64, -2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64,
+        );
+        let gamma0: SpacetimeMultivector<f64> = SpacetimeMultivector::unit_gamma0();
+        let (time, position) = event.spacetime_split();
+        let expected = SpacetimeMultivector::from_scalar(time) + SpacetimeMultivector::from_relative(&position);
+        let result = event * gamma0;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_from_spacetime_split() {
+        let position = EuclideanMultivector3::new(0_f64, 1_f64, -2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let expected = SpacetimeMultivector::new(
+            0_f64, 5_f64, 1_f64, -2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64,
+        );
+        let result = SpacetimeMultivector::from_spacetime_split(5_f64, &position);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_spacetime_interval() {
+        let position = EuclideanMultivector3::new(0_f64, 1_f64, -2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let event = SpacetimeMultivector::from_spacetime_split(5_f64, &position);
+        let expected = 25_f64 - position.magnitude_squared();
+
+        assert_relative_eq!((event * event)[0], expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_to_relative_relative_vectors() {
+        let gamma0: SpacetimeMultivector<f64> = SpacetimeMultivector::unit_gamma0();
+        let sigma1 = SpacetimeMultivector::unit_gamma1() * gamma0;
+        let sigma2 = SpacetimeMultivector::unit_gamma2() * gamma0;
+        let sigma3 = SpacetimeMultivector::unit_gamma3() * gamma0;
+
+        assert_eq!(sigma1.to_relative(), EuclideanMultivector3::unit_e1());
+        assert_eq!(sigma2.to_relative(), EuclideanMultivector3::unit_e2());
+        assert_eq!(sigma3.to_relative(), EuclideanMultivector3::unit_e3());
+    }
+
+    #[test]
+    fn test_to_relative_pseudoscalar() {
+        let gamma0123: SpacetimeMultivector<f64> = SpacetimeMultivector::unit_gamma0123();
+        let e123: EuclideanMultivector3<f64> = EuclideanMultivector3::unit_e123();
+
+        assert_eq!(gamma0123.to_relative(), e123);
+    }
+
+    #[test]
+    fn test_to_relative_ignores_odd_part() {
+        let mv = SpacetimeMultivector::new(
+            1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64, 9_f64, 10_f64, 11_f64, 12_f64, 13_f64, 14_f64, 15_f64, 16_f64,
+        );
+        let even = mv.grade(0) + mv.grade(2) + mv.grade(4);
+
+        assert_eq!(mv.to_relative(), even.to_relative());
+    }
+
+    #[test]
+    fn test_relative_round_trip() {
+        let mv = EuclideanMultivector3::new(1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64);
+        let result = SpacetimeMultivector::from_relative(&mv).to_relative();
+
+        assert_eq!(result, mv);
+    }
+
+    #[test]
+    fn test_from_relative_is_homomorphism() {
+        let mv1 = EuclideanMultivector3::new(1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64);
+        let mv2 = EuclideanMultivector3::new(-2_f64, 1_f64, 0_f64, 3_f64, -1_f64, 2_f64, 4_f64, -3_f64);
+        let expected = SpacetimeMultivector::from_relative(&(mv1 * mv2));
+        let result = SpacetimeMultivector::from_relative(&mv1) * SpacetimeMultivector::from_relative(&mv2);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_to_relative_is_homomorphism() {
+        let mv1 = SpacetimeMultivector::new(
+            1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64, 9_f64, 10_f64, 11_f64, 12_f64, 13_f64, 14_f64, 15_f64, 16_f64,
+        );
+        let mv2 = SpacetimeMultivector::new(
+            3_f64, -1_f64, 4_f64, 1_f64, -5_f64, 9_f64, 2_f64, -6_f64, 5_f64, 3_f64, -5_f64, 8_f64, 9_f64, -7_f64, 9_f64, 3_f64,
+        );
+        let even1 = mv1.grade(0) + mv1.grade(2) + mv1.grade(4);
+        let even2 = mv2.grade(0) + mv2.grade(2) + mv2.grade(4);
+        let expected = even1.to_relative() * even2.to_relative();
+        let result = (even1 * even2).to_relative();
+
+        assert_eq!(result, expected);
+    }
+}
+
+
+#[cfg(test)]
+mod sta_lorentz_transformation_tests {
+    use approx_cmp::assert_relative_eq;
+    use cggeomalg::e3ga::EuclideanMultivector3;
+    use cggeomalg::sta::SpacetimeMultivector;
+    use core::f64;
+
+
+    #[test]
+    fn test_boost_is_unit_rotor() {
+        let direction = EuclideanMultivector3::new(0_f64, 1_f64, -2_f64, 2_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let boost = SpacetimeMultivector::boost(&direction, 0.8_f64);
+        let one: SpacetimeMultivector<f64> = SpacetimeMultivector::unit_scalar();
+
+        assert_relative_eq!(boost * boost.reverse(), one, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_boost_observer() {
+        let direction = EuclideanMultivector3::new(0_f64, 1_f64, -2_f64, 2_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let rapidity = 0.8_f64;
+        let boost = SpacetimeMultivector::boost(&direction, rapidity);
+        let gamma0: SpacetimeMultivector<f64> = SpacetimeMultivector::unit_gamma0();
+        let (time, position) = gamma0.transform(&boost).spacetime_split();
+
+        assert_relative_eq!(time, rapidity.cosh(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(
+            position,
+            direction.normalize() * rapidity.sinh(),
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_boost_orthogonal_direction_is_unchanged() {
+        let direction = EuclideanMultivector3::new(0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let boost = SpacetimeMultivector::boost(&direction, 1.2_f64);
+        let gamma2: SpacetimeMultivector<f64> = SpacetimeMultivector::unit_gamma2();
+        let gamma3: SpacetimeMultivector<f64> = SpacetimeMultivector::unit_gamma3();
+
+        assert_relative_eq!(
+            gamma2.transform(&boost),
+            gamma2,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            gamma3.transform(&boost),
+            gamma3,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_boost_rapidities_add() {
+        let direction = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let boost1 = SpacetimeMultivector::boost(&direction, 0.3_f64);
+        let boost2 = SpacetimeMultivector::boost(&direction, 0.9_f64);
+        let expected = SpacetimeMultivector::boost(&direction, 1.2_f64);
+        let result = boost1 * boost2;
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_boost_inverse() {
+        let direction = EuclideanMultivector3::new(0_f64, 3_f64, 1_f64, -1_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let boost = SpacetimeMultivector::boost(&direction, 0.7_f64);
+        let expected = SpacetimeMultivector::boost(&-direction, 0.7_f64);
+
+        assert_relative_eq!(boost.reverse(), expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_boost_preserves_interval() {
+        let direction = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let boost = SpacetimeMultivector::boost(&direction, 2.5_f64);
+        let event = SpacetimeMultivector::new(
+            0_f64, 3_f64, 1_f64, -2_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64,
+        );
+        let result = event.transform(&boost);
+
+        assert_relative_eq!(
+            (result * result)[0],
+            (event * event)[0],
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_boost_preserves_null_vectors() {
+        let direction = EuclideanMultivector3::new(0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let boost = SpacetimeMultivector::boost(&direction, 1.5_f64);
+        let light_ray = SpacetimeMultivector::new(
+            0_f64, 1_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64,
+        );
+        let result = light_ray.transform(&boost);
+
+        assert_relative_eq!((result * result)[0], 0_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_boost_from_velocity() {
+        let velocity = EuclideanMultivector3::new(0_f64, 0.3_f64, -0.2_f64, 0.6_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let boost = SpacetimeMultivector::boost_from_velocity(&velocity).unwrap();
+        let gamma0: SpacetimeMultivector<f64> = SpacetimeMultivector::unit_gamma0();
+        let (time, position) = gamma0.transform(&boost).spacetime_split();
+        let lorentz_factor = 1_f64 / (1_f64 - velocity.magnitude_squared()).sqrt();
+
+        assert_relative_eq!(time, lorentz_factor, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(
+            position,
+            velocity * lorentz_factor,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_boost_from_zero_velocity() {
+        let velocity = EuclideanMultivector3::zero();
+        let boost = SpacetimeMultivector::boost_from_velocity(&velocity).unwrap();
+        let one: SpacetimeMultivector<f64> = SpacetimeMultivector::unit_scalar();
+
+        assert_eq!(boost, one);
+    }
+
+    #[test]
+    fn test_boost_from_velocity_faster_than_light() {
+        let light_speed = EuclideanMultivector3::new(0_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let faster_than_light = EuclideanMultivector3::new(0_f64, 0_f64, 1_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+
+        assert!(SpacetimeMultivector::boost_from_velocity(&light_speed).is_none());
+        assert!(SpacetimeMultivector::boost_from_velocity(&faster_than_light).is_none());
+    }
+
+    #[test]
+    fn test_boost_time_dilation() {
+        let velocity = EuclideanMultivector3::new(0_f64, 0.6_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let boost = SpacetimeMultivector::boost_from_velocity(&velocity).unwrap();
+        // One unit of proper time of a clock at rest in the moving frame.
+        let tick = SpacetimeMultivector::unit_gamma0().transform(&boost);
+        let (time, _) = tick.spacetime_split();
+
+        assert_relative_eq!(time, 1.25_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotation() {
+        let axis = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let rotation = SpacetimeMultivector::rotation(&axis, f64::consts::FRAC_PI_2);
+        let gamma1: SpacetimeMultivector<f64> = SpacetimeMultivector::unit_gamma1();
+        let gamma2: SpacetimeMultivector<f64> = SpacetimeMultivector::unit_gamma2();
+        let gamma3: SpacetimeMultivector<f64> = SpacetimeMultivector::unit_gamma3();
+
+        assert_relative_eq!(
+            gamma1.transform(&rotation),
+            gamma2,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            gamma2.transform(&rotation),
+            -gamma1,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            gamma3.transform(&rotation),
+            gamma3,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_rotation_fixes_observer() {
+        let axis = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, -2_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let rotation = SpacetimeMultivector::rotation(&axis, 1.3_f64);
+        let gamma0: SpacetimeMultivector<f64> = SpacetimeMultivector::unit_gamma0();
+
+        assert_relative_eq!(
+            gamma0.transform(&rotation),
+            gamma0,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_rotation_matches_euclidean_rotation() {
+        let axis = EuclideanMultivector3::new(0_f64, 1_f64, 2_f64, -2_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let angle = 1.3_f64;
+        let rotation = SpacetimeMultivector::rotation(&axis, angle);
+        let position = EuclideanMultivector3::new(0_f64, 3_f64, -1_f64, 2_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let event = SpacetimeMultivector::from_spacetime_split(4_f64, &position);
+        let expected = position.rotate(&EuclideanMultivector3::from_axis_angle(&axis, angle));
+        let (time, result) = event.transform(&rotation).spacetime_split();
+
+        assert_relative_eq!(time, 4_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotation_of_boost_direction() {
+        let axis = EuclideanMultivector3::new(0_f64, 0_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let rotation = SpacetimeMultivector::rotation(&axis, f64::consts::FRAC_PI_2);
+        let boost_x = SpacetimeMultivector::boost(
+            &EuclideanMultivector3::new(0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64),
+            0.4_f64,
+        );
+        let boost_y = SpacetimeMultivector::boost(
+            &EuclideanMultivector3::new(0_f64, 0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64),
+            0.4_f64,
+        );
+        let result = boost_x.transform(&rotation);
+
+        assert_relative_eq!(result, boost_y, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_lorentz_transformation_composition() {
+        let boost = SpacetimeMultivector::boost(
+            &EuclideanMultivector3::new(0_f64, 1_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64),
+            0.9_f64,
+        );
+        let rotation = SpacetimeMultivector::rotation(
+            &EuclideanMultivector3::new(0_f64, 0_f64, 1_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64),
+            0.6_f64,
+        );
+        let lorentz = rotation * boost;
+        let event = SpacetimeMultivector::new(
+            0_f64, 2_f64, 1_f64, -1_f64, 3_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64,
+        );
+        let expected = event.transform(&boost).transform(&rotation);
+        let result = event.transform(&lorentz);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_transform_electromagnetic_field() {
+        // A pure electric field along `x` is unchanged by a boost along `x`.
+        let electric = EuclideanMultivector3::new(0_f64, 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let field = SpacetimeMultivector::from_relative(&electric);
+        let boost = SpacetimeMultivector::boost(&electric, 0.9_f64);
+
+        assert_relative_eq!(field.transform(&boost), field, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}